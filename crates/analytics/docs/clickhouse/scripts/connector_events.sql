@@ -12,7 +12,8 @@ CREATE TABLE connector_events_queue (
     `latency` UInt128,
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
-    `refund_id` Nullable(String)
+    `refund_id` Nullable(String),
+    `error_class` LowCardinality(Nullable(String))
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-outgoing-connector-events',
 kafka_group_name = 'hyper',
@@ -56,6 +57,7 @@ CREATE TABLE connector_events (
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
     `refund_id` Nullable(String),
+    `error_class` LowCardinality(Nullable(String)),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector_name TYPE bloom_filter GRANULARITY 1,
     INDEX statusIndex status_code TYPE bloom_filter GRANULARITY 1
@@ -150,7 +152,8 @@ CREATE MATERIALIZED VIEW connector_events_mv TO connector_events (
     `latency` UInt128,
     `method` LowCardinality(String),
     `refund_id` Nullable(String),
-    `dispute_id` Nullable(String)
+    `dispute_id` Nullable(String),
+    `error_class` LowCardinality(Nullable(String))
 ) AS
 SELECT
     merchant_id,
@@ -168,7 +171,8 @@ SELECT
     latency,
     method,
     refund_id,
-    dispute_id
+    dispute_id,
+    error_class
 FROM
     connector_events_queue
 WHERE
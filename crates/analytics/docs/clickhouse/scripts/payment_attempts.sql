@@ -37,6 +37,7 @@ CREATE TABLE payment_attempt_queue (
     `net_amount` Nullable(UInt64),
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
+    `unified_error_details` Nullable(String),
     `mandate_data` Nullable(String),
     `client_source` LowCardinality(Nullable(String)),
     `client_version` LowCardinality(Nullable(String)),
@@ -86,6 +87,7 @@ CREATE TABLE payment_attempts (
     `net_amount` Nullable(UInt64),
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
+    `unified_error_details` Nullable(String),
     `mandate_data` Nullable(String),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `client_source` LowCardinality(Nullable(String)),
@@ -139,6 +141,7 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `net_amount` Nullable(UInt64),
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
+    `unified_error_details` Nullable(String),
     `mandate_data` Nullable(String),
     `inserted_at` DateTime64(3),
     `client_source` LowCardinality(Nullable(String)),
@@ -184,6 +187,7 @@ SELECT
     net_amount,
     unified_code,
     unified_message,
+    unified_error_details,
     mandate_data,
     now() AS inserted_at,
     client_source,
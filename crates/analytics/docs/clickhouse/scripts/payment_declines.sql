@@ -0,0 +1,92 @@
+-- Pre-aggregation of the declined payment attempts backing the decline analytics. The table is
+-- maintained by the materialized view below as the attempt events are consumed from kafka, the
+-- attempts being bucketed by the day in which they were created. The issuer of the card is read
+-- from the payment method data of the attempt, and the decline category and the advice of the
+-- card network from the unified error details of the attempt.
+
+CREATE TABLE payment_declines (
+    `merchant_id` LowCardinality(String),
+    `connector` LowCardinality(String),
+    `card_network` LowCardinality(String),
+    `card_isin` String,
+    `card_issuer` String,
+    `error_code` String,
+    `decline_category` LowCardinality(String),
+    `network_advice_code` LowCardinality(String),
+    `created_at` DateTime CODEC(T64, LZ4),
+    `attempts` AggregateFunction(uniq, String)
+) ENGINE = AggregatingMergeTree
+PARTITION BY toStartOfMonth(created_at)
+ORDER BY
+    (
+        merchant_id,
+        created_at,
+        connector,
+        card_network,
+        card_isin,
+        card_issuer,
+        error_code,
+        decline_category,
+        network_advice_code
+    )
+TTL created_at + toIntervalMonth(18)
+SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW payment_declines_mv TO payment_declines (
+    `merchant_id` String,
+    `connector` LowCardinality(String),
+    `card_network` LowCardinality(String),
+    `card_isin` String,
+    `card_issuer` String,
+    `error_code` String,
+    `decline_category` LowCardinality(String),
+    `network_advice_code` LowCardinality(String),
+    `created_at` DateTime,
+    `attempts` AggregateFunction(uniq, String)
+) AS
+SELECT
+    merchant_id,
+    ifNull(connector, 'unknown') AS connector,
+    if(
+        JSONExtractString(ifNull(payment_method_data, ''), 'card', 'card_network') = '',
+        'unknown',
+        JSONExtractString(ifNull(payment_method_data, ''), 'card', 'card_network')
+    ) AS card_network,
+    if(
+        JSONExtractString(ifNull(payment_method_data, ''), 'card', 'card_isin') = '',
+        'unknown',
+        JSONExtractString(ifNull(payment_method_data, ''), 'card', 'card_isin')
+    ) AS card_isin,
+    if(
+        JSONExtractString(ifNull(payment_method_data, ''), 'card', 'card_issuer') = '',
+        'unknown',
+        JSONExtractString(ifNull(payment_method_data, ''), 'card', 'card_issuer')
+    ) AS card_issuer,
+    ifNull(error_code, 'unknown') AS error_code,
+    if(
+        JSONExtractString(ifNull(unified_error_details, ''), 'decline_category') = '',
+        'unknown',
+        JSONExtractString(ifNull(unified_error_details, ''), 'decline_category')
+    ) AS decline_category,
+    if(
+        JSONExtractString(ifNull(unified_error_details, ''), 'network_advice_code') = '',
+        'unknown',
+        JSONExtractString(ifNull(unified_error_details, ''), 'network_advice_code')
+    ) AS network_advice_code,
+    toStartOfDay(created_at) AS created_at,
+    uniqState(attempt_id) AS attempts
+FROM
+    payment_attempt_queue
+WHERE
+    sign_flag = 1
+    AND status IN ('failure', 'authorization_failed')
+GROUP BY
+    merchant_id,
+    connector,
+    card_network,
+    card_isin,
+    card_issuer,
+    error_code,
+    decline_category,
+    network_advice_code,
+    created_at;
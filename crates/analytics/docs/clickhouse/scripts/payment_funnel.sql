@@ -0,0 +1,86 @@
+-- Pre-aggregations of the payment attempts backing the payment funnel analytics. The tables are
+-- maintained by the materialized views below as the attempt events are consumed from kafka, the
+-- attempts being bucketed by the hour in which they were created.
+
+CREATE TABLE payment_funnel (
+    `merchant_id` LowCardinality(String),
+    `payment_method` LowCardinality(String),
+    `status` LowCardinality(String),
+    `created_at` DateTime CODEC(T64, LZ4),
+    `payments` AggregateFunction(uniq, String)
+) ENGINE = AggregatingMergeTree
+PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (merchant_id, created_at, payment_method, status)
+TTL created_at + toIntervalMonth(18)
+SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW payment_funnel_mv TO payment_funnel (
+    `merchant_id` String,
+    `payment_method` LowCardinality(String),
+    `status` LowCardinality(String),
+    `created_at` DateTime,
+    `payments` AggregateFunction(uniq, String)
+) AS
+SELECT
+    merchant_id,
+    ifNull(payment_method, 'unknown') AS payment_method,
+    status,
+    toStartOfHour(created_at) AS created_at,
+    uniqState(payment_id) AS payments
+FROM
+    payment_attempt_queue
+WHERE
+    sign_flag = 1
+GROUP BY
+    merchant_id,
+    payment_method,
+    status,
+    created_at;
+
+CREATE TABLE payment_failure_reasons (
+    `merchant_id` LowCardinality(String),
+    `connector` LowCardinality(String),
+    `error_code` String,
+    `error_message` String,
+    `created_at` DateTime CODEC(T64, LZ4),
+    `attempts` AggregateFunction(uniq, String)
+) ENGINE = AggregatingMergeTree
+PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (merchant_id, created_at, connector, error_code, error_message)
+TTL created_at + toIntervalMonth(18)
+SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW payment_failure_reasons_mv TO payment_failure_reasons (
+    `merchant_id` String,
+    `connector` LowCardinality(String),
+    `error_code` String,
+    `error_message` String,
+    `created_at` DateTime,
+    `attempts` AggregateFunction(uniq, String)
+) AS
+SELECT
+    merchant_id,
+    ifNull(connector, 'unknown') AS connector,
+    ifNull(error_code, 'unknown') AS error_code,
+    ifNull(error_message, 'unknown') AS error_message,
+    toStartOfHour(created_at) AS created_at,
+    uniqState(attempt_id) AS attempts
+FROM
+    payment_attempt_queue
+WHERE
+    sign_flag = 1
+    AND status IN (
+        'failure',
+        'authorization_failed',
+        'authentication_failed',
+        'router_declined',
+        'capture_failed'
+    )
+GROUP BY
+    merchant_id,
+    connector,
+    error_code,
+    error_message,
+    created_at;
@@ -19,6 +19,7 @@ CREATE TABLE payment_intents_queue
     `business_country` LowCardinality(String),
     `business_label` String,
     `attempt_count` UInt8,
+    `profile_id` Nullable(String),
     `modified_at` DateTime CODEC(T64, LZ4),
     `created_at` DateTime CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
@@ -50,6 +51,7 @@ CREATE TABLE payment_intents
     `business_country` LowCardinality(String),
     `business_label` String,
     `attempt_count` UInt8,
+    `profile_id` Nullable(String),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
@@ -86,6 +88,7 @@ CREATE MATERIALIZED VIEW payment_intents_mv TO payment_intents
     `business_country` LowCardinality(String),
     `business_label` String,
     `attempt_count` UInt8,
+    `profile_id` Nullable(String),
     `modified_at` DateTime64(3),
     `created_at` DateTime64(3),
     `last_synced` Nullable(DateTime64(3)),
@@ -112,6 +115,7 @@ SELECT
     business_country,
     business_label,
     attempt_count,
+    profile_id,
     modified_at,
     created_at,
     last_synced,
@@ -24,9 +24,13 @@ use crate::{
         filters::ApiEventFilter,
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
-    connector_events::events::ConnectorEventsResult,
+    connector_events::{events::ConnectorEventsResult, metrics::ConnectorEventMetricRow},
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
+    payment_declines::queries::PaymentDeclineRow,
+    payment_funnel::queries::{
+        PaymentFailureReasonRow, PaymentFunnelRow, PaymentMethodDistributionRow,
+    },
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
 };
@@ -137,9 +141,13 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::SdkEventsAnalytics
             | AnalyticsCollection::ApiEvents
             | AnalyticsCollection::ConnectorEvents
+            | AnalyticsCollection::ConnectorEventsAnalytics
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
-            | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
+            | AnalyticsCollection::ActivePaymentsAnalytics
+            | AnalyticsCollection::PaymentFunnel
+            | AnalyticsCollection::PaymentFailureReasons
+            | AnalyticsCollection::PaymentDeclines => TableEngine::BasicTree,
         }
     }
 }
@@ -168,12 +176,15 @@ impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
 impl super::connector_events::events::ConnectorEventLogAnalytics for ClickhouseClient {}
+impl super::connector_events::metrics::ConnectorEventMetricAnalytics for ClickhouseClient {}
 impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::payment_funnel::queries::PaymentFunnelQueryAnalytics for ClickhouseClient {}
+impl super::payment_declines::queries::PaymentDeclinesQueryAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -217,6 +228,56 @@ impl TryInto<ConnectorEventsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ConnectorEventMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorEventMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorEventMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PaymentFunnelRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentFunnelRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentFunnelRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PaymentFailureReasonRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentFailureReasonRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentFailureReasonRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PaymentMethodDistributionRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentMethodDistributionRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentMethodDistributionRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PaymentDeclineRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentDeclineRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentDeclineRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<PaymentMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -392,9 +453,13 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::ApiEventsAnalytics => Ok("api_events".to_string()),
             Self::PaymentIntent => Ok("payment_intents".to_string()),
             Self::ConnectorEvents => Ok("connector_events_audit".to_string()),
+            Self::ConnectorEventsAnalytics => Ok("connector_events".to_string()),
             Self::OutgoingWebhookEvent => Ok("outgoing_webhook_events_audit".to_string()),
             Self::Dispute => Ok("dispute".to_string()),
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
+            Self::PaymentFunnel => Ok("payment_funnel".to_string()),
+            Self::PaymentFailureReasons => Ok("payment_failure_reasons".to_string()),
+            Self::PaymentDeclines => Ok("payment_declines".to_string()),
         }
     }
 }
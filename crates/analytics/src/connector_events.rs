@@ -1,5 +1,9 @@
 mod core;
 pub mod events;
-pub trait ConnectorEventAnalytics: events::ConnectorEventLogAnalytics {}
+pub mod metrics;
+pub trait ConnectorEventAnalytics:
+    events::ConnectorEventLogAnalytics + metrics::ConnectorEventMetricAnalytics
+{
+}
 
-pub use self::core::connector_events_core;
+pub use self::core::{connector_events_core, connector_metrics_core};
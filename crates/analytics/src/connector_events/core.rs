@@ -1,8 +1,14 @@
-use api_models::analytics::connector_events::ConnectorEventsRequest;
+use api_models::analytics::connector_events::{
+    ConnectorEventsRequest, ConnectorMetricsBucket, ConnectorMetricsRequest,
+    ConnectorMetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
 
-use super::events::{get_connector_events, ConnectorEventsResult};
+use super::{
+    events::{get_connector_events, ConnectorEventsResult},
+    metrics::get_connector_event_metrics,
+};
 use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
 
 pub async fn connector_events_core(
@@ -24,3 +30,48 @@ pub async fn connector_events_core(
     .switch()?;
     Ok(data)
 }
+
+pub async fn connector_metrics_core(
+    pool: &AnalyticsProvider,
+    req: ConnectorMetricsRequest,
+    merchant_id: String,
+) -> AnalyticsResult<ConnectorMetricsResponse> {
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Connector Metrics not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Connector Metrics"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_connector_event_metrics(&merchant_id, &req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    let metrics = rows
+        .into_iter()
+        .map(|row| ConnectorMetricsBucket {
+            success_rate: u32::try_from(row.success_count)
+                .ok()
+                .zip(u32::try_from(row.total_count).ok())
+                .filter(|(_, total_count)| *total_count > 0)
+                .map(|(success_count, total_count)| {
+                    f64::from(success_count) * 100.0 / f64::from(total_count)
+                }),
+            connector_name: row.connector_name,
+            flow: row.flow,
+            total_count: row.total_count,
+            success_count: row.success_count,
+            latency_p50: row.latency_p50,
+            latency_p90: row.latency_p90,
+            latency_p99: row.latency_p99,
+            client_error_count: row.client_error_count,
+            server_error_count: row.server_error_count,
+            timeout_count: row.timeout_count,
+            network_error_count: row.network_error_count,
+        })
+        .collect();
+
+    Ok(ConnectorMetricsResponse { metrics })
+}
@@ -0,0 +1,115 @@
+use api_models::analytics::{connector_events::ConnectorMetricsRequest, Granularity};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, FilterTypes, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+pub trait ConnectorEventMetricAnalytics: LoadRow<ConnectorEventMetricRow> {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorEventMetricRow {
+    pub connector_name: String,
+    pub flow: String,
+    pub total_count: u64,
+    pub success_count: u64,
+    pub latency_p50: Option<u64>,
+    pub latency_p90: Option<u64>,
+    pub latency_p99: Option<u64>,
+    pub client_error_count: u64,
+    pub server_error_count: u64,
+    pub timeout_count: u64,
+    pub network_error_count: u64,
+}
+
+pub async fn get_connector_event_metrics<T>(
+    merchant_id: &str,
+    query_param: &ConnectorMetricsRequest,
+    pool: &T,
+) -> FiltersResult<Vec<ConnectorEventMetricRow>>
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::ConnectorEventsAnalytics);
+
+    for column in [
+        "connector_name",
+        "flow",
+        "count(*) as total_count",
+        "countIf(status_code >= 200 AND status_code < 300) as success_count",
+        "countIf(error_class = 'client_error') as client_error_count",
+        "countIf(error_class = 'server_error') as server_error_count",
+        "countIf(error_class = 'timeout') as timeout_count",
+        "countIf(error_class = 'network_error') as network_error_count",
+    ] {
+        query_builder.add_select_column(column).switch()?;
+    }
+    for (alias, percentile) in [
+        ("latency_p50", &50),
+        ("latency_p90", &90),
+        ("latency_p99", &99),
+    ] {
+        query_builder
+            .add_select_column(Aggregate::Percentile {
+                field: "toUInt64(latency)",
+                alias: Some(alias),
+                percentile: Some(percentile),
+            })
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_param
+        .get_time_range()
+        .set_filter_clause(&mut query_builder)
+        .switch()?;
+
+    if let Some(connector_name) = &query_param.connector_name {
+        query_builder
+            .add_filter_clause("connector_name", connector_name)
+            .switch()?;
+    }
+
+    if let Some(flow) = &query_param.flow {
+        query_builder.add_filter_clause("flow", flow).switch()?;
+    }
+
+    // Connector events are not tagged with the business profile, the calls made for the payments
+    // of the profile are selected instead
+    if let Some(profile_id) = &query_param.profile_id {
+        let profile_id = profile_id.replace(['\'', ' '], "");
+        let merchant_id = merchant_id.replace(['\'', ' '], "");
+        query_builder
+            .add_custom_filter_clause(
+                "payment_id",
+                format!(
+                    "SELECT payment_id FROM payment_intents WHERE merchant_id = '{merchant_id}' AND profile_id = '{profile_id}'"
+                ),
+                FilterTypes::In,
+            )
+            .switch()?;
+    }
+
+    query_builder
+        .add_group_by_clause("connector_name")
+        .switch()?;
+    query_builder.add_group_by_clause("flow").switch()?;
+
+    query_builder
+        .execute_query::<ConnectorEventMetricRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
@@ -14,6 +14,8 @@ pub mod connector_events;
 pub mod health_check;
 pub mod opensearch;
 pub mod outgoing_webhook_event;
+pub mod payment_declines;
+pub mod payment_funnel;
 pub mod sdk_events;
 pub mod search;
 mod sqlx;
@@ -771,6 +773,11 @@ pub enum AnalyticsFlow {
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
+    GetConnectorMetrics,
+    GetPaymentFunnel,
+    GetPaymentFailureReasons,
+    GetPaymentMethodDistribution,
+    GetPaymentDeclines,
     GetOutgoingWebhookEvents,
     GetGlobalSearchResults,
     GetSearchResults,
@@ -0,0 +1,5 @@
+mod core;
+pub mod queries;
+pub trait PaymentDeclinesAnalytics: queries::PaymentDeclinesQueryAnalytics {}
+
+pub use self::core::payment_declines_core;
@@ -0,0 +1,49 @@
+use api_models::analytics::payment_declines::{
+    GetPaymentDeclinesRequest, PaymentDeclineBucket, PaymentDeclinesResponse,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+
+use super::queries::get_payment_declines;
+use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+
+pub async fn payment_declines_core(
+    pool: &AnalyticsProvider,
+    req: GetPaymentDeclinesRequest,
+    merchant_id: String,
+) -> AnalyticsResult<PaymentDeclinesResponse> {
+    let mut rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Payment Declines not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Payment Declines"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_payment_declines(&merchant_id, &req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    rows.sort_by(|a, b| b.decline_count.cmp(&a.decline_count));
+    if let Some(limit) = req.limit.and_then(|limit| usize::try_from(limit).ok()) {
+        rows.truncate(limit);
+    }
+
+    Ok(PaymentDeclinesResponse {
+        declines: rows
+            .into_iter()
+            .map(|row| PaymentDeclineBucket {
+                connector: row.connector,
+                card_network: row.card_network,
+                card_isin: row.card_isin,
+                card_issuer: row.card_issuer,
+                error_code: row.error_code,
+                decline_category: row.decline_category,
+                network_advice_code: row.network_advice_code,
+                time_bucket: row.time_bucket,
+                decline_count: row.decline_count,
+            })
+            .collect(),
+    })
+}
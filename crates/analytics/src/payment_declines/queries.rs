@@ -0,0 +1,98 @@
+use api_models::analytics::{payment_declines::GetPaymentDeclinesRequest, Granularity};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+pub trait PaymentDeclinesQueryAnalytics: LoadRow<PaymentDeclineRow> {}
+
+/// The dimensions not grouped by are not selected, and are left out of the row
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentDeclineRow {
+    pub connector: Option<String>,
+    pub card_network: Option<String>,
+    pub card_isin: Option<String>,
+    pub card_issuer: Option<String>,
+    pub error_code: Option<String>,
+    pub decline_category: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub time_bucket: Option<String>,
+    pub decline_count: u64,
+}
+
+pub async fn get_payment_declines<T>(
+    merchant_id: &str,
+    query_param: &GetPaymentDeclinesRequest,
+    pool: &T,
+) -> FiltersResult<Vec<PaymentDeclineRow>>
+where
+    T: AnalyticsDataSource + PaymentDeclinesQueryAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::PaymentDeclines);
+
+    for dimension in &query_param.group_by_names {
+        query_builder
+            .add_select_column(dimension.as_ref())
+            .switch()?;
+    }
+    query_builder
+        .add_select_column("uniqMerge(attempts) as decline_count")
+        .switch()?;
+
+    if let Some(granularity) = &query_param.granularity {
+        query_builder
+            .add_granularity_in_mins(granularity)
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_param
+        .time_range
+        .set_filter_clause(&mut query_builder)
+        .switch()?;
+
+    let filters = &query_param.filters;
+    for (column, values) in [
+        ("connector", &filters.connector),
+        ("card_network", &filters.card_network),
+        ("card_isin", &filters.card_isin),
+        ("card_issuer", &filters.card_issuer),
+        ("error_code", &filters.error_code),
+        ("decline_category", &filters.decline_category),
+        ("network_advice_code", &filters.network_advice_code),
+    ] {
+        if !values.is_empty() {
+            query_builder
+                .add_filter_in_range_clause(column, values)
+                .switch()?;
+        }
+    }
+
+    for dimension in &query_param.group_by_names {
+        query_builder
+            .add_group_by_clause(dimension.as_ref())
+            .switch()?;
+    }
+    if query_param.granularity.is_some() {
+        query_builder.add_group_by_clause("time_bucket").switch()?;
+    }
+
+    query_builder
+        .execute_query::<PaymentDeclineRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
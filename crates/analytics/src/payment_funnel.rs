@@ -0,0 +1,7 @@
+mod core;
+pub mod queries;
+pub trait PaymentFunnelAnalytics: queries::PaymentFunnelQueryAnalytics {}
+
+pub use self::core::{
+    payment_failure_reasons_core, payment_funnel_core, payment_method_distribution_core,
+};
@@ -0,0 +1,131 @@
+use api_models::analytics::payment_funnel::{
+    GetPaymentFailureReasonsRequest, GetPaymentFunnelRequest, GetPaymentMethodDistributionRequest,
+    PaymentFailureReason, PaymentFailureReasonsResponse, PaymentFunnelResponse,
+    PaymentMethodDistributionBucket, PaymentMethodDistributionResponse,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+
+use super::queries::{
+    get_payment_failure_reasons, get_payment_funnel, get_payment_method_distribution,
+};
+use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+
+/// Share of the payments of a stage of the funnel which reached the next stage, in percentage
+fn get_conversion_rate(converted_count: u64, total_count: u64) -> Option<f64> {
+    u32::try_from(converted_count)
+        .ok()
+        .zip(u32::try_from(total_count).ok())
+        .filter(|(_, total_count)| *total_count > 0)
+        .map(|(converted_count, total_count)| {
+            f64::from(converted_count) * 100.0 / f64::from(total_count)
+        })
+}
+
+pub async fn payment_funnel_core(
+    pool: &AnalyticsProvider,
+    req: GetPaymentFunnelRequest,
+    merchant_id: String,
+) -> AnalyticsResult<PaymentFunnelResponse> {
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Payment Funnel not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Payment Funnel"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_payment_funnel(&merchant_id, &req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    let (created_count, confirmed_count, authorized_count, captured_count) = rows
+        .first()
+        .map(|row| {
+            (
+                row.created_count,
+                row.confirmed_count,
+                row.authorized_count,
+                row.captured_count,
+            )
+        })
+        .unwrap_or_default();
+
+    Ok(PaymentFunnelResponse {
+        created_count,
+        confirmed_count,
+        authorized_count,
+        captured_count,
+        confirmation_rate: get_conversion_rate(confirmed_count, created_count),
+        authorization_rate: get_conversion_rate(authorized_count, confirmed_count),
+        capture_rate: get_conversion_rate(captured_count, authorized_count),
+    })
+}
+
+pub async fn payment_failure_reasons_core(
+    pool: &AnalyticsProvider,
+    req: GetPaymentFailureReasonsRequest,
+    merchant_id: String,
+) -> AnalyticsResult<PaymentFailureReasonsResponse> {
+    let mut rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Payment Failure Reasons not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Payment Failure Reasons"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_payment_failure_reasons(&merchant_id, &req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    rows.sort_by(|a, b| b.attempt_count.cmp(&a.attempt_count));
+    if let Some(limit) = req.limit.and_then(|limit| usize::try_from(limit).ok()) {
+        rows.truncate(limit);
+    }
+
+    Ok(PaymentFailureReasonsResponse {
+        failure_reasons: rows
+            .into_iter()
+            .map(|row| PaymentFailureReason {
+                connector: row.connector,
+                error_code: row.error_code,
+                error_message: row.error_message,
+                attempt_count: row.attempt_count,
+            })
+            .collect(),
+    })
+}
+
+pub async fn payment_method_distribution_core(
+    pool: &AnalyticsProvider,
+    req: GetPaymentMethodDistributionRequest,
+    merchant_id: String,
+) -> AnalyticsResult<PaymentMethodDistributionResponse> {
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Payment Method Distribution not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Payment Method Distribution"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_payment_method_distribution(&merchant_id, &req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    Ok(PaymentMethodDistributionResponse {
+        distribution: rows
+            .into_iter()
+            .map(|row| PaymentMethodDistributionBucket {
+                payment_method: row.payment_method,
+                time_bucket: row.time_bucket,
+                payment_count: row.payment_count,
+                success_count: row.success_count,
+            })
+            .collect(),
+    })
+}
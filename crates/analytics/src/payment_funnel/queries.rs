@@ -0,0 +1,213 @@
+use api_models::analytics::{
+    payment_funnel::{
+        GetPaymentFailureReasonsRequest, GetPaymentFunnelRequest,
+        GetPaymentMethodDistributionRequest,
+    },
+    Granularity,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+/// Statuses of the attempts of the payments which were confirmed by the customer or the merchant
+const CONFIRMED_STATUS_FILTER: &str = "status NOT IN ('started', 'payment_method_awaited', \
+                                       'confirmation_awaited', 'device_data_collection_pending')";
+
+/// Statuses of the attempts of the payments which were authorized by the connector
+const AUTHORIZED_STATUS_FILTER: &str = "status IN ('authorized', 'charged', 'partial_charged', \
+                                        'partial_charged_and_chargeable', 'capture_initiated', \
+                                        'capture_failed', 'voided', 'void_initiated', \
+                                        'void_failed', 'auto_refunded')";
+
+/// Statuses of the attempts of the payments which were captured
+const CAPTURED_STATUS_FILTER: &str =
+    "status IN ('charged', 'partial_charged', 'partial_charged_and_chargeable')";
+
+pub trait PaymentFunnelQueryAnalytics:
+    LoadRow<PaymentFunnelRow> + LoadRow<PaymentFailureReasonRow> + LoadRow<PaymentMethodDistributionRow>
+{
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentFunnelRow {
+    pub created_count: u64,
+    pub confirmed_count: u64,
+    pub authorized_count: u64,
+    pub captured_count: u64,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentFailureReasonRow {
+    pub connector: String,
+    pub error_code: String,
+    pub error_message: String,
+    pub attempt_count: u64,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentMethodDistributionRow {
+    pub payment_method: String,
+    pub time_bucket: Option<String>,
+    pub payment_count: u64,
+    pub success_count: u64,
+}
+
+pub async fn get_payment_funnel<T>(
+    merchant_id: &str,
+    query_param: &GetPaymentFunnelRequest,
+    pool: &T,
+) -> FiltersResult<Vec<PaymentFunnelRow>>
+where
+    T: AnalyticsDataSource + PaymentFunnelQueryAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::PaymentFunnel);
+
+    query_builder
+        .add_select_column("uniqMerge(payments) as created_count")
+        .switch()?;
+    for (status_filter, alias) in [
+        (CONFIRMED_STATUS_FILTER, "confirmed_count"),
+        (AUTHORIZED_STATUS_FILTER, "authorized_count"),
+        (CAPTURED_STATUS_FILTER, "captured_count"),
+    ] {
+        query_builder
+            .add_select_column(format!("uniqMergeIf(payments, {status_filter}) as {alias}"))
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_param
+        .time_range
+        .set_filter_clause(&mut query_builder)
+        .switch()?;
+
+    if let Some(payment_method) = &query_param.payment_method {
+        query_builder
+            .add_filter_clause("payment_method", payment_method)
+            .switch()?;
+    }
+
+    query_builder
+        .execute_query::<PaymentFunnelRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+pub async fn get_payment_failure_reasons<T>(
+    merchant_id: &str,
+    query_param: &GetPaymentFailureReasonsRequest,
+    pool: &T,
+) -> FiltersResult<Vec<PaymentFailureReasonRow>>
+where
+    T: AnalyticsDataSource + PaymentFunnelQueryAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::PaymentFailureReasons);
+
+    for column in [
+        "connector",
+        "error_code",
+        "error_message",
+        "uniqMerge(attempts) as attempt_count",
+    ] {
+        query_builder.add_select_column(column).switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_param
+        .time_range
+        .set_filter_clause(&mut query_builder)
+        .switch()?;
+
+    if let Some(connector) = &query_param.connector {
+        query_builder
+            .add_filter_clause("connector", connector)
+            .switch()?;
+    }
+
+    for column in ["connector", "error_code", "error_message"] {
+        query_builder.add_group_by_clause(column).switch()?;
+    }
+
+    query_builder
+        .execute_query::<PaymentFailureReasonRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+pub async fn get_payment_method_distribution<T>(
+    merchant_id: &str,
+    query_param: &GetPaymentMethodDistributionRequest,
+    pool: &T,
+) -> FiltersResult<Vec<PaymentMethodDistributionRow>>
+where
+    T: AnalyticsDataSource + PaymentFunnelQueryAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::PaymentFunnel);
+
+    query_builder.add_select_column("payment_method").switch()?;
+    query_builder
+        .add_select_column("uniqMerge(payments) as payment_count")
+        .switch()?;
+    query_builder
+        .add_select_column(format!(
+            "uniqMergeIf(payments, {CAPTURED_STATUS_FILTER}) as success_count"
+        ))
+        .switch()?;
+
+    if let Some(granularity) = &query_param.granularity {
+        query_builder
+            .add_granularity_in_mins(granularity)
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_param
+        .time_range
+        .set_filter_clause(&mut query_builder)
+        .switch()?;
+
+    query_builder
+        .add_group_by_clause("payment_method")
+        .switch()?;
+    if query_param.granularity.is_some() {
+        query_builder.add_group_by_clause("time_bucket").switch()?;
+    }
+
+    query_builder
+        .execute_query::<PaymentMethodDistributionRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
@@ -15,13 +15,30 @@ pub async fn msearch_results(
     req: GetGlobalSearchRequest,
     merchant_id: &String,
     indexes: Vec<SearchIndex>,
+) -> CustomResult<Vec<GetSearchResponse>, OpenSearchError> {
+    scoped_msearch_results(
+        client,
+        req.query,
+        vec![("merchant_id".to_string(), merchant_id.to_string())],
+        indexes,
+    )
+    .await
+}
+
+/// Searches the indexes for the documents matching the query and all the scope filters, such as
+/// the merchant or the tenant of the documents
+pub async fn scoped_msearch_results(
+    client: &OpenSearchClient,
+    query: String,
+    scope: Vec<(String, String)>,
+    indexes: Vec<SearchIndex>,
 ) -> CustomResult<Vec<GetSearchResponse>, OpenSearchError> {
     let mut query_builder =
-        OpenSearchQueryBuilder::new(OpenSearchQuery::Msearch(indexes.clone()), req.query);
+        OpenSearchQueryBuilder::new(OpenSearchQuery::Msearch(indexes.clone()), query);
 
-    query_builder
-        .add_filter_clause("merchant_id".to_string(), merchant_id.to_string())
-        .switch()?;
+    for (field, value) in scope {
+        query_builder.add_filter_clause(field, value).switch()?;
+    }
 
     let response_text: OpenMsearchOutput = client
         .execute(query_builder)
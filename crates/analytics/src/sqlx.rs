@@ -550,10 +550,20 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             Self::PaymentIntent => Ok("payment_intent".to_string()),
             Self::ConnectorEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ConnectorEvents table is not implemented for Sqlx"))?,
+            Self::ConnectorEventsAnalytics => {
+                Err(error_stack::report!(ParsingError::UnknownError)
+                    .attach_printable("ConnectorEvents table is not implemented for Sqlx"))?
+            }
             Self::ApiEventsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ApiEvents table is not implemented for Sqlx"))?,
             Self::ActivePaymentsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ActivePaymentsAnalytics table is not implemented for Sqlx"))?,
+            Self::PaymentFunnel => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("PaymentFunnel table is not implemented for Sqlx"))?,
+            Self::PaymentFailureReasons => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("PaymentFailureReasons table is not implemented for Sqlx"))?,
+            Self::PaymentDeclines => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("PaymentDeclines table is not implemented for Sqlx"))?,
             Self::OutgoingWebhookEvent => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("OutgoingWebhookEvents table is not implemented for Sqlx"))?,
             Self::Dispute => Ok("dispute".to_string()),
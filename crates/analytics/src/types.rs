@@ -30,10 +30,14 @@ pub enum AnalyticsCollection {
     ApiEvents,
     PaymentIntent,
     ConnectorEvents,
+    ConnectorEventsAnalytics,
     OutgoingWebhookEvent,
     Dispute,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
+    PaymentFunnel,
+    PaymentFailureReasons,
+    PaymentDeclines,
 }
 
 #[allow(dead_code)]
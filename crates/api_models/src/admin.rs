@@ -108,6 +108,15 @@ pub struct AuthenticationConnectorDetails {
     pub authentication_connectors: Vec<enums::AuthenticationConnectors>,
     /// URL of the (customer service) website that will be shown to the shopper in case of technical errors during the 3D Secure 2 process.
     pub three_ds_requestor_url: String,
+    /// How the authentication connector is chosen among the authentication connectors, defaults to the first one
+    #[schema(value_type = Option<AuthenticationConnectorSelection>, example = "issuer_performance")]
+    pub connector_selection: Option<enums::AuthenticationConnectorSelection>,
+    /// The challenge preference sent to the issuer during the authentication, defaults to no preference
+    #[schema(value_type = Option<ThreeDsChallengePreference>, example = "no_challenge_requested")]
+    pub challenge_preference: Option<enums::ThreeDsChallengePreference>,
+    /// Amount, in the lowest denomination of the currency, from which a challenge is requested regardless of the challenge preference
+    #[schema(value_type = Option<i64>, example = 50000)]
+    pub challenge_amount_threshold: Option<common_utils::types::MinorUnit>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -371,6 +380,28 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The fields of the object sent in the webhooks of each event type. The webhooks of the event
+    /// types without a filter are sent with all the fields of the object.
+    #[schema(value_type = Option<Object>, example = json!({"payment_succeeded": {"include_fields": ["amount", "currency"]}}))]
+    pub payload_filters: Option<HashMap<api_enums::EventType, WebhookPayloadFilter>>,
+}
+
+/// The fields of the object sent in the outgoing webhooks of an event type, given as dot
+/// separated paths such as `amount` or `billing.address.line1`. The identifier of the object is
+/// always included.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookPayloadFilter {
+    /// The only fields of the object included in the webhooks. All the fields are included when
+    /// not provided.
+    #[schema(example = json!(["amount", "currency", "status"]))]
+    pub include_fields: Option<Vec<String>>,
+
+    /// The fields of the object removed from the webhooks
+    #[schema(example = json!(["email", "customer.email"]))]
+    #[serde(default)]
+    pub exclude_fields: Vec<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -822,6 +853,39 @@ pub struct MerchantConnectorDeleteResponse {
     pub deleted: bool,
 }
 
+/// Credentials staged to replace the connector account details of a Merchant Connector on the
+/// next cutover. Until then, the operations rejected by the connector with the current credentials
+/// are retried with them.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorNextCredentialsRequest {
+    #[schema(value_type = Object, example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey" }))]
+    pub connector_account_details: pii::SecretSerdeValue,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorCredentialsCutoverRequest {
+    /// Duration in seconds for which the replaced credentials are still used as a fallback, seven
+    /// days when not set
+    #[schema(example = 86400)]
+    pub rotation_window_in_secs: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantConnectorCredentialsRotationResponse {
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+    /// Whether credentials are staged for the next cutover
+    pub has_next_credentials: bool,
+    /// Whether the credentials replaced on the last cutover are still used as a fallback
+    pub has_previous_credentials: bool,
+    /// The time until which the replaced credentials are used as a fallback
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2024-07-16T10:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub rotation_window_ends_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVResponse {
     /// The identifier for the Merchant Account
@@ -857,6 +921,72 @@ pub struct ToggleAllKVResponse {
     #[schema(example = true)]
     pub kv_enabled: bool,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantKvSettingsUpdateRequest {
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+    /// Status of KV for the specific merchant, left unchanged when not provided
+    #[schema(example = true)]
+    pub kv_enabled: Option<bool>,
+    /// The number of drainer shards the writes of the merchant are spread across, all the shards
+    /// when not provided. It can only be changed while KV is disabled for the merchant
+    #[schema(example = 4)]
+    pub shard_count: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKvSettingsResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Status of KV for the specific merchant
+    #[schema(example = true)]
+    pub kv_enabled: bool,
+    /// The number of drainer shards the writes of the merchant are spread across, all the shards
+    /// when not set
+    #[schema(example = 4)]
+    pub shard_count: Option<u8>,
+    /// The total number of drainer shards
+    #[schema(example = 64)]
+    pub drainer_shard_count: u8,
+}
+
+/// Tuning of the drainer applied at runtime, overriding the drainer configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DrainerTuning {
+    /// The number of shards drained concurrently on each iteration of the drainer, one when not
+    /// set
+    #[schema(example = 4)]
+    pub concurrency: Option<u8>,
+    /// The maximum number of entries read from a shard at once, the configured count when not set
+    #[schema(example = 100)]
+    pub max_read_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct KvShardBacklog {
+    /// The index of the shard
+    #[schema(example = 3)]
+    pub shard: u8,
+    /// The number of entries of the shard waiting to be drained to the database
+    #[schema(example = 120)]
+    pub pending_entries: usize,
+    /// Age of the oldest entry waiting to be drained, in seconds
+    #[schema(example = 2)]
+    pub oldest_entry_age_in_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct KvBacklogResponse {
+    /// The total number of entries waiting to be drained to the database
+    #[schema(example = 1200)]
+    pub total_pending_entries: usize,
+    /// The backlog of each shard
+    pub shards: Vec<KvShardBacklog>,
+}
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -960,6 +1090,30 @@ pub struct BusinessProfileCreate {
     /// Default payout link config
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
+
+    /// Decision matrix mapping the FRM verdicts and scores to the action to be taken on the payment before authorization
+    pub frm_decision_matrix: Option<FrmDecisionMatrix>,
+
+    /// Review of authorized payments with `automatic` capture by the FRM connector, the capture is deferred until the post-authorization verdict is received or the review times out
+    pub frm_post_auth_review: Option<FrmPostAuthReviewConfig>,
+
+    /// Capture of the requests sent to and the responses received from the connectors for the payments of the profile, with the sensitive data masked, to help debugging integrations
+    pub connector_debug_capture: Option<ConnectorDebugCaptureConfig>,
+
+    /// What to do with the authorization of a payment when the connector it is routed to is unavailable, returning network errors or 5xx responses, along with the circuit breaker that steers the payments away from such connectors
+    pub connector_failover_policy: Option<ConnectorFailoverPolicy>,
+
+    /// The vault the cards saved for the customers of the profile are stored in. Defaults to the Hyperswitch card vault
+    pub vault_provider: Option<VaultProvider>,
+
+    /// How the taxes on the orders of the payments of the profile are calculated from their shipping address. Taxes are not calculated when not provided
+    pub tax_provider: Option<TaxProvider>,
+
+    /// Rules responding automatically to the disputes opened against the payments of the profile, by accepting the disputes of small amounts or by submitting standard evidence for some reason codes
+    pub dispute_auto_response: Option<DisputeAutoResponseConfig>,
+
+    /// The typed custom fields the payments of the profile can carry, which are validated when the payments are created. Payments of the profile cannot carry custom fields when not provided
+    pub custom_fields_schema: Option<CustomFieldsSchema>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1048,6 +1202,30 @@ pub struct BusinessProfileResponse {
     /// Default payout link config
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
+
+    /// Decision matrix mapping the FRM verdicts and scores to the action to be taken on the payment before authorization
+    pub frm_decision_matrix: Option<FrmDecisionMatrix>,
+
+    /// Review of authorized payments with `automatic` capture by the FRM connector, the capture is deferred until the post-authorization verdict is received or the review times out
+    pub frm_post_auth_review: Option<FrmPostAuthReviewConfig>,
+
+    /// Capture of the requests sent to and the responses received from the connectors for the payments of the profile, with the sensitive data masked, to help debugging integrations
+    pub connector_debug_capture: Option<ConnectorDebugCaptureConfig>,
+
+    /// What to do with the authorization of a payment when the connector it is routed to is unavailable, returning network errors or 5xx responses, along with the circuit breaker that steers the payments away from such connectors
+    pub connector_failover_policy: Option<ConnectorFailoverPolicy>,
+
+    /// The vault the cards saved for the customers of the profile are stored in. Defaults to the Hyperswitch card vault
+    pub vault_provider: Option<VaultProvider>,
+
+    /// How the taxes on the orders of the payments of the profile are calculated from their shipping address. Taxes are not calculated when not provided
+    pub tax_provider: Option<TaxProvider>,
+
+    /// Rules responding automatically to the disputes opened against the payments of the profile, by accepting the disputes of small amounts or by submitting standard evidence for some reason codes
+    pub dispute_auto_response: Option<DisputeAutoResponseConfig>,
+
+    /// The typed custom fields the payments of the profile can carry, which are validated when the payments are created. Payments of the profile cannot carry custom fields when not provided
+    pub custom_fields_schema: Option<CustomFieldsSchema>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1128,6 +1306,30 @@ pub struct BusinessProfileUpdate {
     /// Default payout link config
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
+
+    /// Decision matrix mapping the FRM verdicts and scores to the action to be taken on the payment before authorization
+    pub frm_decision_matrix: Option<FrmDecisionMatrix>,
+
+    /// Review of authorized payments with `automatic` capture by the FRM connector, the capture is deferred until the post-authorization verdict is received or the review times out
+    pub frm_post_auth_review: Option<FrmPostAuthReviewConfig>,
+
+    /// Capture of the requests sent to and the responses received from the connectors for the payments of the profile, with the sensitive data masked, to help debugging integrations
+    pub connector_debug_capture: Option<ConnectorDebugCaptureConfig>,
+
+    /// What to do with the authorization of a payment when the connector it is routed to is unavailable, returning network errors or 5xx responses, along with the circuit breaker that steers the payments away from such connectors
+    pub connector_failover_policy: Option<ConnectorFailoverPolicy>,
+
+    /// The vault the cards saved for the customers of the profile are stored in. Defaults to the Hyperswitch card vault
+    pub vault_provider: Option<VaultProvider>,
+
+    /// How the taxes on the orders of the payments of the profile are calculated from their shipping address. Taxes are not calculated when not provided
+    pub tax_provider: Option<TaxProvider>,
+
+    /// Rules responding automatically to the disputes opened against the payments of the profile, by accepting the disputes of small amounts or by submitting standard evidence for some reason codes
+    pub dispute_auto_response: Option<DisputeAutoResponseConfig>,
+
+    /// The typed custom fields the payments of the profile can carry, which are validated when the payments are created. Payments of the profile cannot carry custom fields when not provided
+    pub custom_fields_schema: Option<CustomFieldsSchema>,
 }
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct BusinessCollectLinkConfig {
@@ -1258,3 +1460,340 @@ impl std::ops::Deref for TtlForExtendedCardInfo {
         &self.0
     }
 }
+
+/// Decision matrix which maps the verdict and score returned by the FRM connector to the action
+/// to be taken on the payment, evaluated before the payment is authorized
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmDecisionMatrix {
+    /// The rules of the matrix. Rules are evaluated in order, and the action of the first matching rule is taken
+    pub rules: Vec<FrmDecisionRule>,
+
+    /// The action to be taken when none of the rules match. If not provided, payments marked as fraud by the FRM connector are blocked
+    #[schema(value_type = Option<FrmDecisionAction>, example = "allow")]
+    pub default_action: Option<api_enums::FrmDecisionAction>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmDecisionRule {
+    /// The verdicts of the FRM connector for which this rule applies. Applies to all verdicts if not provided
+    #[schema(value_type = Option<Vec<FrmVerdict>>, example = json!(["manual_review"]))]
+    pub verdicts: Option<Vec<api_enums::FrmVerdict>>,
+
+    /// The minimum FRM score (inclusive) for which this rule applies
+    #[schema(example = 60)]
+    pub min_score: Option<i32>,
+
+    /// The maximum FRM score (inclusive) for which this rule applies
+    #[schema(example = 80)]
+    pub max_score: Option<i32>,
+
+    /// The action to be taken on the payment if this rule matches
+    #[schema(value_type = FrmDecisionAction, example = "three_ds_challenge")]
+    pub action: api_enums::FrmDecisionAction,
+}
+
+impl FrmDecisionRule {
+    pub fn is_applicable(
+        &self,
+        verdict: Option<api_enums::FrmVerdict>,
+        score: Option<i32>,
+    ) -> bool {
+        let is_verdict_applicable = self.verdicts.as_ref().map_or(true, |verdicts| {
+            verdict.is_some_and(|verdict| verdicts.contains(&verdict))
+        });
+        let is_score_applicable = match (self.min_score, self.max_score) {
+            (None, None) => true,
+            (min_score, max_score) => score.is_some_and(|score| {
+                min_score.map_or(true, |min_score| score >= min_score)
+                    && max_score.map_or(true, |max_score| score <= max_score)
+            }),
+        };
+        is_verdict_applicable && is_score_applicable
+    }
+}
+
+impl FrmDecisionMatrix {
+    /// Returns the action of the first applicable rule, or the default action of the matrix
+    pub fn get_action(
+        &self,
+        verdict: Option<api_enums::FrmVerdict>,
+        score: Option<i32>,
+    ) -> Option<api_enums::FrmDecisionAction> {
+        self.rules
+            .iter()
+            .find(|rule| rule.is_applicable(verdict, score))
+            .map(|rule| rule.action)
+            .or(self.default_action)
+    }
+}
+
+/// Configuration of the review of authorized payments by the FRM connector, for payments with `automatic` capture.
+/// The capture of such payments is deferred until the post-authorization verdict of the FRM connector is received,
+/// and the payment is captured or voided when no verdict is received within the timeout
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmPostAuthReviewConfig {
+    /// Time in seconds to wait for the verdict of the FRM connector after the payment is authorized
+    #[schema(example = 86400)]
+    pub review_timeout_in_secs: u32,
+
+    /// The action to be taken on the payment when no verdict is received within the timeout
+    #[schema(value_type = FrmReviewAction, example = "void")]
+    pub timeout_action: api_enums::FrmReviewAction,
+}
+
+/// Configuration of the capture of the requests sent to and the responses received from the connectors.
+/// The captured exchanges are masked of sensitive data and retained for a limited time only
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorDebugCaptureConfig {
+    /// The time until which the exchanges with the connectors are captured, at most 72 hours from the time the capture is enabled
+    #[schema(value_type = PrimitiveDateTime, example = "2024-07-04T10:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub enabled_until: time::PrimitiveDateTime,
+}
+
+/// Policy applied when the connector a payment is routed to is unavailable during the authorization
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFailoverPolicy {
+    /// The action to take on the payment when the connector returns a network error or a 5xx response
+    #[schema(value_type = ConnectorFailoverAction, example = "retry_next_connector")]
+    pub action: api_enums::ConnectorFailoverAction,
+
+    /// The circuit breaker of the connectors of the profile. The connectors whose circuit is open are tried last, if at all, until the circuit closes again
+    pub circuit_breaker: Option<ConnectorCircuitBreakerConfig>,
+}
+
+/// Configuration of the circuit breaker of the connectors of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorCircuitBreakerConfig {
+    /// The number of outages of a connector within the window after which its circuit opens
+    #[schema(example = 5)]
+    pub failure_threshold: u32,
+
+    /// The duration in seconds of the window the outages of a connector are counted in
+    #[schema(example = 60)]
+    pub window_in_secs: u32,
+
+    /// The duration in seconds for which the circuit of a connector stays open
+    #[schema(example = 300)]
+    pub open_duration_in_secs: u32,
+}
+
+/// The vault holding the card details saved for the customers of a business profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(tag = "provider", rename_all = "snake_case", deny_unknown_fields)]
+pub enum VaultProvider {
+    /// The Hyperswitch card vault configured for the application
+    HyperswitchLocker,
+    /// A vault hosted outside Hyperswitch, in the PCI environment of the merchant, from the external vaults configured for the application
+    ExternalVault {
+        /// The name of the external vault in the application configuration
+        #[schema(example = "merchant_vault")]
+        vault_name: String,
+    },
+    /// The card details are not stored by Hyperswitch, the saved cards are only usable through the tokens issued by the connector
+    ConnectorTokenization {
+        /// The merchant connector account whose connector tokenizes the cards
+        #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+        merchant_connector_id: String,
+    },
+}
+
+/// How the taxes on the orders of the payments of a business profile are calculated
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(tag = "provider", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TaxProvider {
+    /// Tax rates configured on the profile for the shipping countries and states
+    StaticRules {
+        /// The tax rates, every rule matching the shipping address of a payment is applied
+        rules: Vec<TaxRule>,
+    },
+    /// A tax calculation service from the tax providers configured for the application
+    External {
+        /// The name of the tax provider in the application configuration
+        #[schema(example = "tax_service")]
+        provider_name: String,
+    },
+}
+
+/// A tax rate applied to the orders shipped to a country, or to a state of a country
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TaxRule {
+    /// The name of the tax, shown to the customer in the tax breakdown
+    #[schema(example = "State sales tax")]
+    pub name: String,
+    /// The shipping country the tax applies to
+    #[schema(value_type = CountryAlpha2, example = "US")]
+    pub country: api_enums::CountryAlpha2,
+    /// The shipping state the tax applies to. The tax applies to the whole country when not provided
+    #[schema(example = "CA")]
+    pub state: Option<String>,
+    /// The tax rate, as a percentage of the order amount
+    #[schema(value_type = Object, example = json!({"percentage": 7.25}))]
+    pub rate: common_utils::types::Percentage<{ consts::TAX_RATE_PRECISION_LENGTH }>,
+}
+
+/// Rules responding automatically to the disputes opened against the payments of a business profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAutoResponseConfig {
+    /// The disputes of an amount lower than the amount of the threshold of their currency are accepted as soon as they are opened
+    #[serde(default)]
+    pub accept_below: Vec<DisputeAmountThreshold>,
+    /// The evidence submitted for the disputes opened with some reason codes, when they are not accepted. The first template matching the reason code of a dispute is used
+    #[serde(default)]
+    pub evidence_templates: Vec<DisputeEvidenceTemplate>,
+}
+
+/// An amount in a currency below which the disputes are accepted
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAmountThreshold {
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1500)]
+    pub amount: common_utils::types::MinorUnit,
+}
+
+/// Standard evidence submitted for the disputes opened with some reason codes
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplate {
+    /// The reason codes of the connector the template applies to
+    #[schema(example = json!(["13.6"]))]
+    pub reason_codes: Vec<String>,
+    /// Whether the evidence is only submitted when the payment was refunded, such as for the disputes where the customer claims that a credit was not processed. The refunds of the payment are then described in the evidence
+    #[serde(default)]
+    pub requires_refund: bool,
+    /// Product Description
+    pub product_description: Option<String>,
+    /// File Id of refund policy
+    pub refund_policy: Option<String>,
+    /// Details of showing refund policy to customer before purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// Details why customer is not entitled to refund
+    pub refund_refusal_explanation: Option<String>,
+    /// File Id of cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// Details of showing cancellation policy to customer before purchase
+    pub cancellation_policy_disclosure: Option<String>,
+    /// Any additional supporting file
+    pub uncategorized_file: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
+
+/// The custom fields the payments of a business profile can carry
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomFieldsSchema {
+    /// The definitions of the custom fields, whose names must be unique
+    pub fields: Vec<CustomFieldDefinition>,
+}
+
+/// The definition of a custom field of the payments
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomFieldDefinition {
+    /// The name of the custom field, the key of its value in the custom fields of the payments
+    #[schema(max_length = 64, example = "store_id")]
+    pub name: String,
+    #[schema(value_type = CustomFieldType, example = "string")]
+    pub field_type: CustomFieldType,
+    /// Whether every payment of the profile must carry the custom field
+    #[serde(default)]
+    pub required: bool,
+    /// The largest number of characters of the values of a `string` custom field
+    #[schema(example = 32)]
+    pub max_length: Option<u16>,
+    /// The only values a `string` custom field can have
+    #[schema(example = json!(["online", "in_store"]))]
+    pub allowed_values: Option<Vec<String>>,
+}
+
+/// The type of the values of a custom field
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    String,
+    Number,
+    Boolean,
+    /// A calendar date, formatted as `YYYY-MM-DD`
+    Date,
+}
+
+/// A step of the onboarding of a merchant, required before it can accept payments
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    /// A business profile has been created
+    BusinessProfile,
+    /// An enabled connector has been configured
+    Connector,
+    /// A webhook URL has been configured on the merchant account or on a business profile
+    Webhook,
+    /// A routing algorithm has been activated on the merchant account or on a business profile
+    Routing,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OnboardingStepStatus {
+    #[schema(value_type = OnboardingStep, example = "connector")]
+    pub step: OnboardingStep,
+    pub completed: bool,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OnboardingStatusResponse {
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+    /// Whether every step of the onboarding is complete
+    pub completed: bool,
+    /// The first step of the onboarding that is not complete yet
+    #[schema(value_type = Option<OnboardingStep>, example = "webhook")]
+    pub next_step: Option<OnboardingStep>,
+    pub steps: Vec<OnboardingStepStatus>,
+}
+
+#[cfg(test)]
+mod frm_decision_matrix_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_frm_decision_matrix_get_action() {
+        let matrix: FrmDecisionMatrix = serde_json::from_value(serde_json::json!({
+            "rules": [
+                { "verdicts": ["fraud"], "action": "block" },
+                { "min_score": 60, "max_score": 80, "action": "three_ds_challenge" },
+                { "min_score": 81, "action": "manual_review" }
+            ],
+            "default_action": "allow"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            matrix.get_action(Some(api_enums::FrmVerdict::Fraud), Some(10)),
+            Some(api_enums::FrmDecisionAction::Block)
+        );
+        assert_eq!(
+            matrix.get_action(Some(api_enums::FrmVerdict::Legit), Some(70)),
+            Some(api_enums::FrmDecisionAction::ThreeDsChallenge)
+        );
+        assert_eq!(
+            matrix.get_action(Some(api_enums::FrmVerdict::ManualReview), Some(95)),
+            Some(api_enums::FrmDecisionAction::ManualReview)
+        );
+        assert_eq!(
+            matrix.get_action(None, None),
+            Some(api_enums::FrmDecisionAction::Allow)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use common_utils::{events, pii};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The rate of outcomes watched by an alert rule, over the outcomes of the merchant in the window
+/// of the rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AlertMetric {
+    /// The share of the authorizations that succeeded, breached when it drops below the threshold
+    AuthorizationSuccessRate,
+    /// The share of the outgoing webhooks the merchant server did not acknowledge, breached when it
+    /// rises above the threshold
+    WebhookFailureRate,
+    /// The share of the calls to connectors answered with a server error or not answered at all,
+    /// breached when it rises above the threshold
+    Connector5xxRate,
+}
+
+impl AlertMetric {
+    /// Whether the rate breaches the threshold when it is lower than the threshold, rather than
+    /// higher
+    pub fn is_breached_below_threshold(self) -> bool {
+        match self {
+            Self::AuthorizationSuccessRate => true,
+            Self::WebhookFailureRate | Self::Connector5xxRate => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AlertRule {
+    /// The identifier of the rule, unique among the rules of the merchant
+    #[schema(max_length = 64, example = "auth_success_drop")]
+    pub rule_id: String,
+
+    #[schema(value_type = AlertMetric, example = "authorization_success_rate")]
+    pub metric: AlertMetric,
+
+    /// The rate at which the rule is breached, as a percentage
+    #[schema(minimum = 0, maximum = 100, example = 80.0)]
+    pub threshold_percentage: f64,
+
+    /// The number of minutes of outcomes the rate is computed over
+    #[schema(example = 15)]
+    pub window_in_mins: u16,
+
+    /// The number of outcomes in the window below which the rule is not evaluated, so that a
+    /// handful of outcomes does not raise an alert
+    #[schema(example = 50)]
+    #[serde(default)]
+    pub min_volume: u32,
+
+    /// The connector whose calls are watched, all the connectors being watched when not set. Only
+    /// applies to the connector 5xx rate.
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The number of minutes after an alert during which the rule raises no other alert, defaults
+    /// to the window of the rule
+    #[schema(example = 60)]
+    pub cooldown_in_mins: Option<u16>,
+
+    /// Whether the rule is evaluated
+    #[serde(default = "default_rule_enabled")]
+    pub enabled: bool,
+}
+
+fn default_rule_enabled() -> bool {
+    true
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantAlertRulesRequest {
+    /// The rules evaluated for the merchant, replacing the rules previously set
+    #[serde(default)]
+    pub rules: Vec<AlertRule>,
+
+    /// The URL the alerts are posted to
+    #[schema(example = "https://alerts.example.com/hyperswitch")]
+    pub webhook_url: Option<String>,
+
+    /// The addresses the alerts are emailed to
+    #[schema(value_type = Vec<String>, example = json!(["oncall@example.com"]))]
+    #[serde(default)]
+    pub emails: Vec<pii::Email>,
+}
+
+impl events::ApiEventMetric for MerchantAlertRulesRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The alert rules of a merchant, as persisted in the configs
+pub type MerchantAlertRulesRecord = MerchantAlertRulesRequest;
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct MerchantAlertRulesResponse {
+    /// The identifier of the merchant account
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    /// Whether the alert rules are evaluated by the deployment
+    pub enabled: bool,
+
+    /// The rules evaluated for the merchant
+    pub rules: Vec<AlertRule>,
+
+    /// The URL the alerts are posted to
+    #[schema(example = "https://alerts.example.com/hyperswitch")]
+    pub webhook_url: Option<String>,
+
+    /// The addresses the alerts are emailed to
+    #[schema(value_type = Vec<String>, example = json!(["oncall@example.com"]))]
+    pub emails: Vec<pii::Email>,
+}
+
+impl events::ApiEventMetric for MerchantAlertRulesResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// An alert raised by a rule of the merchant being breached, as delivered to the webhook URL of the
+/// alert rules
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AlertEvent {
+    /// The identifier of the alert
+    #[schema(example = "alert_4fYOwdU6bVKWSoYvo2pT")]
+    pub alert_id: String,
+
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    /// The rule breached
+    #[schema(example = "auth_success_drop")]
+    pub rule_id: String,
+
+    #[schema(value_type = AlertMetric, example = "authorization_success_rate")]
+    pub metric: AlertMetric,
+
+    /// The connector whose calls are watched by the rule
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The rate at which the rule is breached, as a percentage
+    #[schema(example = 80.0)]
+    pub threshold_percentage: f64,
+
+    /// The rate observed in the window of the rule, as a percentage
+    #[schema(example = 62.5)]
+    pub observed_percentage: f64,
+
+    /// The number of outcomes in the window of the rule
+    #[schema(example = 120)]
+    pub volume: u64,
+
+    /// The number of minutes of outcomes the rate was computed over
+    #[schema(example = 15)]
+    pub window_in_mins: u16,
+
+    /// The time at which the alert was raised
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub triggered_at: PrimitiveDateTime,
+}
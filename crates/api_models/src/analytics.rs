@@ -20,6 +20,8 @@ pub mod auth_events;
 pub mod connector_events;
 pub mod disputes;
 pub mod outgoing_webhook_event;
+pub mod payment_declines;
+pub mod payment_funnel;
 pub mod payments;
 pub mod refunds;
 pub mod sdk_events;
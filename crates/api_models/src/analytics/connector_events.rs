@@ -4,3 +4,54 @@ pub struct ConnectorEventsRequest {
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorMetricsRequest {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    #[serde(alias = "startTime")]
+    pub start_time: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(alias = "endTime")]
+    pub end_time: Option<time::PrimitiveDateTime>,
+    pub profile_id: Option<String>,
+    pub connector_name: Option<String>,
+    /// The flow of the connector calls, such as `Authorize`, `Capture` or `Execute` for refunds
+    pub flow: Option<String>,
+}
+
+impl ConnectorMetricsRequest {
+    pub fn get_time_range(&self) -> super::TimeRange {
+        super::TimeRange {
+            start_time: self.start_time,
+            end_time: self.end_time,
+        }
+    }
+}
+
+/// Latency and availability of the calls made to a connector for a flow
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorMetricsBucket {
+    pub connector_name: String,
+    pub flow: String,
+    pub total_count: u64,
+    /// Number of calls for which the connector responded with a 2xx status code
+    pub success_count: u64,
+    pub success_rate: Option<f64>,
+    /// Latencies of the calls in milliseconds
+    pub latency_p50: Option<u64>,
+    pub latency_p90: Option<u64>,
+    pub latency_p99: Option<u64>,
+    /// Number of calls for which the connector responded with a 4xx status code
+    pub client_error_count: u64,
+    /// Number of calls for which the connector responded with a 5xx status code
+    pub server_error_count: u64,
+    /// Number of calls which timed out before the connector responded
+    pub timeout_count: u64,
+    /// Number of calls which could not be sent to the connector
+    pub network_error_count: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorMetricsResponse {
+    pub metrics: Vec<ConnectorMetricsBucket>,
+}
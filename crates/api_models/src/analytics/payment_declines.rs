@@ -0,0 +1,82 @@
+use super::{Granularity, TimeRange};
+
+/// The dimensions the declined payment attempts can be grouped by
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::AsRefStr,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentDeclineDimension {
+    Connector,
+    CardNetwork,
+    /// The issuer identification number of the card, the first digits of the card number
+    CardIsin,
+    CardIssuer,
+    ErrorCode,
+    /// The category of the decline, unified across the connectors
+    DeclineCategory,
+    /// The advice of the card network on retrying the payment, such as the merchant advice code
+    NetworkAdviceCode,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentDeclineFilters {
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub card_network: Vec<String>,
+    #[serde(default)]
+    pub card_isin: Vec<String>,
+    #[serde(default)]
+    pub card_issuer: Vec<String>,
+    #[serde(default)]
+    pub error_code: Vec<String>,
+    #[serde(default)]
+    pub decline_category: Vec<String>,
+    #[serde(default)]
+    pub network_advice_code: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentDeclinesRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PaymentDeclineDimension>,
+    #[serde(default)]
+    pub filters: PaymentDeclineFilters,
+    /// The declines are bucketed by the day in which they occurred, finer granularities are
+    /// aggregated by the day
+    pub granularity: Option<Granularity>,
+    /// Maximum number of buckets returned, the buckets with the most declines being returned first
+    pub limit: Option<u32>,
+}
+
+/// Number of declined payment attempts sharing the values of the dimensions grouped by, the
+/// dimensions not grouped by being left out
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentDeclineBucket {
+    pub connector: Option<String>,
+    pub card_network: Option<String>,
+    pub card_isin: Option<String>,
+    pub card_issuer: Option<String>,
+    pub error_code: Option<String>,
+    pub decline_category: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub time_bucket: Option<String>,
+    pub decline_count: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentDeclinesResponse {
+    pub declines: Vec<PaymentDeclineBucket>,
+}
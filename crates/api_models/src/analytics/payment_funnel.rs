@@ -0,0 +1,66 @@
+use super::{Granularity, TimeRange};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentFunnelRequest {
+    pub time_range: TimeRange,
+    pub payment_method: Option<String>,
+}
+
+/// Number of payments having reached each stage of the funnel, along with the share of the
+/// payments of the previous stage having reached the stage
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentFunnelResponse {
+    pub created_count: u64,
+    pub confirmed_count: u64,
+    pub authorized_count: u64,
+    pub captured_count: u64,
+    pub confirmation_rate: Option<f64>,
+    pub authorization_rate: Option<f64>,
+    pub capture_rate: Option<f64>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentFailureReasonsRequest {
+    pub time_range: TimeRange,
+    pub connector: Option<String>,
+    /// Maximum number of failure reasons returned, the most frequent reasons being returned first
+    pub limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentFailureReason {
+    pub connector: String,
+    pub error_code: String,
+    pub error_message: String,
+    pub attempt_count: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentFailureReasonsResponse {
+    pub failure_reasons: Vec<PaymentFailureReason>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentMethodDistributionRequest {
+    pub time_range: TimeRange,
+    /// The payments are bucketed by the hour in which they were created, finer granularities are
+    /// aggregated by the hour
+    pub granularity: Option<Granularity>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentMethodDistributionBucket {
+    pub payment_method: String,
+    pub time_bucket: Option<String>,
+    pub payment_count: u64,
+    /// Number of payments which were captured
+    pub success_count: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentMethodDistributionResponse {
+    pub distribution: Vec<PaymentMethodDistributionBucket>,
+}
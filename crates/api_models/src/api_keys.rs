@@ -23,6 +23,16 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to, the API Key having access to all the APIs when
+    /// none are specified.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses the API Key can only be used from, the API Key being usable from any IP
+    /// address when none are specified.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub allowed_ips: Option<Vec<std::net::IpAddr>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +70,14 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+    /// The scopes the API Key is restricted to, the API Key having access to all the APIs when
+    /// none are present.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses the API Key can only be used from.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +120,14 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+    /// The scopes the API Key is restricted to, the API Key having access to all the APIs when
+    /// none are present.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses the API Key can only be used from.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +156,16 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The scopes the API Key is restricted to. Passing an empty list lifts the restriction,
+    /// giving the API Key access to all the APIs.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses the API Key can only be used from. Passing an empty list lifts the
+    /// restriction, making the API Key usable from any IP address.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["192.0.2.10"]))]
+    pub allowed_ips: Option<Vec<std::net::IpAddr>>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
@@ -163,6 +199,120 @@ pub struct ListApiKeyConstraints {
     pub skip: Option<i64>,
 }
 
+/// A scope an API Key can be restricted to, the write scope of a resource also granting its read
+/// scope.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum ApiKeyScope {
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+    #[serde(rename = "refunds:read")]
+    #[strum(serialize = "refunds:read")]
+    RefundsRead,
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+    #[serde(rename = "routing:admin")]
+    #[strum(serialize = "routing:admin")]
+    RoutingAdmin,
+    /// Grants access to the sensitive fields of the API responses restricted for the merchant,
+    /// without granting access to any API
+    #[serde(rename = "sensitive_data:read")]
+    #[strum(serialize = "sensitive_data:read")]
+    SensitiveDataRead,
+}
+
+impl ApiKeyScope {
+    /// Whether an API Key restricted to the `granted` scope has this scope
+    pub fn is_granted_by(self, granted: Self) -> bool {
+        self == granted
+            || matches!(
+                (self, granted),
+                (Self::PaymentsRead, Self::PaymentsWrite) | (Self::RefundsRead, Self::RefundsWrite)
+            )
+    }
+}
+
+/// The grant types supported when requesting OAuth2 access tokens.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2GrantType {
+    ClientCredentials,
+}
+
+/// The request body for issuing an OAuth2 access token with the client credentials grant, the
+/// client being identified by the identifier of an API Key and authenticated with the API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+pub struct OAuth2TokenRequest {
+    /// The grant type, only `client_credentials` being supported.
+    pub grant_type: OAuth2GrantType,
+
+    /// The identifier of the API Key used as the client.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub client_id: String,
+
+    /// The plaintext API Key used as the client secret.
+    #[schema(value_type = String)]
+    pub client_secret: StrongSecret<String>,
+
+    /// The space delimited scopes requested for the access token, which must be among the scopes
+    /// of the API Key. The access token gets all the scopes of the API Key when none are
+    /// requested.
+    #[schema(example = "payments:read refunds:write")]
+    pub scope: Option<String>,
+}
+
+/// The response body for issuing an OAuth2 access token.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OAuth2TokenResponse {
+    /// The access token, to be sent in the `Authorization` header as a bearer token.
+    #[schema(value_type = String)]
+    pub access_token: StrongSecret<String>,
+
+    /// The type of the access token, always `Bearer`.
+    #[schema(example = "Bearer")]
+    pub token_type: String,
+
+    /// The number of seconds for which the access token is valid.
+    #[schema(example = 3600)]
+    pub expires_in: i64,
+
+    /// The space delimited scopes of the access token, absent when the access token has access to
+    /// all the APIs.
+    #[schema(example = "payments:read refunds:write")]
+    pub scope: Option<String>,
+}
+
+/// The request body for revoking an OAuth2 access token.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+pub struct OAuth2RevokeRequest {
+    /// The access token to revoke.
+    #[schema(value_type = String)]
+    pub token: StrongSecret<String>,
+
+    /// The identifier of the API Key the access token was issued to.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub client_id: String,
+
+    /// The plaintext API Key used as the client secret.
+    #[schema(value_type = String)]
+    pub client_secret: StrongSecret<String>,
+}
+
 /// The expiration date and time for an API Key.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -302,3 +452,24 @@ mod api_key_expiration_tests {
         assert_eq!(result, None);
     }
 }
+
+#[cfg(test)]
+mod api_key_scope_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_scope_serialization_matches_storage() {
+        let scope = serde_json::from_str::<ApiKeyScope>(r#""refunds:write""#).unwrap();
+        assert_eq!(scope, ApiKeyScope::RefundsWrite);
+        assert_eq!(scope.to_string(), "refunds:write");
+        assert_eq!("refunds:write".parse::<ApiKeyScope>().unwrap(), scope);
+    }
+
+    #[test]
+    fn test_write_scope_grants_read_scope() {
+        assert!(ApiKeyScope::PaymentsRead.is_granted_by(ApiKeyScope::PaymentsWrite));
+        assert!(!ApiKeyScope::PaymentsWrite.is_granted_by(ApiKeyScope::PaymentsRead));
+        assert!(!ApiKeyScope::RefundsRead.is_granted_by(ApiKeyScope::PaymentsWrite));
+    }
+}
@@ -0,0 +1,91 @@
+use common_utils::events;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The versions of the schema of the API responses, named after the date they were released on.
+/// The versions are declared from the oldest to the latest.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+pub enum ApiVersion {
+    /// The responses as they were before the schema was versioned
+    #[default]
+    #[serde(rename = "2024-01-01")]
+    #[strum(serialize = "2024-01-01")]
+    V2024_01_01,
+    /// The deprecated top level customer fields of the payments are removed in favour of the
+    /// `customer` object
+    #[serde(rename = "2024-07-29")]
+    #[strum(serialize = "2024-07-29")]
+    V2024_07_29,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiVersionPinRequest {
+    /// The version of the responses to the requests of the merchant without the `X-API-Version`
+    /// header. The default version of the deployment is used when not set.
+    #[schema(value_type = Option<ApiVersion>, example = "2024-07-29")]
+    pub version: Option<ApiVersion>,
+}
+
+impl events::ApiEventMetric for ApiVersionPinRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The version pinned for a merchant, as persisted in the configs
+pub type ApiVersionPinRecord = ApiVersionPinRequest;
+
+/// The version of the API responses of a merchant. The version requested in the `X-API-Version`
+/// header takes precedence over the version pinned for the merchant.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ApiVersionPinResponse {
+    /// The identifier of the merchant account
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    /// The version pinned for the merchant, if any
+    #[schema(value_type = Option<ApiVersion>, example = "2024-07-29")]
+    pub pinned_version: Option<ApiVersion>,
+
+    /// The version of the responses to the requests of the merchant without the `X-API-Version`
+    /// header
+    #[schema(value_type = ApiVersion, example = "2024-07-29")]
+    pub version: ApiVersion,
+
+    /// The latest version of the responses
+    #[schema(value_type = ApiVersion, example = "2024-07-29")]
+    pub latest_version: ApiVersion,
+
+    /// The time the version was deprecated at, if it is deprecated
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub deprecated_at: Option<PrimitiveDateTime>,
+
+    /// The time the version stops being served at, if it is deprecated
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub sunset_at: Option<PrimitiveDateTime>,
+}
+
+impl events::ApiEventMetric for ApiVersionPinResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,171 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The kind of credential the mutation was made with
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    AdminApiKey,
+    ApiKey,
+    User,
+    /// Mutations made by the application itself, outside of an API request
+    Internal,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditResourceType {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    BusinessProfile,
+    ApiKey,
+    RoutingAlgorithm,
+    /// A card stored in the card vault, identified by its vault reference, or a temporary token
+    /// of a payment method
+    VaultToken,
+    Payment,
+    PaymentMethod,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    /// A routing algorithm was made the active algorithm of a business profile
+    Activate,
+    /// The active routing algorithm of a business profile was deactivated
+    Deactivate,
+    /// The card of a vault token was read from the card vault
+    Read,
+    /// A temporary token was expired before the end of its time to live
+    Expire,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditEventsQuery {
+    /// Only list the events of this merchant
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: Option<String>,
+
+    /// Only list the mutations made by this API key or user
+    pub actor_id: Option<String>,
+
+    /// Only list the mutations of this type of resource
+    #[schema(value_type = Option<AuditResourceType>)]
+    pub resource_type: Option<AuditResourceType>,
+
+    /// Only list the mutations of this resource
+    pub resource_id: Option<String>,
+
+    #[schema(value_type = Option<AuditAction>)]
+    pub action: Option<AuditAction>,
+
+    /// Only list the events recorded after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only list the events recorded before this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of events to list, defaults to 100
+    pub limit: Option<i64>,
+
+    pub offset: Option<i64>,
+}
+
+impl ApiEventMetric for AuditEventsQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AuditEventResponse {
+    #[schema(example = "audit_abcdefghijklmnopqrst")]
+    pub event_id: String,
+
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    #[schema(value_type = AuditActorType, example = "admin_api_key")]
+    pub actor_type: AuditActorType,
+
+    /// The API key or the user that made the mutation, if any
+    pub actor_id: Option<String>,
+
+    #[schema(value_type = AuditResourceType, example = "merchant_connector_account")]
+    pub resource_type: AuditResourceType,
+
+    #[schema(example = "mca_abcdefghijklmnopqrst")]
+    pub resource_id: String,
+
+    #[schema(value_type = AuditAction, example = "update")]
+    pub action: AuditAction,
+
+    /// The fields of the resource that changed, each with its value before and after the mutation.
+    /// Sensitive values are masked
+    #[schema(value_type = Option<Object>, example = json!({"disabled": {"before": false, "after": true}}))]
+    pub diff: Option<serde_json::Value>,
+
+    /// The identifier of the request that made the mutation
+    pub request_id: Option<String>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AuditEventsResponse {
+    pub count: usize,
+    pub data: Vec<AuditEventResponse>,
+}
+
+impl ApiEventMetric for AuditEventsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,171 @@
+use common_enums::{
+    AuthenticationStatus, Currency, DecoupledAuthenticationType, TransactionStatus,
+};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    pii,
+    types::MinorUnit,
+};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::payments::{Address, Card, DeviceChannel, SdkInformation, ThreeDsCompletionIndicator};
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuthenticationCreateRequest {
+    /// The business profile whose authentication connectors authenticate the card
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: String,
+
+    /// The card to authenticate
+    pub card: Card,
+
+    /// The amount of the payment the card is authenticated for, in the lowest denomination of the
+    /// currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The details of the acquirer the payment will be authorized with
+    pub acquirer_details: Option<AuthenticationAcquirerDetails>,
+}
+
+impl ApiEventMetric for AuthenticationCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+pub struct AuthenticationAcquirerDetails {
+    /// The bank identification number of the acquirer
+    #[schema(example = "438309")]
+    pub acquirer_bin: String,
+
+    /// The identifier of the merchant at the acquirer
+    #[schema(example = "00002000000")]
+    pub acquirer_merchant_id: String,
+
+    /// The ISO 3166-1 numeric code of the country of the acquirer
+    #[schema(example = "840")]
+    pub acquirer_country_code: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuthenticationAuthenticateRequest {
+    /// Device Channel indicating whether request is coming from App or Browser
+    pub device_channel: DeviceChannel,
+
+    /// Indicates if 3DS method data was successfully completed or not
+    pub threeds_method_comp_ind: ThreeDsCompletionIndicator,
+
+    /// SDK Information if request is from SDK
+    pub sdk_information: Option<SdkInformation>,
+
+    /// The browser information of the cardholder, required when the request is coming from a
+    /// browser
+    #[schema(value_type = Option<BrowserInformation>)]
+    pub browser_info: Option<serde_json::Value>,
+
+    /// The billing address of the cardholder
+    pub billing: Address,
+
+    /// The shipping address of the order
+    pub shipping: Option<Address>,
+
+    /// The email of the cardholder
+    #[schema(value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<pii::Email>,
+
+    /// The URL the cardholder is redirected to once a challenge is completed
+    #[schema(example = "https://hyperswitch.io")]
+    pub return_url: Option<String>,
+}
+
+impl ApiEventMetric for AuthenticationAuthenticateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+/// A 3DS authentication of a card run without authorizing a payment
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AuthenticationResponse {
+    #[schema(example = "authn_mbabizu24mvu3mela5njyhpit4")]
+    pub authentication_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: String,
+
+    /// The authentication connector the card is authenticated with
+    #[schema(example = "netcetera")]
+    pub authentication_connector: String,
+
+    #[schema(value_type = AuthenticationStatus, example = "success")]
+    pub status: AuthenticationStatus,
+
+    /// Whether the issuer challenged the cardholder
+    #[schema(value_type = Option<DecoupledAuthenticationType>, example = "frictionless")]
+    pub authentication_type: Option<DecoupledAuthenticationType>,
+
+    /// The transaction status returned by the issuer
+    #[schema(value_type = Option<TransactionStatus>, example = "Y")]
+    pub trans_status: Option<TransactionStatus>,
+
+    /// The authentication value (CAVV) to send along with the authorization of the payment
+    #[schema(value_type = Option<String>)]
+    pub authentication_value: Option<Secret<String>>,
+
+    /// The electronic commerce indicator to send along with the authorization of the payment
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+
+    /// The 3DS protocol version of the authentication
+    #[schema(example = "2.2.0")]
+    pub message_version: Option<String>,
+
+    pub three_ds_server_trans_id: Option<String>,
+
+    pub ds_trans_id: Option<String>,
+
+    /// The data to post to the 3DS method URL, before authenticating the card
+    pub three_ds_method_data: Option<String>,
+
+    /// The 3DS method URL of the issuer, loaded in a hidden iframe before authenticating the card
+    pub three_ds_method_url: Option<String>,
+
+    /// The URL of the access control server challenging the cardholder
+    pub acs_url: Option<String>,
+
+    pub challenge_request: Option<String>,
+
+    pub acs_reference_number: Option<String>,
+
+    pub acs_trans_id: Option<String>,
+
+    pub acs_signed_content: Option<String>,
+
+    pub error_code: Option<String>,
+
+    pub error_message: Option<String>,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for AuthenticationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Authentication {
+            authentication_id: self.authentication_id.clone(),
+        })
+    }
+}
@@ -9,6 +9,9 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    Email(String),
+    CustomerId(String),
+    IpAddress(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -28,6 +31,8 @@ pub struct BlocklistResponse {
     pub fingerprint_id: String,
     #[schema(value_type = BlocklistDataKind)]
     pub data_kind: enums::BlocklistDataKind,
+    #[schema(value_type = BlocklistListType)]
+    pub list_type: enums::BlocklistListType,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
 }
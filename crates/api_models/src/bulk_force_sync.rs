@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BulkForceSyncRequest {
+    /// Only sync the payments of this merchant, the payments of every merchant being synced when
+    /// not provided
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: Option<String>,
+
+    /// Only sync the payments made with these connectors
+    #[schema(value_type = Option<Vec<Connector>>, example = json!(["stripe", "adyen"]))]
+    pub connectors: Option<Vec<api_enums::Connector>>,
+
+    /// The statuses of the payment attempts considered stuck, pending and authorizing when not
+    /// provided
+    #[schema(value_type = Option<Vec<AttemptStatus>>, example = json!(["pending", "authorizing"]))]
+    pub statuses: Option<Vec<api_enums::AttemptStatus>>,
+
+    /// The number of minutes a payment attempt must have been left untouched to be considered
+    /// stuck, defaults to 60
+    #[schema(example = 60)]
+    pub older_than_minutes: Option<u32>,
+
+    /// The number of payment attempts fetched from the database at a time, defaults to 100
+    #[schema(maximum = 1000, example = 100)]
+    pub batch_size: Option<u16>,
+
+    /// The largest number of payments synced with each connector every second, defaults to 5
+    #[schema(example = 5)]
+    pub max_syncs_per_second: Option<u16>,
+}
+
+impl ApiEventMetric for BulkForceSyncRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct BulkForceSyncJobId {
+    pub job_id: String,
+}
+
+impl ApiEventMetric for BulkForceSyncJobId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkForceSyncStatus {
+    /// The job is yet to be picked by the scheduler
+    Scheduled,
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// The payments synced with a connector
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorForceSyncSummary {
+    /// The number of payments synced with the connector
+    pub synced_count: u64,
+    /// The number of payments no longer processing once synced
+    pub resolved_count: u64,
+    /// The number of payments which could not be synced
+    pub failed_count: u64,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkForceSyncSummary {
+    /// The number of stuck payment attempts found
+    pub found_count: u64,
+    /// The number of payments synced with their connector
+    pub synced_count: u64,
+    /// The number of payments no longer processing once synced
+    pub resolved_count: u64,
+    /// The number of payments which could not be synced
+    pub failed_count: u64,
+    /// The payments synced with each connector
+    #[schema(value_type = Object)]
+    pub connectors: HashMap<String, ConnectorForceSyncSummary>,
+    /// The number of synced payments of each status
+    #[schema(value_type = Object, example = json!({"succeeded": 12, "failed": 3, "processing": 1}))]
+    pub statuses: HashMap<api_enums::IntentStatus, u64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct BulkForceSyncResponse {
+    /// The identifier of the job
+    #[schema(example = "BULK_FORCE_SYNC_WORKFLOW_BULK_FORCE_SYNC_1721030400")]
+    pub job_id: String,
+
+    #[schema(value_type = BulkForceSyncStatus, example = "completed")]
+    pub status: BulkForceSyncStatus,
+
+    /// Payment attempts last modified before this time are considered stuck
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_before: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_at: PrimitiveDateTime,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    /// The report of the payments synced, available once the job is completed
+    pub summary: Option<BulkForceSyncSummary>,
+}
+
+impl ApiEventMetric for BulkForceSyncResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
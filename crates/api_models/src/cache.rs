@@ -0,0 +1,54 @@
+use common_utils::events;
+
+/// The limits and the number of entries of an in-memory cache, on the instance which served the
+/// request
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct InMemoryCacheStats {
+    /// Name of the cache, e.g. `ROUTING_CACHE`
+    pub name: String,
+    /// Approximate number of entries the cache holds
+    pub entry_count: u64,
+    /// Max number of entries the cache holds before evicting the least recently used ones
+    pub max_entries: Option<u64>,
+    pub time_to_live_in_secs: Option<u64>,
+    pub time_to_idle_in_secs: Option<u64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct InMemoryCacheListResponse {
+    pub caches: Vec<InMemoryCacheStats>,
+}
+
+impl events::ApiEventMetric for InMemoryCacheListResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct InMemoryCacheKeyRequest {
+    /// Name of the cache, e.g. `ROUTING_CACHE`
+    pub cache_name: String,
+    /// The key of the entry, without the prefix of the tenant
+    pub key: String,
+}
+
+impl events::ApiEventMetric for InMemoryCacheKeyRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct InMemoryCacheKeyResponse {
+    pub cache_name: String,
+    pub key: String,
+    /// Whether the entry is cached on the instance which served the request
+    pub present: bool,
+}
+
+impl events::ApiEventMetric for InMemoryCacheKeyResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
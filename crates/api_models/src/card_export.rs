@@ -0,0 +1,110 @@
+use common_enums::CardExportStatus;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardExportCreateRequest {
+    /// Name of the payment service provider receiving the exported cards
+    #[schema(example = "acme_payments")]
+    pub receiving_psp: String,
+
+    /// PEM encoded RSA public key of the receiving payment service provider, to which the export
+    /// file is encrypted
+    #[schema(
+        example = "-----BEGIN PUBLIC KEY-----\nMIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA...\n-----END PUBLIC KEY-----"
+    )]
+    pub receiver_public_key: String,
+
+    /// Only export these payment methods. All the active cards of the merchant are exported when
+    /// not provided
+    #[schema(example = json!(["pm_abcdefghijklmnopqrst"]))]
+    pub payment_method_ids: Option<Vec<String>>,
+
+    /// The reason for the export, shown to the reviewer of the export
+    #[schema(example = "Migration to acme_payments")]
+    pub reason: Option<String>,
+}
+
+impl ApiEventMetric for CardExportCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardExportRejectRequest {
+    /// The reason for rejecting the export
+    #[schema(example = "The receiving PSP was not agreed upon")]
+    pub rejection_reason: Option<String>,
+}
+
+impl ApiEventMetric for CardExportRejectRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CardExportResponse {
+    /// The identifier of the export
+    #[schema(example = "cardexp_abcdefghijklmnopqrst")]
+    pub export_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(example = "acme_payments")]
+    pub receiving_psp: String,
+
+    #[schema(value_type = CardExportStatus, example = "pending_approval")]
+    pub status: CardExportStatus,
+
+    /// The payment methods the export is restricted to, if any
+    pub payment_method_ids: Option<Vec<String>>,
+
+    pub reason: Option<String>,
+
+    /// The user who requested the export
+    pub requested_by: String,
+
+    /// The user who approved or rejected the export
+    pub reviewed_by: Option<String>,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub reviewed_at: Option<PrimitiveDateTime>,
+
+    pub rejection_reason: Option<String>,
+
+    /// The number of cards considered for the export
+    pub total_count: Option<i32>,
+
+    /// The number of cards included in the export file
+    pub exported_count: Option<i32>,
+
+    /// The number of cards which could not be retrieved from the card vault
+    pub failed_count: Option<i32>,
+
+    /// The identifier of the encrypted export file, once the export has completed
+    #[schema(example = "file_abcdefghijklmnopqrst")]
+    pub file_id: Option<String>,
+
+    pub error_message: Option<String>,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for CardExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
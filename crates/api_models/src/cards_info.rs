@@ -26,6 +26,15 @@ pub struct CardInfoResponse {
     pub card_type: Option<String>,
     #[schema(example = "CLASSIC")]
     pub card_sub_type: Option<String>,
+    /// Whether the card is a credit, debit or prepaid card
+    #[schema(value_type = Option<CardFundingType>, example = "credit")]
+    pub card_funding_type: Option<common_enums::CardFundingType>,
+    /// Whether the interchange fees of the card are capped by regulation
+    pub is_regulated: Option<bool>,
     #[schema(example = "INDIA")]
     pub card_issuing_country: Option<String>,
+    /// The networks the card can be processed on when the card is co-badged, the primary network
+    /// first. The customer may choose the network the payment is processed on
+    #[schema(example = json!(["CartesBancaires", "Visa"]))]
+    pub co_badged_card_networks: Option<Vec<String>>,
 }
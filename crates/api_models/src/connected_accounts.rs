@@ -0,0 +1,215 @@
+use common_utils::{
+    consts::PLATFORM_FEE_PERCENTAGE_PRECISION_LENGTH,
+    events, pii,
+    types::{MinorUnit, Percentage},
+};
+use masking::Secret;
+use utoipa::ToSchema;
+
+use crate::{
+    admin::{MerchantAccountMetadata, MerchantAccountResponse, MerchantDetails, WebhookDetails},
+    enums,
+    payments::TimeRange,
+    profile_cloning::BusinessProfileCloneResponse,
+};
+
+/// The fee the platform collects on each payment of a connected account
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PlatformFee {
+    /// Percentage of the amount of the payment
+    #[schema(value_type = Option<f32>, example = 2.5)]
+    pub percentage: Option<Percentage<PLATFORM_FEE_PERCENTAGE_PRECISION_LENGTH>>,
+
+    /// Fixed amount, in the minor unit of the currency of the payment, added to the percentage
+    #[schema(value_type = Option<i64>, example = 30)]
+    pub fixed_amount: Option<MinorUnit>,
+}
+
+/// How the payments of a connected account are split between the connected account and the
+/// platform, sent to the connectors supporting split payments as the charges of the payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectedAccountSplit {
+    #[schema(value_type = PaymentChargeType, example = "direct")]
+    pub charge_type: enums::PaymentChargeType,
+
+    /// The account of the connected account at the connector the funds are transferred to
+    #[schema(example = "acct_1PDftAIhl7EEkW0O")]
+    pub transfer_account_id: String,
+
+    pub platform_fee: PlatformFee,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectedAccountCreateRequest {
+    /// The identifier of the merchant account created for the connected account
+    #[schema(max_length = 255, example = "merchant_seller_1234")]
+    pub merchant_id: String,
+
+    #[schema(value_type = Option<String>, example = "NewAge Retailer")]
+    pub merchant_name: Option<Secret<String>>,
+
+    pub merchant_details: Option<MerchantDetails>,
+
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/success")]
+    pub return_url: Option<url::Url>,
+
+    pub webhook_details: Option<WebhookDetails>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<MerchantAccountMetadata>,
+
+    /// How the payments of the connected account are split with the platform, none when the
+    /// platform collects no fee
+    pub split: Option<ConnectedAccountSplit>,
+
+    /// A business profile template of the platform the default business profile of the connected
+    /// account is created from
+    #[schema(example = "eu_marketplace_seller")]
+    pub profile_template_name: Option<String>,
+}
+
+impl events::ApiEventMetric for ConnectedAccountCreateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectedAccountUpdateRequest {
+    /// Replaces how the payments of the connected account are split with the platform, the split
+    /// being removed when none
+    pub split: Option<ConnectedAccountSplit>,
+}
+
+impl events::ApiEventMetric for ConnectedAccountUpdateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectedAccountResponse {
+    /// The platform the connected account was onboarded by
+    #[schema(example = "merchant_platform")]
+    pub platform_merchant_id: String,
+
+    pub merchant_account: MerchantAccountResponse,
+
+    pub split: Option<ConnectedAccountSplit>,
+
+    /// The business profile created from the template of the platform, when one was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<BusinessProfileCloneResponse>,
+}
+
+impl events::ApiEventMetric for ConnectedAccountResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectedAccountsListResponse {
+    #[schema(example = "merchant_platform")]
+    pub platform_merchant_id: String,
+
+    pub connected_accounts: Vec<ConnectedAccountResponse>,
+}
+
+impl events::ApiEventMetric for ConnectedAccountsListResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The settings of a connected account, as persisted in the configs
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConnectedAccountSettingsRecord {
+    pub split: Option<ConnectedAccountSplit>,
+    pub modified_at: Option<i64>,
+}
+
+/// The credentials the merchant connector account of a connected account is given
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConnectedAccountCredentials {
+    /// The credentials of the merchant connector account of the platform, the payments of the
+    /// connected account being processed through the account of the platform at the connector
+    Shared,
+    /// The own credentials of the connected account at the connector. The merchant connector
+    /// account is created inactive when they are not given.
+    Dedicated {
+        #[schema(value_type = Option<Object>, example = json!({"auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey"}))]
+        connector_account_details: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectedAccountConnectorRequest {
+    /// The merchant connector account of the platform the merchant connector account of the
+    /// connected account is created from
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+
+    /// The business profile of the connected account, defaults to its default business profile
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: Option<String>,
+
+    pub credentials: ConnectedAccountCredentials,
+}
+
+impl events::ApiEventMetric for ConnectedAccountConnectorRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The volume processed by a connected account in a currency
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectedAccountVolume {
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// The amount of the successful payments
+    #[schema(value_type = i64, example = 125000)]
+    pub amount: MinorUnit,
+
+    /// The fees collected by the platform on the successful payments
+    #[schema(value_type = i64, example = 3155)]
+    pub platform_fee: MinorUnit,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectedAccountReport {
+    #[schema(example = "merchant_seller_1234")]
+    pub merchant_id: String,
+
+    /// The number of payments created in the time range
+    pub total_payments: usize,
+
+    /// The number of payments created in the time range which succeeded
+    pub succeeded_payments: usize,
+
+    pub volumes: Vec<ConnectedAccountVolume>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectedAccountsReport {
+    #[schema(example = "merchant_platform")]
+    pub platform_merchant_id: String,
+
+    pub time_range: TimeRange,
+
+    pub connected_accounts: Vec<ConnectedAccountReport>,
+}
+
+impl events::ApiEventMetric for ConnectedAccountsReport {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
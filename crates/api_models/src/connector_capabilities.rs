@@ -0,0 +1,78 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The flows, payment methods, currencies and countries supported by a connector
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorCapabilitiesResponse {
+    /// The name of the connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: api_enums::Connector,
+
+    /// The capture methods the payments can be made with through the connector
+    #[schema(value_type = Vec<CaptureMethod>)]
+    pub capture_methods: Vec<api_enums::CaptureMethod>,
+
+    /// Whether the amount captured can exceed the amount authorized
+    pub overcapture: bool,
+
+    /// The number of days after the payment within which it can be refunded, if the connector
+    /// limits it
+    #[schema(example = 180)]
+    pub refund_window_in_days: Option<u16>,
+
+    /// The payment method types mandates can be set up with through the connector
+    pub mandates: Vec<MandateCapability>,
+
+    /// Whether the connector supports payouts
+    pub payouts: bool,
+
+    /// Whether the connector supports 3DS authentication of card payments
+    pub three_ds: bool,
+
+    /// Whether the 3DS authentication can be made through the connector ahead of the
+    /// authorization
+    pub separate_authentication: bool,
+
+    /// The payment method types and card networks configured for the connector, along with the
+    /// currencies and countries they are available in
+    pub payment_methods: Vec<PaymentMethodCapability>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct MandateCapability {
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: api_enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType)]
+    pub payment_method_type: api_enums::PaymentMethodType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodCapability {
+    /// The payment method type, if the availability is that of a payment method type
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The card network, if the availability is that of a card network
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<api_enums::CardNetwork>,
+
+    /// The currencies the payment method is available in, all of them if not set
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currencies: Option<Vec<api_enums::Currency>>,
+
+    /// The countries the payment method is available in, all of them if not set
+    #[schema(value_type = Option<Vec<CountryAlpha2>>)]
+    pub countries: Option<Vec<api_enums::CountryAlpha2>>,
+
+    /// The capture method the payment method cannot be used with, if any
+    #[schema(value_type = Option<CaptureMethod>)]
+    pub unsupported_capture_method: Option<api_enums::CaptureMethod>,
+}
+
+impl ApiEventMetric for ConnectorCapabilitiesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
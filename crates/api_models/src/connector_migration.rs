@@ -0,0 +1,158 @@
+use common_enums::{
+    ConnectorMigrationPaymentMethodStatus, ConnectorMigrationStatus, CredentialMigrationMethod,
+    Currency,
+};
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMigrationCreateRequest {
+    /// The merchant connector account the stored credentials of the payment methods are migrated
+    /// from
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub source_merchant_connector_id: String,
+
+    /// The merchant connector account the stored credentials of the payment methods are migrated
+    /// to
+    #[schema(example = "mca_9ZtPLi6hXhNnVq2Tc5mA")]
+    pub target_merchant_connector_id: String,
+
+    /// The currency of the zero amount mandates set up with the target connector
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The methods the credentials may be migrated with, in the order of preference. Every method
+    /// is allowed when not provided.
+    #[schema(value_type = Option<Vec<CredentialMigrationMethod>>, example = json!(["network_transaction_id"]))]
+    pub allowed_methods: Option<Vec<CredentialMigrationMethod>>,
+
+    /// Only migrate these payment methods. All the active cards of the merchant with a mandate
+    /// through the source connector are migrated when not provided
+    #[schema(example = json!(["pm_abcdefghijklmnopqrst"]))]
+    pub payment_method_ids: Option<Vec<String>>,
+}
+
+impl ApiEventMetric for ConnectorMigrationCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorMigrationResponse {
+    /// The identifier of the migration
+    #[schema(example = "conmig_abcdefghijklmnopqrst")]
+    pub migration_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub source_merchant_connector_id: String,
+
+    #[schema(example = "mca_9ZtPLi6hXhNnVq2Tc5mA")]
+    pub target_merchant_connector_id: String,
+
+    /// The name of the target connector
+    #[schema(example = "adyen")]
+    pub target_connector: String,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    #[schema(value_type = Vec<CredentialMigrationMethod>)]
+    pub allowed_methods: Vec<CredentialMigrationMethod>,
+
+    /// The payment methods the migration is restricted to, if any
+    pub payment_method_ids: Option<Vec<String>>,
+
+    #[schema(value_type = ConnectorMigrationStatus, example = "processing")]
+    pub status: ConnectorMigrationStatus,
+
+    /// The number of payment methods processed so far
+    pub total_count: i32,
+
+    /// The number of payment methods migrated to the target connector
+    pub migrated_count: i32,
+
+    /// The number of payment methods which could already be used through the target connector
+    pub skipped_count: i32,
+
+    /// The number of payment methods which could not be migrated
+    pub failed_count: i32,
+
+    pub error_message: Option<String>,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ConnectorMigrationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMigrationPaymentMethodListConstraints {
+    /// The maximum number of payment methods to return, defaults to 100
+    #[schema(example = 100)]
+    pub limit: Option<i64>,
+
+    /// The number of payment methods to skip
+    #[schema(example = 0)]
+    pub offset: Option<i64>,
+}
+
+impl ApiEventMetric for ConnectorMigrationPaymentMethodListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorMigrationPaymentMethodResponse {
+    #[schema(example = "pm_abcdefghijklmnopqrst")]
+    pub payment_method_id: String,
+
+    #[schema(value_type = ConnectorMigrationPaymentMethodStatus, example = "migrated")]
+    pub status: ConnectorMigrationPaymentMethodStatus,
+
+    /// The method the credentials were migrated with
+    #[schema(value_type = Option<CredentialMigrationMethod>, example = "card_details")]
+    pub migration_method: Option<CredentialMigrationMethod>,
+
+    /// The zero amount payment which set up the mandate with the target connector
+    #[schema(example = "pay_abcdefghijklmnopqrst")]
+    pub payment_id: Option<String>,
+
+    /// The reason for which the payment method could not be migrated
+    pub error_message: Option<String>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorMigrationPaymentMethodListResponse {
+    /// The number of payment methods returned
+    pub count: usize,
+
+    /// The outcome of the migration of each payment method, in the order of their identifiers
+    pub data: Vec<ConnectorMigrationPaymentMethodResponse>,
+}
+
+impl ApiEventMetric for ConnectorMigrationPaymentMethodListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
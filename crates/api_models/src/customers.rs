@@ -1,9 +1,9 @@
-use common_utils::{crypto, custom_serde, id_type, pii};
+use common_utils::{crypto, custom_serde, id_type, pii, types::MinorUnit};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::payments;
+use crate::{enums as api_enums, payments};
 
 /// The customer details
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
@@ -98,6 +98,134 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// An aggregate view of a customer and of their activity
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerOverviewResponse {
+    /// The customer details
+    pub customer: CustomerResponse,
+    /// The payment methods saved for the customer
+    pub payment_methods: Vec<CustomerOverviewPaymentMethod>,
+    /// The mandates of the customer
+    pub mandates: Vec<CustomerOverviewMandate>,
+    /// The latest payments of the customer, the most recent first
+    pub recent_payments: Vec<CustomerOverviewPayment>,
+    /// The amounts captured from the payments of the customer, by currency
+    pub total_spend: Vec<CustomerSpend>,
+    /// The number of disputes raised against the payments of the customer
+    #[schema(example = 0)]
+    pub dispute_count: i64,
+    /// The reasons for which the customer is considered risky, empty when there are none
+    pub fraud_flags: Vec<CustomerFraudFlag>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerOverviewPaymentMethod {
+    /// The identifier for the payment method
+    #[schema(example = "pm_djh2837dwduh890123")]
+    pub payment_method_id: String,
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    #[schema(value_type = PaymentMethodStatus)]
+    pub status: api_enums::PaymentMethodStatus,
+    /// Whether the payment method is the default payment method of the customer
+    pub is_default: bool,
+    ///  A timestamp (ISO 8601 code) that determines when the payment method was last used
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub last_used_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerOverviewMandate {
+    /// The identifier for the mandate
+    pub mandate_id: String,
+    #[schema(value_type = MandateStatus)]
+    pub status: api_enums::MandateStatus,
+    /// The identifier for the payment method of the mandate
+    pub payment_method_id: String,
+    /// The connector the mandate was set up with
+    pub connector: String,
+    ///  A timestamp (ISO 8601 code) that determines when the mandate was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerOverviewPayment {
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    ///  A timestamp (ISO 8601 code) that determines when the payment was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerSpend {
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The amount captured in the currency, in its lowest denomination
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerFraudFlag {
+    /// The customer id is in the block list of the merchant
+    BlocklistedCustomerId,
+    /// The email of the customer is in the block list of the merchant
+    BlocklistedEmail,
+    /// Payments of the customer were found to be fraudulent by a fraud check
+    FraudulentPayments,
+}
+
+/// The redaction of the personal data of a customer, which runs in the background
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerRedactionResponse {
+    /// The identifier for the redaction
+    #[schema(
+        example = "CUSTOMER_REDACTION_WORKFLOW_CUSTOMER_REDACTION_merchant_1668273825_cus_y3oqhf46pyzuxjbcn2giaqnb44"
+    )]
+    pub redaction_id: String,
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    pub status: CustomerRedactionStatus,
+    ///  A timestamp (ISO 8601 code) that determines when the redaction was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    ///  A timestamp (ISO 8601 code) that determines when the redaction completed or failed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerRedactionStatus {
+    /// The redaction is yet to be picked up
+    Pending,
+    /// The redaction is in progress, or is being retried after a failure
+    InProgress,
+    /// The personal data of the customer was redacted
+    Completed,
+    /// The redaction could not be completed, and can be requested again
+    Failed,
+}
+
 fn unknown_merchant() -> String {
     String::from("merchant_unknown")
 }
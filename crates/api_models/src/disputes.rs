@@ -3,7 +3,7 @@ use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use super::enums::{DisputeStage, DisputeStatus};
-use crate::files;
+use crate::{files, payments::ListCursor};
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
 pub struct DisputeResponse {
@@ -137,6 +137,15 @@ pub struct DisputeListConstraints {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(rename = "received_time.gte")]
     pub received_time_gte: Option<PrimitiveDateTime>,
+    /// Minimum disputed amount, in the lowest denomination of the currency
+    #[serde(rename = "dispute_amount.gte")]
+    pub dispute_amount_gte: Option<i64>,
+    /// Maximum disputed amount, in the lowest denomination of the currency
+    #[serde(rename = "dispute_amount.lte")]
+    pub dispute_amount_lte: Option<i64>,
+    /// The `X-Next-Cursor` header of the previous page, to fetch the disputes which follow it
+    #[schema(value_type = Option<String>)]
+    pub cursor: Option<ListCursor>,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -92,6 +92,7 @@ pub enum Connector {
     // Datatrans,
     Dlocal,
     Ebanx,
+    Externaladapter,
     Fiserv,
     Forte,
     Globalpay,
@@ -214,6 +215,7 @@ impl Connector {
             | Self::Cryptopay
             | Self::Dlocal
             | Self::Ebanx
+            | Self::Externaladapter
             | Self::Fiserv
             | Self::Forte
             | Self::Globalpay
@@ -294,6 +296,33 @@ impl AuthenticationConnectors {
     }
 }
 
+/// How the authentication connector of a 3DS authentication is chosen among the authentication
+/// connectors of the profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthenticationConnectorSelection {
+    /// The first authentication connector of the profile is always used
+    #[default]
+    Priority,
+    /// The authentication connector with the highest authentication success rate for the issuer
+    /// of the card is used, the first one being used until enough authentications are recorded
+    IssuerPerformance,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(
     Clone,
@@ -404,6 +433,73 @@ pub enum FrmAction {
     ManualReview,
 }
 
+/// The verdict returned by the FRM connector for a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FrmVerdict {
+    Fraud,
+    ManualReview,
+    Legit,
+}
+
+/// The action taken on an authorized payment whose automatic capture was deferred until the post-authorization verdict of the FRM connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FrmReviewAction {
+    /// Capture the authorized amount of the payment
+    Capture,
+    /// Void the authorization of the payment
+    Void,
+}
+
+/// The action taken on a payment whose authorization failed because the connector was unavailable
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorFailoverAction {
+    /// Fail the payment
+    Fail,
+    /// Retry the authorization through the next connector the payment is routed to
+    RetryNextConnector,
+    /// Keep the payment pending and synchronize its status with the connector later
+    Queue,
+}
+
 #[derive(
     Clone, Debug, serde::Deserialize, serde::Serialize, strum::Display, strum::EnumString, ToSchema,
 )]
@@ -14,6 +14,7 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
             Self::ConnectorError(_, code) => *code,
+            Self::TooManyRequests(_, _) => StatusCode::TOO_MANY_REQUESTS,
             Self::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::BadRequest(_) => StatusCode::BAD_REQUEST,
@@ -24,8 +25,11 @@ impl actix_web::ResponseError for ApiErrorResponse {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::TooManyRequests(_, retry_after_in_secs) = self {
+            response.insert_header((header::RETRY_AFTER, retry_after_in_secs.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
@@ -91,6 +91,8 @@ pub enum ApiErrorResponse {
     InternalServerError(ApiError),
     NotImplemented(ApiError),
     ConnectorError(ApiError, #[serde(skip_serializing)] StatusCode),
+    /// Carries the number of seconds after which the request can be retried
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
@@ -124,7 +126,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -142,7 +145,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -157,6 +161,7 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
+            | Self::TooManyRequests(_, _)
             | Self::BadRequest(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
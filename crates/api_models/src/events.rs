@@ -23,8 +23,17 @@ use common_utils::{
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
+        api_event::*,
+        auth_events::*,
+        connector_events::{
+            ConnectorEventsRequest, ConnectorMetricsRequest, ConnectorMetricsResponse,
+        },
+        outgoing_webhook_event::OutgoingWebhookLogsRequest,
+        payment_declines::*,
+        payment_funnel::*,
+        sdk_events::*,
+        search::*,
+        *,
     },
     api_keys::*,
     cards_info::*,
@@ -50,6 +59,10 @@ impl_misc_api_event_type!(
     MerchantConnectorId,
     MandateResponse,
     MandateRevokedResponse,
+    MandateMigrationRequest,
+    MandateMigrationResponse,
+    MandatePreNotificationRequest,
+    MandatePreNotificationResponse,
     RetrievePaymentLinkRequest,
     PaymentLinkListConstraints,
     MandateId,
@@ -63,14 +76,25 @@ impl_misc_api_event_type!(
     ToggleKVRequest,
     ToggleAllKVRequest,
     ToggleAllKVResponse,
+    MerchantKvSettingsUpdateRequest,
+    MerchantKvSettingsResponse,
+    DrainerTuning,
+    KvBacklogResponse,
+    OnboardingStatusResponse,
     MerchantAccountDeleteResponse,
     MerchantAccountUpdate,
     CardInfoResponse,
     CreateApiKeyResponse,
     CreateApiKeyRequest,
+    OAuth2TokenRequest,
+    OAuth2TokenResponse,
+    OAuth2RevokeRequest,
     MerchantConnectorDeleteResponse,
     MerchantConnectorUpdate,
     MerchantConnectorCreate,
+    MerchantConnectorNextCredentialsRequest,
+    MerchantConnectorCredentialsCutoverRequest,
+    MerchantConnectorCredentialsRotationResponse,
     MerchantId,
     CardsInfoRequest,
     MerchantAccountResponse,
@@ -100,6 +124,16 @@ impl_misc_api_event_type!(
     SdkEventsRequest,
     ReportRequest,
     ConnectorEventsRequest,
+    ConnectorMetricsRequest,
+    ConnectorMetricsResponse,
+    GetPaymentDeclinesRequest,
+    PaymentDeclinesResponse,
+    GetPaymentFunnelRequest,
+    PaymentFunnelResponse,
+    GetPaymentFailureReasonsRequest,
+    PaymentFailureReasonsResponse,
+    GetPaymentMethodDistributionRequest,
+    PaymentMethodDistributionResponse,
     OutgoingWebhookLogsRequest,
     GetGlobalSearchRequest,
     GetSearchRequest,
@@ -1,6 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest, CustomerResponse};
+use crate::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerOverviewResponse, CustomerRedactionResponse,
+    CustomerRequest, CustomerResponse,
+};
 
 impl ApiEventMetric for CustomerDeleteResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -33,3 +36,19 @@ impl ApiEventMetric for CustomerId {
         })
     }
 }
+
+impl ApiEventMetric for CustomerOverviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerRedactionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
@@ -2,19 +2,23 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::{
     payment_methods::{
-        CustomerDefaultPaymentMethodResponse, CustomerPaymentMethodsListResponse,
+        CustomerDefaultPaymentMethodResponse, CustomerPaymentMethodOrderRequest,
+        CustomerPaymentMethodOrderResponse, CustomerPaymentMethodsListResponse,
         DefaultPaymentMethod, ListCountriesCurrenciesRequest, ListCountriesCurrenciesResponse,
         PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest,
         PaymentMethodCollectLinkResponse, PaymentMethodDeleteResponse, PaymentMethodListRequest,
         PaymentMethodListResponse, PaymentMethodResponse, PaymentMethodUpdate,
+        PaymentMethodUsageRestrictions, PaymentMethodUsageRestrictionsResponse,
     },
     payments::{
-        ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
-        PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
-        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelRequest,
-        PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
-        PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
-        PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
+        ConnectorExchangesResponse, ExtendedCardInfoResponse, PaymentIdType,
+        PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters,
+        PaymentListFiltersV2, PaymentListResponse, PaymentListResponseV2,
+        PaymentStatusHistoryResponse, PaymentsApproveRequest, PaymentsCancelRequest,
+        PaymentsCaptureRequest, PaymentsCaptureScheduleRequest, PaymentsCaptureScheduleResponse,
+        PaymentsCompleteAuthorizeRequest, PaymentsExternalAuthenticationRequest,
+        PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
+        PaymentsManualUpdateRequest, PaymentsMetadataUpdateRequest, PaymentsMetadataUpdateResponse,
         PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsRetrieveRequest,
         PaymentsStartRequest, RedirectionResponse,
     },
@@ -46,6 +50,38 @@ impl ApiEventMetric for PaymentsCaptureRequest {
     }
 }
 
+impl ApiEventMetric for PaymentsCaptureScheduleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentsCaptureScheduleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentsMetadataUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentsMetadataUpdateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentsCompleteAuthorizeRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payment {
@@ -129,6 +165,18 @@ impl ApiEventMetric for PaymentMethodDeleteResponse {
     }
 }
 
+impl ApiEventMetric for PaymentMethodUsageRestrictions {}
+
+impl ApiEventMetric for PaymentMethodUsageRestrictionsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.payment_method_id.clone(),
+            payment_method: None,
+            payment_method_type: None,
+        })
+    }
+}
+
 impl ApiEventMetric for CustomerPaymentMethodsListResponse {}
 
 impl ApiEventMetric for PaymentMethodListRequest {
@@ -148,6 +196,22 @@ impl ApiEventMetric for ListCountriesCurrenciesRequest {}
 impl ApiEventMetric for ListCountriesCurrenciesResponse {}
 impl ApiEventMetric for PaymentMethodListResponse {}
 
+impl ApiEventMetric for CustomerPaymentMethodOrderRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.customer_id
+            .clone()
+            .map(|customer_id| ApiEventsType::Customer { customer_id })
+    }
+}
+
+impl ApiEventMetric for CustomerPaymentMethodOrderResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for CustomerDefaultPaymentMethodResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethod {
@@ -241,6 +305,22 @@ impl ApiEventMetric for PaymentsExternalAuthenticationRequest {
 
 impl ApiEventMetric for ExtendedCardInfoResponse {}
 
+impl ApiEventMetric for ConnectorExchangesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentStatusHistoryResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentsManualUpdateRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payment {
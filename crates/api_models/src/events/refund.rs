@@ -1,10 +1,16 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundListFilters, RefundListMetaData, RefundListRequest, RefundListResponse, RefundRequest,
-    RefundResponse, RefundUpdateRequest, RefundsRetrieveRequest,
+    BulkRefundRequest, RefundListFilters, RefundListMetaData, RefundListRequest,
+    RefundListResponse, RefundRequest, RefundResponse, RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
+impl ApiEventMetric for BulkRefundRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl ApiEventMetric for RefundRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         let payment_id = self.payment_id.clone();
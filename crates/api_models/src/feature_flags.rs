@@ -0,0 +1,110 @@
+use common_utils::events;
+use utoipa::ToSchema;
+
+/// The capabilities rolled out at runtime, enabled for a tenant, a merchant or a business profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FeatureFlag {
+    /// Retry the failed payments with the other connectors, according to the gateway status map
+    SmartRetries,
+}
+
+/// The level a feature flag is set at. The flag of a business profile takes precedence over the
+/// flag of its merchant, which takes precedence over the flag of the tenant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlagScope {
+    Tenant,
+    Merchant,
+    Profile,
+}
+
+/// The tenant, merchant or business profile a feature flag is set for or resolved for. The flag
+/// is set for the tenant when neither the merchant nor the business profile is provided.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureFlagTarget {
+    /// The identifier of the merchant account
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: Option<String>,
+
+    /// The identifier of the business profile of the merchant, requiring the merchant_id
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+}
+
+impl events::ApiEventMetric for FeatureFlagTarget {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureFlagUpdateRequest {
+    /// The identifier of the merchant account, the flag being set for the tenant when neither
+    /// the merchant nor the business profile is provided
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: Option<String>,
+
+    /// The identifier of the business profile of the merchant, requiring the merchant_id
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// Whether the capability is enabled for the target
+    #[schema(example = true)]
+    pub enabled: bool,
+}
+
+impl FeatureFlagUpdateRequest {
+    pub fn get_target(&self) -> FeatureFlagTarget {
+        FeatureFlagTarget {
+            merchant_id: self.merchant_id.clone(),
+            profile_id: self.profile_id.clone(),
+        }
+    }
+}
+
+impl events::ApiEventMetric for FeatureFlagUpdateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct FeatureFlagResponse {
+    #[schema(value_type = FeatureFlag, example = "smart_retries")]
+    pub flag: FeatureFlag,
+
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: Option<String>,
+
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// Whether the capability is enabled for the target
+    #[schema(example = true)]
+    pub enabled: bool,
+
+    /// The level the flag applying to the target is set at, the capability being disabled when
+    /// the flag is set at no level
+    #[schema(value_type = Option<FeatureFlagScope>, example = "merchant")]
+    pub set_at: Option<FeatureFlagScope>,
+}
+
+impl events::ApiEventMetric for FeatureFlagResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,127 @@
+use common_utils::events;
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::enums;
+
+/// Metadata key set in the input of the fraud rules when the payment is a velocity outlier,
+/// suffixed with the name of the velocity check
+pub const VELOCITY_SIGNAL_KEY_PREFIX: &str = "frm_velocity_";
+/// Metadata key set in the input of the fraud rules when the amount of the payment is an outlier
+/// in comparison with the previous payments of the customer
+pub const AMOUNT_ANOMALY_SIGNAL_KEY: &str = "frm_amount_anomaly";
+/// Metadata key set in the input of the fraud rules when the billing country of the payment does
+/// not match the shipping country or the issuing country of the card
+pub const COUNTRY_MISMATCH_SIGNAL_KEY: &str = "frm_country_mismatch";
+/// Value of the signal keys when the signal is raised
+pub const SIGNAL_RAISED_VALUE: &str = "true";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FraudRuleDecision {
+    /// The action to be taken on the payment when the rule matches
+    pub action: enums::FrmDecisionAction,
+}
+
+impl EuclidDirFilter for FraudRuleDecision {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardBin,
+        DirKeyKind::CardType,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::MetaData,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::AuthenticationType,
+        DirKeyKind::CaptureMethod,
+        DirKeyKind::BillingCountry,
+        DirKeyKind::BusinessCountry,
+        DirKeyKind::SetupFutureUsage,
+        DirKeyKind::WalletType,
+        DirKeyKind::PayLaterType,
+        DirKeyKind::BankRedirectType,
+        DirKeyKind::BankTransferType,
+        DirKeyKind::BankDebitType,
+        DirKeyKind::DeviceFingerprint,
+    ];
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityDimension {
+    Card,
+    Email,
+    IpAddress,
+    Device,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityCheck {
+    /// Name of the check, the signal raised by this check is available to the rules under the
+    /// metadata key `frm_velocity_{name}`
+    pub name: String,
+    /// The attribute of the payment on which the payments are counted
+    pub dimension: VelocityDimension,
+    /// The maximum number of payments allowed within the window
+    pub max_count: u32,
+    /// The duration of the window in seconds
+    pub window_in_secs: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AmountAnomalyCheck {
+    /// The signal is raised when the amount exceeds the average amount of the previous payments
+    /// of the customer by this factor
+    pub max_multiplier: u16,
+    /// The minimum number of previous payments of the customer required to evaluate the check
+    pub min_history: u16,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FraudSignalsConfig {
+    #[serde(default)]
+    pub velocity_checks: Vec<VelocityCheck>,
+    pub amount_anomaly: Option<AmountAnomalyCheck>,
+    /// Whether the billing country has to be compared with the shipping and card issuing country
+    #[serde(default)]
+    pub country_mismatch: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FraudRulesRecord {
+    pub name: String,
+    pub profile_id: String,
+    pub signals: FraudSignalsConfig,
+    pub program: Program<FraudRuleDecision>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for FraudRulesRecord {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FraudRulesRequest {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub signals: FraudSignalsConfig,
+    pub program: Program<FraudRuleDecision>,
+}
+
+impl events::ApiEventMetric for FraudRulesRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+pub type FraudRulesResponse = FraudRulesRecord;
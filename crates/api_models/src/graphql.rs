@@ -0,0 +1,54 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GraphQLRequest {
+    /// The GraphQL document holding the queries, only queries being supported
+    #[schema(example = "{ payment(payment_id: \"pay_fafa124123\") { status refunds { status } } }")]
+    pub query: String,
+
+    /// The name of the query of the document to execute, required when the document holds several
+    #[serde(rename = "operationName")]
+    pub operation_name: Option<String>,
+
+    /// The values of the variables of the query
+    #[schema(value_type = Option<Object>, example = json!({"payment_id": "pay_fafa124123"}))]
+    pub variables: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl ApiEventMetric for GraphQLRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct GraphQLError {
+    /// The description of the error
+    #[schema(example = "Payment does not exist in our records")]
+    pub message: String,
+
+    /// The response keys and list indexes leading to the field the error was raised for, the field
+    /// being `null` in the data. Empty when the query could not be executed at all.
+    #[schema(value_type = Vec<Object>, example = json!(["payments", 0, "refunds"]))]
+    pub path: Vec<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct GraphQLResponse {
+    /// The fields selected by the query, not present when the query could not be executed
+    #[schema(value_type = Option<Object>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+
+    /// The errors raised while executing the query, not present when there are none
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GraphQLError>,
+}
+
+impl ApiEventMetric for GraphQLResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -26,6 +26,13 @@ pub struct GsmCreateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// category of the error, unified across the connectors
+    pub decline_category: Option<DeclineCategory>,
+    /// advice of the card network on retrying the payment, such as the merchant advice code
+    pub network_advice_code: Option<String>,
+    /// indicates if the customer can resolve the error, by updating or changing the payment method
+    #[serde(default)]
+    pub user_actionable: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -64,6 +71,45 @@ pub enum GsmDecision {
     DoDefault,
 }
 
+/// The category of the errors of the connectors, normalized across the connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DeclineCategory {
+    /// Declined by the issuer, the payment may succeed when retried later or through another
+    /// connector
+    SoftDecline,
+    /// Declined by the issuer, the payment will not succeed when retried with the same payment
+    /// method
+    HardDecline,
+    /// Declined as suspected of fraud
+    Fraud,
+    /// The connector or the network was unavailable
+    ProcessorDowntime,
+    /// The data of the payment was rejected as incorrect by the connector
+    IncorrectData,
+    /// The connector account of the merchant is not configured for the payment
+    Configuration,
+}
+
+impl DeclineCategory {
+    /// Whether the payments declined with the category may succeed when retried
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::SoftDecline | Self::ProcessorDowntime)
+    }
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GsmUpdateRequest {
     /// The connector through which payment has gone through
@@ -88,6 +134,12 @@ pub struct GsmUpdateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// category of the error, unified across the connectors
+    pub decline_category: Option<DeclineCategory>,
+    /// advice of the card network on retrying the payment, such as the merchant advice code
+    pub network_advice_code: Option<String>,
+    /// indicates if the customer can resolve the error, by updating or changing the payment method
+    pub user_actionable: Option<bool>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -141,4 +193,10 @@ pub struct GsmResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// category of the error, unified across the connectors
+    pub decline_category: Option<DeclineCategory>,
+    /// advice of the card network on retrying the payment, such as the merchant advice code
+    pub network_advice_code: Option<String>,
+    /// indicates if the customer can resolve the error, by updating or changing the payment method
+    pub user_actionable: bool,
 }
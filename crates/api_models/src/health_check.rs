@@ -45,3 +45,54 @@ impl From<HealthState> for Option<bool> {
 }
 
 impl common_utils::events::ApiEventMetric for SchedulerHealthCheckResponse {}
+
+/// The status of a dependency of the application, or of the application as a whole
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DependencyStatus {
+    /// The dependency is not used by this deployment
+    NotApplicable,
+    Healthy,
+    /// The dependency works, but is slower or further behind than tolerated
+    Degraded,
+    Unhealthy,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DependencyHealth {
+    pub status: DependencyStatus,
+    /// Whether the application cannot serve requests while the dependency is unhealthy
+    pub critical: bool,
+    /// Time taken to check the dependency, in milliseconds
+    pub latency_in_ms: u128,
+    /// The measurement the status was derived from, such as a lag in seconds or a queue depth
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    /// The value beyond which the dependency is degraded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DetailedHealthCheckResponse {
+    /// Unhealthy when a critical dependency is unhealthy, degraded when any other dependency is
+    /// not healthy
+    pub status: DependencyStatus,
+    pub dependencies: std::collections::BTreeMap<String, DependencyHealth>,
+}
+
+impl common_utils::events::ApiEventMetric for DetailedHealthCheckResponse {}
@@ -0,0 +1,60 @@
+use common_enums::{JobStatus, JobType};
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct JobResponse {
+    /// The identifier of the job
+    #[schema(example = "job_abcdefghijklmnopqrst")]
+    pub job_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(value_type = JobType, example = "bulk_refund")]
+    pub job_type: JobType,
+
+    #[schema(value_type = JobStatus, example = "running")]
+    pub status: JobStatus,
+
+    /// The number of items to be processed by the job
+    pub total_count: i32,
+
+    /// The number of items processed so far, including the items which failed
+    pub processed_count: i32,
+
+    /// The number of items which could not be processed
+    pub failed_count: i32,
+
+    /// The number of attempts of the job which failed and were retried
+    pub attempt_count: i32,
+
+    /// The number of attempts after which the job is failed
+    pub max_attempts: i32,
+
+    /// The error of the last failed attempt of the job
+    pub error_message: Option<String>,
+
+    /// The outcome of the items processed so far, specific to the type of the job
+    #[schema(value_type = Option<Object>)]
+    pub output: Option<serde_json::Value>,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for JobResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,156 @@
+use common_enums::{Currency, LedgerAccount, LedgerEventType};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerFilters {
+    /// Only include the entries of this merchant connector account
+    #[schema(example = "mca_abcdefghijklmnopqrst")]
+    pub merchant_connector_id: Option<String>,
+
+    /// Only include the entries recorded after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only include the entries recorded before this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for LedgerFilters {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerEntriesQuery {
+    /// Only list the entries of this merchant connector account
+    #[schema(example = "mca_abcdefghijklmnopqrst")]
+    pub merchant_connector_id: Option<String>,
+
+    /// Only list the entries recorded after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only list the entries recorded before this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of entries to list, defaults to 100
+    pub limit: Option<i64>,
+
+    pub offset: Option<i64>,
+}
+
+impl ApiEventMetric for LedgerEntriesQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The balance of an account of the ledger, in the lowest denomination of the currency
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct LedgerAccountBalance {
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    #[schema(example = "mca_abcdefghijklmnopqrst")]
+    pub merchant_connector_id: Option<String>,
+
+    #[schema(value_type = LedgerAccount, example = "connector_receivable")]
+    pub account: LedgerAccount,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// Sum of the amounts debited to the account
+    #[schema(value_type = i64, example = 6540)]
+    pub debit_amount: MinorUnit,
+
+    /// Sum of the amounts credited to the account
+    #[schema(value_type = i64, example = 1000)]
+    pub credit_amount: MinorUnit,
+
+    /// The debited amount less the credited amount
+    #[schema(value_type = i64, example = 5540)]
+    pub balance: MinorUnit,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct LedgerBalancesResponse {
+    pub balances: Vec<LedgerAccountBalance>,
+
+    /// Whether the debits and credits of each currency add up, which is always expected of a
+    /// ledger in which only complete transactions are recorded
+    pub is_balanced: bool,
+}
+
+impl ApiEventMetric for LedgerBalancesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct LedgerEntryResponse {
+    #[schema(example = "le_abcdefghijklmnopqrst")]
+    pub entry_id: String,
+
+    /// The transaction of which the entry is a leg
+    #[schema(example = "capture:pay_mbabizu24mvu3mela5njyhpit4:6540")]
+    pub transaction_id: String,
+
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    #[schema(example = "mca_abcdefghijklmnopqrst")]
+    pub merchant_connector_id: Option<String>,
+
+    #[schema(value_type = LedgerAccount, example = "sales")]
+    pub account: LedgerAccount,
+
+    #[schema(value_type = LedgerEventType, example = "capture")]
+    pub event_type: LedgerEventType,
+
+    /// Signed amount, positive for debits and negative for credits
+    #[schema(value_type = i64, example = -6540)]
+    pub amount: MinorUnit,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    pub payment_id: Option<String>,
+
+    pub refund_id: Option<String>,
+
+    pub dispute_id: Option<String>,
+
+    pub payout_id: Option<String>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct LedgerEntriesResponse {
+    pub count: usize,
+    pub data: Vec<LedgerEntryResponse>,
+}
+
+impl ApiEventMetric for LedgerEntriesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -1,11 +1,21 @@
 #![forbid(unsafe_code)]
 pub mod admin;
+pub mod alerts;
 pub mod analytics;
 pub mod api_keys;
+pub mod api_versioning;
 pub mod apple_pay_certificates_migration;
+pub mod audit_events;
+pub mod authentication;
 pub mod blocklist;
+pub mod bulk_force_sync;
+pub mod cache;
+pub mod card_export;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod connected_accounts;
+pub mod connector_capabilities;
+pub mod connector_migration;
 pub mod connector_onboarding;
 pub mod currency;
 pub mod customers;
@@ -15,26 +25,50 @@ pub mod ephemeral_key;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod events;
+pub mod feature_flags;
 pub mod files;
+pub mod fraud_rules;
+pub mod graphql;
 pub mod gsm;
 pub mod health_check;
+pub mod jobs;
+pub mod ledger;
 pub mod locker_migration;
+pub mod logging;
 pub mod mandates;
+pub mod master_key_rotation;
+pub mod merchant_key_rotation;
+pub mod orders;
 pub mod organization;
+pub mod payment_method_sessions;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod pm_auth;
+pub mod pm_display_rules;
+pub mod pm_filters;
 pub mod poll;
+pub mod process_tracker;
+pub mod profile_cloning;
+pub mod rate_limits;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
+pub mod reports;
+pub mod response_field_access;
 pub mod routing;
+pub mod sandbox_traffic;
+pub mod search;
+pub mod settlements;
 pub mod surcharge_decision_configs;
+pub mod tenant;
 pub mod user;
 pub mod user_role;
+pub mod vault_tokens;
+pub mod velocity_limits;
 pub mod verifications;
 pub mod verify_connector;
+pub mod wallet_decryption_keys;
 pub mod webhook_events;
 pub mod webhooks;
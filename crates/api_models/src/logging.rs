@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use common_utils::events;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LogLevelsUpdateRequest {
+    /// The level of each target, a target being a crate or a module. The levels replace the
+    /// levels previously set, no levels restoring the levels of the configuration.
+    #[schema(value_type = HashMap<String, String>, example = json!({"router::core::payments": "debug"}))]
+    pub levels: BTreeMap<String, String>,
+}
+
+impl events::ApiEventMetric for LogLevelsUpdateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RequestSamplingUpdateRequest {
+    /// The share of all the requests whose bodies are logged, as a percentage
+    #[schema(minimum = 0, maximum = 100, example = 1.0)]
+    #[serde(default)]
+    pub percentage: f64,
+
+    /// The merchants all of whose requests have their bodies logged
+    #[schema(example = json!(["merchant_abc"]))]
+    #[serde(default)]
+    pub merchant_ids: Vec<String>,
+
+    /// Time after which the sampling stops, defaults to 1 hour
+    #[schema(maximum = 86400, example = 3600)]
+    pub duration_in_secs: Option<u32>,
+}
+
+impl events::ApiEventMetric for RequestSamplingUpdateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The outputs the logs are written to
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LogOutput {
+    Console,
+    File,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct LogFilterResponse {
+    #[schema(value_type = LogOutput, example = "console")]
+    pub output: LogOutput,
+
+    /// The filtering directive of the configuration
+    #[schema(example = "warn,router=info")]
+    pub initial_directive: String,
+
+    /// The filtering directive applied, with the levels set at runtime
+    #[schema(example = "warn,router=info,router::core::payments=debug")]
+    pub directive: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RequestSamplingResponse {
+    /// The share of all the requests whose bodies are logged, as a percentage
+    #[schema(example = 1.0)]
+    pub percentage: f64,
+
+    /// The merchants all of whose requests have their bodies logged
+    #[schema(example = json!(["merchant_abc"]))]
+    pub merchant_ids: Vec<String>,
+
+    /// The time at which the sampling stops
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+/// The logging of the instance serving the request, the other instances keeping their own
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct LoggingResponse {
+    /// The levels of the targets set at runtime
+    #[schema(value_type = HashMap<String, String>, example = json!({"router::core::payments": "debug"}))]
+    pub levels: BTreeMap<String, String>,
+
+    /// The filter of the logs of each output
+    pub filters: Vec<LogFilterResponse>,
+
+    /// The requests whose bodies are logged, whatever the log levels. Sampling is disabled when
+    /// no requests are sampled.
+    pub sampling: Option<RequestSamplingResponse>,
+}
+
+impl events::ApiEventMetric for LoggingResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -1,3 +1,4 @@
+use common_utils::{id_type, types::MinorUnit};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -120,3 +121,88 @@ pub enum RecurringDetails {
     MandateId(String),
     PaymentMethodId(String),
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MandateMigrationRequest {
+    /// The list of mandates to be imported from the previous processor
+    pub mandates: Vec<MandateMigrationRecord>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MandateMigrationRecord {
+    /// The identifier for the customer who owns the payment method
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The identifier of the payment method, already saved in Hyperswitch, which the mandate is set up on
+    pub payment_method_id: String,
+    /// The identifier of the merchant connector account through which the mandate was set up
+    pub merchant_connector_id: String,
+    /// The gateway token or the scheme mandate reference with which subsequent merchant initiated
+    /// transactions can be made through the connector
+    #[schema(value_type = Option<String>)]
+    pub connector_mandate_id: Option<Secret<String>>,
+    /// The network transaction id of the initial customer initiated transaction
+    #[schema(value_type = Option<String>)]
+    pub network_transaction_id: Option<Secret<String>>,
+    /// The type of the mandate, defaults to a multi use mandate
+    pub mandate_type: Option<payments::MandateType>,
+    /// Details about the customer’s acceptance, as recorded by the previous processor
+    #[schema(value_type = Option<CustomerAcceptance>)]
+    pub customer_acceptance: Option<payments::CustomerAcceptance>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateMigrationResponse {
+    /// The result of importing each of the mandates, in the order of the request
+    pub mandates: Vec<MandateMigrationRecordResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateMigrationRecordResponse {
+    /// The identifier of the payment method the mandate was imported for
+    pub payment_method_id: String,
+    /// The identifier for the created mandate, present when the import was successful
+    pub mandate_id: Option<String>,
+    /// The status of the created mandate
+    #[schema(value_type = Option<MandateStatus>)]
+    pub status: Option<api_enums::MandateStatus>,
+    /// The reason for which the mandate could not be imported
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MandatePreNotificationRequest {
+    /// The amount that will be debited
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the amount that will be debited
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+    /// The date on which the mandate will be debited
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub debit_date: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandatePreNotificationResponse {
+    /// The identifier for mandate
+    pub mandate_id: String,
+    /// The amount that will be debited
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the amount that will be debited
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+    /// The date on which the mandate will be debited
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub debit_date: PrimitiveDateTime,
+    /// The time at which the pre-notification event will be sent to the merchant
+    #[schema(example = "2022-08-27T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub notification_scheduled_at: PrimitiveDateTime,
+}
@@ -0,0 +1,76 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MasterKeyRotationRequest {
+    /// The number of merchant key stores re-encrypted at a time, defaults to 100
+    #[schema(maximum = 1000, example = 100)]
+    pub batch_size: Option<u16>,
+}
+
+impl ApiEventMetric for MasterKeyRotationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MasterKeyRotationJobId {
+    pub job_id: String,
+}
+
+impl ApiEventMetric for MasterKeyRotationJobId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MasterKeyRotationStatus {
+    /// The job is yet to be picked by the scheduler
+    Scheduled,
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// The progress of the re-encryption of the merchant key stores
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MasterKeyRotationSummary {
+    /// The number of key stores re-encrypted with the master key
+    pub rotated_count: u64,
+    /// The number of key stores which could not be re-encrypted
+    pub failed_count: u64,
+    /// The merchant id of the last key store processed, the job resuming after it when retried
+    pub last_merchant_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct MasterKeyRotationResponse {
+    /// The identifier of the job
+    #[schema(example = "MASTER_KEY_ROTATION_WORKFLOW_MASTER_KEY_ROTATION_1721030400")]
+    pub job_id: String,
+
+    #[schema(value_type = MasterKeyRotationStatus, example = "completed")]
+    pub status: MasterKeyRotationStatus,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_at: PrimitiveDateTime,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    /// The progress of the job, updated after each batch of key stores
+    pub summary: MasterKeyRotationSummary,
+}
+
+impl ApiEventMetric for MasterKeyRotationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
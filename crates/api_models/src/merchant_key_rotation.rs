@@ -0,0 +1,113 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantKeyRotationRequest {
+    /// The number of records re-encrypted at a time, defaults to 100
+    #[schema(maximum = 1000, example = 100)]
+    pub batch_size: Option<u16>,
+}
+
+impl ApiEventMetric for MerchantKeyRotationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyRotationJobId {
+    pub merchant_id: String,
+    pub job_id: String,
+}
+
+impl ApiEventMetric for MerchantKeyRotationJobId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantKeyRotationStatus {
+    /// The job is yet to be picked by the scheduler
+    Scheduled,
+    InProgress,
+    /// Every record has been re-encrypted and the key rotated out has been discarded
+    Completed,
+    /// Some records could not be re-encrypted, the key rotated out being kept until they are
+    Failed,
+}
+
+/// The data of the merchant re-encrypted by the job, in the order they are re-encrypted in
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantKeyRotationStage {
+    MerchantAccount,
+    MerchantConnectorAccounts,
+    Customers,
+    Addresses,
+    PaymentMethods,
+    Events,
+    IncomingWebhookArchives,
+    WalletDecryptionKeys,
+}
+
+/// The progress of the re-encryption of the data of the merchant
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MerchantKeyRotationSummary {
+    /// The data being re-encrypted, not present once every data has been processed
+    #[schema(value_type = Option<MerchantKeyRotationStage>, example = "customers")]
+    pub stage: Option<MerchantKeyRotationStage>,
+    /// The identifier of the last record of the stage processed, the job resuming after it when
+    /// retried
+    pub last_processed_id: Option<String>,
+    /// The number of records re-encrypted with the new key
+    pub reencrypted_count: u64,
+    /// The number of records which could not be re-encrypted
+    pub failed_count: u64,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier of the job
+    #[schema(
+        example = "MERCHANT_KEY_ROTATION_WORKFLOW_MERCHANT_KEY_ROTATION_merchant_1668273825_1721030400"
+    )]
+    pub job_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(value_type = MerchantKeyRotationStatus, example = "completed")]
+    pub status: MerchantKeyRotationStatus,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_at: PrimitiveDateTime,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+
+    /// The progress of the job, updated after each batch of records
+    pub summary: MerchantKeyRotationSummary,
+}
+
+impl ApiEventMetric for MerchantKeyRotationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,160 @@
+use common_enums::{Currency, IntentStatus};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrderCreateRequest {
+    /// The total amount of the order, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 10000)]
+    pub amount: MinorUnit,
+
+    /// The currency of the order, which the payments of the order must be made in
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The business profile the payments of the order must be made with. Payments of any profile
+    /// can be linked to the order when not provided
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The customer the order belongs to
+    #[schema(value_type = Option<String>, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// A description of the order
+    #[schema(max_length = 255, example = "Kitchen renovation")]
+    pub description: Option<String>,
+
+    /// Additional, structured information on the order
+    #[schema(value_type = Option<Object>, example = json!({ "reference": "ORD-1042" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl ApiEventMetric for OrderCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrderUpdateRequest {
+    /// The total amount of the order, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 12000)]
+    pub amount: Option<MinorUnit>,
+
+    /// A description of the order
+    #[schema(max_length = 255, example = "Kitchen renovation")]
+    pub description: Option<String>,
+
+    /// Additional, structured information on the order
+    #[schema(value_type = Option<Object>, example = json!({ "reference": "ORD-1042" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl ApiEventMetric for OrderUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The status of an order, derived from the payments linked to it
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// None of the amount of the order has been paid yet
+    Unpaid,
+    /// Some of the amount of the order has been paid, such as a deposit
+    PartiallyPaid,
+    /// The whole amount of the order has been paid
+    Paid,
+}
+
+/// A payment linked to an order
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct OrderPayment {
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: IntentStatus,
+
+    #[schema(value_type = i64, example = 3000)]
+    pub amount: MinorUnit,
+
+    /// The amount of the payment that has been captured, counted towards the paid amount of the
+    /// order
+    #[schema(value_type = Option<i64>, example = 3000)]
+    pub amount_captured: Option<MinorUnit>,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct OrderResponse {
+    #[schema(example = "ord_mbabizu24mvu3mela5njyhpit4")]
+    pub order_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The total amount of the order
+    #[schema(value_type = i64, example = 10000)]
+    pub amount: MinorUnit,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    #[schema(value_type = OrderStatus, example = "partially_paid")]
+    pub status: OrderStatus,
+
+    /// Sum of the amounts captured by the payments of the order
+    #[schema(value_type = i64, example = 3000)]
+    pub amount_paid: MinorUnit,
+
+    /// Sum of the amounts of the payments of the order authorized and awaiting capture
+    #[schema(value_type = i64, example = 0)]
+    pub amount_authorized: MinorUnit,
+
+    /// The amount of the order left to be paid
+    #[schema(value_type = i64, example = 7000)]
+    pub amount_remaining: MinorUnit,
+
+    pub description: Option<String>,
+
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The payments linked to the order, most recent first
+    pub payments: Vec<OrderPayment>,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for OrderResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Order {
+            order_id: self.order_id.clone(),
+        })
+    }
+}
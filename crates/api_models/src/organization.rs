@@ -1,3 +1,11 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    types::MinorUnit,
+};
+use utoipa::ToSchema;
+
+use crate::enums;
+
 pub struct OrganizationNew {
     pub org_id: String,
     pub org_name: Option<String>,
@@ -11,3 +19,108 @@ impl OrganizationNew {
         }
     }
 }
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentsAggregateBucket {
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: enums::IntentStatus,
+
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+
+    /// The number of payments with this status and currency
+    pub count: usize,
+
+    /// The sum of the amounts of the payments with this status and currency, in the lowest
+    /// denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub total_amount: MinorUnit,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct MerchantPaymentsAggregate {
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    /// The number of payments of the merchant
+    pub count: usize,
+
+    pub data: Vec<PaymentsAggregateBucket>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct OrganizationPaymentsAggregateResponse {
+    #[schema(example = "org_abc")]
+    pub org_id: String,
+
+    /// The number of payments of all the merchants of the organization
+    pub count: usize,
+
+    /// The payments of all the merchants of the organization, by status and currency
+    pub data: Vec<PaymentsAggregateBucket>,
+
+    /// The payments of each merchant of the organization, by status and currency
+    pub merchants: Vec<MerchantPaymentsAggregate>,
+}
+
+impl ApiEventMetric for OrganizationPaymentsAggregateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorShareTarget {
+    /// The merchant of the organization the connector is shared with
+    #[schema(example = "merchant_xyz")]
+    pub merchant_id: String,
+
+    /// The business profile of the merchant the connector is created in, the default profile of
+    /// the merchant being used when not set
+    pub profile_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorShareRequest {
+    /// The merchant of the organization owning the connector
+    #[schema(example = "merchant_abc")]
+    pub source_merchant_id: String,
+
+    /// The connector whose configuration is shared
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+
+    /// The merchants of the organization a copy of the connector is created for
+    pub targets: Vec<ConnectorShareTarget>,
+}
+
+impl ApiEventMetric for ConnectorShareRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorShareResult {
+    #[schema(example = "merchant_xyz")]
+    pub merchant_id: String,
+
+    /// The connector created for the merchant, if the connector could be shared
+    pub merchant_connector_id: Option<String>,
+
+    /// The reason the connector could not be shared with the merchant
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorShareResponse {
+    pub results: Vec<ConnectorShareResult>,
+}
+
+impl ApiEventMetric for ConnectorShareResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,203 @@
+use common_utils::{events, id_type, pii};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{
+    enums,
+    payments::{CustomerAcceptance, MandateType, NextActionData, PaymentMethodDataRequest},
+};
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodSessionCreateRequest {
+    /// The customer the payment method is collected for
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The currency the payment method is verified in with the connector
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// Whether the payment method is used in later payments with the customer present or not,
+    /// defaults to `off_session`
+    #[schema(value_type = Option<FutureUsage>, example = "off_session")]
+    pub usage: Option<enums::FutureUsage>,
+
+    /// Whether the customer is authenticated with 3DS when the payment method is collected
+    #[schema(value_type = Option<AuthenticationType>, example = "three_ds")]
+    pub authentication_type: Option<enums::AuthenticationType>,
+
+    /// The mandate set up with the connector for the payment method, the mandate being set up
+    /// once the customer accepts it when the session is confirmed
+    pub mandate_type: Option<MandateType>,
+
+    /// The payment method types the customer may collect a payment method of
+    #[schema(value_type = Option<Vec<PaymentMethodType>>)]
+    pub allowed_payment_method_types: Option<Vec<enums::PaymentMethodType>>,
+
+    /// The URL the customer is redirected to after the payment method is verified
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<url::Url>,
+
+    /// The business profile the session is created in, defaults to the default business profile
+    /// of the merchant
+    pub profile_id: Option<String>,
+
+    /// The number of seconds the session may be confirmed within, defaults to 15 minutes
+    #[schema(example = 900)]
+    pub session_expiry: Option<u32>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl events::ApiEventMetric for PaymentMethodSessionCreateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodSessionConfirmRequest {
+    /// The client secret of the session, required when confirming with the publishable key
+    #[schema(value_type = Option<String>, example = "pms_mbabizu24mvu3mela5njyhpit4_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: Option<String>,
+
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: enums::PaymentMethod,
+
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+
+    pub payment_method_data: PaymentMethodDataRequest,
+
+    /// The acceptance of the customer, required when the payment method is saved for off session
+    /// payments or a mandate is set up for it
+    pub customer_acceptance: Option<CustomerAcceptance>,
+
+    /// The information of the browser of the customer, required for 3DS with some connectors
+    #[schema(value_type = Option<BrowserInformation>)]
+    pub browser_info: Option<serde_json::Value>,
+
+    /// Replaces the URL the customer is redirected to after the payment method is verified
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<url::Url>,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodSessionRetrieveQuery {
+    /// The client secret of the session, required when retrieving with the publishable key
+    pub client_secret: Option<String>,
+
+    /// Whether the status of the session is synced with the connector
+    pub force_sync: Option<bool>,
+}
+
+/// The status of a payment method session
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodSessionStatus {
+    /// The session is waiting for the payment method of the customer
+    RequiresPaymentMethod,
+    /// The customer has to complete an action, such as 3DS, for the payment method to be verified
+    RequiresCustomerAction,
+    /// The payment method is being verified with the connector
+    Processing,
+    /// The payment method is verified and saved for the customer
+    Succeeded,
+    /// The payment method could not be verified
+    Failed,
+    Cancelled,
+    /// The session was not confirmed before it expired
+    Expired,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentMethodSessionResponse {
+    /// The identifier of the session
+    #[schema(example = "pms_mbabizu24mvu3mela5njyhpit4")]
+    pub session_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: Option<String>,
+
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    #[schema(example = "requires_payment_method")]
+    pub status: PaymentMethodSessionStatus,
+
+    /// The secret the session is confirmed with from the client, along with the publishable key
+    #[schema(value_type = Option<String>, example = "pms_mbabizu24mvu3mela5njyhpit4_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: Option<Secret<String>>,
+
+    #[schema(value_type = Option<FutureUsage>, example = "off_session")]
+    pub usage: Option<enums::FutureUsage>,
+
+    #[schema(value_type = Option<AuthenticationType>, example = "three_ds")]
+    pub authentication_type: Option<enums::AuthenticationType>,
+
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<enums::PaymentMethod>,
+
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+
+    /// The saved payment method, usable in later payments of the customer once the session has
+    /// succeeded
+    #[schema(example = "pm_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_method_id: Option<String>,
+
+    /// The mandate set up with the connector, when one was requested
+    #[schema(example = "mandate_iwer89rnjef349dni3")]
+    pub mandate_id: Option<String>,
+
+    /// The action the customer has to complete for the payment method to be verified
+    pub next_action: Option<NextActionData>,
+
+    /// The connector the payment method was verified with
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    pub profile_id: Option<String>,
+
+    pub error_code: Option<String>,
+
+    pub error_message: Option<String>,
+
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+
+    /// The time the session can no longer be confirmed after
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+impl events::ApiEventMetric for PaymentMethodSessionResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Payment {
+            payment_id: self.session_id.clone(),
+        })
+    }
+}
@@ -342,6 +342,19 @@ pub struct CardDetailFromLocker {
     pub card_isin: Option<String>,
     pub card_issuer: Option<String>,
     pub card_type: Option<String>,
+
+    /// The tier of the card, such as classic, gold or platinum
+    #[schema(example = "PLATINUM")]
+    pub card_tier: Option<String>,
+
+    /// Whether the card is a credit, debit or prepaid card
+    #[schema(value_type = Option<CardFundingType>, example = "debit")]
+    pub card_funding_type: Option<api_enums::CardFundingType>,
+
+    /// Whether the interchange fees of the card are capped by regulation, such as debit cards
+    /// issued by large banks in the US or consumer cards issued in the EEA
+    pub is_regulated: Option<bool>,
+
     pub saved_to_locker: bool,
 }
 
@@ -355,6 +368,9 @@ impl From<CardDetailFromLocker> for payments::AdditionalCardInfo {
             card_issuer: item.card_issuer,
             card_network: item.card_network,
             card_type: item.card_type,
+            card_tier: item.card_tier,
+            card_funding_type: item.card_funding_type,
+            is_regulated: item.is_regulated,
             card_issuing_country: item.issuer_country,
             bank_code: None,
             last4: item.last4_digits,
@@ -388,6 +404,9 @@ impl From<CardDetailsPaymentMethod> for CardDetailFromLocker {
             card_issuer: item.card_issuer,
             card_network: item.card_network,
             card_type: item.card_type,
+            card_tier: None,
+            card_funding_type: None,
+            is_regulated: None,
             saved_to_locker: item.saved_to_locker,
         }
     }
@@ -486,6 +505,9 @@ pub struct SurchargeDetailsResponse {
     pub display_total_surcharge_amount: f64,
     /// sum of original amount,
     pub display_final_amount: f64,
+    /// the jurisdiction whose legal cap limited the surcharge, if the surcharge was capped
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub capped_jurisdiction: Option<api_enums::CountryAlpha2>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -656,6 +678,11 @@ pub struct PaymentMethodListRequest {
     /// Indicates the limit of last used payment methods
     #[schema(example = 1)]
     pub limit: Option<i64>,
+
+    /// The platform the payment methods are listed on, used by the display rules of the business
+    /// profile
+    #[schema(value_type = Option<ClientPlatform>, example = "web")]
+    pub platform: Option<crate::pm_display_rules::ClientPlatform>,
 }
 
 impl<'de> serde::Deserialize<'de> for PaymentMethodListRequest {
@@ -727,6 +754,13 @@ impl<'de> serde::Deserialize<'de> for PaymentMethodListRequest {
                         "limit" => {
                             set_or_reject_duplicate(&mut output.limit, "limit", map.next_value()?)?;
                         }
+                        "platform" => {
+                            set_or_reject_duplicate(
+                                &mut output.platform,
+                                "platform",
+                                map.next_value()?,
+                            )?;
+                        }
                         _ => {}
                     }
                 }
@@ -860,6 +894,69 @@ pub struct CustomerDefaultPaymentMethodResponse {
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
 }
 
+/// The restrictions on the use of a saved payment method in payments, enforced when the payments
+/// are confirmed
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodUsageRestrictions {
+    /// Whether the payment method is barred from off session payments, such as the recurring payments initiated by the merchant
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub off_session_disabled: bool,
+    /// The largest amount the payment method can be charged in a single payment, in the lowest denomination of the currency of the payment
+    #[schema(example = 50000)]
+    pub max_amount: Option<MinorUnit>,
+    /// The business profiles the payment method can be used in, the payment method being usable in every business profile of the merchant when not provided
+    #[schema(example = json!(["pro_abcdefghijklmnop"]))]
+    pub allowed_profile_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodUsageRestrictionsResponse {
+    /// The unique identifier of the Payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+    pub usage_restrictions: PaymentMethodUsageRestrictions,
+}
+
+/// The order in which the saved payment methods of a customer are displayed
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct CustomerPaymentMethodOrder {
+    pub payment_method_ids: Vec<String>,
+    #[serde(default)]
+    pub promote_last_used: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerPaymentMethodOrderRequest {
+    /// The unique identifier of the customer.
+    #[serde(skip_deserializing)]
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The saved payment methods of the customer in the order they are displayed in, after the default payment method. The payment methods left out are displayed after them, the most recently used first
+    #[schema(example = json!(["pm_a3gd8Q9dLb3ys6JVBqPu", "pm_8rFy3Sc2kPDLqxJc1HpN"]))]
+    pub payment_method_ids: Vec<String>,
+    /// Whether the payment method last used by the customer in a payment becomes their default payment method
+    #[serde(default)]
+    pub promote_last_used: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CustomerPaymentMethodOrderResponse {
+    /// The unique identifier of the customer.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The unique identifier of the default payment method of the customer, always displayed first
+    #[schema(example = "pm_a3gd8Q9dLb3ys6JVBqPu")]
+    pub default_payment_method_id: Option<String>,
+    /// The saved payment methods of the customer in the order they are displayed in, after the default payment method
+    #[schema(example = json!(["pm_a3gd8Q9dLb3ys6JVBqPu", "pm_8rFy3Sc2kPDLqxJc1HpN"]))]
+    pub payment_method_ids: Vec<String>,
+    /// Whether the payment method last used by the customer in a payment becomes their default payment method
+    pub promote_last_used: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
 pub struct CustomerPaymentMethod {
     /// Token for payment method in temporary card locker which gets refreshed often
@@ -905,6 +1002,12 @@ pub struct CustomerPaymentMethod {
     #[schema(example = json!({"last4": "1142","exp_month": "03","exp_year": "2030"}))]
     pub card: Option<CardDetailFromLocker>,
 
+    /// The networks the saved card can be processed on when the card is co-badged, the primary
+    /// network first. The customer may choose one of them with `card_network` in the card token
+    /// data when confirming a payment with the saved card
+    #[schema(value_type = Option<Vec<CardNetwork>>, example = json!(["CartesBancaires", "Visa"]))]
+    pub co_badged_card_networks: Option<Vec<api_enums::CardNetwork>>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
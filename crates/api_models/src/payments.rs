@@ -28,6 +28,7 @@ use crate::{
     admin::{self, MerchantConnectorInfo},
     disputes, enums as api_enums,
     ephemeral_key::EphemeralKeyCreateResponse,
+    gsm,
     mandates::RecurringDetails,
     refunds,
 };
@@ -493,6 +494,29 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<Object>, example = r#"{ "coverage_request" : "fraud", "fulfillment_method" : "delivery" }"#)]
     pub frm_metadata: Option<pii::SecretSerdeValue>,
 
+    /// The order the payment is made towards, such as a deposit or a balance payment of the order
+    #[schema(example = "ord_mbabizu24mvu3mela5njyhpit4")]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub order_id: Option<String>,
+
+    /// A reference of the merchant for the payment, such as the identifier of its fulfillment.
+    /// It can be updated at any time through the metadata endpoint of the payment
+    #[schema(max_length = 255, example = "shipment_7829")]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub merchant_reference: Option<String>,
+
+    /// The values of the custom fields of the payment, validated against the custom fields schema of the business profile
+    #[schema(value_type = Option<Object>, example = r#"{ "store_id": "store_42", "is_gift": true }"#)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+
+    /// A timestamp (ISO 8601 code) at which a payment with a manual capture method is captured,
+    /// once authorized. The timestamp must be within the validity of the authorization
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-12T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[remove_in(PaymentsUpdateRequest)]
+    pub capture_at: Option<PrimitiveDateTime>,
+
     /// Whether to perform external authentication (if applicable)
     #[schema(example = true)]
     pub request_external_three_ds_authentication: Option<bool>,
@@ -502,6 +526,35 @@ pub struct PaymentsRequest {
 
     /// Fee information to be charged on the payment being collected
     pub charges: Option<PaymentChargeRequest>,
+
+    /// Whether the payment being confirmed is authorized before responding, or in the background.
+    /// In the background, the payment is returned with the `processing` status and the outcome
+    /// of the authorization is delivered through the webhooks. Only supported for the payments
+    /// confirmed by the merchant without `payment_method_data`, such as the recurring payments.
+    #[schema(value_type = Option<PaymentProcessingMode>, example = "async")]
+    #[remove_in(PaymentsUpdateRequest)]
+    pub processing_mode: Option<PaymentProcessingMode>,
+}
+
+impl PaymentsRequest {
+    /// Whether the payment is confirmed by the request in the background
+    pub fn is_async_confirmation(&self) -> bool {
+        self.confirm == Some(true) && self.processing_mode == Some(PaymentProcessingMode::Async)
+    }
+}
+
+/// How the payments are confirmed
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentProcessingMode {
+    /// The payment is authorized before responding to the request
+    #[default]
+    Sync,
+    /// The payment is authorized in the background, its outcome being delivered through the
+    /// webhooks
+    Async,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -574,6 +627,9 @@ pub struct BrowserInformation {
 
     /// User-agent of the browser
     pub user_agent: Option<String>,
+
+    /// Fingerprint or session identifier of the device, as provided by the device data collection of the SDK
+    pub device_fingerprint: Option<String>,
 }
 
 impl RequestSurchargeDetails {
@@ -660,6 +716,26 @@ pub struct PaymentAttemptResponse {
     pub client_source: Option<String>,
     /// Value passed in X-CLIENT-VERSION header during payments confirm request by the client
     pub client_version: Option<String>,
+    /// The action taken on the attempt by the failover policy of the profile, when the connector was unavailable
+    #[schema(value_type = Option<ConnectorFailoverAction>, example = "retry_next_connector")]
+    pub failover_action: Option<api_enums::ConnectorFailoverAction>,
+    /// The details of the error of the connector, normalized across the connectors
+    pub unified_error_details: Option<UnifiedErrorDetails>,
+}
+
+/// The details of the error of the connector, as mapped in the gateway status map of the connector
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct UnifiedErrorDetails {
+    /// The category of the error, unified across the connectors
+    #[schema(value_type = Option<DeclineCategory>, example = "soft_decline")]
+    pub decline_category: Option<gsm::DeclineCategory>,
+    /// The advice of the card network on retrying the payment, such as the merchant advice code
+    #[schema(example = "03")]
+    pub network_advice_code: Option<String>,
+    /// Whether the payment may succeed when retried
+    pub retryable: bool,
+    /// Whether the customer can resolve the error, by updating or changing the payment method
+    pub user_actionable: bool,
 }
 
 #[derive(
@@ -961,7 +1037,8 @@ pub struct Card {
     #[schema(example = "chase")]
     pub card_issuer: Option<String>,
 
-    /// The card network for the card
+    /// The card network for the card. For co-badged cards, this is the network chosen by the
+    /// customer to process the payment on
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
 
@@ -1113,6 +1190,11 @@ pub struct CardToken {
     /// The CVC number for the card
     #[schema(value_type = Option<String>)]
     pub card_cvc: Option<Secret<String>>,
+
+    /// The network chosen by the customer to process the payment on, when the saved card is
+    /// co-badged
+    #[schema(value_type = Option<CardNetwork>, example = "CartesBancaires")]
+    pub card_network: Option<api_enums::CardNetwork>,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -1777,6 +1859,15 @@ pub struct AdditionalCardInfo {
     /// Card type, can be either `credit` or `debit`
     pub card_type: Option<String>,
 
+    /// The tier of the card, such as classic, gold or platinum
+    pub card_tier: Option<String>,
+
+    /// Whether the card is a credit, debit or prepaid card
+    pub card_funding_type: Option<api_enums::CardFundingType>,
+
+    /// Whether the interchange fees of the card are capped by regulation
+    pub is_regulated: Option<bool>,
+
     pub card_issuing_country: Option<String>,
     pub bank_code: Option<String>,
 
@@ -2669,6 +2760,15 @@ pub struct CardResponse {
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
     pub card_issuer: Option<String>,
+    /// The tier of the card, such as classic, gold or platinum
+    #[schema(example = "PLATINUM")]
+    pub card_tier: Option<String>,
+    /// Whether the card is a credit, debit or prepaid card
+    #[schema(value_type = Option<CardFundingType>, example = "debit")]
+    pub card_funding_type: Option<api_enums::CardFundingType>,
+    /// Whether the interchange fees of the card are capped by regulation, such as debit cards
+    /// issued by large banks in the US or consumer cards issued in the EEA
+    pub is_regulated: Option<bool>,
     pub card_issuing_country: Option<String>,
     pub card_isin: Option<String>,
     pub card_extended_bin: Option<String>,
@@ -3038,6 +3138,76 @@ pub struct PaymentsCaptureRequest {
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsCaptureScheduleRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    pub payment_id: String,
+    /// A timestamp (ISO 8601 code) at which the payment is captured, once authorized. The
+    /// timestamp must be within the validity of the authorization
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-12T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub capture_at: PrimitiveDateTime,
+    /// The amount to capture, the whole amount of the payment is captured when not provided
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount_to_capture: Option<MinorUnit>,
+}
+
+/// The status of the scheduled capture of a payment
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureScheduleStatus {
+    /// The payment will be captured at the scheduled time
+    Scheduled,
+    /// The scheduled capture was cancelled, the payment must be captured through the capture
+    /// endpoint
+    Cancelled,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsCaptureScheduleResponse {
+    /// The unique identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    #[schema(value_type = CaptureScheduleStatus, example = "scheduled")]
+    pub status: CaptureScheduleStatus,
+    /// The time at which the payment is captured
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-12T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub capture_at: Option<PrimitiveDateTime>,
+    /// The amount to capture, the whole amount of the payment is captured when not provided
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount_to_capture: Option<MinorUnit>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsMetadataUpdateRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    pub payment_id: String,
+    /// The metadata of the payment, replacing the metadata set previously
+    #[schema(value_type = Option<Object>, example = r#"{ "tracking_number": "1Z999AA10123456784" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// A reference of the merchant for the payment, such as the identifier of its fulfillment
+    #[schema(max_length = 255, example = "shipment_7829")]
+    pub merchant_reference: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsMetadataUpdateResponse {
+    /// The unique identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The metadata of the payment
+    #[schema(value_type = Option<Object>, example = r#"{ "tracking_number": "1Z999AA10123456784" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// A reference of the merchant for the payment, such as the identifier of its fulfillment
+    #[schema(example = "shipment_7829")]
+    pub merchant_reference: Option<String>,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -3320,6 +3490,11 @@ pub struct PaymentsResponse {
     #[schema(value_type = i64, minimum = 100, example = 6540)]
     pub amount_capturable: Option<MinorUnit>,
 
+    /// The amount of the authorization released by partial reversals, which can no longer be
+    /// captured
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub amount_reversed: Option<MinorUnit>,
+
     /// The amount which is already captured from the payment
     #[schema(value_type = i64, example = 6540)]
     pub amount_received: Option<MinorUnit>,
@@ -3483,6 +3658,9 @@ pub struct PaymentsResponse {
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
 
+    /// The details of the error of the connector, normalized across the connectors, if there was an error while calling connector
+    pub unified_error_details: Option<UnifiedErrorDetails>,
+
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<api_enums::PaymentExperience>,
@@ -3599,6 +3777,50 @@ pub struct PaymentsResponse {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. FRM Metadata is useful for storing additional, structured information on an object related to FRM.
     #[schema(value_type = Option<Object>, example = r#"{ "fulfillment_method" : "deliver", "coverage_request" : "fraud" }"#)]
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+
+    /// The taxes calculated on the order from its shipping address, included in the amount of the payment
+    pub tax_details: Option<TaxDetails>,
+
+    /// The order the payment is made towards
+    #[schema(example = "ord_mbabizu24mvu3mela5njyhpit4")]
+    pub order_id: Option<String>,
+
+    /// A reference of the merchant for the payment, such as the identifier of its fulfillment
+    #[schema(example = "shipment_7829")]
+    pub merchant_reference: Option<String>,
+
+    /// The values of the custom fields of the payment
+    #[schema(value_type = Option<Object>, example = r#"{ "store_id": "store_42", "is_gift": true }"#)]
+    pub custom_fields: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct TaxDetails {
+    /// The amount of the order before taxes
+    #[schema(value_type = i64, example = 6000)]
+    pub order_amount: MinorUnit,
+
+    /// The total amount of taxes applied on the order
+    #[schema(value_type = i64, example = 540)]
+    pub order_tax_amount: MinorUnit,
+
+    /// The taxes that make up the tax amount of the order
+    pub breakdown: Vec<TaxBreakdown>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct TaxBreakdown {
+    /// The name of the tax
+    #[schema(example = "CA state sales tax")]
+    pub name: String,
+
+    /// The amount of the tax
+    #[schema(value_type = i64, example = 540)]
+    pub amount: MinorUnit,
+
+    /// The rate of the tax in percentage, when known
+    #[schema(example = 9.0)]
+    pub rate: Option<f32>,
 }
 
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -3736,6 +3958,8 @@ pub struct PaymentListResponseV2 {
     pub total_count: i64,
     /// The list of payments response objects
     pub data: Vec<PaymentsResponse>,
+    /// The cursor to pass to fetch the next page, absent on the last page
+    pub next_cursor: Option<ListCursor>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -3749,8 +3973,11 @@ pub struct PaymentListFilterConstraints {
     /// The limit on the number of objects. The default limit is 10 and max limit is 20
     #[serde(default = "default_payments_list_limit")]
     pub limit: u32,
-    /// The starting point within a list of objects
+    /// The starting point within a list of objects. Deprecated in favour of `cursor`, which does
+    /// not skip or repeat payments created while paginating
     pub offset: Option<u32>,
+    /// The `next_cursor` of the previous page, to fetch the payments which follow it
+    pub cursor: Option<ListCursor>,
     /// The amount to filter payments list
     pub amount_filter: Option<AmountFilter>,
     /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
@@ -3770,6 +3997,13 @@ pub struct PaymentListFilterConstraints {
     pub authentication_type: Option<Vec<enums::AuthenticationType>>,
     /// The list of merchant connector ids to filter payments list for selected label
     pub merchant_connector_id: Option<Vec<String>>,
+    /// The list of card networks to filter payments list, only card payments are listed when
+    /// provided
+    pub card_network: Option<Vec<api_enums::CardNetwork>>,
+    /// The metadata key value pairs which the payments must all have
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    /// The custom field values which the payments must all have
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
 }
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PaymentListFilters {
@@ -3809,6 +4043,76 @@ pub struct AmountFilter {
     pub end_amount: Option<i64>,
 }
 
+/// An opaque position in a list of objects ordered from the most recently created, pointing at the
+/// last object of a page. The next page starts right after this object, so that objects created
+/// while paginating do not shift the pages like they do with an offset.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ListCursor {
+    /// Creation time of the last object of the page
+    pub created_at: PrimitiveDateTime,
+    /// Identifier of the last object of the page, which orders the objects created at the same time
+    pub id: String,
+}
+
+impl ListCursor {
+    pub fn new(created_at: PrimitiveDateTime, id: String) -> Self {
+        Self { created_at, id }
+    }
+}
+
+impl fmt::Display for ListCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}",
+            self.created_at.assume_utc().unix_timestamp_nanos(),
+            self.id
+        )
+    }
+}
+
+impl std::str::FromStr for ListCursor {
+    type Err = error_stack::Report<common_utils::errors::ParsingError>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parsing_error = || common_utils::errors::ParsingError::StructParseFailure("ListCursor");
+        let (timestamp, id) = value
+            .split_once('_')
+            .filter(|(_, id)| !id.is_empty())
+            .ok_or_else(parsing_error)?;
+        let created_at = timestamp
+            .parse::<i128>()
+            .ok()
+            .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp_nanos(timestamp).ok())
+            .ok_or_else(parsing_error)?;
+        Ok(Self {
+            created_at: PrimitiveDateTime::new(created_at.date(), created_at.time()),
+            id: id.to_owned(),
+        })
+    }
+}
+
+impl Serialize for ListCursor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListCursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(&value), &"a list cursor"))
+    }
+}
+
 #[derive(
     Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash, ToSchema,
 )]
@@ -3901,6 +4205,7 @@ impl From<PaymentsSessionRequest> for PaymentsSessionResponse {
             session_token: vec![],
             payment_id: item.payment_id,
             client_secret,
+            tax_details: None,
         }
     }
 }
@@ -3922,6 +4227,9 @@ impl From<AdditionalCardInfo> for CardResponse {
             card_type: card.card_type,
             card_network: card.card_network,
             card_issuer: card.card_issuer,
+            card_tier: card.card_tier,
+            card_funding_type: card.card_funding_type,
+            is_regulated: card.is_regulated,
             card_issuing_country: card.card_issuing_country,
             card_isin: card.card_isin,
             card_extended_bin: card.card_extended_bin,
@@ -4573,6 +4881,8 @@ pub struct PaymentsSessionResponse {
     pub client_secret: Secret<String, pii::ClientSecret>,
     /// The list of session token object
     pub session_token: Vec<SessionToken>,
+    /// The taxes calculated on the order from its shipping address, included in the amount of the payment
+    pub tax_details: Option<TaxDetails>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -4620,6 +4930,11 @@ pub struct PaymentsCancelRequest {
     pub payment_id: String,
     /// The reason for the payment cancel
     pub cancellation_reason: Option<String>,
+    /// The amount of the authorization to release, the rest of the authorization remaining
+    /// capturable. The whole authorization is voided when not provided. Partial reversals are
+    /// only supported by some connectors
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub amount_to_reverse: Option<MinorUnit>,
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
@@ -4773,6 +5088,9 @@ pub struct FrmMessage {
     pub frm_score: Option<i32>,
     pub frm_reason: Option<serde_json::Value>,
     pub frm_error: Option<String>,
+    /// The action taken on the payment before authorization, based on the FRM decision matrix of the business profile
+    #[schema(value_type = Option<FrmDecisionAction>)]
+    pub frm_decision: Option<api_enums::FrmDecisionAction>,
 }
 
 mod payment_id_type {
@@ -5120,6 +5438,97 @@ pub struct ExtendedCardInfoResponse {
     pub payload: String,
 }
 
+/// A request sent to a connector for the payment along with the response received, captured while
+/// the connector debug capture is enabled for the business profile
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct ConnectorExchange {
+    /// The identifier of the exchange
+    #[schema(example = "cex_7L4ZmyVfFCUvPk9jj1Sk")]
+    pub exchange_id: String,
+    /// The identifier of the payment attempt the connector was called for
+    pub attempt_id: String,
+    /// The connector called
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The flow the connector was called for
+    #[schema(example = "Authorize")]
+    pub flow: String,
+    /// The identifier of the request to the router the connector was called in
+    pub request_id: Option<String>,
+    /// The URL the request was sent to
+    pub url: String,
+    /// The HTTP method of the request
+    #[schema(example = "POST")]
+    pub method: String,
+    /// The body of the request, with the sensitive data masked
+    #[schema(value_type = Option<Object>)]
+    pub request: Option<serde_json::Value>,
+    /// The body of the response, with the sensitive data masked
+    #[schema(value_type = Option<Object>)]
+    pub response: Option<serde_json::Value>,
+    /// The error returned by the connector or encountered while calling it
+    #[schema(value_type = Option<Object>)]
+    pub error: Option<serde_json::Value>,
+    /// The HTTP status code of the response
+    #[schema(example = 200)]
+    pub status_code: u16,
+    /// The time taken by the connector to respond, in milliseconds
+    #[schema(example = 420)]
+    pub latency_in_ms: u64,
+    /// The time at which the exchange was made
+    #[schema(value_type = PrimitiveDateTime, example = "2024-07-03T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time after which the exchange is no longer retained
+    #[schema(value_type = PrimitiveDateTime, example = "2024-07-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct ConnectorExchangesResponse {
+    /// The identifier of the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The exchanges with the connectors for the payment that are still retained, in the order they were made
+    pub exchanges: Vec<ConnectorExchange>,
+}
+
+/// A change of the status of the payment or of one of its attempts
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentStatusTransition {
+    /// The identifier of the attempt whose status changed, absent for a change of the status of the
+    /// payment
+    pub attempt_id: Option<String>,
+    /// Whether the status of the payment or of the attempt changed
+    #[schema(value_type = PaymentStatusObject, example = "payment_intent")]
+    pub object_type: api_enums::PaymentStatusObject,
+    /// The status before the change
+    #[schema(example = "requires_capture")]
+    pub previous_status: String,
+    /// The status after the change
+    #[schema(example = "succeeded")]
+    pub status: String,
+    /// What caused the change
+    #[schema(value_type = PaymentStatusActor, example = "webhook")]
+    pub actor: api_enums::PaymentStatusActor,
+    /// The identifier of the request to the router the status changed in
+    pub request_id: Option<String>,
+    /// The time at which the status changed
+    #[schema(value_type = PrimitiveDateTime, example = "2024-07-30T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentStatusHistoryResponse {
+    /// The identifier of the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The changes of the status of the payment and of its attempts, in the order they were made
+    pub transitions: Vec<PaymentStatusTransition>,
+}
+
 #[cfg(test)]
 mod payments_request_api_contract {
     #![allow(clippy::unwrap_used)]
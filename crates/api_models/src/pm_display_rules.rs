@@ -0,0 +1,148 @@
+use common_utils::{events, types::MinorUnit};
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// The platform the payment methods are listed on, as sent by the SDK
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ClientPlatform {
+    Web,
+    Ios,
+    Android,
+}
+
+/// What happens to the payment methods of a display rule when its conditions are met
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisplayRuleAction {
+    /// The payment methods are hidden when the conditions are met
+    Hide,
+    /// The payment methods are shown only when the conditions are met
+    ShowOnly,
+}
+
+/// The conditions of a display rule, all of which have to be met. A rule without conditions is
+/// always met.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisplayRuleConditions {
+    /// The minimum amount of the payment, in the lowest denomination of its currency
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub min_amount: Option<MinorUnit>,
+
+    /// The maximum amount of the payment, in the lowest denomination of its currency
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+
+    /// The currencies of the payment, any of which has to match
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub currencies: Option<Vec<enums::Currency>>,
+
+    /// The billing countries of the customer, any of which has to match
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["DE", "NL"]))]
+    pub customer_countries: Option<Vec<enums::CountryAlpha2>>,
+
+    /// The platforms the payment methods are listed on, any of which has to match
+    #[schema(value_type = Option<Vec<ClientPlatform>>, example = json!(["ios"]))]
+    pub platforms: Option<Vec<ClientPlatform>>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayRule {
+    /// Name of the rule
+    #[schema(example = "hide_klarna_for_small_amounts")]
+    pub name: String,
+
+    /// The payment method the rule applies to
+    #[schema(value_type = PaymentMethod, example = "pay_later")]
+    pub payment_method: enums::PaymentMethod,
+
+    /// The payment method types the rule applies to, all the types of the payment method when not
+    /// provided
+    #[schema(value_type = Option<Vec<PaymentMethodType>>, example = json!(["klarna"]))]
+    pub payment_method_types: Option<Vec<enums::PaymentMethodType>>,
+
+    /// The conditions of the rule
+    #[serde(default)]
+    pub conditions: DisplayRuleConditions,
+
+    /// What happens to the payment methods when the conditions are met
+    #[schema(value_type = DisplayRuleAction, example = "hide")]
+    pub action: DisplayRuleAction,
+}
+
+impl PaymentMethodDisplayRule {
+    /// Whether the rule applies to the payment method type of the payment method
+    pub fn applies_to(
+        &self,
+        payment_method: enums::PaymentMethod,
+        payment_method_type: enums::PaymentMethodType,
+    ) -> bool {
+        self.payment_method == payment_method
+            && self
+                .payment_method_types
+                .as_ref()
+                .map_or(true, |types| types.contains(&payment_method_type))
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayRulesRequest {
+    /// The rules deciding which payment methods are listed for the payments of the business
+    /// profile
+    pub rules: Vec<PaymentMethodDisplayRule>,
+}
+
+impl events::ApiEventMetric for PaymentMethodDisplayRulesRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodDisplayRulesResponse {
+    /// The identifier of the business profile
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The rules deciding which payment methods are listed for the payments of the business
+    /// profile
+    pub rules: Vec<PaymentMethodDisplayRule>,
+
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for PaymentMethodDisplayRulesResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The display rules of a business profile, as persisted in the configs
+pub type PaymentMethodDisplayRulesRecord = PaymentMethodDisplayRulesResponse;
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use common_utils::events;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// The countries and currencies a payment method of a connector is available in, and the flows
+/// it is not available for
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodFilter {
+    /// The currencies the payment method is available in, all of them when not provided
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["USD", "EUR"]))]
+    pub currency: Option<Vec<enums::Currency>>,
+
+    /// The countries the payment method is available in, all of them when not provided
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "DE"]))]
+    pub country: Option<Vec<enums::CountryAlpha2>>,
+
+    /// The flows the payment method is not available for
+    pub not_available_flows: Option<PaymentMethodNotAvailableFlows>,
+}
+
+#[derive(Clone, Copy, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodNotAvailableFlows {
+    /// The capture method the payment method is not available with
+    #[schema(value_type = Option<CaptureMethod>, example = "manual")]
+    pub capture_method: Option<enums::CaptureMethod>,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorPmFiltersRequest {
+    /// The filters of the payment methods of the connector, keyed by payment method type or card
+    /// network. The filters replace all the filters of the connector set in the configuration of
+    /// the deployment.
+    #[schema(value_type = Object, example = json!({"klarna": {"country": ["US", "GB"], "currency": ["USD", "GBP"]}}))]
+    pub filters: HashMap<String, PaymentMethodFilter>,
+}
+
+impl events::ApiEventMetric for ConnectorPmFiltersRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The filters of the payment methods of the connectors set through the API, keyed by connector
+pub type PmFiltersRecord = HashMap<String, HashMap<String, PaymentMethodFilter>>;
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorPmFiltersResponse {
+    /// The name of the connector, or `default` for the filters of the connectors without filters
+    /// of their own
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The filters of the payment methods of the connector, keyed by payment method type or card
+    /// network
+    #[schema(value_type = Object, example = json!({"klarna": {"country": ["US", "GB"], "currency": ["USD", "GBP"]}}))]
+    pub filters: HashMap<String, PaymentMethodFilter>,
+
+    /// Whether the filters were set through the API, overriding the filters of the configuration
+    /// of the deployment
+    #[schema(example = true)]
+    pub overridden: bool,
+}
+
+impl events::ApiEventMetric for ConnectorPmFiltersResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,136 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The state of a task of the scheduler
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessTrackerTaskState {
+    /// The task is waiting for its schedule time to be run, or to be run again
+    Scheduled,
+    /// The task is not run until it is resumed
+    Paused,
+    /// The task is being run
+    InProgress,
+    /// The task was run to completion
+    Completed,
+    /// The task failed and is not run again unless it is resumed
+    Dead,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerTaskListConstraints {
+    /// Only list the tasks of this workflow
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<String>,
+
+    /// Only list the tasks in this state
+    #[schema(value_type = Option<ProcessTrackerTaskState>, example = "dead")]
+    pub state: Option<ProcessTrackerTaskState>,
+
+    /// The number of tasks listed, defaults to 20
+    #[schema(maximum = 100, example = 20)]
+    pub limit: Option<u32>,
+
+    /// The number of tasks skipped
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+}
+
+impl ApiEventMetric for ProcessTrackerTaskListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ProcessTrackerTaskId {
+    pub merchant_id: String,
+    pub task_id: String,
+}
+
+impl ApiEventMetric for ProcessTrackerTaskId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerTaskRescheduleRequest {
+    /// The time at which the task is to be run
+    #[schema(value_type = PrimitiveDateTime, example = "2024-07-31T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ProcessTrackerTaskRescheduleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ProcessTrackerTaskResponse {
+    /// The identifier of the task
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW_pay_mbabizu24mvu3mela5njyhpit4")]
+    pub task_id: String,
+
+    /// The name of the task
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub name: Option<String>,
+
+    /// The workflow the task is run by
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<String>,
+
+    #[schema(example = json!(["PAYMENT"]))]
+    pub tag: Vec<String>,
+
+    #[schema(value_type = ProcessTrackerTaskState, example = "scheduled")]
+    pub state: ProcessTrackerTaskState,
+
+    /// The outcome of the task as recorded by its workflow
+    #[schema(example = "Pending")]
+    pub business_status: String,
+
+    /// The number of times the task was retried
+    #[schema(example = 2)]
+    pub retry_count: i32,
+
+    /// The time at which the task is to be run
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+
+    /// The data the task is run with
+    #[schema(value_type = Object)]
+    pub tracking_data: serde_json::Value,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ProcessTrackerTaskResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ProcessTrackerTaskListResponse {
+    /// The tasks of the merchant, from the latest scheduled
+    pub tasks: Vec<ProcessTrackerTaskResponse>,
+}
+
+impl ApiEventMetric for ProcessTrackerTaskListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,159 @@
+use common_utils::events;
+use utoipa::ToSchema;
+
+use crate::{admin::BusinessProfileResponse, enums};
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileCloneRequest {
+    /// The name of the business profile created, unique among the profiles of the merchant it is
+    /// created for
+    #[schema(max_length = 64, example = "shoe_business_eu")]
+    pub profile_name: String,
+
+    /// The merchant the business profile is created for, defaults to the merchant of the profile
+    /// cloned
+    #[schema(max_length = 64, example = "merchant_sub_1234")]
+    pub target_merchant_id: Option<String>,
+
+    /// The merchant connector accounts of the profile cloned which are copied to the profile
+    /// created. The copies are created inactive and without credentials, which are to be set
+    /// through the merchant connector account update.
+    #[schema(example = json!(["mca_5apGeP94tMts6rg3U3kR"]))]
+    #[serde(default)]
+    pub merchant_connector_ids: Vec<String>,
+}
+
+impl events::ApiEventMetric for BusinessProfileCloneRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// A merchant connector account copied to a business profile created from another one
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ClonedMerchantConnectorAccount {
+    /// The merchant connector account copied
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub source_merchant_connector_id: String,
+
+    /// The merchant connector account created
+    #[schema(example = "mca_ZXYRmDmbsJtEGeFZyDlH")]
+    pub merchant_connector_id: String,
+
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+
+    #[schema(value_type = ConnectorStatus, example = "inactive")]
+    pub status: enums::ConnectorStatus,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct BusinessProfileCloneResponse {
+    /// The business profile created
+    pub profile: BusinessProfileResponse,
+
+    /// The merchant connector accounts copied to the business profile created, which are to be
+    /// given credentials before being enabled
+    pub merchant_connector_accounts: Vec<ClonedMerchantConnectorAccount>,
+}
+
+impl events::ApiEventMetric for BusinessProfileCloneResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileTemplateRequest {
+    /// The name of the template, unique among the templates of the merchant. A template with the
+    /// same name is replaced.
+    #[schema(max_length = 64, example = "eu_marketplace_seller")]
+    pub template_name: String,
+
+    #[schema(example = "Profile of the sellers onboarded in the EU")]
+    pub description: Option<String>,
+
+    /// The business profile of the merchant the profiles created from the template are cloned from
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The merchant connector accounts of the business profile which are copied to the profiles
+    /// created from the template
+    #[schema(example = json!(["mca_5apGeP94tMts6rg3U3kR"]))]
+    #[serde(default)]
+    pub merchant_connector_ids: Vec<String>,
+}
+
+impl events::ApiEventMetric for BusinessProfileTemplateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// A business profile of the merchant the business profiles of other merchants, such as the
+/// sub-merchants onboarded by a platform, are created from
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BusinessProfileTemplate {
+    #[schema(example = "eu_marketplace_seller")]
+    pub template_name: String,
+
+    #[schema(example = "Profile of the sellers onboarded in the EU")]
+    pub description: Option<String>,
+
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    #[schema(example = json!(["mca_5apGeP94tMts6rg3U3kR"]))]
+    pub merchant_connector_ids: Vec<String>,
+
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for BusinessProfileTemplate {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct BusinessProfileTemplatesResponse {
+    #[schema(example = "merchant_platform")]
+    pub merchant_id: String,
+
+    pub templates: Vec<BusinessProfileTemplate>,
+}
+
+impl events::ApiEventMetric for BusinessProfileTemplatesResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The business profile templates of a merchant, as persisted in the configs
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct BusinessProfileTemplatesRecord {
+    #[serde(default)]
+    pub templates: Vec<BusinessProfileTemplate>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileTemplateApplyRequest {
+    /// The name of the business profile created, unique among the profiles of the merchant it is
+    /// created for
+    #[schema(max_length = 64, example = "default")]
+    pub profile_name: String,
+
+    /// The merchant the business profile is created for, defaults to the merchant of the template
+    #[schema(max_length = 64, example = "merchant_sub_1234")]
+    pub target_merchant_id: Option<String>,
+}
+
+impl events::ApiEventMetric for BusinessProfileTemplateApplyRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
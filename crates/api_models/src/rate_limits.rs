@@ -0,0 +1,102 @@
+use common_utils::events;
+use utoipa::ToSchema;
+
+/// The class of endpoints sharing a quota of requests. Writes to the payments and refunds
+/// endpoints have their own quotas, the other reads and writes sharing theirs
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RateLimitEndpointClass {
+    Payments,
+    Refunds,
+    Reads,
+    Others,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitQuota {
+    /// The number of requests allowed in a minute
+    #[schema(example = 600)]
+    pub requests_per_minute: u32,
+
+    /// The number of requests allowed in a second, absorbing short bursts of traffic
+    #[schema(example = 20)]
+    pub burst: u32,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantRateLimitsRequest {
+    /// The quota of the writes to the payments endpoints, the default quota applying when not set
+    pub payments: Option<RateLimitQuota>,
+
+    /// The quota of the writes to the refunds endpoints, the default quota applying when not set
+    pub refunds: Option<RateLimitQuota>,
+
+    /// The quota of the reads, the default quota applying when not set
+    pub reads: Option<RateLimitQuota>,
+
+    /// The quota of the other writes, the default quota applying when not set
+    pub others: Option<RateLimitQuota>,
+}
+
+impl events::ApiEventMetric for MerchantRateLimitsRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+impl MerchantRateLimitsRequest {
+    pub fn get_quota(&self, endpoint_class: RateLimitEndpointClass) -> Option<RateLimitQuota> {
+        match endpoint_class {
+            RateLimitEndpointClass::Payments => self.payments,
+            RateLimitEndpointClass::Refunds => self.refunds,
+            RateLimitEndpointClass::Reads => self.reads,
+            RateLimitEndpointClass::Others => self.others,
+        }
+    }
+}
+
+/// The quotas overridden for a merchant, as persisted in the configs
+pub type MerchantRateLimitsRecord = MerchantRateLimitsRequest;
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct MerchantRateLimitsResponse {
+    /// The identifier of the merchant account
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    /// Whether the quotas are enforced by the deployment
+    pub enabled: bool,
+
+    /// The quota of the writes to the payments endpoints
+    pub payments: RateLimitQuota,
+
+    /// The quota of the writes to the refunds endpoints
+    pub refunds: RateLimitQuota,
+
+    /// The quota of the reads
+    pub reads: RateLimitQuota,
+
+    /// The quota of the other writes
+    pub others: RateLimitQuota,
+}
+
+impl events::ApiEventMetric for MerchantRateLimitsResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::payments::{AmountFilter, TimeRange};
+use super::payments::{AmountFilter, ListCursor, TimeRange};
 use crate::{
     admin::{self, MerchantConnectorInfo},
     enums,
@@ -83,6 +83,56 @@ pub struct RefundsRetrieveRequest {
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+/// Refunds of many payments, created in the background by a job
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BulkRefundRequest {
+    /// The refunds to be created, at most 1000
+    pub refunds: Vec<BulkRefundItem>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BulkRefundItem {
+    /// The payment to be refunded
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// Unique Identifier for the Refund, generated when the job is created if not provided
+    #[schema(example = "ref_mbabizu24mvu3mela5njyhpit4")]
+    pub refund_id: Option<String>,
+
+    /// The amount to be refunded, defaults to the amount of the payment left to be refunded
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+
+    /// Reason for the refund
+    #[schema(max_length = 255, example = "Customer returned the product")]
+    pub reason: Option<String>,
+}
+
+/// The outcome of the refunds created by a bulk refund job, held in the output of the job
+#[derive(Debug, Default, ToSchema, Clone, Deserialize, Serialize)]
+pub struct BulkRefundJobOutput {
+    pub refunds: Vec<BulkRefundOutcome>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct BulkRefundOutcome {
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    #[schema(example = "ref_mbabizu24mvu3mela5njyhpit4")]
+    pub refund_id: String,
+
+    /// The status of the refund, not set when the refund could not be created
+    #[schema(value_type = Option<RefundStatus>)]
+    pub status: Option<RefundStatus>,
+
+    /// The reason the refund could not be created
+    pub error_message: Option<String>,
+}
+
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundUpdateRequest {
@@ -146,6 +196,13 @@ pub struct RefundResponse {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+    /// The acquirer reference number of the refund, with which the customer can trace the refund at their bank
+    #[schema(example = "74567891234567891234567")]
+    pub refund_arn: Option<String>,
+    /// The time by which the refunded amount is expected to reach the customer, when provided by the connector
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub estimated_arrival_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -158,8 +215,12 @@ pub struct RefundListRequest {
     pub profile_id: Option<String>,
     /// Limit on the number of objects to return
     pub limit: Option<i64>,
-    /// The starting point within a list of objects
+    /// The starting point within a list of objects. Deprecated in favour of `cursor`, which does
+    /// not skip or repeat refunds created while paginating
     pub offset: Option<i64>,
+    /// The `next_cursor` of the previous page, to fetch the refunds which follow it
+    #[schema(value_type = Option<String>)]
+    pub cursor: Option<ListCursor>,
     /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc)
     #[serde(flatten)]
     pub time_range: Option<TimeRange>,
@@ -185,6 +246,9 @@ pub struct RefundListResponse {
     pub total_count: i64,
     /// The List of refund response object
     pub data: Vec<RefundResponse>,
+    /// The cursor to pass to fetch the next page, absent on the last page
+    #[schema(value_type = Option<String>)]
+    pub next_cursor: Option<ListCursor>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, ToSchema)]
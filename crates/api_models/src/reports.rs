@@ -0,0 +1,217 @@
+use common_enums::{Currency, PaymentMethod, ReportRunStatus, ReportType};
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFrequency {
+    Daily,
+    Weekly,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportWeekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// The schedule on which a report is generated. A daily report covers the 24 hours preceding its
+/// generation and a weekly report covers the 7 days preceding its generation.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportSchedule {
+    /// How often the report is generated
+    #[schema(value_type = ReportFrequency, example = "daily")]
+    pub frequency: ReportFrequency,
+
+    /// The hour of the day, in UTC, at which the report is generated
+    #[schema(example = 2, maximum = 23)]
+    pub hour: u8,
+
+    /// The day of the week on which a weekly report is generated
+    #[schema(value_type = Option<ReportWeekday>, example = "monday")]
+    pub day_of_week: Option<ReportWeekday>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportFilters {
+    /// Only include the resources in these currencies
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currency: Option<Vec<Currency>>,
+
+    /// Only include the resources processed by these connectors
+    #[schema(example = json!(["stripe", "adyen"]))]
+    pub connector: Option<Vec<String>>,
+
+    /// Only include the payments made with these payment methods, not applicable to refunds
+    #[schema(value_type = Option<Vec<PaymentMethod>>)]
+    pub payment_method: Option<Vec<PaymentMethod>>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportTemplateCreateRequest {
+    /// Name of the report, used in the name of the generated files
+    #[schema(example = "daily_payments")]
+    pub name: String,
+
+    /// The resources exported by the report
+    #[schema(value_type = ReportType, example = "payments")]
+    pub report_type: ReportType,
+
+    /// The columns of the generated files, in order. All the columns available for the report
+    /// type are included when not provided
+    #[schema(example = json!(["payment_id", "status", "amount", "currency"]))]
+    pub columns: Option<Vec<String>>,
+
+    /// Filters applied to the exported resources
+    pub filters: Option<ReportFilters>,
+
+    /// The schedule on which the report is generated
+    pub schedule: ReportSchedule,
+
+    /// Only include the resources of this business profile
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: Option<String>,
+}
+
+impl ApiEventMetric for ReportTemplateCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportTemplateUpdateRequest {
+    /// Name of the report, used in the name of the generated files
+    #[schema(example = "daily_payments")]
+    pub name: Option<String>,
+
+    /// The columns of the generated files, in order
+    #[schema(example = json!(["payment_id", "status", "amount", "currency"]))]
+    pub columns: Option<Vec<String>>,
+
+    /// Filters applied to the exported resources
+    pub filters: Option<ReportFilters>,
+
+    /// The schedule on which the report is generated
+    pub schedule: Option<ReportSchedule>,
+
+    /// Whether the report is generated on its schedule
+    pub is_active: Option<bool>,
+}
+
+impl ApiEventMetric for ReportTemplateUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The file generated by the last successful run of a report
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct GeneratedReport {
+    /// The identifier of the generated file
+    #[schema(example = "file_abcdefghijklmnopqrst")]
+    pub file_id: String,
+
+    /// A signed link to download the file, which does not require authentication
+    pub download_url: String,
+
+    /// Time after which the download link is no longer valid
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub download_url_expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReportTemplateResponse {
+    /// The identifier of the report
+    #[schema(example = "rpt_abcdefghijklmnopqrst")]
+    pub template_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: Option<String>,
+
+    #[schema(example = "daily_payments")]
+    pub name: String,
+
+    #[schema(value_type = ReportType, example = "payments")]
+    pub report_type: ReportType,
+
+    /// The columns of the generated files, in order
+    pub columns: Vec<String>,
+
+    pub filters: Option<ReportFilters>,
+
+    pub schedule: ReportSchedule,
+
+    /// Whether the report is generated on its schedule
+    pub is_active: bool,
+
+    /// Time at which the report is next generated
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub next_run_at: PrimitiveDateTime,
+
+    /// Time at which the report was last generated
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+
+    #[schema(value_type = Option<ReportRunStatus>)]
+    pub last_run_status: Option<ReportRunStatus>,
+
+    /// The file generated by the last successful run of the report
+    pub last_report: Option<GeneratedReport>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ReportTemplateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ReportDownloadQuery {
+    /// Unix timestamp after which the download link is no longer valid
+    pub expires_at: i64,
+    /// Hex encoded signature of the download link
+    pub signature: String,
+}
+
+impl ApiEventMetric for ReportDownloadQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
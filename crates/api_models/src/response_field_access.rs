@@ -0,0 +1,127 @@
+use common_utils::events;
+use utoipa::ToSchema;
+
+/// The categories of sensitive fields of the API responses whose access can be restricted
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SensitiveFieldCategory {
+    /// The data returned by the connectors, such as the connector metadata, the card checks and
+    /// the detailed error reasons
+    ConnectorResponse,
+    /// The personal details of the customers, such as their name, email, phone number and
+    /// addresses
+    CustomerPii,
+    /// The details of the cards, such as their BIN, expiry, issuer and fingerprint
+    CardMetadata,
+}
+
+/// How the fields of a category are returned to the callers not allowed to see sensitive data
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SensitiveFieldAccess {
+    /// The fields are returned as is
+    #[default]
+    Visible,
+    /// The text of the fields is replaced with `**MASKED**`, the fields being kept
+    Masked,
+    /// The fields are removed from the responses
+    Omitted,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ResponseFieldAccessRequest {
+    /// How the connector response fields are returned to the restricted callers, visible when not
+    /// set
+    #[schema(value_type = Option<SensitiveFieldAccess>, example = "omitted")]
+    pub connector_response: Option<SensitiveFieldAccess>,
+
+    /// How the customer PII fields are returned to the restricted callers, visible when not set
+    #[schema(value_type = Option<SensitiveFieldAccess>, example = "masked")]
+    pub customer_pii: Option<SensitiveFieldAccess>,
+
+    /// How the card metadata fields are returned to the restricted callers, visible when not set
+    #[schema(value_type = Option<SensitiveFieldAccess>, example = "masked")]
+    pub card_metadata: Option<SensitiveFieldAccess>,
+
+    /// The roles of the dashboard users allowed to see the sensitive fields, the dashboard users
+    /// of the other roles being restricted. Every dashboard user is allowed when not set.
+    #[schema(example = json!(["merchant_admin"]))]
+    pub unrestricted_roles: Option<Vec<String>>,
+}
+
+impl ResponseFieldAccessRequest {
+    pub fn get_access(&self, category: SensitiveFieldCategory) -> SensitiveFieldAccess {
+        match category {
+            SensitiveFieldCategory::ConnectorResponse => self.connector_response,
+            SensitiveFieldCategory::CustomerPii => self.customer_pii,
+            SensitiveFieldCategory::CardMetadata => self.card_metadata,
+        }
+        .unwrap_or_default()
+    }
+}
+
+impl events::ApiEventMetric for ResponseFieldAccessRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The access to the sensitive fields configured for a merchant, as persisted in the configs
+pub type ResponseFieldAccessRecord = ResponseFieldAccessRequest;
+
+/// The access to the sensitive fields of the API responses of a merchant. The sensitive fields are
+/// restricted for the API keys without the `sensitive_data:read` scope, when restricted to scopes,
+/// and for the dashboard users whose role is not among the unrestricted roles.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ResponseFieldAccessResponse {
+    /// The identifier of the merchant account
+    #[schema(example = "merchant_abc")]
+    pub merchant_id: String,
+
+    /// How the connector response fields are returned to the restricted callers
+    #[schema(value_type = SensitiveFieldAccess, example = "omitted")]
+    pub connector_response: SensitiveFieldAccess,
+
+    /// How the customer PII fields are returned to the restricted callers
+    #[schema(value_type = SensitiveFieldAccess, example = "masked")]
+    pub customer_pii: SensitiveFieldAccess,
+
+    /// How the card metadata fields are returned to the restricted callers
+    #[schema(value_type = SensitiveFieldAccess, example = "masked")]
+    pub card_metadata: SensitiveFieldAccess,
+
+    /// The roles of the dashboard users allowed to see the sensitive fields, every dashboard user
+    /// being allowed when not set
+    #[schema(example = json!(["merchant_admin"]))]
+    pub unrestricted_roles: Option<Vec<String>>,
+}
+
+impl events::ApiEventMetric for ResponseFieldAccessResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -111,6 +111,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
+        DirKeyKind::DeviceFingerprint,
         DirKeyKind::MetaData,
         DirKeyKind::RewardType,
         DirKeyKind::VoucherType,
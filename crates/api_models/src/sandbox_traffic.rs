@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use common_utils::{events, types::MinorUnit};
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// The payment methods the synthetic payments are made with, all of them supported by the test
+/// connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SandboxPaymentMethod {
+    Visa,
+    Mastercard,
+    AmericanExpress,
+    Discover,
+    DinersClub,
+    /// Redirects the customer to the test connector, the payment awaiting the customer
+    GooglePay,
+    /// Redirects the customer to the test connector, the payment awaiting the customer
+    Paypal,
+}
+
+/// The outcomes the synthetic payments are simulated to have by the test connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SandboxPaymentOutcome {
+    /// Authorized by the test connector, or awaiting the customer for the redirect payment methods
+    Succeeded,
+    /// Declined by the test connector with a do not honor error
+    Declined,
+    InsufficientFunds,
+    ExpiredCard,
+    /// Awaiting the 3DS authentication of the customer, made with a Visa card whatever the card
+    /// network requested
+    ThreeDsRequired,
+    /// Failed with an internal server error of the test connector
+    ConnectorError,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SandboxTrafficRequest {
+    /// The number of synthetic payments to make
+    #[schema(minimum = 1, maximum = 100, example = 20)]
+    pub payment_count: u16,
+
+    /// The business profile the payments are made for, the default business profile of the
+    /// merchant when not provided
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The merchant connector account of the test connector the payments are made with, the first
+    /// test connector of the business profile when not provided
+    #[schema(example = "mca_abcdefghijklmnop")]
+    pub merchant_connector_id: Option<String>,
+
+    /// The relative weights of the payment methods of the payments, cards of the Visa network only
+    /// when not provided
+    #[schema(value_type = Option<Object>, example = json!({"visa": 3, "mastercard": 1}))]
+    pub payment_methods: Option<HashMap<SandboxPaymentMethod, u32>>,
+
+    /// The relative weights of the currencies of the payments, USD only when not provided
+    #[schema(value_type = Option<Object>, example = json!({"USD": 4, "EUR": 1}))]
+    pub currencies: Option<HashMap<enums::Currency, u32>>,
+
+    /// The relative weights of the outcomes of the payments, all of them succeeding when not
+    /// provided
+    #[schema(value_type = Option<Object>, example = json!({"succeeded": 8, "declined": 1, "insufficient_funds": 1}))]
+    pub outcomes: Option<HashMap<SandboxPaymentOutcome, u32>>,
+
+    /// The smallest amount of the payments, in the lowest denomination of the currency. The
+    /// declines and the connector errors are simulated for specific amounts, which are used
+    /// whatever the range of amounts.
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+
+    /// The largest amount of the payments, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 10000)]
+    pub max_amount: Option<MinorUnit>,
+
+    /// The capture method of the payments, automatic when not provided
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<enums::CaptureMethod>,
+
+    /// The URL the customers are redirected to after the redirect payment methods and the 3DS
+    /// authentication, the return URL of the business profile when not provided
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<url::Url>,
+}
+
+impl events::ApiEventMetric for SandboxTrafficRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SandboxPayment {
+    /// The identifier of the payment, absent when the payment could not be made
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+
+    #[schema(value_type = SandboxPaymentMethod, example = "visa")]
+    pub payment_method: SandboxPaymentMethod,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The outcome the payment was simulated to have
+    #[schema(value_type = SandboxPaymentOutcome, example = "succeeded")]
+    pub expected_outcome: SandboxPaymentOutcome,
+
+    /// The status of the payment, absent when the payment could not be made
+    #[schema(value_type = Option<IntentStatus>, example = "succeeded")]
+    pub status: Option<enums::IntentStatus>,
+
+    /// The reason the payment could not be made
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SandboxTrafficResponse {
+    /// The merchant connector account of the test connector the payments were made with
+    #[schema(example = "mca_abcdefghijklmnop")]
+    pub merchant_connector_id: String,
+
+    /// The name of the test connector the payments were made with
+    #[schema(example = "stripe_test")]
+    pub connector_name: String,
+
+    /// The number of payments of each status
+    #[schema(value_type = Object, example = json!({"succeeded": 16, "failed": 4}))]
+    pub status_counts: HashMap<enums::IntentStatus, u32>,
+
+    /// The number of payments which could not be made
+    #[schema(example = 0)]
+    pub error_count: u32,
+
+    pub payments: Vec<SandboxPayment>,
+}
+
+impl events::ApiEventMetric for SandboxTrafficResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
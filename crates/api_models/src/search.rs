@@ -0,0 +1,160 @@
+use common_enums::Currency;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    pii,
+};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SearchRequest {
+    /// Text matched against the identifiers, references and connector transaction ids of the
+    /// objects
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub query: Option<String>,
+
+    /// Email of the customer of the objects
+    #[schema(value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<pii::Email>,
+
+    /// Phone number of the customer of the objects, matched whatever its formatting
+    #[schema(value_type = Option<String>, example = "9123456789")]
+    pub phone: Option<Secret<String>>,
+
+    /// Country code of the phone number of the customer of the objects
+    #[schema(example = "+1")]
+    pub phone_country_code: Option<String>,
+
+    /// Last four digits of the card used for the payment
+    #[schema(example = "4242")]
+    pub last4: Option<String>,
+
+    /// Transaction id assigned by the connector to the payment, refund or dispute
+    #[schema(example = "pi_3MqSBh2eZvKYlo2C1gwZ8KzO")]
+    pub connector_transaction_id: Option<String>,
+
+    /// Reference of the payment shared with the connector
+    pub reference: Option<String>,
+
+    /// Metadata key value pairs which the payments must all have
+    #[schema(value_type = Option<Object>, example = r#"{ "order_id": "ord_1234" }"#)]
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+
+    /// The kinds of objects to search, all of them when not provided
+    pub objects: Option<Vec<SearchObject>>,
+
+    /// The maximum number of results, defaults to 20 and is capped at 100
+    pub limit: Option<u32>,
+}
+
+impl ApiEventMetric for SearchRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SearchObject {
+    Payment,
+    Refund,
+    Dispute,
+    Customer,
+}
+
+/// The criterion of the search an object matched
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    /// The identifier of the object
+    Id,
+    /// The identifier of the payment of the refund or dispute
+    PaymentId,
+    ConnectorTransactionId,
+    Reference,
+    Email,
+    Phone,
+    Last4,
+    Metadata,
+}
+
+impl SearchField {
+    /// Weight of the criterion in the score of the objects, identifiers being the most selective
+    pub fn weight(self) -> u32 {
+        match self {
+            Self::Id => 100,
+            Self::ConnectorTransactionId => 80,
+            Self::Reference => 60,
+            Self::PaymentId => 50,
+            Self::Email | Self::Phone => 40,
+            Self::Metadata => 30,
+            Self::Last4 => 20,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SearchHit {
+    pub object: SearchObject,
+
+    /// The identifier of the object
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub id: String,
+
+    /// The identifier of the payment of the object, if any
+    pub payment_id: Option<String>,
+
+    /// The identifier of the customer of the object, if any
+    pub customer_id: Option<String>,
+
+    /// The status of the object
+    #[schema(example = "succeeded")]
+    pub status: Option<String>,
+
+    /// The amount of the object, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<Currency>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The criteria of the search the object matched
+    pub matched_on: Vec<SearchField>,
+
+    /// Relevance of the object, the sum of the weights of the criteria it matched
+    pub score: u32,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SearchResponse {
+    /// The number of results
+    pub count: usize,
+
+    /// The results, from the most relevant
+    pub hits: Vec<SearchHit>,
+}
+
+impl ApiEventMetric for SearchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
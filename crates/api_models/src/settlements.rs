@@ -0,0 +1,248 @@
+use common_enums::{Currency, SettlementLineType, SettlementReconStatus};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// A line of a connector settlement file, with all the amounts in the lowest denomination of
+/// the currency
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementLineInput {
+    /// The resource settled by the line
+    #[schema(value_type = SettlementLineType, example = "payment")]
+    pub line_type: SettlementLineType,
+
+    /// The reference of the settled resource at the connector. This is the connector transaction
+    /// id of a payment, the connector refund id of a refund and the connector dispute id of a
+    /// dispute
+    #[schema(example = "pi_3MKEivSFNglxLpam0ZaL98q9")]
+    pub reference_id: String,
+
+    /// The connector transaction id of the disputed payment, required for the dispute lines
+    pub payment_reference_id: Option<String>,
+
+    /// The settled amount, before the fees of the connector
+    #[schema(value_type = i64, example = 6540)]
+    pub gross_amount: MinorUnit,
+
+    /// The fees charged by the connector for the line
+    #[schema(value_type = Option<i64>, example = 190)]
+    pub fee_amount: Option<MinorUnit>,
+
+    /// The net amount of the line as reported by the connector. For a payment this is the gross
+    /// amount less the fees, for a refund or a dispute this is the gross amount plus the fees.
+    /// It is computed when not provided.
+    #[schema(value_type = Option<i64>, example = 6350)]
+    pub net_amount: Option<MinorUnit>,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// Time at which the connector settled the line
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+}
+
+/// Settlement lines fetched from the reporting API of a connector
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementLinesIngestRequest {
+    /// The connector which settled the lines
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The merchant connector account the lines were settled for
+    pub merchant_connector_id: Option<String>,
+
+    pub lines: Vec<SettlementLineInput>,
+}
+
+impl ApiEventMetric for SettlementLinesIngestRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+/// A settlement file downloaded from the dashboard of a connector, received as a multipart upload
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct SettlementFileIngestRequest {
+    pub connector: String,
+    pub merchant_connector_id: Option<String>,
+    pub file_name: Option<String>,
+    /// The content of the file, not logged with the request
+    #[serde(skip)]
+    pub file: Vec<u8>,
+}
+
+impl ApiEventMetric for SettlementFileIngestRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SettlementLineResponse {
+    #[schema(example = "stl_abcdefghijklmnopqrst")]
+    pub line_id: String,
+
+    /// The ingestion in which the line was received
+    #[schema(example = "stlb_abcdefghijklmnopqrst")]
+    pub batch_id: String,
+
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    pub merchant_connector_id: Option<String>,
+
+    #[schema(value_type = SettlementLineType)]
+    pub line_type: SettlementLineType,
+
+    pub reference_id: String,
+
+    pub payment_reference_id: Option<String>,
+
+    #[schema(value_type = i64)]
+    pub gross_amount: MinorUnit,
+
+    #[schema(value_type = i64)]
+    pub fee_amount: MinorUnit,
+
+    /// The net amount credited to the merchant, negative for refunds and disputes
+    #[schema(value_type = i64)]
+    pub net_amount: MinorUnit,
+
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+
+    /// The payment the line was matched to
+    pub payment_id: Option<String>,
+
+    /// The refund the line was matched to
+    pub refund_id: Option<String>,
+
+    /// The dispute the line was matched to
+    pub dispute_id: Option<String>,
+
+    #[schema(value_type = SettlementReconStatus)]
+    pub recon_status: SettlementReconStatus,
+
+    /// Description of the mismatch, when the line was not matched
+    pub recon_error_message: Option<String>,
+
+    /// Note of the merchant when the line was resolved manually
+    pub resolution_note: Option<String>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SettlementBatchResponse {
+    #[schema(example = "stlb_abcdefghijklmnopqrst")]
+    pub batch_id: String,
+
+    /// Number of lines in the batch
+    pub total_lines: usize,
+
+    /// Number of lines which were matched or resolved
+    pub matched_lines: usize,
+
+    /// Number of lines which need to be looked into
+    pub exception_lines: usize,
+
+    pub lines: Vec<SettlementLineResponse>,
+}
+
+impl ApiEventMetric for SettlementBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentReconStatus {
+    /// No settlement line was received for the payment
+    Pending,
+    /// All the settlement lines of the payment were matched or resolved
+    Reconciled,
+    /// Some settlement lines of the payment need to be looked into
+    Exception,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentReconciliationResponse {
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    #[schema(value_type = PaymentReconStatus)]
+    pub recon_status: PaymentReconStatus,
+
+    /// Total fees charged by the connectors across the settlement lines of the payment
+    #[schema(value_type = i64)]
+    pub total_fee_amount: MinorUnit,
+
+    /// Net amount settled to the merchant for the payment, its refunds and its disputes
+    #[schema(value_type = i64)]
+    pub net_settled_amount: MinorUnit,
+
+    /// The settlement lines of the payment, its refunds and its disputes
+    pub lines: Vec<SettlementLineResponse>,
+}
+
+impl ApiEventMetric for PaymentReconciliationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementExceptionsQuery {
+    /// Only list the exceptions of this connector
+    pub connector: Option<String>,
+
+    /// Only list the exceptions with this status
+    #[schema(value_type = Option<SettlementReconStatus>)]
+    pub recon_status: Option<SettlementReconStatus>,
+
+    /// The maximum number of exceptions to list, defaults to 100
+    pub limit: Option<i64>,
+
+    pub offset: Option<i64>,
+}
+
+impl ApiEventMetric for SettlementExceptionsQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementLineResolveRequest {
+    /// Why the line is considered resolved
+    #[schema(example = "Fee adjustment agreed with the connector")]
+    pub resolution_note: String,
+}
+
+impl ApiEventMetric for SettlementLineResolveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SettlementLineResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -35,6 +35,8 @@ impl EuclidDirFilter for SurchargeDecisionConfigs {
         DirKeyKind::PaymentCurrency,
         DirKeyKind::BillingCountry,
         DirKeyKind::CardNetwork,
+        DirKeyKind::CardType,
+        DirKeyKind::CardBin,
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::BankTransferType,
@@ -76,6 +78,39 @@ impl events::ApiEventMetric for SurchargeDecisionConfigReq {
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MerchantSurchargeConfigs {
     pub show_surcharge_breakup_screen: Option<bool>,
+    /// The legal limits on the surcharges computed from the surcharge rules, per jurisdiction
+    pub surcharge_caps: Option<Vec<SurchargeCap>>,
+}
+
+/// A legal limit on the surcharge that can be applied to the cards issued in a jurisdiction
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeCap {
+    /// The countries the card must be issued in for the cap to apply
+    pub card_issuing_countries: Vec<common_enums::CountryAlpha2>,
+    /// The funding types of the cards the cap applies to, all cards when not provided
+    pub card_funding_types: Option<Vec<common_enums::CardFundingType>>,
+    /// The maximum surcharge, as a percentage of the payment amount
+    pub max_percentage: Option<Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>>,
+    /// The maximum surcharge amount
+    pub max_amount: Option<MinorUnit>,
+}
+
+impl SurchargeCap {
+    pub fn is_applicable(
+        &self,
+        card_issuing_country: common_enums::CountryAlpha2,
+        card_funding_type: Option<common_enums::CardFundingType>,
+    ) -> bool {
+        self.card_issuing_countries.contains(&card_issuing_country)
+            && match (&self.card_funding_types, card_funding_type) {
+                (Some(card_funding_types), Some(card_funding_type)) => {
+                    card_funding_types.contains(&card_funding_type)
+                }
+                // The cap is applied when the funding type of the card is not known
+                (Some(_), None) | (None, _) => true,
+            }
+    }
 }
 
 pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
@@ -0,0 +1,147 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TenantStatus {
+    #[default]
+    Active,
+    /// The requests made to a disabled tenant are rejected, its data being retained
+    Disabled,
+}
+
+/// The limits on the resources of a tenant
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct TenantQuotas {
+    /// The maximum number of merchant accounts the tenant can create
+    #[schema(example = 100)]
+    pub max_merchant_accounts: Option<u32>,
+
+    /// The maximum number of requests the tenant can make in a minute, across all its merchants
+    #[schema(example = 6000)]
+    pub requests_per_minute: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TenantCreateRequest {
+    /// The identifier of the tenant, sent in the `x-tenant-id` header of the requests
+    #[schema(example = "acme")]
+    pub tenant_id: String,
+
+    #[schema(example = "Acme Payments")]
+    pub tenant_name: String,
+
+    /// The base url of the tenant, used in the links generated for its merchants
+    #[schema(example = "https://acme.example.com")]
+    pub base_url: String,
+
+    /// The postgres schema the data of the tenant is stored in, defaults to the tenant id. The
+    /// schema is created with the tables of the template schema if it does not exist
+    #[schema(example = "acme")]
+    pub schema_name: Option<String>,
+
+    /// The prefix of the redis keys of the tenant, defaults to the tenant id
+    #[schema(example = "acme")]
+    pub redis_key_prefix: Option<String>,
+
+    /// The clickhouse database the analytics of the tenant are read from, defaults to the
+    /// clickhouse database of the deployment
+    pub clickhouse_database: Option<String>,
+
+    pub quotas: Option<TenantQuotas>,
+
+    /// Check that the data of the tenant is isolated from the other tenants before activating it,
+    /// the tenant being left disabled if the check fails
+    #[serde(default)]
+    pub isolation_test_mode: bool,
+}
+
+impl ApiEventMetric for TenantCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TenantUpdateRequest {
+    #[schema(value_type = Option<TenantStatus>)]
+    pub status: Option<TenantStatus>,
+
+    /// Replaces the quotas of the tenant
+    pub quotas: Option<TenantQuotas>,
+}
+
+impl ApiEventMetric for TenantUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The outcome of checking that the data of a tenant cannot be read by the other tenants
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct TenantIsolationCheck {
+    pub passed: bool,
+
+    /// The stores of the other tenants the data of the tenant was visible from
+    pub violations: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct TenantResponse {
+    #[schema(example = "acme")]
+    pub tenant_id: String,
+
+    #[schema(example = "Acme Payments")]
+    pub tenant_name: String,
+
+    pub base_url: String,
+
+    pub schema_name: String,
+
+    pub redis_key_prefix: String,
+
+    pub clickhouse_database: String,
+
+    #[schema(value_type = TenantStatus, example = "active")]
+    pub status: TenantStatus,
+
+    pub quotas: Option<TenantQuotas>,
+
+    pub isolation_test_mode: bool,
+
+    /// The outcome of the isolation check, only run while creating a tenant in isolation test mode
+    pub isolation_check: Option<TenantIsolationCheck>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for TenantResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
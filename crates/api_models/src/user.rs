@@ -101,6 +101,9 @@ pub struct InviteUserRequest {
     pub email: pii::Email,
     pub name: Secret<String>,
     pub role_id: String,
+    /// The business profile the user is restricted to, the user having access to all the
+    /// profiles of the merchant when not set
+    pub profile_id: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -35,6 +35,7 @@ pub enum Permission {
     PayoutWrite,
     PayoutRead,
     WebhookEventWrite,
+    PayoutApprove,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -88,6 +89,9 @@ pub struct PermissionInfo {
 pub struct UpdateUserRoleRequest {
     pub email: pii::Email,
     pub role_id: String,
+    /// The business profile the user is restricted to, the user having access to all the
+    /// profiles of the merchant when not set
+    pub profile_id: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize)]
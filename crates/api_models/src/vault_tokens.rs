@@ -0,0 +1,111 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VaultTokenExpireRequest {
+    /// The temporary token of the payment method, as returned when listing the payment methods
+    /// of a customer
+    #[schema(example = "token_abcdefghijklmnopqrst")]
+    pub token: String,
+
+    /// The payment method the token was issued for
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+}
+
+impl ApiEventMetric for VaultTokenExpireRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct VaultTokenExpireResponse {
+    #[schema(example = "token_abcdefghijklmnopqrst")]
+    pub token: String,
+
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// Whether the token was live and has been expired. Tokens that had already expired are
+    /// reported as not expired
+    pub expired: bool,
+}
+
+impl ApiEventMetric for VaultTokenExpireResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VaultCleanupRequest {
+    /// Number of days a payment method must have been left untouched before its vault entry is
+    /// considered orphaned, defaults to 90 days
+    #[schema(example = 90)]
+    pub retention_days: Option<u16>,
+}
+
+impl ApiEventMetric for VaultCleanupRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct VaultCleanupResponse {
+    /// The identifier of the scheduled cleanup
+    #[schema(example = "VAULT_CLEANUP_WORKFLOW_VAULT_CLEANUP_merchant_1668273825_1721030400")]
+    pub cleanup_id: String,
+
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    #[schema(example = 90)]
+    pub retention_days: u16,
+
+    /// Payment methods last modified before this time are considered for the cleanup
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_before: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for VaultCleanupResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VaultAuditQuery {
+    /// Only list the vault token reads and deletions recorded after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only list the vault token reads and deletions recorded before this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of events to list, defaults to 100
+    pub limit: Option<i64>,
+
+    pub offset: Option<i64>,
+}
+
+impl ApiEventMetric for VaultAuditQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,85 @@
+use common_utils::{events, types::MinorUnit};
+use utoipa::ToSchema;
+
+/// Decline code of the payments blocked for exceeding a velocity limit of the business profile
+pub const VELOCITY_LIMIT_DECLINE_CODE: &str = "velocity_limit_exceeded";
+
+/// The attribute of the payment on which the payments are aggregated by a velocity limit
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityLimitDimension {
+    Customer,
+    Card,
+    IpAddress,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityLimitRule {
+    /// Name of the limit, returned in the decline reason of the payments exceeding it
+    #[schema(example = "customer_hourly_count")]
+    pub name: String,
+
+    /// The attribute of the payment on which the payments are aggregated
+    #[schema(value_type = VelocityLimitDimension, example = "customer")]
+    pub dimension: VelocityLimitDimension,
+
+    /// The maximum number of payments allowed within the window
+    #[schema(example = 5)]
+    pub max_count: Option<u32>,
+
+    /// The maximum total amount of the payments allowed within the window, in the lowest
+    /// denomination of the currency. Payments in different currencies are aggregated separately
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+
+    /// The duration of the sliding window in seconds
+    #[schema(example = 3600)]
+    pub window_in_secs: u32,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityLimitsRequest {
+    /// The velocity limits enforced on the payments of the business profile
+    pub rules: Vec<VelocityLimitRule>,
+}
+
+impl events::ApiEventMetric for VelocityLimitsRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VelocityLimitsResponse {
+    /// The identifier of the business profile
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The velocity limits enforced on the payments of the business profile
+    pub rules: Vec<VelocityLimitRule>,
+
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for VelocityLimitsResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The velocity limits of a business profile, as persisted in the configs
+pub type VelocityLimitsRecord = VelocityLimitsResponse;
@@ -9,3 +9,39 @@ pub struct VerifyConnectorRequest {
 }
 
 common_utils::impl_misc_api_event_type!(VerifyConnectorRequest);
+
+/// The outcome of the validation of the credentials of a merchant connector account
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorValidationStatus {
+    /// The connector accepted the credentials
+    Valid,
+    /// The connector rejected the credentials
+    Invalid,
+    /// The credentials of the connector cannot be validated with a live call
+    Unsupported,
+}
+
+#[derive(Clone, Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct MerchantConnectorValidationResponse {
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+
+    #[schema(value_type = ConnectorValidationStatus, example = "invalid")]
+    pub status: ConnectorValidationStatus,
+
+    /// The error returned by the connector when the credentials were rejected
+    #[schema(example = "Invalid API Key provided: sk_test_****1234")]
+    pub error_message: Option<String>,
+
+    /// What to do to get the credentials accepted by the connector
+    #[schema(
+        example = "Check the API keys in the dashboard of the connector and update the connector_account_details of the merchant connector account"
+    )]
+    pub suggested_action: Option<String>,
+}
+
+common_utils::impl_misc_api_event_type!(MerchantConnectorValidationResponse);
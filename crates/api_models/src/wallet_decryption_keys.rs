@@ -0,0 +1,149 @@
+use common_utils::events;
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The wallet whose tokens are decrypted with a key
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WalletDecryptionKeyWallet {
+    ApplePay,
+    GooglePay,
+}
+
+/// The key with which the wallet tokens are decrypted, along with the wallet it belongs to
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "wallet", rename_all = "snake_case")]
+pub enum WalletDecryptionKeyDetails {
+    ApplePay {
+        /// The base64 encoded DER of the payment processing certificate issued by Apple
+        #[schema(value_type = String)]
+        payment_processing_certificate: Secret<String>,
+
+        /// The PEM encoded private key of the payment processing certificate
+        #[schema(value_type = String)]
+        payment_processing_certificate_key: Secret<String>,
+    },
+    GooglePay {
+        /// The recipient ID the tokens are encrypted for, `merchant:<merchant_id>` for merchants
+        /// decrypting the tokens themselves
+        #[schema(example = "merchant:12345678901234567890")]
+        recipient_id: String,
+
+        /// The PEM encoded private key whose public key was registered with Google Pay
+        #[schema(value_type = String)]
+        private_key: Secret<String>,
+    },
+}
+
+impl WalletDecryptionKeyDetails {
+    pub fn get_wallet(&self) -> WalletDecryptionKeyWallet {
+        match self {
+            Self::ApplePay { .. } => WalletDecryptionKeyWallet::ApplePay,
+            Self::GooglePay { .. } => WalletDecryptionKeyWallet::GooglePay,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct WalletDecryptionKeyCreateRequest {
+    /// The key to decrypt the wallet tokens of the payments of the business profile with
+    #[serde(flatten)]
+    pub key_details: WalletDecryptionKeyDetails,
+}
+
+impl events::ApiEventMetric for WalletDecryptionKeyCreateRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WalletDecryptionKeyRetireRequest {
+    /// Duration in seconds during which the retired key keeps decrypting the wallet tokens, so
+    /// that the tokens encrypted before the key was replaced can still be processed. The key is
+    /// retired immediately when not set.
+    #[schema(example = 86400)]
+    pub grace_period_in_secs: Option<u32>,
+}
+
+impl events::ApiEventMetric for WalletDecryptionKeyRetireRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// Whether the key is used to decrypt the wallet tokens
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletDecryptionKeyStatus {
+    /// The key decrypts the wallet tokens
+    Active,
+    /// The key was retired, but decrypts the wallet tokens until the end of its grace period
+    Retiring,
+    /// The key no longer decrypts the wallet tokens
+    Retired,
+}
+
+/// A key registered on the business profile. The certificate and the private key are never
+/// returned.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct WalletDecryptionKeyResponse {
+    /// The identifier of the key
+    #[schema(example = "wdk_abcdefghijklmnopqrstuvwxyz")]
+    pub key_id: String,
+
+    /// The identifier of the business profile
+    #[schema(example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    #[schema(value_type = WalletDecryptionKeyWallet)]
+    pub wallet: WalletDecryptionKeyWallet,
+
+    /// The identifier the wallet tokens refer to the key with: the base64 encoded hash of the
+    /// public key of the certificate for Apple Pay, the recipient ID for Google Pay
+    pub key_identifier: String,
+
+    #[schema(value_type = WalletDecryptionKeyStatus)]
+    pub status: WalletDecryptionKeyStatus,
+
+    /// The time after which the key no longer decrypts the wallet tokens, once retired
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl events::ApiEventMetric for WalletDecryptionKeyResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct WalletDecryptionKeyListResponse {
+    /// The keys registered on the business profile, including the retired ones
+    pub keys: Vec<WalletDecryptionKeyResponse>,
+}
+
+impl events::ApiEventMetric for WalletDecryptionKeyListResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
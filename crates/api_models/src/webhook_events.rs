@@ -142,6 +142,77 @@ pub struct OutgoingWebhookResponseContent {
     pub error_message: Option<String>,
 }
 
+/// The constraints to apply when filtering the incoming webhooks received.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IncomingWebhookListConstraints {
+    /// Filter webhooks received after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter webhooks received before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of webhooks.
+    pub limit: Option<u16>,
+
+    /// Include webhooks after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The response body for each item when listing the incoming webhooks received.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookListItemResponse {
+    /// The identifier for the archived incoming webhook.
+    #[schema(max_length = 64, example = "whar_0190fb4a3bd27c13b8d1a2f0c3e4d5f6")]
+    pub archive_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the Business Profile of the connector account the webhook was received
+    /// for.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: Option<String>,
+
+    /// The connector which sent the webhook.
+    #[schema(max_length = 64, example = "stripe")]
+    pub connector_name: String,
+
+    /// The identifier for the connector account the webhook was received for.
+    #[schema(max_length = 64, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<String>,
+
+    /// The type of the event notified by the connector, if it could be identified.
+    #[schema(max_length = 64, example = "payment_intent_success")]
+    pub event_type: Option<String>,
+
+    /// Time at which the webhook was received.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for retrieving an incoming webhook received.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookRetrieveResponse {
+    #[serde(flatten)]
+    pub webhook_information: IncomingWebhookListItemResponse,
+
+    /// The body of the webhook as it was received.
+    #[schema(value_type = String)]
+    pub body: Secret<String>,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.webhook_information.merchant_id.clone(),
+        })
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct EventListRequestInternal {
     pub merchant_id_or_profile_id: String,
@@ -183,3 +254,31 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct IncomingWebhookListRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub constraints: IncomingWebhookListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct IncomingWebhookRetrieveRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub archive_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for IncomingWebhookRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{customers, disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -238,6 +238,8 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(payouts::PayoutCreateResponse),
+    #[schema(value_type = CustomerRedactionResponse, title = "CustomerRedactionResponse")]
+    CustomerRedactionDetails(Box<customers::CustomerRedactionResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
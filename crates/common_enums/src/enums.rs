@@ -131,6 +131,7 @@ pub enum RoutableConnectors {
     // Datatrans,
     Dlocal,
     Ebanx,
+    Externaladapter,
     Fiserv,
     Forte,
     Globalpay,
@@ -306,6 +307,35 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
+    CustomerId,
+    IpAddress,
+}
+
+/// The list to which a blocklist entry belongs
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BlocklistListType {
+    /// Payments matching the entry are declined
+    #[default]
+    Block,
+    /// Payments matching the entry bypass the block list
+    Allow,
 }
 
 #[derive(
@@ -547,10 +577,12 @@ pub enum Currency {
 }
 
 impl Currency {
-    /// Convert the amount to its base denomination based on Currency and return String
+    /// Convert the amount to its base denomination based on Currency and return String. The
+    /// amounts of three decimal currencies keep their third decimal.
     pub fn to_currency_base_unit(&self, amount: i64) -> Result<String, TryFromIntError> {
         let amount_f64 = self.to_currency_base_unit_asf64(amount)?;
-        Ok(format!("{amount_f64:.2}"))
+        let precision = usize::from(self.number_of_digits_after_decimal_point().max(2));
+        Ok(format!("{amount_f64:.precision$}"))
     }
 
     /// Convert the amount to its base denomination based on Currency and return f64
@@ -566,7 +598,8 @@ impl Currency {
         Ok(amount)
     }
 
-    ///Convert the higher decimal amount to its base absolute units
+    ///Convert the higher decimal amount to its base absolute units. The amount is rounded to the
+    ///nearest unit, as the floating point multiplication can be off by a fraction of it.
     pub fn to_currency_lower_unit(&self, amount: String) -> Result<String, ParseFloatError> {
         let amount_f64 = amount.parse::<f64>()?;
         let amount_string = if self.is_zero_decimal_currency() {
@@ -576,7 +609,7 @@ impl Currency {
         } else {
             amount_f64 * 100.00
         };
-        Ok(amount_string.to_string())
+        Ok(amount_string.round().to_string())
     }
 
     /// Convert the amount to its base denomination based on Currency and check for zero decimal currency and return String
@@ -1073,6 +1106,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Customers,
 }
 
 #[derive(
@@ -1080,6 +1114,7 @@ pub enum EventClass {
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
@@ -1102,6 +1137,9 @@ pub enum EventType {
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
+    /// The acquirer reference number, the estimated arrival time or the failure reason of a
+    /// refund was updated
+    RefundUpdated,
     DisputeOpened,
     DisputeExpired,
     DisputeAccepted,
@@ -1111,6 +1149,8 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    /// Customer has to be notified of an upcoming direct debit on the mandate
+    MandatePreNotification,
     PayoutSuccess,
     PayoutFailed,
     PayoutInitiated,
@@ -1118,6 +1158,8 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    /// The personal data of a customer was redacted
+    CustomerRedacted,
 }
 
 #[derive(
@@ -1573,6 +1615,27 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// How the payments made with a card are funded, as found in the BIN data of the card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardFundingType {
+    Credit,
+    Debit,
+    Prepaid,
+}
+
 /// Indicates the card network.
 #[derive(
     Clone,
@@ -2256,13 +2319,15 @@ pub enum PaymentSource {
     Sdk,
     Webhook,
     ExternalAuthenticator,
+    /// The payment confirmed in the background, as requested with the `async` processing mode
+    AsyncProcessing,
 }
 
 impl PaymentSource {
     pub fn is_for_internal_use_only(&self) -> bool {
         match self {
             Self::Dashboard | Self::Sdk | Self::MerchantServer | Self::Postman => false,
-            Self::Webhook | Self::ExternalAuthenticator => true,
+            Self::Webhook | Self::ExternalAuthenticator | Self::AsyncProcessing => true,
         }
     }
 }
@@ -2309,6 +2374,34 @@ pub enum FrmSuggestion {
     FrmAuthorizeTransaction, // When manual capture payment which was marked fraud and held, when approved needs to be authorized.
 }
 
+/// The action taken on a payment before authorization, as decided by the FRM decision matrix of the business profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmDecisionAction {
+    /// Proceed with the authorization of the payment
+    Allow,
+    /// Proceed with the authorization of the payment, after challenging the customer with 3DS
+    ThreeDsChallenge,
+    /// Hold the payment before authorization, for the merchant to review it
+    ManualReview,
+    /// Fail the payment without authorizing it
+    Block,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -2332,6 +2425,296 @@ pub enum ReconStatus {
     Disabled,
 }
 
+/// The resources exported by a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportType {
+    /// One row per payment created within the period of the report
+    Payments,
+    /// One row per refund created within the period of the report
+    Refunds,
+    /// One row per payment captured within the period of the report
+    Settlements,
+}
+
+/// The outcome of the last generation of a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportRunStatus {
+    Succeeded,
+    Failed,
+}
+
+/// The resource a line of a connector settlement file settles
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementLineType {
+    Payment,
+    Refund,
+    Dispute,
+}
+
+/// The outcome of matching a settlement line to a payment, refund or dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementReconStatus {
+    /// The line matches the amount and currency of the resource
+    Matched,
+    /// The settled amount differs from the amount of the resource
+    AmountMismatch,
+    /// The settled currency differs from the currency of the resource
+    CurrencyMismatch,
+    /// The resource is not in a state in which it is expected to be settled
+    StatusMismatch,
+    /// The net amount of the line is not its gross amount adjusted by its fee
+    FeeMismatch,
+    /// No resource was found for the reference of the line
+    Unmatched,
+    /// The reference of the line was already settled by another line
+    Duplicate,
+    /// The line was marked as resolved by the merchant
+    ManuallyResolved,
+}
+
+impl SettlementReconStatus {
+    /// Whether the line needs to be looked into by the merchant
+    pub fn is_exception(self) -> bool {
+        !matches!(self, Self::Matched | Self::ManuallyResolved)
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+/// The accounts of the ledger of a merchant, kept per merchant connector account
+pub enum LedgerAccount {
+    /// Funds authorized on the payment methods of the customers, not captured yet
+    AuthorizedFunds,
+    /// Counterpart of the authorized funds, released once the authorizations are captured or voided
+    AuthorizationHolds,
+    /// Funds owed to the merchant by the connector
+    ConnectorReceivable,
+    /// Captured amounts of the payments
+    Sales,
+    /// Amounts refunded to the customers
+    Refunds,
+    /// Amounts of the disputes lost by the merchant
+    Chargebacks,
+    /// Fees charged by the connector
+    ProcessingFees,
+    /// Amounts paid out by the merchant
+    Payouts,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerEventType {
+    Authorization,
+    AuthorizationRelease,
+    Capture,
+    Refund,
+    Dispute,
+    Fee,
+    Payout,
+}
+
+/// The status of an export of the cards vaulted for a merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardExportStatus {
+    /// The export was requested and awaits the approval of another user
+    PendingApproval,
+    /// The export was rejected by the reviewer, no card was exported
+    Rejected,
+    /// The export was approved and the cards are being exported
+    Processing,
+    /// The encrypted export file is available for download
+    Completed,
+    /// The export could not be generated
+    Failed,
+}
+
+/// The status of a migration of the stored credentials of the payment methods of a merchant from a
+/// connector to another
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorMigrationStatus {
+    /// The payment methods are being migrated
+    Processing,
+    /// Every payment method was processed, the outcome of each being recorded
+    Completed,
+    /// The migration was cancelled by the merchant, the payment methods processed until then
+    /// remaining migrated
+    Cancelled,
+    /// The migration could not be carried out
+    Failed,
+}
+
+/// The outcome of the migration of the stored credentials of a payment method
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorMigrationPaymentMethodStatus {
+    /// The payment method can be used for merchant initiated transactions through the target
+    /// connector
+    Migrated,
+    /// The payment method could already be used through the target connector
+    Skipped,
+    /// The payment method could not be migrated
+    Failed,
+}
+
+/// How the stored credentials of a payment method are made usable through the target connector of
+/// a migration
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CredentialMigrationMethod {
+    /// The network transaction id of the payment method is used for the merchant initiated
+    /// transactions, which requires connector agnostic MITs to be enabled on the business profile
+    NetworkTransactionId,
+    /// The card details are retrieved from the vault and a zero amount mandate is set up with the
+    /// target connector, which returns a token of its own
+    CardDetails,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -2393,6 +2776,36 @@ pub enum DecoupledAuthenticationType {
     Frictionless,
 }
 
+/// The preference of the merchant for a challenge of the cardholder, sent to the issuer during
+/// the 3DS authentication. The issuer makes the final decision on whether to challenge.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ThreeDsChallengePreference {
+    /// Leave the decision to the issuer
+    #[default]
+    NoPreference,
+    /// Prefer a frictionless authentication
+    NoChallengeRequested,
+    /// Prefer a challenge of the cardholder
+    ChallengeRequested,
+    /// A challenge of the cardholder is mandated, for instance by regulation
+    ChallengeMandated,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -2551,6 +2964,10 @@ pub enum PermissionGroup {
     MerchantDetailsView,
     MerchantDetailsManage,
     OrganizationManage,
+    PaymentsView,
+    RoutingView,
+    RoutingManage,
+    PayoutsApprove,
 }
 
 /// Name of banks supported by Hyperswitch
@@ -2840,3 +3257,107 @@ pub enum Owner {
     Tenant,
     Internal,
 }
+
+/// The actor that moved a payment to a status
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentStatusActor {
+    /// A request made to the API
+    #[default]
+    Api,
+    /// A webhook received from the connector
+    Webhook,
+    /// A sync of the payment with the connector requested through the API
+    Sync,
+    /// A task run by the scheduler
+    Scheduler,
+}
+
+/// The object of a payment whose status transitioned
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentStatusObject {
+    PaymentIntent,
+    PaymentAttempt,
+}
+
+/// The kind of work done by a job run in the background
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum JobType {
+    /// Refunds of many payments requested at once
+    BulkRefund,
+}
+
+/// The status of a job run in the background
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum JobStatus {
+    /// The job is waiting to be picked by a worker
+    Queued,
+    /// The job is being run, or waiting to be retried after a failed attempt
+    Running,
+    /// Every item of the job was processed
+    Succeeded,
+    /// The job failed on each of its attempts
+    Failed,
+    /// The job was cancelled before it was completed
+    Cancelled,
+}
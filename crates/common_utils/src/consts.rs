@@ -46,9 +46,18 @@ pub fn default_payouts_list_limit() -> u32 {
 /// surcharge percentage maximum precision length
 pub const SURCHARGE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
 
+/// platform fee percentage maximum precision length
+pub const PLATFORM_FEE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
+
+/// tax rate maximum precision length
+pub const TAX_RATE_PRECISION_LENGTH: u8 = 4;
+
 /// Header Key for application overhead of a request
 pub const X_HS_LATENCY: &str = "x-hs-latency";
 
+/// Header Key for the version of the schema of the API responses
+pub const API_VERSION_HEADER: &str = "x-api-version";
+
 /// Default Payment Link Background color
 pub const DEFAULT_BACKGROUND_COLOR: &str = "#212E46";
 
@@ -239,9 +239,54 @@ impl VerifySignature for HmacSha512 {
 }
 
 /// Represents the GCM-AES-256 algorithm
+///
+/// The secret can also be a key ring, several 256-bit keys concatenated: messages are then
+/// encoded with the first key of the ring, and decoded with whichever key of the ring they were
+/// encoded with. This lets data encoded with a key being rotated out be read while it is
+/// re-encoded with the new key.
 #[derive(Debug)]
 pub struct GcmAes256;
 
+impl GcmAes256 {
+    /// The length of a key in bytes
+    pub const KEY_LENGTH: usize = 32;
+
+    /// The keys of the key ring, or the secret itself when it is a single key
+    fn get_keys(secret: &[u8]) -> Vec<&[u8]> {
+        if secret.len() > Self::KEY_LENGTH && secret.len() % Self::KEY_LENGTH == 0 {
+            secret.chunks(Self::KEY_LENGTH).collect()
+        } else {
+            vec![secret]
+        }
+    }
+
+    fn decode_message_with_key(
+        key: &[u8],
+        mut msg: Vec<u8>,
+    ) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let key = UnboundKey::new(&aead::AES_256_GCM, key)
+            .change_context(errors::CryptoError::DecodingFailed)?;
+
+        let nonce_sequence = NonceSequence::from_bytes(
+            <[u8; aead::NONCE_LEN]>::try_from(
+                msg.get(..aead::NONCE_LEN)
+                    .ok_or(errors::CryptoError::DecodingFailed)
+                    .attach_printable("Failed to read the nonce form the encrypted ciphertext")?,
+            )
+            .change_context(errors::CryptoError::DecodingFailed)?,
+        );
+
+        let mut key = OpeningKey::new(key, nonce_sequence);
+        let output = msg.as_mut_slice();
+
+        let result = key
+            .open_within(aead::Aad::empty(), output, aead::NONCE_LEN..)
+            .change_context(errors::CryptoError::DecodingFailed)?;
+
+        Ok(result.to_vec())
+    }
+}
+
 impl EncodeMessage for GcmAes256 {
     fn encode_message(
         &self,
@@ -251,6 +296,7 @@ impl EncodeMessage for GcmAes256 {
         let nonce_sequence =
             NonceSequence::new().change_context(errors::CryptoError::EncodingFailed)?;
         let current_nonce = nonce_sequence.current();
+        let secret = Self::get_keys(secret).first().copied().unwrap_or(secret);
         let key = UnboundKey::new(&aead::AES_256_GCM, secret)
             .change_context(errors::CryptoError::EncodingFailed)?;
         let mut key = SealingKey::new(key, nonce_sequence);
@@ -271,27 +317,20 @@ impl DecodeMessage for GcmAes256 {
         msg: Secret<Vec<u8>, EncryptionStrategy>,
     ) -> CustomResult<Vec<u8>, errors::CryptoError> {
         let msg = msg.expose();
-        let key = UnboundKey::new(&aead::AES_256_GCM, secret)
-            .change_context(errors::CryptoError::DecodingFailed)?;
-
-        let nonce_sequence = NonceSequence::from_bytes(
-            <[u8; aead::NONCE_LEN]>::try_from(
-                msg.get(..aead::NONCE_LEN)
-                    .ok_or(errors::CryptoError::DecodingFailed)
-                    .attach_printable("Failed to read the nonce form the encrypted ciphertext")?,
-            )
-            .change_context(errors::CryptoError::DecodingFailed)?,
+        let mut keys = Self::get_keys(secret).into_iter();
+        let mut result = Self::decode_message_with_key(
+            keys.next().ok_or(errors::CryptoError::DecodingFailed)?,
+            msg.clone(),
         );
 
-        let mut key = OpeningKey::new(key, nonce_sequence);
-        let mut binding = msg;
-        let output = binding.as_mut_slice();
-
-        let result = key
-            .open_within(aead::Aad::empty(), output, aead::NONCE_LEN..)
-            .change_context(errors::CryptoError::DecodingFailed)?;
+        for key in keys {
+            if result.is_ok() {
+                break;
+            }
+            result = Self::decode_message_with_key(key, msg.clone());
+        }
 
-        Ok(result.to_vec())
+        result
     }
 }
 
@@ -653,6 +692,42 @@ mod crypto_tests {
         assert!(err_decoded.is_err());
     }
 
+    #[test]
+    fn test_gcm_aes_256_key_ring() {
+        let message = r#"{"type":"PAYMENT"}"#.as_bytes();
+        let old_key =
+            hex::decode("000102030405060708090a0b0c0d0e0f000102030405060708090a0b0c0d0e0f")
+                .expect("Secret decoding");
+        let new_key =
+            hex::decode("feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308")
+                .expect("Secret decoding");
+        let key_ring = [new_key.as_slice(), old_key.as_slice()].concat();
+        let algorithm = super::GcmAes256;
+
+        let encoded_with_old_key = algorithm
+            .encode_message(&old_key, message)
+            .expect("Encoded message and tag");
+        assert_eq!(
+            algorithm
+                .decode_message(&key_ring, encoded_with_old_key.into())
+                .expect("Decode Failed"),
+            message
+        );
+
+        let encoded_with_key_ring = algorithm
+            .encode_message(&key_ring, message)
+            .expect("Encoded message and tag");
+        assert_eq!(
+            algorithm
+                .decode_message(&new_key, encoded_with_key_ring.clone().into())
+                .expect("Decode Failed"),
+            message
+        );
+        assert!(algorithm
+            .decode_message(&old_key, encoded_with_key_ring.into())
+            .is_err());
+    }
+
     #[test]
     fn test_md5_digest() {
         let message = "abcdefghijklmnopqrstuvwxyz".as_bytes();
@@ -50,6 +50,9 @@ pub enum ParsingError {
     /// Failed to parse String value to Decimal value conversion because `error`
     #[error("Failed to parse String value to Decimal value conversion because {error}")]
     StringToDecimalConversionFailure { error: String },
+    /// Failed to convert the major unit `amount` to the minor unit without losing precision
+    #[error("Failed to convert {amount} to the minor unit without losing precision")]
+    MinorUnitPrecisionLoss { amount: String },
 }
 
 /// Validation errors.
@@ -111,6 +114,31 @@ pub enum PercentageError {
     },
 }
 
+/// Errors of the arithmetic on amounts of money
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
+pub enum MoneyError {
+    /// The amounts are not in the same currency
+    #[error("Amount in {found} cannot be combined with an amount in {expected}")]
+    CurrencyMismatch {
+        /// currency of the amount combined with
+        expected: common_enums::Currency,
+        /// currency of the amount combined
+        found: common_enums::Currency,
+    },
+
+    /// The result does not fit in an amount
+    #[error("Amount overflowed")]
+    Overflow,
+
+    /// The result is a negative amount, such as when more is captured or refunded than was
+    /// authorized or captured
+    #[error("Amount of {amount} is negative")]
+    NegativeAmount {
+        /// amount value
+        amount: MinorUnit,
+    },
+}
+
 /// Allows [error_stack::Report] to change between error contexts
 /// using the dependent [ErrorSwitch] trait to define relations & mappings between traits
 pub trait ReportSwitchExt<T, U> {
@@ -65,6 +65,12 @@ pub enum ApiEventsType {
     Poll {
         poll_id: String,
     },
+    Order {
+        order_id: String,
+    },
+    Authentication {
+        authentication_id: String,
+    },
 }
 
 impl ApiEventMetric for serde_json::Value {}
@@ -21,6 +21,7 @@ pub mod fp_utils;
 pub mod id_type;
 pub mod link_utils;
 pub mod macros;
+pub mod money;
 pub mod pii;
 #[allow(missing_docs)] // Todo: add docs
 pub mod request;
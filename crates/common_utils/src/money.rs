@@ -0,0 +1,175 @@
+//! Amounts of money in a currency, with arithmetic that fails rather than producing a wrong
+//! amount. The bookkeeping of the amounts of a payment, such as the amount capturable left after a
+//! partial capture or the amount refundable left after a refund, goes through these types so that
+//! an amount in another currency, an overflow or an amount going below zero is caught where it
+//! happens.
+
+use common_enums::Currency;
+use error_stack::report;
+
+use crate::{
+    errors::{CustomResult, MoneyError},
+    fp_utils::when,
+    types::MinorUnit,
+};
+
+/// An amount in the minor unit of its currency
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Money {
+    amount: MinorUnit,
+    currency: Currency,
+}
+
+impl Money {
+    /// forms an amount of money in the currency
+    pub fn new(amount: MinorUnit, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// forms a zero amount in the currency
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(MinorUnit::new(0), currency)
+    }
+
+    /// gets the amount in the minor unit of the currency
+    pub fn get_amount(&self) -> MinorUnit {
+        self.amount
+    }
+
+    /// gets the currency of the amount
+    pub fn get_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn ensure_same_currency(&self, other: &Self) -> CustomResult<(), MoneyError> {
+        when(self.currency != other.currency, || {
+            Err(report!(MoneyError::CurrencyMismatch {
+                expected: self.currency,
+                found: other.currency,
+            }))
+        })
+    }
+
+    /// adds the amounts, which must be in the same currency
+    pub fn checked_add(self, other: Self) -> CustomResult<Self, MoneyError> {
+        self.ensure_same_currency(&other)?;
+        self.checked_add_amount(other.amount)
+    }
+
+    /// adds an amount known to be in the currency of this amount, such as the surcharge of a
+    /// payment attempt to the amount of the attempt
+    pub fn checked_add_amount(self, amount: MinorUnit) -> CustomResult<Self, MoneyError> {
+        self.amount
+            .checked_add(amount)
+            .map(|amount| Self::new(amount, self.currency))
+            .ok_or_else(|| report!(MoneyError::Overflow))
+    }
+
+    /// subtracts the amounts, which must be in the same currency, the result not being allowed to
+    /// go below zero
+    pub fn checked_sub(self, other: Self) -> CustomResult<Self, MoneyError> {
+        self.ensure_same_currency(&other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or_else(|| report!(MoneyError::Overflow))?;
+        when(amount.is_negative(), || {
+            Err(report!(MoneyError::NegativeAmount { amount }))
+        })?;
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// sums the amounts, which must all be in the currency
+    pub fn try_sum(
+        currency: Currency,
+        amounts: impl IntoIterator<Item = Self>,
+    ) -> CustomResult<Self, MoneyError> {
+        amounts
+            .into_iter()
+            .try_fold(Self::zero(currency), Self::checked_add)
+    }
+}
+
+/// The net amount of a payment attempt, being the amount of the attempt with the surcharge and the
+/// tax on the surcharge added
+pub fn calculate_net_amount(
+    amount: MinorUnit,
+    surcharge_amount: Option<MinorUnit>,
+    tax_on_surcharge_amount: Option<MinorUnit>,
+) -> CustomResult<MinorUnit, MoneyError> {
+    [surcharge_amount, tax_on_surcharge_amount]
+        .into_iter()
+        .flatten()
+        .try_fold(amount, |net_amount, amount| {
+            net_amount
+                .checked_add(amount)
+                .ok_or_else(|| report!(MoneyError::Overflow))
+        })
+}
+
+#[cfg(test)]
+mod money_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn usd(amount: i64) -> Money {
+        Money::new(MinorUnit::new(amount), Currency::USD)
+    }
+
+    #[test]
+    fn money_arithmetic_in_same_currency() {
+        assert_eq!(usd(1000).checked_add(usd(250)).unwrap(), usd(1250));
+        assert_eq!(usd(1000).checked_sub(usd(1000)).unwrap(), usd(0));
+        assert_eq!(
+            Money::try_sum(Currency::USD, [usd(100), usd(200), usd(300)]).unwrap(),
+            usd(600)
+        );
+    }
+
+    #[test]
+    fn money_arithmetic_rejects_other_currency() {
+        let eur = Money::new(MinorUnit::new(100), Currency::EUR);
+        let error = usd(1000).checked_add(eur).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &MoneyError::CurrencyMismatch {
+                expected: Currency::USD,
+                found: Currency::EUR,
+            }
+        );
+        assert!(Money::try_sum(Currency::USD, [usd(100), eur]).is_err());
+    }
+
+    #[test]
+    fn money_arithmetic_rejects_negative_and_overflowing_amounts() {
+        let error = usd(500).checked_sub(usd(600)).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &MoneyError::NegativeAmount {
+                amount: MinorUnit::new(-100)
+            }
+        );
+        let error = usd(i64::MAX).checked_add(usd(1)).unwrap_err();
+        assert_eq!(error.current_context(), &MoneyError::Overflow);
+    }
+
+    #[test]
+    fn net_amount_includes_surcharge_and_tax() {
+        assert_eq!(
+            calculate_net_amount(
+                MinorUnit::new(1000),
+                Some(MinorUnit::new(30)),
+                Some(MinorUnit::new(3))
+            )
+            .unwrap(),
+            MinorUnit::new(1033)
+        );
+        assert_eq!(
+            calculate_net_amount(MinorUnit::new(1000), None, None).unwrap(),
+            MinorUnit::new(1000)
+        );
+        assert!(
+            calculate_net_amount(MinorUnit::new(i64::MAX), Some(MinorUnit::new(1)), None).is_err()
+        );
+    }
+}
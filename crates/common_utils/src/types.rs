@@ -348,6 +348,21 @@ impl MinorUnit {
         Self(value)
     }
 
+    /// adds the amounts, returning `None` on an overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// subtracts the amounts, returning `None` on an overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// checks whether the amount is below zero
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
     /// Convert the amount to its major denomination based on Currency and return String
     /// Paypal Connector accepts Zero and Two decimal currency but not three decimal and it should be updated as required for 3 decimal currencies.
     /// Paypal Ref - https://developer.paypal.com/docs/reports/reference/paypal-supported-currencies/
@@ -538,6 +553,12 @@ impl StringMajorUnit {
         } else {
             amount_decimal * Decimal::from(100)
         };
+        if !amount.fract().is_zero() {
+            return Err(ParsingError::MinorUnitPrecisionLoss {
+                amount: self.0.clone(),
+            }
+            .into());
+        }
         let amount_i64 = amount
             .to_i64()
             .ok_or(ParsingError::DecimalToI64ConversionFailure)?;
@@ -649,6 +670,31 @@ mod amount_conversion_tests {
             .unwrap();
         assert_eq!(converted_back_amount, request_amount);
     }
+
+    #[test]
+    fn amount_conversion_from_string_major_unit_with_excess_precision() {
+        let required_conversion = StringMajorUnitForConnector;
+
+        let converted_back_amount = required_conversion.convert_back(
+            StringMajorUnit::new("10.005".to_string()),
+            TWO_DECIMAL_CURRENCY,
+        );
+        assert!(converted_back_amount.is_err());
+
+        let converted_back_amount = required_conversion.convert_back(
+            StringMajorUnit::new("10.5".to_string()),
+            ZERO_DECIMAL_CURRENCY,
+        );
+        assert!(converted_back_amount.is_err());
+
+        let converted_back_amount = required_conversion
+            .convert_back(
+                StringMajorUnit::new("10.500".to_string()),
+                TWO_DECIMAL_CURRENCY,
+            )
+            .unwrap();
+        assert_eq!(converted_back_amount, MinorUnit::new(1050));
+    }
 }
 
 // Charges structs
@@ -102,6 +102,7 @@ pub struct ApiModelMetaData {
     pub source_balance_account: Option<String>,
     pub brand_id: Option<String>,
     pub destination_account_number: Option<String>,
+    pub base_url: Option<String>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -219,4 +220,5 @@ pub struct DashboardMetaData {
     pub source_balance_account: Option<String>,
     pub brand_id: Option<String>,
     pub destination_account_number: Option<String>,
+    pub base_url: Option<String>,
 }
@@ -105,6 +105,7 @@ pub struct ConfigMetadata {
     pub source_balance_account: Option<String>,
     pub brand_id: Option<String>,
     pub destination_account_number: Option<String>,
+    pub base_url: Option<String>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -157,6 +158,7 @@ pub struct ConnectorConfig {
     pub bambora: Option<ConnectorTomlConfig>,
     pub dlocal: Option<ConnectorTomlConfig>,
     pub ebanx_payout: Option<ConnectorTomlConfig>,
+    pub externaladapter: Option<ConnectorTomlConfig>,
     pub fiserv: Option<ConnectorTomlConfig>,
     pub forte: Option<ConnectorTomlConfig>,
     pub globalpay: Option<ConnectorTomlConfig>,
@@ -289,6 +291,7 @@ impl ConnectorConfig {
             Connector::Bambora => Ok(connector_data.bambora),
             Connector::Dlocal => Ok(connector_data.dlocal),
             Connector::Ebanx => Ok(connector_data.ebanx_payout),
+            Connector::Externaladapter => Ok(connector_data.externaladapter),
             Connector::Fiserv => Ok(connector_data.fiserv),
             Connector::Forte => Ok(connector_data.forte),
             Connector::Globalpay => Ok(connector_data.globalpay),
@@ -359,6 +359,7 @@ impl From<ApiModelMetaData> for DashboardMetaData {
             source_balance_account: api_model.source_balance_account,
             brand_id: api_model.brand_id,
             destination_account_number: api_model.destination_account_number,
+            base_url: api_model.base_url,
         }
     }
 }
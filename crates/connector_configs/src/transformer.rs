@@ -205,6 +205,7 @@ impl DashboardRequestPayload {
             source_balance_account: None,
             brand_id: None,
             destination_account_number: None,
+            base_url: None,
         };
         let meta_data = match request.metadata {
             Some(data) => data,
@@ -234,6 +235,7 @@ impl DashboardRequestPayload {
         let source_balance_account = meta_data.source_balance_account;
         let brand_id = meta_data.brand_id;
         let destination_account_number = meta_data.destination_account_number;
+        let base_url = meta_data.base_url;
 
         Some(ApiModelMetaData {
             google_pay,
@@ -259,6 +261,7 @@ impl DashboardRequestPayload {
             source_balance_account,
             brand_id,
             destination_account_number,
+            base_url,
         })
     }
 
@@ -89,6 +89,7 @@ impl AddressUpdateInternal {
             country_code: self.country_code,
             modified_at: self.modified_at,
             updated_by: self.updated_by,
+            email: self.email,
             ..source
         }
     }
@@ -16,6 +16,8 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 #[derive(Debug, Insertable)]
@@ -30,6 +32,8 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -39,6 +43,8 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        scopes: Option<Option<Vec<String>>>,
+        allowed_ips: Option<Option<Vec<String>>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -52,6 +58,8 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Option<Vec<String>>>,
+    pub allowed_ips: Option<Option<Vec<String>>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -62,17 +70,23 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                allowed_ips,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                allowed_ips,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                scopes: None,
+                allowed_ips: None,
             },
         }
     }
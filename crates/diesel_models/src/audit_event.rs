@@ -0,0 +1,53 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::audit_event;
+
+/// A mutation of the configuration of a merchant made through the admin plane. Audit events are
+/// append-only, the table rejecting any update or deletion of the recorded events.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = audit_event, primary_key(event_id))]
+pub struct AuditEvent {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub actor_type: String,
+    /// The API key or the user that made the mutation, if any
+    pub actor_id: Option<String>,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub action: String,
+    /// The fields of the resource that changed, along with their values before and after the
+    /// mutation
+    pub diff: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_event)]
+pub struct AuditEventNew {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub actor_type: String,
+    pub actor_id: Option<String>,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub action: String,
+    pub diff: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The constraints the audit events are filtered by, the events being listed from the latest
+#[derive(Clone, Debug, Default)]
+pub struct AuditEventConstraints {
+    pub merchant_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub resource_type: Option<String>,
+    pub resource_id: Option<String>,
+    pub action: Option<String>,
+    pub created_after: Option<PrimitiveDateTime>,
+    pub created_before: Option<PrimitiveDateTime>,
+    pub limit: i64,
+    pub offset: Option<i64>,
+}
@@ -62,7 +62,7 @@ pub struct AuthenticationNew {
     pub merchant_id: String,
     pub authentication_connector: String,
     pub connector_authentication_id: Option<String>,
-    // pub authentication_data: Option<serde_json::Value>,
+    pub authentication_data: Option<serde_json::Value>,
     pub payment_method_id: String,
     pub authentication_type: Option<common_enums::DecoupledAuthenticationType>,
     pub authentication_status: common_enums::AuthenticationStatus,
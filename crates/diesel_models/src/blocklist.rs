@@ -11,6 +11,7 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub list_type: common_enums::BlocklistListType,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
@@ -23,4 +24,5 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub list_type: common_enums::BlocklistListType,
 }
@@ -41,6 +41,14 @@ pub struct BusinessProfile {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub frm_decision_matrix: Option<serde_json::Value>,
+    pub frm_post_auth_review: Option<serde_json::Value>,
+    pub connector_debug_capture: Option<serde_json::Value>,
+    pub connector_failover_policy: Option<serde_json::Value>,
+    pub vault_provider: Option<serde_json::Value>,
+    pub tax_provider: Option<serde_json::Value>,
+    pub dispute_auto_response: Option<serde_json::Value>,
+    pub custom_fields_schema: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -73,6 +81,14 @@ pub struct BusinessProfileNew {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub frm_decision_matrix: Option<serde_json::Value>,
+    pub frm_post_auth_review: Option<serde_json::Value>,
+    pub connector_debug_capture: Option<serde_json::Value>,
+    pub connector_failover_policy: Option<serde_json::Value>,
+    pub vault_provider: Option<serde_json::Value>,
+    pub tax_provider: Option<serde_json::Value>,
+    pub dispute_auto_response: Option<serde_json::Value>,
+    pub custom_fields_schema: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -102,6 +118,14 @@ pub struct BusinessProfileUpdateInternal {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub frm_decision_matrix: Option<serde_json::Value>,
+    pub frm_post_auth_review: Option<serde_json::Value>,
+    pub connector_debug_capture: Option<serde_json::Value>,
+    pub connector_failover_policy: Option<serde_json::Value>,
+    pub vault_provider: Option<serde_json::Value>,
+    pub tax_provider: Option<serde_json::Value>,
+    pub dispute_auto_response: Option<serde_json::Value>,
+    pub custom_fields_schema: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -129,6 +153,14 @@ pub enum BusinessProfileUpdate {
         use_billing_as_payment_method_billing: Option<bool>,
         collect_shipping_details_from_wallet_connector: Option<bool>,
         is_connector_agnostic_mit_enabled: Option<bool>,
+        frm_decision_matrix: Option<serde_json::Value>,
+        frm_post_auth_review: Option<serde_json::Value>,
+        connector_debug_capture: Option<serde_json::Value>,
+        connector_failover_policy: Option<serde_json::Value>,
+        vault_provider: Option<serde_json::Value>,
+        tax_provider: Option<serde_json::Value>,
+        dispute_auto_response: Option<serde_json::Value>,
+        custom_fields_schema: Option<serde_json::Value>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
@@ -164,6 +196,14 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 use_billing_as_payment_method_billing,
                 collect_shipping_details_from_wallet_connector,
                 is_connector_agnostic_mit_enabled,
+                frm_decision_matrix,
+                frm_post_auth_review,
+                connector_debug_capture,
+                connector_failover_policy,
+                vault_provider,
+                tax_provider,
+                dispute_auto_response,
+                custom_fields_schema,
             } => Self {
                 profile_name,
                 modified_at,
@@ -187,6 +227,14 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 use_billing_as_payment_method_billing,
                 collect_shipping_details_from_wallet_connector,
                 is_connector_agnostic_mit_enabled,
+                frm_decision_matrix,
+                frm_post_auth_review,
+                connector_debug_capture,
+                connector_failover_policy,
+                vault_provider,
+                tax_provider,
+                dispute_auto_response,
+                custom_fields_schema,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -235,6 +283,14 @@ impl From<BusinessProfileNew> for BusinessProfile {
             use_billing_as_payment_method_billing: new.use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector: new
                 .collect_shipping_details_from_wallet_connector,
+            frm_decision_matrix: new.frm_decision_matrix,
+            frm_post_auth_review: new.frm_post_auth_review,
+            connector_debug_capture: new.connector_debug_capture,
+            connector_failover_policy: new.connector_failover_policy,
+            vault_provider: new.vault_provider,
+            tax_provider: new.tax_provider,
+            dispute_auto_response: new.dispute_auto_response,
+            custom_fields_schema: new.custom_fields_schema,
         }
     }
 }
@@ -265,6 +321,14 @@ impl BusinessProfileUpdate {
             is_connector_agnostic_mit_enabled,
             use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector,
+            frm_decision_matrix,
+            frm_post_auth_review,
+            connector_debug_capture,
+            connector_failover_policy,
+            vault_provider,
+            tax_provider,
+            dispute_auto_response,
+            custom_fields_schema,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -292,6 +356,14 @@ impl BusinessProfileUpdate {
             extended_card_info_config,
             use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector,
+            frm_decision_matrix,
+            frm_post_auth_review,
+            connector_debug_capture,
+            connector_failover_policy,
+            vault_provider,
+            tax_provider,
+            dispute_auto_response,
+            custom_fields_schema,
             ..source
         }
     }
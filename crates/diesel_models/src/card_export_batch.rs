@@ -0,0 +1,114 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::card_export_batch};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = card_export_batch, primary_key(export_id))]
+pub struct CardExportBatch {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub receiving_psp: String,
+    pub receiver_public_key: String,
+    pub payment_method_ids: Option<serde_json::Value>,
+    pub status: enums::CardExportStatus,
+    pub reason: Option<String>,
+    pub requested_by: String,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub rejection_reason: Option<String>,
+    pub total_count: Option<i32>,
+    pub exported_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub file_id: Option<String>,
+    pub error_message: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_export_batch)]
+pub struct CardExportBatchNew {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub receiving_psp: String,
+    pub receiver_public_key: String,
+    pub payment_method_ids: Option<serde_json::Value>,
+    pub status: enums::CardExportStatus,
+    pub reason: Option<String>,
+    pub requested_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_export_batch)]
+pub struct CardExportBatchUpdateInternal {
+    pub status: Option<enums::CardExportStatus>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub rejection_reason: Option<String>,
+    pub total_count: Option<i32>,
+    pub exported_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub file_id: Option<String>,
+    pub error_message: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum CardExportBatchUpdate {
+    ReviewUpdate {
+        status: enums::CardExportStatus,
+        reviewed_by: String,
+        rejection_reason: Option<String>,
+    },
+    ResultUpdate {
+        status: enums::CardExportStatus,
+        total_count: i32,
+        exported_count: i32,
+        failed_count: i32,
+        file_id: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+impl From<CardExportBatchUpdate> for CardExportBatchUpdateInternal {
+    fn from(value: CardExportBatchUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            CardExportBatchUpdate::ReviewUpdate {
+                status,
+                reviewed_by,
+                rejection_reason,
+            } => Self {
+                status: Some(status),
+                reviewed_by: Some(reviewed_by),
+                reviewed_at: Some(now),
+                rejection_reason,
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            CardExportBatchUpdate::ResultUpdate {
+                status,
+                total_count,
+                exported_count,
+                failed_count,
+                file_id,
+                error_message,
+            } => Self {
+                status: Some(status),
+                total_count: Some(total_count),
+                exported_count: Some(exported_count),
+                failed_count: Some(failed_count),
+                file_id,
+                error_message,
+                completed_at: Some(now),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
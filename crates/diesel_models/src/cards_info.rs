@@ -18,4 +18,53 @@ pub struct CardInfo {
     pub date_created: PrimitiveDateTime,
     pub last_updated: Option<PrimitiveDateTime>,
     pub last_updated_provider: Option<String>,
+    /// The networks other than the primary network of the card that the card can be processed
+    /// on, when the card is co-badged
+    pub co_badged_card_networks: Option<serde_json::Value>,
+    pub is_prepaid: Option<bool>,
+    /// Whether the interchange fees of the card are capped by regulation, such as debit cards
+    /// issued by large banks in the US or consumer cards issued in the EEA
+    pub is_regulated: Option<bool>,
+}
+
+impl CardInfo {
+    /// The networks the card can be processed on, the primary network of the card first. A card
+    /// is co-badged when it can be processed on more than one network.
+    pub fn get_card_networks(&self) -> Vec<storage_enums::CardNetwork> {
+        let co_badged_card_networks = self
+            .co_badged_card_networks
+            .clone()
+            .and_then(|networks| {
+                serde_json::from_value::<Vec<storage_enums::CardNetwork>>(networks).ok()
+            })
+            .unwrap_or_default();
+
+        let mut card_networks: Vec<storage_enums::CardNetwork> =
+            self.card_network.iter().cloned().collect();
+        for card_network in co_badged_card_networks {
+            if !card_networks.contains(&card_network) {
+                card_networks.push(card_network);
+            }
+        }
+        card_networks
+    }
+
+    pub fn is_co_badged(&self) -> bool {
+        self.get_card_networks().len() > 1
+    }
+
+    pub fn get_card_funding_type(&self) -> Option<storage_enums::CardFundingType> {
+        if self.is_prepaid == Some(true) {
+            return Some(storage_enums::CardFundingType::Prepaid);
+        }
+        self.card_type
+            .as_deref()
+            .and_then(|card_type| card_type.to_lowercase().parse().ok())
+    }
+
+    pub fn get_card_issuing_country_code(&self) -> Option<storage_enums::CountryAlpha2> {
+        self.country_code
+            .as_deref()
+            .and_then(|country_code| country_code.to_uppercase().parse().ok())
+    }
 }
@@ -0,0 +1,51 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::connector_exchange;
+
+/// A request sent to a connector along with the response received, captured while debugging the
+/// integration with the connector. The payloads are masked of sensitive data before being stored.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = connector_exchange, primary_key(exchange_id))]
+pub struct ConnectorExchange {
+    pub exchange_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub request_id: Option<String>,
+    pub url: String,
+    pub method: String,
+    pub request: Option<serde_json::Value>,
+    pub response: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+    pub status_code: i32,
+    pub latency_in_ms: i64,
+    pub created_at: PrimitiveDateTime,
+    /// The time after which the exchange is no longer retained
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_exchange)]
+pub struct ConnectorExchangeNew {
+    pub exchange_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub request_id: Option<String>,
+    pub url: String,
+    pub method: String,
+    pub request: Option<serde_json::Value>,
+    pub response: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+    pub status_code: i32,
+    pub latency_in_ms: i64,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
@@ -0,0 +1,137 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums,
+    schema::{connector_migration, connector_migration_payment_method},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = connector_migration, primary_key(migration_id))]
+pub struct ConnectorMigration {
+    pub migration_id: String,
+    pub merchant_id: String,
+    pub source_merchant_connector_id: String,
+    pub target_merchant_connector_id: String,
+    pub target_connector: String,
+    pub currency: enums::Currency,
+    pub allowed_methods: serde_json::Value,
+    pub payment_method_ids: Option<serde_json::Value>,
+    pub status: enums::ConnectorMigrationStatus,
+    pub last_payment_method_id: Option<String>,
+    pub total_count: i32,
+    pub migrated_count: i32,
+    pub skipped_count: i32,
+    pub failed_count: i32,
+    pub error_message: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_migration)]
+pub struct ConnectorMigrationNew {
+    pub migration_id: String,
+    pub merchant_id: String,
+    pub source_merchant_connector_id: String,
+    pub target_merchant_connector_id: String,
+    pub target_connector: String,
+    pub currency: enums::Currency,
+    pub allowed_methods: serde_json::Value,
+    pub payment_method_ids: Option<serde_json::Value>,
+    pub status: enums::ConnectorMigrationStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_migration)]
+pub struct ConnectorMigrationUpdateInternal {
+    pub status: Option<enums::ConnectorMigrationStatus>,
+    pub last_payment_method_id: Option<String>,
+    pub total_count: Option<i32>,
+    pub migrated_count: Option<i32>,
+    pub skipped_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum ConnectorMigrationUpdate {
+    ProgressUpdate {
+        last_payment_method_id: String,
+        total_count: i32,
+        migrated_count: i32,
+        skipped_count: i32,
+        failed_count: i32,
+    },
+    StatusUpdate {
+        status: enums::ConnectorMigrationStatus,
+        error_message: Option<String>,
+    },
+}
+
+impl From<ConnectorMigrationUpdate> for ConnectorMigrationUpdateInternal {
+    fn from(value: ConnectorMigrationUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            ConnectorMigrationUpdate::ProgressUpdate {
+                last_payment_method_id,
+                total_count,
+                migrated_count,
+                skipped_count,
+                failed_count,
+            } => Self {
+                last_payment_method_id: Some(last_payment_method_id),
+                total_count: Some(total_count),
+                migrated_count: Some(migrated_count),
+                skipped_count: Some(skipped_count),
+                failed_count: Some(failed_count),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            ConnectorMigrationUpdate::StatusUpdate {
+                status,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_message,
+                completed_at: Some(now),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(
+    table_name = connector_migration_payment_method,
+    primary_key(migration_id, payment_method_id)
+)]
+pub struct ConnectorMigrationPaymentMethod {
+    pub migration_id: String,
+    pub payment_method_id: String,
+    pub merchant_id: String,
+    pub status: enums::ConnectorMigrationPaymentMethodStatus,
+    pub migration_method: Option<enums::CredentialMigrationMethod>,
+    pub payment_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_migration_payment_method)]
+pub struct ConnectorMigrationPaymentMethodNew {
+    pub migration_id: String,
+    pub payment_method_id: String,
+    pub merchant_id: String,
+    pub status: enums::ConnectorMigrationPaymentMethodStatus,
+    pub migration_method: Option<enums::CredentialMigrationMethod>,
+    pub payment_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -22,6 +22,8 @@ pub struct CustomerNew {
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
     pub updated_by: Option<String>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
 }
 
 impl CustomerNew {
@@ -48,6 +50,9 @@ impl From<CustomerNew> for Customer {
             address_id: customer_new.address_id,
             default_payment_method_id: None,
             updated_by: customer_new.updated_by,
+            payment_method_order: None,
+            email_blind_index: customer_new.email_blind_index,
+            phone_blind_index: customer_new.phone_blind_index,
         }
     }
 }
@@ -70,6 +75,9 @@ pub struct Customer {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
+    pub payment_method_order: Option<serde_json::Value>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
 }
 
 #[derive(
@@ -94,6 +102,9 @@ pub struct CustomerUpdateInternal {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
     pub updated_by: Option<String>,
+    pub payment_method_order: Option<serde_json::Value>,
+    pub email_blind_index: Option<Option<String>>,
+    pub phone_blind_index: Option<Option<String>>,
 }
 
 impl CustomerUpdateInternal {
@@ -108,6 +119,9 @@ impl CustomerUpdateInternal {
             connector_customer,
             address_id,
             default_payment_method_id,
+            payment_method_order,
+            email_blind_index,
+            phone_blind_index,
             ..
         } = self;
 
@@ -124,6 +138,9 @@ impl CustomerUpdateInternal {
             default_payment_method_id: default_payment_method_id
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
+            payment_method_order: payment_method_order.map_or(source.payment_method_order, Some),
+            email_blind_index: email_blind_index.unwrap_or(source.email_blind_index),
+            phone_blind_index: phone_blind_index.unwrap_or(source.phone_blind_index),
             ..source
         }
     }
@@ -69,6 +69,7 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    CustomerRedactionDetails,
 }
 
 #[derive(
@@ -96,6 +97,8 @@ pub enum ProcessTrackerStatus {
     ProcessStarted,
     // Finished by consumer
     Finish,
+    // Paused through the API, not picked by the producer until resumed
+    Paused,
 }
 
 // Refund
@@ -1,4 +1,4 @@
-use common_utils::custom_serde;
+use common_utils::{custom_serde, id_type};
 use diesel::{
     deserialize::FromSqlRow, expression::AsExpression, AsChangeset, Identifiable, Insertable,
     Queryable,
@@ -33,6 +33,7 @@ pub struct EventNew {
 #[diesel(table_name = events)]
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
+    pub request: Option<Encryption>,
     pub response: Option<Encryption>,
 }
 
@@ -82,6 +83,9 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    Customer {
+        customer_id: id_type::CustomerId,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
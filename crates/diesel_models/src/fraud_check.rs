@@ -27,6 +27,7 @@ pub struct FraudCheck {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    pub frm_decision: Option<storage_enums::FrmDecisionAction>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -49,6 +50,7 @@ pub struct FraudCheckNew {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+    pub frm_decision: Option<storage_enums::FrmDecisionAction>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +70,15 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    DecisionUpdate {
+        frm_decision: storage_enums::FrmDecisionAction,
+        last_step: FraudCheckLastStep,
+    },
+    ReviewResolutionUpdate {
+        frm_status: FraudCheckStatus,
+        frm_decision: storage_enums::FrmDecisionAction,
+        last_step: FraudCheckLastStep,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -81,6 +92,7 @@ pub struct FraudCheckUpdateInternal {
     metadata: Option<serde_json::Value>,
     last_step: FraudCheckLastStep,
     payment_capture_method: Option<storage_enums::CaptureMethod>,
+    frm_decision: Option<storage_enums::FrmDecisionAction>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -113,6 +125,24 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_error: error_message,
                 ..Default::default()
             },
+            FraudCheckUpdate::DecisionUpdate {
+                frm_decision,
+                last_step,
+            } => Self {
+                frm_decision: Some(frm_decision),
+                last_step,
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewResolutionUpdate {
+                frm_status,
+                frm_decision,
+                last_step,
+            } => Self {
+                frm_status: Some(frm_status),
+                frm_decision: Some(frm_decision),
+                last_step,
+                ..Default::default()
+            },
         }
     }
 }
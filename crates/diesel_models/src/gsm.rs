@@ -38,6 +38,9 @@ pub struct GatewayStatusMap {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub decline_category: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub user_actionable: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -54,6 +57,9 @@ pub struct GatewayStatusMappingNew {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub decline_category: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub user_actionable: bool,
 }
 
 #[derive(
@@ -79,6 +85,9 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub decline_category: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub user_actionable: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -89,6 +98,9 @@ pub struct GatewayStatusMappingUpdate {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub decline_category: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub user_actionable: Option<bool>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -100,6 +112,9 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            decline_category,
+            network_advice_code,
+            user_actionable,
         } = value;
         Self {
             status,
@@ -108,6 +123,9 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            decline_category,
+            network_advice_code,
+            user_actionable,
             ..Default::default()
         }
     }
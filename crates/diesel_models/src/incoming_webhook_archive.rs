@@ -0,0 +1,40 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::incoming_webhook_archive};
+
+/// The body of an incoming webhook as it was received, stored encrypted with the key of the
+/// merchant
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_archive)]
+pub struct IncomingWebhookArchiveNew {
+    pub archive_id: String,
+    pub merchant_id: String,
+    pub business_profile_id: Option<String>,
+    pub connector_name: String,
+    pub merchant_connector_id: Option<String>,
+    pub event_type: Option<String>,
+    pub body: Encryption,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_archive)]
+pub struct IncomingWebhookArchiveUpdateInternal {
+    pub body: Encryption,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = incoming_webhook_archive, primary_key(archive_id))]
+pub struct IncomingWebhookArchive {
+    pub archive_id: String,
+    pub merchant_id: String,
+    pub business_profile_id: Option<String>,
+    pub connector_name: String,
+    pub merchant_connector_id: Option<String>,
+    pub event_type: Option<String>,
+    pub body: Encryption,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
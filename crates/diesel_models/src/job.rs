@@ -0,0 +1,118 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::job};
+
+/// A job run in the background by the scheduler, whose items are processed batch by batch
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = job, primary_key(job_id))]
+pub struct Job {
+    pub job_id: String,
+    pub merchant_id: String,
+    pub job_type: enums::JobType,
+    pub status: enums::JobStatus,
+    /// The items to be processed by the job, as requested
+    pub input: serde_json::Value,
+    /// The outcome of the items processed so far
+    pub output: Option<serde_json::Value>,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    pub attempt_count: i32,
+    pub max_attempts: i32,
+    pub error_message: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = job)]
+pub struct JobNew {
+    pub job_id: String,
+    pub merchant_id: String,
+    pub job_type: enums::JobType,
+    pub status: enums::JobStatus,
+    pub input: serde_json::Value,
+    pub total_count: i32,
+    pub max_attempts: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = job)]
+pub struct JobUpdateInternal {
+    pub status: Option<enums::JobStatus>,
+    pub output: Option<serde_json::Value>,
+    pub processed_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub attempt_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum JobUpdate {
+    /// The job was picked by a worker for the first time
+    Start,
+    ProgressUpdate {
+        output: serde_json::Value,
+        processed_count: i32,
+        failed_count: i32,
+    },
+    /// An attempt of the job failed, the job being retried from its last progress
+    RetryUpdate {
+        attempt_count: i32,
+        error_message: String,
+    },
+    /// The job was completed, failed or cancelled
+    StatusUpdate {
+        status: enums::JobStatus,
+        error_message: Option<String>,
+    },
+}
+
+impl From<JobUpdate> for JobUpdateInternal {
+    fn from(value: JobUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            JobUpdate::Start => Self {
+                status: Some(enums::JobStatus::Running),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            JobUpdate::ProgressUpdate {
+                output,
+                processed_count,
+                failed_count,
+            } => Self {
+                output: Some(output),
+                processed_count: Some(processed_count),
+                failed_count: Some(failed_count),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            JobUpdate::RetryUpdate {
+                attempt_count,
+                error_message,
+            } => Self {
+                attempt_count: Some(attempt_count),
+                error_message: Some(error_message),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            JobUpdate::StatusUpdate {
+                status,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_message,
+                completed_at: Some(now),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
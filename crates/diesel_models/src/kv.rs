@@ -255,3 +255,15 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+/// Key of the [`DrainerTuning`] in redis
+pub const DRAINER_TUNING_KEY: &str = "drainer_tuning";
+
+/// Tuning of the drainer applied at runtime, overriding the drainer configuration
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DrainerTuning {
+    /// The number of shards drained concurrently on each iteration of the drainer
+    pub concurrency: Option<u8>,
+    /// The maximum number of entries read from a shard at once
+    pub max_read_count: Option<u64>,
+}
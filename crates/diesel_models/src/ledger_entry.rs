@@ -0,0 +1,46 @@
+use common_utils::types::MinorUnit;
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::ledger_entry};
+
+/// A leg of a balanced ledger transaction. Entries are never updated, a transaction is reverted by
+/// recording another transaction.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = ledger_entry, primary_key(entry_id))]
+pub struct LedgerEntry {
+    pub entry_id: String,
+    pub transaction_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub merchant_connector_id: Option<String>,
+    pub account: enums::LedgerAccount,
+    pub event_type: enums::LedgerEventType,
+    /// Signed amount, positive for debits and negative for credits
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub payout_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = ledger_entry)]
+pub struct LedgerEntryNew {
+    pub entry_id: String,
+    pub transaction_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub merchant_connector_id: Option<String>,
+    pub account: enums::LedgerAccount,
+    pub event_type: enums::LedgerEventType,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub payout_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
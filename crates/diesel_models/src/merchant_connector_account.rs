@@ -44,6 +44,9 @@ pub struct MerchantConnectorAccount {
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: storage_enums::ConnectorStatus,
     pub connector_wallets_details: Option<Encryption>,
+    pub next_connector_account_details: Option<Encryption>,
+    pub previous_connector_account_details: Option<Encryption>,
+    pub credentials_rotation_window_ends_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -74,6 +77,9 @@ pub struct MerchantConnectorAccountNew {
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: storage_enums::ConnectorStatus,
     pub connector_wallets_details: Option<Encryption>,
+    pub next_connector_account_details: Option<Encryption>,
+    pub previous_connector_account_details: Option<Encryption>,
+    pub credentials_rotation_window_ends_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -99,6 +105,9 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
+    pub next_connector_account_details: Option<Option<Encryption>>,
+    pub previous_connector_account_details: Option<Option<Encryption>>,
+    pub credentials_rotation_window_ends_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 impl MerchantConnectorAccountUpdateInternal {
@@ -122,6 +131,15 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            next_connector_account_details: self
+                .next_connector_account_details
+                .unwrap_or(source.next_connector_account_details),
+            previous_connector_account_details: self
+                .previous_connector_account_details
+                .unwrap_or(source.previous_connector_account_details),
+            credentials_rotation_window_ends_at: self
+                .credentials_rotation_window_ends_at
+                .unwrap_or(source.credentials_rotation_window_ends_at),
 
             ..source
         }
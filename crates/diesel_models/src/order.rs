@@ -0,0 +1,72 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::orders};
+
+/// An order of a merchant, grouping the payments made towards it, such as deposits, balance
+/// payments and split tenders. The payments are linked to the order through their payment intent.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = orders, primary_key(order_id))]
+pub struct Order {
+    pub order_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = orders)]
+pub struct OrderNew {
+    pub order_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = orders)]
+pub struct OrderUpdateInternal {
+    pub amount: Option<MinorUnit>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum OrderUpdate {
+    Update {
+        amount: Option<MinorUnit>,
+        description: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+impl From<OrderUpdate> for OrderUpdateInternal {
+    fn from(value: OrderUpdate) -> Self {
+        match value {
+            OrderUpdate::Update {
+                amount,
+                description,
+                metadata,
+            } => Self {
+                amount,
+                description,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
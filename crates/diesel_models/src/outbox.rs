@@ -0,0 +1,66 @@
+//! Outbox of the outgoing webhook events.
+//!
+//! The status changes of the payments that raise an outgoing webhook are recorded as outbox
+//! tasks of the process tracker, in the same database transaction as the status change itself.
+//! The scheduler relays the tasks by raising the events that are missing, so that an event is
+//! emitted at least once even when the application stops between the status change and the
+//! event being raised.
+
+use time::Duration;
+
+use crate::{
+    enums, payment_intent::PaymentIntent, process_tracker::ProcessTrackerNew, StorageResult,
+};
+
+/// Name of the task relaying the outgoing webhook events from the outbox
+pub const OUTBOX_RELAY_TASK: &str = "OUTBOX_RELAY";
+
+/// Delay before the outbox is relayed, leaving the application time to raise the event itself
+pub const OUTBOX_RELAY_DELAY: Duration = Duration::minutes(1);
+
+/// The statuses of the payments that raise an outgoing webhook
+const PAYMENT_OUTBOX_STATUSES: [enums::IntentStatus; 4] = [
+    enums::IntentStatus::Succeeded,
+    enums::IntentStatus::Failed,
+    enums::IntentStatus::PartiallyCaptured,
+    enums::IntentStatus::RequiresMerchantAction,
+];
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct OutboxEventTrackingData {
+    pub merchant_id: String,
+    pub event_class: enums::EventClass,
+    pub primary_object_id: String,
+    /// The status of the payment the event is raised for
+    pub status: enums::IntentStatus,
+}
+
+/// The outbox task of the status of the payment, if the status raises an outgoing webhook
+pub fn get_payment_outbox_task(
+    payment_intent: &PaymentIntent,
+) -> Option<StorageResult<ProcessTrackerNew>> {
+    if !PAYMENT_OUTBOX_STATUSES.contains(&payment_intent.status) {
+        return None;
+    }
+
+    let runner = crate::process_tracker::ProcessTrackerRunner::OutboxRelayWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{OUTBOX_RELAY_TASK}_{}_{}",
+        payment_intent.payment_id, payment_intent.status
+    );
+    let tracking_data = OutboxEventTrackingData {
+        merchant_id: payment_intent.merchant_id.clone(),
+        event_class: enums::EventClass::Payments,
+        primary_object_id: payment_intent.payment_id.clone(),
+        status: payment_intent.status,
+    };
+
+    Some(ProcessTrackerNew::new(
+        process_tracker_id,
+        OUTBOX_RELAY_TASK,
+        runner,
+        ["OUTBOX"],
+        tracking_data,
+        common_utils::date_time::now().saturating_add(OUTBOX_RELAY_DELAY),
+    ))
+}
@@ -72,6 +72,9 @@ pub struct PaymentAttempt {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub failover_action: Option<String>,
+    pub unified_error_details: Option<serde_json::Value>,
+    pub amount_reversed: Option<i64>,
 }
 
 impl PaymentAttempt {
@@ -155,6 +158,9 @@ pub struct PaymentAttemptNew {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub failover_action: Option<String>,
+    pub unified_error_details: Option<serde_json::Value>,
+    pub amount_reversed: Option<i64>,
 }
 
 impl PaymentAttemptNew {
@@ -309,6 +315,7 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
+        unified_error_details: Option<serde_json::Value>,
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
@@ -360,6 +367,14 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<String>,
         unified_message: Option<String>,
     },
+    FailoverUpdate {
+        failover_action: String,
+        updated_by: String,
+    },
+    AuthorizationReversalUpdate {
+        amount_reversed: i64,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -410,6 +425,9 @@ pub struct PaymentAttemptUpdateInternal {
     charge_id: Option<String>,
     client_source: Option<String>,
     client_version: Option<String>,
+    failover_action: Option<String>,
+    unified_error_details: Option<serde_json::Value>,
+    amount_reversed: Option<i64>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -478,6 +496,9 @@ impl PaymentAttemptUpdate {
             charge_id,
             client_source,
             client_version,
+            failover_action,
+            unified_error_details,
+            amount_reversed,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -529,6 +550,9 @@ impl PaymentAttemptUpdate {
             charge_id: charge_id.or(source.charge_id),
             client_source: client_source.or(source.client_source),
             client_version: client_version.or(source.client_version),
+            failover_action: failover_action.or(source.failover_action),
+            unified_error_details: unified_error_details.or(source.unified_error_details),
+            amount_reversed: amount_reversed.or(source.amount_reversed),
             ..source
         }
     }
@@ -749,6 +773,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 unified_code,
                 unified_message,
+                unified_error_details,
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
@@ -763,6 +788,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 unified_code,
                 unified_message,
+                unified_error_details,
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
@@ -911,6 +937,22 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_message: unified_message.map(Some),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::FailoverUpdate {
+                failover_action,
+                updated_by,
+            } => Self {
+                failover_action: Some(failover_action),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                amount_reversed,
+                updated_by,
+            } => Self {
+                amount_reversed: Some(amount_reversed),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -59,6 +59,10 @@ pub struct PaymentIntent {
     pub request_external_three_ds_authentication: Option<bool>,
     pub charges: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub tax_details: Option<serde_json::Value>,
+    pub order_id: Option<String>,
+    pub merchant_reference: Option<String>,
+    pub custom_fields: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -114,6 +118,10 @@ pub struct PaymentIntentNew {
     pub request_external_three_ds_authentication: Option<bool>,
     pub charges: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub tax_details: Option<serde_json::Value>,
+    pub order_id: Option<String>,
+    pub merchant_reference: Option<String>,
+    pub custom_fields: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -210,6 +218,16 @@ pub enum PaymentIntentUpdate {
         status: Option<storage_enums::IntentStatus>,
         updated_by: String,
     },
+    TaxDetailsUpdate {
+        amount: MinorUnit,
+        tax_details: serde_json::Value,
+        updated_by: String,
+    },
+    MerchantDetailsUpdate {
+        metadata: Option<pii::SecretSerdeValue>,
+        merchant_reference: Option<String>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -246,6 +264,8 @@ pub struct PaymentIntentUpdateInternal {
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub tax_details: Option<serde_json::Value>,
+    pub merchant_reference: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -281,6 +301,8 @@ impl PaymentIntentUpdate {
             fingerprint_id,
             request_external_three_ds_authentication,
             frm_metadata,
+            tax_details,
+            merchant_reference,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -318,6 +340,8 @@ impl PaymentIntentUpdate {
             request_external_three_ds_authentication: request_external_three_ds_authentication
                 .or(source.request_external_three_ds_authentication),
             frm_metadata: frm_metadata.or(source.frm_metadata),
+            tax_details: tax_details.or(source.tax_details),
+            merchant_reference: merchant_reference.or(source.merchant_reference),
             ..source
         }
     }
@@ -517,6 +541,28 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::TaxDetailsUpdate {
+                amount,
+                tax_details,
+                updated_by,
+            } => Self {
+                amount: Some(amount),
+                tax_details: Some(tax_details),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::MerchantDetailsUpdate {
+                metadata,
+                merchant_reference,
+                updated_by,
+            } => Self {
+                metadata,
+                merchant_reference,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
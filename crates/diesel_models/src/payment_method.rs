@@ -43,6 +43,7 @@ pub struct PaymentMethod {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub usage_restrictions: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -80,6 +81,7 @@ pub struct PaymentMethodNew {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub usage_restrictions: Option<serde_json::Value>,
 }
 
 impl PaymentMethodNew {
@@ -123,6 +125,14 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    UsageRestrictionsUpdate {
+        usage_restrictions: Option<serde_json::Value>,
+    },
+    /// Replaces the encrypted data of the payment method, once re-encrypted with a new key
+    EncryptedDataUpdate {
+        payment_method_data: Option<Encryption>,
+        payment_method_billing_address: Option<Encryption>,
+    },
 }
 
 impl PaymentMethodUpdate {
@@ -152,6 +162,8 @@ pub struct PaymentMethodUpdateInternal {
     updated_by: Option<String>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
+    usage_restrictions: Option<serde_json::Value>,
+    payment_method_billing_address: Option<Encryption>,
 }
 
 impl PaymentMethodUpdateInternal {
@@ -170,6 +182,8 @@ impl PaymentMethodUpdateInternal {
             status,
             connector_mandate_details,
             updated_by,
+            usage_restrictions,
+            payment_method_billing_address,
             ..
         } = self;
 
@@ -183,6 +197,9 @@ impl PaymentMethodUpdateInternal {
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            usage_restrictions: usage_restrictions.map_or(source.usage_restrictions, Some),
+            payment_method_billing_address: payment_method_billing_address
+                .map_or(source.payment_method_billing_address, Some),
             ..source
         }
     }
@@ -203,6 +220,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -218,6 +237,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -231,6 +252,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -247,6 +270,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -260,6 +285,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -280,6 +307,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer,
                 payment_method_type,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -295,6 +324,41 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::UsageRestrictionsUpdate { usage_restrictions } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                usage_restrictions,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::EncryptedDataUpdate {
+                payment_method_data,
+                payment_method_billing_address,
+            } => Self {
+                metadata: None,
+                payment_method_data,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                usage_restrictions: None,
+                payment_method_billing_address,
             },
         }
     }
@@ -336,6 +400,7 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             payment_method_billing_address: payment_method_new
                 .payment_method_billing_address
                 .clone(),
+            usage_restrictions: payment_method_new.usage_restrictions.clone(),
         }
     }
 }
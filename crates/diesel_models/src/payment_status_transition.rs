@@ -0,0 +1,37 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::payment_status_transition};
+
+/// A transition of the status of a payment intent or of a payment attempt. Transitions are never
+/// updated, they make up the history of the statuses of the payment.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = payment_status_transition, primary_key(transition_id))]
+pub struct PaymentStatusTransition {
+    pub transition_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    /// The attempt whose status transitioned, not set for the transitions of the intent
+    pub attempt_id: Option<String>,
+    pub object_type: enums::PaymentStatusObject,
+    pub previous_status: String,
+    pub status: String,
+    pub actor: enums::PaymentStatusActor,
+    pub request_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_status_transition)]
+pub struct PaymentStatusTransitionNew {
+    pub transition_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: Option<String>,
+    pub object_type: enums::PaymentStatusObject,
+    pub previous_status: String,
+    pub status: String,
+    pub actor: enums::PaymentStatusActor,
+    pub request_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
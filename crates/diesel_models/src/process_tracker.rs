@@ -209,6 +209,21 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    MandatePreNotificationWorkflow,
+    FrmReviewTimeoutWorkflow,
+    ReportGenerationWorkflow,
+    CustomerRedactionWorkflow,
+    CardExportWorkflow,
+    VaultCleanupWorkflow,
+    ScheduledCaptureWorkflow,
+    BulkForceSyncWorkflow,
+    MasterKeyRotationWorkflow,
+    MerchantKeyRotationWorkflow,
+    AsyncConfirmationWorkflow,
+    ConnectorMigrationWorkflow,
+    OutboxRelayWorkflow,
+    JobWorkflow,
+    AlertEvaluationWorkflow,
 }
 
 #[cfg(test)]
@@ -1,10 +1,14 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_event;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
+pub mod card_export_batch;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_exchange;
+pub mod connector_migration;
 
 pub mod authentication;
 pub mod authorization;
@@ -19,24 +23,33 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod incoming_webhook_archive;
+pub mod job;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod order;
 pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_status_transition;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod report_template;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_line;
+pub mod tenant;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod wallet_decryption_key;
@@ -144,4 +144,24 @@ impl Address {
         )
         .await
     }
+
+    /// Lists the addresses of the merchant, of both its customers and its payments, in the order
+    /// of their address ids, starting after the address id provided
+    pub async fn list_by_merchant_id_starting_after(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::address_id.gt(starting_after.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::address_id.asc()),
+        )
+        .await
+    }
 }
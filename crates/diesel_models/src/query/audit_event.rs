@@ -0,0 +1,72 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, pg::Pg, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    audit_event::{AuditEvent, AuditEventConstraints, AuditEventNew},
+    errors,
+    schema::audit_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AuditEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuditEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AuditEvent {
+    pub async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: AuditEventConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .order((dsl::created_at.desc(), dsl::event_id.desc()))
+            .limit(constraints.limit)
+            .into_boxed();
+
+        if let Some(merchant_id) = constraints.merchant_id {
+            query = query.filter(dsl::merchant_id.eq(merchant_id));
+        }
+
+        if let Some(actor_id) = constraints.actor_id {
+            query = query.filter(dsl::actor_id.eq(actor_id));
+        }
+
+        if let Some(resource_type) = constraints.resource_type {
+            query = query.filter(dsl::resource_type.eq(resource_type));
+        }
+
+        if let Some(resource_id) = constraints.resource_id {
+            query = query.filter(dsl::resource_id.eq(resource_id));
+        }
+
+        if let Some(action) = constraints.action {
+            query = query.filter(dsl::action.eq(action));
+        }
+
+        if let Some(created_after) = constraints.created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = constraints.created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(offset) = constraints.offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering audit events by constraints")
+    }
+}
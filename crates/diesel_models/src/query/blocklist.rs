@@ -28,10 +28,11 @@ impl Blocklist {
         .await
     }
 
-    pub async fn list_by_merchant_id_data_kind(
+    pub async fn list_by_merchant_id_data_kind_list_type(
         conn: &PgPooledConn,
         merchant_id: &str,
         data_kind: common_enums::BlocklistDataKind,
+        list_type: common_enums::BlocklistListType,
         limit: i64,
         offset: i64,
     ) -> StorageResult<Vec<Self>> {
@@ -39,7 +40,8 @@ impl Blocklist {
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
-                .and(dsl::data_kind.eq(data_kind.to_owned())),
+                .and(dsl::data_kind.eq(data_kind.to_owned()))
+                .and(dsl::list_type.eq(list_type)),
             Some(limit),
             Some(offset),
             Some(dsl::created_at.desc()),
@@ -0,0 +1,71 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    card_export_batch::{
+        CardExportBatch, CardExportBatchNew, CardExportBatchUpdate, CardExportBatchUpdateInternal,
+    },
+    enums,
+    schema::card_export_batch::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CardExportBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CardExportBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CardExportBatch {
+    pub async fn find_by_merchant_id_export_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::export_id.eq(export_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Updates the batch only if it is still in the expected status, so that concurrent reviews
+    /// of the same batch cannot both succeed
+    pub async fn update_with_status(
+        self,
+        conn: &PgPooledConn,
+        expected_status: enums::CardExportStatus,
+        card_export_batch_update: CardExportBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::export_id.eq(self.export_id.to_owned()))
+                .and(dsl::status.eq(expected_status)),
+            CardExportBatchUpdateInternal::from(card_export_batch_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,67 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    connector_exchange::{ConnectorExchange, ConnectorExchangeNew},
+    errors,
+    schema::connector_exchange::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorExchangeNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorExchange> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorExchange {
+    /// Finds the exchanges of the payment that are still retained, in the order they were made
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+        now: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::expires_at.gt(now)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Deletes the exchanges of the merchant that are no longer retained, returning the number of
+    /// exchanges deleted
+    pub async fn delete_expired_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(
+            <Self as HasTable>::table()
+                .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .filter(dsl::expires_at.le(now)),
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.execute_async(conn), DatabaseOperation::Delete)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while deleting expired connector exchanges")
+    }
+}
@@ -0,0 +1,103 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    connector_migration::{
+        ConnectorMigration, ConnectorMigrationNew, ConnectorMigrationPaymentMethod,
+        ConnectorMigrationPaymentMethodNew, ConnectorMigrationUpdate,
+        ConnectorMigrationUpdateInternal,
+    },
+    enums,
+    schema::{connector_migration::dsl, connector_migration_payment_method::dsl as pm_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorMigrationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorMigration> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorMigration {
+    pub async fn find_by_merchant_id_migration_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        migration_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::migration_id.eq(migration_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Updates the migration only if it is still in the expected status, so that the progress of
+    /// the migration cannot override its cancellation
+    pub async fn update_with_status(
+        self,
+        conn: &PgPooledConn,
+        expected_status: enums::ConnectorMigrationStatus,
+        connector_migration_update: ConnectorMigrationUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::migration_id.eq(self.migration_id.to_owned()))
+                .and(dsl::status.eq(expected_status)),
+            ConnectorMigrationUpdateInternal::from(connector_migration_update),
+        )
+        .await
+    }
+}
+
+impl ConnectorMigrationPaymentMethodNew {
+    pub async fn insert(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<ConnectorMigrationPaymentMethod> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorMigrationPaymentMethod {
+    pub async fn list_by_merchant_id_migration_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        migration_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            pm_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(pm_dsl::migration_id.eq(migration_id.to_owned())),
+            limit,
+            offset,
+            Some(pm_dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+}
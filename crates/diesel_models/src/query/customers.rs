@@ -83,6 +83,54 @@ impl Customer {
         .await
     }
 
+    /// Lists the customers of the merchant in the order of their customer ids, starting after the
+    /// customer id provided
+    pub async fn list_by_merchant_id_starting_after(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.gt(starting_after.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::customer_id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the customers of the merchant whose email or phone number has the blind index
+    /// provided, along with the customers whose email or phone number was never indexed
+    pub async fn list_by_merchant_id_blind_indexes(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        email_blind_index: Option<String>,
+        phone_blind_index: Option<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::email_blind_index
+                    .eq(email_blind_index)
+                    .or(dsl::phone_blind_index.eq(phone_blind_index))
+                    .or(dsl::email
+                        .is_not_null()
+                        .and(dsl::email_blind_index.is_null()))
+                    .or(dsl::phone
+                        .is_not_null()
+                        .and(dsl::phone_blind_index.is_null())),
+            ),
+            None,
+            None,
+            Some(dsl::created_at),
+        )
+        .await
+    }
+
     pub async fn find_optional_by_customer_id_merchant_id(
         conn: &PgPooledConn,
         customer_id: &id_type::CustomerId,
@@ -116,6 +116,26 @@ impl Event {
         .await
     }
 
+    /// Lists the events of the merchant, delivery attempts included, in the order of their event
+    /// ids, starting after the event id provided
+    pub async fn list_by_merchant_id_starting_after(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::event_id.gt(starting_after.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::event_id.asc()),
+        )
+        .await
+    }
+
     pub async fn list_initial_attempts_by_profile_id_primary_object_id(
         conn: &PgPooledConn,
         profile_id: &str,
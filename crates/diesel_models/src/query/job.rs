@@ -0,0 +1,55 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums,
+    job::{Job, JobNew, JobUpdate, JobUpdateInternal},
+    schema::job::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl JobNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Job> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Job {
+    pub async fn find_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        job_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Updates the job only if it still has one of the expected statuses, so that the progress of
+    /// the job cannot override its cancellation
+    pub async fn update_with_status(
+        self,
+        conn: &PgPooledConn,
+        expected_statuses: Vec<enums::JobStatus>,
+        job_update: JobUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::job_id.eq(self.job_id.to_owned()))
+                .and(dsl::status.eq_any(expected_statuses)),
+            JobUpdateInternal::from(job_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,105 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, result::Error as DieselError,
+    BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    errors,
+    ledger_entry::{LedgerEntry, LedgerEntryNew},
+    schema::ledger_entry::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl LedgerEntry {
+    /// Inserts all the legs of a transaction in a single statement, so that a transaction is
+    /// either recorded in full or not at all
+    pub async fn insert_transaction(
+        conn: &PgPooledConn,
+        entries: Vec<LedgerEntryNew>,
+    ) -> StorageResult<Vec<Self>> {
+        let query = diesel::insert_into(<Self>::table()).values(entries);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        match track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        {
+            Ok(entries) => Ok(entries),
+            Err(DieselError::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            )) => Err(report!(errors::DatabaseError::UniqueViolation)),
+            Err(error) => Err(report!(error).change_context(errors::DatabaseError::Others)),
+        }
+        .attach_printable("Error while inserting ledger entries")
+    }
+
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn filter_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        merchant_connector_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((dsl::created_at.asc(), dsl::entry_id.asc()))
+            .into_boxed();
+
+        if let Some(merchant_connector_id) = merchant_connector_id {
+            query = query.filter(dsl::merchant_connector_id.eq(merchant_connector_id));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering ledger entries by constraints")
+    }
+}
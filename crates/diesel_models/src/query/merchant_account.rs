@@ -1,4 +1,6 @@
-use diesel::{associations::HasTable, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl, Table};
+use error_stack::ResultExt;
 
 use super::generics;
 use crate::{
@@ -86,6 +88,18 @@ impl MerchantAccount {
         .await
     }
 
+    pub async fn get_count(conn: &PgPooledConn) -> StorageResult<i64> {
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            <Self as HasTable>::table()
+                .count()
+                .get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of merchant accounts")
+    }
+
     pub async fn list_by_organization_id(
         conn: &PgPooledConn,
         organization_id: &str,
@@ -2,7 +2,7 @@ use diesel::{associations::HasTable, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
+    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdateInternal},
     schema::merchant_key_store::dsl,
     PgPooledConn, StorageResult,
 };
@@ -25,6 +25,41 @@ impl MerchantKeyStore {
         .await
     }
 
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        merchant_key_store: MerchantKeyStoreUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            merchant_key_store,
+        )
+        .await
+    }
+
+    /// Lists the key stores in the order of their merchant ids, starting after the merchant id
+    /// provided
+    pub async fn list_after_merchant_id(
+        conn: &PgPooledConn,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.gt(starting_after.unwrap_or_default()),
+            Some(limit),
+            None,
+            Some(dsl::merchant_id.asc()),
+        )
+        .await
+    }
+
     pub async fn delete_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &str,
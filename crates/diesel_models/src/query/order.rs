@@ -0,0 +1,50 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    order::{Order, OrderNew, OrderUpdate, OrderUpdateInternal},
+    schema::orders::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl OrderNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Order> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Order {
+    pub async fn find_by_merchant_id_order_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        order_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::order_id.eq(order_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        order_update: OrderUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::order_id.eq(self.order_id.to_owned())),
+            OrderUpdateInternal::from(order_update),
+        )
+        .await
+    }
+}
@@ -2,8 +2,10 @@ use std::collections::HashSet;
 
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
-    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
-    QueryDsl, Table,
+    associations::HasTable,
+    debug_query,
+    pg::{Pg, PgAnyJsonExpressionMethods},
+    BoolExpressionMethods, ExpressionMethods, QueryDsl, Table,
 };
 use error_stack::{report, ResultExt};
 
@@ -320,6 +322,7 @@ impl PaymentAttempt {
         payment_method_type: Option<Vec<enums::PaymentMethodType>>,
         authentication_type: Option<Vec<enums::AuthenticationType>>,
         merchant_connector_id: Option<Vec<String>>,
+        card_network: Option<Vec<String>>,
     ) -> StorageResult<i64> {
         let mut filter = <Self as HasTable>::table()
             .count()
@@ -343,6 +346,14 @@ impl PaymentAttempt {
         if let Some(merchant_connector_id) = merchant_connector_id {
             filter = filter.filter(dsl::merchant_connector_id.eq_any(merchant_connector_id))
         }
+        if let Some(card_network) = card_network {
+            filter = filter.filter(
+                dsl::payment_method_data
+                    .retrieve_as_object("card")
+                    .retrieve_as_text("card_network")
+                    .eq_any(card_network),
+            );
+        }
         router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
 
         db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
@@ -1,8 +1,10 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
 
 use super::generics;
 use crate::{
-    errors,
+    errors, outbox,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
@@ -17,28 +19,50 @@ impl PaymentIntentNew {
 }
 
 impl PaymentIntent {
+    /// Updates the payment intent, recording the outgoing webhook event raised by the new status
+    /// in the outbox within the same transaction
     pub async fn update(
         self,
         conn: &PgPooledConn,
         payment_intent: PaymentIntentUpdate,
     ) -> StorageResult<Self> {
-        match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::payment_id
-                .eq(self.payment_id.to_owned())
-                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentIntentUpdateInternal::from(payment_intent),
-        )
+        let payment_intent_update = PaymentIntentUpdateInternal::from(payment_intent);
+        let is_status_updated = payment_intent_update.status.is_some();
+
+        conn.transaction_async(|conn| async move {
+            let payment_intent =
+                match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+                    &conn,
+                    dsl::payment_id
+                        .eq(self.payment_id.to_owned())
+                        .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+                    payment_intent_update,
+                )
+                .await
+                {
+                    Err(error) => match error.current_context() {
+                        errors::DatabaseError::NoFieldsToUpdate => return Ok(self),
+                        _ => return Err(*error.current_context()),
+                    },
+                    Ok(mut payment_intents) => payment_intents
+                        .pop()
+                        .ok_or(errors::DatabaseError::NotFound)?,
+                };
+
+            if is_status_updated {
+                if let Some(outbox_task) = outbox::get_payment_outbox_task(&payment_intent) {
+                    outbox_task
+                        .map_err(|error| *error.current_context())?
+                        .insert_process_if_not_exists(&conn)
+                        .await
+                        .map_err(|error| *error.current_context())?;
+                }
+            }
+
+            Ok::<_, errors::DatabaseError>(payment_intent)
+        })
         .await
-        {
-            Err(error) => match error.current_context() {
-                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
-                _ => Err(error),
-            },
-            Ok(mut payment_intents) => payment_intents
-                .pop()
-                .ok_or(error_stack::report!(errors::DatabaseError::NotFound)),
-        }
+        .map_err(|error| report!(error))
     }
 
     pub async fn find_by_payment_id_merchant_id(
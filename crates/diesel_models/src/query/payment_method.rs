@@ -84,6 +84,26 @@ impl PaymentMethod {
         .await
     }
 
+    /// Lists the payment methods of the merchant in the order of their payment method ids,
+    /// starting after the payment method id provided
+    pub async fn list_by_merchant_id_starting_after(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.gt(starting_after.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
     pub async fn find_by_customer_id_merchant_id(
         conn: &PgPooledConn,
         customer_id: &id_type::CustomerId,
@@ -0,0 +1,34 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_status_transition::{PaymentStatusTransition, PaymentStatusTransitionNew},
+    schema::payment_status_transition::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentStatusTransitionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentStatusTransition> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentStatusTransition {
+    /// The transitions of the intent and of the attempts of the payment, from the oldest
+    pub async fn list_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
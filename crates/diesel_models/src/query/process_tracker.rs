@@ -1,8 +1,16 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, sql_types, BoolExpressionMethods,
+    ExpressionMethods, QueryDsl, Table,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 use crate::{
     enums, errors,
     process_tracker::{
@@ -17,6 +25,25 @@ impl ProcessTrackerNew {
     pub async fn insert_process(self, conn: &PgPooledConn) -> StorageResult<ProcessTracker> {
         generics::generic_insert(conn, self).await
     }
+
+    /// Inserts the process unless a process with the same id exists, without failing the
+    /// transaction the insert may be part of. Returns the number of processes inserted.
+    #[instrument(skip(conn))]
+    pub async fn insert_process_if_not_exists(self, conn: &PgPooledConn) -> StorageResult<usize> {
+        let query = diesel::insert_into(<ProcessTracker as HasTable>::table())
+            .values(self)
+            .on_conflict_do_nothing();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<ProcessTracker, _, _>(
+            query.execute_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting process")
+    }
 }
 
 impl ProcessTracker {
@@ -41,6 +68,24 @@ impl ProcessTracker {
         }
     }
 
+    /// Updates the process only if it still has one of the statuses provided, returning `None`
+    /// when it no longer has any of them
+    #[instrument(skip(conn))]
+    pub async fn update_with_status(
+        self,
+        conn: &PgPooledConn,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        process: ProcessTrackerUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id.eq(self.id).and(dsl::status.eq_any(statuses)),
+            ProcessTrackerUpdateInternal::from(process),
+        )
+        .await
+        .map(|mut processes| processes.pop())
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_process_status_by_ids(
         conn: &PgPooledConn,
@@ -89,6 +134,111 @@ impl ProcessTracker {
         .await
     }
 
+    /// The processes of the merchant, from the latest scheduled. The merchant of a process is the
+    /// `merchant_id` of its tracking data.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        runner: Option<String>,
+        statuses: Option<Vec<enums::ProcessTrackerStatus>>,
+        business_statuses: Option<Vec<String>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(
+                diesel::dsl::sql::<sql_types::Bool>("tracking_data ->> 'merchant_id' = ")
+                    .bind::<sql_types::Text, _>(merchant_id.to_owned()),
+            )
+            .order(dsl::schedule_time.desc())
+            .into_boxed();
+
+        if let Some(runner) = runner {
+            query = query.filter(dsl::runner.eq(runner));
+        }
+
+        if let Some(statuses) = statuses {
+            query = query.filter(dsl::status.eq_any(statuses));
+        }
+
+        if let Some(business_statuses) = business_statuses {
+            query = query.filter(dsl::business_status.eq_any(business_statuses));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering processes by merchant")
+    }
+
+    /// The earliest schedule time of the processes with one of the statuses, scheduled within the
+    /// limits
+    #[instrument(skip(conn))]
+    pub async fn find_earliest_schedule_time_by_statuses(
+        conn: &PgPooledConn,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+    ) -> StorageResult<Option<PrimitiveDateTime>> {
+        let query = Self::table()
+            .select(diesel::dsl::min(dsl::schedule_time))
+            .filter(
+                dsl::schedule_time
+                    .between(time_lower_limit, time_upper_limit)
+                    .and(dsl::status.eq_any(statuses)),
+            )
+            .into_boxed();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<Option<PrimitiveDateTime>>(conn),
+            DatabaseOperation::FindOne,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error finding the earliest schedule time of the processes")
+    }
+
+    /// The number of processes of the runners with one of the statuses
+    #[instrument(skip(conn))]
+    pub async fn count_processes_by_runners_statuses(
+        conn: &PgPooledConn,
+        runners: Vec<String>,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+    ) -> StorageResult<i64> {
+        let query = Self::table()
+            .count()
+            .filter(
+                dsl::runner
+                    .eq_any(runners)
+                    .and(dsl::status.eq_any(statuses)),
+            )
+            .into_boxed();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<i64>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting the processes of the runners")
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
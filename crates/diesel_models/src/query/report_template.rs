@@ -0,0 +1,80 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    report_template::{
+        ReportTemplate, ReportTemplateNew, ReportTemplateUpdate, ReportTemplateUpdateInternal,
+    },
+    schema::report_template::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReportTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReportTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReportTemplate {
+    pub async fn find_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::template_id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        report_template_update: ReportTemplateUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::template_id.eq(self.template_id.to_owned())),
+            ReportTemplateUpdateInternal::from(report_template_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::template_id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,145 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums,
+    schema::settlement_line::dsl,
+    settlement_line::{
+        SettlementLine, SettlementLineNew, SettlementLineUpdate, SettlementLineUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SettlementLineNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementLine> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementLine {
+    pub async fn find_by_merchant_id_line_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        line_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::line_id.eq(line_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_connector_reference_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        line_type: enums::SettlementLineType,
+        reference_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::line_type.eq(line_type))
+                .and(dsl::reference_id.eq(reference_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn filter_by_merchant_id_recon_status(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        recon_status: Vec<enums::SettlementReconStatus>,
+        connector: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match connector {
+            Some(connector) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::recon_status.eq_any(recon_status))
+                        .and(dsl::connector.eq(connector)),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::recon_status.eq_any(recon_status)),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        settlement_line_update: SettlementLineUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::line_id.eq(self.line_id.to_owned())),
+            SettlementLineUpdateInternal::from(settlement_line_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,94 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    errors,
+    schema::tenant::dsl,
+    tenant::{Tenant, TenantNew, TenantUpdate, TenantUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl TenantNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Tenant> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Tenant {
+    pub async fn find_by_tenant_id(conn: &PgPooledConn, tenant_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        let query = <Self as HasTable>::table().order(dsl::created_at.asc());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error listing the tenants")
+    }
+
+    pub async fn update_by_tenant_id(
+        conn: &PgPooledConn,
+        tenant_id: &str,
+        update: TenantUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+            TenantUpdateInternal::from(update),
+        )
+        .await
+    }
+}
+
+/// Creates the schema of a tenant with a copy of every table of the template schema, along with
+/// their indexes, constraints and defaults. The tables already present in the schema are left as
+/// they are, so that the setup of a schema can be retried.
+///
+/// The names of the schemas are interpolated in the statement, the callers having to validate
+/// them as plain identifiers.
+pub async fn setup_tenant_schema(
+    conn: &PgPooledConn,
+    schema_name: &str,
+    template_schema_name: &str,
+) -> StorageResult<()> {
+    let statement = format!(
+        r#"DO $$
+DECLARE
+    template_table RECORD;
+BEGIN
+    CREATE SCHEMA IF NOT EXISTS "{schema_name}";
+    FOR template_table IN
+        SELECT tablename FROM pg_tables WHERE schemaname = '{template_schema_name}'
+    LOOP
+        EXECUTE format(
+            'CREATE TABLE IF NOT EXISTS %I.%I (LIKE %I.%I INCLUDING ALL)',
+            '{schema_name}', template_table.tablename,
+            '{template_schema_name}', template_table.tablename
+        );
+    END LOOP;
+END $$"#
+    );
+
+    diesel::sql_query(statement)
+        .execute_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error setting up the schema of the tenant")?;
+    Ok(())
+}
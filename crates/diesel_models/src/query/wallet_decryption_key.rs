@@ -0,0 +1,124 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::wallet_decryption_key::dsl,
+    wallet_decryption_key::{
+        WalletDecryptionKey, WalletDecryptionKeyNew, WalletDecryptionKeyUpdate,
+        WalletDecryptionKeyUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WalletDecryptionKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WalletDecryptionKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WalletDecryptionKey {
+    pub async fn find_by_profile_id_key_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        key_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id_wallet_key_identifier(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        wallet: &str,
+        key_identifier: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::wallet.eq(wallet.to_owned()))
+                .and(dsl::key_identifier.eq(key_identifier.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id_wallet(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        wallet: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::wallet.eq(wallet.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Lists the keys of every business profile of the merchant in the order of their key ids,
+    /// starting after the key id provided
+    pub async fn list_by_merchant_id_starting_after(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::key_id.gt(starting_after.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::key_id.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_key_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        key_id: &str,
+        wallet_decryption_key_update: WalletDecryptionKeyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned())),
+            WalletDecryptionKeyUpdateInternal::from(wallet_decryption_key_update),
+        )
+        .await
+    }
+}
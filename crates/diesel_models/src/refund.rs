@@ -43,6 +43,8 @@ pub struct Refund {
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
     pub charges: Option<ChargeRefunds>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub estimated_arrival_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -86,6 +88,8 @@ pub struct RefundNew {
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
     pub charges: Option<ChargeRefunds>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub estimated_arrival_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -95,7 +99,8 @@ pub enum RefundUpdate {
         refund_status: storage_enums::RefundStatus,
         sent_to_gateway: bool,
         refund_error_message: Option<String>,
-        refund_arn: String,
+        refund_arn: Option<String>,
+        estimated_arrival_at: Option<PrimitiveDateTime>,
         updated_by: String,
     },
     MetadataAndReasonUpdate {
@@ -129,6 +134,7 @@ pub struct RefundUpdateInternal {
     refund_reason: Option<String>,
     refund_error_code: Option<String>,
     updated_by: String,
+    estimated_arrival_at: Option<PrimitiveDateTime>,
 }
 
 impl RefundUpdateInternal {
@@ -143,6 +149,7 @@ impl RefundUpdateInternal {
             refund_reason: self.refund_reason,
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
+            estimated_arrival_at: self.estimated_arrival_at,
             ..source
         }
     }
@@ -157,13 +164,15 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 sent_to_gateway,
                 refund_error_message,
                 refund_arn,
+                estimated_arrival_at,
                 updated_by,
             } => Self {
                 connector_refund_id: Some(connector_refund_id),
                 refund_status: Some(refund_status),
                 sent_to_gateway: Some(sent_to_gateway),
                 refund_error_message,
-                refund_arn: Some(refund_arn),
+                refund_arn,
+                estimated_arrival_at,
                 updated_by,
                 ..Default::default()
             },
@@ -217,6 +226,7 @@ impl RefundUpdate {
             refund_reason,
             refund_error_code,
             updated_by,
+            estimated_arrival_at,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -228,6 +238,7 @@ impl RefundUpdate {
             metadata: metadata.or(source.metadata),
             refund_reason: refund_reason.or(source.refund_reason),
             updated_by,
+            estimated_arrival_at: estimated_arrival_at.or(source.estimated_arrival_at),
             ..source
         }
     }
@@ -0,0 +1,119 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::report_template};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = report_template, primary_key(template_id))]
+pub struct ReportTemplate {
+    pub template_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub name: String,
+    pub report_type: enums::ReportType,
+    pub columns: serde_json::Value,
+    pub filters: Option<serde_json::Value>,
+    pub schedule: serde_json::Value,
+    pub is_active: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub last_run_status: Option<enums::ReportRunStatus>,
+    pub last_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_template)]
+pub struct ReportTemplateNew {
+    pub template_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub name: String,
+    pub report_type: enums::ReportType,
+    pub columns: serde_json::Value,
+    pub filters: Option<serde_json::Value>,
+    pub schedule: serde_json::Value,
+    pub is_active: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_template)]
+pub struct ReportTemplateUpdateInternal {
+    pub name: Option<String>,
+    pub columns: Option<serde_json::Value>,
+    pub filters: Option<Option<serde_json::Value>>,
+    pub schedule: Option<serde_json::Value>,
+    pub is_active: Option<bool>,
+    pub next_run_at: Option<PrimitiveDateTime>,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub last_run_status: Option<enums::ReportRunStatus>,
+    pub last_file_id: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReportTemplateUpdate {
+    TemplateUpdate {
+        name: Option<String>,
+        columns: Option<serde_json::Value>,
+        filters: Option<Option<serde_json::Value>>,
+        schedule: Option<serde_json::Value>,
+        is_active: Option<bool>,
+        next_run_at: Option<PrimitiveDateTime>,
+    },
+    RunUpdate {
+        last_run_at: PrimitiveDateTime,
+        last_run_status: enums::ReportRunStatus,
+        last_file_id: Option<String>,
+        next_run_at: PrimitiveDateTime,
+    },
+}
+
+impl From<ReportTemplateUpdate> for ReportTemplateUpdateInternal {
+    fn from(value: ReportTemplateUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            ReportTemplateUpdate::TemplateUpdate {
+                name,
+                columns,
+                filters,
+                schedule,
+                is_active,
+                next_run_at,
+            } => Self {
+                name,
+                columns,
+                filters,
+                schedule,
+                is_active,
+                next_run_at,
+                last_run_at: None,
+                last_run_status: None,
+                last_file_id: None,
+                modified_at,
+            },
+            ReportTemplateUpdate::RunUpdate {
+                last_run_at,
+                last_run_status,
+                last_file_id,
+                next_run_at,
+            } => Self {
+                name: None,
+                columns: None,
+                filters: None,
+                schedule: None,
+                is_active: None,
+                last_run_at: Some(last_run_at),
+                last_run_status: Some(last_run_status),
+                // The file of the last successful run is retained when a run fails
+                last_file_id,
+                next_run_at: Some(next_run_at),
+                modified_at,
+            },
+        }
+    }
+}
@@ -55,6 +55,34 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+        allowed_ips -> Nullable<Array<Nullable<Text>>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_event (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        actor_type -> Varchar,
+        #[max_length = 255]
+        actor_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        resource_type -> Varchar,
+        #[max_length = 255]
+        resource_id -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        diff -> Nullable<Jsonb>,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -137,6 +165,8 @@ diesel::table! {
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        #[max_length = 16]
+        list_type -> Varchar,
     }
 }
 
@@ -203,6 +233,14 @@ diesel::table! {
         is_connector_agnostic_mit_enabled -> Nullable<Bool>,
         use_billing_as_payment_method_billing -> Nullable<Bool>,
         collect_shipping_details_from_wallet_connector -> Nullable<Bool>,
+        frm_decision_matrix -> Nullable<Jsonb>,
+        frm_post_auth_review -> Nullable<Jsonb>,
+        connector_debug_capture -> Nullable<Jsonb>,
+        connector_failover_policy -> Nullable<Jsonb>,
+        vault_provider -> Nullable<Jsonb>,
+        tax_provider -> Nullable<Jsonb>,
+        dispute_auto_response -> Nullable<Jsonb>,
+        custom_fields_schema -> Nullable<Jsonb>,
     }
 }
 
@@ -241,6 +279,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_export_batch (export_id) {
+        #[max_length = 64]
+        export_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        receiving_psp -> Varchar,
+        receiver_public_key -> Text,
+        payment_method_ids -> Nullable<Jsonb>,
+        #[max_length = 32]
+        status -> Varchar,
+        reason -> Nullable<Text>,
+        #[max_length = 64]
+        requested_by -> Varchar,
+        #[max_length = 64]
+        reviewed_by -> Nullable<Varchar>,
+        reviewed_at -> Nullable<Timestamp>,
+        rejection_reason -> Nullable<Text>,
+        total_count -> Nullable<Int4>,
+        exported_count -> Nullable<Int4>,
+        failed_count -> Nullable<Int4>,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -262,6 +334,9 @@ diesel::table! {
         date_created -> Timestamp,
         last_updated -> Nullable<Timestamp>,
         last_updated_provider -> Nullable<Text>,
+        co_badged_card_networks -> Nullable<Jsonb>,
+        is_prepaid -> Nullable<Bool>,
+        is_regulated -> Nullable<Bool>,
     }
 }
 
@@ -277,6 +352,95 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_exchange (exchange_id) {
+        #[max_length = 64]
+        exchange_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        flow -> Varchar,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        url -> Text,
+        #[max_length = 16]
+        method -> Varchar,
+        request -> Nullable<Jsonb>,
+        response -> Nullable<Jsonb>,
+        error -> Nullable<Jsonb>,
+        status_code -> Int4,
+        latency_in_ms -> Int8,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_migration (migration_id) {
+        #[max_length = 64]
+        migration_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 128]
+        source_merchant_connector_id -> Varchar,
+        #[max_length = 128]
+        target_merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        target_connector -> Varchar,
+        currency -> Currency,
+        allowed_methods -> Jsonb,
+        payment_method_ids -> Nullable<Jsonb>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        last_payment_method_id -> Nullable<Varchar>,
+        total_count -> Int4,
+        migrated_count -> Int4,
+        skipped_count -> Int4,
+        failed_count -> Int4,
+        error_message -> Nullable<Text>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_migration_payment_method (migration_id, payment_method_id) {
+        #[max_length = 64]
+        migration_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 32]
+        migration_method -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -304,6 +468,11 @@ diesel::table! {
         default_payment_method_id -> Nullable<Varchar>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        payment_method_order -> Nullable<Jsonb>,
+        #[max_length = 64]
+        email_blind_index -> Nullable<Varchar>,
+        #[max_length = 64]
+        phone_blind_index -> Nullable<Varchar>,
     }
 }
 
@@ -463,6 +632,8 @@ diesel::table! {
         #[max_length = 64]
         last_step -> Varchar,
         payment_capture_method -> Nullable<CaptureMethod>,
+        #[max_length = 64]
+        frm_decision -> Nullable<Varchar>,
     }
 }
 
@@ -494,6 +665,11 @@ diesel::table! {
         unified_code -> Nullable<Varchar>,
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
+        #[max_length = 64]
+        decline_category -> Nullable<Varchar>,
+        #[max_length = 32]
+        network_advice_code -> Nullable<Varchar>,
+        user_actionable -> Bool,
     }
 }
 
@@ -519,6 +695,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_archive (archive_id) {
+        #[max_length = 64]
+        archive_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        business_profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        event_type -> Nullable<Varchar>,
+        body -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -544,6 +742,66 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    job (job_id) {
+        #[max_length = 64]
+        job_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        job_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        input -> Jsonb,
+        output -> Nullable<Jsonb>,
+        total_count -> Int4,
+        processed_count -> Int4,
+        failed_count -> Int4,
+        attempt_count -> Int4,
+        max_attempts -> Int4,
+        error_message -> Nullable<Text>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 255]
+        transaction_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        account -> Varchar,
+        #[max_length = 32]
+        event_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        dispute_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -707,6 +965,9 @@ diesel::table! {
         pm_auth_config -> Nullable<Jsonb>,
         status -> ConnectorStatus,
         connector_wallets_details -> Nullable<Bytea>,
+        next_connector_account_details -> Nullable<Bytea>,
+        previous_connector_account_details -> Nullable<Bytea>,
+        credentials_rotation_window_ends_at -> Nullable<Timestamp>,
     }
 }
 
@@ -722,6 +983,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    orders (order_id) {
+        #[max_length = 64]
+        order_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -819,6 +1103,10 @@ diesel::table! {
         client_source -> Nullable<Varchar>,
         #[max_length = 64]
         client_version -> Nullable<Varchar>,
+        #[max_length = 32]
+        failover_action -> Nullable<Varchar>,
+        unified_error_details -> Nullable<Jsonb>,
+        amount_reversed -> Nullable<Int8>,
     }
 }
 
@@ -888,6 +1176,12 @@ diesel::table! {
         request_external_three_ds_authentication -> Nullable<Bool>,
         charges -> Nullable<Jsonb>,
         frm_metadata -> Nullable<Jsonb>,
+        tax_details -> Nullable<Jsonb>,
+        #[max_length = 64]
+        order_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        merchant_reference -> Nullable<Varchar>,
+        custom_fields -> Nullable<Jsonb>,
     }
 }
 
@@ -972,6 +1266,34 @@ diesel::table! {
         payment_method_billing_address -> Nullable<Bytea>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        usage_restrictions -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_status_transition (transition_id) {
+        #[max_length = 64]
+        transition_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        object_type -> Varchar,
+        #[max_length = 64]
+        previous_status -> Varchar,
+        #[max_length = 64]
+        status -> Varchar,
+        #[max_length = 32]
+        actor -> Varchar,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -1132,6 +1454,37 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         charges -> Nullable<Jsonb>,
+        estimated_arrival_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    report_template (template_id) {
+        #[max_length = 64]
+        template_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 32]
+        report_type -> Varchar,
+        columns -> Jsonb,
+        filters -> Nullable<Jsonb>,
+        schedule -> Jsonb,
+        is_active -> Bool,
+        next_run_at -> Timestamp,
+        last_run_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        last_run_status -> Nullable<Varchar>,
+        #[max_length = 64]
+        last_file_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1201,6 +1554,72 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_line (line_id) {
+        #[max_length = 64]
+        line_id -> Varchar,
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        line_type -> Varchar,
+        #[max_length = 255]
+        reference_id -> Varchar,
+        #[max_length = 255]
+        payment_reference_id -> Nullable<Varchar>,
+        gross_amount -> Int8,
+        fee_amount -> Int8,
+        net_amount -> Int8,
+        currency -> Currency,
+        settled_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        dispute_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        recon_status -> Varchar,
+        recon_error_message -> Nullable<Text>,
+        resolution_note -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    tenant (tenant_id) {
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        #[max_length = 255]
+        tenant_name -> Varchar,
+        base_url -> Text,
+        #[max_length = 64]
+        schema_name -> Varchar,
+        #[max_length = 64]
+        redis_key_prefix -> Varchar,
+        #[max_length = 64]
+        clickhouse_database -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        quotas -> Nullable<Jsonb>,
+        isolation_test_mode -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1257,6 +1676,8 @@ diesel::table! {
         last_modified_by -> Varchar,
         created_at -> Timestamp,
         last_modified -> Timestamp,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
     }
 }
 
@@ -1286,17 +1707,44 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    wallet_decryption_key (key_id) {
+        #[max_length = 64]
+        key_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        wallet -> Varchar,
+        #[max_length = 255]
+        key_identifier -> Varchar,
+        key_details -> Bytea,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_event,
     authentication,
     blocklist,
     blocklist_fingerprint,
     blocklist_lookup,
     business_profile,
     captures,
+    card_export_batch,
     cards_info,
     configs,
+    connector_exchange,
+    connector_migration,
+    connector_migration_payment_method,
     customers,
     dashboard_metadata,
     dispute,
@@ -1305,26 +1753,35 @@ diesel::allow_tables_to_appear_in_same_query!(
     fraud_check,
     gateway_status_map,
     generic_link,
+    incoming_webhook_archive,
     incremental_authorization,
+    job,
+    ledger_entry,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
     merchant_key_store,
+    orders,
     organization,
     payment_attempt,
     payment_intent,
     payment_link,
     payment_methods,
+    payment_status_transition,
     payout_attempt,
     payouts,
     process_tracker,
     refund,
+    report_template,
     reverse_lookup,
     roles,
     routing_algorithm,
+    settlement_line,
+    tenant,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    wallet_decryption_key,
 );
@@ -0,0 +1,115 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::settlement_line};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = settlement_line, primary_key(line_id))]
+pub struct SettlementLine {
+    pub line_id: String,
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub merchant_connector_id: Option<String>,
+    pub line_type: enums::SettlementLineType,
+    pub reference_id: String,
+    pub payment_reference_id: Option<String>,
+    pub gross_amount: MinorUnit,
+    pub fee_amount: MinorUnit,
+    /// Signed net amount, negative for the lines debited from the merchant
+    pub net_amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub recon_status: enums::SettlementReconStatus,
+    pub recon_error_message: Option<String>,
+    pub resolution_note: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_line)]
+pub struct SettlementLineNew {
+    pub line_id: String,
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub merchant_connector_id: Option<String>,
+    pub line_type: enums::SettlementLineType,
+    pub reference_id: String,
+    pub payment_reference_id: Option<String>,
+    pub gross_amount: MinorUnit,
+    pub fee_amount: MinorUnit,
+    pub net_amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub recon_status: enums::SettlementReconStatus,
+    pub recon_error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_line)]
+pub struct SettlementLineUpdateInternal {
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub recon_status: Option<enums::SettlementReconStatus>,
+    pub recon_error_message: Option<Option<String>>,
+    pub resolution_note: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SettlementLineUpdate {
+    ReconUpdate {
+        payment_id: Option<String>,
+        refund_id: Option<String>,
+        dispute_id: Option<String>,
+        recon_status: enums::SettlementReconStatus,
+        recon_error_message: Option<String>,
+    },
+    ResolutionUpdate {
+        resolution_note: String,
+    },
+}
+
+impl From<SettlementLineUpdate> for SettlementLineUpdateInternal {
+    fn from(value: SettlementLineUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            SettlementLineUpdate::ReconUpdate {
+                payment_id,
+                refund_id,
+                dispute_id,
+                recon_status,
+                recon_error_message,
+            } => Self {
+                payment_id,
+                refund_id,
+                dispute_id,
+                recon_status: Some(recon_status),
+                recon_error_message: Some(recon_error_message),
+                resolution_note: None,
+                modified_at,
+            },
+            SettlementLineUpdate::ResolutionUpdate { resolution_note } => Self {
+                payment_id: None,
+                refund_id: None,
+                dispute_id: None,
+                recon_status: Some(enums::SettlementReconStatus::ManuallyResolved),
+                recon_error_message: None,
+                resolution_note: Some(resolution_note),
+                modified_at,
+            },
+        }
+    }
+}
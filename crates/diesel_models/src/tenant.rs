@@ -0,0 +1,71 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::tenant;
+
+/// A tenant provisioned at runtime through the tenants API, stored in the global schema along
+/// with the schema, the redis key prefix and the clickhouse database allocated to it
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = tenant, primary_key(tenant_id))]
+pub struct Tenant {
+    pub tenant_id: String,
+    pub tenant_name: String,
+    pub base_url: String,
+    pub schema_name: String,
+    pub redis_key_prefix: String,
+    pub clickhouse_database: String,
+    pub status: String,
+    /// The limits on the resources of the tenant, none being enforced when not set
+    pub quotas: Option<serde_json::Value>,
+    pub isolation_test_mode: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = tenant)]
+pub struct TenantNew {
+    pub tenant_id: String,
+    pub tenant_name: String,
+    pub base_url: String,
+    pub schema_name: String,
+    pub redis_key_prefix: String,
+    pub clickhouse_database: String,
+    pub status: String,
+    pub quotas: Option<serde_json::Value>,
+    pub isolation_test_mode: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = tenant)]
+pub struct TenantUpdateInternal {
+    status: Option<String>,
+    quotas: Option<Option<serde_json::Value>>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum TenantUpdate {
+    StatusUpdate { status: String },
+    QuotasUpdate { quotas: Option<serde_json::Value> },
+}
+
+impl From<TenantUpdate> for TenantUpdateInternal {
+    fn from(value: TenantUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            TenantUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                quotas: None,
+                modified_at,
+            },
+            TenantUpdate::QuotasUpdate { quotas } => Self {
+                status: None,
+                quotas: Some(quotas),
+                modified_at,
+            },
+        }
+    }
+}
@@ -76,6 +76,9 @@ pub struct PaymentAttemptBatchNew {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub failover_action: Option<String>,
+    pub unified_error_details: Option<serde_json::Value>,
+    pub amount_reversed: Option<i64>,
 }
 
 #[allow(dead_code)]
@@ -139,6 +142,9 @@ impl PaymentAttemptBatchNew {
             charge_id: self.charge_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            failover_action: self.failover_action,
+            unified_error_details: self.unified_error_details,
+            amount_reversed: self.amount_reversed,
         }
     }
 }
@@ -16,6 +16,7 @@ pub struct UserRole {
     pub last_modified_by: String,
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
+    pub profile_id: Option<String>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -30,6 +31,7 @@ pub struct UserRoleNew {
     pub last_modified_by: String,
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
+    pub profile_id: Option<String>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -39,6 +41,7 @@ pub struct UserRoleUpdateInternal {
     status: Option<enums::UserStatus>,
     last_modified_by: Option<String>,
     last_modified: PrimitiveDateTime,
+    profile_id: Option<Option<String>>,
 }
 
 pub enum UserRoleUpdate {
@@ -48,6 +51,7 @@ pub enum UserRoleUpdate {
     },
     UpdateRole {
         role_id: String,
+        profile_id: Option<String>,
         modified_by: String,
     },
 }
@@ -58,12 +62,14 @@ impl From<UserRoleUpdate> for UserRoleUpdateInternal {
         match value {
             UserRoleUpdate::UpdateRole {
                 role_id,
+                profile_id,
                 modified_by,
             } => Self {
                 role_id: Some(role_id),
                 last_modified_by: Some(modified_by),
                 status: None,
                 last_modified,
+                profile_id: Some(profile_id),
             },
             UserRoleUpdate::UpdateStatus {
                 status,
@@ -73,6 +79,7 @@ impl From<UserRoleUpdate> for UserRoleUpdateInternal {
                 last_modified,
                 last_modified_by: Some(modified_by),
                 role_id: None,
+                profile_id: None,
             },
         }
     }
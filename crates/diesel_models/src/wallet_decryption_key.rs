@@ -0,0 +1,74 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::wallet_decryption_key};
+
+/// A key registered by the merchant on a business profile, with which the wallet tokens of the
+/// payments of the profile are decrypted by the application
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = wallet_decryption_key, primary_key(key_id))]
+pub struct WalletDecryptionKey {
+    pub key_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub wallet: String,
+    /// The identifier the wallet tokens refer to the key with: the hash of the public key of the
+    /// payment processing certificate for Apple Pay, the recipient ID for Google Pay
+    pub key_identifier: String,
+    /// The encrypted certificate and private key
+    pub key_details: Encryption,
+    /// The time after which the key is no longer used to decrypt the wallet tokens, once retired
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = wallet_decryption_key)]
+pub struct WalletDecryptionKeyNew {
+    pub key_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub wallet: String,
+    pub key_identifier: String,
+    pub key_details: Encryption,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WalletDecryptionKeyUpdate {
+    Retire {
+        expires_at: PrimitiveDateTime,
+    },
+    /// Replaces the encrypted details of the key, once re-encrypted with a new key
+    KeyDetailsUpdate {
+        key_details: Encryption,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = wallet_decryption_key)]
+pub struct WalletDecryptionKeyUpdateInternal {
+    pub key_details: Option<Encryption>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WalletDecryptionKeyUpdate> for WalletDecryptionKeyUpdateInternal {
+    fn from(update: WalletDecryptionKeyUpdate) -> Self {
+        match update {
+            WalletDecryptionKeyUpdate::Retire { expires_at } => Self {
+                key_details: None,
+                expires_at: Some(expires_at),
+                modified_at: common_utils::date_time::now(),
+            },
+            WalletDecryptionKeyUpdate::KeyDetailsUpdate { key_details } => Self {
+                key_details: Some(key_details),
+                expires_at: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
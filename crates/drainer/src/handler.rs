@@ -72,17 +72,32 @@ impl Handler {
         while self.running.load(atomic::Ordering::SeqCst) {
             metrics::DRAINER_HEALTH.add(&metrics::CONTEXT, 1, &[]);
             for store in self.stores.values() {
-                if store.is_stream_available(stream_index).await {
-                    let _task_handle = tokio::spawn(
-                        drainer_handler(
-                            store.clone(),
-                            stream_index,
-                            self.conf.max_read_count,
-                            self.active_tasks.clone(),
-                            jobs_picked.clone(),
-                        )
-                        .in_current_span(),
-                    );
+                let tuning = store.get_drainer_tuning().await;
+                let max_read_count = tuning.max_read_count.unwrap_or(self.conf.max_read_count);
+                let concurrency = tuning
+                    .concurrency
+                    .unwrap_or(1)
+                    .clamp(1, self.conf.num_partitions.max(1));
+
+                // The shards following the current one are drained along with it, the shards
+                // already being drained being skipped as they are not available
+                for offset in 0..concurrency {
+                    let shard_index = (u16::from(stream_index) + u16::from(offset))
+                        .checked_rem(u16::from(self.conf.num_partitions))
+                        .and_then(|shard_index| u8::try_from(shard_index).ok())
+                        .unwrap_or(stream_index);
+                    if store.is_stream_available(shard_index).await {
+                        let _task_handle = tokio::spawn(
+                            drainer_handler(
+                                store.clone(),
+                                shard_index,
+                                max_read_count,
+                                self.active_tasks.clone(),
+                                jobs_picked.clone(),
+                            )
+                            .in_current_span(),
+                        );
+                    }
                 }
             }
             stream_index = utils::increment_stream_index(
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use diesel_models::kv;
 use redis_interface as redis;
 use router_env::{logger, tracing};
 
@@ -9,6 +10,24 @@ pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
 impl Store {
+    /// The tuning of the drainer set through the API, the configuration being used as is when
+    /// the tuning cannot be fetched
+    pub async fn get_drainer_tuning(&self) -> kv::DrainerTuning {
+        match self
+            .redis_conn
+            .get_and_deserialize_key::<kv::DrainerTuning>(kv::DRAINER_TUNING_KEY, "DrainerTuning")
+            .await
+        {
+            Ok(tuning) => tuning,
+            Err(error) => {
+                if !matches!(error.current_context(), redis::errors::RedisError::NotFound) {
+                    logger::error!(operation = "get_drainer_tuning", err=?error);
+                }
+                kv::DrainerTuning::default()
+            }
+        }
+    }
+
     #[inline(always)]
     pub fn drainer_stream(&self, shard_key: &str) -> String {
         // Example: {shard_5}_drainer_stream
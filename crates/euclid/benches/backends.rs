@@ -45,6 +45,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             business_country: Some(enums::Country::UnitedStatesOfAmerica),
             billing_country: Some(enums::Country::France),
             business_label: None,
+            device_fingerprint: None,
             setup_future_usage: None,
         },
         payment_method: inputs::PaymentMethodInput {
@@ -27,6 +27,7 @@ pub struct PaymentInput {
     pub business_country: Option<enums::Country>,
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
+    pub device_fingerprint: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
 }
 
@@ -138,6 +138,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -178,6 +179,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -219,6 +221,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -260,6 +263,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -301,6 +305,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -342,6 +347,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -383,6 +389,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -424,6 +431,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
             },
             payment_method: inputs::PaymentMethodInput {
@@ -467,6 +475,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -508,6 +517,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -558,6 +568,7 @@ mod test {
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
                 business_label: None,
+                device_fingerprint: None,
                 setup_future_usage: None,
             },
             payment_method: inputs::PaymentMethodInput {
@@ -101,6 +101,11 @@ impl Context {
                 value: business_label,
             }));
         }
+        if let Some(device_fingerprint) = payment.device_fingerprint {
+            enum_values.insert(EuclidValue::DeviceFingerprint(StrValue {
+                value: device_fingerprint,
+            }));
+        }
         if let Some(setup_future_usage) = payment.setup_future_usage {
             enum_values.insert(EuclidValue::SetupFutureUsage(setup_future_usage));
         }
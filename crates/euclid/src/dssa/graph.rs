@@ -66,6 +66,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::VoucherType(vt) => vt.to_string(),
             Self::GiftCardType(gct) => gct.to_string(),
             Self::BusinessLabel(bl) => bl.value.to_string(),
+            Self::DeviceFingerprint(df) => df.value.to_string(),
             Self::SetupFutureUsage(sfu) => sfu.to_string(),
             Self::CardRedirectType(crt) => crt.to_string(),
             Self::RealTimePaymentType(rtpt) => rtpt.to_string(),
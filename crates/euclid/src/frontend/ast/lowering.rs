@@ -291,6 +291,8 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
 
+        dir::DirKeyKind::DeviceFingerprint => lower_str!(DeviceFingerprint, value),
+
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
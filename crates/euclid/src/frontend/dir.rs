@@ -297,6 +297,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "business_label")]
     BusinessLabel,
+    #[strum(
+        serialize = "device_fingerprint",
+        detailed_message = "Fingerprint or session identifier of the device of the customer",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "device_fingerprint")]
+    DeviceFingerprint,
     #[strum(
         serialize = "setup_future_usage",
         detailed_message = "Identifier for recurring payments",
@@ -363,6 +370,7 @@ impl DirKeyKind {
             Self::Connector => types::DataType::EnumVariant,
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
+            Self::DeviceFingerprint => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
             Self::CardRedirectType => types::DataType::EnumVariant,
             Self::RealTimePaymentType => types::DataType::EnumVariant,
@@ -482,6 +490,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::BusinessLabel => None,
+            Self::DeviceFingerprint => None,
             Self::SetupFutureUsage => Some(
                 enums::SetupFutureUsage::iter()
                     .map(DirValue::SetupFutureUsage)
@@ -558,6 +567,8 @@ pub enum DirValue {
     Connector(Box<ast::ConnectorChoice>),
     #[serde(rename = "business_label")]
     BusinessLabel(types::StrValue),
+    #[serde(rename = "device_fingerprint")]
+    DeviceFingerprint(types::StrValue),
     #[serde(rename = "setup_future_usage")]
     SetupFutureUsage(enums::SetupFutureUsage),
     #[serde(rename = "card_redirect")]
@@ -593,6 +604,7 @@ impl DirValue {
             Self::MandateType(_) => (DirKeyKind::MandateType, None),
             Self::PaymentType(_) => (DirKeyKind::PaymentType, None),
             Self::BusinessLabel(_) => (DirKeyKind::BusinessLabel, None),
+            Self::DeviceFingerprint(_) => (DirKeyKind::DeviceFingerprint, None),
             Self::SetupFutureUsage(_) => (DirKeyKind::SetupFutureUsage, None),
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
@@ -630,6 +642,7 @@ impl DirValue {
             Self::MandateType(_) => None,
             Self::PaymentType(_) => None,
             Self::BusinessLabel(_) => None,
+            Self::DeviceFingerprint(_) => None,
             Self::SetupFutureUsage(_) => None,
             Self::CardRedirectType(_) => None,
             Self::RealTimePaymentType(_) => None,
@@ -675,6 +688,7 @@ impl DirValue {
             (Self::RealTimePaymentType(rtp1), Self::RealTimePaymentType(rtp2)) => rtp1 == rtp2,
             (Self::Connector(c1), Self::Connector(c2)) => c1 == c2,
             (Self::BusinessLabel(bl1), Self::BusinessLabel(bl2)) => bl1 == bl2,
+            (Self::DeviceFingerprint(df1), Self::DeviceFingerprint(df2)) => df1 == df2,
             (Self::SetupFutureUsage(sfu1), Self::SetupFutureUsage(sfu2)) => sfu1 == sfu2,
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
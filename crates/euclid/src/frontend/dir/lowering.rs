@@ -237,6 +237,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::BankDebitType(bdt) => EuclidValue::PaymentMethodType(bdt.into()),
         dir::DirValue::RewardType(rt) => EuclidValue::PaymentMethodType(rt.into()),
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
+        dir::DirValue::DeviceFingerprint(df) => EuclidValue::DeviceFingerprint(df),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
     })
 }
@@ -57,6 +57,8 @@ pub enum EuclidKey {
     BillingCountry,
     #[strum(serialize = "business_label")]
     BusinessLabel,
+    #[strum(serialize = "device_fingerprint")]
+    DeviceFingerprint,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
 }
@@ -136,6 +138,7 @@ impl EuclidKey {
             Self::MandateAcceptanceType => DataType::EnumVariant,
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
+            Self::DeviceFingerprint => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
         }
     }
@@ -251,6 +254,7 @@ pub enum EuclidValue {
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
+    DeviceFingerprint(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
 }
 
@@ -279,6 +283,7 @@ impl EuclidValue {
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
+            Self::DeviceFingerprint(_) => EuclidKey::DeviceFingerprint,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
         }
     }
@@ -268,6 +268,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::DeviceFingerprint
         | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
     };
 
@@ -18,7 +18,7 @@ pub mod no_encryption;
 pub mod managers;
 
 /// Crate specific constants
-#[cfg(feature = "aws_kms")]
+#[cfg(any(feature = "aws_kms", feature = "hashicorp-vault"))]
 pub mod consts {
     /// General purpose base64 engine
     pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
@@ -5,12 +5,16 @@
 use std::sync::Arc;
 
 use common_utils::errors::CustomResult;
+#[cfg(feature = "hashicorp-vault")]
+use error_stack::ResultExt;
 use hyperswitch_interfaces::encryption_interface::{
     EncryptionError, EncryptionManagementInterface,
 };
 
 #[cfg(feature = "aws_kms")]
 use crate::aws_kms;
+#[cfg(feature = "hashicorp-vault")]
+use crate::hashicorp_vault;
 use crate::no_encryption::core::NoEncryption;
 
 /// Enum representing configuration options for encryption management.
@@ -25,6 +29,13 @@ pub enum EncryptionManagementConfig {
         aws_kms: aws_kms::core::AwsKmsConfig,
     },
 
+    /// HashiCorp Vault transit secrets engine configuration
+    #[cfg(feature = "hashicorp-vault")]
+    HashiCorpVault {
+        /// HC-Vault transit config
+        hc_vault: hashicorp_vault::core::HashiCorpVaultTransitConfig,
+    },
+
     /// Variant representing no encryption
     #[default]
     NoEncryption,
@@ -36,6 +47,8 @@ impl EncryptionManagementConfig {
         match self {
             #[cfg(feature = "aws_kms")]
             Self::AwsKms { aws_kms } => aws_kms.validate(),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashiCorpVault { hc_vault } => hc_vault.validate(),
 
             Self::NoEncryption => Ok(()),
         }
@@ -48,6 +61,11 @@ impl EncryptionManagementConfig {
         Ok(match self {
             #[cfg(feature = "aws_kms")]
             Self::AwsKms { aws_kms } => Arc::new(aws_kms::core::AwsKmsClient::new(aws_kms).await),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashiCorpVault { hc_vault } => Arc::new(
+                hashicorp_vault::core::HashiCorpVaultTransit::new(hc_vault)
+                    .change_context(EncryptionError::ClientCreationFailed)?,
+            ),
 
             Self::NoEncryption => Arc::new(NoEncryption),
         })
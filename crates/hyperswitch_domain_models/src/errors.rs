@@ -38,4 +38,10 @@ pub enum StorageError {
     // TODO: deprecate this error type to use a domain error instead
     #[error("RedisError: {0:?}")]
     RedisError(String),
+    #[error("The status of the {entity} cannot move from {from} to {to}")]
+    IllegalStatusTransition {
+        entity: &'static str,
+        from: String,
+        to: String,
+    },
 }
@@ -275,6 +275,10 @@ pub enum ApiErrorResponse {
     MissingTenantId,
     #[error(error_type = ErrorType::ProcessingError, code = "HE_06", message = "Invalid tenant id: {tenant_id}")]
     InvalidTenant { tenant_id: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "Rate limit exceeded, retry after {retry_after_in_secs} seconds")]
+    RateLimitExceeded { retry_after_in_secs: u64 },
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_07", message = "Server is overloaded, retry after {retry_after_in_secs} seconds")]
+    ServerOverloaded { retry_after_in_secs: u64 },
 }
 
 #[derive(Clone)]
@@ -613,6 +617,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InvalidTenant { tenant_id }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Invalid Tenant {tenant_id}"), None))
             }
+            Self::RateLimitExceeded { retry_after_in_secs } => {
+                AER::TooManyRequests(ApiError::new("IR", 28, format!("Rate limit exceeded, retry after {retry_after_in_secs} seconds"), None), *retry_after_in_secs)
+            }
+            Self::ServerOverloaded { retry_after_in_secs } => {
+                AER::TooManyRequests(ApiError::new("HE", 7, format!("Server is overloaded, retry after {retry_after_in_secs} seconds"), None), *retry_after_in_secs)
+            }
         }
     }
 }
@@ -817,6 +817,7 @@ impl From<api_models::payments::CardToken> for CardToken {
         let api_models::payments::CardToken {
             card_holder_name,
             card_cvc,
+            card_network: _,
         } = value;
         Self {
             card_holder_name,
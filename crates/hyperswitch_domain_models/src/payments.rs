@@ -3,6 +3,7 @@ use time::PrimitiveDateTime;
 
 pub mod payment_attempt;
 pub mod payment_intent;
+pub mod state_machine;
 
 use common_enums as storage_enums;
 
@@ -61,4 +62,8 @@ pub struct PaymentIntent {
     pub request_external_three_ds_authentication: Option<bool>,
     pub charges: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub tax_details: Option<serde_json::Value>,
+    pub order_id: Option<String>,
+    pub merchant_reference: Option<String>,
+    pub custom_fields: Option<serde_json::Value>,
 }
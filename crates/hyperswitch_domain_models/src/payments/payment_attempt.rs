@@ -104,6 +104,7 @@ pub trait PaymentAttemptInterface {
         payment_method_type: Option<Vec<storage_enums::PaymentMethodType>>,
         authentication_type: Option<Vec<storage_enums::AuthenticationType>>,
         merchant_connector_id: Option<Vec<String>>,
+        card_network: Option<Vec<storage_enums::CardNetwork>>,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<i64, errors::StorageError>;
 }
@@ -172,6 +173,9 @@ pub struct PaymentAttempt {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub failover_action: Option<String>,
+    pub unified_error_details: Option<serde_json::Value>,
+    pub amount_reversed: Option<MinorUnit>,
 }
 
 impl PaymentAttempt {
@@ -261,6 +265,9 @@ pub struct PaymentAttemptNew {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub failover_action: Option<String>,
+    pub unified_error_details: Option<serde_json::Value>,
+    pub amount_reversed: Option<MinorUnit>,
 }
 
 impl PaymentAttemptNew {
@@ -412,6 +419,7 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
+        unified_error_details: Option<serde_json::Value>,
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
@@ -463,6 +471,14 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<String>,
         unified_message: Option<String>,
     },
+    FailoverUpdate {
+        failover_action: String,
+        updated_by: String,
+    },
+    AuthorizationReversalUpdate {
+        amount_reversed: MinorUnit,
+        updated_by: String,
+    },
 }
 
 impl ForeignIDRef for PaymentAttempt {
@@ -525,6 +541,10 @@ impl behaviour::Conversion for PaymentIntent {
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
             charges: self.charges,
             frm_metadata: self.frm_metadata,
+            tax_details: self.tax_details,
+            order_id: self.order_id,
+            merchant_reference: self.merchant_reference,
+            custom_fields: self.custom_fields,
         })
     }
 
@@ -580,6 +600,10 @@ impl behaviour::Conversion for PaymentIntent {
                 .request_external_three_ds_authentication,
             charges: storage_model.charges,
             frm_metadata: storage_model.frm_metadata,
+            tax_details: storage_model.tax_details,
+            order_id: storage_model.order_id,
+            merchant_reference: storage_model.merchant_reference,
+            custom_fields: storage_model.custom_fields,
         })
     }
 
@@ -628,6 +652,10 @@ impl behaviour::Conversion for PaymentIntent {
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
             charges: self.charges,
             frm_metadata: self.frm_metadata,
+            tax_details: self.tax_details,
+            order_id: self.order_id,
+            merchant_reference: self.merchant_reference,
+            custom_fields: self.custom_fields,
         })
     }
 }
@@ -218,6 +218,16 @@ pub enum PaymentIntentUpdate {
         status: Option<storage_enums::IntentStatus>,
         updated_by: String,
     },
+    TaxDetailsUpdate {
+        amount: MinorUnit,
+        tax_details: serde_json::Value,
+        updated_by: String,
+    },
+    MerchantDetailsUpdate {
+        metadata: Option<pii::SecretSerdeValue>,
+        merchant_reference: Option<String>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -255,6 +265,8 @@ pub struct PaymentIntentUpdateInternal {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub tax_details: Option<serde_json::Value>,
+    pub merchant_reference: Option<String>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -452,6 +464,28 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::TaxDetailsUpdate {
+                amount,
+                tax_details,
+                updated_by,
+            } => Self {
+                amount: Some(amount),
+                tax_details: Some(tax_details),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::MerchantDetailsUpdate {
+                metadata,
+                merchant_reference,
+                updated_by,
+            } => Self {
+                metadata,
+                merchant_reference,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -624,6 +658,24 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => {
                 Self::ManualUpdate { status, updated_by }
             }
+            PaymentIntentUpdate::TaxDetailsUpdate {
+                amount,
+                tax_details,
+                updated_by,
+            } => Self::TaxDetailsUpdate {
+                amount,
+                tax_details,
+                updated_by,
+            },
+            PaymentIntentUpdate::MerchantDetailsUpdate {
+                metadata,
+                merchant_reference,
+                updated_by,
+            } => Self::MerchantDetailsUpdate {
+                metadata,
+                merchant_reference,
+                updated_by,
+            },
         }
     }
 }
@@ -663,6 +715,8 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             fingerprint_id,
             request_external_three_ds_authentication,
             frm_metadata,
+            tax_details,
+            merchant_reference,
         } = value;
 
         Self {
@@ -696,6 +750,8 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             fingerprint_id,
             request_external_three_ds_authentication,
             frm_metadata,
+            tax_details,
+            merchant_reference,
         }
     }
 }
@@ -722,6 +778,11 @@ pub struct PaymentIntentListParams {
     pub starting_after_id: Option<String>,
     pub ending_before_id: Option<String>,
     pub limit: Option<u32>,
+    pub cursor: Option<api_models::payments::ListCursor>,
+    pub card_network: Option<Vec<storage_enums::CardNetwork>>,
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    pub order_id: Option<String>,
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchConstraints {
@@ -743,6 +804,11 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             starting_after_id: value.starting_after,
             ending_before_id: value.ending_before,
             limit: Some(std::cmp::min(value.limit, PAYMENTS_LIST_MAX_LIMIT_V1)),
+            cursor: None,
+            card_network: None,
+            metadata: None,
+            order_id: None,
+            custom_fields: None,
         }))
     }
 }
@@ -766,6 +832,11 @@ impl From<api_models::payments::TimeRange> for PaymentIntentFetchConstraints {
             starting_after_id: None,
             ending_before_id: None,
             limit: None,
+            cursor: None,
+            card_network: None,
+            metadata: None,
+            order_id: None,
+            custom_fields: None,
         }))
     }
 }
@@ -792,6 +863,11 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 starting_after_id: None,
                 ending_before_id: None,
                 limit: Some(std::cmp::min(value.limit, PAYMENTS_LIST_MAX_LIMIT_V2)),
+                cursor: value.cursor,
+                card_network: value.card_network,
+                metadata: value.metadata,
+                order_id: None,
+                custom_fields: value.custom_fields,
             }))
         }
     }
@@ -0,0 +1,175 @@
+//! The transitions allowed between the statuses of payment intents and of payment attempts. A
+//! status can always be set again, the other transitions out of a status are listed with it.
+
+use common_enums::{AttemptStatus, IntentStatus};
+
+use crate::errors::StorageError;
+
+/// Whether the status of a payment intent can move from `from` to `to`
+pub fn is_intent_transition_allowed(from: IntentStatus, to: IntentStatus) -> bool {
+    if from == to {
+        return true;
+    }
+
+    match from {
+        // A payment is over once it succeeded, was cancelled or had its uncaptured amount released
+        IntentStatus::Succeeded | IntentStatus::Cancelled | IntentStatus::PartiallyCaptured => {
+            false
+        }
+        // The amount authorized can only be captured, released or fail to be captured
+        IntentStatus::RequiresCapture | IntentStatus::PartiallyCapturedAndCapturable => matches!(
+            to,
+            IntentStatus::Succeeded
+                | IntentStatus::Failed
+                | IntentStatus::Cancelled
+                | IntentStatus::Processing
+                | IntentStatus::RequiresCapture
+                | IntentStatus::PartiallyCaptured
+                | IntentStatus::PartiallyCapturedAndCapturable
+        ),
+        // Failed payments can be retried, the payments not yet authorized can move to any status
+        IntentStatus::Failed
+        | IntentStatus::Processing
+        | IntentStatus::RequiresCustomerAction
+        | IntentStatus::RequiresMerchantAction
+        | IntentStatus::RequiresPaymentMethod
+        | IntentStatus::RequiresConfirmation => true,
+    }
+}
+
+/// Whether the status of a payment attempt can move from `from` to `to`
+pub fn is_attempt_transition_allowed(from: AttemptStatus, to: AttemptStatus) -> bool {
+    if from == to {
+        return true;
+    }
+
+    match from {
+        // A captured attempt can only be refunded automatically
+        AttemptStatus::Charged | AttemptStatus::PartialCharged => {
+            matches!(to, AttemptStatus::AutoRefunded)
+        }
+        AttemptStatus::Voided | AttemptStatus::AutoRefunded => false,
+        // The amount authorized can only be captured, voided or fail to be captured or voided
+        AttemptStatus::Authorized
+        | AttemptStatus::CaptureInitiated
+        | AttemptStatus::CaptureFailed
+        | AttemptStatus::VoidInitiated
+        | AttemptStatus::VoidFailed
+        | AttemptStatus::PartialChargedAndChargeable => matches!(
+            to,
+            AttemptStatus::Authorized
+                | AttemptStatus::Charged
+                | AttemptStatus::PartialCharged
+                | AttemptStatus::PartialChargedAndChargeable
+                | AttemptStatus::CaptureInitiated
+                | AttemptStatus::CaptureFailed
+                | AttemptStatus::VoidInitiated
+                | AttemptStatus::VoidFailed
+                | AttemptStatus::Voided
+                | AttemptStatus::AutoRefunded
+                | AttemptStatus::Failure
+                | AttemptStatus::Pending
+        ),
+        AttemptStatus::Started
+        | AttemptStatus::AuthenticationFailed
+        | AttemptStatus::RouterDeclined
+        | AttemptStatus::AuthenticationPending
+        | AttemptStatus::AuthenticationSuccessful
+        | AttemptStatus::AuthorizationFailed
+        | AttemptStatus::Authorizing
+        | AttemptStatus::CodInitiated
+        | AttemptStatus::Unresolved
+        | AttemptStatus::Pending
+        | AttemptStatus::Failure
+        | AttemptStatus::PaymentMethodAwaited
+        | AttemptStatus::ConfirmationAwaited
+        | AttemptStatus::DeviceDataCollectionPending => true,
+    }
+}
+
+pub fn validate_intent_transition(
+    from: IntentStatus,
+    to: IntentStatus,
+) -> error_stack::Result<(), StorageError> {
+    if is_intent_transition_allowed(from, to) {
+        Ok(())
+    } else {
+        Err(error_stack::report!(
+            StorageError::IllegalStatusTransition {
+                entity: "payment_intent",
+                from: from.to_string(),
+                to: to.to_string(),
+            }
+        ))
+    }
+}
+
+pub fn validate_attempt_transition(
+    from: AttemptStatus,
+    to: AttemptStatus,
+) -> error_stack::Result<(), StorageError> {
+    if is_attempt_transition_allowed(from, to) {
+        Ok(())
+    } else {
+        Err(error_stack::report!(
+            StorageError::IllegalStatusTransition {
+                entity: "payment_attempt",
+                from: from.to_string(),
+                to: to.to_string(),
+            }
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intent_transitions() {
+        assert!(is_intent_transition_allowed(
+            IntentStatus::RequiresConfirmation,
+            IntentStatus::Succeeded
+        ));
+        assert!(is_intent_transition_allowed(
+            IntentStatus::Failed,
+            IntentStatus::Processing
+        ));
+        assert!(is_intent_transition_allowed(
+            IntentStatus::Succeeded,
+            IntentStatus::Succeeded
+        ));
+        assert!(!is_intent_transition_allowed(
+            IntentStatus::Succeeded,
+            IntentStatus::Failed
+        ));
+        assert!(!is_intent_transition_allowed(
+            IntentStatus::RequiresCapture,
+            IntentStatus::RequiresPaymentMethod
+        ));
+    }
+
+    #[test]
+    fn test_attempt_transitions() {
+        assert!(is_attempt_transition_allowed(
+            AttemptStatus::Pending,
+            AttemptStatus::Charged
+        ));
+        assert!(is_attempt_transition_allowed(
+            AttemptStatus::Charged,
+            AttemptStatus::AutoRefunded
+        ));
+        assert!(!is_attempt_transition_allowed(
+            AttemptStatus::Charged,
+            AttemptStatus::Pending
+        ));
+        assert!(!is_attempt_transition_allowed(
+            AttemptStatus::Voided,
+            AttemptStatus::Charged
+        ));
+        assert!(!is_attempt_transition_allowed(
+            AttemptStatus::Authorized,
+            AttemptStatus::AuthenticationPending
+        ));
+    }
+}
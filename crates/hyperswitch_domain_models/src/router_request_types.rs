@@ -370,6 +370,9 @@ pub struct PaymentsCancelData {
 
     // minor amount data for amount framework
     pub minor_amount: Option<MinorUnit>,
+    /// The amount of the authorization to release, the rest of the authorization being kept
+    /// capturable. The whole authorization is released when not provided.
+    pub minor_amount_to_reverse: Option<MinorUnit>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -396,6 +399,7 @@ pub struct BrowserInformation {
     pub ip_address: Option<std::net::IpAddr>,
     pub accept_header: Option<String>,
     pub user_agent: Option<String>,
+    pub device_fingerprint: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize)]
@@ -434,6 +438,8 @@ pub struct SurchargeDetails {
     pub tax_on_surcharge_amount: MinorUnit,
     /// sum of original amount,
     pub final_amount: MinorUnit,
+    /// the jurisdiction whose legal cap limited the surcharge, if the surcharge was capped
+    pub capped_jurisdiction: Option<common_enums::CountryAlpha2>,
 }
 
 impl SurchargeDetails {
@@ -473,6 +479,7 @@ impl
             surcharge_amount,
             tax_on_surcharge_amount,
             final_amount: payment_attempt.amount + surcharge_amount + tax_on_surcharge_amount,
+            capped_jurisdiction: None,
         }
     }
 }
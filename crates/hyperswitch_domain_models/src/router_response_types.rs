@@ -10,6 +10,11 @@ use crate::router_request_types::{authentication::AuthNFlowType, ResponseId};
 pub struct RefundsResponseData {
     pub connector_refund_id: String,
     pub refund_status: common_enums::RefundStatus,
+    /// The acquirer reference number of the refund, with which the cardholder can trace the
+    /// refund at their issuer
+    pub refund_arn: Option<String>,
+    /// The time by which the refunded amount is expected to reach the customer
+    pub estimated_arrival_at: Option<time::PrimitiveDateTime>,
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
@@ -199,10 +199,9 @@ pub trait ConnectorCommon {
     /// Name of the connector (in lowercase).
     fn id(&self) -> &'static str;
 
-    /// Connector accepted currency unit as either "Base" or "Minor"
-    fn get_currency_unit(&self) -> CurrencyUnit {
-        CurrencyUnit::Minor // Default implementation should be remove once it is implemented in all connectors
-    }
+    /// Connector accepted currency unit as either "Base" or "Minor". Every connector declares it,
+    /// so that the amounts are never sent in a unit the connector does not expect.
+    fn get_currency_unit(&self) -> CurrencyUnit;
 
     /// HTTP header used for authorization.
     fn get_auth_header(
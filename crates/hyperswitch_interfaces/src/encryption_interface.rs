@@ -26,4 +26,8 @@ pub enum EncryptionError {
     /// An error occurred when decrypting input data.
     #[error("Failed to decrypt input data")]
     DecryptionFailed,
+
+    /// An error occurred when creating the client of the encryption manager.
+    #[error("Failed to create the encryption client")]
+    ClientCreationFailed,
 }
@@ -6,6 +6,30 @@ use serde::Serialize;
 use serde_json::json;
 use time::OffsetDateTime;
 
+/// Class of the failure of a connector call, used for the availability analytics of connectors
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorErrorClass {
+    /// The connector responded with a 4xx status code
+    ClientError,
+    /// The connector responded with a 5xx status code
+    ServerError,
+    /// The call timed out before the connector responded
+    Timeout,
+    /// The call could not be sent to the connector
+    NetworkError,
+}
+
+impl ConnectorErrorClass {
+    fn from_status_code(status_code: u16) -> Option<Self> {
+        match status_code {
+            400..=499 => Some(Self::ClientError),
+            500..=599 => Some(Self::ServerError),
+            _ => None,
+        }
+    }
+}
+
 /// struct ConnectorEvent
 #[derive(Debug, Serialize)]
 pub struct ConnectorEvent {
@@ -25,6 +49,7 @@ pub struct ConnectorEvent {
     refund_id: Option<String>,
     dispute_id: Option<String>,
     status_code: u16,
+    error_class: Option<ConnectorErrorClass>,
 }
 
 impl ConnectorEvent {
@@ -66,9 +91,15 @@ impl ConnectorEvent {
             refund_id,
             dispute_id,
             status_code,
+            error_class: ConnectorErrorClass::from_status_code(status_code),
         }
     }
 
+    /// fn set_error_class
+    pub fn set_error_class(&mut self, error_class: ConnectorErrorClass) {
+        self.error_class = Some(error_class);
+    }
+
     /// fn set_response_body
     pub fn set_response_body<T: Serialize>(&mut self, response: &T) {
         match masking::masked_serialize(response) {
@@ -93,4 +124,59 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// The name of the connector called
+    pub fn connector_name(&self) -> &str {
+        &self.connector_name
+    }
+
+    /// The payment the connector was called for
+    pub fn payment_id(&self) -> &str {
+        &self.payment_id
+    }
+
+    /// The merchant the connector was called for
+    pub fn merchant_id(&self) -> &str {
+        &self.merchant_id
+    }
+
+    /// The flow the connector was called for
+    pub fn flow(&self) -> &str {
+        &self.flow
+    }
+
+    /// The URL the request was sent to
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The HTTP method of the request
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// The body of the request, with the sensitive data masked
+    pub fn masked_request(&self) -> &str {
+        &self.request
+    }
+
+    /// The body of the response, with the sensitive data masked
+    pub fn masked_response(&self) -> Option<&str> {
+        self.masked_response.as_deref()
+    }
+
+    /// The error returned by the connector or encountered while calling it
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The HTTP status code of the response
+    pub fn status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    /// The time taken by the connector to respond, in milliseconds
+    pub fn latency(&self) -> u128 {
+        self.latency
+    }
 }
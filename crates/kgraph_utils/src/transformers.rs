@@ -40,6 +40,11 @@ impl IntoContext for BackendInput {
                 value: business_label,
             }));
         }
+        if let Some(device_fingerprint) = self.payment.device_fingerprint {
+            ctx.push(dir::DirValue::DeviceFingerprint(StrValue {
+                value: device_fingerprint,
+            }));
+        }
         if let Some(billing_country) = self.payment.billing_country {
             ctx.push(dir::DirValue::BillingCountry(billing_country));
         }
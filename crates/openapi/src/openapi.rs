@@ -60,12 +60,28 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
+        (name = "Payment Method Sessions", description = "Collect and save payment methods of customers outside of payments"),
         (name = "Disputes", description = "Manage disputes"),
         (name = "API Key", description = "Create and manage API Keys"),
+        (name = "OAuth2", description = "Issue and revoke OAuth2 access tokens"),
         (name = "Payouts", description = "Create and manage payouts"),
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Orders", description = "Group the payments made towards an order"),
+        (name = "Authentication", description = "Authenticate cards with 3DS without authorizing payments"),
+        (name = "Reports", description = "Create and manage scheduled reports"),
+        (name = "Settlements", description = "Reconcile the settlements of the connectors"),
+        (name = "Ledger", description = "Track the money movements of the merchant as balanced ledger entries"),
+        (name = "Connected Accounts", description = "Onboard and manage the sub-merchants of a platform"),
+        (name = "Audit Events", description = "Track the mutations of the configuration of the merchants"),
+        (name = "Organization", description = "Manage the merchants of an organization"),
+        (name = "Tenants", description = "Provision and manage the tenants of the deployment"),
+        (name = "Search", description = "Search the payments, refunds, disputes and customers"),
+        (name = "GraphQL", description = "Read the payments, refunds, disputes, customers and analytics in a single request"),
+        (name = "Card Exports", description = "Export the vaulted cards to another payment service provider"),
+        (name = "Connector Migrations", description = "Migrate the stored credentials of the payment methods to another connector"),
+        (name = "Connector Capabilities", description = "Discover the capabilities of the connectors"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -75,6 +91,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_confirm,
         routes::payments::payments_retrieve,
         routes::payments::payments_capture,
+        routes::payments::payments_capture_schedule,
+        routes::payments::payments_capture_schedule_cancel,
+        routes::payments::payments_metadata_update,
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
         routes::payments::payments_list,
@@ -85,6 +104,7 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for refunds
         routes::refunds::refunds_create,
+        routes::refunds::refunds_create_bulk,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
@@ -95,6 +115,20 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::merchant_account_kv_settings_retrieve,
+        routes::merchant_account::merchant_account_kv_settings_update,
+        routes::merchant_account::merchant_account_rate_limits_retrieve,
+        routes::merchant_account::merchant_account_rate_limits_update,
+        routes::merchant_account::merchant_account_onboarding_status,
+        routes::merchant_account::merchant_account_response_field_access_retrieve,
+        routes::merchant_account::merchant_account_response_field_access_update,
+        routes::merchant_account::merchant_account_api_version_retrieve,
+        routes::merchant_account::merchant_account_api_version_update,
+        routes::merchant_account::merchant_account_key_rotation_schedule,
+        routes::merchant_account::merchant_account_key_rotation_retrieve,
+        routes::merchant_account::drainer_tuning_retrieve,
+        routes::merchant_account::drainer_tuning_update,
+        routes::merchant_account::kv_backlog_retrieve,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::payment_connector_create,
@@ -102,6 +136,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::payment_connector_list,
         routes::merchant_connector_account::payment_connector_update,
         routes::merchant_connector_account::payment_connector_delete,
+        routes::merchant_connector_account::payment_connector_validate,
+        routes::merchant_connector_account::payment_connector_credentials_stage,
+        routes::merchant_connector_account::payment_connector_credentials_cutover,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -109,10 +146,29 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
 
+        // Routes for feature flags
+        routes::feature_flags::retrieve_feature_flag,
+        routes::feature_flags::update_feature_flag,
+        routes::feature_flags::delete_feature_flag,
+        routes::pm_filters::retrieve_pm_filters,
+        routes::pm_filters::update_pm_filters,
+        routes::pm_filters::delete_pm_filters,
+
+        // Routes for sandbox traffic
+        routes::sandbox_traffic::generate_sandbox_traffic,
+
+        // Routes for bulk force syncs
+        routes::bulk_force_sync::schedule_bulk_force_sync,
+        routes::bulk_force_sync::retrieve_bulk_force_sync,
+        routes::master_key_rotation::schedule_master_key_rotation,
+        routes::master_key_rotation::retrieve_master_key_rotation,
+
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
         routes::mandates::customers_mandates_list,
+        routes::mandates::migrate_mandates,
+        routes::mandates::schedule_mandate_pre_notification,
 
         //Routes for customers
         routes::customers::customers_create,
@@ -120,6 +176,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customers_overview,
+        routes::customers::customers_redact,
+        routes::customers::customers_redaction_retrieve,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -127,9 +186,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::list_customer_payment_method_api,
         routes::payment_method::list_customer_payment_method_api_client,
         routes::payment_method::default_payment_method_set_api,
+        routes::payment_method::payment_method_order_set_api,
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::payment_method_usage_restrictions_set_api,
+
+        // Routes for payment method sessions
+        routes::payment_method_sessions::create_payment_method_session,
+        routes::payment_method_sessions::confirm_payment_method_session,
+        routes::payment_method_sessions::retrieve_payment_method_session,
 
         // Routes for Business Profile
         routes::business_profile::business_profile_create,
@@ -137,6 +203,100 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::business_profile::business_profiles_retrieve,
         routes::business_profile::business_profiles_update,
         routes::business_profile::business_profiles_delete,
+        routes::business_profile::velocity_limits_upsert,
+        routes::business_profile::velocity_limits_retrieve,
+        routes::business_profile::velocity_limits_delete,
+        routes::business_profile::pm_display_rules_upsert,
+        routes::business_profile::pm_display_rules_retrieve,
+        routes::business_profile::pm_display_rules_delete,
+        routes::business_profile::wallet_decryption_key_create,
+        routes::business_profile::wallet_decryption_keys_list,
+        routes::business_profile::wallet_decryption_key_retire,
+        routes::business_profile::business_profile_clone,
+        routes::business_profile::business_profile_template_upsert,
+        routes::business_profile::business_profile_templates_list,
+        routes::business_profile::business_profile_template_delete,
+        routes::business_profile::business_profile_template_apply,
+
+        // Routes for orders
+        routes::orders::create_order,
+        routes::orders::retrieve_order,
+        routes::orders::update_order,
+
+        // Routes for standalone authentication
+        routes::authentication::create_authentication,
+        routes::authentication::authenticate,
+        routes::authentication::retrieve_authentication,
+
+        // Routes for reports
+        routes::reports::create_report_template,
+        routes::reports::list_report_templates,
+        routes::reports::retrieve_report_template,
+        routes::reports::update_report_template,
+        routes::reports::delete_report_template,
+
+        // Routes for settlements
+        routes::settlements::ingest_settlement_lines,
+        routes::settlements::retrieve_settlement_batch,
+        routes::settlements::rematch_settlement_batch,
+        routes::settlements::list_settlement_exceptions,
+        routes::settlements::resolve_settlement_line,
+        routes::settlements::retrieve_payment_reconciliation,
+
+        // Routes for ledger
+        routes::ledger::retrieve_ledger_balances,
+        routes::ledger::list_ledger_entries,
+        routes::ledger::export_ledger_entries,
+
+        // Routes for connected accounts
+        routes::connected_accounts::create_connected_account,
+        routes::connected_accounts::list_connected_accounts,
+        routes::connected_accounts::retrieve_connected_account,
+        routes::connected_accounts::update_connected_account,
+        routes::connected_accounts::create_connected_account_connector,
+        routes::connected_accounts::get_connected_accounts_report,
+
+        // Routes for audit events
+        routes::audit_events::list_audit_events,
+
+        // Routes for organization
+        routes::organization::aggregate_organization_payments,
+        routes::organization::share_organization_connector,
+
+        // Routes for tenants
+        routes::tenants::create_tenant,
+        routes::tenants::list_tenants,
+        routes::tenants::retrieve_tenant,
+        routes::tenants::update_tenant,
+
+        // Routes for connector capabilities
+        routes::connector_capabilities::retrieve_connector_capabilities,
+
+        // Routes for search
+        routes::search::search,
+
+        // Routes for GraphQL
+        routes::graphql::graphql_query,
+
+        // Routes for card exports
+        routes::card_export::list_card_exports,
+        routes::card_export::retrieve_card_export,
+
+        // Routes for connector migrations
+        routes::connector_migration::create_connector_migration,
+        routes::connector_migration::list_connector_migrations,
+        routes::connector_migration::retrieve_connector_migration,
+        routes::connector_migration::cancel_connector_migration,
+        routes::connector_migration::list_connector_migration_payment_methods,
+
+        // Routes for jobs
+        routes::jobs::retrieve_job,
+        routes::jobs::cancel_job,
+
+        // Routes for the vault
+        routes::vault_tokens::expire_vault_token,
+        routes::vault_tokens::schedule_vault_cleanup,
+        routes::vault_tokens::list_vault_audit_events,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -159,6 +319,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
+        routes::blocklist::remove_entry_from_allowlist,
+        routes::blocklist::list_allowed_entries,
+        routes::blocklist::add_entry_to_allowlist,
 
         // Routes for payouts
         routes::payouts::payouts_create,
@@ -175,11 +338,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::oauth2_token_create,
+        routes::api_keys::oauth2_token_revoke,
 
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::list_incoming_webhooks,
+        routes::webhook_events::retrieve_incoming_webhook,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -214,14 +381,30 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::PaymentMethodDeleteResponse,
         api_models::payment_methods::PaymentMethodUpdate,
         api_models::payment_methods::CustomerDefaultPaymentMethodResponse,
+        api_models::payment_methods::CustomerPaymentMethodOrderRequest,
+        api_models::payment_methods::CustomerPaymentMethodOrderResponse,
+        api_models::payment_methods::PaymentMethodUsageRestrictions,
+        api_models::payment_methods::PaymentMethodUsageRestrictionsResponse,
         api_models::payment_methods::CardDetailFromLocker,
         api_models::payment_methods::PaymentMethodCreateData,
         api_models::payment_methods::CardDetail,
         api_models::payment_methods::CardDetailUpdate,
         api_models::payment_methods::RequestPaymentMethodTypes,
+        api_models::payment_method_sessions::PaymentMethodSessionCreateRequest,
+        api_models::payment_method_sessions::PaymentMethodSessionConfirmRequest,
+        api_models::payment_method_sessions::PaymentMethodSessionStatus,
+        api_models::payment_method_sessions::PaymentMethodSessionResponse,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
         api_models::customers::CustomerResponse,
+        api_models::customers::CustomerOverviewResponse,
+        api_models::customers::CustomerOverviewPaymentMethod,
+        api_models::customers::CustomerOverviewMandate,
+        api_models::customers::CustomerOverviewPayment,
+        api_models::customers::CustomerSpend,
+        api_models::customers::CustomerFraudFlag,
+        api_models::customers::CustomerRedactionResponse,
+        api_models::customers::CustomerRedactionStatus,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::PaymentType,
@@ -230,6 +413,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
+        api_models::enums::AuthenticationConnectorSelection,
+        api_models::enums::ThreeDsChallengePreference,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
@@ -242,16 +427,33 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
+        api_models::enums::CardFundingType,
         api_models::enums::DisputeStage,
         api_models::enums::DisputeStatus,
         api_models::enums::CountryAlpha2,
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
+        api_models::enums::FrmVerdict,
+        api_models::enums::FrmDecisionAction,
+        api_models::enums::FrmReviewAction,
+        api_models::enums::ConnectorFailoverAction,
         api_models::enums::RetryAction,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
         api_models::enums::ReconStatus,
+        api_models::enums::ReportType,
+        api_models::enums::ReportRunStatus,
+        api_models::enums::CardExportStatus,
+        api_models::enums::ConnectorMigrationStatus,
+        api_models::enums::ConnectorMigrationPaymentMethodStatus,
+        api_models::enums::JobType,
+        api_models::enums::JobStatus,
+        api_models::enums::CredentialMigrationMethod,
+        api_models::enums::SettlementLineType,
+        api_models::enums::SettlementReconStatus,
+        api_models::enums::LedgerAccount,
+        api_models::enums::LedgerEventType,
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
         api_models::enums::PaymentMethodStatus,
@@ -259,6 +461,21 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
         api_models::admin::FrmConfigs,
+        api_models::admin::FrmDecisionMatrix,
+        api_models::admin::FrmDecisionRule,
+        api_models::admin::FrmPostAuthReviewConfig,
+        api_models::admin::ConnectorDebugCaptureConfig,
+        api_models::admin::ConnectorFailoverPolicy,
+        api_models::admin::ConnectorCircuitBreakerConfig,
+        api_models::admin::VaultProvider,
+        api_models::admin::TaxProvider,
+        api_models::admin::TaxRule,
+        api_models::admin::DisputeAutoResponseConfig,
+        api_models::admin::DisputeAmountThreshold,
+        api_models::admin::DisputeEvidenceTemplate,
+        api_models::admin::CustomFieldsSchema,
+        api_models::admin::CustomFieldDefinition,
+        api_models::admin::CustomFieldType,
         api_models::admin::FrmPaymentMethod,
         api_models::admin::FrmPaymentMethodType,
         api_models::admin::PaymentMethodsEnabled,
@@ -267,6 +484,109 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
+        api_models::velocity_limits::VelocityLimitDimension,
+        api_models::velocity_limits::VelocityLimitRule,
+        api_models::velocity_limits::VelocityLimitsRequest,
+        api_models::velocity_limits::VelocityLimitsResponse,
+        api_models::pm_display_rules::ClientPlatform,
+        api_models::pm_display_rules::DisplayRuleAction,
+        api_models::pm_display_rules::DisplayRuleConditions,
+        api_models::pm_display_rules::PaymentMethodDisplayRule,
+        api_models::pm_display_rules::PaymentMethodDisplayRulesRequest,
+        api_models::pm_display_rules::PaymentMethodDisplayRulesResponse,
+        api_models::profile_cloning::BusinessProfileCloneRequest,
+        api_models::profile_cloning::BusinessProfileCloneResponse,
+        api_models::profile_cloning::ClonedMerchantConnectorAccount,
+        api_models::profile_cloning::BusinessProfileTemplateRequest,
+        api_models::profile_cloning::BusinessProfileTemplate,
+        api_models::profile_cloning::BusinessProfileTemplatesResponse,
+        api_models::profile_cloning::BusinessProfileTemplateApplyRequest,
+        api_models::connected_accounts::PlatformFee,
+        api_models::connected_accounts::ConnectedAccountSplit,
+        api_models::connected_accounts::ConnectedAccountCreateRequest,
+        api_models::connected_accounts::ConnectedAccountUpdateRequest,
+        api_models::connected_accounts::ConnectedAccountResponse,
+        api_models::connected_accounts::ConnectedAccountsListResponse,
+        api_models::connected_accounts::ConnectedAccountCredentials,
+        api_models::connected_accounts::ConnectedAccountConnectorRequest,
+        api_models::connected_accounts::ConnectedAccountVolume,
+        api_models::connected_accounts::ConnectedAccountReport,
+        api_models::connected_accounts::ConnectedAccountsReport,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyWallet,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyDetails,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyCreateRequest,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyRetireRequest,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyStatus,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyResponse,
+        api_models::wallet_decryption_keys::WalletDecryptionKeyListResponse,
+        api_models::orders::OrderCreateRequest,
+        api_models::orders::OrderUpdateRequest,
+        api_models::orders::OrderStatus,
+        api_models::orders::OrderPayment,
+        api_models::orders::OrderResponse,
+        api_models::authentication::AuthenticationCreateRequest,
+        api_models::authentication::AuthenticationAcquirerDetails,
+        api_models::authentication::AuthenticationAuthenticateRequest,
+        api_models::authentication::AuthenticationResponse,
+        api_models::reports::ReportFrequency,
+        api_models::reports::ReportWeekday,
+        api_models::reports::ReportSchedule,
+        api_models::reports::ReportFilters,
+        api_models::reports::ReportTemplateCreateRequest,
+        api_models::reports::ReportTemplateUpdateRequest,
+        api_models::reports::ReportTemplateResponse,
+        api_models::reports::GeneratedReport,
+        api_models::settlements::SettlementLineInput,
+        api_models::settlements::SettlementLinesIngestRequest,
+        api_models::settlements::SettlementLineResponse,
+        api_models::settlements::SettlementBatchResponse,
+        api_models::settlements::SettlementLineResolveRequest,
+        api_models::settlements::PaymentReconStatus,
+        api_models::settlements::PaymentReconciliationResponse,
+        api_models::ledger::LedgerAccountBalance,
+        api_models::ledger::LedgerBalancesResponse,
+        api_models::ledger::LedgerEntryResponse,
+        api_models::ledger::LedgerEntriesResponse,
+        api_models::audit_events::AuditActorType,
+        api_models::audit_events::AuditResourceType,
+        api_models::audit_events::AuditAction,
+        api_models::audit_events::AuditEventResponse,
+        api_models::audit_events::AuditEventsResponse,
+        api_models::organization::PaymentsAggregateBucket,
+        api_models::organization::MerchantPaymentsAggregate,
+        api_models::organization::OrganizationPaymentsAggregateResponse,
+        api_models::organization::ConnectorShareTarget,
+        api_models::organization::ConnectorShareRequest,
+        api_models::organization::ConnectorShareResult,
+        api_models::organization::ConnectorShareResponse,
+        api_models::tenant::TenantStatus,
+        api_models::tenant::TenantQuotas,
+        api_models::tenant::TenantCreateRequest,
+        api_models::tenant::TenantUpdateRequest,
+        api_models::tenant::TenantIsolationCheck,
+        api_models::tenant::TenantResponse,
+        api_models::search::SearchRequest,
+        api_models::search::SearchObject,
+        api_models::search::SearchField,
+        api_models::search::SearchHit,
+        api_models::search::SearchResponse,
+        api_models::graphql::GraphQLRequest,
+        api_models::graphql::GraphQLResponse,
+        api_models::graphql::GraphQLError,
+        api_models::card_export::CardExportResponse,
+        api_models::connector_migration::ConnectorMigrationCreateRequest,
+        api_models::connector_migration::ConnectorMigrationResponse,
+        api_models::connector_migration::ConnectorMigrationPaymentMethodResponse,
+        api_models::connector_migration::ConnectorMigrationPaymentMethodListResponse,
+        api_models::jobs::JobResponse,
+        api_models::refunds::BulkRefundRequest,
+        api_models::refunds::BulkRefundItem,
+        api_models::refunds::BulkRefundJobOutput,
+        api_models::refunds::BulkRefundOutcome,
+        api_models::vault_tokens::VaultTokenExpireRequest,
+        api_models::vault_tokens::VaultTokenExpireResponse,
+        api_models::vault_tokens::VaultCleanupRequest,
+        api_models::vault_tokens::VaultCleanupResponse,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
@@ -278,7 +598,30 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
+        api_models::feature_flags::FeatureFlag,
+        api_models::feature_flags::FeatureFlagScope,
+        api_models::feature_flags::FeatureFlagUpdateRequest,
+        api_models::feature_flags::FeatureFlagResponse,
+        api_models::pm_filters::PaymentMethodFilter,
+        api_models::pm_filters::PaymentMethodNotAvailableFlows,
+        api_models::pm_filters::ConnectorPmFiltersRequest,
+        api_models::pm_filters::ConnectorPmFiltersResponse,
+        api_models::sandbox_traffic::SandboxPaymentMethod,
+        api_models::sandbox_traffic::SandboxPaymentOutcome,
+        api_models::sandbox_traffic::SandboxTrafficRequest,
+        api_models::sandbox_traffic::SandboxPayment,
+        api_models::sandbox_traffic::SandboxTrafficResponse,
+        api_models::bulk_force_sync::BulkForceSyncRequest,
+        api_models::bulk_force_sync::BulkForceSyncStatus,
+        api_models::bulk_force_sync::ConnectorForceSyncSummary,
+        api_models::bulk_force_sync::BulkForceSyncSummary,
+        api_models::bulk_force_sync::BulkForceSyncResponse,
+        api_models::master_key_rotation::MasterKeyRotationRequest,
+        api_models::master_key_rotation::MasterKeyRotationStatus,
+        api_models::master_key_rotation::MasterKeyRotationSummary,
+        api_models::master_key_rotation::MasterKeyRotationResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::DeclineCategory,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -354,6 +697,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentIdType,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::PaymentsCaptureScheduleRequest,
+        api_models::payments::PaymentsCaptureScheduleResponse,
+        api_models::payments::CaptureScheduleStatus,
+        api_models::payments::PaymentProcessingMode,
+        api_models::payments::PaymentsMetadataUpdateRequest,
+        api_models::payments::PaymentsMetadataUpdateResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::SessionToken,
@@ -418,6 +767,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentAttemptResponse,
+        api_models::payments::UnifiedErrorDetails,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::IncrementalAuthorizationResponse,
@@ -452,6 +802,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
         api_models::mandates::RecurringDetails,
+        api_models::mandates::MandateMigrationRequest,
+        api_models::mandates::MandateMigrationRecord,
+        api_models::mandates::MandateMigrationResponse,
+        api_models::mandates::MandateMigrationRecordResponse,
+        api_models::mandates::MandatePreNotificationRequest,
+        api_models::mandates::MandatePreNotificationResponse,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
@@ -496,8 +852,46 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::MerchantKvSettingsUpdateRequest,
+        api_models::admin::MerchantKvSettingsResponse,
+        api_models::rate_limits::RateLimitEndpointClass,
+        api_models::rate_limits::RateLimitQuota,
+        api_models::rate_limits::MerchantRateLimitsRequest,
+        api_models::rate_limits::MerchantRateLimitsResponse,
+        api_models::admin::OnboardingStep,
+        api_models::admin::OnboardingStepStatus,
+        api_models::admin::OnboardingStatusResponse,
+        api_models::response_field_access::SensitiveFieldCategory,
+        api_models::response_field_access::SensitiveFieldAccess,
+        api_models::response_field_access::ResponseFieldAccessRequest,
+        api_models::response_field_access::ResponseFieldAccessResponse,
+        api_models::api_versioning::ApiVersion,
+        api_models::api_versioning::ApiVersionPinRequest,
+        api_models::api_versioning::ApiVersionPinResponse,
+        api_models::merchant_key_rotation::MerchantKeyRotationRequest,
+        api_models::merchant_key_rotation::MerchantKeyRotationStatus,
+        api_models::merchant_key_rotation::MerchantKeyRotationStage,
+        api_models::merchant_key_rotation::MerchantKeyRotationSummary,
+        api_models::merchant_key_rotation::MerchantKeyRotationResponse,
+        api_models::verify_connector::ConnectorValidationStatus,
+        api_models::verify_connector::MerchantConnectorValidationResponse,
+        api_models::admin::MerchantConnectorNextCredentialsRequest,
+        api_models::admin::MerchantConnectorCredentialsCutoverRequest,
+        api_models::admin::MerchantConnectorCredentialsRotationResponse,
+        api_models::connector_capabilities::ConnectorCapabilitiesResponse,
+        api_models::connector_capabilities::MandateCapability,
+        api_models::connector_capabilities::PaymentMethodCapability,
+        api_models::admin::DrainerTuning,
+        api_models::admin::KvShardBacklog,
+        api_models::admin::KvBacklogResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookPayloadFilter,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::api_keys::ApiKeyScope,
+        api_models::api_keys::OAuth2GrantType,
+        api_models::api_keys::OAuth2TokenRequest,
+        api_models::api_keys::OAuth2TokenResponse,
+        api_models::api_keys::OAuth2RevokeRequest,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -541,15 +935,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
+        api_models::enums::BlocklistListType,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::IncomingWebhookListItemResponse,
+        api_models::webhook_events::IncomingWebhookRetrieveResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::PaymentChargeRequest,
         api_models::payments::PaymentChargeResponse,
+        api_models::payments::TaxDetails,
+        api_models::payments::TaxBreakdown,
         api_models::refunds::ChargeRefunds,
         api_models::payments::CustomerDetailsResponse,
     )),
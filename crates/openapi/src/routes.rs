@@ -1,21 +1,43 @@
 #![allow(unused)]
 
 pub mod api_keys;
+pub mod audit_events;
+pub mod authentication;
 pub mod blocklist;
+pub mod bulk_force_sync;
 pub mod business_profile;
+pub mod card_export;
+pub mod connected_accounts;
+pub mod connector_capabilities;
+pub mod connector_migration;
 pub mod customers;
 pub mod disputes;
+pub mod feature_flags;
+pub mod graphql;
 pub mod gsm;
+pub mod jobs;
+pub mod ledger;
 pub mod mandates;
+pub mod master_key_rotation;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod orders;
+pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_sessions;
 pub mod payments;
 pub mod payouts;
+pub mod pm_filters;
 pub mod poll;
 pub mod refunds;
+pub mod reports;
 pub mod routing;
+pub mod sandbox_traffic;
+pub mod search;
+pub mod settlements;
+pub mod tenants;
+pub mod vault_tokens;
 pub mod webhook_events;
 
 pub use self::{
@@ -0,0 +1,25 @@
+/// Audit Events - List
+///
+/// Lists the mutations of the configuration of the merchants, most recent first
+#[utoipa::path(
+    get,
+    path = "/audit_events",
+    params(
+        ("merchant_id" = Option<String>, Query, description = "Only list the events of this merchant"),
+        ("actor_id" = Option<String>, Query, description = "Only list the mutations made by this API key or user"),
+        ("resource_type" = Option<AuditResourceType>, Query, description = "Only list the mutations of this type of resource"),
+        ("resource_id" = Option<String>, Query, description = "Only list the mutations of this resource"),
+        ("action" = Option<AuditAction>, Query, description = "Only list the mutations of this kind"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded before this time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to list"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip"),
+    ),
+    responses(
+        (status = 200, description = "Audit events listed", body = AuditEventsResponse)
+    ),
+    tag = "Audit Events",
+    operation_id = "List Audit Events",
+    security(("admin_api_key" = []))
+)]
+pub async fn list_audit_events() {}
@@ -0,0 +1,52 @@
+/// Authentication - Create
+///
+/// Starts a 3DS authentication of a card without authorizing a payment, for payments authorized outside of Hyperswitch. The 3DS method data and URL of the response are used to run the 3DS method of the issuer before authenticating the card
+#[utoipa::path(
+    post,
+    path = "/authentication",
+    request_body = AuthenticationCreateRequest,
+    responses(
+        (status = 200, description = "Authentication created", body = AuthenticationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Authentication",
+    operation_id = "Create an Authentication",
+    security(("api_key" = []))
+)]
+pub async fn create_authentication() {}
+
+/// Authentication - Authenticate
+///
+/// Authenticates the card of an authentication. The authentication value and ECI of the response are sent along with the authorization of the payment once the card is authenticated, the cardholder being challenged first when the issuer requires it
+#[utoipa::path(
+    post,
+    path = "/authentication/{authentication_id}/authenticate",
+    params(("authentication_id" = String, Path, description = "The identifier of the authentication")),
+    request_body = AuthenticationAuthenticateRequest,
+    responses(
+        (status = 200, description = "Card authenticated", body = AuthenticationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Authentication not found")
+    ),
+    tag = "Authentication",
+    operation_id = "Authenticate the card of an Authentication",
+    security(("api_key" = []))
+)]
+pub async fn authenticate() {}
+
+/// Authentication - Retrieve
+///
+/// Retrieves an authentication, along with the outcome of the challenge of the cardholder once completed
+#[utoipa::path(
+    get,
+    path = "/authentication/{authentication_id}",
+    params(("authentication_id" = String, Path, description = "The identifier of the authentication")),
+    responses(
+        (status = 200, description = "Authentication retrieved", body = AuthenticationResponse),
+        (status = 404, description = "Authentication not found")
+    ),
+    tag = "Authentication",
+    operation_id = "Retrieve an Authentication",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_authentication() {}
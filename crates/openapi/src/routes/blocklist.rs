@@ -57,3 +57,47 @@ pub async fn remove_entry_from_blocklist() {}
     security(("api_key" = []))
 )]
 pub async fn list_blocked_payment_methods() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/allow",
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Entry Allowed", body = BlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Add an entry to the allowlist",
+    security(("api_key" = []))
+)]
+pub async fn add_entry_to_allowlist() {}
+
+#[utoipa::path(
+    delete,
+    path = "/blocklist/allow",
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Entry Removed", body = BlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Remove an entry from the allowlist",
+    security(("api_key" = []))
+)]
+pub async fn remove_entry_from_allowlist() {}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/allow",
+    params (
+        ("data_kind" = BlocklistDataKind, Query, description = "Kind of the fingerprint list requested"),
+    ),
+    responses(
+        (status = 200, description = "Allowed Fingerprints", body = BlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "List Allowed fingerprints of a particular kind",
+    security(("api_key" = []))
+)]
+pub async fn list_allowed_entries() {}
@@ -0,0 +1,36 @@
+/// Bulk Force Sync - Schedule
+///
+/// Schedules the sync of the payments stuck in a non-terminal status with their connectors, across every merchant unless a merchant is provided. The payments are synced in batches, each connector being sent at most the number of syncs per second requested.
+#[utoipa::path(
+    post,
+    path = "/force_sync",
+    request_body = BulkForceSyncRequest,
+    responses(
+        (status = 200, description = "Bulk force sync scheduled", body = BulkForceSyncResponse),
+        (status = 400, description = "Invalid request data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Bulk Force Sync",
+    operation_id = "Schedule a Bulk Force Sync",
+    security(("admin_api_key" = [])),
+)]
+pub async fn schedule_bulk_force_sync() {}
+
+/// Bulk Force Sync - Retrieve
+///
+/// Retrieves the status of a bulk force sync, along with the report of the payments synced once completed.
+#[utoipa::path(
+    get,
+    path = "/force_sync/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "The identifier of the bulk force sync"),
+    ),
+    responses(
+        (status = 200, description = "Bulk force sync retrieved", body = BulkForceSyncResponse),
+        (status = 404, description = "Bulk force sync not found")
+    ),
+    tag = "Bulk Force Sync",
+    operation_id = "Retrieve a Bulk Force Sync",
+    security(("admin_api_key" = [])),
+)]
+pub async fn retrieve_bulk_force_sync() {}
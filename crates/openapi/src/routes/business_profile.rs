@@ -122,3 +122,287 @@ pub async fn business_profiles_delete() {}
     security(("api_key" = []))
 )]
 pub async fn business_profiles_retrieve() {}
+
+/// Business Profile - Upsert Velocity Limits
+///
+/// Create or replace the velocity limits enforced on the payments of the *business profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/velocity_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = VelocityLimitsRequest,
+    responses(
+        (status = 200, description = "Velocity Limits Updated", body = VelocityLimitsResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Upsert the Velocity Limits of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn velocity_limits_upsert() {}
+
+/// Business Profile - Retrieve Velocity Limits
+///
+/// Retrieve the velocity limits enforced on the payments of the *business profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/velocity_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Velocity Limits Retrieved", body = VelocityLimitsResponse),
+        (status = 404, description = "Velocity Limits not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve the Velocity Limits of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn velocity_limits_retrieve() {}
+
+/// Business Profile - Delete Velocity Limits
+///
+/// Delete the velocity limits of the *business profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/velocity_limits",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Velocity Limits Deleted"),
+        (status = 404, description = "Velocity Limits not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete the Velocity Limits of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn velocity_limits_delete() {}
+
+/// Business Profile - Upsert Payment Method Display Rules
+///
+/// Create or replace the rules deciding which payment methods are listed for the payments of the *business profile*, based on the amount, the currency, the billing country of the customer and the platform
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = PaymentMethodDisplayRulesRequest,
+    responses(
+        (status = 200, description = "Payment Method Display Rules Updated", body = PaymentMethodDisplayRulesResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Upsert the Payment Method Display Rules of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn pm_display_rules_upsert() {}
+
+/// Business Profile - Retrieve Payment Method Display Rules
+///
+/// Retrieve the rules deciding which payment methods are listed for the payments of the *business profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Payment Method Display Rules Retrieved", body = PaymentMethodDisplayRulesResponse),
+        (status = 404, description = "Payment Method Display Rules not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve the Payment Method Display Rules of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn pm_display_rules_retrieve() {}
+
+/// Business Profile - Delete Payment Method Display Rules
+///
+/// Delete the payment method display rules of the *business profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Payment Method Display Rules Deleted"),
+        (status = 404, description = "Payment Method Display Rules not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete the Payment Method Display Rules of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn pm_display_rules_delete() {}
+
+/// Business Profile - Create Wallet Decryption Key
+///
+/// Register a key with which the Apple Pay or Google Pay tokens of the payments of the *business profile* are decrypted
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/wallet_decryption_keys",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = WalletDecryptionKeyCreateRequest,
+    responses(
+        (status = 200, description = "Wallet Decryption Key Created", body = WalletDecryptionKeyResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Create a Wallet Decryption Key of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn wallet_decryption_key_create() {}
+
+/// Business Profile - List Wallet Decryption Keys
+///
+/// List the wallet decryption keys registered on the *business profile*, without their certificates and private keys
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/wallet_decryption_keys",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Wallet Decryption Keys Retrieved", body = WalletDecryptionKeyListResponse),
+    ),
+    tag = "Business Profile",
+    operation_id = "List the Wallet Decryption Keys of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn wallet_decryption_keys_list() {}
+
+/// Business Profile - Retire Wallet Decryption Key
+///
+/// Retire a wallet decryption key of the *business profile* once its grace period is over
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/wallet_decryption_keys/{key_id}/retire",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("key_id" = String, Path, description = "The unique identifier for the wallet decryption key")
+    ),
+    request_body = WalletDecryptionKeyRetireRequest,
+    responses(
+        (status = 200, description = "Wallet Decryption Key Retired", body = WalletDecryptionKeyResponse),
+        (status = 404, description = "Wallet Decryption Key not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retire a Wallet Decryption Key of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn wallet_decryption_key_retire() {}
+
+/// Business Profile - Clone
+///
+/// Create a *business profile* from another one, for the same merchant or for another merchant. The settings of the profile, its default fallback routing and its payment method display rules are copied, along with the merchant connector accounts selected, which are created inactive and without credentials
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/clone",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile cloned")
+    ),
+    request_body = BusinessProfileCloneRequest,
+    responses(
+        (status = 200, description = "Business Profile Cloned", body = BusinessProfileCloneResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Clone a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_clone() {}
+
+/// Business Profile - Upsert Template
+///
+/// Save a *business profile* of the merchant as a template the business profiles of other merchants, such as the sub-merchants onboarded by a platform, are created from
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/templates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    request_body = BusinessProfileTemplateRequest,
+    responses(
+        (status = 200, description = "Business Profile Template Saved", body = BusinessProfileTemplate),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Upsert a Business Profile Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_template_upsert() {}
+
+/// Business Profile - List Templates
+///
+/// List the business profile templates of the merchant
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/templates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Business Profile Templates Retrieved", body = BusinessProfileTemplatesResponse),
+    ),
+    tag = "Business Profile",
+    operation_id = "List the Business Profile Templates",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_templates_list() {}
+
+/// Business Profile - Delete Template
+///
+/// Delete a business profile template of the merchant, the business profiles created from it being kept
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/templates/{template_name}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("template_name" = String, Path, description = "The name of the business profile template")
+    ),
+    responses(
+        (status = 200, description = "Business Profile Template Deleted", body = BusinessProfileTemplate),
+        (status = 404, description = "Business Profile Template not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete a Business Profile Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_template_delete() {}
+
+/// Business Profile - Apply Template
+///
+/// Create a *business profile* from a template of the merchant, for the merchant or for another merchant
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/templates/{template_name}/apply",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("template_name" = String, Path, description = "The name of the business profile template")
+    ),
+    request_body = BusinessProfileTemplateApplyRequest,
+    responses(
+        (status = 200, description = "Business Profile Created", body = BusinessProfileCloneResponse),
+        (status = 404, description = "Business Profile Template not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Create a Business Profile from a Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_template_apply() {}
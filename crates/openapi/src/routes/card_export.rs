@@ -0,0 +1,32 @@
+/// Card Exports - List
+///
+/// Lists the exports of the cards vaulted for the merchant to other payment service providers.
+/// Card exports are requested, approved and downloaded from the dashboard.
+#[utoipa::path(
+    get,
+    path = "/card_exports",
+    responses(
+        (status = 200, description = "Card exports retrieved", body = Vec<CardExportResponse>)
+    ),
+    tag = "Card Exports",
+    operation_id = "List all Card Exports",
+    security(("api_key" = []))
+)]
+pub async fn list_card_exports() {}
+
+/// Card Exports - Retrieve
+///
+/// Retrieves the status of a card export
+#[utoipa::path(
+    get,
+    path = "/card_exports/{export_id}",
+    params(("export_id" = String, Path, description = "The identifier of the card export")),
+    responses(
+        (status = 200, description = "Card export retrieved", body = CardExportResponse),
+        (status = 404, description = "Card export not found")
+    ),
+    tag = "Card Exports",
+    operation_id = "Retrieve a Card Export",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_card_export() {}
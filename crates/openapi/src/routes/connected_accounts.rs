@@ -0,0 +1,110 @@
+/// Connected Accounts - Create
+///
+/// Onboards a sub-merchant of the platform as a connected account, creating its merchant account
+/// in the organization of the platform. The platform processes payments on behalf of the connected
+/// account by naming it in the `X-Connected-Account-Id` header of its requests.
+#[utoipa::path(
+    post,
+    path = "/connected_accounts",
+    request_body = ConnectedAccountCreateRequest,
+    responses(
+        (status = 200, description = "Connected account created", body = ConnectedAccountResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 412, description = "Connected accounts are not enabled for the merchant")
+    ),
+    tag = "Connected Accounts",
+    operation_id = "Create a Connected Account",
+    security(("api_key" = []))
+)]
+pub async fn create_connected_account() {}
+
+/// Connected Accounts - List
+///
+/// Lists the connected accounts of the platform
+#[utoipa::path(
+    get,
+    path = "/connected_accounts",
+    responses(
+        (status = 200, description = "Connected accounts listed", body = ConnectedAccountsListResponse),
+        (status = 412, description = "Connected accounts are not enabled for the merchant")
+    ),
+    tag = "Connected Accounts",
+    operation_id = "List Connected Accounts",
+    security(("api_key" = []))
+)]
+pub async fn list_connected_accounts() {}
+
+/// Connected Accounts - Retrieve
+///
+/// Retrieves a connected account of the platform along with the split of its payments
+#[utoipa::path(
+    get,
+    path = "/connected_accounts/{merchant_id}",
+    params(("merchant_id" = String, Path, description = "The identifier of the connected account")),
+    responses(
+        (status = 200, description = "Connected account retrieved", body = ConnectedAccountResponse),
+        (status = 404, description = "Connected account not found")
+    ),
+    tag = "Connected Accounts",
+    operation_id = "Retrieve a Connected Account",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_connected_account() {}
+
+/// Connected Accounts - Update
+///
+/// Replaces how the payments of the connected account are split with the platform
+#[utoipa::path(
+    post,
+    path = "/connected_accounts/{merchant_id}",
+    params(("merchant_id" = String, Path, description = "The identifier of the connected account")),
+    request_body = ConnectedAccountUpdateRequest,
+    responses(
+        (status = 200, description = "Connected account updated", body = ConnectedAccountResponse),
+        (status = 404, description = "Connected account not found")
+    ),
+    tag = "Connected Accounts",
+    operation_id = "Update a Connected Account",
+    security(("api_key" = []))
+)]
+pub async fn update_connected_account() {}
+
+/// Connected Accounts - Create Merchant Connector Account
+///
+/// Creates a merchant connector account for the connected account from a merchant connector
+/// account of the platform, sharing the credentials of the platform or with the own credentials
+/// of the connected account
+#[utoipa::path(
+    post,
+    path = "/connected_accounts/{merchant_id}/connectors",
+    params(("merchant_id" = String, Path, description = "The identifier of the connected account")),
+    request_body = ConnectedAccountConnectorRequest,
+    responses(
+        (status = 200, description = "Merchant connector account created", body = MerchantConnectorResponse),
+        (status = 404, description = "Connected account or merchant connector account not found")
+    ),
+    tag = "Connected Accounts",
+    operation_id = "Create a Merchant Connector Account for a Connected Account",
+    security(("api_key" = []))
+)]
+pub async fn create_connected_account_connector() {}
+
+/// Connected Accounts - Report
+///
+/// Reports, for each connected account of the platform, the payments created within the time range
+/// and the volume and platform fees of the successful ones by currency
+#[utoipa::path(
+    get,
+    path = "/connected_accounts/report",
+    params(
+        ("start_time" = PrimitiveDateTime, Query, description = "The start of the time range"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "The end of the time range, defaults to now"),
+    ),
+    responses(
+        (status = 200, description = "Connected accounts report generated", body = ConnectedAccountsReport)
+    ),
+    tag = "Connected Accounts",
+    operation_id = "Report Connected Accounts",
+    security(("api_key" = []))
+)]
+pub async fn get_connected_accounts_report() {}
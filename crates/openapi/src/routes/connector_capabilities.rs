@@ -0,0 +1,19 @@
+/// Connector Capabilities - Retrieve
+///
+/// Retrieves the flows, payment methods, currencies and countries supported by a connector, as
+/// derived from its integration and from the configuration of the connectors
+#[utoipa::path(
+    get,
+    path = "/connectors/{connector}/capabilities",
+    params(
+        ("connector" = String, Path, description = "The name of the connector")
+    ),
+    responses(
+        (status = 200, description = "Connector capabilities retrieved", body = ConnectorCapabilitiesResponse),
+        (status = 400, description = "Invalid connector name")
+    ),
+    tag = "Connector Capabilities",
+    operation_id = "Retrieve the capabilities of a Connector",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_connector_capabilities() {}
@@ -0,0 +1,93 @@
+/// Connector Migrations - Create
+///
+/// Creates a migration of the stored credentials of the payment methods from a merchant connector
+/// account to another. The payment methods with a mandate through the source connector are made
+/// usable for merchant initiated transactions through the target connector in the background,
+/// either through their network transaction id or by setting up a zero amount mandate with the
+/// card details from the vault.
+#[utoipa::path(
+    post,
+    path = "/connector_migrations",
+    request_body = ConnectorMigrationCreateRequest,
+    responses(
+        (status = 200, description = "Connector migration created", body = ConnectorMigrationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant connector account not found")
+    ),
+    tag = "Connector Migrations",
+    operation_id = "Create a Connector Migration",
+    security(("api_key" = []))
+)]
+pub async fn create_connector_migration() {}
+
+/// Connector Migrations - List
+///
+/// Lists the connector migrations of the merchant
+#[utoipa::path(
+    get,
+    path = "/connector_migrations",
+    responses(
+        (status = 200, description = "Connector migrations retrieved", body = Vec<ConnectorMigrationResponse>)
+    ),
+    tag = "Connector Migrations",
+    operation_id = "List all Connector Migrations",
+    security(("api_key" = []))
+)]
+pub async fn list_connector_migrations() {}
+
+/// Connector Migrations - Retrieve
+///
+/// Retrieves the status and the progress of a connector migration
+#[utoipa::path(
+    get,
+    path = "/connector_migrations/{migration_id}",
+    params(("migration_id" = String, Path, description = "The identifier of the connector migration")),
+    responses(
+        (status = 200, description = "Connector migration retrieved", body = ConnectorMigrationResponse),
+        (status = 404, description = "Connector migration not found")
+    ),
+    tag = "Connector Migrations",
+    operation_id = "Retrieve a Connector Migration",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_connector_migration() {}
+
+/// Connector Migrations - Cancel
+///
+/// Cancels a connector migration being processed. The payment methods migrated until then remain
+/// usable through the target connector.
+#[utoipa::path(
+    post,
+    path = "/connector_migrations/{migration_id}/cancel",
+    params(("migration_id" = String, Path, description = "The identifier of the connector migration")),
+    responses(
+        (status = 200, description = "Connector migration cancelled", body = ConnectorMigrationResponse),
+        (status = 400, description = "Connector migration is no longer being processed"),
+        (status = 404, description = "Connector migration not found")
+    ),
+    tag = "Connector Migrations",
+    operation_id = "Cancel a Connector Migration",
+    security(("api_key" = []))
+)]
+pub async fn cancel_connector_migration() {}
+
+/// Connector Migrations - List Payment Methods
+///
+/// Lists the outcome of the migration of each payment method processed so far
+#[utoipa::path(
+    get,
+    path = "/connector_migrations/{migration_id}/payment_methods",
+    params(
+        ("migration_id" = String, Path, description = "The identifier of the connector migration"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of payment methods to return, defaults to 100"),
+        ("offset" = Option<i64>, Query, description = "The number of payment methods to skip")
+    ),
+    responses(
+        (status = 200, description = "Payment methods of the connector migration retrieved", body = ConnectorMigrationPaymentMethodListResponse),
+        (status = 404, description = "Connector migration not found")
+    ),
+    tag = "Connector Migrations",
+    operation_id = "List the Payment Methods of a Connector Migration",
+    security(("api_key" = []))
+)]
+pub async fn list_connector_migration_payment_methods() {}
@@ -100,3 +100,59 @@ pub async fn customers_delete() {}
     security(("api_key" = []))
 )]
 pub async fn customers_list() {}
+
+/// Customers - Overview
+///
+/// Retrieves a customer along with their payment methods, mandates, recent payments, total spend,
+/// dispute count and fraud flags.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/overview",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer overview retrieved", body = CustomerOverviewResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve the overview of a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_overview() {}
+
+/// Customers - Redact
+///
+/// Schedules the redaction of the personal data of a customer. The customer is redacted along with
+/// their addresses, and the personal data held by their payments and mandates, while their saved
+/// payment methods are deleted. The amounts and statuses of the payments are left untouched. A
+/// `customer_redacted` webhook is sent once the redaction completes.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/redact",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer redaction was scheduled", body = CustomerRedactionResponse),
+        (status = 400, description = "Customer has an active mandate"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Redact a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_redact() {}
+
+/// Customers - Retrieve Redaction
+///
+/// Retrieves the status of the redaction of a customer.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/redact",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer redaction retrieved", body = CustomerRedactionResponse),
+        (status = 404, description = "No redaction was requested for the customer")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve the redaction of a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_redaction_retrieve() {}
@@ -32,9 +32,12 @@ pub async fn retrieve_dispute() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("dispute_amount.gte" = Option<i64>, Query, description = "Minimum disputed amount"),
+        ("dispute_amount.lte" = Option<i64>, Query, description = "Maximum disputed amount"),
+        ("cursor" = Option<String>, Query, description = "The X-Next-Cursor header of the previous page, to fetch the disputes which follow it"),
     ),
     responses(
-        (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 200, description = "The dispute list was retrieved successfully, with an X-Next-Cursor header when more disputes may follow", body = Vec<DisputeResponse>),
         (status = 401, description = "Unauthorized request")
     ),
     tag = "Disputes",
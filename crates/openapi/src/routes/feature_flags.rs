@@ -0,0 +1,62 @@
+/// Feature Flags - Retrieve
+///
+/// Retrieves the flag of a capability applying to a tenant, a merchant or a business profile. The flag of a business profile takes precedence over the flag of its merchant, which takes precedence over the flag of the tenant.
+#[utoipa::path(
+    get,
+    path = "/feature_flags/{flag}",
+    params(
+        ("flag" = FeatureFlag, Path, description = "The capability rolled out with the flag"),
+        ("merchant_id" = Option<String>, Query, description = "The merchant the flag is resolved for"),
+        ("profile_id" = Option<String>, Query, description = "The business profile of the merchant the flag is resolved for"),
+    ),
+    responses(
+        (status = 200, description = "Feature flag retrieved", body = FeatureFlagResponse),
+        (status = 404, description = "Merchant account or business profile not found")
+    ),
+    tag = "Feature Flags",
+    operation_id = "Retrieve a Feature Flag",
+    security(("admin_api_key" = [])),
+)]
+pub async fn retrieve_feature_flag() {}
+
+/// Feature Flags - Update
+///
+/// Sets the flag of a capability for a tenant, a merchant or a business profile. The flag takes effect on every instance once the cached configs are invalidated.
+#[utoipa::path(
+    post,
+    path = "/feature_flags/{flag}",
+    params(
+        ("flag" = FeatureFlag, Path, description = "The capability rolled out with the flag"),
+    ),
+    request_body = FeatureFlagUpdateRequest,
+    responses(
+        (status = 200, description = "Feature flag updated", body = FeatureFlagResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account or business profile not found")
+    ),
+    tag = "Feature Flags",
+    operation_id = "Update a Feature Flag",
+    security(("admin_api_key" = [])),
+)]
+pub async fn update_feature_flag() {}
+
+/// Feature Flags - Delete
+///
+/// Removes the flag of a capability set for a tenant, a merchant or a business profile, the flag of the broader levels applying from then on.
+#[utoipa::path(
+    delete,
+    path = "/feature_flags/{flag}",
+    params(
+        ("flag" = FeatureFlag, Path, description = "The capability rolled out with the flag"),
+        ("merchant_id" = Option<String>, Query, description = "The merchant the flag is removed for"),
+        ("profile_id" = Option<String>, Query, description = "The business profile of the merchant the flag is removed for"),
+    ),
+    responses(
+        (status = 200, description = "Feature flag deleted", body = FeatureFlagResponse),
+        (status = 404, description = "Feature flag not set")
+    ),
+    tag = "Feature Flags",
+    operation_id = "Delete a Feature Flag",
+    security(("admin_api_key" = [])),
+)]
+pub async fn delete_feature_flag() {}
@@ -0,0 +1,21 @@
+/// GraphQL - Execute Query
+///
+/// Executes a GraphQL query reading the payments, refunds, disputes, customers and analytics of
+/// the merchant in a single request. The root fields are `payment`, `payments`, `refund`,
+/// `refunds`, `dispute`, `disputes`, `customer`, `customers`, `payment_metrics` and
+/// `refund_metrics`, taking the arguments of the corresponding REST APIs. The fields of the
+/// objects are those of the REST API responses, along with the related objects: the `refunds`,
+/// `disputes` and `customer` of the payments, the `payment` of the refunds and disputes, and the
+/// `payments` of the customers. Only queries are supported, without fragments or directives.
+#[utoipa::path(
+    post,
+    path = "/graphql",
+    request_body = GraphQLRequest,
+    responses(
+        (status = 200, description = "The fields selected by the query, along with the errors raised for some of them", body = GraphQLResponse)
+    ),
+    tag = "GraphQL",
+    operation_id = "Execute GraphQL Query",
+    security(("api_key" = []))
+)]
+pub async fn graphql_query() {}
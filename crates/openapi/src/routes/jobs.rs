@@ -0,0 +1,34 @@
+/// Jobs - Retrieve
+///
+/// Retrieves the status, the progress and the output of a job run in the background
+#[utoipa::path(
+    get,
+    path = "/jobs/{job_id}",
+    params(("job_id" = String, Path, description = "The identifier of the job")),
+    responses(
+        (status = 200, description = "Job retrieved", body = JobResponse),
+        (status = 404, description = "Job not found")
+    ),
+    tag = "Jobs",
+    operation_id = "Retrieve a Job",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_job() {}
+
+/// Jobs - Cancel
+///
+/// Cancels a job yet to be completed. The items processed until then are not reverted.
+#[utoipa::path(
+    post,
+    path = "/jobs/{job_id}/cancel",
+    params(("job_id" = String, Path, description = "The identifier of the job")),
+    responses(
+        (status = 200, description = "Job cancelled", body = JobResponse),
+        (status = 404, description = "Job not found"),
+        (status = 412, description = "Job already completed")
+    ),
+    tag = "Jobs",
+    operation_id = "Cancel a Job",
+    security(("api_key" = []))
+)]
+pub async fn cancel_job() {}
@@ -0,0 +1,63 @@
+/// Ledger - Retrieve Balances
+///
+/// Retrieves the balances of the accounts of the ledger, per merchant connector account and currency
+#[utoipa::path(
+    get,
+    path = "/ledger/balances",
+    params(
+        ("merchant_connector_id" = Option<String>, Query, description = "Only include the entries of this merchant connector account"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include the entries recorded after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include the entries recorded before this time"),
+    ),
+    responses(
+        (status = 200, description = "Ledger balances retrieved", body = LedgerBalancesResponse),
+        (status = 400, description = "Too many entries match the filters")
+    ),
+    tag = "Ledger",
+    operation_id = "Retrieve Ledger Balances",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_ledger_balances() {}
+
+/// Ledger - List Entries
+///
+/// Lists the entries of the ledger, oldest first
+#[utoipa::path(
+    get,
+    path = "/ledger/entries",
+    params(
+        ("merchant_connector_id" = Option<String>, Query, description = "Only list the entries of this merchant connector account"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only list the entries recorded after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only list the entries recorded before this time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of entries to list"),
+        ("offset" = Option<i64>, Query, description = "The number of entries to skip"),
+    ),
+    responses(
+        (status = 200, description = "Ledger entries listed", body = LedgerEntriesResponse)
+    ),
+    tag = "Ledger",
+    operation_id = "List Ledger Entries",
+    security(("api_key" = []))
+)]
+pub async fn list_ledger_entries() {}
+
+/// Ledger - Export Entries
+///
+/// Exports the entries of the ledger as a CSV file, with separate debit and credit columns
+#[utoipa::path(
+    get,
+    path = "/ledger/entries/export",
+    params(
+        ("merchant_connector_id" = Option<String>, Query, description = "Only include the entries of this merchant connector account"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include the entries recorded after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include the entries recorded before this time"),
+    ),
+    responses(
+        (status = 200, description = "Ledger entries exported", content_type = "text/csv", body = String),
+        (status = 400, description = "Too many entries match the filters")
+    ),
+    tag = "Ledger",
+    operation_id = "Export Ledger Entries",
+    security(("api_key" = []))
+)]
+pub async fn export_ledger_entries() {}
@@ -75,3 +75,41 @@ pub async fn retrieve_mandates_list() {}
     security(("api_key" = []))
 )]
 pub async fn customers_mandates_list() {}
+
+/// Mandates - Migrate Mandates
+///
+/// Imports mandates set up with another processor, so that they can be used for merchant initiated transactions
+#[utoipa::path(
+    post,
+    path = "/mandates/migrate",
+    request_body = MandateMigrationRequest,
+    responses(
+        (status = 200, description = "The mandates were processed, the result of each import is part of the response", body = MandateMigrationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Mandates",
+    operation_id = "Migrate Mandates",
+    security(("api_key" = []))
+)]
+pub async fn migrate_mandates() {}
+
+/// Mandates - Schedule Pre-notification
+///
+/// Schedules the notification of the customer, as required by the scheme rules, ahead of a direct debit on the mandate
+#[utoipa::path(
+    post,
+    path = "/mandates/{mandate_id}/pre_notification",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    request_body = MandatePreNotificationRequest,
+    responses(
+        (status = 200, description = "The pre-notification was scheduled successfully", body = MandatePreNotificationResponse),
+        (status = 400, description = "The debit date does not satisfy the notification window of the scheme"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Schedule Mandate Pre-notification",
+    security(("api_key" = []))
+)]
+pub async fn schedule_mandate_pre_notification() {}
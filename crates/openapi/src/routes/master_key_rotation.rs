@@ -0,0 +1,35 @@
+/// Master Key Rotation - Schedule
+///
+/// Schedules the re-encryption of the merchant key stores of the tenant with its master key, once the master key has been rotated and the master key being rotated out is configured as the previous master key. The key stores are re-encrypted in batches, the job resuming after the last key store processed when retried.
+#[utoipa::path(
+    post,
+    path = "/master_key/rotate",
+    request_body = MasterKeyRotationRequest,
+    responses(
+        (status = 200, description = "Master key rotation scheduled", body = MasterKeyRotationResponse),
+        (status = 400, description = "Invalid request data or no previous master key configured")
+    ),
+    tag = "Master Key Rotation",
+    operation_id = "Schedule a Master Key Rotation",
+    security(("admin_api_key" = [])),
+)]
+pub async fn schedule_master_key_rotation() {}
+
+/// Master Key Rotation - Retrieve
+///
+/// Retrieves the status of a master key rotation, along with the number of key stores re-encrypted so far.
+#[utoipa::path(
+    get,
+    path = "/master_key/rotate/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "The identifier of the master key rotation"),
+    ),
+    responses(
+        (status = 200, description = "Master key rotation retrieved", body = MasterKeyRotationResponse),
+        (status = 404, description = "Master key rotation not found")
+    ),
+    tag = "Master Key Rotation",
+    operation_id = "Retrieve a Master Key Rotation",
+    security(("admin_api_key" = [])),
+)]
+pub async fn retrieve_master_key_rotation() {}
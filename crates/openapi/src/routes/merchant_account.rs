@@ -155,3 +155,289 @@ pub async fn delete_merchant_account() {}
     security(("admin_api_key" = []))
 )]
 pub async fn merchant_account_kv_status() {}
+
+/// Merchant Account - Retrieve KV Settings
+///
+/// Retrieve the KV settings of the Merchant Account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/kv/settings",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "KV settings of the Merchant Account", body = MerchantKvSettingsResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the KV settings of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_kv_settings_retrieve() {}
+
+/// Merchant Account - Update KV Settings
+///
+/// Update the KV settings of the Merchant Account. The drainer shards of the merchant can only be changed while KV is disabled for the merchant, or along with disabling it
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/kv/settings",
+    request_body (
+        content = MerchantKvSettingsUpdateRequest,
+        examples (
+            ("Spread the writes of the merchant across 4 shards" = (
+                value = json!({
+                "kv_enabled": false,
+                "shard_count": 4
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "KV settings of the Merchant Account updated", body = MerchantKvSettingsResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found"),
+        (status = 412, description = "The shards cannot be changed while KV is enabled")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the KV settings of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_kv_settings_update() {}
+
+/// Merchant Account - Retrieve Rate Limits
+///
+/// Retrieve the quotas of requests enforced on the Merchant Account, the quotas not overridden for the merchant being the defaults of the deployment
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/rate_limits",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Rate limits of the Merchant Account", body = MerchantRateLimitsResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the rate limits of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_rate_limits_retrieve() {}
+
+/// Merchant Account - Update Rate Limits
+///
+/// Override the quotas of requests enforced on the Merchant Account. The quotas not set in the request fall back to the defaults of the deployment. Requests exceeding a quota are rejected with a `429` status and a `Retry-After` header
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/rate_limits",
+    request_body (
+        content = MerchantRateLimitsRequest,
+        examples (
+            ("Raise the quota of the payments endpoints" = (
+                value = json!({
+                "payments": {
+                    "requests_per_minute": 1200,
+                    "burst": 50
+                }
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Rate limits of the Merchant Account updated", body = MerchantRateLimitsResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the rate limits of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_rate_limits_update() {}
+
+/// Merchant Account - Onboarding Status
+///
+/// Retrieve which steps of the onboarding of the Merchant Account are complete: the creation of a business profile, of an enabled connector, the configuration of a webhook URL and the activation of a routing algorithm
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/onboarding_status",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Onboarding status of the Merchant Account", body = OnboardingStatusResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the onboarding status of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_onboarding_status() {}
+
+/// Merchant Account - Retrieve Response Field Access
+///
+/// Retrieve how the sensitive fields of the API responses are returned to the restricted callers of the Merchant Account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/response_field_access",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Response field access of the Merchant Account", body = ResponseFieldAccessResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the response field access of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_response_field_access_retrieve() {}
+
+/// Merchant Account - Update Response Field Access
+///
+/// Configure how the sensitive fields of the API responses are returned to the restricted callers of the Merchant Account: the connector responses, the customer PII and the card metadata can each be returned as is, masked or omitted. API keys restricted to scopes are restricted unless they have the `sensitive_data:read` scope, and dashboard users are restricted unless their role is among the unrestricted roles
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/response_field_access",
+    request_body (
+        content = ResponseFieldAccessRequest,
+        examples (
+            ("Hide the customer PII and connector responses" = (
+                value = json!({
+                "customer_pii": "masked",
+                "connector_response": "omitted",
+                "unrestricted_roles": ["merchant_admin"]
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Response field access of the Merchant Account updated", body = ResponseFieldAccessResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the response field access of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_response_field_access_update() {}
+
+/// Merchant Account - Retrieve API Version
+///
+/// Retrieve the version of the API responses of the Merchant Account, along with its deprecation and sunset when it is deprecated
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/api_version",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "API version of the Merchant Account", body = ApiVersionPinResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the API version of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_api_version_retrieve() {}
+
+/// Merchant Account - Pin API Version
+///
+/// Pin the version of the API responses of the Merchant Account. The version is used for the requests without the `X-API-Version` header, the header taking precedence over it. The responses of the deprecated versions carry the `Deprecation` and `Sunset` headers
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/api_version",
+    request_body (
+        content = ApiVersionPinRequest,
+        examples (
+            ("Pin the latest version" = (
+                value = json!({
+                "version": "2024-07-29"
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "API version of the Merchant Account pinned", body = ApiVersionPinResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Pin the API version of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_api_version_update() {}
+
+/// Merchant Account - Schedule Key Rotation
+///
+/// Rotate the key the data of the Merchant Account is encrypted with. A new key is generated, the data of the merchant being encrypted with it from then on, and a job re-encrypting the existing data of the merchant with the new key is scheduled. The key rotated out is discarded once every record has been re-encrypted, and kept when some records fail to be, the rotation being retried by scheduling it again
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/key/rotate",
+    request_body = MerchantKeyRotationRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Key rotation of the Merchant Account scheduled", body = MerchantKeyRotationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Schedule a key rotation of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_key_rotation_schedule() {}
+
+/// Merchant Account - Retrieve Key Rotation
+///
+/// Retrieve the status of a key rotation of the Merchant Account, along with the data being re-encrypted and the number of records re-encrypted so far
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/key/rotate/{job_id}",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("job_id" = String, Path, description = "The identifier of the key rotation"),
+    ),
+    responses(
+        (status = 200, description = "Key rotation of the Merchant Account retrieved", body = MerchantKeyRotationResponse),
+        (status = 404, description = "Key rotation not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve a key rotation of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_key_rotation_retrieve() {}
+
+/// Merchant Account - Retrieve Drainer Tuning
+///
+/// Retrieve the tuning of the drainer applied at runtime
+#[utoipa::path(
+    get,
+    path = "/accounts/kv/drainer",
+    responses(
+        (status = 200, description = "Tuning of the drainer", body = DrainerTuning)
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the drainer tuning",
+    security(("admin_api_key" = []))
+)]
+pub async fn drainer_tuning_retrieve() {}
+
+/// Merchant Account - Update Drainer Tuning
+///
+/// Update the tuning of the drainer, applied by the drainer without a restart
+#[utoipa::path(
+    post,
+    path = "/accounts/kv/drainer",
+    request_body = DrainerTuning,
+    responses(
+        (status = 200, description = "Tuning of the drainer updated", body = DrainerTuning),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the drainer tuning",
+    security(("admin_api_key" = []))
+)]
+pub async fn drainer_tuning_update() {}
+
+/// Merchant Account - Retrieve KV Backlog
+///
+/// Retrieve the number of entries of each drainer shard waiting to be drained to the database
+#[utoipa::path(
+    get,
+    path = "/accounts/kv/backlog",
+    responses(
+        (status = 200, description = "Backlog of the drainer shards", body = KvBacklogResponse)
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the KV backlog",
+    security(("admin_api_key" = []))
+)]
+pub async fn kv_backlog_retrieve() {}
@@ -168,3 +168,70 @@ pub async fn payment_connector_update() {}
     security(("admin_api_key" = []))
 )]
 pub async fn payment_connector_delete() {}
+
+/// Merchant Connector - Validate
+///
+/// Validate the credentials of a Merchant Connector with a live low impact call to the connector: a manually captured authorization of a test card, or the request of an access token. The credentials rejected by the connector are reported with the error returned by the connector and what to do about it
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/connectors/{connector_id}/validate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Outcome of the validation of the credentials", body = MerchantConnectorValidationResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Validate a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_connector_validate() {}
+
+/// Merchant Connector - Stage Next Credentials
+///
+/// Stage the credentials to replace the current credentials of a Merchant Connector on the next cutover. Until then, the refunds and the webhooks rejected with the current credentials are retried with the staged ones
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/connectors/{connector_id}/credentials/next",
+    request_body = MerchantConnectorNextCredentialsRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Credentials staged", body = MerchantConnectorCredentialsRotationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Stage the next credentials of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_connector_credentials_stage() {}
+
+/// Merchant Connector - Cutover Credentials
+///
+/// Make the staged credentials the current credentials of a Merchant Connector. During the rotation window, the refunds of the payments made with the replaced credentials and the webhooks signed with them are retried with the replaced credentials
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/connectors/{connector_id}/credentials/cutover",
+    request_body = MerchantConnectorCredentialsCutoverRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Credentials cut over", body = MerchantConnectorCredentialsRotationResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 412, description = "No credentials are staged"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Cut over the credentials of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_connector_credentials_cutover() {}
@@ -0,0 +1,51 @@
+/// Orders - Create
+///
+/// Creates an order, which payments such as deposits, balance payments and split tenders are linked to by passing its order_id on their creation
+#[utoipa::path(
+    post,
+    path = "/orders",
+    request_body = OrderCreateRequest,
+    responses(
+        (status = 200, description = "Order created", body = OrderResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Orders",
+    operation_id = "Create an Order",
+    security(("api_key" = []))
+)]
+pub async fn create_order() {}
+
+/// Orders - Retrieve
+///
+/// Retrieves an order, along with the payments linked to it and the status aggregated from them
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}",
+    params(("order_id" = String, Path, description = "The identifier of the order")),
+    responses(
+        (status = 200, description = "Order retrieved", body = OrderResponse),
+        (status = 404, description = "Order not found")
+    ),
+    tag = "Orders",
+    operation_id = "Retrieve an Order",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_order() {}
+
+/// Orders - Update
+///
+/// Updates the amount, description or metadata of an order
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}",
+    params(("order_id" = String, Path, description = "The identifier of the order")),
+    request_body = OrderUpdateRequest,
+    responses(
+        (status = 200, description = "Order updated", body = OrderResponse),
+        (status = 404, description = "Order not found")
+    ),
+    tag = "Orders",
+    operation_id = "Update an Order",
+    security(("api_key" = []))
+)]
+pub async fn update_order() {}
@@ -0,0 +1,42 @@
+/// Organization - Payments Aggregate
+///
+/// Aggregates the payments created within the time range by all the merchants of the organization,
+/// by status and currency
+#[utoipa::path(
+    get,
+    path = "/organization/{org_id}/payments/aggregate",
+    params(
+        ("org_id" = String, Path, description = "The unique identifier for the organization"),
+        ("start_time" = PrimitiveDateTime, Query, description = "The start of the time range"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "The end of the time range"),
+    ),
+    responses(
+        (status = 200, description = "Payments aggregated", body = OrganizationPaymentsAggregateResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Aggregate the Payments of an Organization",
+    security(("admin_api_key" = []))
+)]
+pub async fn aggregate_organization_payments() {}
+
+/// Organization - Share Connector
+///
+/// Shares a connector of a merchant of the organization with other merchants of the organization,
+/// creating a copy of the connector along with its credentials for each of them
+#[utoipa::path(
+    post,
+    path = "/organization/{org_id}/connectors/share",
+    params(
+        ("org_id" = String, Path, description = "The unique identifier for the organization"),
+    ),
+    request_body = ConnectorShareRequest,
+    responses(
+        (status = 200, description = "Connector shared", body = ConnectorShareResponse),
+        (status = 404, description = "Merchant account or connector not found")
+    ),
+    tag = "Organization",
+    operation_id = "Share a Connector within an Organization",
+    security(("admin_api_key" = []))
+)]
+pub async fn share_organization_connector() {}
@@ -48,6 +48,7 @@ pub async fn create_payment_method_api() {}
         ("maximum_amount" = i64, Query, description = "The maximum amount accepted for processing by the particular payment method."),
         ("recurring_payment_enabled" = bool, Query, description = "Indicates whether the payment method is eligible for recurring payments"),
         ("installment_payment_enabled" = bool, Query, description = "Indicates whether the payment method is eligible for installment payments"),
+        ("platform" = Option<ClientPlatform>, Query, description = "The platform the payment methods are listed on, used by the payment method display rules of the business profile"),
     ),
     responses(
         (status = 200, description = "Payment Methods retrieved", body = PaymentMethodListResponse),
@@ -172,6 +173,27 @@ pub async fn payment_method_update_api() {}
 )]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method - Set Usage Restrictions
+///
+/// Set the restrictions on the use of a saved payment method in payments, replacing the restrictions set before. The restrictions are enforced when the payments are confirmed, and are useful for platforms that vault the payment methods of their customers across several business units.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{method_id}/usage_restrictions",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = PaymentMethodUsageRestrictions,
+    responses(
+        (status = 200, description = "Payment Method usage restrictions set", body = PaymentMethodUsageRestrictionsResponse),
+        (status = 400, description = "Invalid usage restrictions"),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Usage Restrictions of a Payment method",
+    security(("api_key" = []))
+)]
+pub async fn payment_method_usage_restrictions_set_api() {}
+
 /// Payment Method - Set Default Payment Method for Customer
 ///
 /// Set the Payment Method as Default for the Customer.
@@ -192,3 +214,24 @@ pub async fn payment_method_delete_api() {}
     security(("ephemeral_key" = []))
 )]
 pub async fn default_payment_method_set_api() {}
+
+/// Payment Method - Set Payment Method Order for Customer
+///
+/// Set the order in which the saved payment methods of the Customer are displayed, after the default payment method, and whether the payment method last used by the Customer becomes their default payment method.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/order",
+    params (
+        ("customer_id" = String,Path, description ="The unique identifier for the Customer"),
+    ),
+    request_body = CustomerPaymentMethodOrderRequest,
+    responses(
+        (status = 200, description = "Payment Method order has been set", body = CustomerPaymentMethodOrderResponse),
+        (status = 400, description = "Payment Method is not an active saved payment method of the customer"),
+        (status = 404, description = "Customer not found")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Payment Method Order",
+    security(("ephemeral_key" = []))
+)]
+pub async fn payment_method_order_set_api() {}
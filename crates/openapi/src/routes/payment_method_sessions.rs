@@ -0,0 +1,61 @@
+/// Payment Method Sessions - Create
+///
+/// Creates a session to collect a payment method of the customer outside of a payment. Once the
+/// session is confirmed with the payment method, the payment method is verified with the connector,
+/// authenticating the customer with 3DS when required, and saved for the later payments of the
+/// customer.
+#[utoipa::path(
+    post,
+    path = "/payment_method_sessions",
+    request_body = PaymentMethodSessionCreateRequest,
+    responses(
+        (status = 200, description = "Payment method session created", body = PaymentMethodSessionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Method Sessions",
+    operation_id = "Create a Payment Method Session",
+    security(("api_key" = []))
+)]
+pub async fn create_payment_method_session() {}
+
+/// Payment Method Sessions - Confirm
+///
+/// Confirms the session with the payment method of the customer, which may be done from the client
+/// with the publishable key and the client secret of the session. The session requires a customer
+/// action when the customer has to be authenticated with 3DS.
+#[utoipa::path(
+    post,
+    path = "/payment_method_sessions/{session_id}/confirm",
+    params(("session_id" = String, Path, description = "The identifier of the payment method session")),
+    request_body = PaymentMethodSessionConfirmRequest,
+    responses(
+        (status = 200, description = "Payment method session confirmed", body = PaymentMethodSessionResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Payment method session not found")
+    ),
+    tag = "Payment Method Sessions",
+    operation_id = "Confirm a Payment Method Session",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+pub async fn confirm_payment_method_session() {}
+
+/// Payment Method Sessions - Retrieve
+///
+/// Retrieves a payment method session along with the payment method and mandate set up by it
+#[utoipa::path(
+    get,
+    path = "/payment_method_sessions/{session_id}",
+    params(
+        ("session_id" = String, Path, description = "The identifier of the payment method session"),
+        ("client_secret" = Option<String>, Query, description = "The client secret of the session, required with the publishable key"),
+        ("force_sync" = Option<bool>, Query, description = "Whether the status of the session is synced with the connector"),
+    ),
+    responses(
+        (status = 200, description = "Payment method session retrieved", body = PaymentMethodSessionResponse),
+        (status = 404, description = "Payment method session not found")
+    ),
+    tag = "Payment Method Sessions",
+    operation_id = "Retrieve a Payment Method Session",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+pub async fn retrieve_payment_method_session() {}
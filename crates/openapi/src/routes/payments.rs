@@ -369,6 +369,94 @@ pub fn payments_confirm() {}
 )]
 pub fn payments_capture() {}
 
+/// Payments - Schedule Capture
+///
+/// To capture a payment with a manual capture method at a given time, once authorized. Scheduling a capture again reschedules the capture of the payment
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/capture_schedule",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body (
+        content = PaymentsCaptureScheduleRequest,
+        examples(
+            (
+                "Capture the full amount" = (
+                    value = json!({"capture_at": "2022-09-12T10:11:12Z"})
+                )
+            ),
+            (
+                "Capture partial amount" = (
+                    value = json!({"capture_at": "2022-09-12T10:11:12Z", "amount_to_capture": 654})
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Payment capture scheduled", body = PaymentsCaptureScheduleResponse),
+        (status = 400, description = "Invalid capture time")
+    ),
+    tag = "Payments",
+    operation_id = "Schedule the Capture of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_capture_schedule() {}
+
+/// Payments - Cancel Scheduled Capture
+///
+/// To cancel the capture scheduled for a payment, which is then left to be captured through the capture endpoint
+#[utoipa::path(
+    delete,
+    path = "/payments/{payment_id}/capture_schedule",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Scheduled capture cancelled", body = PaymentsCaptureScheduleResponse),
+        (status = 404, description = "No capture is scheduled for the payment")
+    ),
+    tag = "Payments",
+    operation_id = "Cancel the Scheduled Capture of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_capture_schedule_cancel() {}
+
+/// Payments - Update Metadata
+///
+/// To update the metadata and the reference of the merchant for a payment at any point of its lifecycle, such as to attach the identifiers of its fulfillment. The amounts of the payment are never updated
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/metadata",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body (
+        content = PaymentsMetadataUpdateRequest,
+        examples(
+            (
+                "Update the merchant reference" = (
+                    value = json!({"merchant_reference": "shipment_7829"})
+                )
+            ),
+            (
+                "Update the metadata" = (
+                    value = json!({"metadata": {"tracking_number": "1Z999AA10123456784"}})
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Payment metadata updated", body = PaymentsMetadataUpdateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Payments",
+    operation_id = "Update the Metadata of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_metadata_update() {}
+
 /// Payments - Session token
 ///
 /// Creates a session object or a session token for wallets like Apple Pay, Google Pay, etc. These tokens are used by Hyperswitch's SDK to initiate these wallets' SDK.
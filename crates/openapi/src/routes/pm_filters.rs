@@ -0,0 +1,58 @@
+/// Payment Method Filters - Retrieve
+///
+/// Retrieves the countries and currencies the payment methods of a connector are available in, as set through the API or else as set in the configuration of the deployment.
+#[utoipa::path(
+    get,
+    path = "/pm_filters/{connector}",
+    params(
+        ("connector" = String, Path, description = "The name of the connector, or default for the filters of the connectors without filters of their own"),
+    ),
+    responses(
+        (status = 200, description = "Payment method filters retrieved", body = ConnectorPmFiltersResponse),
+        (status = 400, description = "Invalid connector")
+    ),
+    tag = "Payment Method Filters",
+    operation_id = "Retrieve the Payment Method Filters of a Connector",
+    security(("admin_api_key" = [])),
+)]
+pub async fn retrieve_pm_filters() {}
+
+/// Payment Method Filters - Update
+///
+/// Replaces the filters of the payment methods of a connector set in the configuration of the deployment. The filters take effect on every instance once the cached configs and the cached graphs of the connectors of the merchants are invalidated.
+#[utoipa::path(
+    post,
+    path = "/pm_filters/{connector}",
+    params(
+        ("connector" = String, Path, description = "The name of the connector, or default for the filters of the connectors without filters of their own"),
+    ),
+    request_body = ConnectorPmFiltersRequest,
+    responses(
+        (status = 200, description = "Payment method filters updated", body = ConnectorPmFiltersResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Method Filters",
+    operation_id = "Update the Payment Method Filters of a Connector",
+    security(("admin_api_key" = [])),
+)]
+pub async fn update_pm_filters() {}
+
+/// Payment Method Filters - Delete
+///
+/// Removes the filters of the payment methods of a connector set through the API, the filters set in the configuration of the deployment applying to the connector from then on.
+#[utoipa::path(
+    delete,
+    path = "/pm_filters/{connector}",
+    params(
+        ("connector" = String, Path, description = "The name of the connector, or default for the filters of the connectors without filters of their own"),
+    ),
+    responses(
+        (status = 200, description = "Payment method filters deleted", body = ConnectorPmFiltersResponse),
+        (status = 400, description = "Invalid connector"),
+        (status = 404, description = "Payment method filters of the connector are not overridden")
+    ),
+    tag = "Payment Method Filters",
+    operation_id = "Delete the Payment Method Filters of a Connector",
+    security(("admin_api_key" = [])),
+)]
+pub async fn delete_pm_filters() {}
@@ -46,6 +46,24 @@
 )]
 pub async fn refunds_create() {}
 
+/// Refunds - Create in bulk
+///
+/// Creates the refunds of many payments in the background. The progress and the outcome of each
+/// refund are retrieved through the job returned.
+#[utoipa::path(
+    post,
+    path = "/refunds/bulk",
+    request_body = BulkRefundRequest,
+    responses(
+        (status = 200, description = "Bulk refund job created", body = JobResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Refunds",
+    operation_id = "Create Refunds in bulk",
+    security(("api_key" = []))
+)]
+pub async fn refunds_create_bulk() {}
+
 /// Refunds - Retrieve
 ///
 /// Retrieves a Refund. This may be used to get the status of a previously initiated refund
@@ -0,0 +1,83 @@
+/// Reports - Create Template
+///
+/// Creates a report template, whose report is generated on the schedule of the template as a CSV file
+#[utoipa::path(
+    post,
+    path = "/reports/templates",
+    request_body = ReportTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Report template created", body = ReportTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Reports",
+    operation_id = "Create a Report Template",
+    security(("api_key" = []))
+)]
+pub async fn create_report_template() {}
+
+/// Reports - List Templates
+///
+/// Lists the report templates of the merchant
+#[utoipa::path(
+    get,
+    path = "/reports/templates",
+    responses(
+        (status = 200, description = "Report templates retrieved", body = Vec<ReportTemplateResponse>)
+    ),
+    tag = "Reports",
+    operation_id = "List all Report Templates",
+    security(("api_key" = []))
+)]
+pub async fn list_report_templates() {}
+
+/// Reports - Retrieve Template
+///
+/// Retrieves a report template, along with a download link to the last generated report
+#[utoipa::path(
+    get,
+    path = "/reports/templates/{template_id}",
+    params(("template_id" = String, Path, description = "The identifier of the report template")),
+    responses(
+        (status = 200, description = "Report template retrieved", body = ReportTemplateResponse),
+        (status = 404, description = "Report template not found")
+    ),
+    tag = "Reports",
+    operation_id = "Retrieve a Report Template",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_report_template() {}
+
+/// Reports - Update Template
+///
+/// Updates a report template. Updating the schedule or reactivating the template reschedules its next run
+#[utoipa::path(
+    post,
+    path = "/reports/templates/{template_id}",
+    params(("template_id" = String, Path, description = "The identifier of the report template")),
+    request_body = ReportTemplateUpdateRequest,
+    responses(
+        (status = 200, description = "Report template updated", body = ReportTemplateResponse),
+        (status = 404, description = "Report template not found")
+    ),
+    tag = "Reports",
+    operation_id = "Update a Report Template",
+    security(("api_key" = []))
+)]
+pub async fn update_report_template() {}
+
+/// Reports - Delete Template
+///
+/// Deletes a report template and stops the generation of its report
+#[utoipa::path(
+    delete,
+    path = "/reports/templates/{template_id}",
+    params(("template_id" = String, Path, description = "The identifier of the report template")),
+    responses(
+        (status = 200, description = "Report template deleted", body = ReportTemplateResponse),
+        (status = 404, description = "Report template not found")
+    ),
+    tag = "Reports",
+    operation_id = "Delete a Report Template",
+    security(("api_key" = []))
+)]
+pub async fn delete_report_template() {}
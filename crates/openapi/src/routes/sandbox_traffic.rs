@@ -0,0 +1,16 @@
+/// Sandbox Traffic - Generate
+///
+/// Makes synthetic payments with a test connector of the merchant, with the mix of payment methods, currencies and outcomes requested, so that the webhooks, the analytics and the reconciliation of the merchant can be validated end to end. Not available in the production environment.
+#[utoipa::path(
+    post,
+    path = "/sandbox/traffic",
+    request_body = SandboxTrafficRequest,
+    responses(
+        (status = 200, description = "Sandbox payments made", body = SandboxTrafficResponse),
+        (status = 400, description = "Invalid mix of payments requested, or no test connector configured for the business profile")
+    ),
+    tag = "Sandbox Traffic",
+    operation_id = "Generate Sandbox Traffic",
+    security(("api_key" = [])),
+)]
+pub async fn generate_sandbox_traffic() {}
@@ -0,0 +1,18 @@
+/// Search - Search Objects
+///
+/// Searches the payments, refunds, disputes and customers of the merchant by identifier, email,
+/// card last four digits, connector transaction id, reference or metadata. The results are ranked
+/// by the number and selectivity of the criteria they match
+#[utoipa::path(
+    post,
+    path = "/search",
+    request_body = SearchRequest,
+    responses(
+        (status = 200, description = "Search results", body = SearchResponse),
+        (status = 400, description = "No search criterion was provided or a criterion is invalid")
+    ),
+    tag = "Search",
+    operation_id = "Search Objects",
+    security(("api_key" = []))
+)]
+pub async fn search() {}
@@ -0,0 +1,106 @@
+/// Settlements - Ingest Lines
+///
+/// Ingests the settlement lines fetched from the reporting API of a connector and matches each of them to the payment, refund or dispute it settles
+#[utoipa::path(
+    post,
+    path = "/settlements/lines",
+    request_body = SettlementLinesIngestRequest,
+    responses(
+        (status = 200, description = "Settlement lines ingested", body = SettlementBatchResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Settlements",
+    operation_id = "Ingest Settlement Lines",
+    security(("api_key" = []))
+)]
+pub async fn ingest_settlement_lines() {}
+
+/// Settlements - Retrieve Batch
+///
+/// Retrieves the lines ingested in a settlement batch along with their reconciliation status
+#[utoipa::path(
+    get,
+    path = "/settlements/batches/{batch_id}",
+    params(("batch_id" = String, Path, description = "The identifier of the settlement batch")),
+    responses(
+        (status = 200, description = "Settlement batch retrieved", body = SettlementBatchResponse),
+        (status = 404, description = "Settlement batch not found")
+    ),
+    tag = "Settlements",
+    operation_id = "Retrieve a Settlement Batch",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_settlement_batch() {}
+
+/// Settlements - Rematch Batch
+///
+/// Matches the exceptions of a settlement batch again, for the resources which were created or updated after the settlement was ingested
+#[utoipa::path(
+    post,
+    path = "/settlements/batches/{batch_id}/rematch",
+    params(("batch_id" = String, Path, description = "The identifier of the settlement batch")),
+    responses(
+        (status = 200, description = "Settlement batch matched again", body = SettlementBatchResponse),
+        (status = 404, description = "Settlement batch not found")
+    ),
+    tag = "Settlements",
+    operation_id = "Rematch a Settlement Batch",
+    security(("api_key" = []))
+)]
+pub async fn rematch_settlement_batch() {}
+
+/// Settlements - List Exceptions
+///
+/// Lists the settlement lines which could not be matched or which do not match the resource they settle
+#[utoipa::path(
+    get,
+    path = "/settlements/exceptions",
+    params(
+        ("connector" = Option<String>, Query, description = "Only list the exceptions of this connector"),
+        ("recon_status" = Option<SettlementReconStatus>, Query, description = "Only list the exceptions with this status"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of exceptions to list"),
+        ("offset" = Option<i64>, Query, description = "The number of exceptions to skip"),
+    ),
+    responses(
+        (status = 200, description = "Settlement exceptions listed", body = Vec<SettlementLineResponse>)
+    ),
+    tag = "Settlements",
+    operation_id = "List Settlement Exceptions",
+    security(("api_key" = []))
+)]
+pub async fn list_settlement_exceptions() {}
+
+/// Settlements - Resolve Line
+///
+/// Marks a settlement exception as resolved
+#[utoipa::path(
+    post,
+    path = "/settlements/lines/{line_id}/resolve",
+    params(("line_id" = String, Path, description = "The identifier of the settlement line")),
+    request_body = SettlementLineResolveRequest,
+    responses(
+        (status = 200, description = "Settlement line resolved", body = SettlementLineResponse),
+        (status = 404, description = "Settlement line not found")
+    ),
+    tag = "Settlements",
+    operation_id = "Resolve a Settlement Line",
+    security(("api_key" = []))
+)]
+pub async fn resolve_settlement_line() {}
+
+/// Settlements - Retrieve Payment Reconciliation
+///
+/// Retrieves the reconciliation status of a payment, with the fees and the net amount settled for it
+#[utoipa::path(
+    get,
+    path = "/settlements/payments/{payment_id}",
+    params(("payment_id" = String, Path, description = "The identifier of the payment")),
+    responses(
+        (status = 200, description = "Payment reconciliation retrieved", body = PaymentReconciliationResponse),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Settlements",
+    operation_id = "Retrieve the Reconciliation of a Payment",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_payment_reconciliation() {}
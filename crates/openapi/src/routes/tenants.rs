@@ -0,0 +1,71 @@
+/// Tenants - Create
+///
+/// Provisions a tenant at runtime: sets up its schema from the template schema, allocates its
+/// redis key prefix and inserts the default configs in its schema
+#[utoipa::path(
+    post,
+    path = "/tenants",
+    request_body = TenantCreateRequest,
+    responses(
+        (status = 200, description = "Tenant created", body = TenantResponse),
+        (status = 400, description = "Invalid data or the resources are already allocated to another tenant")
+    ),
+    tag = "Tenants",
+    operation_id = "Create a Tenant",
+    security(("admin_api_key" = []))
+)]
+pub async fn create_tenant() {}
+
+/// Tenants - List
+///
+/// Lists the tenants provisioned through the tenants API
+#[utoipa::path(
+    get,
+    path = "/tenants",
+    responses(
+        (status = 200, description = "Tenants listed", body = Vec<TenantResponse>)
+    ),
+    tag = "Tenants",
+    operation_id = "List all Tenants",
+    security(("admin_api_key" = []))
+)]
+pub async fn list_tenants() {}
+
+/// Tenants - Retrieve
+///
+/// Retrieves a tenant provisioned through the tenants API
+#[utoipa::path(
+    get,
+    path = "/tenants/{tenant_id}",
+    params(
+        ("tenant_id" = String, Path, description = "The unique identifier for the tenant")
+    ),
+    responses(
+        (status = 200, description = "Tenant retrieved", body = TenantResponse),
+        (status = 404, description = "Tenant not found")
+    ),
+    tag = "Tenants",
+    operation_id = "Retrieve a Tenant",
+    security(("admin_api_key" = []))
+)]
+pub async fn retrieve_tenant() {}
+
+/// Tenants - Update
+///
+/// Disables or re-enables a tenant provisioned through the tenants API, or replaces its quotas
+#[utoipa::path(
+    post,
+    path = "/tenants/{tenant_id}",
+    params(
+        ("tenant_id" = String, Path, description = "The unique identifier for the tenant")
+    ),
+    request_body = TenantUpdateRequest,
+    responses(
+        (status = 200, description = "Tenant updated", body = TenantResponse),
+        (status = 404, description = "Tenant not found")
+    ),
+    tag = "Tenants",
+    operation_id = "Update a Tenant",
+    security(("admin_api_key" = []))
+)]
+pub async fn update_tenant() {}
@@ -0,0 +1,55 @@
+/// Vault - Expire Token
+///
+/// Expires a temporary payment method token before the end of its time to live, removing the card
+/// data held for it in the temporary locker
+#[utoipa::path(
+    post,
+    path = "/vault/tokens/expire",
+    request_body = VaultTokenExpireRequest,
+    responses(
+        (status = 200, description = "Token expired", body = VaultTokenExpireResponse),
+        (status = 404, description = "Token not found")
+    ),
+    tag = "Vault",
+    operation_id = "Expire a Vault Token",
+    security(("api_key" = []))
+)]
+pub async fn expire_vault_token() {}
+
+/// Vault - Cleanup
+///
+/// Schedules the deletion of the vault entries of the merchant that are no longer linked to a
+/// customer or a mandate, along with their payment methods
+#[utoipa::path(
+    post,
+    path = "/vault/cleanup",
+    request_body = VaultCleanupRequest,
+    responses(
+        (status = 200, description = "Vault cleanup scheduled", body = VaultCleanupResponse)
+    ),
+    tag = "Vault",
+    operation_id = "Schedule a Vault Cleanup",
+    security(("api_key" = []))
+)]
+pub async fn schedule_vault_cleanup() {}
+
+/// Vault - Audit
+///
+/// Lists the reads, deletions and expiries of the vault tokens of the merchant, most recent first
+#[utoipa::path(
+    get,
+    path = "/vault/audit",
+    params(
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only list the events recorded before this time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to list"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip"),
+    ),
+    responses(
+        (status = 200, description = "Vault audit events listed", body = AuditEventsResponse)
+    ),
+    tag = "Vault",
+    operation_id = "List Vault Audit Events",
+    security(("api_key" = []))
+)]
+pub async fn list_vault_audit_events() {}
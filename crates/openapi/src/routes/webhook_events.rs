@@ -92,3 +92,46 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Incoming Webhook List
+///
+/// List the incoming webhooks received for a Merchant Account or Business Profile, from the latest
+/// received.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id_or_profile_id}/incoming",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include webhooks received after the specified time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include webhooks received before the specified time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of webhooks to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of webhooks to skip when retrieving the list of webhooks"),
+    ),
+    responses(
+        (status = 200, description = "List of incoming webhooks retrieved successfully", body = Vec<IncomingWebhookListItemResponse>),
+    ),
+    tag = "Event",
+    operation_id = "List all incoming webhooks received for a Merchant Account or Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn list_incoming_webhooks() {}
+
+/// Events - Incoming Webhook Retrieve
+///
+/// Retrieve an incoming webhook received, along with its body.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id_or_profile_id}/incoming/{archive_id}",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("archive_id" = String, Path, description = "The unique identifier for the incoming webhook"),
+    ),
+    responses(
+        (status = 200, description = "Incoming webhook retrieved successfully", body = IncomingWebhookRetrieveResponse),
+        (status = 404, description = "Incoming webhook not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve an incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_incoming_webhook() {}
@@ -6,16 +6,18 @@
 //!
 //!
 
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
 
 use common_utils::{
     errors::CustomResult,
-    ext_traits::{AsyncExt, ByteSliceExt, Encode, StringExt},
+    ext_traits::{ByteSliceExt, Encode, StringExt},
     fp_utils,
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, SetsInterface, SortedSetsInterface, StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -269,6 +271,27 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    /// Increments the value of the key by one, and sets the expiry of the key when it is created
+    /// by this increment, so that the value is reset once the expiry elapses.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        let value: i64 = self
+            .pool
+            .incr(self.add_prefix(key))
+            .await
+            .change_context(errors::RedisError::IncrementFailed)?;
+
+        if value == 1 {
+            self.set_expiry(key, seconds).await?;
+        }
+
+        Ok(value)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expire_at(
         &self,
@@ -281,6 +304,8 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    /// Sets the hash fields and the expiry of the key in a single round trip. Both commands target
+    /// the same key, hence the same shard when the Redis is clustered.
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_fields<V>(
         &self,
@@ -292,19 +317,27 @@ impl super::RedisConnectionPool {
         V: TryInto<RedisMap> + Debug + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
-        let output: Result<(), _> = self
-            .pool
-            .hset(self.add_prefix(key), values)
+        let key = self.add_prefix(key);
+        let pipeline = self.pool.next().pipeline();
+        pipeline
+            .hset::<(), _, _>(key.as_str(), values)
             .await
-            .change_context(errors::RedisError::SetHashFailed);
-        // setting expiry for the key
-        output
-            .async_and_then(|_| {
-                self.set_expiry(key, ttl.unwrap_or(self.config.default_hash_ttl.into()))
-            })
+            .change_context(errors::RedisError::SetHashFailed)?;
+        pipeline
+            .expire::<(), _>(
+                key.as_str(),
+                ttl.unwrap_or(self.config.default_hash_ttl.into()),
+            )
             .await
+            .change_context(errors::RedisError::SetExpiryFailed)?;
+
+        pipeline
+            .all::<()>()
+            .await
+            .change_context(errors::RedisError::SetHashFailed)
     }
 
+    /// Sets the hash field, unless it exists, and the expiry of the key in a single round trip
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_field_if_not_exist<V>(
         &self,
@@ -317,19 +350,25 @@ impl super::RedisConnectionPool {
         V: TryInto<RedisValue> + Debug + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
-        let output: Result<HsetnxReply, _> = self
-            .pool
-            .hsetnx(self.add_prefix(key), field, value)
+        let key = self.add_prefix(key);
+        let pipeline = self.pool.next().pipeline();
+        pipeline
+            .hsetnx::<(), _, _, _>(key.as_str(), field, value)
+            .await
+            .change_context(errors::RedisError::SetHashFieldFailed)?;
+        pipeline
+            .expire::<(), _>(
+                key.as_str(),
+                ttl.unwrap_or(self.config.default_hash_ttl).into(),
+            )
             .await
-            .change_context(errors::RedisError::SetHashFieldFailed);
+            .change_context(errors::RedisError::SetExpiryFailed)?;
 
-        output
-            .async_and_then(|inner| async {
-                self.set_expiry(key, ttl.unwrap_or(self.config.default_hash_ttl).into())
-                    .await?;
-                Ok(inner)
-            })
+        let (reply, _): (HsetnxReply, RedisValue) = pipeline
+            .all()
             .await
+            .change_context(errors::RedisError::SetHashFieldFailed)?;
+        Ok(reply)
     }
 
     #[instrument(level = "DEBUG", skip(self))]
@@ -351,6 +390,10 @@ impl super::RedisConnectionPool {
             .await
     }
 
+    /// Sets the hash field of each of the keys, unless it exists, along with the expiry of the
+    /// keys. The keys are pipelined by the hash slot they belong to, so that each pipeline is
+    /// served by a single shard when the Redis is clustered. The replies are in the order of the
+    /// keys.
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn serialize_and_set_multiple_hash_field_if_not_exist<V>(
         &self,
@@ -361,14 +404,52 @@ impl super::RedisConnectionPool {
     where
         V: serde::Serialize + Debug,
     {
-        let mut hsetnx: Vec<HsetnxReply> = Vec::with_capacity(kv.len());
-        for (key, val) in kv {
-            hsetnx.push(
-                self.serialize_and_set_hash_field_if_not_exist(key, field, val, ttl)
-                    .await?,
-            );
+        let ttl = i64::from(ttl.unwrap_or(self.config.default_hash_ttl));
+
+        let mut keys_by_slot: BTreeMap<u16, Vec<(usize, String, Vec<u8>)>> = BTreeMap::new();
+        for (index, (key, val)) in kv.iter().enumerate() {
+            let serialized = val
+                .encode_to_vec()
+                .change_context(errors::RedisError::JsonSerializationFailed)?;
+            let key = self.add_prefix(key);
+            keys_by_slot
+                .entry(fred::util::redis_keyslot(key.as_bytes()))
+                .or_default()
+                .push((index, key, serialized));
         }
-        Ok(hsetnx)
+
+        let mut hsetnx: Vec<Option<HsetnxReply>> = kv.iter().map(|_| None).collect();
+        for keys in keys_by_slot.into_values() {
+            let pipeline = self.pool.next().pipeline();
+            for (_, key, serialized) in &keys {
+                pipeline
+                    .hsetnx::<(), _, _, _>(key.as_str(), field, serialized.as_slice())
+                    .await
+                    .change_context(errors::RedisError::SetHashFieldFailed)?;
+                pipeline
+                    .expire::<(), _>(key.as_str(), ttl)
+                    .await
+                    .change_context(errors::RedisError::SetExpiryFailed)?;
+            }
+
+            let replies: Vec<RedisValue> = pipeline
+                .all()
+                .await
+                .change_context(errors::RedisError::SetHashFieldFailed)?;
+            // Every key has the reply of its HSETNX followed by the reply of its EXPIRE
+            for ((index, _, _), reply) in keys.iter().zip(replies.into_iter().step_by(2)) {
+                let reply = HsetnxReply::from_value(reply)
+                    .change_context(errors::RedisError::SetHashFieldFailed)?;
+                if let Some(entry) = hsetnx.get_mut(*index) {
+                    *entry = Some(reply);
+                }
+            }
+        }
+
+        hsetnx
+            .into_iter()
+            .map(|reply| reply.ok_or(report!(errors::RedisError::SetHashFieldFailed)))
+            .collect()
     }
 
     #[instrument(level = "DEBUG", skip(self))]
@@ -474,6 +555,58 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    /// Adds the member to the sorted set with the given score, updating the score of the member
+    /// if it is already present in the sorted set.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn sorted_set_add_member(
+        &self,
+        key: &str,
+        member: &str,
+        score: f64,
+    ) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .zadd::<i64, _, _>(
+                self.add_prefix(key),
+                None,
+                None,
+                false,
+                false,
+                (score, member),
+            )
+            .await
+            .map(|_| ())
+            .change_context(errors::RedisError::SortedSetAddMembersFailed)
+    }
+
+    /// Removes the members of the sorted set with a score within the given range (inclusive).
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn sorted_set_remove_members_by_score(
+        &self,
+        key: &str,
+        min_score: f64,
+        max_score: f64,
+    ) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .zremrangebyscore::<i64, _, _, _>(self.add_prefix(key), min_score, max_score)
+            .await
+            .map(|_| ())
+            .change_context(errors::RedisError::SortedSetRemoveMembersFailed)
+    }
+
+    /// Returns the members of the sorted set with a score within the given range (inclusive).
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn sorted_set_get_members_by_score(
+        &self,
+        key: &str,
+        min_score: f64,
+        max_score: f64,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .zrangebyscore(self.add_prefix(key), min_score, max_score, false, None)
+            .await
+            .change_context(errors::RedisError::SortedSetGetMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
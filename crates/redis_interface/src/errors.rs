@@ -14,6 +14,8 @@ pub enum RedisError {
     SetExFailed,
     #[error("Failed to set expiry for key value in Redis")]
     SetExpiryFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
     #[error("Failed to delete key value in Redis")]
@@ -52,6 +54,12 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to add members to set in Redis")]
     SetAddMembersFailed,
+    #[error("Failed to add members to sorted set in Redis")]
+    SortedSetAddMembersFailed,
+    #[error("Failed to remove members from sorted set in Redis")]
+    SortedSetRemoveMembersFailed,
+    #[error("Failed to get members of sorted set in Redis")]
+    SortedSetGetMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
     #[error("The requested value was not found in Redis")]
@@ -41,6 +41,13 @@ pub struct RedisSettings {
     pub port: u16,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
+    /// Whether the Redis is monitored by sentinels, the primary being discovered through the
+    /// sentinels and followed across failovers
+    pub sentinel_enabled: bool,
+    /// Addresses of the sentinels, as `host:port`
+    pub sentinel_urls: Vec<String>,
+    /// Name of the primary monitored by the sentinels
+    pub sentinel_service_name: String,
     pub use_legacy_version: bool,
     pub pool_size: usize,
     pub reconnect_max_attempts: u32,
@@ -76,6 +83,32 @@ impl RedisSettings {
             ))
         })?;
 
+        when(self.cluster_enabled && self.sentinel_enabled, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
+            ))
+        })?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_urls.is_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_urls` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_service_name.is_default_or_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_service_name` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+            },
+        )?;
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -95,6 +128,9 @@ impl Default for RedisSettings {
             port: 6379,
             cluster_enabled: false,
             cluster_urls: vec![],
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::new(),
             use_legacy_version: false,
             pool_size: 5,
             reconnect_max_attempts: 5,
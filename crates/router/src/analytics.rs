@@ -3,12 +3,25 @@ pub use analytics::*;
 pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
-        api_event::api_events_core, connector_events::connector_events_core,
-        errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
-        outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
+        api_event::api_events_core,
+        connector_events::{connector_events_core, connector_metrics_core},
+        errors::AnalyticsError,
+        lambda_utils::invoke_lambda,
+        opensearch::OpenSearchError,
+        outgoing_webhook_event::outgoing_webhook_events_core,
+        payment_declines::payment_declines_core,
+        payment_funnel::{
+            payment_failure_reasons_core, payment_funnel_core, payment_method_distribution_core,
+        },
+        sdk_events::sdk_events_core,
         AnalyticsFlow,
     };
     use api_models::analytics::{
+        payment_declines::GetPaymentDeclinesRequest,
+        payment_funnel::{
+            GetPaymentFailureReasonsRequest, GetPaymentFunnelRequest,
+            GetPaymentMethodDistributionRequest,
+        },
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
@@ -22,7 +35,7 @@ pub mod routes {
     use crate::{
         consts::opensearch::OPENSEARCH_INDEX_PERMISSIONS,
         core::{api_locking, errors::user::UserErrors},
-        db::user::UserInterface,
+        db::{business_profile::BusinessProfileInterface, user::UserInterface},
         routes::AppState,
         services::{
             api,
@@ -44,6 +57,22 @@ pub mod routes {
                         web::resource("metrics/payments")
                             .route(web::post().to(get_payment_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/payments/funnel")
+                            .route(web::post().to(get_payment_funnel)),
+                    )
+                    .service(
+                        web::resource("metrics/payments/failure_reasons")
+                            .route(web::post().to(get_payment_failure_reasons)),
+                    )
+                    .service(
+                        web::resource("metrics/payments/payment_method_distribution")
+                            .route(web::post().to(get_payment_method_distribution)),
+                    )
+                    .service(
+                        web::resource("metrics/payments/declines")
+                            .route(web::post().to(get_payment_declines)),
+                    )
                     .service(
                         web::resource("metrics/refunds").route(web::post().to(get_refunds_metrics)),
                     )
@@ -89,6 +118,9 @@ pub mod routes {
                         web::resource("connector_event_logs")
                             .route(web::get().to(get_connector_events)),
                     )
+                    .service(
+                        web::resource("connectors").route(web::get().to(get_connector_metrics)),
+                    )
                     .service(
                         web::resource("outgoing_webhook_event_logs")
                             .route(web::get().to(get_outgoing_webhook_events)),
@@ -684,6 +716,130 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_payment_funnel(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFunnelRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFunnel;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                payment_funnel_core(&state.pool, req, auth.merchant_account.merchant_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_payment_failure_reasons(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFailureReasonsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFailureReasons;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                payment_failure_reasons_core(&state.pool, req, auth.merchant_account.merchant_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_payment_method_distribution(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentMethodDistributionRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentMethodDistribution;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                payment_method_distribution_core(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.merchant_id,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_payment_declines(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentDeclinesRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentDeclines;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                payment_declines_core(&state.pool, req, auth.merchant_account.merchant_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_connector_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Query<api_models::analytics::connector_events::ConnectorMetricsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetConnectorMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                if let Some(profile_id) = &req.profile_id {
+                    let business_profile = state
+                        .store
+                        .find_business_profile_by_profile_id(profile_id)
+                        .await
+                        .change_context(AnalyticsError::AccessForbiddenError)?;
+                    if business_profile.merchant_id != auth.merchant_account.merchant_id {
+                        return Err(AnalyticsError::AccessForbiddenError.into());
+                    }
+                }
+                connector_metrics_core(&state.pool, req, auth.merchant_account.merchant_id)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_global_search_results(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
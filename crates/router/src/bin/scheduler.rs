@@ -313,6 +313,107 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::MandatePreNotificationWorkflow => Ok(Box::new(
+                    workflows::mandate_pre_notification::MandatePreNotificationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::FrmReviewTimeoutWorkflow => {
+                    #[cfg(feature = "frm")]
+                    {
+                        Ok(Box::new(
+                            workflows::frm_review_timeout::FrmReviewTimeoutWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "frm"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run FRM review timeout workflow when frm feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::ReportGenerationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::report_generation::ReportGenerationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run report generation workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::CustomerRedactionWorkflow => Ok(Box::new(
+                    workflows::customer_redaction::CustomerRedactionWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CardExportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::card_export::CardExportWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run card export workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::VaultCleanupWorkflow => {
+                    Ok(Box::new(workflows::vault_cleanup::VaultCleanupWorkflow))
+                }
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::BulkForceSyncWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::bulk_force_sync::BulkForceSyncWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run bulk force sync workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::MasterKeyRotationWorkflow => Ok(Box::new(
+                    workflows::master_key_rotation::MasterKeyRotationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow => Ok(Box::new(
+                    workflows::merchant_key_rotation::MerchantKeyRotationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AsyncConfirmationWorkflow => Ok(Box::new(
+                    workflows::async_confirmation::AsyncConfirmationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ConnectorMigrationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::connector_migration::ConnectorMigrationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run connector migration workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::OutboxRelayWorkflow => {
+                    Ok(Box::new(workflows::outbox_relay::OutboxRelayWorkflow))
+                }
+                storage::ProcessTrackerRunner::JobWorkflow => {
+                    Ok(Box::new(workflows::job::JobWorkflow))
+                }
+                storage::ProcessTrackerRunner::AlertEvaluationWorkflow => Ok(Box::new(
+                    workflows::alert_evaluation::AlertEvaluationWorkflow,
+                )),
             }
         };
 
@@ -366,6 +467,10 @@ async fn start_scheduler(
         |state, tenant| {
             Arc::new(state.clone())
                 .get_session_state(tenant, || ProcessTrackerError::TenantNotFound.into())
+                .map(|session_state| {
+                    session_state
+                        .with_status_actor(diesel_models::enums::PaymentStatusActor::Scheduler)
+                })
         },
     )
     .await
@@ -266,6 +266,8 @@ pub enum StripeErrorCode {
     ExtendedCardInfoNotFound,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "IR_28", message = "Invalid tenant")]
     InvalidTenant,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly, retry after {retry_after_in_secs} seconds")]
+    RateLimitExceeded { retry_after_in_secs: u64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -650,6 +652,14 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ExtendedCardInfoNotFound => Self::ExtendedCardInfoNotFound,
             errors::ApiErrorResponse::InvalidTenant { tenant_id: _ }
             | errors::ApiErrorResponse::MissingTenantId => Self::InvalidTenant,
+            errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_in_secs,
+            }
+            | errors::ApiErrorResponse::ServerOverloaded {
+                retry_after_in_secs,
+            } => Self::RateLimitExceeded {
+                retry_after_in_secs,
+            },
         }
     }
 }
@@ -739,15 +749,22 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::RateLimitExceeded {
+            retry_after_in_secs,
+        } = self
+        {
+            response.insert_header((header::RETRY_AFTER, retry_after_in_secs.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
 
@@ -1,6 +1,7 @@
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::{
+    admin::WebhookPayloadFilter,
     enums::{DisputeStatus, MandateStatus},
     webhooks::{self as api},
 };
@@ -9,7 +10,7 @@ use common_utils::{
     crypto::Encryptable,
     pii::{self, Email},
 };
-use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
+use common_utils::{crypto::SignMessage, date_time, id_type};
 use error_stack::ResultExt;
 use router_env::logger;
 use serde::Serialize;
@@ -20,7 +21,10 @@ use super::{
 use crate::{
     core::{
         errors,
-        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+        webhooks::types::{
+            encode_outgoing_webhook_payload, OutgoingWebhookPayloadWithSignature,
+            OutgoingWebhookType,
+        },
     },
     headers,
     services::request::Maskable,
@@ -41,6 +45,7 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        payload_filter: Option<&WebhookPayloadFilter>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let timestamp = self.created;
 
@@ -48,10 +53,8 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
             .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
 
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
+        let webhook_signature_payload =
+            encode_outgoing_webhook_payload(self, "/data/object", payload_filter)?;
 
         let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
         let v1 = hex::encode(
@@ -90,6 +93,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Customer(StripeDeletedCustomerResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -110,6 +114,12 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeDeletedCustomerResponse {
+    pub id: id_type::CustomerId,
+    pub deleted: bool,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -232,6 +242,15 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+impl From<api_models::customers::CustomerRedactionResponse> for StripeDeletedCustomerResponse {
+    fn from(res: api_models::customers::CustomerRedactionResponse) -> Self {
+        Self {
+            id: res.customer_id,
+            deleted: res.status == api_models::customers::CustomerRedactionStatus::Completed,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -267,6 +286,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::ActionRequired => "action.required",
         api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
         api_models::enums::EventType::RefundFailed => "refund.failed",
+        api_models::enums::EventType::RefundUpdated => "refund.updated",
         api_models::enums::EventType::DisputeOpened => "dispute.failed",
         api_models::enums::EventType::DisputeExpired => "dispute.expired",
         api_models::enums::EventType::DisputeAccepted => "dispute.accepted",
@@ -276,6 +296,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::MandatePreNotification => "mandate.pre_notification",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -290,6 +311,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::CustomerRedacted => "customer.deleted",
     }
 }
 
@@ -332,6 +354,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout.into()),
+            api::OutgoingWebhookContent::CustomerRedactionDetails(redaction) => {
+                Self::Customer((*redaction).into())
+            }
         }
     }
 }
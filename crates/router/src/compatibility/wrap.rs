@@ -69,7 +69,7 @@ where
         Ok(api::ApplicationResponse::Json(response)) => {
             let response = S::try_from(response);
             match response {
-                Ok(response) => match serde_json::to_string(&response) {
+                Ok(response) => match api::serialize_json_response(&response, request) {
                     Ok(res) => api::http_response_json(res),
                     Err(_) => api::http_response_err(
                         r#"{
@@ -91,7 +91,7 @@ where
         Ok(api::ApplicationResponse::JsonWithHeaders((response, headers))) => {
             let response = S::try_from(response);
             match response {
-                Ok(response) => match serde_json::to_string(&response) {
+                Ok(response) => match api::serialize_json_response(&response, request) {
                     Ok(res) => api::http_response_json_with_headers(res, headers, None),
                     Err(_) => api::http_response_err(
                         r#"{
@@ -47,6 +47,16 @@ impl Default for super::settings::Database {
     }
 }
 
+impl Default for super::settings::TenantProvisioning {
+    fn default() -> Self {
+        Self {
+            template_schema: "public".into(),
+            clickhouse_database: "default".into(),
+            default_configs: HashMap::new(),
+        }
+    }
+}
+
 impl Default for super::settings::Proxy {
     fn default() -> Self {
         Self {
@@ -102,6 +112,80 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::IncomingWebhookConcurrency {
+    fn default() -> Self {
+        Self {
+            priority_limit: 100,
+            bulk_limit: 20,
+            acquire_timeout_in_ms: 5000,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::IncomingWebhookIdempotency {
+    fn default() -> Self {
+        Self {
+            processing_timeout_in_secs: 60,
+            // 7 days
+            retention_in_secs: 604800,
+        }
+    }
+}
+
+impl Default for super::settings::HealthCheckSettings {
+    fn default() -> Self {
+        Self {
+            scheduler_lag_threshold_in_secs: 300,
+            outgoing_webhook_queue_threshold: 10000,
+        }
+    }
+}
+
+impl Default for super::settings::AlertingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            evaluation_interval_in_secs: 60,
+            max_window_in_mins: 60,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorResponseCacheSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            default_ttl_in_secs: 5,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::LoadSheddingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_in_flight_requests: 1024,
+            checkout: super::settings::AdmissionQuota {
+                max_in_flight_percentage: 100,
+                max_queue_length: 512,
+                queue_timeout_in_ms: 2000,
+            },
+            sync: super::settings::AdmissionQuota {
+                max_in_flight_percentage: 80,
+                max_queue_length: 256,
+                queue_timeout_in_ms: 1000,
+            },
+            batch: super::settings::AdmissionQuota {
+                max_in_flight_percentage: 50,
+                max_queue_length: 32,
+                queue_timeout_in_ms: 500,
+            },
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
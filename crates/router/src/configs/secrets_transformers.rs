@@ -197,6 +197,45 @@ impl SecretsHandler for settings::PaymentMethodAuth {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ExternalVaults {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let mut external_vaults = value.get_inner().0.clone();
+
+        for vault in external_vaults.values_mut() {
+            let (username, password) = tokio::try_join!(
+                secret_management_client.get_secret(vault.username.clone()),
+                secret_management_client.get_secret(vault.password.clone()),
+            )?;
+            vault.username = username;
+            vault.password = password;
+        }
+
+        Ok(value.transition_state(|_| Self(external_vaults)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::TaxProviders {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let mut tax_providers = value.get_inner().0.clone();
+
+        for provider in tax_providers.values_mut() {
+            provider.api_key = secret_management_client
+                .get_secret(provider.api_key.clone())
+                .await?;
+        }
+
+        Ok(value.transition_state(|_| Self(tax_providers)))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::Secrets {
     async fn convert_to_raw_secret(
@@ -210,12 +249,21 @@ impl SecretsHandler for settings::Secrets {
             secret_management_client.get_secret(secrets.recon_admin_api_key.clone()),
             secret_management_client.get_secret(secrets.master_enc_key.clone())
         )?;
+        let previous_master_enc_key = match secrets.previous_master_enc_key.clone() {
+            Some(previous_master_enc_key) => Some(
+                secret_management_client
+                    .get_secret(previous_master_enc_key)
+                    .await?,
+            ),
+            None => None,
+        };
 
         Ok(value.transition_state(|_| Self {
             jwt_secret,
             admin_api_key,
             recon_admin_api_key,
             master_enc_key,
+            previous_master_enc_key,
         }))
     }
 }
@@ -318,6 +366,20 @@ pub(crate) async fn fetch_raw_secrets(
     .await
     .expect("Failed to decrypt payment method auth configs");
 
+    #[allow(clippy::expect_used)]
+    let external_vaults = settings::ExternalVaults::convert_to_raw_secret(
+        conf.external_vaults,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt external vault configs");
+
+    #[allow(clippy::expect_used)]
+    let tax_providers =
+        settings::TaxProviders::convert_to_raw_secret(conf.tax_providers, secret_management_client)
+            .await
+            .expect("Failed to decrypt tax provider configs");
+
     #[allow(clippy::expect_used)]
     let user_auth_methods = settings::UserAuthMethodSettings::convert_to_raw_secret(
         conf.user_auth_methods,
@@ -339,6 +401,8 @@ pub(crate) async fn fetch_raw_secrets(
         env: conf.env,
         #[cfg(feature = "olap")]
         replica_database,
+        #[cfg(feature = "olap")]
+        replica_routing: conf.replica_routing,
         secrets,
         locker: conf.locker,
         connectors: conf.connectors,
@@ -362,6 +426,7 @@ pub(crate) async fn fetch_raw_secrets(
         mandates: conf.mandates,
         network_transaction_id_supported_connectors: conf
             .network_transaction_id_supported_connectors,
+        connector_capabilities: conf.connector_capabilities,
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
@@ -372,7 +437,13 @@ pub(crate) async fn fetch_raw_secrets(
         applepay_decrypt_keys,
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
+        wallet_decryption: conf.wallet_decryption,
+        external_vaults,
+        tax_providers,
         lock_settings: conf.lock_settings,
+        rate_limit: conf.rate_limit,
+        load_shedding: conf.load_shedding,
+        api_versioning: conf.api_versioning,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
@@ -380,6 +451,10 @@ pub(crate) async fn fetch_raw_secrets(
         analytics,
         #[cfg(feature = "olap")]
         opensearch: conf.opensearch,
+        #[cfg(feature = "olap")]
+        scheduled_reports: conf.scheduled_reports,
+        #[cfg(feature = "olap")]
+        search: conf.search,
         #[cfg(feature = "kv_store")]
         kv_config: conf.kv_config,
         #[cfg(feature = "frm")]
@@ -393,6 +468,8 @@ pub(crate) async fn fetch_raw_secrets(
         unmasked_headers: conf.unmasked_headers,
         saved_payment_methods: conf.saved_payment_methods,
         multitenancy: conf.multitenancy,
+        multi_region: conf.multi_region,
+        in_memory_cache: conf.in_memory_cache,
         user_auth_methods,
     }
 }
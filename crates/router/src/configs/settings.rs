@@ -5,7 +5,13 @@ use std::{
 
 #[cfg(feature = "olap")]
 use analytics::{opensearch::OpenSearchConfig, ReportConfig};
-use api_models::{enums, payment_methods::RequiredFieldInfo};
+use api_models::{
+    api_versioning::ApiVersion,
+    enums,
+    payment_methods::RequiredFieldInfo,
+    rate_limits::{RateLimitEndpointClass, RateLimitQuota},
+    tenant::TenantQuotas,
+};
 use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
 #[cfg(feature = "email")]
@@ -27,14 +33,20 @@ pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use rust_decimal::Decimal;
 use scheduler::SchedulerSettings;
 use serde::Deserialize;
-use storage_impl::config::QueueStrategy;
+#[cfg(feature = "olap")]
+use storage_impl::config::ReplicaRouting;
+use storage_impl::config::{InMemoryCacheSettings, MultiRegion, QueueStrategy};
 
 #[cfg(feature = "olap")]
 use crate::analytics::AnalyticsConfig;
 use crate::{
-    core::errors::{ApplicationError, ApplicationResult},
+    core::{
+        errors::{ApplicationError, ApplicationResult},
+        load_shedding::RequestPriority,
+        webhooks::WebhookLane,
+    },
     env::{self, logger, Env},
-    events::EventsConfig,
+    events::{domain_events::DomainEventsConfig, EventsConfig},
 };
 
 #[derive(clap::Parser, Default)]
@@ -65,6 +77,8 @@ pub struct Settings<S: SecretState> {
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
+    #[cfg(feature = "olap")]
+    pub replica_routing: ReplicaRouting,
     pub redis: RedisSettings,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
@@ -94,6 +108,7 @@ pub struct Settings<S: SecretState> {
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
+    pub connector_capabilities: ConnectorCapabilities,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
@@ -104,7 +119,16 @@ pub struct Settings<S: SecretState> {
     pub applepay_decrypt_keys: SecretStateContainer<ApplePayDecryptConifg, S>,
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
+    pub wallet_decryption: WalletDecryptionConfig,
+    pub external_vaults: SecretStateContainer<ExternalVaults, S>,
+    pub tax_providers: SecretStateContainer<TaxProviders, S>,
     pub lock_settings: LockSettings,
+    pub rate_limit: RateLimitSettings,
+    pub load_shedding: LoadSheddingSettings,
+    pub health_check: HealthCheckSettings,
+    pub alerting: AlertingSettings,
+    pub connector_response_cache: ConnectorResponseCacheSettings,
+    pub api_versioning: ApiVersioningSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -118,11 +142,17 @@ pub struct Settings<S: SecretState> {
     pub report_download_config: ReportConfig,
     #[cfg(feature = "olap")]
     pub opensearch: OpenSearchConfig,
+    #[cfg(feature = "olap")]
+    pub scheduled_reports: ScheduledReports,
+    #[cfg(feature = "olap")]
+    pub search: SearchSettings,
     pub events: EventsConfig,
     #[cfg(feature = "olap")]
     pub connector_onboarding: SecretStateContainer<ConnectorOnboarding, S>,
     pub unmasked_headers: UnmaskedHeaders,
     pub multitenancy: Multitenancy,
+    pub multi_region: MultiRegion,
+    pub in_memory_cache: InMemoryCacheSettings,
     pub saved_payment_methods: EligiblePaymentMethods,
     pub user_auth_methods: SecretStateContainer<UserAuthMethodSettings, S>,
 }
@@ -132,6 +162,8 @@ pub struct Multitenancy {
     pub tenants: TenantConfig,
     pub enabled: bool,
     pub global_tenant: GlobalTenant,
+    #[serde(default)]
+    pub provisioning: TenantProvisioning,
 }
 
 impl Multitenancy {
@@ -157,6 +189,29 @@ pub struct Tenant {
     pub schema: String,
     pub redis_key_prefix: String,
     pub clickhouse_database: String,
+    /// The sink to which the domain events of the tenant are exported, if any
+    #[serde(default)]
+    pub domain_events: Option<DomainEventsConfig>,
+    /// The limits on the resources of the tenant, none being enforced when not set
+    #[serde(default)]
+    pub quotas: Option<TenantQuotas>,
+    /// The master key of the tenant wrapped with a key manager, the master encryption key of the
+    /// deployment being used when not set
+    #[serde(default)]
+    pub key_management: Option<TenantKeyManagement>,
+}
+
+/// The master key of a tenant, with which the merchant key stores of the tenant are encrypted,
+/// wrapped with a key which never leaves the key manager
+#[derive(Debug, Deserialize, Clone)]
+pub struct TenantKeyManagement {
+    pub key_manager: EncryptionManagementConfig,
+    /// The hex encoded master key, encrypted with the key manager
+    pub wrapped_master_key: Secret<String>,
+    /// The master key being rotated out, encrypted with the key manager, with which the merchant
+    /// key stores not yet re-encrypted with the master key are decrypted
+    #[serde(default)]
+    pub previous_wrapped_master_key: Option<Secret<String>>,
 }
 
 impl storage_impl::config::TenantConfig for Tenant {
@@ -174,6 +229,18 @@ impl storage_impl::config::ClickHouseConfig for Tenant {
     }
 }
 
+/// The settings of the tenants provisioned at runtime through the tenants API
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TenantProvisioning {
+    /// The schema whose tables are copied into the schema of the tenants being provisioned
+    pub template_schema: String,
+    /// The clickhouse database of the tenants provisioned without one
+    pub clickhouse_database: String,
+    /// The configs inserted in the schema of the tenants being provisioned
+    pub default_configs: HashMap<String, String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct GlobalTenant {
     pub schema: String,
@@ -201,6 +268,35 @@ pub struct Frm {
     pub enabled: bool,
 }
 
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ScheduledReports {
+    /// Validity of the links to download the generated reports
+    pub download_link_expiry_in_secs: u32,
+    /// Key with which the links to download the generated reports are signed
+    pub signing_key: Secret<String>,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SearchSettings {
+    pub backend: SearchBackend,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchBackend {
+    /// Searches the objects in the database, with exact matches on the criteria
+    #[default]
+    Database,
+    /// Searches the objects in the OpenSearch indexes fed by the analytics events, with full text
+    /// matches on the criteria. The customers and the metadata of the payments are not indexed and
+    /// are always searched in the database
+    OpenSearch,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct KvConfig {
     pub ttl: u32,
@@ -347,6 +443,35 @@ pub struct CorsSettings {
 pub struct Mandates {
     pub supported_payment_methods: SupportedPaymentMethodsForMandate,
     pub update_mandate_supported: SupportedPaymentMethodsForMandate,
+    #[serde(default)]
+    pub pre_notification: MandatePreNotificationConfig,
+}
+
+/// Scheme rules for notifying the customer ahead of a direct debit, keyed by payment method type
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MandatePreNotificationConfig(
+    pub HashMap<enums::PaymentMethodType, MandatePreNotificationRules>,
+);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MandatePreNotificationRules {
+    /// Minimum number of days before the debit, by which the customer has to be notified
+    pub notification_days: u16,
+}
+
+/// The capabilities of the connectors that cannot be derived from their integrations, keyed by
+/// connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorCapabilities(pub HashMap<String, ConnectorCapabilitiesConfig>);
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorCapabilitiesConfig {
+    /// Number of days after the payment within which it can be refunded
+    pub refund_window_in_days: Option<u16>,
+    pub three_ds: bool,
+    pub overcapture: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -477,6 +602,9 @@ pub struct Secrets {
     pub admin_api_key: Secret<String>,
     pub recon_admin_api_key: Secret<String>,
     pub master_enc_key: Secret<String>,
+    /// The master encryption key being rotated out, with which the merchant key stores not yet
+    /// re-encrypted with the master encryption key are decrypted
+    pub previous_master_enc_key: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -593,6 +721,53 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub incoming_concurrency: IncomingWebhookConcurrency,
+    pub incoming_idempotency: IncomingWebhookIdempotency,
+}
+
+/// The idempotency ledger of the incoming webhooks, which keeps the webhooks delivered again by
+/// the connectors from applying their effects twice
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookIdempotency {
+    /// Time a webhook is claimed for while it is processed, after which the connector retrying
+    /// the webhook has it processed again
+    pub processing_timeout_in_secs: u32,
+    /// Time a processed webhook is remembered for
+    pub retention_in_secs: u32,
+}
+
+/// The limits of the incoming webhooks of a connector processed at the same time, in the lane of
+/// the time-sensitive payment webhooks and in the lane of the bulk status updates
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookConcurrency {
+    pub priority_limit: usize,
+    pub bulk_limit: usize,
+    /// Time a webhook waits for room in its lane before the connector is asked to retry it
+    pub acquire_timeout_in_ms: u64,
+    /// The limits of the connectors whose webhooks are processed with limits of their own
+    pub connectors: HashMap<String, IncomingWebhookLaneLimits>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IncomingWebhookLaneLimits {
+    pub priority_limit: usize,
+    pub bulk_limit: usize,
+}
+
+impl IncomingWebhookConcurrency {
+    pub fn get_limit(&self, connector_name: &str, lane: WebhookLane) -> usize {
+        let (priority_limit, bulk_limit) = self
+            .connectors
+            .get(connector_name)
+            .map(|limits| (limits.priority_limit, limits.bulk_limit))
+            .unwrap_or((self.priority_limit, self.bulk_limit));
+        match lane {
+            WebhookLane::Priority => priority_limit,
+            WebhookLane::Bulk => bulk_limit,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -620,6 +795,43 @@ pub struct DelayedSessionConfig {
     pub connectors_with_delayed_session_response: HashSet<enums::Connector>,
 }
 
+/// The vaults hosted outside Hyperswitch which business profiles can store the cards in, keyed by
+/// the name the business profiles refer to them with
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ExternalVaults(pub HashMap<String, ExternalVaultConfig>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExternalVaultConfig {
+    pub base_url: String,
+    pub username: Secret<String>,
+    pub password: Secret<String>,
+}
+
+/// The tax calculation services which business profiles can calculate the taxes on their orders
+/// with, keyed by the name the business profiles refer to them with
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct TaxProviders(pub HashMap<String, TaxProviderConfig>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TaxProviderConfig {
+    pub base_url: String,
+    pub api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct WalletDecryptionConfig {
+    /// Connectors lacking native wallet support, to which the wallet tokens decrypted with the
+    /// keys of the business profile are forwarded as card details
+    #[serde(default, deserialize_with = "deserialize_hashset")]
+    pub connectors_with_decrypted_wallet_forwarding: HashSet<enums::Connector>,
+    /// Base64 encoded DER of the root signing keys of Google Pay, which the signatures of the
+    /// Google Pay tokens are verified against
+    #[serde(default, deserialize_with = "deserialize_hashset")]
+    pub google_pay_root_signing_keys: HashSet<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct WebhookSourceVerificationCall {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -700,6 +912,8 @@ impl Settings<SecuredSecret> {
         self.master_database.get_inner().validate()?;
         #[cfg(feature = "olap")]
         self.replica_database.get_inner().validate()?;
+        #[cfg(feature = "olap")]
+        self.replica_routing.validate()?;
         self.redis.validate().map_err(|error| {
             println!("{error}");
             ApplicationError::InvalidConfigurationValueError("Redis configuration".into())
@@ -736,7 +950,31 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.rate_limit.validate()?;
+        self.load_shedding.validate()?;
+        self.health_check.validate()?;
+        self.alerting.validate()?;
+        self.connector_response_cache.validate()?;
+        self.webhooks.incoming_concurrency.validate()?;
+        self.webhooks.incoming_idempotency.validate()?;
+        self.multi_region.validate()?;
+        self.in_memory_cache.validate()?;
         self.events.validate()?;
+        self.multitenancy
+            .get_tenants()
+            .values()
+            .filter_map(|tenant| tenant.domain_events.as_ref())
+            .try_for_each(|domain_events| domain_events.validate(&self.events))?;
+        self.multitenancy
+            .get_tenants()
+            .values()
+            .filter_map(|tenant| tenant.key_management.as_ref())
+            .try_for_each(|key_management| {
+                key_management
+                    .key_manager
+                    .validate()
+                    .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))
+            })?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
@@ -766,6 +1004,117 @@ impl Settings<RawSecret> {
     }
 }
 
+/// The default quotas of the requests a merchant can make to the API, which can be overridden for
+/// a merchant through the rate limits API
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    pub payments: RateLimitQuota,
+    pub refunds: RateLimitQuota,
+    pub reads: RateLimitQuota,
+    pub others: RateLimitQuota,
+}
+
+impl RateLimitSettings {
+    pub fn get_quota(&self, endpoint_class: RateLimitEndpointClass) -> RateLimitQuota {
+        match endpoint_class {
+            RateLimitEndpointClass::Payments => self.payments,
+            RateLimitEndpointClass::Refunds => self.refunds,
+            RateLimitEndpointClass::Reads => self.reads,
+            RateLimitEndpointClass::Others => self.others,
+        }
+    }
+}
+
+/// The thresholds beyond which the dependencies reported by the detailed health check are degraded
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct HealthCheckSettings {
+    /// Time the earliest task due is left waiting for the scheduler before the scheduler is
+    /// degraded
+    pub scheduler_lag_threshold_in_secs: u32,
+    /// Number of outgoing webhooks waiting to be delivered before their delivery is degraded
+    pub outgoing_webhook_queue_threshold: u32,
+}
+
+/// The evaluation of the alert rules of the merchants, the outcomes watched by the rules being
+/// counted in redis by the minute
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AlertingSettings {
+    pub enabled: bool,
+    /// Time between two evaluations of the alert rules of a merchant
+    pub evaluation_interval_in_secs: u32,
+    /// The longest window of the alert rules, the outcomes being counted for this long
+    pub max_window_in_mins: u16,
+}
+
+/// The caching of the responses of the connectors to the payment and refund syncs, so that bursts
+/// of syncs of a payment or refund do not each call the connector
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorResponseCacheSettings {
+    pub enabled: bool,
+    /// Time a response is cached for, for the connectors without a time of their own
+    pub default_ttl_in_secs: u32,
+    /// Time the responses of each connector are cached for, the responses of the connectors with
+    /// a time of zero not being cached
+    pub connectors: HashMap<String, u32>,
+}
+
+impl ConnectorResponseCacheSettings {
+    pub fn get_ttl_in_secs(&self, connector_name: &str) -> u32 {
+        self.connectors
+            .get(connector_name)
+            .copied()
+            .unwrap_or(self.default_ttl_in_secs)
+    }
+}
+
+/// The admission control of the requests processed by the instance, shedding the requests of the
+/// lower priorities first when the instance is overloaded
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LoadSheddingSettings {
+    pub enabled: bool,
+    /// The number of requests the instance processes at the same time
+    pub max_in_flight_requests: usize,
+    pub checkout: AdmissionQuota,
+    pub sync: AdmissionQuota,
+    pub batch: AdmissionQuota,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct AdmissionQuota {
+    /// The share of the requests processed at the same time the requests of the priority can
+    /// take up, as a percentage
+    pub max_in_flight_percentage: u8,
+    /// The number of requests of the priority waiting for room, further requests being shed
+    pub max_queue_length: usize,
+    /// Time a request of the priority waits for room before being shed
+    pub queue_timeout_in_ms: u64,
+}
+
+impl LoadSheddingSettings {
+    pub fn get_quota(&self, priority: RequestPriority) -> AdmissionQuota {
+        match priority {
+            RequestPriority::Checkout => self.checkout,
+            RequestPriority::Sync => self.sync,
+            RequestPriority::Batch => self.batch,
+        }
+    }
+}
+
+/// The versioning of the schema of the API responses
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ApiVersioningSettings {
+    /// The version of the responses to the requests without the `X-API-Version` header, for the
+    /// merchants without a pinned version
+    pub default_version: ApiVersion,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Payouts {
@@ -181,6 +181,64 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        [
+            ("payments", self.payments),
+            ("refunds", self.refunds),
+            ("reads", self.reads),
+            ("others", self.others),
+        ]
+        .into_iter()
+        .try_for_each(|(endpoint_class, quota)| {
+            when(quota.requests_per_minute == 0 || quota.burst == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "rate limit quota of the {endpoint_class} endpoints must be greater than 0"
+                )))
+            })
+        })
+    }
+}
+
+impl super::settings::LoadSheddingSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.max_in_flight_requests == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "max_in_flight_requests of the load shedding must be greater than 0".into(),
+            ))
+        })?;
+        [
+            ("checkout", self.checkout),
+            ("sync", self.sync),
+            ("batch", self.batch),
+        ]
+        .into_iter()
+        .try_for_each(|(priority, quota)| {
+            when(
+                quota.max_in_flight_percentage == 0 || quota.max_in_flight_percentage > 100,
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "max_in_flight_percentage of the {priority} requests must be between 1 \
+                        and 100"
+                    )))
+                },
+            )
+        })
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -198,3 +256,97 @@ impl super::settings::GenericLinkEnvConfig {
         })
     }
 }
+
+impl super::settings::IncomingWebhookConcurrency {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        let has_empty_lane = self.priority_limit == 0
+            || self.bulk_limit == 0
+            || self
+                .connectors
+                .values()
+                .any(|limits| limits.priority_limit == 0 || limits.bulk_limit == 0);
+        when(has_empty_lane, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "incoming webhook lane limits must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::IncomingWebhookIdempotency {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.processing_timeout_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "incoming webhook processing timeout must be greater than zero".into(),
+            ))
+        })?;
+
+        when(
+            self.retention_in_secs < self.processing_timeout_in_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "incoming webhook retention must not be shorter than the processing timeout"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::HealthCheckSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.scheduler_lag_threshold_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduler lag threshold must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.outgoing_webhook_queue_threshold == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "outgoing webhook queue threshold must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorResponseCacheSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        // The responses are cached to absorb bursts of syncs, a response cached for longer hiding
+        // the updates of the payment at the connector
+        const MAX_TTL_IN_SECS: u32 = 60;
+
+        let has_long_ttl = self.default_ttl_in_secs > MAX_TTL_IN_SECS
+            || self.connectors.values().any(|ttl| *ttl > MAX_TTL_IN_SECS);
+        when(has_long_ttl, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "connector responses cannot be cached for more than {MAX_TTL_IN_SECS} seconds"
+            )))
+        })
+    }
+}
+
+impl super::settings::AlertingSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.evaluation_interval_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "alert evaluation interval must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.max_window_in_mins == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum window of the alert rules must be greater than zero".into(),
+            ))
+        })
+    }
+}
@@ -48,6 +48,22 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
         .change_context(storage_errors::StorageError::DatabaseConnectionError)
 }
 
+/// Gets a connection from the pool serving the kind of read, the master pool being used in place
+/// of the replica while the replica is lagging
+pub async fn pg_connection_read_for<T: storage_impl::DatabaseStore>(
+    store: &T,
+    query: storage_impl::ReadQuery,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    storage_errors::StorageError,
+> {
+    store
+        .get_read_pool(query)
+        .get()
+        .await
+        .change_context(storage_errors::StorageError::DatabaseConnectionError)
+}
+
 pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
     store: &T,
 ) -> errors::CustomResult<
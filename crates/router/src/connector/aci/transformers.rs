@@ -867,6 +867,8 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AciRefundResponse>>
                 refund_status: enums::RefundStatus::from(AciRefundStatus::from_str(
                     &item.response.result.code,
                 )?),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -168,6 +168,7 @@ pub struct AdyenPaymentRequest<'a> {
     shopper_name: Option<ShopperName>,
     #[serde(rename = "shopperIP")]
     shopper_ip: Option<Secret<String, pii::IpAddress>>,
+    device_fingerprint: Option<Secret<String>>,
     shopper_locale: Option<String>,
     shopper_email: Option<Email>,
     shopper_statement: Option<String>,
@@ -2607,6 +2608,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         })
     }
@@ -2669,6 +2674,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         })
     }
@@ -2722,6 +2731,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         };
         Ok(request)
@@ -2776,6 +2789,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         };
         Ok(request)
@@ -2826,6 +2843,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         };
         Ok(request)
@@ -2876,6 +2897,10 @@ impl<'a>
             social_security_number: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         };
         Ok(request)
@@ -2936,6 +2961,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         })
     }
@@ -3031,6 +3060,10 @@ impl<'a>
             channel,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         })
     }
@@ -3106,6 +3139,10 @@ impl<'a>
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         })
     }
@@ -3164,6 +3201,10 @@ impl<'a>
             social_security_number: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            device_fingerprint: item
+                .router_data
+                .request
+                .get_device_fingerprint_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
         })
     }
@@ -4033,6 +4074,8 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
                 // through refund notification webhook
                 // For more info: https://docs.adyen.com/online-payments/refund
                 refund_status: storage_enums::RefundStatus::Pending,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
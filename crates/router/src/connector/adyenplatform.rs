@@ -33,6 +33,10 @@ impl ConnectorCommon for Adyenplatform {
         "adyenplatform"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn get_auth_header(
         &self,
         auth_type: &types::ConnectorAuthType,
@@ -690,6 +690,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status,
+                refund_arn: item.response.acquirer_reference_number,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -708,6 +710,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status,
+                refund_arn: item.response.acquirer_reference_number,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
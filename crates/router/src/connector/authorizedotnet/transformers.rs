@@ -1318,6 +1318,8 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AuthorizedotnetRefundRespons
                 None => Ok(types::RefundsResponseData {
                     connector_refund_id: transaction_response.transaction_id.clone(),
                     refund_status,
+                    refund_arn: None,
+                    estimated_arrival_at: None,
                 }),
             },
             ..item.data
@@ -1477,6 +1479,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, AuthorizedotnetRSyncRe
                     response: Ok(types::RefundsResponseData {
                         connector_refund_id: transaction.transaction_id,
                         refund_status,
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     }),
                     ..item.data
                 })
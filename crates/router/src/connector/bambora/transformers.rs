@@ -753,6 +753,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -775,6 +777,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
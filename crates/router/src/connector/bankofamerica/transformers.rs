@@ -2764,6 +2764,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, BankOfAmericaRefundR
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
 
@@ -2860,6 +2862,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, BankOfAmericaRsyncResp
                     Ok(types::RefundsResponseData {
                         connector_refund_id: item.response.id,
                         refund_status,
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     })
                 }
             }
@@ -2870,6 +2874,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, BankOfAmericaRsyncResp
                     Ok(response) => response.refund_status,
                     Err(_) => common_enums::RefundStatus::Pending,
                 },
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
         };
 
@@ -363,6 +363,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.state),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -380,6 +382,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.state),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -237,6 +237,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -254,6 +256,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
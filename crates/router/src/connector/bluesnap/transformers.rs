@@ -926,6 +926,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, BluesnapPaymentsRespon
                 refund_status: enums::RefundStatus::from(
                     item.response.processing_info.processing_status,
                 ),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -943,6 +945,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refund_transaction_id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.refund_status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -108,6 +108,10 @@ impl ConnectorCommon for Boku {
         "boku"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "text/xml;charset=utf-8"
     }
@@ -383,6 +383,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.charge_id,
                 refund_status: get_refund_status(item.response.refund_status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -444,6 +446,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, BokuRsyncResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refunds.refund.refund_id,
                 refund_status: get_refund_status(item.response.refunds.refund.refund_status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -699,6 +699,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, BraintreeRefundRespo
                     Ok(types::RefundsResponseData {
                         connector_refund_id: refund_data.id.clone(),
                         refund_status: enums::RefundStatus::from(refund_data.status),
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     })
                 }
             },
@@ -792,6 +794,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, BraintreeRSyncResponse
                 let response = Ok(types::RefundsResponseData {
                     connector_refund_id: connector_refund_id.to_string(),
                     refund_status: enums::RefundStatus::from(edge_data.node.status.clone()),
+                    refund_arn: None,
+                    estimated_arrival_at: None,
                 });
                 Ok(Self {
                     response,
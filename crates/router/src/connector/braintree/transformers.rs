@@ -463,6 +463,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -480,6 +482,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
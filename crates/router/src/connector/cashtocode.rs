@@ -111,6 +111,10 @@ impl ConnectorCommon for Cashtocode {
         "cashtocode"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Base
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
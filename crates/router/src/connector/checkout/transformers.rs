@@ -973,6 +973,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, CheckoutRefundRespon
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.response.action_id.clone(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -991,6 +993,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CheckoutRefundResponse
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.response.action_id.clone(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1110,6 +1114,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, &ActionResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.action_id.clone(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1128,6 +1134,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, &ActionResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.action_id.clone(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
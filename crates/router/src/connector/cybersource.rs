@@ -261,6 +261,10 @@ impl ConnectorValidation for Cybersource {
         ]);
         connector_utils::is_mandate_supported(pm_data, pm_type, mandate_supported_pmd, self.id())
     }
+
+    fn validate_partial_authorization_reversal(&self) -> CustomResult<(), errors::ConnectorError> {
+        Ok(())
+    }
 }
 
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Cybersource
@@ -1322,7 +1326,9 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
                     field_name: "Currency",
                 })?,
             req.request
-                .amount
+                .minor_amount_to_reverse
+                .map(|amount_to_reverse| amount_to_reverse.get_amount_as_i64())
+                .or(req.request.amount)
                 .ok_or(errors::ConnectorError::MissingRequiredField {
                     field_name: "Amount",
                 })?,
@@ -3003,6 +3003,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, CybersourceRefundRes
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
 
@@ -3067,6 +3069,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CybersourceRsyncRespon
                     Ok(types::RefundsResponseData {
                         connector_refund_id: item.response.id,
                         refund_status,
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     })
                 }
             }
@@ -3077,6 +3081,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CybersourceRsyncRespon
                     Ok(response) => response.refund_status,
                     Err(_) => common_enums::RefundStatus::Pending,
                 },
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
         };
 
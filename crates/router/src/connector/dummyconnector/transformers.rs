@@ -65,6 +65,8 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: Option<enums::CaptureMethod>,
+    webhook_url: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -175,6 +177,8 @@ impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
             payment_method_data: payment_method_data?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method,
+            webhook_url: item.request.webhook_url.clone(),
         })
     }
 }
@@ -204,6 +208,8 @@ pub enum DummyConnectorPaymentStatus {
     Failed,
     #[default]
     Processing,
+    Authorized,
+    Cancelled,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
@@ -212,6 +218,8 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
+            DummyConnectorPaymentStatus::Cancelled => Self::Voided,
         }
     }
 }
@@ -280,11 +288,26 @@ impl DummyConnectorNextAction {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
+    pub webhook_url: Option<String>,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
@@ -292,6 +315,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
             amount: item.request.refund_amount,
+            webhook_url: item.request.webhook_url.clone(),
         })
     }
 }
@@ -340,6 +364,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -357,6 +383,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -374,3 +402,38 @@ pub struct ErrorData {
     pub message: String,
     pub reason: Option<String>,
 }
+
+// WEBHOOKS :
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentAuthorized,
+    PaymentCancelled,
+    RefundSucceeded,
+    RefundFailed,
+}
+
+impl From<&DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: &DummyConnectorWebhookEventType) -> Self {
+        match event_type {
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEventType::PaymentAuthorized => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            DummyConnectorWebhookEventType::PaymentCancelled => Self::PaymentIntentCancelled,
+            DummyConnectorWebhookEventType::RefundSucceeded => Self::RefundSuccess,
+            DummyConnectorWebhookEventType::RefundFailed => Self::RefundFailure,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub payment_id: String,
+    pub refund_id: Option<String>,
+    pub created: String,
+}
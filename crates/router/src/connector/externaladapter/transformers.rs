@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+
+use common_utils::{pii, types::MinorUnit};
+use error_stack::ResultExt;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self as connector_utils, RouterData},
+    core::errors,
+    services,
+    types::{self, api, domain, storage::enums},
+};
+
+/// Version of the adapter protocol the requests are made with, sent in the
+/// `X-Adapter-Protocol-Version` header so that the adapters can reject the versions they do not
+/// support
+pub const ADAPTER_PROTOCOL_VERSION: &str = "1";
+
+pub mod adapter_routes {
+    pub const AUTHORIZE: &str = "v1/payments/authorize";
+    pub const SYNC: &str = "v1/payments/sync";
+    pub const CAPTURE: &str = "v1/payments/capture";
+    pub const VOID: &str = "v1/payments/void";
+    pub const REFUND: &str = "v1/refunds/execute";
+    pub const REFUND_SYNC: &str = "v1/refunds/sync";
+}
+
+pub struct ExternaladapterAuthType {
+    pub(super) api_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for ExternaladapterAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// The metadata of the merchant connector account the adapter is registered with
+#[derive(Debug, Deserialize)]
+pub struct ExternaladapterConnectorMetadataObject {
+    /// The base URL of the adapter, the configured base URL of the connector being used if not
+    /// set
+    pub base_url: Option<Url>,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for ExternaladapterConnectorMetadataObject {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        match meta_data {
+            Some(_) => connector_utils::to_connector_meta_from_secret::<Self>(meta_data.clone())
+                .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                    config: "metadata",
+                }),
+            None => Ok(Self { base_url: None }),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdapterFlow {
+    Authorize,
+    Sync,
+    Capture,
+    Void,
+    Refund,
+    RefundSync,
+}
+
+/// The envelope of the requests sent to the adapter. The identifiers of the payment are those of
+/// the router data of the flow, and the request holds the data specific to the flow.
+#[derive(Debug, Serialize)]
+pub struct ExternaladapterRequest<T> {
+    pub flow: AdapterFlow,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub request: T,
+}
+
+impl<T> ExternaladapterRequest<T> {
+    fn new<F, Req, Resp>(
+        flow: AdapterFlow,
+        router_data: &types::RouterData<F, Req, Resp>,
+        request: T,
+    ) -> Self {
+        Self {
+            flow,
+            merchant_id: router_data.merchant_id.clone(),
+            payment_id: router_data.payment_id.clone(),
+            attempt_id: router_data.attempt_id.clone(),
+            request,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AdapterPaymentMethodData {
+    Card {
+        card_number: cards::CardNumber,
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+        card_cvc: Secret<String>,
+        card_holder_name: Option<Secret<String>>,
+        card_network: Option<enums::CardNetwork>,
+    },
+}
+
+/// Amounts are in the minor unit of the currency
+#[derive(Debug, Serialize)]
+pub struct AdapterAuthorizeRequest {
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub capture_method: Option<enums::CaptureMethod>,
+    pub payment_method: enums::PaymentMethod,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    pub payment_method_data: AdapterPaymentMethodData,
+    pub email: Option<pii::Email>,
+    pub return_url: Option<String>,
+    pub webhook_url: Option<String>,
+    pub statement_descriptor: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData>
+    for ExternaladapterRequest<AdapterAuthorizeRequest>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let payment_method_data = match &item.request.payment_method_data {
+            domain::PaymentMethodData::Card(card) => Ok(AdapterPaymentMethodData::Card {
+                card_number: card.card_number.clone(),
+                card_exp_month: card.card_exp_month.clone(),
+                card_exp_year: card.card_exp_year.clone(),
+                card_cvc: card.card_cvc.clone(),
+                card_holder_name: item.get_optional_billing_full_name(),
+                card_network: card.card_network.clone(),
+            }),
+            domain::PaymentMethodData::CardRedirect(_)
+            | domain::PaymentMethodData::Wallet(_)
+            | domain::PaymentMethodData::PayLater(_)
+            | domain::PaymentMethodData::BankRedirect(_)
+            | domain::PaymentMethodData::BankDebit(_)
+            | domain::PaymentMethodData::BankTransfer(_)
+            | domain::PaymentMethodData::Crypto(_)
+            | domain::PaymentMethodData::MandatePayment
+            | domain::PaymentMethodData::Reward
+            | domain::PaymentMethodData::RealTimePayment(_)
+            | domain::PaymentMethodData::Upi(_)
+            | domain::PaymentMethodData::Voucher(_)
+            | domain::PaymentMethodData::GiftCard(_)
+            | domain::PaymentMethodData::CardToken(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    connector_utils::get_unimplemented_payment_method_error_message(
+                        "Externaladapter",
+                    ),
+                ))
+            }
+        }?;
+
+        Ok(Self::new(
+            AdapterFlow::Authorize,
+            item,
+            AdapterAuthorizeRequest {
+                amount: item.request.minor_amount,
+                currency: item.request.currency,
+                capture_method: item.request.capture_method,
+                payment_method: item.payment_method,
+                payment_method_type: item.request.payment_method_type,
+                payment_method_data,
+                email: item.request.email.clone(),
+                return_url: item.request.router_return_url.clone(),
+                webhook_url: item.request.webhook_url.clone(),
+                statement_descriptor: item.request.statement_descriptor.clone(),
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdapterSyncRequest {
+    pub connector_transaction_id: Option<String>,
+    pub currency: enums::Currency,
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for ExternaladapterRequest<AdapterSyncRequest> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            AdapterFlow::Sync,
+            item,
+            AdapterSyncRequest {
+                connector_transaction_id: item
+                    .request
+                    .connector_transaction_id
+                    .get_connector_transaction_id()
+                    .ok(),
+                currency: item.request.currency,
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdapterCaptureRequest {
+    pub connector_transaction_id: String,
+    pub amount_to_capture: MinorUnit,
+    pub currency: enums::Currency,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for ExternaladapterRequest<AdapterCaptureRequest> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            AdapterFlow::Capture,
+            item,
+            AdapterCaptureRequest {
+                connector_transaction_id: item.request.connector_transaction_id.clone(),
+                amount_to_capture: item.request.minor_amount_to_capture,
+                currency: item.request.currency,
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdapterVoidRequest {
+    pub connector_transaction_id: String,
+    pub cancellation_reason: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for ExternaladapterRequest<AdapterVoidRequest> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            AdapterFlow::Void,
+            item,
+            AdapterVoidRequest {
+                connector_transaction_id: item.request.connector_transaction_id.clone(),
+                cancellation_reason: item.request.cancellation_reason.clone(),
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdapterRefundRequest {
+    pub refund_id: String,
+    pub connector_transaction_id: String,
+    pub connector_refund_id: Option<String>,
+    pub refund_amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub reason: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for ExternaladapterRequest<AdapterRefundRequest> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let flow = if item.request.connector_refund_id.is_some() {
+            AdapterFlow::RefundSync
+        } else {
+            AdapterFlow::Refund
+        };
+        Ok(Self::new(
+            flow,
+            item,
+            AdapterRefundRequest {
+                refund_id: item.request.refund_id.clone(),
+                connector_transaction_id: item.request.connector_transaction_id.clone(),
+                connector_refund_id: item.request.connector_refund_id.clone(),
+                refund_amount: item.request.minor_refund_amount,
+                currency: item.request.currency,
+                reason: item.request.reason.clone(),
+            },
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdapterPaymentStatus {
+    Succeeded,
+    Authorized,
+    Pending,
+    RequiresCustomerAction,
+    Failed,
+    Voided,
+}
+
+impl From<AdapterPaymentStatus> for enums::AttemptStatus {
+    fn from(item: AdapterPaymentStatus) -> Self {
+        match item {
+            AdapterPaymentStatus::Succeeded => Self::Charged,
+            AdapterPaymentStatus::Authorized => Self::Authorized,
+            AdapterPaymentStatus::Pending => Self::Pending,
+            AdapterPaymentStatus::RequiresCustomerAction => Self::AuthenticationPending,
+            AdapterPaymentStatus::Failed => Self::Failure,
+            AdapterPaymentStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdapterRedirection {
+    pub url: Url,
+    pub method: services::Method,
+    #[serde(default)]
+    pub form_fields: HashMap<String, String>,
+}
+
+/// The response of the adapter to the payment flows. The error is only set on failed payments,
+/// and the redirection only on payments requiring a customer action.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExternaladapterPaymentsResponse {
+    pub status: AdapterPaymentStatus,
+    pub connector_transaction_id: Option<String>,
+    pub connector_response_reference_id: Option<String>,
+    pub redirection: Option<AdapterRedirection>,
+    pub error: Option<ExternaladapterErrorResponse>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            ExternaladapterPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            ExternaladapterPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status);
+        let response = match item.response.error {
+            Some(error) if status == enums::AttemptStatus::Failure => Err(types::ErrorResponse {
+                status_code: item.http_code,
+                code: error.code,
+                message: error.message,
+                reason: error.reason,
+                attempt_status: Some(status),
+                connector_transaction_id: item.response.connector_transaction_id,
+            }),
+            _ => Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: item
+                    .response
+                    .connector_transaction_id
+                    .map(types::ResponseId::ConnectorTransactionId)
+                    .unwrap_or(types::ResponseId::NoResponseId),
+                redirection_data: item.response.redirection.map(|redirection| {
+                    services::RedirectForm::Form {
+                        endpoint: redirection.url.to_string(),
+                        method: redirection.method,
+                        form_fields: redirection.form_fields,
+                    }
+                }),
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: item.response.connector_response_reference_id,
+                incremental_authorization_allowed: None,
+                charge_id: None,
+            }),
+        };
+
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdapterRefundStatus {
+    Succeeded,
+    Pending,
+    Failed,
+}
+
+impl From<AdapterRefundStatus> for enums::RefundStatus {
+    fn from(item: AdapterRefundStatus) -> Self {
+        match item {
+            AdapterRefundStatus::Succeeded => Self::Success,
+            AdapterRefundStatus::Pending => Self::Pending,
+            AdapterRefundStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExternaladapterRefundResponse {
+    pub status: AdapterRefundStatus,
+    pub connector_refund_id: String,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, ExternaladapterRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, ExternaladapterRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.connector_refund_id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExternaladapterErrorResponse {
+    pub code: String,
+    pub message: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdapterWebhookEvent {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+    PaymentProcessing,
+    PaymentCancelled,
+    RefundSucceeded,
+    RefundFailed,
+}
+
+impl From<&AdapterWebhookEvent> for api::IncomingWebhookEvent {
+    fn from(item: &AdapterWebhookEvent) -> Self {
+        match item {
+            AdapterWebhookEvent::PaymentSucceeded => Self::PaymentIntentSuccess,
+            AdapterWebhookEvent::PaymentAuthorized => Self::PaymentIntentAuthorizationSuccess,
+            AdapterWebhookEvent::PaymentFailed => Self::PaymentIntentFailure,
+            AdapterWebhookEvent::PaymentProcessing => Self::PaymentIntentProcessing,
+            AdapterWebhookEvent::PaymentCancelled => Self::PaymentIntentCancelled,
+            AdapterWebhookEvent::RefundSucceeded => Self::RefundSuccess,
+            AdapterWebhookEvent::RefundFailed => Self::RefundFailure,
+        }
+    }
+}
+
+/// The notifications forwarded by the adapter, signed with the webhook secret of the merchant
+/// connector account in the `X-Adapter-Signature` header
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExternaladapterWebhookBody {
+    pub event: AdapterWebhookEvent,
+    pub attempt_id: String,
+    pub refund_id: Option<String>,
+    pub status: Option<AdapterPaymentStatus>,
+    pub connector_transaction_id: Option<String>,
+}
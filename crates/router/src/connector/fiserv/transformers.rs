@@ -606,6 +606,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
                 refund_status: enums::RefundStatus::from(
                     item.response.gateway_response.transaction_state,
                 ),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -634,6 +636,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, FiservSyncResponse>>
                 refund_status: enums::RefundStatus::from(
                     gateway_resp.gateway_response.transaction_state.clone(),
                 ),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -368,6 +368,8 @@ impl<T> TryFrom<types::RefundsResponseRouterData<T, GlobepayRefundResponse>>
                 response: Ok(types::RefundsResponseData {
                     connector_refund_id: globepay_refund_id,
                     refund_status: enums::RefundStatus::from(globepay_refund_status),
+                    refund_arn: None,
+                    estimated_arrival_at: None,
                 }),
                 ..item.data
             })
@@ -771,6 +771,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::Pending,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -531,6 +531,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.iata_refund_id.to_string(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
 
@@ -569,6 +571,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.iata_refund_id.to_string(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
         Ok(Self {
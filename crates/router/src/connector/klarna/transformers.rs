@@ -528,6 +528,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, KlarnaRefundResponse
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refund_id,
                 refund_status: status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -557,6 +559,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, KlarnaRefundSyncRespon
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refund_id,
                 refund_status: status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -625,6 +625,8 @@ impl<T> TryFrom<types::RefundsResponseRouterData<T, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1098,6 +1098,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, MultisafepayRefundRe
                     response: Ok(types::RefundsResponseData {
                         connector_refund_id: refund_data.data.refund_id.to_string(),
                         refund_status: enums::RefundStatus::from(refund_status),
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     }),
                     ..item.data
                 })
@@ -1139,6 +1141,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, MultisafepayRefundResp
                     response: Ok(types::RefundsResponseData {
                         connector_refund_id: refund_data.data.refund_id.to_string(),
                         refund_status: enums::RefundStatus::from(refund_status),
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     }),
                     ..item.data
                 })
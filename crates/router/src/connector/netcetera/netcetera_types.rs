@@ -253,6 +253,23 @@ pub enum ThreeDSRequestorChallengeIndicator {
     ChallengeRequestedWhitelistPrompt,
 }
 
+impl From<common_enums::ThreeDsChallengePreference> for ThreeDSRequestorChallengeIndicator {
+    fn from(value: common_enums::ThreeDsChallengePreference) -> Self {
+        match value {
+            common_enums::ThreeDsChallengePreference::NoPreference => Self::NoPreference,
+            common_enums::ThreeDsChallengePreference::NoChallengeRequested => {
+                Self::NoChallengeRequested
+            }
+            common_enums::ThreeDsChallengePreference::ChallengeRequested => {
+                Self::ChallengeRequested3DSRequestorPreference
+            }
+            common_enums::ThreeDsChallengePreference::ChallengeMandated => {
+                Self::ChallengeRequestedMandate
+            }
+        }
+    }
+}
+
 /// This field contains information about how the 3DS Requestor authenticated the cardholder as part of a previous 3DS transaction.
 /// Format of this field was changed with EMV 3DS 2.3.1 version:
 /// In versions prior to 2.3.1, this field is a single object.
@@ -456,11 +456,21 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
         let now = common_utils::date_time::now();
         let request = item.router_data.request.clone();
         let pre_authn_data = request.pre_authentication_data.clone();
+        let challenge_indicator =
+            netcetera_types::ThreeDSRequestorChallengeIndicator::from(request.challenge_preference);
+        // The challenge indicator is a list starting from EMV 3DS 2.3.1
+        let three_ds_requestor_challenge_ind = if pre_authn_data.message_version
+            >= common_utils::types::SemanticVersion::new(2, 3, 1)
+        {
+            netcetera_types::SingleOrListElement::new_list(vec![challenge_indicator])
+        } else {
+            netcetera_types::SingleOrListElement::new_single(challenge_indicator)
+        };
         let three_ds_requestor = netcetera_types::ThreeDSRequestor {
             three_ds_requestor_authentication_ind:
                 netcetera_types::ThreeDSRequestorAuthenticationIndicator::Payment,
             three_ds_requestor_authentication_info: None,
-            three_ds_requestor_challenge_ind: None,
+            three_ds_requestor_challenge_ind: Some(three_ds_requestor_challenge_ind),
             three_ds_requestor_prior_authentication_info: None,
             three_ds_requestor_dec_req_ind: None,
             three_ds_requestor_dec_max_time: None,
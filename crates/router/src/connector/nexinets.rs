@@ -78,6 +78,10 @@ impl ConnectorCommon for Nexinets {
         "nexinets"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
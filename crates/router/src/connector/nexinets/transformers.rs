@@ -531,6 +531,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, NexinetsRefundRespon
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.transaction_id,
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -548,6 +550,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, NexinetsRefundResponse
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.transaction_id,
                 refund_status: enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
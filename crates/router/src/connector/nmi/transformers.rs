@@ -1085,6 +1085,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, StandardResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.orderid,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1103,6 +1105,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Capture, StandardResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.transactionid,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1147,6 +1151,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, NmiRefundSyncResponse>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.transaction.order_id,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -824,6 +824,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.result.transaction.id,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
         Ok(Self {
@@ -892,6 +894,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundSyncResponse>>
             Ok(types::RefundsResponseData {
                 connector_refund_id: noon_transaction.id.to_owned(),
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
         Ok(Self {
@@ -56,6 +56,10 @@ impl ConnectorCommon for Nuvei {
         "nuvei"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Base
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
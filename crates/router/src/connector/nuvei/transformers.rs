@@ -1767,6 +1767,8 @@ fn get_refund_response(
             _ => Ok(types::RefundsResponseData {
                 connector_refund_id: txn_id,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
         },
     }
@@ -76,6 +76,10 @@ impl ConnectorCommon for Opayo {
         "opayo"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
@@ -542,6 +542,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.transaction_id,
                 refund_status: enums::RefundStatus::from(item.response.transaction_status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1014,6 +1014,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, PaymeRefundResponse>
                     .payme_transaction_id
                     .ok_or(errors::ConnectorError::MissingConnectorRefundID)?,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
         Ok(Self {
@@ -1151,6 +1153,8 @@ impl<F, T>
             Ok(types::RefundsResponseData {
                 refund_status,
                 connector_refund_id: pay_sale_response.payme_transaction_id.clone(),
+                refund_arn: None,
+                estimated_arrival_at: None,
             })
         };
         Ok(Self {
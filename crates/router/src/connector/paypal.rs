@@ -1541,6 +1541,11 @@ impl api::IncomingWebhook for Paypal {
                     ),
                 ))
             }
+            paypal::PaypalResource::PaypalVaultWebhooks(resource) => {
+                Ok(api_models::webhooks::ObjectReferenceId::MandateId(
+                    api_models::webhooks::MandateIdType::ConnectorMandateId(resource.id),
+                ))
+            }
         }
     }
 
@@ -1572,6 +1577,7 @@ impl api::IncomingWebhook for Paypal {
             | PaypalWebhookEventType::CheckoutOrderApproved
             | PaypalWebhookEventType::CheckoutOrderCompleted
             | PaypalWebhookEventType::CheckoutOrderProcessed
+            | PaypalWebhookEventType::VaultPaymentTokenDeleted
             | PaypalWebhookEventType::Unknown => None,
         };
 
@@ -1600,7 +1606,8 @@ impl api::IncomingWebhook for Paypal {
             paypal::PaypalResource::PaypalRefundWebhooks(resource) => Box::new(
                 paypal::RefundSyncResponse::try_from((*resource, details.event_type))?,
             ),
-            paypal::PaypalResource::PaypalDisputeWebhooks(_) => Box::new(details),
+            paypal::PaypalResource::PaypalDisputeWebhooks(_)
+            | paypal::PaypalResource::PaypalVaultWebhooks(_) => Box::new(details),
         })
     }
 
@@ -242,6 +242,44 @@ pub enum ShippingPreference {
 #[derive(Debug, Serialize)]
 pub struct PaypalRedirectionRequest {
     experience_context: ContextStruct,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<PaypalVaultAttributes>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalVaultAttributes {
+    vault: PaypalVaultInstruction,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalVaultInstruction {
+    store_in_vault: PaypalStoreInVault,
+    usage_type: PaypalVaultUsageType,
+    customer_type: PaypalVaultCustomerType,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalStoreInVault {
+    OnSuccess,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalVaultUsageType {
+    Merchant,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalVaultCustomerType {
+    Consumer,
+}
+
+// Billing agreement created for the payer during a customer initiated mandate payment
+#[derive(Debug, Serialize)]
+pub struct PaypalVaultedRequest {
+    vault_id: Secret<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -249,6 +287,8 @@ pub struct PaypalRedirectionRequest {
 pub enum PaymentSourceItem {
     Card(CardRequest),
     Paypal(PaypalRedirectionRequest),
+    #[serde(rename = "paypal")]
+    PaypalVault(PaypalVaultedRequest),
     IDeal(RedirectRequest),
     Eps(RedirectRequest),
     Giropay(RedirectRequest),
@@ -372,6 +412,20 @@ fn get_payment_source(
     }
 }
 
+fn get_vault_attributes(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Option<PaypalVaultAttributes> {
+    item.request
+        .is_customer_initiated_mandate_payment()
+        .then_some(PaypalVaultAttributes {
+            vault: PaypalVaultInstruction {
+                store_in_vault: PaypalStoreInVault::OnSuccess,
+                usage_type: PaypalVaultUsageType::Merchant,
+                customer_type: PaypalVaultCustomerType::Consumer,
+            },
+        })
+}
+
 fn get_payee(auth_type: &PaypalAuthType) -> Option<Payee> {
     auth_type
         .get_credentials()
@@ -465,6 +519,7 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                                 },
                                 user_action: Some(UserAction::PayNow),
                             },
+                            attributes: get_vault_attributes(item.router_data),
                         }));
 
                     Ok(Self {
@@ -482,6 +537,7 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                                 shipping_preference: ShippingPreference::GetFromFile,
                                 user_action: Some(UserAction::PayNow),
                             },
+                            attributes: get_vault_attributes(item.router_data),
                         }));
 
                     Ok(Self {
@@ -552,10 +608,15 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                 Self::try_from(giftcard_data.as_ref())
             }
             domain::PaymentMethodData::MandatePayment => {
-                Err(errors::ConnectorError::NotImplemented(
-                    utils::get_unimplemented_payment_method_error_message("Paypal"),
-                )
-                .into())
+                let payment_source = Some(PaymentSourceItem::PaypalVault(PaypalVaultedRequest {
+                    vault_id: Secret::new(item.router_data.request.get_connector_mandate_id()?),
+                }));
+
+                Ok(Self {
+                    intent,
+                    purchase_units,
+                    payment_source,
+                })
             }
             domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::RealTimePayment(_)
@@ -978,6 +1039,52 @@ pub struct PaypalOrdersResponse {
     intent: PaypalPaymentIntent,
     status: PaypalOrderStatus,
     purchase_units: Vec<PurchaseUnitItem>,
+    payment_source: Option<PaypalPaymentSourceResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalPaymentSourceResponse {
+    paypal: Option<PaypalWalletResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalWalletResponse {
+    attributes: Option<PaypalWalletAttributesResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalWalletAttributesResponse {
+    vault: Option<PaypalVaultResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalVaultResponse {
+    id: Option<String>,
+    status: Option<PaypalVaultStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalVaultStatus {
+    Vaulted,
+    Created,
+    Approved,
+}
+
+impl PaypalPaymentSourceResponse {
+    // The vault id is only returned once the payer has approved the billing agreement
+    fn get_mandate_reference(&self) -> Option<types::MandateReference> {
+        self.paypal
+            .as_ref()
+            .and_then(|paypal| paypal.attributes.as_ref())
+            .and_then(|attributes| attributes.vault.as_ref())
+            .filter(|vault| matches!(vault.status, Some(PaypalVaultStatus::Vaulted) | None))
+            .and_then(|vault| vault.id.clone())
+            .map(|vault_id| types::MandateReference {
+                connector_mandate_id: Some(vault_id),
+                payment_method_id: None,
+            })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1144,12 +1251,17 @@ impl<F, T>
         .ok_or(errors::ConnectorError::ResponseDeserializationFailed)?;
         let status = payment_collection_item.status.clone();
         let status = storage_enums::AttemptStatus::from(status);
+        let mandate_reference = item
+            .response
+            .payment_source
+            .as_ref()
+            .and_then(PaypalPaymentSourceResponse::get_mandate_reference);
         Ok(Self {
             status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: order_id,
                 redirection_data: None,
-                mandate_reference: None,
+                mandate_reference,
                 connector_metadata: Some(connector_meta),
                 network_txn_id: None,
                 connector_response_reference_id: purchase_units
@@ -1951,6 +2063,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status: storage_enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -1974,6 +2088,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundSyncResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status: storage_enums::RefundStatus::from(item.response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -2050,6 +2166,8 @@ pub enum PaypalWebhookEventType {
     CustomerDisputedUpdated,
     #[serde(rename = "RISK.DISPUTE.CREATED")]
     RiskDisputeCreated,
+    #[serde(rename = "VAULT.PAYMENT-TOKEN.DELETED")]
+    VaultPaymentTokenDeleted,
     #[serde(other)]
     Unknown,
 }
@@ -2061,6 +2179,18 @@ pub enum PaypalResource {
     PaypalRedirectsWebhooks(Box<PaypalRedirectsWebhooks>),
     PaypalRefundWebhooks(Box<PaypalRefundWebhooks>),
     PaypalDisputeWebhooks(Box<PaypalDisputeWebhooks>),
+    PaypalVaultWebhooks(Box<PaypalVaultWebhooks>),
+}
+
+#[derive(Deserialize, Debug, Serialize)]
+pub struct PaypalVaultWebhooks {
+    pub id: String,
+    pub customer: PaypalVaultCustomer,
+}
+
+#[derive(Deserialize, Debug, Serialize)]
+pub struct PaypalVaultCustomer {
+    pub id: String,
 }
 
 #[derive(Deserialize, Debug, Serialize)]
@@ -2145,6 +2275,7 @@ pub struct PaypalRedirectsWebhooks {
     pub links: Vec<PaypalLinks>,
     pub id: String,
     pub intent: PaypalPaymentIntent,
+    pub payment_source: Option<PaypalPaymentSourceResponse>,
 }
 
 #[derive(Deserialize, Debug, Serialize)]
@@ -2178,6 +2309,7 @@ impl ForeignFrom<(PaypalWebhookEventType, Option<OutcomeCode>)> for api::Incomin
             PaypalWebhookEventType::PaymentCaptureRefunded => Self::RefundSuccess,
             PaypalWebhookEventType::CustomerDisputeCreated => Self::DisputeOpened,
             PaypalWebhookEventType::RiskDisputeCreated => Self::DisputeAccepted,
+            PaypalWebhookEventType::VaultPaymentTokenDeleted => Self::MandateRevoked,
             PaypalWebhookEventType::CustomerDisputeResolved => {
                 if let Some(outcome_code) = outcome {
                     Self::from(outcome_code)
@@ -2307,6 +2439,7 @@ impl TryFrom<(PaypalRedirectsWebhooks, PaypalWebhookEventType)> for PaypalOrders
             intent: webhook_body.intent,
             status: PaypalOrderStatus::try_from(webhook_event)?,
             purchase_units: webhook_body.purchase_units,
+            payment_source: webhook_body.payment_source,
         })
     }
 }
@@ -2341,6 +2474,7 @@ impl TryFrom<PaypalWebhookEventType> for PaypalPaymentStatus {
             | PaypalWebhookEventType::CustomerDisputeResolved
             | PaypalWebhookEventType::CustomerDisputedUpdated
             | PaypalWebhookEventType::RiskDisputeCreated
+            | PaypalWebhookEventType::VaultPaymentTokenDeleted
             | PaypalWebhookEventType::Unknown => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
             }
@@ -2365,6 +2499,7 @@ impl TryFrom<PaypalWebhookEventType> for RefundStatus {
             | PaypalWebhookEventType::CustomerDisputeResolved
             | PaypalWebhookEventType::CustomerDisputedUpdated
             | PaypalWebhookEventType::RiskDisputeCreated
+            | PaypalWebhookEventType::VaultPaymentTokenDeleted
             | PaypalWebhookEventType::Unknown => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
             }
@@ -2389,6 +2524,7 @@ impl TryFrom<PaypalWebhookEventType> for PaypalOrderStatus {
             | PaypalWebhookEventType::CustomerDisputeResolved
             | PaypalWebhookEventType::CustomerDisputedUpdated
             | PaypalWebhookEventType::RiskDisputeCreated
+            | PaypalWebhookEventType::VaultPaymentTokenDeleted
             | PaypalWebhookEventType::Unknown => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
             }
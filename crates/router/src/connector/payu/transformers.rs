@@ -581,6 +581,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refund.refund_id,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -606,6 +608,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundSyncResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: refund.refund_id.clone(),
                 refund_status: enums::RefundStatus::from(refund.status.clone()),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -391,6 +391,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, PlacetopayRefundResp
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.internal_reference.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -431,6 +433,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, PlacetopayRefundRespon
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.internal_reference.to_string(),
                 refund_status: enums::RefundStatus::from(item.response.status.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -423,6 +423,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, PowertranzBaseRespon
                     true => RefundStatus::Success,
                     false => RefundStatus::Failure,
                 },
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             Err,
         );
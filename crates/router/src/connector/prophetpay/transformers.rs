@@ -618,6 +618,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, ProphetpayRefundResp
                         },
                     )?,
                     refund_status: enums::RefundStatus::Success,
+                    refund_arn: None,
+                    estimated_arrival_at: None,
                 }),
                 ..item.data
             })
@@ -658,6 +660,8 @@ impl<T> TryFrom<types::RefundsResponseRouterData<T, ProphetpayRefundSyncResponse
                     // no refund id is generated, rather transaction id is used for referring to status in refund also
                     connector_refund_id: item.data.request.connector_transaction_id.clone(),
                     refund_status: enums::RefundStatus::Success,
+                    refund_arn: None,
+                    estimated_arrival_at: None,
                 }),
                 ..item.data
             })
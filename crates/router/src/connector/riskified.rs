@@ -110,6 +110,10 @@ impl ConnectorCommon for Riskified {
         "riskified"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
@@ -157,7 +157,14 @@ impl TryFrom<&frm_types::FrmCheckoutRouterData> for RiskifiedPaymentsCheckoutReq
                 updated_at: common_utils::date_time::now(),
                 gateway: payment_data.request.gateway.clone(),
                 total_price: payment_data.request.amount,
-                cart_token: payment_data.attempt_id.clone(),
+                // The session id of the device data collection is used as the cart token, so that
+                // the order is linked with the session tracked by the beacon
+                cart_token: payment_data
+                    .request
+                    .browser_info
+                    .as_ref()
+                    .and_then(|browser_info| browser_info.device_fingerprint.clone())
+                    .unwrap_or_else(|| payment_data.attempt_id.clone()),
                 line_items: payment_data
                     .request
                     .get_order_details()?
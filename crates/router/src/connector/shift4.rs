@@ -59,6 +59,10 @@ impl ConnectorCommon for Shift4 {
         "shift4"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
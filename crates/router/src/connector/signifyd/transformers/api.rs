@@ -371,6 +371,14 @@ pub struct SignifydPaymentsCheckoutRequest {
     order_id: String,
     purchase: Purchase,
     coverage_requests: Option<CoverageRequests>,
+    device: Option<Device>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    client_ip_address: Option<std::net::IpAddr>,
+    session_id: Option<String>,
 }
 
 impl TryFrom<&frm_types::FrmCheckoutRouterData> for SignifydPaymentsCheckoutRequest {
@@ -445,11 +453,20 @@ impl TryFrom<&frm_types::FrmCheckoutRouterData> for SignifydPaymentsCheckoutRequ
                 .phone
                 .and_then(|phone_data| phone_data.number),
         };
+        let device = item
+            .request
+            .browser_info
+            .as_ref()
+            .map(|browser_info| Device {
+                client_ip_address: browser_info.ip_address,
+                session_id: browser_info.device_fingerprint.clone(),
+            });
         Ok(Self {
             checkout_id: item.payment_id.clone(),
             order_id: item.attempt_id.clone(),
             purchase,
             coverage_requests: metadata.coverage_request,
+            device,
         })
     }
 }
@@ -455,6 +455,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refund.id,
                 refund_status: enums::RefundStatus::from(item.response.refund.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -472,6 +474,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.refund.id,
                 refund_status: enums::RefundStatus::from(item.response.refund.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -76,6 +76,10 @@ impl ConnectorCommon for Stripe {
         "stripe"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/x-www-form-urlencoded"
     }
@@ -2967,6 +2967,18 @@ pub struct RefundResponse {
     pub payment_intent: String,
     pub status: RefundStatus,
     pub failure_reason: Option<String>,
+    pub destination_details: Option<StripeRefundDestinationDetails>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct StripeRefundDestinationDetails {
+    pub card: Option<StripeRefundCardDestination>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct StripeRefundCardDestination {
+    // The acquirer reference number of the refund
+    pub reference: Option<String>,
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -2994,6 +3006,12 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status,
+                refund_arn: item
+                    .response
+                    .destination_details
+                    .and_then(|destination_details| destination_details.card)
+                    .and_then(|card| card.reference),
+                estimated_arrival_at: None,
             })
         };
 
@@ -3029,6 +3047,12 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status,
+                refund_arn: item
+                    .response
+                    .destination_details
+                    .and_then(|destination_details| destination_details.card)
+                    .and_then(|card| card.reference),
+                estimated_arrival_at: None,
             })
         };
 
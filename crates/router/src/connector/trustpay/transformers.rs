@@ -398,6 +398,7 @@ impl TryFrom<&TrustpayRouterData<&types::PaymentsAuthorizeRouterData>> for Trust
             accept_header: Some(browser_info.accept_header.unwrap_or("*".to_string())),
             user_agent: browser_info.user_agent,
             ip_address: browser_info.ip_address,
+            device_fingerprint: browser_info.device_fingerprint,
         };
         let params = get_mandatory_fields(item.router_data)?;
         let amount = item.amount.to_owned();
@@ -1492,6 +1493,8 @@ fn handle_cards_refund_response(
     let refund_response_data = types::RefundsResponseData {
         connector_refund_id: response.instance_id,
         refund_status,
+        refund_arn: None,
+        estimated_arrival_at: None,
     };
     Ok((error, refund_response_data))
 }
@@ -1529,6 +1532,8 @@ fn handle_webhooks_refund_response(
             .payment_request_id
             .ok_or(errors::ConnectorError::MissingConnectorRefundID)?,
         refund_status,
+        refund_arn: None,
+        estimated_arrival_at: None,
     };
     Ok((error, refund_response_data))
 }
@@ -1554,6 +1559,8 @@ fn handle_bank_redirects_refund_response(
     let refund_response_data = types::RefundsResponseData {
         connector_refund_id: response.payment_request_id.to_string(),
         refund_status,
+        refund_arn: None,
+        estimated_arrival_at: None,
     };
     (error, refund_response_data)
 }
@@ -1590,6 +1597,8 @@ fn handle_bank_redirects_refund_sync_response(
     let refund_response_data = types::RefundsResponseData {
         connector_refund_id: response.payment_information.references.payment_request_id,
         refund_status,
+        refund_arn: None,
+        estimated_arrival_at: None,
     };
     (error, refund_response_data)
 }
@@ -1611,6 +1620,8 @@ fn handle_bank_redirects_refund_sync_error_response(
     let refund_response_data = types::RefundsResponseData {
         connector_refund_id: "".to_string(),
         refund_status: enums::RefundStatus::Failure,
+        refund_arn: None,
+        estimated_arrival_at: None,
     };
     (error, refund_response_data)
 }
@@ -73,6 +73,10 @@ impl ConnectorCommon for Tsys {
         "tsys"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn common_get_content_type(&self) -> &'static str {
         "application/json"
     }
@@ -518,6 +518,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             TsysResponseTypes::SuccessResponse(return_response) => Ok(types::RefundsResponseData {
                 connector_refund_id: return_response.transaction_id,
                 refund_status: enums::RefundStatus::from(return_response.status),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             TsysResponseTypes::ErrorResponse(connector_response) => {
                 Err(get_error_response(connector_response, item.http_code))
@@ -557,6 +559,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, TsysSyncResponse>>
                 Ok(types::RefundsResponseData {
                     connector_refund_id: search_response.transaction_details.transaction_id.clone(),
                     refund_status: enums::RefundStatus::from(search_response.transaction_details),
+                    refund_arn: None,
+                    estimated_arrival_at: None,
                 })
             }
             SearchResponseTypes::ErrorResponse(connector_response) => {
@@ -763,6 +763,7 @@ pub trait PaymentsAuthorizeRequestData {
     fn get_connector_mandate_id(&self) -> Result<String, Error>;
     fn get_complete_authorize_url(&self) -> Result<String, Error>;
     fn get_ip_address_as_optional(&self) -> Option<Secret<String, IpAddress>>;
+    fn get_device_fingerprint_as_optional(&self) -> Option<Secret<String>>;
     fn get_original_amount(&self) -> i64;
     fn get_surcharge_amount(&self) -> Option<i64>;
     fn get_tax_on_surcharge_amount(&self) -> Option<i64>;
@@ -881,6 +882,12 @@ impl PaymentsAuthorizeRequestData for types::PaymentsAuthorizeData {
                 .map(|ip| Secret::new(ip.to_string()))
         })
     }
+    fn get_device_fingerprint_as_optional(&self) -> Option<Secret<String>> {
+        self.browser_info
+            .as_ref()
+            .and_then(|browser_info| browser_info.device_fingerprint.clone())
+            .map(Secret::new)
+    }
     fn get_original_amount(&self) -> i64 {
         self.surcharge_details
             .as_ref()
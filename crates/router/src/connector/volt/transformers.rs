@@ -466,6 +466,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id.to_string(),
                 refund_status: enums::RefundStatus::Pending, //We get Refund Status only by Webhooks
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
@@ -62,6 +62,10 @@ impl ConnectorCommon for Wise {
         "wise"
     }
 
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
     fn get_auth_header(
         &self,
         auth_type: &types::ConnectorAuthType,
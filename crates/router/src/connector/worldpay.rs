@@ -614,6 +614,8 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
                     response: Ok(types::RefundsResponseData {
                         connector_refund_id: ResponseIdStr::try_from(response.links)?.id,
                         refund_status: enums::RefundStatus::Success,
+                        refund_arn: None,
+                        estimated_arrival_at: None,
                     }),
                     ..data.clone()
                 })
@@ -687,6 +689,8 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: data.request.refund_id.clone(),
                 refund_status: enums::RefundStatus::from(response.last_event),
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..data.clone()
         })
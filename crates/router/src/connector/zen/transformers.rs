@@ -1091,6 +1091,8 @@ fn get_zen_refund_response(
     let refund_response_data = types::RefundsResponseData {
         connector_refund_id: response.id,
         refund_status,
+        refund_arn: None,
+        estimated_arrival_at: None,
     };
     Ok((error, refund_response_data))
 }
@@ -1107,6 +1109,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
             response: Ok(types::RefundsResponseData {
                 connector_refund_id: item.response.id,
                 refund_status,
+                refund_arn: None,
+                estimated_arrival_at: None,
             }),
             ..item.data
         })
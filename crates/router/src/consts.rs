@@ -102,6 +102,40 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Max time to wait for the post-authorization verdict of the FRM connector, authorizations are
+/// commonly held by the issuers for 7 days
+pub const MAX_FRM_REVIEW_TIMEOUT: u32 = 7 * 24 * 60 * 60;
+
+/// Min time to wait for the post-authorization verdict of the FRM connector
+pub const MIN_FRM_REVIEW_TIMEOUT: u32 = 60;
+
+/// Max time after the creation of a payment at which its capture can be scheduled, authorizations
+/// are commonly held by the issuers for 7 days
+pub const MAX_CAPTURE_SCHEDULE_DELAY: time::Duration = time::Duration::days(7);
+
+/// Max time for which the exchanges with the connectors can be captured at once
+pub const MAX_CONNECTOR_DEBUG_CAPTURE_WINDOW: time::Duration = time::Duration::hours(72);
+
+/// Time for which the captured exchanges with the connectors are retained
+pub const CONNECTOR_EXCHANGE_RETENTION: time::Duration = time::Duration::days(7);
+
+/// Max difference in seconds between the timestamp of a signed request and the time it is
+/// received, the signatures being remembered for as long to reject the replayed requests
+pub const REQUEST_SIGNATURE_TOLERANCE_IN_SECS: i64 = 300;
+
+/// Prefix of the keys under which the signatures of the requests already received are stored
+pub const REQUEST_SIGNATURE_REDIS_KEY_PREFIX: &str = "REQUEST_SIGNATURE";
+
+/// Key identifier set in the header of the OAuth2 access tokens, telling them apart from the JWTs
+/// issued to the dashboard users
+pub const OAUTH2_ACCESS_TOKEN_KID: &str = "oauth2_access_token";
+
+/// Time in seconds for which the OAuth2 access tokens are valid
+pub const OAUTH2_ACCESS_TOKEN_TTL_IN_SECS: i64 = 60 * 60;
+
+/// Prefix of the keys under which the revoked OAuth2 access tokens are stored until they expire
+pub const OAUTH2_REVOKED_TOKEN_REDIS_KEY_PREFIX: &str = "OAUTH2_REVOKED_TOKEN";
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
@@ -122,3 +156,6 @@ pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
 
 //max_amount allowed is 999999999 in minor units
 pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
+
+/// Prefix of the keys of the configs holding the HTTP client policies of the connectors
+pub const CONNECTOR_HTTP_CLIENT_POLICY_CONFIG_PREFIX: &str = "connector_http_client_policy";
@@ -1,29 +1,61 @@
 pub mod admin;
+pub mod alerts;
 pub mod api_keys;
 pub mod api_locking;
+pub mod api_versioning;
 pub mod apple_pay_certificates_migration;
+pub mod audit_events;
 pub mod authentication;
+pub mod blind_index;
 pub mod blocklist;
+#[cfg(feature = "olap")]
+pub mod bulk_force_sync;
 pub mod cache;
+#[cfg(feature = "olap")]
+pub mod card_export;
 pub mod cards_info;
 pub mod conditional_config;
 pub mod configs;
+pub mod connected_accounts;
+#[cfg(feature = "olap")]
+pub mod connector_capabilities;
+pub mod connector_credentials;
+pub mod connector_exchanges;
+#[cfg(feature = "olap")]
+pub mod connector_migration;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod connector_polling;
+pub mod connector_response_cache;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
 pub mod disputes;
 pub mod errors;
+pub mod feature_flags;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
+pub mod fraud_rules;
+#[cfg(feature = "graphql")]
+pub mod graphql;
 pub mod gsm;
 pub mod health_check;
+pub mod jobs;
+pub mod ledger;
+pub mod load_shedding;
 pub mod locker_migration;
+pub mod logging;
 pub mod mandate;
+pub mod master_key_rotation;
+pub mod merchant_key_rotation;
 pub mod metrics;
+#[cfg(feature = "olap")]
+pub mod orders;
+#[cfg(feature = "olap")]
+pub mod organization;
 pub mod payment_link;
+pub mod payment_method_sessions;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
@@ -31,17 +63,36 @@ pub mod payout_link;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod pm_auth;
+pub mod pm_display_rules;
+pub mod pm_filters;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
+pub mod profile_cloning;
+pub mod rate_limits;
 pub mod refunds;
+#[cfg(feature = "olap")]
+pub mod reports;
+pub mod response_field_access;
 pub mod routing;
+#[cfg(feature = "dummy_connector")]
+pub mod sandbox_traffic;
+#[cfg(feature = "olap")]
+pub mod search;
+#[cfg(feature = "olap")]
+pub mod settlements;
 pub mod surcharge_decision_config;
+pub mod tenants;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
 pub mod utils;
+pub mod vault_tokens;
+pub mod velocity_limits;
 #[cfg(feature = "olap")]
 pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
+pub mod wallet_decryption_keys;
 pub mod webhooks;
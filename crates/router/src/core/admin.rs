@@ -2,12 +2,13 @@ use std::str::FromStr;
 
 use api_models::{
     admin::{self as admin_types},
+    audit_events::{AuditAction, AuditResourceType},
     enums as api_enums, routing as routing_types,
 };
 use common_utils::{
     crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
     date_time,
-    ext_traits::{AsyncExt, ConfigExt, Encode, ValueExt},
+    ext_traits::{AsyncExt, ConfigExt, Encode, StringExt, ValueExt},
     pii,
 };
 use diesel_models::configs;
@@ -21,10 +22,12 @@ use uuid::Uuid;
 use crate::{
     consts,
     core::{
+        audit_events,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payment_methods::card_vault,
+        payments::{helpers, tax_calculation},
         routing::helpers as routing_helpers,
-        utils as core_utils,
+        tenants, utils as core_utils,
     },
     db::StorageInterface,
     routes::{metrics, SessionState},
@@ -54,6 +57,8 @@ pub async fn create_merchant_account(
     state: SessionState,
     req: api::MerchantAccountCreate,
 ) -> RouterResponse<api::MerchantAccountResponse> {
+    tenants::validate_merchant_accounts_quota(&state).await?;
+
     let db = state.store.as_ref();
     let master_key = db.get_master_key();
 
@@ -291,11 +296,22 @@ pub async fn create_merchant_account(
     })
     .ok();
 
-    Ok(service_api::ApplicationResponse::Json(
-        api::MerchantAccountResponse::try_from(merchant_account)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response = api::MerchantAccountResponse::try_from(merchant_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_events::record_audit_event(
+        &state,
+        &response.merchant_id,
+        AuditResourceType::MerchantAccount,
+        &response.merchant_id,
+        AuditAction::Create,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 #[cfg(feature = "olap")]
@@ -457,6 +473,14 @@ pub async fn update_business_profile_cascade(
             use_billing_as_payment_method_billing: None,
             collect_shipping_details_from_wallet_connector: None,
             is_connector_agnostic_mit_enabled: None,
+            frm_decision_matrix: None,
+            frm_post_auth_review: None,
+            connector_debug_capture: None,
+            connector_failover_policy: None,
+            vault_provider: None,
+            tax_provider: None,
+            dispute_auto_response: None,
+            custom_fields_schema: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -491,6 +515,11 @@ pub async fn merchant_account_update(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    let merchant_account_before = db
+        .find_merchant_account_by_merchant_id(&req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
     if &req.merchant_id != merchant_id {
         Err(report!(errors::ValidationError::IncorrectValueProvided {
             field_name: "parent_merchant_id"
@@ -636,11 +665,24 @@ pub async fn merchant_account_update(
 
     // If there are any new business labels generated, create business profile
 
-    Ok(service_api::ApplicationResponse::Json(
-        api::MerchantAccountResponse::try_from(response)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response = api::MerchantAccountResponse::try_from(response)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::MerchantAccount,
+        merchant_id,
+        AuditAction::Update,
+        api::MerchantAccountResponse::try_from(merchant_account_before)
+            .ok()
+            .as_ref(),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn merchant_account_delete(
@@ -649,6 +691,19 @@ pub async fn merchant_account_delete(
 ) -> RouterResponse<api::MerchantAccountDeleteResponse> {
     let mut is_deleted = false;
     let db = state.store.as_ref();
+    let merchant_account_before = match db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+    {
+        Ok(key_store) => db
+            .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+            .await
+            .ok()
+            .and_then(|merchant_account| {
+                api::MerchantAccountResponse::try_from(merchant_account).ok()
+            }),
+        Err(_) => None,
+    };
     let is_merchant_account_deleted = db
         .delete_merchant_account_by_merchant_id(&merchant_id)
         .await
@@ -679,6 +734,17 @@ pub async fn merchant_account_delete(
     }
     .ok();
 
+    audit_events::record_audit_event(
+        &state,
+        &merchant_id,
+        AuditResourceType::MerchantAccount,
+        &merchant_id,
+        AuditAction::Delete,
+        merchant_account_before.as_ref(),
+        None,
+    )
+    .await;
+
     let response = api::MerchantAccountDeleteResponse {
         merchant_id,
         deleted: is_deleted,
@@ -969,6 +1035,9 @@ pub async fn create_payment_connector(
         pm_auth_config: req.pm_auth_config.clone(),
         status: connector_status,
         connector_wallets_details: helpers::get_encrypted_apple_pay_connector_wallets_details(&key_store, &req.metadata).await?,
+        next_connector_account_details: None,
+        previous_connector_account_details: None,
+        credentials_rotation_window_ends_at: None,
     };
 
     let transaction_type = match req.connector_type {
@@ -1041,7 +1110,19 @@ pub async fn create_payment_connector(
         ]),
     );
 
-    let mca_response = mca.try_into()?;
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::MerchantConnectorAccount,
+        &mca_response.merchant_connector_id,
+        AuditAction::Create,
+        None,
+        Some(&mca_response),
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -1278,6 +1359,8 @@ pub async fn update_payment_connector(
         .attach_printable("Missing `profile_id` in merchant connector account")?;
 
     let request_connector_label = req.connector_label;
+    let mca_before: Option<api_models::admin::MerchantConnectorResponse> =
+        mca.clone().try_into().ok();
 
     let updated_mca = db
         .update_merchant_connector_account(mca, payment_connector.into(), &key_store)
@@ -1292,7 +1375,18 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.try_into()?;
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::MerchantConnectorAccount,
+        merchant_connector_id,
+        AuditAction::Update,
+        mca_before.as_ref(),
+        Some(&response),
+    )
+    .await;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -1313,7 +1407,7 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let _mca = db
+    let mca = db
         .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
             &merchant_id,
             &merchant_connector_id,
@@ -1334,6 +1428,18 @@ pub async fn delete_payment_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    let mca_before: Option<api_models::admin::MerchantConnectorResponse> = mca.try_into().ok();
+    audit_events::record_audit_event(
+        &state,
+        &merchant_id,
+        AuditResourceType::MerchantConnectorAccount,
+        &merchant_connector_id,
+        AuditAction::Delete,
+        mca_before.as_ref(),
+        None,
+    )
+    .await;
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -1359,7 +1465,29 @@ pub async fn kv_for_merchant(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let updated_merchant_account = match (enable, merchant_account.storage_scheme) {
+    let updated_merchant_account =
+        switch_merchant_storage_scheme(&state, merchant_account, &key_store, enable).await?;
+    let kv_status = matches!(
+        updated_merchant_account.storage_scheme,
+        MerchantStorageScheme::RedisKv
+    );
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::ToggleKVResponse {
+            merchant_id: updated_merchant_account.merchant_id,
+            kv_enabled: kv_status,
+        },
+    ))
+}
+
+async fn switch_merchant_storage_scheme(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    enable: bool,
+) -> RouterResult<domain::MerchantAccount> {
+    let db = state.store.as_ref();
+    match (enable, merchant_account.storage_scheme) {
         (true, MerchantStorageScheme::RedisKv) | (false, MerchantStorageScheme::PostgresOnly) => {
             Ok(merchant_account)
         }
@@ -1376,7 +1504,7 @@ pub async fn kv_for_merchant(
                 storage::MerchantAccountUpdate::StorageSchemeUpdate {
                     storage_scheme: MerchantStorageScheme::RedisKv,
                 },
-                &key_store,
+                key_store,
             )
             .await
         }
@@ -1386,7 +1514,7 @@ pub async fn kv_for_merchant(
                 storage::MerchantAccountUpdate::StorageSchemeUpdate {
                     storage_scheme: MerchantStorageScheme::PostgresOnly,
                 },
-                &key_store,
+                key_store,
             )
             .await
         }
@@ -1395,18 +1523,7 @@ pub async fn kv_for_merchant(
         error
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed to switch merchant_storage_scheme")
-    })?;
-    let kv_status = matches!(
-        updated_merchant_account.storage_scheme,
-        MerchantStorageScheme::RedisKv
-    );
-
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::ToggleKVResponse {
-            merchant_id: updated_merchant_account.merchant_id,
-            kv_enabled: kv_status,
-        },
-    ))
+    })
 }
 
 pub async fn toggle_kv_for_all_merchants(
@@ -1439,6 +1556,287 @@ pub async fn toggle_kv_for_all_merchants(
     ))
 }
 
+pub async fn retrieve_merchant_kv_settings(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantKvSettingsResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let kv_settings = find_merchant_kv_settings(db, &merchant_id).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::MerchantKvSettingsResponse {
+            merchant_id: merchant_account.merchant_id,
+            kv_enabled: merchant_account.storage_scheme == MerchantStorageScheme::RedisKv,
+            shard_count: kv_settings.and_then(|settings| settings.shard_count),
+            drainer_shard_count: state.conf.drainer.num_partitions,
+        },
+    ))
+}
+
+pub async fn update_merchant_kv_settings(
+    state: SessionState,
+    req: api::MerchantKvSettingsUpdateRequest,
+) -> RouterResponse<api::MerchantKvSettingsResponse> {
+    let db = state.store.as_ref();
+    let drainer_shard_count = state.conf.drainer.num_partitions;
+    if let Some(shard_count) = req.shard_count {
+        utils::when(
+            shard_count == 0 || shard_count > drainer_shard_count,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "shard_count must be between 1 and {drainer_shard_count}, the number of drainer shards"
+                    ),
+                })
+            },
+        )?;
+    }
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &req.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let mut merchant_account = db
+        .find_merchant_account_by_merchant_id(&req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let existing_settings = find_merchant_kv_settings(db, &req.merchant_id).await?;
+    let existing_shard_count = existing_settings
+        .as_ref()
+        .and_then(|settings| settings.shard_count);
+    let shard_count_changed = req
+        .shard_count
+        .is_some_and(|shard_count| Some(shard_count) != existing_shard_count);
+
+    // Moving the writes of a merchant to other shards while they are written to the KV could
+    // drain the updates of an object before the object itself, so the shards can only be changed
+    // while the writes of the merchant go to the database
+    if shard_count_changed {
+        utils::when(
+            merchant_account.storage_scheme == MerchantStorageScheme::RedisKv
+                && req.kv_enabled != Some(false),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message:
+                        "shard_count can only be changed while KV is disabled for the merchant"
+                            .to_owned(),
+                })
+            },
+        )?;
+    }
+
+    if req.kv_enabled == Some(false) {
+        merchant_account =
+            switch_merchant_storage_scheme(&state, merchant_account, &key_store, false).await?;
+    }
+
+    if shard_count_changed {
+        let key = storage_impl::redis::kv_store::get_merchant_kv_settings_key(&req.merchant_id);
+        let config = storage_impl::redis::kv_store::MerchantKvSettings {
+            shard_count: req.shard_count,
+        }
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the KV settings of the merchant")?;
+
+        match existing_settings {
+            Some(_) => db
+                .update_config_by_key(
+                    &key,
+                    configs::ConfigUpdate::Update {
+                        config: Some(config),
+                    },
+                )
+                .await
+                .map(|_| ()),
+            None => db
+                .insert_config(configs::ConfigNew { key, config })
+                .await
+                .map(|_| ()),
+        }
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the KV settings of the merchant")?;
+    }
+
+    if req.kv_enabled == Some(true) {
+        merchant_account =
+            switch_merchant_storage_scheme(&state, merchant_account, &key_store, true).await?;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::MerchantKvSettingsResponse {
+            merchant_id: merchant_account.merchant_id,
+            kv_enabled: merchant_account.storage_scheme == MerchantStorageScheme::RedisKv,
+            shard_count: if shard_count_changed {
+                req.shard_count
+            } else {
+                existing_shard_count
+            },
+            drainer_shard_count,
+        },
+    ))
+}
+
+/// The KV settings of the merchant, read from the database since they are cached as
+/// [`storage_impl::redis::kv_store::MerchantKvSettings`] rather than as a config
+async fn find_merchant_kv_settings(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<storage_impl::redis::kv_store::MerchantKvSettings>> {
+    let key = storage_impl::redis::kv_store::get_merchant_kv_settings_key(merchant_id);
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("MerchantKvSettings")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the KV settings of the merchant")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the KV settings of the merchant")),
+    }
+}
+
+pub async fn retrieve_drainer_tuning(state: SessionState) -> RouterResponse<api::DrainerTuning> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let tuning = match redis_conn
+        .get_and_deserialize_key::<diesel_models::kv::DrainerTuning>(
+            diesel_models::kv::DRAINER_TUNING_KEY,
+            "DrainerTuning",
+        )
+        .await
+    {
+        Ok(tuning) => tuning,
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) =>
+        {
+            diesel_models::kv::DrainerTuning::default()
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the drainer tuning"))?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(api::DrainerTuning {
+        concurrency: tuning.concurrency,
+        max_read_count: tuning.max_read_count,
+    }))
+}
+
+pub async fn update_drainer_tuning(
+    state: SessionState,
+    req: api::DrainerTuning,
+) -> RouterResponse<api::DrainerTuning> {
+    utils::when(
+        req.concurrency == Some(0) || req.max_read_count == Some(0),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "concurrency and max_read_count must be greater than zero".to_owned(),
+            })
+        },
+    )?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The drainer reads the tuning on every iteration, so the new tuning applies without a restart
+    redis_conn
+        .serialize_and_set_key(
+            diesel_models::kv::DRAINER_TUNING_KEY,
+            diesel_models::kv::DrainerTuning {
+                concurrency: req.concurrency,
+                max_read_count: req.max_read_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the drainer tuning")?;
+
+    Ok(service_api::ApplicationResponse::Json(req))
+}
+
+pub async fn retrieve_kv_backlog(state: SessionState) -> RouterResponse<api::KvBacklogResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let now_in_millis = date_time::now_unix_timestamp().saturating_mul(1000);
+
+    let shards = try_join_all((0..state.conf.drainer.num_partitions).map(|shard| {
+        let redis_conn = redis_conn.clone();
+        let stream = format!("{{shard_{shard}}}_{}", state.conf.drainer.stream_name);
+        async move {
+            let pending_entries = redis_conn
+                .stream_get_length(&stream)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Failed to get the length of {stream}"))?;
+
+            let oldest_entry_age_in_secs = if pending_entries > 0 {
+                redis_conn
+                    .stream_read_entries(stream.as_str(), "0-0", Some(1))
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to read the oldest entry of {stream}")
+                    })?
+                    .into_values()
+                    .next()
+                    .and_then(|entries| entries.into_iter().next())
+                    // Stream entry ids start with the time the entry was added at, in milliseconds
+                    .and_then(|(id, _)| id.split_once('-').map(|(added_at, _)| added_at.to_owned()))
+                    .and_then(|added_at| added_at.parse::<i64>().ok())
+                    .map(|added_at| {
+                        u64::try_from(now_in_millis.saturating_sub(added_at) / 1000)
+                            .unwrap_or_default()
+                    })
+            } else {
+                None
+            };
+
+            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(api::KvShardBacklog {
+                shard,
+                pending_entries,
+                oldest_entry_age_in_secs,
+            })
+        }
+    }))
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::KvBacklogResponse {
+            total_pending_entries: shards.iter().map(|shard| shard.pending_entries).sum(),
+            shards,
+        },
+    ))
+}
+
 pub async fn check_merchant_account_kv_status(
     state: SessionState,
     merchant_id: String,
@@ -1489,6 +1887,101 @@ pub fn get_frm_config_as_secret(
     }
 }
 
+fn has_webhook_url(webhook_details: Option<&serde_json::Value>) -> bool {
+    webhook_details
+        .cloned()
+        .and_then(|webhook_details| {
+            webhook_details
+                .parse_value::<admin_types::WebhookDetails>("WebhookDetails")
+                .ok()
+        })
+        .is_some_and(|webhook_details| webhook_details.webhook_url.is_some())
+}
+
+fn has_active_routing_algorithm(routing_algorithm: Option<&serde_json::Value>) -> bool {
+    routing_algorithm
+        .cloned()
+        .and_then(|routing_algorithm| {
+            routing_algorithm
+                .parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+                .ok()
+        })
+        .is_some_and(|routing_algorithm| routing_algorithm.algorithm_id.is_some())
+}
+
+/// The steps of the onboarding of the merchant, in the order they are usually completed in, each
+/// being complete if either the merchant account or any of its business profiles completes it
+pub async fn retrieve_onboarding_status(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<admin_types::OnboardingStatusResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profiles = db
+        .list_business_profile_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the business profiles of the merchant")?;
+    let enabled_connectors = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_id,
+            false,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the connectors of the merchant")?;
+
+    let steps = [
+        (
+            admin_types::OnboardingStep::BusinessProfile,
+            !business_profiles.is_empty(),
+        ),
+        (
+            admin_types::OnboardingStep::Connector,
+            !enabled_connectors.is_empty(),
+        ),
+        (
+            admin_types::OnboardingStep::Webhook,
+            has_webhook_url(merchant_account.webhook_details.as_ref())
+                || business_profiles
+                    .iter()
+                    .any(|profile| has_webhook_url(profile.webhook_details.as_ref())),
+        ),
+        (
+            admin_types::OnboardingStep::Routing,
+            has_active_routing_algorithm(merchant_account.routing_algorithm.as_ref())
+                || business_profiles.iter().any(|profile| {
+                    has_active_routing_algorithm(profile.routing_algorithm.as_ref())
+                }),
+        ),
+    ]
+    .into_iter()
+    .map(|(step, completed)| admin_types::OnboardingStepStatus { step, completed })
+    .collect::<Vec<_>>();
+
+    let next_step = steps
+        .iter()
+        .find(|step_status| !step_status.completed)
+        .map(|step_status| step_status.step);
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::OnboardingStatusResponse {
+            merchant_id,
+            completed: next_step.is_none(),
+            next_step,
+            steps,
+        },
+    ))
+}
+
 pub async fn create_and_insert_business_profile(
     db: &dyn StorageInterface,
     request: api::BusinessProfileCreate,
@@ -1519,6 +2012,34 @@ pub async fn create_business_profile(
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+
+    if let Some(frm_decision_matrix) = &request.frm_decision_matrix {
+        helpers::validate_frm_decision_matrix(frm_decision_matrix)?;
+    }
+
+    if let Some(frm_post_auth_review) = &request.frm_post_auth_review {
+        helpers::validate_frm_post_auth_review_config(frm_post_auth_review)?;
+    }
+
+    if let Some(connector_debug_capture) = &request.connector_debug_capture {
+        helpers::validate_connector_debug_capture_config(connector_debug_capture)?;
+    }
+
+    if let Some(connector_failover_policy) = &request.connector_failover_policy {
+        helpers::validate_connector_failover_policy(connector_failover_policy)?;
+    }
+
+    if let Some(vault_provider) = &request.vault_provider {
+        card_vault::validate_vault_provider(&state, merchant_id, vault_provider).await?;
+    }
+
+    if let Some(tax_provider) = &request.tax_provider {
+        tax_calculation::validate_tax_provider(&state, tax_provider)?;
+    }
+
+    if let Some(custom_fields_schema) = &request.custom_fields_schema {
+        helpers::validate_custom_fields_schema(custom_fields_schema)?;
+    }
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1552,10 +2073,21 @@ pub async fn create_business_profile(
             .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     }
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+    let response = api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::BusinessProfile,
+        &response.profile_id,
+        AuditAction::Create,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn list_business_profile(
@@ -1602,13 +2134,31 @@ pub async fn delete_business_profile(
     merchant_id: &str,
 ) -> RouterResponse<bool> {
     let db = state.store.as_ref();
+    let business_profile_before = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .ok()
+        .and_then(|business_profile| {
+            api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile).ok()
+        });
     let delete_result = db
         .delete_business_profile_by_profile_id_merchant_id(&profile_id, merchant_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id,
+            id: profile_id.clone(),
         })?;
 
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::BusinessProfile,
+        &profile_id,
+        AuditAction::Delete,
+        business_profile_before.as_ref(),
+        None,
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(delete_result))
 }
 
@@ -1636,6 +2186,34 @@ pub async fn update_business_profile(
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
 
+    if let Some(frm_decision_matrix) = &request.frm_decision_matrix {
+        helpers::validate_frm_decision_matrix(frm_decision_matrix)?;
+    }
+
+    if let Some(frm_post_auth_review) = &request.frm_post_auth_review {
+        helpers::validate_frm_post_auth_review_config(frm_post_auth_review)?;
+    }
+
+    if let Some(connector_debug_capture) = &request.connector_debug_capture {
+        helpers::validate_connector_debug_capture_config(connector_debug_capture)?;
+    }
+
+    if let Some(connector_failover_policy) = &request.connector_failover_policy {
+        helpers::validate_connector_failover_policy(connector_failover_policy)?;
+    }
+
+    if let Some(vault_provider) = &request.vault_provider {
+        card_vault::validate_vault_provider(&state, merchant_id, vault_provider).await?;
+    }
+
+    if let Some(tax_provider) = &request.tax_provider {
+        tax_calculation::validate_tax_provider(&state, tax_provider)?;
+    }
+
+    if let Some(custom_fields_schema) = &request.custom_fields_schema {
+        helpers::validate_custom_fields_schema(custom_fields_schema)?;
+    }
+
     let webhook_details = request
         .webhook_details
         .as_ref()
@@ -1724,8 +2302,74 @@ pub async fn update_business_profile(
         collect_shipping_details_from_wallet_connector: request
             .collect_shipping_details_from_wallet_connector,
         is_connector_agnostic_mit_enabled: request.is_connector_agnostic_mit_enabled,
+        frm_decision_matrix: request
+            .frm_decision_matrix
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "frm_decision_matrix",
+            })?,
+        frm_post_auth_review: request
+            .frm_post_auth_review
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "frm_post_auth_review",
+            })?,
+        connector_debug_capture: request
+            .connector_debug_capture
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "connector_debug_capture",
+            })?,
+        connector_failover_policy: request
+            .connector_failover_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "connector_failover_policy",
+            })?,
+        vault_provider: request
+            .vault_provider
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "vault_provider",
+            })?,
+        tax_provider: request
+            .tax_provider
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "tax_provider",
+            })?,
+        dispute_auto_response: request
+            .dispute_auto_response
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "dispute_auto_response",
+            })?,
+        custom_fields_schema: request
+            .custom_fields_schema
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "custom_fields_schema",
+            })?,
     };
 
+    let business_profile_before =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile.clone()).ok();
     let updated_business_profile = db
         .update_business_profile_by_profile_id(business_profile, business_profile_update)
         .await
@@ -1733,10 +2377,22 @@ pub async fn update_business_profile(
             id: profile_id.to_owned(),
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
+    let response =
         api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::BusinessProfile,
+        profile_id,
+        AuditAction::Update,
+        business_profile_before.as_ref(),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn extended_card_info_toggle(
@@ -1947,6 +2603,13 @@ pub(crate) fn validate_auth_and_metadata_type_with_connector(
             ebanx::transformers::EbanxAuthType::try_from(val)?;
             Ok(())
         }
+        api_enums::Connector::Externaladapter => {
+            externaladapter::transformers::ExternaladapterAuthType::try_from(val)?;
+            externaladapter::transformers::ExternaladapterConnectorMetadataObject::try_from(
+                connector_meta_data,
+            )?;
+            Ok(())
+        }
         api_enums::Connector::Fiserv => {
             fiserv::transformers::FiservAuthType::try_from(val)?;
             fiserv::transformers::FiservSessionObject::try_from(connector_meta_data)?;
@@ -0,0 +1,563 @@
+//! Alert rules of the merchants. The outcomes watched by the rules are counted in redis in buckets
+//! of a minute as the payments, webhooks and connector calls are processed, and a scheduler task
+//! of each merchant with alert rules evaluates the rules over the buckets of their window, raising
+//! an alert to the webhook URL and the emails of the merchant when a rule is breached.
+
+use std::collections::HashSet;
+
+use api_models::alerts::{
+    AlertEvent, AlertMetric, AlertRule, MerchantAlertRulesRecord, MerchantAlertRulesRequest,
+    MerchantAlertRulesResponse,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    request::RequestContent,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use tracing_futures::Instrument;
+
+#[cfg(feature = "email")]
+use crate::services::email::types as email_types;
+#[cfg(feature = "email")]
+use crate::types::domain;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services,
+    types::storage::{self, enums},
+};
+
+pub const ALERT_EVALUATION_TASK: &str = "ALERT_EVALUATION";
+pub const ALERT_EVALUATION_TAG: &str = "ALERT";
+
+/// Duration in seconds of the buckets the outcomes are counted in
+const SIGNAL_BUCKET_IN_SECS: i64 = 60;
+
+/// The scope of the outcomes counted across all the connectors
+const ALL_CONNECTORS_SCOPE: &str = "all";
+
+const MAX_RULES_PER_MERCHANT: usize = 20;
+
+const MAX_RULE_ID_LENGTH: usize = 64;
+
+/// Time the delivery of an alert to the webhook URL waits for a response
+const ALERT_WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+pub fn get_alert_rules_config_key(merchant_id: &str) -> String {
+    format!("alert_rules_{merchant_id}")
+}
+
+pub fn generate_task_id_for_alert_evaluation_workflow(merchant_id: &str) -> String {
+    format!(
+        "{runner}_{task}_{merchant_id}",
+        runner = storage::ProcessTrackerRunner::AlertEvaluationWorkflow,
+        task = ALERT_EVALUATION_TASK
+    )
+}
+
+/// The key of the count of outcomes of the minute starting at `bucket_start`, `matching` counting
+/// the outcomes the rate of the metric is made of and not all the outcomes
+fn get_signal_key(
+    merchant_id: &str,
+    metric: AlertMetric,
+    scope: &str,
+    matching: bool,
+    bucket_start: i64,
+) -> String {
+    let count = if matching { "matching" } else { "total" };
+    format!("alert_signal_{merchant_id}_{metric}_{scope}_{count}_{bucket_start}")
+}
+
+fn get_alert_cooldown_key(merchant_id: &str, rule_id: &str) -> String {
+    format!("alert_cooldown_{merchant_id}_{rule_id}")
+}
+
+/// Counts an outcome watched by the alert rules, `matching` being whether the outcome is one the
+/// rate of the metric is made of: a successful authorization, an unacknowledged webhook or a
+/// connector server error. The outcome is counted in the background, failures being logged.
+pub fn record_alert_signal(
+    state: &SessionState,
+    merchant_id: &str,
+    metric: AlertMetric,
+    connector: Option<&str>,
+    matching: bool,
+) {
+    let alerting = &state.conf.alerting;
+    if !alerting.enabled {
+        return;
+    }
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the alert signals"
+            );
+            return;
+        }
+    };
+
+    let now = common_utils::date_time::now_unix_timestamp();
+    let bucket_start = now - now.rem_euclid(SIGNAL_BUCKET_IN_SECS);
+    // The buckets are kept for the longest window, and the minute being counted
+    let expiry_in_secs = (i64::from(alerting.max_window_in_mins) + 1) * SIGNAL_BUCKET_IN_SECS;
+    let keys = std::iter::once(ALL_CONNECTORS_SCOPE)
+        .chain(connector)
+        .flat_map(|scope| {
+            std::iter::once(false)
+                .chain(matching.then_some(true))
+                .map(move |matching| {
+                    get_signal_key(merchant_id, metric, scope, matching, bucket_start)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    tokio::spawn(
+        async move {
+            for key in keys {
+                if let Err(error) = redis_conn
+                    .increment_key_with_expiry(&key, expiry_in_secs)
+                    .await
+                {
+                    logger::error!(?error, "Failed to count the alert signal");
+                }
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+fn validate_alert_rules(
+    state: &SessionState,
+    request: &MerchantAlertRulesRequest,
+) -> RouterResult<()> {
+    let invalid_request = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    if request.rules.len() > MAX_RULES_PER_MERCHANT {
+        return invalid_request(format!(
+            "a merchant cannot have more than {MAX_RULES_PER_MERCHANT} alert rules"
+        ));
+    }
+    if !request.rules.is_empty() && request.webhook_url.is_none() && request.emails.is_empty() {
+        return invalid_request(
+            "webhook_url or emails have to be set for the alerts to be delivered".to_string(),
+        );
+    }
+    if let Some(webhook_url) = &request.webhook_url {
+        if url::Url::parse(webhook_url).map_or(true, |url| url.scheme() != "https") {
+            return invalid_request("webhook_url has to be a valid https URL".to_string());
+        }
+    }
+
+    let max_window_in_mins = state.conf.alerting.max_window_in_mins;
+    let mut rule_ids = HashSet::new();
+    for rule in &request.rules {
+        let rule_id = &rule.rule_id;
+        if rule_id.is_empty() || rule_id.len() > MAX_RULE_ID_LENGTH {
+            return invalid_request(format!(
+                "rule_id has to be between 1 and {MAX_RULE_ID_LENGTH} characters long"
+            ));
+        }
+        if !rule_ids.insert(rule_id) {
+            return invalid_request(format!("rule_id {rule_id} is used by several rules"));
+        }
+        if !(0.0..=100.0).contains(&rule.threshold_percentage) {
+            return invalid_request(format!(
+                "threshold_percentage of the rule {rule_id} has to be between 0 and 100"
+            ));
+        }
+        if rule.window_in_mins == 0 || rule.window_in_mins > max_window_in_mins {
+            return invalid_request(format!(
+                "window_in_mins of the rule {rule_id} has to be between 1 and {max_window_in_mins}"
+            ));
+        }
+        if rule.connector.is_some() && rule.metric != AlertMetric::Connector5xxRate {
+            return invalid_request(format!(
+                "connector of the rule {rule_id} can only be set for the {} metric",
+                AlertMetric::Connector5xxRate
+            ));
+        }
+    }
+    Ok(())
+}
+
+async fn find_merchant_alert_rules(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<MerchantAlertRulesRecord> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_alert_rules_config_key(merchant_id),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the alert rules config")?
+        .config
+        .parse_struct("MerchantAlertRulesRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the alert rules record")
+}
+
+fn get_alert_rules_response(
+    state: &SessionState,
+    merchant_id: String,
+    record: MerchantAlertRulesRecord,
+) -> MerchantAlertRulesResponse {
+    MerchantAlertRulesResponse {
+        merchant_id,
+        enabled: state.conf.alerting.enabled,
+        rules: record.rules,
+        webhook_url: record.webhook_url,
+        emails: record.emails,
+    }
+}
+
+/// Schedules the evaluation of the alert rules of the merchant, the task of the merchant being
+/// run again if it was finished for the merchant having no rules to evaluate
+async fn schedule_alert_evaluation_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<()> {
+    let task_id = generate_task_id_for_alert_evaluation_workflow(merchant_id);
+    let now = common_utils::date_time::now();
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the alert evaluation task")?;
+
+    match process {
+        Some(process) if process.status == enums::ProcessTrackerStatus::Finish => db
+            .as_scheduler()
+            .reset_process(process, now)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the alert evaluation task"),
+        Some(_) => Ok(()),
+        None => {
+            let tracking_data = storage::AlertEvaluationTrackingData {
+                merchant_id: merchant_id.to_owned(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                ALERT_EVALUATION_TASK,
+                storage::ProcessTrackerRunner::AlertEvaluationWorkflow,
+                [ALERT_EVALUATION_TAG],
+                tracking_data,
+                now,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct alert evaluation process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the alert evaluation task")?;
+
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "AlertEvaluation")]),
+            );
+            Ok(())
+        }
+    }
+}
+
+pub async fn retrieve_alert_rules(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<MerchantAlertRulesResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let record = find_merchant_alert_rules(&state, &merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_alert_rules_response(&state, merchant_id, record),
+    ))
+}
+
+/// Replaces the alert rules of the merchant and where their alerts are delivered, scheduling the
+/// evaluation of the rules
+pub async fn update_alert_rules(
+    state: SessionState,
+    merchant_id: String,
+    request: MerchantAlertRulesRequest,
+) -> RouterResponse<MerchantAlertRulesResponse> {
+    let db = state.store.as_ref();
+    validate_alert_rules(&state, &request)?;
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key = get_alert_rules_config_key(&merchant_id);
+    let config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the alert rules config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the alert rules config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the alert rules config")?,
+    };
+
+    if request.rules.iter().any(|rule| rule.enabled) {
+        schedule_alert_evaluation_task(db, &merchant_id).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_alert_rules_response(&state, merchant_id, request),
+    ))
+}
+
+/// The rate of the rule over the outcomes of its window as a percentage, if the rule is breached.
+/// Rules are not breached by fewer outcomes than their minimum volume.
+fn get_breached_percentage(rule: &AlertRule, total: u64, matching: u64) -> Option<f64> {
+    if total == 0 || total < u64::from(rule.min_volume) {
+        return None;
+    }
+
+    let to_f64 = |count: u64| u32::try_from(count).map_or(f64::from(u32::MAX), f64::from);
+    let percentage = to_f64(matching.min(total)) * 100.0 / to_f64(total);
+    let is_breached = if rule.metric.is_breached_below_threshold() {
+        percentage < rule.threshold_percentage
+    } else {
+        percentage > rule.threshold_percentage
+    };
+    is_breached.then_some(percentage)
+}
+
+/// Sums the counts of the outcomes of the rule over the buckets of its window, the current minute
+/// included
+async fn count_rule_signals(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    merchant_id: &str,
+    rule: &AlertRule,
+    now: i64,
+) -> errors::CustomResult<(u64, u64), errors::RedisError> {
+    let scope = rule.connector.as_deref().unwrap_or(ALL_CONNECTORS_SCOPE);
+    let current_bucket_start = now - now.rem_euclid(SIGNAL_BUCKET_IN_SECS);
+
+    let mut counts = (0, 0);
+    for bucket in 0..i64::from(rule.window_in_mins) {
+        let bucket_start = current_bucket_start - bucket * SIGNAL_BUCKET_IN_SECS;
+        for matching in [false, true] {
+            let count = redis_conn
+                .get_key::<Option<i64>>(&get_signal_key(
+                    merchant_id,
+                    rule.metric,
+                    scope,
+                    matching,
+                    bucket_start,
+                ))
+                .await?
+                .and_then(|count| u64::try_from(count).ok())
+                .unwrap_or_default();
+            if matching {
+                counts.1 += count;
+            } else {
+                counts.0 += count;
+            }
+        }
+    }
+    Ok(counts)
+}
+
+/// Posts the alert to the webhook URL and emails it, failures being logged so that a failing
+/// destination does not prevent the delivery to the others
+async fn deliver_alert(state: &SessionState, record: &MerchantAlertRulesRecord, alert: AlertEvent) {
+    if let Some(webhook_url) = &record.webhook_url {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(webhook_url)
+            .attach_default_headers()
+            .set_body(RequestContent::Json(Box::new(alert.clone())))
+            .build();
+        match state
+            .api_client
+            .send_request(
+                state,
+                request,
+                Some(ALERT_WEBHOOK_TIMEOUT_SECS),
+                None,
+                false,
+            )
+            .await
+        {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => {
+                logger::warn!(status_code = %response.status(), "Alert webhook was not received");
+            }
+            Err(error) => logger::error!(?error, "Failed to deliver the alert webhook"),
+        }
+    }
+
+    #[cfg(feature = "email")]
+    for email in &record.emails {
+        let recipient_email = match domain::UserEmail::from_pii_email(email.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(?error, "Invalid email address of the alert rules");
+                continue;
+            }
+        };
+        let email_contents = email_types::AlertNotification {
+            recipient_email,
+            subject: format!("Alert rule {} breached", alert.rule_id),
+            alert: alert.clone(),
+        };
+        if let Err(error) = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to email the alert");
+        }
+    }
+    #[cfg(not(feature = "email"))]
+    if !record.emails.is_empty() {
+        logger::warn!("Alerts cannot be emailed when the email feature is disabled");
+    }
+}
+
+/// Evaluates the enabled alert rules of the merchant, raising an alert for each rule breached
+/// that is not cooling down from a previous alert. Returns whether the merchant has rules to
+/// evaluate.
+#[instrument(skip_all, fields(merchant_id = %merchant_id))]
+pub async fn evaluate_alert_rules(state: &SessionState, merchant_id: &str) -> RouterResult<bool> {
+    let record = find_merchant_alert_rules(state, merchant_id).await?;
+    if !record.rules.iter().any(|rule| rule.enabled) {
+        return Ok(false);
+    }
+    if !state.conf.alerting.enabled {
+        return Ok(true);
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection for the alert signals")?;
+    let now = common_utils::date_time::now_unix_timestamp();
+
+    for rule in record.rules.iter().filter(|rule| rule.enabled) {
+        let (total, matching) = count_rule_signals(&redis_conn, merchant_id, rule, now)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to count the alert signals")?;
+        let Some(observed_percentage) = get_breached_percentage(rule, total, matching) else {
+            continue;
+        };
+
+        let cooldown_in_mins = rule.cooldown_in_mins.unwrap_or(rule.window_in_mins);
+        let cooldown = redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &get_alert_cooldown_key(merchant_id, &rule.rule_id),
+                now,
+                Some(i64::from(cooldown_in_mins.max(1)) * SIGNAL_BUCKET_IN_SECS),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set the cooldown of the alert rule")?;
+        if cooldown == redis_interface::SetnxReply::KeyNotSet {
+            logger::debug!(rule_id = %rule.rule_id, "Alert rule breached while cooling down");
+            continue;
+        }
+
+        let alert = AlertEvent {
+            alert_id: common_utils::generate_id_with_default_len("alert"),
+            merchant_id: merchant_id.to_owned(),
+            rule_id: rule.rule_id.clone(),
+            metric: rule.metric,
+            connector: rule.connector.clone(),
+            threshold_percentage: rule.threshold_percentage,
+            observed_percentage,
+            volume: total,
+            window_in_mins: rule.window_in_mins,
+            triggered_at: common_utils::date_time::now(),
+        };
+        logger::warn!(?alert, "Alert rule breached");
+        metrics::ALERTS_TRIGGERED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("metric", rule.metric.to_string())]),
+        );
+        deliver_alert(state, &record, alert).await;
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(metric: AlertMetric, threshold_percentage: f64, min_volume: u32) -> AlertRule {
+        AlertRule {
+            rule_id: "rule".to_string(),
+            metric,
+            threshold_percentage,
+            window_in_mins: 15,
+            min_volume,
+            connector: None,
+            cooldown_in_mins: None,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_success_rate_breached_below_threshold() {
+        let rule = rule(AlertMetric::AuthorizationSuccessRate, 80.0, 10);
+
+        assert_eq!(get_breached_percentage(&rule, 100, 75), Some(75.0));
+        assert_eq!(get_breached_percentage(&rule, 100, 90), None);
+    }
+
+    #[test]
+    fn test_failure_rate_breached_above_threshold() {
+        let rule = rule(AlertMetric::Connector5xxRate, 5.0, 10);
+
+        assert_eq!(get_breached_percentage(&rule, 20, 2), Some(10.0));
+        assert_eq!(get_breached_percentage(&rule, 100, 2), None);
+    }
+
+    #[test]
+    fn test_rule_not_breached_below_min_volume() {
+        let rule = rule(AlertMetric::WebhookFailureRate, 5.0, 50);
+
+        assert_eq!(get_breached_percentage(&rule, 10, 10), None);
+        assert_eq!(get_breached_percentage(&rule, 0, 0), None);
+    }
+}
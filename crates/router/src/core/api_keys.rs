@@ -1,6 +1,8 @@
+use api_models::audit_events::{AuditAction, AuditResourceType};
 use common_utils::date_time;
+use diesel_models::api_keys::ApiKey;
 #[cfg(feature = "email")]
-use diesel_models::{api_keys::ApiKey, enums as storage_enums};
+use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
 use router_env::{instrument, metrics::add_attributes, tracing};
@@ -8,10 +10,16 @@ use router_env::{instrument, metrics::add_attributes, tracing};
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_events,
+        errors::{self, RouterResponse, StorageErrorExt},
+    },
     routes::{metrics, SessionState},
-    services::ApplicationResponse,
-    types::{api, storage, transformers::ForeignInto},
+    services::{authentication as auth, ApplicationResponse},
+    types::{
+        api, storage,
+        transformers::{self, ForeignInto},
+    },
     utils,
 };
 
@@ -140,6 +148,12 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key
+            .scopes
+            .and_then(transformers::encode_api_key_restriction),
+        allowed_ips: api_key
+            .allowed_ips
+            .and_then(transformers::encode_api_key_restriction),
     };
 
     let api_key = store
@@ -168,6 +182,18 @@ pub async fn create_api_key(
         }
     }
 
+    let api_key_snapshot: api::RetrieveApiKeyResponse = api_key.clone().foreign_into();
+    audit_events::record_audit_event(
+        &state,
+        &api_key.merchant_id,
+        AuditResourceType::ApiKey,
+        &api_key.key_id,
+        AuditAction::Create,
+        None,
+        Some(&api_key_snapshot),
+    )
+    .await;
+
     Ok(ApplicationResponse::Json(
         (api_key, plaintext_api_key).foreign_into(),
     ))
@@ -268,6 +294,13 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    let api_key_before: Option<api::RetrieveApiKeyResponse> = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .ok()
+        .flatten()
+        .map(ForeignInto::foreign_into);
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -328,7 +361,19 @@ pub async fn update_api_key(
         }
     }
 
-    Ok(ApplicationResponse::Json(api_key.foreign_into()))
+    let response: api::RetrieveApiKeyResponse = api_key.foreign_into();
+    audit_events::record_audit_event(
+        &state,
+        &merchant_id,
+        AuditResourceType::ApiKey,
+        &key_id,
+        AuditAction::Update,
+        api_key_before.as_ref(),
+        Some(&response),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
 }
 
 // Update api_key_expiry task in the process_tracker table.
@@ -402,6 +447,12 @@ pub async fn revoke_api_key(
     key_id: &str,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
     let store = state.store.as_ref();
+    let api_key_before: Option<api::RetrieveApiKeyResponse> = store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await
+        .ok()
+        .flatten()
+        .map(ForeignInto::foreign_into);
     let revoked = store
         .revoke_api_key(merchant_id, key_id)
         .await
@@ -433,6 +484,17 @@ pub async fn revoke_api_key(
         }
     }
 
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::ApiKey,
+        key_id,
+        AuditAction::Delete,
+        api_key_before.as_ref(),
+        None,
+    )
+    .await;
+
     Ok(ApplicationResponse::Json(api::RevokeApiKeyResponse {
         merchant_id: merchant_id.to_owned(),
         key_id: key_id.to_owned(),
@@ -484,6 +546,141 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+/// Authenticates the OAuth2 client, which is identified by the identifier of an API key and
+/// authenticated with the API key
+async fn authenticate_oauth2_client(
+    state: &SessionState,
+    client_id: &str,
+    client_secret: &StrongSecret<String>,
+) -> errors::RouterResult<ApiKey> {
+    let api_key = auth::authenticate_api_key(client_secret.peek(), state).await?;
+    if api_key.key_id != client_id {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Client secret does not belong to the client");
+    }
+    auth::verify_api_key_allowed_ips(&api_key, state.api_key_request_context.as_ref())?;
+
+    Ok(api_key)
+}
+
+#[instrument(skip_all)]
+pub async fn issue_oauth2_access_token(
+    state: SessionState,
+    request: api::OAuth2TokenRequest,
+) -> RouterResponse<api::OAuth2TokenResponse> {
+    let api::OAuth2GrantType::ClientCredentials = request.grant_type;
+    let api_key =
+        authenticate_oauth2_client(&state, &request.client_id, &request.client_secret).await?;
+
+    let api_key_scopes = api_key
+        .scopes
+        .as_deref()
+        .map(transformers::parse_api_key_scopes);
+    let scopes = match request.scope.as_deref().map(str::split_whitespace) {
+        None => api_key_scopes,
+        Some(requested_scopes) => {
+            let requested_scopes = requested_scopes
+                .map(|scope| {
+                    scope
+                        .parse::<api_models::api_keys::ApiKeyScope>()
+                        .map_err(|_| {
+                            report!(errors::ApiErrorResponse::InvalidRequestData {
+                                message: format!("Invalid scope {scope}"),
+                            })
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(api_key_scopes) = &api_key_scopes {
+                if let Some(scope) = requested_scopes.iter().find(|scope| {
+                    !api_key_scopes
+                        .iter()
+                        .any(|granted| scope.is_granted_by(*granted))
+                }) {
+                    return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!("Scope {scope} is not granted to the client"),
+                    }));
+                }
+            }
+            Some(requested_scopes)
+        }
+    };
+
+    let expires_at = date_time::now_unix_timestamp() + consts::OAUTH2_ACCESS_TOKEN_TTL_IN_SECS;
+    let claims = auth::OAuth2AccessTokenClaims {
+        jti: utils::generate_id(consts::ID_LENGTH, "oat"),
+        merchant_id: api_key.merchant_id,
+        client_id: api_key.key_id,
+        scopes: scopes.clone(),
+        exp: u64::try_from(expires_at)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to compute the expiry of the access token")?,
+    };
+    let header = jsonwebtoken::Header {
+        kid: Some(consts::OAUTH2_ACCESS_TOKEN_KID.to_owned()),
+        ..Default::default()
+    };
+    let access_token = jsonwebtoken::encode(
+        &header,
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(
+            state.conf.secrets.get_inner().jwt_secret.peek().as_bytes(),
+        ),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode the access token")?;
+
+    Ok(ApplicationResponse::Json(api::OAuth2TokenResponse {
+        access_token: access_token.into(),
+        token_type: "Bearer".to_owned(),
+        expires_in: consts::OAUTH2_ACCESS_TOKEN_TTL_IN_SECS,
+        scope: scopes.map(|scopes| {
+            scopes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        }),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_oauth2_access_token(
+    state: SessionState,
+    request: api::OAuth2RevokeRequest,
+) -> RouterResponse<()> {
+    authenticate_oauth2_client(&state, &request.client_id, &request.client_secret).await?;
+
+    // Invalid and expired access tokens can no longer be used, so there is nothing to revoke
+    let Ok(claims) =
+        auth::decode_jwt::<auth::OAuth2AccessTokenClaims>(request.token.peek(), &state).await
+    else {
+        return Ok(ApplicationResponse::StatusOk);
+    };
+    if claims.client_id != request.client_id {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Access token was issued to another client");
+    }
+
+    let remaining_validity = i64::try_from(claims.exp)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        - date_time::now_unix_timestamp();
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_with_expiry(
+            &auth::get_oauth2_revoked_token_key(&claims.jti),
+            "true",
+            remaining_validity.max(1),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revoke the access token")?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
 #[cfg(feature = "email")]
 fn generate_task_id_for_api_key_expiry_workflow(key_id: &str) -> String {
     format!("{API_KEY_EXPIRY_RUNNER}_{API_KEY_EXPIRY_NAME}_{key_id}")
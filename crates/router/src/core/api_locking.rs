@@ -4,6 +4,7 @@ use actix_web::rt::time as actix_time;
 use error_stack::{report, ResultExt};
 use redis_interface as redis;
 use router_env::{instrument, logger, tracing};
+use storage_impl::redis::region;
 
 use super::errors::{self, RouterResult};
 use crate::routes::{app::SessionStateInfo, lock_utils};
@@ -54,10 +55,12 @@ impl LockAction {
     {
         match self {
             Self::Hold { input } => {
-                let redis_conn = state
-                    .store()
-                    .get_redis_conn()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                let redis_conn = region::lock_redis_conn(
+                    state
+                        .store()
+                        .get_redis_conn()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                );
 
                 let redis_locking_key = input.get_redis_locking_key(merchant_id);
                 let delay_between_retries_in_milliseconds = state
@@ -115,10 +118,12 @@ impl LockAction {
     {
         match self {
             Self::Hold { input } => {
-                let redis_conn = state
-                    .store()
-                    .get_redis_conn()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                let redis_conn = region::lock_redis_conn(
+                    state
+                        .store()
+                        .get_redis_conn()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                );
 
                 let redis_locking_key = input.get_redis_locking_key(merchant_id);
 
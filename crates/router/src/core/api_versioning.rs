@@ -0,0 +1,396 @@
+use std::str::FromStr;
+
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use api_models::api_versioning::{
+    ApiVersion, ApiVersionPinRecord, ApiVersionPinRequest, ApiVersionPinResponse,
+};
+use common_utils::{
+    consts::API_VERSION_HEADER,
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, metrics::add_attributes, tracing};
+use strum::IntoEnumIterator;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    headers,
+    routes::{metrics, SessionState},
+    services::{api as service_api, authentication as auth},
+};
+
+/// The latest version of the API responses
+pub const LATEST_API_VERSION: ApiVersion = ApiVersion::V2024_07_29;
+
+/// A breaking change to the API responses, made from a version onwards
+struct ResponseChange {
+    version: ApiVersion,
+    /// The resources, as the first segment of the path of the requests, whose responses change
+    resources: &'static [&'static str],
+    /// The paths of the fields removed from the responses, the fields of the items of the lists
+    /// on the way being looked up in each item
+    removed_fields: &'static [&'static str],
+}
+
+const RESPONSE_CHANGES: [ResponseChange; 1] = [ResponseChange {
+    version: ApiVersion::V2024_07_29,
+    resources: &["payments"],
+    removed_fields: &["customer_id", "email", "name", "phone"],
+}];
+
+/// When a version superseded was deprecated, and when it stops being served
+struct VersionLifecycle {
+    deprecated_at: OffsetDateTime,
+    sunset_at: OffsetDateTime,
+}
+
+fn get_version_lifecycle(version: ApiVersion) -> Option<VersionLifecycle> {
+    // The unix timestamps of the dates, at midnight UTC
+    let (deprecated_at, sunset_at) = match version {
+        // Deprecated on 2024-07-29, served until 2025-07-29
+        ApiVersion::V2024_01_01 => (1_722_211_200, 1_753_747_200),
+        ApiVersion::V2024_07_29 => return None,
+    };
+
+    Some(VersionLifecycle {
+        deprecated_at: OffsetDateTime::from_unix_timestamp(deprecated_at).ok()?,
+        sunset_at: OffsetDateTime::from_unix_timestamp(sunset_at).ok()?,
+    })
+}
+
+fn to_primitive_date_time(date_time: OffsetDateTime) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(date_time.date(), date_time.time())
+}
+
+/// Formats the time as an HTTP date, such as `Tue, 29 Jul 2025 00:00:00 GMT`
+fn format_http_date(date_time: OffsetDateTime) -> Option<String> {
+    let format = time::format_description::parse(
+        "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT",
+    )
+    .ok()?;
+    date_time.format(&format).ok()
+}
+
+pub fn get_api_version_config_key(merchant_id: &str) -> String {
+    format!("api_version_{merchant_id}")
+}
+
+async fn find_api_version_pin(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<ApiVersionPinRecord> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_api_version_config_key(merchant_id),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the API version config")?
+        .config
+        .parse_struct("ApiVersionPinRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the API version record")
+}
+
+fn get_api_version_pin_response(
+    state: &SessionState,
+    merchant_id: String,
+    record: ApiVersionPinRecord,
+) -> ApiVersionPinResponse {
+    let version = record
+        .version
+        .unwrap_or(state.conf.api_versioning.default_version);
+    let lifecycle = get_version_lifecycle(version);
+
+    ApiVersionPinResponse {
+        merchant_id,
+        pinned_version: record.version,
+        version,
+        latest_version: LATEST_API_VERSION,
+        deprecated_at: lifecycle
+            .as_ref()
+            .map(|lifecycle| to_primitive_date_time(lifecycle.deprecated_at)),
+        sunset_at: lifecycle.map(|lifecycle| to_primitive_date_time(lifecycle.sunset_at)),
+    }
+}
+
+pub async fn retrieve_api_version_pin(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<ApiVersionPinResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let record = find_api_version_pin(&state, &merchant_id).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_api_version_pin_response(&state, merchant_id, record),
+    ))
+}
+
+/// Pins the version of the API responses of the merchant, the default version of the deployment
+/// being used when no version is set in the request. The versions past their sunset cannot be
+/// pinned.
+pub async fn update_api_version_pin(
+    state: SessionState,
+    merchant_id: String,
+    request: ApiVersionPinRequest,
+) -> RouterResponse<ApiVersionPinResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if let Some(version) = request.version {
+        if get_version_lifecycle(version).map_or(false, |lifecycle| {
+            lifecycle.sunset_at <= OffsetDateTime::now_utc()
+        }) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("version {version} is past its sunset and cannot be pinned"),
+            }));
+        }
+    }
+
+    let key = get_api_version_config_key(&merchant_id);
+    let config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the API version config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the API version config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the API version config")?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_api_version_pin_response(&state, merchant_id, request),
+    ))
+}
+
+/// The version of the response to a request, kept in the extensions of the request until the
+/// response is serialized
+#[derive(Clone, Debug)]
+pub struct ResponseVersion {
+    version: ApiVersion,
+    /// The fields removed from the response by the changes made up to the version
+    removed_fields: Vec<&'static str>,
+}
+
+impl ResponseVersion {
+    fn new(version: ApiVersion, resource: &str) -> Self {
+        let removed_fields = RESPONSE_CHANGES
+            .iter()
+            .filter(|change| change.version <= version && change.resources.contains(&resource))
+            .flat_map(|change| change.removed_fields.iter().copied())
+            .collect();
+
+        Self {
+            version,
+            removed_fields,
+        }
+    }
+
+    /// Changes the serialized response to the schema of the version. The fields are looked up in
+    /// the response, in each of its items when it is a list, and in each item of its `data` field
+    /// when it is a paginated list.
+    pub fn apply(&self, response: &mut serde_json::Value) {
+        for path in &self.removed_fields {
+            let segments = path.split('.').collect::<Vec<_>>();
+            remove_field(response, &segments);
+            if let Some(data) = response.get_mut("data") {
+                remove_field(data, &segments);
+            }
+        }
+    }
+
+    /// Adds the version of the response to its headers, along with the `Deprecation` and the
+    /// `Sunset` headers when the version is deprecated
+    pub fn add_headers(&self, response_headers: &mut HeaderMap) {
+        let mut version_headers = vec![(API_VERSION_HEADER, self.version.to_string())];
+        if let Some(lifecycle) = get_version_lifecycle(self.version) {
+            version_headers.push((
+                headers::DEPRECATION,
+                format!("@{}", lifecycle.deprecated_at.unix_timestamp()),
+            ));
+            if let Some(sunset_at) = format_http_date(lifecycle.sunset_at) {
+                version_headers.push((headers::SUNSET, sunset_at));
+            }
+        }
+
+        for (name, value) in version_headers {
+            if let (Ok(name), Ok(value)) =
+                (HeaderName::try_from(name), HeaderValue::from_str(&value))
+            {
+                response_headers.insert(name, value);
+            }
+        }
+    }
+}
+
+fn remove_field(value: &mut serde_json::Value, path: &[&str]) {
+    match value {
+        serde_json::Value::Array(items) => {
+            items.iter_mut().for_each(|item| remove_field(item, path))
+        }
+        serde_json::Value::Object(fields) => match path {
+            [field] => {
+                fields.remove(*field);
+            }
+            [segment, rest @ ..] => {
+                if let Some(value) = fields.get_mut(*segment) {
+                    remove_field(value, rest);
+                }
+            }
+            [] => {}
+        },
+        _ => {}
+    }
+}
+
+/// The version of the response to the request: the version requested in the `X-API-Version`
+/// header, else the version pinned for the merchant, else the default version of the deployment
+#[instrument(skip_all)]
+pub async fn get_response_version(
+    state: &SessionState,
+    auth_type: &auth::AuthenticationType,
+    request: &actix_web::HttpRequest,
+) -> RouterResult<ResponseVersion> {
+    let requested_version = request
+        .headers()
+        .get(API_VERSION_HEADER)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .and_then(|value| ApiVersion::from_str(value.trim()).ok())
+                .ok_or_else(|| {
+                    report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "unsupported {API_VERSION_HEADER} header, the supported versions are {}",
+                            ApiVersion::iter()
+                                .map(|version| version.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    })
+                })
+        })
+        .transpose()?;
+
+    let version = match (requested_version, auth_type.get_merchant_id()) {
+        (Some(version), _) => version,
+        (None, Some(merchant_id)) => find_api_version_pin(state, merchant_id)
+            .await?
+            .version
+            .unwrap_or(state.conf.api_versioning.default_version),
+        (None, None) => state.conf.api_versioning.default_version,
+    };
+
+    if get_version_lifecycle(version).is_some() {
+        metrics::DEPRECATED_API_VERSION_REQUESTS.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("version", version.to_string())]),
+        );
+    }
+
+    let resource = request
+        .path()
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    Ok(ResponseVersion::new(version, resource))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_fields_are_removed_from_the_later_versions() {
+        let mut response = serde_json::json!({
+            "payment_id": "pay_1",
+            "customer_id": "cus_1",
+            "email": "john@example.com",
+            "customer": { "id": "cus_1", "email": "john@example.com" }
+        });
+        let legacy_response = response.clone();
+
+        ResponseVersion::new(ApiVersion::V2024_01_01, "payments").apply(&mut response);
+        assert_eq!(response, legacy_response);
+
+        ResponseVersion::new(ApiVersion::V2024_07_29, "refunds").apply(&mut response);
+        assert_eq!(response, legacy_response);
+
+        ResponseVersion::new(ApiVersion::V2024_07_29, "payments").apply(&mut response);
+        assert_eq!(
+            response,
+            serde_json::json!({
+                "payment_id": "pay_1",
+                "customer": { "id": "cus_1", "email": "john@example.com" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_fields_of_listed_items_are_removed() {
+        let mut response = serde_json::json!({
+            "count": 1,
+            "data": [{ "payment_id": "pay_1", "name": "John Doe", "phone": "999999999" }]
+        });
+        ResponseVersion::new(ApiVersion::V2024_07_29, "payments").apply(&mut response);
+
+        assert_eq!(
+            response["data"][0],
+            serde_json::json!({ "payment_id": "pay_1" })
+        );
+    }
+
+    #[test]
+    fn test_deprecated_versions_have_deprecation_headers() {
+        let mut response_headers = HeaderMap::new();
+        ResponseVersion::new(ApiVersion::V2024_01_01, "payments")
+            .add_headers(&mut response_headers);
+
+        assert_eq!(
+            response_headers.get(API_VERSION_HEADER).unwrap(),
+            "2024-01-01"
+        );
+        assert_eq!(
+            response_headers.get(headers::DEPRECATION).unwrap(),
+            "@1722211200"
+        );
+        assert_eq!(
+            response_headers.get(headers::SUNSET).unwrap(),
+            "Tue, 29 Jul 2025 00:00:00 GMT"
+        );
+
+        let mut response_headers = HeaderMap::new();
+        ResponseVersion::new(LATEST_API_VERSION, "payments").add_headers(&mut response_headers);
+        assert!(response_headers.get(headers::DEPRECATION).is_none());
+    }
+}
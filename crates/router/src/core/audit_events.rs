@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use api_models::audit_events::{
+    AuditAction, AuditActorType, AuditEventResponse, AuditEventsQuery, AuditEventsResponse,
+    AuditResourceType,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::{app::SessionStateInfo, SessionState},
+    services::{authentication::AuthenticationType, ApplicationResponse},
+    types::storage,
+    utils,
+};
+
+const DEFAULT_EVENTS_LIMIT: i64 = 100;
+
+/// The kind and the identifier of the credential the request being served was authenticated with
+fn get_audit_actor(auth_type: Option<&AuthenticationType>) -> (AuditActorType, Option<String>) {
+    match auth_type {
+        Some(AuthenticationType::AdminApiKey) => (AuditActorType::AdminApiKey, None),
+        Some(AuthenticationType::ApiKey { key_id, .. }) => {
+            (AuditActorType::ApiKey, Some(key_id.clone()))
+        }
+        Some(AuthenticationType::OAuth2 { client_id, .. }) => {
+            (AuditActorType::ApiKey, Some(client_id.clone()))
+        }
+        Some(AuthenticationType::MerchantJwt { user_id, .. }) => {
+            (AuditActorType::User, user_id.clone())
+        }
+        Some(
+            AuthenticationType::UserJwt { user_id }
+            | AuthenticationType::SinglePurposeJwt { user_id, .. }
+            | AuthenticationType::SinglePurposeOrLoginJwt { user_id, .. },
+        ) => (AuditActorType::User, Some(user_id.clone())),
+        Some(
+            AuthenticationType::MerchantId { .. }
+            | AuthenticationType::PublishableKey { .. }
+            | AuthenticationType::WebhookAuth { .. }
+            | AuthenticationType::NoAuth,
+        )
+        | None => (AuditActorType::Internal, None),
+    }
+}
+
+/// The fields whose values differ between the two snapshots of a resource, each with its value
+/// before and after the mutation
+fn get_audit_diff(before: Option<Value>, after: Option<Value>) -> Option<Value> {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let mut diff = Map::new();
+            for key in before.keys().chain(after.keys()) {
+                let before_value = before.get(key).unwrap_or(&Value::Null);
+                let after_value = after.get(key).unwrap_or(&Value::Null);
+                if before_value != after_value && !diff.contains_key(key) {
+                    diff.insert(
+                        key.clone(),
+                        json!({ "before": before_value, "after": after_value }),
+                    );
+                }
+            }
+            Some(Value::Object(diff))
+        }
+        (None, None) => None,
+        (before, after) if before == after => Some(Value::Object(Map::new())),
+        (before, after) => Some(json!({ "before": before, "after": after })),
+    }
+}
+
+/// Records a mutation of the configuration of a merchant in the audit log, along with the actor
+/// of the request being served. The snapshots of the resource are masked before being diffed.
+/// Failures are logged without failing the request, the mutation having been made by then.
+#[instrument(skip_all, fields(%resource_type, %action))]
+pub async fn record_audit_event<T: serde::Serialize>(
+    state: &SessionState,
+    merchant_id: &str,
+    resource_type: AuditResourceType,
+    resource_id: &str,
+    action: AuditAction,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    let mask = |snapshot: Option<&T>| {
+        snapshot
+            .map(masking::masked_serialize)
+            .transpose()
+            .map_err(|error| logger::error!(?error, "Failed to mask the audited resource"))
+            .ok()
+            .flatten()
+    };
+    let (actor_type, actor_id) = get_audit_actor(state.auth_type.as_ref());
+
+    let audit_event = storage::AuditEventNew {
+        event_id: utils::generate_id(consts::ID_LENGTH, "audit"),
+        merchant_id: merchant_id.to_owned(),
+        actor_type: actor_type.to_string(),
+        actor_id,
+        resource_type: resource_type.to_string(),
+        resource_id: resource_id.to_owned(),
+        action: action.to_string(),
+        diff: get_audit_diff(mask(before), mask(after)),
+        request_id: state.get_request_id(),
+        created_at: common_utils::date_time::now(),
+    };
+
+    if let Err(error) = state.store.insert_audit_event(audit_event).await {
+        logger::error!(?error, "Failed to record the audit event");
+    }
+}
+
+fn get_audit_event_response(
+    audit_event: storage::AuditEvent,
+) -> errors::CustomResult<AuditEventResponse, errors::ApiErrorResponse> {
+    Ok(AuditEventResponse {
+        actor_type: AuditActorType::from_str(&audit_event.actor_type)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the actor type of the audit event")?,
+        resource_type: AuditResourceType::from_str(&audit_event.resource_type)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the resource type of the audit event")?,
+        action: AuditAction::from_str(&audit_event.action)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the action of the audit event")?,
+        event_id: audit_event.event_id,
+        merchant_id: audit_event.merchant_id,
+        actor_id: audit_event.actor_id,
+        resource_id: audit_event.resource_id,
+        diff: audit_event.diff,
+        request_id: audit_event.request_id,
+        created_at: audit_event.created_at,
+    })
+}
+
+pub async fn list_audit_events(
+    state: SessionState,
+    query: AuditEventsQuery,
+) -> RouterResponse<AuditEventsResponse> {
+    let constraints = storage::AuditEventConstraints {
+        merchant_id: query.merchant_id,
+        actor_id: query.actor_id,
+        resource_type: query
+            .resource_type
+            .map(|resource_type| resource_type.to_string()),
+        resource_id: query.resource_id,
+        action: query.action.map(|action| action.to_string()),
+        created_after: query.created_after,
+        created_before: query.created_before,
+        limit: query.limit.unwrap_or(DEFAULT_EVENTS_LIMIT),
+        offset: query.offset,
+    };
+
+    let data = state
+        .store
+        .filter_audit_events_by_constraints(constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the audit events")?
+        .into_iter()
+        .map(get_audit_event_response)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ApplicationResponse::Json(AuditEventsResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::get_audit_diff;
+
+    #[test]
+    fn test_audit_diff_only_includes_changed_fields() {
+        let before = json!({ "disabled": false, "connector_label": "stripe_US", "metadata": null });
+        let after = json!({ "disabled": true, "connector_label": "stripe_US", "test_mode": true });
+
+        assert_eq!(
+            get_audit_diff(Some(before), Some(after)),
+            Some(json!({
+                "disabled": { "before": false, "after": true },
+                "test_mode": { "before": null, "after": true },
+            }))
+        );
+    }
+
+    #[test]
+    fn test_audit_diff_of_created_resource() {
+        let after = json!({ "key_id": "dev_abc" });
+
+        assert_eq!(
+            get_audit_diff(None, Some(after.clone())),
+            Some(json!({ "before": null, "after": after }))
+        );
+        assert_eq!(get_audit_diff(None, None), None);
+    }
+}
@@ -1,8 +1,12 @@
+pub(crate) mod decision;
+pub mod standalone;
 pub(crate) mod utils;
 
 pub mod transformers;
 pub mod types;
 
+use std::str::FromStr;
+
 use api_models::payments;
 use common_enums::Currency;
 use common_utils::errors::CustomResult;
@@ -39,6 +43,7 @@ pub async fn perform_authentication(
     email: Option<common_utils::pii::Email>,
     webhook_url: String,
     three_ds_requestor_url: String,
+    challenge_preference: common_enums::ThreeDsChallengePreference,
 ) -> CustomResult<api::authentication::AuthenticationResponse, ApiErrorResponse> {
     let router_data = transformers::construct_authentication_router_data(
         merchant_id,
@@ -60,6 +65,7 @@ pub async fn perform_authentication(
         email,
         webhook_url,
         three_ds_requestor_url,
+        challenge_preference,
     )?;
     let response =
         utils::do_auth_connector_call(state, authentication_connector.clone(), router_data).await?;
@@ -83,14 +89,6 @@ pub async fn perform_post_authentication(
     business_profile: storage::BusinessProfile,
     authentication_id: String,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
-    let (authentication_connector, three_ds_connector_account) =
-        utils::get_authentication_connector_data(state, key_store, &business_profile).await?;
-    let is_pull_mechanism_enabled =
-        check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
-            three_ds_connector_account
-                .get_metadata()
-                .map(|metadata| metadata.expose()),
-        );
     let authentication = state
         .store
         .find_authentication_by_merchant_id_authentication_id(
@@ -100,6 +98,25 @@ pub async fn perform_post_authentication(
         .await
         .to_not_found_response(ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))?;
+    // The authentication connector may have been chosen among several authentication connectors
+    let authentication_connector = api_models::enums::AuthenticationConnectors::from_str(
+        &authentication.authentication_connector,
+    )
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid authentication_connector found in authentication record")?;
+    let three_ds_connector_account = utils::get_authentication_connector_account(
+        state,
+        key_store,
+        &business_profile,
+        authentication_connector,
+    )
+    .await?;
+    let is_pull_mechanism_enabled =
+        check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
+            three_ds_connector_account
+                .get_metadata()
+                .map(|metadata| metadata.expose()),
+        );
     if !authentication.authentication_status.is_terminal_status() && is_pull_mechanism_enabled {
         let router_data = transformers::construct_post_authentication_router_data(
             authentication_connector.to_string(),
@@ -124,9 +141,24 @@ pub async fn perform_pre_authentication(
     business_profile: &storage::BusinessProfile,
     acquirer_details: Option<types::AcquirerDetails>,
     payment_id: Option<String>,
+    authentication_data: Option<serde_json::Value>,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
-    let (authentication_connector, three_ds_connector_account) =
-        utils::get_authentication_connector_data(state, key_store, business_profile).await?;
+    let authentication_details = utils::get_authentication_connector_details(business_profile)?;
+    let card_bin = card_number.get_card_isin();
+    let authentication_connector = decision::select_authentication_connector(
+        state,
+        business_profile,
+        &authentication_details,
+        &card_bin,
+    )
+    .await?;
+    let three_ds_connector_account = utils::get_authentication_connector_account(
+        state,
+        key_store,
+        business_profile,
+        authentication_connector,
+    )
+    .await?;
     let authentication_connector_name = authentication_connector.to_string();
     let authentication = utils::create_new_authentication(
         state,
@@ -139,8 +171,10 @@ pub async fn perform_pre_authentication(
             .get_mca_id()
             .ok_or(ApiErrorResponse::InternalServerError)
             .attach_printable("Error while finding mca_id from merchant_connector_account")?,
+        authentication_data,
     )
     .await?;
+    decision::record_authentication_start(state, &authentication, &card_bin).await;
 
     let authentication = if authentication_connector.is_separate_version_call_required() {
         let router_data: core_types::authentication::PreAuthNVersionCallRouterData =
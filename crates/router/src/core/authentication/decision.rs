@@ -0,0 +1,302 @@
+use api_models::{
+    admin::AuthenticationConnectorDetails,
+    enums::{
+        AuthenticationConnectorSelection, AuthenticationConnectors, ThreeDsChallengePreference,
+    },
+};
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage,
+};
+
+/// Length of the windows the authentications of an issuer are counted in. The success rate of an
+/// authentication connector for an issuer is computed over the current and the previous window.
+const ISSUER_PERFORMANCE_WINDOW_IN_SECS: i64 = 24 * 60 * 60;
+
+/// Number of authentications of an issuer an authentication connector must have been used for,
+/// over the current and the previous window, before its success rate is taken into account
+const MIN_ISSUER_AUTHENTICATIONS: i64 = 20;
+
+/// The authentication and success counts of an authentication connector for an issuer
+#[derive(Debug, Clone, Copy, Default)]
+struct IssuerPerformance {
+    authentications: i64,
+    successes: i64,
+}
+
+impl IssuerPerformance {
+    fn has_enough_authentications(&self) -> bool {
+        self.authentications >= MIN_ISSUER_AUTHENTICATIONS
+    }
+
+    /// Whether the success rate is higher than the success rate of the other performance,
+    /// compared without dividing the counts
+    fn has_higher_success_rate_than(&self, other: &Self) -> bool {
+        self.successes.saturating_mul(other.authentications)
+            > other.successes.saturating_mul(self.authentications)
+    }
+}
+
+/// The challenge preference to send to the issuer for an authentication of the amount. A
+/// challenge is requested for the amounts from the challenge amount threshold of the profile.
+pub fn get_challenge_preference(
+    authentication_details: &AuthenticationConnectorDetails,
+    amount: Option<MinorUnit>,
+) -> ThreeDsChallengePreference {
+    let is_above_challenge_threshold = authentication_details
+        .challenge_amount_threshold
+        .zip(amount)
+        .is_some_and(|(threshold, amount)| amount >= threshold);
+
+    match authentication_details.challenge_preference {
+        Some(ThreeDsChallengePreference::ChallengeMandated) => {
+            ThreeDsChallengePreference::ChallengeMandated
+        }
+        _ if is_above_challenge_threshold => ThreeDsChallengePreference::ChallengeRequested,
+        challenge_preference => challenge_preference.unwrap_or_default(),
+    }
+}
+
+/// Chooses the authentication connector of an authentication of a card issued by the issuer of
+/// the card BIN.
+///
+/// When the profile selects the authentication connector by issuer performance, the
+/// authentication connectors that have not been used for enough authentications of the issuer
+/// yet are used first, in the order of the profile, so that their success rate can be measured.
+/// The authentication connector with the highest success rate for the issuer is used otherwise.
+#[instrument(skip_all)]
+pub async fn select_authentication_connector(
+    state: &SessionState,
+    business_profile: &storage::BusinessProfile,
+    authentication_details: &AuthenticationConnectorDetails,
+    card_bin: &str,
+) -> RouterResult<AuthenticationConnectors> {
+    let first_authentication_connector = authentication_details
+        .authentication_connectors
+        .first()
+        .copied()
+        .ok_or(errors::ApiErrorResponse::UnprocessableEntity {
+            message: format!(
+                "No authentication_connector found for profile_id {}",
+                business_profile.profile_id
+            ),
+        })
+        .attach_printable(
+            "No authentication_connector found from merchant_account.authentication_details",
+        )?;
+
+    let is_selected_by_issuer_performance = authentication_details.connector_selection
+        == Some(AuthenticationConnectorSelection::IssuerPerformance);
+    if !is_selected_by_issuer_performance
+        || authentication_details.authentication_connectors.len() < 2
+    {
+        return Ok(first_authentication_connector);
+    }
+
+    let mut best_authentication_connector: Option<(AuthenticationConnectors, IssuerPerformance)> =
+        None;
+    for authentication_connector in &authentication_details.authentication_connectors {
+        let Some(performance) = get_issuer_performance(
+            state,
+            &business_profile.merchant_id,
+            card_bin,
+            *authentication_connector,
+        )
+        .await
+        else {
+            return Ok(first_authentication_connector);
+        };
+        if !performance.has_enough_authentications() {
+            return Ok(*authentication_connector);
+        }
+        let is_best = best_authentication_connector.map_or(true, |(_, best)| {
+            performance.has_higher_success_rate_than(&best)
+        });
+        if is_best {
+            best_authentication_connector = Some((*authentication_connector, performance));
+        }
+    }
+
+    Ok(best_authentication_connector
+        .map(|(authentication_connector, _)| authentication_connector)
+        .unwrap_or(first_authentication_connector))
+}
+
+/// Counts a new authentication of the issuer of the card BIN with the authentication connector
+/// of the authentication, and remembers the card BIN of the authentication so that its outcome
+/// can be counted once known. Failures are logged without failing the authentication.
+#[instrument(skip_all)]
+pub async fn record_authentication_start(
+    state: &SessionState,
+    authentication: &storage::Authentication,
+    card_bin: &str,
+) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the issuer performance"
+            );
+            return;
+        }
+    };
+
+    if let Err(error) = redis_conn
+        .set_key_with_expiry(
+            &get_authentication_card_bin_key(&authentication.authentication_id),
+            card_bin,
+            ISSUER_PERFORMANCE_WINDOW_IN_SECS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to store the card BIN of the authentication");
+        return;
+    }
+    if let Err(error) = redis_conn
+        .increment_key_with_expiry(
+            &get_issuer_performance_key(
+                &authentication.merchant_id,
+                card_bin,
+                &authentication.authentication_connector,
+                get_current_window(),
+                "authentications",
+            ),
+            2 * ISSUER_PERFORMANCE_WINDOW_IN_SECS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to count the authentication of the issuer");
+    }
+}
+
+/// Feeds the outcome of the authentication back into the issuer performance of its
+/// authentication connector, once the authentication reaches a terminal status. Failures are
+/// logged without failing the authentication.
+#[instrument(skip_all)]
+pub async fn record_authentication_outcome(
+    state: &SessionState,
+    previous_status: common_enums::AuthenticationStatus,
+    authentication: &storage::Authentication,
+) {
+    if previous_status.is_terminal_status()
+        || authentication.authentication_status != common_enums::AuthenticationStatus::Success
+    {
+        return;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the issuer performance"
+            );
+            return;
+        }
+    };
+    // Authentications started before the issuer performance was recorded have no card BIN
+    let card_bin = match redis_conn
+        .get_key::<Option<String>>(&get_authentication_card_bin_key(
+            &authentication.authentication_id,
+        ))
+        .await
+    {
+        Ok(Some(card_bin)) => card_bin,
+        Ok(None) => return,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the card BIN of the authentication");
+            return;
+        }
+    };
+
+    if let Err(error) = redis_conn
+        .increment_key_with_expiry(
+            &get_issuer_performance_key(
+                &authentication.merchant_id,
+                &card_bin,
+                &authentication.authentication_connector,
+                get_current_window(),
+                "successes",
+            ),
+            2 * ISSUER_PERFORMANCE_WINDOW_IN_SECS,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to count the successful authentication of the issuer"
+        );
+    }
+}
+
+/// The counts of the authentication connector for the issuer over the current and the previous
+/// window, if they could be fetched
+async fn get_issuer_performance(
+    state: &SessionState,
+    merchant_id: &str,
+    card_bin: &str,
+    authentication_connector: AuthenticationConnectors,
+) -> Option<IssuerPerformance> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the issuer performance"
+            )
+        })
+        .ok()?;
+    let authentication_connector = authentication_connector.to_string();
+    let current_window = get_current_window();
+
+    let mut performance = IssuerPerformance::default();
+    for window in [current_window - 1, current_window] {
+        for (kind, count) in [
+            ("authentications", &mut performance.authentications),
+            ("successes", &mut performance.successes),
+        ] {
+            let value = redis_conn
+                .get_key::<Option<i64>>(&get_issuer_performance_key(
+                    merchant_id,
+                    card_bin,
+                    &authentication_connector,
+                    window,
+                    kind,
+                ))
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to fetch the issuer performance"))
+                .ok()?;
+            *count += value.unwrap_or_default();
+        }
+    }
+
+    Some(performance)
+}
+
+fn get_current_window() -> i64 {
+    common_utils::date_time::now().assume_utc().unix_timestamp() / ISSUER_PERFORMANCE_WINDOW_IN_SECS
+}
+
+fn get_issuer_performance_key(
+    merchant_id: &str,
+    card_bin: &str,
+    authentication_connector: &str,
+    window: i64,
+    kind: &str,
+) -> String {
+    format!(
+        "3ds_issuer_performance_{merchant_id}_{card_bin}_{connector}_{window}_{kind}",
+        connector = authentication_connector
+    )
+}
+
+fn get_authentication_card_bin_key(authentication_id: &str) -> String {
+    format!("3ds_authentication_card_bin_{authentication_id}")
+}
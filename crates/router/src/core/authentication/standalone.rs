@@ -0,0 +1,304 @@
+use std::str::FromStr;
+
+use api_models::{
+    authentication::{
+        AuthenticationAuthenticateRequest, AuthenticationCreateRequest, AuthenticationResponse,
+    },
+    enums::AuthenticationConnectors,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use super::{decision, types, utils};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::vault,
+        payments::helpers,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage, BrowserInformation},
+    utils::OptionExt,
+};
+
+/// The details of a standalone authentication kept on the authentication record, which are
+/// needed to authenticate the card once the pre-authentication is completed
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct StandaloneAuthenticationData {
+    amount: MinorUnit,
+    currency: common_enums::Currency,
+}
+
+/// Starts a 3DS authentication of a card, not linked to any payment, with an authentication
+/// connector of the profile. The card is kept in the temporary locker until it is authenticated.
+#[instrument(skip_all)]
+pub async fn create_authentication(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: AuthenticationCreateRequest,
+) -> RouterResponse<AuthenticationResponse> {
+    let business_profile = core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        Some(&request.profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?
+    .get_required_value("business_profile")?;
+    if request.amount <= MinorUnit::new(0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string(),
+        }));
+    }
+
+    let card_number = request.card.card_number.clone();
+    let token = vault::Vault::store_payment_method_data_in_locker(
+        &state,
+        None,
+        &api::PaymentMethodData::Card(request.card),
+        None,
+        common_enums::PaymentMethod::Card,
+        &key_store,
+    )
+    .await?;
+    let authentication_data = StandaloneAuthenticationData {
+        amount: request.amount,
+        currency: request.currency,
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize the standalone authentication data")?;
+    let acquirer_details =
+        request
+            .acquirer_details
+            .map(|acquirer_details| types::AcquirerDetails {
+                acquirer_bin: acquirer_details.acquirer_bin,
+                acquirer_merchant_id: acquirer_details.acquirer_merchant_id,
+                acquirer_country_code: acquirer_details.acquirer_country_code,
+            });
+
+    let authentication = super::perform_pre_authentication(
+        &state,
+        &key_store,
+        card_number,
+        token,
+        &business_profile,
+        acquirer_details,
+        None,
+        Some(authentication_data),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_authentication_response(
+        authentication,
+    )))
+}
+
+/// Authenticates the card of a standalone authentication whose pre-authentication is completed.
+/// The issuer either authenticates the card frictionlessly or requires a challenge of the
+/// cardholder, the outcome of which is received later on.
+#[instrument(skip_all)]
+pub async fn authenticate(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_id: String,
+    request: AuthenticationAuthenticateRequest,
+) -> RouterResponse<AuthenticationResponse> {
+    let db = state.store.as_ref();
+    let authentication =
+        find_standalone_authentication(db, &merchant_account.merchant_id, &authentication_id)
+            .await?;
+    if authentication.authentication_status != common_enums::AuthenticationStatus::Pending
+        || authentication.trans_status.is_some()
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The authentication cannot be performed when the status is {}",
+                authentication.authentication_status
+            ),
+        }));
+    }
+
+    let authentication_data: StandaloneAuthenticationData = authentication
+        .authentication_data
+        .clone()
+        .get_required_value("authentication_data")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .parse_value("StandaloneAuthenticationData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the standalone authentication data")?;
+    let token = authentication
+        .payment_method_id
+        .strip_prefix("eph_")
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payment_method_id found in authentication record")?;
+    let (payment_method_data, _) =
+        vault::Vault::get_payment_method_data_from_locker(&state, token, &key_store).await?;
+    let payment_method_data =
+        payment_method_data.ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The card of the authentication has expired".to_string(),
+        })?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&authentication.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: authentication.profile_id.clone(),
+        })?;
+    let authentication_details = utils::get_authentication_connector_details(&business_profile)?;
+    let authentication_connector =
+        AuthenticationConnectors::from_str(&authentication.authentication_connector)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid authentication_connector found in authentication record")?;
+    let merchant_connector_account = utils::get_authentication_connector_account(
+        &state,
+        &key_store,
+        &business_profile,
+        authentication_connector,
+    )
+    .await?;
+    let browser_info: Option<BrowserInformation> = request
+        .browser_info
+        .map(|browser_information| browser_information.parse_value("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+    let webhook_url = helpers::create_webhook_url(
+        &state.base_url,
+        &merchant_account.merchant_id,
+        &authentication.authentication_connector,
+    );
+    let challenge_preference = decision::get_challenge_preference(
+        &authentication_details,
+        Some(authentication_data.amount),
+    );
+
+    Box::pin(super::perform_authentication(
+        &state,
+        merchant_account.merchant_id.clone(),
+        authentication.authentication_connector.clone(),
+        payment_method_data,
+        common_enums::PaymentMethod::Card,
+        request.billing,
+        request.shipping,
+        browser_info,
+        merchant_connector_account,
+        Some(authentication_data.amount),
+        Some(authentication_data.currency),
+        api::authentication::MessageCategory::Payment,
+        request.device_channel,
+        authentication,
+        request.return_url,
+        request.sdk_information,
+        request.threeds_method_comp_ind,
+        request.email,
+        webhook_url,
+        authentication_details.three_ds_requestor_url,
+        challenge_preference,
+    ))
+    .await?;
+
+    let authentication =
+        find_standalone_authentication(db, &merchant_account.merchant_id, &authentication_id)
+            .await?;
+    Ok(ApplicationResponse::Json(get_authentication_response(
+        authentication,
+    )))
+}
+
+/// Retrieves a standalone authentication. The outcome of a challenge is fetched from the
+/// authentication connector when it is not pushed by the authentication connector.
+#[instrument(skip_all)]
+pub async fn retrieve_authentication(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_id: String,
+) -> RouterResponse<AuthenticationResponse> {
+    let db = state.store.as_ref();
+    let authentication =
+        find_standalone_authentication(db, &merchant_account.merchant_id, &authentication_id)
+            .await?;
+
+    let authentication = if authentication.trans_status
+        == Some(common_enums::TransactionStatus::ChallengeRequired)
+    {
+        let business_profile = db
+            .find_business_profile_by_profile_id(&authentication.profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: authentication.profile_id.clone(),
+            })?;
+        super::perform_post_authentication(&state, &key_store, business_profile, authentication_id)
+            .await?
+    } else {
+        authentication
+    };
+
+    Ok(ApplicationResponse::Json(get_authentication_response(
+        authentication,
+    )))
+}
+
+/// Fetches an authentication of the merchant that is not linked to a payment, the
+/// authentications of payments being performed through the payments API
+async fn find_standalone_authentication(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    authentication_id: &str,
+) -> RouterResult<storage::Authentication> {
+    let authentication = db
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_id.to_string(),
+            authentication_id.to_string(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
+            id: authentication_id.to_string(),
+        })?;
+    if authentication.payment_id.is_some() {
+        return Err(report!(errors::ApiErrorResponse::AuthenticationNotFound {
+            id: authentication_id.to_string(),
+        }));
+    }
+    Ok(authentication)
+}
+
+fn get_authentication_response(authentication: storage::Authentication) -> AuthenticationResponse {
+    AuthenticationResponse {
+        authentication_id: authentication.authentication_id,
+        merchant_id: authentication.merchant_id,
+        profile_id: authentication.profile_id,
+        authentication_connector: authentication.authentication_connector,
+        status: authentication.authentication_status,
+        authentication_type: authentication.authentication_type,
+        trans_status: authentication.trans_status,
+        authentication_value: authentication.cavv.map(Secret::new),
+        eci: authentication.eci,
+        message_version: authentication
+            .message_version
+            .map(|message_version| message_version.to_string()),
+        three_ds_server_trans_id: authentication.threeds_server_transaction_id,
+        ds_trans_id: authentication.ds_trans_id,
+        three_ds_method_data: authentication.three_ds_method_data,
+        three_ds_method_url: authentication.three_ds_method_url,
+        acs_url: authentication.acs_url,
+        challenge_request: authentication.challenge_request,
+        acs_reference_number: authentication.acs_reference_number,
+        acs_trans_id: authentication.acs_trans_id,
+        acs_signed_content: authentication.acs_signed_content,
+        error_code: authentication.error_code,
+        error_message: authentication.error_message,
+        created_at: authentication.created_at,
+    }
+}
@@ -45,6 +45,7 @@ pub fn construct_authentication_router_data(
     email: Option<common_utils::pii::Email>,
     webhook_url: String,
     three_ds_requestor_url: String,
+    challenge_preference: common_enums::ThreeDsChallengePreference,
 ) -> RouterResult<types::authentication::ConnectorAuthenticationRouterData> {
     let router_request = types::authentication::ConnectorAuthenticationRequestData {
         payment_method_data: From::from(payment_method_data),
@@ -64,6 +65,7 @@ pub fn construct_authentication_router_data(
         three_ds_requestor_url,
         threeds_method_comp_ind,
         webhook_url,
+        challenge_preference,
     };
     construct_router_data(
         authentication_connector,
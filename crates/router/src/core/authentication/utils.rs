@@ -54,6 +54,7 @@ pub async fn update_trackers<F: Clone, Req>(
     authentication: storage::Authentication,
     acquirer_details: Option<super::types::AcquirerDetails>,
 ) -> RouterResult<storage::Authentication> {
+    let previous_status = authentication.authentication_status;
     let authentication_update = match router_data.response {
         Ok(response) => match response {
             AuthenticationResponseData::PreAuthNResponse {
@@ -152,7 +153,7 @@ pub async fn update_trackers<F: Clone, Req>(
             error_code: Some(error.code),
         },
     };
-    state
+    let authentication = state
         .store
         .update_authentication_by_merchant_id_authentication_id(
             authentication,
@@ -160,7 +161,9 @@ pub async fn update_trackers<F: Clone, Req>(
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error while updating authentication")
+        .attach_printable("Error while updating authentication")?;
+    super::decision::record_authentication_outcome(state, previous_status, &authentication).await;
+    Ok(authentication)
 }
 
 impl ForeignFrom<common_enums::AuthenticationStatus> for common_enums::AttemptStatus {
@@ -182,6 +185,7 @@ pub async fn create_new_authentication(
     profile_id: String,
     payment_id: Option<String>,
     merchant_connector_id: String,
+    authentication_data: Option<serde_json::Value>,
 ) -> RouterResult<storage::Authentication> {
     let authentication_id =
         common_utils::generate_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
@@ -190,6 +194,7 @@ pub async fn create_new_authentication(
         merchant_id,
         authentication_connector,
         connector_authentication_id: None,
+        authentication_data,
         payment_method_id: format!("eph_{}", token),
         authentication_type: None,
         authentication_status: common_enums::AuthenticationStatus::Started,
@@ -264,52 +269,38 @@ where
     Ok(router_data)
 }
 
-pub async fn get_authentication_connector_data(
-    state: &SessionState,
-    key_store: &domain::MerchantKeyStore,
+pub fn get_authentication_connector_details(
     business_profile: &storage::BusinessProfile,
-) -> RouterResult<(
-    api_models::enums::AuthenticationConnectors,
-    payments::helpers::MerchantConnectorAccountType,
-)> {
-    let authentication_details: api_models::admin::AuthenticationConnectorDetails =
-        business_profile
-            .authentication_connector_details
-            .clone()
-            .get_required_value("authentication_details")
-            .change_context(errors::ApiErrorResponse::UnprocessableEntity {
-                message: "authentication_connector_details is not available in business profile"
-                    .into(),
-            })
-            .attach_printable("authentication_connector_details not configured by the merchant")?
-            .parse_value("AuthenticationConnectorDetails")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable(
-                "Error while parsing authentication_connector_details from business_profile",
-            )?;
-    let authentication_connector = authentication_details
-        .authentication_connectors
-        .first()
-        .ok_or(errors::ApiErrorResponse::UnprocessableEntity {
-            message: format!(
-                "No authentication_connector found for profile_id {}",
-                business_profile.profile_id
-            ),
+) -> RouterResult<api_models::admin::AuthenticationConnectorDetails> {
+    business_profile
+        .authentication_connector_details
+        .clone()
+        .get_required_value("authentication_details")
+        .change_context(errors::ApiErrorResponse::UnprocessableEntity {
+            message: "authentication_connector_details is not available in business profile".into(),
         })
+        .attach_printable("authentication_connector_details not configured by the merchant")?
+        .parse_value("AuthenticationConnectorDetails")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable(
-            "No authentication_connector found from merchant_account.authentication_details",
-        )?
-        .to_owned();
-    let profile_id = &business_profile.profile_id;
-    let authentication_connector_mca = payments::helpers::get_merchant_connector_account(
+            "Error while parsing authentication_connector_details from business_profile",
+        )
+}
+
+pub async fn get_authentication_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::BusinessProfile,
+    authentication_connector: api_models::enums::AuthenticationConnectors,
+) -> RouterResult<payments::helpers::MerchantConnectorAccountType> {
+    payments::helpers::get_merchant_connector_account(
         state,
         &business_profile.merchant_id,
         None,
         key_store,
-        profile_id,
+        &business_profile.profile_id,
         authentication_connector.to_string().as_str(),
         None,
     )
-    .await?;
-    Ok((authentication_connector, authentication_connector_mca))
+    .await
 }
@@ -0,0 +1,175 @@
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    errors::{CryptoError, CustomResult},
+};
+use masking::PeekInterface;
+
+use crate::types::domain;
+
+/// The blind indexes of the email and phone number of a customer, kept alongside the encrypted
+/// fields so that the customers can be searched by them without decrypting the customers
+#[derive(Debug, Default)]
+pub struct CustomerBlindIndexes {
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+/// The fields of the customers their blind indexes are kept for
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum BlindIndexField {
+    Email,
+    Phone,
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// The digits of the phone number preceded by the digits of its country code, so that the same
+/// number is indexed alike however it is formatted
+fn normalize_phone(phone: &str, phone_country_code: Option<&str>) -> String {
+    phone_country_code
+        .unwrap_or_default()
+        .chars()
+        .chain(phone.chars())
+        .filter(char::is_ascii_digit)
+        .collect()
+}
+
+/// Generates the blind index of the normalized value of a field of the customers of the merchant,
+/// the HMAC of the value keyed with the master key. The index is deterministic, so the customers
+/// can be looked up by the index of the value searched, and scoped to the merchant, so the same
+/// value gets different indexes for different merchants.
+fn generate_blind_index(
+    key: &[u8],
+    merchant_id: &str,
+    field: BlindIndexField,
+    normalized_value: &str,
+) -> CustomResult<Option<String>, CryptoError> {
+    if normalized_value.is_empty() {
+        return Ok(None);
+    }
+
+    HmacSha256
+        .sign_message(
+            key,
+            format!("blind_index:{merchant_id}:{field}:{normalized_value}").as_bytes(),
+        )
+        .map(|signature| Some(hex::encode(signature)))
+}
+
+/// Generates the blind index of the email of a customer of the merchant
+pub fn generate_email_blind_index(
+    key: &[u8],
+    merchant_id: &str,
+    email: &str,
+) -> CustomResult<Option<String>, CryptoError> {
+    generate_blind_index(
+        key,
+        merchant_id,
+        BlindIndexField::Email,
+        &normalize_email(email),
+    )
+}
+
+/// Generates the blind index of the phone number of a customer of the merchant
+pub fn generate_phone_blind_index(
+    key: &[u8],
+    merchant_id: &str,
+    phone: &str,
+    phone_country_code: Option<&str>,
+) -> CustomResult<Option<String>, CryptoError> {
+    generate_blind_index(
+        key,
+        merchant_id,
+        BlindIndexField::Phone,
+        &normalize_phone(phone, phone_country_code),
+    )
+}
+
+/// The blind indexes of the email and phone number of a new customer of the merchant
+pub fn get_customer_blind_indexes(
+    key: &[u8],
+    merchant_id: &str,
+    email: Option<&str>,
+    phone: Option<&str>,
+    phone_country_code: Option<&str>,
+) -> CustomResult<CustomerBlindIndexes, CryptoError> {
+    Ok(CustomerBlindIndexes {
+        email: email
+            .map(|email| generate_email_blind_index(key, merchant_id, email))
+            .transpose()?
+            .flatten(),
+        phone: phone
+            .map(|phone| generate_phone_blind_index(key, merchant_id, phone, phone_country_code))
+            .transpose()?
+            .flatten(),
+    })
+}
+
+/// The blind indexes of the email and phone number being updated of the customer, `None` for the
+/// fields left as is. The phone number is indexed along with its country code, so its index is
+/// updated when either is, with the other taken from the customer when not updated.
+pub fn get_customer_update_blind_indexes(
+    key: &[u8],
+    customer: &domain::Customer,
+    email: Option<&str>,
+    phone: Option<&str>,
+    phone_country_code: Option<&str>,
+) -> CustomResult<CustomerBlindIndexes, CryptoError> {
+    let phone = if phone.is_some() || phone_country_code.is_some() {
+        phone.or(customer
+            .phone
+            .as_ref()
+            .map(|phone| phone.get_inner().peek().as_str()))
+    } else {
+        None
+    };
+
+    get_customer_blind_indexes(
+        key,
+        &customer.merchant_id,
+        email,
+        phone,
+        phone_country_code.or(customer.phone_country_code.as_deref()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_blind_indexes_of_equivalent_values_match() {
+        let key = b"blind_index_test_key";
+        assert_eq!(
+            generate_email_blind_index(key, "merchant_1", " John.Doe@Example.com ").unwrap(),
+            generate_email_blind_index(key, "merchant_1", "john.doe@example.com").unwrap()
+        );
+        assert_eq!(
+            generate_phone_blind_index(key, "merchant_1", "999 999-9999", Some("+1")).unwrap(),
+            generate_phone_blind_index(key, "merchant_1", "9999999999", Some("1")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_blind_indexes_are_scoped_to_the_merchant_and_field() {
+        let key = b"blind_index_test_key";
+        let email_index = generate_email_blind_index(key, "merchant_1", "123456").unwrap();
+        assert!(email_index.is_some());
+        assert_ne!(
+            email_index,
+            generate_email_blind_index(key, "merchant_2", "123456").unwrap()
+        );
+        assert_ne!(
+            email_index,
+            generate_phone_blind_index(key, "merchant_1", "123456", None).unwrap()
+        );
+        assert_eq!(
+            generate_phone_blind_index(key, "merchant_1", "", None).unwrap(),
+            None
+        );
+    }
+}
@@ -15,9 +15,14 @@ pub async fn add_entry_to_blocklist(
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(&state, merchant_account.merchant_id, body)
-        .await
-        .map(services::ApplicationResponse::Json)
+    utils::insert_entry_into_blocklist(
+        &state,
+        merchant_account.merchant_id,
+        body,
+        common_enums::BlocklistListType::Block,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
 }
 
 pub async fn remove_entry_from_blocklist(
@@ -25,9 +30,14 @@ pub async fn remove_entry_from_blocklist(
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::DeleteFromBlocklistRequest,
 ) -> RouterResponse<api_blocklist::DeleteFromBlocklistResponse> {
-    utils::delete_entry_from_blocklist(&state, merchant_account.merchant_id, body)
-        .await
-        .map(services::ApplicationResponse::Json)
+    utils::delete_entry_from_blocklist(
+        &state,
+        merchant_account.merchant_id,
+        body,
+        common_enums::BlocklistListType::Block,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
 }
 
 pub async fn list_blocklist_entries(
@@ -35,9 +45,59 @@ pub async fn list_blocklist_entries(
     merchant_account: domain::MerchantAccount,
     query: api_blocklist::ListBlocklistQuery,
 ) -> RouterResponse<Vec<api_blocklist::BlocklistResponse>> {
-    utils::list_blocklist_entries_for_merchant(&state, merchant_account.merchant_id, query)
-        .await
-        .map(services::ApplicationResponse::Json)
+    utils::list_blocklist_entries_for_merchant(
+        &state,
+        merchant_account.merchant_id,
+        query,
+        common_enums::BlocklistListType::Block,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+pub async fn add_entry_to_allowlist(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    body: api_blocklist::AddToBlocklistRequest,
+) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
+    utils::insert_entry_into_blocklist(
+        &state,
+        merchant_account.merchant_id,
+        body,
+        common_enums::BlocklistListType::Allow,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+pub async fn remove_entry_from_allowlist(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    body: api_blocklist::DeleteFromBlocklistRequest,
+) -> RouterResponse<api_blocklist::DeleteFromBlocklistResponse> {
+    utils::delete_entry_from_blocklist(
+        &state,
+        merchant_account.merchant_id,
+        body,
+        common_enums::BlocklistListType::Allow,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
+pub async fn list_allowlist_entries(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: api_blocklist::ListBlocklistQuery,
+) -> RouterResponse<Vec<api_blocklist::BlocklistResponse>> {
+    utils::list_blocklist_entries_for_merchant(
+        &state,
+        merchant_account.merchant_id,
+        query,
+        common_enums::BlocklistListType::Allow,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
 }
 
 pub async fn toggle_blocklist_guard(
@@ -27,6 +27,7 @@ impl ForeignFrom<storage::Blocklist> for blocklist::AddToBlocklistResponse {
         Self {
             fingerprint_id: from.fingerprint_id,
             data_kind: from.data_kind,
+            list_type: from.list_type,
             created_at: from.created_at,
         }
     }
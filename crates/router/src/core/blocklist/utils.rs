@@ -1,9 +1,17 @@
+use std::{net::IpAddr, str::FromStr};
+
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    crypto::{self, SignMessage},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+    id_type,
+    pii::Email,
+};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -12,8 +20,9 @@ use crate::{
         errors::{RouterResult, StorageErrorExt},
         payments::PaymentData,
     },
+    events::audit_events::{AuditEvent, AuditEventType},
     logger,
-    types::{domain, storage, transformers::ForeignInto},
+    types::{domain, storage, transformers::ForeignInto, BrowserInformation},
     utils,
 };
 
@@ -21,24 +30,28 @@ pub async fn delete_entry_from_blocklist(
     state: &SessionState,
     merchant_id: String,
     request: api_blocklist::DeleteFromBlocklistRequest,
+    list_type: common_enums::BlocklistListType,
 ) -> RouterResult<api_blocklist::DeleteFromBlocklistResponse> {
-    let blocklist_entry = match request {
-        api_blocklist::DeleteFromBlocklistRequest::CardBin(bin) => {
-            delete_card_bin_blocklist_entry(state, &bin, &merchant_id).await?
-        }
+    let (fingerprint_id, _) = get_list_entry_fingerprint(state, &merchant_id, &request).await?;
+    let not_found_error = errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("no {list_type} list record for the given data was found"),
+    };
 
-        api_blocklist::DeleteFromBlocklistRequest::ExtendedCardBin(xbin) => {
-            delete_card_bin_blocklist_entry(state, &xbin, &merchant_id).await?
-        }
+    // An entry is only removed from the list that it was added to
+    let blocklist_entry = state
+        .store
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(not_found_error.clone())?;
+    if blocklist_entry.list_type != list_type {
+        return Err(not_found_error.into());
+    }
 
-        api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => state
-            .store
-            .delete_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, &fingerprint_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-                message: "no blocklist record for the given fingerprint id was found".to_string(),
-            })?,
-    };
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(not_found_error)?;
 
     Ok(blocklist_entry.foreign_into())
 }
@@ -97,12 +110,14 @@ pub async fn list_blocklist_entries_for_merchant(
     state: &SessionState,
     merchant_id: String,
     query: api_blocklist::ListBlocklistQuery,
+    list_type: common_enums::BlocklistListType,
 ) -> RouterResult<Vec<api_blocklist::BlocklistResponse>> {
     state
         .store
-        .list_blocklist_entries_by_merchant_id_data_kind(
+        .list_blocklist_entries_by_merchant_id_data_kind_list_type(
             &merchant_id,
             query.data_kind,
+            list_type,
             query.limit.into(),
             query.offset.into(),
         )
@@ -137,167 +152,241 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
-pub async fn insert_entry_into_blocklist(
+fn validate_customer_id(customer_id: &str) -> RouterResult<()> {
+    if !customer_id.is_empty() && customer_id.len() <= consts::MAX_ID_LENGTH {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: format!(
+                "a customer id of at most {} characters",
+                consts::MAX_ID_LENGTH
+            ),
+        }
+        .into())
+    }
+}
+
+/// Provides the fingerprint with which the data of the request is stored in the lists, along with
+/// the kind of the data. Emails and IP addresses are not stored in plain text, and are hashed with
+/// the fingerprint secret of the merchant.
+async fn get_list_entry_fingerprint(
     state: &SessionState,
-    merchant_id: String,
-    to_block: api_blocklist::AddToBlocklistRequest,
-) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
+    merchant_id: &str,
+    request: &api_blocklist::BlocklistRequest,
+) -> RouterResult<(String, common_enums::BlocklistDataKind)> {
+    match request {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                &merchant_id,
-                common_enums::BlocklistDataKind::CardBin,
-            )
-            .await?
+            Ok((bin.clone(), common_enums::BlocklistDataKind::CardBin))
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                &merchant_id,
+            Ok((
+                bin.clone(),
                 common_enums::BlocklistDataKind::ExtendedCardBin,
-            )
-            .await?
+            ))
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
-            let blocklist_entry_result = state
-                .store
-                .find_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, fingerprint_id)
-                .await;
-
-            match blocklist_entry_result {
-                Ok(_) => {
-                    return Err(errors::ApiErrorResponse::PreconditionFailed {
-                        message: "data associated with the given fingerprint is already blocked"
-                            .to_string(),
-                    }
-                    .into());
-                }
-
-                // if it is a db not found error, we can proceed as normal
-                Err(inner) if inner.current_context().is_db_not_found() => {}
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => Ok((
+            fingerprint_id.clone(),
+            common_enums::BlocklistDataKind::PaymentMethod,
+        )),
+
+        api_blocklist::BlocklistRequest::CustomerId(customer_id) => {
+            validate_customer_id(customer_id)?;
+            Ok((
+                customer_id.clone(),
+                common_enums::BlocklistDataKind::CustomerId,
+            ))
+        }
 
-                err @ Err(_) => {
-                    err.change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("error fetching blocklist entry from table")?;
-                }
-            }
+        api_blocklist::BlocklistRequest::Email(email) => {
+            let email = Email::from_str(email.trim()).change_context(
+                errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "a valid email address".to_string(),
+                },
+            )?;
+            let fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+            Ok((
+                generate_data_fingerprint(&fingerprint_secret, &normalize_email(email.peek()))?,
+                common_enums::BlocklistDataKind::Email,
+            ))
+        }
 
-            state
-                .store
-                .insert_blocklist_entry(storage::BlocklistNew {
-                    merchant_id: merchant_id.clone(),
-                    fingerprint_id: fingerprint_id.clone(),
-                    data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
-                    metadata: None,
-                    created_at: common_utils::date_time::now(),
-                })
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to add fingerprint to blocklist")?
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => {
+            let ip_address = IpAddr::from_str(ip_address.trim()).change_context(
+                errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "a valid IPv4 or IPv6 address".to_string(),
+                },
+            )?;
+            let fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+            Ok((
+                generate_data_fingerprint(&fingerprint_secret, &ip_address.to_string())?,
+                common_enums::BlocklistDataKind::IpAddress,
+            ))
         }
-    };
-    Ok(blocklist_entry.foreign_into())
+    }
 }
 
-pub async fn get_merchant_fingerprint_secret(
+/// Provides the kinds of the data of the customer which would get their payments blocked, entries
+/// in the allow list taking precedence over the entries in the block list as they do for payments.
+pub async fn get_blocked_customer_data_kinds(
     state: &SessionState,
     merchant_id: &str,
-) -> RouterResult<String> {
-    let key = get_merchant_fingerprint_secret_key(merchant_id);
-    let config_fetch_result = state.store.find_config_by_key(&key).await;
-
-    match config_fetch_result {
-        Ok(config) => Ok(config.config),
-
-        Err(e) if e.current_context().is_db_not_found() => {
-            let new_fingerprint_secret =
-                utils::generate_id(consts::FINGERPRINT_SECRET_LENGTH, "fs");
-            let new_config = storage::ConfigNew {
-                key,
-                config: new_fingerprint_secret.clone(),
-            };
+    customer_id: &id_type::CustomerId,
+    email: Option<&Email>,
+) -> RouterResult<Vec<common_enums::BlocklistDataKind>> {
+    let db = &state.store;
+    let email_fingerprint = match email {
+        Some(email) => {
+            let fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+            Some(generate_data_fingerprint(
+                &fingerprint_secret,
+                &normalize_email(email.peek()),
+            )?)
+        }
+        None => None,
+    };
 
-            state
-                .store
-                .insert_config(new_config)
-                .await
+    let fingerprints = [
+        Some(customer_id.get_string_repr().to_string()),
+        email_fingerprint,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    let mut list_hits = Vec::new();
+    for fingerprint in fingerprints {
+        match db
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint)
+            .await
+        {
+            Ok(blocklist_entry) => list_hits.push(blocklist_entry),
+            Err(e) if e.current_context().is_db_not_found() => {}
+            Err(e) => Err(e)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("unable to create new fingerprint secret for merchant")?;
-
-            Ok(new_fingerprint_secret)
+                .attach_printable("failed to look up the customer in the blocklist")?,
         }
+    }
 
-        Err(e) => Err(e)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("error fetching merchant fingerprint secret"),
+    let is_allow_listed = list_hits
+        .iter()
+        .any(|list_hit| list_hit.list_type == common_enums::BlocklistListType::Allow);
+    if is_allow_listed {
+        return Ok(Vec::new());
     }
+
+    Ok(list_hits
+        .into_iter()
+        .filter(|list_hit| list_hit.list_type == common_enums::BlocklistListType::Block)
+        .map(|list_hit| list_hit.data_kind)
+        .collect())
 }
 
-fn get_merchant_fingerprint_secret_key(merchant_id: &str) -> String {
-    format!("fingerprint_secret_{merchant_id}")
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+fn generate_data_fingerprint(fingerprint_secret: &str, data: &str) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(fingerprint_secret.as_bytes(), data.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error generating fingerprint for the blocklist data")
+        .map(hex::encode)
+}
+
+pub async fn insert_entry_into_blocklist(
     state: &SessionState,
-    merchant_id: &str,
-    data_kind: common_enums::BlocklistDataKind,
-) -> RouterResult<storage::Blocklist> {
+    merchant_id: String,
+    to_block: api_blocklist::AddToBlocklistRequest,
+    list_type: common_enums::BlocklistListType,
+) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
+    let (fingerprint_id, data_kind) =
+        get_list_entry_fingerprint(state, &merchant_id, &to_block).await?;
+
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, &fingerprint_id)
         .await;
 
     match blocklist_entry_result {
-        Ok(_) => {
+        Ok(blocklist_entry) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: format!(
+                    "the given data is already present in the {} list",
+                    blocklist_entry.list_type
+                ),
             }
             .into());
         }
 
-        Err(e) if e.current_context().is_db_not_found() => {}
+        // if it is a db not found error, we can proceed as normal
+        Err(inner) if inner.current_context().is_db_not_found() => {}
 
         err @ Err(_) => {
-            return err
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("unable to fetch blocklist entry");
+            err.change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("error fetching blocklist entry from table")?;
         }
     }
 
-    state
+    let blocklist_entry = state
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
-            merchant_id: merchant_id.to_string(),
-            fingerprint_id: bin.to_string(),
+            merchant_id,
+            fingerprint_id,
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
+            list_type,
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error inserting pm blocklist item")
+        .attach_printable("failed to add entry to the list")?;
+
+    Ok(blocklist_entry.foreign_into())
 }
 
-async fn delete_card_bin_blocklist_entry(
+pub async fn get_merchant_fingerprint_secret(
     state: &SessionState,
-    bin: &str,
     merchant_id: &str,
-) -> RouterResult<storage::Blocklist> {
-    state
-        .store
-        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "could not find a blocklist entry for the given bin".to_string(),
-        })
+) -> RouterResult<String> {
+    let key = get_merchant_fingerprint_secret_key(merchant_id);
+    let config_fetch_result = state.store.find_config_by_key(&key).await;
+
+    match config_fetch_result {
+        Ok(config) => Ok(config.config),
+
+        Err(e) if e.current_context().is_db_not_found() => {
+            let new_fingerprint_secret =
+                utils::generate_id(consts::FINGERPRINT_SECRET_LENGTH, "fs");
+            let new_config = storage::ConfigNew {
+                key,
+                config: new_fingerprint_secret.clone(),
+            };
+
+            state
+                .store
+                .insert_config(new_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to create new fingerprint secret for merchant")?;
+
+            Ok(new_fingerprint_secret)
+        }
+
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("error fetching merchant fingerprint secret"),
+    }
+}
+
+fn get_merchant_fingerprint_secret_key(merchant_id: &str) -> String {
+    format!("fingerprint_secret_{merchant_id}")
 }
 
 pub async fn validate_data_for_blocklist<F>(
@@ -361,45 +450,91 @@ where
                 _ => None,
             });
 
-    //validating the payment method.
-    let mut blocklist_futures = Vec::new();
-    if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            card_number_fingerprint,
-        ));
-    }
-
-    if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(
-            db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-                merchant_id,
-                card_bin_fingerprint,
-            ),
-        );
-    }
-
-    if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            extended_card_bin_fingerprint,
-        ));
-    }
+    // Hashed email to check whether or not this payment should be blocked.
+    let email_fingerprint = payment_data
+        .email
+        .as_ref()
+        .map(|email| {
+            generate_data_fingerprint(&merchant_fingerprint_secret, &normalize_email(email.peek()))
+        })
+        .transpose()?;
+
+    // Hashed IP address to check whether or not this payment should be blocked.
+    let ip_address_fingerprint = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<BrowserInformation>("BrowserInformation")
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| {
+            generate_data_fingerprint(&merchant_fingerprint_secret, &ip_address.to_string())
+        })
+        .transpose()?;
 
-    let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
+    let customer_id = payment_data
+        .payment_intent
+        .customer_id
+        .as_ref()
+        .map(|customer_id| customer_id.get_string_repr().to_string());
 
-    let mut should_payment_be_blocked = false;
+    //validating the payment method.
+    let fingerprints = [
+        card_number_fingerprint,
+        card_bin_fingerprint,
+        extended_card_bin_fingerprint,
+        email_fingerprint,
+        customer_id,
+        ip_address_fingerprint,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    let blocklist_lookups = futures::future::join_all(fingerprints.iter().map(|fingerprint| {
+        db.find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint)
+    }))
+    .await;
+
+    let mut list_hits = Vec::new();
     for lookup in blocklist_lookups {
         match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
-            }
+            Ok(blocklist_entry) => list_hits.push(blocklist_entry),
+            Err(e) if e.current_context().is_db_not_found() => {}
             Err(e) => {
                 logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
             }
         }
     }
-    if should_payment_be_blocked {
+
+    let event_context = state.get_req_state().event_context;
+    for list_hit in &list_hits {
+        event_context
+            .event(AuditEvent::new(AuditEventType::BlocklistHit {
+                list_type: list_hit.list_type,
+                data_kind: list_hit.data_kind.clone(),
+                fingerprint_id: list_hit.fingerprint_id.clone(),
+            }))
+            .with(payment_data.to_event())
+            .emit();
+    }
+
+    // Entries in the allow list take precedence over the entries in the block list
+    let is_allow_listed = list_hits
+        .iter()
+        .any(|list_hit| list_hit.list_type == common_enums::BlocklistListType::Allow);
+    let blocking_entry = list_hits
+        .iter()
+        .find(|list_hit| list_hit.list_type == common_enums::BlocklistListType::Block)
+        .filter(|_| !is_allow_listed);
+
+    if let Some(blocking_entry) = blocking_entry {
+        let (decline_code, decline_message) =
+            get_blocklist_decline_details(&blocking_entry.data_kind);
+
         // Update db for attempt and intent status.
         db.update_payment_intent(
             payment_data.payment_intent.clone(),
@@ -421,7 +556,7 @@ where
         let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
             status: common_enums::AttemptStatus::Failure,
             error_code: Some(Some("HE-03".to_string())),
-            error_message: Some(Some("This payment method is blocked".to_string())),
+            error_message: Some(Some(decline_message.to_string())),
             updated_by: merchant_account.storage_scheme.to_string(),
         };
         db.update_payment_attempt_with_attempt_id(
@@ -437,9 +572,9 @@ where
 
         Err(errors::ApiErrorResponse::PaymentBlockedError {
             code: 200,
-            message: "This payment method is blocked".to_string(),
+            message: decline_message.to_string(),
             status: "Failed".to_string(),
-            reason: "Blocked".to_string(),
+            reason: decline_code.to_string(),
         }
         .into())
     } else {
@@ -453,6 +588,35 @@ where
     }
 }
 
+/// Provides the standardized decline code and message for the payments declined due to a block
+/// list entry of the given kind
+fn get_blocklist_decline_details(
+    data_kind: &common_enums::BlocklistDataKind,
+) -> (&'static str, &'static str) {
+    match data_kind {
+        common_enums::BlocklistDataKind::PaymentMethod => {
+            ("blocked_payment_method", "This payment method is blocked")
+        }
+        common_enums::BlocklistDataKind::CardBin
+        | common_enums::BlocklistDataKind::ExtendedCardBin => (
+            "blocked_card_bin",
+            "The card bin of this payment method is blocked",
+        ),
+        common_enums::BlocklistDataKind::Email => (
+            "blocked_email",
+            "The email associated with this payment is blocked",
+        ),
+        common_enums::BlocklistDataKind::CustomerId => (
+            "blocked_customer",
+            "The customer associated with this payment is blocked",
+        ),
+        common_enums::BlocklistDataKind::IpAddress => (
+            "blocked_ip_address",
+            "The IP address of this payment is blocked",
+        ),
+    }
+}
+
 pub async fn generate_payment_fingerprint(
     state: &SessionState,
     merchant_id: String,
@@ -0,0 +1,390 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use api_models::{
+    bulk_force_sync::{
+        BulkForceSyncRequest, BulkForceSyncResponse, BulkForceSyncStatus, BulkForceSyncSummary,
+    },
+    enums as api_enums,
+    payments::{HeaderPayload, PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
+};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{payments_core, CallConnectorAction, PaymentStatus},
+    },
+    routes::{metrics, SessionState},
+    services::{ApplicationResponse, AuthFlow},
+    types::{api::PSync, domain, storage},
+};
+
+pub const BULK_FORCE_SYNC_TASK: &str = "BULK_FORCE_SYNC";
+pub const BULK_FORCE_SYNC_TAG: &str = "BULK_FORCE_SYNC";
+
+/// Number of minutes a payment attempt must have been left untouched before it is considered
+/// stuck, when not provided in the request
+const DEFAULT_STUCK_PAYMENT_AGE_MINUTES: u32 = 60;
+
+/// Number of payment attempts fetched from the database at a time, when not provided in the
+/// request
+const DEFAULT_BULK_FORCE_SYNC_BATCH_SIZE: u16 = 100;
+
+const MAX_BULK_FORCE_SYNC_BATCH_SIZE: u16 = 1000;
+
+/// Number of payments synced with each connector every second, when not provided in the request
+const DEFAULT_MAX_SYNCS_PER_SECOND: u16 = 5;
+
+/// The statuses of the payment attempts considered stuck, when not provided in the request
+const DEFAULT_STUCK_ATTEMPT_STATUSES: [enums::AttemptStatus; 2] = [
+    enums::AttemptStatus::Pending,
+    enums::AttemptStatus::Authorizing,
+];
+
+/// Schedules the sync of the payments stuck in a non-terminal status with their connectors, for
+/// the payments of every merchant unless a merchant is provided
+#[instrument(skip_all)]
+pub async fn schedule_bulk_force_sync(
+    state: SessionState,
+    request: BulkForceSyncRequest,
+) -> RouterResponse<BulkForceSyncResponse> {
+    let db = &*state.store;
+    let batch_size = request
+        .batch_size
+        .unwrap_or(DEFAULT_BULK_FORCE_SYNC_BATCH_SIZE);
+    if batch_size == 0 || batch_size > MAX_BULK_FORCE_SYNC_BATCH_SIZE {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("batch_size must be between 1 and {MAX_BULK_FORCE_SYNC_BATCH_SIZE}"),
+        }));
+    }
+    let max_syncs_per_second = request
+        .max_syncs_per_second
+        .unwrap_or(DEFAULT_MAX_SYNCS_PER_SECOND);
+    if max_syncs_per_second == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "max_syncs_per_second must be greater than 0".to_string(),
+        }));
+    }
+
+    if let Some(merchant_id) = &request.merchant_id {
+        db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    }
+
+    let now = common_utils::date_time::now();
+    let older_than_minutes = request
+        .older_than_minutes
+        .unwrap_or(DEFAULT_STUCK_PAYMENT_AGE_MINUTES);
+    let modified_before =
+        now.saturating_sub(time::Duration::minutes(i64::from(older_than_minutes)));
+
+    let tracking_data = storage::BulkForceSyncTrackingData {
+        merchant_id: request.merchant_id,
+        connectors: request
+            .connectors
+            .unwrap_or_default()
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        statuses: request
+            .statuses
+            .filter(|statuses| !statuses.is_empty())
+            .unwrap_or_else(|| DEFAULT_STUCK_ATTEMPT_STATUSES.to_vec()),
+        modified_before,
+        batch_size,
+        max_syncs_per_second,
+        summary: None,
+    };
+    let job_id = format!(
+        "{runner}_{task}_{timestamp}",
+        runner = storage::ProcessTrackerRunner::BulkForceSyncWorkflow,
+        task = BULK_FORCE_SYNC_TASK,
+        timestamp = now.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        job_id.clone(),
+        BULK_FORCE_SYNC_TASK,
+        storage::ProcessTrackerRunner::BulkForceSyncWorkflow,
+        [BULK_FORCE_SYNC_TAG],
+        tracking_data,
+        now,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct bulk force sync process tracker task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A bulk force sync was scheduled within the last second".to_string(),
+        })?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "BulkForceSync")]),
+    );
+
+    Ok(ApplicationResponse::Json(BulkForceSyncResponse {
+        job_id,
+        status: BulkForceSyncStatus::Scheduled,
+        modified_before,
+        scheduled_at: now,
+        completed_at: None,
+        summary: None,
+    }))
+}
+
+/// Retrieves the status of the bulk force sync, along with its summary once completed
+#[instrument(skip_all)]
+pub async fn retrieve_bulk_force_sync(
+    state: SessionState,
+    job_id: String,
+) -> RouterResponse<BulkForceSyncResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&job_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the bulk force sync task")?
+        .filter(|process| {
+            process.runner.as_deref()
+                == Some(
+                    storage::ProcessTrackerRunner::BulkForceSyncWorkflow
+                        .to_string()
+                        .as_str(),
+                )
+        })
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Bulk force sync {job_id} does not exist"),
+            })
+        })?;
+
+    get_bulk_force_sync_response(&process).map(ApplicationResponse::Json)
+}
+
+fn get_bulk_force_sync_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<BulkForceSyncResponse> {
+    let tracking_data: storage::BulkForceSyncTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("BulkForceSyncTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let status = match process.status {
+        enums::ProcessTrackerStatus::New | enums::ProcessTrackerStatus::Paused => {
+            BulkForceSyncStatus::Scheduled
+        }
+        enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::ProcessStarted => BulkForceSyncStatus::InProgress,
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            BulkForceSyncStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => BulkForceSyncStatus::Failed,
+    };
+
+    Ok(BulkForceSyncResponse {
+        job_id: process.id.clone(),
+        status,
+        modified_before: tracking_data.modified_before,
+        scheduled_at: process.created_at,
+        completed_at: (process.status == enums::ProcessTrackerStatus::Finish)
+            .then_some(process.updated_at),
+        summary: tracking_data.summary,
+    })
+}
+
+/// Force syncs the stuck payments with their connectors, batch by batch. The payments of a batch
+/// are synced with the different connectors concurrently, each connector being sent at most the
+/// number of syncs per second of the job. Payments that fail to be synced are skipped.
+#[instrument(skip_all)]
+pub async fn run_bulk_force_sync(
+    state: &SessionState,
+    tracking_data: &storage::BulkForceSyncTrackingData,
+) -> RouterResult<BulkForceSyncSummary> {
+    let db = &*state.store;
+    let mut summary = BulkForceSyncSummary::default();
+    let mut merchant_contexts = HashMap::new();
+    let mut starting_after: Option<String> = None;
+    let limit = i64::from(tracking_data.batch_size);
+    let sync_interval =
+        Duration::from_secs(1) / u32::from(tracking_data.max_syncs_per_second.max(1));
+
+    loop {
+        let attempts = db
+            .list_stuck_payment_attempts(
+                tracking_data.merchant_id.as_deref(),
+                &tracking_data.connectors,
+                &tracking_data.statuses,
+                tracking_data.modified_before,
+                starting_after.as_deref(),
+                limit,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the stuck payment attempts")?;
+        let is_last_page = i64::try_from(attempts.len()).map_or(true, |count| count < limit);
+        starting_after = attempts.last().map(|attempt| attempt.attempt_id.clone());
+
+        // A payment is synced once even when several of its attempts are stuck
+        let mut payment_ids = HashSet::new();
+        let mut payments_by_connector = HashMap::<String, Vec<(String, String)>>::new();
+        for attempt in attempts {
+            summary.found_count += 1;
+            let Some(connector) = attempt.connector else {
+                continue;
+            };
+            if !payment_ids.insert((attempt.merchant_id.clone(), attempt.payment_id.clone())) {
+                continue;
+            }
+            if !merchant_contexts.contains_key(&attempt.merchant_id) {
+                let merchant_context = get_merchant_context(state, &attempt.merchant_id)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(
+                            ?error,
+                            merchant_id = %attempt.merchant_id,
+                            "Failed to fetch the merchant of the stuck payments"
+                        );
+                    })
+                    .ok();
+                merchant_contexts.insert(attempt.merchant_id.clone(), merchant_context);
+            }
+            payments_by_connector
+                .entry(connector)
+                .or_default()
+                .push((attempt.merchant_id, attempt.payment_id));
+        }
+
+        let batch_merchant_contexts = &merchant_contexts;
+        let connector_results = futures::future::join_all(payments_by_connector.into_iter().map(
+            |(connector, payments)| async move {
+                let mut results = Vec::with_capacity(payments.len());
+                for (merchant_id, payment_id) in payments {
+                    let result = match batch_merchant_contexts.get(&merchant_id) {
+                        Some(Some((merchant_account, key_store))) => {
+                            let result =
+                                force_sync_payment(state, merchant_account, key_store, &payment_id)
+                                    .await;
+                            tokio::time::sleep(sync_interval).await;
+                            result
+                        }
+                        _ => Err(report!(errors::ApiErrorResponse::MerchantAccountNotFound)),
+                    };
+                    if let Err(error) = &result {
+                        logger::error!(
+                            ?error,
+                            %merchant_id,
+                            %payment_id,
+                            %connector,
+                            "Failed to force sync the stuck payment"
+                        );
+                    }
+                    results.push(result);
+                }
+                (connector, results)
+            },
+        ))
+        .await;
+
+        for (connector, results) in connector_results {
+            let connector_summary = summary.connectors.entry(connector).or_default();
+            for result in results {
+                match result {
+                    Ok(status) => {
+                        summary.synced_count += 1;
+                        connector_summary.synced_count += 1;
+                        if status != api_enums::IntentStatus::Processing {
+                            summary.resolved_count += 1;
+                            connector_summary.resolved_count += 1;
+                        }
+                        *summary.statuses.entry(status).or_default() += 1;
+                    }
+                    Err(_) => {
+                        summary.failed_count += 1;
+                        connector_summary.failed_count += 1;
+                    }
+                }
+            }
+        }
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    metrics::PAYMENTS_FORCE_SYNCED_IN_BULK.add(&metrics::CONTEXT, summary.synced_count, &[]);
+
+    Ok(summary)
+}
+
+async fn get_merchant_context(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<(domain::MerchantAccount, domain::MerchantKeyStore)> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    Ok((merchant_account, key_store))
+}
+
+/// Syncs the payment with its connector, returning the status of the payment once synced
+async fn force_sync_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+) -> RouterResult<api_enums::IntentStatus> {
+    let request = PaymentsRetrieveRequest {
+        resource_id: PaymentIdType::PaymentIntentId(payment_id.to_string()),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        force_sync: true,
+        ..Default::default()
+    };
+
+    match Box::pin(payments_core::<PSync, PaymentsResponse, _, _, _>(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        PaymentStatus,
+        request,
+        AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(payments_response)
+        | ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response.status)
+        }
+        ApplicationResponse::StatusOk
+        | ApplicationResponse::TextPlain(_)
+        | ApplicationResponse::JsonForRedirection(_)
+        | ApplicationResponse::Form(_)
+        | ApplicationResponse::GenericLinkForm(_)
+        | ApplicationResponse::PaymentLinkForm(_)
+        | ApplicationResponse::FileData(_) => {
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response from the payment sync")
+        }
+    }
+}
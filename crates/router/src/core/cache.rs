@@ -1,8 +1,11 @@
+use api_models::cache as cache_api;
 use common_utils::errors::CustomResult;
 use error_stack::{report, ResultExt};
-use storage_impl::redis::cache::{publish_into_redact_channel, CacheKind};
+use storage_impl::redis::cache::{
+    self as in_memory_cache, publish_into_redact_channel, CacheKey, CacheKind,
+};
 
-use super::errors;
+use super::errors::{self, RouterResponse};
 use crate::{routes::SessionState, services};
 
 pub async fn invalidate(
@@ -26,3 +29,102 @@ pub async fn invalidate(
             .attach_printable("Failed to invalidate cache"))
     }
 }
+
+/// The limits and the number of entries of the in-memory caches of the instance serving the
+/// request
+pub async fn list_in_memory_caches(
+    _state: SessionState,
+) -> RouterResponse<cache_api::InMemoryCacheListResponse> {
+    let mut caches = Vec::new();
+    for cache in in_memory_cache::all_caches() {
+        let stats = cache.stats().await;
+        caches.push(cache_api::InMemoryCacheStats {
+            name: stats.name.to_string(),
+            entry_count: stats.entry_count,
+            max_entries: stats.max_entries,
+            time_to_live_in_secs: stats.time_to_live.map(|ttl| ttl.as_secs()),
+            time_to_idle_in_secs: stats.time_to_idle.map(|tti| tti.as_secs()),
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        cache_api::InMemoryCacheListResponse { caches },
+    ))
+}
+
+/// Whether the key is cached in the in-memory cache of the instance serving the request
+pub async fn retrieve_in_memory_cache_key(
+    state: SessionState,
+    req: cache_api::InMemoryCacheKeyRequest,
+) -> RouterResponse<cache_api::InMemoryCacheKeyResponse> {
+    let cache = get_cache(&req.cache_name)?;
+    let present = cache.exists(get_tenant_cache_key(&state, &req.key)?).await;
+
+    Ok(services::ApplicationResponse::Json(
+        cache_api::InMemoryCacheKeyResponse {
+            cache_name: req.cache_name,
+            key: req.key,
+            present,
+        },
+    ))
+}
+
+/// Flushes the key from the in-memory cache of every instance, and from redis
+pub async fn flush_in_memory_cache_key(
+    state: SessionState,
+    req: cache_api::InMemoryCacheKeyRequest,
+) -> RouterResponse<cache_api::InMemoryCacheKeyResponse> {
+    let cache = get_cache(&req.cache_name)?;
+    cache.remove(get_tenant_cache_key(&state, &req.key)?).await;
+
+    let cache_kind = match cache.name() {
+        "CONFIG_CACHE" => CacheKind::Config(req.key.as_str().into()),
+        "ACCOUNTS_CACHE" => CacheKind::Accounts(req.key.as_str().into()),
+        "ROUTING_CACHE" | "MISSING_ROUTING_ALGORITHM_CACHE" => {
+            CacheKind::Routing(req.key.as_str().into())
+        }
+        "DECISION_MANAGER_CACHE" => CacheKind::DecisionManager(req.key.as_str().into()),
+        "SURCHARGE_CACHE" => CacheKind::Surcharge(req.key.as_str().into()),
+        "CGRAPH_CACHE" => CacheKind::CGraph(req.key.as_str().into()),
+        _ => CacheKind::All(req.key.as_str().into()),
+    };
+    publish_into_redact_channel(state.store.get_cache_store().as_ref(), [cache_kind])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to publish the invalidation of the cache key")?;
+
+    Ok(services::ApplicationResponse::Json(
+        cache_api::InMemoryCacheKeyResponse {
+            cache_name: req.cache_name,
+            key: req.key,
+            present: false,
+        },
+    ))
+}
+
+fn get_cache(
+    cache_name: &str,
+) -> CustomResult<&'static in_memory_cache::Cache, errors::ApiErrorResponse> {
+    in_memory_cache::get_cache_by_name(cache_name).ok_or_else(|| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unknown in-memory cache {cache_name}"),
+        })
+    })
+}
+
+/// The key of the entry in the caches, prefixed for the tenant of the request
+fn get_tenant_cache_key(
+    state: &SessionState,
+    key: &str,
+) -> CustomResult<CacheKey, errors::ApiErrorResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    Ok(CacheKey {
+        key: key.to_string(),
+        prefix: redis_conn.key_prefix.clone(),
+    })
+}
@@ -0,0 +1,600 @@
+use api_models::{
+    card_export::{CardExportCreateRequest, CardExportRejectRequest, CardExportResponse},
+    enums as api_enums,
+};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, metrics, SessionState},
+    services::{encryption, ApplicationResponse},
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+pub const CARD_EXPORT_TASK: &str = "CARD_EXPORT";
+pub const CARD_EXPORT_TAG: &str = "CARD_EXPORT";
+
+/// Number of payment methods fetched from the database at a time while exporting cards
+const CARD_EXPORT_PAGE_SIZE: i64 = 1000;
+
+/// Maximum number of payment methods an export can be restricted to
+const MAX_EXPORT_PAYMENT_METHOD_IDS: usize = 10_000;
+
+/// A card in the export file. The file holds one card per line, each line being a JSON object.
+#[derive(Debug, serde::Serialize)]
+struct ExportedCard {
+    payment_method_id: String,
+    customer_id: String,
+    card_number: String,
+    card_exp_month: String,
+    card_exp_year: String,
+    name_on_card: Option<String>,
+    card_network: Option<String>,
+}
+
+fn get_card_export_response(
+    card_export_batch: storage::CardExportBatch,
+) -> RouterResult<CardExportResponse> {
+    let payment_method_ids = card_export_batch
+        .payment_method_ids
+        .map(|payment_method_ids| payment_method_ids.parse_value("CardExportPaymentMethodIds"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(CardExportResponse {
+        export_id: card_export_batch.export_id,
+        merchant_id: card_export_batch.merchant_id,
+        receiving_psp: card_export_batch.receiving_psp,
+        status: card_export_batch.status,
+        payment_method_ids,
+        reason: card_export_batch.reason,
+        requested_by: card_export_batch.requested_by,
+        reviewed_by: card_export_batch.reviewed_by,
+        reviewed_at: card_export_batch.reviewed_at,
+        rejection_reason: card_export_batch.rejection_reason,
+        total_count: card_export_batch.total_count,
+        exported_count: card_export_batch.exported_count,
+        failed_count: card_export_batch.failed_count,
+        file_id: card_export_batch.file_id,
+        error_message: card_export_batch.error_message,
+        completed_at: card_export_batch.completed_at,
+        created_at: card_export_batch.created_at,
+    })
+}
+
+fn validate_card_export_request(request: &CardExportCreateRequest) -> RouterResult<()> {
+    utils::when(request.receiving_psp.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "receiving_psp must not be empty".to_string(),
+        }))
+    })?;
+
+    josekit::jwe::RSA_OAEP_256
+        .encrypter_from_pem(&request.receiver_public_key)
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "receiver_public_key must be a PEM encoded RSA public key".to_string(),
+        })?;
+
+    if let Some(payment_method_ids) = &request.payment_method_ids {
+        utils::when(
+            payment_method_ids.is_empty()
+                || payment_method_ids.len() > MAX_EXPORT_PAYMENT_METHOD_IDS,
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "payment_method_ids must hold between 1 and {MAX_EXPORT_PAYMENT_METHOD_IDS} payment methods"
+                    ),
+                }))
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_card_export(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    request: CardExportCreateRequest,
+) -> RouterResponse<CardExportResponse> {
+    validate_card_export_request(&request)?;
+
+    let now = common_utils::date_time::now();
+    let card_export_batch_new = storage::CardExportBatchNew {
+        export_id: common_utils::generate_id(consts::ID_LENGTH, "cardexp"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        receiving_psp: request.receiving_psp,
+        receiver_public_key: request.receiver_public_key,
+        payment_method_ids: request
+            .payment_method_ids
+            .map(|payment_method_ids| payment_method_ids.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        status: enums::CardExportStatus::PendingApproval,
+        reason: request.reason,
+        requested_by: user_id,
+        created_at: now,
+        modified_at: now,
+    };
+    let card_export_batch = state
+        .store
+        .insert_card_export_batch(card_export_batch_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the card export batch")?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CardExportRequested {
+            export_id: card_export_batch.export_id.clone(),
+            receiving_psp: card_export_batch.receiving_psp.clone(),
+            requested_by: card_export_batch.requested_by.clone(),
+        }))
+        .emit();
+
+    get_card_export_response(card_export_batch).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card_export(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    export_id: String,
+) -> RouterResponse<CardExportResponse> {
+    let card_export_batch = find_card_export_batch(&state, &merchant_account, &export_id).await?;
+
+    get_card_export_response(card_export_batch).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_card_exports(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<CardExportResponse>> {
+    let card_export_batches = state
+        .store
+        .list_card_export_batches_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the card export batches")?;
+
+    card_export_batches
+        .into_iter()
+        .map(get_card_export_response)
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+async fn find_card_export_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    export_id: &str,
+) -> RouterResult<storage::CardExportBatch> {
+    state
+        .store
+        .find_card_export_batch_by_merchant_id_export_id(&merchant_account.merchant_id, export_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Card export not found".to_string(),
+        })
+}
+
+/// Approves an export pending approval and schedules the export of the cards. An export must be
+/// approved by a user other than the one who requested it.
+#[instrument(skip_all)]
+pub async fn approve_card_export(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    export_id: String,
+) -> RouterResponse<CardExportResponse> {
+    let db = state.store.as_ref();
+    let card_export_batch = find_card_export_batch(&state, &merchant_account, &export_id).await?;
+    validate_card_export_pending_approval(&card_export_batch)?;
+    utils::when(card_export_batch.requested_by == user_id, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message:
+                "The card export must be approved by a user other than the one who requested it"
+                    .to_string(),
+        }))
+    })?;
+
+    let card_export_batch = db
+        .update_card_export_batch_with_status(
+            card_export_batch,
+            enums::CardExportStatus::PendingApproval,
+            storage::CardExportBatchUpdate::ReviewUpdate {
+                status: enums::CardExportStatus::Processing,
+                reviewed_by: user_id,
+                rejection_reason: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The card export has already been reviewed".to_string(),
+        })?;
+
+    let tracking_data = storage::CardExportTrackingData {
+        merchant_id: card_export_batch.merchant_id.clone(),
+        export_id: card_export_batch.export_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        format!(
+            "{runner}_{task}_{export_id}",
+            runner = storage::ProcessTrackerRunner::CardExportWorkflow,
+            task = CARD_EXPORT_TASK,
+            export_id = card_export_batch.export_id
+        ),
+        CARD_EXPORT_TASK,
+        storage::ProcessTrackerRunner::CardExportWorkflow,
+        [CARD_EXPORT_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct card export process tracker task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the card export task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "CardExport")]),
+    );
+
+    emit_card_export_reviewed_event(&req_state, &card_export_batch);
+
+    get_card_export_response(card_export_batch).map(ApplicationResponse::Json)
+}
+
+/// Rejects an export pending approval, in which case no card is exported
+#[instrument(skip_all)]
+pub async fn reject_card_export(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    export_id: String,
+    request: CardExportRejectRequest,
+) -> RouterResponse<CardExportResponse> {
+    let card_export_batch = find_card_export_batch(&state, &merchant_account, &export_id).await?;
+    validate_card_export_pending_approval(&card_export_batch)?;
+
+    let card_export_batch = state
+        .store
+        .update_card_export_batch_with_status(
+            card_export_batch,
+            enums::CardExportStatus::PendingApproval,
+            storage::CardExportBatchUpdate::ReviewUpdate {
+                status: enums::CardExportStatus::Rejected,
+                reviewed_by: user_id,
+                rejection_reason: request.rejection_reason,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The card export has already been reviewed".to_string(),
+        })?;
+
+    emit_card_export_reviewed_event(&req_state, &card_export_batch);
+
+    get_card_export_response(card_export_batch).map(ApplicationResponse::Json)
+}
+
+fn validate_card_export_pending_approval(
+    card_export_batch: &storage::CardExportBatch,
+) -> RouterResult<()> {
+    utils::when(
+        card_export_batch.status != enums::CardExportStatus::PendingApproval,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The card export cannot be reviewed as it is {}",
+                    card_export_batch.status
+                ),
+            }))
+        },
+    )
+}
+
+fn emit_card_export_reviewed_event(
+    req_state: &ReqState,
+    card_export_batch: &storage::CardExportBatch,
+) {
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CardExportReviewed {
+            export_id: card_export_batch.export_id.clone(),
+            status: card_export_batch.status,
+            reviewed_by: card_export_batch.reviewed_by.clone().unwrap_or_default(),
+        }))
+        .emit();
+}
+
+/// Downloads the export file, which is encrypted to the public key of the receiving payment
+/// service provider as a compact JWE using RSA-OAEP-256 and A256GCM
+#[instrument(skip_all)]
+pub async fn download_card_export(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    export_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let card_export_batch = find_card_export_batch(&state, &merchant_account, &export_id).await?;
+    let file_id = card_export_batch
+        .file_id
+        .clone()
+        .filter(|_| card_export_batch.status == enums::CardExportStatus::Completed)
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)?;
+
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)?;
+    let provider_file_id = file_metadata
+        .provider_file_id
+        .filter(|_| file_metadata.available)
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)?;
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the card export from the file storage")?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CardExportDownloaded {
+            export_id: card_export_batch.export_id,
+            downloaded_by: user_id,
+        }))
+        .emit();
+
+    Ok(ApplicationResponse::FileData((
+        file_data,
+        mime::APPLICATION_OCTET_STREAM,
+    )))
+}
+
+/// Retrieves the cards of the export from the card vault, one page of payment methods at a time.
+/// Returns the exported cards along with the number of cards considered for the export.
+async fn collect_exported_cards(
+    state: &SessionState,
+    card_export_batch: &storage::CardExportBatch,
+) -> RouterResult<(Vec<ExportedCard>, i32)> {
+    let payment_method_ids: Option<Vec<String>> = card_export_batch
+        .payment_method_ids
+        .clone()
+        .map(|payment_method_ids| payment_method_ids.parse_value("CardExportPaymentMethodIds"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let mut exported_cards = Vec::new();
+    let mut total_count: i32 = 0;
+    let mut starting_after: Option<String> = None;
+    loop {
+        let payment_methods = state
+            .store
+            .list_card_payment_methods_for_export(
+                &card_export_batch.merchant_id,
+                payment_method_ids.as_deref(),
+                starting_after.as_deref(),
+                CARD_EXPORT_PAGE_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the cards to export")?;
+        let is_last_page = i64::try_from(payment_methods.len())
+            .map(|count| count < CARD_EXPORT_PAGE_SIZE)
+            .unwrap_or(true);
+        starting_after = payment_methods
+            .last()
+            .map(|payment_method| payment_method.payment_method_id.clone());
+
+        for payment_method in payment_methods {
+            total_count += 1;
+            let card = match cards::get_card_from_locker(
+                state,
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                payment_method
+                    .locker_id
+                    .as_ref()
+                    .unwrap_or(&payment_method.payment_method_id),
+            )
+            .await
+            {
+                Ok(card) => card,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        payment_method_id = %payment_method.payment_method_id,
+                        "Failed to retrieve the card to export from the card vault"
+                    );
+                    continue;
+                }
+            };
+
+            exported_cards.push(ExportedCard {
+                payment_method_id: payment_method.payment_method_id,
+                customer_id: payment_method.customer_id.get_string_repr().to_owned(),
+                card_number: card.card_number.get_card_no(),
+                card_exp_month: card.card_exp_month.expose(),
+                card_exp_year: card.card_exp_year.expose(),
+                name_on_card: card.name_on_card.map(ExposeInterface::expose),
+                card_network: payment_method.scheme.or(card.card_brand),
+            });
+        }
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok((exported_cards, total_count))
+}
+
+async fn generate_card_export_file(
+    state: &SessionState,
+    card_export_batch: &storage::CardExportBatch,
+    exported_cards: &[ExportedCard],
+) -> RouterResult<String> {
+    let mut file = Vec::new();
+    for exported_card in exported_cards {
+        serde_json::to_writer(&mut file, exported_card)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the exported card")?;
+        file.push(b'\n');
+    }
+
+    let encrypted_file =
+        encryption::encrypt_jwe(&file, card_export_batch.receiver_public_key.as_bytes())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the card export")?
+            .into_bytes();
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", card_export_batch.merchant_id, file_id);
+    let file_size = i32::try_from(encrypted_file.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Card export is too large")?;
+    state
+        .file_storage_client
+        .upload_file(&file_key, encrypted_file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the card export to the file storage")?;
+
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: card_export_batch.merchant_id.clone(),
+        file_name: Some(format!("{}.jwe", card_export_batch.export_id)),
+        file_size,
+        file_type: mime::APPLICATION_OCTET_STREAM.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(api_enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: None,
+        merchant_connector_id: None,
+    };
+    state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the file metadata of the card export")?;
+
+    Ok(file_id)
+}
+
+async fn export_cards(
+    state: &SessionState,
+    card_export_batch: &storage::CardExportBatch,
+) -> RouterResult<storage::CardExportBatchUpdate> {
+    let (exported_cards, total_count) = collect_exported_cards(state, card_export_batch).await?;
+    let exported_count = i32::try_from(exported_cards.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let failed_count = total_count - exported_count;
+
+    if exported_cards.is_empty() && total_count > 0 {
+        return Ok(storage::CardExportBatchUpdate::ResultUpdate {
+            status: enums::CardExportStatus::Failed,
+            total_count,
+            exported_count,
+            failed_count,
+            file_id: None,
+            error_message: Some(
+                "None of the cards could be retrieved from the card vault".to_string(),
+            ),
+        });
+    }
+
+    let file_id = generate_card_export_file(state, card_export_batch, &exported_cards).await?;
+    Ok(storage::CardExportBatchUpdate::ResultUpdate {
+        status: enums::CardExportStatus::Completed,
+        total_count,
+        exported_count,
+        failed_count,
+        file_id: Some(file_id),
+        error_message: None,
+    })
+}
+
+/// Exports the cards of an approved export and records the outcome on the export. A failed export
+/// is not retried, a new export has to be requested and approved.
+#[instrument(skip_all)]
+pub async fn run_card_export(
+    state: &SessionState,
+    card_export_batch: storage::CardExportBatch,
+) -> RouterResult<storage::CardExportBatch> {
+    let card_export_batch_update = match export_cards(state, &card_export_batch).await {
+        Ok(card_export_batch_update) => card_export_batch_update,
+        Err(error) => {
+            logger::error!(?error, "Failed to export the cards");
+            storage::CardExportBatchUpdate::ResultUpdate {
+                status: enums::CardExportStatus::Failed,
+                total_count: 0,
+                exported_count: 0,
+                failed_count: 0,
+                file_id: None,
+                error_message: Some("The export file could not be generated".to_string()),
+            }
+        }
+    };
+
+    let card_export_batch = state
+        .store
+        .update_card_export_batch_with_status(
+            card_export_batch,
+            enums::CardExportStatus::Processing,
+            card_export_batch_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the outcome of the card export")?;
+
+    if card_export_batch.status == enums::CardExportStatus::Completed {
+        metrics::CARDS_EXPORTED.add(
+            &metrics::CONTEXT,
+            card_export_batch
+                .exported_count
+                .and_then(|exported_count| u64::try_from(exported_count).ok())
+                .unwrap_or_default(),
+            &[],
+        );
+    }
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CardExportCompleted {
+            export_id: card_export_batch.export_id.clone(),
+            status: card_export_batch.status,
+            exported_count: card_export_batch.exported_count.unwrap_or_default(),
+            failed_count: card_export_batch.failed_count.unwrap_or_default(),
+        }))
+        .emit();
+
+    Ok(card_export_batch)
+}
@@ -1,13 +1,29 @@
+use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::{self, utils::StorageErrorExt, RouterResponse},
+    consts,
+    core::errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
     routes::SessionState,
-    services::ApplicationResponse,
+    services::{api::client::HttpClientPolicy, ApplicationResponse},
     types::{api, transformers::ForeignInto},
 };
 
+/// Validates the value of the configs read as a structure, so that a malformed value is rejected
+/// rather than ignored where it is read
+fn validate_config_value(key: &str, value: &str) -> RouterResult<()> {
+    if key.starts_with(consts::CONNECTOR_HTTP_CLIENT_POLICY_CONFIG_PREFIX) {
+        let _: HttpClientPolicy = value.parse_struct("HttpClientPolicy").change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "value is not a valid HTTP client policy".to_string(),
+            },
+        )?;
+    }
+    Ok(())
+}
+
 pub async fn set_config(state: SessionState, config: api::Config) -> RouterResponse<api::Config> {
+    validate_config_value(&config.key, &config.value)?;
     let store = state.store.as_ref();
     let config = store
         .insert_config(diesel_models::configs::ConfigNew {
@@ -34,6 +50,7 @@ pub async fn update_config(
     state: SessionState,
     config_update: &api::ConfigUpdate,
 ) -> RouterResponse<api::Config> {
+    validate_config_value(&config_update.key, &config_update.value)?;
     let store = state.store.as_ref();
     let config = store
         .update_config_by_key(&config_update.key, config_update.foreign_into())
@@ -0,0 +1,675 @@
+//! Connected accounts, the sub-merchants a platform onboards and processes payments on behalf of.
+//! A connected account is a merchant account of the organization of the platform, linked to the
+//! platform through its parent merchant. The platform acts on behalf of a connected account by
+//! naming it in the `X-Connected-Account-Id` header of the requests it makes with its own API
+//! key, and collects a fee on the payments of the connected account through the split of the
+//! payments with the connectors supporting it.
+
+#[cfg(feature = "olap")]
+use std::collections::HashMap;
+
+#[cfg(feature = "olap")]
+use api_models::{
+    admin::MerchantConnectorResponse,
+    connected_accounts::{
+        ConnectedAccountConnectorRequest, ConnectedAccountCredentials, ConnectedAccountReport,
+        ConnectedAccountVolume, ConnectedAccountsListResponse, ConnectedAccountsReport,
+    },
+    payments::TimeRange,
+};
+use api_models::{
+    audit_events::{AuditAction, AuditResourceType},
+    connected_accounts::{
+        ConnectedAccountCreateRequest, ConnectedAccountResponse, ConnectedAccountSettingsRecord,
+        ConnectedAccountSplit, ConnectedAccountUpdateRequest, PlatformFee,
+    },
+    payments::PaymentChargeRequest,
+    profile_cloning::{BusinessProfileCloneResponse, BusinessProfileTemplateApplyRequest},
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    types::MinorUnit,
+};
+#[cfg(feature = "olap")]
+use common_utils::{ext_traits::ValueExt, money::Money};
+use diesel_models::configs;
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+#[cfg(feature = "olap")]
+use crate::{
+    core::organization,
+    types::{self, storage::enums},
+};
+use crate::{
+    core::{
+        admin, audit_events,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        profile_cloning,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api, domain, storage},
+};
+
+/// Whether the merchant can onboard connected accounts, set by the operator through the configs
+pub fn get_connected_accounts_enabled_config_key(merchant_id: &str) -> String {
+    format!("connected_accounts_enabled_{merchant_id}")
+}
+
+pub fn get_connected_account_settings_config_key(merchant_id: &str) -> String {
+    format!("connected_account_settings_{merchant_id}")
+}
+
+/// Checks that the merchant is a platform, which cannot itself be the connected account of
+/// another platform
+async fn validate_platform(
+    db: &dyn StorageInterface,
+    platform: &domain::MerchantAccount,
+) -> RouterResult<()> {
+    let is_platform = db
+        .find_config_by_key_unwrap_or(
+            &get_connected_accounts_enabled_config_key(&platform.merchant_id),
+            Some("false".to_string()),
+        )
+        .await
+        .map(|config| config.config == "true")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connected accounts config of the merchant")?;
+
+    if !is_platform || platform.parent_merchant_id.is_some() {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Connected accounts are not enabled for the merchant".to_string(),
+        })?
+    }
+    Ok(())
+}
+
+fn validate_split(split: &ConnectedAccountSplit) -> RouterResult<()> {
+    if split.transfer_account_id.trim().is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "transfer_account_id cannot be empty".to_string(),
+        })?
+    }
+    if split
+        .platform_fee
+        .fixed_amount
+        .is_some_and(|fixed_amount| fixed_amount.is_negative())
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "fixed_amount of the platform fee cannot be negative".to_string(),
+        })?
+    }
+    Ok(())
+}
+
+/// Finds the connected account of the platform, the merchants which are not connected accounts of
+/// the platform being reported as not found
+async fn find_connected_account(
+    db: &dyn StorageInterface,
+    platform: &domain::MerchantAccount,
+    merchant_id: &str,
+) -> RouterResult<(domain::MerchantAccount, domain::MerchantKeyStore)> {
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if merchant_account.parent_merchant_id.as_ref() != Some(&platform.merchant_id) {
+        Err(errors::ApiErrorResponse::MerchantAccountNotFound)
+            .attach_printable("Merchant account is not a connected account of the platform")?
+    }
+    Ok((merchant_account, key_store))
+}
+
+pub async fn find_connected_account_settings(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<ConnectedAccountSettingsRecord> {
+    db.find_config_by_key_unwrap_or(
+        &get_connected_account_settings_config_key(merchant_id),
+        Some("{}".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the connected account settings config")?
+    .config
+    .parse_struct("ConnectedAccountSettingsRecord")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the connected account settings record")
+}
+
+async fn store_connected_account_settings(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    record: &ConnectedAccountSettingsRecord,
+) -> RouterResult<()> {
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    // The config is created when the settings are first read
+    db.update_config_by_key(
+        &get_connected_account_settings_config_key(merchant_id),
+        configs::ConfigUpdate::Update {
+            config: Some(config),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the connected account settings config")?;
+
+    Ok(())
+}
+
+fn get_connected_account_response(
+    platform_merchant_id: String,
+    merchant_account: domain::MerchantAccount,
+    settings: ConnectedAccountSettingsRecord,
+    profile: Option<BusinessProfileCloneResponse>,
+) -> RouterResult<ConnectedAccountResponse> {
+    Ok(ConnectedAccountResponse {
+        platform_merchant_id,
+        merchant_account: api::MerchantAccountResponse::try_from(merchant_account)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while generating response")?,
+        split: settings.split,
+        profile,
+    })
+}
+
+/// The fee of the platform on an amount, the percentage of the amount rounded up added to the
+/// fixed amount
+pub fn calculate_platform_fee(
+    platform_fee: &PlatformFee,
+    amount: MinorUnit,
+) -> RouterResult<MinorUnit> {
+    let percentage_fee = platform_fee
+        .percentage
+        .as_ref()
+        .map(|percentage| percentage.apply_and_ceil_result(amount))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to apply the percentage of the platform fee")?;
+
+    [percentage_fee, platform_fee.fixed_amount]
+        .into_iter()
+        .flatten()
+        .try_fold(MinorUnit::new(0), MinorUnit::checked_add)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Platform fee overflowed")
+}
+
+/// The charges of a payment of a connected account splitting the payment with its platform, none
+/// for the merchants which are not connected accounts or do not split their payments, and for the
+/// zero amount payments setting up a payment method
+pub async fn get_platform_charges(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    amount: MinorUnit,
+) -> RouterResult<Option<PaymentChargeRequest>> {
+    if merchant_account.parent_merchant_id.is_none() || amount == MinorUnit::new(0) {
+        return Ok(None);
+    }
+    let Some(split) = find_connected_account_settings(db, &merchant_account.merchant_id)
+        .await?
+        .split
+    else {
+        return Ok(None);
+    };
+
+    let fees = calculate_platform_fee(&split.platform_fee, amount)?;
+    if fees > amount {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The platform fee {} exceeds the amount of the payment",
+                fees.get_amount_as_i64()
+            ),
+        })?
+    }
+
+    Ok(Some(PaymentChargeRequest {
+        charge_type: split.charge_type,
+        fees,
+        transfer_account_id: split.transfer_account_id,
+    }))
+}
+
+/// Onboards a connected account for the platform, creating its merchant account in the
+/// organization of the platform along with its default business profile
+#[instrument(skip_all)]
+pub async fn create_connected_account(
+    state: SessionState,
+    platform: domain::MerchantAccount,
+    request: ConnectedAccountCreateRequest,
+) -> RouterResponse<ConnectedAccountResponse> {
+    let db = state.store.as_ref();
+    validate_platform(db, &platform).await?;
+    if let Some(split) = request.split.as_ref() {
+        validate_split(split)?;
+    }
+    if let Some(template_name) = request.profile_template_name.as_ref() {
+        profile_cloning::find_profile_template(db, &platform.merchant_id, template_name).await?;
+    }
+
+    let merchant_account_create = api::MerchantAccountCreate {
+        merchant_id: request.merchant_id,
+        merchant_name: request.merchant_name,
+        merchant_details: request.merchant_details,
+        return_url: request.return_url,
+        webhook_details: request.webhook_details,
+        routing_algorithm: None,
+        #[cfg(feature = "payouts")]
+        payout_routing_algorithm: None,
+        // The merchant account is linked to the platform once created
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: None,
+        metadata: request.metadata,
+        publishable_key: None,
+        locker_id: None,
+        primary_business_details: None,
+        frm_routing_algorithm: None,
+        organization_id: Some(platform.organization_id.clone()),
+        pm_collect_link_config: None,
+    };
+    let merchant_id = match Box::pin(admin::create_merchant_account(
+        state.clone(),
+        merchant_account_create,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(merchant_account) => merchant_account.merchant_id,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while creating the connected account")?,
+    };
+
+    let profile = match request.profile_template_name {
+        Some(template_name) => {
+            match profile_cloning::apply_profile_template(
+                state.clone(),
+                platform.merchant_id.clone(),
+                template_name.clone(),
+                BusinessProfileTemplateApplyRequest {
+                    profile_name: template_name,
+                    target_merchant_id: Some(merchant_id.clone()),
+                },
+            )
+            .await?
+            {
+                services::ApplicationResponse::Json(profile) => Some(profile),
+                _ => Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
+                    "Unexpected response while creating the profile of the connected account",
+                )?,
+            }
+        }
+        None => None,
+    };
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .update_specific_fields_in_merchant(
+            &merchant_id,
+            storage::MerchantAccountUpdate::ConnectedAccountUpdate {
+                parent_merchant_id: platform.merchant_id.clone(),
+                default_profile: profile
+                    .as_ref()
+                    .map(|profile| profile.profile.profile_id.clone()),
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Failed to link the connected account to the platform")?;
+
+    let settings = ConnectedAccountSettingsRecord {
+        split: request.split,
+        modified_at: Some(date_time::now_unix_timestamp()),
+    };
+    if settings.split.is_some() {
+        find_connected_account_settings(db, &merchant_id).await?;
+        store_connected_account_settings(db, &merchant_id, &settings).await?;
+    }
+
+    let response =
+        get_connected_account_response(platform.merchant_id, merchant_account, settings, profile)?;
+    audit_events::record_audit_event(
+        &state,
+        &response.platform_merchant_id,
+        AuditResourceType::MerchantAccount,
+        &merchant_id,
+        AuditAction::Create,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn list_connected_accounts(
+    state: SessionState,
+    platform: domain::MerchantAccount,
+) -> RouterResponse<ConnectedAccountsListResponse> {
+    let db = state.store.as_ref();
+    validate_platform(db, &platform).await?;
+
+    let merchant_accounts = db
+        .list_merchant_accounts_by_organization_id(&platform.organization_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant accounts of the organization")?;
+
+    let mut connected_accounts = Vec::new();
+    for merchant_account in merchant_accounts.into_iter().filter(|merchant_account| {
+        merchant_account.parent_merchant_id.as_ref() == Some(&platform.merchant_id)
+    }) {
+        let settings = find_connected_account_settings(db, &merchant_account.merchant_id).await?;
+        connected_accounts.push(get_connected_account_response(
+            platform.merchant_id.clone(),
+            merchant_account,
+            settings,
+            None,
+        )?);
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectedAccountsListResponse {
+            platform_merchant_id: platform.merchant_id,
+            connected_accounts,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connected_account(
+    state: SessionState,
+    platform: domain::MerchantAccount,
+    merchant_id: String,
+) -> RouterResponse<ConnectedAccountResponse> {
+    let db = state.store.as_ref();
+    let (merchant_account, _) = find_connected_account(db, &platform, &merchant_id).await?;
+    let settings = find_connected_account_settings(db, &merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_connected_account_response(platform.merchant_id, merchant_account, settings, None)?,
+    ))
+}
+
+/// Replaces how the payments of the connected account are split with the platform, applying to
+/// the payments created afterwards
+#[instrument(skip_all)]
+pub async fn update_connected_account(
+    state: SessionState,
+    platform: domain::MerchantAccount,
+    merchant_id: String,
+    request: ConnectedAccountUpdateRequest,
+) -> RouterResponse<ConnectedAccountResponse> {
+    let db = state.store.as_ref();
+    validate_platform(db, &platform).await?;
+    let (merchant_account, _) = find_connected_account(db, &platform, &merchant_id).await?;
+    if let Some(split) = request.split.as_ref() {
+        validate_split(split)?;
+    }
+
+    let previous_settings = find_connected_account_settings(db, &merchant_id).await?;
+    let settings = ConnectedAccountSettingsRecord {
+        split: request.split,
+        modified_at: Some(date_time::now_unix_timestamp()),
+    };
+    store_connected_account_settings(db, &merchant_id, &settings).await?;
+
+    let response =
+        get_connected_account_response(platform.merchant_id, merchant_account, settings, None)?;
+    audit_events::record_audit_event(
+        &state,
+        &response.platform_merchant_id,
+        AuditResourceType::MerchantAccount,
+        &merchant_id,
+        AuditAction::Update,
+        Some(&previous_settings.split),
+        Some(&response.split),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Creates a merchant connector account for the connected account from a merchant connector
+/// account of the platform, with the credentials of the platform or with credentials of the
+/// connected account's own
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn create_connected_account_connector(
+    state: SessionState,
+    platform: domain::MerchantAccount,
+    merchant_id: String,
+    request: ConnectedAccountConnectorRequest,
+) -> RouterResponse<MerchantConnectorResponse> {
+    let db = state.store.as_ref();
+    validate_platform(db, &platform).await?;
+    find_connected_account(db, &platform, &merchant_id).await?;
+
+    let source = match admin::retrieve_payment_connector(
+        state.clone(),
+        platform.merchant_id.clone(),
+        request.merchant_connector_id,
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(source) => source,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while retrieving the platform connector")?,
+    };
+
+    let mut create_request =
+        organization::get_shared_connector_create_request(&source, request.profile_id)?;
+    // The payment method authentication config refers to connector accounts of the platform
+    create_request.pm_auth_config = None;
+    if let ConnectedAccountCredentials::Dedicated {
+        connector_account_details,
+    } = request.credentials
+    {
+        let connector_account_details = match connector_account_details {
+            Some(connector_account_details) => connector_account_details,
+            None => types::ConnectorAuthType::TemporaryAuth
+                .encode_to_value()
+                .map(Secret::new)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the temporary connector account details")?,
+        };
+        create_request.connector_account_details = Some(connector_account_details);
+        // The webhook secrets are credentials of the account at the connector
+        create_request.connector_webhook_details = None;
+        create_request.status = None;
+        create_request.disabled = None;
+    }
+
+    admin::create_payment_connector(state, create_request, &merchant_id).await
+}
+
+/// Adds the successful payment to the volumes of the connected account, the fee of the platform
+/// being the fee of the charges the payment was split with
+#[cfg(feature = "olap")]
+fn add_payment_to_volumes(
+    volumes: &mut HashMap<enums::Currency, (Money, Money)>,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let Some(currency) = payment_intent.currency else {
+        return Ok(());
+    };
+    let platform_fee = payment_intent
+        .charges
+        .as_ref()
+        .map(|charges| {
+            charges
+                .peek()
+                .clone()
+                .parse_value::<PaymentChargeRequest>("PaymentChargeRequest")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to parse PaymentChargeRequest for payment_intent {}",
+                payment_intent.payment_id
+            )
+        })?
+        .map_or(MinorUnit::new(0), |charges| charges.fees);
+    let amount = payment_intent
+        .amount_captured
+        .unwrap_or(payment_intent.amount);
+
+    let (volume, fee) = volumes
+        .entry(currency)
+        .or_insert((Money::zero(currency), Money::zero(currency)));
+    *volume = volume
+        .checked_add_amount(amount)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Volume of the connected account overflowed")?;
+    *fee = fee
+        .checked_add_amount(platform_fee)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Platform fees of the connected account overflowed")?;
+    Ok(())
+}
+
+/// Reports, for each connected account of the platform, the payments created within the time
+/// range and the volume and platform fees of the successful ones by currency
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn get_connected_accounts_report(
+    state: SessionState,
+    platform: domain::MerchantAccount,
+    time_range: TimeRange,
+) -> RouterResponse<ConnectedAccountsReport> {
+    let db = state.store.as_ref();
+    validate_platform(db, &platform).await?;
+
+    let merchant_accounts = db
+        .list_merchant_accounts_by_organization_id(&platform.organization_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant accounts of the organization")?;
+
+    let mut connected_accounts = Vec::new();
+    for merchant_account in merchant_accounts.into_iter().filter(|merchant_account| {
+        merchant_account.parent_merchant_id.as_ref() == Some(&platform.merchant_id)
+    }) {
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &merchant_account.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        let payment_intents = db
+            .filter_payment_intents_by_time_range_constraints(
+                &merchant_account.merchant_id,
+                &time_range,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payments of the connected account")?;
+
+        let mut volumes = HashMap::new();
+        let mut succeeded_payments = 0;
+        for payment_intent in payment_intents.iter().filter(|payment_intent| {
+            matches!(
+                payment_intent.status,
+                enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+            )
+        }) {
+            succeeded_payments += 1;
+            add_payment_to_volumes(&mut volumes, payment_intent)?;
+        }
+
+        let mut volumes = volumes
+            .into_values()
+            .map(|(volume, fee)| ConnectedAccountVolume {
+                currency: volume.get_currency(),
+                amount: volume.get_amount(),
+                platform_fee: fee.get_amount(),
+            })
+            .collect::<Vec<_>>();
+        volumes.sort_by_key(|volume| volume.currency.to_string());
+        connected_accounts.push(ConnectedAccountReport {
+            merchant_id: merchant_account.merchant_id,
+            total_payments: payment_intents.len(),
+            succeeded_payments,
+            volumes,
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectedAccountsReport {
+            platform_merchant_id: platform.merchant_id,
+            time_range,
+            connected_accounts,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::Percentage;
+
+    use super::*;
+
+    fn platform_fee(percentage: Option<&str>, fixed_amount: Option<i64>) -> PlatformFee {
+        PlatformFee {
+            percentage: percentage
+                .map(|percentage| Percentage::from_string(percentage.to_string()).unwrap()),
+            fixed_amount: fixed_amount.map(MinorUnit::new),
+        }
+    }
+
+    #[test]
+    fn platform_fee_adds_rounded_up_percentage_to_fixed_amount() {
+        let amount = MinorUnit::new(1999);
+        assert_eq!(
+            calculate_platform_fee(&platform_fee(Some("2.5"), Some(30)), amount).unwrap(),
+            MinorUnit::new(80)
+        );
+        assert_eq!(
+            calculate_platform_fee(&platform_fee(Some("2.5"), None), amount).unwrap(),
+            MinorUnit::new(50)
+        );
+        assert_eq!(
+            calculate_platform_fee(&platform_fee(None, Some(30)), amount).unwrap(),
+            MinorUnit::new(30)
+        );
+        assert_eq!(
+            calculate_platform_fee(&platform_fee(None, None), amount).unwrap(),
+            MinorUnit::new(0)
+        );
+    }
+
+    #[test]
+    fn platform_fee_overflow_is_rejected() {
+        assert!(calculate_platform_fee(
+            &platform_fee(Some("100"), Some(i64::MAX)),
+            MinorUnit::new(100)
+        )
+        .is_err());
+    }
+}
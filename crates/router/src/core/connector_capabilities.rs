@@ -0,0 +1,176 @@
+use std::str::FromStr;
+
+use api_models::{
+    connector_capabilities::{
+        ConnectorCapabilitiesResponse, MandateCapability, PaymentMethodCapability,
+    },
+    enums as api_enums,
+};
+use error_stack::ResultExt;
+use strum::IntoEnumIterator;
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        pm_filters,
+    },
+    routes::SessionState,
+    services::{ApplicationResponse, ConnectorValidation},
+    types::api,
+};
+
+/// The payment method types and card networks configured for the connector in the payment method
+/// filters, falling back to the default filters for the connectors without filters of their own
+fn get_payment_method_capabilities(
+    pm_filters: &settings::ConnectorFilters,
+    connector_name: &str,
+) -> Vec<PaymentMethodCapability> {
+    let Some(filters) = pm_filters
+        .0
+        .get(connector_name)
+        .or_else(|| pm_filters.0.get("default"))
+    else {
+        return Vec::new();
+    };
+
+    let mut capabilities = filters
+        .0
+        .iter()
+        .map(|(key, filter)| {
+            let (payment_method_type, card_network) = match key {
+                settings::PaymentMethodFilterKey::PaymentMethodType(payment_method_type) => {
+                    (Some(*payment_method_type), None)
+                }
+                settings::PaymentMethodFilterKey::CardNetwork(card_network) => {
+                    (None, Some(card_network.clone()))
+                }
+            };
+            let mut currencies = filter
+                .currency
+                .as_ref()
+                .map(|currencies| currencies.iter().copied().collect::<Vec<_>>());
+            let mut countries = filter
+                .country
+                .as_ref()
+                .map(|countries| countries.iter().copied().collect::<Vec<_>>());
+            currencies
+                .iter_mut()
+                .for_each(|currencies| currencies.sort_by_key(|currency| currency.to_string()));
+            countries
+                .iter_mut()
+                .for_each(|countries| countries.sort_by_key(|country| country.to_string()));
+
+            PaymentMethodCapability {
+                payment_method_type,
+                card_network,
+                currencies,
+                countries,
+                unsupported_capture_method: filter
+                    .not_available_flows
+                    .and_then(|flows| flows.capture_method),
+            }
+        })
+        .collect::<Vec<_>>();
+    capabilities.sort_by_key(|capability| {
+        (
+            capability.payment_method_type.map(|pmt| pmt.to_string()),
+            capability
+                .card_network
+                .as_ref()
+                .map(|network| network.to_string()),
+        )
+    });
+    capabilities
+}
+
+fn get_mandate_capabilities(
+    mandates: &settings::Mandates,
+    connector: api_enums::Connector,
+) -> Vec<MandateCapability> {
+    let mut capabilities = mandates
+        .supported_payment_methods
+        .0
+        .iter()
+        .flat_map(|(payment_method, payment_method_types)| {
+            payment_method_types
+                .0
+                .iter()
+                .filter(|(_, connectors)| connectors.connector_list.contains(&connector))
+                .map(|(payment_method_type, _)| MandateCapability {
+                    payment_method: *payment_method,
+                    payment_method_type: *payment_method_type,
+                })
+        })
+        .collect::<Vec<_>>();
+    capabilities.sort_by_key(|capability| {
+        (
+            capability.payment_method.to_string(),
+            capability.payment_method_type.to_string(),
+        )
+    });
+    capabilities
+}
+
+/// The capabilities of the connector, derived from its integration and from the configuration of
+/// the connectors, so that they never drift from what the payments made through it support
+pub async fn get_connector_capabilities(
+    state: &SessionState,
+    connector: api_enums::Connector,
+) -> RouterResult<ConnectorCapabilitiesResponse> {
+    let connector_name = connector.to_string();
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        None,
+    )
+    .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+    .attach_printable("Failed to construct the connector")?;
+
+    let capture_methods = api_enums::CaptureMethod::iter()
+        .filter(|capture_method| {
+            connector_data
+                .connector
+                .validate_capture_method(Some(*capture_method), None)
+                .is_ok()
+        })
+        .collect();
+    let pm_filters = pm_filters::get_pm_filters(state).await?;
+    let config = state
+        .conf
+        .connector_capabilities
+        .0
+        .get(&connector_name)
+        .cloned()
+        .unwrap_or_default();
+    #[cfg(feature = "payouts")]
+    let payouts = api_enums::PayoutConnectors::try_from(connector).is_ok();
+    #[cfg(not(feature = "payouts"))]
+    let payouts = false;
+
+    Ok(ConnectorCapabilitiesResponse {
+        connector,
+        capture_methods,
+        overcapture: config.overcapture,
+        refund_window_in_days: config.refund_window_in_days,
+        mandates: get_mandate_capabilities(&state.conf.mandates, connector),
+        payouts,
+        three_ds: config.three_ds,
+        separate_authentication: connector.is_separate_authentication_supported(),
+        payment_methods: get_payment_method_capabilities(&pm_filters, &connector_name),
+    })
+}
+
+pub async fn retrieve_connector_capabilities(
+    state: SessionState,
+    connector_name: String,
+) -> RouterResponse<ConnectorCapabilitiesResponse> {
+    let connector = api_enums::Connector::from_str(&connector_name)
+        .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+        .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
+
+    get_connector_capabilities(&state, connector)
+        .await
+        .map(ApplicationResponse::Json)
+}
@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use api_models::admin as admin_types;
+use common_utils::{crypto::Encryptable, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{self, domain, domain::types as domain_types, storage},
+};
+
+/// Duration in seconds for which the replaced credentials are used as a fallback when the
+/// rotation window is not set on the cutover
+const DEFAULT_ROTATION_WINDOW_IN_SECS: u32 = 7 * 24 * 60 * 60;
+
+/// The credentials to retry the operations rejected by the connector with, while the credentials
+/// of the merchant connector account are rotated: the replaced credentials during the rotation
+/// window following a cutover, or else the credentials staged for the next cutover
+fn get_fallback_connector_account_details(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> Option<&Encryptable<masking::Secret<serde_json::Value>>> {
+    let is_in_rotation_window = merchant_connector_account
+        .credentials_rotation_window_ends_at
+        .is_some_and(|window_ends_at| window_ends_at > common_utils::date_time::now());
+
+    merchant_connector_account
+        .previous_connector_account_details
+        .as_ref()
+        .filter(|_| is_in_rotation_window)
+        .or(merchant_connector_account
+            .next_connector_account_details
+            .as_ref())
+}
+
+/// The merchant connector account with its fallback credentials in place of its current ones, if
+/// its credentials are being rotated
+pub fn get_merchant_connector_account_with_fallback_credentials(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> Option<domain::MerchantConnectorAccount> {
+    get_fallback_connector_account_details(merchant_connector_account).map(
+        |connector_account_details| domain::MerchantConnectorAccount {
+            connector_account_details: connector_account_details.clone(),
+            ..merchant_connector_account.clone()
+        },
+    )
+}
+
+/// The fallback credentials of the merchant connector account, parsed for the connector. Failures
+/// are logged, the operation then failing with the current credentials as it would have anyway.
+pub async fn find_fallback_connector_auth(
+    state: &SessionState,
+    merchant_id: &str,
+    merchant_connector_id: Option<&str>,
+    key_store: &domain::MerchantKeyStore,
+) -> Option<types::ConnectorAuthType> {
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            merchant_id,
+            merchant_connector_id?,
+            key_store,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to fetch the merchant connector account");
+        })
+        .ok()?;
+
+    get_fallback_connector_account_details(&merchant_connector_account)?
+        .clone()
+        .into_inner()
+        .expose()
+        .parse_value("ConnectorAuthType")
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the fallback connector account details"
+            );
+        })
+        .ok()
+}
+
+/// Whether the connector rejected the credentials of the request
+pub fn is_authentication_failure<T>(response: &Result<T, types::ErrorResponse>) -> bool {
+    matches!(response, Err(error) if matches!(error.status_code, 401 | 403))
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+) -> RouterResult<(domain::MerchantKeyStore, domain::MerchantConnectorAccount)> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.to_string(),
+        })?;
+
+    Ok((key_store, merchant_connector_account))
+}
+
+fn get_rotation_response(
+    merchant_connector_account: domain::MerchantConnectorAccount,
+) -> admin_types::MerchantConnectorCredentialsRotationResponse {
+    let rotation_window_ends_at = merchant_connector_account
+        .credentials_rotation_window_ends_at
+        .filter(|window_ends_at| *window_ends_at > common_utils::date_time::now());
+
+    admin_types::MerchantConnectorCredentialsRotationResponse {
+        has_next_credentials: merchant_connector_account
+            .next_connector_account_details
+            .is_some(),
+        has_previous_credentials: rotation_window_ends_at.is_some()
+            && merchant_connector_account
+                .previous_connector_account_details
+                .is_some(),
+        rotation_window_ends_at,
+        merchant_connector_id: merchant_connector_account.merchant_connector_id,
+    }
+}
+
+/// Stages the credentials to replace the current credentials of the merchant connector account
+/// on the next cutover, replacing the credentials staged before if any
+#[instrument(skip_all)]
+pub async fn stage_next_credentials(
+    state: SessionState,
+    merchant_id: String,
+    merchant_connector_id: String,
+    req: admin_types::MerchantConnectorNextCredentialsRequest,
+) -> RouterResponse<admin_types::MerchantConnectorCredentialsRotationResponse> {
+    let (key_store, merchant_connector_account) =
+        find_merchant_connector_account(&state, &merchant_id, &merchant_connector_id).await?;
+
+    let auth: types::ConnectorAuthType = req
+        .connector_account_details
+        .peek()
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_account_details".to_string(),
+            expected_format: "auth_type and api_key".to_string(),
+        })?;
+    let connector_name = &merchant_connector_account.connector_name;
+    let connector_enum = api_models::enums::Connector::from_str(connector_name)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        })
+        .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
+    admin::validate_auth_and_metadata_type(
+        connector_enum,
+        &auth,
+        &merchant_connector_account.metadata,
+    )?;
+    let next_connector_account_details =
+        domain_types::encrypt(req.connector_account_details, key_store.key.peek())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt the next connector account details")?;
+
+    let merchant_connector_account = state
+        .store
+        .update_merchant_connector_account(
+            merchant_connector_account,
+            storage::MerchantConnectorAccountUpdate::CredentialsRotationUpdate {
+                connector_account_details: None,
+                next_connector_account_details: Some(Some(next_connector_account_details)),
+                previous_connector_account_details: None,
+                credentials_rotation_window_ends_at: None,
+            }
+            .into(),
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to stage the next connector account details")?;
+
+    Ok(ApplicationResponse::Json(get_rotation_response(
+        merchant_connector_account,
+    )))
+}
+
+/// Makes the staged credentials the current credentials of the merchant connector account. The
+/// replaced credentials are kept as a fallback during the rotation window, so that the operations
+/// on the payments made with them keep succeeding.
+#[instrument(skip_all)]
+pub async fn cutover_credentials(
+    state: SessionState,
+    merchant_id: String,
+    merchant_connector_id: String,
+    req: admin_types::MerchantConnectorCredentialsCutoverRequest,
+) -> RouterResponse<admin_types::MerchantConnectorCredentialsRotationResponse> {
+    let (key_store, merchant_connector_account) =
+        find_merchant_connector_account(&state, &merchant_id, &merchant_connector_id).await?;
+
+    let next_connector_account_details = merchant_connector_account
+        .next_connector_account_details
+        .clone()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "no credentials are staged for the merchant connector account".to_string(),
+        })?;
+    let rotation_window_in_secs = req
+        .rotation_window_in_secs
+        .unwrap_or(DEFAULT_ROTATION_WINDOW_IN_SECS);
+    let credentials_rotation_window_ends_at = common_utils::date_time::now()
+        + time::Duration::seconds(i64::from(rotation_window_in_secs));
+    let previous_connector_account_details =
+        merchant_connector_account.connector_account_details.clone();
+
+    let merchant_connector_account = state
+        .store
+        .update_merchant_connector_account(
+            merchant_connector_account,
+            storage::MerchantConnectorAccountUpdate::CredentialsRotationUpdate {
+                connector_account_details: Some(next_connector_account_details),
+                next_connector_account_details: Some(None),
+                previous_connector_account_details: Some(Some(previous_connector_account_details)),
+                credentials_rotation_window_ends_at: Some(Some(
+                    credentials_rotation_window_ends_at,
+                )),
+            }
+            .into(),
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cut over the connector account details")?;
+
+    logger::info!(
+        %merchant_connector_id,
+        %credentials_rotation_window_ends_at,
+        "Cut over the credentials of the merchant connector account"
+    );
+
+    Ok(ApplicationResponse::Json(get_rotation_response(
+        merchant_connector_account,
+    )))
+}
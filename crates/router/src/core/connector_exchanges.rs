@@ -0,0 +1,146 @@
+use api_models::{admin::ConnectorDebugCaptureConfig, payments as payment_types};
+use common_utils::{date_time, ext_traits::ValueExt, generate_id};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    events::connector_api_logs::ConnectorEvent,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Capture of the exchanges with the connectors for the payments of a business profile, set on the
+/// session state while the connector debug capture is enabled for the profile
+#[derive(Clone, Debug)]
+pub struct ConnectorDebugCapture {
+    profile_id: String,
+}
+
+impl ConnectorDebugCapture {
+    /// The capture of the exchanges for the payments of the profile, if the capture is enabled for
+    /// the profile at the moment
+    pub fn for_profile(
+        business_profile: &storage::business_profile::BusinessProfile,
+    ) -> Option<Self> {
+        let config = business_profile
+            .connector_debug_capture
+            .clone()?
+            .parse_value::<ConnectorDebugCaptureConfig>("ConnectorDebugCaptureConfig")
+            .map_err(|error| {
+                logger::error!(?error, "Failed to parse the connector debug capture config");
+            })
+            .ok()?;
+
+        (config.enabled_until > date_time::now()).then(|| Self {
+            profile_id: business_profile.profile_id.clone(),
+        })
+    }
+
+    /// Stores the exchange in the background, so that a failure to store it never fails the call to
+    /// the connector
+    pub fn record(&self, state: &SessionState, attempt_id: &str, connector_event: &ConnectorEvent) {
+        let created_at = date_time::now();
+        let exchange = storage::ConnectorExchangeNew {
+            exchange_id: generate_id(consts::ID_LENGTH, "cex"),
+            merchant_id: connector_event.merchant_id().to_owned(),
+            profile_id: self.profile_id.clone(),
+            payment_id: connector_event.payment_id().to_owned(),
+            attempt_id: attempt_id.to_owned(),
+            connector: connector_event.connector_name().to_owned(),
+            flow: connector_event.flow().to_owned(),
+            request_id: Some(connector_event.request_id.clone()),
+            url: connector_event.url().to_owned(),
+            method: connector_event.method().to_owned(),
+            request: Some(parse_payload(connector_event.masked_request())),
+            response: connector_event.masked_response().map(parse_payload),
+            error: connector_event.error().map(parse_payload),
+            status_code: i32::from(connector_event.status_code()),
+            latency_in_ms: i64::try_from(connector_event.latency()).unwrap_or(i64::MAX),
+            created_at,
+            expires_at: created_at.saturating_add(consts::CONNECTOR_EXCHANGE_RETENTION),
+        };
+
+        let db = state.store.clone();
+        tokio::spawn(
+            async move {
+                let merchant_id = exchange.merchant_id.clone();
+                if let Err(error) = db.insert_connector_exchange(exchange).await {
+                    logger::error!(?error, "Failed to store the connector exchange");
+                }
+
+                // The exchanges that are no longer retained are purged as new ones are captured,
+                // since exchanges are only captured for merchants debugging their integrations
+                if let Err(error) = db
+                    .delete_expired_connector_exchanges_by_merchant_id(&merchant_id, created_at)
+                    .await
+                {
+                    logger::error!(?error, "Failed to purge the expired connector exchanges");
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+/// The payloads are serialized JSON once masked, payloads that are not JSON are kept as strings
+fn parse_payload(payload: &str) -> serde_json::Value {
+    serde_json::from_str(payload).unwrap_or_else(|_| serde_json::Value::String(payload.to_owned()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_exchanges(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<payment_types::ConnectorExchangesResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let exchanges = db
+        .find_connector_exchanges_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_intent.payment_id,
+            date_time::now(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector exchanges of the payment")?;
+
+    Ok(ApplicationResponse::Json(
+        payment_types::ConnectorExchangesResponse {
+            payment_id: payment_intent.payment_id,
+            exchanges: exchanges
+                .into_iter()
+                .map(|exchange| payment_types::ConnectorExchange {
+                    exchange_id: exchange.exchange_id,
+                    attempt_id: exchange.attempt_id,
+                    connector: exchange.connector,
+                    flow: exchange.flow,
+                    request_id: exchange.request_id,
+                    url: exchange.url,
+                    method: exchange.method,
+                    request: exchange.request,
+                    response: exchange.response,
+                    error: exchange.error,
+                    status_code: u16::try_from(exchange.status_code).unwrap_or_default(),
+                    latency_in_ms: u64::try_from(exchange.latency_in_ms).unwrap_or_default(),
+                    created_at: exchange.created_at,
+                    expires_at: exchange.expires_at,
+                })
+                .collect(),
+        },
+    ))
+}
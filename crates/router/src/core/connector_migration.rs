@@ -0,0 +1,864 @@
+use std::str::FromStr;
+
+use api_models::{
+    connector_migration::{
+        ConnectorMigrationCreateRequest, ConnectorMigrationPaymentMethodListConstraints,
+        ConnectorMigrationPaymentMethodListResponse, ConnectorMigrationPaymentMethodResponse,
+        ConnectorMigrationResponse,
+    },
+    enums as api_enums,
+    payments::{HeaderPayload, PaymentIdType, PaymentsRequest, PaymentsResponse},
+};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    routes::{metrics, payment_methods::ParentPaymentMethodToken, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+pub const CONNECTOR_MIGRATION_TASK: &str = "CONNECTOR_MIGRATION";
+pub const CONNECTOR_MIGRATION_TAG: &str = "CONNECTOR_MIGRATION";
+
+/// Number of payment methods processed by each run of the migration task, the task being
+/// rescheduled until every payment method is processed
+const CONNECTOR_MIGRATION_PAGE_SIZE: i64 = 100;
+
+/// The number of times a page of the migration is retried when it fails on an internal error,
+/// before the migration is failed
+pub const MAX_CONNECTOR_MIGRATION_RETRIES: i32 = 3;
+
+/// The delay before a page of the migration is retried, multiplied by the number of the retry
+pub const CONNECTOR_MIGRATION_RETRY_DELAY: time::Duration = time::Duration::minutes(1);
+
+/// Maximum number of payment methods a migration can be restricted to
+const MAX_MIGRATION_PAYMENT_METHOD_IDS: usize = 10_000;
+
+/// Default and maximum number of payment methods returned when listing the outcome of a migration
+const DEFAULT_PAYMENT_METHOD_LIST_LIMIT: i64 = 100;
+const MAX_PAYMENT_METHOD_LIST_LIMIT: i64 = 1000;
+
+/// The outcome of the migration of a payment method
+struct MigrationOutcome {
+    status: enums::ConnectorMigrationPaymentMethodStatus,
+    migration_method: Option<enums::CredentialMigrationMethod>,
+    payment_id: Option<String>,
+    error_message: Option<String>,
+}
+
+impl MigrationOutcome {
+    fn migrated(
+        migration_method: enums::CredentialMigrationMethod,
+        payment_id: Option<String>,
+    ) -> Self {
+        Self {
+            status: enums::ConnectorMigrationPaymentMethodStatus::Migrated,
+            migration_method: Some(migration_method),
+            payment_id,
+            error_message: None,
+        }
+    }
+
+    fn failed(payment_id: Option<String>, error_message: String) -> Self {
+        Self {
+            status: enums::ConnectorMigrationPaymentMethodStatus::Failed,
+            migration_method: None,
+            payment_id,
+            error_message: Some(error_message),
+        }
+    }
+}
+
+fn parse_payment_method_ids(
+    connector_migration: &storage::ConnectorMigration,
+) -> RouterResult<Option<Vec<String>>> {
+    connector_migration
+        .payment_method_ids
+        .clone()
+        .map(|payment_method_ids| {
+            payment_method_ids.parse_value("ConnectorMigrationPaymentMethodIds")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn parse_allowed_methods(
+    connector_migration: &storage::ConnectorMigration,
+) -> RouterResult<Vec<enums::CredentialMigrationMethod>> {
+    connector_migration
+        .allowed_methods
+        .clone()
+        .parse_value("CredentialMigrationMethods")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn get_connector_migration_response(
+    connector_migration: storage::ConnectorMigration,
+) -> RouterResult<ConnectorMigrationResponse> {
+    let payment_method_ids = parse_payment_method_ids(&connector_migration)?;
+    let allowed_methods = parse_allowed_methods(&connector_migration)?;
+
+    Ok(ConnectorMigrationResponse {
+        migration_id: connector_migration.migration_id,
+        merchant_id: connector_migration.merchant_id,
+        source_merchant_connector_id: connector_migration.source_merchant_connector_id,
+        target_merchant_connector_id: connector_migration.target_merchant_connector_id,
+        target_connector: connector_migration.target_connector,
+        currency: connector_migration.currency,
+        allowed_methods,
+        payment_method_ids,
+        status: connector_migration.status,
+        total_count: connector_migration.total_count,
+        migrated_count: connector_migration.migrated_count,
+        skipped_count: connector_migration.skipped_count,
+        failed_count: connector_migration.failed_count,
+        error_message: connector_migration.error_message,
+        completed_at: connector_migration.completed_at,
+        created_at: connector_migration.created_at,
+    })
+}
+
+fn validate_connector_migration_request(
+    request: &ConnectorMigrationCreateRequest,
+) -> RouterResult<()> {
+    utils::when(
+        request.source_merchant_connector_id == request.target_merchant_connector_id,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message:
+                    "source_merchant_connector_id and target_merchant_connector_id must differ"
+                        .to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        request
+            .allowed_methods
+            .as_ref()
+            .is_some_and(|allowed_methods| allowed_methods.is_empty()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "allowed_methods must hold at least one method".to_string(),
+            }))
+        },
+    )?;
+
+    if let Some(payment_method_ids) = &request.payment_method_ids {
+        utils::when(
+            payment_method_ids.is_empty()
+                || payment_method_ids.len() > MAX_MIGRATION_PAYMENT_METHOD_IDS,
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "payment_method_ids must hold between 1 and {MAX_MIGRATION_PAYMENT_METHOD_IDS} payment methods"
+                    ),
+                }))
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_id: &str,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_account.merchant_id,
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.to_string(),
+        })
+}
+
+/// Creates a migration of the stored credentials of the payment methods from a connector to
+/// another and schedules it. The payment methods are migrated in the background, the merchant
+/// connector accounts remaining usable in the meantime.
+#[instrument(skip_all)]
+pub async fn create_connector_migration(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: ConnectorMigrationCreateRequest,
+) -> RouterResponse<ConnectorMigrationResponse> {
+    validate_connector_migration_request(&request)?;
+    let db = state.store.as_ref();
+
+    find_merchant_connector_account(
+        &state,
+        &merchant_account,
+        &key_store,
+        &request.source_merchant_connector_id,
+    )
+    .await?;
+    let target_merchant_connector_account = find_merchant_connector_account(
+        &state,
+        &merchant_account,
+        &key_store,
+        &request.target_merchant_connector_id,
+    )
+    .await?;
+    utils::when(
+        target_merchant_connector_account.connector_type
+            != api_enums::ConnectorType::PaymentProcessor
+            || target_merchant_connector_account.disabled == Some(true),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "The target merchant connector account must be an enabled payment processor"
+                        .to_string(),
+            }))
+        },
+    )?;
+
+    let allowed_methods = request.allowed_methods.unwrap_or_else(|| {
+        vec![
+            enums::CredentialMigrationMethod::NetworkTransactionId,
+            enums::CredentialMigrationMethod::CardDetails,
+        ]
+    });
+
+    let now = common_utils::date_time::now();
+    let connector_migration_new = storage::ConnectorMigrationNew {
+        migration_id: common_utils::generate_id(consts::ID_LENGTH, "conmig"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        source_merchant_connector_id: request.source_merchant_connector_id,
+        target_merchant_connector_id: request.target_merchant_connector_id,
+        target_connector: target_merchant_connector_account.connector_name,
+        currency: request.currency,
+        allowed_methods: allowed_methods
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        payment_method_ids: request
+            .payment_method_ids
+            .map(|payment_method_ids| payment_method_ids.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        status: enums::ConnectorMigrationStatus::Processing,
+        created_at: now,
+        modified_at: now,
+    };
+    let connector_migration = db
+        .insert_connector_migration(connector_migration_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the connector migration")?;
+
+    let tracking_data = storage::ConnectorMigrationTrackingData {
+        merchant_id: connector_migration.merchant_id.clone(),
+        migration_id: connector_migration.migration_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        format!(
+            "{runner}_{task}_{migration_id}",
+            runner = storage::ProcessTrackerRunner::ConnectorMigrationWorkflow,
+            task = CONNECTOR_MIGRATION_TASK,
+            migration_id = connector_migration.migration_id
+        ),
+        CONNECTOR_MIGRATION_TASK,
+        storage::ProcessTrackerRunner::ConnectorMigrationWorkflow,
+        [CONNECTOR_MIGRATION_TAG],
+        tracking_data,
+        now,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct connector migration process tracker task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the connector migration task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "ConnectorMigration")]),
+    );
+
+    get_connector_migration_response(connector_migration).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_migration(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    migration_id: String,
+) -> RouterResponse<ConnectorMigrationResponse> {
+    let connector_migration =
+        find_connector_migration(&state, &merchant_account, &migration_id).await?;
+
+    get_connector_migration_response(connector_migration).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_connector_migrations(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<ConnectorMigrationResponse>> {
+    let connector_migrations = state
+        .store
+        .list_connector_migrations_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the connector migrations")?;
+
+    connector_migrations
+        .into_iter()
+        .map(get_connector_migration_response)
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+/// Cancels a migration being processed. The payment methods migrated until then remain usable
+/// through the target connector.
+#[instrument(skip_all)]
+pub async fn cancel_connector_migration(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    migration_id: String,
+) -> RouterResponse<ConnectorMigrationResponse> {
+    let connector_migration =
+        find_connector_migration(&state, &merchant_account, &migration_id).await?;
+    utils::when(
+        connector_migration.status != enums::ConnectorMigrationStatus::Processing,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The connector migration cannot be cancelled as it is {}",
+                    connector_migration.status
+                ),
+            }))
+        },
+    )?;
+
+    let connector_migration = state
+        .store
+        .update_connector_migration_with_status(
+            connector_migration,
+            enums::ConnectorMigrationStatus::Processing,
+            storage::ConnectorMigrationUpdate::StatusUpdate {
+                status: enums::ConnectorMigrationStatus::Cancelled,
+                error_message: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The connector migration is no longer being processed".to_string(),
+        })?;
+
+    get_connector_migration_response(connector_migration).map(ApplicationResponse::Json)
+}
+
+/// Lists the outcome of the migration of each payment method processed so far
+#[instrument(skip_all)]
+pub async fn list_connector_migration_payment_methods(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    migration_id: String,
+    constraints: ConnectorMigrationPaymentMethodListConstraints,
+) -> RouterResponse<ConnectorMigrationPaymentMethodListResponse> {
+    let connector_migration =
+        find_connector_migration(&state, &merchant_account, &migration_id).await?;
+    let limit = constraints
+        .limit
+        .unwrap_or(DEFAULT_PAYMENT_METHOD_LIST_LIMIT)
+        .clamp(1, MAX_PAYMENT_METHOD_LIST_LIMIT);
+
+    let migration_payment_methods = state
+        .store
+        .list_connector_migration_payment_methods(
+            &connector_migration.merchant_id,
+            &connector_migration.migration_id,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment methods of the connector migration")?;
+
+    let data = migration_payment_methods
+        .into_iter()
+        .map(
+            |migration_payment_method| ConnectorMigrationPaymentMethodResponse {
+                payment_method_id: migration_payment_method.payment_method_id,
+                status: migration_payment_method.status,
+                migration_method: migration_payment_method.migration_method,
+                payment_id: migration_payment_method.payment_id,
+                error_message: migration_payment_method.error_message,
+                created_at: migration_payment_method.created_at,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        ConnectorMigrationPaymentMethodListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+async fn find_connector_migration(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    migration_id: &str,
+) -> RouterResult<storage::ConnectorMigration> {
+    state
+        .store
+        .find_connector_migration_by_merchant_id_migration_id(
+            &merchant_account.merchant_id,
+            migration_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Connector migration not found".to_string(),
+        })
+}
+
+fn get_connector_mandate_details(
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<Option<storage::PaymentsMandateReference>> {
+    payment_method
+        .connector_mandate_details
+        .clone()
+        .map(|connector_mandate_details| {
+            connector_mandate_details.parse_value("PaymentsMandateReference")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the connector mandate details")
+}
+
+/// Sets up a zero amount mandate for the payment method with the target connector. The card is
+/// retrieved from the vault through a token of the saved payment method, as for the payments made
+/// with a saved card, so that the mandate reference returned by the connector is stored on the
+/// payment method. Returns the identifier of the payment.
+async fn set_up_target_connector_mandate(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_migration: &storage::ConnectorMigration,
+    target_merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<Result<String, MigrationOutcome>> {
+    let Some(customer_acceptance) = payment_method
+        .customer_acceptance
+        .clone()
+        .map(|customer_acceptance| {
+            customer_acceptance
+                .expose()
+                .parse_value::<api_models::payments::CustomerAcceptance>("CustomerAcceptance")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+    else {
+        return Ok(Err(MigrationOutcome::failed(
+            None,
+            "No customer acceptance is recorded for the payment method".to_string(),
+        )));
+    };
+
+    let payment_token = common_utils::generate_id(consts::ID_LENGTH, "token");
+    ParentPaymentMethodToken::create_key_for_token((
+        &payment_token,
+        api_enums::PaymentMethod::Card,
+    ))
+    .insert(
+        None,
+        storage::PaymentTokenData::permanent_card(
+            Some(payment_method.payment_method_id.clone()),
+            payment_method
+                .locker_id
+                .clone()
+                .or(Some(payment_method.payment_method_id.clone())),
+            payment_method
+                .locker_id
+                .clone()
+                .unwrap_or(payment_method.payment_method_id.clone()),
+        ),
+        state,
+    )
+    .await?;
+
+    let payment_id = common_utils::generate_id(consts::ID_LENGTH, "pay");
+    let request = PaymentsRequest {
+        payment_id: Some(PaymentIdType::PaymentIntentId(payment_id.clone())),
+        amount: Some(api::Amount::Zero),
+        currency: Some(connector_migration.currency),
+        customer_id: Some(payment_method.customer_id.clone()),
+        confirm: Some(true),
+        payment_token: Some(payment_token),
+        payment_method: Some(api_enums::PaymentMethod::Card),
+        payment_method_type: payment_method.payment_method_type,
+        setup_future_usage: Some(api_enums::FutureUsage::OffSession),
+        customer_acceptance: Some(customer_acceptance),
+        payment_type: Some(api_enums::PaymentType::SetupMandate),
+        routing: Some(serde_json::json!({
+            "type": "single",
+            "data": {
+                "connector": target_merchant_connector_account.connector_name,
+                "merchant_connector_id": target_merchant_connector_account.merchant_connector_id,
+            }
+        })),
+        profile_id: target_merchant_connector_account.profile_id.clone(),
+        description: Some(format!(
+            "Migration {} of the stored credentials",
+            connector_migration.migration_id
+        )),
+        ..Default::default()
+    };
+
+    let payments_response = match Box::pin(payments::payments_core::<
+        api::SetupMandate,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payments::PaymentCreate,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await
+    {
+        Ok(
+            ApplicationResponse::Json(payments_response)
+            | ApplicationResponse::JsonWithHeaders((payments_response, _)),
+        ) => payments_response,
+        Ok(_) => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response from the mandate setup");
+        }
+        Err(error) => {
+            return Ok(Err(MigrationOutcome::failed(
+                Some(payment_id),
+                error.current_context().error_message(),
+            )));
+        }
+    };
+
+    if payments_response.status != api_enums::IntentStatus::Succeeded {
+        return Ok(Err(MigrationOutcome::failed(
+            Some(payment_id),
+            payments_response.error_message.unwrap_or_else(|| {
+                format!(
+                    "The mandate setup with the target connector is {}",
+                    payments_response.status
+                )
+            }),
+        )));
+    }
+
+    // The mandate reference is stored on the payment method once the mandate is set up
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &payment_method.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    let has_target_mandate =
+        get_connector_mandate_details(&payment_method)?.is_some_and(|mandates| {
+            mandates.contains_key(&connector_migration.target_merchant_connector_id)
+        });
+    if !has_target_mandate {
+        return Ok(Err(MigrationOutcome::failed(
+            Some(payment_id),
+            "The target connector did not return a mandate reference".to_string(),
+        )));
+    }
+
+    Ok(Ok(payment_id))
+}
+
+/// Migrates the stored credentials of a payment method with the first of the allowed methods
+/// which succeeds
+async fn migrate_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_migration: &storage::ConnectorMigration,
+    target_merchant_connector_account: &domain::MerchantConnectorAccount,
+    business_profile: &storage::BusinessProfile,
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<MigrationOutcome> {
+    let target_connector = api_enums::Connector::from_str(&connector_migration.target_connector)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid target connector name")?;
+
+    let mut failure_reasons = Vec::new();
+    let mut last_payment_id = None;
+    for migration_method in parse_allowed_methods(connector_migration)? {
+        match migration_method {
+            enums::CredentialMigrationMethod::NetworkTransactionId => {
+                // The network transaction id is used for the MITs through the target connector
+                // as is, no call to the connector being required
+                if payments::is_network_transaction_id_flow(
+                    state,
+                    business_profile.is_connector_agnostic_mit_enabled,
+                    target_connector,
+                    payment_method,
+                ) {
+                    return Ok(MigrationOutcome::migrated(migration_method, None));
+                }
+                failure_reasons.push(
+                    "The network transaction id cannot be used through the target connector"
+                        .to_string(),
+                );
+            }
+            enums::CredentialMigrationMethod::CardDetails => match set_up_target_connector_mandate(
+                state,
+                merchant_account,
+                key_store,
+                connector_migration,
+                target_merchant_connector_account,
+                payment_method,
+            )
+            .await?
+            {
+                Ok(payment_id) => {
+                    return Ok(MigrationOutcome::migrated(
+                        migration_method,
+                        Some(payment_id),
+                    ))
+                }
+                Err(outcome) => {
+                    last_payment_id = outcome.payment_id;
+                    failure_reasons.extend(outcome.error_message);
+                }
+            },
+        }
+    }
+
+    Ok(MigrationOutcome::failed(
+        last_payment_id,
+        failure_reasons.join("; "),
+    ))
+}
+
+/// Migrates the next page of payment methods of a migration, recording the outcome for each of
+/// them, and completes the migration once every payment method is processed. Only the payment
+/// methods with a mandate through the source connector are migrated. The migration resumes after
+/// the last payment method processed, so that a migration interrupted midway is not started over.
+#[instrument(skip_all, fields(migration_id = %connector_migration.migration_id))]
+pub async fn run_connector_migration(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_migration: storage::ConnectorMigration,
+) -> RouterResult<storage::ConnectorMigration> {
+    let db = state.store.as_ref();
+    let payment_method_ids = parse_payment_method_ids(&connector_migration)?;
+    let payment_methods = db
+        .list_card_payment_methods_for_export(
+            &connector_migration.merchant_id,
+            payment_method_ids.as_deref(),
+            connector_migration.last_payment_method_id.as_deref(),
+            CONNECTOR_MIGRATION_PAGE_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment methods to migrate")?;
+    let is_last_page = i64::try_from(payment_methods.len())
+        .map(|count| count < CONNECTOR_MIGRATION_PAGE_SIZE)
+        .unwrap_or(true);
+
+    let mut connector_migration = connector_migration;
+    if let Some(last_payment_method_id) = payment_methods
+        .last()
+        .map(|payment_method| payment_method.payment_method_id.clone())
+    {
+        let target_merchant_connector_account = find_merchant_connector_account(
+            state,
+            merchant_account,
+            key_store,
+            &connector_migration.target_merchant_connector_id,
+        )
+        .await?;
+        let profile_id = target_merchant_connector_account
+            .profile_id
+            .clone()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(&profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id,
+            })?;
+
+        let mut total_count = connector_migration.total_count;
+        let mut migrated_count = connector_migration.migrated_count;
+        let mut skipped_count = connector_migration.skipped_count;
+        let mut failed_count = connector_migration.failed_count;
+        for payment_method in payment_methods {
+            let Some(connector_mandate_details) = get_connector_mandate_details(&payment_method)?
+            else {
+                continue;
+            };
+            if !connector_mandate_details
+                .contains_key(&connector_migration.source_merchant_connector_id)
+            {
+                continue;
+            }
+
+            let outcome = if connector_mandate_details
+                .contains_key(&connector_migration.target_merchant_connector_id)
+            {
+                MigrationOutcome {
+                    status: enums::ConnectorMigrationPaymentMethodStatus::Skipped,
+                    migration_method: None,
+                    payment_id: None,
+                    error_message: None,
+                }
+            } else {
+                migrate_payment_method(
+                    state,
+                    merchant_account,
+                    key_store,
+                    &connector_migration,
+                    &target_merchant_connector_account,
+                    &business_profile,
+                    &payment_method,
+                )
+                .await?
+            };
+
+            total_count += 1;
+            match outcome.status {
+                enums::ConnectorMigrationPaymentMethodStatus::Migrated => migrated_count += 1,
+                enums::ConnectorMigrationPaymentMethodStatus::Skipped => skipped_count += 1,
+                enums::ConnectorMigrationPaymentMethodStatus::Failed => failed_count += 1,
+            }
+            metrics::CONNECTOR_MIGRATION_PAYMENT_METHODS.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("status", outcome.status.to_string())]),
+            );
+
+            let migration_payment_method = storage::ConnectorMigrationPaymentMethodNew {
+                migration_id: connector_migration.migration_id.clone(),
+                payment_method_id: payment_method.payment_method_id,
+                merchant_id: connector_migration.merchant_id.clone(),
+                status: outcome.status,
+                migration_method: outcome.migration_method,
+                payment_id: outcome.payment_id,
+                error_message: outcome.error_message,
+                created_at: common_utils::date_time::now(),
+            };
+            match db
+                .insert_connector_migration_payment_method(migration_payment_method)
+                .await
+            {
+                Ok(_) => {}
+                // The payment method was processed by a run of the migration which was interrupted
+                Err(error) if error.current_context().is_db_unique_violation() => {
+                    logger::info!("Payment method was already processed by the migration");
+                }
+                Err(error) => {
+                    return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                        .attach_printable(
+                            "Failed to record the migration of the payment method",
+                        );
+                }
+            }
+        }
+
+        connector_migration = match db
+            .update_connector_migration_with_status(
+                connector_migration.clone(),
+                enums::ConnectorMigrationStatus::Processing,
+                storage::ConnectorMigrationUpdate::ProgressUpdate {
+                    last_payment_method_id,
+                    total_count,
+                    migrated_count,
+                    skipped_count,
+                    failed_count,
+                },
+            )
+            .await
+        {
+            Ok(connector_migration) => connector_migration,
+            // The migration was cancelled while the page was being processed
+            Err(error) if error.current_context().is_db_not_found() => {
+                return find_connector_migration(
+                    state,
+                    merchant_account,
+                    &connector_migration.migration_id,
+                )
+                .await;
+            }
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Failed to record the progress of the connector migration");
+            }
+        };
+    }
+
+    if !is_last_page {
+        return Ok(connector_migration);
+    }
+
+    db.update_connector_migration_with_status(
+        connector_migration,
+        enums::ConnectorMigrationStatus::Processing,
+        storage::ConnectorMigrationUpdate::StatusUpdate {
+            status: enums::ConnectorMigrationStatus::Completed,
+            error_message: None,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to complete the connector migration")
+}
+
+/// Fails a migration which could not be carried out, the payment methods migrated until then
+/// remaining usable through the target connector
+#[instrument(skip_all, fields(migration_id = %connector_migration.migration_id))]
+pub async fn fail_connector_migration(
+    state: &SessionState,
+    connector_migration: storage::ConnectorMigration,
+    error_message: String,
+) -> RouterResult<storage::ConnectorMigration> {
+    state
+        .store
+        .update_connector_migration_with_status(
+            connector_migration,
+            enums::ConnectorMigrationStatus::Processing,
+            storage::ConnectorMigrationUpdate::StatusUpdate {
+                status: enums::ConnectorMigrationStatus::Failed,
+                error_message: Some(error_message),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fail the connector migration")
+}
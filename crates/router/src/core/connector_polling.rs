@@ -0,0 +1,204 @@
+use common_utils::ext_traits::StringExt;
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use rand::Rng;
+use router_env::logger;
+use scheduler::{consumer::types::process_data, utils as scheduler_utils};
+
+use crate::{
+    db::StorageInterface,
+    errors,
+    types::storage::{self, enums},
+};
+
+/// The payment methods of the connectors whose payments are never resolved through the sync API
+/// of the connector, the connector notifying their outcome through webhooks only
+const WEBHOOK_ONLY_PAYMENT_METHODS: &[(&str, enums::PaymentMethod)] =
+    &[("stripe", enums::PaymentMethod::BankTransfer)];
+
+/// The objects whose status is polled from the sync API of their connector until they reach a
+/// terminal status
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum PollingObject {
+    Payment,
+    Refund,
+}
+
+impl PollingObject {
+    /// The key of the config holding the polling intervals of the objects of the connector
+    fn get_config_key(self, connector: &str) -> String {
+        match self {
+            Self::Payment => format!("pt_mapping_{connector}"),
+            Self::Refund => format!("pt_mapping_refund_sync_{connector}"),
+        }
+    }
+}
+
+/// Whether the payments of the connector made with the payment method are to be polled from the
+/// sync API of the connector until they reach a terminal status
+pub fn is_payment_polling_required(
+    connector: Option<&str>,
+    payment_method: Option<enums::PaymentMethod>,
+) -> bool {
+    !WEBHOOK_ONLY_PAYMENT_METHODS.iter().any(
+        |&(webhook_only_connector, webhook_only_payment_method)| {
+            connector == Some(webhook_only_connector)
+                && payment_method == Some(webhook_only_payment_method)
+        },
+    )
+}
+
+/// The polling intervals of the objects of the connector, read from the config of the object and
+/// connector or else the default intervals
+async fn get_polling_mapping(
+    db: &dyn StorageInterface,
+    object: PollingObject,
+    connector: &str,
+) -> process_data::ConnectorPTMapping {
+    let mapping = db
+        .find_config_by_key(&object.get_config_key(connector))
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("ConnectorPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+
+    match mapping {
+        Ok(mapping) => mapping,
+        Err(error) => {
+            logger::info!(?error, %object, %connector, "Using the default polling intervals");
+            process_data::ConnectorPTMapping::default()
+        }
+    }
+}
+
+/// Moves the delay earlier or later by up to the percentage provided, at random
+fn apply_jitter(delay: i32, jitter_percentage: u8) -> i32 {
+    let max_jitter = delay.saturating_mul(i32::from(jitter_percentage.min(100))) / 100;
+    if max_jitter <= 0 {
+        return delay;
+    }
+
+    delay
+        .saturating_add(rand::thread_rng().gen_range(-max_jitter..=max_jitter))
+        .max(0)
+}
+
+/// Get the time the object is to be polled at next, `None` once all the polls are exhausted
+///
+/// The polling intervals can be configured for each connector in configs, with the key
+/// `pt_mapping_{connector}` for payments and `pt_mapping_refund_sync_{connector}` for refunds
+/// ```json
+/// {
+///     "default_mapping": {
+///         "start_after": 60,
+///         "frequencies": [[300, 5]]
+///     },
+///     "custom_merchant_mapping": {},
+///     "max_retries_count": 5,
+///     "jitter_percentage": 10
+/// }
+/// ```
+///
+/// This config represents
+///
+/// `start_after`: The first poll should happen after 60 seconds
+///
+/// `frequencies`: The next 5 polls should have an interval of 300 seconds between them
+///
+/// `jitter_percentage`: Each interval is moved earlier or later by up to 10% of it at random
+pub async fn get_polling_schedule_time(
+    db: &dyn StorageInterface,
+    object: PollingObject,
+    connector: &str,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+    let mapping = get_polling_mapping(db, object, connector).await;
+    let jitter_percentage = mapping.jitter_percentage;
+    let time_delta = scheduler_utils::get_schedule_time(mapping, merchant_id, retry_count)
+        .map(|delay| apply_jitter(delay, jitter_percentage));
+
+    Ok(scheduler_utils::get_time_from_delta(time_delta))
+}
+
+/// Schedules the next poll of the object tracked by the task, finishing the task once all the
+/// polls are exhausted
+///
+/// Returns bool which indicates whether this was the last poll or not
+pub async fn retry_polling_task(
+    db: &dyn StorageInterface,
+    object: PollingObject,
+    connector: &str,
+    merchant_id: &str,
+    pt: storage::ProcessTracker,
+) -> Result<bool, errors::ProcessTrackerError> {
+    let schedule_time =
+        get_polling_schedule_time(db, object, connector, merchant_id, pt.retry_count + 1).await?;
+
+    match schedule_time {
+        Some(s_time) => {
+            db.as_scheduler().retry_process(pt, s_time).await?;
+            Ok(false)
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(pt, business_status::RETRIES_EXCEEDED)
+                .await?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_default_schedule_time() {
+        let schedule_time_delta =
+            scheduler_utils::get_schedule_time(process_data::ConnectorPTMapping::default(), "-", 0)
+                .unwrap();
+        let first_retry_time_delta =
+            scheduler_utils::get_schedule_time(process_data::ConnectorPTMapping::default(), "-", 1)
+                .unwrap();
+        let cpt_default = process_data::ConnectorPTMapping::default().default_mapping;
+        assert_eq!(
+            vec![schedule_time_delta, first_retry_time_delta],
+            vec![
+                cpt_default.start_after,
+                cpt_default.frequencies.first().unwrap().0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_jitter_stays_within_percentage() {
+        for _ in 0..100 {
+            let delay = apply_jitter(300, 10);
+            assert!((270..=330).contains(&delay));
+        }
+        assert_eq!(apply_jitter(300, 0), 300);
+        assert_eq!(apply_jitter(5, 10), 5);
+    }
+
+    #[test]
+    fn test_webhook_only_payment_methods_are_not_polled() {
+        assert!(!is_payment_polling_required(
+            Some("stripe"),
+            Some(enums::PaymentMethod::BankTransfer)
+        ));
+        assert!(is_payment_polling_required(
+            Some("stripe"),
+            Some(enums::PaymentMethod::Card)
+        ));
+        assert!(is_payment_polling_required(
+            Some("adyen"),
+            Some(enums::PaymentMethod::BankTransfer)
+        ));
+    }
+}
@@ -0,0 +1,127 @@
+//! Brief caching of the responses of the connectors to the payment and refund syncs. Dashboards
+//! polling a payment make bursts of syncs, each of which would otherwise call the connector and
+//! consume the rate limits of the merchant at the connector. The successful responses are cached
+//! for a few seconds, keyed by the connector transaction id along with the status the payment or
+//! refund had when it was synced, so that a sync following a capture or a void of the payment is
+//! not served the response cached before it.
+
+use std::fmt::Display;
+
+use masking::{ExposeInterface, Secret};
+use router_env::logger;
+use tracing_futures::Instrument;
+
+use crate::{core::errors, routes::SessionState, types};
+
+/// The successful response of a connector, as cached. The body is kept out of the logs like the
+/// other responses of the connectors.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CachedConnectorResponse {
+    status_code: u16,
+    body: Secret<String>,
+}
+
+/// The cache of the response of a connector to the sync of a transaction
+#[derive(Debug, Clone)]
+pub struct ConnectorResponseCache {
+    key: String,
+    ttl_in_secs: u32,
+}
+
+impl ConnectorResponseCache {
+    /// The cache of the response of the connector to the sync of the transaction, if the responses
+    /// of the connector are cached
+    pub fn new(
+        state: &SessionState,
+        merchant_id: &str,
+        connector: &str,
+        flow: &str,
+        connector_transaction_id: &str,
+        status: impl Display,
+    ) -> Option<Self> {
+        let settings = &state.conf.connector_response_cache;
+        if !settings.enabled {
+            return None;
+        }
+
+        let ttl_in_secs = settings.get_ttl_in_secs(connector);
+        (ttl_in_secs > 0).then(|| Self {
+            key: format!(
+                "connector_response_{merchant_id}_{connector}_{flow}_{connector_transaction_id}_{status}"
+            ),
+            ttl_in_secs,
+        })
+    }
+
+    /// The response cached, failures to read the cache being logged and treated as a miss
+    pub async fn get(&self, state: &SessionState) -> Option<types::Response> {
+        let redis_conn = match state.store.get_redis_conn() {
+            Ok(redis_conn) => redis_conn,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to get redis connection for the response cache"
+                );
+                return None;
+            }
+        };
+
+        match redis_conn
+            .get_and_deserialize_key::<CachedConnectorResponse>(
+                &self.key,
+                "CachedConnectorResponse",
+            )
+            .await
+        {
+            Ok(cached_response) => {
+                logger::debug!("Serving the sync with the cached connector response");
+                Some(types::Response {
+                    headers: None,
+                    response: cached_response.body.expose().into_bytes().into(),
+                    status_code: cached_response.status_code,
+                })
+            }
+            Err(error) if matches!(error.current_context(), errors::RedisError::NotFound) => None,
+            Err(error) => {
+                logger::error!(?error, "Failed to read the cached connector response");
+                None
+            }
+        }
+    }
+
+    /// Caches the response in the background, the responses whose body is not UTF-8 being left
+    /// out
+    pub fn store(&self, state: &SessionState, response: &types::Response) {
+        let Ok(body) = String::from_utf8(response.response.to_vec()) else {
+            return;
+        };
+        let redis_conn = match state.store.get_redis_conn() {
+            Ok(redis_conn) => redis_conn,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to get redis connection for the response cache"
+                );
+                return;
+            }
+        };
+
+        let key = self.key.clone();
+        let ttl_in_secs = i64::from(self.ttl_in_secs);
+        let cached_response = CachedConnectorResponse {
+            status_code: response.status_code,
+            body: Secret::new(body),
+        };
+        tokio::spawn(
+            async move {
+                if let Err(error) = redis_conn
+                    .serialize_and_set_key_with_expiry(&key, cached_response, ttl_in_secs)
+                    .await
+                {
+                    logger::error!(?error, "Failed to cache the connector response");
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
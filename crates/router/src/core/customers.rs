@@ -2,13 +2,15 @@ use common_utils::{
     crypto::{Encryptable, GcmAes256},
     errors::ReportSwitchExt,
     ext_traits::OptionExt,
+    id_type,
 };
 use error_stack::{report, ResultExt};
 use masking::ExposeInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, metrics::add_attributes, tracing};
 
 use crate::{
     core::{
+        blind_index,
         errors::{self, StorageErrorExt},
         payment_methods::cards,
     },
@@ -27,6 +29,8 @@ use crate::{
 };
 
 pub const REDACTED: &str = "Redacted";
+pub const CUSTOMER_REDACTION_TASK: &str = "CUSTOMER_REDACTION";
+pub const CUSTOMER_REDACTION_TAG: &str = "CUSTOMER";
 
 #[instrument(skip(state))]
 pub async fn create_customer(
@@ -96,6 +100,22 @@ pub async fn create_customer(
         None
     };
 
+    let blind_indexes = blind_index::get_customer_blind_indexes(
+        db.get_master_key(),
+        merchant_id,
+        customer_data
+            .email
+            .as_ref()
+            .map(|email| email.peek().as_str()),
+        customer_data
+            .phone
+            .as_ref()
+            .map(|phone| phone.peek().as_str()),
+        customer_data.phone_country_code.as_deref(),
+    )
+    .change_context(errors::CustomersErrorResponse::InternalServerError)
+    .attach_printable("Failed to generate the blind indexes of the customer")?;
+
     let new_customer = async {
         Ok(domain::Customer {
             customer_id: customer_id.to_owned(),
@@ -122,6 +142,9 @@ pub async fn create_customer(
             modified_at: common_utils::date_time::now(),
             default_payment_method_id: None,
             updated_by: None,
+            payment_method_order: None,
+            email_blind_index: blind_indexes.email,
+            phone_blind_index: blind_indexes.phone,
         })
     }
     .await
@@ -170,6 +193,166 @@ pub async fn retrieve_customer(
     ))
 }
 
+/// Number of the latest payments of the customer included in the overview of the customer
+#[cfg(feature = "olap")]
+const OVERVIEW_RECENT_PAYMENTS_LIMIT: u32 = 10;
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn get_customer_overview(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> errors::RouterResponse<customers::CustomerOverviewResponse> {
+    use hyperswitch_domain_models::payments::payment_intent::{
+        PaymentIntentFetchConstraints, PaymentIntentListParams,
+    };
+
+    let db = state.store.as_ref();
+    let merchant_id = &merchant_account.merchant_id;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(&req.customer_id, merchant_id, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the customer")?
+        .into_iter()
+        .map(|payment_method| customers::CustomerOverviewPaymentMethod {
+            is_default: customer.default_payment_method_id.as_ref()
+                == Some(&payment_method.payment_method_id),
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            status: payment_method.status,
+            last_used_at: payment_method.last_used_at,
+        })
+        .collect();
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?
+        .into_iter()
+        .map(|mandate| customers::CustomerOverviewMandate {
+            mandate_id: mandate.mandate_id,
+            status: mandate.mandate_status,
+            payment_method_id: mandate.payment_method_id,
+            connector: mandate.connector,
+            created_at: mandate.created_at,
+        })
+        .collect();
+
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: Some(req.customer_id.clone()),
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(OVERVIEW_RECENT_PAYMENTS_LIMIT),
+        cursor: None,
+        card_network: None,
+        metadata: None,
+        order_id: None,
+        custom_fields: None,
+    }));
+    let recent_payments = db
+        .filter_payment_intent_by_constraints(
+            merchant_id,
+            &constraints,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments of the customer")?
+        .into_iter()
+        .map(|payment_intent| customers::CustomerOverviewPayment {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            amount: payment_intent.amount,
+            currency: payment_intent.currency,
+            created_at: payment_intent.created_at,
+        })
+        .collect();
+
+    let activity = db
+        .get_customer_activity(&req.customer_id, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the activity of the customer")?;
+
+    let email = customer.email.clone().map(common_utils::pii::Email::from);
+    let mut fraud_flags = crate::core::blocklist::utils::get_blocked_customer_data_kinds(
+        &state,
+        merchant_id,
+        &req.customer_id,
+        email.as_ref(),
+    )
+    .await?
+    .into_iter()
+    .filter_map(|data_kind| match data_kind {
+        common_enums::BlocklistDataKind::CustomerId => {
+            Some(customers::CustomerFraudFlag::BlocklistedCustomerId)
+        }
+        common_enums::BlocklistDataKind::Email => {
+            Some(customers::CustomerFraudFlag::BlocklistedEmail)
+        }
+        _ => None,
+    })
+    .collect::<Vec<_>>();
+    if activity.fraudulent_payment_count > 0 {
+        fraud_flags.push(customers::CustomerFraudFlag::FraudulentPayments);
+    }
+
+    let address = match &customer.address_id {
+        Some(address_id) => Some(api_models::payments::AddressDetails::from(
+            db.find_address_by_address_id(address_id, &key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the address of the customer")?,
+        )),
+        None => None,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerOverviewResponse {
+            customer: customers::CustomerResponse::from((customer, address)).into_inner(),
+            payment_methods,
+            mandates,
+            recent_payments,
+            total_spend: activity
+                .captured_amounts
+                .into_iter()
+                .map(|(currency, amount)| customers::CustomerSpend { currency, amount })
+                .collect(),
+            dispute_count: activity.dispute_count,
+            fraud_flags,
+        },
+    ))
+}
+
 #[instrument(skip(state))]
 pub async fn list_customers(
     state: SessionState,
@@ -221,58 +404,87 @@ pub async fn delete_customer(
         }
     }
 
+    delete_customer_payment_methods(&state, &merchant_account.merchant_id, &req.customer_id)
+        .await
+        .switch()?;
+
+    redact_customer_details(&state, &merchant_account, &key_store, customer_orig)
+        .await
+        .switch()?;
+
+    let response = customers::CustomerDeleteResponse {
+        customer_id: req.customer_id,
+        customer_deleted: true,
+        address_deleted: true,
+        payment_methods_deleted: true,
+    };
+    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Deletes the payment methods of the customer, along with the cards saved in the locker
+async fn delete_customer_payment_methods(
+    state: &SessionState,
+    merchant_id: &str,
+    customer_id: &id_type::CustomerId,
+) -> errors::RouterResult<()> {
+    let db = &state.store;
+
     match db
-        .find_payment_method_by_customer_id_merchant_id_list(
-            &req.customer_id,
-            &merchant_account.merchant_id,
-            None,
-        )
+        .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id, None)
         .await
     {
-        // check this in review
         Ok(customer_payment_methods) => {
             for pm in customer_payment_methods.into_iter() {
                 if pm.payment_method == Some(enums::PaymentMethod::Card) {
                     cards::delete_card_from_locker(
-                        &state,
-                        &req.customer_id,
-                        &merchant_account.merchant_id,
+                        state,
+                        customer_id,
+                        merchant_id,
                         pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id),
                     )
-                    .await
-                    .switch()?;
+                    .await?;
                 }
                 db.delete_payment_method_by_merchant_id_payment_method_id(
-                    &merchant_account.merchant_id,
+                    merchant_id,
                     &pm.payment_method_id,
                 )
                 .await
-                .switch()?;
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "failed delete_payment_method_by_merchant_id_payment_method_id",
+                )?;
             }
+            Ok(())
         }
-        Err(error) => {
-            if error.current_context().is_db_not_found() {
-                Ok(())
-            } else {
-                Err(error)
-                    .change_context(errors::CustomersErrorResponse::InternalServerError)
-                    .attach_printable("failed find_payment_method_by_customer_id_merchant_id_list")
-            }?
-        }
-    };
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed find_payment_method_by_customer_id_merchant_id_list"),
+    }
+}
 
+/// Overwrites the personal data of the customer and of their addresses with redacted values, the
+/// customer being considered as deleted afterwards
+async fn redact_customer_details(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer: domain::Customer,
+) -> errors::RouterResult<()> {
+    let db = &state.store;
     let key = key_store.key.get_inner().peek();
 
     let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
         Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
             .await
-            .switch()?;
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
     let redacted_encrypted_email: Encryptable<
         masking::Secret<_, common_utils::pii::EmailStrategy>,
     > = Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
         .await
-        .switch()?;
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
     let update_address = storage::AddressUpdate::Update {
         city: Some(REDACTED.to_string()),
@@ -287,15 +499,15 @@ pub async fn delete_customer(
         phone_number: Some(redacted_encrypted_value.clone()),
         country_code: Some(REDACTED.to_string()),
         updated_by: merchant_account.storage_scheme.to_string(),
-        email: Some(redacted_encrypted_email),
+        email: Some(redacted_encrypted_email.clone()),
     };
 
     match db
         .update_address_by_merchant_id_customer_id(
-            &req.customer_id,
+            &customer.customer_id,
             &merchant_account.merchant_id,
             update_address,
-            &key_store,
+            key_store,
         )
         .await
     {
@@ -305,7 +517,7 @@ pub async fn delete_customer(
                 Ok(())
             } else {
                 Err(error)
-                    .change_context(errors::CustomersErrorResponse::InternalServerError)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("failed update_address_by_merchant_id_customer_id")
             }
         }
@@ -313,37 +525,246 @@ pub async fn delete_customer(
 
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(redacted_encrypted_value.clone()),
-        email: Some(
-            Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
-                .await
-                .switch()?,
-        ),
+        email: Some(redacted_encrypted_email),
         phone: Box::new(Some(redacted_encrypted_value.clone())),
         description: Some(REDACTED.to_string()),
         phone_country_code: Some(REDACTED.to_string()),
         metadata: None,
         connector_customer: None,
         address_id: None,
+        email_blind_index: Some(None),
+        phone_blind_index: Some(None),
     };
     db.update_customer_by_customer_id_merchant_id(
-        req.customer_id.clone(),
-        merchant_account.merchant_id,
-        customer_orig,
+        customer.customer_id.clone(),
+        merchant_account.merchant_id.clone(),
+        customer,
         updated_customer,
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed update_customer_by_customer_id_merchant_id")?;
+
+    Ok(())
+}
+
+pub fn generate_task_id_for_customer_redaction_workflow(
+    merchant_id: &str,
+    customer_id: &id_type::CustomerId,
+) -> String {
+    format!(
+        "{runner}_{task}_{merchant_id}_{customer_id}",
+        runner = storage::ProcessTrackerRunner::CustomerRedactionWorkflow,
+        task = CUSTOMER_REDACTION_TASK,
+        customer_id = customer_id.get_string_repr()
+    )
+}
+
+#[instrument(skip(state))]
+pub async fn redact_customer(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> errors::RouterResponse<customers::CustomerRedactionResponse> {
+    let db = &*state.store;
+    let task_id = generate_task_id_for_customer_redaction_workflow(
+        &merchant_account.merchant_id,
+        &req.customer_id,
+    );
+
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer redaction task")?;
+
+    // A redaction which is yet to complete or has completed is not scheduled again, so that the
+    // request can be safely retried
+    if let Some(process) = &process {
+        let response = get_customer_redaction_response(process, req.customer_id.clone());
+        if response.status != customers::CustomerRedactionStatus::Failed {
+            return Ok(services::ApplicationResponse::Json(response));
+        }
+    }
+
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        &merchant_account.merchant_id,
         &key_store,
         merchant_account.storage_scheme,
     )
     .await
-    .switch()?;
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let response = customers::CustomerDeleteResponse {
-        customer_id: req.customer_id,
-        customer_deleted: true,
-        address_deleted: true,
-        payment_methods_deleted: true,
+    let has_active_mandate = db
+        .find_mandate_by_merchant_id_customer_id(&merchant_account.merchant_id, &req.customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?
+        .iter()
+        .any(|mandate| mandate.mandate_status == enums::MandateStatus::Active);
+    if has_active_mandate {
+        Err(errors::ApiErrorResponse::MandateActive)?
+    }
+
+    let schedule_time = common_utils::date_time::now();
+    match process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the customer redaction task")?,
+        None => {
+            let tracking_data = storage::CustomerRedactionTrackingData {
+                merchant_id: merchant_account.merchant_id.clone(),
+                customer_id: req.customer_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id.clone(),
+                CUSTOMER_REDACTION_TASK,
+                storage::ProcessTrackerRunner::CustomerRedactionWorkflow,
+                [CUSTOMER_REDACTION_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct customer redaction process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the customer redaction task")?;
+
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "CustomerRedaction")]),
+            );
+        }
+    }
+
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer redaction task")?
+        .get_required_value("process")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Customer redaction task not found after scheduling it")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_redaction_response(&process, req.customer_id),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_customer_redaction(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: customers::CustomerId,
+) -> errors::RouterResponse<customers::CustomerRedactionResponse> {
+    let task_id = generate_task_id_for_customer_redaction_workflow(
+        &merchant_account.merchant_id,
+        &req.customer_id,
+    );
+    let process = state
+        .store
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer redaction task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "no redaction was requested for the customer".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_redaction_response(&process, req.customer_id),
+    ))
+}
+
+pub fn get_customer_redaction_response(
+    process: &storage::ProcessTracker,
+    customer_id: id_type::CustomerId,
+) -> customers::CustomerRedactionResponse {
+    let status = match process.status {
+        enums::ProcessTrackerStatus::New | enums::ProcessTrackerStatus::Paused => {
+            customers::CustomerRedactionStatus::Pending
+        }
+        enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::ProcessStarted => {
+            customers::CustomerRedactionStatus::InProgress
+        }
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            customers::CustomerRedactionStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => customers::CustomerRedactionStatus::Failed,
     };
-    metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
-    Ok(services::ApplicationResponse::Json(response))
+
+    customers::CustomerRedactionResponse {
+        redaction_id: process.id.clone(),
+        customer_id,
+        status,
+        created_at: process.created_at,
+        completed_at: (process.status == enums::ProcessTrackerStatus::Finish)
+            .then_some(process.updated_at),
+    }
+}
+
+/// Redacts the personal data of the customer across the customer, their addresses, payment
+/// methods, payments and mandates, leaving the financial records of the payments untouched.
+/// The redaction can be run again after a partial failure.
+pub async fn run_customer_redaction(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+
+    let customer = match db
+        .find_customer_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(customer) => Some(customer),
+        // The customer was redacted by an earlier run, which failed after doing so
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::StorageError::CustomerRedacted
+            ) =>
+        {
+            None
+        }
+        Err(error) => {
+            Err(error).to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?
+        }
+    };
+
+    delete_customer_payment_methods(state, &merchant_account.merchant_id, customer_id).await?;
+
+    db.redact_customer_payment_data(customer_id, &merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to redact the payments of the customer")?;
+
+    if let Some(customer) = customer {
+        redact_customer_details(state, merchant_account, key_store, customer).await?;
+        metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
+    }
+
+    Ok(())
 }
 
 #[instrument(skip(state))]
@@ -375,6 +796,22 @@ pub async fn update_customer(
 
     let key = key_store.key.get_inner().peek();
 
+    let blind_indexes = blind_index::get_customer_update_blind_indexes(
+        db.get_master_key(),
+        &customer,
+        update_customer
+            .email
+            .as_ref()
+            .map(|email| email.peek().as_str()),
+        update_customer
+            .phone
+            .as_ref()
+            .map(|phone| phone.peek().as_str()),
+        update_customer.phone_country_code.as_deref(),
+    )
+    .change_context(errors::CustomersErrorResponse::InternalServerError)
+    .attach_printable("Failed to generate the blind indexes of the customer")?;
+
     let address = if let Some(addr) = &update_customer.address {
         match customer.address_id.clone() {
             Some(address_id) => {
@@ -455,6 +892,8 @@ pub async fn update_customer(
                     description: update_customer.description,
                     connector_customer: None,
                     address_id: address.clone().map(|addr| addr.address_id),
+                    email_blind_index: blind_indexes.email.map(Some),
+                    phone_blind_index: blind_indexes.phone.map(Some),
                 })
             }
             .await
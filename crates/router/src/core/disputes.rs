@@ -2,6 +2,7 @@ use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
+pub mod auto_response;
 pub mod transformers;
 
 use super::{
@@ -45,17 +46,41 @@ pub async fn retrieve_disputes_list(
     merchant_account: domain::MerchantAccount,
     constraints: api_models::disputes::DisputeListConstraints,
 ) -> RouterResponse<Vec<api_models::disputes::DisputeResponse>> {
+    let limit = constraints.limit;
     let disputes = state
         .store
         .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve disputes")?;
+
+    // The list is returned as is for compatibility, so the position of the next page is sent in a
+    // header
+    let next_cursor_header = disputes
+        .last()
+        .filter(|_| {
+            limit
+                .is_some_and(|limit| disputes.len() >= usize::try_from(limit).unwrap_or(usize::MAX))
+        })
+        .map(|dispute| {
+            let cursor = api_models::payments::ListCursor::new(
+                dispute.created_at,
+                dispute.dispute_id.clone(),
+            );
+            (
+                crate::headers::X_NEXT_CURSOR.to_string(),
+                cursor.to_string().into(),
+            )
+        });
+
     let disputes_list = disputes
         .into_iter()
         .map(api_models::disputes::DisputeResponse::foreign_from)
         .collect();
-    Ok(services::ApplicationResponse::Json(disputes_list))
+    Ok(services::ApplicationResponse::JsonWithHeaders((
+        disputes_list,
+        next_cursor_header.into_iter().collect(),
+    )))
 }
 
 #[instrument(skip(state))]
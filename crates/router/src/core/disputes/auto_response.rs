@@ -0,0 +1,181 @@
+use api_models::{
+    admin::{DisputeAutoResponseConfig, DisputeEvidenceTemplate},
+    disputes as dispute_models,
+};
+use common_utils::{ext_traits::ValueExt, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    types::{
+        api::disputes,
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// The response to a dispute chosen by the auto response rules of its profile
+enum DisputeAutoResponse {
+    Accept,
+    SubmitEvidence(Box<dispute_models::SubmitEvidenceRequest>),
+}
+
+/// Responds to a dispute that was just opened with the auto response rules of the profile of the
+/// payment. The dispute is accepted when its amount is below the threshold of its currency, or
+/// else the evidence of the first template matching its reason code is submitted. Returns the
+/// updated dispute when a response was submitted to the connector.
+#[instrument(skip_all)]
+pub async fn auto_respond_to_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::BusinessProfile,
+    dispute: &storage::Dispute,
+) -> RouterResult<Option<storage::Dispute>> {
+    let Some(config) = business_profile
+        .dispute_auto_response
+        .clone()
+        .map(|config| config.parse_value::<DisputeAutoResponseConfig>("DisputeAutoResponseConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the dispute auto response rules")?
+    else {
+        return Ok(None);
+    };
+    let Some(auto_response) = get_auto_response(state, merchant_account, &config, dispute).await?
+    else {
+        return Ok(None);
+    };
+
+    let dispute_id = dispute.dispute_id.clone();
+    match auto_response {
+        DisputeAutoResponse::Accept => {
+            Box::pin(super::accept_dispute(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                disputes::DisputeId {
+                    dispute_id: dispute_id.clone(),
+                },
+            ))
+            .await?;
+        }
+        DisputeAutoResponse::SubmitEvidence(request) => {
+            Box::pin(super::submit_evidence(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                *request,
+            ))
+            .await?;
+        }
+    }
+    logger::info!(%dispute_id, "Responded to the dispute with the auto response rules");
+
+    state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })
+        .map(Some)
+}
+
+async fn get_auto_response(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    config: &DisputeAutoResponseConfig,
+    dispute: &storage::Dispute,
+) -> RouterResult<Option<DisputeAutoResponse>> {
+    let is_below_threshold = config.accept_below.iter().any(|threshold| {
+        // Connectors report the currency of the disputes in lower or upper case
+        threshold
+            .currency
+            .to_string()
+            .eq_ignore_ascii_case(&dispute.currency)
+            && MinorUnit::new(dispute.dispute_amount) < threshold.amount
+    });
+    if is_below_threshold {
+        return Ok(Some(DisputeAutoResponse::Accept));
+    }
+
+    let Some(template) = dispute
+        .connector_reason_code
+        .as_ref()
+        .and_then(|reason_code| {
+            config
+                .evidence_templates
+                .iter()
+                .find(|template| template.reason_codes.contains(reason_code))
+        })
+    else {
+        return Ok(None);
+    };
+    let refunds_description = if template.requires_refund {
+        let refunds = state
+            .store
+            .find_refund_by_payment_id_merchant_id(
+                &dispute.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds of the disputed payment")?;
+        let Some(refunds_description) = get_refunds_description(&refunds) else {
+            return Ok(None);
+        };
+        Some(refunds_description)
+    } else {
+        None
+    };
+
+    Ok(Some(DisputeAutoResponse::SubmitEvidence(Box::new(
+        get_evidence_request(template, dispute, refunds_description),
+    ))))
+}
+
+/// Describes the successful refunds of the disputed payment, as evidence that the customer was
+/// credited
+fn get_refunds_description(refunds: &[storage::Refund]) -> Option<String> {
+    let descriptions = refunds
+        .iter()
+        .filter(|refund| refund.refund_status == enums::RefundStatus::Success)
+        .map(|refund| {
+            format!(
+                "Refund {refund_id} of {amount} {currency} was processed on {date}",
+                refund_id = refund.refund_id,
+                amount = refund.refund_amount.get_amount_as_i64(),
+                currency = refund.currency,
+                date = refund.updated_at.date(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    (!descriptions.is_empty()).then(|| descriptions.join(". "))
+}
+
+fn get_evidence_request(
+    template: &DisputeEvidenceTemplate,
+    dispute: &storage::Dispute,
+    refunds_description: Option<String>,
+) -> dispute_models::SubmitEvidenceRequest {
+    let uncategorized_text = match (template.uncategorized_text.clone(), refunds_description) {
+        (Some(text), Some(refunds_description)) => Some(format!("{text}\n{refunds_description}")),
+        (text, refunds_description) => text.or(refunds_description),
+    };
+
+    dispute_models::SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        product_description: template.product_description.clone(),
+        refund_policy: template.refund_policy.clone(),
+        refund_policy_disclosure: template.refund_policy_disclosure.clone(),
+        refund_refusal_explanation: template.refund_refusal_explanation.clone(),
+        cancellation_policy: template.cancellation_policy.clone(),
+        cancellation_policy_disclosure: template.cancellation_policy_disclosure.clone(),
+        uncategorized_file: template.uncategorized_file.clone(),
+        uncategorized_text,
+        ..Default::default()
+    }
+}
@@ -124,6 +124,8 @@ pub enum VaultError {
     SaveCardFailed,
     #[error("Failed to fetch card details from card vault")]
     FetchCardFailed,
+    #[error("Failed to delete card from card vault")]
+    DeleteCardFailed,
     #[error("Failed to encode card vault request")]
     RequestEncodingFailed,
     #[error("Failed to deserialize card vault response")]
@@ -224,6 +226,26 @@ pub enum ApplePayDecryptionError {
     DerivingSharedSecretKeyFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum GooglePayDecryptionError {
+    #[error("Failed to base64 decode input data")]
+    Base64DecodingFailed,
+    #[error("Failed to parse the Google Pay token")]
+    TokenParsingFailed,
+    #[error("Unsupported protocol version of the Google Pay token")]
+    UnsupportedProtocolVersion,
+    #[error("Failed to verify the signature of the Google Pay token")]
+    SignatureVerificationFailed,
+    #[error("The Google Pay token has expired")]
+    TokenExpired,
+    #[error("Key Deserialization failure")]
+    KeyDeserializationFailed,
+    #[error("Failed to Derive a shared secret key")]
+    DerivingSharedSecretKeyFailed,
+    #[error("Failed to decrypt input data")]
+    DecryptionFailed,
+}
+
 #[cfg(feature = "detailed_errors")]
 pub mod error_stack_parsing {
 
@@ -57,6 +57,11 @@ impl<T> StorageErrorExt<T, errors::ApiErrorResponse>
                 hyperswitch_domain_models::errors::StorageError::CustomerRedacted => {
                     errors::ApiErrorResponse::CustomerRedacted
                 }
+                hyperswitch_domain_models::errors::StorageError::IllegalStatusTransition {
+                    ..
+                } => errors::ApiErrorResponse::PreconditionFailed {
+                    message: err.current_context().to_string(),
+                },
                 _ => errors::ApiErrorResponse::InternalServerError,
             };
             err.change_context(new_err)
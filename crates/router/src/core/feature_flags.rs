@@ -0,0 +1,283 @@
+use api_models::feature_flags::{
+    FeatureFlag, FeatureFlagResponse, FeatureFlagScope, FeatureFlagTarget, FeatureFlagUpdateRequest,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+};
+
+/// The value cached for the flags that are not set, so that checking a flag that is set at no
+/// level does not query the database every time
+const UNSET_FEATURE_FLAG: &str = "";
+
+/// The key of the config holding the flag set at the scope of the target. The configs are stored
+/// in the schema of the tenant, the flag of the tenant being the one without a merchant.
+pub fn get_feature_flag_config_key(
+    flag: FeatureFlag,
+    merchant_id: Option<&str>,
+    profile_id: Option<&str>,
+) -> String {
+    match (merchant_id, profile_id) {
+        (Some(merchant_id), Some(profile_id)) => {
+            format!("feature_flag_{flag}_{merchant_id}_{profile_id}")
+        }
+        (Some(merchant_id), None) => format!("feature_flag_{flag}_{merchant_id}"),
+        (None, _) => format!("feature_flag_{flag}"),
+    }
+}
+
+/// Whether the capability is enabled for the business profile of the merchant, or for the
+/// merchant when no business profile is provided. Failures are logged and the capability is
+/// considered disabled.
+#[instrument(skip_all, fields(%flag))]
+pub async fn is_feature_enabled(
+    db: &dyn StorageInterface,
+    flag: FeatureFlag,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+) -> bool {
+    match resolve_feature_flag(db, flag, Some(merchant_id), profile_id).await {
+        Ok(Some((enabled, _))) => enabled,
+        Ok(None) => false,
+        Err(error) => {
+            logger::error!(?error, "Failed to resolve the feature flag");
+            false
+        }
+    }
+}
+
+/// The flag applying to the target and the scope it is set at, the flag of the business profile
+/// taking precedence over the flag of the merchant, which takes precedence over the flag of the
+/// tenant
+async fn resolve_feature_flag(
+    db: &dyn StorageInterface,
+    flag: FeatureFlag,
+    merchant_id: Option<&str>,
+    profile_id: Option<&str>,
+) -> RouterResult<Option<(bool, FeatureFlagScope)>> {
+    let mut scopes = Vec::with_capacity(3);
+    if merchant_id.is_some() && profile_id.is_some() {
+        scopes.push((
+            FeatureFlagScope::Profile,
+            get_feature_flag_config_key(flag, merchant_id, profile_id),
+        ));
+    }
+    if merchant_id.is_some() {
+        scopes.push((
+            FeatureFlagScope::Merchant,
+            get_feature_flag_config_key(flag, merchant_id, None),
+        ));
+    }
+    scopes.push((
+        FeatureFlagScope::Tenant,
+        get_feature_flag_config_key(flag, None, None),
+    ));
+
+    for (scope, key) in scopes {
+        if let Some(enabled) = find_feature_flag(db, &key).await? {
+            return Ok(Some((enabled, scope)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// The flag stored in the config of the key, read through the in-memory cache of the configs,
+/// which is invalidated on every instance when the config is updated or deleted
+async fn find_feature_flag(db: &dyn StorageInterface, key: &str) -> RouterResult<Option<bool>> {
+    let config = db
+        .find_config_by_key_unwrap_or(key, Some(UNSET_FEATURE_FLAG.to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the feature flag config")?;
+
+    match config.config.as_str() {
+        UNSET_FEATURE_FLAG => Ok(None),
+        "true" => Ok(Some(true)),
+        "false" => Ok(Some(false)),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| format!("Invalid value of the feature flag config {key}")),
+    }
+}
+
+/// Validates that the business profile of the target belongs to its merchant, and that the
+/// merchant exists
+async fn validate_feature_flag_target(
+    state: &SessionState,
+    target: &FeatureFlagTarget,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let merchant_id = match (&target.merchant_id, &target.profile_id) {
+        (None, None) => return Ok(()),
+        (None, Some(_)) => {
+            return Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "merchant_id"
+            }))
+        }
+        (Some(merchant_id), _) => merchant_id,
+    };
+
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if let Some(profile_id) = &target.profile_id {
+        let business_profile = db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.clone(),
+            })?;
+        if business_profile.merchant_id != *merchant_id {
+            return Err(report!(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.clone(),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+fn get_target_scope(target: &FeatureFlagTarget) -> FeatureFlagScope {
+    match (&target.merchant_id, &target.profile_id) {
+        (Some(_), Some(_)) => FeatureFlagScope::Profile,
+        (Some(_), None) => FeatureFlagScope::Merchant,
+        (None, _) => FeatureFlagScope::Tenant,
+    }
+}
+
+fn get_feature_flag_response(
+    flag: FeatureFlag,
+    target: FeatureFlagTarget,
+    resolved_flag: Option<(bool, FeatureFlagScope)>,
+) -> FeatureFlagResponse {
+    FeatureFlagResponse {
+        flag,
+        merchant_id: target.merchant_id,
+        profile_id: target.profile_id,
+        enabled: resolved_flag.is_some_and(|(enabled, _)| enabled),
+        set_at: resolved_flag.map(|(_, scope)| scope),
+    }
+}
+
+/// Retrieves the flag applying to the target, along with the scope it is set at
+pub async fn retrieve_feature_flag(
+    state: SessionState,
+    flag: FeatureFlag,
+    target: FeatureFlagTarget,
+) -> RouterResponse<FeatureFlagResponse> {
+    validate_feature_flag_target(&state, &target).await?;
+    let resolved_flag = resolve_feature_flag(
+        state.store.as_ref(),
+        flag,
+        target.merchant_id.as_deref(),
+        target.profile_id.as_deref(),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_feature_flag_response(
+        flag,
+        target,
+        resolved_flag,
+    )))
+}
+
+/// Sets the flag at the scope of the target, taking effect on every instance once the cached
+/// configs are invalidated
+pub async fn update_feature_flag(
+    state: SessionState,
+    flag: FeatureFlag,
+    request: FeatureFlagUpdateRequest,
+) -> RouterResponse<FeatureFlagResponse> {
+    let db = state.store.as_ref();
+    let target = request.get_target();
+    validate_feature_flag_target(&state, &target).await?;
+
+    let key = get_feature_flag_config_key(
+        flag,
+        target.merchant_id.as_deref(),
+        target.profile_id.as_deref(),
+    );
+    let config = request.enabled.to_string();
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the feature flag config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the feature flag config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the feature flag config")?,
+    };
+
+    // The flag of the scope of the target takes precedence over the flags of the broader scopes
+    let resolved_flag = Some((request.enabled, get_target_scope(&target)));
+    Ok(ApplicationResponse::Json(get_feature_flag_response(
+        flag,
+        target,
+        resolved_flag,
+    )))
+}
+
+/// Removes the flag set at the scope of the target, the flag of the broader scopes applying to
+/// the target from then on
+pub async fn delete_feature_flag(
+    state: SessionState,
+    flag: FeatureFlag,
+    target: FeatureFlagTarget,
+) -> RouterResponse<FeatureFlagResponse> {
+    validate_feature_flag_target(&state, &target).await?;
+
+    let key = get_feature_flag_config_key(
+        flag,
+        target.merchant_id.as_deref(),
+        target.profile_id.as_deref(),
+    );
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Feature flag {flag} is not set for the target"),
+        })?;
+
+    // The flag of the broader scopes is resolved without the deleted flag, which may still be
+    // cached until the invalidation of the cached configs is received
+    let resolved_flag = match get_target_scope(&target) {
+        FeatureFlagScope::Profile => {
+            resolve_feature_flag(
+                state.store.as_ref(),
+                flag,
+                target.merchant_id.as_deref(),
+                None,
+            )
+            .await?
+        }
+        FeatureFlagScope::Merchant => {
+            resolve_feature_flag(state.store.as_ref(), flag, None, None).await?
+        }
+        FeatureFlagScope::Tenant => None,
+    };
+    Ok(ApplicationResponse::Json(get_feature_flag_response(
+        flag,
+        target,
+        resolved_flag,
+    )))
+}
@@ -1,6 +1,9 @@
 use std::fmt::Debug;
 
-use api_models::{admin::FrmConfigs, enums as api_enums};
+use api_models::{
+    admin::{FrmConfigs, FrmDecisionMatrix},
+    enums as api_enums,
+};
 use common_enums::CaptureMethod;
 use error_stack::ResultExt;
 use masking::{ExposeInterface, PeekInterface};
@@ -16,7 +19,7 @@ use self::{
         PaymentDetails, PaymentToFrmData,
     },
 };
-use super::errors::{ConnectorErrorExt, RouterResponse};
+use super::errors::{ConnectorErrorExt, RouterResponse, StorageErrorExt};
 use crate::{
     core::{
         errors::{self, RouterResult},
@@ -35,8 +38,8 @@ use crate::{
         domain, fraud_check as frm_types,
         storage::{
             enums::{
-                AttemptStatus, FraudCheckLastStep, FraudCheckStatus, FraudCheckType, FrmSuggestion,
-                IntentStatus,
+                AttemptStatus, AuthenticationType, FraudCheckLastStep, FraudCheckStatus,
+                FraudCheckType, FrmDecisionAction, FrmSuggestion, IntentStatus,
             },
             fraud_check::{FraudCheck, FraudCheckUpdate},
             PaymentIntent,
@@ -46,6 +49,7 @@ use crate::{
 };
 pub mod flows;
 pub mod operation;
+pub mod post_auth_review;
 pub mod types;
 
 #[instrument(skip_all)]
@@ -413,7 +417,7 @@ where
                     customer,
                 )
                 .await?;
-                let frm_data_updated = fraud_check_operation
+                let mut frm_data_updated = fraud_check_operation
                     .to_update_tracker()?
                     .update_tracker(
                         &*state.store,
@@ -424,12 +428,46 @@ where
                         frm_router_data,
                     )
                     .await?;
-                let frm_fraud_check = frm_data_updated.fraud_check.clone();
-                payment_data.frm_message = Some(frm_fraud_check.clone());
-                if matches!(frm_fraud_check.frm_status, FraudCheckStatus::Fraud) {
-                    *should_continue_transaction = false;
-                    frm_info.suggested_action = Some(FrmSuggestion::FrmCancelTransaction);
+                let frm_decision_matrix = get_frm_decision_matrix(
+                    &*state.store,
+                    payment_data.payment_intent.profile_id.as_ref(),
+                )
+                .await?;
+                let frm_decision = get_frm_decision_action(
+                    frm_decision_matrix.as_ref(),
+                    &frm_data_updated.fraud_check,
+                );
+                match frm_decision {
+                    FrmDecisionAction::Allow => (),
+                    FrmDecisionAction::ThreeDsChallenge => {
+                        payment_data.payment_attempt.authentication_type =
+                            Some(AuthenticationType::ThreeDs);
+                    }
+                    FrmDecisionAction::ManualReview => {
+                        *should_continue_transaction = false;
+                        frm_info.suggested_action = Some(FrmSuggestion::FrmManualReview);
+                    }
+                    FrmDecisionAction::Block => {
+                        *should_continue_transaction = false;
+                        frm_info.suggested_action = Some(FrmSuggestion::FrmCancelTransaction);
+                    }
                 }
+                let frm_fraud_check = state
+                    .store
+                    .update_fraud_check_response_with_attempt_id(
+                        frm_data_updated.fraud_check.clone(),
+                        FraudCheckUpdate::DecisionUpdate {
+                            frm_decision,
+                            last_step: frm_data_updated.fraud_check.last_step,
+                        },
+                    )
+                    .await
+                    .map_err(|error| {
+                        error.change_context(errors::ApiErrorResponse::PaymentNotFound)
+                    })
+                    .attach_printable("Failed to update the frm decision in fraud check")?;
+                frm_data_updated.fraud_check = frm_fraud_check.clone();
+                payment_data.frm_message = Some(frm_fraud_check);
                 logger::debug!(
                     "frm_updated_data: {:?} {:?}",
                     frm_info.fraud_check_operation,
@@ -455,6 +493,45 @@ where
     Ok(frm_data)
 }
 
+async fn get_frm_decision_matrix(
+    db: &dyn StorageInterface,
+    profile_id: Option<&String>,
+) -> RouterResult<Option<FrmDecisionMatrix>> {
+    let Some(profile_id) = profile_id else {
+        return Ok(None);
+    };
+    db.find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?
+        .frm_decision_matrix
+        .map(|matrix| matrix.parse_value("FrmDecisionMatrix"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse frm_decision_matrix of the business profile")
+}
+
+/// Evaluates the decision matrix against the verdict and score of the fraud check. When the
+/// matrix is not configured or has no applicable decision, payments marked as fraud are blocked.
+pub fn get_frm_decision_action(
+    frm_decision_matrix: Option<&FrmDecisionMatrix>,
+    fraud_check: &FraudCheck,
+) -> FrmDecisionAction {
+    let frm_verdict = match fraud_check.frm_status {
+        FraudCheckStatus::Fraud => Some(api_enums::FrmVerdict::Fraud),
+        FraudCheckStatus::ManualReview => Some(api_enums::FrmVerdict::ManualReview),
+        FraudCheckStatus::Legit => Some(api_enums::FrmVerdict::Legit),
+        FraudCheckStatus::Pending | FraudCheckStatus::TransactionFailure => None,
+    };
+    frm_decision_matrix
+        .and_then(|matrix| matrix.get_action(frm_verdict, fraud_check.frm_score))
+        .unwrap_or(match fraud_check.frm_status {
+            FraudCheckStatus::Fraud => FrmDecisionAction::Block,
+            _ => FrmDecisionAction::Allow,
+        })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn post_payment_frm_core<'a, F>(
     state: &SessionState,
@@ -13,7 +13,7 @@ use crate::{
     core::{
         errors::{RouterResult, StorageErrorExt},
         fraud_check::{
-            self as frm_core,
+            self as frm_core, post_auth_review,
             types::{FrmData, PaymentDetails, PaymentToFrmData, CANCEL_INITIATED},
             ConnectorDetailsCore, FrmConfigsObject,
         },
@@ -110,6 +110,7 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPost {
                     modified_at: common_utils::date_time::now(),
                     last_step: FraudCheckLastStep::Processing,
                     payment_capture_method: payment_data.payment_attempt.capture_method,
+                    frm_decision: None,
                 })
                 .await
             }
@@ -205,6 +206,7 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPost {
                 payment_id: frm_data.payment_intent.payment_id.clone(),
                 cancellation_reason: frm_data.fraud_check.frm_error.clone(),
                 merchant_connector_details: None,
+                amount_to_reverse: None,
             };
             let cancel_res = Box::pin(payments::payments_core::<
                 Void,
@@ -246,6 +248,25 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPost {
             FraudCheckStatus::ManualReview
         ) {
             *frm_suggestion = Some(FrmSuggestion::FrmManualReview);
+            if post_auth_review::is_awaiting_post_auth_review(&frm_data.fraud_check) {
+                post_auth_review::defer_capture_for_post_auth_review(
+                    state,
+                    &req_state,
+                    payment_data,
+                    &frm_data.fraud_check,
+                )
+                .await?;
+            }
+        } else if post_auth_review::is_awaiting_post_auth_review(&frm_data.fraud_check) {
+            // The verdict of the FRM connector is pending, the payment stays authorized until
+            // the verdict is received through the webhooks of the FRM connector
+            post_auth_review::defer_capture_for_post_auth_review(
+                state,
+                &req_state,
+                payment_data,
+                &frm_data.fraud_check,
+            )
+            .await?;
         } else if matches!(frm_data.fraud_check.frm_status, FraudCheckStatus::Legit)
             && matches!(
                 frm_data.fraud_check.payment_capture_method,
@@ -0,0 +1,352 @@
+use api_models::{admin::FrmPostAuthReviewConfig, enums::FrmReviewAction, payments::HeaderPayload};
+use common_enums::CaptureMethod;
+use error_stack::ResultExt;
+use router_env::{instrument, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments,
+    },
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, metrics, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{
+            self,
+            enums::{
+                AttemptStatus, FraudCheckStatus, FraudCheckType, FrmDecisionAction, IntentStatus,
+                MerchantDecision,
+            },
+        },
+    },
+    utils::ValueExt,
+};
+
+pub const FRM_REVIEW_TIMEOUT_TASK: &str = "FRM_REVIEW_TIMEOUT";
+pub const FRM_REVIEW_TIMEOUT_TAG: &str = "FRM";
+
+/// The outcome of the review of an authorized payment by the FRM connector
+#[derive(Debug, Clone, Copy)]
+pub enum PostAuthReviewResolution {
+    /// The payment was approved by the FRM connector or by the merchant
+    Approved,
+    /// The payment was rejected by the FRM connector or by the merchant
+    Declined,
+    /// No verdict was received within the review timeout configured for the business profile
+    TimedOut(FrmReviewAction),
+}
+
+impl PostAuthReviewResolution {
+    fn get_action(self) -> FrmReviewAction {
+        match self {
+            Self::Approved => FrmReviewAction::Capture,
+            Self::Declined => FrmReviewAction::Void,
+            Self::TimedOut(action) => action,
+        }
+    }
+}
+
+pub fn generate_task_id_for_frm_review_timeout_workflow(payment_id: &str) -> String {
+    format!(
+        "{runner}_{task}_{payment_id}",
+        runner = storage::ProcessTrackerRunner::FrmReviewTimeoutWorkflow,
+        task = FRM_REVIEW_TIMEOUT_TASK
+    )
+}
+
+/// Whether the automatic capture of the payment was deferred until the post-authorization verdict
+/// of the FRM connector, and the review of the payment has not been resolved yet
+pub fn is_awaiting_post_auth_review(fraud_check: &storage::FraudCheck) -> bool {
+    fraud_check.frm_transaction_type == FraudCheckType::PostFrm
+        && fraud_check.payment_capture_method == Some(CaptureMethod::Automatic)
+        && matches!(
+            fraud_check.frm_status,
+            FraudCheckStatus::Pending | FraudCheckStatus::ManualReview
+        )
+        && fraud_check.frm_decision.is_none()
+}
+
+async fn get_post_auth_review_config(
+    db: &dyn StorageInterface,
+    profile_id: Option<&String>,
+) -> RouterResult<Option<FrmPostAuthReviewConfig>> {
+    let Some(profile_id) = profile_id else {
+        return Ok(None);
+    };
+    db.find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?
+        .frm_post_auth_review
+        .map(|config| config.parse_value("FrmPostAuthReviewConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse frm_post_auth_review of the business profile")
+}
+
+/// Records that the automatic capture of an authorized payment is deferred until the verdict of
+/// the FRM connector is received, and schedules the review timeout configured for the business
+/// profile, if any.
+#[instrument(skip_all)]
+pub async fn defer_capture_for_post_auth_review<F: Clone>(
+    state: &SessionState,
+    req_state: &ReqState,
+    payment_data: &payments::PaymentData<F>,
+    fraud_check: &storage::FraudCheck,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let review_config =
+        get_post_auth_review_config(db, payment_data.payment_intent.profile_id.as_ref()).await?;
+
+    if let Some(review_config) = &review_config {
+        let tracking_data = storage::FrmReviewTimeoutTrackingData {
+            merchant_id: fraud_check.merchant_id.clone(),
+            payment_id: fraud_check.payment_id.clone(),
+            timeout_action: review_config.timeout_action,
+        };
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            i64::from(review_config.review_timeout_in_secs),
+        ));
+        let process_tracker_entry = storage::ProcessTrackerNew::new(
+            generate_task_id_for_frm_review_timeout_workflow(&fraud_check.payment_id),
+            FRM_REVIEW_TIMEOUT_TASK,
+            storage::ProcessTrackerRunner::FrmReviewTimeoutWorkflow,
+            [FRM_REVIEW_TIMEOUT_TAG],
+            tracking_data,
+            schedule_time,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct FRM review timeout process tracker task")?;
+
+        db.insert_process(process_tracker_entry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the FRM review timeout task")?;
+
+        metrics::TASKS_ADDED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "FrmReviewTimeout")]),
+        );
+    }
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::CaptureDeferredForFrmReview {
+                frm_status: fraud_check.frm_status,
+                review_timeout_in_secs: review_config.map(|config| config.review_timeout_in_secs),
+            },
+        ))
+        .with(payment_data.to_event())
+        .emit();
+
+    Ok(())
+}
+
+async fn revoke_frm_review_timeout_task(
+    db: &dyn StorageInterface,
+    payment_id: &str,
+) -> RouterResult<()> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![generate_task_id_for_frm_review_timeout_workflow(payment_id)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage::enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to revoke the FRM review timeout task")?;
+
+    Ok(())
+}
+
+/// Resolves the review of a payment whose automatic capture was deferred until the
+/// post-authorization verdict of the FRM connector, by capturing the payment when it is approved
+/// and voiding its authorization when it is declined. Returns `None` when the payment is not
+/// awaiting such a review, in which case the verdict is handled by the regular approve and reject
+/// flows.
+#[instrument(skip_all)]
+pub async fn resolve_post_auth_review(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    resolution: PostAuthReviewResolution,
+    cancellation_reason: Option<String>,
+) -> RouterResult<Option<services::ApplicationResponse<api::PaymentsResponse>>> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let Some(fraud_check) = db
+        .find_fraud_check_by_payment_id_if_present(payment_id.to_owned(), merchant_id.to_owned())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fraud check of the payment")?
+        .filter(is_awaiting_post_auth_review)
+    else {
+        return Ok(None);
+    };
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // The payment may have been captured or cancelled by the merchant while awaiting the verdict
+    if !matches!(
+        payment_intent.status,
+        IntentStatus::RequiresCapture | IntentStatus::RequiresMerchantAction
+    ) {
+        return Ok(None);
+    }
+
+    let mut payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_id,
+            &fraud_check.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // Payments held for manual review are moved back to the authorized state, for the deferred
+    // capture or the void of the authorization to be processed
+    if payment_attempt.status == AttemptStatus::Unresolved {
+        payment_attempt = db
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::StatusUpdate {
+                    status: AttemptStatus::Authorized,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    let merchant_decision = match resolution {
+        PostAuthReviewResolution::Approved => Some(MerchantDecision::Approved.to_string()),
+        PostAuthReviewResolution::Declined => Some(MerchantDecision::Rejected.to_string()),
+        PostAuthReviewResolution::TimedOut(_) => None,
+    };
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::ApproveUpdate {
+                status: IntentStatus::RequiresCapture,
+                merchant_decision,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let action = resolution.get_action();
+    let frm_decision = match action {
+        FrmReviewAction::Capture => FrmDecisionAction::Allow,
+        FrmReviewAction::Void => FrmDecisionAction::Block,
+    };
+    let frm_status = match resolution {
+        PostAuthReviewResolution::Approved => FraudCheckStatus::Legit,
+        PostAuthReviewResolution::Declined => FraudCheckStatus::Fraud,
+        PostAuthReviewResolution::TimedOut(_) => fraud_check.frm_status,
+    };
+    let last_step = fraud_check.last_step;
+    db.update_fraud_check_response_with_attempt_id(
+        fraud_check,
+        storage::FraudCheckUpdate::ReviewResolutionUpdate {
+            frm_status,
+            frm_decision,
+            last_step,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the review resolution of the fraud check")?;
+
+    // The timeout task marks itself as finished once it is executed
+    if !matches!(resolution, PostAuthReviewResolution::TimedOut(_)) {
+        revoke_frm_review_timeout_task(db, payment_id).await?;
+    }
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::FrmReviewResolved {
+            frm_status,
+            action,
+            timed_out: matches!(resolution, PostAuthReviewResolution::TimedOut(_)),
+        }))
+        .with(payments::PaymentEvent::new(payment_intent, payment_attempt))
+        .emit();
+
+    let payments_response = match action {
+        FrmReviewAction::Capture => {
+            Box::pin(payments::payments_core::<
+                api::Capture,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account.clone(),
+                key_store.clone(),
+                payments::PaymentCapture,
+                api::PaymentsCaptureRequest {
+                    payment_id: payment_id.to_owned(),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            ))
+            .await?
+        }
+        FrmReviewAction::Void => {
+            Box::pin(payments::payments_core::<
+                api::Void,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account.clone(),
+                key_store.clone(),
+                payments::PaymentCancel,
+                api::PaymentsCancelRequest {
+                    payment_id: payment_id.to_owned(),
+                    cancellation_reason,
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            ))
+            .await?
+        }
+    };
+
+    Ok(Some(payments_response))
+}
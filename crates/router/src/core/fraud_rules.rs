@@ -0,0 +1,599 @@
+use std::str::FromStr;
+
+use api_models::{
+    enums as api_enums,
+    fraud_rules::{
+        self as fraud_rules_types, FraudRuleDecision, FraudRulesRecord, FraudRulesRequest,
+        FraudRulesResponse, FraudSignalsConfig, VelocityDimension,
+    },
+};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use euclid::{
+    backend::{self, EuclidBackend},
+    frontend::ast,
+};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use rustc_hash::FxHashMap;
+use storage_impl::redis::cache::{self, CacheKind, DECISION_MANAGER_CACHE};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, routing::make_dsl_input},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{
+        api, domain,
+        storage::{
+            self,
+            enums::{
+                AuthenticationType, FraudCheckLastStep, FraudCheckStatus, FraudCheckType,
+                FrmDecisionAction, FrmSuggestion,
+            },
+            fraud_check::{FraudCheckNew, FraudCheckUpdate},
+        },
+        BrowserInformation,
+    },
+    utils::{self as router_utils, OptionExt},
+};
+
+/// Name recorded on the fraud checks performed by the in-house rules engine
+pub const FRAUD_RULES_ENGINE_NAME: &str = "hyperswitch_fraud_rules";
+
+/// Expiry of the per customer amount statistics used by the amount anomaly check
+const AMOUNT_STATISTICS_EXPIRY_IN_SECS: i64 = 90 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FraudRulesEngine {
+    signals: FraudSignalsConfig,
+    interpreter: backend::VirInterpreterBackend<FraudRuleDecision>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct AmountStatistics {
+    count: u64,
+    total_amount: i64,
+}
+
+pub fn get_fraud_rules_config_key(profile_id: &str) -> String {
+    format!("fraud_rules_{profile_id}")
+}
+
+pub async fn upsert_fraud_rules_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+    request: FraudRulesRequest,
+) -> RouterResponse<FraudRulesResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    ast::lowering::lower_program(request.program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+
+    let key = get_fraud_rules_config_key(&profile_id);
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    let record = match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: FraudRulesRecord = config
+                .config
+                .parse_struct("FraudRulesRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the fraud rules record")?;
+
+            let record = FraudRulesRecord {
+                name: request.name.unwrap_or(previous_record.name),
+                profile_id,
+                signals: request.signals,
+                program: request.program,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the fraud rules config")?;
+
+            record
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let record = FraudRulesRecord {
+                name: request.name.get_required_value("name").change_context(
+                    errors::ApiErrorResponse::MissingRequiredField { field_name: "name" },
+                )?,
+                profile_id,
+                signals: request.signals,
+                program: request.program,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.insert_config(configs::ConfigNew {
+                key: key.clone(),
+                config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the fraud rules config")?;
+
+            record
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the fraud rules config")?,
+    };
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::DecisionManager(key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the fraud rules cache")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn retrieve_fraud_rules_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+) -> RouterResponse<FraudRulesResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    let record: FraudRulesRecord = db
+        .find_config_by_key(&get_fraud_rules_config_key(&profile_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?
+        .config
+        .parse_struct("FraudRulesRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the fraud rules record")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn delete_fraud_rules_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    let key = get_fraud_rules_config_key(&profile_id);
+    db.delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::DecisionManager(key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the fraud rules cache")?;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+async fn get_fraud_rules_engine(
+    state: &SessionState,
+    profile_id: &str,
+) -> RouterResult<Option<FraudRulesEngine>> {
+    let db = &*state.store;
+    let key = get_fraud_rules_config_key(profile_id);
+
+    let find_engine_from_db = || async {
+        let record: FraudRulesRecord = db
+            .find_config_by_key(&key)
+            .await?
+            .config
+            .parse_struct("FraudRulesRecord")
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to parse the fraud rules record")?;
+
+        let interpreter = backend::VirInterpreterBackend::with_program(record.program)
+            .change_context(errors::StorageError::ValueNotFound("Program".to_string()))
+            .attach_printable("Error initializing DSL interpreter backend")?;
+
+        Ok(FraudRulesEngine {
+            signals: record.signals,
+            interpreter,
+        })
+    };
+
+    match cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &key,
+        find_engine_from_db,
+        &DECISION_MANAGER_CACHE,
+    )
+    .await
+    {
+        Ok(engine) => Ok(Some(engine)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the fraud rules of the business profile"),
+    }
+}
+
+/// Evaluates the fraud rules of the business profile against the payment, and applies the
+/// action of the matching rule. Returns the suggestion to be used while updating the payment,
+/// when the payment is not to be authorized.
+#[instrument(skip_all)]
+pub async fn perform_fraud_rules<F: Clone>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_data: &mut payments::PaymentData<F>,
+    should_continue_transaction: &mut bool,
+) -> RouterResult<Option<FrmSuggestion>> {
+    let Some(engine) = get_fraud_rules_engine(state, &business_profile.profile_id).await? else {
+        return Ok(None);
+    };
+
+    let raised_signals = get_raised_fraud_signals(
+        state,
+        &business_profile.profile_id,
+        &engine.signals,
+        payment_data,
+    )
+    .await;
+
+    let mut backend_input = make_dsl_input(payment_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the input for the fraud rules")?;
+    backend_input
+        .metadata
+        .get_or_insert_with(FxHashMap::default)
+        .extend(raised_signals.iter().map(|signal| {
+            (
+                signal.to_owned(),
+                fraud_rules_types::SIGNAL_RAISED_VALUE.to_string(),
+            )
+        }));
+
+    let output = engine
+        .interpreter
+        .execute(backend_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to execute the fraud rules")?;
+    let frm_decision = output.connector_selection.action;
+
+    // Only the payments which hit a rule are recorded, the default selection of the program is
+    // applied silently
+    if let Some(rule_name) = output.rule_name {
+        logger::info!(
+            fraud_rule = %rule_name,
+            ?frm_decision,
+            ?raised_signals,
+            "Fraud rule hit"
+        );
+        let fraud_check = record_fraud_rule_hit(
+            state,
+            merchant_account,
+            payment_data,
+            rule_name,
+            raised_signals,
+            frm_decision,
+        )
+        .await?;
+        payment_data.frm_message = Some(fraud_check);
+    }
+
+    Ok(match frm_decision {
+        FrmDecisionAction::Allow => None,
+        FrmDecisionAction::ThreeDsChallenge => {
+            payment_data.payment_attempt.authentication_type = Some(AuthenticationType::ThreeDs);
+            None
+        }
+        FrmDecisionAction::ManualReview => {
+            *should_continue_transaction = false;
+            Some(FrmSuggestion::FrmManualReview)
+        }
+        FrmDecisionAction::Block => {
+            *should_continue_transaction = false;
+            Some(FrmSuggestion::FrmCancelTransaction)
+        }
+    })
+}
+
+async fn record_fraud_rule_hit<F: Clone>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &payments::PaymentData<F>,
+    rule_name: String,
+    raised_signals: Vec<String>,
+    frm_decision: FrmDecisionAction,
+) -> RouterResult<storage::FraudCheck> {
+    let db = &*state.store;
+    let frm_status = match frm_decision {
+        FrmDecisionAction::Block => FraudCheckStatus::Fraud,
+        FrmDecisionAction::ManualReview => FraudCheckStatus::ManualReview,
+        FrmDecisionAction::Allow | FrmDecisionAction::ThreeDsChallenge => FraudCheckStatus::Legit,
+    };
+    let frm_reason = serde_json::json!({
+        "rule_name": rule_name,
+        "signals": raised_signals,
+    });
+
+    let existing_fraud_check = db
+        .find_fraud_check_by_payment_id_if_present(
+            payment_data.payment_intent.payment_id.clone(),
+            merchant_account.merchant_id.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fraud check of the payment")?;
+
+    match existing_fraud_check {
+        Some(fraud_check) => {
+            let fraud_check = db
+                .update_fraud_check_response_with_attempt_id(
+                    fraud_check,
+                    FraudCheckUpdate::ResponseUpdate {
+                        frm_status,
+                        frm_transaction_id: None,
+                        frm_reason: Some(frm_reason),
+                        frm_score: None,
+                        metadata: None,
+                        modified_at: common_utils::date_time::now(),
+                        last_step: FraudCheckLastStep::CheckoutOrSale,
+                        payment_capture_method: payment_data.payment_attempt.capture_method,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the fraud check of the payment")?;
+            db.update_fraud_check_response_with_attempt_id(
+                fraud_check,
+                FraudCheckUpdate::DecisionUpdate {
+                    frm_decision,
+                    last_step: FraudCheckLastStep::CheckoutOrSale,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the frm decision in fraud check")
+        }
+        None => db
+            .insert_fraud_check_response(FraudCheckNew {
+                frm_id: router_utils::generate_id(consts::ID_LENGTH, "frm"),
+                payment_id: payment_data.payment_intent.payment_id.clone(),
+                merchant_id: merchant_account.merchant_id.clone(),
+                attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+                created_at: common_utils::date_time::now(),
+                frm_name: FRAUD_RULES_ENGINE_NAME.to_string(),
+                frm_transaction_id: None,
+                frm_transaction_type: FraudCheckType::PreFrm,
+                frm_status,
+                frm_score: None,
+                frm_reason: Some(frm_reason),
+                frm_error: None,
+                payment_details: None,
+                metadata: None,
+                modified_at: common_utils::date_time::now(),
+                last_step: FraudCheckLastStep::CheckoutOrSale,
+                payment_capture_method: payment_data.payment_attempt.capture_method,
+                frm_decision: Some(frm_decision),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the fraud check of the payment"),
+    }
+}
+
+/// Computes the signals configured for the fraud rules. Failures while computing a signal are
+/// logged and the signal is considered as not raised, so that the payment is not failed.
+async fn get_raised_fraud_signals<F: Clone>(
+    state: &SessionState,
+    profile_id: &str,
+    signals_config: &FraudSignalsConfig,
+    payment_data: &payments::PaymentData<F>,
+) -> Vec<String> {
+    let mut raised_signals = Vec::new();
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the fraud signals"
+            );
+            return raised_signals;
+        }
+    };
+
+    for velocity_check in &signals_config.velocity_checks {
+        let Some(dimension_value) =
+            get_velocity_dimension_value(&velocity_check.dimension, payment_data)
+        else {
+            continue;
+        };
+        let Ok(digest) = Sha256.generate_digest(dimension_value.as_bytes()) else {
+            continue;
+        };
+        let key = format!(
+            "frm_velocity_{profile_id}_{}_{}",
+            velocity_check.name,
+            hex::encode(digest)
+        );
+        match redis_conn
+            .increment_key_with_expiry(&key, velocity_check.window_in_secs.into())
+            .await
+        {
+            Ok(count) if count > i64::from(velocity_check.max_count) => {
+                raised_signals.push(format!(
+                    "{}{}",
+                    fraud_rules_types::VELOCITY_SIGNAL_KEY_PREFIX,
+                    velocity_check.name
+                ))
+            }
+            Ok(_) => (),
+            Err(error) => logger::error!(?error, "Failed to update the velocity counter"),
+        }
+    }
+
+    if let Some((amount_anomaly, customer_id)) = signals_config
+        .amount_anomaly
+        .as_ref()
+        .zip(payment_data.payment_intent.customer_id.as_ref())
+    {
+        let key = format!(
+            "frm_amount_statistics_{profile_id}_{}_{}",
+            customer_id.get_string_repr(),
+            payment_data.currency
+        );
+        let amount = payment_data.payment_intent.amount.get_amount_as_i64();
+        let statistics = redis_conn
+            .get_and_deserialize_key::<AmountStatistics>(&key, "AmountStatistics")
+            .await
+            .unwrap_or_default();
+
+        if statistics.count >= u64::from(amount_anomaly.min_history)
+            && statistics.count > 0
+            && amount.saturating_mul(i64::try_from(statistics.count).unwrap_or(i64::MAX))
+                > statistics
+                    .total_amount
+                    .saturating_mul(i64::from(amount_anomaly.max_multiplier))
+        {
+            raised_signals.push(fraud_rules_types::AMOUNT_ANOMALY_SIGNAL_KEY.to_string());
+        }
+
+        let updated_statistics = AmountStatistics {
+            count: statistics.count.saturating_add(1),
+            total_amount: statistics.total_amount.saturating_add(amount),
+        };
+        if let Err(error) = redis_conn
+            .serialize_and_set_key_with_expiry(
+                &key,
+                updated_statistics,
+                AMOUNT_STATISTICS_EXPIRY_IN_SECS,
+            )
+            .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to update the amount statistics of the customer"
+            );
+        }
+    }
+
+    if signals_config.country_mismatch && is_country_mismatch(payment_data) {
+        raised_signals.push(fraud_rules_types::COUNTRY_MISMATCH_SIGNAL_KEY.to_string());
+    }
+
+    raised_signals
+}
+
+fn get_velocity_dimension_value<F: Clone>(
+    dimension: &VelocityDimension,
+    payment_data: &payments::PaymentData<F>,
+) -> Option<String> {
+    match dimension {
+        VelocityDimension::Card => {
+            payment_data
+                .payment_method_data
+                .as_ref()
+                .and_then(|payment_method_data| match payment_method_data {
+                    api::PaymentMethodData::Card(card) => Some(card.card_number.get_card_no()),
+                    _ => None,
+                })
+        }
+        VelocityDimension::Email => payment_data
+            .email
+            .as_ref()
+            .map(|email| email.peek().to_lowercase()),
+        VelocityDimension::IpAddress => payment_data
+            .payment_attempt
+            .browser_info
+            .clone()
+            .and_then(|browser_info| {
+                browser_info
+                    .parse_value::<BrowserInformation>("BrowserInformation")
+                    .ok()
+            })
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| ip_address.to_string()),
+        VelocityDimension::Device => payments::helpers::get_device_fingerprint_from_browser_info(
+            payment_data.payment_attempt.browser_info.as_ref(),
+        ),
+    }
+}
+
+fn is_country_mismatch<F: Clone>(payment_data: &payments::PaymentData<F>) -> bool {
+    let Some(billing_country) = payment_data
+        .address
+        .get_payment_method_billing()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country)
+    else {
+        return false;
+    };
+
+    let shipping_country = payment_data
+        .address
+        .get_shipping()
+        .and_then(|shipping| shipping.address.as_ref())
+        .and_then(|address| address.country);
+    let card_issuing_country = payment_data
+        .payment_method_data
+        .as_ref()
+        .and_then(|payment_method_data| match payment_method_data {
+            api::PaymentMethodData::Card(card) => card.card_issuing_country.as_ref(),
+            _ => None,
+        })
+        .and_then(|country| api_enums::CountryAlpha2::from_str(country).ok());
+
+    [shipping_country, card_issuing_country]
+        .into_iter()
+        .flatten()
+        .any(|country| country != billing_country)
+}
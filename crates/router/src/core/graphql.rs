@@ -0,0 +1,640 @@
+pub mod parser;
+
+use std::collections::HashMap;
+
+use api_models::{
+    graphql::{GraphQLError, GraphQLRequest, GraphQLResponse},
+    payments::{HeaderPayload, PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
+};
+use common_utils::id_type;
+use futures::{future::LocalBoxFuture, FutureExt};
+use router_env::{instrument, tracing};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+
+use self::parser::Field;
+use crate::{
+    core::{
+        customers, disputes,
+        errors::{self, RouterResponse},
+        payments::{self, CallConnectorAction, PaymentStatus},
+        refunds,
+        response_field_access::ResponseFieldRestrictions,
+    },
+    routes::SessionState,
+    services::{ApplicationResponse, AuthFlow},
+    types::{api, domain, transformers::ForeignFrom},
+};
+
+/// The number of objects looked up through the fields of other objects a field can be nested in,
+/// such as the payment of a refund of a payment
+const MAX_RESOLVER_DEPTH: usize = 3;
+
+/// The number of distinct objects and lists of objects a query can look up, the lookups of the
+/// same objects being made once per query
+const MAX_LOOKUPS_PER_QUERY: usize = 500;
+
+/// The arguments of the filters whose names are not valid GraphQL names, provided with an
+/// underscore in place of the dot
+const PAYMENT_LIST_DOTTED_ARGUMENTS: [&str; 4] =
+    ["created.lt", "created.gt", "created.lte", "created.gte"];
+
+const DISPUTE_LIST_DOTTED_ARGUMENTS: [&str; 4] = [
+    "received_time.lt",
+    "received_time.gt",
+    "received_time.lte",
+    "received_time.gte",
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display)]
+enum ObjectType {
+    Payment,
+    Refund,
+    Dispute,
+    Customer,
+}
+
+/// The fields of the objects resolved by looking up other objects, the other fields being taken
+/// from the objects as returned by the REST API
+#[derive(Clone, Copy, Debug)]
+enum Relation {
+    PaymentRefunds,
+    PaymentDisputes,
+    PaymentCustomer,
+    RefundPayment,
+    DisputePayment,
+    CustomerPayments,
+}
+
+impl Relation {
+    fn find(object_type: ObjectType, field_name: &str) -> Option<Self> {
+        match (object_type, field_name) {
+            (ObjectType::Payment, "refunds") => Some(Self::PaymentRefunds),
+            (ObjectType::Payment, "disputes") => Some(Self::PaymentDisputes),
+            (ObjectType::Payment, "customer") => Some(Self::PaymentCustomer),
+            (ObjectType::Refund, "payment") => Some(Self::RefundPayment),
+            (ObjectType::Dispute, "payment") => Some(Self::DisputePayment),
+            (ObjectType::Customer, "payments") => Some(Self::CustomerPayments),
+            _ => None,
+        }
+    }
+
+    /// The field of the object holding the identifier the related objects are looked up by
+    fn get_key_field(self) -> &'static str {
+        match self {
+            Self::PaymentRefunds
+            | Self::PaymentDisputes
+            | Self::RefundPayment
+            | Self::DisputePayment => "payment_id",
+            Self::PaymentCustomer | Self::CustomerPayments => "customer_id",
+        }
+    }
+
+    fn get_object_type(self) -> ObjectType {
+        match self {
+            Self::PaymentRefunds => ObjectType::Refund,
+            Self::PaymentDisputes => ObjectType::Dispute,
+            Self::PaymentCustomer => ObjectType::Customer,
+            Self::RefundPayment | Self::DisputePayment | Self::CustomerPayments => {
+                ObjectType::Payment
+            }
+        }
+    }
+
+    fn get_lookup(self, id: String) -> Lookup {
+        match self {
+            Self::PaymentRefunds => Lookup::PaymentRefunds(id),
+            Self::PaymentDisputes => Lookup::PaymentDisputes(id),
+            Self::PaymentCustomer => Lookup::Customer(id),
+            Self::RefundPayment | Self::DisputePayment => Lookup::Payment(id),
+            Self::CustomerPayments => Lookup::CustomerPayments(id),
+        }
+    }
+}
+
+/// The objects, or lists of objects, looked up by their identifier
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Lookup {
+    Payment(String),
+    Refund(String),
+    Dispute(String),
+    Customer(String),
+    PaymentRefunds(String),
+    PaymentDisputes(String),
+    CustomerPayments(String),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PaymentArguments {
+    payment_id: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RefundArguments {
+    refund_id: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DisputeArguments {
+    dispute_id: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomerArguments {
+    customer_id: String,
+}
+
+/// Deserializes the arguments of the field, the arguments provided for the dotted arguments being
+/// renamed to them
+fn parse_arguments<T: DeserializeOwned>(
+    field: &Field,
+    dotted_arguments: &[&str],
+) -> Result<T, String> {
+    let mut arguments = field.arguments.clone();
+    for dotted_argument in dotted_arguments {
+        if let Some(value) = arguments.remove(&dotted_argument.replace('.', "_")) {
+            arguments.insert(dotted_argument.to_string(), value);
+        }
+    }
+
+    serde_json::from_value(Value::Object(arguments))
+        .map_err(|error| format!("Invalid arguments for the field `{}`: {error}", field.name))
+}
+
+fn ensure_no_arguments(field: &Field) -> Result<(), String> {
+    if field.arguments.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("The field `{}` takes no arguments", field.name))
+    }
+}
+
+fn get_json_response<T: Serialize>(response: ApplicationResponse<T>) -> Result<Value, String> {
+    match response {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => serde_json::to_value(response)
+            .map_err(|_| "Failed to serialize the response".to_string()),
+        ApplicationResponse::StatusOk
+        | ApplicationResponse::TextPlain(_)
+        | ApplicationResponse::JsonForRedirection(_)
+        | ApplicationResponse::Form(_)
+        | ApplicationResponse::GenericLinkForm(_)
+        | ApplicationResponse::PaymentLinkForm(_)
+        | ApplicationResponse::FileData(_) => Err("Unexpected response".to_string()),
+    }
+}
+
+fn get_error_message(error: error_stack::Report<errors::ApiErrorResponse>) -> String {
+    error.current_context().error_message()
+}
+
+/// The items of a paginated list response
+fn get_list_data(mut response: Value) -> Value {
+    response
+        .get_mut("data")
+        .map(Value::take)
+        .unwrap_or(Value::Array(Vec::new()))
+}
+
+/// Executes the queries of the dashboards in a single request: the payments, refunds, disputes
+/// and customers are looked up along with the objects related to them, and only the fields
+/// selected by the query are returned. The errors raised for a field are returned along with the
+/// other fields, the field being `null`.
+#[instrument(skip_all)]
+pub async fn execute_query(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    restrictions: Option<ResponseFieldRestrictions>,
+    request: GraphQLRequest,
+) -> RouterResponse<GraphQLResponse> {
+    let fields = match parser::parse_query(
+        &request.query,
+        request.operation_name.as_deref(),
+        &request.variables.unwrap_or_default(),
+    ) {
+        Ok(fields) => fields,
+        Err(error) => {
+            return Ok(ApplicationResponse::Json(GraphQLResponse {
+                data: None,
+                errors: vec![GraphQLError {
+                    message: error.to_string(),
+                    path: Vec::new(),
+                }],
+            }))
+        }
+    };
+
+    let mut executor = Executor {
+        state,
+        merchant_account,
+        key_store,
+        restrictions,
+        lookups: HashMap::new(),
+        errors: Vec::new(),
+    };
+
+    let mut data = Map::new();
+    for field in &fields {
+        let value = executor.resolve_root_field(field).await;
+        data.insert(field.response_key().to_string(), value);
+    }
+
+    Ok(ApplicationResponse::Json(GraphQLResponse {
+        data: Some(Value::Object(data)),
+        errors: executor.errors,
+    }))
+}
+
+struct Executor {
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    /// The sensitive fields hidden from the caller, hidden in the objects before the fields are
+    /// selected
+    restrictions: Option<ResponseFieldRestrictions>,
+    lookups: HashMap<Lookup, Result<Value, String>>,
+    errors: Vec<GraphQLError>,
+}
+
+impl Executor {
+    async fn resolve_root_field(&mut self, field: &Field) -> Value {
+        let path = vec![Value::from(field.response_key())];
+        match self.fetch_root_field(field).await {
+            Ok((value, object_type)) => {
+                self.select(value, object_type, &field.selection_set, path, 0)
+                    .await
+            }
+            Err(message) => {
+                self.errors.push(GraphQLError { message, path });
+                Value::Null
+            }
+        }
+    }
+
+    async fn fetch_root_field(
+        &mut self,
+        field: &Field,
+    ) -> Result<(Value, Option<ObjectType>), String> {
+        let (value, object_type) = match field.name.as_str() {
+            "__typename" => return Ok((Value::from("Query"), None)),
+            "payment" => {
+                let arguments = parse_arguments::<PaymentArguments>(field, &[])?;
+                let payment = self.fetch(Lookup::Payment(arguments.payment_id)).await?;
+                (payment, ObjectType::Payment)
+            }
+            "refund" => {
+                let arguments = parse_arguments::<RefundArguments>(field, &[])?;
+                let refund = self.fetch(Lookup::Refund(arguments.refund_id)).await?;
+                (refund, ObjectType::Refund)
+            }
+            "dispute" => {
+                let arguments = parse_arguments::<DisputeArguments>(field, &[])?;
+                let dispute = self.fetch(Lookup::Dispute(arguments.dispute_id)).await?;
+                (dispute, ObjectType::Dispute)
+            }
+            "customer" => {
+                let arguments = parse_arguments::<CustomerArguments>(field, &[])?;
+                let customer = self.fetch(Lookup::Customer(arguments.customer_id)).await?;
+                (customer, ObjectType::Customer)
+            }
+            "payments" => {
+                let constraints = parse_arguments(field, &PAYMENT_LIST_DOTTED_ARGUMENTS)?;
+                let payments = self.list_payments(constraints).await?;
+                (payments, ObjectType::Payment)
+            }
+            "refunds" => {
+                let request = parse_arguments(field, &[])?;
+                let refunds = refunds::refund_list(
+                    self.state.clone(),
+                    self.merchant_account.clone(),
+                    request,
+                )
+                .await
+                .map_err(get_error_message)
+                .and_then(get_json_response)?;
+                (get_list_data(refunds), ObjectType::Refund)
+            }
+            "disputes" => {
+                let constraints = parse_arguments(field, &DISPUTE_LIST_DOTTED_ARGUMENTS)?;
+                let disputes = disputes::retrieve_disputes_list(
+                    self.state.clone(),
+                    self.merchant_account.clone(),
+                    constraints,
+                )
+                .await
+                .map_err(get_error_message)
+                .and_then(get_json_response)?;
+                (disputes, ObjectType::Dispute)
+            }
+            "customers" => {
+                ensure_no_arguments(field)?;
+                let customers = customers::list_customers(
+                    self.state.clone(),
+                    self.merchant_account.merchant_id.clone(),
+                    self.key_store.clone(),
+                )
+                .await
+                .map_err(|error| error.current_context().to_string())
+                .and_then(get_json_response)?;
+                (customers, ObjectType::Customer)
+            }
+            "payment_metrics" => {
+                let request = parse_arguments(field, &[])?;
+                let metrics = analytics::payments::get_metrics(
+                    &self.state.pool,
+                    &self.merchant_account.merchant_id,
+                    request,
+                )
+                .await
+                .map_err(|error| error.current_context().to_string())?;
+                return serde_json::to_value(metrics)
+                    .map(|metrics| (metrics, None))
+                    .map_err(|_| "Failed to serialize the response".to_string());
+            }
+            "refund_metrics" => {
+                let request = parse_arguments(field, &[])?;
+                let metrics = analytics::refunds::get_metrics(
+                    &self.state.pool,
+                    &self.merchant_account.merchant_id,
+                    request,
+                )
+                .await
+                .map_err(|error| error.current_context().to_string())?;
+                return serde_json::to_value(metrics)
+                    .map(|metrics| (metrics, None))
+                    .map_err(|_| "Failed to serialize the response".to_string());
+            }
+            name => return Err(format!("Unknown field `{name}` of the query")),
+        };
+
+        Ok((self.restrict(value), Some(object_type)))
+    }
+
+    /// Selects the fields of the value, looking up the related objects the fields selected refer
+    /// to. The fields of the items of the lists are selected in each item.
+    fn select<'a>(
+        &'a mut self,
+        value: Value,
+        object_type: Option<ObjectType>,
+        fields: &'a [Field],
+        path: Vec<Value>,
+        depth: usize,
+    ) -> LocalBoxFuture<'a, Value> {
+        async move {
+            if fields.is_empty() {
+                return value;
+            }
+
+            match value {
+                Value::Array(items) => {
+                    let mut selected = Vec::with_capacity(items.len());
+                    for (index, item) in items.into_iter().enumerate() {
+                        let mut item_path = path.clone();
+                        item_path.push(Value::from(index));
+                        selected.push(
+                            self.select(item, object_type, fields, item_path, depth)
+                                .await,
+                        );
+                    }
+                    Value::Array(selected)
+                }
+                Value::Object(object) => {
+                    let mut selected = Map::new();
+                    for field in fields {
+                        let mut field_path = path.clone();
+                        field_path.push(Value::from(field.response_key()));
+                        let relation = object_type
+                            .and_then(|object_type| Relation::find(object_type, &field.name));
+
+                        let value = match (field.name.as_str(), relation) {
+                            ("__typename", _) => object_type
+                                .map(|object_type| Value::from(object_type.to_string()))
+                                .unwrap_or(Value::Null),
+                            (_, Some(relation)) => {
+                                match self.resolve_relation(&object, relation, field, depth).await {
+                                    Ok(Some(related)) => {
+                                        self.select(
+                                            related,
+                                            Some(relation.get_object_type()),
+                                            &field.selection_set,
+                                            field_path,
+                                            depth + 1,
+                                        )
+                                        .await
+                                    }
+                                    Ok(None) => Value::Null,
+                                    Err(message) => {
+                                        self.errors.push(GraphQLError {
+                                            message,
+                                            path: field_path,
+                                        });
+                                        Value::Null
+                                    }
+                                }
+                            }
+                            (name, None) => {
+                                let value = object.get(name).cloned().unwrap_or(Value::Null);
+                                self.select(value, None, &field.selection_set, field_path, depth)
+                                    .await
+                            }
+                        };
+                        selected.insert(field.response_key().to_string(), value);
+                    }
+                    Value::Object(selected)
+                }
+                value => value,
+            }
+        }
+        .boxed_local()
+    }
+
+    /// Looks up the objects related to the object, `None` when the object is not related to any
+    async fn resolve_relation(
+        &mut self,
+        object: &Map<String, Value>,
+        relation: Relation,
+        field: &Field,
+        depth: usize,
+    ) -> Result<Option<Value>, String> {
+        ensure_no_arguments(field)?;
+        if depth >= MAX_RESOLVER_DEPTH {
+            return Err(format!(
+                "The field `{}` is nested more than {MAX_RESOLVER_DEPTH} levels deep",
+                field.name
+            ));
+        }
+
+        match object.get(relation.get_key_field()).and_then(Value::as_str) {
+            Some(id) => self
+                .fetch(relation.get_lookup(id.to_string()))
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Looks up the objects, once per query
+    async fn fetch(&mut self, lookup: Lookup) -> Result<Value, String> {
+        if let Some(result) = self.lookups.get(&lookup) {
+            return result.clone();
+        }
+        if self.lookups.len() >= MAX_LOOKUPS_PER_QUERY {
+            return Err(format!(
+                "The query looks up more than {MAX_LOOKUPS_PER_QUERY} objects"
+            ));
+        }
+
+        let result = self
+            .run_lookup(&lookup)
+            .await
+            .map(|value| self.restrict(value));
+        self.lookups.insert(lookup, result.clone());
+        result
+    }
+
+    async fn run_lookup(&self, lookup: &Lookup) -> Result<Value, String> {
+        match lookup {
+            Lookup::Payment(payment_id) => {
+                let request = PaymentsRetrieveRequest {
+                    resource_id: PaymentIdType::PaymentIntentId(payment_id.clone()),
+                    merchant_id: Some(self.merchant_account.merchant_id.clone()),
+                    force_sync: false,
+                    ..Default::default()
+                };
+                Box::pin(payments::payments_core::<
+                    api::PSync,
+                    PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                >(
+                    self.state.clone(),
+                    self.state.get_req_state(),
+                    self.merchant_account.clone(),
+                    self.key_store.clone(),
+                    PaymentStatus,
+                    request,
+                    AuthFlow::Merchant,
+                    CallConnectorAction::Avoid,
+                    None,
+                    HeaderPayload::default(),
+                ))
+                .await
+                .map_err(get_error_message)
+                .and_then(get_json_response)
+            }
+            Lookup::Refund(refund_id) => refunds::refund_response_wrapper(
+                self.state.clone(),
+                self.merchant_account.clone(),
+                self.key_store.clone(),
+                api_models::refunds::RefundsRetrieveRequest {
+                    refund_id: refund_id.clone(),
+                    ..Default::default()
+                },
+                refunds::refund_retrieve_core,
+            )
+            .await
+            .map_err(get_error_message)
+            .and_then(get_json_response),
+            Lookup::Dispute(dispute_id) => disputes::retrieve_dispute(
+                self.state.clone(),
+                self.merchant_account.clone(),
+                api::DisputeId {
+                    dispute_id: dispute_id.clone(),
+                },
+            )
+            .await
+            .map_err(get_error_message)
+            .and_then(get_json_response),
+            Lookup::Customer(customer_id) => customers::retrieve_customer(
+                self.state.clone(),
+                self.merchant_account.clone(),
+                self.key_store.clone(),
+                api_models::customers::CustomerId {
+                    customer_id: get_customer_id(customer_id)?,
+                },
+            )
+            .await
+            .map_err(|error| error.current_context().to_string())
+            .and_then(get_json_response),
+            Lookup::PaymentRefunds(payment_id) => refunds::refund_list(
+                self.state.clone(),
+                self.merchant_account.clone(),
+                api_models::refunds::RefundListRequest {
+                    payment_id: Some(payment_id.clone()),
+                    refund_id: None,
+                    profile_id: None,
+                    limit: None,
+                    offset: None,
+                    cursor: None,
+                    time_range: None,
+                    amount_filter: None,
+                    connector: None,
+                    merchant_connector_id: None,
+                    currency: None,
+                    refund_status: None,
+                },
+            )
+            .await
+            .map_err(get_error_message)
+            .and_then(get_json_response)
+            .map(get_list_data),
+            Lookup::PaymentDisputes(payment_id) => {
+                let disputes = self
+                    .state
+                    .store
+                    .find_disputes_by_merchant_id_payment_id(
+                        &self.merchant_account.merchant_id,
+                        payment_id,
+                    )
+                    .await
+                    .map_err(|_| "Failed to retrieve the disputes of the payment".to_string())?;
+                serde_json::to_value(
+                    disputes
+                        .into_iter()
+                        .map(api_models::disputes::DisputeResponse::foreign_from)
+                        .collect::<Vec<_>>(),
+                )
+                .map_err(|_| "Failed to serialize the response".to_string())
+            }
+            Lookup::CustomerPayments(customer_id) => {
+                let constraints = serde_json::from_value(json!({ "customer_id": customer_id }))
+                    .map_err(|_| "Invalid customer id".to_string())?;
+                self.list_payments(constraints).await
+            }
+        }
+    }
+
+    async fn list_payments(
+        &self,
+        constraints: api::PaymentListConstraints,
+    ) -> Result<Value, String> {
+        payments::list_payments(
+            self.state.clone(),
+            self.merchant_account.clone(),
+            self.key_store.clone(),
+            constraints,
+        )
+        .await
+        .map_err(get_error_message)
+        .and_then(get_json_response)
+        .map(get_list_data)
+    }
+
+    fn restrict(&self, mut value: Value) -> Value {
+        if let Some(restrictions) = &self.restrictions {
+            restrictions.apply(&mut value);
+        }
+        value
+    }
+}
+
+fn get_customer_id(customer_id: &str) -> Result<id_type::CustomerId, String> {
+    id_type::CustomerId::from(customer_id.to_string().into())
+        .map_err(|_| "Invalid customer id".to_string())
+}
@@ -0,0 +1,622 @@
+//! A parser of the subset of the GraphQL query language served by the GraphQL endpoint: queries
+//! with their variables, aliases and arguments. Fragments, directives, mutations and
+//! subscriptions are not supported.
+
+use std::{iter::Peekable, str::CharIndices};
+
+use serde_json::{Map, Number, Value};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("{message}, at position {position}")]
+pub struct ParseError {
+    /// The byte offset in the document the error was raised at
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+/// A field selected by the query, its arguments holding the values of the variables they refer to
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub alias: Option<String>,
+    pub name: String,
+    pub arguments: Map<String, Value>,
+    pub selection_set: Vec<Field>,
+}
+
+impl Field {
+    /// The key of the field in the response, its alias when aliased
+    pub fn response_key(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Name(String),
+    Number(Number),
+    String(String),
+    Punctuator(char),
+    Spread,
+}
+
+/// A value of the document, the variables it refers to being resolved once the query to execute
+/// is known
+#[derive(Clone, Debug)]
+enum InputValue {
+    Variable(String),
+    Const(Value),
+    List(Vec<InputValue>),
+    Object(Vec<(String, InputValue)>),
+}
+
+struct VariableDefinition {
+    position: usize,
+    name: String,
+    non_null: bool,
+    default_value: Option<InputValue>,
+}
+
+struct RawField {
+    position: usize,
+    alias: Option<String>,
+    name: String,
+    arguments: Vec<(String, InputValue)>,
+    selection_set: Vec<RawField>,
+}
+
+struct Operation {
+    name: Option<String>,
+    variable_definitions: Vec<VariableDefinition>,
+    selection_set: Vec<RawField>,
+}
+
+/// Parses the document and returns the fields selected by its query named after the operation
+/// name, or by its only query when the operation name is not provided
+pub fn parse_query(
+    document: &str,
+    operation_name: Option<&str>,
+    variables: &Map<String, Value>,
+) -> Result<Vec<Field>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(document)?,
+        index: 0,
+        end: document.len(),
+    };
+
+    let mut operations = Vec::new();
+    while parser.peek().is_some() {
+        operations.push(parser.parse_operation()?);
+    }
+
+    let operation = match operation_name {
+        Some(operation_name) => operations
+            .into_iter()
+            .find(|operation| operation.name.as_deref() == Some(operation_name))
+            .ok_or_else(|| ParseError::new(0, format!("unknown operation `{operation_name}`")))?,
+        None if operations.len() > 1 => Err(ParseError::new(
+            0,
+            "the operation name is required when the document holds several operations",
+        ))?,
+        None => operations
+            .pop()
+            .ok_or_else(|| ParseError::new(0, "the document holds no operation"))?,
+    };
+
+    let mut values = Map::new();
+    for definition in operation.variable_definitions {
+        let value = match (variables.get(&definition.name), definition.default_value) {
+            (Some(value), _) => value.clone(),
+            (None, Some(default_value)) => {
+                resolve_value(default_value, &Map::new()).map_err(|_| {
+                    ParseError::new(
+                        definition.position,
+                        "the default value of a variable can not refer to variables",
+                    )
+                })?
+            }
+            (None, None) => Value::Null,
+        };
+        if value.is_null() && definition.non_null {
+            Err(ParseError::new(
+                definition.position,
+                format!("the variable `${}` is required", definition.name),
+            ))?
+        }
+        values.insert(definition.name, value);
+    }
+
+    operation
+        .selection_set
+        .into_iter()
+        .map(|field| resolve_field(field, &values))
+        .collect()
+}
+
+fn resolve_field(field: RawField, variables: &Map<String, Value>) -> Result<Field, ParseError> {
+    let mut arguments = Map::new();
+    for (name, value) in field.arguments {
+        let value = resolve_value(value, variables).map_err(|variable| {
+            ParseError::new(
+                field.position,
+                format!("the variable `${variable}` is not defined"),
+            )
+        })?;
+        arguments.insert(name, value);
+    }
+
+    Ok(Field {
+        alias: field.alias,
+        name: field.name,
+        arguments,
+        selection_set: field
+            .selection_set
+            .into_iter()
+            .map(|field| resolve_field(field, variables))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Replaces the variables the value refers to with their values, returning the name of the first
+/// variable not defined when there is one
+fn resolve_value(value: InputValue, variables: &Map<String, Value>) -> Result<Value, String> {
+    match value {
+        InputValue::Variable(name) => variables.get(&name).cloned().ok_or(name),
+        InputValue::Const(value) => Ok(value),
+        InputValue::List(items) => items
+            .into_iter()
+            .map(|item| resolve_value(item, variables))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        InputValue::Object(fields) => fields
+            .into_iter()
+            .map(|(name, value)| Ok::<_, String>((name, resolve_value(value, variables)?)))
+            .collect::<Result<_, _>>()
+            .map(Value::Object),
+    }
+}
+
+fn tokenize(document: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = document.char_indices().peekable();
+
+    while let Some((position, character)) = chars.next() {
+        match character {
+            // Commas are insignificant in GraphQL, as white spaces are
+            ',' | '\u{feff}' => {}
+            character if character.is_whitespace() => {}
+            '#' => {
+                while chars
+                    .next_if(|(_, next)| !matches!(next, '\n' | '\r'))
+                    .is_some()
+                {}
+            }
+            '{' | '}' | '(' | ')' | '[' | ']' | ':' | '!' | '$' | '=' | '@' | '|' | '&' => {
+                tokens.push((position, Token::Punctuator(character)))
+            }
+            '.' => {
+                if chars.next_if(|(_, next)| *next == '.').is_none()
+                    || chars.next_if(|(_, next)| *next == '.').is_none()
+                {
+                    Err(ParseError::new(position, "unexpected character `.`"))?
+                }
+                tokens.push((position, Token::Spread));
+            }
+            '"' => tokens.push((position, Token::String(read_string(&mut chars, position)?))),
+            character if character == '_' || character.is_ascii_alphabetic() => {
+                let mut name = String::from(character);
+                while let Some((_, next)) =
+                    chars.next_if(|(_, next)| *next == '_' || next.is_ascii_alphanumeric())
+                {
+                    name.push(next);
+                }
+                tokens.push((position, Token::Name(name)));
+            }
+            character if character == '-' || character.is_ascii_digit() => {
+                let mut number = String::from(character);
+                while let Some((_, next)) = chars.next_if(|(_, next)| {
+                    next.is_ascii_digit() || matches!(next, '.' | 'e' | 'E' | '+' | '-')
+                }) {
+                    number.push(next);
+                }
+                let value = number
+                    .parse::<i64>()
+                    .map(Number::from)
+                    .ok()
+                    .or_else(|| number.parse::<f64>().ok().and_then(Number::from_f64))
+                    .ok_or_else(|| {
+                        ParseError::new(position, format!("invalid number `{number}`"))
+                    })?;
+                tokens.push((position, Token::Number(value)));
+            }
+            character => Err(ParseError::new(
+                position,
+                format!("unexpected character `{character}`"),
+            ))?,
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn read_string(chars: &mut Peekable<CharIndices<'_>>, start: usize) -> Result<String, ParseError> {
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some((_, '"')) => return Ok(value),
+            Some((position, '\\')) => {
+                let escaped = match chars.next() {
+                    Some((_, '"')) => '"',
+                    Some((_, '\\')) => '\\',
+                    Some((_, '/')) => '/',
+                    Some((_, 'b')) => '\u{8}',
+                    Some((_, 'f')) => '\u{c}',
+                    Some((_, 'n')) => '\n',
+                    Some((_, 'r')) => '\r',
+                    Some((_, 't')) => '\t',
+                    Some((_, 'u')) => {
+                        let code = (0..4)
+                            .filter_map(|_| chars.next().map(|(_, digit)| digit))
+                            .collect::<String>();
+                        u32::from_str_radix(&code, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| {
+                                ParseError::new(position, "invalid unicode escape sequence")
+                            })?
+                    }
+                    _ => Err(ParseError::new(position, "invalid escape sequence"))?,
+                };
+                value.push(escaped);
+            }
+            Some((_, '\n' | '\r')) | None => Err(ParseError::new(start, "unterminated string"))?,
+            Some((_, character)) => value.push(character),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    /// The length of the document, the position of the errors raised at its end
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.index)
+            .map_or(self.end, |(position, _)| *position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.index).map(|(_, token)| token.clone());
+        self.index += 1;
+        token
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(Token::Name(name)) => {
+                ParseError::new(self.position(), format!("unexpected name `{name}`"))
+            }
+            Some(Token::Number(number)) => {
+                ParseError::new(self.position(), format!("unexpected number `{number}`"))
+            }
+            Some(Token::String(_)) => ParseError::new(self.position(), "unexpected string"),
+            Some(Token::Punctuator('@')) => {
+                ParseError::new(self.position(), "directives are not supported")
+            }
+            Some(Token::Punctuator(punctuator)) => {
+                ParseError::new(self.position(), format!("unexpected `{punctuator}`"))
+            }
+            Some(Token::Spread) => ParseError::new(self.position(), "fragments are not supported"),
+            None => ParseError::new(self.position(), "unexpected end of document"),
+        }
+    }
+
+    fn eat(&mut self, punctuator: char) -> bool {
+        let is_next = self.peek() == Some(&Token::Punctuator(punctuator));
+        if is_next {
+            self.index += 1;
+        }
+        is_next
+    }
+
+    fn expect(&mut self, punctuator: char) -> Result<(), ParseError> {
+        if self.eat(punctuator) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn expect_name(&mut self) -> Result<String, ParseError> {
+        match self.peek() {
+            Some(Token::Name(name)) => {
+                let name = name.clone();
+                self.index += 1;
+                Ok(name)
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_operation(&mut self) -> Result<Operation, ParseError> {
+        let position = self.position();
+        match self.peek() {
+            Some(Token::Punctuator('{')) => Ok(Operation {
+                name: None,
+                variable_definitions: Vec::new(),
+                selection_set: self.parse_selection_set()?,
+            }),
+            Some(Token::Name(keyword)) if keyword == "query" => {
+                self.index += 1;
+                let name = match self.peek() {
+                    Some(Token::Name(_)) => Some(self.expect_name()?),
+                    _ => None,
+                };
+                let variable_definitions = self.parse_variable_definitions()?;
+                Ok(Operation {
+                    name,
+                    variable_definitions,
+                    selection_set: self.parse_selection_set()?,
+                })
+            }
+            Some(Token::Name(keyword)) if keyword == "mutation" || keyword == "subscription" => {
+                Err(ParseError::new(position, "only queries are supported"))
+            }
+            Some(Token::Name(keyword)) if keyword == "fragment" => {
+                Err(ParseError::new(position, "fragments are not supported"))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_variable_definitions(&mut self) -> Result<Vec<VariableDefinition>, ParseError> {
+        let mut definitions = Vec::new();
+        if !self.eat('(') {
+            return Ok(definitions);
+        }
+
+        while !self.eat(')') {
+            let position = self.position();
+            self.expect('$')?;
+            let name = self.expect_name()?;
+            self.expect(':')?;
+            let non_null = self.parse_type()?;
+            let default_value = if self.eat('=') {
+                Some(self.parse_value()?)
+            } else {
+                None
+            };
+            if definitions
+                .iter()
+                .any(|definition: &VariableDefinition| definition.name == name)
+            {
+                Err(ParseError::new(
+                    position,
+                    format!("the variable `${name}` is defined more than once"),
+                ))?
+            }
+            definitions.push(VariableDefinition {
+                position,
+                name,
+                non_null,
+                default_value,
+            });
+        }
+
+        Ok(definitions)
+    }
+
+    /// Parses the type of a variable, returning whether it is a non-null type. The types are not
+    /// checked, the values of the variables being checked when the arguments are deserialized.
+    fn parse_type(&mut self) -> Result<bool, ParseError> {
+        if self.eat('[') {
+            self.parse_type()?;
+            self.expect(']')?;
+        } else {
+            self.expect_name()?;
+        }
+        Ok(self.eat('!'))
+    }
+
+    fn parse_selection_set(&mut self) -> Result<Vec<RawField>, ParseError> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        while !self.eat('}') {
+            fields.push(self.parse_field()?);
+        }
+        if fields.is_empty() {
+            Err(ParseError::new(self.position(), "empty selection set"))?
+        }
+        Ok(fields)
+    }
+
+    fn parse_field(&mut self) -> Result<RawField, ParseError> {
+        let position = self.position();
+        let mut name = self.expect_name()?;
+        let alias = if self.eat(':') {
+            Some(std::mem::replace(&mut name, self.expect_name()?))
+        } else {
+            None
+        };
+
+        let mut arguments = Vec::new();
+        if self.eat('(') {
+            while !self.eat(')') {
+                let argument_position = self.position();
+                let argument = self.expect_name()?;
+                self.expect(':')?;
+                let value = self.parse_value()?;
+                if arguments.iter().any(|(name, _)| name == &argument) {
+                    Err(ParseError::new(
+                        argument_position,
+                        format!("the argument `{argument}` is provided more than once"),
+                    ))?
+                }
+                arguments.push((argument, value));
+            }
+        }
+
+        let selection_set = match self.peek() {
+            Some(Token::Punctuator('{')) => self.parse_selection_set()?,
+            _ => Vec::new(),
+        };
+
+        Ok(RawField {
+            position,
+            alias,
+            name,
+            arguments,
+            selection_set,
+        })
+    }
+
+    fn parse_value(&mut self) -> Result<InputValue, ParseError> {
+        match self.peek() {
+            Some(Token::Punctuator('$')) => {
+                self.index += 1;
+                Ok(InputValue::Variable(self.expect_name()?))
+            }
+            Some(Token::Punctuator('[')) => {
+                self.index += 1;
+                let mut items = Vec::new();
+                while !self.eat(']') {
+                    items.push(self.parse_value()?);
+                }
+                Ok(InputValue::List(items))
+            }
+            Some(Token::Punctuator('{')) => {
+                self.index += 1;
+                let mut fields = Vec::new();
+                while !self.eat('}') {
+                    let name = self.expect_name()?;
+                    self.expect(':')?;
+                    fields.push((name, self.parse_value()?));
+                }
+                Ok(InputValue::Object(fields))
+            }
+            Some(Token::Name(_) | Token::Number(_) | Token::String(_)) => {
+                let value = match self.next() {
+                    Some(Token::Name(name)) => match name.as_str() {
+                        "true" => Value::Bool(true),
+                        "false" => Value::Bool(false),
+                        "null" => Value::Null,
+                        // Enum values are passed as the strings they are deserialized from
+                        _ => Value::String(name),
+                    },
+                    Some(Token::Number(number)) => Value::Number(number),
+                    Some(Token::String(text)) => Value::String(text),
+                    _ => Value::Null,
+                };
+                Ok(InputValue::Const(value))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use serde_json::json;
+
+    use super::*;
+
+    fn variables(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(variables) => variables,
+            _ => Map::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_query_with_aliases_arguments_and_nested_fields() {
+        let fields = parse_query(
+            r#"
+            # The latest payments of the customer along with their refunds
+            {
+                latest: payments(customer_id: "cus_1", limit: 2) {
+                    payment_id
+                    refunds { refund_id, status }
+                }
+                customer(customer_id: "cus_1") { email }
+            }
+            "#,
+            None,
+            &Map::new(),
+        )
+        .unwrap();
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].response_key(), "latest");
+        assert_eq!(fields[0].name, "payments");
+        assert_eq!(
+            Value::Object(fields[0].arguments.clone()),
+            json!({"customer_id": "cus_1", "limit": 2})
+        );
+        assert_eq!(fields[0].selection_set[1].name, "refunds");
+        assert_eq!(fields[0].selection_set[1].selection_set.len(), 2);
+        assert_eq!(fields[1].response_key(), "customer");
+    }
+
+    #[test]
+    fn test_parse_query_resolves_variables_of_the_operation() {
+        let document = r#"
+            query Payment($payment_id: String!, $expand: Boolean = false) {
+                payment(payment_id: $payment_id, filters: { expand: [$expand] }) { status }
+            }
+            query Refund($refund_id: String!) { refund(refund_id: $refund_id) { status } }
+        "#;
+
+        let fields = parse_query(
+            document,
+            Some("Payment"),
+            &variables(json!({"payment_id": "pay_1"})),
+        )
+        .unwrap();
+        assert_eq!(
+            Value::Object(fields[0].arguments.clone()),
+            json!({"payment_id": "pay_1", "filters": {"expand": [false]}})
+        );
+
+        assert!(parse_query(document, None, &Map::new()).is_err());
+        assert_eq!(
+            parse_query(document, Some("Refund"), &Map::new())
+                .unwrap_err()
+                .message,
+            "the variable `$refund_id` is required"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_rejects_the_unsupported_syntax() {
+        for document in [
+            "mutation { payment { status } }",
+            "{ payment { ...PaymentFields } }",
+            "{ payment @include(if: true) { status } }",
+            "{ payment(payment_id: $payment_id) { status } }",
+            "{ payment { status }",
+            "{ }",
+        ] {
+            assert!(
+                parse_query(document, None, &Map::new()).is_err(),
+                "{document}"
+            );
+        }
+    }
+}
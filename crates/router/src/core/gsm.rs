@@ -67,6 +67,9 @@ pub async fn update_gsm_rule(
         step_up_possible,
         unified_code,
         unified_message,
+        decline_category,
+        network_advice_code,
+        user_actionable,
     } = gsm_request;
     GsmInterface::update_gsm_rule(
         db,
@@ -82,6 +85,9 @@ pub async fn update_gsm_rule(
             step_up_possible,
             unified_code,
             unified_message,
+            decline_category: decline_category.map(|category| category.to_string()),
+            network_advice_code,
+            user_actionable,
         },
     )
     .await
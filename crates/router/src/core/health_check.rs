@@ -1,16 +1,48 @@
+use std::{collections::BTreeMap, future::Future, time::Instant};
+
 #[cfg(feature = "olap")]
 use analytics::health_check::HealthCheck;
-use api_models::health_check::HealthState;
+use api_models::health_check::{
+    DependencyHealth, DependencyStatus, DetailedHealthCheckResponse, HealthState,
+};
+use diesel_models::enums::ProcessTrackerStatus;
 use error_stack::ResultExt;
-use router_env::logger;
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     consts,
-    core::errors::{self, CustomResult},
+    core::errors::{self, CustomResult, RouterResponse},
     routes::app,
     services::api as services,
+    types::storage::ProcessTrackerRunner,
 };
 
+/// Header set on the response of the detailed health check with its overall status, the load
+/// balancers reading the status without parsing the body
+pub const HEALTH_STATUS_HEADER: &str = "x-health-status";
+
+/// The statuses of the tasks yet to be picked by the scheduler
+const SCHEDULER_WAITING_STATUSES: [ProcessTrackerStatus; 2] =
+    [ProcessTrackerStatus::New, ProcessTrackerStatus::Pending];
+
+/// The statuses of the outgoing webhooks yet to be delivered
+const OUTGOING_WEBHOOK_QUEUED_STATUSES: [ProcessTrackerStatus; 4] = [
+    ProcessTrackerStatus::New,
+    ProcessTrackerStatus::Pending,
+    ProcessTrackerStatus::Processing,
+    ProcessTrackerStatus::ProcessStarted,
+];
+
+/// The workflows delivering the outgoing webhooks
+const OUTGOING_WEBHOOK_RUNNERS: [ProcessTrackerRunner; 2] = [
+    ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+    ProcessTrackerRunner::OutboxRelayWorkflow,
+];
+
+/// The tasks scheduled earlier than this are not picked by the scheduler, and so are not counted
+/// in its lag, when the scheduler is not configured
+const DEFAULT_SCHEDULER_LAG_WINDOW_IN_SECS: i64 = 1800;
+
 #[async_trait::async_trait]
 pub trait HealthCheckInterface {
     async fn health_check_db(&self) -> CustomResult<HealthState, errors::HealthCheckDBError>;
@@ -159,3 +191,278 @@ impl HealthCheckInterface for app::SessionState {
         Ok(HealthState::Running)
     }
 }
+
+/// The outcome of checking a dependency, before its latency is recorded
+struct DependencyCheck {
+    status: DependencyStatus,
+    value: Option<f64>,
+    threshold: Option<f64>,
+    message: Option<String>,
+}
+
+impl DependencyCheck {
+    fn from_health_state<E: error_stack::Context>(result: CustomResult<HealthState, E>) -> Self {
+        match result {
+            Ok(HealthState::Running) => Self::with_status(DependencyStatus::Healthy),
+            Ok(HealthState::NotApplicable) => Self::with_status(DependencyStatus::NotApplicable),
+            Ok(HealthState::Error) => Self::with_status(DependencyStatus::Unhealthy),
+            Err(error) => Self::failed(error),
+        }
+    }
+
+    fn with_status(status: DependencyStatus) -> Self {
+        Self {
+            status,
+            value: None,
+            threshold: None,
+            message: None,
+        }
+    }
+
+    fn failed<E: error_stack::Context>(error: error_stack::Report<E>) -> Self {
+        Self {
+            message: Some(error.to_string()),
+            ..Self::with_status(DependencyStatus::Unhealthy)
+        }
+    }
+
+    /// Degraded when the measurement is beyond the threshold
+    fn measured(value: f64, threshold: f64) -> Self {
+        Self {
+            status: if value > threshold {
+                DependencyStatus::Degraded
+            } else {
+                DependencyStatus::Healthy
+            },
+            value: Some(value),
+            threshold: Some(threshold),
+            message: None,
+        }
+    }
+}
+
+async fn check_dependency(
+    critical: bool,
+    check: impl Future<Output = DependencyCheck>,
+) -> DependencyHealth {
+    let start_instant = Instant::now();
+    let check = check.await;
+
+    DependencyHealth {
+        status: check.status,
+        critical,
+        latency_in_ms: start_instant.elapsed().as_millis(),
+        value: check.value,
+        threshold: check.threshold,
+        message: check.message,
+    }
+}
+
+/// Replication lag of the read replica in seconds, degraded beyond the lag tolerated before the
+/// reads are served by the primary
+async fn check_db_replica(state: &app::SessionState) -> DependencyCheck {
+    match state.store.health_check_db_replica().await {
+        #[cfg(feature = "olap")]
+        Ok(Some(lag)) => {
+            let max_lag_in_secs = state.conf.replica_routing.max_lag_in_secs;
+            let max_lag = u32::try_from(max_lag_in_secs).map_or(f64::MAX, f64::from);
+            DependencyCheck::measured(lag, max_lag)
+        }
+        #[cfg(not(feature = "olap"))]
+        Ok(Some(_)) => DependencyCheck::with_status(DependencyStatus::NotApplicable),
+        Ok(None) => DependencyCheck::with_status(DependencyStatus::NotApplicable),
+        Err(error) => DependencyCheck::failed(error),
+    }
+}
+
+/// Time in seconds the earliest task due has been waiting to be picked by the scheduler
+async fn check_scheduler_lag(state: &app::SessionState) -> DependencyCheck {
+    let now = common_utils::date_time::now();
+    let window_in_secs = state
+        .conf
+        .scheduler
+        .as_ref()
+        .map_or(DEFAULT_SCHEDULER_LAG_WINDOW_IN_SECS, |scheduler| {
+            scheduler.producer.lower_fetch_limit
+        });
+    let time_lower_limit = now.saturating_sub(time::Duration::seconds(window_in_secs));
+
+    match state
+        .store
+        .find_earliest_process_schedule_time(
+            time_lower_limit,
+            now,
+            SCHEDULER_WAITING_STATUSES.to_vec(),
+        )
+        .await
+    {
+        Ok(earliest_schedule_time) => DependencyCheck::measured(
+            earliest_schedule_time
+                .map_or(0.0, |schedule_time| (now - schedule_time).as_seconds_f64()),
+            f64::from(state.conf.health_check.scheduler_lag_threshold_in_secs),
+        ),
+        Err(error) => DependencyCheck::failed(error),
+    }
+}
+
+/// Number of outgoing webhooks waiting to be delivered, or being delivered
+async fn check_outgoing_webhook_queue(state: &app::SessionState) -> DependencyCheck {
+    match state
+        .store
+        .count_processes_by_runners_statuses(
+            OUTGOING_WEBHOOK_RUNNERS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            OUTGOING_WEBHOOK_QUEUED_STATUSES.to_vec(),
+        )
+        .await
+    {
+        Ok(queue_depth) => DependencyCheck::measured(
+            u32::try_from(queue_depth).map_or(f64::MAX, f64::from),
+            f64::from(state.conf.health_check.outgoing_webhook_queue_threshold),
+        ),
+        Err(error) => DependencyCheck::failed(error),
+    }
+}
+
+/// Unhealthy when a critical dependency is unhealthy, degraded when any other dependency is not
+/// healthy
+fn get_overall_status(dependencies: &BTreeMap<String, DependencyHealth>) -> DependencyStatus {
+    dependencies
+        .values()
+        .map(|dependency| match dependency.status {
+            DependencyStatus::Unhealthy if !dependency.critical => DependencyStatus::Degraded,
+            DependencyStatus::NotApplicable => DependencyStatus::Healthy,
+            status => status,
+        })
+        .max()
+        .unwrap_or(DependencyStatus::Healthy)
+}
+
+/// Checks each dependency of the application, reporting the status of each of them instead of
+/// failing on the first unhealthy one
+#[instrument(skip_all)]
+pub async fn detailed_health_check(
+    state: app::SessionState,
+) -> RouterResponse<DetailedHealthCheckResponse> {
+    let (
+        postgres_primary,
+        postgres_replica,
+        redis,
+        locker,
+        scheduler,
+        outgoing_webhooks,
+        outgoing_request,
+    ) = tokio::join!(
+        check_dependency(true, async {
+            DependencyCheck::from_health_state(state.health_check_db().await)
+        }),
+        check_dependency(false, check_db_replica(&state)),
+        check_dependency(true, async {
+            DependencyCheck::from_health_state(state.health_check_redis().await)
+        }),
+        check_dependency(true, async {
+            DependencyCheck::from_health_state(state.health_check_locker().await)
+        }),
+        check_dependency(false, check_scheduler_lag(&state)),
+        check_dependency(false, check_outgoing_webhook_queue(&state)),
+        check_dependency(false, async {
+            DependencyCheck::from_health_state(state.health_check_outgoing().await)
+        }),
+    );
+
+    let mut dependencies = BTreeMap::from([
+        ("postgres_primary".to_string(), postgres_primary),
+        ("postgres_replica".to_string(), postgres_replica),
+        ("redis".to_string(), redis),
+        ("locker".to_string(), locker),
+        ("scheduler".to_string(), scheduler),
+        ("outgoing_webhooks".to_string(), outgoing_webhooks),
+        ("outgoing_request".to_string(), outgoing_request),
+    ]);
+
+    #[cfg(feature = "olap")]
+    {
+        let (analytics, opensearch) = tokio::join!(
+            check_dependency(false, async {
+                DependencyCheck::from_health_state(state.health_check_analytics().await)
+            }),
+            check_dependency(false, async {
+                DependencyCheck::from_health_state(state.health_check_opensearch().await)
+            }),
+        );
+        dependencies.insert("analytics".to_string(), analytics);
+        dependencies.insert("opensearch".to_string(), opensearch);
+    }
+
+    let status = get_overall_status(&dependencies);
+    for (name, dependency) in dependencies
+        .iter()
+        .filter(|(_, dependency)| dependency.status > DependencyStatus::Healthy)
+    {
+        logger::warn!(
+            dependency = %name,
+            status = ?dependency.status,
+            message = ?dependency.message,
+            "Dependency is not healthy"
+        );
+    }
+
+    Ok(services::ApplicationResponse::JsonWithHeaders((
+        DetailedHealthCheckResponse {
+            status,
+            dependencies,
+        },
+        vec![(HEALTH_STATUS_HEADER.to_string(), status.to_string().into())],
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(status: DependencyStatus, critical: bool) -> DependencyHealth {
+        DependencyHealth {
+            status,
+            critical,
+            latency_in_ms: 0,
+            value: None,
+            threshold: None,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_overall_status() {
+        let mut dependencies = BTreeMap::from([
+            (
+                "redis".to_string(),
+                dependency(DependencyStatus::Healthy, true),
+            ),
+            (
+                "locker".to_string(),
+                dependency(DependencyStatus::NotApplicable, true),
+            ),
+        ]);
+        assert_eq!(get_overall_status(&dependencies), DependencyStatus::Healthy);
+
+        dependencies.insert(
+            "analytics".to_string(),
+            dependency(DependencyStatus::Unhealthy, false),
+        );
+        assert_eq!(
+            get_overall_status(&dependencies),
+            DependencyStatus::Degraded
+        );
+
+        dependencies.insert(
+            "postgres_primary".to_string(),
+            dependency(DependencyStatus::Unhealthy, true),
+        );
+        assert_eq!(
+            get_overall_status(&dependencies),
+            DependencyStatus::Unhealthy
+        );
+    }
+}
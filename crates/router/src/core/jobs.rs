@@ -0,0 +1,291 @@
+//! Jobs run in the background by the scheduler for the operations on many objects at once. The
+//! items of a job are processed batch by batch, one batch by each run of the job task, the progress
+//! being recorded on the job after each batch so that a job retried after a failed attempt resumes
+//! from its last batch and a cancelled job stops at the end of the batch being processed.
+
+pub mod bulk_refunds;
+
+use api_models::jobs::JobResponse;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+pub const JOB_TASK: &str = "JOB";
+pub const JOB_TAG: &str = "JOB";
+
+/// The number of attempts of a job, after which the job is failed
+const DEFAULT_JOB_MAX_ATTEMPTS: i32 = 3;
+
+/// The delay before a job is retried after a failed attempt, multiplied by the number of the
+/// attempt
+pub const JOB_RETRY_DELAY: time::Duration = time::Duration::minutes(1);
+
+/// The statuses of the jobs yet to be completed
+const ACTIVE_JOB_STATUSES: [enums::JobStatus; 2] =
+    [enums::JobStatus::Queued, enums::JobStatus::Running];
+
+/// The items processed by a batch of a job, along with the outcome of all the items processed so
+/// far
+pub struct JobBatch {
+    pub output: serde_json::Value,
+    pub processed_count: i32,
+    pub failed_count: i32,
+}
+
+fn get_job_response(job: storage::Job) -> JobResponse {
+    JobResponse {
+        job_id: job.job_id,
+        merchant_id: job.merchant_id,
+        job_type: job.job_type,
+        status: job.status,
+        total_count: job.total_count,
+        processed_count: job.processed_count,
+        failed_count: job.failed_count,
+        attempt_count: job.attempt_count,
+        max_attempts: job.max_attempts,
+        error_message: job.error_message,
+        output: job.output,
+        completed_at: job.completed_at,
+        created_at: job.created_at,
+        modified_at: job.modified_at,
+    }
+}
+
+/// Creates a job of the merchant and queues it to be run by the scheduler
+#[instrument(skip_all)]
+pub async fn create_job(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    job_type: enums::JobType,
+    input: serde_json::Value,
+    total_count: i32,
+) -> RouterResponse<JobResponse> {
+    let db = state.store.as_ref();
+    let now = common_utils::date_time::now();
+    let job_new = storage::JobNew {
+        job_id: common_utils::generate_id(consts::ID_LENGTH, "job"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        job_type,
+        status: enums::JobStatus::Queued,
+        input,
+        total_count,
+        max_attempts: DEFAULT_JOB_MAX_ATTEMPTS,
+        created_at: now,
+        modified_at: now,
+    };
+    let job = db
+        .insert_job(job_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the job")?;
+
+    let tracking_data = storage::JobTrackingData {
+        merchant_id: job.merchant_id.clone(),
+        job_id: job.job_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        format!(
+            "{runner}_{task}_{job_id}",
+            runner = storage::ProcessTrackerRunner::JobWorkflow,
+            task = JOB_TASK,
+            job_id = job.job_id
+        ),
+        JOB_TASK,
+        storage::ProcessTrackerRunner::JobWorkflow,
+        [JOB_TAG],
+        tracking_data,
+        now,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct job process tracker task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the job task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "Job"), ("job_type", job_type.to_string())]),
+    );
+
+    Ok(ApplicationResponse::Json(get_job_response(job)))
+}
+
+async fn find_job(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    job_id: &str,
+) -> RouterResult<storage::Job> {
+    state
+        .store
+        .find_job_by_merchant_id_job_id(&merchant_account.merchant_id, job_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Job not found".to_string(),
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_job(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    job_id: String,
+) -> RouterResponse<JobResponse> {
+    let job = find_job(&state, &merchant_account, &job_id).await?;
+
+    Ok(ApplicationResponse::Json(get_job_response(job)))
+}
+
+/// Cancels a job yet to be completed. The items processed until then are not reverted.
+#[instrument(skip_all)]
+pub async fn cancel_job(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    job_id: String,
+) -> RouterResponse<JobResponse> {
+    let job = find_job(&state, &merchant_account, &job_id).await?;
+    utils::when(!ACTIVE_JOB_STATUSES.contains(&job.status), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("The job cannot be cancelled as it is {}", job.status),
+        }))
+    })?;
+
+    let job = state
+        .store
+        .update_job_with_status(
+            job,
+            ACTIVE_JOB_STATUSES.to_vec(),
+            storage::JobUpdate::StatusUpdate {
+                status: enums::JobStatus::Cancelled,
+                error_message: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The job was completed in the meantime".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_job_response(job)))
+}
+
+/// Updates the job if it was not cancelled in the meantime, returning the job as stored
+async fn update_active_job(
+    state: &SessionState,
+    job: storage::Job,
+    job_update: storage::JobUpdate,
+) -> RouterResult<storage::Job> {
+    let db = state.store.as_ref();
+    match db
+        .update_job_with_status(job.clone(), ACTIVE_JOB_STATUSES.to_vec(), job_update)
+        .await
+    {
+        Ok(job) => Ok(job),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .find_job_by_merchant_id_job_id(&job.merchant_id, &job.job_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the job"),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to update the job"),
+    }
+}
+
+/// Runs the next batch of the job and records its progress. The job returned is still running
+/// when it has items left to be processed.
+#[instrument(skip_all, fields(job_id = %job.job_id))]
+pub async fn run_job(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    job: storage::Job,
+) -> RouterResult<storage::Job> {
+    let job = match job.status {
+        enums::JobStatus::Queued => {
+            update_active_job(state, job, storage::JobUpdate::Start).await?
+        }
+        enums::JobStatus::Running => job,
+        enums::JobStatus::Succeeded | enums::JobStatus::Failed | enums::JobStatus::Cancelled => {
+            return Ok(job)
+        }
+    };
+    if job.status != enums::JobStatus::Running {
+        return Ok(job);
+    }
+
+    let batch = match job.job_type {
+        enums::JobType::BulkRefund => {
+            bulk_refunds::run_bulk_refund_batch(state, merchant_account, key_store, &job).await?
+        }
+    };
+    let is_completed = batch.processed_count >= job.total_count;
+    let job = update_active_job(
+        state,
+        job,
+        storage::JobUpdate::ProgressUpdate {
+            output: batch.output,
+            processed_count: batch.processed_count,
+            failed_count: batch.failed_count,
+        },
+    )
+    .await?;
+
+    if !is_completed || job.status != enums::JobStatus::Running {
+        return Ok(job);
+    }
+    update_active_job(
+        state,
+        job,
+        storage::JobUpdate::StatusUpdate {
+            status: enums::JobStatus::Succeeded,
+            error_message: None,
+        },
+    )
+    .await
+}
+
+/// Records the failed attempt of the job, the job being retried from its last progress
+pub async fn retry_job(
+    state: &SessionState,
+    job: storage::Job,
+    error_message: String,
+) -> RouterResult<storage::Job> {
+    let attempt_count = job.attempt_count + 1;
+    update_active_job(
+        state,
+        job,
+        storage::JobUpdate::RetryUpdate {
+            attempt_count,
+            error_message,
+        },
+    )
+    .await
+}
+
+/// Fails the job after its last attempt failed
+pub async fn fail_job(
+    state: &SessionState,
+    job: storage::Job,
+    error_message: String,
+) -> RouterResult<storage::Job> {
+    update_active_job(
+        state,
+        job,
+        storage::JobUpdate::StatusUpdate {
+            status: enums::JobStatus::Failed,
+            error_message: Some(error_message),
+        },
+    )
+    .await
+}
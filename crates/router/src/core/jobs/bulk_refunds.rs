@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use actix_web::ResponseError;
+use api_models::{
+    jobs::JobResponse,
+    refunds::{
+        BulkRefundJobOutput, BulkRefundOutcome, BulkRefundRequest, RefundRequest, RefundStatus,
+    },
+};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::JobBatch;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        refunds,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+/// Maximum number of refunds created by a bulk refund job
+const MAX_BULK_REFUND_ITEMS: usize = 1000;
+
+/// Number of refunds created by each run of the job task
+const BULK_REFUND_BATCH_SIZE: usize = 50;
+
+/// Creates a job creating the refunds in the background. The refunds without an identifier are
+/// given one, so that a refund created by an attempt of the job which failed is not created again
+/// when the job is retried.
+#[instrument(skip_all)]
+pub async fn create_bulk_refund_job(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    mut request: BulkRefundRequest,
+) -> RouterResponse<JobResponse> {
+    utils::when(
+        request.refunds.is_empty() || request.refunds.len() > MAX_BULK_REFUND_ITEMS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("refunds must hold between 1 and {MAX_BULK_REFUND_ITEMS} refunds"),
+            }))
+        },
+    )?;
+
+    let mut refund_ids = HashSet::new();
+    for item in request.refunds.iter_mut() {
+        let refund_id = item
+            .refund_id
+            .get_or_insert_with(|| common_utils::generate_id(consts::ID_LENGTH, "ref"));
+        utils::when(!refund_ids.insert(refund_id.clone()), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("refund_id {refund_id} is provided for more than one refund"),
+            }))
+        })?;
+    }
+
+    let total_count = i32::try_from(request.refunds.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let input = request
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    super::create_job(
+        &state,
+        &merchant_account,
+        enums::JobType::BulkRefund,
+        input,
+        total_count,
+    )
+    .await
+}
+
+/// Creates the next batch of refunds of the job. The refunds which cannot be created are recorded
+/// as failed, the errors of the server failing the attempt of the job instead.
+#[instrument(skip_all)]
+pub async fn run_bulk_refund_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    job: &storage::Job,
+) -> RouterResult<JobBatch> {
+    let request: BulkRefundRequest = job
+        .input
+        .clone()
+        .parse_value("BulkRefundRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let mut output: BulkRefundJobOutput = job
+        .output
+        .clone()
+        .map(|output| output.parse_value("BulkRefundJobOutput"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .unwrap_or_default();
+    let processed_count = usize::try_from(job.processed_count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    // The outcome of the refunds of a batch whose progress could not be recorded is overwritten
+    output.refunds.truncate(processed_count);
+
+    let mut failed_count = job.failed_count;
+    for item in request
+        .refunds
+        .into_iter()
+        .skip(processed_count)
+        .take(BULK_REFUND_BATCH_SIZE)
+    {
+        let refund_id = item
+            .refund_id
+            .get_required_value("refund_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let refund_request = RefundRequest {
+            payment_id: item.payment_id.clone(),
+            refund_id: Some(refund_id.clone()),
+            amount: item.amount,
+            reason: item.reason,
+            ..Default::default()
+        };
+        let outcome = match Box::pin(refunds::refund_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            refund_request,
+        ))
+        .await
+        {
+            Ok(
+                ApplicationResponse::Json(refund)
+                | ApplicationResponse::JsonWithHeaders((refund, _)),
+            ) => Ok(refund.status),
+            Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response from the refund creation"),
+            // The refund was created by an attempt of the job which failed
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::DuplicateRefundRequest
+                ) =>
+            {
+                state
+                    .store
+                    .find_refund_by_merchant_id_refund_id(
+                        &merchant_account.merchant_id,
+                        &refund_id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the refund created by the job")
+                    .map(|refund| RefundStatus::from(refund.refund_status))
+            }
+            Err(error) => Err(error),
+        };
+
+        let outcome = match outcome {
+            Ok(status) => BulkRefundOutcome {
+                payment_id: item.payment_id,
+                refund_id,
+                status: Some(status),
+                error_message: None,
+            },
+            Err(error) if error.current_context().status_code().is_server_error() => {
+                return Err(error);
+            }
+            Err(error) => {
+                logger::info!(?error, payment_id = %item.payment_id, "Failed to create the refund");
+                failed_count += 1;
+                BulkRefundOutcome {
+                    payment_id: item.payment_id,
+                    refund_id,
+                    status: None,
+                    error_message: Some(error.current_context().to_string()),
+                }
+            }
+        };
+        output.refunds.push(outcome);
+    }
+
+    Ok(JobBatch {
+        processed_count: i32::try_from(output.refunds.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        failed_count,
+        output: output
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    })
+}
@@ -0,0 +1,373 @@
+use std::str::FromStr;
+
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+#[cfg(feature = "olap")]
+pub mod reporting;
+
+#[derive(Default)]
+struct LedgerReferences {
+    payment_id: Option<String>,
+    refund_id: Option<String>,
+    dispute_id: Option<String>,
+    payout_id: Option<String>,
+}
+
+/// A balanced transaction, moving an amount from the credited account to the debited account.
+/// The identifier of a transaction is derived from the event it records, so that recording the
+/// same event again is a no-op.
+struct LedgerTransaction {
+    transaction_id: String,
+    event_type: enums::LedgerEventType,
+    connector: String,
+    merchant_connector_id: Option<String>,
+    debit_account: enums::LedgerAccount,
+    credit_account: enums::LedgerAccount,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    references: LedgerReferences,
+}
+
+impl LedgerTransaction {
+    fn into_entries(self, merchant_id: &str) -> Vec<storage::LedgerEntryNew> {
+        let created_at = common_utils::date_time::now();
+        [
+            (self.debit_account, self.amount),
+            (
+                self.credit_account,
+                MinorUnit::new(-self.amount.get_amount_as_i64()),
+            ),
+        ]
+        .into_iter()
+        .map(|(account, amount)| storage::LedgerEntryNew {
+            entry_id: common_utils::generate_id(consts::ID_LENGTH, "le"),
+            transaction_id: self.transaction_id.clone(),
+            merchant_id: merchant_id.to_owned(),
+            connector: self.connector.clone(),
+            merchant_connector_id: self.merchant_connector_id.clone(),
+            account,
+            event_type: self.event_type,
+            amount,
+            currency: self.currency,
+            payment_id: self.references.payment_id.clone(),
+            refund_id: self.references.refund_id.clone(),
+            dispute_id: self.references.dispute_id.clone(),
+            payout_id: self.references.payout_id.clone(),
+            created_at,
+        })
+        .collect()
+    }
+}
+
+async fn post_transaction(
+    state: &SessionState,
+    merchant_id: &str,
+    transaction: LedgerTransaction,
+) -> RouterResult<()> {
+    if transaction.amount.get_amount_as_i64() <= 0 {
+        return Ok(());
+    }
+
+    let transaction_id = transaction.transaction_id.clone();
+    match state
+        .store
+        .insert_ledger_transaction(transaction.into_entries(merchant_id))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!(%transaction_id, "Ledger transaction was already recorded");
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to record the ledger transaction `{transaction_id}`")
+            }),
+    }
+}
+
+fn get_account_total(entries: &[storage::LedgerEntry], account: enums::LedgerAccount) -> i64 {
+    entries
+        .iter()
+        .filter(|entry| entry.account == account)
+        .map(|entry| entry.amount.get_amount_as_i64())
+        .sum()
+}
+
+fn is_authorized(status: enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
+            | enums::AttemptStatus::CaptureInitiated
+            | enums::AttemptStatus::CaptureFailed
+            | enums::AttemptStatus::VoidInitiated
+            | enums::AttemptStatus::Voided
+            | enums::AttemptStatus::VoidFailed
+    )
+}
+
+/// Whether no more amount can be captured on the payment
+fn is_capture_complete(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::Failed
+    )
+}
+
+async fn post_payment_transactions(
+    state: &SessionState,
+    merchant_id: &str,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let (Some(connector), Some(currency)) = (
+        payment_attempt.connector.clone(),
+        payment_attempt.currency.or(payment_intent.currency),
+    ) else {
+        return Ok(());
+    };
+    if !is_authorized(payment_attempt.status) {
+        return Ok(());
+    }
+
+    let entries = state
+        .store
+        .find_ledger_entries_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the ledger entries of the payment")?;
+    let payment_transaction =
+        |transaction_id: String, event_type, debit_account, credit_account, amount| {
+            LedgerTransaction {
+                transaction_id,
+                event_type,
+                connector: connector.clone(),
+                merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                debit_account,
+                credit_account,
+                amount,
+                currency,
+                references: LedgerReferences {
+                    payment_id: Some(payment_intent.payment_id.clone()),
+                    ..Default::default()
+                },
+            }
+        };
+
+    let mut held_amount = get_account_total(&entries, enums::LedgerAccount::AuthorizedFunds);
+    let authorization_id = format!("authorization:{}", payment_attempt.attempt_id);
+    if !entries
+        .iter()
+        .any(|entry| entry.transaction_id == authorization_id)
+    {
+        post_transaction(
+            state,
+            merchant_id,
+            payment_transaction(
+                authorization_id,
+                enums::LedgerEventType::Authorization,
+                enums::LedgerAccount::AuthorizedFunds,
+                enums::LedgerAccount::AuthorizationHolds,
+                payment_attempt.net_amount,
+            ),
+        )
+        .await?;
+        held_amount += payment_attempt.net_amount.get_amount_as_i64();
+    }
+
+    // Captures are recorded by the increase of the captured amount of the payment, as a payment
+    // can be captured in several parts
+    let captured_amount = payment_intent
+        .amount_captured
+        .unwrap_or_default()
+        .get_amount_as_i64();
+    let recorded_captured_amount = -get_account_total(&entries, enums::LedgerAccount::Sales);
+    if captured_amount > recorded_captured_amount {
+        post_transaction(
+            state,
+            merchant_id,
+            payment_transaction(
+                format!("capture:{}:{captured_amount}", payment_intent.payment_id),
+                enums::LedgerEventType::Capture,
+                enums::LedgerAccount::ConnectorReceivable,
+                enums::LedgerAccount::Sales,
+                MinorUnit::new(captured_amount - recorded_captured_amount),
+            ),
+        )
+        .await?;
+    }
+
+    if is_capture_complete(payment_intent.status) && held_amount > 0 {
+        post_transaction(
+            state,
+            merchant_id,
+            payment_transaction(
+                format!("authorization_release:{}", payment_attempt.attempt_id),
+                enums::LedgerEventType::AuthorizationRelease,
+                enums::LedgerAccount::AuthorizationHolds,
+                enums::LedgerAccount::AuthorizedFunds,
+                MinorUnit::new(held_amount),
+            ),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Records the authorization, the captures and the release of the authorization of a payment,
+/// from its current state. The ledger does not affect the payment flows, so failures are only
+/// logged.
+#[instrument(skip_all)]
+pub async fn record_payment_entries(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    if let Err(error) = post_payment_transactions(
+        state,
+        &payment_intent.merchant_id,
+        payment_intent,
+        payment_attempt,
+    )
+    .await
+    {
+        logger::error!(?error, payment_id = %payment_intent.payment_id, "Failed to record the ledger entries of the payment");
+    }
+}
+
+/// Records a successful refund
+#[instrument(skip_all)]
+pub async fn record_refund_entries(state: &SessionState, refund: &storage::Refund) {
+    if refund.refund_status != enums::RefundStatus::Success {
+        return;
+    }
+
+    let transaction = LedgerTransaction {
+        transaction_id: format!("refund:{}", refund.refund_id),
+        event_type: enums::LedgerEventType::Refund,
+        connector: refund.connector.clone(),
+        merchant_connector_id: refund.merchant_connector_id.clone(),
+        debit_account: enums::LedgerAccount::Refunds,
+        credit_account: enums::LedgerAccount::ConnectorReceivable,
+        amount: refund.refund_amount,
+        currency: refund.currency,
+        references: LedgerReferences {
+            payment_id: Some(refund.payment_id.clone()),
+            refund_id: Some(refund.refund_id.clone()),
+            ..Default::default()
+        },
+    };
+    if let Err(error) = post_transaction(state, &refund.merchant_id, transaction).await {
+        logger::error!(?error, refund_id = %refund.refund_id, "Failed to record the ledger entries of the refund");
+    }
+}
+
+/// Records a dispute lost by the merchant
+#[instrument(skip_all)]
+pub async fn record_dispute_entries(state: &SessionState, dispute: &storage::Dispute) {
+    if dispute.dispute_status != enums::DisputeStatus::DisputeLost {
+        return;
+    }
+    let Ok(currency) = enums::Currency::from_str(&dispute.currency) else {
+        logger::error!(dispute_id = %dispute.dispute_id, currency = %dispute.currency, "Unknown currency of the dispute, not recording its ledger entries");
+        return;
+    };
+
+    let transaction = LedgerTransaction {
+        transaction_id: format!("dispute:{}", dispute.dispute_id),
+        event_type: enums::LedgerEventType::Dispute,
+        connector: dispute.connector.clone(),
+        merchant_connector_id: dispute.merchant_connector_id.clone(),
+        debit_account: enums::LedgerAccount::Chargebacks,
+        credit_account: enums::LedgerAccount::ConnectorReceivable,
+        amount: MinorUnit::new(dispute.dispute_amount),
+        currency,
+        references: LedgerReferences {
+            payment_id: Some(dispute.payment_id.clone()),
+            dispute_id: Some(dispute.dispute_id.clone()),
+            ..Default::default()
+        },
+    };
+    if let Err(error) = post_transaction(state, &dispute.merchant_id, transaction).await {
+        logger::error!(?error, dispute_id = %dispute.dispute_id, "Failed to record the ledger entries of the dispute");
+    }
+}
+
+/// Records the fees of a settlement line matched to the resource it settles
+#[instrument(skip_all)]
+pub async fn record_fee_entries(state: &SessionState, settlement_line: &storage::SettlementLine) {
+    if settlement_line.recon_status != enums::SettlementReconStatus::Matched {
+        return;
+    }
+
+    let transaction = LedgerTransaction {
+        transaction_id: format!("fee:{}", settlement_line.line_id),
+        event_type: enums::LedgerEventType::Fee,
+        connector: settlement_line.connector.clone(),
+        merchant_connector_id: settlement_line.merchant_connector_id.clone(),
+        debit_account: enums::LedgerAccount::ProcessingFees,
+        credit_account: enums::LedgerAccount::ConnectorReceivable,
+        amount: settlement_line.fee_amount,
+        currency: settlement_line.currency,
+        references: LedgerReferences {
+            payment_id: settlement_line.payment_id.clone(),
+            refund_id: settlement_line.refund_id.clone(),
+            dispute_id: settlement_line.dispute_id.clone(),
+            payout_id: None,
+        },
+    };
+    if let Err(error) = post_transaction(state, &settlement_line.merchant_id, transaction).await {
+        logger::error!(?error, line_id = %settlement_line.line_id, "Failed to record the ledger entries of the settlement fees");
+    }
+}
+
+/// Records a successful payout
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn record_payout_entries(
+    state: &SessionState,
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) {
+    if payout_attempt.status != enums::PayoutStatus::Success {
+        return;
+    }
+    let Some(connector) = payout_attempt.connector.clone() else {
+        return;
+    };
+
+    let transaction = LedgerTransaction {
+        transaction_id: format!("payout:{}", payouts.payout_id),
+        event_type: enums::LedgerEventType::Payout,
+        connector,
+        merchant_connector_id: payout_attempt.merchant_connector_id.clone(),
+        debit_account: enums::LedgerAccount::Payouts,
+        credit_account: enums::LedgerAccount::ConnectorReceivable,
+        amount: MinorUnit::new(payouts.amount),
+        currency: payouts.destination_currency,
+        references: LedgerReferences {
+            payout_id: Some(payouts.payout_id.clone()),
+            ..Default::default()
+        },
+    };
+    if let Err(error) = post_transaction(state, &payouts.merchant_id, transaction).await {
+        logger::error!(?error, payout_id = %payouts.payout_id, "Failed to record the ledger entries of the payout");
+    }
+}
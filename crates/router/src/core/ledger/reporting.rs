@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use api_models::ledger::{
+    LedgerAccountBalance, LedgerBalancesResponse, LedgerEntriesQuery, LedgerEntriesResponse,
+    LedgerEntryResponse, LedgerFilters,
+};
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        reports,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Number of entries listed when the limit is not provided
+const DEFAULT_ENTRIES_LIMIT: i64 = 100;
+
+/// Maximum number of entries included in the balances and in an export
+const MAX_EXPORTED_ENTRIES: i64 = 100_000;
+
+const LEDGER_EXPORT_COLUMNS: &[&str] = &[
+    "entry_id",
+    "transaction_id",
+    "created_at",
+    "connector",
+    "merchant_connector_id",
+    "account",
+    "event_type",
+    "debit_amount",
+    "credit_amount",
+    "currency",
+    "payment_id",
+    "refund_id",
+    "dispute_id",
+    "payout_id",
+];
+
+fn get_ledger_entry_response(entry: storage::LedgerEntry) -> LedgerEntryResponse {
+    LedgerEntryResponse {
+        entry_id: entry.entry_id,
+        transaction_id: entry.transaction_id,
+        connector: entry.connector,
+        merchant_connector_id: entry.merchant_connector_id,
+        account: entry.account,
+        event_type: entry.event_type,
+        amount: entry.amount,
+        currency: entry.currency,
+        payment_id: entry.payment_id,
+        refund_id: entry.refund_id,
+        dispute_id: entry.dispute_id,
+        payout_id: entry.payout_id,
+        created_at: entry.created_at,
+    }
+}
+
+async fn fetch_filtered_entries(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    filters: LedgerFilters,
+) -> RouterResult<Vec<storage::LedgerEntry>> {
+    let entries = state
+        .store
+        .filter_ledger_entries_by_merchant_id_constraints(
+            &merchant_account.merchant_id,
+            filters.merchant_connector_id,
+            filters.created_after,
+            filters.created_before,
+            Some(MAX_EXPORTED_ENTRIES + 1),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the ledger entries")?;
+    if i64::try_from(entries.len()).unwrap_or(i64::MAX) > MAX_EXPORTED_ENTRIES {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "more than {MAX_EXPORTED_ENTRIES} ledger entries match the filters, narrow down the time range"
+            ),
+        })?
+    }
+    Ok(entries)
+}
+
+/// Computes the balances of the accounts of the ledger of the merchant, per merchant connector
+/// account and currency
+#[instrument(skip_all)]
+pub async fn retrieve_ledger_balances(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    filters: LedgerFilters,
+) -> RouterResponse<LedgerBalancesResponse> {
+    let entries = fetch_filtered_entries(&state, &merchant_account, filters).await?;
+
+    let mut totals: HashMap<_, (i64, i64)> = HashMap::new();
+    let mut currency_totals: HashMap<enums::Currency, i64> = HashMap::new();
+    for entry in entries {
+        let amount = entry.amount.get_amount_as_i64();
+        *currency_totals.entry(entry.currency).or_default() += amount;
+        let (debit_amount, credit_amount) = totals
+            .entry((
+                entry.connector,
+                entry.merchant_connector_id,
+                entry.account,
+                entry.currency,
+            ))
+            .or_default();
+        if amount >= 0 {
+            *debit_amount += amount;
+        } else {
+            *credit_amount -= amount;
+        }
+    }
+
+    let mut balances: Vec<_> = totals
+        .into_iter()
+        .map(
+            |(
+                (connector, merchant_connector_id, account, currency),
+                (debit_amount, credit_amount),
+            )| {
+                LedgerAccountBalance {
+                    connector,
+                    merchant_connector_id,
+                    account,
+                    currency,
+                    debit_amount: MinorUnit::new(debit_amount),
+                    credit_amount: MinorUnit::new(credit_amount),
+                    balance: MinorUnit::new(debit_amount - credit_amount),
+                }
+            },
+        )
+        .collect();
+    balances.sort_by_cached_key(|balance| {
+        (
+            balance.connector.clone(),
+            balance.merchant_connector_id.clone(),
+            balance.currency.to_string(),
+            balance.account.to_string(),
+        )
+    });
+
+    Ok(ApplicationResponse::Json(LedgerBalancesResponse {
+        balances,
+        is_balanced: currency_totals.values().all(|total| *total == 0),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_ledger_entries(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: LedgerEntriesQuery,
+) -> RouterResponse<LedgerEntriesResponse> {
+    let entries = state
+        .store
+        .filter_ledger_entries_by_merchant_id_constraints(
+            &merchant_account.merchant_id,
+            query.merchant_connector_id,
+            query.created_after,
+            query.created_before,
+            Some(query.limit.unwrap_or(DEFAULT_ENTRIES_LIMIT)),
+            query.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the ledger entries")?;
+
+    Ok(ApplicationResponse::Json(LedgerEntriesResponse {
+        count: entries.len(),
+        data: entries.into_iter().map(get_ledger_entry_response).collect(),
+    }))
+}
+
+/// Exports the ledger entries as a CSV file, with a debit and a credit column so that the file can
+/// be imported in accounting tools
+#[instrument(skip_all)]
+pub async fn export_ledger_entries(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    filters: LedgerFilters,
+) -> RouterResponse<serde_json::Value> {
+    let entries = fetch_filtered_entries(&state, &merchant_account, filters).await?;
+
+    let columns: Vec<String> = LEDGER_EXPORT_COLUMNS
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let rows = entries
+        .into_iter()
+        .map(|entry| {
+            let amount = entry.amount.get_amount_as_i64();
+            let (debit_amount, credit_amount) = if amount >= 0 {
+                (Some(amount.to_string()), None)
+            } else {
+                (None, Some((-amount).to_string()))
+            };
+            vec![
+                Some(entry.entry_id),
+                Some(entry.transaction_id),
+                Some(reports::format_timestamp(entry.created_at)),
+                Some(entry.connector),
+                entry.merchant_connector_id,
+                Some(entry.account.to_string()),
+                Some(entry.event_type.to_string()),
+                debit_amount,
+                credit_amount,
+                Some(entry.currency.to_string()),
+                entry.payment_id,
+                entry.refund_id,
+                entry.dispute_id,
+                entry.payout_id,
+            ]
+        })
+        .collect();
+
+    Ok(ApplicationResponse::FileData((
+        reports::build_csv(&columns, rows),
+        mime::TEXT_CSV,
+    )))
+}
@@ -0,0 +1,240 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use actix_web::http::Method;
+use error_stack::report;
+use once_cell::sync::Lazy;
+use router_env::{logger, metrics::add_attributes};
+use tokio::sync::Notify;
+
+use crate::{
+    configs::settings::LoadSheddingSettings,
+    core::errors::{self, RouterResult},
+    routes::metrics,
+};
+
+/// The priority of a request, the requests of the lower priorities being shed first when the
+/// instance is overloaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum RequestPriority {
+    /// The requests made while a customer is checking out, whose latency is protected
+    Checkout,
+    /// The requests syncing the status of the resources, such as the retrieves and the webhooks
+    Sync,
+    /// The requests reading or processing the resources in bulk, such as the lists and the reports
+    Batch,
+}
+
+/// The resources all of whose requests are batch requests
+const BATCH_RESOURCES: [&str; 14] = [
+    "analytics",
+    "apple_pay_certificates_migration",
+    "audit_events",
+    "card_exports",
+    "connector_migrations",
+    "events",
+    "force_sync",
+    "graphql",
+    "ledger",
+    "master_key",
+    "recon",
+    "reports",
+    "search",
+    "settlements",
+];
+
+/// The path segments of the batch requests to the other resources
+const BATCH_SEGMENTS: [&str; 5] = ["list", "filter", "aggregate", "migrate", "migrate_batch"];
+
+/// The resources whose requests are made during the checkout. The health checks are included so
+/// that a busy instance is not reported unhealthy.
+const CHECKOUT_RESOURCES: [&str; 7] = [
+    "payments",
+    "payment_methods",
+    "ephemeral_keys",
+    "poll",
+    "authentication",
+    "payment_link",
+    "health",
+];
+
+/// The priority of the request with the method and the path provided
+pub fn get_priority(method: &Method, path: &str) -> RequestPriority {
+    let mut segments = path.trim_start_matches('/').split('/');
+    let resource = segments.next().unwrap_or_default();
+    let is_read = matches!(*method, Method::GET | Method::HEAD);
+
+    if BATCH_RESOURCES.contains(&resource)
+        || segments
+            .clone()
+            .any(|segment| BATCH_SEGMENTS.contains(&segment))
+    {
+        RequestPriority::Batch
+    } else if resource == "payments" && is_read && !segments.any(|segment| segment == "redirect") {
+        // The retrieves of the payments, except for the redirections of the customers
+        RequestPriority::Sync
+    } else if CHECKOUT_RESOURCES.contains(&resource) {
+        RequestPriority::Checkout
+    } else {
+        RequestPriority::Sync
+    }
+}
+
+/// The requests in flight on the instance, and the requests of each priority waiting for
+/// admission
+struct AdmissionController {
+    in_flight: AtomicUsize,
+    waiting: [AtomicUsize; 3],
+    released: Notify,
+}
+
+static ADMISSION_CONTROLLER: Lazy<AdmissionController> = Lazy::new(|| AdmissionController {
+    in_flight: AtomicUsize::new(0),
+    waiting: [
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+    ],
+    released: Notify::new(),
+});
+
+impl AdmissionController {
+    fn waiting(&self, priority: RequestPriority) -> &AtomicUsize {
+        match priority {
+            RequestPriority::Checkout => &self.waiting[0],
+            RequestPriority::Sync => &self.waiting[1],
+            RequestPriority::Batch => &self.waiting[2],
+        }
+    }
+
+    /// Admits a request if fewer requests than the limit provided are in flight
+    fn try_admit(&'static self, limit: usize) -> Option<AdmissionPermit> {
+        self.in_flight
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |in_flight| {
+                (in_flight < limit).then_some(in_flight + 1)
+            })
+            .ok()
+            .map(|_| AdmissionPermit { controller: self })
+    }
+}
+
+/// A permit for a request to be processed, released when dropped
+pub struct AdmissionPermit {
+    controller: &'static AdmissionController,
+}
+
+impl Drop for AdmissionPermit {
+    fn drop(&mut self) {
+        self.controller.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.controller.released.notify_waiters();
+    }
+}
+
+/// Admits a request of the priority provided. A priority can only take up its share of the
+/// requests processed at the same time, so that the requests of the lower priorities are held
+/// back first when the instance is busy. The requests wait in the queue of their priority for
+/// room, and are shed when the queue is full or when they wait for longer than its timeout.
+/// Returns `None` when the load shedding is disabled.
+pub async fn admit_request(
+    config: &LoadSheddingSettings,
+    priority: RequestPriority,
+) -> RouterResult<Option<AdmissionPermit>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let controller = &*ADMISSION_CONTROLLER;
+    let quota = config.get_quota(priority);
+    let limit = config.max_in_flight_requests * usize::from(quota.max_in_flight_percentage) / 100;
+    let attributes = add_attributes([("priority", priority.to_string())]);
+
+    if let Some(permit) = controller.try_admit(limit) {
+        return Ok(Some(permit));
+    }
+
+    let waiting = controller.waiting(priority);
+    let queue_length = waiting.fetch_add(1, Ordering::SeqCst);
+    let permit = if queue_length < quota.max_queue_length {
+        let wait_start = Instant::now();
+        let deadline =
+            tokio::time::Instant::now() + Duration::from_millis(quota.queue_timeout_in_ms);
+        let permit = loop {
+            // Registered before checking for room so that no release is missed in between
+            let released = controller.released.notified();
+            if let Some(permit) = controller.try_admit(limit) {
+                break Some(permit);
+            }
+            if tokio::time::timeout_at(deadline, released).await.is_err() {
+                break controller.try_admit(limit);
+            }
+        };
+        metrics::REQUEST_ADMISSION_WAIT_TIME.record(
+            &metrics::CONTEXT,
+            wait_start.elapsed().as_secs_f64(),
+            &attributes,
+        );
+        permit
+    } else {
+        None
+    };
+    waiting.fetch_sub(1, Ordering::SeqCst);
+
+    permit.map(Some).ok_or_else(|| {
+        logger::warn!(
+            %priority,
+            limit,
+            "Instance is overloaded, shedding the request"
+        );
+        metrics::REQUESTS_SHED_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+        report!(errors::ApiErrorResponse::ServerOverloaded {
+            retry_after_in_secs: Duration::from_millis(quota.queue_timeout_in_ms)
+                .as_secs()
+                .max(1),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::Method;
+
+    use super::{get_priority, RequestPriority};
+
+    #[test]
+    fn test_requests_are_prioritized() {
+        let cases = [
+            (Method::POST, "/payments", RequestPriority::Checkout),
+            (
+                Method::POST,
+                "/payments/pay_1/confirm",
+                RequestPriority::Checkout,
+            ),
+            (
+                Method::GET,
+                "/payments/redirect/pay_1/merchant_1/attempt_1",
+                RequestPriority::Checkout,
+            ),
+            (Method::GET, "/payment_methods", RequestPriority::Checkout),
+            (Method::GET, "/payments/pay_1", RequestPriority::Sync),
+            (
+                Method::POST,
+                "/webhooks/merchant_1/stripe",
+                RequestPriority::Sync,
+            ),
+            (Method::POST, "/refunds", RequestPriority::Sync),
+            (Method::POST, "/payments/list", RequestPriority::Batch),
+            (Method::GET, "/refunds/filter", RequestPriority::Batch),
+            (
+                Method::POST,
+                "/analytics/v1/metrics/payments",
+                RequestPriority::Batch,
+            ),
+        ];
+        for (method, path, priority) in cases {
+            assert_eq!(get_priority(&method, path), priority, "{method} {path}");
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::{collections::HashSet, sync::RwLock};
+
+use api_models::logging::{
+    LogFilterResponse, LogLevelsUpdateRequest, LogOutput, LoggingResponse, RequestSamplingResponse,
+    RequestSamplingUpdateRequest,
+};
+use error_stack::report;
+use once_cell::sync::Lazy;
+use rand::Rng;
+use router_env::{instrument, logger, reload, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::ApplicationResponse,
+};
+
+/// Time the requests are sampled for, when not provided in the request
+const DEFAULT_SAMPLING_DURATION_IN_SECS: u32 = 3600;
+
+const MAX_SAMPLING_DURATION_IN_SECS: u32 = 86400;
+
+/// The requests whose bodies are logged under the sampled requests target, whatever the log
+/// levels
+#[derive(Debug, Clone)]
+struct RequestSampling {
+    /// The share of all the requests sampled, as a percentage
+    percentage: f64,
+    /// The merchants all of whose requests are sampled
+    merchant_ids: HashSet<String>,
+    /// The sampling stops at this time, so that it is not left enabled after an investigation
+    expires_at: PrimitiveDateTime,
+}
+
+static REQUEST_SAMPLING: Lazy<RwLock<Option<RequestSampling>>> = Lazy::new(|| RwLock::new(None));
+
+fn get_request_sampling() -> Option<RequestSampling> {
+    REQUEST_SAMPLING
+        .read()
+        .ok()
+        .and_then(|sampling| sampling.clone())
+        .filter(|sampling| sampling.expires_at > common_utils::date_time::now())
+}
+
+/// Whether the bodies of the request of the merchant are to be logged
+pub fn should_sample_request(merchant_id: Option<&str>) -> bool {
+    let Some(sampling) = get_request_sampling() else {
+        return false;
+    };
+
+    merchant_id.is_some_and(|merchant_id| sampling.merchant_ids.contains(merchant_id))
+        || (sampling.percentage > 0.0
+            && rand::thread_rng().gen_range(0.0..100.0) < sampling.percentage)
+}
+
+fn get_logging_response() -> LoggingResponse {
+    LoggingResponse {
+        levels: reload::get_runtime_log_levels(),
+        filters: reload::get_log_filters()
+            .into_iter()
+            .map(|filter| LogFilterResponse {
+                output: match filter.output {
+                    reload::LogOutput::Console => LogOutput::Console,
+                    reload::LogOutput::File => LogOutput::File,
+                },
+                initial_directive: filter.initial_directive,
+                directive: filter.directive,
+            })
+            .collect(),
+        sampling: get_request_sampling().map(|sampling| RequestSamplingResponse {
+            percentage: sampling.percentage,
+            merchant_ids: sampling.merchant_ids.into_iter().collect(),
+            expires_at: sampling.expires_at,
+        }),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_logging(_state: SessionState) -> RouterResponse<LoggingResponse> {
+    Ok(ApplicationResponse::Json(get_logging_response()))
+}
+
+/// Sets the log levels of the instance serving the request
+#[instrument(skip_all)]
+pub async fn update_log_levels(
+    _state: SessionState,
+    req: LogLevelsUpdateRequest,
+) -> RouterResponse<LoggingResponse> {
+    let levels = req.levels;
+    reload::set_runtime_log_levels(levels.clone()).map_err(|error| match error {
+        reload::LogLevelError::InvalidLevel { .. } => {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: error.to_string(),
+            })
+        }
+        reload::LogLevelError::NotReloadable | reload::LogLevelError::ReloadFailed(_) => {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(error)
+        }
+    })?;
+    logger::warn!(?levels, "Log levels changed at runtime");
+
+    Ok(ApplicationResponse::Json(get_logging_response()))
+}
+
+/// Sets the requests sampled by the instance serving the request, the sampling being disabled
+/// when no requests are sampled
+#[instrument(skip_all)]
+pub async fn update_request_sampling(
+    _state: SessionState,
+    req: RequestSamplingUpdateRequest,
+) -> RouterResponse<LoggingResponse> {
+    if !(0.0..=100.0).contains(&req.percentage) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "percentage must be between 0 and 100".to_string(),
+        }));
+    }
+    let duration_in_secs = req
+        .duration_in_secs
+        .unwrap_or(DEFAULT_SAMPLING_DURATION_IN_SECS);
+    if duration_in_secs == 0 || duration_in_secs > MAX_SAMPLING_DURATION_IN_SECS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "duration_in_secs must be between 1 and {MAX_SAMPLING_DURATION_IN_SECS}"
+            ),
+        }));
+    }
+
+    let sampling =
+        (req.percentage > 0.0 || !req.merchant_ids.is_empty()).then(|| RequestSampling {
+            percentage: req.percentage,
+            merchant_ids: req.merchant_ids.into_iter().collect(),
+            expires_at: common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(i64::from(duration_in_secs))),
+        });
+    logger::warn!(?sampling, "Request sampling changed at runtime");
+    *REQUEST_SAMPLING.write().map_err(|_| {
+        report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Request sampling lock poisoned")
+    })? = sampling;
+
+    Ok(ApplicationResponse::Json(get_logging_response()))
+}
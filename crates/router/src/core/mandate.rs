@@ -5,10 +5,12 @@ use common_utils::{ext_traits::Encode, id_type};
 use diesel_models::{enums as storage_enums, Mandate};
 use error_stack::{report, ResultExt};
 use futures::future;
+use masking::ExposeInterface;
 use router_env::{instrument, logger, metrics::add_attributes, tracing};
 
-use super::payments::helpers as payment_helper;
+use super::payments::{helpers as payment_helper, tokenization};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResponse, StorageErrorExt},
         payments::CallConnectorAction,
@@ -195,10 +197,18 @@ pub async fn update_connector_mandate_id(
             .await
             .change_context(errors::ApiErrorResponse::MandateNotFound)?;
 
+        let new_connector_mandate_id =
+            mandate_details.and_then(|mandate_reference| mandate_reference.connector_mandate_id);
+        // Wallet billing agreements can be renewed by the connector, in which case the
+        // connector returns a new token for the same mandate
+        let is_connector_mandate_id_renewed = mandate
+            .connector_mandate_id
+            .as_ref()
+            .zip(new_connector_mandate_id.as_ref())
+            .is_some_and(|(existing, new)| existing != new);
         let update_mandate_details = match payment_method_id {
             Some(pmd_id) => storage::MandateUpdate::ConnectorMandateIdUpdate {
-                connector_mandate_id: mandate_details
-                    .and_then(|mandate_reference| mandate_reference.connector_mandate_id),
+                connector_mandate_id: new_connector_mandate_id,
                 connector_mandate_ids: Some(connector_id),
                 payment_method_id: pmd_id,
                 original_payment_id: None,
@@ -208,8 +218,8 @@ pub async fn update_connector_mandate_id(
             },
         };
 
-        // only update the connector_mandate_id if existing is none
-        if mandate.connector_mandate_id.is_none() {
+        // only update the connector_mandate_id if existing is none or it has been renewed
+        if mandate.connector_mandate_id.is_none() || is_connector_mandate_id_renewed {
             db.update_mandate_by_merchant_id_mandate_id(
                 &merchant_account,
                 &mandate_id,
@@ -262,6 +272,335 @@ pub async fn get_customer_mandates(
     }
 }
 
+#[instrument(skip(state))]
+pub async fn migrate_mandates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: mandates::MandateMigrationRequest,
+) -> RouterResponse<mandates::MandateMigrationResponse> {
+    let mut response_vec = Vec::with_capacity(req.mandates.len());
+    for record in req.mandates {
+        let payment_method_id = record.payment_method_id.clone();
+        let record_response =
+            match migrate_mandate_record(&state, &merchant_account, &key_store, record).await {
+                Ok(mandate) => mandates::MandateMigrationRecordResponse {
+                    payment_method_id,
+                    mandate_id: Some(mandate.mandate_id),
+                    status: Some(mandate.mandate_status),
+                    error_message: None,
+                },
+                Err(error) => {
+                    logger::error!(mandate_migration_error=?error);
+                    mandates::MandateMigrationRecordResponse {
+                        payment_method_id,
+                        mandate_id: None,
+                        status: None,
+                        error_message: Some(error.current_context().error_message()),
+                    }
+                }
+            };
+        response_vec.push(record_response);
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        mandates::MandateMigrationResponse {
+            mandates: response_vec,
+        },
+    ))
+}
+
+async fn migrate_mandate_record(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    record: mandates::MandateMigrationRecord,
+) -> errors::RouterResult<Mandate> {
+    let db = state.store.as_ref();
+
+    if record.connector_mandate_id.is_none() && record.network_transaction_id.is_none() {
+        Err(errors::ApiErrorResponse::MissingRequiredFields {
+            field_names: vec!["connector_mandate_id", "network_transaction_id"],
+        })?
+    }
+
+    let payment_method = db
+        .find_payment_method(&record.payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.merchant_id != merchant_account.merchant_id
+        || payment_method.customer_id != record.customer_id
+    {
+        Err(errors::ApiErrorResponse::PaymentMethodNotFound)
+            .attach_printable("Payment method does not belong to the customer")?
+    }
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_account.merchant_id,
+            &record.merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: record.merchant_connector_id.clone(),
+        })?;
+
+    let connector_mandate_id = record
+        .connector_mandate_id
+        .map(|connector_mandate_id| connector_mandate_id.expose());
+    let network_transaction_id = record
+        .network_transaction_id
+        .map(|network_transaction_id| network_transaction_id.expose());
+
+    let (mandate_amount, mandate_currency, start_date, end_date) =
+        match record.mandate_type.clone().unwrap_or_default() {
+            payments::MandateType::SingleUse(data)
+            | payments::MandateType::MultiUse(Some(data)) => (
+                Some(data.amount.get_amount_as_i64()),
+                Some(data.currency),
+                data.start_date,
+                data.end_date,
+            ),
+            payments::MandateType::MultiUse(None) => (None, None, None, None),
+        };
+    let mandate_type = match record.mandate_type {
+        Some(payments::MandateType::SingleUse(_)) => storage_enums::MandateType::SingleUse,
+        Some(payments::MandateType::MultiUse(_)) | None => storage_enums::MandateType::MultiUse,
+    };
+
+    // Connector mandate details are updated on the payment method as well, so that the imported
+    // mandate can be used for token based MITs without a new customer interaction
+    if connector_mandate_id.is_some() {
+        let connector_mandate_details =
+            tokenization::update_connector_mandate_details_in_payment_method(
+                payment_method.clone(),
+                payment_method.payment_method_type,
+                mandate_amount,
+                mandate_currency,
+                Some(record.merchant_connector_id.clone()),
+                connector_mandate_id.clone(),
+            )?;
+        db.update_payment_method(
+            payment_method.clone(),
+            storage::PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
+                connector_mandate_details,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update connector mandate details in payment method")?;
+    }
+
+    if payment_method.network_transaction_id.is_none() && network_transaction_id.is_some() {
+        db.update_payment_method(
+            payment_method.clone(),
+            storage::PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
+                network_transaction_id: network_transaction_id.clone(),
+                status: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network transaction id in payment method")?;
+    }
+
+    let connector_mandate_ids = connector_mandate_id
+        .clone()
+        .map(|connector_mandate_id| {
+            payments::ConnectorMandateReferenceId {
+                connector_mandate_id: Some(connector_mandate_id),
+                payment_method_id: Some(payment_method.payment_method_id.clone()),
+                update_history: None,
+            }
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .map(masking::Secret::new)
+        })
+        .transpose()?;
+
+    let customer_acceptance = record
+        .customer_acceptance
+        .map(hyperswitch_domain_models::mandates::CustomerAcceptance::from);
+
+    let mut new_mandate = storage::MandateNew::default();
+    new_mandate
+        .set_mandate_id(common_utils::generate_id(consts::ID_LENGTH, "man"))
+        .set_customer_id(record.customer_id)
+        .set_merchant_id(merchant_account.merchant_id.clone())
+        .set_payment_method_id(payment_method.payment_method_id.clone())
+        .set_connector(merchant_connector_account.connector_name.clone())
+        .set_merchant_connector_id(Some(record.merchant_connector_id))
+        .set_mandate_status(storage_enums::MandateStatus::Active)
+        .set_mandate_type(mandate_type)
+        .set_mandate_amount(mandate_amount)
+        .set_mandate_currency(mandate_currency)
+        .set_start_date(start_date)
+        .set_end_date(end_date)
+        .set_connector_mandate_id(connector_mandate_id)
+        .set_connector_mandate_ids(connector_mandate_ids)
+        .set_network_transaction_id(network_transaction_id)
+        .set_customer_ip_address(
+            customer_acceptance
+                .as_ref()
+                .and_then(|acceptance| acceptance.get_ip_address())
+                .map(masking::Secret::new),
+        )
+        .set_customer_user_agent(
+            customer_acceptance
+                .as_ref()
+                .and_then(|acceptance| acceptance.get_user_agent()),
+        )
+        .set_customer_accepted_at(
+            customer_acceptance
+                .as_ref()
+                .map(|acceptance| acceptance.get_accepted_at()),
+        );
+
+    db.insert_mandate(new_mandate, merchant_account.storage_scheme)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate)
+}
+
+#[instrument(skip(state))]
+pub async fn schedule_mandate_pre_notification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    mandate_id: String,
+    req: mandates::MandatePreNotificationRequest,
+) -> RouterResponse<mandates::MandatePreNotificationResponse> {
+    let db = state.store.as_ref();
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    if mandate.mandate_status != storage_enums::MandateStatus::Active {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "mandate is not active".into()
+        }))?
+    }
+
+    payment_helper::verify_mandate_details(req.amount, req.currency, mandate.clone())?;
+
+    let payment_method = db
+        .find_payment_method(&mandate.payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let notification_rules = payment_method
+        .payment_method_type
+        .and_then(|payment_method_type| {
+            state
+                .conf
+                .mandates
+                .pre_notification
+                .0
+                .get(&payment_method_type)
+        })
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "pre-notification is not required for the payment method of the mandate"
+                .into(),
+        })?;
+
+    let notification_scheduled_at = req
+        .debit_date
+        .saturating_sub(time::Duration::days(i64::from(
+            notification_rules.notification_days,
+        )));
+    if notification_scheduled_at < common_utils::date_time::now() {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "debit_date must be at least {} days from now",
+                notification_rules.notification_days
+            ),
+        }))?
+    }
+
+    let tracking_data = storage::MandatePreNotificationTrackingData {
+        mandate_id: mandate.mandate_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        amount: req.amount.get_amount_as_i64(),
+        currency: req.currency,
+        debit_date: req.debit_date,
+    };
+
+    // A mandate has a single pre-notification scheduled at any time, scheduling a new one
+    // replaces the previous pre-notification
+    let process_tracker_id =
+        helpers::generate_task_id_for_mandate_pre_notification_workflow(&mandate.mandate_id);
+    match db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandate pre-notification")?
+    {
+        Some(process) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(notification_scheduled_at),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(storage::business_status::PENDING)),
+                        status: Some(storage_enums::ProcessTrackerStatus::New),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the mandate pre-notification")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                helpers::MANDATE_PRE_NOTIFICATION_TASK,
+                storage::ProcessTrackerRunner::MandatePreNotificationWorkflow,
+                [helpers::MANDATE_PRE_NOTIFICATION_TAG],
+                tracking_data,
+                notification_scheduled_at,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to construct mandate pre-notification process tracker task",
+            )?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the mandate pre-notification")?;
+        }
+    }
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "MandatePreNotification")]),
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        mandates::MandatePreNotificationResponse {
+            mandate_id: mandate.mandate_id,
+            amount: req.amount,
+            currency: req.currency,
+            debit_date: req.debit_date,
+            notification_scheduled_at,
+        },
+    ))
+}
+
 fn get_insensitive_payment_method_data_if_exists<F, FData>(
     router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
 ) -> Option<domain::PaymentMethodData>
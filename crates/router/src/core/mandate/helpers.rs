@@ -1,16 +1,19 @@
 use api_models::payments as api_payments;
 use common_enums::enums;
-use common_utils::errors::CustomResult;
-use diesel_models::Mandate;
-use error_stack::ResultExt;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, types::MinorUnit};
+use diesel_models::{process_tracker::business_status, Mandate};
+use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::mandates::MandateData;
 
 use crate::{
     core::{errors, payments},
     routes::SessionState,
-    types::{api, domain},
+    types::{api, domain, storage},
 };
 
+pub const MANDATE_PRE_NOTIFICATION_TASK: &str = "MANDATE_PRE_NOTIFICATION";
+pub const MANDATE_PRE_NOTIFICATION_TAG: &str = "MANDATE";
+
 pub async fn get_profile_id_for_mandate(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -90,3 +93,73 @@ pub struct MandateGenericData {
     pub mandate_connector: Option<payments::MandateConnectorDetails>,
     pub payment_method_info: Option<diesel_models::PaymentMethod>,
 }
+
+pub fn generate_task_id_for_mandate_pre_notification_workflow(mandate_id: &str) -> String {
+    format!(
+        "{runner}_{task}_{mandate_id}",
+        runner = storage::ProcessTrackerRunner::MandatePreNotificationWorkflow,
+        task = MANDATE_PRE_NOTIFICATION_TASK
+    )
+}
+
+/// Block direct debits on a mandate for which the customer has not been notified as required by
+/// the scheme rules configured for the payment method type.
+pub async fn validate_mandate_pre_notification(
+    state: &SessionState,
+    mandate: &Mandate,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(notification_rules) = payment_method_type.and_then(|payment_method_type| {
+        state
+            .conf
+            .mandates
+            .pre_notification
+            .0
+            .get(&payment_method_type)
+    }) else {
+        return Ok(());
+    };
+
+    let process_tracker_id =
+        generate_task_id_for_mandate_pre_notification_workflow(&mandate.mandate_id);
+    let pre_notification = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandate pre-notification")?
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "customer has to be notified at least {} days before debiting the mandate",
+                notification_rules.notification_days
+            ),
+        }))?;
+
+    if pre_notification.business_status != business_status::COMPLETED_BY_PT {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "customer has not yet been notified of the upcoming debit".into()
+        }))?
+    }
+
+    let tracking_data: storage::MandatePreNotificationTrackingData = pre_notification
+        .tracking_data
+        .parse_value("MandatePreNotificationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    if tracking_data.amount != amount.get_amount_as_i64() || tracking_data.currency != currency {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "amount and currency must match the ones the customer was notified of".into()
+        }))?
+    }
+
+    if common_utils::date_time::now().date() < tracking_data.debit_date.date() {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "mandate cannot be debited before the date the customer was notified of"
+                .into()
+        }))?
+    }
+
+    Ok(())
+}
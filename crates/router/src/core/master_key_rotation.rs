@@ -0,0 +1,221 @@
+use api_models::master_key_rotation::{
+    MasterKeyRotationRequest, MasterKeyRotationResponse, MasterKeyRotationStatus,
+};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums;
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+pub const MASTER_KEY_ROTATION_TASK: &str = "MASTER_KEY_ROTATION";
+pub const MASTER_KEY_ROTATION_TAG: &str = "MASTER_KEY_ROTATION";
+
+/// Number of key stores re-encrypted at a time, when not provided in the request
+const DEFAULT_MASTER_KEY_ROTATION_BATCH_SIZE: u16 = 100;
+
+const MAX_MASTER_KEY_ROTATION_BATCH_SIZE: u16 = 1000;
+
+/// Schedules the re-encryption of the merchant key stores of the tenant with the master key of
+/// the tenant, once the master key has been rotated and the master key being rotated out is
+/// configured as the previous master key
+#[instrument(skip_all)]
+pub async fn schedule_master_key_rotation(
+    state: SessionState,
+    request: MasterKeyRotationRequest,
+) -> RouterResponse<MasterKeyRotationResponse> {
+    let db = &*state.store;
+    let batch_size = request
+        .batch_size
+        .unwrap_or(DEFAULT_MASTER_KEY_ROTATION_BATCH_SIZE);
+    if batch_size == 0 || batch_size > MAX_MASTER_KEY_ROTATION_BATCH_SIZE {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "batch_size must be between 1 and {MAX_MASTER_KEY_ROTATION_BATCH_SIZE}"
+            ),
+        }));
+    }
+    if db.get_previous_master_key().is_none() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The master key being rotated out must be configured as the previous master \
+                      key to re-encrypt the merchant key stores"
+                .to_string(),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let tracking_data = storage::MasterKeyRotationTrackingData {
+        batch_size,
+        summary: Default::default(),
+    };
+    let job_id = format!(
+        "{runner}_{task}_{timestamp}",
+        runner = storage::ProcessTrackerRunner::MasterKeyRotationWorkflow,
+        task = MASTER_KEY_ROTATION_TASK,
+        timestamp = now.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        job_id.clone(),
+        MASTER_KEY_ROTATION_TASK,
+        storage::ProcessTrackerRunner::MasterKeyRotationWorkflow,
+        [MASTER_KEY_ROTATION_TAG],
+        tracking_data.clone(),
+        now,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct master key rotation process tracker task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A master key rotation was scheduled within the last second".to_string(),
+        })?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "MasterKeyRotation")]),
+    );
+
+    Ok(ApplicationResponse::Json(MasterKeyRotationResponse {
+        job_id,
+        status: MasterKeyRotationStatus::Scheduled,
+        scheduled_at: now,
+        completed_at: None,
+        summary: tracking_data.summary,
+    }))
+}
+
+/// Retrieves the status of the master key rotation, along with its progress
+#[instrument(skip_all)]
+pub async fn retrieve_master_key_rotation(
+    state: SessionState,
+    job_id: String,
+) -> RouterResponse<MasterKeyRotationResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&job_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the master key rotation task")?
+        .filter(|process| {
+            process.runner.as_deref()
+                == Some(
+                    storage::ProcessTrackerRunner::MasterKeyRotationWorkflow
+                        .to_string()
+                        .as_str(),
+                )
+        })
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Master key rotation {job_id} does not exist"),
+            })
+        })?;
+
+    get_master_key_rotation_response(&process).map(ApplicationResponse::Json)
+}
+
+fn get_master_key_rotation_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<MasterKeyRotationResponse> {
+    let tracking_data: storage::MasterKeyRotationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("MasterKeyRotationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let status = match process.status {
+        enums::ProcessTrackerStatus::New | enums::ProcessTrackerStatus::Paused => {
+            MasterKeyRotationStatus::Scheduled
+        }
+        enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::ProcessStarted => MasterKeyRotationStatus::InProgress,
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            MasterKeyRotationStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => MasterKeyRotationStatus::Failed,
+    };
+
+    Ok(MasterKeyRotationResponse {
+        job_id: process.id.clone(),
+        status,
+        scheduled_at: process.created_at,
+        completed_at: (process.status == enums::ProcessTrackerStatus::Finish)
+            .then_some(process.updated_at),
+        summary: tracking_data.summary,
+    })
+}
+
+/// Re-encrypts the next batch of key stores with the master key, recording the progress in the
+/// tracking data. Returns whether every key store has been processed. Key stores that fail to be
+/// re-encrypted are skipped.
+#[instrument(skip_all)]
+pub async fn rotate_merchant_key_stores_batch(
+    state: &SessionState,
+    tracking_data: &mut storage::MasterKeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let limit = i64::from(tracking_data.batch_size);
+    let master_key = Secret::new(db.get_master_key().to_vec());
+
+    let merchant_ids = db
+        .list_merchant_key_store_ids(tracking_data.summary.last_merchant_id.clone(), limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant key stores")?;
+
+    for merchant_id in &merchant_ids {
+        match rotate_merchant_key_store(db, merchant_id, &master_key).await {
+            Ok(()) => tracking_data.summary.rotated_count += 1,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    %merchant_id,
+                    "Failed to re-encrypt the key store with the master key"
+                );
+                tracking_data.summary.failed_count += 1;
+            }
+        }
+    }
+
+    if let Some(merchant_id) = merchant_ids.last() {
+        tracking_data.summary.last_merchant_id = Some(merchant_id.clone());
+    }
+
+    Ok(i64::try_from(merchant_ids.len()).map_or(true, |count| count < limit))
+}
+
+/// Re-encrypts the key of the merchant with the master key. The key store is decrypted with the
+/// previous master key when it is not encrypted with the master key yet.
+async fn rotate_merchant_key_store(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    master_key: &Secret<Vec<u8>>,
+) -> RouterResult<()> {
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, master_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the merchant key store")?;
+
+    let key = domain::types::encrypt(key_store.key.into_inner(), master_key.peek())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the merchant key with the master key")?;
+
+    db.update_merchant_key_store(domain::MerchantKeyStore { key, ..key_store }, master_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the merchant key store")?;
+
+    Ok(())
+}
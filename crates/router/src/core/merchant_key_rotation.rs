@@ -0,0 +1,852 @@
+use api_models::merchant_key_rotation::{
+    MerchantKeyRotationJobId, MerchantKeyRotationRequest, MerchantKeyRotationResponse,
+    MerchantKeyRotationStage, MerchantKeyRotationStatus, MerchantKeyRotationSummary,
+};
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, Encryptable, GcmAes256},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+};
+use diesel_models::{encryption::Encryption, enums};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{domain, storage},
+};
+
+pub const MERCHANT_KEY_ROTATION_TASK: &str = "MERCHANT_KEY_ROTATION";
+pub const MERCHANT_KEY_ROTATION_TAG: &str = "MERCHANT_KEY_ROTATION";
+
+/// Number of records re-encrypted at a time, when not provided in the request
+const DEFAULT_MERCHANT_KEY_ROTATION_BATCH_SIZE: u16 = 100;
+
+const MAX_MERCHANT_KEY_ROTATION_BATCH_SIZE: u16 = 1000;
+
+/// Number of seconds the re-encryption is started after, for the requests in flight holding the
+/// key store of the merchant without the new key to be done with it
+const MERCHANT_KEY_ROTATION_DELAY_IN_SECONDS: i64 = 60;
+
+async fn get_merchant_key_store(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+}
+
+/// Schedules the rotation of the key the data of the merchant is encrypted with
+///
+/// A new key is generated and kept in the key store of the merchant ahead of the key being
+/// rotated out, the data of the merchant being encrypted with the new key while still being
+/// decrypted with either key. The job then re-encrypts every record of the merchant with the new
+/// key, the key rotated out being discarded once every record has been re-encrypted. When a
+/// previous rotation has not completed, the job is scheduled again without generating another key.
+#[instrument(skip_all)]
+pub async fn schedule_merchant_key_rotation(
+    state: SessionState,
+    merchant_id: String,
+    request: MerchantKeyRotationRequest,
+) -> RouterResponse<MerchantKeyRotationResponse> {
+    let db = &*state.store;
+    let batch_size = request
+        .batch_size
+        .unwrap_or(DEFAULT_MERCHANT_KEY_ROTATION_BATCH_SIZE);
+    if batch_size == 0 || batch_size > MAX_MERCHANT_KEY_ROTATION_BATCH_SIZE {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "batch_size must be between 1 and {MAX_MERCHANT_KEY_ROTATION_BATCH_SIZE}"
+            ),
+        }));
+    }
+
+    let key_store = get_merchant_key_store(db, &merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if key_store.key.get_inner().peek().len() == GcmAes256::KEY_LENGTH {
+        let new_key = services::generate_aes256_key()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate the new merchant key")?;
+        let master_key = Secret::new(db.get_master_key().to_vec());
+        let key = domain::types::encrypt(
+            Secret::new(
+                [
+                    new_key.as_slice(),
+                    key_store.key.get_inner().peek().as_slice(),
+                ]
+                .concat(),
+            ),
+            master_key.peek(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the merchant keys with the master key")?;
+
+        db.update_merchant_key_store(domain::MerchantKeyStore { key, ..key_store }, &master_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add the new key to the merchant key store")?;
+    }
+
+    let now = common_utils::date_time::now();
+    let tracking_data = storage::MerchantKeyRotationTrackingData {
+        merchant_id: merchant_id.clone(),
+        batch_size,
+        summary: MerchantKeyRotationSummary {
+            stage: Some(MerchantKeyRotationStage::MerchantAccount),
+            ..Default::default()
+        },
+    };
+    let job_id = format!(
+        "{runner}_{task}_{merchant_id}_{timestamp}",
+        runner = storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow,
+        task = MERCHANT_KEY_ROTATION_TASK,
+        timestamp = now.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        job_id.clone(),
+        MERCHANT_KEY_ROTATION_TASK,
+        storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow,
+        [MERCHANT_KEY_ROTATION_TAG],
+        tracking_data.clone(),
+        now.saturating_add(time::Duration::seconds(
+            MERCHANT_KEY_ROTATION_DELAY_IN_SECONDS,
+        )),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct merchant key rotation process tracker task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A key rotation of the merchant was scheduled within the last second"
+                .to_string(),
+        })?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "MerchantKeyRotation")]),
+    );
+
+    Ok(ApplicationResponse::Json(MerchantKeyRotationResponse {
+        job_id,
+        merchant_id,
+        status: MerchantKeyRotationStatus::Scheduled,
+        scheduled_at: now,
+        completed_at: None,
+        summary: tracking_data.summary,
+    }))
+}
+
+/// Retrieves the status of the key rotation of the merchant, along with its progress
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_key_rotation(
+    state: SessionState,
+    request: MerchantKeyRotationJobId,
+) -> RouterResponse<MerchantKeyRotationResponse> {
+    let MerchantKeyRotationJobId {
+        merchant_id,
+        job_id,
+    } = request;
+    let process = state
+        .store
+        .find_process_by_id(&job_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key rotation task")?
+        .filter(|process| {
+            process.runner.as_deref()
+                == Some(
+                    storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow
+                        .to_string()
+                        .as_str(),
+                )
+        })
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Merchant key rotation {job_id} does not exist"),
+            })
+        })?;
+
+    let response = get_merchant_key_rotation_response(&process)?;
+    if response.merchant_id != merchant_id {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Merchant key rotation {job_id} does not exist"),
+        }));
+    }
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+fn get_merchant_key_rotation_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<MerchantKeyRotationResponse> {
+    let tracking_data: storage::MerchantKeyRotationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("MerchantKeyRotationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let status = match process.status {
+        enums::ProcessTrackerStatus::New | enums::ProcessTrackerStatus::Paused => {
+            MerchantKeyRotationStatus::Scheduled
+        }
+        enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::ProcessStarted => MerchantKeyRotationStatus::InProgress,
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            MerchantKeyRotationStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => MerchantKeyRotationStatus::Failed,
+    };
+
+    Ok(MerchantKeyRotationResponse {
+        job_id: process.id.clone(),
+        merchant_id: tracking_data.merchant_id,
+        status,
+        scheduled_at: process.created_at,
+        completed_at: (process.status == enums::ProcessTrackerStatus::Finish)
+            .then_some(process.updated_at),
+        summary: tracking_data.summary,
+    })
+}
+
+/// The data re-encrypted after the data of the stage, `None` for the last stage
+fn get_next_stage(stage: MerchantKeyRotationStage) -> Option<MerchantKeyRotationStage> {
+    match stage {
+        MerchantKeyRotationStage::MerchantAccount => {
+            Some(MerchantKeyRotationStage::MerchantConnectorAccounts)
+        }
+        MerchantKeyRotationStage::MerchantConnectorAccounts => {
+            Some(MerchantKeyRotationStage::Customers)
+        }
+        MerchantKeyRotationStage::Customers => Some(MerchantKeyRotationStage::Addresses),
+        MerchantKeyRotationStage::Addresses => Some(MerchantKeyRotationStage::PaymentMethods),
+        MerchantKeyRotationStage::PaymentMethods => Some(MerchantKeyRotationStage::Events),
+        MerchantKeyRotationStage::Events => Some(MerchantKeyRotationStage::IncomingWebhookArchives),
+        MerchantKeyRotationStage::IncomingWebhookArchives => {
+            Some(MerchantKeyRotationStage::WalletDecryptionKeys)
+        }
+        MerchantKeyRotationStage::WalletDecryptionKeys => None,
+    }
+}
+
+/// The identifiers of the records of a batch, along with the outcome of their re-encryption
+type BatchResults = Vec<(String, RouterResult<()>)>;
+
+/// Re-encrypts the next batch of records of the merchant with the new key, recording the progress
+/// in the tracking data. Returns whether every record has been processed. Records that fail to be
+/// re-encrypted are skipped.
+#[instrument(skip_all)]
+pub async fn reencrypt_merchant_data_batch(
+    state: &SessionState,
+    tracking_data: &mut storage::MerchantKeyRotationTrackingData,
+) -> RouterResult<bool> {
+    let Some(stage) = tracking_data.summary.stage else {
+        return Ok(true);
+    };
+    let db = &*state.store;
+    let merchant_id = tracking_data.merchant_id.as_str();
+    let limit = i64::from(tracking_data.batch_size);
+    let starting_after = tracking_data.summary.last_processed_id.clone();
+
+    let key_store = get_merchant_key_store(db, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key store")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant account")?;
+
+    let results = match stage {
+        MerchantKeyRotationStage::MerchantAccount => {
+            reencrypt_merchant_account(db, merchant_account, starting_after, &key_store).await
+        }
+        MerchantKeyRotationStage::MerchantConnectorAccounts => {
+            reencrypt_merchant_connector_accounts(
+                db,
+                merchant_id,
+                starting_after,
+                limit,
+                &key_store,
+            )
+            .await?
+        }
+        MerchantKeyRotationStage::Customers => {
+            reencrypt_customers(db, &merchant_account, starting_after, limit, &key_store).await?
+        }
+        MerchantKeyRotationStage::Addresses => {
+            reencrypt_addresses(db, &merchant_account, starting_after, limit, &key_store).await?
+        }
+        MerchantKeyRotationStage::PaymentMethods => {
+            reencrypt_payment_methods(db, &merchant_account, starting_after, limit, &key_store)
+                .await?
+        }
+        MerchantKeyRotationStage::Events => {
+            reencrypt_events(db, merchant_id, starting_after, limit, &key_store).await?
+        }
+        MerchantKeyRotationStage::IncomingWebhookArchives => {
+            reencrypt_incoming_webhook_archives(db, merchant_id, starting_after, limit, &key_store)
+                .await?
+        }
+        MerchantKeyRotationStage::WalletDecryptionKeys => {
+            reencrypt_wallet_decryption_keys(db, merchant_id, starting_after, limit, &key_store)
+                .await?
+        }
+    };
+
+    for (id, result) in &results {
+        match result {
+            Ok(()) => tracking_data.summary.reencrypted_count += 1,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    %merchant_id,
+                    %stage,
+                    %id,
+                    "Failed to re-encrypt the record with the new merchant key"
+                );
+                tracking_data.summary.failed_count += 1;
+            }
+        }
+    }
+
+    if i64::try_from(results.len()).map_or(true, |count| count < limit) {
+        tracking_data.summary.stage = get_next_stage(stage);
+        tracking_data.summary.last_processed_id = None;
+    } else if let Some((id, _)) = results.last() {
+        tracking_data.summary.last_processed_id = Some(id.clone());
+    }
+
+    Ok(tracking_data.summary.stage.is_none())
+}
+
+/// Discards the key rotated out from the key store of the merchant, once every record of the
+/// merchant has been re-encrypted with the new key
+#[instrument(skip_all)]
+pub async fn complete_merchant_key_rotation(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_store = get_merchant_key_store(db, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key store")?;
+    if key_store.key.get_inner().peek().len() <= GcmAes256::KEY_LENGTH {
+        return Ok(());
+    }
+
+    let master_key = Secret::new(db.get_master_key().to_vec());
+    let key = domain::types::encrypt(
+        Secret::new(key_store.key.get_inner().peek()[..GcmAes256::KEY_LENGTH].to_vec()),
+        master_key.peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the merchant key with the master key")?;
+
+    db.update_merchant_key_store(domain::MerchantKeyStore { key, ..key_store }, &master_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to discard the key rotated out from the merchant key store")?;
+
+    Ok(())
+}
+
+/// Re-encrypts the value, decrypted with either key of the merchant, with the new key
+async fn reencrypt_optional<T: Clone, S>(
+    value: Option<Encryptable<Secret<T, S>>>,
+    key: &[u8],
+) -> CustomResult<Option<Encryptable<Secret<T, S>>>, errors::StorageError>
+where
+    Secret<T, S>: Send,
+    S: masking::Strategy<T>,
+    Encryptable<Secret<T, S>>: domain::types::TypeEncryption<T, GcmAes256, S>,
+{
+    domain::types::encrypt_optional(value.map(Encryptable::into_inner), key)
+        .await
+        .change_context(errors::StorageError::EncryptionError)
+}
+
+/// Re-encrypts data stored encrypted as is, which is decrypted with either key of the merchant
+fn reencrypt_stored_data(
+    data: Encryption,
+    key: &[u8],
+) -> CustomResult<Encryption, errors::StorageError> {
+    let message = GcmAes256
+        .decode_message(key, data.into_inner())
+        .change_context(errors::StorageError::DecryptionError)?;
+    GcmAes256
+        .encode_message(key, &message)
+        .map(|encrypted| Encryption::new(encrypted.into()))
+        .change_context(errors::StorageError::EncryptionError)
+}
+
+async fn reencrypt_merchant_account(
+    db: &dyn StorageInterface,
+    merchant_account: domain::MerchantAccount,
+    starting_after: Option<String>,
+    key_store: &domain::MerchantKeyStore,
+) -> BatchResults {
+    if starting_after.is_some() {
+        return Vec::new();
+    }
+
+    let key = key_store.key.get_inner().peek();
+    let merchant_id = merchant_account.merchant_id.clone();
+    let result = async {
+        let update = domain::MerchantAccountUpdate::EncryptedDataUpdate {
+            merchant_name: reencrypt_optional(merchant_account.merchant_name, key).await?,
+            merchant_details: reencrypt_optional(merchant_account.merchant_details, key).await?,
+        };
+        db.update_specific_fields_in_merchant(&merchant_id, update, key_store)
+            .await
+    }
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .map(|_| ());
+
+    vec![(merchant_id, result)]
+}
+
+async fn reencrypt_merchant_connector_accounts(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let mut merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            merchant_id,
+            true,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant connector accounts")?;
+    merchant_connector_accounts.retain(|merchant_connector_account| {
+        starting_after.as_deref() < Some(merchant_connector_account.merchant_connector_id.as_str())
+    });
+    merchant_connector_accounts
+        .sort_by(|a, b| a.merchant_connector_id.cmp(&b.merchant_connector_id));
+    merchant_connector_accounts.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+    let mut results = Vec::with_capacity(merchant_connector_accounts.len());
+    for merchant_connector_account in merchant_connector_accounts {
+        let merchant_connector_id = merchant_connector_account.merchant_connector_id.clone();
+        let result = async {
+            let update = domain::MerchantConnectorAccountUpdate::EncryptedDataUpdate {
+                connector_account_details: domain::types::encrypt(
+                    merchant_connector_account
+                        .connector_account_details
+                        .clone()
+                        .into_inner(),
+                    key,
+                )
+                .await
+                .change_context(errors::StorageError::EncryptionError)?,
+                connector_wallets_details: reencrypt_optional(
+                    merchant_connector_account.connector_wallets_details.clone(),
+                    key,
+                )
+                .await?,
+                next_connector_account_details: reencrypt_optional(
+                    merchant_connector_account
+                        .next_connector_account_details
+                        .clone(),
+                    key,
+                )
+                .await?,
+                previous_connector_account_details: reencrypt_optional(
+                    merchant_connector_account
+                        .previous_connector_account_details
+                        .clone(),
+                    key,
+                )
+                .await?,
+            };
+            db.update_merchant_connector_account(
+                merchant_connector_account,
+                update.into(),
+                key_store,
+            )
+            .await
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .map(|_| ());
+        results.push((merchant_connector_id, result));
+    }
+
+    Ok(results)
+}
+
+async fn reencrypt_customers(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let customers = db
+        .list_customers_by_merchant_id_starting_after(
+            &merchant_account.merchant_id,
+            starting_after,
+            limit,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customers")?;
+
+    let mut results = Vec::with_capacity(customers.len());
+    for listed_customer in customers {
+        let customer_id = listed_customer.customer_id.get_string_repr().to_owned();
+        let result = async {
+            // The customer is fetched again for its latest copy, which may be kept in redis
+            // alone, the customers redacted being re-encrypted from the copy in the database
+            let customer = match db
+                .find_customer_optional_by_customer_id_merchant_id(
+                    &listed_customer.customer_id,
+                    &merchant_account.merchant_id,
+                    key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(Some(customer)) => customer,
+                Ok(None) => return Ok(()),
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::StorageError::CustomerRedacted
+                    ) =>
+                {
+                    listed_customer
+                }
+                Err(error) => return Err(error),
+            };
+
+            let update = storage::CustomerUpdate::Update {
+                name: reencrypt_optional(customer.name.clone(), key).await?,
+                email: reencrypt_optional(customer.email.clone(), key).await?,
+                phone: Box::new(reencrypt_optional(customer.phone.clone(), key).await?),
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                address_id: None,
+                email_blind_index: None,
+                phone_blind_index: None,
+            };
+            db.update_customer_by_customer_id_merchant_id(
+                customer.customer_id.clone(),
+                merchant_account.merchant_id.clone(),
+                customer,
+                update,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map(|_| ())
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        results.push((customer_id, result));
+    }
+
+    Ok(results)
+}
+
+/// The update replacing the encrypted fields of the address with their re-encrypted values, the
+/// other fields being kept as is
+async fn get_address_update(
+    address: domain::Address,
+    key: &[u8],
+) -> CustomResult<domain::AddressUpdate, errors::StorageError> {
+    Ok(domain::AddressUpdate::Update {
+        city: address.city,
+        country: address.country,
+        line1: reencrypt_optional(address.line1, key).await?,
+        line2: reencrypt_optional(address.line2, key).await?,
+        line3: reencrypt_optional(address.line3, key).await?,
+        state: reencrypt_optional(address.state, key).await?,
+        zip: reencrypt_optional(address.zip, key).await?,
+        first_name: reencrypt_optional(address.first_name, key).await?,
+        last_name: reencrypt_optional(address.last_name, key).await?,
+        phone_number: reencrypt_optional(address.phone_number, key).await?,
+        country_code: address.country_code,
+        updated_by: address.updated_by,
+        email: reencrypt_optional(address.email, key).await?,
+    })
+}
+
+async fn reencrypt_addresses(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let addresses = db
+        .list_addresses_by_merchant_id_starting_after(
+            &merchant_account.merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the addresses")?;
+
+    let mut results = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        let address_id = address.address_id;
+        let payment_id = address.payment_id;
+        let result = async {
+            // The addresses of payments are fetched and updated through the payment, their latest
+            // copy being kept in redis when the merchant uses the KV store
+            match payment_id {
+                Some(payment_id) => {
+                    let payment_address = db
+                        .find_address_by_merchant_id_payment_id_address_id(
+                            &merchant_account.merchant_id,
+                            &payment_id,
+                            &address_id,
+                            key_store,
+                            merchant_account.storage_scheme,
+                        )
+                        .await?;
+                    let update = get_address_update(payment_address.address.clone(), key).await?;
+                    db.update_address_for_payments(
+                        payment_address,
+                        update,
+                        payment_id,
+                        key_store,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .map(|_| ())
+                }
+                None => {
+                    let customer_address = db
+                        .find_address_by_address_id(&address_id, key_store)
+                        .await?;
+                    let update = get_address_update(customer_address, key).await?;
+                    db.update_address(address_id.clone(), update, key_store)
+                        .await
+                        .map(|_| ())
+                }
+            }
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        results.push((address_id, result));
+    }
+
+    Ok(results)
+}
+
+async fn reencrypt_payment_methods(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let payment_methods = db
+        .list_payment_methods_by_merchant_id_starting_after(
+            &merchant_account.merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment methods")?;
+
+    let mut results = Vec::with_capacity(payment_methods.len());
+    for payment_method in payment_methods {
+        let payment_method_id = payment_method.payment_method_id.clone();
+        let result = async {
+            // The payment method is fetched again for its latest copy, which may be kept in
+            // redis alone
+            let payment_method = db
+                .find_payment_method(&payment_method_id, merchant_account.storage_scheme)
+                .await?;
+            let update = storage::PaymentMethodUpdate::EncryptedDataUpdate {
+                payment_method_data: payment_method
+                    .payment_method_data
+                    .clone()
+                    .map(|data| reencrypt_stored_data(data, key))
+                    .transpose()?,
+                payment_method_billing_address: payment_method
+                    .payment_method_billing_address
+                    .clone()
+                    .map(|data| reencrypt_stored_data(data, key))
+                    .transpose()?,
+            };
+            db.update_payment_method(payment_method, update, merchant_account.storage_scheme)
+                .await
+                .map(|_| ())
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        results.push((payment_method_id, result));
+    }
+
+    Ok(results)
+}
+
+async fn reencrypt_events(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let events = db
+        .list_events_by_merchant_id_starting_after(merchant_id, starting_after, limit, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the events")?;
+
+    let mut results = Vec::with_capacity(events.len());
+    for event in events {
+        let event_id = event.event_id.clone();
+        let result = async {
+            let update = domain::EventUpdate::EncryptedDataUpdate {
+                request: reencrypt_optional(event.request, key).await?,
+                response: reencrypt_optional(event.response, key).await?,
+            };
+            db.update_event_by_merchant_id_event_id(merchant_id, &event_id, update, key_store)
+                .await
+                .map(|_| ())
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        results.push((event_id, result));
+    }
+
+    Ok(results)
+}
+
+async fn reencrypt_incoming_webhook_archives(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let archives = db
+        .list_incoming_webhook_archives_by_merchant_id_starting_after(
+            merchant_id,
+            starting_after,
+            limit,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the incoming webhook archives")?;
+
+    let mut results = Vec::with_capacity(archives.len());
+    for archive in archives {
+        let archive_id = archive.archive_id.clone();
+        let result = async {
+            let update = domain::IncomingWebhookArchiveUpdate::EncryptedDataUpdate {
+                body: domain::types::encrypt(archive.body.into_inner(), key)
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            };
+            db.update_incoming_webhook_archive_by_merchant_id_archive_id(
+                merchant_id,
+                &archive_id,
+                update,
+                key_store,
+            )
+            .await
+            .map(|_| ())
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        results.push((archive_id, result));
+    }
+
+    Ok(results)
+}
+
+async fn reencrypt_wallet_decryption_keys(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    starting_after: Option<String>,
+    limit: i64,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<BatchResults> {
+    let key = key_store.key.get_inner().peek();
+    let wallet_decryption_keys = db
+        .list_wallet_decryption_keys_by_merchant_id_starting_after(
+            merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the wallet decryption keys")?;
+
+    let mut results = Vec::with_capacity(wallet_decryption_keys.len());
+    for wallet_decryption_key in wallet_decryption_keys {
+        let key_id = wallet_decryption_key.key_id.clone();
+        let result = async {
+            let update = storage::WalletDecryptionKeyUpdate::KeyDetailsUpdate {
+                key_details: reencrypt_stored_data(wallet_decryption_key.key_details, key)?,
+            };
+            db.update_wallet_decryption_key_by_profile_id_key_id(
+                &wallet_decryption_key.profile_id,
+                &key_id,
+                update,
+            )
+            .await
+            .map(|_| ())
+        }
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        results.push((key_id, result));
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_stage_is_followed_by_a_later_stage() {
+        let mut stages = vec![MerchantKeyRotationStage::MerchantAccount];
+        while let Some(stage) = stages.last().copied().and_then(get_next_stage) {
+            assert!(!stages.contains(&stage));
+            stages.push(stage);
+        }
+        assert_eq!(stages.len(), 8);
+        assert_eq!(
+            stages.last(),
+            Some(&MerchantKeyRotationStage::WalletDecryptionKeys)
+        );
+    }
+}
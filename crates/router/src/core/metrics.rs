@@ -1,5 +1,5 @@
 pub use router_env::opentelemetry::KeyValue;
-use router_env::{counter_metric, global_meter, metrics_context};
+use router_env::{counter_metric, gauge_metric, global_meter, histogram_metric, metrics_context};
 
 metrics_context!(CONTEXT);
 global_meter!(GLOBAL_METER, "ROUTER_API");
@@ -42,6 +42,12 @@ counter_metric!(
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+gauge_metric!(WEBHOOK_INCOMING_QUEUE_DEPTH, GLOBAL_METER); // No. of incoming webhooks waiting for room in their lane
+histogram_metric!(WEBHOOK_INCOMING_QUEUE_WAIT_TIME, GLOBAL_METER); // Time spent waiting for room in the lane
+counter_metric!(WEBHOOK_INCOMING_REJECTED_COUNT, GLOBAL_METER); // No. of incoming webhooks rejected as their lane was full
+counter_metric!(WEBHOOK_INCOMING_REPLAYED_COUNT, GLOBAL_METER); // No. of incoming webhooks acknowledged as they were already processed
+counter_metric!(WEBHOOK_INCOMING_IN_PROGRESS_COUNT, GLOBAL_METER); // No. of incoming webhooks rejected as they were being processed by another request
+counter_metric!(WEBHOOK_INCOMING_STATUS_REGRESSION_COUNT, GLOBAL_METER); // No. of incoming webhooks whose status regression was ignored
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
@@ -79,6 +85,7 @@ counter_metric!(
     GLOBAL_METER
 );
 counter_metric!(ROUTING_RETRIEVE_CONFIG_FOR_PROFILE, GLOBAL_METER);
+counter_metric!(ROUTING_ALGORITHM_MISSING, GLOBAL_METER);
 counter_metric!(
     ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
     GLOBAL_METER
@@ -0,0 +1,261 @@
+use api_models::orders::{
+    OrderCreateRequest, OrderPayment, OrderResponse, OrderStatus, OrderUpdateRequest,
+};
+use common_utils::types::MinorUnit;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Creates an order, which the payments made towards it are linked to on their creation
+#[instrument(skip_all)]
+pub async fn create_order(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: OrderCreateRequest,
+) -> RouterResponse<OrderResponse> {
+    let db = state.store.as_ref();
+    if let Some(profile_id) = &request.profile_id {
+        core_utils::validate_and_get_business_profile(
+            db,
+            Some(profile_id),
+            &merchant_account.merchant_id,
+        )
+        .await?;
+    }
+    if let Some(customer_id) = &request.customer_id {
+        db.find_customer_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    }
+    validate_order_amount(request.amount)?;
+
+    let now = common_utils::date_time::now();
+    let order = db
+        .insert_order(storage::OrderNew {
+            order_id: common_utils::generate_id(consts::ID_LENGTH, "ord"),
+            merchant_id: merchant_account.merchant_id,
+            profile_id: request.profile_id,
+            customer_id: request.customer_id,
+            amount: request.amount,
+            currency: request.currency,
+            description: request.description,
+            metadata: request.metadata,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Order already exists".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_order_response(order, vec![])))
+}
+
+/// Retrieves an order, with its status aggregated from the payments linked to it
+#[instrument(skip_all)]
+pub async fn retrieve_order(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    order_id: String,
+) -> RouterResponse<OrderResponse> {
+    let order = find_order(
+        state.store.as_ref(),
+        &merchant_account.merchant_id,
+        &order_id,
+    )
+    .await?;
+    let payment_intents =
+        list_order_payment_intents(&state, &merchant_account, &key_store, &order).await?;
+
+    Ok(ApplicationResponse::Json(get_order_response(
+        order,
+        payment_intents,
+    )))
+}
+
+/// Updates the amount, description or metadata of an order
+#[instrument(skip_all)]
+pub async fn update_order(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    order_id: String,
+    request: OrderUpdateRequest,
+) -> RouterResponse<OrderResponse> {
+    let db = state.store.as_ref();
+    let order = find_order(db, &merchant_account.merchant_id, &order_id).await?;
+    if let Some(amount) = request.amount {
+        validate_order_amount(amount)?;
+    }
+
+    let order = db
+        .update_order(
+            order,
+            storage::OrderUpdate::Update {
+                amount: request.amount,
+                description: request.description,
+                metadata: request.metadata,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Order not found".to_string(),
+        })?;
+    let payment_intents =
+        list_order_payment_intents(&state, &merchant_account, &key_store, &order).await?;
+
+    Ok(ApplicationResponse::Json(get_order_response(
+        order,
+        payment_intents,
+    )))
+}
+
+async fn find_order(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    order_id: &str,
+) -> RouterResult<storage::Order> {
+    db.find_order_by_merchant_id_order_id(merchant_id, order_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Order not found".to_string(),
+        })
+}
+
+fn validate_order_amount(amount: MinorUnit) -> RouterResult<()> {
+    if amount <= MinorUnit::new(0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+async fn list_order_payment_intents(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    order: &storage::Order,
+) -> RouterResult<Vec<storage::PaymentIntent>> {
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: None,
+        cursor: None,
+        card_network: None,
+        metadata: None,
+        order_id: Some(order.order_id.clone()),
+        custom_fields: None,
+    }));
+    state
+        .store
+        .filter_payment_intent_by_constraints(
+            &merchant_account.merchant_id,
+            &constraints,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments of the order")
+}
+
+fn get_order_response(
+    order: storage::Order,
+    payment_intents: Vec<storage::PaymentIntent>,
+) -> OrderResponse {
+    let (amount_paid, amount_authorized) = payment_intents.iter().fold(
+        (MinorUnit::new(0), MinorUnit::new(0)),
+        |(amount_paid, amount_authorized), payment_intent| match payment_intent.status {
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::PartiallyCapturedAndCapturable => (
+                amount_paid
+                    + payment_intent
+                        .amount_captured
+                        .unwrap_or(payment_intent.amount),
+                amount_authorized,
+            ),
+            enums::IntentStatus::RequiresCapture => {
+                (amount_paid, amount_authorized + payment_intent.amount)
+            }
+            _ => (amount_paid, amount_authorized),
+        },
+    );
+    let status = if amount_paid >= order.amount {
+        OrderStatus::Paid
+    } else if amount_paid > MinorUnit::new(0) {
+        OrderStatus::PartiallyPaid
+    } else {
+        OrderStatus::Unpaid
+    };
+    let amount_remaining = if amount_paid >= order.amount {
+        MinorUnit::new(0)
+    } else {
+        order.amount - amount_paid
+    };
+
+    OrderResponse {
+        order_id: order.order_id,
+        merchant_id: order.merchant_id,
+        profile_id: order.profile_id,
+        customer_id: order.customer_id,
+        amount: order.amount,
+        currency: order.currency,
+        status,
+        amount_paid,
+        amount_authorized,
+        amount_remaining,
+        description: order.description,
+        metadata: order.metadata,
+        payments: payment_intents
+            .into_iter()
+            .map(|payment_intent| OrderPayment {
+                payment_id: payment_intent.payment_id,
+                status: payment_intent.status,
+                amount: payment_intent.amount,
+                amount_captured: payment_intent.amount_captured,
+                created_at: payment_intent.created_at,
+            })
+            .collect(),
+        created_at: order.created_at,
+        modified_at: order.modified_at,
+    }
+}
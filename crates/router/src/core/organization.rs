@@ -0,0 +1,263 @@
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::{
+    admin as admin_types, enums as api_enums,
+    organization::{
+        ConnectorShareRequest, ConnectorShareResponse, ConnectorShareResult,
+        MerchantPaymentsAggregate, OrganizationPaymentsAggregateResponse, PaymentsAggregateBucket,
+    },
+    payments::TimeRange,
+};
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage::enums},
+};
+
+async fn list_organization_merchant_accounts(
+    state: &SessionState,
+    org_id: &str,
+) -> RouterResult<Vec<domain::MerchantAccount>> {
+    state
+        .store
+        .find_organization_by_org_id(org_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "organization with the given id does not exist".to_string(),
+        })?;
+
+    state
+        .store
+        .list_merchant_accounts_by_organization_id(org_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant accounts of the organization")
+}
+
+/// Checks that the merchant belongs to the organization, so that the users of an organization can
+/// never act on the merchants of another one
+async fn validate_merchant_in_organization(
+    state: &SessionState,
+    org_id: &str,
+    merchant_id: &str,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if merchant_account.organization_id != org_id {
+        Err(errors::ApiErrorResponse::MerchantAccountNotFound)
+            .attach_printable("Merchant account belongs to another organization")?
+    }
+    Ok(())
+}
+
+pub async fn list_organization_merchants(
+    state: SessionState,
+    org_id: String,
+) -> RouterResponse<Vec<api::MerchantAccountResponse>> {
+    let merchant_accounts = list_organization_merchant_accounts(&state, &org_id).await?;
+
+    let merchant_accounts = merchant_accounts
+        .into_iter()
+        .map(|merchant_account| {
+            api::MerchantAccountResponse::try_from(merchant_account).change_context(
+                errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "merchant_account",
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ApplicationResponse::Json(merchant_accounts))
+}
+
+pub async fn update_organization_merchant(
+    state: SessionState,
+    org_id: String,
+    merchant_id: String,
+    req: api::MerchantAccountUpdate,
+) -> RouterResponse<api::MerchantAccountResponse> {
+    validate_merchant_in_organization(&state, &org_id, &merchant_id).await?;
+
+    admin::merchant_account_update(state, &merchant_id, req).await
+}
+
+type PaymentsAggregateBuckets =
+    HashMap<(enums::IntentStatus, Option<enums::Currency>), (usize, i64)>;
+
+fn get_payments_aggregate_buckets(
+    buckets: PaymentsAggregateBuckets,
+) -> Vec<PaymentsAggregateBucket> {
+    buckets
+        .into_iter()
+        .map(
+            |((status, currency), (count, total_amount))| PaymentsAggregateBucket {
+                status,
+                currency,
+                count,
+                total_amount: MinorUnit::new(total_amount),
+            },
+        )
+        .collect()
+}
+
+/// Aggregates the payments created within the time range by all the merchants of the
+/// organization, by status and currency
+#[instrument(skip_all)]
+pub async fn aggregate_organization_payments(
+    state: SessionState,
+    org_id: String,
+    time_range: TimeRange,
+) -> RouterResponse<OrganizationPaymentsAggregateResponse> {
+    let db = state.store.as_ref();
+    let merchant_accounts = list_organization_merchant_accounts(&state, &org_id).await?;
+
+    let mut organization_buckets = PaymentsAggregateBuckets::new();
+    let mut merchants = Vec::with_capacity(merchant_accounts.len());
+    for merchant_account in merchant_accounts {
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &merchant_account.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        let payment_intents = db
+            .filter_payment_intents_by_time_range_constraints(
+                &merchant_account.merchant_id,
+                &time_range,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payments of the merchant")?;
+
+        let mut merchant_buckets = PaymentsAggregateBuckets::new();
+        for payment_intent in &payment_intents {
+            let key = (payment_intent.status, payment_intent.currency);
+            for buckets in [&mut merchant_buckets, &mut organization_buckets] {
+                let (count, total_amount) = buckets.entry(key).or_insert((0, 0));
+                *count += 1;
+                *total_amount += payment_intent.amount.get_amount_as_i64();
+            }
+        }
+
+        merchants.push(MerchantPaymentsAggregate {
+            merchant_id: merchant_account.merchant_id,
+            count: payment_intents.len(),
+            data: get_payments_aggregate_buckets(merchant_buckets),
+        });
+    }
+
+    Ok(ApplicationResponse::Json(
+        OrganizationPaymentsAggregateResponse {
+            org_id,
+            count: merchants.iter().map(|merchant| merchant.count).sum(),
+            data: get_payments_aggregate_buckets(organization_buckets),
+            merchants,
+        },
+    ))
+}
+
+pub(crate) fn get_shared_connector_create_request(
+    source: &admin_types::MerchantConnectorResponse,
+    profile_id: Option<String>,
+) -> RouterResult<api::MerchantConnectorCreate> {
+    Ok(api::MerchantConnectorCreate {
+        connector_type: source.connector_type,
+        connector_name: api_enums::Connector::from_str(&source.connector_name)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the connector name of the shared connector")?,
+        connector_label: None,
+        profile_id,
+        connector_account_details: Some(source.connector_account_details.clone()),
+        payment_methods_enabled: source.payment_methods_enabled.clone(),
+        connector_webhook_details: source.connector_webhook_details.clone(),
+        metadata: source.metadata.clone(),
+        test_mode: source.test_mode,
+        disabled: source.disabled,
+        frm_configs: source.frm_configs.clone(),
+        business_country: None,
+        business_label: None,
+        business_sub_label: None,
+        merchant_connector_id: None,
+        pm_auth_config: source.pm_auth_config.clone(),
+        status: Some(source.status),
+    })
+}
+
+/// Creates a copy of the connector of a merchant of the organization for each of the target
+/// merchants, along with its credentials. The connector is shared with every target it can be
+/// shared with, the failures being reported per target.
+#[instrument(skip_all)]
+pub async fn share_connector(
+    state: SessionState,
+    org_id: String,
+    req: ConnectorShareRequest,
+) -> RouterResponse<ConnectorShareResponse> {
+    validate_merchant_in_organization(&state, &org_id, &req.source_merchant_id).await?;
+    for target in &req.targets {
+        validate_merchant_in_organization(&state, &org_id, &target.merchant_id).await?;
+    }
+
+    let source = match admin::retrieve_payment_connector(
+        state.clone(),
+        req.source_merchant_id.clone(),
+        req.merchant_connector_id.clone(),
+    )
+    .await?
+    {
+        ApplicationResponse::Json(source) => source,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while retrieving the shared connector")?,
+    };
+
+    let mut results = Vec::with_capacity(req.targets.len());
+    for target in req.targets {
+        let create_request = get_shared_connector_create_request(&source, target.profile_id)?;
+        let result =
+            admin::create_payment_connector(state.clone(), create_request, &target.merchant_id)
+                .await;
+
+        results.push(match result {
+            Ok(ApplicationResponse::Json(connector)) => ConnectorShareResult {
+                merchant_id: target.merchant_id,
+                merchant_connector_id: Some(connector.merchant_connector_id),
+                error_message: None,
+            },
+            Ok(_) => ConnectorShareResult {
+                merchant_id: target.merchant_id,
+                merchant_connector_id: None,
+                error_message: Some("Unexpected response while creating the connector".into()),
+            },
+            Err(error) => {
+                logger::warn!(?error, "Failed to share the connector with the merchant");
+                ConnectorShareResult {
+                    merchant_id: target.merchant_id,
+                    merchant_connector_id: None,
+                    error_message: Some(error.current_context().error_message()),
+                }
+            }
+        });
+    }
+
+    Ok(ApplicationResponse::Json(ConnectorShareResponse {
+        results,
+    }))
+}
@@ -0,0 +1,321 @@
+use api_models::{
+    payment_method_sessions::{
+        PaymentMethodSessionConfirmRequest, PaymentMethodSessionCreateRequest,
+        PaymentMethodSessionResponse, PaymentMethodSessionRetrieveQuery,
+        PaymentMethodSessionStatus,
+    },
+    payments::{
+        HeaderPayload, MandateData, PaymentIdType, PaymentsRequest, PaymentsResponse,
+        PaymentsRetrieveRequest,
+    },
+};
+use common_utils::date_time;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments,
+    },
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api::{self, enums},
+        domain,
+    },
+};
+
+/// The prefix of the identifiers of payment method sessions, telling them apart from the
+/// identifiers of the payments the sessions are backed by
+const PAYMENT_METHOD_SESSION_ID_PREFIX: &str = "pms";
+
+fn validate_session_id(session_id: &str) -> RouterResult<()> {
+    if session_id.starts_with(&format!("{PAYMENT_METHOD_SESSION_ID_PREFIX}_")) {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payment method session {session_id} does not exist"),
+        }))
+    }
+}
+
+fn get_session_status(
+    payments_response: &PaymentsResponse,
+    now: time::PrimitiveDateTime,
+) -> PaymentMethodSessionStatus {
+    match payments_response.status {
+        enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation => {
+            if payments_response
+                .expires_on
+                .map_or(false, |expires_on| expires_on < now)
+            {
+                PaymentMethodSessionStatus::Expired
+            } else {
+                PaymentMethodSessionStatus::RequiresPaymentMethod
+            }
+        }
+        enums::IntentStatus::RequiresCustomerAction => {
+            PaymentMethodSessionStatus::RequiresCustomerAction
+        }
+        enums::IntentStatus::Succeeded => PaymentMethodSessionStatus::Succeeded,
+        enums::IntentStatus::Failed => PaymentMethodSessionStatus::Failed,
+        enums::IntentStatus::Cancelled => PaymentMethodSessionStatus::Cancelled,
+        enums::IntentStatus::Processing
+        | enums::IntentStatus::RequiresMerchantAction
+        | enums::IntentStatus::RequiresCapture
+        | enums::IntentStatus::PartiallyCaptured
+        | enums::IntentStatus::PartiallyCapturedAndCapturable => {
+            PaymentMethodSessionStatus::Processing
+        }
+    }
+}
+
+fn get_session_response(
+    response: RouterResponse<PaymentsResponse>,
+) -> RouterResponse<PaymentMethodSessionResponse> {
+    let payments_response = match response? {
+        ApplicationResponse::Json(payments_response)
+        | ApplicationResponse::JsonWithHeaders((payments_response, _)) => payments_response,
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response from the payment of the session");
+        }
+    };
+
+    let status = get_session_status(&payments_response, date_time::now());
+    let session_id = payments_response
+        .payment_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing payment_id in the payment of the session")?;
+
+    Ok(ApplicationResponse::Json(PaymentMethodSessionResponse {
+        session_id,
+        merchant_id: payments_response.merchant_id,
+        customer_id: payments_response.customer_id,
+        status,
+        client_secret: payments_response.client_secret,
+        usage: payments_response.setup_future_usage,
+        authentication_type: payments_response.authentication_type,
+        payment_method: payments_response.payment_method,
+        payment_method_type: payments_response.payment_method_type,
+        payment_method_id: payments_response.payment_method_id,
+        mandate_id: payments_response.mandate_id,
+        next_action: payments_response.next_action,
+        connector: payments_response.connector,
+        profile_id: payments_response.profile_id,
+        error_code: payments_response.error_code,
+        error_message: payments_response.error_message,
+        metadata: payments_response.metadata,
+        created_at: payments_response.created,
+        expires_at: payments_response.expires_on,
+    }))
+}
+
+/// A payment method session is a zero amount payment setting up the payment method of the
+/// customer, the payment method being saved, and the mandate set up, once it is confirmed
+#[instrument(skip_all)]
+pub async fn create_payment_method_session(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentMethodSessionCreateRequest,
+) -> RouterResponse<PaymentMethodSessionResponse> {
+    let session_id = common_utils::generate_id(consts::ID_LENGTH, PAYMENT_METHOD_SESSION_ID_PREFIX);
+    let payments_request = PaymentsRequest {
+        payment_id: Some(PaymentIdType::PaymentIntentId(session_id)),
+        amount: Some(api::Amount::Zero),
+        currency: Some(request.currency),
+        customer_id: Some(request.customer_id),
+        confirm: Some(false),
+        setup_future_usage: Some(request.usage.unwrap_or(enums::FutureUsage::OffSession)),
+        authentication_type: request.authentication_type,
+        mandate_data: request.mandate_type.map(|mandate_type| MandateData {
+            update_mandate_id: None,
+            customer_acceptance: None,
+            mandate_type: Some(mandate_type),
+        }),
+        allowed_payment_method_types: request.allowed_payment_method_types,
+        return_url: request.return_url,
+        profile_id: request.profile_id,
+        session_expiry: request.session_expiry,
+        metadata: request.metadata,
+        payment_type: Some(enums::PaymentType::SetupMandate),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::SetupMandate,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    get_session_response(response)
+}
+
+/// Builds the confirmation of the payment of the session, the payment method of the customer
+/// being verified with the connector when it is confirmed
+pub fn get_session_confirm_request(
+    session_id: String,
+    request: PaymentMethodSessionConfirmRequest,
+) -> RouterResult<PaymentsRequest> {
+    validate_session_id(&session_id)?;
+
+    Ok(PaymentsRequest {
+        payment_id: Some(PaymentIdType::PaymentIntentId(session_id)),
+        client_secret: request.client_secret,
+        confirm: Some(true),
+        payment_method: Some(request.payment_method),
+        payment_method_type: request.payment_method_type,
+        payment_method_data: Some(request.payment_method_data),
+        // The mandate type the session was created with is kept on the payment attempt and
+        // applied to the mandate the customer accepts
+        mandate_data: request
+            .customer_acceptance
+            .clone()
+            .map(|customer_acceptance| MandateData {
+                update_mandate_id: None,
+                customer_acceptance: Some(customer_acceptance),
+                mandate_type: None,
+            }),
+        customer_acceptance: request.customer_acceptance,
+        browser_info: request.browser_info,
+        return_url: request.return_url,
+        payment_type: Some(enums::PaymentType::SetupMandate),
+        ..Default::default()
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn confirm_payment_method_session(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payments_request: PaymentsRequest,
+    auth_flow: services::AuthFlow,
+    header_payload: HeaderPayload,
+) -> RouterResponse<PaymentMethodSessionResponse> {
+    let response = Box::pin(payments::payments_core::<
+        api::SetupMandate,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        payments::PaymentConfirm,
+        payments_request,
+        auth_flow,
+        payments::CallConnectorAction::Trigger,
+        None,
+        header_payload,
+    ))
+    .await;
+
+    get_session_response(response)
+}
+
+pub fn get_session_retrieve_request(
+    session_id: String,
+    query: PaymentMethodSessionRetrieveQuery,
+) -> RouterResult<PaymentsRetrieveRequest> {
+    validate_session_id(&session_id)?;
+
+    Ok(PaymentsRetrieveRequest {
+        resource_id: PaymentIdType::PaymentIntentId(session_id),
+        force_sync: query.force_sync.unwrap_or(false),
+        client_secret: query.client_secret,
+        ..Default::default()
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_method_session(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payments_request: PaymentsRetrieveRequest,
+    auth_flow: services::AuthFlow,
+) -> RouterResponse<PaymentMethodSessionResponse> {
+    let response = Box::pin(payments::payments_core::<
+        api::PSync,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        payments::PaymentStatus,
+        payments_request,
+        auth_flow,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    get_session_response(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_prefixed_identifiers_are_sessions() {
+        assert!(validate_session_id("pms_mbabizu24mvu3mela5njyhpit4").is_ok());
+        assert!(validate_session_id("pay_mbabizu24mvu3mela5njyhpit4").is_err());
+        assert!(validate_session_id("pmsmbabizu24mvu3mela5njyhpit4").is_err());
+    }
+
+    #[test]
+    fn test_unconfirmed_session_expires() {
+        let now = date_time::now();
+        let mut payments_response = PaymentsResponse {
+            status: enums::IntentStatus::RequiresPaymentMethod,
+            expires_on: Some(now + time::Duration::minutes(15)),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_session_status(&payments_response, now),
+            PaymentMethodSessionStatus::RequiresPaymentMethod
+        );
+
+        payments_response.expires_on = Some(now - time::Duration::minutes(1));
+        assert_eq!(
+            get_session_status(&payments_response, now),
+            PaymentMethodSessionStatus::Expired
+        );
+
+        payments_response.status = enums::IntentStatus::Succeeded;
+        assert_eq!(
+            get_session_status(&payments_response, now),
+            PaymentMethodSessionStatus::Succeeded
+        );
+    }
+}
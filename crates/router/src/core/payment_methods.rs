@@ -1,3 +1,4 @@
+pub mod card_vault;
 pub mod cards;
 pub mod surcharge_decision_configs;
 pub mod transformers;
@@ -0,0 +1,584 @@
+use api_models::{admin::VaultProvider, enums as api_enums, payment_methods::Card};
+use base64::Engine;
+use common_utils::{
+    ext_traits::{BytesExt, ValueExt},
+    generate_id, id_type,
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::{cards, transformers as payment_methods},
+    },
+    headers,
+    routes::SessionState,
+    services,
+    types::{api, storage},
+};
+
+const EXTERNAL_VAULT_REFERENCE_PREFIX: &str = "evault";
+const CONNECTOR_TOKENIZATION_REFERENCE_PREFIX: &str = "ctoken";
+const CARD_REFERENCE_SEPARATOR: char = ':';
+
+/// A vault the card details saved for the customers are stored in.
+///
+/// The card references handled by a vault are the ones issued by it, the references stored on the
+/// payment methods are qualified with the vault they belong to by the functions of this module.
+#[async_trait::async_trait]
+pub trait CardVault: Send + Sync {
+    async fn store_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card: Card,
+        card_reference: Option<&str>,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError>;
+
+    async fn retrieve_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError>;
+
+    async fn delete_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError>;
+}
+
+/// The Hyperswitch card vault configured for the application
+pub struct HyperswitchCardVault;
+
+#[async_trait::async_trait]
+impl CardVault for HyperswitchCardVault {
+    async fn store_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card: Card,
+        card_reference: Option<&str>,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        let payload = payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+            merchant_id,
+            merchant_customer_id: customer_id.to_owned(),
+            requestor_card_reference: card_reference.map(str::to_string),
+            card,
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        });
+
+        cards::call_to_locker_hs(
+            state,
+            &payload,
+            customer_id,
+            api_enums::LockerChoice::HyperswitchCardVault,
+        )
+        .await
+    }
+
+    async fn retrieve_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        cards::get_card_from_hs_locker(
+            state,
+            customer_id,
+            merchant_id,
+            card_reference,
+            api_enums::LockerChoice::HyperswitchCardVault,
+        )
+        .await
+    }
+
+    async fn delete_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        cards::delete_card_from_hs_locker(state, customer_id, merchant_id, card_reference)
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)
+    }
+}
+
+/// A vault hosted outside Hyperswitch, in the PCI environment of the merchant.
+///
+/// The vault exposes the `/cards/add`, `/cards/retrieve` and `/cards/delete` endpoints of the
+/// Hyperswitch card vault, with plain JSON bodies authenticated with basic auth.
+pub struct ExternalCardVault {
+    config: settings::ExternalVaultConfig,
+}
+
+impl ExternalCardVault {
+    fn build_request<T>(&self, path: &str, payload: T) -> services::Request
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        let credentials = consts::BASE64_ENGINE.encode(format!(
+            "{}:{}",
+            self.config.username.peek(),
+            self.config.password.peek()
+        ));
+        let mut request = services::Request::new(
+            services::Method::Post,
+            &format!("{}{path}", self.config.base_url),
+        );
+        request.add_header(headers::CONTENT_TYPE, "application/json".into());
+        request.add_header(
+            headers::AUTHORIZATION,
+            format!("Basic {credentials}").into_masked(),
+        );
+        request.set_body(RequestContent::Json(Box::new(payload)));
+        request
+    }
+
+    async fn call_vault(
+        &self,
+        state: &SessionState,
+        request: services::Request,
+        flow: &str,
+        error: errors::VaultError,
+    ) -> CustomResult<bytes::Bytes, errors::VaultError> {
+        let response = services::call_connector_api(state, request, flow)
+            .await
+            .change_context(error)
+            .attach_printable("Failed while calling the external vault")?;
+        match response {
+            Ok(response) => Ok(response.response),
+            Err(response) => Err(report!(errors::VaultError::UnexpectedResponseError(
+                response.response
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVault for ExternalCardVault {
+    async fn store_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card: Card,
+        card_reference: Option<&str>,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        let payload = ExternalVaultStoreCardRequest {
+            merchant_id: merchant_id.to_owned(),
+            merchant_customer_id: customer_id.to_owned(),
+            requestor_card_reference: card_reference.map(str::to_string),
+            card,
+        };
+        let request = self.build_request("/cards/add", payload);
+        let response: payment_methods::StoreCardResp = self
+            .call_vault(
+                state,
+                request,
+                "add_card_to_external_vault",
+                errors::VaultError::SaveCardFailed,
+            )
+            .await?
+            .parse_struct("StoreCardResp")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        response
+            .payload
+            .ok_or(errors::VaultError::SaveCardFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "The external vault failed to store the card: {:?}",
+                    response.error_message
+                )
+            })
+    }
+
+    async fn retrieve_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        let payload = ExternalVaultCardRequest {
+            merchant_id: merchant_id.to_owned(),
+            merchant_customer_id: customer_id.to_owned(),
+            card_reference: card_reference.to_owned(),
+        };
+        let request = self.build_request("/cards/retrieve", payload);
+        let response: payment_methods::RetrieveCardResp = self
+            .call_vault(
+                state,
+                request,
+                "get_card_from_external_vault",
+                errors::VaultError::FetchCardFailed,
+            )
+            .await?
+            .parse_struct("RetrieveCardResp")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        response
+            .payload
+            .and_then(|payload| payload.card)
+            .ok_or(errors::VaultError::FetchCardFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "The external vault failed to retrieve the card: {:?}",
+                    response.error_message
+                )
+            })
+    }
+
+    async fn delete_card(
+        &self,
+        state: &SessionState,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        let payload = ExternalVaultCardRequest {
+            merchant_id: merchant_id.to_owned(),
+            merchant_customer_id: customer_id.to_owned(),
+            card_reference: card_reference.to_owned(),
+        };
+        let request = self.build_request("/cards/delete", payload);
+        self.call_vault(
+            state,
+            request,
+            "delete_card_from_external_vault",
+            errors::VaultError::DeleteCardFailed,
+        )
+        .await?
+        .parse_struct("DeleteCardResp")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct ExternalVaultStoreCardRequest {
+    merchant_id: String,
+    merchant_customer_id: id_type::CustomerId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requestor_card_reference: Option<String>,
+    card: Card,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct ExternalVaultCardRequest {
+    merchant_id: String,
+    merchant_customer_id: id_type::CustomerId,
+    card_reference: String,
+}
+
+/// The card details are not kept by Hyperswitch, the connector tokenizes the card when the payment
+/// saving it is made and the saved card can only be used through the connector mandate details of
+/// the payment method.
+pub struct ConnectorTokenizationVault;
+
+#[async_trait::async_trait]
+impl CardVault for ConnectorTokenizationVault {
+    async fn store_card(
+        &self,
+        _state: &SessionState,
+        _merchant_id: &str,
+        _customer_id: &id_type::CustomerId,
+        _card: Card,
+        card_reference: Option<&str>,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        Ok(payment_methods::StoreCardRespPayload {
+            card_reference: card_reference
+                .map_or_else(|| generate_id(consts::ID_LENGTH, "card"), str::to_string),
+            duplication_check: None,
+        })
+    }
+
+    async fn retrieve_card(
+        &self,
+        _state: &SessionState,
+        _merchant_id: &str,
+        _customer_id: &id_type::CustomerId,
+        _card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        Err(report!(errors::VaultError::FetchCardFailed))
+            .attach_printable("The card details are held by the connector which tokenized the card")
+    }
+
+    async fn delete_card(
+        &self,
+        _state: &SessionState,
+        _merchant_id: &str,
+        _customer_id: &id_type::CustomerId,
+        _card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        Ok(payment_methods::DeleteCardResp {
+            status: "Ok".to_string(),
+            error_code: None,
+            error_message: None,
+        })
+    }
+}
+
+/// The vault a card reference stored on a payment method belongs to, along with the reference
+/// issued by that vault
+enum CardVaultReference<'a> {
+    Hyperswitch(&'a str),
+    External {
+        vault_name: &'a str,
+        card_reference: &'a str,
+    },
+    ConnectorTokenization {
+        merchant_connector_id: &'a str,
+        card_reference: &'a str,
+    },
+}
+
+impl<'a> CardVaultReference<'a> {
+    fn parse(card_reference: &'a str) -> Self {
+        let mut parts = card_reference.splitn(3, CARD_REFERENCE_SEPARATOR);
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(EXTERNAL_VAULT_REFERENCE_PREFIX), Some(vault_name), Some(card_reference)) => {
+                Self::External {
+                    vault_name,
+                    card_reference,
+                }
+            }
+            (
+                Some(CONNECTOR_TOKENIZATION_REFERENCE_PREFIX),
+                Some(merchant_connector_id),
+                Some(card_reference),
+            ) => Self::ConnectorTokenization {
+                merchant_connector_id,
+                card_reference,
+            },
+            _ => Self::Hyperswitch(card_reference),
+        }
+    }
+
+    fn card_reference(&self) -> &'a str {
+        match self {
+            Self::Hyperswitch(card_reference)
+            | Self::External { card_reference, .. }
+            | Self::ConnectorTokenization { card_reference, .. } => card_reference,
+        }
+    }
+
+    fn qualify(vault_provider: &VaultProvider, card_reference: &str) -> String {
+        match vault_provider {
+            VaultProvider::HyperswitchLocker => card_reference.to_owned(),
+            VaultProvider::ExternalVault { vault_name } => {
+                [EXTERNAL_VAULT_REFERENCE_PREFIX, vault_name, card_reference]
+                    .join(&CARD_REFERENCE_SEPARATOR.to_string())
+            }
+            VaultProvider::ConnectorTokenization {
+                merchant_connector_id,
+            } => [
+                CONNECTOR_TOKENIZATION_REFERENCE_PREFIX,
+                merchant_connector_id,
+                card_reference,
+            ]
+            .join(&CARD_REFERENCE_SEPARATOR.to_string()),
+        }
+    }
+
+    fn vault_provider(&self) -> VaultProvider {
+        match self {
+            Self::Hyperswitch(_) => VaultProvider::HyperswitchLocker,
+            Self::External { vault_name, .. } => VaultProvider::ExternalVault {
+                vault_name: vault_name.to_string(),
+            },
+            Self::ConnectorTokenization {
+                merchant_connector_id,
+                ..
+            } => VaultProvider::ConnectorTokenization {
+                merchant_connector_id: merchant_connector_id.to_string(),
+            },
+        }
+    }
+}
+
+pub fn get_card_vault(
+    state: &SessionState,
+    vault_provider: &VaultProvider,
+) -> CustomResult<Box<dyn CardVault>, errors::VaultError> {
+    match vault_provider {
+        VaultProvider::HyperswitchLocker => Ok(Box::new(HyperswitchCardVault)),
+        VaultProvider::ExternalVault { vault_name } => {
+            let config = state
+                .conf
+                .external_vaults
+                .get_inner()
+                .0
+                .get(vault_name)
+                .cloned()
+                .ok_or(errors::VaultError::MissingRequiredField {
+                    field_name: "external_vaults",
+                })
+                .attach_printable_lazy(|| {
+                    format!("External vault {vault_name} is not configured")
+                })?;
+            Ok(Box::new(ExternalCardVault { config }))
+        }
+        VaultProvider::ConnectorTokenization { .. } => Ok(Box::new(ConnectorTokenizationVault)),
+    }
+}
+
+pub fn get_vault_provider(
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<Option<VaultProvider>> {
+    business_profile
+        .vault_provider
+        .clone()
+        .map(|vault_provider| vault_provider.parse_value("VaultProvider"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the vault provider of the business profile")
+}
+
+pub fn get_locker_card(card: &api::CardDetail) -> Card {
+    Card {
+        card_number: card.card_number.to_owned(),
+        name_on_card: card.card_holder_name.to_owned(),
+        card_exp_month: card.card_exp_month.to_owned(),
+        card_exp_year: card.card_exp_year.to_owned(),
+        card_brand: card.card_network.as_ref().map(ToString::to_string),
+        card_isin: None,
+        nick_name: card.nick_name.as_ref().map(Secret::peek).cloned(),
+    }
+}
+
+/// Stores the card in the vault of the business profile, or in the vault the card reference
+/// belongs to when the card stored against an existing reference is replaced
+#[instrument(skip_all)]
+pub async fn store_card(
+    state: &SessionState,
+    vault_provider: Option<&VaultProvider>,
+    merchant_id: &str,
+    customer_id: &id_type::CustomerId,
+    card: &api::CardDetail,
+    card_reference: Option<&str>,
+) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+    let card_vault_reference = card_reference.map(CardVaultReference::parse);
+    let vault_provider = card_vault_reference
+        .as_ref()
+        .map(CardVaultReference::vault_provider)
+        .or(vault_provider.cloned())
+        .unwrap_or(VaultProvider::HyperswitchLocker);
+
+    let stored_card = get_card_vault(state, &vault_provider)?
+        .store_card(
+            state,
+            merchant_id,
+            customer_id,
+            get_locker_card(card),
+            card_vault_reference
+                .as_ref()
+                .map(CardVaultReference::card_reference),
+        )
+        .await?;
+
+    Ok(payment_methods::StoreCardRespPayload {
+        card_reference: CardVaultReference::qualify(&vault_provider, &stored_card.card_reference),
+        duplication_check: stored_card.duplication_check,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card(
+    state: &SessionState,
+    merchant_id: &str,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> CustomResult<Card, errors::VaultError> {
+    let card_vault_reference = CardVaultReference::parse(card_reference);
+    get_card_vault(state, &card_vault_reference.vault_provider())?
+        .retrieve_card(
+            state,
+            merchant_id,
+            customer_id,
+            card_vault_reference.card_reference(),
+        )
+        .await
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: &SessionState,
+    merchant_id: &str,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+    let card_vault_reference = CardVaultReference::parse(card_reference);
+    get_card_vault(state, &card_vault_reference.vault_provider())?
+        .delete_card(
+            state,
+            merchant_id,
+            customer_id,
+            card_vault_reference.card_reference(),
+        )
+        .await
+}
+
+pub async fn validate_vault_provider(
+    state: &SessionState,
+    merchant_id: &str,
+    vault_provider: &VaultProvider,
+) -> RouterResult<()> {
+    match vault_provider {
+        VaultProvider::HyperswitchLocker => Ok(()),
+        VaultProvider::ExternalVault { vault_name } => {
+            if vault_name.contains(CARD_REFERENCE_SEPARATOR)
+                || !state
+                    .conf
+                    .external_vaults
+                    .get_inner()
+                    .0
+                    .contains_key(vault_name)
+            {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("external vault {vault_name} is not configured"),
+                }
+                .into());
+            }
+            Ok(())
+        }
+        VaultProvider::ConnectorTokenization {
+            merchant_connector_id,
+        } => {
+            let db = &*state.store;
+            let key_store = db
+                .get_merchant_key_store_by_merchant_id(
+                    merchant_id,
+                    &db.get_master_key().to_vec().into(),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+            db.find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                merchant_id,
+                merchant_connector_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(
+                errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                    id: merchant_connector_id.to_owned(),
+                },
+            )?;
+            Ok(())
+        }
+    }
+}
@@ -6,13 +6,16 @@ use std::{
 
 use api_models::{
     admin::{self, PaymentMethodsEnabled},
+    audit_events::{AuditAction, AuditResourceType},
     enums::{self as api_enums},
     payment_methods::{
         BankAccountTokenData, Card, CardDetailUpdate, CardDetailsPaymentMethod, CardNetworkTypes,
-        CountryCodeWithName, CustomerDefaultPaymentMethodResponse, ListCountriesCurrenciesRequest,
-        ListCountriesCurrenciesResponse, MaskedBankDetails, PaymentExperienceTypes,
-        PaymentMethodsData, RequestPaymentMethodTypes, RequiredFieldInfo,
-        ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
+        CountryCodeWithName, CustomerDefaultPaymentMethodResponse, CustomerPaymentMethodOrder,
+        CustomerPaymentMethodOrderRequest, CustomerPaymentMethodOrderResponse,
+        ListCountriesCurrenciesRequest, ListCountriesCurrenciesResponse, MaskedBankDetails,
+        PaymentExperienceTypes, PaymentMethodUsageRestrictions,
+        PaymentMethodUsageRestrictionsResponse, PaymentMethodsData, RequestPaymentMethodTypes,
+        RequiredFieldInfo, ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
         ResponsePaymentMethodsEnabled,
     },
     payments::BankCodeResponse,
@@ -44,15 +47,17 @@ use crate::core::utils::get_connector_label;
 use crate::{
     configs::settings,
     core::{
+        audit_events,
         errors::{self, StorageErrorExt},
         payment_methods::{
-            add_payment_method_status_update_task, transformers as payment_methods, vault,
+            add_payment_method_status_update_task, card_vault, transformers as payment_methods,
+            vault,
         },
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
         },
-        utils as core_utils,
+        pm_display_rules, pm_filters, utils as core_utils,
     },
     db, logger,
     pii::prelude::*,
@@ -139,6 +144,7 @@ pub async fn create_payment_method(
                 last_used_at: current_time,
                 payment_method_billing_address,
                 updated_by: None,
+                usage_restrictions: None,
             },
             storage_scheme,
         )
@@ -405,6 +411,7 @@ pub async fn add_payment_method_data(
                 &customer_id,
                 &merchant_account,
                 None,
+                None,
             ))
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError);
@@ -567,6 +574,7 @@ pub async fn add_payment_method(
                     &customer_id,
                     merchant_account,
                     None,
+                    None,
                 ))
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -627,20 +635,20 @@ pub async fn add_payment_method(
                     )
                     .await?;
 
-                    let add_card_resp = add_card_hs(
+                    let add_card_resp = Box::pin(add_card_to_locker(
                         &state,
                         req.clone(),
                         &card,
                         &customer_id,
                         merchant_account,
-                        api::enums::LockerChoice::HyperswitchCardVault,
+                        None,
                         Some(
                             existing_pm
                                 .locker_id
                                 .as_ref()
                                 .unwrap_or(&existing_pm.payment_method_id),
                         ),
-                    )
+                    ))
                     .await;
 
                     if let Err(err) = add_card_resp {
@@ -682,6 +690,9 @@ pub async fn add_payment_method(
                         card_network: card.card_network.or(existing_pm_data.card_network),
                         card_issuer: card.card_issuer.or(existing_pm_data.card_issuer),
                         card_type: card.card_type.or(existing_pm_data.card_type),
+                        card_tier: None,
+                        card_funding_type: None,
+                        is_regulated: None,
                         saved_to_locker: true,
                     });
 
@@ -910,6 +921,7 @@ pub async fn update_customer_payment_method(
                 &updated_card_details,
                 &pm.customer_id,
                 &merchant_account,
+                None,
                 Some(pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id)),
             ))
             .await
@@ -936,6 +948,9 @@ pub async fn update_customer_payment_method(
                 card_isin: existing_card_data.card_isin,
                 card_issuer: existing_card_data.card_issuer,
                 card_type: existing_card_data.card_type,
+                card_tier: existing_card_data.card_tier,
+                card_funding_type: existing_card_data.card_funding_type,
+                is_regulated: existing_card_data.is_regulated,
                 saved_to_locker: true,
             });
 
@@ -1105,12 +1120,16 @@ pub async fn add_bank_to_locker(
 }
 
 /// The response will be the tuple of PaymentMethodResponse and the duplication check of payment_method
+///
+/// The card is stored in the vault of the card reference when one is given, in the vault of the
+/// business profile otherwise, defaulting to the Hyperswitch card vault
 pub async fn add_card_to_locker(
     state: &routes::SessionState,
     req: api::PaymentMethodCreate,
     card: &api::CardDetail,
     customer_id: &id_type::CustomerId,
     merchant_account: &domain::MerchantAccount,
+    vault_provider: Option<&admin::VaultProvider>,
     card_reference: Option<&str>,
 ) -> errors::CustomResult<
     (
@@ -1122,16 +1141,26 @@ pub async fn add_card_to_locker(
     metrics::STORED_TO_LOCKER.add(&metrics::CONTEXT, 1, &[]);
     let add_card_to_hs_resp = common_utils::metrics::utils::record_operation_time(
         async {
-            add_card_hs(
+            card_vault::store_card(
                 state,
-                req.clone(),
-                card,
+                vault_provider,
+                &merchant_account.merchant_id,
                 customer_id,
-                merchant_account,
-                api_enums::LockerChoice::HyperswitchCardVault,
+                card,
                 card_reference,
             )
             .await
+            .map(|store_card_payload| {
+                (
+                    payment_methods::mk_add_card_response_hs(
+                        card.clone(),
+                        store_card_payload.card_reference,
+                        req.clone(),
+                        &merchant_account.merchant_id,
+                    ),
+                    store_card_payload.duplication_check,
+                )
+            })
             .map_err(|error| {
                 metrics::CARD_LOCKER_FAILURES.add(
                     &metrics::CONTEXT,
@@ -1150,7 +1179,7 @@ pub async fn add_card_to_locker(
     )
     .await?;
 
-    logger::debug!("card added to hyperswitch-card-vault");
+    logger::debug!("card added to card vault");
     Ok(add_card_to_hs_resp)
 }
 
@@ -1164,27 +1193,21 @@ pub async fn get_card_from_locker(
 
     let get_card_from_rs_locker_resp = common_utils::metrics::utils::record_operation_time(
         async {
-            get_card_from_hs_locker(
-                state,
-                customer_id,
-                merchant_id,
-                card_reference,
-                api_enums::LockerChoice::HyperswitchCardVault,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while getting card from hyperswitch card vault")
-            .map_err(|error| {
-                metrics::CARD_LOCKER_FAILURES.add(
-                    &metrics::CONTEXT,
-                    1,
-                    &[
-                        router_env::opentelemetry::KeyValue::new("locker", "rust"),
-                        router_env::opentelemetry::KeyValue::new("operation", "get"),
-                    ],
-                );
-                error
-            })
+            card_vault::retrieve_card(state, merchant_id, customer_id, card_reference)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while getting card from card vault")
+                .map_err(|error| {
+                    metrics::CARD_LOCKER_FAILURES.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[
+                            router_env::opentelemetry::KeyValue::new("locker", "rust"),
+                            router_env::opentelemetry::KeyValue::new("operation", "get"),
+                        ],
+                    );
+                    error
+                })
         },
         &metrics::CARD_GET_TIME,
         &metrics::CONTEXT,
@@ -1192,6 +1215,17 @@ pub async fn get_card_from_locker(
     )
     .await?;
 
+    audit_events::record_audit_event::<()>(
+        state,
+        merchant_id,
+        AuditResourceType::VaultToken,
+        card_reference,
+        AuditAction::Read,
+        None,
+        None,
+    )
+    .await;
+
     logger::debug!("card retrieved from rust locker");
     Ok(get_card_from_rs_locker_resp)
 }
@@ -1204,10 +1238,12 @@ pub async fn delete_card_from_locker(
 ) -> errors::RouterResult<payment_methods::DeleteCardResp> {
     metrics::DELETE_FROM_LOCKER.add(&metrics::CONTEXT, 1, &[]);
 
-    common_utils::metrics::utils::record_operation_time(
+    let delete_card_resp = common_utils::metrics::utils::record_operation_time(
         async move {
-            delete_card_from_hs_locker(state, customer_id, merchant_id, card_reference)
+            card_vault::delete_card(state, merchant_id, customer_id, card_reference)
                 .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while deleting card from card vault")
                 .map_err(|error| {
                     metrics::CARD_LOCKER_FAILURES.add(&metrics::CONTEXT, 1, &[]);
                     error
@@ -1217,7 +1253,20 @@ pub async fn delete_card_from_locker(
         &metrics::CONTEXT,
         &[],
     )
-    .await
+    .await?;
+
+    audit_events::record_audit_event::<()>(
+        state,
+        merchant_id,
+        AuditResourceType::VaultToken,
+        card_reference,
+        AuditAction::Delete,
+        None,
+        None,
+    )
+    .await;
+
+    Ok(delete_card_resp)
 }
 
 #[instrument(skip_all)]
@@ -1240,15 +1289,7 @@ pub async fn add_card_hs(
         merchant_id: &merchant_account.merchant_id,
         merchant_customer_id: customer_id.to_owned(),
         requestor_card_reference: card_reference.map(str::to_string),
-        card: Card {
-            card_number: card.card_number.to_owned(),
-            name_on_card: card.card_holder_name.to_owned(),
-            card_exp_month: card.card_exp_month.to_owned(),
-            card_exp_year: card.card_exp_year.to_owned(),
-            card_brand: card.card_network.as_ref().map(ToString::to_string),
-            card_isin: None,
-            nick_name: card.nick_name.as_ref().map(Secret::peek).cloned(),
-        },
+        card: card_vault::get_locker_card(card),
         ttl: state.conf.locker.ttl_for_storage_in_secs,
     });
 
@@ -1767,7 +1808,7 @@ pub async fn list_payment_methods(
     mut req: api::PaymentMethodListRequest,
 ) -> errors::RouterResponse<api::PaymentMethodListResponse> {
     let db = &*state.store;
-    let pm_config_mapping = &state.conf.pm_filters;
+    let pm_config_mapping = pm_filters::get_pm_filters(&state).await?;
 
     let payment_intent = if let Some(cs) = &req.client_secret {
         if cs.starts_with("pm_") {
@@ -1924,7 +1965,7 @@ pub async fn list_payment_methods(
             payment_attempt.as_ref(),
             billing_address.as_ref(),
             mca.connector_name.clone(),
-            pm_config_mapping,
+            &pm_config_mapping,
             &state.conf.mandates.supported_payment_methods,
             &state.conf.mandates.update_mandate_supported,
             &state.conf.saved_payment_methods,
@@ -1932,6 +1973,19 @@ pub async fn list_payment_methods(
         .await?;
     }
 
+    if let Some(business_profile) = business_profile.as_ref() {
+        pm_display_rules::apply_pm_display_rules(
+            &state,
+            &business_profile.profile_id,
+            payment_attempt.as_ref(),
+            payment_intent.as_ref(),
+            billing_address.as_ref(),
+            req.platform,
+            &mut response,
+        )
+        .await?;
+    }
+
     // Filter out wallet payment method from mca if customer has already saved it
     customer
         .as_ref()
@@ -3624,6 +3678,20 @@ pub async fn list_customer_payment_method(
             connector_mandate_details,
         )
         .await?;
+        let card_info = match payment_method_retrieval_context.card_details.as_ref() {
+            Some(card_details) => get_card_info_of_card_details(state, card_details).await,
+            None => None,
+        };
+        let co_badged_card_networks = card_info
+            .as_ref()
+            .filter(|card_info| card_info.is_co_badged())
+            .map(|card_info| card_info.get_card_networks());
+        let card_details = payment_method_retrieval_context
+            .card_details
+            .map(|card_details| match &card_info {
+                Some(card_info) => apply_card_bin_metadata(card_details, card_info),
+                None => card_details,
+            });
         // Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: parent_payment_method_token.to_owned(),
@@ -3632,7 +3700,8 @@ pub async fn list_customer_payment_method(
             payment_method,
             payment_method_type: pm.payment_method_type,
             payment_method_issuer: pm.payment_method_issuer,
-            card: payment_method_retrieval_context.card_details,
+            card: card_details,
+            co_badged_card_networks,
             metadata: pm.metadata,
             payment_method_issuer_code: pm.payment_method_issuer_code,
             recurring_enabled: mca_enabled,
@@ -3703,6 +3772,9 @@ pub async fn list_customer_payment_method(
         }
     }
 
+    let payment_method_order = get_customer_payment_method_order(&customer)?;
+    sort_customer_payment_methods(&mut customer_pms, &payment_method_order);
+
     let mut response = api::CustomerPaymentMethodsListResponse {
         customer_payment_methods: customer_pms,
         is_guest_customer: payment_intent.as_ref().map(|_| false), //to return this key only when the request is tied to a payment intent
@@ -4103,6 +4175,274 @@ pub async fn update_last_used_at(
 
     Ok(())
 }
+
+fn get_customer_payment_method_order(
+    customer: &domain::Customer,
+) -> errors::RouterResult<CustomerPaymentMethodOrder> {
+    customer
+        .payment_method_order
+        .clone()
+        .map(|payment_method_order| payment_method_order.parse_value("CustomerPaymentMethodOrder"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment method order of the customer")
+        .map(Option::unwrap_or_default)
+}
+
+/// Sorts the saved payment methods of a customer in the order they are displayed in: the default
+/// payment method first, then the payment methods in the order set for the customer, then the
+/// remaining payment methods in their current order, the most recently used first
+fn sort_customer_payment_methods(
+    customer_payment_methods: &mut [api::CustomerPaymentMethod],
+    payment_method_order: &CustomerPaymentMethodOrder,
+) {
+    customer_payment_methods.sort_by_key(|customer_payment_method| {
+        (
+            !customer_payment_method.default_payment_method_set,
+            payment_method_order
+                .payment_method_ids
+                .iter()
+                .position(|payment_method_id| {
+                    *payment_method_id == customer_payment_method.payment_method_id
+                })
+                .unwrap_or(usize::MAX),
+        )
+    });
+}
+
+/// Sets the order in which the saved payment methods of a customer are displayed, and whether the
+/// payment method last used by the customer becomes their default payment method
+pub async fn set_payment_method_order(
+    state: routes::SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: CustomerPaymentMethodOrderRequest,
+) -> errors::RouterResponse<CustomerPaymentMethodOrderResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+    let customer_id = req.customer_id.get_required_value("customer_id")?;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mut listed_payment_method_ids = HashSet::new();
+    if let Some(payment_method_id) = req
+        .payment_method_ids
+        .iter()
+        .find(|payment_method_id| !listed_payment_method_ids.insert(payment_method_id.as_str()))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("payment method {payment_method_id} is listed more than once"),
+        }));
+    }
+
+    let saved_payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_status(
+            &customer_id,
+            merchant_id,
+            common_enums::PaymentMethodStatus::Active,
+            None,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    if let Some(payment_method_id) = req.payment_method_ids.iter().find(|payment_method_id| {
+        !saved_payment_methods.iter().any(|saved_payment_method| {
+            saved_payment_method.payment_method_id == **payment_method_id
+        })
+    }) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payment method {payment_method_id} is not an active saved payment method of the \
+                 customer"
+            ),
+        }));
+    }
+
+    let payment_method_order = CustomerPaymentMethodOrder {
+        payment_method_ids: req.payment_method_ids,
+        promote_last_used: req.promote_last_used,
+    };
+    let customer_update = CustomerUpdate::UpdatePaymentMethodOrder {
+        payment_method_order: payment_method_order
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the payment method order of the customer")?,
+    };
+    let updated_customer = db
+        .update_customer_by_customer_id_merchant_id(
+            customer_id.clone(),
+            merchant_id.to_owned(),
+            customer,
+            customer_update,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payment method order of the customer")?;
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerPaymentMethodOrderResponse {
+            customer_id,
+            default_payment_method_id: updated_customer.default_payment_method_id,
+            payment_method_ids: payment_method_order.payment_method_ids,
+            promote_last_used: payment_method_order.promote_last_used,
+        },
+    ))
+}
+
+/// Sets the payment method last used in a payment as the default payment method of its customer,
+/// when the customer opted for their last used payment method being promoted
+pub async fn promote_last_used_payment_method(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &diesel_models::PaymentMethod,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            &payment_method.customer_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    if !get_customer_payment_method_order(&customer)?.promote_last_used
+        || customer.default_payment_method_id.as_ref() == Some(&payment_method.payment_method_id)
+    {
+        return Ok(());
+    }
+
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id.clone(),
+        merchant_account.merchant_id.clone(),
+        customer,
+        CustomerUpdate::UpdateDefaultPaymentMethod {
+            default_payment_method_id: Some(Some(payment_method.payment_method_id.clone())),
+        },
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to promote the last used payment method of the customer")?;
+
+    Ok(())
+}
+
+/// The restrictions on the use of the saved payment method in payments, no restrictions applying
+/// to the payment methods they were never set for
+pub fn get_payment_method_usage_restrictions(
+    payment_method: &diesel_models::PaymentMethod,
+) -> errors::RouterResult<PaymentMethodUsageRestrictions> {
+    payment_method
+        .usage_restrictions
+        .clone()
+        .map(|usage_restrictions| usage_restrictions.parse_value("PaymentMethodUsageRestrictions"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the usage restrictions of the payment method")
+        .map(Option::unwrap_or_default)
+}
+
+/// Sets the restrictions on the use of the saved payment method in payments, replacing the
+/// restrictions set before. The restrictions are enforced when the payments are confirmed.
+pub async fn set_payment_method_usage_restrictions(
+    state: routes::SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_method_id: String,
+    req: PaymentMethodUsageRestrictions,
+) -> errors::RouterResponse<PaymentMethodUsageRestrictionsResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let payment_method = db
+        .find_payment_method(&payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    if payment_method.merchant_id != *merchant_id {
+        return Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound));
+    }
+
+    if req
+        .max_amount
+        .is_some_and(|max_amount| max_amount.get_amount_as_i64() <= 0)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "max_amount must be greater than zero".to_string(),
+        }));
+    }
+    if let Some(allowed_profile_ids) = &req.allowed_profile_ids {
+        if allowed_profile_ids.is_empty() {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "allowed_profile_ids must not be empty".to_string(),
+            }));
+        }
+        let business_profiles = db
+            .list_business_profile_by_merchant_id(merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the business profiles of the merchant")?;
+        if let Some(profile_id) = allowed_profile_ids.iter().find(|profile_id| {
+            !business_profiles
+                .iter()
+                .any(|business_profile| business_profile.profile_id == **profile_id)
+        }) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("{profile_id} is not a business profile of the merchant"),
+            }));
+        }
+    }
+
+    let before = PaymentMethodUsageRestrictionsResponse {
+        payment_method_id: payment_method.payment_method_id.clone(),
+        usage_restrictions: get_payment_method_usage_restrictions(&payment_method)?,
+    };
+
+    let pm_update = storage::PaymentMethodUpdate::UsageRestrictionsUpdate {
+        usage_restrictions: Some(
+            req.encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to encode the usage restrictions of the payment method",
+                )?,
+        ),
+    };
+    let payment_method = db
+        .update_payment_method(payment_method, pm_update, merchant_account.storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the usage restrictions of the payment method")?;
+    let after = PaymentMethodUsageRestrictionsResponse {
+        payment_method_id: payment_method.payment_method_id,
+        usage_restrictions: req,
+    };
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::PaymentMethod,
+        &after.payment_method_id,
+        AuditAction::Update,
+        Some(&before),
+        Some(&after),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(after))
+}
 #[cfg(feature = "payouts")]
 pub async fn get_bank_from_hs_locker(
     state: &routes::SessionState,
@@ -4269,7 +4609,10 @@ pub async fn retrieve_payment_method(
         } else {
             get_card_details_without_locker_fallback(&pm, key, &state).await?
         };
-        Some(card_detail)
+        match get_card_info_of_card_details(&state, &card_detail).await {
+            Some(card_info) => Some(apply_card_bin_metadata(card_detail, &card_info)),
+            None => Some(card_detail),
+        }
     } else {
         None
     };
@@ -4294,6 +4637,46 @@ pub async fn retrieve_payment_method(
     ))
 }
 
+/// Looks up the BIN data of a saved card
+async fn get_card_info_of_card_details(
+    state: &routes::SessionState,
+    card_details: &api::CardDetailFromLocker,
+) -> Option<storage::CardInfo> {
+    let card_isin = card_details.card_isin.clone().or_else(|| {
+        card_details
+            .card_number
+            .as_ref()
+            .map(|card_number| card_number.get_card_isin())
+    })?;
+    state
+        .store
+        .get_card_info(&card_isin)
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()
+}
+
+/// Fills the details of a saved card with the issuer, the tier, the funding type and the
+/// regulated status of the card found in its BIN data
+fn apply_card_bin_metadata(
+    card_details: api::CardDetailFromLocker,
+    card_info: &storage::CardInfo,
+) -> api::CardDetailFromLocker {
+    api::CardDetailFromLocker {
+        card_issuer: card_details
+            .card_issuer
+            .or_else(|| card_info.card_issuer.clone()),
+        card_type: card_details
+            .card_type
+            .or_else(|| card_info.card_type.clone()),
+        card_tier: card_info.card_subtype.clone(),
+        card_funding_type: card_info.get_card_funding_type(),
+        is_regulated: card_info.is_regulated,
+        ..card_details
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn delete_payment_method(
     state: routes::SessionState,
@@ -4410,7 +4793,7 @@ pub async fn list_countries_currencies_for_connector_payment_method(
 ) -> errors::RouterResponse<ListCountriesCurrenciesResponse> {
     Ok(services::ApplicationResponse::Json(
         list_countries_currencies_for_connector_payment_method_util(
-            state.conf.pm_filters.clone(),
+            pm_filters::get_pm_filters(&state).await?.into_owned(),
             req.connector,
             req.payment_method_type,
         )
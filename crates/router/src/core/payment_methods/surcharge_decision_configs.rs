@@ -3,13 +3,17 @@ use api_models::{
     payments, routing,
     surcharge_decision_configs::{self, SurchargeDecisionConfigs, SurchargeDecisionManagerRecord},
 };
-use common_utils::{ext_traits::StringExt, types as common_utils_types};
+use common_utils::{
+    ext_traits::StringExt,
+    money,
+    types::{self as common_utils_types, MinorUnit},
+};
 use error_stack::{self, ResultExt};
 use euclid::{
     backend,
     backend::{inputs as dsl_inputs, EuclidBackend},
 };
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use serde::{Deserialize, Serialize};
 use storage_impl::redis::cache::{self, SURCHARGE_CACHE};
 
@@ -50,6 +54,52 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     }
 }
 
+/// The details of a card that decide the legal surcharge caps applicable to a payment made with it
+#[derive(Debug, Clone, Default)]
+pub struct SurchargeCardDetails {
+    pub card_issuing_country: Option<common_enums::CountryAlpha2>,
+    pub card_funding_type: Option<common_enums::CardFundingType>,
+}
+
+impl SurchargeCardDetails {
+    /// Builds the card details from the BIN data of the card. The card type of the payment method
+    /// type and the billing country are used when the BIN data of the card is not known.
+    pub fn new(
+        card_info: Option<&storage::CardInfo>,
+        payment_method_type: Option<common_enums::PaymentMethodType>,
+        billing_country: Option<common_enums::CountryAlpha2>,
+    ) -> Self {
+        let card_funding_type = card_info
+            .and_then(|card_info| card_info.get_card_funding_type())
+            .or(match payment_method_type {
+                Some(common_enums::PaymentMethodType::Credit) => {
+                    Some(common_enums::CardFundingType::Credit)
+                }
+                Some(common_enums::PaymentMethodType::Debit) => {
+                    Some(common_enums::CardFundingType::Debit)
+                }
+                _ => None,
+            });
+        Self {
+            card_issuing_country: card_info
+                .and_then(|card_info| card_info.get_card_issuing_country_code())
+                .or(billing_country),
+            card_funding_type,
+        }
+    }
+
+    /// The card payment method type the surcharges of the card are listed under
+    pub fn get_card_payment_method_type(&self) -> Option<common_enums::PaymentMethodType> {
+        self.card_funding_type
+            .map(|card_funding_type| match card_funding_type {
+                common_enums::CardFundingType::Credit => common_enums::PaymentMethodType::Credit,
+                common_enums::CardFundingType::Debit | common_enums::CardFundingType::Prepaid => {
+                    common_enums::PaymentMethodType::Debit
+                }
+            })
+    }
+}
+
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
     Generate(VirInterpreterBackendCacheWrapper),
@@ -61,6 +111,7 @@ impl SurchargeSource {
     pub fn generate_surcharge_details_and_populate_surcharge_metadata(
         &self,
         backend_input: &backend::BackendInput,
+        card_details: &SurchargeCardDetails,
         payment_attempt: &storage::PaymentAttempt,
         surcharge_metadata_and_key: (&mut types::SurchargeMetadata, types::SurchargeKey),
     ) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
@@ -77,6 +128,13 @@ impl SurchargeSource {
                             surcharge_details,
                             payment_attempt,
                         )
+                        .and_then(|surcharge_details| {
+                            apply_surcharge_caps(
+                                surcharge_details,
+                                &interpreter.merchant_surcharge_configs,
+                                card_details,
+                            )
+                        })
                     })
                     .transpose()?
                     .map(|surcharge_details| {
@@ -136,6 +194,10 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
         }
     };
 
+    let billing_country = billing_address
+        .as_ref()
+        .and_then(|billing_address| billing_address.address.as_ref())
+        .and_then(|address| address.country);
     let mut backend_input =
         make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
             .change_context(ConfigError::InputConstructionError)?;
@@ -150,12 +212,17 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                 Some(payment_methods_enabled.payment_method);
 
             if let Some(card_network_list) = &mut payment_method_type_response.card_networks {
+                // The card is not known yet, the caps of the jurisdiction of the billing address
+                // are applied to the surcharges listed for the card type
+                let card_details =
+                    SurchargeCardDetails::new(None, Some(payment_method_type), billing_country);
                 for card_network_type in card_network_list.iter_mut() {
                     backend_input.payment_method.card_network =
                         Some(card_network_type.card_network.clone());
                     let surcharge_details = surcharge_source
                         .generate_surcharge_details_and_populate_surcharge_metadata(
                             &backend_input,
+                            &card_details,
                             payment_attempt,
                             (
                                 &mut surcharge_metadata,
@@ -181,6 +248,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                 let surcharge_details = surcharge_source
                     .generate_surcharge_details_and_populate_surcharge_metadata(
                         &backend_input,
+                        &SurchargeCardDetails::default(),
                         payment_attempt,
                         (
                             &mut surcharge_metadata,
@@ -249,6 +317,7 @@ where
         backend_input.payment_method.payment_method = Some(payment_method_type.to_owned().into());
         surcharge_source.generate_surcharge_details_and_populate_surcharge_metadata(
             &backend_input,
+            &SurchargeCardDetails::default(),
             &payment_data.payment_attempt,
             (
                 &mut surcharge_metadata,
@@ -307,9 +376,24 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
                     .change_context(ConfigError::DslExecutionError)
             })
             .transpose()?;
+        let card_isin = customer_payment_method
+            .card
+            .as_ref()
+            .and_then(|card| card.card_isin.clone());
+        let card_info = match card_isin.as_deref() {
+            Some(card_isin) => get_card_info(state, card_isin).await,
+            None => None,
+        };
+        backend_input.payment.card_bin = card_isin;
+        let card_details = SurchargeCardDetails::new(
+            card_info.as_ref(),
+            customer_payment_method.payment_method_type,
+            None,
+        );
         let surcharge_details = surcharge_source
             .generate_surcharge_details_and_populate_surcharge_metadata(
                 &backend_input,
+                &card_details,
                 payment_attempt,
                 (
                     &mut surcharge_metadata,
@@ -361,10 +445,134 @@ fn get_surcharge_details_from_surcharge_output(
         tax_on_surcharge: surcharge_details.tax_on_surcharge,
         surcharge_amount,
         tax_on_surcharge_amount,
-        final_amount: payment_attempt.amount + surcharge_amount + tax_on_surcharge_amount,
+        final_amount: money::calculate_net_amount(
+            payment_attempt.amount,
+            Some(surcharge_amount),
+            Some(tax_on_surcharge_amount),
+        )
+        .change_context(ConfigError::DslExecutionError)
+        .attach_printable("Failed to calculate the final amount")?,
+        capped_jurisdiction: None,
     })
 }
 
+/// Limits the surcharge to the lowest of the legal caps of the jurisdiction the card was issued
+/// in. The tax on the surcharge is computed again on the capped surcharge.
+fn apply_surcharge_caps(
+    surcharge_details: types::SurchargeDetails,
+    merchant_surcharge_configs: &surcharge_decision_configs::MerchantSurchargeConfigs,
+    card_details: &SurchargeCardDetails,
+) -> ConditionalConfigResult<types::SurchargeDetails> {
+    let Some(card_issuing_country) = card_details.card_issuing_country else {
+        return Ok(surcharge_details);
+    };
+
+    let mut max_surcharge_amount: Option<MinorUnit> = None;
+    for surcharge_cap in merchant_surcharge_configs
+        .surcharge_caps
+        .iter()
+        .flatten()
+        .filter(|surcharge_cap| {
+            surcharge_cap.is_applicable(card_issuing_country, card_details.card_funding_type)
+        })
+    {
+        let percentage_cap_amount = surcharge_cap
+            .max_percentage
+            .as_ref()
+            .map(|max_percentage| {
+                max_percentage
+                    .apply_and_ceil_result(surcharge_details.original_amount)
+                    .change_context(ConfigError::DslExecutionError)
+                    .attach_printable("Failed to calculate the surcharge cap amount")
+            })
+            .transpose()?;
+        for cap_amount in percentage_cap_amount
+            .into_iter()
+            .chain(surcharge_cap.max_amount)
+        {
+            max_surcharge_amount = match max_surcharge_amount {
+                Some(max_amount) if max_amount <= cap_amount => Some(max_amount),
+                _ => Some(cap_amount),
+            };
+        }
+    }
+
+    match max_surcharge_amount {
+        Some(max_surcharge_amount) if surcharge_details.surcharge_amount > max_surcharge_amount => {
+            let tax_on_surcharge_amount = surcharge_details
+                .tax_on_surcharge
+                .as_ref()
+                .map(|tax_on_surcharge| {
+                    tax_on_surcharge
+                        .apply_and_ceil_result(max_surcharge_amount)
+                        .change_context(ConfigError::DslExecutionError)
+                        .attach_printable("Failed to Calculate tax amount")
+                })
+                .transpose()?
+                .unwrap_or_default();
+            Ok(types::SurchargeDetails {
+                surcharge: common_utils_types::Surcharge::Fixed(max_surcharge_amount),
+                surcharge_amount: max_surcharge_amount,
+                tax_on_surcharge_amount,
+                final_amount: money::calculate_net_amount(
+                    surcharge_details.original_amount,
+                    Some(max_surcharge_amount),
+                    Some(tax_on_surcharge_amount),
+                )
+                .change_context(ConfigError::DslExecutionError)
+                .attach_printable("Failed to calculate the final amount")?,
+                capped_jurisdiction: Some(card_issuing_country),
+                ..surcharge_details
+            })
+        }
+        _ => Ok(surcharge_details),
+    }
+}
+
+/// Applies the legal surcharge caps of the jurisdiction of the card used to confirm the payment
+/// to the surcharge listed for it, so that the surcharge applied never exceeds what is allowed
+/// for the card presented, even when it was listed against the billing country
+#[instrument(skip_all)]
+pub async fn enforce_surcharge_caps(
+    state: &SessionState,
+    algorithm_ref: routing::RoutingAlgorithmRef,
+    merchant_id: &str,
+    surcharge_details: types::SurchargeDetails,
+    card_details: &SurchargeCardDetails,
+) -> ConditionalConfigResult<types::SurchargeDetails> {
+    let Some(algorithm_id) = algorithm_ref.surcharge_config_algo_id else {
+        return Ok(surcharge_details);
+    };
+    let cached_algo =
+        ensure_algorithm_cached(&*state.store, merchant_id, algorithm_id.as_str()).await?;
+    let listed_surcharge_amount = surcharge_details.surcharge_amount;
+    let surcharge_details = apply_surcharge_caps(
+        surcharge_details,
+        &cached_algo.merchant_surcharge_configs,
+        card_details,
+    )?;
+    if surcharge_details.surcharge_amount != listed_surcharge_amount {
+        logger::info!(
+            ?listed_surcharge_amount,
+            capped_surcharge_amount = ?surcharge_details.surcharge_amount,
+            capped_jurisdiction = ?surcharge_details.capped_jurisdiction,
+            "Capped the listed surcharge for the jurisdiction of the card"
+        );
+    }
+    Ok(surcharge_details)
+}
+
+/// Looks up the BIN data of a card, the surcharge is computed without it when it is not found
+pub async fn get_card_info(state: &SessionState, card_isin: &str) -> Option<storage::CardInfo> {
+    state
+        .store
+        .get_card_info(card_isin)
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()
+}
+
 #[instrument(skip_all)]
 pub async fn ensure_algorithm_cached(
     store: &dyn StorageInterface,
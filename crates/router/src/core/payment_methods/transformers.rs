@@ -358,6 +358,9 @@ pub fn mk_add_card_response_hs(
         card_issuer: card.card_issuer,
         card_network: card.card_network,
         card_type: card.card_type,
+        card_tier: None,
+        card_funding_type: None,
+        is_regulated: None,
         saved_to_locker: true,
     };
     api::PaymentMethodResponse {
@@ -536,6 +539,9 @@ pub fn get_card_detail(
         card_issuer: None,
         card_network: None,
         card_type: None,
+        card_tier: None,
+        card_funding_type: None,
+        is_regulated: None,
         saved_to_locker: true,
     };
     Ok(card_detail)
@@ -1,13 +1,19 @@
 pub mod access_token;
+pub mod async_confirmation;
+pub mod capture_schedule;
 pub mod conditional_configs;
+pub mod connector_failover;
 pub mod connector_integration_v2_impls;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
+pub mod metadata_update;
 pub mod operations;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod status_history;
+pub mod tax_calculation;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -27,7 +33,7 @@ use api_models::{
 };
 use common_utils::{
     ext_traits::{AsyncExt, StringExt},
-    pii,
+    money, pii,
     types::{MinorUnit, Surcharge},
 };
 use diesel_models::{ephemeral_key, fraud_check::FraudCheck};
@@ -41,7 +47,7 @@ pub use hyperswitch_domain_models::{
     router_data::RouterData,
     router_request_types::CustomerDetails,
 };
-use masking::{ExposeInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use redis_interface::errors::RedisError;
 use router_env::{instrument, metrics::add_attributes, tracing};
 #[cfg(feature = "olap")]
@@ -64,7 +70,8 @@ use self::{
     routing::{self as self_routing, SessionFlowRoutingInput},
 };
 use super::{
-    errors::StorageErrorExt, payment_methods::surcharge_decision_configs, routing::TransactionData,
+    connector_polling, errors::StorageErrorExt, payment_methods::surcharge_decision_configs,
+    routing::TransactionData,
 };
 #[cfg(feature = "frm")]
 use crate::core::fraud_check as frm_core;
@@ -74,9 +81,10 @@ use crate::{
     core::{
         authentication as authentication_core,
         errors::{self, CustomResult, RouterResponse, RouterResult},
-        utils,
+        fraud_rules as fraud_rules_core, ledger, utils, velocity_limits, wallet_decryption_keys,
     },
     db::StorageInterface,
+    events::domain_events::DomainEvent,
     logger,
     routes::{app::ReqState, metrics, payment_methods::ParentPaymentMethodToken, SessionState},
     services::{self, api::Authenticate, ConnectorRedirectResponse},
@@ -92,7 +100,6 @@ use crate::{
         add_apple_pay_flow_metrics, add_connector_http_status_code_metrics, Encode, OptionExt,
         ValueExt,
     },
-    workflows::payment_sync,
 };
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
@@ -173,6 +180,17 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
+    if velocity_limits::is_velocity_limited_operation(&operation) {
+        Box::pin(velocity_limits::enforce_velocity_limits(
+            state,
+            &merchant_account,
+            &key_store,
+            &business_profile,
+            &mut payment_data,
+        ))
+        .await?;
+    }
+
     call_decision_manager(state, &merchant_account, &mut payment_data).await?;
 
     let connector = get_connector_choice(
@@ -238,6 +256,24 @@ where
             should_continue_capture,
         );
 
+        // The fraud rules of the profile are evaluated only when the payment was not already
+        // checked by an external FRM connector
+        let fraud_rules_suggestion = if should_continue_transaction
+            && payment_data.frm_message.is_none()
+            && is_operation_confirm(&operation)
+        {
+            Box::pin(fraud_rules_core::perform_fraud_rules(
+                state,
+                &merchant_account,
+                &business_profile,
+                &mut payment_data,
+                &mut should_continue_transaction,
+            ))
+            .await?
+        } else {
+            None
+        };
+
         operation
             .to_domain()?
             .call_external_three_ds_authentication_if_eligible(
@@ -272,8 +308,9 @@ where
             payment_data = match connector_details {
                 ConnectorCallType::PreDetermined(connector) => {
                     let schedule_time = if should_add_task_to_process_tracker {
-                        payment_sync::get_sync_process_schedule_time(
+                        connector_polling::get_polling_schedule_time(
                             &*state.store,
+                            connector_polling::PollingObject::Payment,
                             connector.connector.id(),
                             &merchant_account.merchant_id,
                             0,
@@ -305,6 +342,36 @@ where
                     )
                     .await?;
 
+                    let router_data = match connector_failover::get_outage_failover_policy(
+                        &business_profile,
+                        &router_data,
+                    ) {
+                        Some(failover_policy) => {
+                            connector_failover::handle_connector_outage(
+                                state,
+                                req_state.clone(),
+                                &mut payment_data,
+                                failover_policy,
+                                Vec::new().into_iter(),
+                                connector.clone(),
+                                router_data,
+                                &merchant_account,
+                                &key_store,
+                                &operation,
+                                &customer,
+                                &validate_result,
+                                schedule_time,
+                                #[cfg(feature = "frm")]
+                                frm_info.as_ref().and_then(|fi| fi.suggested_action),
+                                #[cfg(not(feature = "frm"))]
+                                None,
+                                &business_profile,
+                            )
+                            .await?
+                        }
+                        None => router_data,
+                    };
+
                     let operation = Box::new(PaymentResponse);
 
                     connector_http_status_code = router_data.connector_http_status_code;
@@ -338,13 +405,20 @@ where
                 }
 
                 ConnectorCallType::Retryable(connectors) => {
-                    let mut connectors = connectors.into_iter();
+                    let mut connectors = connector_failover::prioritize_available_connectors(
+                        state,
+                        &business_profile,
+                        connectors,
+                    )
+                    .await
+                    .into_iter();
 
                     let connector_data = get_connector_data(&mut connectors)?;
 
                     let schedule_time = if should_add_task_to_process_tracker {
-                        payment_sync::get_sync_process_schedule_time(
+                        connector_polling::get_polling_schedule_time(
                             &*state.store,
+                            connector_polling::PollingObject::Payment,
                             connector_data.connector.id(),
                             &merchant_account.merchant_id,
                             0,
@@ -376,22 +450,16 @@ where
                     )
                     .await?;
 
-                    #[cfg(feature = "retry")]
-                    let mut router_data = router_data;
-                    #[cfg(feature = "retry")]
-                    {
-                        use crate::core::payments::retry::{self, GsmValidation};
-                        let config_bool = retry::config_should_call_gsm(
-                            &*state.store,
-                            &merchant_account.merchant_id,
-                        )
-                        .await;
-
-                        if config_bool && router_data.should_call_gsm() {
-                            router_data = retry::do_gsm_actions(
+                    let router_data = match connector_failover::get_outage_failover_policy(
+                        &business_profile,
+                        &router_data,
+                    ) {
+                        Some(failover_policy) => {
+                            connector_failover::handle_connector_outage(
                                 state,
                                 req_state.clone(),
                                 &mut payment_data,
+                                failover_policy,
                                 connectors,
                                 connector_data.clone(),
                                 router_data,
@@ -407,9 +475,47 @@ where
                                 None,
                                 &business_profile,
                             )
-                            .await?;
-                        };
-                    }
+                            .await?
+                        }
+                        None => {
+                            #[cfg(feature = "retry")]
+                            let mut router_data = router_data;
+                            #[cfg(feature = "retry")]
+                            {
+                                use crate::core::payments::retry::{self, GsmValidation};
+                                let config_bool = retry::config_should_call_gsm(
+                                    &*state.store,
+                                    &merchant_account.merchant_id,
+                                    payment_data.payment_intent.profile_id.as_deref(),
+                                )
+                                .await;
+
+                                if config_bool && router_data.should_call_gsm() {
+                                    router_data = retry::do_gsm_actions(
+                                        state,
+                                        req_state.clone(),
+                                        &mut payment_data,
+                                        connectors,
+                                        connector_data.clone(),
+                                        router_data,
+                                        &merchant_account,
+                                        &key_store,
+                                        &operation,
+                                        &customer,
+                                        &validate_result,
+                                        schedule_time,
+                                        #[cfg(feature = "frm")]
+                                        frm_info.as_ref().and_then(|fi| fi.suggested_action),
+                                        #[cfg(not(feature = "frm"))]
+                                        None,
+                                        &business_profile,
+                                    )
+                                    .await?;
+                                };
+                            }
+                            router_data
+                        }
+                    };
 
                     let operation = Box::new(PaymentResponse);
                     connector_http_status_code = router_data.connector_http_status_code;
@@ -498,9 +604,11 @@ where
                     None,
                     &key_store,
                     #[cfg(feature = "frm")]
-                    frm_info.and_then(|info| info.suggested_action),
+                    frm_info
+                        .and_then(|info| info.suggested_action)
+                        .or(fraud_rules_suggestion),
                     #[cfg(not(feature = "frm"))]
-                    None,
+                    fraud_rules_suggestion,
                     header_payload.clone(),
                 )
                 .await?;
@@ -610,6 +718,7 @@ where
 #[instrument(skip_all)]
 async fn populate_surcharge_details<F>(
     state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
     payment_data: &mut PaymentData<F>,
 ) -> RouterResult<()>
 where
@@ -627,17 +736,41 @@ where
             payment_data.surcharge_details = Some(surcharge_details);
             return Ok(());
         }
+        let card_info = match payment_data.payment_method_data.as_ref() {
+            Some(api_models::payments::PaymentMethodData::Card(card)) => {
+                surcharge_decision_configs::get_card_info(state, &card.card_number.get_card_isin())
+                    .await
+            }
+            _ => None,
+        };
+        let billing_country = payment_data
+            .address
+            .get_payment_method_billing()
+            .and_then(|billing_address| billing_address.address.as_ref())
+            .and_then(|address| address.country);
+        let card_details = surcharge_decision_configs::SurchargeCardDetails::new(
+            card_info.as_ref(),
+            None,
+            billing_country,
+        );
         let raw_card_key = payment_data
             .payment_method_data
             .as_ref()
             .and_then(get_key_params_for_surcharge_details)
             .map(|(payment_method, payment_method_type, card_network)| {
+                let payment_method_type = match payment_method {
+                    storage_enums::PaymentMethod::Card => card_details
+                        .get_card_payment_method_type()
+                        .unwrap_or(payment_method_type),
+                    _ => payment_method_type,
+                };
                 types::SurchargeKey::PaymentMethodData(
                     payment_method,
                     payment_method_type,
                     card_network,
                 )
             });
+        let is_raw_card_key = raw_card_key.is_some();
         let saved_card_key = payment_data.token.clone().map(types::SurchargeKey::Token);
 
         let surcharge_key = raw_card_key
@@ -660,6 +793,34 @@ where
                 }
             };
 
+        // The surcharges of raw cards are listed before the card is known, the caps of the
+        // jurisdiction of the card are enforced once the card is presented
+        let calculated_surcharge_details = match calculated_surcharge_details {
+            Some(surcharge_details) if is_raw_card_key => {
+                let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
+                    .routing_algorithm
+                    .clone()
+                    .map(|val| val.parse_value("routing algorithm"))
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not decode the routing algorithm")?
+                    .unwrap_or_default();
+                Some(
+                    surcharge_decision_configs::enforce_surcharge_caps(
+                        state,
+                        algorithm_ref,
+                        &merchant_account.merchant_id,
+                        surcharge_details,
+                        &card_details,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to apply the surcharge caps")?,
+                )
+            }
+            surcharge_details => surcharge_details,
+        };
+
         payment_data.surcharge_details = calculated_surcharge_details;
     } else {
         let surcharge_details =
@@ -699,8 +860,13 @@ where
 {
     if let Some(surcharge_amount) = payment_data.payment_attempt.surcharge_amount {
         let tax_on_surcharge_amount = payment_data.payment_attempt.tax_amount.unwrap_or_default();
-        let final_amount =
-            payment_data.payment_attempt.amount + surcharge_amount + tax_on_surcharge_amount;
+        let final_amount = money::calculate_net_amount(
+            payment_data.payment_attempt.amount,
+            Some(surcharge_amount),
+            Some(tax_on_surcharge_amount),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to calculate the final amount with the surcharge")?;
         Ok(Some(api::SessionSurchargeDetails::PreDetermined(
             types::SurchargeDetails {
                 original_amount: payment_data.payment_attempt.amount,
@@ -709,6 +875,7 @@ where
                 surcharge_amount,
                 tax_on_surcharge_amount,
                 final_amount,
+                capped_jurisdiction: None,
             },
         )))
     } else {
@@ -793,6 +960,13 @@ where
         )
         .await?;
 
+    ledger::record_payment_entries(
+        &state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await;
+
     Res::generate_response(
         payment_data,
         customer,
@@ -806,6 +980,87 @@ where
     )
 }
 
+/// Approves a payment held for review by the FRM connector. Payments whose automatic capture was
+/// deferred until the post-authorization verdict of the FRM connector are captured once approved.
+pub async fn payments_approve_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentsCaptureRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    #[cfg(feature = "frm")]
+    if let Some(payments_response) = Box::pin(frm_core::post_auth_review::resolve_post_auth_review(
+        &state,
+        req_state.clone(),
+        &merchant_account,
+        &key_store,
+        &req.payment_id,
+        frm_core::post_auth_review::PostAuthReviewResolution::Approved,
+        None,
+    ))
+    .await?
+    {
+        return Ok(payments_response);
+    }
+
+    Box::pin(
+        payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+            state,
+            req_state,
+            merchant_account,
+            key_store,
+            PaymentApprove,
+            req,
+            services::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+            None,
+            HeaderPayload::default(),
+        ),
+    )
+    .await
+}
+
+/// Rejects a payment held for review by the FRM connector. The authorization of payments whose
+/// automatic capture was deferred until the post-authorization verdict of the FRM connector is
+/// voided once rejected.
+pub async fn payments_reject_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentsCancelRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    #[cfg(feature = "frm")]
+    if let Some(payments_response) = Box::pin(frm_core::post_auth_review::resolve_post_auth_review(
+        &state,
+        req_state.clone(),
+        &merchant_account,
+        &key_store,
+        &req.payment_id,
+        frm_core::post_auth_review::PostAuthReviewResolution::Declined,
+        req.cancellation_reason.clone(),
+    ))
+    .await?
+    {
+        return Ok(payments_response);
+    }
+
+    Box::pin(payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        PaymentReject,
+        req,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await
+}
+
 fn is_start_pay<Op: Debug>(operation: &Op) -> bool {
     format!("{operation:?}").eq("PaymentStart")
 }
@@ -1377,7 +1632,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
 }
 
 #[allow(clippy::too_many_arguments)]
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(connector = %connector.connector_name))]
 pub async fn call_connector_service<F, RouterDReq, ApiRequest>(
     state: &SessionState,
     req_state: ReqState,
@@ -1407,6 +1662,9 @@ where
 {
     let stime_connector = Instant::now();
 
+    let state_with_debug_capture = state.with_connector_debug_capture(business_profile);
+    let state = state_with_debug_capture.as_ref();
+
     let merchant_connector_account = construct_profile_id_and_get_mca(
         state,
         merchant_account,
@@ -1453,7 +1711,27 @@ where
     )
     .await?;
 
-    let mut router_data = payment_data
+    // The wallet token decrypted with the keys of the business profile is forwarded as card
+    // details to the connectors lacking native wallet support, the payment data keeping the
+    // wallet token for the retries on other connectors
+    let decrypted_wallet_payment_method_data =
+        wallet_decryption_keys::get_decrypted_wallet_payment_method_data(
+            state,
+            &connector.connector_name.to_string(),
+            &business_profile.profile_id,
+            payment_data.payment_method_data.as_ref(),
+            key_store,
+        )
+        .await?;
+    let wallet_payment_method_data =
+        decrypted_wallet_payment_method_data.map(|payment_method_data| {
+            std::mem::replace(
+                &mut payment_data.payment_method_data,
+                Some(payment_method_data),
+            )
+        });
+
+    let router_data = payment_data
         .construct_router_data(
             state,
             connector.connector.id(),
@@ -1462,7 +1740,11 @@ where
             customer,
             &merchant_connector_account,
         )
-        .await?;
+        .await;
+    if let Some(wallet_payment_method_data) = wallet_payment_method_data {
+        payment_data.payment_method_data = wallet_payment_method_data;
+    }
+    let mut router_data = router_data?;
 
     let add_access_token_result = router_data
         .add_access_token(
@@ -2128,6 +2410,42 @@ fn decide_apple_pay_flow(
     })
 }
 
+/// The flow decrypting the Apple Pay token with the payment processing certificate registered on
+/// the business profile, when the token was encrypted for one of the certificates of the profile
+async fn decide_apple_pay_flow_with_profile_keys<F: Clone>(
+    state: &SessionState,
+    payment_method_type: &Option<enums::PaymentMethodType>,
+    payment_data: &PaymentData<F>,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<domain::ApplePayFlow>> {
+    let (Some(enums::PaymentMethodType::ApplePay), Some(profile_id), Some(payment_method_data)) = (
+        payment_method_type,
+        payment_data.payment_intent.profile_id.as_ref(),
+        payment_data.payment_method_data.clone(),
+    ) else {
+        return Ok(None);
+    };
+    let domain::PaymentMethodData::Wallet(domain::WalletData::ApplePay(wallet_data)) =
+        domain::PaymentMethodData::from(payment_method_data)
+    else {
+        return Ok(None);
+    };
+    // Tokens that cannot be parsed are left to the flows decrypting them to be rejected
+    let Ok(apple_pay_data) = ApplePayData::token_json(domain::WalletData::ApplePay(wallet_data))
+    else {
+        return Ok(None);
+    };
+
+    Ok(wallet_decryption_keys::find_apple_pay_processing_details(
+        state,
+        profile_id,
+        apple_pay_data.public_key_hash().peek(),
+        merchant_key_store,
+    )
+    .await?
+    .map(domain::ApplePayFlow::Simplified))
+}
+
 fn check_apple_pay_metadata(
     state: &SessionState,
     merchant_connector_account: Option<&helpers::MerchantConnectorAccountType>,
@@ -2321,8 +2639,21 @@ where
                 .get_required_value("payment_method")?;
             let payment_method_type = &payment_data.payment_attempt.payment_method_type;
 
-            let apple_pay_flow =
-                decide_apple_pay_flow(state, payment_method_type, Some(merchant_connector_account));
+            let apple_pay_flow = match decide_apple_pay_flow_with_profile_keys(
+                state,
+                payment_method_type,
+                payment_data,
+                merchant_key_store,
+            )
+            .await?
+            {
+                Some(apple_pay_flow) => Some(apple_pay_flow),
+                None => decide_apple_pay_flow(
+                    state,
+                    payment_method_type,
+                    Some(merchant_connector_account),
+                ),
+            };
 
             let is_connector_tokenization_enabled =
                 is_payment_method_tokenization_enabled_for_connector(
@@ -2510,6 +2841,8 @@ where
     pub authentication: Option<storage::Authentication>,
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
+    /// The amount of the authorization to release, when only part of the authorization is voided
+    pub amount_to_reverse: Option<MinorUnit>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -2518,8 +2851,20 @@ pub struct PaymentEvent {
     payment_attempt: storage::PaymentAttempt,
 }
 
+impl PaymentEvent {
+    pub fn new(
+        payment_intent: storage::PaymentIntent,
+        payment_attempt: storage::PaymentAttempt,
+    ) -> Self {
+        Self {
+            payment_intent,
+            payment_attempt,
+        }
+    }
+}
+
 impl<F: Clone> PaymentData<F> {
-    fn to_event(&self) -> PaymentEvent {
+    pub fn to_event(&self) -> PaymentEvent {
         PaymentEvent {
             payment_intent: self.payment_intent.clone(),
             payment_attempt: self.payment_attempt.clone(),
@@ -2742,6 +3087,11 @@ pub async fn apply_filters_on_payments(
 ) -> RouterResponse<api::PaymentListResponseV2> {
     let limit = &constraints.limit;
     helpers::validate_payment_list_request_for_joins(*limit)?;
+    if constraints.offset.is_some() && constraints.cursor.is_some() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "only one of offset and cursor can be provided".to_string(),
+        })?
+    }
     let db = state.store.as_ref();
     let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
         .get_filtered_payment_intents_attempt(
@@ -2753,6 +3103,15 @@ pub async fn apply_filters_on_payments(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // A full page may be followed by more payments, which are fetched from the last payment of
+    // the page
+    let next_cursor = list
+        .last()
+        .filter(|_| list.len() >= usize::try_from(*limit).unwrap_or(usize::MAX))
+        .map(|(payment_intent, _)| {
+            api::ListCursor::new(payment_intent.created_at, payment_intent.payment_id.clone())
+        });
+
     let data: Vec<api::PaymentsResponse> =
         list.into_iter().map(ForeignFrom::foreign_from).collect();
 
@@ -2774,6 +3133,7 @@ pub async fn apply_filters_on_payments(
             constraints.payment_method_type,
             constraints.authentication_type,
             constraints.merchant_connector_id,
+            constraints.card_network,
             merchant.storage_scheme,
         )
         .await
@@ -2784,6 +3144,7 @@ pub async fn apply_filters_on_payments(
             count: data.len(),
             total_count,
             data,
+            next_cursor,
         },
     ))
 }
@@ -2986,6 +3347,7 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
 pub async fn get_connector_choice<F, Req>(
     operation: &BoxedOperation<'_, F, Req>,
     state: &SessionState,
@@ -3141,6 +3503,7 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
 pub async fn decide_connector<F>(
     state: SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -3250,8 +3613,12 @@ where
             }
 
             #[cfg(all(feature = "retry", feature = "connector_choice_mca_id"))]
-            let should_do_retry =
-                retry::config_should_call_gsm(&*state.store, &merchant_account.merchant_id).await;
+            let should_do_retry = retry::config_should_call_gsm(
+                &*state.store,
+                &merchant_account.merchant_id,
+                payment_data.payment_intent.profile_id.as_deref(),
+            )
+            .await;
 
             #[cfg(all(feature = "retry", feature = "connector_choice_mca_id"))]
             if payment_data.payment_attempt.payment_method_type
@@ -3632,14 +3999,9 @@ pub fn is_network_transaction_id_flow(
 }
 
 pub fn should_add_task_to_process_tracker<F: Clone>(payment_data: &PaymentData<F>) -> bool {
-    let connector = payment_data.payment_attempt.connector.as_deref();
-
-    !matches!(
-        (payment_data.payment_attempt.payment_method, connector),
-        (
-            Some(storage_enums::PaymentMethod::BankTransfer),
-            Some("stripe")
-        )
+    connector_polling::is_payment_polling_required(
+        payment_data.payment_attempt.connector.as_deref(),
+        payment_data.payment_attempt.payment_method,
     )
 }
 
@@ -3776,6 +4138,7 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
 pub async fn route_connector_v1<F>(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -3820,6 +4183,7 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not decode merchant routing algorithm ref")?
         .unwrap_or_default();
+    let algorithm_id = algorithm_ref.algorithm_id.clone();
 
     let connectors = routing::perform_static_routing_v1(
         state,
@@ -3869,6 +4233,17 @@ where
 
     match transaction_data {
         TransactionData::Payment(payment_data) => {
+            if let Some(domain_events) = &state.domain_events {
+                domain_events.publish(DomainEvent::routing_decision(
+                    &payment_data.payment_intent,
+                    algorithm_id,
+                    connector_data
+                        .iter()
+                        .map(|connector| connector.connector_name.to_string())
+                        .collect(),
+                ));
+            }
+
             decide_multiplex_connector_for_normal_or_recurring_payment(
                 state,
                 payment_data,
@@ -4066,6 +4441,10 @@ pub async fn payment_external_authentication(
             .attach_printable(
                 "Error while parsing authentication_connector_details from business_profile",
             )?;
+    let challenge_preference = authentication_core::decision::get_challenge_preference(
+        &authentication_details,
+        Some(amount),
+    );
 
     let authentication_response = Box::pin(authentication_core::perform_authentication(
         &state,
@@ -4093,6 +4472,7 @@ pub async fn payment_external_authentication(
         optional_customer.and_then(|customer| customer.email.map(pii::Email::from)),
         webhook_url,
         authentication_details.three_ds_requestor_url.clone(),
+        challenge_preference,
     ))
     .await?;
     Ok(services::ApplicationResponse::Json(
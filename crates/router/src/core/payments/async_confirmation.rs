@@ -0,0 +1,512 @@
+use api_models::payments::HeaderPayload;
+use common_utils::ext_traits::Encode;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers, operations::Operation},
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums},
+    },
+    utils::OptionExt,
+};
+
+pub const ASYNC_CONFIRMATION_TASK: &str = "ASYNC_CONFIRMATION";
+pub const ASYNC_CONFIRMATION_TAG: &str = "PAYMENT";
+
+/// The number of times the confirmation of a payment is retried when it fails on an internal
+/// error, before the payment is failed
+pub const MAX_ASYNC_CONFIRMATION_RETRIES: i32 = 3;
+
+/// The delay before the confirmation of a payment is retried, multiplied by the number of the
+/// retry
+pub const ASYNC_CONFIRMATION_RETRY_DELAY: time::Duration = time::Duration::seconds(30);
+
+/// The error code the payments failed in the background without reaching the connector are
+/// marked with
+pub const ASYNC_CONFIRMATION_FAILED_ERROR_CODE: &str = "ASYNC_CONFIRMATION_FAILED";
+
+/// The statuses of the attempts yet to be sent to the connector
+const UNCONFIRMED_ATTEMPT_STATUSES: [enums::AttemptStatus; 3] = [
+    enums::AttemptStatus::Started,
+    enums::AttemptStatus::PaymentMethodAwaited,
+    enums::AttemptStatus::ConfirmationAwaited,
+];
+
+pub fn generate_task_id_for_async_confirmation_workflow(payment_id: &str) -> String {
+    format!(
+        "{runner}_{task}_{payment_id}",
+        runner = storage::ProcessTrackerRunner::AsyncConfirmationWorkflow,
+        task = ASYNC_CONFIRMATION_TASK
+    )
+}
+
+/// Validates that the payment confirmed by the request can be confirmed in the background. The
+/// request is kept until the payment is confirmed, so it must not hold card details, and the
+/// payment must be confirmed by the merchant since no customer is present to authenticate.
+pub fn validate_async_confirmation(
+    request: &api::PaymentsRequest,
+    auth_flow: services::AuthFlow,
+) -> RouterResult<()> {
+    if auth_flow != services::AuthFlow::Merchant {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The async processing_mode is only supported for the payments confirmed \
+                with the API key"
+                .to_string(),
+        }));
+    }
+    let unsupported_field = if request.payment_method_data.is_some() {
+        Some("payment_method_data")
+    } else if request.card_cvc.is_some() {
+        Some("card_cvc")
+    } else if request.merchant_connector_details.is_some() {
+        Some("merchant_connector_details")
+    } else {
+        None
+    };
+    match unsupported_field {
+        Some(field_name) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} can not be provided with the async processing_mode"),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// The request the payment is confirmed with in the background. The details of the customer and
+/// the addresses are saved with the payment before it is scheduled, so they are not kept with
+/// the scheduled confirmation.
+fn get_deferred_confirmation_request(
+    request: &api::PaymentsRequest,
+    payment_id: &str,
+) -> api::PaymentsRequest {
+    api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.to_owned())),
+        confirm: Some(true),
+        processing_mode: None,
+        billing: None,
+        shipping: None,
+        customer: None,
+        email: None,
+        name: None,
+        phone: None,
+        phone_country_code: None,
+        ..request.clone()
+    }
+}
+
+/// Saves the payment with the details of the request and schedules its confirmation in the
+/// background. The payment is created by the `PaymentCreate` operation, or updated by the
+/// `PaymentUpdate` operation, and returned with the `processing` status.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn payments_confirm_async<Op>(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    operation: Op,
+    request: api::PaymentsRequest,
+    auth_flow: services::AuthFlow,
+    header_payload: HeaderPayload,
+) -> RouterResponse<api::PaymentsResponse>
+where
+    Op: Operation<api::Authorize, api::PaymentsRequest> + Send + Sync + Clone + std::fmt::Debug,
+{
+    validate_async_confirmation(&request, auth_flow)?;
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        key_store.clone(),
+        operation,
+        api::PaymentsRequest {
+            confirm: Some(false),
+            processing_mode: None,
+            ..request.clone()
+        },
+        auth_flow,
+        payments::CallConnectorAction::Trigger,
+        None,
+        header_payload,
+    ))
+    .await?;
+
+    let mut payments_response = match payments_response {
+        ApplicationResponse::JsonWithHeaders((payments_response, _))
+        | ApplicationResponse::Json(payments_response) => payments_response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the payment operation")?,
+    };
+    let payment_id = payments_response
+        .payment_id
+        .clone()
+        .get_required_value("payment_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    schedule_async_confirmation(
+        &state,
+        &merchant_account,
+        &key_store,
+        get_deferred_confirmation_request(&request, &payment_id),
+    )
+    .await?;
+
+    payments_response.status = enums::IntentStatus::Processing;
+    Ok(ApplicationResponse::Json(payments_response))
+}
+
+/// Marks the payment as processing and schedules its confirmation with the request provided. The
+/// status of the payment is restored if the confirmation could not be scheduled.
+#[instrument(skip_all)]
+async fn schedule_async_confirmation(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    request: api::PaymentsRequest,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_id = request
+        .payment_id
+        .as_ref()
+        .get_required_value("payment_id")?
+        .get_payment_intent_id()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ],
+        "confirm",
+    )?;
+    let previous_status = payment_intent.status;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Processing,
+                incremental_authorization_allowed: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let tracking_data = storage::AsyncConfirmationTrackingData {
+        merchant_id: merchant_account.merchant_id.clone(),
+        payment_id: payment_id.clone(),
+        request,
+    };
+    if let Err(error) = insert_async_confirmation_task(db, &payment_id, tracking_data).await {
+        db.update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: previous_status,
+                incremental_authorization_allowed: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to restore the status of the payment"))
+        .ok();
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Schedules the confirmation of a payment immediately, replacing any confirmation previously
+/// scheduled for the payment
+async fn insert_async_confirmation_task(
+    db: &dyn StorageInterface,
+    payment_id: &str,
+    tracking_data: storage::AsyncConfirmationTrackingData,
+) -> RouterResult<()> {
+    let schedule_time = common_utils::date_time::now();
+    let process_tracker_id = generate_task_id_for_async_confirmation_workflow(payment_id);
+    match db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the scheduled confirmation")?
+    {
+        Some(process) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(schedule_time),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(storage::business_status::PENDING)),
+                        status: Some(enums::ProcessTrackerStatus::New),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the scheduled confirmation")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                ASYNC_CONFIRMATION_TASK,
+                storage::ProcessTrackerRunner::AsyncConfirmationWorkflow,
+                [ASYNC_CONFIRMATION_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct async confirmation process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the scheduled confirmation")?;
+        }
+    }
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "AsyncConfirmation")]),
+    );
+
+    Ok(())
+}
+
+/// Fetches the payment whose confirmation was scheduled, if it is still awaiting its
+/// confirmation. A payment whose attempt was sent to the connector is left to the payment sync.
+async fn find_unconfirmed_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+) -> RouterResult<Option<(storage::PaymentIntent, storage::PaymentAttempt)>> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if payment_intent.status != enums::IntentStatus::Processing {
+        return Ok(None);
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if !UNCONFIRMED_ATTEMPT_STATUSES.contains(&payment_attempt.status) {
+        return Ok(None);
+    }
+
+    Ok(Some((payment_intent, payment_attempt)))
+}
+
+/// Confirms a payment whose confirmation was scheduled, if it is still awaiting its
+/// confirmation. Returns `None` when the payment is no longer awaiting its confirmation.
+#[instrument(skip_all)]
+pub async fn confirm_scheduled_payment(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &storage::AsyncConfirmationTrackingData,
+) -> RouterResult<Option<ApplicationResponse<api::PaymentsResponse>>> {
+    if find_unconfirmed_payment(
+        state,
+        &merchant_account,
+        &key_store,
+        &tracking_data.payment_id,
+    )
+    .await?
+    .is_none()
+    {
+        return Ok(None);
+    }
+
+    let request = tracking_data.request.clone();
+    let eligible_connectors = request.connector.clone();
+    let header_payload = HeaderPayload::with_source(enums::PaymentSource::AsyncProcessing);
+    let payments_response = match request.payment_type.unwrap_or_default() {
+        api_models::enums::PaymentType::Normal
+        | api_models::enums::PaymentType::RecurringMandate
+        | api_models::enums::PaymentType::NewMandate => {
+            Box::pin(payments::payments_core::<
+                api::Authorize,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account,
+                key_store,
+                payments::PaymentConfirm,
+                request,
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                eligible_connectors,
+                header_payload,
+            ))
+            .await?
+        }
+        api_models::enums::PaymentType::SetupMandate => {
+            Box::pin(payments::payments_core::<
+                api::SetupMandate,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account,
+                key_store,
+                payments::PaymentConfirm,
+                request,
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                eligible_connectors,
+                header_payload,
+            ))
+            .await?
+        }
+    };
+
+    Ok(Some(payments_response))
+}
+
+/// Fails a payment whose confirmation could not be completed in the background, if it is still
+/// awaiting its confirmation, and notifies the merchant through the webhooks
+#[instrument(skip_all)]
+pub async fn fail_scheduled_payment(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &storage::AsyncConfirmationTrackingData,
+    error_message: String,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+    let Some((payment_intent, payment_attempt)) = find_unconfirmed_payment(
+        state,
+        &merchant_account,
+        &key_store,
+        &tracking_data.payment_id,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::ErrorUpdate {
+            connector: None,
+            status: enums::AttemptStatus::Failure,
+            error_code: Some(Some(ASYNC_CONFIRMATION_FAILED_ERROR_CODE.to_string())),
+            error_message: Some(Some(error_message)),
+            error_reason: None,
+            amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
+            updated_by: storage_scheme.to_string(),
+            unified_code: None,
+            unified_message: None,
+            unified_error_details: None,
+            connector_transaction_id: None,
+            payment_method_data: None,
+            authentication_type: None,
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Failed,
+            incremental_authorization_allowed: Some(false),
+            updated_by: storage_scheme.to_string(),
+        },
+        &key_store,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // Retrieving the failed payment triggers the outgoing webhook notifying the merchant
+    Box::pin(payments::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        key_store,
+        payments::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Avoid,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,326 @@
+use api_models::payments::{
+    CaptureScheduleStatus, HeaderPayload, PaymentsCaptureScheduleRequest,
+    PaymentsCaptureScheduleResponse,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers},
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+pub const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+pub const SCHEDULED_CAPTURE_TAG: &str = "PAYMENT";
+
+pub fn generate_task_id_for_scheduled_capture_workflow(payment_id: &str) -> String {
+    format!(
+        "{runner}_{task}_{payment_id}",
+        runner = storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        task = SCHEDULED_CAPTURE_TASK
+    )
+}
+
+/// The time until which the authorization of a payment created at the time provided is expected
+/// to be held by the issuer
+fn get_authorization_expiry(payment_created_at: PrimitiveDateTime) -> PrimitiveDateTime {
+    payment_created_at.saturating_add(consts::MAX_CAPTURE_SCHEDULE_DELAY)
+}
+
+/// Validates that the capture of a payment with the capture method provided can be scheduled at
+/// the time provided, which must be in the future and before the authorization of the payment
+/// expires.
+pub fn validate_capture_at(
+    capture_at: PrimitiveDateTime,
+    capture_method: Option<enums::CaptureMethod>,
+    payment_created_at: PrimitiveDateTime,
+) -> RouterResult<()> {
+    if capture_method != Some(enums::CaptureMethod::Manual) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_at can only be provided for payments with a manual capture_method"
+                .to_string(),
+        }));
+    }
+    if capture_at <= common_utils::date_time::now() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_at must be in the future".to_string(),
+        }));
+    }
+    if capture_at > get_authorization_expiry(payment_created_at) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "capture_at must be within {} days of the creation of the payment",
+                consts::MAX_CAPTURE_SCHEDULE_DELAY.whole_days()
+            ),
+        }));
+    }
+    Ok(())
+}
+
+/// Schedules the capture of a payment at the time provided. A payment has a single capture
+/// scheduled at any time, scheduling a new one replaces the previous capture.
+#[instrument(skip_all)]
+pub async fn schedule_capture(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_id: &str,
+    capture_at: PrimitiveDateTime,
+    amount_to_capture: Option<MinorUnit>,
+) -> RouterResult<()> {
+    let tracking_data = storage::ScheduledCaptureTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        payment_id: payment_id.to_owned(),
+        amount_to_capture,
+    };
+
+    let process_tracker_id = generate_task_id_for_scheduled_capture_workflow(payment_id);
+    match db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the scheduled capture")?
+    {
+        Some(process) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: Some(0),
+                        schedule_time: Some(capture_at),
+                        tracking_data: Some(tracking_data),
+                        business_status: Some(String::from(storage::business_status::PENDING)),
+                        status: Some(enums::ProcessTrackerStatus::New),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the scheduled capture")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                SCHEDULED_CAPTURE_TASK,
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+                [SCHEDULED_CAPTURE_TAG],
+                tracking_data,
+                capture_at,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct scheduled capture process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the scheduled capture")?;
+        }
+    }
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "ScheduledCapture")]),
+    );
+
+    Ok(())
+}
+
+/// Schedules the capture of an authorized payment, or of a payment yet to be authorized, or
+/// reschedules its capture when one is already scheduled
+#[instrument(skip_all)]
+pub async fn payments_capture_schedule(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsCaptureScheduleRequest,
+) -> RouterResponse<PaymentsCaptureScheduleResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    helpers::validate_payment_status_against_not_allowed_statuses(
+        &payment_intent.status,
+        &[
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::Failed,
+            enums::IntentStatus::Cancelled,
+            enums::IntentStatus::PartiallyCaptured,
+            enums::IntentStatus::PartiallyCapturedAndCapturable,
+        ],
+        "schedule the capture",
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &req.payment_id,
+            merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    validate_capture_at(
+        req.capture_at,
+        payment_attempt.capture_method,
+        payment_intent.created_at,
+    )?;
+    if req
+        .amount_to_capture
+        .is_some_and(|amount_to_capture| amount_to_capture > payment_attempt.get_total_amount())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount_to_capture must not exceed the amount of the payment".to_string(),
+        }));
+    }
+
+    schedule_capture(
+        db,
+        merchant_id,
+        &req.payment_id,
+        req.capture_at,
+        req.amount_to_capture,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(PaymentsCaptureScheduleResponse {
+        payment_id: req.payment_id,
+        status: CaptureScheduleStatus::Scheduled,
+        capture_at: Some(req.capture_at),
+        amount_to_capture: req.amount_to_capture,
+    }))
+}
+
+/// Cancels the capture scheduled for a payment, which is then left to be captured through the
+/// capture endpoint
+#[instrument(skip_all)]
+pub async fn payments_capture_schedule_cancel(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<PaymentsCaptureScheduleResponse> {
+    let db = &*state.store;
+    let process_tracker_id = generate_task_id_for_scheduled_capture_workflow(&payment_id);
+    let process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the scheduled capture")?
+        .filter(|process| process.status != enums::ProcessTrackerStatus::Finish)
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No capture is scheduled for the payment".to_string(),
+        })?;
+    let tracking_data: storage::ScheduledCaptureTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("ScheduledCaptureTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    if tracking_data.merchant_id != merchant_account.merchant_id {
+        return Err(report!(errors::ApiErrorResponse::PaymentNotFound));
+    }
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![process_tracker_id],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to revoke the scheduled capture")?;
+
+    Ok(ApplicationResponse::Json(PaymentsCaptureScheduleResponse {
+        payment_id,
+        status: CaptureScheduleStatus::Cancelled,
+        capture_at: process.schedule_time,
+        amount_to_capture: tracking_data.amount_to_capture,
+    }))
+}
+
+/// Captures a payment whose capture was scheduled, if it is still awaiting its capture and its
+/// authorization has not expired. Returns `None` when the payment is no longer awaiting its
+/// capture, such as when it was captured or cancelled by the merchant in the meantime.
+#[instrument(skip_all)]
+pub async fn capture_scheduled_payment(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &storage::ScheduledCaptureTrackingData,
+) -> RouterResult<Option<services::ApplicationResponse<api::PaymentsResponse>>> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &tracking_data.payment_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Ok(None);
+    }
+    if common_utils::date_time::now() > get_authorization_expiry(payment_intent.created_at) {
+        logger::warn!(
+            payment_id = %tracking_data.payment_id,
+            "The authorization of the payment expired before its scheduled capture"
+        );
+        return Ok(None);
+    }
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Capture,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        key_store,
+        payments::PaymentCapture,
+        api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            amount_to_capture: tracking_data.amount_to_capture,
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    Ok(Some(payments_response))
+}
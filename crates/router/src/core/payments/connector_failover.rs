@@ -0,0 +1,367 @@
+use std::vec::IntoIter;
+
+use api_models::{
+    admin::{ConnectorCircuitBreakerConfig, ConnectorFailoverPolicy},
+    enums::ConnectorFailoverAction,
+};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+#[cfg(feature = "retry")]
+use crate::core::payments::retry;
+use crate::{
+    core::{
+        connector_polling,
+        errors::{self, RouterResult},
+        payments::{
+            self,
+            flows::{ConstructFlowSpecificData, Feature},
+            operations,
+        },
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{self, api, domain, storage},
+};
+
+/// Delay after which the payments queued on a connector outage are synchronized with the
+/// connector, when no schedule is configured for the connector
+const DEFAULT_QUEUED_PAYMENT_SYNC_DELAY_IN_SECS: i64 = 5 * 60;
+
+/// The failover policy of the profile. A policy that cannot be parsed is logged and ignored, the
+/// outages then being handled as if no policy was set.
+fn get_connector_failover_policy(
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> Option<ConnectorFailoverPolicy> {
+    business_profile
+        .connector_failover_policy
+        .clone()
+        .map(|policy| policy.parse_value("ConnectorFailoverPolicy"))
+        .transpose()
+        .map_err(|error| logger::error!(?error, "Failed to parse the connector failover policy"))
+        .ok()
+        .flatten()
+}
+
+/// Whether the connector was unavailable: the request failed on the network, timed out or was
+/// answered with a 5xx response
+fn is_connector_outage<F, FData>(
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> bool {
+    matches!(&router_data.response, Err(error) if (500..=599).contains(&error.status_code))
+}
+
+/// The failover policy of the profile to apply to the response of the connector, if the
+/// connector was unavailable during the authorization of the payment. Failover does not apply to
+/// the other flows.
+pub fn get_outage_failover_policy<F, FData>(
+    business_profile: &storage::business_profile::BusinessProfile,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> Option<ConnectorFailoverPolicy> {
+    let is_authorize_flow = std::any::type_name::<F>() == std::any::type_name::<api::Authorize>();
+    if is_authorize_flow && is_connector_outage(router_data) {
+        get_connector_failover_policy(business_profile)
+    } else {
+        None
+    }
+}
+
+fn get_circuit_open_key(profile_id: &str, connector_name: &str) -> String {
+    format!("connector_circuit_open_{profile_id}_{connector_name}")
+}
+
+fn get_circuit_failures_key(profile_id: &str, connector_name: &str) -> String {
+    format!("connector_circuit_failures_{profile_id}_{connector_name}")
+}
+
+/// Whether the circuit of the connector is open for the profile. Failures to reach redis are
+/// logged and the circuit considered closed, so that the payments keep flowing.
+async fn is_circuit_open(state: &SessionState, profile_id: &str, connector_name: &str) -> bool {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the circuit breaker"
+            );
+            return false;
+        }
+    };
+
+    redis_conn
+        .exists::<()>(&get_circuit_open_key(profile_id, connector_name))
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to check the circuit of the connector"))
+        .unwrap_or(false)
+}
+
+/// Counts the outage of the connector within the window of the circuit breaker, opening the
+/// circuit of the connector once the failure threshold is reached
+async fn record_connector_outage(
+    state: &SessionState,
+    profile_id: &str,
+    circuit_breaker: &ConnectorCircuitBreakerConfig,
+    connector_name: &str,
+) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the circuit breaker"
+            );
+            return;
+        }
+    };
+
+    match redis_conn
+        .increment_key_with_expiry(
+            &get_circuit_failures_key(profile_id, connector_name),
+            circuit_breaker.window_in_secs.into(),
+        )
+        .await
+    {
+        Ok(failures) if failures >= i64::from(circuit_breaker.failure_threshold) => {
+            logger::warn!(%connector_name, failures, "Opening the circuit of the connector");
+            if let Err(error) = redis_conn
+                .set_key_with_expiry(
+                    &get_circuit_open_key(profile_id, connector_name),
+                    "open",
+                    circuit_breaker.open_duration_in_secs.into(),
+                )
+                .await
+            {
+                logger::error!(?error, "Failed to open the circuit of the connector");
+            }
+        }
+        Ok(_) => (),
+        Err(error) => logger::error!(?error, "Failed to count the outage of the connector"),
+    }
+}
+
+/// Moves the connectors whose circuit is open after the other connectors, keeping the order the
+/// connectors were routed in otherwise
+#[instrument(skip_all)]
+pub async fn prioritize_available_connectors(
+    state: &SessionState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    connectors: Vec<api::ConnectorData>,
+) -> Vec<api::ConnectorData> {
+    let has_circuit_breaker = get_connector_failover_policy(business_profile)
+        .is_some_and(|policy| policy.circuit_breaker.is_some());
+    if !has_circuit_breaker {
+        return connectors;
+    }
+
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+    let mut unavailable_connectors = Vec::new();
+    for connector_data in connectors {
+        if is_circuit_open(
+            state,
+            &business_profile.profile_id,
+            &connector_data.connector_name.to_string(),
+        )
+        .await
+        {
+            unavailable_connectors.push(connector_data);
+        } else {
+            available_connectors.push(connector_data);
+        }
+    }
+    available_connectors.extend(unavailable_connectors);
+    available_connectors
+}
+
+async fn record_failover_action<F: Clone>(
+    state: &SessionState,
+    payment_data: &mut payments::PaymentData<F>,
+    action: ConnectorFailoverAction,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::FailoverUpdate {
+                failover_action: action.to_string(),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the failover action on the payment attempt")?;
+
+    Ok(())
+}
+
+/// Makes sure the status of the queued payment is synchronized with the connector later on. The
+/// task may already have been added by the connector call, which is not an error.
+async fn queue_payment_sync(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_name: &str,
+) -> RouterResult<()> {
+    let schedule_time = connector_polling::get_polling_schedule_time(
+        &*state.store,
+        connector_polling::PollingObject::Payment,
+        connector_name,
+        &payment_attempt.merchant_id,
+        0,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while getting process schedule time")?
+    .unwrap_or_else(|| {
+        common_utils::date_time::now()
+            + time::Duration::seconds(DEFAULT_QUEUED_PAYMENT_SYNC_DELAY_IN_SECS)
+    });
+
+    match payments::add_process_sync_task(&*state.store, payment_attempt, schedule_time).await {
+        Err(error) if !error.current_context().is_db_unique_violation() => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding task to process tracker"),
+        _ => Ok(()),
+    }
+}
+
+/// Applies the failover policy of the profile to the authorization that failed because the
+/// connector was unavailable. Each outage is counted by the circuit breaker, and the action taken
+/// is recorded on the attempt it was taken on.
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "retry"), allow(unused_variables, unused_mut))]
+#[instrument(skip_all)]
+pub async fn handle_connector_outage<F, ApiRequest, FData>(
+    state: &SessionState,
+    req_state: ReqState,
+    payment_data: &mut payments::PaymentData<F>,
+    policy: ConnectorFailoverPolicy,
+    mut connectors: IntoIter<api::ConnectorData>,
+    original_connector_data: api::ConnectorData,
+    mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    operation: &operations::BoxedOperation<'_, F, ApiRequest>,
+    customer: &Option<domain::Customer>,
+    validate_result: &operations::ValidateResult<'_>,
+    schedule_time: Option<time::PrimitiveDateTime>,
+    frm_suggestion: Option<storage_enums::FrmSuggestion>,
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
+where
+    F: Clone + Send + Sync,
+    FData: Send + Sync,
+    payments::PaymentResponse: operations::Operation<F, FData>,
+
+    payments::PaymentData<F>: ConstructFlowSpecificData<F, FData, types::PaymentsResponseData>,
+    types::RouterData<F, FData, types::PaymentsResponseData>: Feature<F, FData>,
+    dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
+{
+    let mut connector_data = original_connector_data;
+
+    loop {
+        if !is_connector_outage(&router_data) {
+            return Ok(router_data);
+        }
+        let connector_name = connector_data.connector_name.to_string();
+        logger::info!(%connector_name, action = %policy.action, "Connector outage");
+        if let Some(circuit_breaker) = &policy.circuit_breaker {
+            record_connector_outage(
+                state,
+                &business_profile.profile_id,
+                circuit_breaker,
+                &connector_name,
+            )
+            .await;
+        }
+
+        match policy.action {
+            ConnectorFailoverAction::Fail => {
+                if let Err(error) = &mut router_data.response {
+                    error.attempt_status = Some(storage_enums::AttemptStatus::Failure);
+                }
+                record_failover_action(
+                    state,
+                    payment_data,
+                    policy.action,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+                return Ok(router_data);
+            }
+            ConnectorFailoverAction::Queue => {
+                if let Err(error) = &mut router_data.response {
+                    error.attempt_status = Some(storage_enums::AttemptStatus::Pending);
+                }
+                record_failover_action(
+                    state,
+                    payment_data,
+                    policy.action,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+                queue_payment_sync(state, &payment_data.payment_attempt, &connector_name).await?;
+                return Ok(router_data);
+            }
+            #[cfg(feature = "retry")]
+            ConnectorFailoverAction::RetryNextConnector => {
+                let mut next_connector = None;
+                for candidate in connectors.by_ref() {
+                    if policy.circuit_breaker.is_some()
+                        && is_circuit_open(
+                            state,
+                            &business_profile.profile_id,
+                            &candidate.connector_name.to_string(),
+                        )
+                        .await
+                    {
+                        logger::info!(
+                            connector_name = %candidate.connector_name,
+                            "Skipping the connector, its circuit is open"
+                        );
+                        continue;
+                    }
+                    next_connector = Some(candidate);
+                    break;
+                }
+                let Some(next_connector) = next_connector else {
+                    logger::info!("Connectors exhausted for the failover of the payment");
+                    return Ok(router_data);
+                };
+
+                record_failover_action(
+                    state,
+                    payment_data,
+                    policy.action,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+                router_data = retry::do_retry(
+                    state,
+                    req_state.clone(),
+                    next_connector.clone(),
+                    operation,
+                    customer,
+                    merchant_account,
+                    key_store,
+                    payment_data,
+                    router_data,
+                    validate_result,
+                    schedule_time,
+                    false,
+                    frm_suggestion,
+                    business_profile,
+                )
+                .await?;
+                connector_data = next_connector;
+            }
+            #[cfg(not(feature = "retry"))]
+            ConnectorFailoverAction::RetryNextConnector => {
+                logger::info!("Retries are not supported, the payment is not failed over");
+                return Ok(router_data);
+            }
+        }
+    }
+}
@@ -663,6 +663,7 @@ default_imp_for_new_connector_integration_payment!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -744,6 +745,7 @@ default_imp_for_new_connector_integration_refund!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -820,6 +822,7 @@ default_imp_for_new_connector_integration_connector_access_token!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -918,6 +921,7 @@ default_imp_for_new_connector_integration_accept_dispute!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -998,6 +1002,7 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 default_imp_for_new_connector_integration_submit_evidence!(
@@ -1062,6 +1067,7 @@ default_imp_for_new_connector_integration_submit_evidence!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1153,6 +1159,7 @@ default_imp_for_new_connector_integration_file_upload!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1226,6 +1233,7 @@ default_imp_for_new_connector_integration_payouts!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1309,6 +1317,7 @@ default_imp_for_new_connector_integration_payouts_create!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1392,6 +1401,7 @@ default_imp_for_new_connector_integration_payouts_eligibility!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1475,6 +1485,7 @@ default_imp_for_new_connector_integration_payouts_fulfill!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1558,6 +1569,7 @@ default_imp_for_new_connector_integration_payouts_cancel!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1641,6 +1653,7 @@ default_imp_for_new_connector_integration_payouts_quote!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1724,6 +1737,7 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1807,6 +1821,7 @@ default_imp_for_new_connector_integration_payouts_recipient_account!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1888,6 +1903,7 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1961,6 +1977,7 @@ default_imp_for_new_connector_integration_frm!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2044,6 +2061,7 @@ default_imp_for_new_connector_integration_frm_sale!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2127,6 +2145,7 @@ default_imp_for_new_connector_integration_frm_checkout!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2210,6 +2229,7 @@ default_imp_for_new_connector_integration_frm_transaction!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2293,6 +2313,7 @@ default_imp_for_new_connector_integration_frm_fulfillment!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2376,6 +2397,7 @@ default_imp_for_new_connector_integration_frm_record_return!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2456,6 +2478,7 @@ default_imp_for_new_connector_integration_revoking_mandates!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2564,5 +2587,6 @@ default_imp_for_new_connector_integration_connector_authentication!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
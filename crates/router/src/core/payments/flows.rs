@@ -203,6 +203,7 @@ default_imp_for_complete_authorize!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 macro_rules! default_imp_for_webhook_source_verification {
@@ -293,6 +294,7 @@ default_imp_for_webhook_source_verification!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -383,6 +385,7 @@ default_imp_for_create_customer!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -462,6 +465,7 @@ default_imp_for_connector_redirect_response!(
     connector::Wise,
     connector::Worldline,
     connector::Worldpay,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -478,6 +482,7 @@ impl<const T: u8> api::ConnectorTransactionId for connector::DummyConnector<T> {
 
 default_imp_for_connector_request_id!(
     connector::Adyenplatform,
+    connector::Externaladapter,
     connector::Zsl,
     connector::Aci,
     connector::Adyen,
@@ -632,6 +637,7 @@ default_imp_for_accept_dispute!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -745,6 +751,7 @@ default_imp_for_file_upload!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -836,6 +843,7 @@ default_imp_for_submit_evidence!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -928,6 +936,7 @@ default_imp_for_defend_dispute!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1009,6 +1018,7 @@ default_imp_for_pre_processing_steps!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1078,6 +1088,7 @@ default_imp_for_payouts!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1167,6 +1178,7 @@ default_imp_for_payouts_create!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1261,6 +1273,7 @@ default_imp_for_payouts_eligibility!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1347,6 +1360,7 @@ default_imp_for_payouts_fulfill!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1437,6 +1451,7 @@ default_imp_for_payouts_cancel!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1529,6 +1544,7 @@ default_imp_for_payouts_quote!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1620,6 +1636,7 @@ default_imp_for_payouts_recipient!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1716,6 +1733,7 @@ default_imp_for_payouts_recipient_account!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1809,6 +1827,7 @@ default_imp_for_approve!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1902,6 +1921,7 @@ default_imp_for_reject!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -1977,6 +1997,7 @@ default_imp_for_fraud_check!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2070,6 +2091,7 @@ default_imp_for_frm_sale!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2163,6 +2185,7 @@ default_imp_for_frm_checkout!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2256,6 +2279,7 @@ default_imp_for_frm_transaction!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2349,6 +2373,7 @@ default_imp_for_frm_fulfillment!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2442,6 +2467,7 @@ default_imp_for_frm_record_return!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2534,6 +2560,7 @@ default_imp_for_incremental_authorization!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2623,6 +2650,7 @@ default_imp_for_revoking_mandates!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2772,6 +2800,7 @@ default_imp_for_connector_authentication!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
 
@@ -2860,5 +2889,6 @@ default_imp_for_authorize_session_token!(
     connector::Worldline,
     connector::Worldpay,
     connector::Zen,
+    connector::Externaladapter,
     connector::Zsl
 );
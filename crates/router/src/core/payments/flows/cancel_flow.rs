@@ -97,6 +97,13 @@ impl Feature<api::Void, types::PaymentsCancelData>
     ) -> RouterResult<(Option<services::Request>, bool)> {
         let request = match call_connector_action {
             payments::CallConnectorAction::Trigger => {
+                if self.request.minor_amount_to_reverse.is_some() {
+                    connector
+                        .connector
+                        .validate_partial_authorization_reversal()
+                        .to_payment_failed_response()?;
+                }
+
                 let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
                     api::Void,
                     types::PaymentsCancelData,
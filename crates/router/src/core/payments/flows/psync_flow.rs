@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
     core::{
+        connector_response_cache::ConnectorResponseCache,
         errors::{ApiErrorResponse, ConnectorErrorExt, RouterResult},
         payments::{self, access_token, helpers, transformers, PaymentData},
     },
@@ -83,13 +84,31 @@ impl Feature<api::PSync, types::PaymentsSyncData>
             }
             (types::SyncRequestType::MultipleCaptureSync(_), Err(err)) => Err(err),
             _ => {
+                // The syncs following a redirection carry the data of the redirection, and are
+                // never served a cached response
+                let response_cache = match self.request.connector_transaction_id {
+                    types::ResponseId::ConnectorTransactionId(ref connector_transaction_id)
+                        if self.request.encoded_data.is_none() =>
+                    {
+                        ConnectorResponseCache::new(
+                            state,
+                            &self.merchant_id,
+                            &self.connector,
+                            "psync",
+                            connector_transaction_id,
+                            self.status,
+                        )
+                    }
+                    _ => None,
+                };
                 // for bulk sync of captures, above logic needs to be handled at connector end
-                let resp = services::execute_connector_processing_step(
+                let resp = services::execute_connector_processing_step_with_response_cache(
                     state,
                     connector_integration,
                     &self,
                     call_connector_action,
                     connector_request,
+                    response_cache.as_ref(),
                 )
                 .await
                 .to_payment_failed_response()?;
@@ -24,9 +24,14 @@ use hyperswitch_domain_models::{
 use josekit::jwe;
 use masking::{ExposeInterface, PeekInterface};
 use openssl::{
+    bn::BigNumContext,
     derive::Deriver,
+    ec::{EcGroup, EcKey, EcPoint},
+    hash::MessageDigest,
+    nid::Nid,
     pkey::PKey,
-    symm::{decrypt_aead, Cipher},
+    sign::{Signer, Verifier},
+    symm::{decrypt, decrypt_aead, Cipher},
 };
 use router_env::{instrument, logger, metrics::add_attributes, tracing};
 use uuid::Uuid;
@@ -41,7 +46,7 @@ use crate::{
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
-        authentication,
+        authentication, blind_index,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers::MandateGenericData,
         payment_methods::{self, cards, vault},
@@ -61,8 +66,8 @@ use crate::{
             self, enums as storage_enums, ephemeral_key, CardTokenData, CustomerUpdate::Update,
         },
         transformers::{ForeignFrom, ForeignTryFrom},
-        AdditionalPaymentMethodConnectorResponse, ErrorResponse, MandateReference,
-        RecurringMandatePaymentData, RouterData,
+        AdditionalPaymentMethodConnectorResponse, BrowserInformation, ErrorResponse,
+        MandateReference, RecurringMandatePaymentData, RouterData,
     },
     utils::{
         self,
@@ -716,6 +721,15 @@ pub async fn get_token_for_recurring_mandate(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
+    mandate::helpers::validate_mandate_pre_notification(
+        state,
+        &mandate,
+        payment_method.payment_method_type,
+        req.amount.get_required_value("amount")?.into(),
+        req.currency.get_required_value("currency")?,
+    )
+    .await?;
+
     let token = Uuid::new_v4().to_string();
     let payment_method_type = payment_method.payment_method_type;
     let mandate_connector_details = payments::MandateConnectorDetails {
@@ -1203,8 +1217,12 @@ pub fn verify_mandate_details(
             mandate
                 .mandate_amount
                 .map(|mandate_amount| {
-                    (mandate.amount_captured.unwrap_or(0) + request_amount.get_amount_as_i64())
-                        > mandate_amount
+                    // An overflowing total is beyond any mandate amount
+                    MinorUnit::new(mandate.amount_captured.unwrap_or(0))
+                        .checked_add(request_amount)
+                        .map_or(true, |total_amount| {
+                            total_amount > MinorUnit::new(mandate_amount)
+                        })
                 })
                 .unwrap_or(false),
             || {
@@ -1604,6 +1622,21 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                         | request_customer_details.phone_country_code.is_some()
                     {
                         let key = key_store.key.get_inner().peek();
+                        let blind_indexes = blind_index::get_customer_update_blind_indexes(
+                            db.get_master_key(),
+                            &c,
+                            request_customer_details
+                                .email
+                                .as_ref()
+                                .map(|email| email.peek().as_str()),
+                            request_customer_details
+                                .phone
+                                .as_ref()
+                                .map(|phone| phone.peek().as_str()),
+                            request_customer_details.phone_country_code.as_deref(),
+                        )
+                        .change_context(errors::StorageError::EncryptionError)
+                        .attach_printable("Failed to generate the blind indexes of the customer")?;
                         let customer_update = async {
                             Ok::<_, error_stack::Report<common_utils::errors::CryptoError>>(
                                 Update {
@@ -1633,6 +1666,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                                     connector_customer: None,
                                     metadata: None,
                                     address_id: None,
+                                    email_blind_index: blind_indexes.email.map(Some),
+                                    phone_blind_index: blind_indexes.phone.map(Some),
                                 },
                             )
                         }
@@ -1654,6 +1689,21 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                     }
                 }
                 None => {
+                    let blind_indexes = blind_index::get_customer_blind_indexes(
+                        db.get_master_key(),
+                        merchant_id,
+                        request_customer_details
+                            .email
+                            .as_ref()
+                            .map(|email| email.peek().as_str()),
+                        request_customer_details
+                            .phone
+                            .as_ref()
+                            .map(|phone| phone.peek().as_str()),
+                        request_customer_details.phone_country_code.as_deref(),
+                    )
+                    .change_context(errors::StorageError::EncryptionError)
+                    .attach_printable("Failed to generate the blind indexes of the customer")?;
                     let new_customer = async {
                         let key = key_store.key.get_inner().peek();
                         Ok::<_, error_stack::Report<common_utils::errors::CryptoError>>(
@@ -1691,6 +1741,9 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                                 address_id: None,
                                 default_payment_method_id: None,
                                 updated_by: None,
+                                payment_method_order: None,
+                                email_blind_index: blind_indexes.email,
+                                phone_blind_index: blind_indexes.phone,
                             },
                         )
                     }
@@ -2079,9 +2132,64 @@ pub async fn make_pm_data<'a, F: Clone, R>(
         _ => Ok((None, None)),
     }?;
 
+    let payment_method = match payment_method {
+        Some(api::PaymentMethodData::Card(card)) => {
+            let chosen_card_network = card_token_data
+                .and_then(|token_data| token_data.card_network)
+                .or(card.card_network.clone());
+            Some(api::PaymentMethodData::Card(
+                apply_card_network_choice(&*state.store, card, chosen_card_network).await?,
+            ))
+        }
+        payment_method => payment_method,
+    };
+
     Ok((operation, payment_method, pm_id))
 }
 
+/// Sets the network the card is to be processed on. For co-badged cards, the network chosen by
+/// the customer must be one of the networks of the card found in the BIN data, the primary
+/// network of the card being used when the customer made no choice.
+#[instrument(skip_all)]
+pub async fn apply_card_network_choice(
+    db: &dyn StorageInterface,
+    mut card: api::Card,
+    chosen_card_network: Option<enums::CardNetwork>,
+) -> RouterResult<api::Card> {
+    let card_info = db
+        .get_card_info(&card.card_number.get_card_isin())
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten();
+
+    let Some(card_networks) = card_info
+        .map(|card_info| card_info.get_card_networks())
+        .filter(|card_networks| card_networks.len() > 1)
+    else {
+        return Ok(card);
+    };
+
+    card.card_network = match chosen_card_network {
+        Some(card_network) if card_networks.contains(&card_network) => Some(card_network),
+        Some(card_network) => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "card_network {card_network} is not a network of the co-badged card, expected one of {}",
+                    card_networks
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }))
+        }
+        None => card_networks.first().cloned(),
+    };
+
+    Ok(card)
+}
+
 pub async fn store_in_vault_and_generate_ppmt(
     state: &SessionState,
     payment_method_data: &api_models::payments::PaymentMethodData,
@@ -2737,6 +2845,36 @@ pub fn check_if_operation_confirm<Op: std::fmt::Debug>(operations: Op) -> bool {
     format!("{operations:?}") == "PaymentConfirm"
 }
 
+/// Decide whether the network transaction id returned by the connector has to be persisted
+/// against the payment method.
+///
+/// As per the card network stored credential frameworks, merchant initiated transactions must
+/// reference the network transaction id of the initial customer initiated transaction. Hence the
+/// id is stored only for CITs which set up the payment method for off session usage, and an
+/// already stored id is never overwritten by the id of a subsequent transaction.
+pub fn get_network_transaction_id_to_store(
+    network_transaction_id: Option<String>,
+    setup_future_usage: Option<storage_enums::FutureUsage>,
+    stored_network_transaction_id: Option<&String>,
+) -> Option<String> {
+    match (network_transaction_id, stored_network_transaction_id) {
+        (Some(_), Some(_)) => {
+            logger::info!("Network transaction id of the initial CIT is already stored");
+            None
+        }
+        (Some(network_transaction_id), None)
+            if setup_future_usage == Some(storage_enums::FutureUsage::OffSession) =>
+        {
+            Some(network_transaction_id)
+        }
+        (Some(_), None) => {
+            logger::info!("Skip storing network transaction id for on session payment");
+            None
+        }
+        (None, _) => None,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn generate_mandate(
     merchant_id: String,
@@ -3062,6 +3200,10 @@ mod tests {
             request_external_three_ds_authentication: None,
             charges: None,
             frm_metadata: None,
+            tax_details: None,
+            order_id: None,
+            merchant_reference: None,
+            custom_fields: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3121,6 +3263,10 @@ mod tests {
             request_external_three_ds_authentication: None,
             charges: None,
             frm_metadata: None,
+            tax_details: None,
+            order_id: None,
+            merchant_reference: None,
+            custom_fields: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3179,10 +3325,115 @@ mod tests {
             request_external_three_ds_authentication: None,
             charges: None,
             frm_metadata: None,
+            tax_details: None,
+            order_id: None,
+            merchant_reference: None,
+            custom_fields: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    fn get_custom_fields_schema() -> api_models::admin::CustomFieldsSchema {
+        api_models::admin::CustomFieldsSchema {
+            fields: vec![
+                api_models::admin::CustomFieldDefinition {
+                    name: "channel".to_string(),
+                    field_type: api_models::admin::CustomFieldType::String,
+                    required: true,
+                    max_length: None,
+                    allowed_values: Some(vec!["online".to_string(), "in_store".to_string()]),
+                },
+                api_models::admin::CustomFieldDefinition {
+                    name: "delivery_date".to_string(),
+                    field_type: api_models::admin::CustomFieldType::Date,
+                    required: false,
+                    max_length: None,
+                    allowed_values: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_validate_custom_fields() {
+        let schema = get_custom_fields_schema();
+        let custom_fields = |value: serde_json::Value| match value {
+            serde_json::Value::Object(custom_fields) => custom_fields,
+            _ => serde_json::Map::new(),
+        };
+
+        let valid = custom_fields(serde_json::json!({
+            "channel": "online",
+            "delivery_date": "2024-07-24"
+        }));
+        assert!(validate_custom_fields(Some(&schema), Some(&valid)).is_ok());
+
+        let missing_required = custom_fields(serde_json::json!({"delivery_date": "2024-07-24"}));
+        assert!(validate_custom_fields(Some(&schema), Some(&missing_required)).is_err());
+        assert!(validate_custom_fields(Some(&schema), None).is_err());
+
+        let not_allowed = custom_fields(serde_json::json!({"channel": "phone"}));
+        assert!(validate_custom_fields(Some(&schema), Some(&not_allowed)).is_err());
+
+        let invalid_date = custom_fields(serde_json::json!({
+            "channel": "online",
+            "delivery_date": "24/07/2024"
+        }));
+        assert!(validate_custom_fields(Some(&schema), Some(&invalid_date)).is_err());
+
+        let undefined = custom_fields(serde_json::json!({"channel": "online", "store": 42}));
+        assert!(validate_custom_fields(Some(&schema), Some(&undefined)).is_err());
+        assert!(validate_custom_fields(None, Some(&valid)).is_err());
+        assert!(validate_custom_fields(None, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_custom_fields_schema() {
+        let mut schema = get_custom_fields_schema();
+        assert!(validate_custom_fields_schema(&schema).is_ok());
+
+        schema.fields[1].max_length = Some(10);
+        assert!(validate_custom_fields_schema(&schema).is_err());
+
+        let mut schema = get_custom_fields_schema();
+        schema.fields[1].name = "channel".to_string();
+        assert!(validate_custom_fields_schema(&schema).is_err());
+    }
+
+    #[test]
+    fn test_validate_payment_method_usage_restrictions() {
+        let profile_id = "pro_abcdefghijklmnop".to_string();
+        let usage_restrictions = api_models::payment_methods::PaymentMethodUsageRestrictions {
+            off_session_disabled: true,
+            max_amount: Some(MinorUnit::new(5000)),
+            allowed_profile_ids: Some(vec![profile_id.clone()]),
+        };
+        let validate = |off_session, amount, profile_id: Option<&String>| {
+            validate_payment_method_usage_restrictions(
+                &usage_restrictions,
+                off_session,
+                MinorUnit::new(amount),
+                profile_id,
+            )
+        };
+
+        assert!(validate(Some(false), 5000, Some(&profile_id)).is_ok());
+        assert!(validate(None, 100, Some(&profile_id)).is_ok());
+        assert!(validate(Some(true), 100, Some(&profile_id)).is_err());
+        assert!(validate(Some(false), 5001, Some(&profile_id)).is_err());
+        assert!(validate(Some(false), 100, Some(&"pro_other".to_string())).is_err());
+        assert!(validate(Some(false), 100, None).is_err());
+
+        let unrestricted = api_models::payment_methods::PaymentMethodUsageRestrictions::default();
+        assert!(validate_payment_method_usage_restrictions(
+            &unrestricted,
+            Some(true),
+            MinorUnit::new(i64::MAX),
+            None
+        )
+        .is_ok());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3650,6 +3901,9 @@ impl AttemptType {
             charge_id: None,
             client_source: old_payment_attempt.client_source,
             client_version: old_payment_attempt.client_version,
+            failover_action: None,
+            unified_error_details: None,
+            amount_reversed: None,
         }
     }
 
@@ -3801,6 +4055,39 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_get_network_transaction_id_to_store() {
+        use diesel_models::enums::FutureUsage;
+
+        let network_transaction_id = Some("ntid_123".to_string());
+        let stored_network_transaction_id = "ntid_initial_cit".to_string();
+
+        assert_eq!(
+            Some("ntid_123".to_string()),
+            super::get_network_transaction_id_to_store(
+                network_transaction_id.clone(),
+                Some(FutureUsage::OffSession),
+                None,
+            )
+        );
+        assert_eq!(
+            None,
+            super::get_network_transaction_id_to_store(
+                network_transaction_id.clone(),
+                Some(FutureUsage::OnSession),
+                None,
+            )
+        );
+        assert_eq!(
+            None,
+            super::get_network_transaction_id_to_store(
+                network_transaction_id,
+                Some(FutureUsage::OffSession),
+                Some(&stored_network_transaction_id),
+            )
+        );
+    }
 }
 
 #[instrument(skip_all)]
@@ -3836,6 +4123,9 @@ pub async fn get_additional_payment_data(
                         card_issuer: card_data.card_issuer.to_owned(),
                         card_network: card_data.card_network.clone(),
                         card_type: card_data.card_type.to_owned(),
+                        card_tier: None,
+                        card_funding_type: None,
+                        is_regulated: None,
                         card_issuing_country: card_data.card_issuing_country.to_owned(),
                         bank_code: card_data.bank_code.to_owned(),
                         card_exp_month: Some(card_data.card_exp_month.clone()),
@@ -3861,12 +4151,16 @@ pub async fn get_additional_payment_data(
                     .await
                     .flatten()
                     .map(|card_info| {
+                        let card_funding_type = card_info.get_card_funding_type();
                         api_models::payments::AdditionalPaymentData::Card(Box::new(
                             api_models::payments::AdditionalCardInfo {
                                 card_issuer: card_info.card_issuer,
                                 card_network: card_info.card_network.clone(),
                                 bank_code: card_info.bank_code,
                                 card_type: card_info.card_type,
+                                card_tier: card_info.card_subtype,
+                                card_funding_type,
+                                is_regulated: card_info.is_regulated,
                                 card_issuing_country: card_info.card_issuing_country,
                                 last4: last4.clone(),
                                 card_isin: card_isin.clone(),
@@ -3887,6 +4181,9 @@ pub async fn get_additional_payment_data(
                             card_network: None,
                             bank_code: None,
                             card_type: None,
+                            card_tier: None,
+                            card_funding_type: None,
+                            is_regulated: None,
                             card_issuing_country: None,
                             last4,
                             card_isin,
@@ -4242,6 +4539,12 @@ impl ApplePayData {
         Ok(json_wallet_data)
     }
 
+    /// The base64 encoded hash of the public key of the payment processing certificate the token
+    /// was encrypted for
+    pub fn public_key_hash(&self) -> &masking::Secret<String> {
+        &self.header.public_key_hash
+    }
+
     pub async fn decrypt(
         &self,
         payment_processing_certificate: &masking::Secret<String>,
@@ -4378,6 +4681,318 @@ impl ApplePayData {
     }
 }
 
+/// The only protocol version of the Google Pay tokens supported for the decryption
+const GOOGLE_PAY_PROTOCOL_VERSION: &str = "ECv2";
+
+/// The sender the signatures of the Google Pay tokens are computed for
+const GOOGLE_PAY_SENDER_ID: &str = "Google";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayTokenData {
+    signature: masking::Secret<String>,
+    intermediate_signing_key: GooglePayIntermediateSigningKey,
+    protocol_version: String,
+    signed_message: masking::Secret<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayIntermediateSigningKey {
+    signed_key: masking::Secret<String>,
+    signatures: Vec<masking::Secret<String>>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePaySignedKey {
+    key_value: masking::Secret<String>,
+    key_expiration: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePaySignedMessage {
+    encrypted_message: masking::Secret<String>,
+    ephemeral_public_key: masking::Secret<String>,
+    tag: masking::Secret<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayDecryptedData {
+    pub message_expiration: String,
+    pub payment_method: String,
+    pub payment_method_details: GooglePayPaymentMethodDetails,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPaymentMethodDetails {
+    pub auth_method: GooglePayAuthMethod,
+    pub pan: cards::CardNumber,
+    pub expiration_month: u8,
+    pub expiration_year: u16,
+    pub cryptogram: Option<masking::Secret<String>>,
+    pub eci_indicator: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GooglePayAuthMethod {
+    PanOnly,
+    #[serde(rename = "CRYPTOGRAM_3DS")]
+    Cryptogram3ds,
+}
+
+impl GooglePayTokenData {
+    pub fn token_json(token: &str) -> CustomResult<Self, errors::GooglePayDecryptionError> {
+        serde_json::from_str(token)
+            .change_context(errors::GooglePayDecryptionError::TokenParsingFailed)
+            .attach_printable("Failed to parse the Google Pay token")
+    }
+
+    /// Verifies the signatures of the token against the root signing keys of Google Pay, then
+    /// decrypts the payment method details with the private key of the recipient
+    pub fn decrypt(
+        &self,
+        recipient_id: &str,
+        private_key: &masking::Secret<String>,
+        root_signing_keys: &std::collections::HashSet<String>,
+    ) -> CustomResult<GooglePayDecryptedData, errors::GooglePayDecryptionError> {
+        if self.protocol_version != GOOGLE_PAY_PROTOCOL_VERSION {
+            Err(errors::GooglePayDecryptionError::UnsupportedProtocolVersion)
+                .attach_printable(format!("protocol version {}", self.protocol_version))?
+        }
+
+        let signed_key = self.verify_intermediate_signing_key(root_signing_keys)?;
+        self.verify_signed_message(recipient_id, &signed_key)?;
+
+        let signed_message: GooglePaySignedMessage =
+            serde_json::from_str(self.signed_message.peek())
+                .change_context(errors::GooglePayDecryptionError::TokenParsingFailed)
+                .attach_printable("Failed to parse the signed message of the token")?;
+        let decrypted = signed_message.decrypt(private_key)?;
+        let decrypted_data: GooglePayDecryptedData = serde_json::from_slice(&decrypted)
+            .change_context(errors::GooglePayDecryptionError::DecryptionFailed)
+            .attach_printable("Failed to parse the decrypted payment method details")?;
+
+        if is_google_pay_timestamp_expired(&decrypted_data.message_expiration)? {
+            Err(errors::GooglePayDecryptionError::TokenExpired)
+                .attach_printable("The message of the Google Pay token has expired")?
+        }
+
+        Ok(decrypted_data)
+    }
+
+    fn verify_intermediate_signing_key(
+        &self,
+        root_signing_keys: &std::collections::HashSet<String>,
+    ) -> CustomResult<GooglePaySignedKey, errors::GooglePayDecryptionError> {
+        let signed_string = get_google_pay_signed_string(&[
+            GOOGLE_PAY_SENDER_ID.as_bytes(),
+            GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+            self.intermediate_signing_key.signed_key.peek().as_bytes(),
+        ]);
+
+        let is_verified = root_signing_keys
+            .iter()
+            .filter(|root_signing_key| !root_signing_key.is_empty())
+            .any(|root_signing_key| {
+                self.intermediate_signing_key
+                    .signatures
+                    .iter()
+                    .any(|signature| {
+                        verify_ecdsa_sha256_signature(root_signing_key, &signed_string, signature)
+                            .map_err(|error| {
+                                logger::warn!(
+                                    ?error,
+                                    "Failed to verify the intermediate signing key"
+                                )
+                            })
+                            .unwrap_or(false)
+                    })
+            });
+        if !is_verified {
+            Err(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+                .attach_printable("The intermediate signing key is not signed by Google Pay")?
+        }
+
+        let signed_key: GooglePaySignedKey =
+            serde_json::from_str(self.intermediate_signing_key.signed_key.peek())
+                .change_context(errors::GooglePayDecryptionError::TokenParsingFailed)
+                .attach_printable("Failed to parse the intermediate signing key")?;
+        if is_google_pay_timestamp_expired(&signed_key.key_expiration)? {
+            Err(errors::GooglePayDecryptionError::TokenExpired)
+                .attach_printable("The intermediate signing key has expired")?
+        }
+
+        Ok(signed_key)
+    }
+
+    fn verify_signed_message(
+        &self,
+        recipient_id: &str,
+        signed_key: &GooglePaySignedKey,
+    ) -> CustomResult<(), errors::GooglePayDecryptionError> {
+        let signed_string = get_google_pay_signed_string(&[
+            GOOGLE_PAY_SENDER_ID.as_bytes(),
+            recipient_id.as_bytes(),
+            GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+            self.signed_message.peek().as_bytes(),
+        ]);
+
+        if !verify_ecdsa_sha256_signature(
+            signed_key.key_value.peek(),
+            &signed_string,
+            &self.signature,
+        )? {
+            Err(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+                .attach_printable("The message is not signed by the intermediate signing key")?
+        }
+        Ok(())
+    }
+}
+
+impl GooglePaySignedMessage {
+    fn decrypt(
+        &self,
+        private_key: &masking::Secret<String>,
+    ) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+        let ephemeral_public_key = BASE64_ENGINE
+            .decode(self.ephemeral_public_key.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let encrypted_message = BASE64_ENGINE
+            .decode(self.encrypted_message.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let tag = BASE64_ENGINE
+            .decode(self.tag.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+
+        let shared_secret = get_google_pay_shared_secret(&ephemeral_public_key, private_key)?;
+
+        // The symmetric encryption key and the MAC key are derived with HKDF-SHA256 from the
+        // ephemeral public key and the shared secret, with an empty salt and the sender as info
+        let input_key_material = [ephemeral_public_key.as_slice(), &shared_secret].concat();
+        let pseudo_random_key = hmac_sha256(&[0u8; 32], &input_key_material)?;
+        let first_block = hmac_sha256(
+            &pseudo_random_key,
+            &[GOOGLE_PAY_SENDER_ID.as_bytes(), &[1]].concat(),
+        )?;
+        let second_block = hmac_sha256(
+            &pseudo_random_key,
+            &[&first_block, GOOGLE_PAY_SENDER_ID.as_bytes(), &[2]].concat(),
+        )?;
+
+        let computed_tag = hmac_sha256(&second_block, &encrypted_message)?;
+        if computed_tag.len() != tag.len() || !openssl::memcmp::eq(&computed_tag, &tag) {
+            Err(errors::GooglePayDecryptionError::DecryptionFailed)
+                .attach_printable("The tag of the encrypted message does not match")?
+        }
+
+        decrypt(
+            Cipher::aes_256_ctr(),
+            &first_block,
+            Some(&[0u8; 16]),
+            &encrypted_message,
+        )
+        .change_context(errors::GooglePayDecryptionError::DecryptionFailed)
+    }
+}
+
+/// The string signed by Google Pay: each field prefixed by its length in bytes, as a 4 bytes
+/// little-endian integer
+fn get_google_pay_signed_string(fields: &[&[u8]]) -> Vec<u8> {
+    fields
+        .iter()
+        .flat_map(|field| {
+            u32::try_from(field.len())
+                .unwrap_or(u32::MAX)
+                .to_le_bytes()
+                .into_iter()
+                .chain(field.iter().copied())
+        })
+        .collect()
+}
+
+fn is_google_pay_timestamp_expired(
+    timestamp_in_millis: &str,
+) -> CustomResult<bool, errors::GooglePayDecryptionError> {
+    let timestamp_in_millis: i128 = timestamp_in_millis
+        .parse()
+        .change_context(errors::GooglePayDecryptionError::TokenParsingFailed)
+        .attach_printable("Failed to parse the expiration of the Google Pay token")?;
+    let now_in_millis = time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000;
+    Ok(timestamp_in_millis <= now_in_millis)
+}
+
+fn verify_ecdsa_sha256_signature(
+    encoded_public_key: &str,
+    message: &[u8],
+    encoded_signature: &masking::Secret<String>,
+) -> CustomResult<bool, errors::GooglePayDecryptionError> {
+    let public_key = BASE64_ENGINE
+        .decode(encoded_public_key)
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+    let public_key = PKey::public_key_from_der(&public_key)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+        .attach_printable("Failed to deserialize the signing key")?;
+    let signature = BASE64_ENGINE
+        .decode(encoded_signature.peek())
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+
+    let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key)
+        .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)?;
+    verifier
+        .update(message)
+        .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)?;
+    verifier
+        .verify(&signature)
+        .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+}
+
+fn get_google_pay_shared_secret(
+    ephemeral_public_key: &[u8],
+    private_key: &masking::Secret<String>,
+) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+    // The ephemeral public key is an uncompressed point of the P-256 curve
+    let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+    let mut big_num_context = BigNumContext::new()
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+    let point = EcPoint::from_bytes(&group, ephemeral_public_key, &mut big_num_context)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+        .attach_printable("Failed to deserialize the ephemeral public key")?;
+    let public_key = EcKey::from_public_key(&group, &point)
+        .and_then(PKey::from_ec_key)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+
+    let private_key = PKey::private_key_from_pem(private_key.peek().as_bytes())
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+        .attach_printable("Failed to deserialize the private key")?;
+
+    let mut deriver = Deriver::new(&private_key)
+        .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)?;
+    deriver
+        .set_peer(&public_key)
+        .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)?;
+    deriver
+        .derive_to_vec()
+        .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+    let key = PKey::hmac(key).change_context(errors::GooglePayDecryptionError::DecryptionFailed)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)
+        .change_context(errors::GooglePayDecryptionError::DecryptionFailed)?;
+    signer
+        .update(data)
+        .change_context(errors::GooglePayDecryptionError::DecryptionFailed)?;
+    signer
+        .sign_to_vec()
+        .change_context(errors::GooglePayDecryptionError::DecryptionFailed)
+}
+
 pub fn get_key_params_for_surcharge_details(
     payment_method_data: &api_models::payments::PaymentMethodData,
 ) -> Option<(
@@ -4387,8 +5002,8 @@ pub fn get_key_params_for_surcharge_details(
 )> {
     match payment_method_data {
         api_models::payments::PaymentMethodData::Card(card) => {
-            // surcharge generated will always be same for credit as well as debit
-            // since surcharge conditions cannot be defined on card_type
+            // the card type is not known from the card data, callers resolve it from the BIN
+            // data of the card and fall back to credit
             Some((
                 common_enums::PaymentMethod::Card,
                 common_enums::PaymentMethodType::Credit,
@@ -4514,6 +5129,49 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+/// The category of the error as mapped in the gateway status map. A category that cannot be parsed
+/// is logged and ignored.
+pub fn get_decline_category(
+    gsm: &storage::gsm::GatewayStatusMap,
+) -> Option<api_models::gsm::DeclineCategory> {
+    gsm.decline_category.as_deref().and_then(|category| {
+        api_models::gsm::DeclineCategory::from_str(category)
+            .map_err(|error| logger::warn!(?error, "Failed to parse the gsm decline category"))
+            .ok()
+    })
+}
+
+/// The details of the error as normalized in the gateway status map, encoded to be stored on the
+/// attempt the error occurred on. The error is considered retryable as per its category, or as
+/// per the retry decision of the gateway status map when not categorized.
+pub fn get_unified_error_details(
+    option_gsm: Option<&storage::gsm::GatewayStatusMap>,
+) -> RouterResult<Option<serde_json::Value>> {
+    option_gsm
+        .map(|gsm| {
+            let decline_category = get_decline_category(gsm);
+            let retryable = decline_category
+                .map(api_models::gsm::DeclineCategory::is_retryable)
+                .unwrap_or_else(|| {
+                    matches!(
+                        api_models::gsm::GsmDecision::from_str(&gsm.decision),
+                        Ok(api_models::gsm::GsmDecision::Retry)
+                            | Ok(api_models::gsm::GsmDecision::Requeue)
+                    )
+                });
+            api_models::payments::UnifiedErrorDetails {
+                decline_category,
+                network_advice_code: gsm.network_advice_code.clone(),
+                retryable,
+                user_actionable: gsm.user_actionable,
+            }
+            .encode_to_value()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the unified error details")
+}
+
 pub fn validate_order_details_amount(
     order_details: Vec<api_models::payments::OrderDetailsWithAmount>,
     amount: i64,
@@ -4549,6 +5207,241 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+/// Provides the device fingerprint provided by the SDK, which is persisted on the payment attempt
+/// as a part of its browser information
+pub fn get_device_fingerprint_from_browser_info(
+    browser_info: Option<&serde_json::Value>,
+) -> Option<String> {
+    browser_info
+        .cloned()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<BrowserInformation>("BrowserInformation")
+                .map_err(|error| logger::error!(?error, "Failed to parse browser information"))
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.device_fingerprint)
+}
+
+pub fn validate_frm_decision_matrix(
+    frm_decision_matrix: &api_models::admin::FrmDecisionMatrix,
+) -> Result<(), errors::ApiErrorResponse> {
+    frm_decision_matrix
+        .rules
+        .iter()
+        .try_for_each(|rule| match (rule.min_score, rule.max_score) {
+            (Some(min_score), Some(max_score)) if min_score > max_score => {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "min_score of a frm_decision_matrix rule cannot be greater than its max_score".to_string(),
+                })
+            }
+            _ => Ok(()),
+        })
+}
+
+pub fn validate_frm_post_auth_review_config(
+    frm_post_auth_review: &api_models::admin::FrmPostAuthReviewConfig,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(consts::MIN_FRM_REVIEW_TIMEOUT..=consts::MAX_FRM_REVIEW_TIMEOUT)
+        .contains(&frm_post_auth_review.review_timeout_in_secs)
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "review_timeout_in_secs should be between 60(1 min) to 604800(7 days)."
+                .to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_connector_debug_capture_config(
+    connector_debug_capture: &api_models::admin::ConnectorDebugCaptureConfig,
+) -> Result<(), errors::ApiErrorResponse> {
+    let max_enabled_until =
+        common_utils::date_time::now().saturating_add(consts::MAX_CONNECTOR_DEBUG_CAPTURE_WINDOW);
+    if connector_debug_capture.enabled_until > max_enabled_until {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "enabled_until should be at most 72 hours from now.".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_connector_failover_policy(
+    connector_failover_policy: &api_models::admin::ConnectorFailoverPolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    match &connector_failover_policy.circuit_breaker {
+        Some(circuit_breaker)
+            if circuit_breaker.failure_threshold == 0
+                || circuit_breaker.window_in_secs == 0
+                || circuit_breaker.open_duration_in_secs == 0 =>
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "circuit_breaker values should be greater than 0.".to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The largest number of characters of the names of the custom fields of the payments
+const MAX_CUSTOM_FIELD_NAME_LENGTH: usize = 64;
+
+pub fn validate_custom_fields_schema(
+    custom_fields_schema: &api_models::admin::CustomFieldsSchema,
+) -> Result<(), errors::ApiErrorResponse> {
+    let mut names = std::collections::HashSet::new();
+    for field in &custom_fields_schema.fields {
+        if field.name.is_empty() || field.name.chars().count() > MAX_CUSTOM_FIELD_NAME_LENGTH {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "custom field names must be between 1 and {MAX_CUSTOM_FIELD_NAME_LENGTH} \
+                     characters long"
+                ),
+            });
+        }
+        if !names.insert(field.name.as_str()) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("custom field `{}` is defined more than once", field.name),
+            });
+        }
+        if field.field_type != api_models::admin::CustomFieldType::String
+            && (field.max_length.is_some() || field.allowed_values.is_some())
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "max_length and allowed_values can only be set for string custom fields, \
+                     which `{}` is not",
+                    field.name
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validates the custom fields of a payment against the custom fields schema of its business
+/// profile. Every custom field must be defined in the schema with a value of its type, and every
+/// required custom field must be provided.
+pub fn validate_custom_fields(
+    custom_fields_schema: Option<&api_models::admin::CustomFieldsSchema>,
+    custom_fields: Option<&serde_json::Map<String, serde_json::Value>>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let definitions = custom_fields_schema
+        .map(|schema| schema.fields.as_slice())
+        .unwrap_or_default();
+
+    for (name, value) in custom_fields.into_iter().flatten() {
+        let definition = definitions
+            .iter()
+            .find(|definition| definition.name == *name)
+            .ok_or_else(|| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "custom field `{name}` is not defined in the custom fields schema of the \
+                     business profile"
+                ),
+            })?;
+        let is_valid = match definition.field_type {
+            api_models::admin::CustomFieldType::String => value.as_str().is_some_and(|value| {
+                definition.max_length.map_or(true, |max_length| {
+                    value.chars().count() <= usize::from(max_length)
+                }) && definition
+                    .allowed_values
+                    .as_ref()
+                    .map_or(true, |allowed_values| {
+                        allowed_values.iter().any(|allowed| allowed == value)
+                    })
+            }),
+            api_models::admin::CustomFieldType::Number => value.is_number(),
+            api_models::admin::CustomFieldType::Boolean => value.is_boolean(),
+            api_models::admin::CustomFieldType::Date => value.as_str().is_some_and(|value| {
+                time::Date::parse(
+                    value,
+                    time::macros::format_description!("[year]-[month]-[day]"),
+                )
+                .is_ok()
+            }),
+        };
+        if !is_valid {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "the value of custom field `{name}` does not match its definition in the \
+                     custom fields schema of the business profile"
+                ),
+            });
+        }
+    }
+
+    match definitions.iter().find(|definition| {
+        definition.required
+            && !custom_fields
+                .is_some_and(|custom_fields| custom_fields.contains_key(&definition.name))
+    }) {
+        Some(definition) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("custom field `{}` is required", definition.name),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates that the saved payment method used in the payment being confirmed is not barred from
+/// the payment by the restrictions on its use set by the merchant
+pub fn validate_payment_method_usage(
+    payment_method_info: Option<&storage::PaymentMethod>,
+    off_session: Option<bool>,
+    amount: MinorUnit,
+    profile_id: Option<&String>,
+) -> RouterResult<()> {
+    match payment_method_info {
+        Some(payment_method) => Ok(validate_payment_method_usage_restrictions(
+            &cards::get_payment_method_usage_restrictions(payment_method)?,
+            off_session,
+            amount,
+            profile_id,
+        )?),
+        None => Ok(()),
+    }
+}
+
+fn validate_payment_method_usage_restrictions(
+    usage_restrictions: &api_models::payment_methods::PaymentMethodUsageRestrictions,
+    off_session: Option<bool>,
+    amount: MinorUnit,
+    profile_id: Option<&String>,
+) -> Result<(), errors::ApiErrorResponse> {
+    if usage_restrictions.off_session_disabled && off_session == Some(true) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment method can not be used in off session payments".to_string(),
+        });
+    }
+
+    if let Some(max_amount) = usage_restrictions
+        .max_amount
+        .filter(|max_amount| amount > *max_amount)
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The payment method can not be charged more than {} in a payment",
+                max_amount.get_amount_as_i64()
+            ),
+        });
+    }
+
+    match &usage_restrictions.allowed_profile_ids {
+        Some(allowed_profile_ids)
+            if !profile_id.is_some_and(|profile_id| allowed_profile_ids.contains(profile_id)) =>
+        {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment method can not be used in the business profile of the \
+                          payment"
+                    .to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: AdditionalPaymentMethodConnectorResponse,
@@ -4880,3 +5773,40 @@ pub async fn config_skip_saving_wallet_at_connector(
         }
     })
 }
+
+/// Validates that a payment being created can be linked to the order, the payment having to be
+/// made in the currency of the order and with its business profile, if any
+pub async fn validate_order_for_payment(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    order_id: &str,
+    profile_id: &str,
+    currency: storage_enums::Currency,
+) -> RouterResult<()> {
+    let order = db
+        .find_order_by_merchant_id_order_id(merchant_id, order_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Order not found".to_string(),
+        })?;
+    if order.currency != currency {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "currency must be {} to link the payment to the order {order_id}",
+                order.currency
+            ),
+        }));
+    }
+    if order
+        .profile_id
+        .as_deref()
+        .is_some_and(|order_profile_id| order_profile_id != profile_id)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "the payment must be made with the business profile of the order {order_id}"
+            ),
+        }));
+    }
+    Ok(())
+}
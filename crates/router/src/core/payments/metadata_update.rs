@@ -0,0 +1,102 @@
+use api_models::{
+    audit_events::{AuditAction, AuditResourceType},
+    payments::{PaymentsMetadataUpdateRequest, PaymentsMetadataUpdateResponse},
+};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        audit_events,
+        errors::{self, RouterResponse, StorageErrorExt},
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// The largest number of characters of the reference of the merchant for a payment
+const MAX_MERCHANT_REFERENCE_LENGTH: usize = 255;
+
+/// Updates the metadata and the reference of the merchant for a payment, at any point of the
+/// lifecycle of the payment. The amounts and every other field of the payment are left untouched,
+/// and the change is recorded in the audit events of the merchant.
+#[instrument(skip_all)]
+pub async fn payments_metadata_update(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsMetadataUpdateRequest,
+) -> RouterResponse<PaymentsMetadataUpdateResponse> {
+    if req.metadata.is_none() && req.merchant_reference.is_none() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Either metadata or merchant_reference must be provided".to_string(),
+        }));
+    }
+    if req
+        .merchant_reference
+        .as_ref()
+        .is_some_and(|merchant_reference| {
+            merchant_reference.is_empty()
+                || merchant_reference.chars().count() > MAX_MERCHANT_REFERENCE_LENGTH
+        })
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "merchant_reference must be between 1 and {MAX_MERCHANT_REFERENCE_LENGTH} \
+                 characters long"
+            ),
+        }));
+    }
+
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let before = PaymentsMetadataUpdateResponse {
+        payment_id: payment_intent.payment_id.clone(),
+        metadata: payment_intent.metadata.clone(),
+        merchant_reference: payment_intent.merchant_reference.clone(),
+    };
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::MerchantDetailsUpdate {
+                metadata: req.metadata,
+                merchant_reference: req.merchant_reference,
+                updated_by: storage_scheme.to_string(),
+            },
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let after = PaymentsMetadataUpdateResponse {
+        payment_id: payment_intent.payment_id,
+        metadata: payment_intent.metadata,
+        merchant_reference: payment_intent.merchant_reference,
+    };
+
+    audit_events::record_audit_event(
+        &state,
+        merchant_id,
+        AuditResourceType::Payment,
+        &after.payment_id,
+        AuditAction::Update,
+        Some(&before),
+        Some(&after),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(after))
+}
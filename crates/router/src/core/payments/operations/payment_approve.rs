@@ -180,6 +180,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            amount_to_reverse: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -2,8 +2,8 @@ use std::marker::PhantomData;
 
 use api_models::enums::FrmSuggestion;
 use async_trait::async_trait;
-use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use common_utils::{ext_traits::AsyncExt, types::MinorUnit};
+use error_stack::{report, ResultExt};
 use router_derive;
 use router_env::{instrument, tracing};
 
@@ -81,6 +81,24 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        if let Some(amount_to_reverse) = request.amount_to_reverse {
+            if payment_intent.status != enums::IntentStatus::RequiresCapture {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "amount_to_reverse can only be provided for authorized payments"
+                        .to_string(),
+                }));
+            }
+            if amount_to_reverse <= MinorUnit::new(0)
+                || amount_to_reverse >= payment_attempt.amount_capturable
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "amount_to_reverse must be greater than zero and less than the \
+                        amount capturable"
+                        .to_string(),
+                }));
+            }
+        }
+
         let shipping_address = helpers::get_address_by_id(
             db,
             payment_intent.shipping_address_id.clone(),
@@ -193,6 +211,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            amount_to_reverse: request.amount_to_reverse,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
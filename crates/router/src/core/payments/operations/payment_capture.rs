@@ -239,6 +239,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            amount_to_reverse: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
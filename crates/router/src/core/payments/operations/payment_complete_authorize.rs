@@ -318,6 +318,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             authentication: None,
             recurring_details,
             poll_config: None,
+            amount_to_reverse: None,
         };
 
         let customer_details = Some(CustomerDetails {
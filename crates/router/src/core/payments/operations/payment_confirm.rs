@@ -23,8 +23,8 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, capture_schedule, helpers, operations, populate_surcharge_details,
+            tax_calculation, CustomerDetails, PaymentAddress, PaymentData,
         },
         utils as core_utils,
     },
@@ -90,7 +90,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
 
-        if [
+        if payment_confirm_source == Some(common_enums::PaymentSource::AsyncProcessing) {
+            // The payments confirmed in the background are marked as processing when scheduled
+            helpers::validate_payment_status_against_allowed_statuses(
+                &payment_intent.status,
+                &[storage_enums::IntentStatus::Processing],
+                "confirm",
+            )?;
+        } else if [
             Some(common_enums::PaymentSource::Webhook),
             Some(common_enums::PaymentSource::ExternalAuthenticator),
         ]
@@ -256,7 +263,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 api_models::enums::IntentStatus::RequiresCustomerAction
                 | api_models::enums::IntentStatus::RequiresMerchantAction
                 | api_models::enums::IntentStatus::RequiresPaymentMethod
-                | api_models::enums::IntentStatus::RequiresConfirmation => {
+                | api_models::enums::IntentStatus::RequiresConfirmation
+                // Only the payments confirmed in the background are confirmed while processing
+                | api_models::enums::IntentStatus::Processing => {
                     // Normal payment
                     // Parallel calls - level 1
                     let (payment_attempt, shipping_address, billing_address, business_profile, _) =
@@ -358,6 +367,20 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        tax_calculation::calculate_tax_for_shipping_address(
+            state,
+            key_store,
+            storage_scheme,
+            &business_profile,
+            &mut payment_intent,
+            &mut payment_attempt,
+            shipping_address
+                .as_ref()
+                .map(api_models::payments::Address::from)
+                .as_ref(),
+        )
+        .await?;
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -568,6 +591,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             (None, payment_method_info)
         };
 
+        helpers::validate_payment_method_usage(
+            payment_method_info.as_ref(),
+            request.off_session.or(payment_intent.off_session),
+            payment_attempt.get_total_amount(),
+            payment_intent.profile_id.as_ref(),
+        )?;
+
         // The operation merges mandate data from both request and payment_attempt
         let setup_mandate = mandate_data.map(|mut sm| {
             sm.mandate_type = payment_attempt.mandate_details.clone().or(sm.mandate_type);
@@ -601,6 +631,22 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .as_ref()
             .map(|payment_method_billing| payment_method_billing.address_id.clone());
 
+        if let Some(capture_at) = request.capture_at {
+            capture_schedule::validate_capture_at(
+                capture_at,
+                payment_attempt.capture_method,
+                payment_intent.created_at,
+            )?;
+            capture_schedule::schedule_capture(
+                &*state.store,
+                merchant_id,
+                &payment_id,
+                capture_at,
+                request.amount_to_capture,
+            )
+            .await?;
+        }
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -644,6 +690,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            amount_to_reverse: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -766,9 +813,9 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         &'a self,
         state: &SessionState,
         payment_data: &mut PaymentData<F>,
-        _merchant_account: &domain::MerchantAccount,
+        merchant_account: &domain::MerchantAccount,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
-        populate_surcharge_details(state, payment_data).await
+        populate_surcharge_details(state, merchant_account, payment_data).await
     }
 
     async fn call_external_three_ds_authentication_if_eligible<'a>(
@@ -803,6 +850,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
                     business_profile,
                     Some(acquirer_details),
                     Some(payment_data.payment_attempt.payment_id.clone()),
+                    None,
                 )
                 .await?;
                 if authentication.is_separate_authn_required()
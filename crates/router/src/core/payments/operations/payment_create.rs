@@ -23,10 +23,14 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     consts,
     core::{
+        connected_accounts,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_link,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, capture_schedule, helpers, operations, CustomerDetails, PaymentAddress,
+            PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -104,6 +108,24 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     id: profile_id.to_string(),
                 })?
         };
+
+        if let Some(order_id) = &request.order_id {
+            helpers::validate_order_for_payment(db, merchant_id, order_id, &profile_id, currency)
+                .await?;
+        }
+
+        let custom_fields_schema: Option<api_models::admin::CustomFieldsSchema> = business_profile
+            .custom_fields_schema
+            .clone()
+            .map(|schema| schema.parse_value("CustomFieldsSchema"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the custom fields schema of the business profile")?;
+        helpers::validate_custom_fields(
+            custom_fields_schema.as_ref(),
+            request.custom_fields.as_ref(),
+        )?;
+
         let customer_acceptance = request.customer_acceptance.clone().map(From::from);
 
         let recurring_details = request.recurring_details.clone();
@@ -244,11 +266,26 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             None
         };
 
+        // The payments of the connected accounts of a platform are split with the platform, unless
+        // the charges of the payment are given
+        let platform_charges = match request.charges {
+            Some(_) => None,
+            None => {
+                connected_accounts::get_platform_charges(
+                    db,
+                    merchant_account,
+                    MinorUnit::from(amount),
+                )
+                .await?
+            }
+        };
+
         let payment_intent_new = Self::make_payment_intent(
             &payment_id,
             merchant_account,
             money,
             request,
+            platform_charges.as_ref(),
             shipping_address
                 .as_ref()
                 .map(|address| address.address_id.clone()),
@@ -307,6 +344,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             request.setup_future_usage,
             mandate_details_present,
         )?;
+
+        if let Some(capture_at) = request.capture_at {
+            capture_schedule::schedule_capture(
+                db,
+                merchant_id,
+                &payment_id,
+                capture_at,
+                request.amount_to_capture,
+            )
+            .await?;
+        }
         // connector mandate reference update history
         let mandate_id = request
             .mandate_id
@@ -406,6 +454,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data.apply_additional_payment_data(additional_payment_data)
             });
 
+        if request.confirm == Some(true) {
+            helpers::validate_payment_method_usage(
+                payment_method_info.as_ref(),
+                request.off_session,
+                payment_attempt.get_total_amount(),
+                payment_intent.profile_id.as_ref(),
+            )?;
+        }
+
         let amount = payment_attempt.get_total_amount().into();
 
         let payment_data = PaymentData {
@@ -451,6 +508,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            amount_to_reverse: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -697,6 +755,13 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         })?;
 
         helpers::validate_amount_to_capture_and_capture_method(None, request)?;
+        if let Some(capture_at) = request.capture_at {
+            capture_schedule::validate_capture_at(
+                capture_at,
+                request.capture_method,
+                common_utils::date_time::now(),
+            )?;
+        }
         helpers::validate_card_data(
             request
                 .payment_method_data
@@ -948,6 +1013,9 @@ impl PaymentCreate {
                 charge_id: None,
                 client_source: None,
                 client_version: None,
+                failover_action: None,
+                unified_error_details: None,
+                amount_reversed: None,
             },
             additional_pm_data,
         ))
@@ -960,6 +1028,7 @@ impl PaymentCreate {
         merchant_account: &domain::MerchantAccount,
         money: (api::Amount, enums::Currency),
         request: &api::PaymentsRequest,
+        platform_charges: Option<&api_models::payments::PaymentChargeRequest>,
         shipping_address_id: Option<String>,
         payment_link_data: Option<api_models::payments::PaymentLinkResponse>,
         billing_address_id: Option<String>,
@@ -1013,6 +1082,7 @@ impl PaymentCreate {
         let charges = request
             .charges
             .as_ref()
+            .or(platform_charges)
             .map(|charges| {
                 charges.encode_to_value().map_err(|err| {
                     logger::warn!("Failed to serialize PaymentCharges - {}", err);
@@ -1070,6 +1140,10 @@ impl PaymentCreate {
                 .request_external_three_ds_authentication,
             charges,
             frm_metadata: request.frm_metadata.clone(),
+            tax_details: None,
+            order_id: request.order_id.clone(),
+            merchant_reference: request.merchant_reference.clone(),
+            custom_fields: request.custom_fields.clone().map(serde_json::Value::Object),
         })
     }
 
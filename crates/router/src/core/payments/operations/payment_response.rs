@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
+use api_models::alerts::AlertMetric;
 use async_trait::async_trait;
 use common_enums::AuthorizationStatus;
-use common_utils::{ext_traits::Encode, types::MinorUnit};
+use common_utils::{ext_traits::Encode, money::Money, types::MinorUnit};
 use error_stack::{report, ResultExt};
 use futures::FutureExt;
 use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
@@ -15,6 +16,7 @@ use super::{Operation, PostUpdateTracker};
 use crate::{
     connector::utils::PaymentResponseRouterData,
     core::{
+        alerts,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate, payment_methods,
         payments::{
@@ -35,7 +37,7 @@ use crate::{
         transformers::{ForeignFrom, ForeignTryFrom},
         CaptureSyncResponse, ErrorResponse,
     },
-    utils,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -127,6 +129,18 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
                     logger::error!("Failed to update last used at: {:?}", e);
                 })
                 .ok();
+
+                payment_methods::cards::promote_last_used_payment_method(
+                    state,
+                    merchant_account,
+                    key_store,
+                    payment_method_info,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to promote the last used payment method");
+                })
+                .ok();
             }
         };
 
@@ -415,7 +429,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
         merchant_account: &domain::MerchantAccount,
         _key_store: &domain::MerchantKeyStore,
         payment_data: &mut PaymentData<F>,
-        business_profile: &storage::business_profile::BusinessProfile,
+        _business_profile: &storage::business_profile::BusinessProfile,
     ) -> CustomResult<(), errors::ApiErrorResponse>
     where
         F: 'b + Clone + Send + Sync,
@@ -426,7 +440,6 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
             resp.status,
             resp.response.clone(),
             merchant_account.storage_scheme,
-            business_profile.is_connector_agnostic_mit_enabled,
         )
         .await?;
         Ok(())
@@ -500,13 +513,23 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
         db: &'b SessionState,
         payment_id: &api::PaymentIdType,
         mut payment_data: PaymentData<F>,
-        router_data: types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>,
+        mut router_data: types::RouterData<
+            F,
+            types::PaymentsCancelData,
+            types::PaymentsResponseData,
+        >,
         key_store: &domain::MerchantKeyStore,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
+        // A partially reversed authorization remains capturable for the rest of its amount
+        let amount_to_reverse = router_data.request.minor_amount_to_reverse;
+        if amount_to_reverse.is_some() && router_data.status == enums::AttemptStatus::Voided {
+            router_data.status = enums::AttemptStatus::Authorized;
+        }
+
         payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_id,
@@ -517,6 +540,28 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
         ))
         .await?;
 
+        if let Some(amount_to_reverse) = amount_to_reverse
+            .filter(|_| payment_data.payment_attempt.status == enums::AttemptStatus::Authorized)
+        {
+            let amount_reversed = payment_data
+                .payment_attempt
+                .amount_reversed
+                .unwrap_or_default()
+                + amount_to_reverse;
+            payment_data.payment_attempt = db
+                .store
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt.clone(),
+                    storage::PaymentAttemptUpdate::AuthorizationReversalUpdate {
+                        amount_reversed,
+                        updated_by: storage_scheme.to_string(),
+                    },
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        }
+
         Ok(payment_data)
     }
 }
@@ -715,7 +760,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
         merchant_account: &domain::MerchantAccount,
         _key_store: &domain::MerchantKeyStore,
         payment_data: &mut PaymentData<F>,
-        business_profile: &storage::business_profile::BusinessProfile,
+        _business_profile: &storage::business_profile::BusinessProfile,
     ) -> CustomResult<(), errors::ApiErrorResponse>
     where
         F: 'b + Clone + Send + Sync,
@@ -726,7 +771,6 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
             resp.status,
             resp.response.clone(),
             merchant_account.storage_scheme,
-            business_profile.is_connector_agnostic_mit_enabled,
         )
         .await?;
         Ok(())
@@ -760,6 +804,34 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .as_mut()
             .map(|info| info.status = status)
     });
+
+    // The outcome of the authorization is counted for the alert rules of the merchant, the
+    // authorizations still waiting on the customer or the connector being left out
+    if core_utils::get_flow_name::<F>()? == "Authorize" {
+        let is_authorized = match router_data.response {
+            Err(_) => Some(false),
+            Ok(_) => match router_data.status {
+                enums::AttemptStatus::Authorized
+                | enums::AttemptStatus::Charged
+                | enums::AttemptStatus::PartialCharged
+                | enums::AttemptStatus::PartialChargedAndChargeable => Some(true),
+                enums::AttemptStatus::AuthenticationFailed
+                | enums::AttemptStatus::AuthorizationFailed
+                | enums::AttemptStatus::Failure => Some(false),
+                _ => None,
+            },
+        };
+        if let Some(is_authorized) = is_authorized {
+            alerts::record_alert_signal(
+                state,
+                &payment_data.payment_attempt.merchant_id,
+                AlertMetric::AuthorizationSuccessRate,
+                None,
+                is_authorized,
+            );
+        }
+    }
+
     let (capture_update, mut payment_attempt_update) = match router_data.response.clone() {
         Err(err) => {
             let auth_update = if Some(router_data.auth_type)
@@ -805,6 +877,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         flow_name.clone(),
                     )
                     .await;
+                    let unified_error_details =
+                        payments_helpers::get_unified_error_details(option_gsm.as_ref())?;
 
                     let status = match err.attempt_status {
                         // Use the status sent by connector in error_response if it's present
@@ -848,6 +922,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             updated_by: storage_scheme.to_string(),
                             unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
                             unified_message: option_gsm.map(|gsm| gsm.unified_message),
+                            unified_error_details,
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
                             authentication_type: auth_update,
@@ -1094,11 +1169,18 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             }
 
             let authorized_amount = payment_data.payment_attempt.get_total_amount();
+            let currency = payment_data
+                .payment_attempt
+                .currency
+                .get_required_value("currency")?;
+            let amount_capturable = multiple_capture_data
+                .get_amount_capturable(Money::new(authorized_amount, currency))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to compute the amount capturable from the captures")?;
 
             payment_attempt_update = Some(storage::PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status: multiple_capture_data.get_attempt_status(authorized_amount),
-                amount_capturable: authorized_amount
-                    - multiple_capture_data.get_total_blocked_amount(),
+                amount_capturable: amount_capturable.get_amount(),
                 updated_by: storage_scheme.to_string(),
             });
             Some(multiple_capture_data)
@@ -1306,7 +1388,6 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
     attempt_status: common_enums::AttemptStatus,
     payment_response: Result<types::PaymentsResponseData, ErrorResponse>,
     storage_scheme: enums::MerchantStorageScheme,
-    is_connector_agnostic_mit_enabled: Option<bool>,
 ) -> RouterResult<()> {
     if let Some(id) = &payment_data.payment_attempt.payment_method_id {
         let pm = state
@@ -1325,20 +1406,11 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
     .ok()
     .flatten();
 
-        let network_transaction_id =
-            if let Some(network_transaction_id) = pm_resp_network_transaction_id {
-                if is_connector_agnostic_mit_enabled == Some(true)
-                    && payment_data.payment_intent.setup_future_usage
-                        == Some(diesel_models::enums::FutureUsage::OffSession)
-                {
-                    Some(network_transaction_id)
-                } else {
-                    logger::info!("Skip storing network transaction id");
-                    None
-                }
-            } else {
-                None
-            };
+        let network_transaction_id = payments_helpers::get_network_transaction_id_to_store(
+            pm_resp_network_transaction_id,
+            payment_data.payment_intent.setup_future_usage,
+            pm.network_transaction_id.as_ref(),
+        );
 
         let pm_update = if pm.status != common_enums::PaymentMethodStatus::Active
             && pm.status != attempt_status.into()
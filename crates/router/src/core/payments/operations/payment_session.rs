@@ -11,7 +11,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{self, helpers, operations, PaymentData},
+        payments::{self, helpers, operations, tax_calculation, PaymentData},
     },
     db::StorageInterface,
     routes::{app::ReqState, SessionState},
@@ -86,8 +86,6 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
 
         payment_attempt.payment_method = Some(storage_enums::PaymentMethod::Wallet);
 
-        let amount = payment_attempt.get_total_amount().into();
-
         let shipping_address = helpers::get_address_by_id(
             db,
             payment_intent.shipping_address_id.clone(),
@@ -161,6 +159,22 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 id: profile_id.to_string(),
             })?;
 
+        tax_calculation::calculate_tax_for_shipping_address(
+            state,
+            key_store,
+            storage_scheme,
+            &business_profile,
+            &mut payment_intent,
+            &mut payment_attempt,
+            shipping_address
+                .as_ref()
+                .map(api_models::payments::Address::from)
+                .as_ref(),
+        )
+        .await?;
+
+        let amount = payment_attempt.get_total_amount().into();
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -204,6 +218,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            amount_to_reverse: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -475,6 +475,7 @@ async fn get_tracker_for_sync<
         authentication,
         recurring_details: None,
         poll_config: None,
+        amount_to_reverse: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -1,5 +1,6 @@
 use std::{str::FromStr, vec::IntoIter};
 
+use api_models::feature_flags::FeatureFlag;
 use common_utils::{ext_traits::Encode, types::MinorUnit};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
@@ -11,6 +12,7 @@ use router_env::{
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        feature_flags,
         payments::{
             self,
             flows::{ConstructFlowSpecificData, Feature},
@@ -237,6 +239,9 @@ pub async fn get_gsm<F, FData>(
 pub fn get_gsm_decision(
     option_gsm: Option<storage::gsm::GatewayStatusMap>,
 ) -> api_models::gsm::GsmDecision {
+    let decline_category = option_gsm
+        .as_ref()
+        .and_then(payments::helpers::get_decline_category);
     let option_gsm_decision = option_gsm
             .and_then(|gsm| {
                 api_models::gsm::GsmDecision::from_str(gsm.decision.as_str())
@@ -252,7 +257,16 @@ pub fn get_gsm_decision(
     if option_gsm_decision.is_some() {
         metrics::AUTO_RETRY_GSM_MATCH_COUNT.add(&metrics::CONTEXT, 1, &[]);
     }
-    option_gsm_decision.unwrap_or_default()
+
+    // The errors that would occur again when retried, such as hard declines, are not retried
+    // whatever the decision of the gsm
+    match decline_category {
+        Some(decline_category) if !decline_category.is_retryable() => {
+            logger::info!(%decline_category, "Skipping the retry of the non retryable error");
+            api_models::gsm::GsmDecision::DoDefault
+        }
+        _ => option_gsm_decision.unwrap_or_default(),
+    }
 }
 
 #[inline]
@@ -423,6 +437,8 @@ where
         }
         Err(ref error_response) => {
             let option_gsm = get_gsm(state, &router_data).await?;
+            let unified_error_details =
+                payments::helpers::get_unified_error_details(option_gsm.as_ref())?;
             let auth_update = if Some(router_data.auth_type)
                 != payment_data.payment_attempt.authentication_type
             {
@@ -443,6 +459,7 @@ where
                     updated_by: storage_scheme.to_string(),
                     unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
                     unified_message: option_gsm.map(|gsm| gsm.unified_message),
+                    unified_error_details,
                     connector_transaction_id: error_response.connector_transaction_id.clone(),
                     payment_method_data: additional_payment_method_data,
                     authentication_type: auth_update,
@@ -530,20 +547,31 @@ pub fn make_new_payment_attempt(
     }
 }
 
-pub async fn config_should_call_gsm(db: &dyn StorageInterface, merchant_id: &String) -> bool {
+/// Whether the failed payments of the business profile of the merchant are retried according to
+/// the gateway status map, either through the config of the merchant or through the smart
+/// retries feature flag
+pub async fn config_should_call_gsm(
+    db: &dyn StorageInterface,
+    merchant_id: &String,
+    profile_id: Option<&str>,
+) -> bool {
     let config = db
         .find_config_by_key_unwrap_or(
             format!("should_call_gsm_{}", merchant_id).as_str(),
             Some("false".to_string()),
         )
         .await;
-    match config {
+    let is_enabled_by_config = match config {
         Ok(conf) => conf.config == "true",
         Err(err) => {
             logger::error!("{err}");
             false
         }
-    }
+    };
+
+    is_enabled_by_config
+        || feature_flags::is_feature_enabled(db, FeatureFlag::SmartRetries, merchant_id, profile_id)
+            .await
 }
 
 pub trait GsmValidation<F: Send + Clone + Sync, FData: Send + Sync, Resp> {
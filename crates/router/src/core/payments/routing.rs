@@ -4,7 +4,7 @@ use std::{
     collections::{hash_map, HashMap},
     hash::{Hash, Hasher},
     str::FromStr,
-    sync::Arc,
+    sync::{Arc, Mutex, Weak},
 };
 
 use api_models::{
@@ -14,7 +14,7 @@ use api_models::{
     routing::ConnectorSelection,
 };
 use diesel_models::enums as storage_enums;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use euclid::{
     backend::{self, inputs as dsl_inputs, EuclidBackend},
     dssa::graph::{self as euclid_graph, CgraphExt},
@@ -27,12 +27,17 @@ use kgraph_utils::{
     types::CountryCurrencyFilter,
 };
 use masking::PeekInterface;
+use once_cell::sync::Lazy;
 use rand::{
     distributions::{self, Distribution},
     SeedableRng,
 };
+use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
-use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+use storage_impl::redis::cache::{
+    CacheKey, CGRAPH_CACHE, MISSING_ROUTING_ALGORITHM_CACHE, ROUTING_CACHE,
+};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
 
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
@@ -40,9 +45,10 @@ use crate::core::payouts;
 use crate::utils::StringExt;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        errors, errors as oss_errors, metrics, payments as payments_oss, pm_filters,
         routing::{self, helpers as routing_helpers},
     },
+    events::audit_events::{AuditEvent, AuditEventType},
     logger,
     types::{
         api::{self, routing as routing_types},
@@ -53,6 +59,32 @@ use crate::{
     SessionState,
 };
 
+/// The locks of the routing algorithms and cgraphs being rebuilt, so that when a cached value
+/// expires only one task rebuilds it while the other tasks wait for the rebuilt value
+static CACHE_REFRESH_LOCKS: Lazy<Mutex<HashMap<String, Weak<AsyncMutex<()>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Waits for the other tasks rebuilding the cached value of the key to be done. The locks are
+/// dropped once no task holds or waits for them.
+async fn lock_cache_refresh(key: CacheKey) -> RoutingResult<OwnedMutexGuard<()>> {
+    let key = String::from(key);
+    let lock = {
+        let mut locks = CACHE_REFRESH_LOCKS
+            .lock()
+            .map_err(|_| errors::RoutingError::DslCachePoisoned)?;
+        match locks.get(&key).and_then(Weak::upgrade) {
+            Some(lock) => lock,
+            None => {
+                locks.retain(|_, lock| lock.strong_count() > 0);
+                let lock = Arc::new(AsyncMutex::new(()));
+                locks.insert(key, Arc::downgrade(&lock));
+                lock
+            }
+        }
+    };
+    Ok(lock.lock_owned().await)
+}
+
 pub enum CachedAlgorithm {
     Single(Box<routing_types::RoutableConnectorChoice>),
     Priority(Vec<routing_types::RoutableConnectorChoice>),
@@ -135,6 +167,7 @@ pub fn make_dsl_input_for_payouts(
             .and_then(|bic| bic.country)
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
+        device_fingerprint: None,
         setup_future_usage: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
@@ -205,6 +238,7 @@ where
             .as_ref()
             .and_then(|pm_data| match pm_data {
                 api::PaymentMethodData::Card(card) => card.card_network.clone(),
+                api::PaymentMethodData::CardToken(card_token) => card_token.card_network.clone(),
 
                 _ => None,
             }),
@@ -238,6 +272,9 @@ where
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
         business_label: payment_data.payment_intent.business_label.clone(),
+        device_fingerprint: payments_oss::helpers::get_device_fingerprint_from_browser_info(
+            payment_data.payment_attempt.browser_info.as_ref(),
+        ),
         setup_future_usage: payment_data.payment_intent.setup_future_usage,
     };
 
@@ -262,6 +299,7 @@ where
     })
 }
 
+#[instrument(skip_all)]
 pub async fn perform_static_routing_v1<F: Clone>(
     state: &SessionState,
     merchant_id: &str,
@@ -366,27 +404,43 @@ async fn ensure_algorithm_cached_v1(
         }
     };
 
+    let cache_key = CacheKey {
+        key: key.clone(),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
     let cached_algorithm = ROUTING_CACHE
-        .get_val::<Arc<CachedAlgorithm>>(CacheKey {
-            key: key.clone(),
-            prefix: state.tenant.redis_key_prefix.clone(),
-        })
+        .get_val::<Arc<CachedAlgorithm>>(cache_key.clone())
         .await;
+    if let Some(algo) = cached_algorithm {
+        return Ok(algo);
+    }
 
-    let algorithm = if let Some(algo) = cached_algorithm {
-        algo
-    } else {
-        refresh_routing_cache_v1(
-            state,
-            key.clone(),
-            algorithm_id,
-            #[cfg(feature = "business_profile_routing")]
-            profile_id,
-        )
-        .await?
-    };
+    let _refresh_guard = lock_cache_refresh(cache_key.clone()).await?;
+    // The algorithm may have been rebuilt by the task holding the lock while waiting for it
+    let cached_algorithm = ROUTING_CACHE
+        .get_val::<Arc<CachedAlgorithm>>(cache_key.clone())
+        .await;
+    if let Some(algo) = cached_algorithm {
+        return Ok(algo);
+    }
+
+    let is_missing = MISSING_ROUTING_ALGORITHM_CACHE
+        .get_val::<String>(cache_key)
+        .await
+        .is_some_and(|missing_algorithm_id| missing_algorithm_id == algorithm_id);
+    if is_missing {
+        return Err(report!(errors::RoutingError::DslMissingInDb))
+            .attach_printable("Routing algorithm was recently found missing from the DB");
+    }
 
-    Ok(algorithm)
+    refresh_routing_cache_v1(
+        state,
+        key,
+        algorithm_id,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await
 }
 
 pub fn perform_straight_through_routing(
@@ -439,14 +493,22 @@ pub async fn refresh_routing_cache_v1(
 ) -> RoutingResult<Arc<CachedAlgorithm>> {
     #[cfg(feature = "business_profile_routing")]
     let algorithm = {
-        let algorithm = state
+        let algorithm = match state
             .store
             .find_routing_algorithm_by_profile_id_algorithm_id(
-                &profile_id.unwrap_or_default(),
+                &profile_id.clone().unwrap_or_default(),
                 algorithm_id,
             )
             .await
-            .change_context(errors::RoutingError::DslMissingInDb)?;
+        {
+            Ok(algorithm) => algorithm,
+            Err(error) => {
+                if error.current_context().is_db_not_found() {
+                    cache_missing_routing_algorithm(state, key, algorithm_id, profile_id).await;
+                }
+                return Err(error.change_context(errors::RoutingError::DslMissingInDb));
+            }
+        };
         let algorithm: routing_types::RoutingAlgorithm = algorithm
             .algorithm_data
             .parse_value("RoutingAlgorithm")
@@ -456,12 +518,16 @@ pub async fn refresh_routing_cache_v1(
 
     #[cfg(not(feature = "business_profile_routing"))]
     let algorithm = {
-        let config = state
-            .store
-            .find_config_by_key(algorithm_id)
-            .await
-            .change_context(errors::RoutingError::DslMissingInDb)
-            .attach_printable("DSL not found in DB")?;
+        let config = match state.store.find_config_by_key(algorithm_id).await {
+            Ok(config) => config,
+            Err(error) => {
+                if error.current_context().is_db_not_found() {
+                    cache_missing_routing_algorithm(state, key, algorithm_id, None).await;
+                }
+                return Err(error.change_context(errors::RoutingError::DslMissingInDb))
+                    .attach_printable("DSL not found in DB");
+            }
+        };
 
         let algorithm: routing_types::RoutingAlgorithm = config
             .config
@@ -500,6 +566,41 @@ pub async fn refresh_routing_cache_v1(
     Ok(arc_cached_algorithm)
 }
 
+/// Remembers for a short while that the routing algorithm of the key is missing from the DB, so
+/// that the payments of a misconfigured business profile fail fast instead of querying the DB
+/// every time. The algorithm is forgotten as soon as a routing algorithm is activated for the key.
+async fn cache_missing_routing_algorithm(
+    state: &SessionState,
+    key: String,
+    algorithm_id: &str,
+    profile_id: Option<String>,
+) {
+    logger::error!(
+        %algorithm_id,
+        ?profile_id,
+        "Active routing algorithm is missing from the DB"
+    );
+    metrics::ROUTING_ALGORITHM_MISSING.add(&metrics::CONTEXT, 1, &[]);
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::RoutingAlgorithmMissing {
+            algorithm_id: algorithm_id.to_string(),
+            profile_id,
+        }))
+        .emit();
+
+    MISSING_ROUTING_ALGORITHM_CACHE
+        .push(
+            CacheKey {
+                key,
+                prefix: state.tenant.redis_key_prefix.clone(),
+            },
+            algorithm_id.to_string(),
+        )
+        .await;
+}
+
 pub fn perform_volume_split(
     mut splits: Vec<routing_types::ConnectorVolumeSplit>,
     rng_seed: Option<&str>,
@@ -564,30 +665,39 @@ pub async fn get_merchant_cgraph<'a>(
         api_enums::TransactionType::Payout => format!("cgraph_po_{}", merchant_id),
     };
 
+    let cache_key = CacheKey {
+        key: key.clone(),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
     let cached_cgraph = CGRAPH_CACHE
         .get_val::<Arc<hyperswitch_constraint_graph::ConstraintGraph<'_, euclid_dir::DirValue>>>(
-            CacheKey {
-                key: key.clone(),
-                prefix: state.tenant.redis_key_prefix.clone(),
-            },
+            cache_key.clone(),
         )
         .await;
+    if let Some(graph) = cached_cgraph {
+        return Ok(graph);
+    }
 
-    let cgraph = if let Some(graph) = cached_cgraph {
-        graph
-    } else {
-        refresh_cgraph_cache(
-            state,
-            key_store,
-            key.clone(),
-            #[cfg(feature = "business_profile_routing")]
-            profile_id,
-            transaction_type,
+    let _refresh_guard = lock_cache_refresh(cache_key.clone()).await?;
+    // The cgraph may have been rebuilt by the task holding the lock while waiting for it
+    let cached_cgraph = CGRAPH_CACHE
+        .get_val::<Arc<hyperswitch_constraint_graph::ConstraintGraph<'_, euclid_dir::DirValue>>>(
+            cache_key,
         )
-        .await?
-    };
+        .await;
+    if let Some(graph) = cached_cgraph {
+        return Ok(graph);
+    }
 
-    Ok(cgraph)
+    refresh_cgraph_cache(
+        state,
+        key_store,
+        key,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+        transaction_type,
+    )
+    .await
 }
 
 pub async fn refresh_cgraph_cache<'a>(
@@ -599,11 +709,7 @@ pub async fn refresh_cgraph_cache<'a>(
 ) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<'a, euclid_dir::DirValue>>> {
     let mut merchant_connector_accounts = state
         .store
-        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
-            &key_store.merchant_id,
-            false,
-            key_store,
-        )
+        .find_merchant_connector_accounts_for_cgraph(&key_store.merchant_id, key_store)
         .await
         .change_context(errors::RoutingError::KgraphCacheRefreshFailed)?;
 
@@ -634,9 +740,11 @@ pub async fn refresh_cgraph_cache<'a>(
         .map(admin_api::MerchantConnectorResponse::try_from)
         .collect::<Result<Vec<_>, _>>()
         .change_context(errors::RoutingError::KgraphCacheRefreshFailed)?;
-    let connector_configs = state
-        .conf
-        .pm_filters
+    let pm_filters = pm_filters::get_pm_filters(state)
+        .await
+        .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
+        .attach_printable("Failed to fetch the payment method filters")?;
+    let connector_configs = pm_filters
         .0
         .clone()
         .into_iter()
@@ -648,9 +756,7 @@ pub async fn refresh_cgraph_cache<'a>(
             Ok((key, value.foreign_into()))
         })
         .collect::<Result<HashMap<_, _>, errors::RoutingError>>()?;
-    let default_configs = state
-        .conf
-        .pm_filters
+    let default_configs = pm_filters
         .0
         .get("default")
         .cloned()
@@ -730,6 +836,7 @@ async fn perform_cgraph_filtering(
     Ok(final_selection)
 }
 
+#[instrument(skip_all)]
 pub async fn perform_eligibility_analysis<F: Clone>(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -757,6 +864,7 @@ pub async fn perform_eligibility_analysis<F: Clone>(
     .await
 }
 
+#[instrument(skip_all)]
 pub async fn perform_fallback_routing<F: Clone>(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -803,6 +911,7 @@ pub async fn perform_fallback_routing<F: Clone>(
     .await
 }
 
+#[instrument(skip_all)]
 pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -852,6 +961,7 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
     Ok(final_selection)
 }
 
+#[instrument(skip_all)]
 pub async fn perform_session_flow_routing(
     session_input: SessionFlowRoutingInput<'_>,
     transaction_type: &api_enums::TransactionType,
@@ -926,6 +1036,9 @@ pub async fn perform_session_flow_routing(
             .country
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
+        device_fingerprint: payments_oss::helpers::get_device_fingerprint_from_browser_info(
+            session_input.payment_attempt.browser_info.as_ref(),
+        ),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
     };
 
@@ -1157,6 +1270,9 @@ pub fn make_dsl_input_for_surcharge(
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
+        device_fingerprint: payments_oss::helpers::get_device_fingerprint_from_browser_info(
+            payment_attempt.browser_info.as_ref(),
+        ),
         setup_future_usage: payment_intent.setup_future_usage,
     };
     let metadata = payment_intent
@@ -0,0 +1,53 @@
+use api_models::payments::{PaymentStatusHistoryResponse, PaymentStatusTransition};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// Retrieves the changes of the status of the payment and of its attempts, along with what caused
+/// each of them
+#[instrument(skip_all)]
+pub async fn retrieve_payment_status_history(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<PaymentStatusHistoryResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let transitions = db
+        .list_payment_status_transitions(&merchant_account.merchant_id, &payment_intent.payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the status transitions of the payment")?;
+
+    Ok(ApplicationResponse::Json(PaymentStatusHistoryResponse {
+        payment_id: payment_intent.payment_id,
+        transitions: transitions
+            .into_iter()
+            .map(|transition| PaymentStatusTransition {
+                attempt_id: transition.attempt_id,
+                object_type: transition.object_type,
+                previous_status: transition.previous_status,
+                status: transition.status,
+                actor: transition.actor,
+                request_id: transition.request_id,
+                created_at: transition.created_at,
+            })
+            .collect(),
+    }))
+}
@@ -0,0 +1,360 @@
+use api_models::{
+    admin::{TaxProvider, TaxRule},
+    enums as api_enums,
+    payments::{Address, AddressDetails, TaxBreakdown, TaxDetails},
+};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::{BytesExt, Encode, ValueExt},
+    request::RequestContent,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult, StorageErrorExt},
+    headers,
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+/// The tax details of a payment as stored on the payment intent
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentTaxDetails {
+    pub order_amount: MinorUnit,
+    pub order_tax_amount: MinorUnit,
+    pub breakdown: Vec<TaxBreakdown>,
+    /// Digest of the parts of the shipping address the taxes were calculated for, used to detect
+    /// changes of the shipping address without storing it
+    pub shipping_address_key: String,
+}
+
+impl From<PaymentTaxDetails> for TaxDetails {
+    fn from(tax_details: PaymentTaxDetails) -> Self {
+        Self {
+            order_amount: tax_details.order_amount,
+            order_tax_amount: tax_details.order_tax_amount,
+            breakdown: tax_details.breakdown,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ExternalTaxCalculationRequest {
+    merchant_id: String,
+    payment_id: String,
+    currency: api_enums::Currency,
+    order_amount: MinorUnit,
+    order_details: Option<Vec<Secret<serde_json::Value>>>,
+    shipping_address: ExternalTaxShippingAddress,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ExternalTaxShippingAddress {
+    country: Option<api_enums::CountryAlpha2>,
+    state: Option<Secret<String>>,
+    city: Option<String>,
+    zip: Option<Secret<String>>,
+    line1: Option<Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ExternalTaxCalculationResponse {
+    order_tax_amount: MinorUnit,
+    #[serde(default)]
+    breakdown: Vec<TaxBreakdown>,
+}
+
+pub fn get_tax_provider(
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<Option<TaxProvider>> {
+    business_profile
+        .tax_provider
+        .clone()
+        .map(|tax_provider| tax_provider.parse_value("TaxProvider"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the tax provider of the business profile")
+}
+
+pub fn get_payment_tax_details(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<PaymentTaxDetails>> {
+    payment_intent
+        .tax_details
+        .clone()
+        .map(|tax_details| tax_details.parse_value("PaymentTaxDetails"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the tax details of the payment intent")
+}
+
+pub fn validate_tax_provider(state: &SessionState, tax_provider: &TaxProvider) -> RouterResult<()> {
+    match tax_provider {
+        TaxProvider::StaticRules { rules } => {
+            if rules.is_empty() {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "tax_provider.rules must contain at least one rule".to_string(),
+                }));
+            }
+            Ok(())
+        }
+        TaxProvider::External { provider_name } => {
+            if !state
+                .conf
+                .tax_providers
+                .get_inner()
+                .0
+                .contains_key(provider_name)
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("tax provider {provider_name} is not configured"),
+                }));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Calculates the taxes on the order of the payment with the tax provider of the business profile,
+/// when the shipping address of the payment has changed since they were last calculated.
+///
+/// The amount of the payment intent and of the payment attempt is updated to the order amount
+/// including the taxes, and the tax details are persisted on the payment intent.
+#[instrument(skip_all)]
+pub async fn calculate_tax_for_shipping_address(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_intent: &mut storage::PaymentIntent,
+    payment_attempt: &mut storage::PaymentAttempt,
+    shipping_address: Option<&Address>,
+) -> RouterResult<()> {
+    let Some(tax_provider) = get_tax_provider(business_profile)? else {
+        return Ok(());
+    };
+    let Some(shipping_address) = shipping_address.and_then(|address| address.address.as_ref())
+    else {
+        return Ok(());
+    };
+
+    let shipping_address_key = get_shipping_address_key(shipping_address)?;
+    let existing_tax_details = get_payment_tax_details(payment_intent)?;
+    // The amount of the payment may have been updated after the taxes were calculated, in which
+    // case the updated amount is the new order amount
+    let existing_tax_details = existing_tax_details.filter(|tax_details| {
+        tax_details
+            .order_amount
+            .checked_add(tax_details.order_tax_amount)
+            == Some(payment_intent.amount)
+    });
+    if let Some(tax_details) = &existing_tax_details {
+        if tax_details.shipping_address_key == shipping_address_key {
+            payment_attempt.amount = payment_intent.amount;
+            payment_attempt.net_amount = payment_attempt.get_total_amount();
+            return Ok(());
+        }
+    }
+    let order_amount = existing_tax_details
+        .map(|tax_details| tax_details.order_amount)
+        .unwrap_or(payment_intent.amount);
+
+    let (order_tax_amount, breakdown) = match &tax_provider {
+        TaxProvider::StaticRules { rules } => {
+            calculate_tax_with_rules(rules, order_amount, shipping_address)?
+        }
+        TaxProvider::External { provider_name } => {
+            let config = state
+                .conf
+                .tax_providers
+                .get_inner()
+                .0
+                .get(provider_name)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Tax provider {provider_name} is not configured")
+                })?;
+            calculate_tax_with_external_provider(
+                state,
+                config,
+                payment_intent,
+                order_amount,
+                shipping_address,
+            )
+            .await?
+        }
+    };
+
+    let tax_details = PaymentTaxDetails {
+        order_amount,
+        order_tax_amount,
+        breakdown,
+        shipping_address_key,
+    };
+    let amount = order_amount
+        .checked_add(order_tax_amount)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The order amount with the taxes overflowed")?;
+    let tax_details_value = tax_details
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the tax details of the payment")?;
+
+    state
+        .store
+        .update_payment_intent(
+            payment_intent.clone(),
+            storage::PaymentIntentUpdate::TaxDetailsUpdate {
+                amount,
+                tax_details: tax_details_value.clone(),
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_intent.amount = amount;
+    payment_intent.tax_details = Some(tax_details_value);
+    payment_attempt.amount = amount;
+    payment_attempt.net_amount = payment_attempt.get_total_amount();
+
+    Ok(())
+}
+
+fn get_shipping_address_key(shipping_address: &AddressDetails) -> RouterResult<String> {
+    let key = format!(
+        "{}|{}|{}|{}|{}",
+        shipping_address
+            .country
+            .map(|country| country.to_string())
+            .unwrap_or_default(),
+        shipping_address
+            .state
+            .as_ref()
+            .map(|state| state.peek().to_uppercase())
+            .unwrap_or_default(),
+        shipping_address
+            .city
+            .as_ref()
+            .map(|city| city.to_uppercase())
+            .unwrap_or_default(),
+        shipping_address
+            .zip
+            .as_ref()
+            .map(|zip| zip.peek().to_uppercase())
+            .unwrap_or_default(),
+        shipping_address
+            .line1
+            .as_ref()
+            .map(|line1| line1.peek().to_uppercase())
+            .unwrap_or_default(),
+    );
+    let digest = crypto::Sha256
+        .generate_digest(key.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the digest of the shipping address")?;
+    Ok(hex::encode(digest))
+}
+
+fn calculate_tax_with_rules(
+    rules: &[TaxRule],
+    order_amount: MinorUnit,
+    shipping_address: &AddressDetails,
+) -> RouterResult<(MinorUnit, Vec<TaxBreakdown>)> {
+    let Some(country) = shipping_address.country else {
+        return Ok((MinorUnit::new(0), vec![]));
+    };
+    let shipping_state = shipping_address.state.as_ref().map(PeekInterface::peek);
+
+    let breakdown = rules
+        .iter()
+        .filter(|rule| {
+            rule.country == country
+                && rule.state.as_ref().map_or(true, |state| {
+                    shipping_state
+                        .is_some_and(|shipping_state| state.eq_ignore_ascii_case(shipping_state))
+                })
+        })
+        .map(|rule| {
+            rule.rate
+                .apply_and_ceil_result(order_amount)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to apply the tax rate on the order amount")
+                .map(|amount| TaxBreakdown {
+                    name: rule.name.clone(),
+                    amount,
+                    rate: Some(rule.rate.get_percentage()),
+                })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+    let order_tax_amount = breakdown
+        .iter()
+        .try_fold(MinorUnit::new(0), |total, tax| {
+            total.checked_add(tax.amount)
+        })
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The taxes on the order amount overflowed")?;
+
+    Ok((order_tax_amount, breakdown))
+}
+
+async fn calculate_tax_with_external_provider(
+    state: &SessionState,
+    config: &settings::TaxProviderConfig,
+    payment_intent: &storage::PaymentIntent,
+    order_amount: MinorUnit,
+    shipping_address: &AddressDetails,
+) -> RouterResult<(MinorUnit, Vec<TaxBreakdown>)> {
+    let payload = ExternalTaxCalculationRequest {
+        merchant_id: payment_intent.merchant_id.clone(),
+        payment_id: payment_intent.payment_id.clone(),
+        currency: payment_intent
+            .currency
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Currency not set in payment intent")?,
+        order_amount,
+        order_details: payment_intent.order_details.clone(),
+        shipping_address: ExternalTaxShippingAddress {
+            country: shipping_address.country,
+            state: shipping_address.state.clone(),
+            city: shipping_address.city.clone(),
+            zip: shipping_address.zip.clone(),
+            line1: shipping_address.line1.clone(),
+        },
+    };
+    let mut request = services::Request::new(
+        services::Method::Post,
+        &format!("{}/tax/calculate", config.base_url),
+    );
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        format!("Bearer {}", config.api_key.clone().expose()).into_masked(),
+    );
+    request.set_body(RequestContent::Json(Box::new(payload)));
+
+    let response = services::call_connector_api(state, request, "calculate_tax")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while calling the tax provider")?
+        .map_err(|response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "The tax provider failed to calculate the taxes with status code {}",
+                response.status_code
+            ))
+        })?;
+    let response: ExternalTaxCalculationResponse = response
+        .response
+        .parse_struct("ExternalTaxCalculationResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the response of the tax provider")?;
+
+    Ok((response.order_tax_amount, response.breakdown))
+}
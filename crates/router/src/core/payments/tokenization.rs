@@ -90,20 +90,13 @@ where
                 _ => None,
             };
 
-            let network_transaction_id =
-                if let Some(network_transaction_id) = network_transaction_id {
-                    if business_profile.is_connector_agnostic_mit_enabled == Some(true)
-                        && save_payment_method_data.request.get_setup_future_usage()
-                            == Some(storage_enums::FutureUsage::OffSession)
-                    {
-                        Some(network_transaction_id)
-                    } else {
-                        logger::info!("Skip storing network transaction id");
-                        None
-                    }
-                } else {
-                    None
-                };
+            // The network transaction id is persisted irrespective of whether connector agnostic
+            // MIT is enabled for the profile, so that it is available if the merchant enables it
+            let network_transaction_id = helpers::get_network_transaction_id_to_store(
+                network_transaction_id,
+                save_payment_method_data.request.get_setup_future_usage(),
+                None,
+            );
 
             let connector_token = if token_store {
                 let tokens = save_payment_method_data
@@ -200,6 +193,7 @@ where
                     Box::pin(save_in_locker(
                         state,
                         merchant_account,
+                        business_profile,
                         payment_method_create_request.to_owned(),
                     ))
                     .await?
@@ -435,21 +429,22 @@ where
                                 )
                                 .await?;
 
-                                let add_card_resp = payment_methods::cards::add_card_hs(
-                                    state,
-                                    payment_method_create_request,
-                                    &card,
-                                    &customer_id,
-                                    merchant_account,
-                                    api::enums::LockerChoice::HyperswitchCardVault,
-                                    Some(
-                                        existing_pm
-                                            .locker_id
-                                            .as_ref()
-                                            .unwrap_or(&existing_pm.payment_method_id),
-                                    ),
-                                )
-                                .await;
+                                let add_card_resp =
+                                    Box::pin(payment_methods::cards::add_card_to_locker(
+                                        state,
+                                        payment_method_create_request,
+                                        &card,
+                                        &customer_id,
+                                        merchant_account,
+                                        None,
+                                        Some(
+                                            existing_pm
+                                                .locker_id
+                                                .as_ref()
+                                                .unwrap_or(&existing_pm.payment_method_id),
+                                        ),
+                                    ))
+                                    .await;
 
                                 if let Err(err) = add_card_resp {
                                     logger::error!(vault_err=?err);
@@ -496,6 +491,9 @@ where
                                         .or(existing_pm_data.card_network),
                                     card_issuer: card.card_issuer.or(existing_pm_data.card_issuer),
                                     card_type: card.card_type.or(existing_pm_data.card_type),
+                                    card_tier: None,
+                                    card_funding_type: None,
+                                    is_regulated: None,
                                     saved_to_locker: true,
                                 });
 
@@ -655,6 +653,9 @@ async fn skip_saving_card_in_locker(
                 card_issuer: card.card_issuer.clone(),
                 card_network: card.card_network.clone(),
                 card_type: card.card_type.clone(),
+                card_tier: None,
+                card_funding_type: None,
+                is_regulated: None,
                 saved_to_locker: false,
             };
             let pm_resp = api::PaymentMethodResponse {
@@ -704,6 +705,7 @@ async fn skip_saving_card_in_locker(
 pub async fn save_in_locker(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
+    business_profile: &storage::business_profile::BusinessProfile,
     payment_method_request: api::PaymentMethodCreate,
 ) -> RouterResult<(
     api_models::payment_methods::PaymentMethodResponse,
@@ -722,6 +724,7 @@ pub async fn save_in_locker(
             &card,
             &customer_id,
             merchant_account,
+            payment_methods::card_vault::get_vault_provider(business_profile)?.as_ref(),
             None,
         ))
         .await
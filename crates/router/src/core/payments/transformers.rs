@@ -19,7 +19,7 @@ use crate::{
     connector::{Helcim, Nexinets},
     core::{
         errors::{self, RouterResponse, RouterResult},
-        payments::{self, helpers},
+        payments::{self, helpers, tax_calculation},
         utils as core_utils,
     },
     headers::X_PAYMENT_CONFIRM_SOURCE,
@@ -285,6 +285,8 @@ where
         _external_latency: Option<u128>,
         _is_latency_header_enabled: Option<bool>,
     ) -> RouterResponse<Self> {
+        let tax_details =
+            tax_calculation::get_payment_tax_details(&payment_data.payment_intent)?.map(From::from);
         Ok(services::ApplicationResponse::JsonWithHeaders((
             Self {
                 session_token: payment_data.sessions_token,
@@ -294,6 +296,7 @@ where
                     .client_secret
                     .get_required_value("client_secret")?
                     .into(),
+                tax_details,
             },
             vec![],
         )))
@@ -538,6 +541,9 @@ where
     } else {
         let mut next_action_response = None;
 
+        let tax_details =
+            tax_calculation::get_payment_tax_details(&payment_intent)?.map(From::from);
+
         let bank_transfer_next_steps = bank_transfer_next_steps_check(payment_attempt.clone())?;
 
         let next_action_voucher = voucher_next_steps_check(payment_attempt.clone())?;
@@ -693,6 +699,7 @@ where
                 .set_status(payment_intent.status)
                 .set_amount(payment_attempt.amount)
                 .set_amount_capturable(Some(payment_attempt.amount_capturable))
+                .set_amount_reversed(payment_attempt.amount_reversed)
                 .set_amount_received(payment_intent.amount_captured)
                 .set_surcharge_details(surcharge_details)
                 .set_connector(routed_through)
@@ -820,6 +827,11 @@ where
                 .set_merchant_connector_id(payment_attempt.merchant_connector_id)
                 .set_unified_code(payment_attempt.unified_code)
                 .set_unified_message(payment_attempt.unified_message)
+                .set_unified_error_details(
+                    payment_attempt
+                        .unified_error_details
+                        .and_then(|details| details.parse_value("UnifiedErrorDetails").ok()),
+                )
                 .set_incremental_authorization_allowed(
                     payment_intent.incremental_authorization_allowed,
                 )
@@ -838,6 +850,10 @@ where
                 .set_updated(Some(payment_intent.modified_at))
                 .set_charges(charges_response)
                 .set_frm_metadata(payment_intent.frm_metadata)
+                .set_tax_details(tax_details)
+                .set_order_id(payment_intent.order_id)
+                .set_merchant_reference(payment_intent.merchant_reference)
+                .set_custom_fields(payment_intent.custom_fields)
                 .to_owned(),
             headers,
         ))
@@ -1486,6 +1502,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
         Ok(Self {
             amount: Some(amount.get_amount_as_i64()), // This should be removed once we start moving to connector module
             minor_amount: Some(amount),
+            minor_amount_to_reverse: payment_data.amount_to_reverse,
             currency: Some(payment_data.currency),
             connector_transaction_id: connector
                 .connector
@@ -1830,6 +1847,7 @@ impl ForeignFrom<payments::FraudCheck> for FrmMessage {
             frm_score: fraud_check.frm_score,
             frm_reason: fraud_check.frm_reason,
             frm_error: fraud_check.frm_error,
+            frm_decision: fraud_check.frm_decision,
         }
     }
 }
@@ -2,8 +2,9 @@ use std::{collections::HashMap, num::TryFromIntError};
 
 use api_models::payment_methods::SurchargeDetailsResponse;
 use common_utils::{
-    errors::CustomResult,
+    errors::{CustomResult, MoneyError},
     ext_traits::{Encode, OptionExt},
+    money::Money,
     types as common_types,
 };
 use diesel_models::business_profile::BusinessProfile;
@@ -108,6 +109,29 @@ impl MultipleCaptureData {
                     }
             })
     }
+    /// The amount left to capture out of the amount authorized, once the captures charged or
+    /// pending are deducted. Captures exceeding the amount authorized, or in another currency than
+    /// it, are reported rather than leaving a negative amount capturable on the attempt.
+    pub fn get_amount_capturable(
+        &self,
+        authorized_amount: Money,
+    ) -> CustomResult<Money, MoneyError> {
+        let currency = authorized_amount.get_currency();
+        let total_blocked_amount = Money::try_sum(
+            currency,
+            self.all_captures
+                .values()
+                .filter(|capture| {
+                    matches!(
+                        capture.status,
+                        storage_enums::CaptureStatus::Charged
+                            | storage_enums::CaptureStatus::Pending
+                    )
+                })
+                .map(|capture| Money::new(capture.amount, capture.currency.unwrap_or(currency))),
+        )?;
+        authorized_amount.checked_sub(total_blocked_amount)
+    }
     pub fn get_captures_count(&self) -> RouterResult<i16> {
         i16::try_from(self.all_captures.len())
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -214,6 +238,7 @@ impl ForeignTryFrom<(&SurchargeDetails, &PaymentAttempt)> for SurchargeDetailsRe
             display_tax_on_surcharge_amount,
             display_total_surcharge_amount,
             display_final_amount,
+            capped_jurisdiction: surcharge_details.capped_jurisdiction,
         })
     }
 }
@@ -37,6 +37,7 @@ use crate::types::domain::behaviour::Conversion;
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
+        ledger,
         payments::{self, helpers as payment_helpers},
         utils as core_utils,
     },
@@ -347,6 +348,8 @@ pub async fn payouts_create_core(
         .await?
     };
 
+    ledger::record_payout_entries(&state, &payout_data.payouts, &payout_data.payout_attempt).await;
+
     response_handler(&merchant_account, &payout_data).await
 }
 
@@ -413,6 +416,8 @@ pub async fn payouts_confirm_core(
     )
     .await?;
 
+    ledger::record_payout_entries(&state, &payout_data.payouts, &payout_data.payout_attempt).await;
+
     response_handler(&merchant_account, &payout_data).await
 }
 
@@ -676,6 +681,8 @@ pub async fn payouts_fulfill_core(
         }));
     }
 
+    ledger::record_payout_entries(&state, &payout_data.payouts, &payout_data.payout_attempt).await;
+
     response_handler(&merchant_account, &payout_data).await
 }
 
@@ -13,6 +13,7 @@ use router_env::logger;
 use super::PayoutData;
 use crate::{
     core::{
+        blind_index,
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::{
             cards,
@@ -602,6 +603,21 @@ pub async fn get_or_create_customer_details(
     {
         Some(customer) => Ok(Some(customer)),
         None => {
+            let blind_indexes = blind_index::get_customer_blind_indexes(
+                db.get_master_key(),
+                merchant_id,
+                customer_details
+                    .email
+                    .as_ref()
+                    .map(|email| email.peek().as_str()),
+                customer_details
+                    .phone
+                    .as_ref()
+                    .map(|phone| phone.peek().as_str()),
+                customer_details.phone_country_code.as_deref(),
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate the blind indexes of the customer")?;
             let customer = domain::Customer {
                 customer_id,
                 merchant_id: merchant_id.to_string(),
@@ -627,6 +643,9 @@ pub async fn get_or_create_customer_details(
                 address_id: None,
                 default_payment_method_id: None,
                 updated_by: None,
+                payment_method_order: None,
+                email_blind_index: blind_indexes.email,
+                phone_blind_index: blind_indexes.phone,
             };
 
             Ok(Some(
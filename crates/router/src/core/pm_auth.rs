@@ -467,6 +467,7 @@ async fn store_bank_details_in_payment_methods(
                 client_secret: None,
                 payment_method_billing_address: None,
                 updated_by: None,
+                usage_restrictions: None,
             };
 
             new_entries.push(pm_new);
@@ -0,0 +1,455 @@
+use std::collections::HashSet;
+
+use api_models::{
+    enums as api_enums,
+    payment_methods::ResponsePaymentMethodIntermediate,
+    pm_display_rules::{
+        ClientPlatform, DisplayRuleAction, DisplayRuleConditions, PaymentMethodDisplayRule,
+        PaymentMethodDisplayRulesRecord, PaymentMethodDisplayRulesRequest,
+        PaymentMethodDisplayRulesResponse,
+    },
+};
+use common_utils::ext_traits::{Encode, StringExt};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use euclid::{
+    dssa::graph::AnalysisContext,
+    frontend::dir,
+    types::{NumValue, NumValueRefinement},
+};
+use hyperswitch_constraint_graph::{CheckingContext, NodeValue, Strength};
+use kgraph_utils::transformers::IntoContext;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{self, CacheKind, DECISION_MANAGER_CACHE};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::routing,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{domain, storage},
+};
+
+pub fn get_pm_display_rules_config_key(profile_id: &str) -> String {
+    format!("pm_display_rules_{profile_id}")
+}
+
+fn validate_pm_display_rules(rules: &[PaymentMethodDisplayRule]) -> RouterResult<()> {
+    let mut rule_names = HashSet::new();
+    for rule in rules {
+        if !rule_names.insert(rule.name.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("display rule '{}' is configured more than once", rule.name),
+            })?
+        }
+        if rule
+            .payment_method_types
+            .as_ref()
+            .is_some_and(|payment_method_types| payment_method_types.is_empty())
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payment_method_types of the display rule '{}' cannot be empty",
+                    rule.name
+                ),
+            })?
+        }
+        if let (Some(min_amount), Some(max_amount)) =
+            (rule.conditions.min_amount, rule.conditions.max_amount)
+        {
+            if min_amount > max_amount {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "min_amount of the display rule '{}' cannot be greater than its max_amount",
+                        rule.name
+                    ),
+                })?
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn upsert_pm_display_rules(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+    request: PaymentMethodDisplayRulesRequest,
+) -> RouterResponse<PaymentMethodDisplayRulesResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+    validate_pm_display_rules(&request.rules)?;
+
+    let key = get_pm_display_rules_config_key(&profile_id);
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    let record = match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: PaymentMethodDisplayRulesRecord = config
+                .config
+                .parse_struct("PaymentMethodDisplayRulesRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the payment method display rules record")?;
+
+            let record = PaymentMethodDisplayRulesRecord {
+                profile_id,
+                rules: request.rules,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the payment method display rules config")?;
+
+            record
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let record = PaymentMethodDisplayRulesRecord {
+                profile_id,
+                rules: request.rules,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.insert_config(configs::ConfigNew {
+                key: key.clone(),
+                config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the payment method display rules config")?;
+
+            record
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment method display rules config")?,
+    };
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::DecisionManager(key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the payment method display rules cache")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn retrieve_pm_display_rules(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<PaymentMethodDisplayRulesResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    let record: PaymentMethodDisplayRulesRecord = db
+        .find_config_by_key(&get_pm_display_rules_config_key(&profile_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?
+        .config
+        .parse_struct("PaymentMethodDisplayRulesRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment method display rules record")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn delete_pm_display_rules(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    let key = get_pm_display_rules_config_key(&profile_id);
+    db.delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::DecisionManager(key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the payment method display rules cache")?;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+async fn get_pm_display_rules(
+    state: &SessionState,
+    profile_id: &str,
+) -> RouterResult<Option<PaymentMethodDisplayRulesRecord>> {
+    let db = &*state.store;
+    let key = get_pm_display_rules_config_key(profile_id);
+
+    let find_record_from_db = || async {
+        db.find_config_by_key(&key)
+            .await?
+            .config
+            .parse_struct::<PaymentMethodDisplayRulesRecord>("PaymentMethodDisplayRulesRecord")
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to parse the payment method display rules record")
+    };
+
+    match cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &key,
+        find_record_from_db,
+        &DECISION_MANAGER_CACHE,
+    )
+    .await
+    {
+        Ok(record) => Ok(Some(record)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment method display rules"),
+    }
+}
+
+/// Whether the conditions of a rule are met by the payment. Each condition is checked against the
+/// analysis context of the payment, the platform being checked against the platform of the list
+/// request. A condition on a value missing from the context is not met.
+fn are_conditions_met(
+    conditions: &DisplayRuleConditions,
+    context: &AnalysisContext,
+    platform: Option<ClientPlatform>,
+) -> bool {
+    let is_present =
+        |value: dir::DirValue| context.check_presence(&NodeValue::Value(value), Strength::Strong);
+    let amount_value = |amount: common_utils::types::MinorUnit, refinement| {
+        dir::DirValue::PaymentAmount(NumValue {
+            number: amount.get_amount_as_i64(),
+            refinement: Some(refinement),
+        })
+    };
+
+    let is_min_amount_met = conditions.min_amount.map_or(true, |min_amount| {
+        is_present(amount_value(
+            min_amount,
+            NumValueRefinement::GreaterThanEqual,
+        ))
+    });
+    let is_max_amount_met = conditions.max_amount.map_or(true, |max_amount| {
+        is_present(amount_value(max_amount, NumValueRefinement::LessThanEqual))
+    });
+    let is_currency_met = conditions.currencies.as_ref().map_or(true, |currencies| {
+        currencies
+            .iter()
+            .any(|currency| is_present(dir::DirValue::PaymentCurrency(*currency)))
+    });
+    let is_country_met = conditions
+        .customer_countries
+        .as_ref()
+        .map_or(true, |countries| {
+            countries.iter().any(|country| {
+                is_present(dir::DirValue::BillingCountry(
+                    api_enums::Country::from_alpha2(*country),
+                ))
+            })
+        });
+    let is_platform_met = conditions.platforms.as_ref().map_or(true, |platforms| {
+        platform.map_or(false, |platform| platforms.contains(&platform))
+    });
+
+    is_min_amount_met && is_max_amount_met && is_currency_met && is_country_met && is_platform_met
+}
+
+/// Whether the payment method type is listed under the display rules. The payment method type is
+/// hidden when a `hide` rule applying to it is met, or when a `show_only` rule applying to it is
+/// not met.
+fn is_displayed(
+    rules: &[PaymentMethodDisplayRule],
+    payment_method: api_enums::PaymentMethod,
+    payment_method_type: api_enums::PaymentMethodType,
+    context: &AnalysisContext,
+    platform: Option<ClientPlatform>,
+) -> bool {
+    rules
+        .iter()
+        .filter(|rule| rule.applies_to(payment_method, payment_method_type))
+        .all(|rule| {
+            let is_met = are_conditions_met(&rule.conditions, context, platform);
+            match rule.action {
+                DisplayRuleAction::Hide => !is_met,
+                DisplayRuleAction::ShowOnly => is_met,
+            }
+        })
+}
+
+/// Removes the payment methods hidden by the display rules of the business profile from the
+/// payment methods listed. The conditions of the rules are evaluated in the same analysis context
+/// as the routing of the payment, built from the payment the payment methods are listed for.
+#[instrument(skip_all)]
+pub async fn apply_pm_display_rules(
+    state: &SessionState,
+    profile_id: &str,
+    payment_attempt: Option<&storage::PaymentAttempt>,
+    payment_intent: Option<&storage::PaymentIntent>,
+    billing_address: Option<&domain::Address>,
+    platform: Option<ClientPlatform>,
+    payment_methods: &mut Vec<ResponsePaymentMethodIntermediate>,
+) -> RouterResult<()> {
+    let Some(record) = get_pm_display_rules(state, profile_id).await? else {
+        return Ok(());
+    };
+    if record.rules.is_empty() {
+        return Ok(());
+    }
+
+    let context_values = match payment_attempt.zip(payment_intent) {
+        Some((payment_attempt, payment_intent)) => routing::make_dsl_input_for_surcharge(
+            payment_attempt,
+            payment_intent,
+            billing_address.map(Into::into),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the input of the display rules")?
+        .into_context()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the context of the display rules")?,
+        None => Vec::new(),
+    };
+    let context = AnalysisContext::from_dir_values(context_values);
+
+    payment_methods.retain(|payment_method| {
+        let is_displayed = is_displayed(
+            &record.rules,
+            payment_method.payment_method,
+            payment_method.payment_method_type,
+            &context,
+            platform,
+        );
+        if !is_displayed {
+            logger::debug!(
+                payment_method_type = %payment_method.payment_method_type,
+                connector = %payment_method.connector,
+                "Payment method hidden by the display rules"
+            );
+        }
+        is_displayed
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn get_context(amount: i64, currency: api_enums::Currency) -> AnalysisContext {
+        AnalysisContext::from_dir_values([
+            dir::DirValue::PaymentAmount(NumValue {
+                number: amount,
+                refinement: None,
+            }),
+            dir::DirValue::PaymentCurrency(currency),
+            dir::DirValue::BillingCountry(api_enums::Country::Germany),
+        ])
+    }
+
+    #[test]
+    fn test_hide_rules_hide_the_payment_methods_when_met() {
+        let rules = [PaymentMethodDisplayRule {
+            name: "hide_klarna_for_small_amounts".to_string(),
+            payment_method: api_enums::PaymentMethod::PayLater,
+            payment_method_types: Some(vec![api_enums::PaymentMethodType::Klarna]),
+            conditions: DisplayRuleConditions {
+                max_amount: Some(MinorUnit::new(1000)),
+                currencies: Some(vec![api_enums::Currency::EUR]),
+                ..Default::default()
+            },
+            action: DisplayRuleAction::Hide,
+        }];
+
+        let is_klarna_displayed = |context: &AnalysisContext| {
+            is_displayed(
+                &rules,
+                api_enums::PaymentMethod::PayLater,
+                api_enums::PaymentMethodType::Klarna,
+                context,
+                None,
+            )
+        };
+        assert!(!is_klarna_displayed(&get_context(
+            500,
+            api_enums::Currency::EUR
+        )));
+        assert!(is_klarna_displayed(&get_context(
+            5000,
+            api_enums::Currency::EUR
+        )));
+        assert!(is_klarna_displayed(&get_context(
+            500,
+            api_enums::Currency::USD
+        )));
+        assert!(is_displayed(
+            &rules,
+            api_enums::PaymentMethod::PayLater,
+            api_enums::PaymentMethodType::AfterpayClearpay,
+            &get_context(500, api_enums::Currency::EUR),
+            None,
+        ));
+    }
+
+    #[test]
+    fn test_show_only_rules_hide_the_payment_methods_when_not_met() {
+        let rules = [PaymentMethodDisplayRule {
+            name: "apple_pay_on_ios".to_string(),
+            payment_method: api_enums::PaymentMethod::Wallet,
+            payment_method_types: Some(vec![api_enums::PaymentMethodType::ApplePay]),
+            conditions: DisplayRuleConditions {
+                customer_countries: Some(vec![api_enums::CountryAlpha2::DE]),
+                platforms: Some(vec![ClientPlatform::Ios]),
+                ..Default::default()
+            },
+            action: DisplayRuleAction::ShowOnly,
+        }];
+        let context = get_context(500, api_enums::Currency::EUR);
+
+        let is_apple_pay_displayed = |context: &AnalysisContext, platform| {
+            is_displayed(
+                &rules,
+                api_enums::PaymentMethod::Wallet,
+                api_enums::PaymentMethodType::ApplePay,
+                context,
+                platform,
+            )
+        };
+        assert!(is_apple_pay_displayed(&context, Some(ClientPlatform::Ios)));
+        assert!(!is_apple_pay_displayed(&context, Some(ClientPlatform::Web)));
+        assert!(!is_apple_pay_displayed(&context, None));
+        assert!(!is_apple_pay_displayed(
+            &AnalysisContext::from_dir_values([]),
+            Some(ClientPlatform::Ios)
+        ));
+    }
+}
@@ -0,0 +1,303 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use api_models::{
+    enums as api_enums,
+    pm_filters::{
+        ConnectorPmFiltersRequest, ConnectorPmFiltersResponse, PaymentMethodFilter,
+        PaymentMethodNotAvailableFlows, PmFiltersRecord,
+    },
+};
+use common_utils::ext_traits::{Encode, StringExt, ValueExt};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{publish_into_redact_channel, CacheKind};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+};
+
+/// The key of the config holding the filters of the payment methods set through the API, which
+/// override the filters of the configuration of the deployment
+pub const PM_FILTERS_CONFIG_KEY: &str = "pm_filters";
+
+/// The connector whose filters apply to the connectors without filters of their own
+const DEFAULT_PM_FILTERS_CONNECTOR: &str = "default";
+
+/// The filters of the payment methods set through the API, read through the in-memory cache of
+/// the configs, which is invalidated on every instance when the config is updated or deleted
+async fn find_pm_filters_record(state: &SessionState) -> RouterResult<PmFiltersRecord> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(PM_FILTERS_CONFIG_KEY, Some("{}".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment method filters config")?
+        .config
+        .parse_struct("PmFiltersRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment method filters record")
+}
+
+/// The filters of the payment methods set through the API read from the database, so that they
+/// are never updated from a stale cached copy. Returns `None` when no filters were ever set.
+async fn find_pm_filters_record_from_db(
+    state: &SessionState,
+) -> RouterResult<Option<PmFiltersRecord>> {
+    match state
+        .store
+        .find_config_by_key_from_db(PM_FILTERS_CONFIG_KEY)
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("PmFiltersRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the payment method filters record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment method filters config"),
+    }
+}
+
+/// The filters of the payment methods of the connectors in effect, the filters set through the
+/// API for a connector replacing all the filters of the connector in the configuration of the
+/// deployment. The configuration is borrowed as is when no filters were set through the API.
+#[instrument(skip_all)]
+pub async fn get_pm_filters(
+    state: &SessionState,
+) -> RouterResult<Cow<'_, settings::ConnectorFilters>> {
+    let record = find_pm_filters_record(state).await?;
+    if record.is_empty() {
+        return Ok(Cow::Borrowed(&state.conf.pm_filters));
+    }
+
+    let mut pm_filters = state.conf.pm_filters.clone();
+    for (connector, filters) in record {
+        pm_filters
+            .0
+            .insert(connector, get_payment_method_filters(filters)?);
+    }
+    Ok(Cow::Owned(pm_filters))
+}
+
+fn parse_payment_method_filter_key(key: &str) -> RouterResult<settings::PaymentMethodFilterKey> {
+    serde_json::Value::String(key.to_string())
+        .parse_value("PaymentMethodFilterKey")
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{key} is neither a payment method type nor a card network"),
+        })
+}
+
+fn get_payment_method_filters(
+    filters: HashMap<String, PaymentMethodFilter>,
+) -> RouterResult<settings::PaymentMethodFilters> {
+    filters
+        .into_iter()
+        .map(|(key, filter)| {
+            let filter = settings::CurrencyCountryFlowFilter {
+                // The configuration of the deployment treats an empty list as no filter
+                currency: filter
+                    .currency
+                    .filter(|currency| !currency.is_empty())
+                    .map(|currency| currency.into_iter().collect()),
+                country: filter
+                    .country
+                    .filter(|country| !country.is_empty())
+                    .map(|country| country.into_iter().collect()),
+                not_available_flows: filter.not_available_flows.map(|flows| {
+                    settings::NotAvailableFlows {
+                        capture_method: flows.capture_method,
+                    }
+                }),
+            };
+            Ok((parse_payment_method_filter_key(&key)?, filter))
+        })
+        .collect::<RouterResult<_>>()
+        .map(settings::PaymentMethodFilters)
+}
+
+fn get_payment_method_filter_key_name(key: &settings::PaymentMethodFilterKey) -> String {
+    match key {
+        settings::PaymentMethodFilterKey::PaymentMethodType(payment_method_type) => {
+            payment_method_type.to_string()
+        }
+        settings::PaymentMethodFilterKey::CardNetwork(card_network) => card_network.to_string(),
+    }
+}
+
+fn get_payment_method_filters_response(
+    filters: &settings::PaymentMethodFilters,
+) -> HashMap<String, PaymentMethodFilter> {
+    filters
+        .0
+        .iter()
+        .map(|(key, filter)| {
+            let filter = PaymentMethodFilter {
+                currency: filter
+                    .currency
+                    .as_ref()
+                    .map(|currency| currency.iter().copied().collect()),
+                country: filter
+                    .country
+                    .as_ref()
+                    .map(|country| country.iter().copied().collect()),
+                not_available_flows: filter.not_available_flows.map(|flows| {
+                    PaymentMethodNotAvailableFlows {
+                        capture_method: flows.capture_method,
+                    }
+                }),
+            };
+            (get_payment_method_filter_key_name(key), filter)
+        })
+        .collect()
+}
+
+/// Validates that the connector is a routable connector, the cgraphs being built only from the
+/// filters of the routable connectors, or the connector of the default filters
+fn validate_pm_filters_connector(connector: &str) -> RouterResult<()> {
+    if connector == DEFAULT_PM_FILTERS_CONNECTOR
+        || api_enums::RoutableConnectors::from_str(connector).is_ok()
+    {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{connector} is not a routable connector"),
+        }))
+    }
+}
+
+async fn store_pm_filters_record(
+    state: &SessionState,
+    record: &PmFiltersRecord,
+    is_stored: bool,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    if is_stored {
+        db.update_config_by_key(
+            PM_FILTERS_CONFIG_KEY,
+            configs::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payment method filters config")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key: PM_FILTERS_CONFIG_KEY.to_string(),
+            config,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payment method filters config")?;
+    }
+
+    // The cgraphs of every merchant are built from the filters, and are rebuilt with the new
+    // filters the next time they are used
+    publish_into_redact_channel(
+        state.store.get_cache_store().as_ref(),
+        [CacheKind::AllCGraphs],
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to invalidate the cached cgraphs"))
+    .ok();
+
+    Ok(())
+}
+
+/// Retrieves the filters of the payment methods of the connector in effect, and whether they were
+/// set through the API
+pub async fn retrieve_pm_filters(
+    state: SessionState,
+    connector: String,
+) -> RouterResponse<ConnectorPmFiltersResponse> {
+    validate_pm_filters_connector(&connector)?;
+    let record = find_pm_filters_record(&state).await?;
+
+    let response = match record.get(&connector) {
+        Some(filters) => ConnectorPmFiltersResponse {
+            connector,
+            filters: filters.clone(),
+            overridden: true,
+        },
+        None => ConnectorPmFiltersResponse {
+            filters: state
+                .conf
+                .pm_filters
+                .0
+                .get(&connector)
+                .map(get_payment_method_filters_response)
+                .unwrap_or_default(),
+            connector,
+            overridden: false,
+        },
+    };
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Replaces the filters of the payment methods of the connector, taking effect on every instance
+/// once the cached configs and cgraphs are invalidated
+pub async fn update_pm_filters(
+    state: SessionState,
+    connector: String,
+    request: ConnectorPmFiltersRequest,
+) -> RouterResponse<ConnectorPmFiltersResponse> {
+    validate_pm_filters_connector(&connector)?;
+    for key in request.filters.keys() {
+        parse_payment_method_filter_key(key)?;
+    }
+
+    let stored_record = find_pm_filters_record_from_db(&state).await?;
+    let is_stored = stored_record.is_some();
+    let mut record = stored_record.unwrap_or_default();
+    record.insert(connector.clone(), request.filters.clone());
+    store_pm_filters_record(&state, &record, is_stored).await?;
+
+    Ok(ApplicationResponse::Json(ConnectorPmFiltersResponse {
+        connector,
+        filters: request.filters,
+        overridden: true,
+    }))
+}
+
+/// Removes the filters of the payment methods of the connector set through the API, the filters
+/// of the configuration of the deployment applying to the connector from then on
+pub async fn delete_pm_filters(
+    state: SessionState,
+    connector: String,
+) -> RouterResponse<ConnectorPmFiltersResponse> {
+    validate_pm_filters_connector(&connector)?;
+
+    let mut record = find_pm_filters_record_from_db(&state)
+        .await?
+        .unwrap_or_default();
+    if record.remove(&connector).is_none() {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payment method filters of {connector} are not overridden"),
+        }));
+    }
+    store_pm_filters_record(&state, &record, true).await?;
+
+    Ok(ApplicationResponse::Json(ConnectorPmFiltersResponse {
+        filters: state
+            .conf
+            .pm_filters
+            .0
+            .get(&connector)
+            .map(get_payment_method_filters_response)
+            .unwrap_or_default(),
+        connector,
+        overridden: false,
+    }))
+}
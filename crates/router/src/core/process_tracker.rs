@@ -0,0 +1,312 @@
+use api_models::process_tracker::{
+    ProcessTrackerTaskListConstraints, ProcessTrackerTaskListResponse,
+    ProcessTrackerTaskRescheduleRequest, ProcessTrackerTaskResponse, ProcessTrackerTaskState,
+};
+use diesel_models::enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::storage::{self, business_status},
+};
+
+/// Number of tasks listed, when not provided in the request
+const DEFAULT_TASK_LIST_LIMIT: u32 = 20;
+
+const MAX_TASK_LIST_LIMIT: u32 = 100;
+
+/// The business statuses with which workflows finish the tasks they gave up on
+const DEAD_BUSINESS_STATUSES: [&str; 4] = [
+    business_status::GLOBAL_FAILURE,
+    business_status::GLOBAL_ERROR,
+    business_status::FAILURE,
+    business_status::RETRIES_EXCEEDED,
+];
+
+/// The statuses of the tasks yet to be picked by the producer
+const WAITING_STATUSES: [enums::ProcessTrackerStatus; 2] = [
+    enums::ProcessTrackerStatus::New,
+    enums::ProcessTrackerStatus::Pending,
+];
+
+fn get_task_state(process: &storage::ProcessTracker) -> ProcessTrackerTaskState {
+    match process.status {
+        enums::ProcessTrackerStatus::New | enums::ProcessTrackerStatus::Pending => {
+            ProcessTrackerTaskState::Scheduled
+        }
+        enums::ProcessTrackerStatus::Paused => ProcessTrackerTaskState::Paused,
+        enums::ProcessTrackerStatus::Processing | enums::ProcessTrackerStatus::ProcessStarted => {
+            ProcessTrackerTaskState::InProgress
+        }
+        enums::ProcessTrackerStatus::Finish
+            if process.is_valid_business_status(&DEAD_BUSINESS_STATUSES) =>
+        {
+            ProcessTrackerTaskState::Dead
+        }
+        enums::ProcessTrackerStatus::Finish => ProcessTrackerTaskState::Completed,
+    }
+}
+
+fn get_task_response(process: storage::ProcessTracker) -> ProcessTrackerTaskResponse {
+    ProcessTrackerTaskResponse {
+        state: get_task_state(&process),
+        task_id: process.id,
+        name: process.name,
+        runner: process.runner,
+        tag: process.tag,
+        business_status: process.business_status,
+        retry_count: process.retry_count,
+        schedule_time: process.schedule_time,
+        tracking_data: process.tracking_data,
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    }
+}
+
+/// Finds the task of the merchant. The merchant of a task is the `merchant_id` of its tracking
+/// data, the tasks of other merchants are not found.
+async fn find_merchant_task(
+    state: &SessionState,
+    merchant_id: &str,
+    task_id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the task")?
+        .filter(|process| {
+            process
+                .tracking_data
+                .get("merchant_id")
+                .and_then(serde_json::Value::as_str)
+                == Some(merchant_id)
+        })
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Task {task_id} does not exist"),
+            })
+        })
+}
+
+/// Updates the task if it still has one of the statuses, the producer picking the task in the
+/// meantime failing the update
+async fn update_merchant_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    statuses: Vec<enums::ProcessTrackerStatus>,
+    process_update: storage::ProcessTrackerUpdate,
+) -> RouterResult<storage::ProcessTracker> {
+    let task_id = process.id.clone();
+    let updated_process = state
+        .store
+        .update_process_with_status(process, statuses, process_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Task {task_id} was picked by the scheduler, retry later"),
+            })
+        })?;
+    logger::info!(%task_id, status = %updated_process.status, "Updated the task");
+
+    Ok(updated_process)
+}
+
+#[instrument(skip_all)]
+pub async fn list_tasks(
+    state: SessionState,
+    merchant_id: String,
+    constraints: ProcessTrackerTaskListConstraints,
+) -> RouterResponse<ProcessTrackerTaskListResponse> {
+    let limit = constraints.limit.unwrap_or(DEFAULT_TASK_LIST_LIMIT);
+    if limit > MAX_TASK_LIST_LIMIT {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit cannot be more than {MAX_TASK_LIST_LIMIT}"),
+        }));
+    }
+    let (statuses, business_statuses) = match constraints.state {
+        None => (None, None),
+        Some(ProcessTrackerTaskState::Scheduled) => (Some(WAITING_STATUSES.to_vec()), None),
+        Some(ProcessTrackerTaskState::Paused) => {
+            (Some(vec![enums::ProcessTrackerStatus::Paused]), None)
+        }
+        Some(ProcessTrackerTaskState::InProgress) => (
+            Some(vec![
+                enums::ProcessTrackerStatus::Processing,
+                enums::ProcessTrackerStatus::ProcessStarted,
+            ]),
+            None,
+        ),
+        Some(ProcessTrackerTaskState::Dead) => (
+            Some(vec![enums::ProcessTrackerStatus::Finish]),
+            Some(DEAD_BUSINESS_STATUSES.map(String::from).to_vec()),
+        ),
+        Some(ProcessTrackerTaskState::Completed) => {
+            (Some(vec![enums::ProcessTrackerStatus::Finish]), None)
+        }
+    };
+
+    let processes = state
+        .store
+        .find_processes_by_merchant_id_constraints(
+            &merchant_id,
+            constraints.runner,
+            statuses,
+            business_statuses,
+            Some(i64::from(limit)),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the tasks of the merchant")?;
+
+    Ok(ApplicationResponse::Json(ProcessTrackerTaskListResponse {
+        tasks: processes
+            .into_iter()
+            .map(get_task_response)
+            // The finished tasks that are not dead cannot be filtered in the query
+            .filter(|task| constraints.state.map_or(true, |state| state == task.state))
+            .collect(),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_task(
+    state: SessionState,
+    merchant_id: String,
+    task_id: String,
+) -> RouterResponse<ProcessTrackerTaskResponse> {
+    let process = find_merchant_task(&state, &merchant_id, &task_id).await?;
+
+    Ok(ApplicationResponse::Json(get_task_response(process)))
+}
+
+/// Pauses a task waiting to be run, the producer not picking it until it is resumed
+#[instrument(skip_all)]
+pub async fn pause_task(
+    state: SessionState,
+    merchant_id: String,
+    task_id: String,
+) -> RouterResponse<ProcessTrackerTaskResponse> {
+    let process = find_merchant_task(&state, &merchant_id, &task_id).await?;
+    if !WAITING_STATUSES.contains(&process.status) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Task {task_id} is not waiting to be run and cannot be paused"),
+        }));
+    }
+
+    let process = update_merchant_task(
+        &state,
+        process,
+        WAITING_STATUSES.to_vec(),
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: enums::ProcessTrackerStatus::Paused,
+            business_status: None,
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_task_response(process)))
+}
+
+/// Resumes a paused task, or runs a dead task again from its first attempt. Tasks whose schedule
+/// time has passed are run right away.
+#[instrument(skip_all)]
+pub async fn resume_task(
+    state: SessionState,
+    merchant_id: String,
+    task_id: String,
+) -> RouterResponse<ProcessTrackerTaskResponse> {
+    let process = find_merchant_task(&state, &merchant_id, &task_id).await?;
+    let now = common_utils::date_time::now();
+
+    let (status, process_update) = match get_task_state(&process) {
+        ProcessTrackerTaskState::Paused => (
+            enums::ProcessTrackerStatus::Paused,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(process.schedule_time.map_or(now, |time| time.max(now))),
+                tracking_data: None,
+                business_status: None,
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        ),
+        ProcessTrackerTaskState::Dead => (
+            enums::ProcessTrackerStatus::Finish,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(now),
+                tracking_data: None,
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        ),
+        ProcessTrackerTaskState::Scheduled
+        | ProcessTrackerTaskState::InProgress
+        | ProcessTrackerTaskState::Completed => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Task {task_id} is neither paused nor dead and cannot be resumed"),
+            }));
+        }
+    };
+
+    let process = update_merchant_task(&state, process, vec![status], process_update).await?;
+
+    Ok(ApplicationResponse::Json(get_task_response(process)))
+}
+
+/// Moves the schedule time of a task waiting to be run or paused
+#[instrument(skip_all)]
+pub async fn reschedule_task(
+    state: SessionState,
+    merchant_id: String,
+    task_id: String,
+    req: ProcessTrackerTaskRescheduleRequest,
+) -> RouterResponse<ProcessTrackerTaskResponse> {
+    let now = common_utils::date_time::now();
+    if req.schedule_time < now {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "schedule_time cannot be in the past".to_string(),
+        }));
+    }
+
+    let process = find_merchant_task(&state, &merchant_id, &task_id).await?;
+    let statuses = vec![
+        enums::ProcessTrackerStatus::New,
+        enums::ProcessTrackerStatus::Pending,
+        enums::ProcessTrackerStatus::Paused,
+    ];
+    if !statuses.contains(&process.status) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Task {task_id} is not waiting to be run and cannot be rescheduled"),
+        }));
+    }
+
+    let process = update_merchant_task(
+        &state,
+        process,
+        statuses,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(req.schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: None,
+            updated_at: Some(now),
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_task_response(process)))
+}
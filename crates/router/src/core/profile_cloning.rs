@@ -0,0 +1,772 @@
+//! Cloning of business profiles, within the merchant of the profile or into another merchant, and
+//! the business profile templates platforms onboard their sub-merchants with. The settings of the
+//! profile, its default fallback routing, its payment method display rules and the merchant
+//! connector accounts selected are copied. The merchant connector accounts are copied without
+//! their credentials, the copies being inactive until credentials are set on them.
+
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::{
+    admin::VaultProvider,
+    audit_events::{AuditAction, AuditResourceType},
+    pm_display_rules::PaymentMethodDisplayRulesRecord,
+    profile_cloning::{
+        BusinessProfileCloneRequest, BusinessProfileCloneResponse, BusinessProfileTemplate,
+        BusinessProfileTemplateApplyRequest, BusinessProfileTemplateRequest,
+        BusinessProfileTemplatesRecord, BusinessProfileTemplatesResponse,
+        ClonedMerchantConnectorAccount,
+    },
+    routing as routing_types,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        audit_events,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        pm_display_rules,
+        routing::helpers as routing_helpers,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        self, api,
+        api::enums as api_enums,
+        domain::{self, types as domain_types},
+        storage,
+        transformers::ForeignTryFrom,
+    },
+    utils,
+};
+
+pub fn get_profile_templates_config_key(merchant_id: &str) -> String {
+    format!("business_profile_templates_{merchant_id}")
+}
+
+/// Finds the merchant connector accounts of the business profile to be copied, each of which must
+/// belong to the profile
+async fn find_profile_connector_accounts(
+    db: &dyn StorageInterface,
+    business_profile: &storage::business_profile::BusinessProfile,
+    merchant_connector_ids: &[String],
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Vec<domain::MerchantConnectorAccount>> {
+    let mut merchant_connector_accounts: Vec<domain::MerchantConnectorAccount> =
+        Vec::with_capacity(merchant_connector_ids.len());
+    for merchant_connector_id in merchant_connector_ids {
+        if merchant_connector_accounts
+            .iter()
+            .any(|mca| &mca.merchant_connector_id == merchant_connector_id)
+        {
+            continue;
+        }
+        let mca = db
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &business_profile.merchant_id,
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.to_owned(),
+            })?;
+        if mca.profile_id.as_ref() != Some(&business_profile.profile_id) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "merchant connector account {merchant_connector_id} does not belong to the business profile {}",
+                    business_profile.profile_id
+                ),
+            })?
+        }
+        merchant_connector_accounts.push(mca);
+    }
+    Ok(merchant_connector_accounts)
+}
+
+/// The vault of the profile created, the vault of a connector whose merchant connector account is
+/// not copied being left unset for a profile of another merchant
+fn get_cloned_vault_provider(
+    vault_provider: Option<&serde_json::Value>,
+    is_same_merchant: bool,
+    cloned_mca_ids: &HashMap<String, String>,
+) -> RouterResult<Option<serde_json::Value>> {
+    let Some(vault_provider) = vault_provider else {
+        return Ok(None);
+    };
+    let vault_provider: VaultProvider = vault_provider
+        .clone()
+        .parse_value("VaultProvider")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the vault provider of the business profile")?;
+
+    let vault_provider = match vault_provider {
+        VaultProvider::ConnectorTokenization {
+            merchant_connector_id,
+        } => match cloned_mca_ids.get(&merchant_connector_id) {
+            Some(cloned_mca_id) => Some(VaultProvider::ConnectorTokenization {
+                merchant_connector_id: cloned_mca_id.clone(),
+            }),
+            None => is_same_merchant.then_some(VaultProvider::ConnectorTokenization {
+                merchant_connector_id,
+            }),
+        },
+        vault_provider => Some(vault_provider),
+    };
+
+    vault_provider
+        .as_ref()
+        .map(Encode::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the vault provider of the business profile")
+}
+
+fn get_cloned_business_profile(
+    source_profile: &storage::business_profile::BusinessProfile,
+    profile_id: String,
+    target_merchant_id: &str,
+    profile_name: String,
+    cloned_mca_ids: &HashMap<String, String>,
+) -> RouterResult<storage::business_profile::BusinessProfileNew> {
+    let is_same_merchant = source_profile.merchant_id == target_merchant_id;
+    let current_time = date_time::now();
+
+    Ok(storage::business_profile::BusinessProfileNew {
+        profile_id,
+        merchant_id: target_merchant_id.to_owned(),
+        profile_name,
+        created_at: current_time,
+        modified_at: current_time,
+        return_url: source_profile.return_url.clone(),
+        enable_payment_response_hash: source_profile.enable_payment_response_hash,
+        // The hash key is a secret of the profile, never shared with the profile it is cloned from
+        payment_response_hash_key: Some(
+            common_utils::crypto::generate_cryptographically_secure_random_string(64),
+        ),
+        redirect_to_merchant_with_http_post: source_profile.redirect_to_merchant_with_http_post,
+        webhook_details: source_profile.webhook_details.clone(),
+        metadata: source_profile.metadata.clone(),
+        // The routing algorithms of the profile cloned are not activated for the profile created
+        routing_algorithm: Some(serde_json::json!({
+            "algorithm_id": null,
+            "timestamp": 0
+        })),
+        intent_fulfillment_time: source_profile.intent_fulfillment_time,
+        frm_routing_algorithm: source_profile.frm_routing_algorithm.clone(),
+        payout_routing_algorithm: None,
+        is_recon_enabled: false,
+        applepay_verified_domains: None,
+        payment_link_config: source_profile.payment_link_config.clone(),
+        session_expiry: source_profile.session_expiry,
+        authentication_connector_details: source_profile.authentication_connector_details.clone(),
+        payout_link_config: source_profile.payout_link_config.clone(),
+        is_extended_card_info_enabled: source_profile.is_extended_card_info_enabled,
+        extended_card_info_config: source_profile.extended_card_info_config.clone(),
+        is_connector_agnostic_mit_enabled: source_profile.is_connector_agnostic_mit_enabled,
+        use_billing_as_payment_method_billing: source_profile.use_billing_as_payment_method_billing,
+        collect_shipping_details_from_wallet_connector: source_profile
+            .collect_shipping_details_from_wallet_connector,
+        frm_decision_matrix: source_profile.frm_decision_matrix.clone(),
+        frm_post_auth_review: source_profile.frm_post_auth_review.clone(),
+        connector_debug_capture: source_profile.connector_debug_capture.clone(),
+        connector_failover_policy: source_profile.connector_failover_policy.clone(),
+        vault_provider: get_cloned_vault_provider(
+            source_profile.vault_provider.as_ref(),
+            is_same_merchant,
+            cloned_mca_ids,
+        )?,
+        tax_provider: source_profile.tax_provider.clone(),
+        dispute_auto_response: source_profile.dispute_auto_response.clone(),
+        custom_fields_schema: source_profile.custom_fields_schema.clone(),
+    })
+}
+
+/// Copies the merchant connector account to the profile, with temporary credentials which leave
+/// the copy inactive until its credentials are set
+async fn get_cloned_connector_account(
+    source_mca: &domain::MerchantConnectorAccount,
+    target_merchant_id: &str,
+    profile_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let connector_account_details = types::ConnectorAuthType::TemporaryAuth
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the temporary connector account details")?;
+    let current_time = date_time::now();
+
+    Ok(domain::MerchantConnectorAccount {
+        id: None,
+        merchant_id: target_merchant_id.to_owned(),
+        connector_name: source_mca.connector_name.clone(),
+        connector_account_details: domain_types::encrypt(
+            Secret::new(connector_account_details),
+            key_store.key.peek(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt connector account details")?,
+        test_mode: source_mca.test_mode,
+        disabled: Some(true),
+        merchant_connector_id: utils::generate_id(consts::ID_LENGTH, "mca"),
+        payment_methods_enabled: source_mca.payment_methods_enabled.clone(),
+        connector_type: source_mca.connector_type,
+        metadata: source_mca.metadata.clone(),
+        frm_configs: source_mca.frm_configs.clone(),
+        connector_label: source_mca.connector_label.clone(),
+        business_country: source_mca.business_country,
+        business_label: source_mca.business_label.clone(),
+        business_sub_label: source_mca.business_sub_label.clone(),
+        created_at: current_time,
+        modified_at: current_time,
+        // The webhook secrets are credentials of the connector account, set with the credentials
+        connector_webhook_details: None,
+        profile_id: Some(profile_id.to_owned()),
+        applepay_verified_domains: None,
+        // The payment method authentication config refers to connector accounts of the profile
+        // cloned
+        pm_auth_config: None,
+        status: api_enums::ConnectorStatus::Inactive,
+        connector_wallets_details: helpers::get_encrypted_apple_pay_connector_wallets_details(
+            key_store,
+            &source_mca.metadata,
+        )
+        .await?,
+        next_connector_account_details: None,
+        previous_connector_account_details: None,
+        credentials_rotation_window_ends_at: None,
+    })
+}
+
+fn get_transaction_type(mca: &domain::MerchantConnectorAccount) -> api_enums::TransactionType {
+    match mca.connector_type {
+        #[cfg(feature = "payouts")]
+        api_enums::ConnectorType::PayoutProcessor => api_enums::TransactionType::Payout,
+        _ => api_enums::TransactionType::Payment,
+    }
+}
+
+fn get_connector_choice(
+    mca: &domain::MerchantConnectorAccount,
+) -> Option<routing_types::RoutableConnectorChoice> {
+    let connector = api_enums::RoutableConnectors::from_str(&mca.connector_name).ok()?;
+    Some(routing_types::RoutableConnectorChoice {
+        #[cfg(feature = "backwards_compatibility")]
+        choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+        connector,
+        #[cfg(feature = "connector_choice_mca_id")]
+        merchant_connector_id: Some(mca.merchant_connector_id.clone()),
+        #[cfg(not(feature = "connector_choice_mca_id"))]
+        sub_label: mca.business_sub_label.clone(),
+    })
+}
+
+/// The choice of the default fallback routing of the profile cloned, as a choice of the profile
+/// created, if its merchant connector account was copied
+#[cfg(feature = "connector_choice_mca_id")]
+fn get_cloned_connector_choice(
+    choice: &routing_types::RoutableConnectorChoice,
+    cloned_mcas: &HashMap<String, domain::MerchantConnectorAccount>,
+) -> Option<routing_types::RoutableConnectorChoice> {
+    choice
+        .merchant_connector_id
+        .as_ref()
+        .and_then(|merchant_connector_id| cloned_mcas.get(merchant_connector_id))
+        .and_then(get_connector_choice)
+}
+
+/// The choice of the default fallback routing of the profile cloned, as a choice of the profile
+/// created, if a merchant connector account of its connector was copied
+#[cfg(not(feature = "connector_choice_mca_id"))]
+fn get_cloned_connector_choice(
+    choice: &routing_types::RoutableConnectorChoice,
+    cloned_mcas: &HashMap<String, domain::MerchantConnectorAccount>,
+) -> Option<routing_types::RoutableConnectorChoice> {
+    let connector_name = choice.connector.to_string();
+    cloned_mcas
+        .values()
+        .any(|mca| mca.connector_name == connector_name)
+        .then(|| choice.clone())
+}
+
+/// Sets the default fallback routing of the profile created to the one of the profile cloned,
+/// restricted to the merchant connector accounts copied, and adds the copies to the default
+/// fallback routing of their merchant as the creation of a merchant connector account does
+async fn clone_default_fallback_routing(
+    db: &dyn StorageInterface,
+    source_profile_id: &str,
+    business_profile: &storage::business_profile::BusinessProfile,
+    cloned_mcas: &HashMap<String, domain::MerchantConnectorAccount>,
+) -> RouterResult<()> {
+    let mut transaction_types = vec![api_enums::TransactionType::Payment];
+    #[cfg(feature = "payouts")]
+    transaction_types.push(api_enums::TransactionType::Payout);
+
+    for transaction_type in transaction_types {
+        let mut default_routing_config = routing_helpers::get_merchant_default_config(
+            db,
+            &business_profile.merchant_id,
+            &transaction_type,
+        )
+        .await?;
+        let source_default_routing_config =
+            routing_helpers::get_merchant_default_config(db, source_profile_id, &transaction_type)
+                .await?;
+        let mut profile_default_routing_config = routing_helpers::get_merchant_default_config(
+            db,
+            &business_profile.profile_id,
+            &transaction_type,
+        )
+        .await?;
+
+        for choice in source_default_routing_config
+            .iter()
+            .filter_map(|choice| get_cloned_connector_choice(choice, cloned_mcas))
+        {
+            if !profile_default_routing_config.contains(&choice) {
+                profile_default_routing_config.push(choice);
+            }
+        }
+
+        let mut is_default_routing_config_updated = false;
+        for choice in cloned_mcas
+            .values()
+            .filter(|mca| get_transaction_type(mca) == transaction_type)
+            .filter_map(get_connector_choice)
+        {
+            if !default_routing_config.contains(&choice) {
+                default_routing_config.push(choice.clone());
+                is_default_routing_config_updated = true;
+            }
+            if !profile_default_routing_config.contains(&choice) {
+                profile_default_routing_config.push(choice);
+            }
+        }
+
+        if is_default_routing_config_updated {
+            routing_helpers::update_merchant_default_config(
+                db,
+                &business_profile.merchant_id,
+                default_routing_config,
+                &transaction_type,
+            )
+            .await?;
+        }
+        if !profile_default_routing_config.is_empty() {
+            routing_helpers::update_merchant_default_config(
+                db,
+                &business_profile.profile_id,
+                profile_default_routing_config,
+                &transaction_type,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn clone_pm_display_rules(
+    db: &dyn StorageInterface,
+    source_profile_id: &str,
+    profile_id: &str,
+) -> RouterResult<()> {
+    let source_key = pm_display_rules::get_pm_display_rules_config_key(source_profile_id);
+    let record: PaymentMethodDisplayRulesRecord = match db.find_config_by_key(&source_key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("PaymentMethodDisplayRulesRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the payment method display rules record")?,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment method display rules config")?,
+    };
+
+    let timestamp = date_time::now_unix_timestamp();
+    let record = PaymentMethodDisplayRulesRecord {
+        profile_id: profile_id.to_owned(),
+        created_at: timestamp,
+        modified_at: timestamp,
+        ..record
+    };
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: pm_display_rules::get_pm_display_rules_config_key(profile_id),
+        config,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert the payment method display rules config")?;
+
+    Ok(())
+}
+
+/// Creates a business profile for the target merchant from the business profile, copying the
+/// merchant connector accounts selected
+async fn clone_profile(
+    state: &SessionState,
+    source_profile: storage::business_profile::BusinessProfile,
+    merchant_connector_ids: &[String],
+    target_merchant_id: &str,
+    profile_name: String,
+) -> RouterResult<BusinessProfileCloneResponse> {
+    let db = state.store.as_ref();
+    let master_key = db.get_master_key().to_vec().into();
+    let source_key_store = db
+        .get_merchant_key_store_by_merchant_id(&source_profile.merchant_id, &master_key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let target_key_store = if source_profile.merchant_id == target_merchant_id {
+        source_key_store.clone()
+    } else {
+        db.get_merchant_key_store_by_merchant_id(target_merchant_id, &master_key)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+    };
+
+    let source_mcas = find_profile_connector_accounts(
+        db,
+        &source_profile,
+        merchant_connector_ids,
+        &source_key_store,
+    )
+    .await?;
+
+    // The identifiers of the merchant connector accounts created are known before they are
+    // inserted, so that the settings of the profile referring to them can be updated
+    let profile_id = common_utils::generate_id_with_default_len("pro");
+    let mut cloned_mcas = HashMap::with_capacity(source_mcas.len());
+    for source_mca in &source_mcas {
+        let cloned_mca = get_cloned_connector_account(
+            source_mca,
+            target_merchant_id,
+            &profile_id,
+            &target_key_store,
+        )
+        .await?;
+        cloned_mcas.insert(source_mca.merchant_connector_id.clone(), cloned_mca);
+    }
+    let cloned_mca_ids = cloned_mcas
+        .iter()
+        .map(|(source_mca_id, mca)| (source_mca_id.clone(), mca.merchant_connector_id.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let business_profile_new = get_cloned_business_profile(
+        &source_profile,
+        profile_id,
+        target_merchant_id,
+        profile_name,
+        &cloned_mca_ids,
+    )?;
+    let profile_name = business_profile_new.profile_name.clone();
+    let business_profile = db
+        .insert_business_profile(business_profile_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!(
+                "Business Profile with the profile_name {profile_name} already exists"
+            ),
+        })
+        .attach_printable("Failed to insert the cloned business profile")?;
+
+    let mut merchant_connector_accounts = Vec::with_capacity(cloned_mcas.len());
+    let mut inserted_mcas = HashMap::with_capacity(cloned_mcas.len());
+    for (source_mca_id, cloned_mca) in cloned_mcas {
+        let connector_label = cloned_mca.connector_label.clone().unwrap_or_default();
+        let mca = db
+            .insert_merchant_connector_account(cloned_mca, &target_key_store)
+            .await
+            .to_duplicate_response(
+                errors::ApiErrorResponse::DuplicateMerchantConnectorAccount {
+                    profile_id: business_profile.profile_id.clone(),
+                    connector_label,
+                },
+            )?;
+
+        let mca_response: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
+        audit_events::record_audit_event(
+            state,
+            target_merchant_id,
+            AuditResourceType::MerchantConnectorAccount,
+            &mca_response.merchant_connector_id,
+            AuditAction::Create,
+            None,
+            Some(&mca_response),
+        )
+        .await;
+
+        merchant_connector_accounts.push(ClonedMerchantConnectorAccount {
+            source_merchant_connector_id: source_mca_id.clone(),
+            merchant_connector_id: mca.merchant_connector_id.clone(),
+            connector_name: mca.connector_name.clone(),
+            status: mca.status,
+        });
+        inserted_mcas.insert(source_mca_id, mca);
+    }
+
+    clone_default_fallback_routing(
+        db,
+        &source_profile.profile_id,
+        &business_profile,
+        &inserted_mcas,
+    )
+    .await?;
+    clone_pm_display_rules(db, &source_profile.profile_id, &business_profile.profile_id).await?;
+
+    let profile = api::BusinessProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    audit_events::record_audit_event(
+        state,
+        target_merchant_id,
+        AuditResourceType::BusinessProfile,
+        &profile.profile_id,
+        AuditAction::Create,
+        None,
+        Some(&profile),
+    )
+    .await;
+
+    merchant_connector_accounts.sort_by(|first, second| {
+        first
+            .source_merchant_connector_id
+            .cmp(&second.source_merchant_connector_id)
+    });
+    Ok(BusinessProfileCloneResponse {
+        profile,
+        merchant_connector_accounts,
+    })
+}
+
+/// Creates a business profile from the business profile of the merchant, for the merchant or for
+/// another merchant
+#[instrument(skip_all)]
+pub async fn clone_business_profile(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+    request: BusinessProfileCloneRequest,
+) -> RouterResponse<BusinessProfileCloneResponse> {
+    let db = state.store.as_ref();
+    let source_profile =
+        core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id)
+            .await?
+            .ok_or(errors::ApiErrorResponse::BusinessProfileNotFound { id: profile_id })?;
+    let target_merchant_id = request
+        .target_merchant_id
+        .unwrap_or_else(|| merchant_id.to_owned());
+
+    let response = clone_profile(
+        &state,
+        source_profile,
+        &request.merchant_connector_ids,
+        &target_merchant_id,
+        request.profile_name,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+async fn find_profile_templates(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<BusinessProfileTemplatesRecord> {
+    db.find_config_by_key_unwrap_or(
+        &get_profile_templates_config_key(merchant_id),
+        Some("{}".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the business profile templates config")?
+    .config
+    .parse_struct("BusinessProfileTemplatesRecord")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the business profile templates record")
+}
+
+async fn store_profile_templates(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    record: &BusinessProfileTemplatesRecord,
+) -> RouterResult<()> {
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    // The config is created when the templates are first read
+    db.update_config_by_key(
+        &get_profile_templates_config_key(merchant_id),
+        configs::ConfigUpdate::Update {
+            config: Some(config),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the business profile templates config")?;
+
+    Ok(())
+}
+
+pub async fn find_profile_template(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    template_name: &str,
+) -> RouterResult<BusinessProfileTemplate> {
+    find_profile_templates(db, merchant_id)
+        .await?
+        .templates
+        .into_iter()
+        .find(|template| template.template_name == template_name)
+        .ok_or_else(|| {
+            errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("business profile template {template_name} not found"),
+            }
+            .into()
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn list_profile_templates(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<BusinessProfileTemplatesResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let record = find_profile_templates(db, &merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        BusinessProfileTemplatesResponse {
+            merchant_id,
+            templates: record.templates,
+        },
+    ))
+}
+
+/// Saves the business profile of the merchant as a template, replacing the template of the same
+/// name
+#[instrument(skip_all)]
+pub async fn upsert_profile_template(
+    state: SessionState,
+    merchant_id: String,
+    request: BusinessProfileTemplateRequest,
+) -> RouterResponse<BusinessProfileTemplate> {
+    let db = state.store.as_ref();
+    if request.template_name.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "template_name cannot be empty".to_string(),
+        })?
+    }
+    let business_profile =
+        core_utils::validate_and_get_business_profile(db, Some(&request.profile_id), &merchant_id)
+            .await?
+            .ok_or_else(|| errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: request.profile_id.clone(),
+            })?;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    find_profile_connector_accounts(
+        db,
+        &business_profile,
+        &request.merchant_connector_ids,
+        &key_store,
+    )
+    .await?;
+
+    let mut record = find_profile_templates(db, &merchant_id).await?;
+    let timestamp = date_time::now_unix_timestamp();
+    let created_at = record
+        .templates
+        .iter()
+        .find(|template| template.template_name == request.template_name)
+        .map_or(timestamp, |template| template.created_at);
+    let template = BusinessProfileTemplate {
+        template_name: request.template_name,
+        description: request.description,
+        profile_id: request.profile_id,
+        merchant_connector_ids: request.merchant_connector_ids,
+        created_at,
+        modified_at: timestamp,
+    };
+    record
+        .templates
+        .retain(|existing_template| existing_template.template_name != template.template_name);
+    record.templates.push(template.clone());
+    store_profile_templates(db, &merchant_id, &record).await?;
+
+    Ok(services::ApplicationResponse::Json(template))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_profile_template(
+    state: SessionState,
+    merchant_id: String,
+    template_name: String,
+) -> RouterResponse<BusinessProfileTemplate> {
+    let db = state.store.as_ref();
+    let mut record = find_profile_templates(db, &merchant_id).await?;
+    let position = record
+        .templates
+        .iter()
+        .position(|template| template.template_name == template_name)
+        .ok_or_else(|| errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("business profile template {template_name} not found"),
+        })?;
+    let template = record.templates.remove(position);
+    store_profile_templates(db, &merchant_id, &record).await?;
+
+    Ok(services::ApplicationResponse::Json(template))
+}
+
+/// Creates a business profile from the template of the merchant, for the merchant or for another
+/// merchant such as a sub-merchant being onboarded
+#[instrument(skip_all)]
+pub async fn apply_profile_template(
+    state: SessionState,
+    merchant_id: String,
+    template_name: String,
+    request: BusinessProfileTemplateApplyRequest,
+) -> RouterResponse<BusinessProfileCloneResponse> {
+    let db = state.store.as_ref();
+    let template = find_profile_template(db, &merchant_id, &template_name).await?;
+    let source_profile =
+        core_utils::validate_and_get_business_profile(db, Some(&template.profile_id), &merchant_id)
+            .await?
+            .ok_or_else(|| errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: template.profile_id.clone(),
+            })?;
+    let target_merchant_id = request.target_merchant_id.unwrap_or(merchant_id);
+
+    let response = clone_profile(
+        &state,
+        source_profile,
+        &template.merchant_connector_ids,
+        &target_merchant_id,
+        request.profile_name,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
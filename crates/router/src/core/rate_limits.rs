@@ -0,0 +1,243 @@
+use actix_web::http::Method;
+use api_models::rate_limits::{
+    MerchantRateLimitsRecord, MerchantRateLimitsRequest, MerchantRateLimitsResponse,
+    RateLimitEndpointClass, RateLimitQuota,
+};
+use common_utils::ext_traits::{Encode, StringExt};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::api as service_api,
+};
+
+/// Duration in seconds of the window in which the burst of requests is counted
+const BURST_WINDOW_IN_SECS: i64 = 1;
+
+/// Duration in seconds of the window in which the sustained rate of requests is counted
+const SUSTAINED_WINDOW_IN_SECS: i64 = 60;
+
+pub fn get_rate_limits_config_key(merchant_id: &str) -> String {
+    format!("rate_limits_{merchant_id}")
+}
+
+/// The class of endpoints whose quota the request is counted against
+pub fn get_endpoint_class(request: &actix_web::HttpRequest) -> RateLimitEndpointClass {
+    if matches!(*request.method(), Method::GET | Method::HEAD) {
+        return RateLimitEndpointClass::Reads;
+    }
+    match request.path().trim_start_matches('/').split('/').next() {
+        Some("payments") => RateLimitEndpointClass::Payments,
+        Some("refunds") => RateLimitEndpointClass::Refunds,
+        _ => RateLimitEndpointClass::Others,
+    }
+}
+
+fn validate_rate_limit_quotas(request: &MerchantRateLimitsRequest) -> RouterResult<()> {
+    for endpoint_class in [
+        RateLimitEndpointClass::Payments,
+        RateLimitEndpointClass::Refunds,
+        RateLimitEndpointClass::Reads,
+        RateLimitEndpointClass::Others,
+    ] {
+        let Some(quota) = request.get_quota(endpoint_class) else {
+            continue;
+        };
+        if quota.requests_per_minute == 0 || quota.burst == 0 {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "requests_per_minute and burst of the {endpoint_class} quota have to be greater than zero"
+                ),
+            })?
+        }
+        if quota.burst > quota.requests_per_minute {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "burst of the {endpoint_class} quota cannot be greater than its requests_per_minute"
+                ),
+            })?
+        }
+    }
+    Ok(())
+}
+
+async fn find_merchant_rate_limits(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<MerchantRateLimitsRecord> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_rate_limits_config_key(merchant_id),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the rate limits config")?
+        .config
+        .parse_struct("MerchantRateLimitsRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the rate limits record")
+}
+
+fn get_rate_limits_response(
+    state: &SessionState,
+    merchant_id: String,
+    record: &MerchantRateLimitsRecord,
+) -> MerchantRateLimitsResponse {
+    let rate_limit = &state.conf.rate_limit;
+    let get_quota = |endpoint_class| {
+        record
+            .get_quota(endpoint_class)
+            .unwrap_or_else(|| rate_limit.get_quota(endpoint_class))
+    };
+
+    MerchantRateLimitsResponse {
+        merchant_id,
+        enabled: rate_limit.enabled,
+        payments: get_quota(RateLimitEndpointClass::Payments),
+        refunds: get_quota(RateLimitEndpointClass::Refunds),
+        reads: get_quota(RateLimitEndpointClass::Reads),
+        others: get_quota(RateLimitEndpointClass::Others),
+    }
+}
+
+pub async fn retrieve_rate_limits(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<MerchantRateLimitsResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let record = find_merchant_rate_limits(&state, &merchant_id).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_rate_limits_response(&state, merchant_id, &record),
+    ))
+}
+
+/// Replaces the quotas overridden for the merchant, the quotas not set in the request falling
+/// back to the defaults of the deployment
+pub async fn update_rate_limits(
+    state: SessionState,
+    merchant_id: String,
+    request: MerchantRateLimitsRequest,
+) -> RouterResponse<MerchantRateLimitsResponse> {
+    let db = state.store.as_ref();
+    validate_rate_limit_quotas(&request)?;
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key = get_rate_limits_config_key(&merchant_id);
+    let config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the rate limits config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the rate limits config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the rate limits config")?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_rate_limits_response(&state, merchant_id, &request),
+    ))
+}
+
+/// Enforces the quota of the endpoint class of the request on the merchant. The requests are
+/// counted in redis in fixed windows of a second for the burst and of a minute for the sustained
+/// rate, the windows being aligned to the epoch so that every instance counts in the same key.
+/// Failures while reading or updating the counters are logged and the request is allowed.
+#[instrument(skip_all)]
+pub async fn enforce_rate_limit(
+    state: &SessionState,
+    merchant_id: &str,
+    request: &actix_web::HttpRequest,
+) -> RouterResult<()> {
+    if !state.conf.rate_limit.enabled {
+        return Ok(());
+    }
+
+    let endpoint_class = get_endpoint_class(request);
+    let quota = match find_merchant_rate_limits(state, merchant_id).await {
+        Ok(record) => record.get_quota(endpoint_class),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the rate limits of the merchant");
+            None
+        }
+    }
+    .unwrap_or_else(|| state.conf.rate_limit.get_quota(endpoint_class));
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for the rate limits");
+            return Ok(());
+        }
+    };
+
+    let now = common_utils::date_time::now_unix_timestamp();
+    let RateLimitQuota {
+        requests_per_minute,
+        burst,
+    } = quota;
+
+    for (window_in_secs, max_requests) in [
+        (BURST_WINDOW_IN_SECS, burst),
+        (SUSTAINED_WINDOW_IN_SECS, requests_per_minute),
+    ] {
+        let window_start = now - now.rem_euclid(window_in_secs);
+        let key =
+            format!("rate_limit_{merchant_id}_{endpoint_class}_{window_in_secs}_{window_start}");
+        let count = match redis_conn
+            .increment_key_with_expiry(&key, window_in_secs)
+            .await
+        {
+            Ok(count) => count,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to count the request in the rate limit window"
+                );
+                continue;
+            }
+        };
+
+        if count > i64::from(max_requests) {
+            let retry_after_in_secs = window_start + window_in_secs - now;
+            logger::info!(
+                %endpoint_class,
+                window_in_secs,
+                "Merchant exceeded the rate limit of the endpoints"
+            );
+            return Err(errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_in_secs: u64::try_from(retry_after_in_secs).unwrap_or(1),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
@@ -7,22 +7,25 @@ use std::collections::HashMap;
 use api_models::admin::MerchantConnectorInfo;
 use common_utils::{
     ext_traits::{AsyncExt, ValueExt},
+    money::Money,
     types::MinorUnit,
 };
 use diesel_models::process_tracker::business_status;
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use router_env::{instrument, metrics::add_attributes, tracing};
-use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
 #[cfg(feature = "olap")]
 use strum::IntoEnumIterator;
 
 use crate::{
     consts,
     core::{
+        connector_credentials, connector_polling,
+        connector_response_cache::ConnectorResponseCache,
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        ledger,
         payments::{self, access_token, types::PaymentCharges},
-        utils as core_utils,
+        utils as core_utils, webhooks as webhooks_core,
     },
     db, logger,
     routes::{metrics, SessionState},
@@ -36,7 +39,6 @@ use crate::{
         ChargeRefunds,
     },
     utils::{self, OptionExt},
-    workflows::payment_sync,
 };
 
 // ********************************************** REFUND EXECUTE **********************************************
@@ -266,7 +268,46 @@ pub async fn trigger_refund_to_gateway(
                     )
                 })?;
         }
-        router_data_res.to_refund_failed_response()?
+        let router_data_res = router_data_res.to_refund_failed_response()?;
+
+        // While the credentials of the connector are rotated, the refunds of the payments made
+        // with the other credentials are retried with them. The access tokens are obtained with
+        // the current credentials, so the connectors using them are not retried.
+        if !add_access_token_result.connector_supports_access_token
+            && connector_credentials::is_authentication_failure(&router_data_res.response)
+        {
+            match connector_credentials::find_fallback_connector_auth(
+                state,
+                &merchant_account.merchant_id,
+                payment_attempt.merchant_connector_id.as_deref(),
+                key_store,
+            )
+            .await
+            {
+                Some(fallback_connector_auth) => {
+                    logger::info!("Retrying the refund with the fallback connector credentials");
+                    let mut fallback_router_data = router_data.clone();
+                    fallback_router_data.connector_auth_type = fallback_connector_auth;
+                    let connector_integration: services::BoxedRefundConnectorIntegrationInterface<
+                        api::Execute,
+                        types::RefundsData,
+                        types::RefundsResponseData,
+                    > = connector.connector.get_connector_integration();
+                    services::execute_connector_processing_step(
+                        state,
+                        connector_integration,
+                        &fallback_router_data,
+                        payments::CallConnectorAction::Trigger,
+                        None,
+                    )
+                    .await
+                    .to_refund_failed_response()?
+                }
+                None => router_data_res,
+            }
+        } else {
+            router_data_res
+        }
     } else {
         router_data
     };
@@ -291,7 +332,8 @@ pub async fn trigger_refund_to_gateway(
                 refund_status: response.refund_status,
                 sent_to_gateway: true,
                 refund_error_message: None,
-                refund_arn: "".to_string(),
+                refund_arn: response.refund_arn,
+                estimated_arrival_at: response.estimated_arrival_at,
                 updated_by: storage_scheme.to_string(),
             }
         }
@@ -312,6 +354,7 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    ledger::record_refund_entries(state, &response).await;
     Ok(response)
 }
 
@@ -495,12 +538,26 @@ pub async fn sync_refund_with_gateway(
             types::RefundsData,
             types::RefundsResponseData,
         > = connector.connector.get_connector_integration();
-        services::execute_connector_processing_step(
+        let response_cache = refund
+            .connector_refund_id
+            .as_ref()
+            .and_then(|connector_refund_id| {
+                ConnectorResponseCache::new(
+                    state,
+                    &merchant_account.merchant_id,
+                    &connector_id,
+                    "rsync",
+                    connector_refund_id,
+                    refund.refund_status,
+                )
+            });
+        services::execute_connector_processing_step_with_response_cache(
             state,
             connector_integration,
             &router_data,
             payments::CallConnectorAction::Trigger,
             None,
+            response_cache.as_ref(),
         )
         .await
         .to_refund_failed_response()?
@@ -527,7 +584,8 @@ pub async fn sync_refund_with_gateway(
             refund_status: response.refund_status,
             sent_to_gateway: true,
             refund_error_message: None,
-            refund_arn: "".to_string(),
+            refund_arn: response.refund_arn,
+            estimated_arrival_at: response.estimated_arrival_at,
             updated_by: storage_scheme.to_string(),
         },
     };
@@ -547,9 +605,67 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    ledger::record_refund_entries(state, &response).await;
+    trigger_refund_details_outgoing_webhook(state, merchant_account, key_store, refund, &response)
+        .await;
     Ok(response)
 }
 
+/// Notifies the merchant when the acquirer reference number, the estimated arrival time or the
+/// failure reason of a refund changes on syncing it with the connector, so that the merchant can
+/// trace the refund without contacting the connector. Failures are logged without failing the
+/// sync.
+#[instrument(skip_all)]
+async fn trigger_refund_details_outgoing_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    previous_refund: &storage::Refund,
+    updated_refund: &storage::Refund,
+) {
+    let are_details_updated = previous_refund.refund_arn != updated_refund.refund_arn
+        || previous_refund.estimated_arrival_at != updated_refund.estimated_arrival_at
+        || previous_refund.refund_error_message != updated_refund.refund_error_message;
+    if !are_details_updated {
+        return;
+    }
+    let Some(profile_id) = updated_refund.profile_id.as_ref() else {
+        logger::info!("No business profile to send the refund details webhook to");
+        return;
+    };
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the business profile of the webhook"
+            );
+            return;
+        }
+    };
+
+    if let Err(error) = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        enums::EventType::RefundUpdated,
+        enums::EventClass::Refunds,
+        updated_refund.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(updated_refund.clone().foreign_into()),
+        Some(updated_refund.created_at),
+    ))
+    .await
+    {
+        logger::error!(?error, "Failed to raise the refund details webhook");
+    }
+}
+
 // ********************************************** REFUND UPDATE **********************************************
 
 pub async fn refund_update_core(
@@ -686,9 +802,9 @@ pub async fn validate_and_create_refund(
         .unwrap_or(payment_attempt.amount);
 
     validator::validate_refund_amount(
-        total_amount_captured.get_amount_as_i64(),
+        Money::new(total_amount_captured, currency),
         &all_refunds,
-        refund_amount.get_amount_as_i64(),
+        Money::new(refund_amount, currency),
     )
     .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
@@ -780,6 +896,11 @@ pub async fn refund_list(
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
     let db = state.store;
     let limit = validator::validate_refund_list(req.limit)?;
+    if req.offset.is_some() && req.cursor.is_some() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "only one of offset and cursor can be provided".to_string(),
+        })?
+    }
     let offset = req.offset.unwrap_or_default();
 
     let refund_list = db
@@ -793,6 +914,13 @@ pub async fn refund_list(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
 
+    let next_cursor = refund_list
+        .last()
+        .filter(|_| refund_list.len() >= usize::try_from(limit).unwrap_or(usize::MAX))
+        .map(|refund| {
+            api_models::payments::ListCursor::new(refund.created_at, refund.refund_id.clone())
+        });
+
     let data: Vec<refunds::RefundResponse> = refund_list
         .into_iter()
         .map(ForeignInto::foreign_into)
@@ -812,6 +940,7 @@ pub async fn refund_list(
             count: data.len(),
             total_count,
             data,
+            next_cursor,
         },
     ))
 }
@@ -898,6 +1027,8 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
             charges: refund.charges,
+            refund_arn: refund.refund_arn,
+            estimated_arrival_at: refund.estimated_arrival_at,
         }
     }
 }
@@ -1040,10 +1171,11 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            _ = payment_sync::retry_sync_task(
+            _ = connector_polling::retry_polling_task(
                 &*state.store,
-                response.connector,
-                response.merchant_id,
+                connector_polling::PollingObject::Refund,
+                &response.connector,
+                &response.merchant_id,
                 refund_tracker.to_owned(),
             )
             .await?;
@@ -1292,31 +1424,3 @@ pub async fn add_refund_execute_task(
         })?;
     Ok(response)
 }
-
-pub async fn get_refund_sync_process_schedule_time(
-    db: &dyn db::StorageInterface,
-    connector: &str,
-    merchant_id: &str,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        db::get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_refund_sync_{connector}"),
-            "ConnectorPTMapping",
-        )
-        .await;
-
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::error!("Error: while getting connector mapping: {}", err);
-            process_data::ConnectorPTMapping::default()
-        }
-    };
-
-    let time_delta =
-        process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
-
-    Ok(process_tracker_utils::get_time_from_delta(time_delta))
-}
@@ -1,4 +1,5 @@
-use error_stack::report;
+use common_utils::money::Money;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
@@ -44,31 +45,32 @@ pub fn validate_success_transaction(
 
 #[instrument(skip_all)]
 pub fn validate_refund_amount(
-    amount_captured: i64,
+    amount_captured: Money,
     all_refunds: &[storage::Refund],
-    refund_amount: i64,
+    refund_amount: Money,
 ) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
-        .iter()
-        .filter_map(|refund| {
-            if refund.refund_status != enums::RefundStatus::Failure
-                && refund.refund_status != enums::RefundStatus::TransactionFailure
-            {
-                Some(refund.refund_amount.get_amount_as_i64())
-            } else {
-                None
-            }
-        })
-        .sum();
-
-    utils::when(
-        refund_amount > (amount_captured - total_refunded_amount),
-        || {
-            Err(report!(
-                RefundValidationError::RefundAmountExceedsPaymentAmount
-            ))
-        },
+    let total_refunded_amount = Money::try_sum(
+        amount_captured.get_currency(),
+        all_refunds
+            .iter()
+            .filter(|refund| {
+                refund.refund_status != enums::RefundStatus::Failure
+                    && refund.refund_status != enums::RefundStatus::TransactionFailure
+            })
+            .map(|refund| Money::new(refund.refund_amount, refund.currency)),
     )
+    .change_context(RefundValidationError::RefundAmountExceedsPaymentAmount)
+    .attach_printable("Failed to total the amounts refunded")?;
+
+    // The amount refundable going below zero means that more than captured was already refunded
+    let amount_refundable = amount_captured
+        .checked_sub(total_refunded_amount)
+        .change_context(RefundValidationError::RefundAmountExceedsPaymentAmount)?;
+
+    amount_refundable
+        .checked_sub(refund_amount)
+        .map(|_| ())
+        .change_context(RefundValidationError::RefundAmountExceedsPaymentAmount)
 }
 
 #[instrument(skip_all)]
@@ -0,0 +1,1054 @@
+use std::str::FromStr;
+
+use api_models::{
+    enums as api_enums,
+    reports::{
+        GeneratedReport, ReportDownloadQuery, ReportFilters, ReportFrequency, ReportSchedule,
+        ReportTemplateCreateRequest, ReportTemplateResponse, ReportTemplateUpdateRequest,
+        ReportWeekday,
+    },
+};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage, VerifySignature},
+    ext_traits::{Encode, ValueExt},
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::{
+    payment_attempt::PaymentAttempt,
+    payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+    PaymentIntent,
+};
+use masking::PeekInterface;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+pub const REPORT_GENERATION_TASK: &str = "REPORT_GENERATION";
+pub const REPORT_GENERATION_TAG: &str = "REPORT";
+
+/// Number of records fetched from the database at a time while generating a report
+const REPORT_PAGE_SIZE: u32 = 1000;
+
+/// Payments captured within the period of a settlement report are looked up among the payments
+/// created within this many days before the end of the period
+const SETTLEMENT_LOOKBACK_DAYS: i64 = 30;
+
+const PAYMENT_COLUMNS: &[&str] = &[
+    "payment_id",
+    "attempt_id",
+    "profile_id",
+    "status",
+    "amount",
+    "amount_captured",
+    "currency",
+    "connector",
+    "payment_method",
+    "payment_method_type",
+    "customer_id",
+    "description",
+    "merchant_reference",
+    "custom_fields",
+    "error_code",
+    "error_message",
+    "created_at",
+    "modified_at",
+];
+
+const REFUND_COLUMNS: &[&str] = &[
+    "refund_id",
+    "payment_id",
+    "profile_id",
+    "refund_status",
+    "refund_amount",
+    "total_amount",
+    "currency",
+    "connector",
+    "connector_refund_id",
+    "refund_reason",
+    "error_code",
+    "error_message",
+    "created_at",
+    "modified_at",
+];
+
+const SETTLEMENT_COLUMNS: &[&str] = &[
+    "payment_id",
+    "attempt_id",
+    "profile_id",
+    "status",
+    "amount",
+    "amount_captured",
+    "currency",
+    "connector",
+    "connector_transaction_id",
+    "payment_method",
+    "payment_method_type",
+    "captured_at",
+];
+
+fn get_available_columns(report_type: enums::ReportType) -> &'static [&'static str] {
+    match report_type {
+        enums::ReportType::Payments => PAYMENT_COLUMNS,
+        enums::ReportType::Refunds => REFUND_COLUMNS,
+        enums::ReportType::Settlements => SETTLEMENT_COLUMNS,
+    }
+}
+
+fn validate_columns(
+    report_type: enums::ReportType,
+    columns: Option<Vec<String>>,
+) -> RouterResult<Vec<String>> {
+    let available_columns = get_available_columns(report_type);
+    let Some(columns) = columns else {
+        return Ok(available_columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect());
+    };
+
+    if columns.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "columns must not be empty".to_string(),
+        })?
+    }
+    if let Some(column) = columns
+        .iter()
+        .find(|column| !available_columns.contains(&column.as_str()))
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "column `{column}` is not available for {report_type} reports, available columns are {}",
+                available_columns.join(", ")
+            ),
+        })?
+    }
+
+    Ok(columns)
+}
+
+fn validate_filters(filters: &ReportFilters) -> RouterResult<()> {
+    filters
+        .connector
+        .iter()
+        .flatten()
+        .try_for_each(|connector| {
+            api_enums::Connector::from_str(connector)
+                .map(|_| ())
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("invalid connector `{connector}` in filters"),
+                })
+        })
+}
+
+fn validate_schedule(schedule: &ReportSchedule) -> RouterResult<()> {
+    if schedule.hour > 23 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "schedule hour must be between 0 and 23".to_string(),
+        })?
+    }
+    match (schedule.frequency, schedule.day_of_week) {
+        (ReportFrequency::Weekly, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "schedule.day_of_week",
+        })?,
+        (ReportFrequency::Daily, Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "day_of_week is only applicable to weekly reports".to_string(),
+        })?,
+        _ => Ok(()),
+    }
+}
+
+fn get_weekday(day_of_week: ReportWeekday) -> time::Weekday {
+    match day_of_week {
+        ReportWeekday::Monday => time::Weekday::Monday,
+        ReportWeekday::Tuesday => time::Weekday::Tuesday,
+        ReportWeekday::Wednesday => time::Weekday::Wednesday,
+        ReportWeekday::Thursday => time::Weekday::Thursday,
+        ReportWeekday::Friday => time::Weekday::Friday,
+        ReportWeekday::Saturday => time::Weekday::Saturday,
+        ReportWeekday::Sunday => time::Weekday::Sunday,
+    }
+}
+
+fn get_period_length(schedule: &ReportSchedule) -> time::Duration {
+    match schedule.frequency {
+        ReportFrequency::Daily => time::Duration::days(1),
+        ReportFrequency::Weekly => time::Duration::weeks(1),
+    }
+}
+
+/// The first time strictly after `after` at which a report with the schedule is generated
+pub fn get_next_run_at(
+    schedule: &ReportSchedule,
+    after: PrimitiveDateTime,
+) -> RouterResult<PrimitiveDateTime> {
+    let time_of_day = time::Time::from_hms(schedule.hour, 0, 0)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid hour in the report schedule")?;
+    let mut next_run_at = PrimitiveDateTime::new(after.date(), time_of_day);
+
+    if let Some(day_of_week) = schedule.day_of_week {
+        let days_ahead = (7 + get_weekday(day_of_week).number_days_from_monday()
+            - next_run_at.weekday().number_days_from_monday())
+            % 7;
+        next_run_at = next_run_at.saturating_add(time::Duration::days(i64::from(days_ahead)));
+    }
+    if next_run_at <= after {
+        next_run_at = next_run_at.saturating_add(get_period_length(schedule));
+    }
+
+    Ok(next_run_at)
+}
+
+pub fn generate_task_id_for_report_generation_workflow(template_id: &str) -> String {
+    format!(
+        "{runner}_{task}_{template_id}",
+        runner = storage::ProcessTrackerRunner::ReportGenerationWorkflow,
+        task = REPORT_GENERATION_TASK
+    )
+}
+
+async fn add_report_generation_task(
+    db: &dyn StorageInterface,
+    report_template: &storage::ReportTemplate,
+) -> RouterResult<()> {
+    let tracking_data = storage::ReportGenerationTrackingData {
+        merchant_id: report_template.merchant_id.clone(),
+        template_id: report_template.template_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_report_generation_workflow(&report_template.template_id),
+        REPORT_GENERATION_TASK,
+        storage::ProcessTrackerRunner::ReportGenerationWorkflow,
+        [REPORT_GENERATION_TAG],
+        tracking_data,
+        report_template.next_run_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct report generation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the report generation task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "ReportGeneration")]),
+    );
+
+    Ok(())
+}
+
+async fn reschedule_report_generation_task(
+    db: &dyn StorageInterface,
+    report_template: &storage::ReportTemplate,
+) -> RouterResult<()> {
+    let task_id = generate_task_id_for_report_generation_workflow(&report_template.template_id);
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the report generation task")?;
+
+    match process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, report_template.next_run_at)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the report generation task"),
+        None => add_report_generation_task(db, report_template).await,
+    }
+}
+
+async fn revoke_report_generation_task(
+    db: &dyn StorageInterface,
+    template_id: &str,
+) -> RouterResult<()> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![generate_task_id_for_report_generation_workflow(template_id)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: enums::ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::REVOKED)),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to revoke the report generation task")?;
+
+    Ok(())
+}
+
+fn sign_download_link(
+    state: &SessionState,
+    merchant_id: &str,
+    file_id: &str,
+    expires_at: i64,
+) -> RouterResult<Vec<u8>> {
+    let signing_key = state.conf.scheduled_reports.signing_key.peek();
+    if signing_key.is_empty() {
+        Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Signing key of the report download links is not configured")?
+    }
+
+    HmacSha256
+        .sign_message(
+            signing_key.as_bytes(),
+            format!("{merchant_id}:{file_id}:{expires_at}").as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign the report download link")
+}
+
+fn get_generated_report(
+    state: &SessionState,
+    merchant_id: &str,
+    file_id: String,
+) -> RouterResult<GeneratedReport> {
+    let download_url_expires_at =
+        common_utils::date_time::now().saturating_add(time::Duration::seconds(i64::from(
+            state.conf.scheduled_reports.download_link_expiry_in_secs,
+        )));
+    let expires_at = download_url_expires_at.assume_utc().unix_timestamp();
+    let signature = hex::encode(sign_download_link(
+        state,
+        merchant_id,
+        &file_id,
+        expires_at,
+    )?);
+
+    Ok(GeneratedReport {
+        download_url: format!(
+            "{}/reports/{merchant_id}/files/{file_id}/download?expires_at={expires_at}&signature={signature}",
+            state.base_url
+        ),
+        file_id,
+        download_url_expires_at,
+    })
+}
+
+fn get_report_template_response(
+    state: &SessionState,
+    report_template: storage::ReportTemplate,
+) -> RouterResult<ReportTemplateResponse> {
+    let last_report = report_template
+        .last_file_id
+        .map(|file_id| get_generated_report(state, &report_template.merchant_id, file_id))
+        .transpose()?;
+
+    Ok(ReportTemplateResponse {
+        template_id: report_template.template_id,
+        merchant_id: report_template.merchant_id,
+        profile_id: report_template.profile_id,
+        name: report_template.name,
+        report_type: report_template.report_type,
+        columns: report_template
+            .columns
+            .parse_value("ReportColumns")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        filters: report_template
+            .filters
+            .map(|filters| filters.parse_value("ReportFilters"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        schedule: report_template
+            .schedule
+            .parse_value("ReportSchedule")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        is_active: report_template.is_active,
+        next_run_at: report_template.next_run_at,
+        last_run_at: report_template.last_run_at,
+        last_run_status: report_template.last_run_status,
+        last_report,
+        created_at: report_template.created_at,
+        modified_at: report_template.modified_at,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn create_report_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: ReportTemplateCreateRequest,
+) -> RouterResponse<ReportTemplateResponse> {
+    let db = state.store.as_ref();
+    if let Some(profile_id) = &request.profile_id {
+        core_utils::validate_and_get_business_profile(
+            db,
+            Some(profile_id),
+            &merchant_account.merchant_id,
+        )
+        .await?;
+    }
+    let columns = validate_columns(request.report_type, request.columns)?;
+    if let Some(filters) = &request.filters {
+        validate_filters(filters)?;
+    }
+    validate_schedule(&request.schedule)?;
+
+    let now = common_utils::date_time::now();
+    let report_template_new = storage::ReportTemplateNew {
+        template_id: common_utils::generate_id(consts::ID_LENGTH, "rpt"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        profile_id: request.profile_id,
+        name: request.name,
+        report_type: request.report_type,
+        columns: columns
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        filters: request
+            .filters
+            .map(|filters| filters.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        schedule: request
+            .schedule
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        is_active: true,
+        next_run_at: get_next_run_at(&request.schedule, now)?,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let report_template = db
+        .insert_report_template(report_template_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the report template")?;
+
+    add_report_generation_task(db, &report_template).await?;
+
+    get_report_template_response(&state, report_template).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_report_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    template_id: String,
+) -> RouterResponse<ReportTemplateResponse> {
+    let report_template = state
+        .store
+        .find_report_template_by_merchant_id_template_id(
+            &merchant_account.merchant_id,
+            &template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report template not found".to_string(),
+        })?;
+
+    get_report_template_response(&state, report_template).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_report_templates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<ReportTemplateResponse>> {
+    let report_templates = state
+        .store
+        .list_report_templates_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the report templates")?;
+
+    report_templates
+        .into_iter()
+        .map(|report_template| get_report_template_response(&state, report_template))
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn update_report_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    template_id: String,
+    request: ReportTemplateUpdateRequest,
+) -> RouterResponse<ReportTemplateResponse> {
+    let db = state.store.as_ref();
+    let report_template = db
+        .find_report_template_by_merchant_id_template_id(
+            &merchant_account.merchant_id,
+            &template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report template not found".to_string(),
+        })?;
+
+    let columns = request
+        .columns
+        .map(|columns| validate_columns(report_template.report_type, Some(columns)))
+        .transpose()?;
+    if let Some(filters) = &request.filters {
+        validate_filters(filters)?;
+    }
+    if let Some(schedule) = &request.schedule {
+        validate_schedule(schedule)?;
+    }
+
+    // The next run is recomputed when the schedule changes or the report is reactivated
+    let is_reactivated = request.is_active == Some(true) && !report_template.is_active;
+    let next_run_at = if request.schedule.is_some() || is_reactivated {
+        let schedule = match &request.schedule {
+            Some(schedule) => schedule.clone(),
+            None => report_template
+                .schedule
+                .clone()
+                .parse_value("ReportSchedule")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        };
+        Some(get_next_run_at(&schedule, common_utils::date_time::now())?)
+    } else {
+        None
+    };
+
+    let report_template_update = storage::ReportTemplateUpdate::TemplateUpdate {
+        name: request.name,
+        columns: columns
+            .map(|columns| columns.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        filters: request
+            .filters
+            .map(|filters| filters.encode_to_value().map(Some))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        schedule: request
+            .schedule
+            .map(|schedule| schedule.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        is_active: request.is_active,
+        next_run_at,
+    };
+    let report_template = db
+        .update_report_template(report_template, report_template_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the report template")?;
+
+    if !report_template.is_active {
+        revoke_report_generation_task(db, &report_template.template_id).await?;
+    } else if next_run_at.is_some() {
+        reschedule_report_generation_task(db, &report_template).await?;
+    }
+
+    get_report_template_response(&state, report_template).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn delete_report_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    template_id: String,
+) -> RouterResponse<ReportTemplateResponse> {
+    let db = state.store.as_ref();
+    let report_template = db
+        .delete_report_template_by_merchant_id_template_id(
+            &merchant_account.merchant_id,
+            &template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report template not found".to_string(),
+        })?;
+
+    revoke_report_generation_task(db, &report_template.template_id).await?;
+
+    get_report_template_response(&state, report_template).map(ApplicationResponse::Json)
+}
+
+/// Serves a generated report through a signed download link, which does not require the merchant
+/// to be authenticated
+#[instrument(skip_all)]
+pub async fn download_report(
+    state: SessionState,
+    merchant_id: String,
+    file_id: String,
+    query: ReportDownloadQuery,
+) -> RouterResponse<serde_json::Value> {
+    let is_signature_valid = hex::decode(&query.signature)
+        .ok()
+        .map(|signature| {
+            HmacSha256.verify_signature(
+                state.conf.scheduled_reports.signing_key.peek().as_bytes(),
+                &signature,
+                format!("{merchant_id}:{file_id}:{}", query.expires_at).as_bytes(),
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .unwrap_or(false);
+    if !is_signature_valid {
+        Err(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "Invalid report download link".to_string(),
+        })?
+    }
+    if query.expires_at < common_utils::date_time::now_unix_timestamp() {
+        Err(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "Report download link has expired".to_string(),
+        })?
+    }
+
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_id, &file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)?;
+    let provider_file_id = file_metadata
+        .provider_file_id
+        .filter(|_| file_metadata.available)
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)?;
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the report from the file storage")?;
+
+    Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)))
+}
+
+fn escape_csv_field(field: String) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+pub(crate) fn build_csv(columns: &[String], rows: Vec<Vec<Option<String>>>) -> Vec<u8> {
+    let mut csv = columns.join(",");
+    csv.push('\n');
+    for row in rows {
+        let line = row
+            .into_iter()
+            .map(|field| escape_csv_field(field.unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+    csv.into_bytes()
+}
+
+pub(crate) fn format_timestamp(timestamp: PrimitiveDateTime) -> String {
+    timestamp.assume_utc().to_string()
+}
+
+fn get_payment_field(
+    column: &str,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> Option<String> {
+    match column {
+        "payment_id" => Some(payment_intent.payment_id.clone()),
+        "attempt_id" => Some(payment_attempt.attempt_id.clone()),
+        "profile_id" => payment_intent.profile_id.clone(),
+        "status" => Some(payment_intent.status.to_string()),
+        "amount" => Some(payment_intent.amount.to_string()),
+        "amount_captured" => payment_intent
+            .amount_captured
+            .map(|amount| amount.to_string()),
+        "currency" => payment_intent.currency.map(|currency| currency.to_string()),
+        "connector" => payment_attempt.connector.clone(),
+        "connector_transaction_id" => payment_attempt.connector_transaction_id.clone(),
+        "payment_method" => payment_attempt
+            .payment_method
+            .map(|payment_method| payment_method.to_string()),
+        "payment_method_type" => payment_attempt
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string()),
+        "customer_id" => payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned()),
+        "description" => payment_intent.description.clone(),
+        "merchant_reference" => payment_intent.merchant_reference.clone(),
+        "custom_fields" => payment_intent
+            .custom_fields
+            .as_ref()
+            .map(|custom_fields| custom_fields.to_string()),
+        "error_code" => payment_attempt.error_code.clone(),
+        "error_message" => payment_attempt.error_message.clone(),
+        "created_at" => Some(format_timestamp(payment_intent.created_at)),
+        "modified_at" => Some(format_timestamp(payment_intent.modified_at)),
+        "captured_at" => Some(format_timestamp(payment_attempt.modified_at)),
+        _ => None,
+    }
+}
+
+fn get_refund_field(column: &str, refund: &storage::Refund) -> Option<String> {
+    match column {
+        "refund_id" => Some(refund.refund_id.clone()),
+        "payment_id" => Some(refund.payment_id.clone()),
+        "profile_id" => refund.profile_id.clone(),
+        "refund_status" => Some(refund.refund_status.to_string()),
+        "refund_amount" => Some(refund.refund_amount.to_string()),
+        "total_amount" => Some(refund.total_amount.to_string()),
+        "currency" => Some(refund.currency.to_string()),
+        "connector" => Some(refund.connector.clone()),
+        "connector_refund_id" => refund.connector_refund_id.clone(),
+        "refund_reason" => refund.refund_reason.clone(),
+        "error_code" => refund.refund_error_code.clone(),
+        "error_message" => refund.refund_error_message.clone(),
+        "created_at" => Some(format_timestamp(refund.created_at)),
+        "modified_at" => Some(format_timestamp(refund.updated_at)),
+        _ => None,
+    }
+}
+
+async fn fetch_payments(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    report_template: &storage::ReportTemplate,
+    filters: &ReportFilters,
+    (starting_at, ending_at): (PrimitiveDateTime, PrimitiveDateTime),
+    status: Option<Vec<enums::IntentStatus>>,
+) -> RouterResult<Vec<(PaymentIntent, PaymentAttempt)>> {
+    let connector = filters
+        .connector
+        .as_ref()
+        .map(|connectors| {
+            connectors
+                .iter()
+                .map(|connector| api_enums::Connector::from_str(connector))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector in the filters of the report template")?;
+
+    let mut payments: Vec<(PaymentIntent, PaymentAttempt)> = Vec::new();
+    loop {
+        let cursor = payments.last().map(|(payment_intent, _)| {
+            api_models::payments::ListCursor::new(
+                payment_intent.created_at,
+                payment_intent.payment_id.clone(),
+            )
+        });
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: 0,
+            starting_at: Some(starting_at),
+            ending_at: Some(ending_at),
+            amount_filter: None,
+            connector: connector.clone(),
+            currency: filters.currency.clone(),
+            status: status.clone(),
+            payment_method: filters.payment_method.clone(),
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: report_template.profile_id.clone(),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(REPORT_PAGE_SIZE),
+            cursor,
+            card_network: None,
+            metadata: None,
+            order_id: None,
+            custom_fields: None,
+        }));
+        let page = state
+            .store
+            .get_filtered_payment_intents_attempt(
+                &merchant_account.merchant_id,
+                &constraints,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payments of the report")?;
+
+        let is_last_page = page.len() < usize::try_from(REPORT_PAGE_SIZE).unwrap_or(usize::MAX);
+        payments.extend(page);
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(payments)
+}
+
+async fn fetch_refunds(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    report_template: &storage::ReportTemplate,
+    filters: &ReportFilters,
+    (start_time, end_time): (PrimitiveDateTime, PrimitiveDateTime),
+) -> RouterResult<Vec<storage::Refund>> {
+    let page_size = i64::from(REPORT_PAGE_SIZE);
+    let mut refund_list_request = api_models::refunds::RefundListRequest {
+        payment_id: None,
+        refund_id: None,
+        profile_id: report_template.profile_id.clone(),
+        limit: None,
+        offset: None,
+        cursor: None,
+        time_range: Some(api_models::payments::TimeRange {
+            start_time,
+            end_time: Some(end_time),
+        }),
+        amount_filter: None,
+        connector: filters.connector.clone(),
+        merchant_connector_id: None,
+        currency: filters.currency.clone(),
+        refund_status: None,
+    };
+
+    let mut refunds: Vec<storage::Refund> = Vec::new();
+    loop {
+        refund_list_request.cursor = refunds.last().map(|refund| {
+            api_models::payments::ListCursor::new(refund.created_at, refund.refund_id.clone())
+        });
+        let page = state
+            .store
+            .filter_refund_by_constraints(
+                &merchant_account.merchant_id,
+                &refund_list_request,
+                merchant_account.storage_scheme,
+                page_size,
+                0,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds of the report")?;
+
+        let is_last_page = page.len() < usize::try_from(page_size).unwrap_or(usize::MAX);
+        refunds.extend(page);
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(refunds)
+}
+
+async fn generate_report_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    report_template: &storage::ReportTemplate,
+    columns: &[String],
+    (period_start, period_end): (PrimitiveDateTime, PrimitiveDateTime),
+) -> RouterResult<Vec<Vec<Option<String>>>> {
+    let filters: ReportFilters = report_template
+        .filters
+        .clone()
+        .map(|filters| filters.parse_value("ReportFilters"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .unwrap_or_default();
+
+    let rows = match report_template.report_type {
+        enums::ReportType::Payments => fetch_payments(
+            state,
+            merchant_account,
+            key_store,
+            report_template,
+            &filters,
+            (period_start, period_end),
+            None,
+        )
+        .await?
+        .iter()
+        .map(|(payment_intent, payment_attempt)| {
+            columns
+                .iter()
+                .map(|column| get_payment_field(column, payment_intent, payment_attempt))
+                .collect()
+        })
+        .collect(),
+        enums::ReportType::Refunds => fetch_refunds(
+            state,
+            merchant_account,
+            report_template,
+            &filters,
+            (period_start, period_end),
+        )
+        .await?
+        .iter()
+        .map(|refund| {
+            columns
+                .iter()
+                .map(|column| get_refund_field(column, refund))
+                .collect()
+        })
+        .collect(),
+        enums::ReportType::Settlements => fetch_payments(
+            state,
+            merchant_account,
+            key_store,
+            report_template,
+            &filters,
+            (
+                period_end.saturating_sub(time::Duration::days(SETTLEMENT_LOOKBACK_DAYS)),
+                period_end,
+            ),
+            Some(vec![
+                enums::IntentStatus::Succeeded,
+                enums::IntentStatus::PartiallyCaptured,
+                enums::IntentStatus::PartiallyCapturedAndCapturable,
+            ]),
+        )
+        .await?
+        .iter()
+        .filter(|(_, payment_attempt)| {
+            payment_attempt.modified_at >= period_start && payment_attempt.modified_at < period_end
+        })
+        .map(|(payment_intent, payment_attempt)| {
+            columns
+                .iter()
+                .map(|column| get_payment_field(column, payment_intent, payment_attempt))
+                .collect()
+        })
+        .collect(),
+    };
+
+    Ok(rows)
+}
+
+/// Generates the report of the period ending at `period_end` and stores it as a file of the
+/// merchant, returning the identifier of the file
+#[instrument(skip_all, fields(template_id = %report_template.template_id))]
+async fn generate_report(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    report_template: &storage::ReportTemplate,
+    schedule: &ReportSchedule,
+    period_end: PrimitiveDateTime,
+) -> RouterResult<String> {
+    let columns: Vec<String> = report_template
+        .columns
+        .clone()
+        .parse_value("ReportColumns")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let period_start = period_end.saturating_sub(get_period_length(schedule));
+
+    let rows = generate_report_rows(
+        state,
+        merchant_account,
+        key_store,
+        report_template,
+        &columns,
+        (period_start, period_end),
+    )
+    .await?;
+    logger::info!(rows = rows.len(), "Generated report");
+    let file = build_csv(&columns, rows);
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", merchant_account.merchant_id, file_id);
+    let file_size = i32::try_from(file.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Generated report is too large")?;
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the report to the file storage")?;
+
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        file_name: Some(format!(
+            "{}_{}.csv",
+            report_template.name,
+            period_start.date()
+        )),
+        file_size,
+        file_type: mime::TEXT_CSV.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(api_enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: report_template.profile_id.clone(),
+        merchant_connector_id: None,
+    };
+    state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the file metadata of the report")?;
+
+    Ok(file_id)
+}
+
+/// Generates the report due for the template and records the outcome on the template. A failed
+/// run is not retried, the report is generated again on its next scheduled run.
+#[instrument(skip_all)]
+pub async fn run_scheduled_report(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    report_template: storage::ReportTemplate,
+) -> RouterResult<storage::ReportTemplate> {
+    let schedule: ReportSchedule = report_template
+        .schedule
+        .clone()
+        .parse_value("ReportSchedule")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let period_end = report_template.next_run_at;
+
+    let generated_report = generate_report(
+        state,
+        merchant_account,
+        key_store,
+        &report_template,
+        &schedule,
+        period_end,
+    )
+    .await;
+    let (last_run_status, last_file_id) = match generated_report {
+        Ok(file_id) => (enums::ReportRunStatus::Succeeded, Some(file_id)),
+        Err(error) => {
+            logger::error!(?error, "Failed to generate the scheduled report");
+            (enums::ReportRunStatus::Failed, None)
+        }
+    };
+
+    // Runs missed while the scheduler was down are skipped
+    let next_run_at = get_next_run_at(
+        &schedule,
+        std::cmp::max(period_end, common_utils::date_time::now()),
+    )?;
+    state
+        .store
+        .update_report_template(
+            report_template,
+            storage::ReportTemplateUpdate::RunUpdate {
+                last_run_at: common_utils::date_time::now(),
+                last_run_status,
+                last_file_id,
+                next_run_at,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the run of the report template")
+}
@@ -0,0 +1,417 @@
+use api_models::{
+    api_keys::ApiKeyScope,
+    response_field_access::{
+        ResponseFieldAccessRecord, ResponseFieldAccessRequest, ResponseFieldAccessResponse,
+        SensitiveFieldAccess, SensitiveFieldCategory,
+    },
+};
+use common_utils::ext_traits::{Encode, StringExt};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::{api as service_api, authentication as auth},
+    types::transformers,
+};
+
+/// The resources whose API responses carry the sensitive fields
+const SENSITIVE_RESOURCES: [&str; 8] = [
+    "payments",
+    "refunds",
+    "customers",
+    "payment_methods",
+    "mandates",
+    "disputes",
+    "payouts",
+    "graphql",
+];
+
+/// The text the sensitive fields are replaced with when masked
+const MASKED_VALUE: &str = "**MASKED**";
+
+const CONNECTOR_RESPONSE_FIELDS: [&str; 7] = [
+    "connector_metadata",
+    "error_reason",
+    "network_advice_code",
+    "attempts.connector_metadata",
+    "attempts.error_reason",
+    "payment_method_data.card.payment_checks",
+    "payment_method_data.card.authentication_data",
+];
+
+const CUSTOMER_PII_FIELDS: [&str; 12] = [
+    "name",
+    "email",
+    "phone",
+    "address",
+    "billing",
+    "shipping",
+    "customer.name",
+    "customer.email",
+    "customer.phone",
+    "payment_method_data.billing",
+    "payment_method_data.card.card_holder_name",
+    "card.card_holder_name",
+];
+
+const CARD_METADATA_FIELDS: [&str; 12] = [
+    "payment_method_data.card.card_isin",
+    "payment_method_data.card.card_extended_bin",
+    "payment_method_data.card.card_issuer",
+    "payment_method_data.card.card_issuing_country",
+    "payment_method_data.card.card_exp_month",
+    "payment_method_data.card.card_exp_year",
+    "card.card_isin",
+    "card.card_issuer",
+    "card.issuer_country",
+    "card.expiry_month",
+    "card.expiry_year",
+    "card.card_fingerprint",
+];
+
+/// The paths of the fields of the category in the API responses, the fields of the items of the
+/// lists on the way being looked up in each item
+fn get_sensitive_fields(category: SensitiveFieldCategory) -> &'static [&'static str] {
+    match category {
+        SensitiveFieldCategory::ConnectorResponse => &CONNECTOR_RESPONSE_FIELDS,
+        SensitiveFieldCategory::CustomerPii => &CUSTOMER_PII_FIELDS,
+        SensitiveFieldCategory::CardMetadata => &CARD_METADATA_FIELDS,
+    }
+}
+
+pub fn get_response_field_access_config_key(merchant_id: &str) -> String {
+    format!("response_field_access_{merchant_id}")
+}
+
+async fn find_response_field_access(
+    state: &SessionState,
+    merchant_id: &str,
+) -> RouterResult<ResponseFieldAccessRecord> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_response_field_access_config_key(merchant_id),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the response field access config")?
+        .config
+        .parse_struct("ResponseFieldAccessRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the response field access record")
+}
+
+fn get_response_field_access_response(
+    merchant_id: String,
+    record: ResponseFieldAccessRecord,
+) -> ResponseFieldAccessResponse {
+    ResponseFieldAccessResponse {
+        merchant_id,
+        connector_response: record.get_access(SensitiveFieldCategory::ConnectorResponse),
+        customer_pii: record.get_access(SensitiveFieldCategory::CustomerPii),
+        card_metadata: record.get_access(SensitiveFieldCategory::CardMetadata),
+        unrestricted_roles: record.unrestricted_roles,
+    }
+}
+
+pub async fn retrieve_response_field_access(
+    state: SessionState,
+    merchant_id: String,
+) -> RouterResponse<ResponseFieldAccessResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let record = find_response_field_access(&state, &merchant_id).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_response_field_access_response(merchant_id, record),
+    ))
+}
+
+/// Replaces the access to the sensitive fields configured for the merchant, the categories not
+/// set in the request being visible
+pub async fn update_response_field_access(
+    state: SessionState,
+    merchant_id: String,
+    request: ResponseFieldAccessRequest,
+) -> RouterResponse<ResponseFieldAccessResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key = get_response_field_access_config_key(&merchant_id);
+    let config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize config to string")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the response field access config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the response field access config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the response field access config")?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_response_field_access_response(merchant_id, request),
+    ))
+}
+
+/// The sensitive fields hidden from the response of a request, kept in the extensions of the
+/// request until the response is serialized
+#[derive(Clone, Debug)]
+pub struct ResponseFieldRestrictions {
+    restrictions: Vec<(SensitiveFieldCategory, SensitiveFieldAccess)>,
+}
+
+impl ResponseFieldRestrictions {
+    /// Hides the sensitive fields from the serialized response. The fields are looked up in the
+    /// response, in each of its items when it is a list, and in each item of its `data` field
+    /// when it is a paginated list.
+    pub fn apply(&self, response: &mut serde_json::Value) {
+        for &(category, access) in &self.restrictions {
+            for path in get_sensitive_fields(category) {
+                let segments = path.split('.').collect::<Vec<_>>();
+                apply_field_access(response, &segments, access);
+                if let Some(data) = response.get_mut("data") {
+                    apply_field_access(data, &segments, access);
+                }
+            }
+        }
+    }
+}
+
+fn apply_field_access(value: &mut serde_json::Value, path: &[&str], access: SensitiveFieldAccess) {
+    match value {
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| apply_field_access(item, path, access)),
+        serde_json::Value::Object(fields) => match path {
+            [field] => match access {
+                SensitiveFieldAccess::Visible => {}
+                SensitiveFieldAccess::Masked => {
+                    if let Some(value) = fields.get_mut(*field) {
+                        mask_value(value);
+                    }
+                }
+                SensitiveFieldAccess::Omitted => {
+                    fields.remove(*field);
+                }
+            },
+            [segment, rest @ ..] => {
+                if let Some(value) = fields.get_mut(*segment) {
+                    apply_field_access(value, rest, access);
+                }
+            }
+            [] => {}
+        },
+        _ => {}
+    }
+}
+
+/// Replaces the text of the value with the masked value, the structure of the value being kept
+fn mask_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::String(text) => *text = MASKED_VALUE.to_string(),
+        serde_json::Value::Array(items) => items.iter_mut().for_each(mask_value),
+        serde_json::Value::Object(fields) => fields.values_mut().for_each(mask_value),
+        serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {}
+    }
+}
+
+fn is_sensitive_data_granted(scopes: &[ApiKeyScope]) -> bool {
+    scopes
+        .iter()
+        .any(|scope| ApiKeyScope::SensitiveDataRead.is_granted_by(*scope))
+}
+
+/// Whether the caller is not allowed to see the sensitive fields. API keys, and the OAuth2 access
+/// tokens issued to them, are restricted when restricted to scopes without the
+/// `sensitive_data:read` scope. Dashboard users are restricted when their role is not among the
+/// unrestricted roles of the merchant.
+async fn is_caller_restricted(
+    state: &SessionState,
+    auth_type: &auth::AuthenticationType,
+    request: &actix_web::HttpRequest,
+    record: &ResponseFieldAccessRecord,
+) -> RouterResult<bool> {
+    let get_api_key_scopes = |merchant_id: String, key_id: String| async move {
+        state
+            .store
+            .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve API key")
+            .map(|api_key| {
+                api_key
+                    .and_then(|api_key| api_key.scopes)
+                    .map(|scopes| transformers::parse_api_key_scopes(&scopes))
+            })
+    };
+
+    let scopes = match auth_type {
+        auth::AuthenticationType::ApiKey {
+            merchant_id,
+            key_id,
+        } => get_api_key_scopes(merchant_id.clone(), key_id.clone()).await?,
+        auth::AuthenticationType::OAuth2 {
+            merchant_id,
+            client_id,
+        } => {
+            let token_scopes = match auth::get_oauth2_access_token(request.headers()) {
+                Some(access_token) => {
+                    auth::decode_oauth2_access_token(access_token, state)
+                        .await?
+                        .scopes
+                }
+                None => None,
+            };
+            match token_scopes {
+                Some(scopes) => Some(scopes),
+                None => get_api_key_scopes(merchant_id.clone(), client_id.clone()).await?,
+            }
+        }
+        auth::AuthenticationType::MerchantJwt { .. } => {
+            let Some(unrestricted_roles) = &record.unrestricted_roles else {
+                return Ok(false);
+            };
+            let payload =
+                auth::parse_jwt_payload::<_, auth::AuthToken>(request.headers(), state).await?;
+            return Ok(!unrestricted_roles.contains(&payload.role_id));
+        }
+        _ => return Ok(false),
+    };
+
+    Ok(scopes.map_or(false, |scopes| !is_sensitive_data_granted(&scopes)))
+}
+
+/// The sensitive fields to hide from the response of the request, `None` when the caller is
+/// allowed to see every field or the request is not for a resource carrying sensitive fields
+#[instrument(skip_all)]
+pub async fn get_response_field_restrictions(
+    state: &SessionState,
+    auth_type: &auth::AuthenticationType,
+    request: &actix_web::HttpRequest,
+) -> RouterResult<Option<ResponseFieldRestrictions>> {
+    let resource = request.path().trim_start_matches('/').split('/').next();
+    if !resource.map_or(false, |resource| SENSITIVE_RESOURCES.contains(&resource)) {
+        return Ok(None);
+    }
+    let Some(merchant_id) = auth_type.get_merchant_id() else {
+        return Ok(None);
+    };
+
+    let record = find_response_field_access(state, merchant_id).await?;
+    let restrictions = [
+        SensitiveFieldCategory::ConnectorResponse,
+        SensitiveFieldCategory::CustomerPii,
+        SensitiveFieldCategory::CardMetadata,
+    ]
+    .into_iter()
+    .map(|category| (category, record.get_access(category)))
+    .filter(|(_, access)| *access != SensitiveFieldAccess::Visible)
+    .collect::<Vec<_>>();
+    if restrictions.is_empty() || !is_caller_restricted(state, auth_type, request, &record).await? {
+        return Ok(None);
+    }
+
+    Ok(Some(ResponseFieldRestrictions { restrictions }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_sensitive_fields_are_masked_and_omitted() {
+        let restrictions = ResponseFieldRestrictions {
+            restrictions: vec![
+                (
+                    SensitiveFieldCategory::CustomerPii,
+                    SensitiveFieldAccess::Masked,
+                ),
+                (
+                    SensitiveFieldCategory::ConnectorResponse,
+                    SensitiveFieldAccess::Omitted,
+                ),
+            ],
+        };
+        let mut response = serde_json::json!({
+            "payment_id": "pay_1",
+            "email": "john@example.com",
+            "customer": { "id": "cus_1", "name": "John Doe" },
+            "billing": { "address": { "line1": "1 Main St", "zip": "94122" }, "phone": null },
+            "connector_metadata": { "reference": "abc" },
+            "attempts": [{ "attempt_id": "att_1", "error_reason": "Do not honor" }]
+        });
+        restrictions.apply(&mut response);
+
+        assert_eq!(
+            response,
+            serde_json::json!({
+                "payment_id": "pay_1",
+                "email": MASKED_VALUE,
+                "customer": { "id": "cus_1", "name": MASKED_VALUE },
+                "billing": {
+                    "address": { "line1": MASKED_VALUE, "zip": MASKED_VALUE },
+                    "phone": null
+                },
+                "attempts": [{ "attempt_id": "att_1" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_sensitive_fields_of_listed_items_are_hidden() {
+        let restrictions = ResponseFieldRestrictions {
+            restrictions: vec![(
+                SensitiveFieldCategory::CardMetadata,
+                SensitiveFieldAccess::Omitted,
+            )],
+        };
+        let mut response = serde_json::json!({
+            "count": 1,
+            "data": [{
+                "payment_id": "pay_1",
+                "payment_method_data": { "card": { "last4": "4242", "card_isin": "424242" } }
+            }]
+        });
+        restrictions.apply(&mut response);
+
+        assert_eq!(
+            response["data"][0]["payment_method_data"],
+            serde_json::json!({ "card": { "last4": "4242" } })
+        );
+    }
+
+    #[test]
+    fn test_sensitive_data_scope_grants_access() {
+        assert!(is_sensitive_data_granted(&[
+            ApiKeyScope::PaymentsRead,
+            ApiKeyScope::SensitiveDataRead
+        ]));
+        assert!(!is_sensitive_data_granted(&[ApiKeyScope::PaymentsWrite]));
+    }
+}
@@ -4,6 +4,7 @@ pub mod transformers;
 #[cfg(feature = "business_profile_routing")]
 use api_models::routing::{RoutingRetrieveLinkQuery, RoutingRetrieveQuery};
 use api_models::{
+    audit_events::{AuditAction, AuditResourceType},
     enums,
     routing::{self as routing_types, RoutingAlgorithmId},
 };
@@ -26,6 +27,7 @@ use crate::types::transformers::{ForeignInto, ForeignTryFrom};
 use crate::{
     consts,
     core::{
+        audit_events,
         errors::{RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
@@ -94,6 +96,28 @@ pub async fn retrieve_merchant_routing_dictionary(
     ))
 }
 
+async fn record_routing_audit_event(
+    state: &SessionState,
+    merchant_id: &str,
+    record: &routing_types::RoutingDictionaryRecord,
+    action: AuditAction,
+) {
+    let (before, after) = match action {
+        AuditAction::Create | AuditAction::Activate => (None, Some(record)),
+        AuditAction::Update | AuditAction::Delete | AuditAction::Deactivate => (Some(record), None),
+    };
+    audit_events::record_audit_event(
+        state,
+        merchant_id,
+        AuditResourceType::RoutingAlgorithm,
+        &record.id,
+        action,
+        before,
+        after,
+    )
+    .await;
+}
+
 pub async fn create_routing_config(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
@@ -176,6 +200,13 @@ pub async fn create_routing_config(
             .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
         let new_record = record.foreign_into();
+        record_routing_audit_event(
+            &state,
+            &merchant_account.merchant_id,
+            &new_record,
+            AuditAction::Create,
+        )
+        .await;
 
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
@@ -247,6 +278,13 @@ pub async fn create_routing_config(
             merchant_dictionary,
         )
         .await?;
+        record_routing_audit_event(
+            &state,
+            &merchant_account.merchant_id,
+            &new_record,
+            AuditAction::Create,
+        )
+        .await;
 
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
@@ -321,10 +359,17 @@ pub async fn link_routing_config(
         )
         .await?;
 
+        let response = routing_algorithm.foreign_into();
+        record_routing_audit_event(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            AuditAction::Activate,
+        )
+        .await;
+
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-        Ok(service_api::ApplicationResponse::Json(
-            routing_algorithm.foreign_into(),
-        ))
+        Ok(service_api::ApplicationResponse::Json(response))
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
@@ -372,6 +417,13 @@ pub async fn link_routing_config(
         let key =
             cache::CacheKind::Routing(format!("dsl_{}", &merchant_account.merchant_id).into());
         helpers::update_merchant_active_algorithm_ref(db, &key_store, key, routing_ref).await?;
+        record_routing_audit_event(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            AuditAction::Activate,
+        )
+        .await;
 
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
@@ -520,6 +572,13 @@ pub async fn unlink_routing_config(
                             transaction_type,
                         )
                         .await?;
+                        record_routing_audit_event(
+                            &state,
+                            &merchant_account.merchant_id,
+                            &response,
+                            AuditAction::Deactivate,
+                        )
+                        .await;
 
                         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
                             &metrics::CONTEXT,
@@ -632,6 +691,13 @@ pub async fn unlink_routing_config(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update routing algorithm ref in merchant account")?;
+        record_routing_audit_event(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            AuditAction::Deactivate,
+        )
+        .await;
 
         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
@@ -286,6 +286,14 @@ pub async fn update_business_profile_active_algorithm_ref(
         use_billing_as_payment_method_billing: None,
         collect_shipping_details_from_wallet_connector: None,
         is_connector_agnostic_mit_enabled: None,
+        frm_decision_matrix: None,
+        frm_post_auth_review: None,
+        connector_debug_capture: None,
+        connector_failover_policy: None,
+        vault_provider: None,
+        tax_provider: None,
+        dispute_auto_response: None,
+        custom_fields_schema: None,
     };
 
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
@@ -0,0 +1,422 @@
+use std::{collections::HashMap, hash::Hash, str::FromStr};
+
+use api_models::{
+    enums as api_enums, payments as payment_types,
+    sandbox_traffic::{
+        SandboxPayment, SandboxPaymentMethod, SandboxPaymentOutcome, SandboxTrafficRequest,
+        SandboxTrafficResponse,
+    },
+};
+use common_utils::{date_time, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+use router_env::{env, instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments, utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{api, domain},
+};
+
+/// The largest number of synthetic payments made in a single request
+const MAX_SANDBOX_PAYMENT_COUNT: u16 = 100;
+
+const DEFAULT_MIN_AMOUNT: i64 = 100;
+const DEFAULT_MAX_AMOUNT: i64 = 10000;
+
+/// The amounts the test connectors simulate failures for, which are kept clear of the amounts
+/// of the payments expected to have any other outcome
+const SIMULATED_FAILURE_AMOUNTS: [i64; 7] = [4001, 4002, 4003, 4290, 5000, 5030, 5040];
+
+/// The card the test connectors require the 3DS authentication of
+const THREE_DS_CARD_NUMBER: &str = "4000003800000446";
+
+/// A synthetic payment, drawn before any of the payments is made
+struct SandboxPaymentPlan {
+    payment_method: SandboxPaymentMethod,
+    currency: api_enums::Currency,
+    outcome: SandboxPaymentOutcome,
+    amount: MinorUnit,
+}
+
+/// The test connector of the merchant connector account, if it is one
+fn get_test_connector(connector_name: &str) -> Option<api_enums::Connector> {
+    api_enums::Connector::from_str(connector_name)
+        .ok()
+        .filter(|connector| {
+            matches!(
+                connector,
+                api_enums::Connector::DummyConnector1
+                    | api_enums::Connector::DummyConnector2
+                    | api_enums::Connector::DummyConnector3
+                    | api_enums::Connector::DummyConnector4
+                    | api_enums::Connector::DummyConnector5
+                    | api_enums::Connector::DummyConnector6
+                    | api_enums::Connector::DummyConnector7
+            )
+        })
+}
+
+/// The choices along with the distribution of their weights, the default choice being the only
+/// choice when no weights are provided
+fn get_weighted_choices<T: Copy + Eq + Hash>(
+    field_name: &str,
+    weights: Option<HashMap<T, u32>>,
+    default: T,
+) -> RouterResult<(Vec<T>, WeightedIndex<u32>)> {
+    let (choices, weights): (Vec<T>, Vec<u32>) = weights
+        .unwrap_or_else(|| HashMap::from([(default, 1)]))
+        .into_iter()
+        .unzip();
+    let distribution = WeightedIndex::new(weights).map_err(|_| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} must have at least one choice with a non-zero weight"),
+        })
+    })?;
+    Ok((choices, distribution))
+}
+
+fn get_amount(
+    rng: &mut impl Rng,
+    outcome: SandboxPaymentOutcome,
+    min_amount: i64,
+    max_amount: i64,
+) -> MinorUnit {
+    let amount = match outcome {
+        SandboxPaymentOutcome::Declined => 4001,
+        SandboxPaymentOutcome::InsufficientFunds => 4002,
+        SandboxPaymentOutcome::ExpiredCard => 4003,
+        SandboxPaymentOutcome::ConnectorError => 5000,
+        SandboxPaymentOutcome::Succeeded | SandboxPaymentOutcome::ThreeDsRequired => {
+            let amount = rng.gen_range(min_amount..=max_amount);
+            if SIMULATED_FAILURE_AMOUNTS.contains(&amount) {
+                amount + 10
+            } else {
+                amount
+            }
+        }
+    };
+    MinorUnit::new(amount)
+}
+
+fn get_card(
+    card_number: &str,
+    card_network: api_enums::CardNetwork,
+    card_cvc: &str,
+) -> RouterResult<payment_types::PaymentMethodData> {
+    let card_number = cards::CardNumber::from_str(card_number)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid test card number")?;
+    Ok(payment_types::PaymentMethodData::Card(
+        payment_types::Card {
+            card_number,
+            card_exp_month: Secret::new("12".to_string()),
+            card_exp_year: Secret::new((date_time::now().year() + 3).to_string()),
+            card_holder_name: Some(Secret::new("Sandbox Customer".to_string())),
+            card_cvc: Secret::new(card_cvc.to_string()),
+            card_issuer: None,
+            card_network: Some(card_network),
+            card_type: None,
+            card_issuing_country: None,
+            bank_code: None,
+            nick_name: None,
+        },
+    ))
+}
+
+/// The payment method, the payment method type and the payment method data of the payment, the
+/// test card being picked for the outcome expected of the payment
+fn get_payment_method_data(
+    payment_method: SandboxPaymentMethod,
+    outcome: SandboxPaymentOutcome,
+) -> RouterResult<(
+    api_enums::PaymentMethod,
+    api_enums::PaymentMethodType,
+    payment_types::PaymentMethodData,
+)> {
+    let (card_number, card_network, card_cvc) = match payment_method {
+        SandboxPaymentMethod::GooglePay => {
+            return Ok((
+                api_enums::PaymentMethod::Wallet,
+                api_enums::PaymentMethodType::GooglePay,
+                payment_types::PaymentMethodData::Wallet(
+                    payment_types::WalletData::GooglePayRedirect(Box::new(
+                        payment_types::GooglePayRedirectData {},
+                    )),
+                ),
+            ))
+        }
+        SandboxPaymentMethod::Paypal => {
+            return Ok((
+                api_enums::PaymentMethod::Wallet,
+                api_enums::PaymentMethodType::Paypal,
+                payment_types::PaymentMethodData::Wallet(
+                    payment_types::WalletData::PaypalRedirect(payment_types::PaypalRedirection {
+                        email: None,
+                    }),
+                ),
+            ))
+        }
+        _ if outcome == SandboxPaymentOutcome::ThreeDsRequired => {
+            (THREE_DS_CARD_NUMBER, api_enums::CardNetwork::Visa, "123")
+        }
+        SandboxPaymentMethod::Visa => ("4242424242424242", api_enums::CardNetwork::Visa, "123"),
+        SandboxPaymentMethod::Mastercard => (
+            "5555555555554444",
+            api_enums::CardNetwork::Mastercard,
+            "123",
+        ),
+        SandboxPaymentMethod::AmericanExpress => (
+            "378282246310005",
+            api_enums::CardNetwork::AmericanExpress,
+            "1234",
+        ),
+        SandboxPaymentMethod::Discover => {
+            ("6011111111111117", api_enums::CardNetwork::Discover, "123")
+        }
+        SandboxPaymentMethod::DinersClub => {
+            ("38000000000006", api_enums::CardNetwork::DinersClub, "123")
+        }
+    };
+
+    Ok((
+        api_enums::PaymentMethod::Card,
+        api_enums::PaymentMethodType::Credit,
+        get_card(card_number, card_network, card_cvc)?,
+    ))
+}
+
+/// Draws the payment method, the currency, the outcome and the amount of every payment
+fn get_payment_plans(request: &SandboxTrafficRequest) -> RouterResult<Vec<SandboxPaymentPlan>> {
+    let min_amount = request
+        .min_amount
+        .map_or(DEFAULT_MIN_AMOUNT, |amount| amount.get_amount_as_i64());
+    let max_amount = request
+        .max_amount
+        .map_or(DEFAULT_MAX_AMOUNT.max(min_amount), |amount| {
+            amount.get_amount_as_i64()
+        });
+    if min_amount <= 0 || min_amount > max_amount {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "min_amount must be positive and not greater than max_amount".to_string(),
+        }));
+    }
+
+    let (payment_methods, payment_method_distribution) = get_weighted_choices(
+        "payment_methods",
+        request.payment_methods.clone(),
+        SandboxPaymentMethod::Visa,
+    )?;
+    let (currencies, currency_distribution) = get_weighted_choices(
+        "currencies",
+        request.currencies.clone(),
+        api_enums::Currency::USD,
+    )?;
+    let (outcomes, outcome_distribution) = get_weighted_choices(
+        "outcomes",
+        request.outcomes.clone(),
+        SandboxPaymentOutcome::Succeeded,
+    )?;
+
+    let mut rng = rand::thread_rng();
+    Ok((0..request.payment_count)
+        .map(|_| {
+            let outcome = outcomes[outcome_distribution.sample(&mut rng)];
+            SandboxPaymentPlan {
+                payment_method: payment_methods[payment_method_distribution.sample(&mut rng)],
+                currency: currencies[currency_distribution.sample(&mut rng)],
+                outcome,
+                amount: get_amount(&mut rng, outcome, min_amount, max_amount),
+            }
+        })
+        .collect())
+}
+
+/// The merchant connector account of the test connector the payments are made with, which must
+/// belong to the business profile
+async fn get_test_merchant_connector_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    merchant_connector_id: Option<&String>,
+) -> RouterResult<(domain::MerchantConnectorAccount, api_enums::Connector)> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?;
+
+    merchant_connector_accounts
+        .into_iter()
+        .filter(|mca| mca.profile_id.as_deref() == Some(profile_id))
+        .filter(|mca| {
+            merchant_connector_id.map_or(true, |merchant_connector_id| {
+                mca.merchant_connector_id == *merchant_connector_id
+            })
+        })
+        .find_map(|mca| get_test_connector(&mca.connector_name).map(|connector| (mca, connector)))
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "No enabled test connector is configured for the business profile {profile_id}"
+                ),
+            })
+        })
+}
+
+/// Makes synthetic payments with the test connector of the merchant, so that the webhooks, the
+/// analytics and the reconciliation of the merchant can be validated end to end. The payments go
+/// through the same flows as the payments made by the merchant, and are marked as sandbox
+/// traffic in their metadata.
+#[instrument(skip_all)]
+pub async fn generate_sandbox_traffic(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: SandboxTrafficRequest,
+) -> RouterResponse<SandboxTrafficResponse> {
+    if matches!(env::which(), env::Env::Production) {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Sandbox traffic in the production environment".to_string(),
+        }));
+    }
+    if request.payment_count == 0 || request.payment_count > MAX_SANDBOX_PAYMENT_COUNT {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("payment_count must be between 1 and {MAX_SANDBOX_PAYMENT_COUNT}"),
+        }));
+    }
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        None,
+        None,
+        &merchant_account,
+        request.profile_id.as_ref(),
+        state.store.as_ref(),
+        true,
+    )
+    .await?;
+    let (merchant_connector_account, connector) = get_test_merchant_connector_account(
+        &state,
+        &merchant_account,
+        &key_store,
+        &profile_id,
+        request.merchant_connector_id.as_ref(),
+    )
+    .await?;
+    let payment_plans = get_payment_plans(&request)?;
+
+    let mut status_counts = HashMap::new();
+    let mut error_count = 0;
+    let mut payments = Vec::with_capacity(payment_plans.len());
+    for plan in payment_plans {
+        let (payment_method, payment_method_type, payment_method_data) =
+            get_payment_method_data(plan.payment_method, plan.outcome)?;
+        let payment_experience = (payment_method == api_enums::PaymentMethod::Wallet)
+            .then_some(api_enums::PaymentExperience::RedirectToUrl);
+        let authentication_type = if plan.outcome == SandboxPaymentOutcome::ThreeDsRequired {
+            api_enums::AuthenticationType::ThreeDs
+        } else {
+            api_enums::AuthenticationType::NoThreeDs
+        };
+
+        let payment_id = common_utils::generate_id(consts::ID_LENGTH, "pay");
+        let payment_request = payment_types::PaymentsRequest {
+            payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+                payment_id.clone(),
+            )),
+            amount: Some(plan.amount.into()),
+            currency: Some(plan.currency),
+            confirm: Some(true),
+            capture_method: request.capture_method,
+            authentication_type: Some(authentication_type),
+            payment_method: Some(payment_method),
+            payment_method_type: Some(payment_method_type),
+            payment_experience,
+            payment_method_data: Some(payment_types::PaymentMethodDataRequest {
+                payment_method_data: Some(payment_method_data),
+                billing: None,
+            }),
+            return_url: request.return_url.clone(),
+            description: Some("Sandbox traffic".to_string()),
+            metadata: Some(Secret::new(serde_json::json!({
+                "sandbox_traffic": true,
+                "expected_outcome": plan.outcome,
+            }))),
+            profile_id: Some(profile_id.clone()),
+            ..Default::default()
+        };
+
+        let response = Box::pin(payments::payments_core::<
+            api::Authorize,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+        >(
+            state.clone(),
+            req_state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            payments::PaymentCreate,
+            payment_request,
+            services::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            Some(vec![connector]),
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        let (status, error_message) = match response {
+            Ok(ApplicationResponse::Json(response))
+            | Ok(ApplicationResponse::JsonWithHeaders((response, _))) => {
+                *status_counts.entry(response.status).or_insert(0) += 1;
+                (Some(response.status), None)
+            }
+            Ok(_) => {
+                error_count += 1;
+                (
+                    None,
+                    Some("Unexpected response while making the payment".to_string()),
+                )
+            }
+            Err(error) => {
+                logger::warn!(?error, "Failed to make the sandbox payment");
+                error_count += 1;
+                (None, Some(error.current_context().error_message()))
+            }
+        };
+        payments.push(SandboxPayment {
+            payment_id: status.is_some().then_some(payment_id),
+            payment_method: plan.payment_method,
+            currency: plan.currency,
+            amount: plan.amount,
+            expected_outcome: plan.outcome,
+            status,
+            error_message,
+        });
+    }
+
+    Ok(ApplicationResponse::Json(SandboxTrafficResponse {
+        merchant_connector_id: merchant_connector_account.merchant_connector_id,
+        connector_name: merchant_connector_account.connector_name,
+        status_counts,
+        error_count,
+        payments,
+    }))
+}
@@ -0,0 +1,576 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::{
+    analytics::search::{SearchIndex, SearchStatus},
+    search::{SearchField, SearchHit, SearchObject, SearchRequest, SearchResponse},
+};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    configs::settings::SearchBackend,
+    core::{
+        blind_index,
+        errors::{self, RouterResponse, RouterResult},
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Number of results returned when the limit is not provided
+const DEFAULT_SEARCH_LIMIT: u32 = 20;
+
+/// Maximum number of results returned, which is also the maximum number of objects fetched for
+/// each criterion
+const MAX_SEARCH_LIMIT: u32 = 100;
+
+/// The objects matching the search, each object being scored once per criterion it matched
+#[derive(Default)]
+struct SearchHits(HashMap<(SearchObject, String), SearchHit>);
+
+impl SearchHits {
+    fn add(&mut self, hit: SearchHit, field: SearchField) {
+        let hit = self.0.entry((hit.object, hit.id.clone())).or_insert(hit);
+        if !hit.matched_on.contains(&field) {
+            hit.matched_on.push(field);
+            hit.score += field.weight();
+        }
+    }
+
+    /// The most relevant objects, the most recent objects first among the equally relevant ones
+    fn into_ranked(self, limit: usize) -> Vec<SearchHit> {
+        let mut hits = self.0.into_values().collect::<Vec<_>>();
+        hits.sort_by(|first, second| {
+            second
+                .score
+                .cmp(&first.score)
+                .then_with(|| second.created_at.cmp(&first.created_at))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+fn new_hit(object: SearchObject, id: String, created_at: PrimitiveDateTime) -> SearchHit {
+    SearchHit {
+        object,
+        id,
+        payment_id: None,
+        customer_id: None,
+        status: None,
+        amount: None,
+        currency: None,
+        created_at,
+        matched_on: Vec::new(),
+        score: 0,
+    }
+}
+
+fn payment_hit(payment_intent: &diesel_models::PaymentIntent) -> SearchHit {
+    SearchHit {
+        payment_id: Some(payment_intent.payment_id.clone()),
+        customer_id: payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned()),
+        status: Some(payment_intent.status.to_string()),
+        amount: Some(payment_intent.amount.get_amount_as_i64()),
+        currency: payment_intent.currency,
+        ..new_hit(
+            SearchObject::Payment,
+            payment_intent.payment_id.clone(),
+            payment_intent.created_at,
+        )
+    }
+}
+
+fn refund_hit(refund: &storage::Refund) -> SearchHit {
+    SearchHit {
+        payment_id: Some(refund.payment_id.clone()),
+        status: Some(refund.refund_status.to_string()),
+        amount: Some(refund.refund_amount.get_amount_as_i64()),
+        currency: Some(refund.currency),
+        ..new_hit(
+            SearchObject::Refund,
+            refund.refund_id.clone(),
+            refund.created_at,
+        )
+    }
+}
+
+fn dispute_hit(dispute: &storage::Dispute) -> SearchHit {
+    SearchHit {
+        payment_id: Some(dispute.payment_id.clone()),
+        status: Some(dispute.dispute_status.to_string()),
+        amount: Some(dispute.dispute_amount),
+        currency: dispute.currency.parse().ok(),
+        ..new_hit(
+            SearchObject::Dispute,
+            dispute.dispute_id.clone(),
+            dispute.created_at,
+        )
+    }
+}
+
+fn customer_hit(customer: &domain::Customer) -> SearchHit {
+    let customer_id = customer.customer_id.get_string_repr().to_owned();
+    SearchHit {
+        customer_id: Some(customer_id.clone()),
+        ..new_hit(SearchObject::Customer, customer_id, customer.created_at)
+    }
+}
+
+/// Builds a result from a document of the analytics indexes, whose timestamps are in seconds
+fn document_hit(index: SearchIndex, document: &serde_json::Value) -> Option<SearchHit> {
+    let text = |key: &str| {
+        document
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(ToOwned::to_owned)
+    };
+    let (object, id_key, status_key, amount_key) = match index {
+        SearchIndex::PaymentIntents | SearchIndex::PaymentAttempts => {
+            (SearchObject::Payment, "payment_id", "status", "amount")
+        }
+        SearchIndex::Refunds => (
+            SearchObject::Refund,
+            "refund_id",
+            "refund_status",
+            "refund_amount",
+        ),
+        SearchIndex::Disputes => (
+            SearchObject::Dispute,
+            "dispute_id",
+            "dispute_status",
+            "dispute_amount",
+        ),
+    };
+    let created_at = document
+        .get("created_at")
+        .and_then(serde_json::Value::as_i64)
+        .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        .map(|created_at| PrimitiveDateTime::new(created_at.date(), created_at.time()))?;
+
+    Some(SearchHit {
+        payment_id: text("payment_id"),
+        customer_id: text("customer_id"),
+        status: text(status_key),
+        amount: document.get(amount_key).and_then(serde_json::Value::as_i64),
+        currency: document
+            .get("currency")
+            .and_then(|currency| serde_json::from_value(currency.clone()).ok()),
+        ..new_hit(object, text(id_key)?, created_at)
+    })
+}
+
+fn validate_search_request(req: &SearchRequest) -> RouterResult<()> {
+    let has_criteria = req.query.is_some()
+        || req.email.is_some()
+        || req.phone.is_some()
+        || req.last4.is_some()
+        || req.connector_transaction_id.is_some()
+        || req.reference.is_some()
+        || req.metadata.is_some();
+    if !has_criteria {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "at least one search criterion must be provided".to_string(),
+        })?
+    }
+
+    if let Some(last4) = &req.last4 {
+        if last4.len() != 4 || !last4.chars().all(|digit| digit.is_ascii_digit()) {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "last4",
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn search(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: SearchRequest,
+) -> RouterResponse<SearchResponse> {
+    validate_search_request(&req)?;
+
+    let limit = req
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let objects = req
+        .objects
+        .clone()
+        .filter(|objects| !objects.is_empty())
+        .map(|objects| objects.into_iter().collect::<HashSet<_>>())
+        .unwrap_or_else(|| SearchObject::iter().collect());
+    let merchant_id = merchant_account.merchant_id.as_str();
+
+    let mut hits = SearchHits::default();
+
+    let customer_matches = search_customers(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req,
+        &objects,
+        &mut hits,
+    )
+    .await?;
+
+    match state.conf.search.backend {
+        SearchBackend::Database => {
+            search_database(&state, merchant_id, &req, &objects, limit, &mut hits).await?
+        }
+        SearchBackend::OpenSearch => {
+            search_opensearch(&state, merchant_id, &req, &objects, &mut hits).await?
+        }
+    }
+
+    // Neither the customers nor the metadata of the payments are indexed, these payments are
+    // always searched in the database
+    if objects.contains(&SearchObject::Payment) {
+        let db = &*state.store;
+        let mut criteria = Vec::new();
+        for (field, customer_ids) in customer_matches.iter() {
+            if !customer_ids.is_empty() {
+                criteria.push((*field, storage::SearchFilter::CustomerIds(customer_ids)));
+            }
+        }
+        if let Some(metadata) = &req.metadata {
+            criteria.push((
+                SearchField::Metadata,
+                storage::SearchFilter::Metadata(metadata),
+            ));
+        }
+        for (field, filter) in criteria {
+            db.search_payment_intents(merchant_id, filter, i64::from(limit))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to search the payments")?
+                .iter()
+                .for_each(|payment_intent| hits.add(payment_hit(payment_intent), field));
+        }
+    }
+
+    let hits = hits.into_ranked(usize::try_from(limit).unwrap_or(usize::MAX));
+
+    Ok(ApplicationResponse::Json(SearchResponse {
+        count: hits.len(),
+        hits,
+    }))
+}
+
+/// Searches the customers by email, phone number and identifier, returning the identifiers of the
+/// customers with the email or phone number so that their payments can be searched
+async fn search_customers(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &SearchRequest,
+    objects: &HashSet<SearchObject>,
+    hits: &mut SearchHits,
+) -> RouterResult<Vec<(SearchField, Vec<String>)>> {
+    let db = &*state.store;
+    let include_customers = objects.contains(&SearchObject::Customer);
+    let mut customer_matches = Vec::new();
+
+    if include_customers || objects.contains(&SearchObject::Payment) {
+        let key = db.get_master_key();
+        let merchant_id = merchant_account.merchant_id.as_str();
+        let searched = blind_index::get_customer_blind_indexes(
+            key,
+            merchant_id,
+            req.email.as_ref().map(|email| email.peek().as_str()),
+            req.phone.as_ref().map(|phone| phone.peek().as_str()),
+            req.phone_country_code.as_deref(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the blind indexes of the search")?;
+
+        if searched.email.is_some() || searched.phone.is_some() {
+            // The emails and phone numbers are encrypted, so the customers are looked up by their
+            // blind indexes, along with the customers never indexed which are matched once
+            // decrypted
+            let customers = db
+                .list_customers_by_blind_indexes(
+                    merchant_id,
+                    searched.email.clone(),
+                    searched.phone.clone(),
+                    key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to search the customers")?;
+
+            let mut email_customer_ids = Vec::new();
+            let mut phone_customer_ids = Vec::new();
+            for customer in customers.iter() {
+                let indexes = blind_index::get_customer_blind_indexes(
+                    key,
+                    merchant_id,
+                    customer
+                        .email
+                        .as_ref()
+                        .map(|email| email.get_inner().peek().as_str()),
+                    customer
+                        .phone
+                        .as_ref()
+                        .map(|phone| phone.get_inner().peek().as_str()),
+                    customer.phone_country_code.as_deref(),
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to generate the blind indexes of the customer")?;
+
+                let customer_id = customer.customer_id.get_string_repr();
+                if searched.email.is_some() && indexes.email == searched.email {
+                    email_customer_ids.push(customer_id.to_owned());
+                    if include_customers {
+                        hits.add(customer_hit(customer), SearchField::Email);
+                    }
+                }
+                if searched.phone.is_some() && indexes.phone == searched.phone {
+                    phone_customer_ids.push(customer_id.to_owned());
+                    if include_customers {
+                        hits.add(customer_hit(customer), SearchField::Phone);
+                    }
+                }
+            }
+            customer_matches.push((SearchField::Email, email_customer_ids));
+            customer_matches.push((SearchField::Phone, phone_customer_ids));
+        }
+    }
+
+    let query_customer_id = req
+        .query
+        .clone()
+        .filter(|_| include_customers)
+        .and_then(|query| id_type::CustomerId::from(query.into()).ok());
+    if let Some(customer_id) = query_customer_id {
+        let customer = db
+            .find_customer_optional_by_customer_id_merchant_id(
+                &customer_id,
+                &merchant_account.merchant_id,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to search the customers")?;
+        if let Some(customer) = customer {
+            hits.add(customer_hit(&customer), SearchField::Id);
+        }
+    }
+
+    Ok(customer_matches)
+}
+
+/// Searches the payments, refunds and disputes in the database, with a query per criterion
+async fn search_database(
+    state: &SessionState,
+    merchant_id: &str,
+    req: &SearchRequest,
+    objects: &HashSet<SearchObject>,
+    limit: u32,
+    hits: &mut SearchHits,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let limit = i64::from(limit);
+
+    let query_payment_ids = req.query.clone().into_iter().collect::<Vec<_>>();
+    let mut criteria = Vec::new();
+    if let Some(query) = req.query.as_deref() {
+        criteria.push((SearchField::Id, storage::SearchFilter::Id(query)));
+        criteria.push((
+            SearchField::PaymentId,
+            storage::SearchFilter::PaymentIds(&query_payment_ids),
+        ));
+        criteria.push((
+            SearchField::ConnectorTransactionId,
+            storage::SearchFilter::ConnectorTransactionId(query),
+        ));
+        criteria.push((
+            SearchField::Reference,
+            storage::SearchFilter::Reference(query),
+        ));
+    }
+    if let Some(connector_transaction_id) = req.connector_transaction_id.as_deref() {
+        criteria.push((
+            SearchField::ConnectorTransactionId,
+            storage::SearchFilter::ConnectorTransactionId(connector_transaction_id),
+        ));
+    }
+    if let Some(reference) = req.reference.as_deref() {
+        criteria.push((
+            SearchField::Reference,
+            storage::SearchFilter::Reference(reference),
+        ));
+    }
+    if let Some(last4) = req.last4.as_deref() {
+        criteria.push((SearchField::Last4, storage::SearchFilter::Last4(last4)));
+    }
+
+    if objects.contains(&SearchObject::Payment) {
+        // The identifiers of the payments whose attempts matched, the payments being reported
+        // rather than their attempts
+        let mut attempt_matches = Vec::new();
+        for (field, filter) in criteria.iter().copied() {
+            // The payment id of a payment is its own identifier
+            if field == SearchField::PaymentId {
+                continue;
+            }
+
+            db.search_payment_intents(merchant_id, filter, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to search the payments")?
+                .iter()
+                .for_each(|payment_intent| hits.add(payment_hit(payment_intent), field));
+
+            db.search_payment_attempts(merchant_id, filter, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to search the payment attempts")?
+                .into_iter()
+                .for_each(|payment_attempt| {
+                    attempt_matches.push((payment_attempt.payment_id, field))
+                });
+        }
+
+        if !attempt_matches.is_empty() {
+            let payment_ids = attempt_matches
+                .iter()
+                .map(|(payment_id, _)| payment_id.clone())
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+            let payment_intents = db
+                .search_payment_intents(
+                    merchant_id,
+                    storage::SearchFilter::PaymentIds(&payment_ids),
+                    i64::try_from(payment_ids.len()).unwrap_or(i64::MAX),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payments of the matching attempts")?
+                .into_iter()
+                .map(|payment_intent| (payment_intent.payment_id.clone(), payment_intent))
+                .collect::<HashMap<_, _>>();
+
+            for (payment_id, field) in attempt_matches {
+                if let Some(payment_intent) = payment_intents.get(&payment_id) {
+                    hits.add(payment_hit(payment_intent), field);
+                }
+            }
+        }
+    }
+
+    if objects.contains(&SearchObject::Refund) {
+        for (field, filter) in criteria.iter().copied() {
+            db.search_refunds(merchant_id, filter, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to search the refunds")?
+                .iter()
+                .for_each(|refund| hits.add(refund_hit(refund), field));
+        }
+    }
+
+    if objects.contains(&SearchObject::Dispute) {
+        for (field, filter) in criteria.iter().copied() {
+            db.search_disputes(merchant_id, filter, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to search the disputes")?
+                .iter()
+                .for_each(|dispute| hits.add(dispute_hit(dispute), field));
+        }
+    }
+
+    Ok(())
+}
+
+/// Searches the payments, refunds and disputes in the analytics indexes of the tenant, with a full
+/// text search per criterion
+async fn search_opensearch(
+    state: &SessionState,
+    merchant_id: &str,
+    req: &SearchRequest,
+    objects: &HashSet<SearchObject>,
+    hits: &mut SearchHits,
+) -> RouterResult<()> {
+    // The payment intents are searched before the attempts, so that the payments are reported with
+    // the details of the intent when both match
+    let indexes = [
+        (SearchObject::Payment, SearchIndex::PaymentIntents),
+        (SearchObject::Payment, SearchIndex::PaymentAttempts),
+        (SearchObject::Refund, SearchIndex::Refunds),
+        (SearchObject::Dispute, SearchIndex::Disputes),
+    ]
+    .into_iter()
+    .filter(|(object, _)| objects.contains(object))
+    .map(|(_, index)| index)
+    .collect::<Vec<_>>();
+    if indexes.is_empty() {
+        return Ok(());
+    }
+
+    // The criterion matched by the free text query depends on the field of the document it matched
+    let terms = [
+        (None, req.query.as_deref()),
+        (
+            Some(SearchField::ConnectorTransactionId),
+            req.connector_transaction_id.as_deref(),
+        ),
+        (Some(SearchField::Reference), req.reference.as_deref()),
+        (Some(SearchField::Last4), req.last4.as_deref()),
+    ];
+    let scope = vec![
+        ("merchant_id".to_string(), merchant_id.to_string()),
+        ("tenant_id".to_string(), state.tenant.schema.clone()),
+    ];
+
+    for (field, term) in terms
+        .into_iter()
+        .filter_map(|(field, term)| term.map(|term| (field, term)))
+    {
+        let responses = analytics::search::scoped_msearch_results(
+            &state.opensearch_client,
+            term.to_owned(),
+            scope.clone(),
+            indexes.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to search the analytics indexes")?;
+
+        for response in responses {
+            if let SearchStatus::Failure = response.status {
+                logger::warn!(index = ?response.index, "Skipping the failed search of an index");
+                continue;
+            }
+            for document in &response.hits {
+                if let Some(hit) = document_hit(response.index, document) {
+                    let field = field.unwrap_or(if hit.id == term {
+                        SearchField::Id
+                    } else if hit.payment_id.as_deref() == Some(term) {
+                        SearchField::PaymentId
+                    } else {
+                        SearchField::Reference
+                    });
+                    hits.add(hit, field);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,983 @@
+use std::str::FromStr;
+
+use api_models::{
+    enums as api_enums,
+    settlements::{
+        PaymentReconStatus, PaymentReconciliationResponse, SettlementBatchResponse,
+        SettlementExceptionsQuery, SettlementFileIngestRequest, SettlementLineInput,
+        SettlementLineResolveRequest, SettlementLineResponse, SettlementLinesIngestRequest,
+    },
+};
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        ledger,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Maximum number of lines accepted in a single ingestion
+const MAX_SETTLEMENT_LINES: usize = 10_000;
+
+/// Number of exceptions listed when the limit is not provided
+const DEFAULT_EXCEPTIONS_LIMIT: i64 = 100;
+
+const SETTLEMENT_FILE_COLUMNS: &[&str] = &[
+    "line_type",
+    "reference_id",
+    "payment_reference_id",
+    "gross_amount",
+    "fee_amount",
+    "net_amount",
+    "currency",
+    "settled_at",
+];
+
+/// The result of matching a settlement line to the resource it settles
+struct ReconOutcome {
+    payment_id: Option<String>,
+    refund_id: Option<String>,
+    dispute_id: Option<String>,
+    recon_status: enums::SettlementReconStatus,
+    recon_error_message: Option<String>,
+}
+
+impl ReconOutcome {
+    fn unmatched(message: String) -> Self {
+        Self {
+            payment_id: None,
+            refund_id: None,
+            dispute_id: None,
+            recon_status: enums::SettlementReconStatus::Unmatched,
+            recon_error_message: Some(message),
+        }
+    }
+
+    fn with_mismatch(
+        mut self,
+        recon_status: enums::SettlementReconStatus,
+        recon_error_message: String,
+    ) -> Self {
+        self.recon_status = recon_status;
+        self.recon_error_message = Some(recon_error_message);
+        self
+    }
+}
+
+/// The line of a settlement file with the fields needed to match it
+struct SettlementLineDetails<'a> {
+    connector: &'a str,
+    line_type: enums::SettlementLineType,
+    reference_id: &'a str,
+    payment_reference_id: Option<&'a str>,
+    gross_amount: MinorUnit,
+    currency: enums::Currency,
+}
+
+impl<'a> From<&'a storage::SettlementLine> for SettlementLineDetails<'a> {
+    fn from(line: &'a storage::SettlementLine) -> Self {
+        Self {
+            connector: &line.connector,
+            line_type: line.line_type,
+            reference_id: &line.reference_id,
+            payment_reference_id: line.payment_reference_id.as_deref(),
+            gross_amount: line.gross_amount,
+            currency: line.currency,
+        }
+    }
+}
+
+/// The net amount credited to the merchant for a line. Payments are credited their gross amount
+/// less the fees, refunds and disputes are debited their gross amount plus the fees.
+fn get_net_amount(
+    line_type: enums::SettlementLineType,
+    gross_amount: MinorUnit,
+    fee_amount: MinorUnit,
+) -> MinorUnit {
+    let gross_amount = gross_amount.get_amount_as_i64();
+    let fee_amount = fee_amount.get_amount_as_i64();
+    match line_type {
+        enums::SettlementLineType::Payment => {
+            MinorUnit::new(gross_amount.saturating_sub(fee_amount))
+        }
+        enums::SettlementLineType::Refund | enums::SettlementLineType::Dispute => {
+            MinorUnit::new(gross_amount.saturating_add(fee_amount).saturating_neg())
+        }
+    }
+}
+
+fn validate_settlement_line(index: usize, line: &SettlementLineInput) -> RouterResult<()> {
+    let invalid_line = |message: &str| {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("settlement line {}: {message}", index.saturating_add(1)),
+        })
+    };
+
+    if line.reference_id.trim().is_empty() {
+        invalid_line("reference_id must not be empty")?
+    }
+    if line.gross_amount.get_amount_as_i64() < 0
+        || line
+            .fee_amount
+            .is_some_and(|fee_amount| fee_amount.get_amount_as_i64() < 0)
+        || line
+            .net_amount
+            .is_some_and(|net_amount| net_amount.get_amount_as_i64() < 0)
+    {
+        invalid_line("amounts must not be negative")?
+    }
+    if line.line_type == enums::SettlementLineType::Dispute && line.payment_reference_id.is_none() {
+        invalid_line("payment_reference_id is required for dispute lines")?
+    }
+
+    Ok(())
+}
+
+async fn validate_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector: &str,
+    merchant_connector_id: Option<&String>,
+) -> RouterResult<()> {
+    api_enums::Connector::from_str(connector).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("invalid connector `{connector}`"),
+        },
+    )?;
+
+    if let Some(merchant_connector_id) = merchant_connector_id {
+        let merchant_connector_account = state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &merchant_account.merchant_id,
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.to_owned(),
+            })?;
+        if merchant_connector_account.connector_name != connector {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "merchant connector account `{merchant_connector_id}` is not a {connector} account"
+                ),
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+async fn match_payment_line(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    line: &SettlementLineDetails<'_>,
+) -> RouterResult<ReconOutcome> {
+    let db = state.store.as_ref();
+    let payment_attempt = match db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            line.reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_attempt) => payment_attempt,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(ReconOutcome::unmatched(format!(
+                "No payment found for the connector transaction id `{}`",
+                line.reference_id
+            )));
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment attempt of the settlement line")?,
+    };
+    if payment_attempt.connector.as_deref() != Some(line.connector) {
+        return Ok(ReconOutcome::unmatched(format!(
+            "Payment `{}` was not processed by {}",
+            payment_attempt.payment_id, line.connector
+        )));
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_attempt.payment_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment intent of the settlement line")?;
+
+    let outcome = ReconOutcome {
+        payment_id: Some(payment_attempt.payment_id.clone()),
+        refund_id: None,
+        dispute_id: None,
+        recon_status: enums::SettlementReconStatus::Matched,
+        recon_error_message: None,
+    };
+    if payment_attempt.currency != Some(line.currency) {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::CurrencyMismatch,
+            format!(
+                "Payment currency is {}, settled currency is {}",
+                payment_attempt
+                    .currency
+                    .map(|currency| currency.to_string())
+                    .unwrap_or_default(),
+                line.currency
+            ),
+        ));
+    }
+    if !matches!(
+        payment_intent.status,
+        enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::PartiallyCapturedAndCapturable
+    ) {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::StatusMismatch,
+            format!("Payment is {} but was settled", payment_intent.status),
+        ));
+    }
+    let captured_amount = payment_intent
+        .amount_captured
+        .unwrap_or(payment_attempt.net_amount);
+    if captured_amount != line.gross_amount {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::AmountMismatch,
+            format!(
+                "Captured amount is {captured_amount}, settled amount is {}",
+                line.gross_amount
+            ),
+        ));
+    }
+
+    Ok(outcome)
+}
+
+async fn match_refund_line(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    line: &SettlementLineDetails<'_>,
+) -> RouterResult<ReconOutcome> {
+    let refund = match state
+        .store
+        .find_refund_by_merchant_id_connector_refund_id_connector(
+            &merchant_account.merchant_id,
+            line.reference_id,
+            line.connector,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => refund,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(ReconOutcome::unmatched(format!(
+                "No refund found for the connector refund id `{}`",
+                line.reference_id
+            )));
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refund of the settlement line")?,
+    };
+
+    let outcome = ReconOutcome {
+        payment_id: Some(refund.payment_id.clone()),
+        refund_id: Some(refund.refund_id.clone()),
+        dispute_id: None,
+        recon_status: enums::SettlementReconStatus::Matched,
+        recon_error_message: None,
+    };
+    if refund.currency != line.currency {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::CurrencyMismatch,
+            format!(
+                "Refund currency is {}, settled currency is {}",
+                refund.currency, line.currency
+            ),
+        ));
+    }
+    if refund.refund_status != enums::RefundStatus::Success {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::StatusMismatch,
+            format!("Refund is {} but was settled", refund.refund_status),
+        ));
+    }
+    if refund.refund_amount != line.gross_amount {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::AmountMismatch,
+            format!(
+                "Refund amount is {}, settled amount is {}",
+                refund.refund_amount, line.gross_amount
+            ),
+        ));
+    }
+
+    Ok(outcome)
+}
+
+async fn match_dispute_line(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    line: &SettlementLineDetails<'_>,
+) -> RouterResult<ReconOutcome> {
+    let db = state.store.as_ref();
+    let payment_reference_id = line.payment_reference_id.unwrap_or_default();
+    let payment_attempt = match db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            payment_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_attempt) => payment_attempt,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(ReconOutcome::unmatched(format!(
+                "No payment found for the connector transaction id `{payment_reference_id}`"
+            )));
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the disputed payment of the settlement line")?,
+    };
+
+    let dispute = db
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+            line.reference_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the dispute of the settlement line")?;
+    let Some(dispute) = dispute else {
+        return Ok(ReconOutcome::unmatched(format!(
+            "No dispute found for the connector dispute id `{}` of payment `{}`",
+            line.reference_id, payment_attempt.payment_id
+        )));
+    };
+
+    let outcome = ReconOutcome {
+        payment_id: Some(dispute.payment_id.clone()),
+        refund_id: None,
+        dispute_id: Some(dispute.dispute_id.clone()),
+        recon_status: enums::SettlementReconStatus::Matched,
+        recon_error_message: None,
+    };
+    if !dispute
+        .currency
+        .eq_ignore_ascii_case(&line.currency.to_string())
+    {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::CurrencyMismatch,
+            format!(
+                "Dispute currency is {}, settled currency is {}",
+                dispute.currency, line.currency
+            ),
+        ));
+    }
+    if dispute.amount.parse::<i64>().ok() != Some(line.gross_amount.get_amount_as_i64()) {
+        return Ok(outcome.with_mismatch(
+            enums::SettlementReconStatus::AmountMismatch,
+            format!(
+                "Dispute amount is {}, settled amount is {}",
+                dispute.amount, line.gross_amount
+            ),
+        ));
+    }
+
+    Ok(outcome)
+}
+
+async fn match_settlement_line(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    line: &SettlementLineDetails<'_>,
+) -> RouterResult<ReconOutcome> {
+    match line.line_type {
+        enums::SettlementLineType::Payment => {
+            match_payment_line(state, merchant_account, key_store, line).await
+        }
+        enums::SettlementLineType::Refund => match_refund_line(state, merchant_account, line).await,
+        enums::SettlementLineType::Dispute => {
+            match_dispute_line(state, merchant_account, line).await
+        }
+    }
+}
+
+async fn reconcile_settlement_line(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    line: SettlementLineDetails<'_>,
+    fee_amount: MinorUnit,
+    reported_net_amount: Option<MinorUnit>,
+) -> RouterResult<ReconOutcome> {
+    let previous_lines = state
+        .store
+        .find_settlement_lines_by_merchant_id_connector_reference_id(
+            &merchant_account.merchant_id,
+            line.connector,
+            line.line_type,
+            line.reference_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the previous settlement lines of the reference")?;
+    if let Some(previous_line) = previous_lines.first() {
+        return Ok(ReconOutcome {
+            payment_id: previous_line.payment_id.clone(),
+            refund_id: previous_line.refund_id.clone(),
+            dispute_id: previous_line.dispute_id.clone(),
+            recon_status: enums::SettlementReconStatus::Duplicate,
+            recon_error_message: Some(format!(
+                "Reference was already settled by line `{}`",
+                previous_line.line_id
+            )),
+        });
+    }
+
+    let outcome = match_settlement_line(state, merchant_account, key_store, &line).await?;
+    let expected_net_amount =
+        get_net_amount(line.line_type, line.gross_amount, fee_amount).get_amount_as_i64();
+    match reported_net_amount {
+        Some(reported_net_amount)
+            if outcome.recon_status == enums::SettlementReconStatus::Matched
+                && reported_net_amount.get_amount_as_i64() != expected_net_amount.abs() =>
+        {
+            Ok(outcome.with_mismatch(
+                enums::SettlementReconStatus::FeeMismatch,
+                format!(
+                    "Net amount is {reported_net_amount}, expected {} from the gross amount and the fees",
+                    expected_net_amount.abs()
+                ),
+            ))
+        }
+        _ => Ok(outcome),
+    }
+}
+
+fn get_settlement_line_response(line: storage::SettlementLine) -> SettlementLineResponse {
+    SettlementLineResponse {
+        line_id: line.line_id,
+        batch_id: line.batch_id,
+        connector: line.connector,
+        merchant_connector_id: line.merchant_connector_id,
+        line_type: line.line_type,
+        reference_id: line.reference_id,
+        payment_reference_id: line.payment_reference_id,
+        gross_amount: line.gross_amount,
+        fee_amount: line.fee_amount,
+        net_amount: line.net_amount,
+        currency: line.currency,
+        settled_at: line.settled_at,
+        payment_id: line.payment_id,
+        refund_id: line.refund_id,
+        dispute_id: line.dispute_id,
+        recon_status: line.recon_status,
+        recon_error_message: line.recon_error_message,
+        resolution_note: line.resolution_note,
+        created_at: line.created_at,
+    }
+}
+
+fn get_settlement_batch_response(
+    batch_id: String,
+    lines: Vec<storage::SettlementLine>,
+) -> SettlementBatchResponse {
+    let exception_lines = lines
+        .iter()
+        .filter(|line| line.recon_status.is_exception())
+        .count();
+    SettlementBatchResponse {
+        batch_id,
+        total_lines: lines.len(),
+        matched_lines: lines.len().saturating_sub(exception_lines),
+        exception_lines,
+        lines: lines
+            .into_iter()
+            .map(get_settlement_line_response)
+            .collect(),
+    }
+}
+
+/// Stores the lines of a settlement of a connector and matches each of them to the payment,
+/// refund or dispute it settles
+#[instrument(skip_all)]
+pub async fn ingest_settlement_lines(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: SettlementLinesIngestRequest,
+) -> RouterResponse<SettlementBatchResponse> {
+    if request.lines.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "lines must not be empty".to_string(),
+        })?
+    }
+    if request.lines.len() > MAX_SETTLEMENT_LINES {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("at most {MAX_SETTLEMENT_LINES} lines can be ingested at a time"),
+        })?
+    }
+    validate_connector(
+        &state,
+        &merchant_account,
+        &key_store,
+        &request.connector,
+        request.merchant_connector_id.as_ref(),
+    )
+    .await?;
+    request
+        .lines
+        .iter()
+        .enumerate()
+        .try_for_each(|(index, line)| validate_settlement_line(index, line))?;
+
+    let batch_id = common_utils::generate_id(consts::ID_LENGTH, "stlb");
+    let mut lines = Vec::with_capacity(request.lines.len());
+    for line in request.lines {
+        let fee_amount = line.fee_amount.unwrap_or(MinorUnit::new(0));
+        let outcome = reconcile_settlement_line(
+            &state,
+            &merchant_account,
+            &key_store,
+            SettlementLineDetails {
+                connector: &request.connector,
+                line_type: line.line_type,
+                reference_id: &line.reference_id,
+                payment_reference_id: line.payment_reference_id.as_deref(),
+                gross_amount: line.gross_amount,
+                currency: line.currency,
+            },
+            fee_amount,
+            line.net_amount,
+        )
+        .await?;
+
+        let now = common_utils::date_time::now();
+        let settlement_line_new = storage::SettlementLineNew {
+            line_id: common_utils::generate_id(consts::ID_LENGTH, "stl"),
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: request.connector.clone(),
+            merchant_connector_id: request.merchant_connector_id.clone(),
+            line_type: line.line_type,
+            net_amount: get_net_amount(line.line_type, line.gross_amount, fee_amount),
+            reference_id: line.reference_id,
+            payment_reference_id: line.payment_reference_id,
+            gross_amount: line.gross_amount,
+            fee_amount,
+            currency: line.currency,
+            settled_at: line.settled_at,
+            payment_id: outcome.payment_id,
+            refund_id: outcome.refund_id,
+            dispute_id: outcome.dispute_id,
+            recon_status: outcome.recon_status,
+            recon_error_message: outcome.recon_error_message,
+            created_at: now,
+            modified_at: now,
+        };
+        let settlement_line = state
+            .store
+            .insert_settlement_line(settlement_line_new)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the settlement line")?;
+        ledger::record_fee_entries(&state, &settlement_line).await;
+        lines.push(settlement_line);
+    }
+
+    let response = get_settlement_batch_response(batch_id, lines);
+    logger::info!(
+        batch_id = %response.batch_id,
+        total_lines = response.total_lines,
+        exception_lines = response.exception_lines,
+        "Ingested settlement lines"
+    );
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+fn parse_csv_record(record: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = record.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(character),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn parse_timestamp(value: &str) -> Option<PrimitiveDateTime> {
+    use time::format_description::well_known::{Iso8601, Rfc3339};
+
+    time::OffsetDateTime::parse(value, &Rfc3339)
+        .map(|timestamp| {
+            let timestamp = timestamp.to_offset(time::UtcOffset::UTC);
+            PrimitiveDateTime::new(timestamp.date(), timestamp.time())
+        })
+        .or_else(|_| PrimitiveDateTime::parse(value, &Iso8601::DEFAULT))
+        .ok()
+}
+
+/// Parses a settlement file, a CSV file with a header row naming its columns among
+/// `SETTLEMENT_FILE_COLUMNS`, in any order
+fn parse_settlement_file(file: &[u8]) -> RouterResult<Vec<SettlementLineInput>> {
+    let content =
+        std::str::from_utf8(file).change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "settlement file must be UTF-8 encoded".to_string(),
+        })?;
+    let mut records = content
+        .lines()
+        .map(|record| record.trim_end_matches('\r'))
+        .enumerate()
+        .filter(|(_, record)| !record.trim().is_empty());
+
+    let header = records
+        .next()
+        .map(|(_, header)| parse_csv_record(header))
+        .ok_or(errors::ApiErrorResponse::MissingFile)
+        .attach_printable("Settlement file is empty")?;
+    let columns = header
+        .iter()
+        .map(|column| column.trim().to_lowercase())
+        .collect::<Vec<_>>();
+    if let Some(column) = columns
+        .iter()
+        .find(|column| !SETTLEMENT_FILE_COLUMNS.contains(&column.as_str()))
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "unknown column `{column}` in the settlement file, expected columns are {}",
+                SETTLEMENT_FILE_COLUMNS.join(", ")
+            ),
+        })?
+    }
+
+    records
+        .map(|(index, record)| {
+            let invalid_record = |message: String| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "settlement file line {}: {message}",
+                    index.saturating_add(1)
+                ),
+            };
+            let fields = parse_csv_record(record);
+            let get_field = |name: &str| {
+                columns
+                    .iter()
+                    .position(|column| column == name)
+                    .and_then(|position| fields.get(position))
+                    .map(|field| field.trim())
+                    .filter(|field| !field.is_empty())
+            };
+            let get_required_field = |name: &str| {
+                get_field(name).ok_or_else(|| invalid_record(format!("missing {name}")))
+            };
+            let get_amount = |name: &str| {
+                get_field(name)
+                    .map(|amount| {
+                        amount
+                            .parse::<i64>()
+                            .map(MinorUnit::new)
+                            .map_err(|_| invalid_record(format!("invalid {name} `{amount}`")))
+                    })
+                    .transpose()
+            };
+
+            let line_type = get_required_field("line_type")?;
+            let currency = get_required_field("currency")?;
+            Ok(SettlementLineInput {
+                line_type: enums::SettlementLineType::from_str(&line_type.to_lowercase())
+                    .map_err(|_| invalid_record(format!("invalid line_type `{line_type}`")))?,
+                reference_id: get_required_field("reference_id")?.to_string(),
+                payment_reference_id: get_field("payment_reference_id").map(String::from),
+                gross_amount: get_amount("gross_amount")?
+                    .ok_or_else(|| invalid_record("missing gross_amount".to_string()))?,
+                fee_amount: get_amount("fee_amount")?,
+                net_amount: get_amount("net_amount")?,
+                currency: enums::Currency::from_str(&currency.to_uppercase())
+                    .map_err(|_| invalid_record(format!("invalid currency `{currency}`")))?,
+                settled_at: get_field("settled_at")
+                    .map(|settled_at| {
+                        parse_timestamp(settled_at).ok_or_else(|| {
+                            invalid_record(format!("invalid settled_at `{settled_at}`"))
+                        })
+                    })
+                    .transpose()?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(error_stack::Report::from)
+}
+
+/// Ingests a settlement file downloaded from the dashboard of a connector
+#[instrument(skip_all)]
+pub async fn ingest_settlement_file(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: SettlementFileIngestRequest,
+) -> RouterResponse<SettlementBatchResponse> {
+    let lines = parse_settlement_file(&request.file)?;
+
+    ingest_settlement_lines(
+        state,
+        merchant_account,
+        key_store,
+        SettlementLinesIngestRequest {
+            connector: request.connector,
+            merchant_connector_id: request.merchant_connector_id,
+            lines,
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_settlement_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<SettlementBatchResponse> {
+    let lines = state
+        .store
+        .find_settlement_lines_by_merchant_id_batch_id(&merchant_account.merchant_id, &batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the settlement lines of the batch")?;
+    if lines.is_empty() {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Settlement batch not found".to_string(),
+        })?
+    }
+
+    Ok(ApplicationResponse::Json(get_settlement_batch_response(
+        batch_id, lines,
+    )))
+}
+
+/// Matches the exceptions of a batch again, for the lines whose resources were created or updated
+/// after the settlement was ingested. Duplicate and resolved lines are left untouched.
+#[instrument(skip_all)]
+pub async fn rematch_settlement_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    batch_id: String,
+) -> RouterResponse<SettlementBatchResponse> {
+    let lines = state
+        .store
+        .find_settlement_lines_by_merchant_id_batch_id(&merchant_account.merchant_id, &batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the settlement lines of the batch")?;
+    if lines.is_empty() {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Settlement batch not found".to_string(),
+        })?
+    }
+
+    let mut rematched_lines = Vec::with_capacity(lines.len());
+    for line in lines {
+        if !line.recon_status.is_exception()
+            || line.recon_status == enums::SettlementReconStatus::Duplicate
+        {
+            rematched_lines.push(line);
+            continue;
+        }
+
+        let outcome = match_settlement_line(
+            &state,
+            &merchant_account,
+            &key_store,
+            &SettlementLineDetails::from(&line),
+        )
+        .await?;
+        let line = state
+            .store
+            .update_settlement_line(
+                line,
+                storage::SettlementLineUpdate::ReconUpdate {
+                    payment_id: outcome.payment_id,
+                    refund_id: outcome.refund_id,
+                    dispute_id: outcome.dispute_id,
+                    recon_status: outcome.recon_status,
+                    recon_error_message: outcome.recon_error_message,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the settlement line")?;
+        ledger::record_fee_entries(&state, &line).await;
+        rematched_lines.push(line);
+    }
+
+    Ok(ApplicationResponse::Json(get_settlement_batch_response(
+        batch_id,
+        rematched_lines,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_settlement_exceptions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: SettlementExceptionsQuery,
+) -> RouterResponse<Vec<SettlementLineResponse>> {
+    let recon_status = match query.recon_status {
+        Some(recon_status) if recon_status.is_exception() => vec![recon_status],
+        Some(recon_status) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{recon_status} settlement lines are not exceptions"),
+        })?,
+        None => vec![
+            enums::SettlementReconStatus::AmountMismatch,
+            enums::SettlementReconStatus::CurrencyMismatch,
+            enums::SettlementReconStatus::StatusMismatch,
+            enums::SettlementReconStatus::FeeMismatch,
+            enums::SettlementReconStatus::Unmatched,
+            enums::SettlementReconStatus::Duplicate,
+        ],
+    };
+
+    let lines = state
+        .store
+        .filter_settlement_lines_by_merchant_id_recon_status(
+            &merchant_account.merchant_id,
+            recon_status,
+            query.connector,
+            Some(query.limit.unwrap_or(DEFAULT_EXCEPTIONS_LIMIT)),
+            query.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the settlement exceptions")?;
+
+    Ok(ApplicationResponse::Json(
+        lines
+            .into_iter()
+            .map(get_settlement_line_response)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn resolve_settlement_line(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    line_id: String,
+    request: SettlementLineResolveRequest,
+) -> RouterResponse<SettlementLineResponse> {
+    let line = state
+        .store
+        .find_settlement_line_by_merchant_id_line_id(&merchant_account.merchant_id, &line_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Settlement line not found".to_string(),
+        })?;
+    if !line.recon_status.is_exception() {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Settlement line is already {}", line.recon_status),
+        })?
+    }
+
+    let line = state
+        .store
+        .update_settlement_line(
+            line,
+            storage::SettlementLineUpdate::ResolutionUpdate {
+                resolution_note: request.resolution_note,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to resolve the settlement line")?;
+
+    Ok(ApplicationResponse::Json(get_settlement_line_response(
+        line,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_reconciliation(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<PaymentReconciliationResponse> {
+    let db = state.store.as_ref();
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        &merchant_account.merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let lines = db
+        .find_settlement_lines_by_merchant_id_payment_id(&merchant_account.merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the settlement lines of the payment")?;
+
+    let recon_status = if lines.is_empty() {
+        PaymentReconStatus::Pending
+    } else if lines.iter().any(|line| line.recon_status.is_exception()) {
+        PaymentReconStatus::Exception
+    } else {
+        PaymentReconStatus::Reconciled
+    };
+    // Duplicate lines are not settled twice, so they are not counted in the totals
+    let (total_fee_amount, net_settled_amount) = lines
+        .iter()
+        .filter(|line| line.recon_status != enums::SettlementReconStatus::Duplicate)
+        .fold((0_i64, 0_i64), |(fee_amount, net_amount), line| {
+            (
+                fee_amount.saturating_add(line.fee_amount.get_amount_as_i64()),
+                net_amount.saturating_add(line.net_amount.get_amount_as_i64()),
+            )
+        });
+
+    Ok(ApplicationResponse::Json(PaymentReconciliationResponse {
+        payment_id,
+        recon_status,
+        total_fee_amount: MinorUnit::new(total_fee_amount),
+        net_settled_amount: MinorUnit::new(net_settled_amount),
+        lines: lines
+            .into_iter()
+            .map(get_settlement_line_response)
+            .collect(),
+    }))
+}
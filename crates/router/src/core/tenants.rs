@@ -0,0 +1,558 @@
+use std::str::FromStr;
+
+use api_models::tenant::{
+    TenantCreateRequest, TenantIsolationCheck, TenantQuotas, TenantResponse, TenantStatus,
+    TenantUpdateRequest,
+};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use storage_impl::config::TenantConfig;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{
+        app::{ProvisionedTenant, TenantState},
+        AppState, SessionState,
+    },
+    services::ApplicationResponse,
+    types::storage,
+    utils,
+};
+
+/// The maximum length of the identifiers of postgres
+const MAX_IDENTIFIER_LENGTH: usize = 63;
+
+/// Duration in seconds of the window in which the requests of a tenant are counted
+const REQUESTS_WINDOW_IN_SECS: i64 = 60;
+
+/// Duration in seconds for which the redis probe of the isolation check is retained
+const ISOLATION_PROBE_EXPIRY_IN_SECS: i64 = 60;
+
+/// The names of the schemas, redis key prefixes and clickhouse databases are interpolated in
+/// statements, and are hence restricted to lowercase letters, digits and underscores
+fn validate_identifier(field_name: &str, value: &str) -> RouterResult<()> {
+    let is_valid = value
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_lowercase())
+        && value.len() <= MAX_IDENTIFIER_LENGTH
+        && value
+            .chars()
+            .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_');
+
+    if !is_valid {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{field_name} must start with a lowercase letter and only contain lowercase letters, digits and underscores, with at most {MAX_IDENTIFIER_LENGTH} characters"
+            ),
+        })?
+    }
+    Ok(())
+}
+
+/// Checks that the resources allocated to the tenant are not used by the configured or the
+/// provisioned tenants
+fn validate_tenant_allocation(
+    app_state: &AppState,
+    tenant_id: &str,
+    schema_name: &str,
+    redis_key_prefix: &str,
+) -> RouterResult<()> {
+    let multitenancy = &app_state.conf.multitenancy;
+    let provisioned_tenants = app_state.get_provisioned_tenants();
+    let allocated_tenants = multitenancy
+        .get_tenants()
+        .iter()
+        .map(|(tenant_id, tenant)| (tenant_id.as_str(), tenant))
+        .chain(provisioned_tenants.iter().map(|provisioned_tenant| {
+            (
+                provisioned_tenant.tenant.name.as_str(),
+                &provisioned_tenant.tenant,
+            )
+        }));
+
+    let global_tenant = &multitenancy.global_tenant;
+    let mut schemas_in_use = vec![global_tenant.get_schema()];
+    let mut redis_key_prefixes_in_use = vec![global_tenant.get_redis_key_prefix()];
+    for (allocated_tenant_id, tenant) in allocated_tenants {
+        if allocated_tenant_id == tenant_id {
+            Err(errors::ApiErrorResponse::GenericDuplicateError {
+                message: format!("tenant with the id {tenant_id} already exists"),
+            })?
+        }
+        schemas_in_use.push(tenant.get_schema());
+        redis_key_prefixes_in_use.push(tenant.get_redis_key_prefix());
+    }
+
+    if schemas_in_use.contains(&schema_name) {
+        Err(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!("schema {schema_name} is already used by another tenant"),
+        })?
+    }
+    if redis_key_prefixes_in_use.contains(&redis_key_prefix) {
+        Err(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!(
+                "redis key prefix {redis_key_prefix} is already used by another tenant"
+            ),
+        })?
+    }
+    Ok(())
+}
+
+/// The configuration of a provisioned tenant, as it is served by the application, and its status
+pub fn get_provisioned_tenant_config(
+    tenant: &storage::Tenant,
+) -> RouterResult<(settings::Tenant, TenantStatus)> {
+    let status = TenantStatus::from_str(&tenant.status)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the status of the tenant")?;
+    let quotas = tenant
+        .quotas
+        .clone()
+        .map(|quotas| quotas.parse_value::<TenantQuotas>("TenantQuotas"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the quotas of the tenant")?;
+
+    Ok((
+        settings::Tenant {
+            name: tenant.tenant_id.clone(),
+            base_url: tenant.base_url.clone(),
+            schema: tenant.schema_name.clone(),
+            redis_key_prefix: tenant.redis_key_prefix.clone(),
+            clickhouse_database: tenant.clickhouse_database.clone(),
+            domain_events: None,
+            quotas,
+            key_management: None,
+        },
+        status,
+    ))
+}
+
+fn get_tenant_response(
+    tenant: storage::Tenant,
+    isolation_check: Option<TenantIsolationCheck>,
+) -> RouterResult<TenantResponse> {
+    let (tenant_config, status) = get_provisioned_tenant_config(&tenant)?;
+
+    Ok(TenantResponse {
+        tenant_id: tenant.tenant_id,
+        tenant_name: tenant.tenant_name,
+        base_url: tenant.base_url,
+        schema_name: tenant.schema_name,
+        redis_key_prefix: tenant.redis_key_prefix,
+        clickhouse_database: tenant.clickhouse_database,
+        status,
+        quotas: tenant_config.quotas,
+        isolation_test_mode: tenant.isolation_test_mode,
+        isolation_check,
+        created_at: tenant.created_at,
+        modified_at: tenant.modified_at,
+    })
+}
+
+/// Inserts the default configs of the deployment in the schema of the tenant, the configs
+/// already present being left as they are
+async fn insert_default_configs(app_state: &AppState, tenant_state: &TenantState) {
+    for (key, config) in &app_state.conf.multitenancy.provisioning.default_configs {
+        let config = storage::ConfigNew {
+            key: key.clone(),
+            config: config.clone(),
+        };
+        if let Err(error) = tenant_state.store.insert_config(config).await {
+            if !error.current_context().is_db_unique_violation() {
+                logger::error!(?error, %key, "Failed to insert the default config of the tenant");
+            }
+        }
+    }
+}
+
+/// Writes a probe in the database and the redis of the tenant, and checks that it cannot be read
+/// from the stores of any other tenant
+#[instrument(skip_all)]
+async fn check_tenant_isolation(
+    app_state: &AppState,
+    tenant_id: &str,
+    tenant_state: &TenantState,
+) -> RouterResult<TenantIsolationCheck> {
+    let key = format!("tenant_isolation_probe_{tenant_id}");
+    let probe = utils::generate_id(consts::ID_LENGTH, "probe");
+
+    tenant_state
+        .store
+        .insert_config(storage::ConfigNew {
+            key: key.clone(),
+            config: probe.clone(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the database probe of the isolation check")?;
+    let redis_conn = tenant_state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection of the tenant")?;
+    redis_conn
+        .set_key_with_expiry(&key, probe.clone(), ISOLATION_PROBE_EXPIRY_IN_SECS)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the redis probe of the isolation check")?;
+
+    let other_stores = app_state
+        .stores
+        .iter()
+        .map(|(other_tenant_id, store)| (other_tenant_id.clone(), store.clone()))
+        .chain(
+            app_state
+                .get_provisioned_tenants()
+                .into_iter()
+                .map(|provisioned_tenant| {
+                    (
+                        provisioned_tenant.tenant.name,
+                        provisioned_tenant.state.store,
+                    )
+                }),
+        )
+        .filter(|(other_tenant_id, _)| other_tenant_id != tenant_id);
+
+    let mut violations = Vec::new();
+    for (other_tenant_id, store) in other_stores {
+        let is_visible_in_db = store
+            .find_config_by_key_from_db(&key)
+            .await
+            .is_ok_and(|config| config.config == probe);
+        if is_visible_in_db {
+            violations.push(format!("{other_tenant_id} database"));
+        }
+
+        let is_visible_in_redis = match store.get_redis_conn() {
+            Ok(other_redis_conn) => other_redis_conn
+                .get_key::<Option<String>>(&key)
+                .await
+                .is_ok_and(|value| value.as_deref() == Some(probe.as_str())),
+            Err(_) => false,
+        };
+        if is_visible_in_redis {
+            violations.push(format!("{other_tenant_id} redis"));
+        }
+    }
+
+    if let Err(error) = tenant_state.store.delete_config_by_key(&key).await {
+        logger::warn!(
+            ?error,
+            "Failed to delete the database probe of the isolation check"
+        );
+    }
+    if let Err(error) = redis_conn.delete_key(&key).await {
+        logger::warn!(
+            ?error,
+            "Failed to delete the redis probe of the isolation check"
+        );
+    }
+
+    Ok(TenantIsolationCheck {
+        passed: violations.is_empty(),
+        violations,
+    })
+}
+
+/// Provisions a tenant: sets up its schema from the template schema, allocates its redis key
+/// prefix, inserts the default configs in its schema and starts serving its requests, without
+/// restarting the application. A tenant in isolation test mode is only activated if the check
+/// of its isolation from the other tenants passes.
+#[instrument(skip_all)]
+pub async fn create_tenant(
+    state: SessionState,
+    app_state: AppState,
+    req: TenantCreateRequest,
+) -> RouterResponse<TenantResponse> {
+    let provisioning = &app_state.conf.multitenancy.provisioning;
+    let schema_name = req.schema_name.unwrap_or_else(|| req.tenant_id.clone());
+    let redis_key_prefix = req
+        .redis_key_prefix
+        .unwrap_or_else(|| req.tenant_id.clone());
+    let clickhouse_database = req
+        .clickhouse_database
+        .unwrap_or_else(|| provisioning.clickhouse_database.clone());
+
+    validate_identifier("tenant_id", &req.tenant_id)?;
+    validate_identifier("schema_name", &schema_name)?;
+    validate_identifier("redis_key_prefix", &redis_key_prefix)?;
+    validate_identifier("clickhouse_database", &clickhouse_database)?;
+    validate_tenant_allocation(&app_state, &req.tenant_id, &schema_name, &redis_key_prefix)?;
+
+    state
+        .global_store
+        .setup_tenant_schema(&schema_name, &provisioning.template_schema)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set up the schema of the tenant")?;
+
+    let tenant_config = settings::Tenant {
+        name: req.tenant_id.clone(),
+        base_url: req.base_url.clone(),
+        schema: schema_name.clone(),
+        redis_key_prefix: redis_key_prefix.clone(),
+        clickhouse_database: clickhouse_database.clone(),
+        domain_events: None,
+        quotas: req.quotas,
+        key_management: None,
+    };
+    let tenant_state = AppState::get_tenant_state(
+        &app_state.storage_impl,
+        &app_state.event_handler,
+        &app_state.conf,
+        &tenant_config,
+        app_state.cache_store.clone(),
+        app_state.file_storage_client.clone(),
+    )
+    .await;
+    insert_default_configs(&app_state, &tenant_state).await;
+
+    let isolation_check = if req.isolation_test_mode {
+        Some(check_tenant_isolation(&app_state, &req.tenant_id, &tenant_state).await?)
+    } else {
+        None
+    };
+    let status = match &isolation_check {
+        Some(isolation_check) if !isolation_check.passed => {
+            logger::error!(violations = ?isolation_check.violations, "Tenant failed the isolation check");
+            TenantStatus::Disabled
+        }
+        _ => TenantStatus::Active,
+    };
+
+    let now = common_utils::date_time::now();
+    let tenant = state
+        .global_store
+        .insert_tenant(storage::TenantNew {
+            tenant_id: req.tenant_id.clone(),
+            tenant_name: req.tenant_name,
+            base_url: req.base_url,
+            schema_name,
+            redis_key_prefix,
+            clickhouse_database,
+            status: status.to_string(),
+            quotas: req
+                .quotas
+                .map(|quotas| quotas.encode_to_value())
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the quotas of the tenant")?,
+            isolation_test_mode: req.isolation_test_mode,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!("tenant with the id {} already exists", req.tenant_id),
+        })?;
+
+    app_state.set_provisioned_tenant(
+        req.tenant_id,
+        ProvisionedTenant {
+            tenant: tenant_config,
+            status,
+            state: tenant_state,
+        },
+    );
+
+    Ok(ApplicationResponse::Json(get_tenant_response(
+        tenant,
+        isolation_check,
+    )?))
+}
+
+pub async fn list_tenants(state: SessionState) -> RouterResponse<Vec<TenantResponse>> {
+    let tenants = state
+        .global_store
+        .list_tenants()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the tenants")?
+        .into_iter()
+        .map(|tenant| get_tenant_response(tenant, None))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ApplicationResponse::Json(tenants))
+}
+
+pub async fn retrieve_tenant(
+    state: SessionState,
+    tenant_id: String,
+) -> RouterResponse<TenantResponse> {
+    let tenant = state
+        .global_store
+        .find_tenant_by_tenant_id(&tenant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "tenant with the given id does not exist".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_tenant_response(
+        tenant, None,
+    )?))
+}
+
+/// Updates the status or the quotas of a provisioned tenant, the change being applied to the
+/// requests served from then on. The tenants configured in the config file cannot be updated.
+#[instrument(skip_all)]
+pub async fn update_tenant(
+    state: SessionState,
+    app_state: AppState,
+    tenant_id: String,
+    req: TenantUpdateRequest,
+) -> RouterResponse<TenantResponse> {
+    let mut provisioned_tenant = app_state.get_provisioned_tenant(&tenant_id).ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "tenant with the given id does not exist".to_string(),
+        },
+    )?;
+
+    let mut tenant = None;
+    if let Some(status) = req.status {
+        tenant = Some(
+            state
+                .global_store
+                .update_tenant_by_tenant_id(
+                    &tenant_id,
+                    storage::TenantUpdate::StatusUpdate {
+                        status: status.to_string(),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the status of the tenant")?,
+        );
+        provisioned_tenant.status = status;
+    }
+    if let Some(quotas) = req.quotas {
+        tenant = Some(
+            state
+                .global_store
+                .update_tenant_by_tenant_id(
+                    &tenant_id,
+                    storage::TenantUpdate::QuotasUpdate {
+                        quotas: Some(
+                            quotas
+                                .encode_to_value()
+                                .change_context(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable("Failed to serialize the quotas of the tenant")?,
+                        ),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the quotas of the tenant")?,
+        );
+        provisioned_tenant.tenant.quotas = Some(quotas);
+    }
+
+    let tenant = match tenant {
+        Some(tenant) => tenant,
+        None => state
+            .global_store
+            .find_tenant_by_tenant_id(&tenant_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "tenant with the given id does not exist".to_string(),
+            })?,
+    };
+    app_state.set_provisioned_tenant(tenant_id, provisioned_tenant);
+
+    Ok(ApplicationResponse::Json(get_tenant_response(
+        tenant, None,
+    )?))
+}
+
+/// Enforces the quota of requests of the tenant, counted in its redis in fixed windows of a
+/// minute. Failures while updating the counter are logged and the request is allowed.
+#[instrument(skip_all)]
+pub async fn enforce_tenant_request_quota(state: &SessionState) -> RouterResult<()> {
+    let Some(requests_per_minute) = state
+        .tenant
+        .quotas
+        .and_then(|quotas| quotas.requests_per_minute)
+    else {
+        return Ok(());
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the tenant quota"
+            );
+            return Ok(());
+        }
+    };
+
+    let now = common_utils::date_time::now_unix_timestamp();
+    let window_start = now - now.rem_euclid(REQUESTS_WINDOW_IN_SECS);
+    let key = format!("tenant_requests_{window_start}");
+    let count = match redis_conn
+        .increment_key_with_expiry(&key, REQUESTS_WINDOW_IN_SECS)
+        .await
+    {
+        Ok(count) => count,
+        Err(error) => {
+            logger::error!(?error, "Failed to count the request in the tenant quota");
+            return Ok(());
+        }
+    };
+
+    if count > i64::from(requests_per_minute) {
+        logger::info!(tenant = %state.tenant.name, "Tenant exceeded its quota of requests");
+        return Err(errors::ApiErrorResponse::RateLimitExceeded {
+            retry_after_in_secs: u64::try_from(window_start + REQUESTS_WINDOW_IN_SECS - now)
+                .unwrap_or(1),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Checks that the tenant can create another merchant account within its quota
+pub async fn validate_merchant_accounts_quota(state: &SessionState) -> RouterResult<()> {
+    let Some(max_merchant_accounts) = state
+        .tenant
+        .quotas
+        .and_then(|quotas| quotas.max_merchant_accounts)
+    else {
+        return Ok(());
+    };
+
+    let merchant_accounts_count = state
+        .store
+        .get_merchant_accounts_count()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the merchant accounts of the tenant")?;
+
+    if merchant_accounts_count >= i64::from(max_merchant_accounts) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "the tenant cannot have more than {max_merchant_accounts} merchant accounts"
+            ),
+        })?
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_identifier;
+
+    #[test]
+    fn test_tenant_identifiers_are_validated() {
+        assert!(validate_identifier("schema_name", "acme_payments_2").is_ok());
+        assert!(validate_identifier("schema_name", "").is_err());
+        assert!(validate_identifier("schema_name", "2acme").is_err());
+        assert!(validate_identifier("schema_name", "acme\"; DROP SCHEMA public; --").is_err());
+        assert!(validate_identifier("schema_name", &"a".repeat(64)).is_err());
+    }
+}
@@ -669,6 +669,13 @@ async fn handle_invitation(
             .attach_printable(format!("role_id = {} is not invitable", request.role_id));
     }
 
+    utils::user_role::validate_profile_id(
+        state,
+        &user_from_token.merchant_id,
+        request.profile_id.as_deref(),
+    )
+    .await?;
+
     let invitee_email = domain::UserEmail::from_pii_email(request.email.clone())?;
     let invitee_user = state
         .global_store
@@ -731,6 +738,7 @@ async fn handle_existing_user_invitation(
             last_modified_by: user_from_token.user_id.clone(),
             created_at: now,
             last_modified: now,
+            profile_id: request.profile_id.clone(),
         })
         .await
         .map_err(|e| {
@@ -813,6 +821,7 @@ async fn handle_new_user_invitation(
             last_modified_by: user_from_token.user_id.clone(),
             created_at: now,
             last_modified: now,
+            profile_id: request.profile_id.clone(),
         })
         .await
         .map_err(|e| {
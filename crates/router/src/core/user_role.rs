@@ -65,6 +65,13 @@ pub async fn update_user_role(
             .attach_printable(format!("User role cannot be updated to {}", req.role_id));
     }
 
+    utils::user_role::validate_profile_id(
+        &state,
+        &user_from_token.merchant_id,
+        req.profile_id.as_deref(),
+    )
+    .await?;
+
     let user_to_be_updated =
         utils::user::get_user_from_db_by_email(&state, domain::UserEmail::try_from(req.email)?)
             .await
@@ -104,6 +111,7 @@ pub async fn update_user_role(
             user_role_to_be_updated.merchant_id.as_str(),
             UserRoleUpdate::UpdateRole {
                 role_id: req.role_id.clone(),
+                profile_id: req.profile_id.clone(),
                 modified_by: user_from_token.user_id,
             },
         )
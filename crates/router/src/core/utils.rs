@@ -343,6 +343,8 @@ pub async fn construct_refund_router_data<'a, F>(
         response: Ok(types::RefundsResponseData {
             connector_refund_id: refund.connector_refund_id.clone().unwrap_or_default(),
             refund_status: refund.refund_status,
+            refund_arn: refund.refund_arn.clone(),
+            estimated_arrival_at: refund.estimated_arrival_at,
         }),
         access_token: None,
         session_token: None,
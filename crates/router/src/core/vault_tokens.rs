@@ -0,0 +1,290 @@
+use api_models::{
+    audit_events::{AuditAction, AuditEventsQuery, AuditEventsResponse, AuditResourceType},
+    vault_tokens::{
+        VaultAuditQuery, VaultCleanupRequest, VaultCleanupResponse, VaultTokenExpireRequest,
+        VaultTokenExpireResponse,
+    },
+};
+use common_utils::ext_traits::StringExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        audit_events,
+        errors::{self, RouterResponse, RouterResult},
+        payment_methods::{cards, vault},
+    },
+    routes::{metrics, payment_methods::ParentPaymentMethodToken, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, PaymentTokenData},
+    },
+};
+
+pub const VAULT_CLEANUP_TASK: &str = "VAULT_CLEANUP";
+pub const VAULT_CLEANUP_TAG: &str = "VAULT_CLEANUP";
+
+/// Number of days a payment method must have been left untouched before its vault entry is
+/// considered orphaned, when not provided in the cleanup request
+const DEFAULT_VAULT_RETENTION_DAYS: u16 = 90;
+
+/// Number of payment methods fetched from the database at a time while cleaning up the vault
+const VAULT_CLEANUP_PAGE_SIZE: i64 = 500;
+
+/// The outcome of a vault cleanup
+#[derive(Debug, Default)]
+pub struct VaultCleanupSummary {
+    pub deleted_count: u64,
+    pub failed_count: u64,
+}
+
+/// Expires a temporary payment method token before the end of its time to live, removing the
+/// card data held for it in the temporary locker
+#[instrument(skip_all)]
+pub async fn expire_vault_token(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: VaultTokenExpireRequest,
+) -> RouterResponse<VaultTokenExpireResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = format!(
+        "pm_token_{}_{}_hyperswitch",
+        request.token, request.payment_method
+    );
+    let token_data = redis_conn
+        .get_key::<Option<String>>(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the token from redis")?
+        .map(|token_data| token_data.parse_struct("PaymentTokenData"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the payment token data")?;
+
+    let Some(token_data) = token_data else {
+        return Ok(ApplicationResponse::Json(VaultTokenExpireResponse {
+            token: request.token,
+            payment_method: request.payment_method,
+            expired: false,
+        }));
+    };
+
+    let payment_method_id = match &token_data {
+        PaymentTokenData::Permanent(card_token) | PaymentTokenData::PermanentCard(card_token) => {
+            card_token.payment_method_id.clone()
+        }
+        PaymentTokenData::WalletToken(wallet_token) => Some(wallet_token.payment_method_id.clone()),
+        PaymentTokenData::Temporary(_)
+        | PaymentTokenData::TemporaryGeneric(_)
+        | PaymentTokenData::AuthBankDebit(_) => None,
+    };
+    if let Some(payment_method_id) = payment_method_id {
+        let payment_method = state
+            .store
+            .find_payment_method(&payment_method_id, merchant_account.storage_scheme)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment method of the token")?;
+        if payment_method.merchant_id != merchant_account.merchant_id {
+            return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Token not found".to_string(),
+            }));
+        }
+    }
+
+    if let PaymentTokenData::Temporary(generic_token)
+    | PaymentTokenData::TemporaryGeneric(generic_token) = &token_data
+    {
+        // The card data may have been removed from the temporary locker by the payment already
+        if let Err(error) = vault::delete_tokenized_data(&state, &generic_token.token).await {
+            logger::info!(
+                ?error,
+                "Failed to delete the token from the temporary locker"
+            );
+        }
+    }
+    ParentPaymentMethodToken::create_key_for_token((&request.token, request.payment_method))
+        .delete(&state)
+        .await?;
+
+    audit_events::record_audit_event::<()>(
+        &state,
+        &merchant_account.merchant_id,
+        AuditResourceType::VaultToken,
+        &request.token,
+        AuditAction::Expire,
+        None,
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(VaultTokenExpireResponse {
+        token: request.token,
+        payment_method: request.payment_method,
+        expired: true,
+    }))
+}
+
+/// Schedules the deletion of the vault entries of the merchant that are no longer linked to a
+/// customer or a mandate
+#[instrument(skip_all)]
+pub async fn schedule_vault_cleanup(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: VaultCleanupRequest,
+) -> RouterResponse<VaultCleanupResponse> {
+    let retention_days = request
+        .retention_days
+        .unwrap_or(DEFAULT_VAULT_RETENTION_DAYS);
+    let now = common_utils::date_time::now();
+    let last_modified_before = now.saturating_sub(time::Duration::days(i64::from(retention_days)));
+
+    let tracking_data = storage::VaultCleanupTrackingData {
+        merchant_id: merchant_account.merchant_id.clone(),
+        last_modified_before,
+    };
+    let cleanup_id = format!(
+        "{runner}_{task}_{merchant_id}_{timestamp}",
+        runner = storage::ProcessTrackerRunner::VaultCleanupWorkflow,
+        task = VAULT_CLEANUP_TASK,
+        merchant_id = merchant_account.merchant_id,
+        timestamp = now.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        cleanup_id.clone(),
+        VAULT_CLEANUP_TASK,
+        storage::ProcessTrackerRunner::VaultCleanupWorkflow,
+        [VAULT_CLEANUP_TAG],
+        tracking_data,
+        now,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct vault cleanup process tracker task")?;
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the vault cleanup task")?;
+
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "VaultCleanup")]),
+    );
+
+    Ok(ApplicationResponse::Json(VaultCleanupResponse {
+        cleanup_id,
+        merchant_id: merchant_account.merchant_id,
+        retention_days,
+        last_modified_before,
+        scheduled_at: now,
+    }))
+}
+
+/// Deletes the orphaned vault entries of the merchant from the card vault, along with their
+/// payment methods. Entries that fail to be deleted are skipped and retried by later cleanups.
+#[instrument(skip_all)]
+pub async fn run_vault_cleanup(
+    state: &SessionState,
+    tracking_data: &storage::VaultCleanupTrackingData,
+) -> RouterResult<VaultCleanupSummary> {
+    let db = &*state.store;
+    let mut summary = VaultCleanupSummary::default();
+    let mut starting_after: Option<String> = None;
+
+    loop {
+        let payment_methods = db
+            .list_orphaned_vault_payment_methods(
+                &tracking_data.merchant_id,
+                tracking_data.last_modified_before,
+                starting_after.as_deref(),
+                VAULT_CLEANUP_PAGE_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the orphaned vault entries")?;
+        let is_last_page = i64::try_from(payment_methods.len())
+            .map_or(true, |count| count < VAULT_CLEANUP_PAGE_SIZE);
+        starting_after = payment_methods
+            .last()
+            .map(|payment_method| payment_method.payment_method_id.clone());
+
+        for payment_method in payment_methods {
+            match delete_orphaned_vault_entry(state, &payment_method).await {
+                Ok(()) => summary.deleted_count += 1,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        payment_method_id = %payment_method.payment_method_id,
+                        "Failed to delete the orphaned vault entry"
+                    );
+                    summary.failed_count += 1;
+                }
+            }
+        }
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    metrics::VAULT_ENTRIES_CLEANED_UP.add(&metrics::CONTEXT, summary.deleted_count, &[]);
+
+    Ok(summary)
+}
+
+async fn delete_orphaned_vault_entry(
+    state: &SessionState,
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<()> {
+    if let Some(locker_id) = &payment_method.locker_id {
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            locker_id,
+        )
+        .await?;
+    }
+
+    state
+        .store
+        .delete_payment_method_by_merchant_id_payment_method_id(
+            &payment_method.merchant_id,
+            &payment_method.payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the payment method of the orphaned vault entry")?;
+
+    Ok(())
+}
+
+/// Lists the reads, deletions and expiries of the vault tokens of the merchant
+#[instrument(skip_all)]
+pub async fn list_vault_audit_events(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: VaultAuditQuery,
+) -> RouterResponse<AuditEventsResponse> {
+    audit_events::list_audit_events(
+        state,
+        AuditEventsQuery {
+            merchant_id: Some(merchant_account.merchant_id),
+            resource_type: Some(AuditResourceType::VaultToken),
+            created_after: query.created_after,
+            created_before: query.created_before,
+            limit: query.limit,
+            offset: query.offset,
+            ..Default::default()
+        },
+    )
+    .await
+}
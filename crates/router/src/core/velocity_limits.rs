@@ -0,0 +1,443 @@
+use std::collections::HashSet;
+
+use api_models::velocity_limits::{
+    self as velocity_limits_types, VelocityLimitDimension, VelocityLimitRule, VelocityLimitsRecord,
+    VelocityLimitsRequest, VelocityLimitsResponse,
+};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::{Encode, StringExt, ValueExt},
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{self, CacheKind, DECISION_MANAGER_CACHE};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{
+        api, domain,
+        storage::{self, enums::MerchantDecision},
+        BrowserInformation,
+    },
+};
+
+pub fn get_velocity_limits_config_key(profile_id: &str) -> String {
+    format!("velocity_limits_{profile_id}")
+}
+
+fn validate_velocity_limit_rules(rules: &[VelocityLimitRule]) -> RouterResult<()> {
+    let mut rule_names = HashSet::new();
+    for rule in rules {
+        if !rule_names.insert(rule.name.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "velocity limit '{}' is configured more than once",
+                    rule.name
+                ),
+            })?
+        }
+        if rule.max_count.is_none() && rule.max_amount.is_none() {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "either max_count or max_amount has to be provided for the velocity limit '{}'",
+                    rule.name
+                ),
+            })?
+        }
+        if rule.window_in_secs == 0 {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "window_in_secs of the velocity limit '{}' has to be greater than zero",
+                    rule.name
+                ),
+            })?
+        }
+    }
+    Ok(())
+}
+
+pub async fn upsert_velocity_limits(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+    request: VelocityLimitsRequest,
+) -> RouterResponse<VelocityLimitsResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+    validate_velocity_limit_rules(&request.rules)?;
+
+    let key = get_velocity_limits_config_key(&profile_id);
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    let record = match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: VelocityLimitsRecord = config
+                .config
+                .parse_struct("VelocityLimitsRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the velocity limits record")?;
+
+            let record = VelocityLimitsRecord {
+                profile_id,
+                rules: request.rules,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the velocity limits config")?;
+
+            record
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let record = VelocityLimitsRecord {
+                profile_id,
+                rules: request.rules,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.insert_config(configs::ConfigNew {
+                key: key.clone(),
+                config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the velocity limits config")?;
+
+            record
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the velocity limits config")?,
+    };
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::DecisionManager(key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the velocity limits cache")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn retrieve_velocity_limits(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<VelocityLimitsResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    let record: VelocityLimitsRecord = db
+        .find_config_by_key(&get_velocity_limits_config_key(&profile_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?
+        .config
+        .parse_struct("VelocityLimitsRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the velocity limits record")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn delete_velocity_limits(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    let key = get_velocity_limits_config_key(&profile_id);
+    db.delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::DecisionManager(key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the velocity limits cache")?;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+async fn get_velocity_limits(
+    state: &SessionState,
+    profile_id: &str,
+) -> RouterResult<Option<VelocityLimitsRecord>> {
+    let db = &*state.store;
+    let key = get_velocity_limits_config_key(profile_id);
+
+    let find_record_from_db = || async {
+        db.find_config_by_key(&key)
+            .await?
+            .config
+            .parse_struct::<VelocityLimitsRecord>("VelocityLimitsRecord")
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to parse the velocity limits record")
+    };
+
+    match cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &key,
+        find_record_from_db,
+        &DECISION_MANAGER_CACHE,
+    )
+    .await
+    {
+        Ok(record) => Ok(Some(record)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the velocity limits of the business profile"),
+    }
+}
+
+pub fn is_velocity_limited_operation<Op: std::fmt::Debug>(operation: &Op) -> bool {
+    matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentCreate" | "PaymentConfirm"
+    )
+}
+
+/// Enforces the velocity limits of the business profile on the payment. The payments within the
+/// sliding window of a limit are tracked in a redis sorted set scored by the time at which they
+/// were made, so that the payments older than the window can be evicted on every evaluation.
+/// Failures while reading or updating the windows are logged and the payment is allowed.
+#[instrument(skip_all)]
+pub async fn enforce_velocity_limits<F: Clone>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::BusinessProfile,
+    payment_data: &mut payments::PaymentData<F>,
+) -> RouterResult<()> {
+    let Some(velocity_limits) = get_velocity_limits(state, &business_profile.profile_id).await?
+    else {
+        return Ok(());
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the velocity limits"
+            );
+            return Ok(());
+        }
+    };
+
+    let payment_id = &payment_data.payment_intent.payment_id;
+    let amount = payment_data.payment_intent.amount;
+    // The amount is a part of the member, so that the total amount within the window can be
+    // computed from the members of the window
+    let window_member = format!("{payment_id}:{}", amount.get_amount_as_i64());
+    let now = common_utils::date_time::now().assume_utc();
+    let now_in_secs = (now - time::OffsetDateTime::UNIX_EPOCH).as_seconds_f64();
+
+    let mut windows = Vec::new();
+    for rule in &velocity_limits.rules {
+        let Some(dimension_value) =
+            get_velocity_limit_dimension_value(rule.dimension, payment_data)
+        else {
+            continue;
+        };
+        let Ok(digest) = Sha256.generate_digest(dimension_value.as_bytes()) else {
+            continue;
+        };
+        let key = format!(
+            "velocity_limit_{}_{}_{}_{}",
+            business_profile.profile_id,
+            rule.name,
+            hex::encode(digest),
+            payment_data.currency
+        );
+        let window_start = now_in_secs - f64::from(rule.window_in_secs);
+
+        if let Err(error) = redis_conn
+            .sorted_set_remove_members_by_score(&key, 0.0, window_start)
+            .await
+        {
+            logger::error!(?error, "Failed to evict the expired payments of the window");
+        }
+        let members = match redis_conn
+            .sorted_set_get_members_by_score(&key, window_start, now_in_secs)
+            .await
+        {
+            Ok(members) => members,
+            Err(error) => {
+                logger::error!(?error, "Failed to fetch the payments of the window");
+                continue;
+            }
+        };
+
+        // Previous evaluations of the same payment, such as on its creation, are not counted
+        let (count, total_amount) = members
+            .iter()
+            .filter_map(|member| member.rsplit_once(':'))
+            .filter(|(member_payment_id, _)| member_payment_id != payment_id)
+            .fold((0u32, 0i64), |(count, total_amount), (_, amount)| {
+                (
+                    count.saturating_add(1),
+                    total_amount.saturating_add(amount.parse::<i64>().unwrap_or_default()),
+                )
+            });
+
+        let is_count_exceeded = rule
+            .max_count
+            .is_some_and(|max_count| count.saturating_add(1) > max_count);
+        let is_amount_exceeded = rule.max_amount.is_some_and(|max_amount| {
+            MinorUnit::new(total_amount.saturating_add(amount.get_amount_as_i64())) > max_amount
+        });
+
+        if is_count_exceeded || is_amount_exceeded {
+            return decline_payment_for_velocity_limit(
+                state,
+                merchant_account,
+                key_store,
+                payment_data,
+                rule,
+            )
+            .await;
+        }
+
+        windows.push((key, rule.window_in_secs));
+    }
+
+    for (key, window_in_secs) in windows {
+        if let Err(error) = redis_conn
+            .sorted_set_add_member(&key, &window_member, now_in_secs)
+            .await
+        {
+            logger::error!(?error, "Failed to record the payment in the window");
+            continue;
+        }
+        if let Err(error) = redis_conn.set_expiry(&key, window_in_secs.into()).await {
+            logger::error!(?error, "Failed to set the expiry of the window");
+        }
+    }
+
+    Ok(())
+}
+
+async fn decline_payment_for_velocity_limit<F: Clone>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut payments::PaymentData<F>,
+    rule: &VelocityLimitRule,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let decline_message = format!(
+        "The payment exceeds the velocity limit '{}' of the business profile",
+        rule.name
+    );
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::RejectUpdate {
+                status: common_enums::IntentStatus::Failed,
+                merchant_decision: Some(MerchantDecision::Rejected.to_string()),
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable(
+            "Failed to update status in Payment Intent to failed due to a velocity limit",
+        )?;
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::BlocklistUpdate {
+                status: common_enums::AttemptStatus::Failure,
+                error_code: Some(Some(
+                    velocity_limits_types::VELOCITY_LIMIT_DECLINE_CODE.to_string(),
+                )),
+                error_message: Some(Some(decline_message.clone())),
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable(
+            "Failed to update status in Payment Attempt to failed due to a velocity limit",
+        )?;
+
+    Err(errors::ApiErrorResponse::PaymentBlockedError {
+        code: 200,
+        message: decline_message,
+        status: "Failed".to_string(),
+        reason: velocity_limits_types::VELOCITY_LIMIT_DECLINE_CODE.to_string(),
+    }
+    .into())
+}
+
+fn get_velocity_limit_dimension_value<F: Clone>(
+    dimension: VelocityLimitDimension,
+    payment_data: &payments::PaymentData<F>,
+) -> Option<String> {
+    match dimension {
+        VelocityLimitDimension::Customer => payment_data
+            .payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned()),
+        VelocityLimitDimension::Card => {
+            payment_data
+                .payment_method_data
+                .as_ref()
+                .and_then(|payment_method_data| match payment_method_data {
+                    api::PaymentMethodData::Card(card) => Some(card.card_number.get_card_no()),
+                    _ => None,
+                })
+        }
+        VelocityLimitDimension::IpAddress => payment_data
+            .payment_attempt
+            .browser_info
+            .clone()
+            .and_then(|browser_info| {
+                browser_info
+                    .parse_value::<BrowserInformation>("BrowserInformation")
+                    .ok()
+            })
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| ip_address.to_string()),
+    }
+}
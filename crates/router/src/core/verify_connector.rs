@@ -1,14 +1,25 @@
-use api_models::{enums::Connector, verify_connector::VerifyConnectorRequest};
+use std::str::FromStr;
+
+use api_models::{
+    enums::Connector,
+    verify_connector::{
+        ConnectorValidationStatus, MerchantConnectorValidationResponse, VerifyConnectorRequest,
+    },
+};
+use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
+use masking::ExposeInterface;
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     connector,
-    core::errors,
+    core::errors::{self, StorageErrorExt},
     services,
     types::{
+        self,
         api::{
             self,
-            verify_connector::{self as types, VerifyConnector},
+            verify_connector::{self as verify_types, VerifyConnector},
         },
         transformers::ForeignInto,
     },
@@ -16,51 +27,134 @@ use crate::{
     SessionState,
 };
 
-pub async fn verify_connector_credentials(
-    state: SessionState,
-    req: VerifyConnectorRequest,
+/// Makes a low impact call to the connector with the credentials: a manually captured
+/// authorization of a test card for the connectors verified through payments, or the request
+/// of an access token for the connectors authenticated with one
+async fn verify_connector_account(
+    state: &SessionState,
+    connector_name: Connector,
+    connector_auth: types::ConnectorAuthType,
 ) -> errors::RouterResponse<()> {
     let boxed_connector = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
-        &req.connector_name.to_string(),
+        &connector_name.to_string(),
         api::GetToken::Connector,
         None,
     )
     .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
 
-    let card_details = utils::get_test_card_details(req.connector_name)?.ok_or(
+    let card_details = utils::get_test_card_details(connector_name)?.ok_or(
         errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         },
     )?;
 
-    match req.connector_name {
-        Connector::Stripe => {
-            connector::Stripe::verify(
-                &state,
-                types::VerifyConnectorData {
-                    connector: boxed_connector.connector,
-                    connector_auth: req.connector_account_details.foreign_into(),
-                    card_details,
-                },
-            )
+    let connector_data = verify_types::VerifyConnectorData {
+        connector: boxed_connector.connector,
+        connector_auth,
+        card_details,
+    };
+
+    match connector_name {
+        Connector::Stripe => connector::Stripe::verify(state, connector_data).await,
+        Connector::Paypal => connector::Paypal::get_access_token(state, connector_data)
             .await
-        }
-        Connector::Paypal => connector::Paypal::get_access_token(
-            &state,
-            types::VerifyConnectorData {
-                connector: boxed_connector.connector,
-                connector_auth: req.connector_account_details.foreign_into(),
-                card_details,
-            },
-        )
-        .await
-        .map(|_| services::ApplicationResponse::StatusOk),
+            .map(|_| services::ApplicationResponse::StatusOk),
         _ => Err(errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         }
         .into()),
     }
 }
+
+pub async fn verify_connector_credentials(
+    state: SessionState,
+    req: VerifyConnectorRequest,
+) -> errors::RouterResponse<()> {
+    verify_connector_account(
+        &state,
+        req.connector_name,
+        req.connector_account_details.foreign_into(),
+    )
+    .await
+}
+
+/// Validates the credentials of a merchant connector account with a live call to the connector.
+/// The credentials rejected by the connector are reported in the response along with what to do
+/// about them, rather than failing the request.
+#[instrument(skip_all)]
+pub async fn validate_merchant_connector_account(
+    state: SessionState,
+    merchant_id: String,
+    merchant_connector_id: String,
+) -> errors::RouterResponse<MerchantConnectorValidationResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.clone(),
+        })?;
+
+    let connector_name = Connector::from_str(&mca.connector_name)
+        .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+        .attach_printable("Failed to parse the connector name of the merchant connector account")?;
+    let connector_auth: types::ConnectorAuthType = mca
+        .connector_account_details
+        .into_inner()
+        .expose()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector account details")?;
+
+    let (status, error_message, suggested_action) = match verify_connector_account(
+        &state,
+        connector_name,
+        connector_auth,
+    )
+    .await
+    {
+        Ok(_) => (ConnectorValidationStatus::Valid, None, None),
+        Err(error) => match error.current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { message } => {
+                logger::info!(%connector_name, "Connector rejected the credentials");
+                let suggested_action = format!(
+                    "Check the credentials in the dashboard of {connector_name} and update the connector_account_details of the merchant connector account"
+                );
+                (
+                    ConnectorValidationStatus::Invalid,
+                    Some(message.clone()),
+                    Some(suggested_action),
+                )
+            }
+            errors::ApiErrorResponse::FlowNotSupported { .. } => (
+                ConnectorValidationStatus::Unsupported,
+                None,
+                Some(format!(
+                    "Make a test payment through {connector_name} to validate the credentials"
+                )),
+            ),
+            _ => return Err(error),
+        },
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        MerchantConnectorValidationResponse {
+            merchant_connector_id: mca.merchant_connector_id,
+            connector_name: mca.connector_name,
+            status,
+            error_message,
+            suggested_action,
+        },
+    ))
+}
@@ -0,0 +1,432 @@
+use api_models::wallet_decryption_keys::{
+    WalletDecryptionKeyCreateRequest, WalletDecryptionKeyDetails, WalletDecryptionKeyListResponse,
+    WalletDecryptionKeyResponse, WalletDecryptionKeyRetireRequest, WalletDecryptionKeyStatus,
+    WalletDecryptionKeyWallet,
+};
+use base64::Engine;
+use common_utils::{
+    crypto::{Encryptable, GcmAes256},
+    ext_traits::{Encode, ValueExt},
+    generate_id,
+};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts::{self, BASE64_ENGINE},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        api,
+        domain::{
+            self,
+            types::{self as domain_types, TypeEncryption},
+        },
+        storage,
+    },
+};
+
+fn get_wallet_decryption_key_status(
+    wallet_decryption_key: &storage::WalletDecryptionKey,
+) -> WalletDecryptionKeyStatus {
+    match wallet_decryption_key.expires_at {
+        None => WalletDecryptionKeyStatus::Active,
+        Some(expires_at) if expires_at > common_utils::date_time::now() => {
+            WalletDecryptionKeyStatus::Retiring
+        }
+        Some(_) => WalletDecryptionKeyStatus::Retired,
+    }
+}
+
+fn is_wallet_decryption_key_usable(wallet_decryption_key: &storage::WalletDecryptionKey) -> bool {
+    !matches!(
+        get_wallet_decryption_key_status(wallet_decryption_key),
+        WalletDecryptionKeyStatus::Retired
+    )
+}
+
+fn get_wallet_decryption_key_response(
+    wallet_decryption_key: storage::WalletDecryptionKey,
+) -> RouterResult<WalletDecryptionKeyResponse> {
+    let wallet = wallet_decryption_key
+        .wallet
+        .parse::<WalletDecryptionKeyWallet>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the wallet of the wallet decryption key")?;
+
+    Ok(WalletDecryptionKeyResponse {
+        status: get_wallet_decryption_key_status(&wallet_decryption_key),
+        key_id: wallet_decryption_key.key_id,
+        profile_id: wallet_decryption_key.profile_id,
+        wallet,
+        key_identifier: wallet_decryption_key.key_identifier,
+        expires_at: wallet_decryption_key.expires_at,
+        created_at: wallet_decryption_key.created_at,
+    })
+}
+
+/// The identifier the wallet tokens refer to the key with. The key is validated against its
+/// certificate for Apple Pay, so that a mismatching pair is rejected when registered rather than
+/// failing every payment.
+fn get_key_identifier(key_details: &WalletDecryptionKeyDetails) -> RouterResult<String> {
+    match key_details {
+        WalletDecryptionKeyDetails::ApplePay {
+            payment_processing_certificate,
+            payment_processing_certificate_key,
+        } => {
+            let certificate = BASE64_ENGINE
+                .decode(payment_processing_certificate.peek())
+                .ok()
+                .and_then(|certificate| openssl::x509::X509::from_der(&certificate).ok())
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "payment_processing_certificate has to be a base64 encoded DER \
+                        certificate"
+                        .to_string(),
+                })?;
+            let private_key = openssl::pkey::PKey::private_key_from_pem(
+                payment_processing_certificate_key.peek().as_bytes(),
+            )
+            .map_err(|_| errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment_processing_certificate_key has to be a PEM encoded private key"
+                    .to_string(),
+            })?;
+            let public_key = certificate.public_key().change_context(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Failed to read the public key of the certificate".to_string(),
+                },
+            )?;
+            if !private_key.public_eq(&public_key) {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "payment_processing_certificate_key does not match the certificate"
+                        .to_string(),
+                })?
+            }
+
+            let public_key = public_key
+                .public_key_to_der()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode the public key of the certificate")?;
+            Ok(BASE64_ENGINE.encode(openssl::sha::sha256(&public_key)))
+        }
+        WalletDecryptionKeyDetails::GooglePay {
+            recipient_id,
+            private_key,
+        } => {
+            if recipient_id.trim().is_empty() {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "recipient_id cannot be empty".to_string(),
+                })?
+            }
+            openssl::pkey::PKey::private_key_from_pem(private_key.peek().as_bytes())
+                .ok()
+                .and_then(|private_key| private_key.ec_key().ok())
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "private_key has to be a PEM encoded EC private key".to_string(),
+                })?;
+            Ok(recipient_id.clone())
+        }
+    }
+}
+
+async fn decrypt_key_details(
+    wallet_decryption_key: storage::WalletDecryptionKey,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<WalletDecryptionKeyDetails> {
+    Encryptable::<Secret<serde_json::Value>>::decrypt(
+        wallet_decryption_key.key_details,
+        key_store.key.peek(),
+        GcmAes256,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the wallet decryption key")?
+    .into_inner()
+    .expose()
+    .parse_value("WalletDecryptionKeyDetails")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+#[instrument(skip_all)]
+pub async fn create_wallet_decryption_key(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+    request: WalletDecryptionKeyCreateRequest,
+) -> RouterResponse<WalletDecryptionKeyResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key_identifier = get_key_identifier(&request.key_details)?;
+    let wallet = request.key_details.get_wallet();
+    let key_details = request
+        .key_details
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the wallet decryption key")?;
+    let key_details = domain_types::encrypt(Secret::new(key_details), key_store.key.peek())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt the wallet decryption key")?;
+
+    let now = common_utils::date_time::now();
+    let wallet_decryption_key = db
+        .insert_wallet_decryption_key(storage::WalletDecryptionKeyNew {
+            key_id: generate_id(consts::ID_LENGTH, "wdk"),
+            merchant_id: merchant_id.to_string(),
+            profile_id,
+            wallet: wallet.to_string(),
+            key_identifier,
+            key_details: key_details.into(),
+            expires_at: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the wallet decryption key")?;
+
+    Ok(ApplicationResponse::Json(
+        get_wallet_decryption_key_response(wallet_decryption_key)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_wallet_decryption_keys(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<WalletDecryptionKeyListResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    let keys = db
+        .list_wallet_decryption_keys_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the wallet decryption keys")?
+        .into_iter()
+        .map(get_wallet_decryption_key_response)
+        .collect::<RouterResult<_>>()?;
+
+    Ok(ApplicationResponse::Json(WalletDecryptionKeyListResponse {
+        keys,
+    }))
+}
+
+/// Retires the key once its grace period is over. Keys are rotated by registering the new key
+/// before retiring the replaced one, the tokens being decrypted with any of the keys that are
+/// not retired yet.
+#[instrument(skip_all)]
+pub async fn retire_wallet_decryption_key(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: String,
+    key_id: String,
+    request: WalletDecryptionKeyRetireRequest,
+) -> RouterResponse<WalletDecryptionKeyResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    let wallet_decryption_key = db
+        .find_wallet_decryption_key_by_profile_id_key_id(&profile_id, &key_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    if !is_wallet_decryption_key_usable(&wallet_decryption_key) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The wallet decryption key is already retired".to_string(),
+        })?
+    }
+
+    let expires_at = common_utils::date_time::now()
+        + time::Duration::seconds(request.grace_period_in_secs.unwrap_or_default().into());
+    let wallet_decryption_key = db
+        .update_wallet_decryption_key_by_profile_id_key_id(
+            &profile_id,
+            &key_id,
+            storage::WalletDecryptionKeyUpdate::Retire { expires_at },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retire the wallet decryption key")?;
+
+    Ok(ApplicationResponse::Json(
+        get_wallet_decryption_key_response(wallet_decryption_key)?,
+    ))
+}
+
+/// The payment processing certificate and key registered on the business profile that the Apple
+/// Pay token was encrypted for, if any, the latest key being used when several match
+#[instrument(skip_all)]
+pub async fn find_apple_pay_processing_details(
+    state: &SessionState,
+    profile_id: &str,
+    public_key_hash: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<api_models::payments::PaymentProcessingDetails>> {
+    let wallet_decryption_key = state
+        .store
+        .list_wallet_decryption_keys_by_profile_id_wallet_key_identifier(
+            profile_id,
+            &WalletDecryptionKeyWallet::ApplePay.to_string(),
+            public_key_hash,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the Apple Pay decryption keys")?
+        .into_iter()
+        .filter(is_wallet_decryption_key_usable)
+        .last();
+
+    let Some(wallet_decryption_key) = wallet_decryption_key else {
+        return Ok(None);
+    };
+    match decrypt_key_details(wallet_decryption_key, key_store).await? {
+        WalletDecryptionKeyDetails::ApplePay {
+            payment_processing_certificate,
+            payment_processing_certificate_key,
+        } => Ok(Some(api_models::payments::PaymentProcessingDetails {
+            payment_processing_certificate,
+            payment_processing_certificate_key,
+        })),
+        WalletDecryptionKeyDetails::GooglePay { .. } => {
+            Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Google Pay key registered as an Apple Pay key")
+        }
+    }
+}
+
+/// Decrypts the Google Pay token with the keys registered on the business profile, the latest
+/// keys being tried first
+async fn decrypt_google_pay_token(
+    state: &SessionState,
+    profile_id: &str,
+    token: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<helpers::GooglePayDecryptedData>> {
+    let wallet_decryption_keys = state
+        .store
+        .list_wallet_decryption_keys_by_profile_id_wallet(
+            profile_id,
+            &WalletDecryptionKeyWallet::GooglePay.to_string(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the Google Pay decryption keys")?;
+    if !wallet_decryption_keys
+        .iter()
+        .any(is_wallet_decryption_key_usable)
+    {
+        return Ok(None);
+    }
+
+    let token_data = helpers::GooglePayTokenData::token_json(token).change_context(
+        errors::ApiErrorResponse::InvalidWalletToken {
+            wallet_name: "Google Pay".to_string(),
+        },
+    )?;
+    let root_signing_keys = &state.conf.wallet_decryption.google_pay_root_signing_keys;
+
+    for wallet_decryption_key in wallet_decryption_keys
+        .into_iter()
+        .rev()
+        .filter(is_wallet_decryption_key_usable)
+    {
+        let key_id = wallet_decryption_key.key_id.clone();
+        let WalletDecryptionKeyDetails::GooglePay {
+            recipient_id,
+            private_key,
+        } = decrypt_key_details(wallet_decryption_key, key_store).await?
+        else {
+            continue;
+        };
+        match token_data.decrypt(&recipient_id, &private_key, root_signing_keys) {
+            Ok(decrypted_data) => return Ok(Some(decrypted_data)),
+            Err(error) => {
+                logger::info!(?error, %key_id, "Failed to decrypt the Google Pay token");
+            }
+        }
+    }
+
+    Err(errors::ApiErrorResponse::InvalidWalletToken {
+        wallet_name: "Google Pay".to_string(),
+    })
+    .attach_printable("The Google Pay token could not be decrypted with the keys of the profile")
+}
+
+/// The card details to forward to the connector in place of the wallet token, when the connector
+/// lacks native support of the wallet and the token was decrypted with the keys of the business
+/// profile. Only the tokens of the cards stored on the wallet account can be forwarded, the
+/// cryptograms of the device tokens having no place in the card details.
+#[instrument(skip_all)]
+pub async fn get_decrypted_wallet_payment_method_data(
+    state: &SessionState,
+    connector_name: &str,
+    profile_id: &str,
+    payment_method_data: Option<&api::PaymentMethodData>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<api::PaymentMethodData>> {
+    let is_forwarding_enabled = connector_name
+        .parse::<api_models::enums::Connector>()
+        .is_ok_and(|connector| {
+            state
+                .conf
+                .wallet_decryption
+                .connectors_with_decrypted_wallet_forwarding
+                .contains(&connector)
+        });
+    let (
+        true,
+        Some(api::PaymentMethodData::Wallet(api_models::payments::WalletData::GooglePay(
+            google_pay_data,
+        ))),
+    ) = (is_forwarding_enabled, payment_method_data)
+    else {
+        return Ok(None);
+    };
+
+    let Some(decrypted_data) = decrypt_google_pay_token(
+        state,
+        profile_id,
+        &google_pay_data.tokenization_data.token,
+        key_store,
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let payment_method_details = decrypted_data.payment_method_details;
+    if payment_method_details.auth_method != helpers::GooglePayAuthMethod::PanOnly {
+        Err(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Forwarding the Google Pay device tokens to the {connector_name} connector"
+            ),
+        })?
+    }
+
+    Ok(Some(api::PaymentMethodData::Card(
+        api_models::payments::Card {
+            card_number: payment_method_details.pan,
+            card_exp_month: Secret::new(format!("{:02}", payment_method_details.expiration_month)),
+            card_exp_year: Secret::new(payment_method_details.expiration_year.to_string()),
+            card_holder_name: None,
+            card_cvc: Secret::new(String::new()),
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            card_issuing_country: None,
+            bank_code: None,
+            nick_name: None,
+        },
+    )))
+}
@@ -1,3 +1,6 @@
+mod archive;
+mod concurrency;
+mod idempotency;
 mod incoming;
 mod outgoing;
 pub mod types;
@@ -5,6 +8,7 @@ pub mod utils;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
+pub use self::concurrency::WebhookLane;
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
@@ -0,0 +1,79 @@
+use api_models::webhooks::IncomingWebhookEvent;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::domain::{self, types as domain_types},
+};
+
+const ARCHIVE_ID_PREFIX: &str = "whar";
+
+/// Archives the body of an incoming webhook as it was received, encrypted with the key of the
+/// merchant. The archive does not affect the processing of the webhook, so failures are only
+/// logged.
+#[instrument(skip_all)]
+pub(super) async fn archive_incoming_webhook(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: Option<&domain::MerchantConnectorAccount>,
+    connector_name: &str,
+    event_type: Option<IncomingWebhookEvent>,
+    body: &[u8],
+) {
+    if let Err(error) = insert_archive(
+        state,
+        key_store,
+        merchant_connector_account,
+        connector_name,
+        event_type,
+        body,
+    )
+    .await
+    {
+        logger::error!(?error, %connector_name, "Failed to archive the incoming webhook");
+    }
+}
+
+async fn insert_archive(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: Option<&domain::MerchantConnectorAccount>,
+    connector_name: &str,
+    event_type: Option<IncomingWebhookEvent>,
+    body: &[u8],
+) -> RouterResult<()> {
+    let body = domain_types::encrypt(
+        Secret::new(String::from_utf8_lossy(body).into_owned()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the incoming webhook body")?;
+
+    let archive = domain::IncomingWebhookArchive {
+        archive_id: common_utils::generate_time_ordered_id(ARCHIVE_ID_PREFIX),
+        merchant_id: key_store.merchant_id.clone(),
+        business_profile_id: merchant_connector_account.and_then(|mca| mca.profile_id.clone()),
+        connector_name: connector_name.to_owned(),
+        merchant_connector_id: merchant_connector_account
+            .map(|mca| mca.merchant_connector_id.clone()),
+        // The event type is stored as it is serialized, `payment_intent_success` for instance
+        event_type: event_type
+            .and_then(|event_type| serde_json::to_value(event_type).ok())
+            .and_then(|event_type| event_type.as_str().map(String::from)),
+        body,
+        created_at: common_utils::date_time::now(),
+    };
+
+    state
+        .store
+        .insert_incoming_webhook_archive(archive, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the incoming webhook archive")?;
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+use error_stack::{report, ResultExt};
+use once_cell::sync::Lazy;
+use router_env::{logger, metrics::add_attributes};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{
+    configs::settings::IncomingWebhookConcurrency,
+    core::{
+        errors::{self, RouterResult},
+        metrics,
+    },
+    types::api,
+};
+
+/// The lanes the incoming webhooks of a connector are processed in. Each lane has its own limit of
+/// webhooks processed at the same time, so that a flood of bulk status updates of a connector
+/// cannot hold up the processing of its time-sensitive payment webhooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookLane {
+    Priority,
+    Bulk,
+}
+
+impl WebhookLane {
+    /// The lane of the webhooks of the flow, if they are processed in a lane
+    pub fn from_flow(flow_type: &api::WebhookFlow) -> Option<Self> {
+        match flow_type {
+            api::WebhookFlow::Payment
+            | api::WebhookFlow::BankTransfer
+            | api::WebhookFlow::Mandate
+            | api::WebhookFlow::ExternalAuthentication
+            | api::WebhookFlow::FraudCheck => Some(Self::Priority),
+            #[cfg(feature = "payouts")]
+            api::WebhookFlow::Payout => Some(Self::Bulk),
+            api::WebhookFlow::Refund
+            | api::WebhookFlow::Dispute
+            | api::WebhookFlow::Subscription => Some(Self::Bulk),
+            api::WebhookFlow::ReturnResponse => None,
+        }
+    }
+}
+
+/// The webhooks of a connector processed in a lane, and the webhooks waiting to be processed
+struct LaneLimiter {
+    limit: usize,
+    semaphore: Arc<Semaphore>,
+    waiting: AtomicU64,
+}
+
+impl LaneLimiter {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            semaphore: Arc::new(Semaphore::new(limit)),
+            waiting: AtomicU64::new(0),
+        }
+    }
+}
+
+static LANE_LIMITERS: Lazy<RwLock<HashMap<(String, WebhookLane), Arc<LaneLimiter>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// A permit to process an incoming webhook in its lane, released when dropped
+pub struct WebhookLanePermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Waits for the lane of the incoming webhooks of the connector to have room for one more
+/// webhook. The connector is asked to retry the webhook later when the lane stays full for longer
+/// than the configured timeout.
+pub async fn acquire_lane_permit(
+    config: &IncomingWebhookConcurrency,
+    connector_name: &str,
+    lane: WebhookLane,
+) -> RouterResult<WebhookLanePermit> {
+    let limit = config.get_limit(connector_name, lane);
+    let limiter = get_lane_limiter(connector_name, lane, limit)?;
+    let attributes = add_attributes([
+        ("connector", connector_name.to_string()),
+        ("lane", lane.to_string()),
+    ]);
+
+    let queue_depth = limiter.waiting.fetch_add(1, Ordering::SeqCst) + 1;
+    metrics::WEBHOOK_INCOMING_QUEUE_DEPTH.observe(&metrics::CONTEXT, queue_depth, &attributes);
+    let wait_start = Instant::now();
+    let permit = tokio::time::timeout(
+        Duration::from_millis(config.acquire_timeout_in_ms),
+        Arc::clone(&limiter.semaphore).acquire_owned(),
+    )
+    .await;
+    let queue_depth = limiter.waiting.fetch_sub(1, Ordering::SeqCst) - 1;
+    metrics::WEBHOOK_INCOMING_QUEUE_DEPTH.observe(&metrics::CONTEXT, queue_depth, &attributes);
+    metrics::WEBHOOK_INCOMING_QUEUE_WAIT_TIME.record(
+        &metrics::CONTEXT,
+        wait_start.elapsed().as_secs_f64(),
+        &attributes,
+    );
+
+    match permit {
+        Ok(Ok(permit)) => Ok(WebhookLanePermit { _permit: permit }),
+        Ok(Err(_)) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Incoming webhook lane was closed"),
+        Err(_) => {
+            logger::warn!(
+                connector = connector_name,
+                %lane,
+                limit,
+                "Incoming webhook lane is full, asking the connector to retry the webhook"
+            );
+            metrics::WEBHOOK_INCOMING_REJECTED_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+            Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_in_secs: Duration::from_millis(config.acquire_timeout_in_ms)
+                    .as_secs()
+                    .max(1),
+            }))
+        }
+    }
+}
+
+/// The limiter of the lane of the connector, created with the limit provided or recreated when
+/// the limit was changed. Webhooks holding a permit of a replaced limiter finish normally.
+fn get_lane_limiter(
+    connector_name: &str,
+    lane: WebhookLane,
+    limit: usize,
+) -> RouterResult<Arc<LaneLimiter>> {
+    let key = (connector_name.to_string(), lane);
+    let limiter = LANE_LIMITERS
+        .read()
+        .map_err(|_| errors::ApiErrorResponse::InternalServerError)?
+        .get(&key)
+        .filter(|limiter| limiter.limit == limit)
+        .cloned();
+    if let Some(limiter) = limiter {
+        return Ok(limiter);
+    }
+
+    let mut limiters = LANE_LIMITERS
+        .write()
+        .map_err(|_| errors::ApiErrorResponse::InternalServerError)?;
+    let limiter = limiters
+        .entry(key)
+        .and_modify(|limiter| {
+            if limiter.limit != limit {
+                *limiter = Arc::new(LaneLimiter::new(limit));
+            }
+        })
+        .or_insert_with(|| Arc::new(LaneLimiter::new(limit)));
+    Ok(Arc::clone(limiter))
+}
@@ -0,0 +1,222 @@
+use common_utils::crypto::{self, GenerateDigest};
+use error_stack::{report, ResultExt};
+use redis_interface::SetnxReply;
+use router_env::{logger, metrics::add_attributes};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics,
+    },
+    routes::SessionState,
+    types::storage::enums,
+};
+
+const INCOMING_WEBHOOK_LEDGER_PREFIX: &str = "WH_LEDGER";
+
+/// The states of an incoming webhook in the idempotency ledger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+enum LedgerState {
+    Processing,
+    Processed,
+}
+
+/// The claim of an incoming webhook on its effects. A webhook received again from the connector
+/// is acknowledged without its effects being applied again.
+#[derive(Debug)]
+pub enum WebhookClaim {
+    /// The webhook is processed for the first time, under the ledger key held
+    Claimed { ledger_key: String },
+    /// The effects of the webhook were already applied
+    Replayed,
+    /// The ledger could not be reached, the webhook is processed without it
+    Untracked,
+}
+
+impl WebhookClaim {
+    pub fn is_replayed(&self) -> bool {
+        matches!(self, Self::Replayed)
+    }
+
+    /// Records the webhook as processed when its effects were applied, and releases the claim
+    /// otherwise, so that the connector retrying the webhook has it processed again
+    pub async fn finish(self, state: &SessionState, is_processed: bool) {
+        let Self::Claimed { ledger_key } = self else {
+            return;
+        };
+        let Ok(redis_conn) = state.store.get_redis_conn() else {
+            logger::error!("Failed to get the redis connection to finish the webhook claim");
+            return;
+        };
+
+        let result = if is_processed {
+            redis_conn
+                .set_key_with_expiry(
+                    &ledger_key,
+                    LedgerState::Processed.to_string(),
+                    i64::from(state.conf.webhooks.incoming_idempotency.retention_in_secs),
+                )
+                .await
+        } else {
+            redis_conn.delete_key(&ledger_key).await.map(|_| ())
+        };
+        if let Err(error) = result {
+            logger::error!(?error, %ledger_key, "Failed to finish the claim of the incoming webhook");
+        }
+    }
+}
+
+/// The key of the webhook in the ledger. Connectors deliver a webhook again with the same body,
+/// so the digest of the body identifies the event the webhook carries.
+fn get_ledger_key(merchant_id: &str, connector_name: &str, body: &[u8]) -> RouterResult<String> {
+    let digest = crypto::Sha256
+        .generate_digest(body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the digest of the incoming webhook body")?;
+    Ok(format!(
+        "{INCOMING_WEBHOOK_LEDGER_PREFIX}_{merchant_id}_{connector_name}_{}",
+        hex::encode(digest)
+    ))
+}
+
+/// Claims the effects of an incoming webhook in the idempotency ledger. The connector is asked to
+/// retry a webhook that is being processed by another request at the same time.
+pub async fn claim_incoming_webhook(
+    state: &SessionState,
+    merchant_id: &str,
+    connector_name: &str,
+    body: &[u8],
+) -> RouterResult<WebhookClaim> {
+    let ledger_key = get_ledger_key(merchant_id, connector_name, body)?;
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get the redis connection to claim the webhook"
+            );
+            return Ok(WebhookClaim::Untracked);
+        }
+    };
+
+    let config = &state.conf.webhooks.incoming_idempotency;
+    let claim = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &ledger_key,
+            LedgerState::Processing.to_string(),
+            Some(i64::from(config.processing_timeout_in_secs)),
+        )
+        .await;
+    match claim {
+        Ok(SetnxReply::KeySet) => Ok(WebhookClaim::Claimed { ledger_key }),
+        Ok(SetnxReply::KeyNotSet) => {
+            let ledger_state = redis_conn
+                .get_key::<Option<String>>(&ledger_key)
+                .await
+                .ok()
+                .flatten()
+                .and_then(|ledger_state| ledger_state.parse::<LedgerState>().ok());
+            let attributes = add_attributes([("connector", connector_name.to_string())]);
+            if ledger_state == Some(LedgerState::Processed) {
+                logger::info!(%ledger_key, "Incoming webhook was already processed");
+                metrics::WEBHOOK_INCOMING_REPLAYED_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+                Ok(WebhookClaim::Replayed)
+            } else {
+                logger::info!(%ledger_key, "Incoming webhook is being processed by another request");
+                metrics::WEBHOOK_INCOMING_IN_PROGRESS_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+                Err(report!(errors::ApiErrorResponse::ResourceBusy))
+            }
+        }
+        Err(error) => {
+            logger::error!(?error, %ledger_key, "Failed to claim the incoming webhook");
+            Ok(WebhookClaim::Untracked)
+        }
+    }
+}
+
+fn get_refund_status_rank(status: enums::RefundStatus) -> u8 {
+    match status {
+        enums::RefundStatus::Pending => 0,
+        enums::RefundStatus::ManualReview => 1,
+        enums::RefundStatus::Success
+        | enums::RefundStatus::Failure
+        | enums::RefundStatus::TransactionFailure => 2,
+    }
+}
+
+/// The status the refund is moved to by a webhook, if any. Webhooks are delivered out of order,
+/// so a webhook reporting the current status of the refund or a status the refund has already
+/// moved past leaves the refund as it is. A terminal status can still be corrected by the
+/// connector to another terminal status.
+pub fn resolve_refund_status(
+    current_status: enums::RefundStatus,
+    webhook_status: enums::RefundStatus,
+) -> Option<enums::RefundStatus> {
+    if current_status == webhook_status {
+        return None;
+    }
+    if get_refund_status_rank(webhook_status) < get_refund_status_rank(current_status) {
+        logger::warn!(
+            ?current_status,
+            ?webhook_status,
+            "Ignoring the regression of the refund status reported by the webhook"
+        );
+        metrics::WEBHOOK_INCOMING_STATUS_REGRESSION_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "refund")]),
+        );
+        return None;
+    }
+    Some(webhook_status)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_resolve_refund_status() {
+        use enums::RefundStatus;
+
+        assert_eq!(
+            resolve_refund_status(RefundStatus::Pending, RefundStatus::Success),
+            Some(RefundStatus::Success)
+        );
+        assert_eq!(
+            resolve_refund_status(RefundStatus::Success, RefundStatus::Success),
+            None
+        );
+        assert_eq!(
+            resolve_refund_status(RefundStatus::Success, RefundStatus::Pending),
+            None
+        );
+        assert_eq!(
+            resolve_refund_status(RefundStatus::Failure, RefundStatus::ManualReview),
+            None
+        );
+        assert_eq!(
+            resolve_refund_status(RefundStatus::Success, RefundStatus::Failure),
+            Some(RefundStatus::Failure)
+        );
+    }
+
+    #[test]
+    fn test_ledger_key_of_replayed_webhook() {
+        let body = br#"{"id":"evt_1","type":"refund.succeeded"}"#;
+        let ledger_key = get_ledger_key("merchant_1", "stripe", body).unwrap();
+
+        assert_eq!(
+            ledger_key,
+            get_ledger_key("merchant_1", "stripe", body).unwrap()
+        );
+        assert_ne!(
+            ledger_key,
+            get_ledger_key("merchant_1", "adyen", body).unwrap()
+        );
+        assert!(ledger_key.starts_with("WH_LEDGER_merchant_1_stripe_"));
+    }
+}
@@ -12,13 +12,13 @@ use error_stack::{report, ResultExt};
 use masking::ExposeInterface;
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
-use super::{types, utils, MERCHANT_ID};
+use super::{archive, concurrency, idempotency, types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, authentication as authentication_core, connector_credentials, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payments, refunds, utils as core_utils,
+        ledger, metrics, payments, refunds, utils as core_utils,
     },
     db::StorageInterface,
     events::api_logs::ApiEvent,
@@ -54,6 +54,7 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
     let start_instant = Instant::now();
+    let state = state.with_status_actor(enums::PaymentStatusActor::Webhook);
     let (application_response, webhooks_response_tracker, serialized_req) =
         Box::pin(incoming_webhooks_core::<W>(
             state.clone(),
@@ -107,7 +108,61 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     Ok(application_response)
 }
 
-#[instrument(skip_all)]
+async fn verify_webhook_source(
+    state: &SessionState,
+    connector: &ConnectorEnum,
+    connector_enum: api_models::enums::Connector,
+    connector_name: &str,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<bool> {
+    let connectors_with_source_verification_call = &state.conf.webhook_source_verification_call;
+
+    if connectors_with_source_verification_call
+        .connectors_with_webhook_source_verification_call
+        .contains(&connector_enum)
+    {
+        connector
+            .verify_webhook_source_verification_call(
+                state,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+                request_details,
+            )
+            .await
+            .or_else(|error| match error.current_context() {
+                errors::ConnectorError::WebhookSourceVerificationFailed => {
+                    logger::error!(?error, "Source Verification Failed");
+                    Ok(false)
+                }
+                _ => Err(error),
+            })
+            .switch()
+            .attach_printable("There was an issue in incoming webhook source verification")
+    } else {
+        connector
+            .verify_webhook_source(
+                request_details,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+            )
+            .await
+            .or_else(|error| match error.current_context() {
+                errors::ConnectorError::WebhookSourceVerificationFailed => {
+                    logger::error!(?error, "Source Verification Failed");
+                    Ok(false)
+                }
+                _ => Err(error),
+            })
+            .switch()
+            .attach_printable("There was an issue in incoming webhook source verification")
+    }
+}
+
+#[instrument(skip_all, fields(connector = %connector_name_or_mca_id))]
 async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
     req_state: ReqState,
@@ -160,7 +215,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
 
     request_details.body = &decoded_body;
 
-    let event_type = match connector
+    let event_type = connector
         .get_webhook_event_type(&request_details)
         .allow_webhook_event_type_not_found(
             state
@@ -172,8 +227,19 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 .unwrap_or(true),
         )
         .switch()
-        .attach_printable("Could not find event type in incoming webhook body")?
-    {
+        .attach_printable("Could not find event type in incoming webhook body")?;
+
+    archive::archive_incoming_webhook(
+        &state,
+        &key_store,
+        merchant_connector_account.as_ref(),
+        &connector_name,
+        event_type,
+        &body,
+    )
+    .await;
+
+    let event_type = match event_type {
         Some(event_type) => event_type,
         // Early return allows us to acknowledge the webhooks that we do not support
         None => {
@@ -223,250 +289,267 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     logger::info!(process_webhook=?process_webhook_further);
 
     let flow_type: api::WebhookFlow = event_type.into();
+    // The permit is held until the webhook is processed, so that the webhooks of the connector
+    // processed at the same time stay within the limit of their lane
+    let _lane_permit = match concurrency::WebhookLane::from_flow(&flow_type) {
+        Some(lane) if process_webhook_further => Some(
+            concurrency::acquire_lane_permit(
+                &state.conf.webhooks.incoming_concurrency,
+                &connector_name,
+                lane,
+            )
+            .await?,
+        ),
+        _ => None,
+    };
     let mut event_object: Box<dyn masking::ErasedMaskSerialize> = Box::new(serde_json::Value::Null);
-    let webhook_effect = if process_webhook_further
-        && !matches!(flow_type, api::WebhookFlow::ReturnResponse)
-    {
-        let object_ref_id = connector
-            .get_webhook_object_reference_id(&request_details)
-            .switch()
-            .attach_printable("Could not find object reference id in incoming webhook body")?;
-        let connector_enum = api_models::enums::Connector::from_str(&connector_name)
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "connector",
-            })
-            .attach_printable_lazy(|| {
-                format!("unable to parse connector name {connector_name:?}")
-            })?;
-        let connectors_with_source_verification_call = &state.conf.webhook_source_verification_call;
-
-        let merchant_connector_account = match merchant_connector_account {
-            Some(merchant_connector_account) => merchant_connector_account,
-            None => {
-                helper_utils::get_mca_from_object_reference_id(
-                    &*state.clone().store,
-                    object_ref_id.clone(),
-                    &merchant_account,
-                    &connector_name,
-                    &key_store,
-                )
-                .await?
-            }
-        };
-
-        let source_verified = if connectors_with_source_verification_call
-            .connectors_with_webhook_source_verification_call
-            .contains(&connector_enum)
-        {
-            connector
-                .verify_webhook_source_verification_call(
-                    &state,
-                    &merchant_account,
-                    merchant_connector_account.clone(),
-                    &connector_name,
-                    &request_details,
-                )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
+    let webhook_effect =
+        if process_webhook_further && !matches!(flow_type, api::WebhookFlow::ReturnResponse) {
+            let object_ref_id = connector
+                .get_webhook_object_reference_id(&request_details)
                 .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        } else {
-            connector
-                .verify_webhook_source(
-                    &request_details,
-                    &merchant_account,
-                    merchant_connector_account.clone(),
-                    connector_name.as_str(),
-                )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
+                .attach_printable("Could not find object reference id in incoming webhook body")?;
+            let connector_enum = api_models::enums::Connector::from_str(&connector_name)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "connector",
                 })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        };
+                .attach_printable_lazy(|| {
+                    format!("unable to parse connector name {connector_name:?}")
+                })?;
+            let merchant_connector_account = match merchant_connector_account {
+                Some(merchant_connector_account) => merchant_connector_account,
+                None => {
+                    helper_utils::get_mca_from_object_reference_id(
+                        &*state.clone().store,
+                        object_ref_id.clone(),
+                        &merchant_account,
+                        &connector_name,
+                        &key_store,
+                    )
+                    .await?
+                }
+            };
 
-        if source_verified {
-            metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
-                &metrics::CONTEXT,
-                1,
-                &[metrics::KeyValue::new(
-                    MERCHANT_ID,
-                    merchant_account.merchant_id.clone(),
-                )],
-            );
-        } else if connector.is_webhook_source_verification_mandatory() {
-            // if webhook consumption is mandatory for connector, fail webhook
-            // so that merchant can retrigger it after updating merchant_secret
-            return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
-        }
+            let mut source_verified = verify_webhook_source(
+                &state,
+                &connector,
+                connector_enum,
+                &connector_name,
+                &merchant_account,
+                merchant_connector_account.clone(),
+                &request_details,
+            )
+            .await?;
+
+            // While the credentials of the connector are rotated, the webhooks signed with the
+            // other credentials are verified with them
+            if !source_verified {
+                if let Some(fallback_merchant_connector_account) =
+                    connector_credentials::get_merchant_connector_account_with_fallback_credentials(
+                        &merchant_connector_account,
+                    )
+                {
+                    source_verified = verify_webhook_source(
+                        &state,
+                        &connector,
+                        connector_enum,
+                        &connector_name,
+                        &merchant_account,
+                        fallback_merchant_connector_account,
+                        &request_details,
+                    )
+                    .await?;
+                    logger::info!(
+                        source_verified,
+                        "Verified the webhook source with the fallback connector credentials"
+                    );
+                }
+            }
 
-        logger::info!(source_verified=?source_verified);
+            if source_verified {
+                metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::KeyValue::new(
+                        MERCHANT_ID,
+                        merchant_account.merchant_id.clone(),
+                    )],
+                );
+            } else if connector.is_webhook_source_verification_mandatory() {
+                // if webhook consumption is mandatory for connector, fail webhook
+                // so that merchant can retrigger it after updating merchant_secret
+                return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
+            }
 
-        event_object = connector
-            .get_webhook_resource_object(&request_details)
-            .switch()
-            .attach_printable("Could not find resource object in incoming webhook body")?;
+            logger::info!(source_verified=?source_verified);
+
+            event_object = connector
+                .get_webhook_resource_object(&request_details)
+                .switch()
+                .attach_printable("Could not find resource object in incoming webhook body")?;
+
+            let webhook_details = api::IncomingWebhookDetails {
+                object_reference_id: object_ref_id.clone(),
+                resource_object: serde_json::to_vec(&event_object)
+                    .change_context(errors::ParsingError::EncodeError("byte-vec"))
+                    .attach_printable("Unable to convert webhook payload to a value")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "There was an issue when encoding the incoming webhook body to bytes",
+                    )?,
+            };
 
-        let webhook_details = api::IncomingWebhookDetails {
-            object_reference_id: object_ref_id.clone(),
-            resource_object: serde_json::to_vec(&event_object)
-                .change_context(errors::ParsingError::EncodeError("byte-vec"))
-                .attach_printable("Unable to convert webhook payload to a value")
+            let profile_id = merchant_connector_account
+                .profile_id
+                .as_ref()
+                .get_required_value("profile_id")
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "There was an issue when encoding the incoming webhook body to bytes",
-                )?,
-        };
+                .attach_printable("Could not find profile_id in merchant connector account")?;
 
-        let profile_id = merchant_connector_account
-            .profile_id
-            .as_ref()
-            .get_required_value("profile_id")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not find profile_id in merchant connector account")?;
+            let business_profile = state
+                .store
+                .find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_string(),
+                })?;
 
-        let business_profile = state
-            .store
-            .find_business_profile_by_profile_id(profile_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-                id: profile_id.to_string(),
-            })?;
+            let webhook_claim = idempotency::claim_incoming_webhook(
+                &state,
+                &merchant_account.merchant_id,
+                &connector_name,
+                request_details.body,
+            )
+            .await?;
 
-        match flow_type {
-            api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            let webhook_effect = match flow_type {
+                // The effects of a webhook delivered again by the connector were already applied
+                _ if webhook_claim.is_replayed() => Ok(WebhookResponseTracker::NoEffect),
 
-            api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                connector_name.as_str(),
-                source_verified,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+                api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payments failed"),
 
-            api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-                &connector,
-                &request_details,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for disputes failed")?,
+                api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    connector_name.as_str(),
+                    source_verified,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for refunds failed"),
 
-            api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming bank-transfer webhook flow failed")?,
+                api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                    &connector,
+                    &request_details,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for disputes failed"),
+
+                api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming bank-transfer webhook flow failed"),
 
-            api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+                api::WebhookFlow::ReturnResponse => Ok(WebhookResponseTracker::NoEffect),
 
-            api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for mandates failed")?,
+                api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for mandates failed"),
 
-            api::WebhookFlow::ExternalAuthentication => {
-                Box::pin(external_authentication_incoming_webhook_flow(
+                api::WebhookFlow::ExternalAuthentication => {
+                    Box::pin(external_authentication_incoming_webhook_flow(
+                        state.clone(),
+                        req_state,
+                        merchant_account,
+                        key_store,
+                        source_verified,
+                        event_type,
+                        &request_details,
+                        &connector,
+                        object_ref_id,
+                        business_profile,
+                        merchant_connector_account,
+                    ))
+                    .await
+                    .attach_printable("Incoming webhook flow for external authentication failed")
+                }
+                api::WebhookFlow::FraudCheck => Box::pin(frm_incoming_webhook_flow(
                     state.clone(),
                     req_state,
                     merchant_account,
                     key_store,
                     source_verified,
                     event_type,
-                    &request_details,
-                    &connector,
                     object_ref_id,
                     business_profile,
-                    merchant_connector_account,
                 ))
                 .await
-                .attach_printable("Incoming webhook flow for external authentication failed")?
-            }
-            api::WebhookFlow::FraudCheck => Box::pin(frm_incoming_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                key_store,
-                source_verified,
-                event_type,
-                object_ref_id,
-                business_profile,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for fraud check failed")?,
+                .attach_printable("Incoming webhook flow for fraud check failed"),
 
-            #[cfg(feature = "payouts")]
-            api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                event_type,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payouts failed")?,
+                #[cfg(feature = "payouts")]
+                api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    event_type,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payouts failed"),
 
-            _ => Err(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
-        }
-    } else {
-        metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
-            &metrics::CONTEXT,
-            1,
-            &[metrics::KeyValue::new(
-                MERCHANT_ID,
-                merchant_account.merchant_id.clone(),
-            )],
-        );
-        WebhookResponseTracker::NoEffect
-    };
+                _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Unsupported Flow Type received in incoming webhooks"),
+            };
+            webhook_claim.finish(&state, webhook_effect.is_ok()).await;
+            webhook_effect?
+        } else {
+            metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new(
+                    MERCHANT_ID,
+                    merchant_account.merchant_id.clone(),
+                )],
+            );
+            WebhookResponseTracker::NoEffect
+        };
 
     let response = connector
         .get_webhook_api_response(&request_details)
@@ -705,6 +788,8 @@ async fn payouts_incoming_webhook_flow(
                 )
             })?;
 
+        ledger::record_payout_entries(&state, &payout_data.payouts, &updated_payout_attempt).await;
+
         let event_type: Option<enums::EventType> = updated_payout_attempt.status.foreign_into();
 
         // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -788,22 +873,44 @@ async fn refunds_incoming_webhook_flow(
     let refund_id = refund.refund_id.to_owned();
     //if source verified then update refund status else trigger refund sync
     let updated_refund = if source_verified {
+        let webhook_refund_status = common_enums::RefundStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed refund status mapping from event type")?;
+        // A webhook that does not move the refund forward has no effects, so that a refund
+        // success delivered again is not applied twice
+        let Some(refund_status) =
+            idempotency::resolve_refund_status(refund.refund_status, webhook_refund_status)
+        else {
+            logger::info!(
+                refund_status = ?refund.refund_status,
+                ?webhook_refund_status,
+                "Refund is not updated by the webhook"
+            );
+            return Ok(WebhookResponseTracker::Refund {
+                payment_id: refund.payment_id,
+                refund_id: refund.refund_id,
+                status: refund.refund_status,
+            });
+        };
         let refund_update = storage::RefundUpdate::StatusUpdate {
             connector_refund_id: None,
             sent_to_gateway: true,
-            refund_status: common_enums::RefundStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed refund status mapping from event type")?,
+            refund_status,
             updated_by: merchant_account.storage_scheme.to_string(),
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating refund: refund_id: {refund_id}")
+            })?;
+        ledger::record_refund_entries(&state, &updated_refund).await;
+        updated_refund
     } else {
         Box::pin(refunds::refund_retrieve_core(
             state.clone(),
@@ -904,7 +1011,7 @@ async fn get_or_update_dispute_object(
             let new_dispute = diesel_models::dispute::DisputeNew {
                 dispute_id,
                 amount: dispute_details.amount.clone(),
-                currency: dispute_details.currency,
+                currency: dispute_details.currency.clone(),
                 dispute_stage: dispute_details.dispute_stage,
                 dispute_status: common_enums::DisputeStatus::foreign_try_from(event_type)
                     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
@@ -913,10 +1020,10 @@ async fn get_or_update_dispute_object(
                 connector: connector_name.to_owned(),
                 attempt_id: payment_attempt.attempt_id.to_owned(),
                 merchant_id: merchant_id.to_owned(),
-                connector_status: dispute_details.connector_status,
-                connector_dispute_id: dispute_details.connector_dispute_id,
-                connector_reason: dispute_details.connector_reason,
-                connector_reason_code: dispute_details.connector_reason_code,
+                connector_status: dispute_details.connector_status.clone(),
+                connector_dispute_id: dispute_details.connector_dispute_id.clone(),
+                connector_reason: dispute_details.connector_reason.clone(),
+                connector_reason_code: dispute_details.connector_reason_code.clone(),
                 challenge_required_by: dispute_details.challenge_required_by,
                 connector_created_at: dispute_details.created_at,
                 connector_updated_at: dispute_details.updated_at,
@@ -925,42 +1032,82 @@ async fn get_or_update_dispute_object(
                 merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
                 dispute_amount: dispute_details.amount.parse::<i64>().unwrap_or(0),
             };
-            state
-                .store
-                .insert_dispute(new_dispute.clone())
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        }
-        Some(dispute) => {
-            logger::info!("Dispute Already exists, Updating the dispute details");
-            metrics::INCOMING_DISPUTE_WEBHOOK_UPDATE_RECORD_METRIC.add(&metrics::CONTEXT, 1, &[]);
-            let dispute_status = diesel_models::enums::DisputeStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("event type to dispute state conversion failure")?;
-            crate::core::utils::validate_dispute_stage_and_dispute_status(
-                dispute.dispute_stage,
-                dispute.dispute_status,
-                dispute_details.dispute_stage,
-                dispute_status,
-            )
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("dispute stage and status validation failed")?;
-            let update_dispute = diesel_models::dispute::DisputeUpdate::Update {
-                dispute_stage: dispute_details.dispute_stage,
-                dispute_status,
-                connector_status: dispute_details.connector_status,
-                connector_reason: dispute_details.connector_reason,
-                connector_reason_code: dispute_details.connector_reason_code,
-                challenge_required_by: dispute_details.challenge_required_by,
-                connector_updated_at: dispute_details.updated_at,
-            };
-            db.update_dispute(dispute, update_dispute)
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            match state.store.insert_dispute(new_dispute).await {
+                Ok(dispute) => Ok(dispute),
+                // The dispute was created by a webhook processed at the same time, so that the
+                // dispute is updated instead of being recorded twice
+                Err(error) if error.current_context().is_db_unique_violation() => {
+                    logger::info!("Dispute was created by another webhook, updating the dispute");
+                    let dispute = db
+                        .find_by_merchant_id_payment_id_connector_dispute_id(
+                            merchant_id,
+                            &payment_attempt.payment_id,
+                            &dispute_details.connector_dispute_id,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?
+                        .get_required_value("dispute")
+                        .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+                    update_dispute_object(db, dispute, dispute_details, event_type).await
+                }
+                Err(error) => Err(error)
+                    .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound),
+            }
         }
+        Some(dispute) => update_dispute_object(db, dispute, dispute_details, event_type).await,
     }
 }
 
+/// Updates the dispute with the details of the webhook. Webhooks are delivered out of order, so
+/// a webhook moving the dispute back to a stage or status it has moved past leaves the dispute as
+/// it is.
+async fn update_dispute_object(
+    db: &dyn StorageInterface,
+    dispute: diesel_models::dispute::Dispute,
+    dispute_details: api::disputes::DisputePayload,
+    event_type: webhooks::IncomingWebhookEvent,
+) -> CustomResult<diesel_models::dispute::Dispute, errors::ApiErrorResponse> {
+    logger::info!("Dispute Already exists, Updating the dispute details");
+    metrics::INCOMING_DISPUTE_WEBHOOK_UPDATE_RECORD_METRIC.add(&metrics::CONTEXT, 1, &[]);
+    let dispute_status = diesel_models::enums::DisputeStatus::foreign_try_from(event_type)
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("event type to dispute state conversion failure")?;
+    if let Err(error) = crate::core::utils::validate_dispute_stage_and_dispute_status(
+        dispute.dispute_stage,
+        dispute.dispute_status,
+        dispute_details.dispute_stage,
+        dispute_status,
+    ) {
+        logger::warn!(
+            ?error,
+            dispute_id = %dispute.dispute_id,
+            dispute_stage = ?dispute.dispute_stage,
+            dispute_status = ?dispute.dispute_status,
+            webhook_dispute_stage = ?dispute_details.dispute_stage,
+            webhook_dispute_status = ?dispute_status,
+            "Ignoring the regression of the dispute reported by the webhook"
+        );
+        metrics::WEBHOOK_INCOMING_STATUS_REGRESSION_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "dispute")]),
+        );
+        return Ok(dispute);
+    }
+    let update_dispute = diesel_models::dispute::DisputeUpdate::Update {
+        dispute_stage: dispute_details.dispute_stage,
+        dispute_status,
+        connector_status: dispute_details.connector_status,
+        connector_reason: dispute_details.connector_reason,
+        connector_reason_code: dispute_details.connector_reason_code,
+        challenge_required_by: dispute_details.challenge_required_by,
+        connector_updated_at: dispute_details.updated_at,
+    };
+    db.update_dispute(dispute, update_dispute)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn external_authentication_incoming_webhook_flow(
@@ -1024,6 +1171,7 @@ async fn external_authentication_incoming_webhook_flow(
                     "received a non-external-authentication id for retrieving authentication",
                 )
             }?;
+        let previous_status = authentication.authentication_status;
         let updated_authentication = state
             .store
             .update_authentication_by_merchant_id_authentication_id(
@@ -1033,6 +1181,12 @@ async fn external_authentication_incoming_webhook_flow(
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error while updating authentication")?;
+        authentication_core::decision::record_authentication_outcome(
+            &state,
+            previous_status,
+            &updated_authentication,
+        )
+        .await;
         // Check if it's a payment authentication flow, payment_id would be there only for payment authentication flows
         if let Some(payment_id) = updated_authentication.payment_id {
             let is_pull_mechanism_enabled = helper_utils::check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(merchant_connector_account.metadata.map(|metadata| metadata.expose()));
@@ -1242,43 +1396,25 @@ async fn frm_incoming_webhook_flow(
                 .await?;
         let payment_response = match event_type {
             webhooks::IncomingWebhookEvent::FrmApproved => {
-                Box::pin(payments::payments_core::<
-                    api::Capture,
-                    api::PaymentsResponse,
-                    _,
-                    _,
-                    _,
-                >(
+                Box::pin(payments::payments_approve_core(
                     state.clone(),
                     req_state,
                     merchant_account.clone(),
                     key_store.clone(),
-                    payments::PaymentApprove,
                     api::PaymentsCaptureRequest {
                         payment_id: payment_attempt.payment_id,
                         amount_to_capture: payment_attempt.amount_to_capture,
                         ..Default::default()
                     },
-                    services::api::AuthFlow::Merchant,
-                    payments::CallConnectorAction::Trigger,
-                    None,
-                    HeaderPayload::default(),
                 ))
                 .await?
             }
             webhooks::IncomingWebhookEvent::FrmRejected => {
-                Box::pin(payments::payments_core::<
-                    api::Void,
-                    api::PaymentsResponse,
-                    _,
-                    _,
-                    _,
-                >(
+                Box::pin(payments::payments_reject_core(
                     state.clone(),
                     req_state,
                     merchant_account.clone(),
                     key_store.clone(),
-                    payments::PaymentReject,
                     api::PaymentsCancelRequest {
                         payment_id: payment_attempt.payment_id.clone(),
                         cancellation_reason: Some(
@@ -1286,10 +1422,6 @@ async fn frm_incoming_webhook_flow(
                         ),
                         ..Default::default()
                     },
-                    services::api::AuthFlow::Merchant,
-                    payments::CallConnectorAction::Trigger,
-                    None,
-                    HeaderPayload::default(),
                 ))
                 .await?
             }
@@ -1367,6 +1499,12 @@ async fn disputes_incoming_webhook_flow(
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        let was_dispute_opened = option_dispute
+            .as_ref()
+            .is_some_and(|dispute| dispute.dispute_status == enums::DisputeStatus::DisputeOpened);
+        let previous_stage_and_status = option_dispute
+            .as_ref()
+            .map(|dispute| (dispute.dispute_stage, dispute.dispute_status));
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute,
@@ -1378,23 +1516,63 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
-        let disputes_response = Box::new(dispute_object.clone().foreign_into());
-        let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
-
-        super::create_event_and_trigger_outgoing_webhook(
-            state,
-            merchant_account,
-            business_profile,
+        // A webhook that does not move the dispute forward has no effects, so that the merchant
+        // is not notified of the same dispute status twice
+        if previous_stage_and_status
+            == Some((dispute_object.dispute_stage, dispute_object.dispute_status))
+        {
+            logger::info!("Dispute stage and status are not updated by the webhook");
+            return Ok(WebhookResponseTracker::Dispute {
+                dispute_id: dispute_object.dispute_id,
+                payment_id: dispute_object.payment_id,
+                status: dispute_object.dispute_status,
+            });
+        }
+        ledger::record_dispute_entries(&state, &dispute_object).await;
+        trigger_dispute_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile.clone(),
             &key_store,
-            event_type,
-            enums::EventClass::Disputes,
-            dispute_object.dispute_id.clone(),
-            enums::EventObjectType::DisputeDetails,
-            api::OutgoingWebhookContent::DisputeDetails(disputes_response),
-            Some(dispute_object.created_at),
+            &dispute_object,
         )
         .await?;
         metrics::INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC.add(&metrics::CONTEXT, 1, &[]);
+
+        let is_dispute_newly_opened = !was_dispute_opened
+            && dispute_object.dispute_stage == enums::DisputeStage::Dispute
+            && dispute_object.dispute_status == enums::DisputeStatus::DisputeOpened;
+        let dispute_object = if is_dispute_newly_opened {
+            match Box::pin(disputes::auto_response::auto_respond_to_dispute(
+                &state,
+                &merchant_account,
+                &key_store,
+                &business_profile,
+                &dispute_object,
+            ))
+            .await
+            {
+                Ok(Some(updated_dispute)) => {
+                    ledger::record_dispute_entries(&state, &updated_dispute).await;
+                    trigger_dispute_outgoing_webhook(
+                        state,
+                        merchant_account,
+                        business_profile,
+                        &key_store,
+                        &updated_dispute,
+                    )
+                    .await?;
+                    updated_dispute
+                }
+                Ok(None) => dispute_object,
+                Err(error) => {
+                    logger::error!(?error, "Failed to respond to the dispute automatically");
+                    dispute_object
+                }
+            }
+        } else {
+            dispute_object
+        };
         Ok(WebhookResponseTracker::Dispute {
             dispute_id: dispute_object.dispute_id,
             payment_id: dispute_object.payment_id,
@@ -1408,6 +1586,31 @@ async fn disputes_incoming_webhook_flow(
     }
 }
 
+async fn trigger_dispute_outgoing_webhook(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let disputes_response = Box::new(dispute.clone().foreign_into());
+    let event_type: enums::EventType = dispute.dispute_status.foreign_into();
+
+    super::create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Disputes,
+        dispute.dispute_id.clone(),
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+        Some(dispute.created_at),
+    )
+    .await
+}
+
 #[instrument(skip_all)]
 async fn bank_transfer_webhook_flow(
     state: SessionState,
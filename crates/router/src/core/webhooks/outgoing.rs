@@ -1,4 +1,6 @@
 use api_models::{
+    admin::WebhookPayloadFilter,
+    alerts::AlertMetric,
     webhook_events::{OutgoingWebhookRequestContent, OutgoingWebhookResponseContent},
     webhooks,
 };
@@ -17,6 +19,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     core::{
+        alerts,
         errors::{self, CustomResult},
         metrics,
     },
@@ -83,13 +86,10 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content = get_outgoing_webhook_request(
-        &merchant_account,
-        outgoing_webhook,
-        business_profile.payment_response_hash_key.as_deref(),
-    )
-    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-    .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content =
+        get_outgoing_webhook_request(&merchant_account, outgoing_webhook, &business_profile)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from((&content, &primary_object_id));
 
@@ -183,7 +183,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
 }
 
 #[allow(clippy::too_many_arguments)]
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(event_id = %event.event_id, ?delivery_attempt))]
 pub(crate) async fn trigger_webhook_and_raise_event(
     state: SessionState,
     business_profile: diesel_models::business_profile::BusinessProfile,
@@ -269,7 +269,13 @@ async fn trigger_webhook_to_merchant(
 
     let response = state
         .api_client
-        .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
+        .send_request(
+            &state,
+            request,
+            Some(OUTGOING_WEBHOOK_TIMEOUT_SECS),
+            None,
+            false,
+        )
         .await;
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
@@ -281,6 +287,13 @@ async fn trigger_webhook_to_merchant(
         )],
     );
     logger::debug!(outgoing_webhook_response=?response);
+    alerts::record_alert_signal(
+        &state,
+        &business_profile.merchant_id,
+        AlertMetric::WebhookFailureRate,
+        None,
+        !matches!(&response, Ok(response) if response.status().is_success()),
+    );
 
     match delivery_attempt {
         enums::WebhookDeliveryAttempt::InitialAttempt => match response {
@@ -546,15 +559,35 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// The payload filter of the profile for the outgoing webhooks of the event type, if any
+fn get_webhook_payload_filter(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event_type: enums::EventType,
+) -> CustomResult<Option<WebhookPayloadFilter>, errors::WebhooksFlowError> {
+    let Some(webhook_details_json) = business_profile.webhook_details.clone() else {
+        return Ok(None);
+    };
+
+    let webhook_details: api::WebhookDetails =
+        webhook_details_json
+            .parse_value("WebhookDetails")
+            .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+
+    Ok(webhook_details
+        .payload_filters
+        .and_then(|mut payload_filters| payload_filters.remove(&event_type)))
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
-    payment_response_hash_key: Option<&str>,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         payment_response_hash_key: Option<&str>,
+        payload_filter: Option<&WebhookPayloadFilter>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![(
             reqwest::header::CONTENT_TYPE.to_string(),
@@ -564,7 +597,7 @@ pub(crate) fn get_outgoing_webhook_request(
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
 
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(payment_response_hash_key, payload_filter)?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
@@ -579,16 +612,24 @@ pub(crate) fn get_outgoing_webhook_request(
         })
     }
 
+    let payment_response_hash_key = business_profile.payment_response_hash_key.as_deref();
+    // The payload is filtered before it is signed, so that the signature covers the payload
+    // delivered to the merchant
+    let payload_filter = get_webhook_payload_filter(business_profile, outgoing_webhook.event_type)?;
+
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(
-            outgoing_webhook, payment_response_hash_key
-        ),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                payment_response_hash_key,
+                payload_filter.as_ref(),
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             payment_response_hash_key,
+            payload_filter.as_ref(),
         ),
     }
 }
@@ -845,6 +886,11 @@ impl ForeignFrom<(&api::OutgoingWebhookContent, &str)> for storage::EventMetadat
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::CustomerRedactionDetails(redaction_response) => {
+                Self::Customer {
+                    customer_id: redaction_response.customer_id.clone(),
+                }
+            }
         }
     }
 }
@@ -889,5 +935,11 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Customer { customer_id } => {
+            OutgoingWebhookEventContent::Customer {
+                customer_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
@@ -1,4 +1,4 @@
-use api_models::webhooks;
+use api_models::{admin::WebhookPayloadFilter, webhooks};
 use common_utils::{crypto::SignMessage, ext_traits::Encode};
 use error_stack::ResultExt;
 use masking::Secret;
@@ -17,6 +17,7 @@ pub trait OutgoingWebhookType:
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        payload_filter: Option<&WebhookPayloadFilter>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
@@ -26,11 +27,10 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        payload_filter: Option<&WebhookPayloadFilter>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
+        let webhook_signature_payload =
+            encode_outgoing_webhook_payload(self, "/content/object", payload_filter)?;
 
         let signature = payment_response_hash_key
             .map(|key| {
@@ -56,6 +56,33 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     }
 }
 
+/// Encodes the payload of an outgoing webhook, with the object found at the JSON pointer provided
+/// filtered with the payload filter of the event type of the webhook
+pub fn encode_outgoing_webhook_payload<T: Serialize + std::fmt::Debug>(
+    webhook: &T,
+    object_pointer: &str,
+    payload_filter: Option<&WebhookPayloadFilter>,
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let Some(payload_filter) = payload_filter else {
+        return webhook
+            .encode_to_string_of_json()
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("failed encoding outgoing webhook payload");
+    };
+
+    let mut payload = webhook
+        .encode_to_value()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("failed encoding outgoing webhook payload")?;
+    if let Some(object) = payload.pointer_mut(object_pointer) {
+        super::utils::filter_webhook_payload_object(object, payload_filter);
+    }
+    payload
+        .encode_to_string_of_json()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("failed encoding filtered outgoing webhook payload")
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: String,
@@ -146,3 +146,79 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+/// The fields identifying the objects sent in outgoing webhooks, which are kept when the payload
+/// of the webhooks of an event type is filtered
+const WEBHOOK_OBJECT_ID_FIELDS: [&str; 6] = [
+    "payment_id",
+    "refund_id",
+    "dispute_id",
+    "mandate_id",
+    "payout_id",
+    "id",
+];
+
+/// Filters the object of an outgoing webhook with the payload filter of its event type, before
+/// the webhook is signed and delivered. Fields that the object does not have are ignored.
+pub(crate) fn filter_webhook_payload_object(
+    object: &mut serde_json::Value,
+    payload_filter: &api_models::admin::WebhookPayloadFilter,
+) {
+    if let Some(include_fields) = &payload_filter.include_fields {
+        let mut filtered_object = serde_json::Value::Object(serde_json::Map::new());
+        for path in include_fields
+            .iter()
+            .map(String::as_str)
+            .chain(WEBHOOK_OBJECT_ID_FIELDS)
+        {
+            copy_webhook_payload_field(object, &mut filtered_object, path);
+        }
+        *object = filtered_object;
+    }
+
+    for path in &payload_filter.exclude_fields {
+        remove_webhook_payload_field(object, path);
+    }
+}
+
+fn copy_webhook_payload_field(
+    source: &serde_json::Value,
+    target: &mut serde_json::Value,
+    path: &str,
+) {
+    let Some(value) = path
+        .split('.')
+        .try_fold(source, |value, segment| value.get(segment))
+    else {
+        return;
+    };
+
+    let mut segments = path.split('.').peekable();
+    let mut target = target;
+    while let Some(segment) = segments.next() {
+        let Some(fields) = target.as_object_mut() else {
+            return;
+        };
+        if segments.peek().is_none() {
+            fields.insert(segment.to_string(), value.clone());
+            return;
+        }
+        target = fields
+            .entry(segment)
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    }
+}
+
+fn remove_webhook_payload_field(object: &mut serde_json::Value, path: &str) {
+    let (parent_path, field) = path.rsplit_once('.').unwrap_or(("", path));
+    let parent = if parent_path.is_empty() {
+        Some(object)
+    } else {
+        parent_path
+            .split('.')
+            .try_fold(object, |value, segment| value.get_mut(segment))
+    };
+    if let Some(fields) = parent.and_then(serde_json::Value::as_object_mut) {
+        fields.remove(field);
+    }
+}
@@ -6,7 +6,10 @@ use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
     services::ApplicationResponse,
-    types::{api, domain, storage, transformers::ForeignTryFrom},
+    types::{
+        api, domain, storage,
+        transformers::{ForeignFrom, ForeignTryFrom},
+    },
     utils::{OptionExt, StringExt},
 };
 
@@ -254,6 +257,96 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+/// Lists the incoming webhooks received for the merchant, or for the business profile, without
+/// their bodies
+#[instrument(skip(state))]
+pub async fn list_incoming_webhooks(
+    state: SessionState,
+    merchant_id_or_profile_id: String,
+    constraints: api::webhook_events::IncomingWebhookListConstraints,
+) -> RouterResponse<Vec<api::webhook_events::IncomingWebhookListItemResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number less than {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT}"
+                ),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let (account, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+    let profile_id = match account {
+        MerchantAccountOrBusinessProfile::MerchantAccount(_) => None,
+        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => {
+            Some(business_profile.profile_id)
+        }
+    };
+
+    let archives = state
+        .store
+        .list_incoming_webhook_archives_by_merchant_id_constraints(
+            &key_store.merchant_id,
+            profile_id,
+            constraints.created_after,
+            constraints.created_before,
+            Some(limit),
+            offset,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list incoming webhooks with specified constraints")?;
+
+    Ok(ApplicationResponse::Json(
+        archives
+            .into_iter()
+            .map(api::webhook_events::IncomingWebhookListItemResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Retrieves an incoming webhook received, with its body decrypted
+#[instrument(skip(state))]
+pub async fn retrieve_incoming_webhook(
+    state: SessionState,
+    merchant_id_or_profile_id: String,
+    archive_id: String,
+) -> RouterResponse<api::webhook_events::IncomingWebhookRetrieveResponse> {
+    let (account, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let archive = state
+        .store
+        .find_incoming_webhook_archive_by_merchant_id_archive_id(
+            &key_store.merchant_id,
+            &archive_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    // The webhooks received for the other business profiles of the merchant are not found
+    if let MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) = account {
+        if archive.business_profile_id.as_ref() != Some(&business_profile.profile_id) {
+            return Err(error_stack::report!(
+                errors::ApiErrorResponse::EventNotFound
+            ));
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::IncomingWebhookRetrieveResponse::foreign_from(archive),
+    ))
+}
+
 async fn determine_identifier_and_get_key_store(
     state: SessionState,
     merchant_id_or_profile_id: String,
@@ -1,14 +1,19 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_event;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
+pub mod bulk_force_sync;
 pub mod business_profile;
 pub mod capture;
+pub mod card_export_batch;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_exchange;
+pub mod connector_migration;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -19,23 +24,34 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod incoming_webhook_archive;
+pub mod job;
 pub mod kafka_store;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod order;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_status_transition;
 pub mod refund;
+pub mod report_template;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod search;
+pub mod settlement_line;
+pub mod tenant;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod vault_cleanup;
+pub mod wallet_decryption_key;
 
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
@@ -65,7 +81,7 @@ pub use crate::{
     },
 };
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum StorageImpl {
     Postgresql,
     PostgresqlTest,
@@ -79,8 +95,11 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + audit_event::AuditEventInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
+    + connector_exchange::ConnectorExchangeInterface
+    + connector_migration::ConnectorMigrationInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
@@ -89,6 +108,9 @@ pub trait StorageInterface:
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + incoming_webhook_archive::IncomingWebhookArchiveInterface
+    + job::JobInterface
+    + ledger_entry::LedgerEntryInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -97,13 +119,19 @@ pub trait StorageInterface:
     + PaymentAttemptInterface
     + PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payment_status_transition::PaymentStatusTransitionInterface
     + blocklist::BlocklistInterface
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface
     + PayoutsInterface
     + refund::RefundInterface
+    + order::OrderInterface
+    + report_template::ReportTemplateInterface
     + reverse_lookup::ReverseLookupInterface
+    + search::SearchInterface
+    + settlement_line::SettlementLineInterface
+    + card_export_batch::CardExportBatchInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
@@ -122,6 +150,9 @@ pub trait StorageInterface:
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
+    + wallet_decryption_key::WalletDecryptionKeyInterface
+    + vault_cleanup::VaultCleanupInterface
+    + bulk_force_sync::BulkForceSyncInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -136,6 +167,7 @@ pub trait GlobalStorageInterface:
     + dyn_clone::DynClone
     + user::UserInterface
     + user_key_store::UserKeyStoreInterface
+    + tenant::TenantInterface
     + 'static
 {
 }
@@ -147,12 +179,21 @@ pub trait CommonStorageInterface: StorageInterface + GlobalStorageInterface {
 
 pub trait MasterKeyInterface {
     fn get_master_key(&self) -> &[u8];
+
+    /// The master key being rotated out, with which the merchant key stores not yet re-encrypted
+    /// with the master key are decrypted
+    fn get_previous_master_key(&self) -> Option<&[u8]>;
 }
 
 impl MasterKeyInterface for Store {
     fn get_master_key(&self) -> &[u8] {
         self.master_key().peek()
     }
+
+    fn get_previous_master_key(&self) -> Option<&[u8]> {
+        self.previous_master_key()
+            .map(|previous_master_key| previous_master_key.peek().as_slice())
+    }
 }
 
 /// Default dummy key for MockDb
@@ -163,6 +204,10 @@ impl MasterKeyInterface for MockDb {
             25, 26, 27, 28, 29, 30, 31, 32,
         ]
     }
+
+    fn get_previous_master_key(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 #[async_trait::async_trait]
@@ -216,6 +261,9 @@ pub trait RequestIdStore {
     fn get_request_id(&self) -> Option<String> {
         None
     }
+    /// The actor the status transitions of the payments updated through the store are recorded
+    /// with
+    fn add_status_actor(&mut self, _status_actor: diesel_models::enums::PaymentStatusActor) {}
 }
 
 impl RequestIdStore for MockDb {}
@@ -228,6 +276,10 @@ impl RequestIdStore for Store {
     fn get_request_id(&self) -> Option<String> {
         self.request_id.clone()
     }
+
+    fn add_status_actor(&mut self, status_actor: diesel_models::enums::PaymentStatusActor) {
+        self.set_status_actor(status_actor)
+    }
 }
 
 pub async fn get_and_deserialize_key<T>(
@@ -253,6 +305,10 @@ impl RequestIdStore for KafkaStore {
     fn add_request_id(&mut self, request_id: String) {
         self.diesel_store.add_request_id(request_id)
     }
+
+    fn add_status_actor(&mut self, status_actor: diesel_models::enums::PaymentStatusActor) {
+        self.diesel_store.add_status_actor(status_actor)
+    }
 }
 
 #[async_trait::async_trait]
@@ -72,6 +72,15 @@ where
         address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    /// Lists the addresses of the merchant, as stored in the database, in the order of their
+    /// address ids, starting after the address id provided
+    async fn list_addresses_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -141,6 +150,24 @@ mod storage {
             .await
         }
 
+        #[instrument(skip_all)]
+        async fn list_addresses_by_merchant_id_starting_after(
+            &self,
+            merchant_id: &str,
+            starting_after: Option<String>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::list_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn update_address(
             &self,
@@ -367,6 +394,24 @@ mod storage {
                 .change_context(errors::StorageError::DecryptionError)
         }
 
+        #[instrument(skip_all)]
+        async fn list_addresses_by_merchant_id_starting_after(
+            &self,
+            merchant_id: &str,
+            starting_after: Option<String>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::list_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn update_address(
             &self,
@@ -674,6 +719,28 @@ impl AddressInterface for MockDb {
         }
     }
 
+    async fn list_addresses_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError> {
+        let mut addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == merchant_id
+                    && starting_after.as_ref() < Some(&address.address_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        addresses.sort_by(|a, b| a.address_id.cmp(&b.address_id));
+        addresses.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(addresses)
+    }
+
     async fn update_address(
         &self,
         address_id: String,
@@ -0,0 +1,67 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{MockDb, ReadQuery};
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+/// The audit events can only be recorded and read, never modified nor deleted
+#[async_trait::async_trait]
+pub trait AuditEventInterface {
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError>;
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: storage::AuditEventConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_audit_event(
+        &self,
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_audit_events_by_constraints(
+        &self,
+        constraints: storage::AuditEventConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::AuditEvent::filter_by_constraints(&conn, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditEventInterface for MockDb {
+    async fn insert_audit_event(
+        &self,
+        _audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_audit_events_by_constraints(
+        &self,
+        _constraints: storage::AuditEventConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
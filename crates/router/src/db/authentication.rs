@@ -121,7 +121,7 @@ impl AuthenticationInterface for MockDb {
             authentication_status: authentication.authentication_status,
             authentication_connector: authentication.authentication_connector,
             connector_authentication_id: authentication.connector_authentication_id,
-            authentication_data: None,
+            authentication_data: authentication.authentication_data,
             payment_method_id: authentication.payment_method_id,
             authentication_type: authentication.authentication_type,
             authentication_lifecycle_status: authentication.authentication_lifecycle_status,
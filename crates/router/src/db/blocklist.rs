@@ -34,10 +34,11 @@ pub trait BlocklistInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_data_kind_list_type(
         &self,
         merchant_id: &str,
         data_kind: common_enums::BlocklistDataKind,
+        list_type: common_enums::BlocklistListType,
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
@@ -81,18 +82,20 @@ impl BlocklistInterface for Store {
     }
 
     #[instrument(skip_all)]
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_data_kind_list_type(
         &self,
         merchant_id: &str,
         data_kind: common_enums::BlocklistDataKind,
+        list_type: common_enums::BlocklistListType,
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
         let conn = connection::pg_connection_write(self).await?;
-        storage::Blocklist::list_by_merchant_id_data_kind(
+        storage::Blocklist::list_by_merchant_id_data_kind_list_type(
             &conn,
             merchant_id,
             data_kind,
+            list_type,
             limit,
             offset,
         )
@@ -138,10 +141,11 @@ impl BlocklistInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_data_kind_list_type(
         &self,
         _merchant_id: &str,
         _data_kind: common_enums::BlocklistDataKind,
+        _list_type: common_enums::BlocklistListType,
         _limit: i64,
         _offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
@@ -190,15 +194,22 @@ impl BlocklistInterface for KafkaStore {
     }
 
     #[instrument(skip_all)]
-    async fn list_blocklist_entries_by_merchant_id_data_kind(
+    async fn list_blocklist_entries_by_merchant_id_data_kind_list_type(
         &self,
         merchant_id: &str,
         data_kind: common_enums::BlocklistDataKind,
+        list_type: common_enums::BlocklistListType,
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
         self.diesel_store
-            .list_blocklist_entries_by_merchant_id_data_kind(merchant_id, data_kind, limit, offset)
+            .list_blocklist_entries_by_merchant_id_data_kind_list_type(
+                merchant_id,
+                data_kind,
+                list_type,
+                limit,
+                offset,
+            )
             .await
     }
 
@@ -0,0 +1,68 @@
+use diesel_models::{enums, payment_attempt::PaymentAttempt};
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{MockDb, ReadQuery};
+use time::PrimitiveDateTime;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{BulkForceSyncDbExt, StuckPaymentAttempt},
+};
+
+#[async_trait::async_trait]
+pub trait BulkForceSyncInterface {
+    #[allow(clippy::too_many_arguments)]
+    async fn list_stuck_payment_attempts(
+        &self,
+        merchant_id: Option<&str>,
+        connectors: &[String],
+        statuses: &[enums::AttemptStatus],
+        modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<StuckPaymentAttempt>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BulkForceSyncInterface for Store {
+    #[instrument(skip_all)]
+    async fn list_stuck_payment_attempts(
+        &self,
+        merchant_id: Option<&str>,
+        connectors: &[String],
+        statuses: &[enums::AttemptStatus],
+        modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<StuckPaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        PaymentAttempt::list_stuck_payment_attempts(
+            &conn,
+            merchant_id,
+            connectors,
+            statuses,
+            modified_before,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl BulkForceSyncInterface for MockDb {
+    async fn list_stuck_payment_attempts(
+        &self,
+        _merchant_id: Option<&str>,
+        _connectors: &[String],
+        _statuses: &[enums::AttemptStatus],
+        _modified_before: PrimitiveDateTime,
+        _starting_after: Option<&str>,
+        _limit: i64,
+    ) -> CustomResult<Vec<StuckPaymentAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,160 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{MockDb, ReadQuery};
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{self, enums, CardExportDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait CardExportBatchInterface {
+    async fn insert_card_export_batch(
+        &self,
+        card_export_batch: storage::CardExportBatchNew,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError>;
+
+    async fn find_card_export_batch_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError>;
+
+    async fn list_card_export_batches_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::CardExportBatch>, errors::StorageError>;
+
+    async fn update_card_export_batch_with_status(
+        &self,
+        card_export_batch: storage::CardExportBatch,
+        expected_status: enums::CardExportStatus,
+        card_export_batch_update: storage::CardExportBatchUpdate,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError>;
+
+    async fn list_card_payment_methods_for_export(
+        &self,
+        merchant_id: &str,
+        payment_method_ids: Option<&[String]>,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CardExportBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_card_export_batch(
+        &self,
+        card_export_batch: storage::CardExportBatchNew,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        card_export_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_card_export_batch_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CardExportBatch::find_by_merchant_id_export_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_card_export_batches_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::CardExportBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::CardExportBatch::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_card_export_batch_with_status(
+        &self,
+        card_export_batch: storage::CardExportBatch,
+        expected_status: enums::CardExportStatus,
+        card_export_batch_update: storage::CardExportBatchUpdate,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        card_export_batch
+            .update_with_status(&conn, expected_status, card_export_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_card_payment_methods_for_export(
+        &self,
+        merchant_id: &str,
+        payment_method_ids: Option<&[String]>,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::PaymentMethod::list_cards_for_export(
+            &conn,
+            merchant_id,
+            payment_method_ids,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardExportBatchInterface for MockDb {
+    async fn insert_card_export_batch(
+        &self,
+        _card_export_batch: storage::CardExportBatchNew,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_card_export_batch_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &str,
+        _export_id: &str,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_card_export_batches_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::CardExportBatch>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_card_export_batch_with_status(
+        &self,
+        _card_export_batch: storage::CardExportBatch,
+        _expected_status: enums::CardExportStatus,
+        _card_export_batch_update: storage::CardExportBatchUpdate,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_card_payment_methods_for_export(
+        &self,
+        _merchant_id: &str,
+        _payment_method_ids: Option<&[String]>,
+        _starting_after: Option<&str>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,105 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+use time::PrimitiveDateTime;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorExchangeInterface {
+    async fn insert_connector_exchange(
+        &self,
+        exchange: storage::ConnectorExchangeNew,
+    ) -> CustomResult<storage::ConnectorExchange, errors::StorageError>;
+
+    /// Finds the exchanges of the payment that have not expired by `now`
+    async fn find_connector_exchanges_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ConnectorExchange>, errors::StorageError>;
+
+    async fn delete_expired_connector_exchanges_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorExchangeInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_exchange(
+        &self,
+        exchange: storage::ConnectorExchangeNew,
+    ) -> CustomResult<storage::ConnectorExchange, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        exchange
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_exchanges_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ConnectorExchange>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorExchange::find_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            now,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_connector_exchanges_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorExchange::delete_expired_by_merchant_id(&conn, merchant_id, now)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorExchangeInterface for MockDb {
+    async fn insert_connector_exchange(
+        &self,
+        _exchange: storage::ConnectorExchangeNew,
+    ) -> CustomResult<storage::ConnectorExchange, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_connector_exchanges_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+        _now: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ConnectorExchange>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_expired_connector_exchanges_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _now: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,188 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{MockDb, ReadQuery};
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorMigrationInterface {
+    async fn insert_connector_migration(
+        &self,
+        connector_migration: storage::ConnectorMigrationNew,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError>;
+
+    async fn find_connector_migration_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &str,
+        migration_id: &str,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError>;
+
+    async fn list_connector_migrations_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorMigration>, errors::StorageError>;
+
+    async fn update_connector_migration_with_status(
+        &self,
+        connector_migration: storage::ConnectorMigration,
+        expected_status: enums::ConnectorMigrationStatus,
+        connector_migration_update: storage::ConnectorMigrationUpdate,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError>;
+
+    async fn insert_connector_migration_payment_method(
+        &self,
+        migration_payment_method: storage::ConnectorMigrationPaymentMethodNew,
+    ) -> CustomResult<storage::ConnectorMigrationPaymentMethod, errors::StorageError>;
+
+    async fn list_connector_migration_payment_methods(
+        &self,
+        merchant_id: &str,
+        migration_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConnectorMigrationPaymentMethod>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorMigrationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_migration(
+        &self,
+        connector_migration: storage::ConnectorMigrationNew,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        connector_migration
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_migration_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &str,
+        migration_id: &str,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorMigration::find_by_merchant_id_migration_id(
+            &conn,
+            merchant_id,
+            migration_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_migrations_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorMigration>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::ConnectorMigration::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_migration_with_status(
+        &self,
+        connector_migration: storage::ConnectorMigration,
+        expected_status: enums::ConnectorMigrationStatus,
+        connector_migration_update: storage::ConnectorMigrationUpdate,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        connector_migration
+            .update_with_status(&conn, expected_status, connector_migration_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_connector_migration_payment_method(
+        &self,
+        migration_payment_method: storage::ConnectorMigrationPaymentMethodNew,
+    ) -> CustomResult<storage::ConnectorMigrationPaymentMethod, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        migration_payment_method
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_migration_payment_methods(
+        &self,
+        merchant_id: &str,
+        migration_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConnectorMigrationPaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::ConnectorMigrationPaymentMethod::list_by_merchant_id_migration_id(
+            &conn,
+            merchant_id,
+            migration_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorMigrationInterface for MockDb {
+    async fn insert_connector_migration(
+        &self,
+        _connector_migration: storage::ConnectorMigrationNew,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_connector_migration_by_merchant_id_migration_id(
+        &self,
+        _merchant_id: &str,
+        _migration_id: &str,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_connector_migrations_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorMigration>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_connector_migration_with_status(
+        &self,
+        _connector_migration: storage::ConnectorMigration,
+        _expected_status: enums::ConnectorMigrationStatus,
+        _connector_migration_update: storage::ConnectorMigrationUpdate,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_connector_migration_payment_method(
+        &self,
+        _migration_payment_method: storage::ConnectorMigrationPaymentMethodNew,
+    ) -> CustomResult<storage::ConnectorMigrationPaymentMethod, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_connector_migration_payment_methods(
+        &self,
+        _merchant_id: &str,
+        _migration_id: &str,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConnectorMigrationPaymentMethod>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
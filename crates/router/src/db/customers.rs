@@ -59,12 +59,45 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
 
+    /// Lists the customers of the merchant whose email or phone number has the blind index
+    /// provided, along with the customers whose email or phone number was never indexed
+    async fn list_customers_by_blind_indexes(
+        &self,
+        merchant_id: &str,
+        email_blind_index: Option<String>,
+        phone_blind_index: Option<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+
+    /// Lists the customers of the merchant in the order of their customer ids, starting after the
+    /// customer id provided. The customers are read from the database, redacted customers
+    /// included.
+    async fn list_customers_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
+
+    async fn get_customer_activity(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &str,
+    ) -> CustomResult<storage_types::CustomerActivity, errors::StorageError>;
+
+    async fn redact_customer_payment_data(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &str,
+    ) -> CustomResult<(), errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -75,8 +108,9 @@ mod storage {
     use futures::future::try_join_all;
     use masking::PeekInterface;
     use router_env::{instrument, tracing};
-    use storage_impl::redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    use storage_impl::{
+        redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+        ReadQuery,
     };
 
     use super::CustomerInterface;
@@ -92,7 +126,10 @@ mod storage {
                 self,
                 behaviour::{Conversion, ReverseConversion},
             },
-            storage::{self as storage_types, enums::MerchantStorageScheme},
+            storage::{
+                self as storage_types, enums::MerchantStorageScheme, CustomerActivityDbExt,
+                CustomerRedactionDbExt,
+            },
         },
         utils::db_utils,
     };
@@ -306,7 +343,7 @@ mod storage {
             merchant_id: &str,
             key_store: &domain::MerchantKeyStore,
         ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
 
             let encrypted_customers =
                 storage_types::Customer::list_by_merchant_id(&conn, merchant_id)
@@ -326,6 +363,70 @@ mod storage {
             Ok(customers)
         }
 
+        #[instrument(skip_all)]
+        async fn list_customers_by_blind_indexes(
+            &self,
+            merchant_id: &str,
+            email_blind_index: Option<String>,
+            phone_blind_index: Option<String>,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_blind_indexes(
+                &conn,
+                merchant_id,
+                email_blind_index,
+                phone_blind_index,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            try_join_all(
+                encrypted_customers
+                    .into_iter()
+                    .map(|encrypted_customer| async {
+                        encrypted_customer
+                            .convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)
+                    }),
+            )
+            .await
+        }
+
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id_starting_after(
+            &self,
+            merchant_id: &str,
+            starting_after: Option<String>,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            try_join_all(
+                encrypted_customers
+                    .into_iter()
+                    .map(|encrypted_customer| async {
+                        encrypted_customer
+                            .convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)
+                    }),
+            )
+            .await
+        }
+
         #[instrument(skip_all)]
         async fn insert_customer(
             &self,
@@ -414,6 +515,30 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn get_customer_activity(
+            &self,
+            customer_id: &id_type::CustomerId,
+            merchant_id: &str,
+        ) -> CustomResult<storage_types::CustomerActivity, errors::StorageError> {
+            let conn = connection::pg_connection_read_for(self, ReadQuery::Analytics).await?;
+            storage_types::Customer::get_activity(&conn, merchant_id, customer_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn redact_customer_payment_data(
+            &self,
+            customer_id: &id_type::CustomerId,
+            merchant_id: &str,
+        ) -> CustomResult<(), errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Customer::redact_payment_data(&conn, merchant_id, customer_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -424,6 +549,7 @@ mod storage {
     use futures::future::try_join_all;
     use masking::PeekInterface;
     use router_env::{instrument, tracing};
+    use storage_impl::ReadQuery;
 
     use super::CustomerInterface;
     use crate::{
@@ -438,7 +564,10 @@ mod storage {
                 self,
                 behaviour::{Conversion, ReverseConversion},
             },
-            storage::{self as storage_types, enums::MerchantStorageScheme},
+            storage::{
+                self as storage_types, enums::MerchantStorageScheme, CustomerActivityDbExt,
+                CustomerRedactionDbExt,
+            },
         },
     };
 
@@ -544,7 +673,7 @@ mod storage {
             merchant_id: &str,
             key_store: &domain::MerchantKeyStore,
         ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
 
             let encrypted_customers =
                 storage_types::Customer::list_by_merchant_id(&conn, merchant_id)
@@ -564,6 +693,70 @@ mod storage {
             Ok(customers)
         }
 
+        #[instrument(skip_all)]
+        async fn list_customers_by_blind_indexes(
+            &self,
+            merchant_id: &str,
+            email_blind_index: Option<String>,
+            phone_blind_index: Option<String>,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_blind_indexes(
+                &conn,
+                merchant_id,
+                email_blind_index,
+                phone_blind_index,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            try_join_all(
+                encrypted_customers
+                    .into_iter()
+                    .map(|encrypted_customer| async {
+                        encrypted_customer
+                            .convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)
+                    }),
+            )
+            .await
+        }
+
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id_starting_after(
+            &self,
+            merchant_id: &str,
+            starting_after: Option<String>,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            try_join_all(
+                encrypted_customers
+                    .into_iter()
+                    .map(|encrypted_customer| async {
+                        encrypted_customer
+                            .convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)
+                    }),
+            )
+            .await
+        }
+
         #[instrument(skip_all)]
         async fn insert_customer(
             &self,
@@ -602,6 +795,30 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn get_customer_activity(
+            &self,
+            customer_id: &id_type::CustomerId,
+            merchant_id: &str,
+        ) -> CustomResult<storage_types::CustomerActivity, errors::StorageError> {
+            let conn = connection::pg_connection_read_for(self, ReadQuery::Analytics).await?;
+            storage_types::Customer::get_activity(&conn, merchant_id, customer_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn redact_customer_payment_data(
+            &self,
+            customer_id: &id_type::CustomerId,
+            merchant_id: &str,
+        ) -> CustomResult<(), errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Customer::redact_payment_data(&conn, merchant_id, customer_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -656,6 +873,72 @@ impl CustomerInterface for MockDb {
         Ok(customers)
     }
 
+    async fn list_customers_by_blind_indexes(
+        &self,
+        merchant_id: &str,
+        email_blind_index: Option<String>,
+        phone_blind_index: Option<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+
+        try_join_all(
+            customers
+                .iter()
+                .filter(|customer| {
+                    customer.merchant_id == merchant_id
+                        && ((email_blind_index.is_some()
+                            && customer.email_blind_index == email_blind_index)
+                            || (phone_blind_index.is_some()
+                                && customer.phone_blind_index == phone_blind_index)
+                            || (customer.email.is_some() && customer.email_blind_index.is_none())
+                            || (customer.phone.is_some() && customer.phone_blind_index.is_none()))
+                })
+                .map(|customer| async {
+                    customer
+                        .to_owned()
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                }),
+        )
+        .await
+    }
+
+    async fn list_customers_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let mut customers = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id
+                    && starting_after.as_deref() < Some(customer.customer_id.get_string_repr())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        customers.sort_by(|a, b| {
+            a.customer_id
+                .get_string_repr()
+                .cmp(b.customer_id.get_string_repr())
+        });
+        customers.truncate(usize::try_from(limit).unwrap_or(0));
+
+        try_join_all(customers.into_iter().map(|customer| async {
+            customer
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }))
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
@@ -710,4 +993,20 @@ impl CustomerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn get_customer_activity(
+        &self,
+        _customer_id: &id_type::CustomerId,
+        _merchant_id: &str,
+    ) -> CustomResult<storage_types::CustomerActivity, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn redact_customer_payment_data(
+        &self,
+        _customer_id: &id_type::CustomerId,
+        _merchant_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -1,5 +1,6 @@
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
+use storage_impl::ReadQuery;
 
 use super::{MockDb, Store};
 use crate::{
@@ -97,7 +98,7 @@ impl DisputeInterface for Store {
         merchant_id: &str,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
         storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
@@ -272,6 +273,21 @@ impl DisputeInterface for MockDb {
                         .as_ref()
                         .map(|received_time_gte| received_time_gte <= &d.created_at)
                         .unwrap_or(true)
+                    && dispute_constraints
+                        .dispute_amount_gte
+                        .map(|dispute_amount_gte| dispute_amount_gte <= d.dispute_amount)
+                        .unwrap_or(true)
+                    && dispute_constraints
+                        .dispute_amount_lte
+                        .map(|dispute_amount_lte| dispute_amount_lte >= d.dispute_amount)
+                        .unwrap_or(true)
+                    && dispute_constraints
+                        .cursor
+                        .as_ref()
+                        .map(|cursor| {
+                            (d.created_at, &d.dispute_id) < (cursor.created_at, &cursor.id)
+                        })
+                        .unwrap_or(true)
             })
             .take(
                 dispute_constraints
@@ -565,6 +581,9 @@ mod tests {
                         received_time_lte: None,
                         received_time_gte: None,
                         profile_id: None,
+                        dispute_amount_gte: None,
+                        dispute_amount_lte: None,
+                        cursor: None,
                     },
                 )
                 .await
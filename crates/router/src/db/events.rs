@@ -89,6 +89,16 @@ where
         event: domain::EventUpdate,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError>;
+
+    /// Lists the events of the merchant, delivery attempts included, in the order of their event
+    /// ids, starting after the event id provided
+    async fn list_events_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -332,6 +342,38 @@ impl EventInterface for Store {
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
+
+    #[instrument(skip_all)]
+    async fn list_events_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_by_merchant_id_starting_after(
+            &conn,
+            merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(merchant_key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -653,6 +695,14 @@ impl EventInterface for MockDb {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
             }
+            domain::EventUpdate::EncryptedDataUpdate { request, response } => {
+                if let Some(request) = request {
+                    event_to_update.request = Some(request.into());
+                }
+                if let Some(response) = response {
+                    event_to_update.response = Some(response.into());
+                }
+            }
         }
 
         event_to_update
@@ -661,6 +711,37 @@ impl EventInterface for MockDb {
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
+
+    async fn list_events_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && starting_after.as_ref() < Some(&event.event_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| a.event_id.cmp(&b.event_id));
+        events.truncate(usize::try_from(limit).unwrap_or(0));
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events {
+            let domain_event = event
+                .convert(merchant_key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
 }
 
 #[cfg(test)]
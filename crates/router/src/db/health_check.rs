@@ -2,6 +2,7 @@ use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
 use diesel_models::ConfigNew;
 use error_stack::ResultExt;
 use router_env::{instrument, logger, tracing};
+use storage_impl::{database::store::get_replication_lag, DatabaseStore};
 
 use super::{MockDb, Store};
 use crate::{
@@ -13,6 +14,11 @@ use crate::{
 #[async_trait::async_trait]
 pub trait HealthCheckDbInterface {
     async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError>;
+
+    /// Replication lag of the read replica in seconds, `None` when there is no read replica
+    async fn health_check_db_replica(
+        &self,
+    ) -> CustomResult<Option<f64>, errors::HealthCheckDBError>;
 }
 
 #[async_trait::async_trait]
@@ -59,6 +65,20 @@ impl HealthCheckDbInterface for Store {
 
         Ok(())
     }
+
+    #[instrument(skip_all)]
+    async fn health_check_db_replica(
+        &self,
+    ) -> CustomResult<Option<f64>, errors::HealthCheckDBError> {
+        if self.get_replica_routing().is_none() {
+            return Ok(None);
+        }
+
+        get_replication_lag(self.get_replica_pool())
+            .await
+            .map(Some)
+            .change_context(errors::HealthCheckDBError::DBReadError)
+    }
 }
 
 #[async_trait::async_trait]
@@ -66,4 +86,10 @@ impl HealthCheckDbInterface for MockDb {
     async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
         Ok(())
     }
+
+    async fn health_check_db_replica(
+        &self,
+    ) -> CustomResult<Option<f64>, errors::HealthCheckDBError> {
+        Ok(None)
+    }
 }
@@ -0,0 +1,251 @@
+use common_utils::ext_traits::AsyncExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookArchiveInterface
+where
+    domain::IncomingWebhookArchive: Conversion<
+        DstType = storage::IncomingWebhookArchive,
+        NewDstType = storage::IncomingWebhookArchiveNew,
+    >,
+{
+    async fn insert_incoming_webhook_archive(
+        &self,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError>;
+
+    async fn find_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        merchant_id: &str,
+        archive_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError>;
+
+    /// Lists the webhooks received for the merchant, from the latest received, only those of the
+    /// business profile when one is provided
+    #[allow(clippy::too_many_arguments)]
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError>;
+
+    /// Lists the webhooks received for the merchant in the order of their archive ids, starting
+    /// after the archive id provided
+    async fn list_incoming_webhook_archives_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError>;
+
+    async fn update_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        merchant_id: &str,
+        archive_id: &str,
+        archive_update: domain::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError>;
+}
+
+async fn convert_archives(
+    archives: Vec<storage::IncomingWebhookArchive>,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+    let mut domain_archives = Vec::with_capacity(archives.len());
+    for archive in archives.into_iter() {
+        domain_archives.push(
+            archive
+                .convert(merchant_key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?,
+        );
+    }
+    Ok(domain_archives)
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookArchiveInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_archive(
+        &self,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        archive
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        merchant_id: &str,
+        archive_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookArchive::find_by_merchant_id_archive_id(
+            &conn,
+            merchant_id,
+            archive_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookArchive::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|archives| convert_archives(archives, merchant_key_store))
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_archives_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookArchive::list_by_merchant_id_starting_after(
+            &conn,
+            merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|archives| convert_archives(archives, merchant_key_store))
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        merchant_id: &str,
+        archive_id: &str,
+        archive_update: domain::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IncomingWebhookArchive::update_by_merchant_id_archive_id(
+            &conn,
+            merchant_id,
+            archive_id,
+            archive_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookArchiveInterface for MockDb {
+    async fn insert_incoming_webhook_archive(
+        &self,
+        _archive: domain::IncomingWebhookArchive,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        _merchant_id: &str,
+        _archive_id: &str,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &str,
+        _profile_id: Option<String>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_incoming_webhook_archives_by_merchant_id_starting_after(
+        &self,
+        _merchant_id: &str,
+        _starting_after: Option<String>,
+        _limit: i64,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        _merchant_id: &str,
+        _archive_id: &str,
+        _archive_update: domain::IncomingWebhookArchiveUpdate,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
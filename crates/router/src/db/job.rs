@@ -0,0 +1,97 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait JobInterface {
+    async fn insert_job(
+        &self,
+        job: storage::JobNew,
+    ) -> CustomResult<storage::Job, errors::StorageError>;
+
+    async fn find_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &str,
+        job_id: &str,
+    ) -> CustomResult<storage::Job, errors::StorageError>;
+
+    async fn update_job_with_status(
+        &self,
+        job: storage::Job,
+        expected_statuses: Vec<enums::JobStatus>,
+        job_update: storage::JobUpdate,
+    ) -> CustomResult<storage::Job, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl JobInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_job(
+        &self,
+        job: storage::JobNew,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        job.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &str,
+        job_id: &str,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Job::find_by_merchant_id_job_id(&conn, merchant_id, job_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_job_with_status(
+        &self,
+        job: storage::Job,
+        expected_statuses: Vec<enums::JobStatus>,
+        job_update: storage::JobUpdate,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        job.update_with_status(&conn, expected_statuses, job_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl JobInterface for MockDb {
+    async fn insert_job(
+        &self,
+        _job: storage::JobNew,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &str,
+        _job_id: &str,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_job_with_status(
+        &self,
+        _job: storage::Job,
+        _expected_statuses: Vec<enums::JobStatus>,
+        _job_update: storage::JobUpdate,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
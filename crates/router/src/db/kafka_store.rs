@@ -32,6 +32,7 @@ use time::PrimitiveDateTime;
 use super::{
     dashboard_metadata::DashboardMetadataInterface,
     role::RoleInterface,
+    tenant::TenantInterface,
     user::{sample_data::BatchSampleDataInterface, UserInterface},
     user_authentication_method::UserAuthenticationMethodInterface,
     user_key_store::UserKeyStoreInterface,
@@ -44,12 +45,17 @@ use crate::{
     db::{
         address::AddressInterface,
         api_keys::ApiKeyInterface,
+        audit_event::AuditEventInterface,
         authentication::AuthenticationInterface,
         authorization::AuthorizationInterface,
+        bulk_force_sync::BulkForceSyncInterface,
         business_profile::BusinessProfileInterface,
         capture::CaptureInterface,
+        card_export_batch::CardExportBatchInterface,
         cards_info::CardsInfoInterface,
         configs::ConfigInterface,
+        connector_exchange::ConnectorExchangeInterface,
+        connector_migration::ConnectorMigrationInterface,
         customers::CustomerInterface,
         dispute::DisputeInterface,
         ephemeral_key::EphemeralKeyInterface,
@@ -58,18 +64,29 @@ use crate::{
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        incoming_webhook_archive::IncomingWebhookArchiveInterface,
+        job::JobInterface,
+        ledger_entry::LedgerEntryInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
         merchant_connector_account::{ConnectorAccessToken, MerchantConnectorAccountInterface},
         merchant_key_store::MerchantKeyStoreInterface,
+        order::OrderInterface,
         payment_link::PaymentLinkInterface,
         payment_method::PaymentMethodInterface,
+        payment_status_transition::PaymentStatusTransitionInterface,
         refund::RefundInterface,
+        report_template::ReportTemplateInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        search::SearchInterface,
+        settlement_line::SettlementLineInterface,
+        vault_cleanup::VaultCleanupInterface,
+        wallet_decryption_key::WalletDecryptionKeyInterface,
         CommonStorageInterface, GlobalStorageInterface, MasterKeyInterface, StorageInterface,
     },
+    events::domain_events::{DomainEvent, DomainEventsHandler},
     services::{authentication, kafka::KafkaProducer, Store},
     types::{
         domain,
@@ -86,14 +103,27 @@ pub struct KafkaStore {
     kafka_producer: KafkaProducer,
     pub diesel_store: Store,
     pub tenant_id: TenantID,
+    domain_events: Option<DomainEventsHandler>,
 }
 
 impl KafkaStore {
-    pub async fn new(store: Store, kafka_producer: KafkaProducer, tenant_id: TenantID) -> Self {
+    pub async fn new(
+        store: Store,
+        kafka_producer: KafkaProducer,
+        tenant_id: TenantID,
+        domain_events: Option<DomainEventsHandler>,
+    ) -> Self {
         Self {
             kafka_producer,
             diesel_store: store,
             tenant_id,
+            domain_events,
+        }
+    }
+
+    fn publish_domain_event(&self, event: Option<DomainEvent>) {
+        if let Some((domain_events, event)) = self.domain_events.as_ref().zip(event) {
+            domain_events.publish(event);
         }
     }
 }
@@ -186,6 +216,17 @@ impl AddressInterface for KafkaStore {
             .update_address_by_merchant_id_customer_id(customer_id, merchant_id, address, key_store)
             .await
     }
+
+    async fn list_addresses_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        self.diesel_store
+            .list_addresses_by_merchant_id_starting_after(merchant_id, starting_after, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -378,6 +419,60 @@ impl CustomerInterface for KafkaStore {
             .await
     }
 
+    async fn list_customers_by_blind_indexes(
+        &self,
+        merchant_id: &str,
+        email_blind_index: Option<String>,
+        phone_blind_index: Option<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_customers_by_blind_indexes(
+                merchant_id,
+                email_blind_index,
+                phone_blind_index,
+                key_store,
+            )
+            .await
+    }
+
+    async fn list_customers_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_customers_by_merchant_id_starting_after(
+                merchant_id,
+                starting_after,
+                limit,
+                key_store,
+            )
+            .await
+    }
+
+    async fn get_customer_activity(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &str,
+    ) -> CustomResult<storage::CustomerActivity, errors::StorageError> {
+        self.diesel_store
+            .get_customer_activity(customer_id, merchant_id)
+            .await
+    }
+
+    async fn redact_customer_payment_data(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .redact_customer_payment_data(customer_id, merchant_id)
+            .await
+    }
+
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &id_type::CustomerId,
@@ -414,6 +509,7 @@ impl DisputeInterface for KafkaStore {
         dispute_new: storage::DisputeNew,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let dispute = self.diesel_store.insert_dispute(dispute_new).await?;
+        self.publish_domain_event(DomainEvent::from_dispute(None, &dispute));
 
         if let Err(er) = self
             .kafka_producer
@@ -470,6 +566,7 @@ impl DisputeInterface for KafkaStore {
             .diesel_store
             .update_dispute(this.clone(), dispute)
             .await?;
+        self.publish_domain_event(DomainEvent::from_dispute(Some(&this), &dispute_new));
         if let Err(er) = self
             .kafka_producer
             .log_dispute(&dispute_new, Some(this), self.tenant_id.clone())
@@ -651,6 +748,23 @@ impl EventInterface for KafkaStore {
             .update_event_by_merchant_id_event_id(merchant_id, event_id, event, merchant_key_store)
             .await
     }
+
+    async fn list_events_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_events_by_merchant_id_starting_after(
+                merchant_id,
+                starting_after,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -867,6 +981,10 @@ impl MerchantAccountInterface for KafkaStore {
             .await
     }
 
+    async fn get_merchant_accounts_count(&self) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store.get_merchant_accounts_count().await
+    }
+
     #[cfg(feature = "olap")]
     async fn list_multiple_merchant_accounts(
         &self,
@@ -1040,6 +1158,16 @@ impl MerchantConnectorAccountInterface for KafkaStore {
             .await
     }
 
+    async fn find_merchant_connector_accounts_for_cgraph(
+        &self,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError> {
+        self.diesel_store
+            .find_merchant_connector_accounts_for_cgraph(merchant_id, key_store)
+            .await
+    }
+
     async fn update_merchant_connector_account(
         &self,
         this: domain::MerchantConnectorAccount,
@@ -1294,6 +1422,7 @@ impl PaymentAttemptInterface for KafkaStore {
         payment_method_type: Option<Vec<common_enums::PaymentMethodType>>,
         authentication_type: Option<Vec<common_enums::AuthenticationType>>,
         merchant_connector_id: Option<Vec<String>>,
+        card_network: Option<Vec<common_enums::CardNetwork>>,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::DataStorageError> {
         self.diesel_store
@@ -1305,6 +1434,7 @@ impl PaymentAttemptInterface for KafkaStore {
                 payment_method_type,
                 authentication_type,
                 merchant_connector_id,
+                card_network,
                 storage_scheme,
             )
             .await
@@ -1335,6 +1465,7 @@ impl PaymentIntentInterface for KafkaStore {
             .diesel_store
             .update_payment_intent(this.clone(), payment_intent, key_store, storage_scheme)
             .await?;
+        self.publish_domain_event(DomainEvent::from_payment_intent(Some(&this), &intent));
 
         if let Err(er) = self
             .kafka_producer
@@ -1358,6 +1489,7 @@ impl PaymentIntentInterface for KafkaStore {
             .diesel_store
             .insert_payment_intent(new, key_store, storage_scheme)
             .await?;
+        self.publish_domain_event(DomainEvent::from_payment_intent(None, &intent));
 
         if let Err(er) = self
             .kafka_producer
@@ -1566,6 +1698,17 @@ impl PaymentMethodInterface for KafkaStore {
             .delete_payment_method_by_merchant_id_payment_method_id(merchant_id, payment_method_id)
             .await
     }
+
+    async fn list_payment_methods_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_methods_by_merchant_id_starting_after(merchant_id, starting_after, limit)
+            .await
+    }
 }
 
 #[cfg(not(feature = "payouts"))]
@@ -1858,6 +2001,59 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        runner: Option<String>,
+        statuses: Option<Vec<ProcessTrackerStatus>>,
+        business_statuses: Option<Vec<String>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_merchant_id_constraints(
+                merchant_id,
+                runner,
+                statuses,
+                business_statuses,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    async fn find_earliest_process_schedule_time(
+        &self,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        statuses: Vec<ProcessTrackerStatus>,
+    ) -> CustomResult<Option<PrimitiveDateTime>, errors::StorageError> {
+        self.diesel_store
+            .find_earliest_process_schedule_time(time_lower_limit, time_upper_limit, statuses)
+            .await
+    }
+
+    async fn count_processes_by_runners_statuses(
+        &self,
+        runners: Vec<String>,
+        statuses: Vec<ProcessTrackerStatus>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_processes_by_runners_statuses(runners, statuses)
+            .await
+    }
+
+    async fn update_process_with_status(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .update_process_with_status(this, statuses, process)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1967,6 +2163,7 @@ impl RefundInterface for KafkaStore {
             .diesel_store
             .update_refund(this.clone(), refund, storage_scheme)
             .await?;
+        self.publish_domain_event(DomainEvent::from_refund(Some(&this), &refund));
 
         if let Err(er) = self
             .kafka_producer
@@ -1999,6 +2196,7 @@ impl RefundInterface for KafkaStore {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Refund, errors::StorageError> {
         let refund = self.diesel_store.insert_refund(new, storage_scheme).await?;
+        self.publish_domain_event(DomainEvent::from_refund(None, &refund));
 
         if let Err(er) = self
             .kafka_producer
@@ -2077,6 +2275,16 @@ impl MerchantKeyStoreInterface for KafkaStore {
             .await
     }
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_store(merchant_key_store, key)
+            .await
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -2086,6 +2294,16 @@ impl MerchantKeyStoreInterface for KafkaStore {
             .await
     }
 
+    async fn list_merchant_key_store_ids(
+        &self,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        self.diesel_store
+            .list_merchant_key_store_ids(starting_after, limit)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn list_multiple_key_stores(
         &self,
@@ -2263,159 +2481,421 @@ impl RoutingAlgorithmInterface for KafkaStore {
 }
 
 #[async_trait::async_trait]
-impl GsmInterface for KafkaStore {
-    async fn add_gsm_rule(
+impl OrderInterface for KafkaStore {
+    async fn insert_order(
         &self,
-        rule: storage::GatewayStatusMappingNew,
-    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        self.diesel_store.add_gsm_rule(rule).await
+        order: storage::OrderNew,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        self.diesel_store.insert_order(order).await
     }
 
-    async fn find_gsm_decision(
+    async fn find_order_by_merchant_id_order_id(
         &self,
-        connector: String,
-        flow: String,
-        sub_flow: String,
-        code: String,
-        message: String,
-    ) -> CustomResult<String, errors::StorageError> {
+        merchant_id: &str,
+        order_id: &str,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
         self.diesel_store
-            .find_gsm_decision(connector, flow, sub_flow, code, message)
+            .find_order_by_merchant_id_order_id(merchant_id, order_id)
             .await
     }
 
-    async fn find_gsm_rule(
+    async fn update_order(
         &self,
-        connector: String,
-        flow: String,
-        sub_flow: String,
-        code: String,
-        message: String,
-    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        self.diesel_store
-            .find_gsm_rule(connector, flow, sub_flow, code, message)
-            .await
+        order: storage::Order,
+        order_update: storage::OrderUpdate,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        self.diesel_store.update_order(order, order_update).await
     }
+}
 
-    async fn update_gsm_rule(
+#[async_trait::async_trait]
+impl ReportTemplateInterface for KafkaStore {
+    async fn insert_report_template(
         &self,
-        connector: String,
-        flow: String,
-        sub_flow: String,
-        code: String,
-        message: String,
-        data: storage::GatewayStatusMappingUpdate,
-    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+        report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
         self.diesel_store
-            .update_gsm_rule(connector, flow, sub_flow, code, message, data)
+            .insert_report_template(report_template)
             .await
     }
 
-    async fn delete_gsm_rule(
+    async fn find_report_template_by_merchant_id_template_id(
         &self,
-        connector: String,
-        flow: String,
-        sub_flow: String,
-        code: String,
-        message: String,
-    ) -> CustomResult<bool, errors::StorageError> {
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
         self.diesel_store
-            .delete_gsm_rule(connector, flow, sub_flow, code, message)
+            .find_report_template_by_merchant_id_template_id(merchant_id, template_id)
             .await
     }
-}
 
-#[async_trait::async_trait]
-impl StorageInterface for KafkaStore {
-    fn get_scheduler_db(&self) -> Box<dyn SchedulerInterface> {
-        Box::new(self.clone())
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_report_templates_by_merchant_id(merchant_id)
+            .await
     }
 
-    fn get_cache_store(&self) -> Box<(dyn RedisConnInterface + Send + Sync + 'static)> {
-        Box::new(self.clone())
+    async fn update_report_template(
+        &self,
+        report_template: storage::ReportTemplate,
+        report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        self.diesel_store
+            .update_report_template(report_template, report_template_update)
+            .await
     }
-}
-
-impl GlobalStorageInterface for KafkaStore {}
 
-impl CommonStorageInterface for KafkaStore {
-    fn get_storage_interface(&self) -> Box<dyn StorageInterface> {
-        Box::new(self.clone())
-    }
-    fn get_global_storage_interface(&self) -> Box<dyn GlobalStorageInterface> {
-        Box::new(self.clone())
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        self.diesel_store
+            .delete_report_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
     }
 }
 
 #[async_trait::async_trait]
-impl SchedulerInterface for KafkaStore {}
-
-impl MasterKeyInterface for KafkaStore {
-    fn get_master_key(&self) -> &[u8] {
-        self.diesel_store.get_master_key()
-    }
-}
-#[async_trait::async_trait]
-impl UserInterface for KafkaStore {
-    async fn insert_user(
+impl AuditEventInterface for KafkaStore {
+    async fn insert_audit_event(
         &self,
-        user_data: storage::UserNew,
-    ) -> CustomResult<storage::User, errors::StorageError> {
-        self.diesel_store.insert_user(user_data).await
+        audit_event: storage::AuditEventNew,
+    ) -> CustomResult<storage::AuditEvent, errors::StorageError> {
+        self.diesel_store.insert_audit_event(audit_event).await
     }
 
-    async fn find_user_by_email(
+    async fn filter_audit_events_by_constraints(
         &self,
-        user_email: &pii::Email,
-    ) -> CustomResult<storage::User, errors::StorageError> {
-        self.diesel_store.find_user_by_email(user_email).await
+        constraints: storage::AuditEventConstraints,
+    ) -> CustomResult<Vec<storage::AuditEvent>, errors::StorageError> {
+        self.diesel_store
+            .filter_audit_events_by_constraints(constraints)
+            .await
     }
+}
 
-    async fn find_user_by_id(
+#[async_trait::async_trait]
+impl ConnectorExchangeInterface for KafkaStore {
+    async fn insert_connector_exchange(
         &self,
-        user_id: &str,
-    ) -> CustomResult<storage::User, errors::StorageError> {
-        self.diesel_store.find_user_by_id(user_id).await
+        exchange: storage::ConnectorExchangeNew,
+    ) -> CustomResult<storage::ConnectorExchange, errors::StorageError> {
+        self.diesel_store.insert_connector_exchange(exchange).await
     }
 
-    async fn update_user_by_user_id(
+    async fn find_connector_exchanges_by_merchant_id_payment_id(
         &self,
-        user_id: &str,
-        user: storage::UserUpdate,
-    ) -> CustomResult<storage::User, errors::StorageError> {
+        merchant_id: &str,
+        payment_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ConnectorExchange>, errors::StorageError> {
         self.diesel_store
-            .update_user_by_user_id(user_id, user)
+            .find_connector_exchanges_by_merchant_id_payment_id(merchant_id, payment_id, now)
             .await
     }
 
-    async fn update_user_by_email(
+    async fn delete_expired_connector_exchanges_by_merchant_id(
         &self,
-        user_email: &pii::Email,
-        user: storage::UserUpdate,
-    ) -> CustomResult<storage::User, errors::StorageError> {
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
         self.diesel_store
-            .update_user_by_email(user_email, user)
+            .delete_expired_connector_exchanges_by_merchant_id(merchant_id, now)
             .await
     }
+}
 
-    async fn delete_user_by_user_id(
+#[async_trait::async_trait]
+impl LedgerEntryInterface for KafkaStore {
+    async fn insert_ledger_transaction(
         &self,
-        user_id: &str,
-    ) -> CustomResult<bool, errors::StorageError> {
-        self.diesel_store.delete_user_by_user_id(user_id).await
+        entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store.insert_ledger_transaction(entries).await
     }
 
-    async fn find_users_by_user_ids(
+    async fn find_ledger_entries_by_merchant_id_payment_id(
         &self,
-        user_ids: Vec<String>,
-    ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
-        self.diesel_store.find_users_by_user_ids(user_ids).await
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_ledger_entries_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
     }
-}
 
-impl RedisConnInterface for KafkaStore {
-    fn get_redis_conn(&self) -> CustomResult<Arc<RedisConnectionPool>, RedisError> {
-        self.diesel_store.get_redis_conn()
+    async fn filter_ledger_entries_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        merchant_connector_id: Option<String>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .filter_ledger_entries_by_merchant_id_constraints(
+                merchant_id,
+                merchant_connector_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementLineInterface for KafkaStore {
+    async fn insert_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        self.diesel_store
+            .insert_settlement_line(settlement_line)
+            .await
+    }
+
+    async fn find_settlement_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &str,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_line_by_merchant_id_line_id(merchant_id, line_id)
+            .await
+    }
+
+    async fn find_settlement_lines_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_lines_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_lines_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    async fn find_settlement_lines_by_merchant_id_connector_reference_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        line_type: enums::SettlementLineType,
+        reference_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_lines_by_merchant_id_connector_reference_id(
+                merchant_id,
+                connector,
+                line_type,
+                reference_id,
+            )
+            .await
+    }
+
+    async fn filter_settlement_lines_by_merchant_id_recon_status(
+        &self,
+        merchant_id: &str,
+        recon_status: Vec<enums::SettlementReconStatus>,
+        connector: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        self.diesel_store
+            .filter_settlement_lines_by_merchant_id_recon_status(
+                merchant_id,
+                recon_status,
+                connector,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    async fn update_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLine,
+        settlement_line_update: storage::SettlementLineUpdate,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        self.diesel_store
+            .update_settlement_line(settlement_line, settlement_line_update)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl GsmInterface for KafkaStore {
+    async fn add_gsm_rule(
+        &self,
+        rule: storage::GatewayStatusMappingNew,
+    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+        self.diesel_store.add_gsm_rule(rule).await
+    }
+
+    async fn find_gsm_decision(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+    ) -> CustomResult<String, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_decision(connector, flow, sub_flow, code, message)
+            .await
+    }
+
+    async fn find_gsm_rule(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_rule(connector, flow, sub_flow, code, message)
+            .await
+    }
+
+    async fn update_gsm_rule(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        data: storage::GatewayStatusMappingUpdate,
+    ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+        self.diesel_store
+            .update_gsm_rule(connector, flow, sub_flow, code, message, data)
+            .await
+    }
+
+    async fn delete_gsm_rule(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_gsm_rule(connector, flow, sub_flow, code, message)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageInterface for KafkaStore {
+    fn get_scheduler_db(&self) -> Box<dyn SchedulerInterface> {
+        Box::new(self.clone())
+    }
+
+    fn get_cache_store(&self) -> Box<(dyn RedisConnInterface + Send + Sync + 'static)> {
+        Box::new(self.clone())
+    }
+}
+
+impl GlobalStorageInterface for KafkaStore {}
+
+impl CommonStorageInterface for KafkaStore {
+    fn get_storage_interface(&self) -> Box<dyn StorageInterface> {
+        Box::new(self.clone())
+    }
+    fn get_global_storage_interface(&self) -> Box<dyn GlobalStorageInterface> {
+        Box::new(self.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl SchedulerInterface for KafkaStore {}
+
+impl MasterKeyInterface for KafkaStore {
+    fn get_master_key(&self) -> &[u8] {
+        self.diesel_store.get_master_key()
+    }
+
+    fn get_previous_master_key(&self) -> Option<&[u8]> {
+        self.diesel_store.get_previous_master_key()
+    }
+}
+#[async_trait::async_trait]
+impl UserInterface for KafkaStore {
+    async fn insert_user(
+        &self,
+        user_data: storage::UserNew,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        self.diesel_store.insert_user(user_data).await
+    }
+
+    async fn find_user_by_email(
+        &self,
+        user_email: &pii::Email,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        self.diesel_store.find_user_by_email(user_email).await
+    }
+
+    async fn find_user_by_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        self.diesel_store.find_user_by_id(user_id).await
+    }
+
+    async fn update_user_by_user_id(
+        &self,
+        user_id: &str,
+        user: storage::UserUpdate,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        self.diesel_store
+            .update_user_by_user_id(user_id, user)
+            .await
+    }
+
+    async fn update_user_by_email(
+        &self,
+        user_email: &pii::Email,
+        user: storage::UserUpdate,
+    ) -> CustomResult<storage::User, errors::StorageError> {
+        self.diesel_store
+            .update_user_by_email(user_email, user)
+            .await
+    }
+
+    async fn delete_user_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store.delete_user_by_user_id(user_id).await
+    }
+
+    async fn find_users_by_user_ids(
+        &self,
+        user_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
+        self.diesel_store.find_users_by_user_ids(user_ids).await
+    }
+}
+
+impl RedisConnInterface for KafkaStore {
+    fn get_redis_conn(&self) -> CustomResult<Arc<RedisConnectionPool>, RedisError> {
+        self.diesel_store.get_redis_conn()
     }
 }
 
@@ -2821,6 +3301,12 @@ impl HealthCheckDbInterface for KafkaStore {
     async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
         self.diesel_store.health_check_db().await
     }
+
+    async fn health_check_db_replica(
+        &self,
+    ) -> CustomResult<Option<f64>, errors::HealthCheckDBError> {
+        self.diesel_store.health_check_db_replica().await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3008,3 +3494,480 @@ impl UserAuthenticationMethodInterface for KafkaStore {
             .await
     }
 }
+
+#[async_trait::async_trait]
+impl SearchInterface for KafkaStore {
+    async fn search_payment_intents(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .search_payment_intents(merchant_id, filter, limit)
+            .await
+    }
+
+    async fn search_payment_attempts(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        self.diesel_store
+            .search_payment_attempts(merchant_id, filter, limit)
+            .await
+    }
+
+    async fn search_refunds(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        self.diesel_store
+            .search_refunds(merchant_id, filter, limit)
+            .await
+    }
+
+    async fn search_disputes(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .search_disputes(merchant_id, filter, limit)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl CardExportBatchInterface for KafkaStore {
+    async fn insert_card_export_batch(
+        &self,
+        card_export_batch: storage::CardExportBatchNew,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        self.diesel_store
+            .insert_card_export_batch(card_export_batch)
+            .await
+    }
+
+    async fn find_card_export_batch_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        self.diesel_store
+            .find_card_export_batch_by_merchant_id_export_id(merchant_id, export_id)
+            .await
+    }
+
+    async fn list_card_export_batches_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::CardExportBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_card_export_batches_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_card_export_batch_with_status(
+        &self,
+        card_export_batch: storage::CardExportBatch,
+        expected_status: enums::CardExportStatus,
+        card_export_batch_update: storage::CardExportBatchUpdate,
+    ) -> CustomResult<storage::CardExportBatch, errors::StorageError> {
+        self.diesel_store
+            .update_card_export_batch_with_status(
+                card_export_batch,
+                expected_status,
+                card_export_batch_update,
+            )
+            .await
+    }
+
+    async fn list_card_payment_methods_for_export(
+        &self,
+        merchant_id: &str,
+        payment_method_ids: Option<&[String]>,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_card_payment_methods_for_export(
+                merchant_id,
+                payment_method_ids,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorMigrationInterface for KafkaStore {
+    async fn insert_connector_migration(
+        &self,
+        connector_migration: storage::ConnectorMigrationNew,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_migration(connector_migration)
+            .await
+    }
+
+    async fn find_connector_migration_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &str,
+        migration_id: &str,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        self.diesel_store
+            .find_connector_migration_by_merchant_id_migration_id(merchant_id, migration_id)
+            .await
+    }
+
+    async fn list_connector_migrations_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorMigration>, errors::StorageError> {
+        self.diesel_store
+            .list_connector_migrations_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_connector_migration_with_status(
+        &self,
+        connector_migration: storage::ConnectorMigration,
+        expected_status: enums::ConnectorMigrationStatus,
+        connector_migration_update: storage::ConnectorMigrationUpdate,
+    ) -> CustomResult<storage::ConnectorMigration, errors::StorageError> {
+        self.diesel_store
+            .update_connector_migration_with_status(
+                connector_migration,
+                expected_status,
+                connector_migration_update,
+            )
+            .await
+    }
+
+    async fn insert_connector_migration_payment_method(
+        &self,
+        migration_payment_method: storage::ConnectorMigrationPaymentMethodNew,
+    ) -> CustomResult<storage::ConnectorMigrationPaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_migration_payment_method(migration_payment_method)
+            .await
+    }
+
+    async fn list_connector_migration_payment_methods(
+        &self,
+        merchant_id: &str,
+        migration_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConnectorMigrationPaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_connector_migration_payment_methods(merchant_id, migration_id, limit, offset)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl TenantInterface for KafkaStore {
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        self.diesel_store.insert_tenant(tenant).await
+    }
+
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        self.diesel_store.find_tenant_by_tenant_id(tenant_id).await
+    }
+
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError> {
+        self.diesel_store.list_tenants().await
+    }
+
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        self.diesel_store
+            .update_tenant_by_tenant_id(tenant_id, tenant_update)
+            .await
+    }
+
+    async fn setup_tenant_schema(
+        &self,
+        schema_name: &str,
+        template_schema_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .setup_tenant_schema(schema_name, template_schema_name)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WalletDecryptionKeyInterface for KafkaStore {
+    async fn insert_wallet_decryption_key(
+        &self,
+        wallet_decryption_key: storage::WalletDecryptionKeyNew,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        self.diesel_store
+            .insert_wallet_decryption_key(wallet_decryption_key)
+            .await
+    }
+
+    async fn find_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        self.diesel_store
+            .find_wallet_decryption_key_by_profile_id_key_id(profile_id, key_id)
+            .await
+    }
+
+    async fn list_wallet_decryption_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        self.diesel_store
+            .list_wallet_decryption_keys_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn list_wallet_decryption_keys_by_profile_id_wallet(
+        &self,
+        profile_id: &str,
+        wallet: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        self.diesel_store
+            .list_wallet_decryption_keys_by_profile_id_wallet(profile_id, wallet)
+            .await
+    }
+
+    async fn list_wallet_decryption_keys_by_profile_id_wallet_key_identifier(
+        &self,
+        profile_id: &str,
+        wallet: &str,
+        key_identifier: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        self.diesel_store
+            .list_wallet_decryption_keys_by_profile_id_wallet_key_identifier(
+                profile_id,
+                wallet,
+                key_identifier,
+            )
+            .await
+    }
+
+    async fn update_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+        wallet_decryption_key_update: storage::WalletDecryptionKeyUpdate,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        self.diesel_store
+            .update_wallet_decryption_key_by_profile_id_key_id(
+                profile_id,
+                key_id,
+                wallet_decryption_key_update,
+            )
+            .await
+    }
+
+    async fn list_wallet_decryption_keys_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        self.diesel_store
+            .list_wallet_decryption_keys_by_merchant_id_starting_after(
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultCleanupInterface for KafkaStore {
+    async fn list_orphaned_vault_payment_methods(
+        &self,
+        merchant_id: &str,
+        last_modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_orphaned_vault_payment_methods(
+                merchant_id,
+                last_modified_before,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl BulkForceSyncInterface for KafkaStore {
+    async fn list_stuck_payment_attempts(
+        &self,
+        merchant_id: Option<&str>,
+        connectors: &[String],
+        statuses: &[enums::AttemptStatus],
+        modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::StuckPaymentAttempt>, errors::StorageError> {
+        self.diesel_store
+            .list_stuck_payment_attempts(
+                merchant_id,
+                connectors,
+                statuses,
+                modified_before,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentStatusTransitionInterface for KafkaStore {
+    async fn list_payment_status_transitions(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentStatusTransition>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_status_transitions(merchant_id, payment_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl JobInterface for KafkaStore {
+    async fn insert_job(
+        &self,
+        job: storage::JobNew,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        self.diesel_store.insert_job(job).await
+    }
+
+    async fn find_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &str,
+        job_id: &str,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        self.diesel_store
+            .find_job_by_merchant_id_job_id(merchant_id, job_id)
+            .await
+    }
+
+    async fn update_job_with_status(
+        &self,
+        job: storage::Job,
+        expected_statuses: Vec<enums::JobStatus>,
+        job_update: storage::JobUpdate,
+    ) -> CustomResult<storage::Job, errors::StorageError> {
+        self.diesel_store
+            .update_job_with_status(job, expected_statuses, job_update)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookArchiveInterface for KafkaStore {
+    async fn insert_incoming_webhook_archive(
+        &self,
+        archive: domain::IncomingWebhookArchive,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        self.diesel_store
+            .insert_incoming_webhook_archive(archive, merchant_key_store)
+            .await
+    }
+
+    async fn find_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        merchant_id: &str,
+        archive_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        self.diesel_store
+            .find_incoming_webhook_archive_by_merchant_id_archive_id(
+                merchant_id,
+                archive_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_incoming_webhook_archives_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<String>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_archives_by_merchant_id_constraints(
+                merchant_id,
+                profile_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_incoming_webhook_archives_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookArchive>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_archives_by_merchant_id_starting_after(
+                merchant_id,
+                starting_after,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn update_incoming_webhook_archive_by_merchant_id_archive_id(
+        &self,
+        merchant_id: &str,
+        archive_id: &str,
+        archive_update: domain::IncomingWebhookArchiveUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookArchive, errors::StorageError> {
+        self.diesel_store
+            .update_incoming_webhook_archive_by_merchant_id_archive_id(
+                merchant_id,
+                archive_id,
+                archive_update,
+                merchant_key_store,
+            )
+            .await
+    }
+}
@@ -0,0 +1,117 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+use time::PrimitiveDateTime;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait LedgerEntryInterface {
+    /// Records all the legs of a transaction, failing with a duplicate value error when the
+    /// transaction was already recorded
+    async fn insert_ledger_transaction(
+        &self,
+        entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn find_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn filter_ledger_entries_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        merchant_connector_id: Option<String>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_ledger_transaction(
+        &self,
+        entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LedgerEntry::insert_transaction(&conn, entries)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_ledger_entries_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        merchant_connector_id: Option<String>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::filter_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for MockDb {
+    async fn insert_ledger_transaction(
+        &self,
+        _entries: Vec<storage::LedgerEntryNew>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_ledger_entries_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_ledger_entries_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &str,
+        _merchant_connector_id: Option<String>,
+        _created_after: Option<PrimitiveDateTime>,
+        _created_before: Option<PrimitiveDateTime>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -76,6 +76,8 @@ where
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
 
+    async fn get_merchant_accounts_count(&self) -> CustomResult<i64, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn list_multiple_merchant_accounts(
         &self,
@@ -310,6 +312,14 @@ impl MerchantAccountInterface for Store {
         Ok(is_deleted)
     }
 
+    #[instrument(skip_all)]
+    async fn get_merchant_accounts_count(&self) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantAccount::get_count(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn list_multiple_merchant_accounts(
@@ -486,6 +496,11 @@ impl MerchantAccountInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn get_merchant_accounts_count(&self) -> CustomResult<i64, errors::StorageError> {
+        let accounts = self.merchant_accounts.lock().await;
+        i64::try_from(accounts.len()).change_context(errors::StorageError::MockDbError)
+    }
+
     #[cfg(feature = "olap")]
     async fn list_merchant_accounts_by_organization_id(
         &self,
@@ -3,9 +3,10 @@ use common_utils::ext_traits::{AsyncExt, ByteSliceExt, Encode};
 use diesel_models::encryption::Encryption;
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
-#[cfg(feature = "accounts_cache")]
-use storage_impl::redis::cache;
-use storage_impl::redis::kv_store::RedisConnInterface;
+use storage_impl::{
+    redis::{cache, kv_store::RedisConnInterface},
+    ReadQuery,
+};
 
 use super::{MockDb, Store};
 use crate::{
@@ -21,6 +22,26 @@ use crate::{
     },
 };
 
+/// The cache kinds of the cgraphs of payments and payouts built from the merchant connector
+/// accounts of the merchant. The cgraphs are cached per business profile when routing by business
+/// profile and per merchant otherwise, with the keys they are cached with when built for routing.
+fn get_cgraph_cache_kinds(
+    merchant_id: &str,
+    profile_id: Option<&str>,
+) -> Vec<cache::CacheKind<'static>> {
+    let mut keys = vec![
+        format!("cgraph_{merchant_id}"),
+        format!("cgraph_po_{merchant_id}"),
+    ];
+    if let Some(profile_id) = profile_id {
+        keys.push(format!("cgraph_{merchant_id}_{profile_id}"));
+        keys.push(format!("cgraph_po_{merchant_id}_{profile_id}"));
+    }
+    keys.into_iter()
+        .map(|key| cache::CacheKind::CGraph(key.into()))
+        .collect()
+}
+
 #[async_trait::async_trait]
 pub trait ConnectorAccessToken {
     async fn get_access_token(
@@ -160,6 +181,14 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError>;
 
+    /// Finds the enabled connector accounts of the merchant which the constraint graphs are built
+    /// from, reading from the database configured for the constraint graph refreshes
+    async fn find_merchant_connector_accounts_for_cgraph(
+        &self,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError>;
+
     async fn update_merchant_connector_account(
         &self,
         this: domain::MerchantConnectorAccount,
@@ -351,19 +380,25 @@ impl MerchantConnectorAccountInterface for Store {
         t: domain::MerchantConnectorAccount,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantConnectorAccount, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        t.construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-            .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
-                    .await
-                    .change_context(errors::StorageError::DecryptionError)
-            })
-            .await
+        // The cgraphs built before the connector was added would leave it out of the routing
+        let cache_kinds = get_cgraph_cache_kinds(&t.merchant_id, t.profile_id.as_deref());
+        let insert_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            t.construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|item| async {
+                    item.convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        };
+
+        cache::publish_and_redact_multiple(self, cache_kinds, insert_call).await
     }
 
     #[instrument(skip_all)]
@@ -391,6 +426,30 @@ impl MerchantConnectorAccountInterface for Store {
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn find_merchant_connector_accounts_for_cgraph(
+        &self,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::CGraph).await?;
+        storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id, false)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|items| async {
+                let mut output = Vec::with_capacity(items.len());
+                for item in items.into_iter() {
+                    output.push(
+                        item.convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
+            })
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn update_multiple_merchant_connector_accounts(
         &self,
@@ -422,11 +481,11 @@ impl MerchantConnectorAccountInterface for Store {
                 merchant_connector_accounts
             {
                 let _connector_name = merchant_connector_account.connector_name.clone();
-                let _profile_id = merchant_connector_account.profile_id.clone().ok_or(
+                let profile_id = merchant_connector_account.profile_id.clone().ok_or(
                     errors::StorageError::ValueNotFound("profile_id".to_string()),
                 )?;
 
-                let _merchant_id = merchant_connector_account.merchant_id.clone();
+                let merchant_id = merchant_connector_account.merchant_id.clone();
                 let _merchant_connector_id =
                     merchant_connector_account.merchant_connector_id.clone();
 
@@ -438,50 +497,31 @@ impl MerchantConnectorAccountInterface for Store {
                     ),
                 );
 
+                let cache_kinds = get_cgraph_cache_kinds(&merchant_id, Some(&profile_id));
                 #[cfg(feature = "accounts_cache")]
                 // Redact all caches as any of might be used because of backwards compatibility
-                cache::publish_and_redact_multiple(
-                    self,
-                    [
-                        cache::CacheKind::Accounts(
-                            format!("{}_{}", _profile_id, _connector_name).into(),
-                        ),
-                        cache::CacheKind::Accounts(
-                            format!("{}_{}", _merchant_id, _merchant_connector_id).into(),
-                        ),
-                        cache::CacheKind::CGraph(
-                            format!("cgraph_{}_{_profile_id}", _merchant_id).into(),
-                        ),
-                    ],
-                    || update,
-                )
-                .await
-                .map_err(|error| {
-                    // Returning `DatabaseConnectionError` after logging the actual error because
-                    // -> it is not possible to get the underlying from `error_stack::Report<C>`
-                    // -> it is not possible to write a `From` impl to convert the `diesel::result::Error` to `error_stack::Report<StorageError>`
-                    //    because of Rust's orphan rules
-                    router_env::logger::error!(
-                        ?error,
-                        "DB transaction for updating multiple merchant connector account failed"
-                    );
-                    errors::StorageError::DatabaseConnectionError
-                })?;
-
-                #[cfg(not(feature = "accounts_cache"))]
-                {
-                    update.await.map_err(|error| {
+                let cache_kinds = cache_kinds.into_iter().chain([
+                    cache::CacheKind::Accounts(
+                        format!("{}_{}", profile_id, _connector_name).into(),
+                    ),
+                    cache::CacheKind::Accounts(
+                        format!("{}_{}", merchant_id, _merchant_connector_id).into(),
+                    ),
+                ]);
+
+                cache::publish_and_redact_multiple(self, cache_kinds, || update)
+                    .await
+                    .map_err(|error| {
                         // Returning `DatabaseConnectionError` after logging the actual error because
                         // -> it is not possible to get the underlying from `error_stack::Report<C>`
                         // -> it is not possible to write a `From` impl to convert the `diesel::result::Error` to `error_stack::Report<StorageError>`
                         //    because of Rust's orphan rules
                         router_env::logger::error!(
-                        ?error,
-                        "DB transaction for updating multiple merchant connector account failed"
-                    );
+                            ?error,
+                            "DB transaction for updating multiple merchant connector account failed"
+                        );
                         errors::StorageError::DatabaseConnectionError
                     })?;
-                }
             }
             Ok::<_, errors::StorageError>(())
         })
@@ -497,14 +537,14 @@ impl MerchantConnectorAccountInterface for Store {
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantConnectorAccount, errors::StorageError> {
         let _connector_name = this.connector_name.clone();
-        let _profile_id = this
+        let profile_id = this
             .profile_id
             .clone()
             .ok_or(errors::StorageError::ValueNotFound(
                 "profile_id".to_string(),
             ))?;
 
-        let _merchant_id = this.merchant_id.clone();
+        let merchant_id = this.merchant_id.clone();
         let _merchant_connector_id = this.merchant_connector_id.clone();
 
         let update_call = || async {
@@ -523,31 +563,18 @@ impl MerchantConnectorAccountInterface for Store {
                 .await
         };
 
+        // The cgraphs built before the update would keep routing to a disabled connector
+        let cache_kinds = get_cgraph_cache_kinds(&merchant_id, Some(&profile_id));
         #[cfg(feature = "accounts_cache")]
-        {
-            // Redact all caches as any of might be used because of backwards compatibility
-            cache::publish_and_redact_multiple(
-                self,
-                [
-                    cache::CacheKind::Accounts(
-                        format!("{}_{}", _profile_id, _connector_name).into(),
-                    ),
-                    cache::CacheKind::Accounts(
-                        format!("{}_{}", _merchant_id, _merchant_connector_id).into(),
-                    ),
-                    cache::CacheKind::CGraph(
-                        format!("cgraph_{}_{_profile_id}", _merchant_id).into(),
-                    ),
-                ],
-                update_call,
-            )
-            .await
-        }
+        // Redact all caches as any of might be used because of backwards compatibility
+        let cache_kinds = cache_kinds.into_iter().chain([
+            cache::CacheKind::Accounts(format!("{}_{}", profile_id, _connector_name).into()),
+            cache::CacheKind::Accounts(
+                format!("{}_{}", merchant_id, _merchant_connector_id).into(),
+            ),
+        ]);
 
-        #[cfg(not(feature = "accounts_cache"))]
-        {
-            update_call().await
-        }
+        cache::publish_and_redact_multiple(self, cache_kinds, update_call).await
     }
 
     #[instrument(skip_all)]
@@ -567,44 +594,33 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
         };
 
-        #[cfg(feature = "accounts_cache")]
-        {
-            // We need to fetch mca here because the key that's saved in cache in
-            // {merchant_id}_{connector_label}.
-            // Used function from storage model to reuse the connection that made here instead of
-            // creating new.
-
-            let mca = storage::MerchantConnectorAccount::find_by_merchant_id_merchant_connector_id(
-                &conn,
-                merchant_id,
-                merchant_connector_id,
-            )
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))?;
-
-            let _profile_id = mca.profile_id.ok_or(errors::StorageError::ValueNotFound(
-                "profile_id".to_string(),
-            ))?;
+        // We need to fetch mca here because the key that's saved in cache in
+        // {merchant_id}_{connector_label}, and the cgraphs are cached per business profile.
+        // Used function from storage model to reuse the connection that made here instead of
+        // creating new.
+        let mca = storage::MerchantConnectorAccount::find_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
 
-            cache::publish_and_redact_multiple(
-                self,
-                [
-                    cache::CacheKind::Accounts(
-                        format!("{}_{}", mca.merchant_id, _profile_id).into(),
-                    ),
-                    cache::CacheKind::CGraph(
-                        format!("cgraph_{}_{_profile_id}", mca.merchant_id).into(),
-                    ),
-                ],
-                delete_call,
-            )
-            .await
-        }
+        let cache_kinds = get_cgraph_cache_kinds(&mca.merchant_id, mca.profile_id.as_deref());
+        #[cfg(feature = "accounts_cache")]
+        let cache_kinds = {
+            let profile_id = mca
+                .profile_id
+                .as_ref()
+                .ok_or(errors::StorageError::ValueNotFound(
+                    "profile_id".to_string(),
+                ))?;
+            cache_kinds.into_iter().chain([cache::CacheKind::Accounts(
+                format!("{}_{}", mca.merchant_id, profile_id).into(),
+            )])
+        };
 
-        #[cfg(not(feature = "accounts_cache"))]
-        {
-            delete_call().await
-        }
+        cache::publish_and_redact_multiple(self, cache_kinds, delete_call).await
     }
 }
 
@@ -778,6 +794,11 @@ impl MerchantConnectorAccountInterface for MockDb {
             pm_auth_config: t.pm_auth_config,
             status: t.status,
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
+            next_connector_account_details: t.next_connector_account_details.map(Encryption::from),
+            previous_connector_account_details: t
+                .previous_connector_account_details
+                .map(Encryption::from),
+            credentials_rotation_window_ends_at: t.credentials_rotation_window_ends_at,
         };
         accounts.push(account.clone());
         account
@@ -786,6 +807,19 @@ impl MerchantConnectorAccountInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn find_merchant_connector_accounts_for_cgraph(
+        &self,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError> {
+        self.find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+    }
+
     async fn find_merchant_connector_account_by_merchant_id_and_disabled_list(
         &self,
         merchant_id: &str,
@@ -985,6 +1019,9 @@ mod merchant_connector_account_cache_tests {
                 .await
                 .unwrap(),
             ),
+            next_connector_account_details: None,
+            previous_connector_account_details: None,
+            credentials_rotation_window_ends_at: None,
         };
 
         db.insert_merchant_connector_account(mca.clone(), &merchant_key)
@@ -7,7 +7,7 @@ use storage_impl::redis::cache::{self, CacheKind, ACCOUNTS_CACHE};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    db::MockDb,
+    db::{MasterKeyInterface, MockDb},
     services::Store,
     types::domain::{
         self,
@@ -29,11 +29,26 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
 
+    /// Updates the key of the merchant, which is to be encrypted with the master key provided
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
 
+    /// Lists the merchant ids of the key stores in their order, starting after the merchant id
+    /// provided
+    async fn list_merchant_key_store_ids(
+        &self,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn list_multiple_key_stores(
         &self,
@@ -82,27 +97,66 @@ impl MerchantKeyStoreInterface for Store {
 
         #[cfg(not(feature = "accounts_cache"))]
         {
-            fetch_func()
-                .await?
-                .convert(key)
-                .await
-                .change_context(errors::StorageError::DecryptionError)
+            decrypt_key_store(self, fetch_func().await?, key).await
         }
 
         #[cfg(feature = "accounts_cache")]
         {
             let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
-            cache::get_or_populate_in_memory(
+            let key_store = cache::get_or_populate_in_memory(
                 self,
                 &key_store_cache_key,
                 fetch_func,
                 &ACCOUNTS_CACHE,
             )
+            .await?;
+
+            decrypt_key_store(self, key_store, key).await
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let merchant_id = merchant_key_store.merchant_id.clone();
+        let merchant_key_store = Conversion::convert(merchant_key_store)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                &merchant_id,
+                diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal {
+                    merchant_id: merchant_key_store.merchant_id.clone(),
+                    key: merchant_key_store.key.clone(),
+                },
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let updated_key_store = update_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let updated_key_store = {
+            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
+            cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
             .await?
+        };
+
+        updated_key_store
             .convert(key)
             .await
             .change_context(errors::StorageError::DecryptionError)
-        }
     }
 
     #[instrument(skip_all)]
@@ -137,6 +191,29 @@ impl MerchantKeyStoreInterface for Store {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn list_merchant_key_store_ids(
+        &self,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+
+        diesel_models::merchant_key_store::MerchantKeyStore::list_after_merchant_id(
+            &conn,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .map(|key_stores| {
+            key_stores
+                .into_iter()
+                .map(|key_store| key_store.merchant_id)
+                .collect()
+        })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn list_multiple_key_stores(
@@ -155,16 +232,35 @@ impl MerchantKeyStoreInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
         };
 
-        futures::future::try_join_all(fetch_func().await?.into_iter().map(|key_store| async {
-            key_store
-                .convert(key)
-                .await
-                .change_context(errors::StorageError::DecryptionError)
-        }))
+        futures::future::try_join_all(
+            fetch_func()
+                .await?
+                .into_iter()
+                .map(|key_store| decrypt_key_store(self, key_store, key)),
+        )
         .await
     }
 }
 
+/// Decrypts the key store with the master key provided, the key stores not yet re-encrypted with
+/// the master key since it was rotated being decrypted with the master key being rotated out
+async fn decrypt_key_store(
+    store: &Store,
+    key_store: diesel_models::merchant_key_store::MerchantKeyStore,
+    key: &Secret<Vec<u8>>,
+) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+    let decrypted_key_store = key_store.clone().convert(key).await;
+    match (decrypted_key_store, store.get_previous_master_key()) {
+        (Err(_), Some(previous_master_key)) => key_store
+            .convert(&previous_master_key.to_vec().into())
+            .await
+            .change_context(errors::StorageError::DecryptionError),
+        (decrypted_key_store, _) => {
+            decrypted_key_store.change_context(errors::StorageError::DecryptionError)
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl MerchantKeyStoreInterface for MockDb {
     async fn insert_merchant_key_store(
@@ -214,6 +310,30 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store(
+        &self,
+        merchant_key_store: domain::MerchantKeyStore,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let merchant_key_store = Conversion::convert(merchant_key_store)
+            .await
+            .change_context(errors::StorageError::MockDbError)?;
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let existing_key_store = merchant_key_stores
+            .iter_mut()
+            .find(|mks| mks.merchant_id == merchant_key_store.merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(String::from(
+                "merchant_key_store",
+            )))?;
+        existing_key_store.key = merchant_key_store.key;
+
+        existing_key_store
+            .clone()
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -230,6 +350,24 @@ impl MerchantKeyStoreInterface for MockDb {
         Ok(true)
     }
 
+    async fn list_merchant_key_store_ids(
+        &self,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let mut merchant_ids = self
+            .merchant_key_store
+            .lock()
+            .await
+            .iter()
+            .map(|mks| mks.merchant_id.clone())
+            .filter(|merchant_id| starting_after.as_ref() < Some(merchant_id))
+            .collect::<Vec<_>>();
+        merchant_ids.sort();
+        merchant_ids.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(merchant_ids)
+    }
+
     #[cfg(feature = "olap")]
     async fn list_multiple_key_stores(
         &self,
@@ -255,6 +393,7 @@ impl MerchantKeyStoreInterface for MockDb {
 
 #[cfg(test)]
 mod tests {
+    use masking::PeekInterface;
     use time::macros::datetime;
 
     use crate::{
@@ -325,4 +464,64 @@ mod tests {
             .await;
         assert!(find_merchant_key_with_incorrect_master_key_result.is_err());
     }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mock_db_merchant_key_store_update_and_list() {
+        #[allow(clippy::expect_used)]
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let master_key = mock_db.get_master_key();
+
+        for merchant_id in ["merchant2", "merchant1", "merchant3"] {
+            mock_db
+                .insert_merchant_key_store(
+                    domain::MerchantKeyStore {
+                        merchant_id: merchant_id.into(),
+                        key: domain::types::encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                            master_key,
+                        )
+                        .await
+                        .unwrap(),
+                        created_at: datetime!(2023-02-01 0:00),
+                    },
+                    &master_key.to_vec().into(),
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            mock_db.list_merchant_key_store_ids(None, 2).await.unwrap(),
+            vec!["merchant1".to_string(), "merchant2".to_string()]
+        );
+        assert_eq!(
+            mock_db
+                .list_merchant_key_store_ids(Some("merchant2".to_string()), 2)
+                .await
+                .unwrap(),
+            vec!["merchant3".to_string()]
+        );
+
+        let key_store = mock_db
+            .get_merchant_key_store_by_merchant_id("merchant1", &master_key.to_vec().into())
+            .await
+            .unwrap();
+        let key = key_store.key.clone().into_inner();
+        let updated_key_store = mock_db
+            .update_merchant_key_store(
+                domain::MerchantKeyStore {
+                    key: domain::types::encrypt(key.clone(), master_key)
+                        .await
+                        .unwrap(),
+                    ..key_store
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated_key_store.key.into_inner().peek(), key.peek());
+    }
 }
@@ -0,0 +1,96 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait OrderInterface {
+    async fn insert_order(
+        &self,
+        order: storage::OrderNew,
+    ) -> CustomResult<storage::Order, errors::StorageError>;
+
+    async fn find_order_by_merchant_id_order_id(
+        &self,
+        merchant_id: &str,
+        order_id: &str,
+    ) -> CustomResult<storage::Order, errors::StorageError>;
+
+    async fn update_order(
+        &self,
+        order: storage::Order,
+        order_update: storage::OrderUpdate,
+    ) -> CustomResult<storage::Order, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl OrderInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_order(
+        &self,
+        order: storage::OrderNew,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        order
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_order_by_merchant_id_order_id(
+        &self,
+        merchant_id: &str,
+        order_id: &str,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Order::find_by_merchant_id_order_id(&conn, merchant_id, order_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_order(
+        &self,
+        order: storage::Order,
+        order_update: storage::OrderUpdate,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        order
+            .update(&conn, order_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl OrderInterface for MockDb {
+    async fn insert_order(
+        &self,
+        _order: storage::OrderNew,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_order_by_merchant_id_order_id(
+        &self,
+        _merchant_id: &str,
+        _order_id: &str,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_order(
+        &self,
+        _order: storage::Order,
+        _order_update: storage::OrderUpdate,
+    ) -> CustomResult<storage::Order, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
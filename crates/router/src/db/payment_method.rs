@@ -63,6 +63,15 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
         payment_method_id: &str,
     ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
+
+    /// Lists the payment methods of the merchant, as stored in the database, in the order of
+    /// their payment method ids, starting after the payment method id provided
+    async fn list_payment_methods_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -362,6 +371,24 @@ mod storage {
             }
         }
 
+        #[instrument(skip_all)]
+        async fn list_payment_methods_by_merchant_id_starting_after(
+            &self,
+            merchant_id: &str,
+            starting_after: Option<String>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::list_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_list(
             &self,
@@ -540,6 +567,24 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn list_payment_methods_by_merchant_id_starting_after(
+            &self,
+            merchant_id: &str,
+            starting_after: Option<String>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::list_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_list(
             &self,
@@ -696,11 +741,34 @@ impl PaymentMethodInterface for MockDb {
             network_transaction_id: payment_method_new.network_transaction_id,
             updated_by: payment_method_new.updated_by,
             payment_method_billing_address: payment_method_new.payment_method_billing_address,
+            usage_restrictions: payment_method_new.usage_restrictions,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
     }
 
+    async fn list_payment_methods_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let mut payment_methods = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == merchant_id
+                    && starting_after.as_ref() < Some(&pm.payment_method_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_methods.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        payment_methods.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(payment_methods)
+    }
+
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
         customer_id: &id_type::CustomerId,
@@ -0,0 +1,49 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentStatusTransitionInterface {
+    async fn list_payment_status_transitions(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentStatusTransition>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentStatusTransitionInterface for Store {
+    #[instrument(skip_all)]
+    async fn list_payment_status_transitions(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentStatusTransition>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentStatusTransition::list_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentStatusTransitionInterface for MockDb {
+    async fn list_payment_status_transitions(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentStatusTransition>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
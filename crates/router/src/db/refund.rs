@@ -97,6 +97,8 @@ pub trait RefundInterface {
 mod storage {
     use error_stack::report;
     use router_env::{instrument, tracing};
+    #[cfg(feature = "olap")]
+    use storage_impl::ReadQuery;
 
     use super::RefundInterface;
     use crate::{
@@ -222,7 +224,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -242,7 +244,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::Analytics).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(
                 &conn,
                 merchant_id,
@@ -259,7 +261,7 @@ mod storage {
             refund_details: &api_models::refunds::RefundListRequest,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::Analytics).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -280,6 +282,8 @@ mod storage {
     use storage_impl::redis::kv_store::{
         decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
     };
+    #[cfg(feature = "olap")]
+    use storage_impl::ReadQuery;
 
     use super::RefundInterface;
     use crate::{
@@ -397,6 +401,7 @@ mod storage {
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
                         charges: new.charges.clone(),
+                        estimated_arrival_at: new.estimated_arrival_at,
                     };
 
                     let field = format!(
@@ -748,7 +753,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -768,7 +773,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::Analytics).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(&conn, merchant_id, refund_details)
                         .await
                         .map_err(|error|report!(errors::StorageError::from(error)))
@@ -782,7 +787,7 @@ mod storage {
             refund_details: &api_models::refunds::RefundListRequest,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_for(self, ReadQuery::Analytics).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -852,6 +857,7 @@ impl RefundInterface for MockDb {
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
             charges: new.charges,
+            estimated_arrival_at: new.estimated_arrival_at,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -1048,6 +1054,11 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
+            .filter(|refund| {
+                refund_details.cursor.as_ref().map_or(true, |cursor| {
+                    (refund.created_at, &refund.refund_id) < (cursor.created_at, &cursor.id)
+                })
+            })
             .skip(usize::try_from(offset).unwrap_or_default())
             .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
             .cloned()
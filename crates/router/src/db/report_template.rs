@@ -0,0 +1,145 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReportTemplateInterface {
+    async fn insert_report_template(
+        &self,
+        report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError>;
+
+    async fn update_report_template(
+        &self,
+        report_template: storage::ReportTemplate,
+        report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReportTemplateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_report_template(
+        &self,
+        report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportTemplate::find_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportTemplate::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_report_template(
+        &self,
+        report_template: storage::ReportTemplate,
+        report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report_template
+            .update(&conn, report_template_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &str,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReportTemplate::delete_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportTemplateInterface for MockDb {
+    async fn insert_report_template(
+        &self,
+        _report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &str,
+        _template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_report_template(
+        &self,
+        _report_template: storage::ReportTemplate,
+        _report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &str,
+        _template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,135 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{MockDb, ReadQuery};
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{self, SearchDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait SearchInterface {
+    async fn search_payment_intents(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError>;
+
+    async fn search_payment_attempts(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError>;
+
+    async fn search_refunds(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError>;
+
+    async fn search_disputes(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SearchInterface for Store {
+    #[instrument(skip_all)]
+    async fn search_payment_intents(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        diesel_models::PaymentIntent::search(&conn, merchant_id, filter, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn search_payment_attempts(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        diesel_models::PaymentAttempt::search(&conn, merchant_id, filter, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn search_refunds(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::Refund::search(&conn, merchant_id, filter, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn search_disputes(
+        &self,
+        merchant_id: &str,
+        filter: storage::SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::Dispute::search(&conn, merchant_id, filter, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchInterface for MockDb {
+    async fn search_payment_intents(
+        &self,
+        _merchant_id: &str,
+        _filter: storage::SearchFilter<'_>,
+        _limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn search_payment_attempts(
+        &self,
+        _merchant_id: &str,
+        _filter: storage::SearchFilter<'_>,
+        _limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn search_refunds(
+        &self,
+        _merchant_id: &str,
+        _filter: storage::SearchFilter<'_>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn search_disputes(
+        &self,
+        _merchant_id: &str,
+        _filter: storage::SearchFilter<'_>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,228 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait SettlementLineInterface {
+    async fn insert_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError>;
+
+    async fn find_settlement_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &str,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError>;
+
+    async fn find_settlement_lines_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError>;
+
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError>;
+
+    async fn find_settlement_lines_by_merchant_id_connector_reference_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        line_type: enums::SettlementLineType,
+        reference_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError>;
+
+    async fn filter_settlement_lines_by_merchant_id_recon_status(
+        &self,
+        merchant_id: &str,
+        recon_status: Vec<enums::SettlementReconStatus>,
+        connector: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError>;
+
+    async fn update_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLine,
+        settlement_line_update: storage::SettlementLineUpdate,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementLineInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_line
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_line_by_merchant_id_line_id(
+        &self,
+        merchant_id: &str,
+        line_id: &str,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::find_by_merchant_id_line_id(&conn, merchant_id, line_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_lines_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_lines_by_merchant_id_connector_reference_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        line_type: enums::SettlementLineType,
+        reference_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::find_by_merchant_id_connector_reference_id(
+            &conn,
+            merchant_id,
+            connector,
+            line_type,
+            reference_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_settlement_lines_by_merchant_id_recon_status(
+        &self,
+        merchant_id: &str,
+        recon_status: Vec<enums::SettlementReconStatus>,
+        connector: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementLine::filter_by_merchant_id_recon_status(
+            &conn,
+            merchant_id,
+            recon_status,
+            connector,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_line(
+        &self,
+        settlement_line: storage::SettlementLine,
+        settlement_line_update: storage::SettlementLineUpdate,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_line
+            .update(&conn, settlement_line_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementLineInterface for MockDb {
+    async fn insert_settlement_line(
+        &self,
+        _settlement_line: storage::SettlementLineNew,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_line_by_merchant_id_line_id(
+        &self,
+        _merchant_id: &str,
+        _line_id: &str,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_lines_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &str,
+        _batch_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_lines_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_lines_by_merchant_id_connector_reference_id(
+        &self,
+        _merchant_id: &str,
+        _connector: &str,
+        _line_type: enums::SettlementLineType,
+        _reference_id: &str,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_settlement_lines_by_merchant_id_recon_status(
+        &self,
+        _merchant_id: &str,
+        _recon_status: Vec<enums::SettlementReconStatus>,
+        _connector: Option<String>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SettlementLine>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_settlement_line(
+        &self,
+        _settlement_line: storage::SettlementLine,
+        _settlement_line_update: storage::SettlementLineUpdate,
+    ) -> CustomResult<storage::SettlementLine, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,133 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+/// The tenants provisioned at runtime, stored in the global schema
+#[async_trait::async_trait]
+pub trait TenantInterface {
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError>;
+
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError>;
+
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError>;
+
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError>;
+
+    async fn setup_tenant_schema(
+        &self,
+        schema_name: &str,
+        template_schema_name: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl TenantInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        tenant
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Tenant::find_by_tenant_id(&conn, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Tenant::list(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Tenant::update_by_tenant_id(&conn, tenant_id, tenant_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn setup_tenant_schema(
+        &self,
+        schema_name: &str,
+        template_schema_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::setup_tenant_schema(&conn, schema_name, template_schema_name)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl TenantInterface for MockDb {
+    async fn insert_tenant(
+        &self,
+        _tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_tenant_by_tenant_id(
+        &self,
+        _tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_tenant_by_tenant_id(
+        &self,
+        _tenant_id: &str,
+        _tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn setup_tenant_schema(
+        &self,
+        _schema_name: &str,
+        _template_schema_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
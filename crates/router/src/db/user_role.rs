@@ -202,6 +202,7 @@ impl UserRoleInterface for Store {
                 org_id.to_owned(),
                 storage::UserRoleUpdate::UpdateRole {
                     role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
+                    profile_id: None,
                     modified_by: from_user_id.to_owned(),
                 },
             )
@@ -214,6 +215,7 @@ impl UserRoleInterface for Store {
                 org_id.to_owned(),
                 storage::UserRoleUpdate::UpdateRole {
                     role_id: consts::user_role::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
+                    profile_id: None,
                     modified_by: from_user_id.to_owned(),
                 },
             )
@@ -243,6 +245,7 @@ impl UserRoleInterface for Store {
                                 last_modified_by: from_user_id.to_string(),
                                 created_at: now,
                                 last_modified: now,
+                                profile_id: None,
                             }
                         })
                 });
@@ -292,6 +295,7 @@ impl UserRoleInterface for MockDb {
             last_modified: user_role.last_modified,
             last_modified_by: user_role.last_modified_by,
             org_id: user_role.org_id,
+            profile_id: user_role.profile_id,
         };
         user_roles.push(user_role.clone());
         Ok(user_role)
@@ -346,9 +350,11 @@ impl UserRoleInterface for MockDb {
                 *user_role = match &update {
                     storage::UserRoleUpdate::UpdateRole {
                         role_id,
+                        profile_id,
                         modified_by,
                     } => storage::UserRole {
                         role_id: role_id.to_string(),
+                        profile_id: profile_id.clone(),
                         last_modified_by: modified_by.to_string(),
                         ..user_role.to_owned()
                     },
@@ -384,9 +390,11 @@ impl UserRoleInterface for MockDb {
                 match &update {
                     storage::UserRoleUpdate::UpdateRole {
                         role_id,
+                        profile_id,
                         modified_by,
                     } => {
                         user_role.role_id = role_id.to_string();
+                        profile_id.clone_into(&mut user_role.profile_id);
                         user_role.last_modified_by = modified_by.to_string();
                     }
                     storage::UserRoleUpdate::UpdateStatus {
@@ -422,6 +430,7 @@ impl UserRoleInterface for MockDb {
                 org_id,
                 storage::UserRoleUpdate::UpdateRole {
                     role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
+                    profile_id: None,
                     modified_by: from_user_id.to_string(),
                 },
             )
@@ -433,6 +442,7 @@ impl UserRoleInterface for MockDb {
                 org_id,
                 storage::UserRoleUpdate::UpdateRole {
                     role_id: consts::user_role::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
+                    profile_id: None,
                     modified_by: from_user_id.to_string(),
                 },
             )
@@ -459,6 +469,7 @@ impl UserRoleInterface for MockDb {
                         last_modified_by: from_user_id.to_string(),
                         created_at: now,
                         last_modified: now,
+                        profile_id: None,
                     })
                 } else {
                     None
@@ -0,0 +1,58 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{MockDb, ReadQuery};
+use time::PrimitiveDateTime;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage::{self, VaultCleanupDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait VaultCleanupInterface {
+    async fn list_orphaned_vault_payment_methods(
+        &self,
+        merchant_id: &str,
+        last_modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultCleanupInterface for Store {
+    #[instrument(skip_all)]
+    async fn list_orphaned_vault_payment_methods(
+        &self,
+        merchant_id: &str,
+        last_modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read_for(self, ReadQuery::List).await?;
+        storage::PaymentMethod::list_orphaned_vault_entries(
+            &conn,
+            merchant_id,
+            last_modified_before,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultCleanupInterface for MockDb {
+    async fn list_orphaned_vault_payment_methods(
+        &self,
+        _merchant_id: &str,
+        _last_modified_before: PrimitiveDateTime,
+        _starting_after: Option<&str>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
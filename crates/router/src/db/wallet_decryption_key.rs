@@ -0,0 +1,222 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WalletDecryptionKeyInterface {
+    async fn insert_wallet_decryption_key(
+        &self,
+        wallet_decryption_key: storage::WalletDecryptionKeyNew,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError>;
+
+    async fn find_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError>;
+
+    async fn list_wallet_decryption_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError>;
+
+    async fn list_wallet_decryption_keys_by_profile_id_wallet(
+        &self,
+        profile_id: &str,
+        wallet: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError>;
+
+    async fn list_wallet_decryption_keys_by_profile_id_wallet_key_identifier(
+        &self,
+        profile_id: &str,
+        wallet: &str,
+        key_identifier: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError>;
+
+    async fn update_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+        wallet_decryption_key_update: storage::WalletDecryptionKeyUpdate,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError>;
+
+    /// Lists the keys of every business profile of the merchant in the order of their key ids,
+    /// starting after the key id provided
+    async fn list_wallet_decryption_keys_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WalletDecryptionKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_wallet_decryption_key(
+        &self,
+        wallet_decryption_key: storage::WalletDecryptionKeyNew,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        wallet_decryption_key
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WalletDecryptionKey::find_by_profile_id_key_id(&conn, profile_id, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_wallet_decryption_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WalletDecryptionKey::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_wallet_decryption_keys_by_profile_id_wallet(
+        &self,
+        profile_id: &str,
+        wallet: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WalletDecryptionKey::list_by_profile_id_wallet(&conn, profile_id, wallet)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_wallet_decryption_keys_by_profile_id_wallet_key_identifier(
+        &self,
+        profile_id: &str,
+        wallet: &str,
+        key_identifier: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WalletDecryptionKey::list_by_profile_id_wallet_key_identifier(
+            &conn,
+            profile_id,
+            wallet,
+            key_identifier,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+        wallet_decryption_key_update: storage::WalletDecryptionKeyUpdate,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WalletDecryptionKey::update_by_profile_id_key_id(
+            &conn,
+            profile_id,
+            key_id,
+            wallet_decryption_key_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_wallet_decryption_keys_by_merchant_id_starting_after(
+        &self,
+        merchant_id: &str,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WalletDecryptionKey::list_by_merchant_id_starting_after(
+            &conn,
+            merchant_id,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WalletDecryptionKeyInterface for MockDb {
+    async fn insert_wallet_decryption_key(
+        &self,
+        _wallet_decryption_key: storage::WalletDecryptionKeyNew,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        _profile_id: &str,
+        _key_id: &str,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_wallet_decryption_keys_by_profile_id(
+        &self,
+        _profile_id: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_wallet_decryption_keys_by_profile_id_wallet(
+        &self,
+        _profile_id: &str,
+        _wallet: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_wallet_decryption_keys_by_profile_id_wallet_key_identifier(
+        &self,
+        _profile_id: &str,
+        _wallet: &str,
+        _key_identifier: &str,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_wallet_decryption_key_by_profile_id_key_id(
+        &self,
+        _profile_id: &str,
+        _key_id: &str,
+        _wallet_decryption_key_update: storage::WalletDecryptionKeyUpdate,
+    ) -> CustomResult<storage::WalletDecryptionKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_wallet_decryption_keys_by_merchant_id_starting_after(
+        &self,
+        _merchant_id: &str,
+        _starting_after: Option<String>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::WalletDecryptionKey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -17,6 +17,7 @@ use crate::{
 pub mod api_logs;
 pub mod audit_events;
 pub mod connector_api_logs;
+pub mod domain_events;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
 #[derive(Debug, Serialize, Clone, Copy)]
@@ -8,10 +8,11 @@ use time::OffsetDateTime;
 use super::EventType;
 #[cfg(feature = "dummy_connector")]
 use crate::routes::dummy_connector::types::{
-    DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
-    DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
-    DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
-    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorCaptureRequest, DummyConnectorPaymentCompleteRequest,
+    DummyConnectorPaymentConfirmRequest, DummyConnectorPaymentRequest,
+    DummyConnectorPaymentResponse, DummyConnectorPaymentRetrieveRequest,
+    DummyConnectorRefundRequest, DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorVoidRequest,
 };
 use crate::{
     core::payments::PaymentsRedirectResponseData,
@@ -129,7 +130,9 @@ impl_misc_api_event_type!(
     DummyConnectorPaymentConfirmRequest,
     DummyConnectorRefundRetrieveRequest,
     DummyConnectorRefundResponse,
-    DummyConnectorRefundRequest
+    DummyConnectorRefundRequest,
+    DummyConnectorCaptureRequest,
+    DummyConnectorVoidRequest
 );
 
 impl ApiEventMetric for PaymentsRedirectResponseData {
@@ -1,5 +1,7 @@
+use api_models::enums::FrmReviewAction;
+use common_enums::{BlocklistDataKind, BlocklistListType, CardExportStatus};
 use common_utils::types::MinorUnit;
-use diesel_models::fraud_check::FraudCheck;
+use diesel_models::{enums::FraudCheckStatus, fraud_check::FraudCheck};
 use events::{Event, EventInfo};
 use serde::Serialize;
 use time::PrimitiveDateTime;
@@ -27,6 +29,44 @@ pub enum AuditEventType {
         capture_amount: Option<MinorUnit>,
         multiple_capture_count: Option<i16>,
     },
+    BlocklistHit {
+        list_type: BlocklistListType,
+        data_kind: BlocklistDataKind,
+        fingerprint_id: String,
+    },
+    CaptureDeferredForFrmReview {
+        frm_status: FraudCheckStatus,
+        review_timeout_in_secs: Option<u32>,
+    },
+    FrmReviewResolved {
+        frm_status: FraudCheckStatus,
+        action: FrmReviewAction,
+        timed_out: bool,
+    },
+    CardExportRequested {
+        export_id: String,
+        receiving_psp: String,
+        requested_by: String,
+    },
+    CardExportReviewed {
+        export_id: String,
+        status: CardExportStatus,
+        reviewed_by: String,
+    },
+    CardExportCompleted {
+        export_id: String,
+        status: CardExportStatus,
+        exported_count: i32,
+        failed_count: i32,
+    },
+    CardExportDownloaded {
+        export_id: String,
+        downloaded_by: String,
+    },
+    RoutingAlgorithmMissing {
+        algorithm_id: String,
+        profile_id: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -65,6 +105,14 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::BlocklistHit { .. } => "blocklist_hit",
+            AuditEventType::CaptureDeferredForFrmReview { .. } => "capture_deferred_for_frm_review",
+            AuditEventType::FrmReviewResolved { .. } => "frm_review_resolved",
+            AuditEventType::CardExportRequested { .. } => "card_export_requested",
+            AuditEventType::CardExportReviewed { .. } => "card_export_reviewed",
+            AuditEventType::CardExportCompleted { .. } => "card_export_completed",
+            AuditEventType::CardExportDownloaded { .. } => "card_export_downloaded",
+            AuditEventType::RoutingAlgorithmMissing { .. } => "routing_algorithm_missing",
         };
         format!(
             "{event_type}-{}",
@@ -1,4 +1,4 @@
-pub use hyperswitch_interfaces::events::connector_api_logs::ConnectorEvent;
+pub use hyperswitch_interfaces::events::connector_api_logs::{ConnectorErrorClass, ConnectorEvent};
 
 use super::EventType;
 use crate::services::kafka::KafkaMessage;
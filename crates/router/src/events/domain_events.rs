@@ -0,0 +1,337 @@
+use std::sync::Arc;
+
+use diesel_models::refund::Refund;
+use external_services::file_storage::FileStorageInterface;
+use hyperswitch_domain_models::payments::PaymentIntent;
+use router_env::{logger, tracing::Instrument};
+use serde::{Deserialize, Serialize};
+use storage_impl::errors::ApplicationError;
+use time::PrimitiveDateTime;
+
+use super::{EventsConfig, EventsHandler};
+use crate::{
+    db::{kafka_store::TenantID, KafkaProducer},
+    types::storage::Dispute,
+};
+
+/// The sink to which the domain events of a tenant are exported
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "sink", rename_all = "snake_case")]
+pub enum DomainEventsConfig {
+    /// Published to the given topic of the Kafka cluster configured for the events
+    Kafka { topic: String },
+    /// Written as one JSON object per event under the given prefix of the file storage
+    S3 { prefix: String },
+}
+
+impl DomainEventsConfig {
+    pub fn validate(&self, events: &EventsConfig) -> Result<(), ApplicationError> {
+        use common_utils::ext_traits::ConfigExt;
+
+        // Domain events are published by the Kafka events store, which is only used when the
+        // events are sourced to Kafka
+        common_utils::fp_utils::when(!matches!(events, EventsConfig::Kafka { .. }), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Domain events export requires the events source to be kafka".into(),
+            ))
+        })?;
+
+        match self {
+            Self::Kafka { topic } => {
+                common_utils::fp_utils::when(topic.is_default_or_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "Domain events Kafka topic must not be empty".into(),
+                    ))
+                })
+            }
+            Self::S3 { prefix } => {
+                common_utils::fp_utils::when(prefix.is_default_or_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "Domain events S3 prefix must not be empty".into(),
+                    ))
+                })
+            }
+        }
+    }
+
+    pub fn get_domain_events_handler(
+        &self,
+        event_handler: &EventsHandler,
+        file_storage_client: Arc<dyn FileStorageInterface>,
+        tenant_id: TenantID,
+    ) -> Option<DomainEventsHandler> {
+        let sink = match (self, event_handler) {
+            (Self::Kafka { topic }, EventsHandler::Kafka(producer)) => DomainEventsSink::Kafka {
+                producer: producer.clone(),
+                topic: topic.clone(),
+            },
+            (Self::Kafka { .. }, EventsHandler::Logs(_)) => {
+                logger::error!(
+                    tenant_id = ?tenant_id,
+                    "Domain events cannot be exported to Kafka without a Kafka events source"
+                );
+                return None;
+            }
+            (Self::S3 { prefix }, _) => DomainEventsSink::S3 {
+                file_storage_client,
+                prefix: prefix.clone(),
+            },
+        };
+
+        Some(DomainEventsHandler { tenant_id, sink })
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DomainEventType {
+    PaymentStatusChanged,
+    RefundStatusChanged,
+    DisputeStatusChanged,
+    RoutingDecision,
+}
+
+/// A normalized event describing a change in the state of a resource of a merchant, exported for
+/// the merchants to build their own data warehouses
+#[derive(Debug, Serialize, Clone)]
+pub struct DomainEvent {
+    pub event_id: String,
+    pub event_type: DomainEventType,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub payment_id: String,
+    /// Identifier of the refund or the dispute, when the event is about one
+    pub resource_id: Option<String>,
+    pub previous_status: Option<String>,
+    pub status: Option<String>,
+    pub data: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl DomainEvent {
+    fn new(
+        event_type: DomainEventType,
+        merchant_id: String,
+        profile_id: Option<String>,
+        payment_id: String,
+    ) -> Self {
+        Self {
+            event_id: common_utils::generate_id(crate::consts::ID_LENGTH, "evt"),
+            event_type,
+            merchant_id,
+            profile_id,
+            payment_id,
+            resource_id: None,
+            previous_status: None,
+            status: None,
+            data: serde_json::Value::Null,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    /// Builds the event for a payment whose status changed, returns `None` when the status is
+    /// unchanged
+    pub fn from_payment_intent(
+        old_intent: Option<&PaymentIntent>,
+        intent: &PaymentIntent,
+    ) -> Option<Self> {
+        let previous_status = old_intent.map(|old_intent| old_intent.status);
+        if previous_status == Some(intent.status) {
+            return None;
+        }
+
+        Some(Self {
+            previous_status: previous_status.map(|status| status.to_string()),
+            status: Some(intent.status.to_string()),
+            data: serde_json::json!({
+                "amount": intent.amount,
+                "currency": intent.currency,
+                "amount_captured": intent.amount_captured,
+                "attempt_count": intent.attempt_count,
+            }),
+            ..Self::new(
+                DomainEventType::PaymentStatusChanged,
+                intent.merchant_id.clone(),
+                intent.profile_id.clone(),
+                intent.payment_id.clone(),
+            )
+        })
+    }
+
+    /// Builds the event for a refund whose status changed, returns `None` when the status is
+    /// unchanged
+    pub fn from_refund(old_refund: Option<&Refund>, refund: &Refund) -> Option<Self> {
+        let previous_status = old_refund.map(|old_refund| old_refund.refund_status);
+        if previous_status == Some(refund.refund_status) {
+            return None;
+        }
+
+        Some(Self {
+            resource_id: Some(refund.refund_id.clone()),
+            previous_status: previous_status.map(|status| status.to_string()),
+            status: Some(refund.refund_status.to_string()),
+            data: serde_json::json!({
+                "refund_amount": refund.refund_amount,
+                "currency": refund.currency,
+                "connector": refund.connector,
+            }),
+            ..Self::new(
+                DomainEventType::RefundStatusChanged,
+                refund.merchant_id.clone(),
+                refund.profile_id.clone(),
+                refund.payment_id.clone(),
+            )
+        })
+    }
+
+    /// Builds the event for a dispute whose status changed, returns `None` when the status is
+    /// unchanged
+    pub fn from_dispute(old_dispute: Option<&Dispute>, dispute: &Dispute) -> Option<Self> {
+        let previous_status = old_dispute.map(|old_dispute| old_dispute.dispute_status);
+        if previous_status == Some(dispute.dispute_status) {
+            return None;
+        }
+
+        Some(Self {
+            resource_id: Some(dispute.dispute_id.clone()),
+            previous_status: previous_status.map(|status| status.to_string()),
+            status: Some(dispute.dispute_status.to_string()),
+            data: serde_json::json!({
+                "amount": dispute.amount,
+                "currency": dispute.currency,
+                "connector": dispute.connector,
+                "dispute_stage": dispute.dispute_stage,
+            }),
+            ..Self::new(
+                DomainEventType::DisputeStatusChanged,
+                dispute.merchant_id.clone(),
+                dispute.profile_id.clone(),
+                dispute.payment_id.clone(),
+            )
+        })
+    }
+
+    /// Builds the event for the connectors chosen by the routing of a payment, in the order in
+    /// which they are tried
+    pub fn routing_decision(
+        intent: &PaymentIntent,
+        algorithm_id: Option<String>,
+        connectors: Vec<String>,
+    ) -> Self {
+        Self {
+            data: serde_json::json!({
+                "algorithm_id": algorithm_id,
+                "connectors": connectors,
+            }),
+            ..Self::new(
+                DomainEventType::RoutingDecision,
+                intent.merchant_id.clone(),
+                intent.profile_id.clone(),
+                intent.payment_id.clone(),
+            )
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DomainEventRecord<'a> {
+    #[serde(flatten)]
+    event: &'a DomainEvent,
+    tenant_id: TenantID,
+}
+
+impl DomainEventRecord<'_> {
+    pub(crate) fn key(&self) -> String {
+        format!("{}_{}", self.event.merchant_id, self.event.payment_id)
+    }
+
+    pub(crate) fn creation_timestamp(&self) -> i64 {
+        self.event.created_at.assume_utc().unix_timestamp() * 1_000
+    }
+}
+
+#[derive(Clone)]
+enum DomainEventsSink {
+    Kafka {
+        producer: KafkaProducer,
+        topic: String,
+    },
+    S3 {
+        file_storage_client: Arc<dyn FileStorageInterface>,
+        prefix: String,
+    },
+}
+
+impl std::fmt::Debug for DomainEventsSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Kafka { topic, .. } => f.debug_struct("Kafka").field("topic", topic).finish(),
+            Self::S3 { prefix, .. } => f.debug_struct("S3").field("prefix", prefix).finish(),
+        }
+    }
+}
+
+/// Exports the domain events of a tenant to the sink configured for it
+#[derive(Debug, Clone)]
+pub struct DomainEventsHandler {
+    tenant_id: TenantID,
+    sink: DomainEventsSink,
+}
+
+impl DomainEventsHandler {
+    /// Publishes the event to the sink of the tenant. Failures are logged and never fail the
+    /// operation which produced the event.
+    pub fn publish(&self, event: DomainEvent) {
+        let record = DomainEventRecord {
+            event: &event,
+            tenant_id: self.tenant_id.clone(),
+        };
+
+        match &self.sink {
+            DomainEventsSink::Kafka { producer, topic } => {
+                if let Err(error) = producer.log_domain_event(topic, &record) {
+                    logger::error!(message = "Failed to publish domain event", ?event, ?error);
+                }
+            }
+            DomainEventsSink::S3 {
+                file_storage_client,
+                prefix,
+            } => {
+                let payload = match serde_json::to_vec(&record) {
+                    Ok(payload) => payload,
+                    Err(error) => {
+                        logger::error!(message = "Failed to serialize domain event", ?error);
+                        return;
+                    }
+                };
+                let file_key = format!(
+                    "{prefix}/{tenant}/{event_type}/{date}/{event_id}.json",
+                    tenant = self.tenant_id.0,
+                    event_type = event.event_type,
+                    date = event.created_at.date(),
+                    event_id = event.event_id,
+                );
+                let file_storage_client = Arc::clone(file_storage_client);
+
+                // The upload is not awaited, to keep the latency of the storage operations which
+                // produce the events unaffected
+                tokio::spawn(
+                    async move {
+                        if let Err(error) =
+                            file_storage_client.upload_file(&file_key, payload).await
+                        {
+                            logger::error!(
+                                message = "Failed to write domain event",
+                                file_key,
+                                ?error
+                            );
+                        }
+                    }
+                    .in_current_span(),
+                );
+            }
+        }
+    }
+}
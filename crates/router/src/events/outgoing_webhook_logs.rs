@@ -1,4 +1,5 @@
 use api_models::{enums::EventType as OutgoingWebhookEventType, webhooks::OutgoingWebhookContent};
+use common_utils::id_type;
 use serde::Serialize;
 use serde_json::Value;
 use time::OffsetDateTime;
@@ -47,6 +48,10 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    Customer {
+        customer_id: id_type::CustomerId,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -84,6 +89,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::CustomerRedactionDetails(redaction_payload) => {
+                Some(OutgoingWebhookEventContent::Customer {
+                    customer_id: redaction_payload.customer_id.clone(),
+                    content: masking::masked_serialize(&redaction_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
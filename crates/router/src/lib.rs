@@ -55,11 +55,14 @@ pub mod headers {
     pub const AUTHORIZATION: &str = "Authorization";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
+    pub const DEPRECATION: &str = "Deprecation";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
     pub const X_API_KEY: &str = "X-API-KEY";
+    pub const X_ADAPTER_PROTOCOL_VERSION: &str = "X-Adapter-Protocol-Version";
+    pub const X_ADAPTER_SIGNATURE: &str = "X-Adapter-Signature";
     pub const X_API_VERSION: &str = "X-ApiVersion";
     pub const X_FORWARDED_FOR: &str = "X-Forwarded-For";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
@@ -71,12 +74,19 @@ pub mod headers {
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
+    pub const X_NEXT_CURSOR: &str = "X-Next-Cursor";
+    pub const SUNSET: &str = "Sunset";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
     pub const X_CLIENT_VERSION: &str = "X-Client-Version";
     pub const X_CLIENT_SOURCE: &str = "X-Client-Source";
+    pub const X_CONNECTED_ACCOUNT_ID: &str = "X-Connected-Account-Id";
     pub const X_PAYMENT_CONFIRM_SOURCE: &str = "X-Payment-Confirm-Source";
     pub const CONTENT_LENGTH: &str = "Content-Length";
+    pub const X_REQUEST_SIGNATURE: &str = "X-Request-Signature";
+    pub const X_REQUEST_TIMESTAMP: &str = "X-Request-Timestamp";
+    /// Set by the router alone, holding the SHA-256 digest of the body of a signed request
+    pub const X_INTERNAL_REQUEST_BODY_DIGEST: &str = "X-Internal-Request-Body-Digest";
 }
 
 pub mod pii {
@@ -107,6 +117,11 @@ pub fn mk_app(
         server_app = server_app.service(DummyConnector::server(state.clone()));
     }
 
+    #[cfg(all(feature = "dummy_connector", feature = "oltp"))]
+    {
+        server_app = server_app.service(routes::SandboxTraffic::server(state.clone()));
+    }
+
     #[cfg(any(feature = "olap", feature = "oltp"))]
     {
         #[cfg(feature = "olap")]
@@ -123,15 +138,20 @@ pub fn mk_app(
             .service(routes::Refunds::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()))
+            .service(routes::Vault::server(state.clone()))
     }
 
     #[cfg(feature = "oltp")]
     {
         server_app = server_app
             .service(routes::EphemeralKey::server(state.clone()))
+            .service(routes::OAuth2::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()))
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::Poll::server(state.clone()))
+            .service(routes::Jobs::server(state.clone()))
+            .service(routes::Authentication::server(state.clone()))
+            .service(routes::PaymentMethodSessions::server(state.clone()))
     }
 
     #[cfg(feature = "olap")]
@@ -144,7 +164,24 @@ pub fn mk_app(
             .service(routes::Analytics::server(state.clone()))
             .service(routes::Routing::server(state.clone()))
             .service(routes::Blocklist::server(state.clone()))
+            .service(routes::Reports::server(state.clone()))
+            .service(routes::Settlements::server(state.clone()))
+            .service(routes::Ledger::server(state.clone()))
+            .service(routes::ConnectedAccounts::server(state.clone()))
+            .service(routes::AuditEvents::server(state.clone()))
+            .service(routes::Orders::server(state.clone()))
+            .service(routes::Organization::server(state.clone()))
+            .service(routes::Tenants::server(state.clone()))
+            .service(routes::ConnectorCapabilities::server(state.clone()))
+            .service(routes::Search::server(state.clone()))
+            .service(routes::CardExport::server(state.clone()))
+            .service(routes::ConnectorMigration::server(state.clone()))
             .service(routes::Gsm::server(state.clone()))
+            .service(routes::FeatureFlags::server(state.clone()))
+            .service(routes::PmFilters::server(state.clone()))
+            .service(routes::BulkForceSync::server(state.clone()))
+            .service(routes::ProcessTracker::server(state.clone()))
+            .service(routes::MasterKeyRotation::server(state.clone()))
             .service(routes::ApplePayCertificatesMigration::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()))
             .service(routes::User::server(state.clone()))
@@ -153,6 +190,11 @@ pub fn mk_app(
             .service(routes::WebhookEvents::server(state.clone()));
     }
 
+    #[cfg(feature = "graphql")]
+    {
+        server_app = server_app.service(routes::GraphQL::server(state.clone()));
+    }
+
     #[cfg(feature = "payouts")]
     {
         server_app = server_app
@@ -172,6 +214,7 @@ pub fn mk_app(
 
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::Logging::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
 
     server_app
@@ -269,6 +312,7 @@ pub fn get_application_builder(
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
         .wrap(middleware::Http400RequestDetailsLogger)
+        .wrap(middleware::RequestBodyDigest)
         .wrap(middleware::LogSpanInitializer)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
 }
@@ -315,3 +315,93 @@ where
         })
     }
 }
+
+/// Middleware computing the digest of the body of the signed requests, for the signature to be
+/// verified once the merchant making the request is authenticated
+pub struct RequestBodyDigest;
+
+impl<S: 'static, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest>
+    for RequestBodyDigest
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RequestBodyDigestMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RequestBodyDigestMiddleware {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub struct RequestBodyDigestMiddleware<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for RequestBodyDigestMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use common_utils::crypto::GenerateDigest;
+
+        let svc = self.service.clone();
+        // The digest must never be supplied by the client
+        req.headers_mut()
+            .remove(headers::X_INTERNAL_REQUEST_BODY_DIGEST);
+        if !req.headers().contains_key(headers::X_REQUEST_SIGNATURE) {
+            return Box::pin(svc.call(req));
+        }
+
+        Box::pin(async move {
+            let (http_req, payload) = req.into_parts();
+            let result_payload: Vec<Result<bytes::Bytes, actix_web::error::PayloadError>> =
+                payload.collect().await;
+            let bytes = result_payload
+                .into_iter()
+                .collect::<Result<Vec<bytes::Bytes>, actix_web::error::PayloadError>>()?
+                .concat();
+            let digest = common_utils::crypto::Sha256
+                .generate_digest(&bytes)
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to compute the digest of the request body");
+                    actix_web::error::ErrorInternalServerError("Failed to read the request body")
+                })?;
+
+            // we are creating h1 payload manually from bytes, currently there's no way to create http2 payload with actix
+            let (_, mut new_payload) = actix_http::h1::Payload::create(true);
+            new_payload.unread_data(bytes.into());
+            let mut new_req =
+                actix_web::dev::ServiceRequest::from_parts(http_req, new_payload.into());
+            new_req.headers_mut().insert(
+                http::header::HeaderName::from_static("x-internal-request-body-digest"),
+                http::HeaderValue::from_str(&hex::encode(digest))?,
+            );
+
+            svc.call(new_req).await
+        })
+    }
+}
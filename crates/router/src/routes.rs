@@ -3,11 +3,25 @@ pub mod api_keys;
 pub mod app;
 pub mod apple_pay_certificates_migration;
 #[cfg(feature = "olap")]
+pub mod audit_events;
+#[cfg(feature = "oltp")]
+pub mod authentication;
+#[cfg(feature = "olap")]
 pub mod blocklist;
+#[cfg(feature = "olap")]
+pub mod bulk_force_sync;
 pub mod cache;
+#[cfg(feature = "olap")]
+pub mod card_export;
 pub mod cards_info;
 pub mod configs;
 #[cfg(feature = "olap")]
+pub mod connected_accounts;
+#[cfg(feature = "olap")]
+pub mod connector_capabilities;
+#[cfg(feature = "olap")]
+pub mod connector_migration;
+#[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
@@ -16,16 +30,32 @@ pub mod disputes;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
 pub mod ephemeral_key;
+#[cfg(feature = "olap")]
+pub mod feature_flags;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
+#[cfg(feature = "graphql")]
+pub mod graphql;
 pub mod gsm;
 pub mod health;
+pub mod jobs;
+#[cfg(feature = "olap")]
+pub mod ledger;
 pub mod lock_utils;
 pub mod locker_migration;
+pub mod logging;
 pub mod mandates;
+#[cfg(feature = "olap")]
+pub mod master_key_rotation;
 pub mod metrics;
+#[cfg(feature = "olap")]
+pub mod orders;
+#[cfg(feature = "olap")]
+pub mod organization;
 pub mod payment_link;
+#[cfg(feature = "oltp")]
+pub mod payment_method_sessions;
 pub mod payment_methods;
 pub mod payments;
 pub mod payout_link;
@@ -33,16 +63,32 @@ pub mod payout_link;
 pub mod payouts;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod pm_auth;
+#[cfg(feature = "olap")]
+pub mod pm_filters;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
+pub mod reports;
+#[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(all(feature = "dummy_connector", feature = "oltp"))]
+pub mod sandbox_traffic;
+#[cfg(feature = "olap")]
+pub mod search;
+#[cfg(feature = "olap")]
+pub mod settlements;
+#[cfg(feature = "olap")]
+pub mod tenants;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
+#[cfg(any(feature = "olap", feature = "oltp"))]
+pub mod vault_tokens;
 #[cfg(feature = "olap")]
 pub mod verification;
 #[cfg(feature = "olap")]
@@ -55,16 +101,25 @@ pub mod webhooks;
 pub use self::app::DummyConnector;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub use self::app::Forex;
+#[cfg(feature = "graphql")]
+pub use self::app::GraphQL;
 #[cfg(all(feature = "olap", feature = "recon"))]
 pub use self::app::Recon;
+#[cfg(all(feature = "dummy_connector", feature = "oltp"))]
+pub use self::app::SandboxTraffic;
 pub use self::app::{
-    ApiKeys, AppState, ApplePayCertificatesMigration, BusinessProfile, Cache, Cards, Configs,
-    ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Gsm, Health, Mandates,
-    MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll,
-    Refunds, SessionState, User, Webhooks,
+    ApiKeys, AppState, ApplePayCertificatesMigration, Authentication, BusinessProfile, Cache,
+    Cards, Configs, ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Gsm, Health,
+    Jobs, Logging, Mandates, MerchantAccount, MerchantConnectorAccount, OAuth2, PaymentLink,
+    PaymentMethodSessions, PaymentMethods, Payments, Poll, Refunds, SessionState, User, Vault,
+    Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    AuditEvents, Blocklist, BulkForceSync, CardExport, ConnectedAccounts, ConnectorCapabilities,
+    ConnectorMigration, FeatureFlags, Ledger, MasterKeyRotation, Orders, Organization, PmFilters,
+    ProcessTracker, Reports, Routing, Search, Settlements, Tenants, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(feature = "stripe")]
@@ -2,8 +2,14 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "olap")]
+use crate::core::verify_connector;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{
+        admin::*, alerts, api_locking, api_versioning, connector_credentials,
+        merchant_key_rotation, pm_display_rules, profile_cloning, rate_limits,
+        response_field_access, velocity_limits, wallet_decryption_keys,
+    },
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -433,6 +439,126 @@ pub async fn payment_connector_delete(
     ))
     .await
 }
+
+/// Merchant Connector - Validate
+///
+/// Validate the credentials of a Merchant Connector with a live low impact call to the connector
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsValidate))]
+pub async fn payment_connector_validate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsValidate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            verify_connector::validate_merchant_connector_account(
+                state,
+                req.merchant_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Stage Next Credentials
+///
+/// Stage the credentials to replace the current credentials of a Merchant Connector on the next cutover
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsStage))]
+pub async fn payment_connector_credentials_stage(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::MerchantConnectorNextCredentialsRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsStage;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            connector_credentials::stage_next_credentials(
+                state,
+                merchant_id.clone(),
+                merchant_connector_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantConnectorAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Cutover Credentials
+///
+/// Make the staged credentials the current credentials of a Merchant Connector
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsCutover))]
+pub async fn payment_connector_credentials_cutover(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::MerchantConnectorCredentialsCutoverRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsCutover;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            connector_credentials::cutover_credentials(
+                state,
+                merchant_id.clone(),
+                merchant_connector_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantConnectorAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Account - Toggle KV
 ///
 /// Toggle KV mode for the Merchant Account
@@ -525,12 +651,13 @@ pub async fn business_profile_retrieve(
         flow,
         state,
         &req,
-        profile_id,
+        profile_id.clone(),
         |state, _, profile_id, _| retrieve_business_profile(state, profile_id),
         auth::auth_type(
             &auth::AdminApiAuth,
-            &auth::JWTAuthMerchantFromRoute {
+            &auth::JWTAuthProfileFromRoute {
                 merchant_id,
+                profile_id,
                 required_permission: Permission::MerchantAccountRead,
             },
             req.headers(),
@@ -557,8 +684,9 @@ pub async fn business_profile_update(
         |state, _, req, _| update_business_profile(state, &profile_id, &merchant_id, req),
         auth::auth_type(
             &auth::AdminApiAuth,
-            &auth::JWTAuthMerchantFromRoute {
+            &auth::JWTAuthProfileFromRoute {
                 merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
                 required_permission: Permission::MerchantAccountWrite,
             },
             req.headers(),
@@ -615,6 +743,127 @@ pub async fn business_profiles_list(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileClone))]
+pub async fn business_profile_clone(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::profile_cloning::BusinessProfileCloneRequest>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileClone;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            profile_cloning::clone_business_profile(state, &merchant_id, profile_id.clone(), req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileTemplateUpsert))]
+pub async fn business_profile_template_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::profile_cloning::BusinessProfileTemplateRequest>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileTemplateUpsert;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            profile_cloning::upsert_profile_template(state, merchant_id.clone(), req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileTemplatesList))]
+pub async fn business_profile_templates_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileTemplatesList;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| profile_cloning::list_profile_templates(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileTemplateDelete))]
+pub async fn business_profile_template_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileTemplateDelete;
+    let (merchant_id, template_name) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        template_name,
+        |state, _, template_name, _| {
+            profile_cloning::delete_profile_template(state, merchant_id.clone(), template_name)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileTemplateApply))]
+pub async fn business_profile_template_apply(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::profile_cloning::BusinessProfileTemplateApplyRequest>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileTemplateApply;
+    let (merchant_id, template_name) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            profile_cloning::apply_profile_template(
+                state,
+                merchant_id.clone(),
+                template_name.clone(),
+                req,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::ToggleConnectorAgnosticMit))]
 pub async fn toggle_connector_agnostic_mit(
     state: web::Data<AppState>,
@@ -664,24 +913,717 @@ pub async fn merchant_account_kv_status(
     .await
 }
 
-#[instrument(skip_all, fields(flow = ?Flow::ToggleExtendedCardInfo))]
-pub async fn toggle_extended_card_info(
+/// Merchant Account - Retrieve KV Settings
+///
+/// Retrieve the KV settings of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKvSettingsRetrieve))]
+pub async fn merchant_account_kv_settings_retrieve(
     state: web::Data<AppState>,
     req: HttpRequest,
-    path: web::Path<(String, String)>,
-    json_payload: web::Json<api_models::admin::ExtendedCardInfoChoice>,
+    path: web::Path<String>,
 ) -> HttpResponse {
-    let flow = Flow::ToggleExtendedCardInfo;
-    let (_, profile_id) = path.into_inner();
+    let flow = Flow::MerchantKvSettingsRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| retrieve_merchant_kv_settings(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update KV Settings
+///
+/// Update the KV settings of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKvSettingsUpdate))]
+pub async fn merchant_account_kv_settings_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::MerchantKvSettingsUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKvSettingsUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| update_merchant_kv_settings(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Rate Limits
+///
+/// Retrieve the quotas of requests enforced on the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantRateLimitsRetrieve))]
+pub async fn merchant_account_rate_limits_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantRateLimitsRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| rate_limits::retrieve_rate_limits(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update Rate Limits
+///
+/// Override the quotas of requests enforced on the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantRateLimitsUpdate))]
+pub async fn merchant_account_rate_limits_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::rate_limits::MerchantRateLimitsRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantRateLimitsUpdate;
+    let merchant_id = path.into_inner();
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| extended_card_info_toggle(state, &profile_id, req),
+        |state, _, payload, _| rate_limits::update_rate_limits(state, merchant_id.clone(), payload),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
+
+/// Merchant Account - Retrieve Alert Rules
+///
+/// Retrieve the alert rules evaluated for the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantAlertRulesRetrieve))]
+pub async fn merchant_account_alert_rules_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantAlertRulesRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| alerts::retrieve_alert_rules(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update Alert Rules
+///
+/// Replace the alert rules evaluated for the Merchant Account and where their alerts are delivered
+#[instrument(skip_all, fields(flow = ?Flow::MerchantAlertRulesUpdate))]
+pub async fn merchant_account_alert_rules_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::alerts::MerchantAlertRulesRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantAlertRulesUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| alerts::update_alert_rules(state, merchant_id.clone(), payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Response Field Access
+///
+/// Retrieve how the sensitive fields of the API responses are returned to the restricted callers
+/// of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantResponseFieldAccessRetrieve))]
+pub async fn merchant_account_response_field_access_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantResponseFieldAccessRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| {
+            response_field_access::retrieve_response_field_access(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update Response Field Access
+///
+/// Configure how the sensitive fields of the API responses are returned to the restricted callers
+/// of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantResponseFieldAccessUpdate))]
+pub async fn merchant_account_response_field_access_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::response_field_access::ResponseFieldAccessRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantResponseFieldAccessUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            response_field_access::update_response_field_access(state, merchant_id.clone(), payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve API Version
+///
+/// Retrieve the version of the API responses of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantApiVersionRetrieve))]
+pub async fn merchant_account_api_version_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantApiVersionRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| api_versioning::retrieve_api_version_pin(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Pin API Version
+///
+/// Pin the version of the API responses of the Merchant Account, used for the requests without
+/// the `X-API-Version` header
+#[instrument(skip_all, fields(flow = ?Flow::MerchantApiVersionUpdate))]
+pub async fn merchant_account_api_version_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::api_versioning::ApiVersionPinRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantApiVersionUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            api_versioning::update_api_version_pin(state, merchant_id.clone(), payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Schedule Key Rotation
+///
+/// Rotate the key the data of the Merchant Account is encrypted with
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationSchedule))]
+pub async fn merchant_account_key_rotation_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::merchant_key_rotation::MerchantKeyRotationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationSchedule;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            merchant_key_rotation::schedule_merchant_key_rotation(
+                state,
+                merchant_id.clone(),
+                payload,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Key Rotation
+///
+/// Retrieve the status of a key rotation of the Merchant Account, along with its progress
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationRetrieve))]
+pub async fn merchant_account_key_rotation_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationRetrieve;
+    let (merchant_id, job_id) = path.into_inner();
+    let payload = api_models::merchant_key_rotation::MerchantKeyRotationJobId {
+        merchant_id,
+        job_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            merchant_key_rotation::retrieve_merchant_key_rotation(state, payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Onboarding Status
+///
+/// Retrieve which steps of the onboarding of the Merchant Account are complete
+#[instrument(skip_all, fields(flow = ?Flow::MerchantOnboardingStatus))]
+pub async fn merchant_account_onboarding_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantOnboardingStatus;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.clone(),
+        |state, _, merchant_id, _| retrieve_onboarding_status(state, merchant_id),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Drainer Tuning
+///
+/// Retrieve the tuning of the drainer applied at runtime
+#[instrument(skip_all, fields(flow = ?Flow::DrainerTuningRetrieve))]
+pub async fn drainer_tuning_retrieve(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::DrainerTuningRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| retrieve_drainer_tuning(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update Drainer Tuning
+///
+/// Update the tuning of the drainer applied at runtime
+#[instrument(skip_all, fields(flow = ?Flow::DrainerTuningUpdate))]
+pub async fn drainer_tuning_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<admin::DrainerTuning>,
+) -> HttpResponse {
+    let flow = Flow::DrainerTuningUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| update_drainer_tuning(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve KV Backlog
+///
+/// Retrieve the number of entries of each drainer shard waiting to be drained to the database
+#[instrument(skip_all, fields(flow = ?Flow::KvBacklogRetrieve))]
+pub async fn kv_backlog_retrieve(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::KvBacklogRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| retrieve_kv_backlog(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ToggleExtendedCardInfo))]
+pub async fn toggle_extended_card_info(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::ExtendedCardInfoChoice>,
+) -> HttpResponse {
+    let flow = Flow::ToggleExtendedCardInfo;
+    let (_, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| extended_card_info_toggle(state, &profile_id, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::VelocityLimitsUpsert))]
+pub async fn velocity_limits_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::velocity_limits::VelocityLimitsRequest>,
+) -> HttpResponse {
+    let flow = Flow::VelocityLimitsUpsert;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            velocity_limits::upsert_velocity_limits(state, &merchant_id, profile_id.clone(), req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::VelocityLimitsRetrieve))]
+pub async fn velocity_limits_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::VelocityLimitsRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id, _| {
+            velocity_limits::retrieve_velocity_limits(state, &merchant_id, profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::VelocityLimitsDelete))]
+pub async fn velocity_limits_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::VelocityLimitsDelete;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id, _| {
+            velocity_limits::delete_velocity_limits(state, &merchant_id, profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmDisplayRulesUpsert))]
+pub async fn pm_display_rules_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::pm_display_rules::PaymentMethodDisplayRulesRequest>,
+) -> HttpResponse {
+    let flow = Flow::PmDisplayRulesUpsert;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            pm_display_rules::upsert_pm_display_rules(state, &merchant_id, profile_id.clone(), req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmDisplayRulesRetrieve))]
+pub async fn pm_display_rules_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::PmDisplayRulesRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id, _| {
+            pm_display_rules::retrieve_pm_display_rules(state, &merchant_id, profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmDisplayRulesDelete))]
+pub async fn pm_display_rules_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::PmDisplayRulesDelete;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id, _| {
+            pm_display_rules::delete_pm_display_rules(state, &merchant_id, profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WalletDecryptionKeyCreate))]
+pub async fn wallet_decryption_key_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::wallet_decryption_keys::WalletDecryptionKeyCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WalletDecryptionKeyCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            wallet_decryption_keys::create_wallet_decryption_key(
+                state,
+                &merchant_id,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WalletDecryptionKeysList))]
+pub async fn wallet_decryption_keys_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WalletDecryptionKeysList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id, _| {
+            wallet_decryption_keys::list_wallet_decryption_keys(state, &merchant_id, profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WalletDecryptionKeyRetire))]
+pub async fn wallet_decryption_key_retire(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+    json_payload: web::Json<api_models::wallet_decryption_keys::WalletDecryptionKeyRetireRequest>,
+) -> HttpResponse {
+    let flow = Flow::WalletDecryptionKeyRetire;
+    let (merchant_id, profile_id, key_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            wallet_decryption_keys::retire_wallet_decryption_key(
+                state,
+                &merchant_id,
+                profile_id.clone(),
+                key_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
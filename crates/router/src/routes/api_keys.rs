@@ -248,3 +248,76 @@ pub async fn api_key_list(
     )
     .await
 }
+
+/// OAuth2 - Token
+///
+/// Issue a short-lived access token with the client credentials grant, the client being
+/// identified by the identifier of an API Key and authenticated with the API Key. The access
+/// token can be used in place of the API Key by sending it in the `Authorization` header as a
+/// bearer token.
+#[utoipa::path(
+    post,
+    path = "/oauth2/token",
+    request_body(content = OAuth2TokenRequest, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Access token issued", body = OAuth2TokenResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Client authentication failed")
+    ),
+    tag = "OAuth2",
+    operation_id = "Issue an OAuth2 Access Token"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OAuth2TokenCreate))]
+pub async fn oauth2_token_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    form_payload: web::Form<api_types::OAuth2TokenRequest>,
+) -> impl Responder {
+    let flow = Flow::OAuth2TokenCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        form_payload.into_inner(),
+        |state, _, payload, _| api_keys::issue_oauth2_access_token(state, payload),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// OAuth2 - Revoke
+///
+/// Revoke an access token before it expires, the client authenticating itself the same way as
+/// when issuing the access token.
+#[utoipa::path(
+    post,
+    path = "/oauth2/revoke",
+    request_body(content = OAuth2RevokeRequest, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Access token revoked"),
+        (status = 401, description = "Client authentication failed")
+    ),
+    tag = "OAuth2",
+    operation_id = "Revoke an OAuth2 Access Token"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OAuth2TokenRevoke))]
+pub async fn oauth2_token_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    form_payload: web::Form<api_types::OAuth2RevokeRequest>,
+) -> impl Responder {
+    let flow = Flow::OAuth2TokenRevoke;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        form_payload.into_inner(),
+        |state, _, payload, _| api_keys::revoke_oauth2_access_token(state, payload),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
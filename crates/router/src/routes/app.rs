@@ -1,8 +1,12 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use actix_web::{web, Scope};
 #[cfg(all(feature = "business_profile_routing", feature = "olap"))]
-use api_models::routing::RoutingRetrieveQuery;
+use api_models::{routing::RoutingRetrieveQuery, tenant::TenantStatus};
 #[cfg(feature = "olap")]
 use common_enums::TransactionType;
 #[cfg(feature = "email")]
@@ -12,22 +16,61 @@ use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
 };
-use router_env::tracing_actix_web::RequestId;
+use router_env::{logger, tracing_actix_web::RequestId};
 use scheduler::SchedulerInterface;
 use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
 #[cfg(feature = "olap")]
+use super::audit_events;
+#[cfg(feature = "olap")]
 use super::blocklist;
+#[cfg(feature = "olap")]
+use super::bulk_force_sync;
+#[cfg(feature = "olap")]
+use super::card_export;
+#[cfg(feature = "olap")]
+use super::connected_accounts;
+#[cfg(feature = "olap")]
+use super::connector_capabilities;
+#[cfg(feature = "olap")]
+use super::connector_migration;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
+#[cfg(feature = "olap")]
+use super::feature_flags;
+#[cfg(feature = "graphql")]
+use super::graphql;
+#[cfg(feature = "olap")]
+use super::ledger;
+use super::logging;
+#[cfg(feature = "olap")]
+use super::master_key_rotation;
+#[cfg(feature = "olap")]
+use super::orders;
+#[cfg(feature = "olap")]
+use super::organization;
 #[cfg(feature = "payouts")]
 use super::payout_link::*;
 #[cfg(feature = "payouts")]
 use super::payouts::*;
 #[cfg(feature = "olap")]
+use super::pm_filters;
+#[cfg(feature = "olap")]
+use super::process_tracker;
+#[cfg(feature = "olap")]
+use super::reports;
+#[cfg(feature = "olap")]
 use super::routing as cloud_routing;
+#[cfg(all(feature = "dummy_connector", feature = "oltp"))]
+use super::sandbox_traffic;
+#[cfg(feature = "olap")]
+use super::search;
+#[cfg(feature = "olap")]
+use super::settlements;
+#[cfg(feature = "olap")]
+use super::tenants;
 #[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
@@ -35,15 +78,19 @@ use super::{
     admin::*, api_keys::*, apple_pay_certificates_migration, connector_onboarding::*, disputes::*,
     files::*, gsm::*, payment_link::*, user::*, user_role::*, webhook_events::*,
 };
+#[cfg(feature = "oltp")]
+use super::{
+    api_keys::{oauth2_token_create, oauth2_token_revoke},
+    ephemeral_key::*,
+    webhooks::*,
+};
+#[cfg(feature = "oltp")]
+use super::{authentication, jobs, payment_method_sessions, pm_auth, poll::retrieve_poll_status};
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{currency, payment_methods::*};
-#[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, webhooks::*};
-#[cfg(feature = "oltp")]
-use super::{pm_auth, poll::retrieve_poll_status};
+use super::{currency, payment_methods::*, vault_tokens};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -60,11 +107,18 @@ pub use crate::{
     db::{CommonStorageInterface, GlobalStorageInterface, StorageImpl, StorageInterface},
     events::EventsHandler,
     routes::cards_info::card_iin_info,
-    services::{get_cache_store, get_store},
+    services::{
+        authentication::{ApiKeyRequestContext, AuthenticationType},
+        get_cache_store, get_store,
+    },
 };
 use crate::{
     configs::{secrets_transformers, Settings},
+    core::{connector_exchanges::ConnectorDebugCapture, tenants::get_provisioned_tenant_config},
     db::kafka_store::{KafkaStore, TenantID},
+    events::domain_events::DomainEventsHandler,
+    services::key_management,
+    types::storage,
 };
 
 #[derive(Clone)]
@@ -90,6 +144,14 @@ pub struct SessionState {
     pub tenant: Tenant,
     #[cfg(feature = "olap")]
     pub opensearch_client: Arc<OpenSearchClient>,
+    pub domain_events: Option<DomainEventsHandler>,
+    /// Set while calling the connectors for a payment of a profile with the connector debug
+    /// capture enabled
+    pub connector_debug_capture: Option<ConnectorDebugCapture>,
+    /// Details of the incoming request the restrictions of the API keys are enforced against
+    pub api_key_request_context: Option<ApiKeyRequestContext>,
+    /// Authentication of the request being served, recorded as the actor of the audit events
+    pub auth_type: Option<AuthenticationType>,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -102,6 +164,27 @@ impl SessionState {
             event_context: events::EventContext::new(self.event_handler.clone()),
         }
     }
+
+    /// The state to call the connectors with for the payments of the profile, capturing the
+    /// exchanges with the connectors when the connector debug capture is enabled for the profile
+    pub fn with_connector_debug_capture(
+        &self,
+        business_profile: &storage::business_profile::BusinessProfile,
+    ) -> Cow<'_, Self> {
+        match ConnectorDebugCapture::for_profile(business_profile) {
+            Some(connector_debug_capture) => Cow::Owned(Self {
+                connector_debug_capture: Some(connector_debug_capture),
+                ..self.clone()
+            }),
+            None => Cow::Borrowed(self),
+        }
+    }
+
+    /// The state recording the status transitions of the payments it updates with the actor
+    pub fn with_status_actor(mut self, status_actor: storage::enums::PaymentStatusActor) -> Self {
+        self.store.add_status_actor(status_actor);
+        self
+    }
 }
 
 pub trait SessionStateInfo {
@@ -110,6 +193,7 @@ pub trait SessionStateInfo {
     fn event_handler(&self) -> EventsHandler;
     fn get_request_id(&self) -> Option<String>;
     fn add_request_id(&mut self, request_id: RequestId);
+    fn get_api_key_request_context(&self) -> Option<ApiKeyRequestContext>;
 }
 
 impl SessionStateInfo for SessionState {
@@ -130,6 +214,9 @@ impl SessionStateInfo for SessionState {
         self.store.add_request_id(request_id.to_string());
         self.request_id.replace(request_id);
     }
+    fn get_api_key_request_context(&self) -> Option<ApiKeyRequestContext> {
+        self.api_key_request_context.clone()
+    }
 }
 #[derive(Clone)]
 pub struct AppState {
@@ -148,10 +235,40 @@ pub struct AppState {
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
+    pub domain_events: HashMap<String, DomainEventsHandler>,
+    /// The tenants provisioned at runtime, shared by the clones of the application state so that
+    /// a tenant provisioned through one of the workers is served by all of them
+    pub provisioned_tenants: Arc<RwLock<HashMap<String, ProvisionedTenant>>>,
+    pub storage_impl: StorageImpl,
+    pub cache_store: Arc<RedisStore>,
+}
+
+/// The stores of a tenant and the handlers of its events
+#[derive(Clone)]
+pub struct TenantState {
+    pub store: Box<dyn StorageInterface>,
+    #[cfg(feature = "olap")]
+    pub pool: AnalyticsProvider,
+    pub domain_events: Option<DomainEventsHandler>,
+}
+
+/// A tenant provisioned at runtime through the tenants API
+#[derive(Clone)]
+pub struct ProvisionedTenant {
+    pub tenant: Tenant,
+    pub status: TenantStatus,
+    pub state: TenantState,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<String> {
-        self.conf.multitenancy.get_tenant_names()
+        let mut tenants = self.conf.multitenancy.get_tenant_names();
+        tenants.extend(self.get_provisioned_tenants().into_iter().filter_map(
+            |provisioned_tenant| {
+                (provisioned_tenant.status == TenantStatus::Active)
+                    .then_some(provisioned_tenant.tenant.name)
+            },
+        ));
+        tenants
     }
 }
 pub trait AppStateInfo {
@@ -254,6 +371,8 @@ impl AppState {
             #[cfg(feature = "olap")]
             let mut pools: HashMap<String, AnalyticsProvider> = HashMap::new();
             let mut stores = HashMap::new();
+            let mut domain_events = HashMap::new();
+            let file_storage_client = conf.file_storage.get_file_storage_client().await;
             #[allow(clippy::expect_used)]
             let cache_store = get_cache_store(&conf.clone(), shut_down_signal, testable)
                 .await
@@ -263,34 +382,48 @@ impl AppState {
                 &event_handler,
                 &conf,
                 &conf.multitenancy.global_tenant,
+                None,
                 Arc::clone(&cache_store),
                 testable,
+                None,
             )
             .await
             .get_global_storage_interface();
             for (tenant_name, tenant) in conf.clone().multitenancy.get_tenants() {
-                let store: Box<dyn StorageInterface> = Self::get_store_interface(
+                let tenant_state = Self::get_tenant_state(
                     &storage_impl,
                     &event_handler,
                     &conf,
                     tenant,
                     Arc::clone(&cache_store),
-                    testable,
+                    Arc::clone(&file_storage_client),
                 )
-                .await
-                .get_storage_interface();
-                stores.insert(tenant_name.clone(), store);
-                #[cfg(feature = "olap")]
-                let pool = AnalyticsProvider::from_conf(conf.analytics.get_inner(), tenant).await;
+                .await;
+                stores.insert(tenant_name.clone(), tenant_state.store);
+                if let Some(tenant_domain_events) = tenant_state.domain_events {
+                    domain_events.insert(tenant_name.clone(), tenant_domain_events);
+                }
                 #[cfg(feature = "olap")]
-                pools.insert(tenant_name.clone(), pool);
+                pools.insert(tenant_name.clone(), tenant_state.pool);
             }
 
+            let provisioned_tenants = if storage_impl == StorageImpl::Mock {
+                HashMap::new()
+            } else {
+                Self::load_provisioned_tenants(
+                    &storage_impl,
+                    &event_handler,
+                    &conf,
+                    global_store.as_ref(),
+                    Arc::clone(&cache_store),
+                    Arc::clone(&file_storage_client),
+                )
+                .await
+            };
+
             #[cfg(feature = "email")]
             let email_client = Arc::new(create_email_client(&conf).await);
 
-            let file_storage_client = conf.file_storage.get_file_storage_client().await;
-
             Self {
                 flow_name: String::from("default"),
                 stores,
@@ -307,37 +440,184 @@ impl AppState {
                 request_id: None,
                 file_storage_client,
                 encryption_client,
+                domain_events,
+                provisioned_tenants: Arc::new(RwLock::new(provisioned_tenants)),
+                storage_impl,
+                cache_store,
             }
         })
         .await
     }
 
+    /// Creates the stores of the tenant and the handlers of its events
+    pub async fn get_tenant_state(
+        storage_impl: &StorageImpl,
+        event_handler: &EventsHandler,
+        conf: &Settings,
+        tenant: &Tenant,
+        cache_store: Arc<RedisStore>,
+        file_storage_client: Arc<dyn FileStorageInterface>,
+    ) -> TenantState {
+        let testable = *storage_impl == StorageImpl::PostgresqlTest;
+        let domain_events = tenant.domain_events.as_ref().and_then(|config| {
+            config.get_domain_events_handler(
+                event_handler,
+                file_storage_client,
+                TenantID(tenant.get_schema().to_string()),
+            )
+        });
+        let store: Box<dyn StorageInterface> = Self::get_store_interface(
+            storage_impl,
+            event_handler,
+            conf,
+            tenant,
+            tenant.key_management.as_ref(),
+            cache_store,
+            testable,
+            domain_events.clone(),
+        )
+        .await
+        .get_storage_interface();
+
+        TenantState {
+            store,
+            #[cfg(feature = "olap")]
+            pool: AnalyticsProvider::from_conf(conf.analytics.get_inner(), tenant).await,
+            domain_events,
+        }
+    }
+
+    /// Creates the stores of the tenants provisioned through the tenants API. The tenants that
+    /// cannot be loaded are logged and not served.
+    async fn load_provisioned_tenants(
+        storage_impl: &StorageImpl,
+        event_handler: &EventsHandler,
+        conf: &Settings,
+        global_store: &dyn GlobalStorageInterface,
+        cache_store: Arc<RedisStore>,
+        file_storage_client: Arc<dyn FileStorageInterface>,
+    ) -> HashMap<String, ProvisionedTenant> {
+        let tenants = match global_store.list_tenants().await {
+            Ok(tenants) => tenants,
+            Err(error) => {
+                logger::error!(?error, "Failed to load the provisioned tenants");
+                return HashMap::new();
+            }
+        };
+
+        let mut provisioned_tenants = HashMap::new();
+        for tenant in tenants {
+            let (tenant_config, status) = match get_provisioned_tenant_config(&tenant) {
+                Ok(provisioned_tenant) => provisioned_tenant,
+                Err(error) => {
+                    logger::error!(?error, tenant_id = %tenant.tenant_id, "Failed to load the provisioned tenant");
+                    continue;
+                }
+            };
+            let state = Self::get_tenant_state(
+                storage_impl,
+                event_handler,
+                conf,
+                &tenant_config,
+                Arc::clone(&cache_store),
+                Arc::clone(&file_storage_client),
+            )
+            .await;
+            provisioned_tenants.insert(
+                tenant.tenant_id,
+                ProvisionedTenant {
+                    tenant: tenant_config,
+                    status,
+                    state,
+                },
+            );
+        }
+        provisioned_tenants
+    }
+
+    pub fn get_provisioned_tenants(&self) -> Vec<ProvisionedTenant> {
+        self.provisioned_tenants
+            .read()
+            .map(|provisioned_tenants| provisioned_tenants.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn get_provisioned_tenant(&self, tenant_id: &str) -> Option<ProvisionedTenant> {
+        self.provisioned_tenants
+            .read()
+            .ok()
+            .and_then(|provisioned_tenants| provisioned_tenants.get(tenant_id).cloned())
+    }
+
+    /// Adds or replaces the tenant in the tenants served by every worker
+    pub fn set_provisioned_tenant(&self, tenant_id: String, provisioned_tenant: ProvisionedTenant) {
+        match self.provisioned_tenants.write() {
+            Ok(mut provisioned_tenants) => {
+                provisioned_tenants.insert(tenant_id, provisioned_tenant);
+            }
+            Err(error) => logger::error!(%error, "Failed to update the provisioned tenants"),
+        }
+    }
+
+    /// Whether requests can be served for the tenant, either configured or provisioned and active
+    pub fn is_tenant_active(&self, tenant_id: &str) -> bool {
+        self.conf.multitenancy.get_tenant(tenant_id).is_some()
+            || self
+                .get_provisioned_tenant(tenant_id)
+                .is_some_and(|provisioned_tenant| provisioned_tenant.status == TenantStatus::Active)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the master keys of the tenant cannot be unwrapped or decoded
     async fn get_store_interface(
         storage_impl: &StorageImpl,
         event_handler: &EventsHandler,
         conf: &Settings,
         tenant: &dyn TenantConfig,
+        key_management: Option<&settings::TenantKeyManagement>,
         cache_store: Arc<RedisStore>,
         testable: bool,
+        domain_events: Option<DomainEventsHandler>,
     ) -> Box<dyn CommonStorageInterface> {
+        #[allow(clippy::expect_used)]
+        let get_master_keys = || async {
+            key_management::get_master_keys(conf, key_management)
+                .await
+                .expect("Failed to get the master keys of the tenant")
+        };
+
         match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => match event_handler {
                 EventsHandler::Kafka(kafka_client) => Box::new(
                     KafkaStore::new(
                         #[allow(clippy::expect_used)]
-                        get_store(&conf.clone(), tenant, Arc::clone(&cache_store), testable)
-                            .await
-                            .expect("Failed to create store"),
+                        get_store(
+                            &conf.clone(),
+                            tenant,
+                            get_master_keys().await,
+                            Arc::clone(&cache_store),
+                            testable,
+                        )
+                        .await
+                        .expect("Failed to create store"),
                         kafka_client.clone(),
                         TenantID(tenant.get_schema().to_string()),
+                        domain_events,
                     )
                     .await,
                 ),
                 EventsHandler::Logs(_) => Box::new(
                     #[allow(clippy::expect_used)]
-                    get_store(conf, tenant, Arc::clone(&cache_store), testable)
-                        .await
-                        .expect("Failed to create store"),
+                    get_store(
+                        conf,
+                        tenant,
+                        get_master_keys().await,
+                        Arc::clone(&cache_store),
+                        testable,
+                    )
+                    .await
+                    .expect("Failed to create store"),
                 ),
             },
             #[allow(clippy::expect_used)]
@@ -372,23 +652,42 @@ impl AppState {
     where
         F: FnOnce() -> E + Copy,
     {
-        let tenant_conf = self.conf.multitenancy.get_tenant(tenant).ok_or_else(err)?;
+        let (tenant_conf, tenant_state) = match self.conf.multitenancy.get_tenant(tenant) {
+            Some(tenant_conf) => (
+                tenant_conf.clone(),
+                TenantState {
+                    store: self.stores.get(tenant).ok_or_else(err)?.clone(),
+                    #[cfg(feature = "olap")]
+                    pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
+                    domain_events: self.domain_events.get(tenant).cloned(),
+                },
+            ),
+            None => self
+                .get_provisioned_tenant(tenant)
+                .filter(|provisioned_tenant| provisioned_tenant.status == TenantStatus::Active)
+                .map(|provisioned_tenant| (provisioned_tenant.tenant, provisioned_tenant.state))
+                .ok_or_else(err)?,
+        };
         Ok(SessionState {
-            store: self.stores.get(tenant).ok_or_else(err)?.clone(),
+            store: tenant_state.store,
             global_store: self.global_store.clone(),
             conf: Arc::clone(&self.conf),
             api_client: self.api_client.clone(),
             event_handler: self.event_handler.clone(),
             #[cfg(feature = "olap")]
-            pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
+            pool: tenant_state.pool,
             file_storage_client: self.file_storage_client.clone(),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
-            tenant: tenant_conf.clone(),
+            tenant: tenant_conf,
             #[cfg(feature = "email")]
             email_client: Arc::clone(&self.email_client),
             #[cfg(feature = "olap")]
             opensearch_client: Arc::clone(&self.opensearch_client),
+            domain_events: tenant_state.domain_events,
+            connector_debug_capture: None,
+            api_key_request_context: None,
+            auth_type: None,
         })
     }
 }
@@ -401,6 +700,7 @@ impl Health {
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::get().to(health)))
             .service(web::resource("/ready").route(web::get().to(deep_health_check)))
+            .service(web::resource("/ready/detailed").route(web::get().to(detailed_health_check)))
     }
 }
 
@@ -422,6 +722,14 @@ impl DummyConnector {
                 web::resource("/payments/{payment_id}")
                     .route(web::get().to(dummy_connector_payment_data)),
             )
+            .service(
+                web::resource("/payments/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/void")
+                    .route(web::post().to(dummy_connector_void)),
+            )
             .service(
                 web::resource("/{payment_id}/refund").route(web::post().to(dummy_connector_refund)),
             )
@@ -443,6 +751,21 @@ impl DummyConnector {
     }
 }
 
+#[cfg(all(feature = "dummy_connector", feature = "oltp"))]
+pub struct SandboxTraffic;
+
+#[cfg(all(feature = "dummy_connector", feature = "oltp"))]
+impl SandboxTraffic {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/sandbox")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/traffic")
+                    .route(web::post().to(sandbox_traffic::generate_sandbox_traffic)),
+            )
+    }
+}
+
 pub struct Payments;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -464,6 +787,14 @@ impl Payments {
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments_manual_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/connector_exchanges")
+                        .route(web::get().to(payments_connector_exchanges)),
+                )
+                .service(
+                    web::resource("/{payment_id}/status_history")
+                        .route(web::get().to(payments_status_history)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -491,6 +822,15 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/capture_schedule")
+                        .route(web::post().to(payments_capture_schedule))
+                        .route(web::delete().to(payments_capture_schedule_cancel)),
+                )
+                .service(
+                    web::resource("/{payment_id}/metadata")
+                        .route(web::post().to(payments_metadata_update)),
+                )
                 .service(
                     web::resource("/{payment_id}/approve")
                         .route(web::post().to(payments_approve)),
@@ -636,6 +976,12 @@ impl Routing {
                     .route(web::get().to(cloud_routing::retrieve_decision_manager_config))
                     .route(web::delete().to(cloud_routing::delete_decision_manager_config)),
             )
+            .service(
+                web::resource("/decision/fraud_rules/{profile_id}")
+                    .route(web::put().to(cloud_routing::upsert_fraud_rules_config))
+                    .route(web::get().to(cloud_routing::retrieve_fraud_rules_config))
+                    .route(web::delete().to(cloud_routing::delete_fraud_rules_config)),
+            )
             .service(
                 web::resource("/decision/surcharge")
                     .route(web::put().to(cloud_routing::upsert_surcharge_decision_manager_config))
@@ -808,6 +1154,10 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/overview")
+                        .route(web::get().to(customers_overview)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers_list)))
         }
 
@@ -827,6 +1177,15 @@ impl Customers {
                     web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
                         .route(web::post().to(default_payment_method_set_api)),
                 )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/order")
+                        .route(web::post().to(payment_method_order_set_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/redact")
+                        .route(web::post().to(customers_redact))
+                        .route(web::get().to(customers_redaction_retrieve)),
+                )
                 .service(
                     web::resource("/{customer_id}")
                         .route(web::get().to(customers_retrieve))
@@ -857,6 +1216,7 @@ impl Refunds {
         {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
+                .service(web::resource("/bulk").route(web::post().to(refunds_create_bulk)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
                 .service(
                     web::resource("/{id}")
@@ -943,6 +1303,10 @@ impl PaymentMethods {
                     web::resource("/{payment_method_id}/save")
                         .route(web::post().to(save_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/usage_restrictions")
+                        .route(web::post().to(payment_method_usage_restrictions_set_api)),
+                )
                 .service(
                     web::resource("/auth/link").route(web::post().to(pm_auth::link_token_create)),
                 )
@@ -954,6 +1318,35 @@ impl PaymentMethods {
     }
 }
 
+pub struct Vault;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Vault {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/vault").app_data(web::Data::new(state));
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(
+                    web::resource("/cleanup")
+                        .route(web::post().to(vault_tokens::schedule_vault_cleanup)),
+                )
+                .service(
+                    web::resource("/audit")
+                        .route(web::get().to(vault_tokens::list_vault_audit_events)),
+                );
+        }
+        #[cfg(feature = "oltp")]
+        {
+            route = route.service(
+                web::resource("/tokens/expire")
+                    .route(web::post().to(vault_tokens::expire_vault_token)),
+            );
+        }
+        route
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "recon"))]
 pub struct Recon;
 
@@ -988,12 +1381,304 @@ impl Blocklist {
                     .route(web::post().to(blocklist::add_entry_to_blocklist))
                     .route(web::delete().to(blocklist::remove_entry_from_blocklist)),
             )
+            .service(
+                web::resource("/allow")
+                    .route(web::get().to(blocklist::list_allowed_entries))
+                    .route(web::post().to(blocklist::add_entry_to_allowlist))
+                    .route(web::delete().to(blocklist::remove_entry_from_allowlist)),
+            )
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct Reports;
+
+#[cfg(feature = "olap")]
+impl Reports {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/reports")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/templates")
+                    .route(web::get().to(reports::list_report_templates))
+                    .route(web::post().to(reports::create_report_template)),
+            )
+            .service(
+                web::resource("/templates/{template_id}")
+                    .route(web::get().to(reports::retrieve_report_template))
+                    .route(web::post().to(reports::update_report_template))
+                    .route(web::delete().to(reports::delete_report_template)),
+            )
+            .service(
+                web::resource("/{merchant_id}/files/{file_id}/download")
+                    .route(web::get().to(reports::download_report)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Settlements;
+
+#[cfg(feature = "olap")]
+impl Settlements {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/settlements")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/files").route(web::post().to(settlements::ingest_settlement_file)),
+            )
+            .service(
+                web::resource("/lines").route(web::post().to(settlements::ingest_settlement_lines)),
+            )
+            .service(
+                web::resource("/lines/{line_id}/resolve")
+                    .route(web::post().to(settlements::resolve_settlement_line)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}")
+                    .route(web::get().to(settlements::retrieve_settlement_batch)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/rematch")
+                    .route(web::post().to(settlements::rematch_settlement_batch)),
+            )
+            .service(
+                web::resource("/exceptions")
+                    .route(web::get().to(settlements::list_settlement_exceptions)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}")
+                    .route(web::get().to(settlements::retrieve_payment_reconciliation)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Ledger;
+
+#[cfg(feature = "olap")]
+impl Ledger {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/ledger")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/balances").route(web::get().to(ledger::retrieve_ledger_balances)),
+            )
+            .service(web::resource("/entries").route(web::get().to(ledger::list_ledger_entries)))
+            .service(
+                web::resource("/entries/export")
+                    .route(web::get().to(ledger::export_ledger_entries)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ConnectedAccounts;
+
+#[cfg(feature = "olap")]
+impl ConnectedAccounts {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connected_accounts")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(connected_accounts::create_connected_account))
+                    .route(web::get().to(connected_accounts::list_connected_accounts)),
+            )
+            .service(
+                web::resource("/report")
+                    .route(web::get().to(connected_accounts::get_connected_accounts_report)),
+            )
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(web::get().to(connected_accounts::retrieve_connected_account))
+                    .route(web::post().to(connected_accounts::update_connected_account)),
+            )
+            .service(
+                web::resource("/{merchant_id}/connectors")
+                    .route(web::post().to(connected_accounts::create_connected_account_connector)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct AuditEvents;
+
+#[cfg(feature = "olap")]
+impl AuditEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_events::list_audit_events)))
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Orders;
+
+#[cfg(feature = "olap")]
+impl Orders {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/orders")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(orders::create_order)))
+            .service(
+                web::resource("/{order_id}")
+                    .route(web::get().to(orders::retrieve_order))
+                    .route(web::post().to(orders::update_order)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Organization;
+
+#[cfg(feature = "olap")]
+impl Organization {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/organization/{org_id}")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/merchants")
+                    .route(web::get().to(organization::list_organization_merchants)),
+            )
+            .service(
+                web::resource("/merchants/{merchant_id}")
+                    .route(web::post().to(organization::update_organization_merchant)),
+            )
+            .service(
+                web::resource("/payments/aggregate")
+                    .route(web::get().to(organization::aggregate_organization_payments)),
+            )
+            .service(
+                web::resource("/connectors/share")
+                    .route(web::post().to(organization::share_organization_connector)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Tenants;
+
+#[cfg(feature = "olap")]
+impl Tenants {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/tenants")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(tenants::create_tenant))
+                    .route(web::get().to(tenants::list_tenants)),
+            )
+            .service(
+                web::resource("/{tenant_id}")
+                    .route(web::get().to(tenants::retrieve_tenant))
+                    .route(web::post().to(tenants::update_tenant)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ConnectorCapabilities;
+
+#[cfg(feature = "olap")]
+impl ConnectorCapabilities {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connectors")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}/capabilities")
+                    .route(web::get().to(connector_capabilities::retrieve_connector_capabilities)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct CardExport;
+
+#[cfg(feature = "olap")]
+impl CardExport {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/card_exports")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(card_export::list_card_exports))
+                    .route(web::post().to(card_export::create_card_export)),
+            )
+            .service(
+                web::resource("/{export_id}")
+                    .route(web::get().to(card_export::retrieve_card_export)),
+            )
+            .service(
+                web::resource("/{export_id}/approve")
+                    .route(web::post().to(card_export::approve_card_export)),
+            )
+            .service(
+                web::resource("/{export_id}/reject")
+                    .route(web::post().to(card_export::reject_card_export)),
+            )
+            .service(
+                web::resource("/{export_id}/file")
+                    .route(web::get().to(card_export::download_card_export)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ConnectorMigration;
+
+#[cfg(feature = "olap")]
+impl ConnectorMigration {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_migrations")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(connector_migration::list_connector_migrations))
+                    .route(web::post().to(connector_migration::create_connector_migration)),
+            )
+            .service(
+                web::resource("/{migration_id}")
+                    .route(web::get().to(connector_migration::retrieve_connector_migration)),
+            )
+            .service(
+                web::resource("/{migration_id}/cancel")
+                    .route(web::post().to(connector_migration::cancel_connector_migration)),
+            )
+            .service(web::resource("/{migration_id}/payment_methods").route(
+                web::get().to(connector_migration::list_connector_migration_payment_methods),
+            ))
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Search;
+
+#[cfg(feature = "olap")]
+impl Search {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/search")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(search::search)))
+    }
+}
+
+#[cfg(feature = "graphql")]
+pub struct GraphQL;
+
+#[cfg(feature = "graphql")]
+impl GraphQL {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/graphql")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(graphql::graphql_query)))
+    }
+}
+
 pub struct MerchantAccount;
 
 #[cfg(feature = "olap")]
@@ -1008,7 +1693,50 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/kv/settings")
+                    .route(web::get().to(merchant_account_kv_settings_retrieve))
+                    .route(web::post().to(merchant_account_kv_settings_update)),
+            )
+            .service(
+                web::resource("/{id}/rate_limits")
+                    .route(web::get().to(merchant_account_rate_limits_retrieve))
+                    .route(web::post().to(merchant_account_rate_limits_update)),
+            )
+            .service(
+                web::resource("/{id}/alert_rules")
+                    .route(web::get().to(merchant_account_alert_rules_retrieve))
+                    .route(web::post().to(merchant_account_alert_rules_update)),
+            )
+            .service(
+                web::resource("/{id}/response_field_access")
+                    .route(web::get().to(merchant_account_response_field_access_retrieve))
+                    .route(web::post().to(merchant_account_response_field_access_update)),
+            )
+            .service(
+                web::resource("/{id}/api_version")
+                    .route(web::get().to(merchant_account_api_version_retrieve))
+                    .route(web::post().to(merchant_account_api_version_update)),
+            )
+            .service(
+                web::resource("/{id}/key/rotate")
+                    .route(web::post().to(merchant_account_key_rotation_schedule)),
+            )
+            .service(
+                web::resource("/{id}/key/rotate/{job_id}")
+                    .route(web::get().to(merchant_account_key_rotation_retrieve)),
+            )
+            .service(
+                web::resource("/{id}/onboarding_status")
+                    .route(web::get().to(merchant_account_onboarding_status)),
+            )
             .service(web::resource("/kv").route(web::post().to(merchant_account_toggle_all_kv)))
+            .service(
+                web::resource("/kv/drainer")
+                    .route(web::get().to(drainer_tuning_retrieve))
+                    .route(web::post().to(drainer_tuning_update)),
+            )
+            .service(web::resource("/kv/backlog").route(web::get().to(kv_backlog_retrieve)))
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -1044,6 +1772,22 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(payment_connector_retrieve))
                         .route(web::post().to(payment_connector_update))
                         .route(web::delete().to(payment_connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/validate")
+                        .route(web::post().to(payment_connector_validate)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/credentials/next",
+                    )
+                    .route(web::post().to(payment_connector_credentials_stage)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/credentials/cutover",
+                    )
+                    .route(web::post().to(payment_connector_credentials_cutover)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1056,6 +1800,18 @@ impl MerchantConnectorAccount {
     }
 }
 
+pub struct OAuth2;
+
+#[cfg(feature = "oltp")]
+impl OAuth2 {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/oauth2")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/token").route(web::post().to(oauth2_token_create)))
+            .service(web::resource("/revoke").route(web::post().to(oauth2_token_revoke)))
+    }
+}
+
 pub struct EphemeralKey;
 
 #[cfg(feature = "oltp")]
@@ -1068,6 +1824,47 @@ impl EphemeralKey {
     }
 }
 
+pub struct Authentication;
+
+#[cfg(feature = "oltp")]
+impl Authentication {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/authentication")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::post().to(authentication::create_authentication)))
+            .service(
+                web::resource("/{authentication_id}")
+                    .route(web::get().to(authentication::retrieve_authentication)),
+            )
+            .service(
+                web::resource("/{authentication_id}/authenticate")
+                    .route(web::post().to(authentication::authenticate)),
+            )
+    }
+}
+
+pub struct PaymentMethodSessions;
+
+#[cfg(feature = "oltp")]
+impl PaymentMethodSessions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_method_sessions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(payment_method_sessions::create_payment_method_session)),
+            )
+            .service(
+                web::resource("/{session_id}")
+                    .route(web::get().to(payment_method_sessions::retrieve_payment_method_session)),
+            )
+            .service(
+                web::resource("/{session_id}/confirm")
+                    .route(web::post().to(payment_method_sessions::confirm_payment_method_session)),
+            )
+    }
+}
+
 pub struct Mandates;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -1079,12 +1876,18 @@ impl Mandates {
         {
             route =
                 route.service(web::resource("/list").route(web::get().to(retrieve_mandates_list)));
+            route =
+                route.service(web::resource("/migrate").route(web::post().to(migrate_mandates)));
             route = route.service(web::resource("/{id}").route(web::get().to(get_mandate)));
         }
         #[cfg(feature = "oltp")]
         {
             route =
                 route.service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
+            route = route.service(
+                web::resource("/{id}/pre_notification")
+                    .route(web::post().to(schedule_mandate_pre_notification)),
+            );
         }
         route
     }
@@ -1164,6 +1967,18 @@ impl Poll {
     }
 }
 
+pub struct Jobs;
+
+#[cfg(feature = "oltp")]
+impl Jobs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/jobs")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/{job_id}").route(web::get().to(jobs::retrieve_job)))
+            .service(web::resource("/{job_id}/cancel").route(web::post().to(jobs::cancel_job)))
+    }
+}
+
 pub struct ApiKeys;
 
 #[cfg(feature = "olap")]
@@ -1238,6 +2053,26 @@ impl Cache {
         web::scope("/cache")
             .app_data(web::Data::new(state))
             .service(web::resource("/invalidate/{key}").route(web::post().to(invalidate)))
+            .service(web::resource("/in_memory").route(web::get().to(list_in_memory_caches)))
+            .service(
+                web::resource("/in_memory/{cache_name}/{key}")
+                    .route(web::get().to(retrieve_in_memory_cache_key))
+                    .route(web::delete().to(flush_in_memory_cache_key)),
+            )
+    }
+}
+
+pub struct Logging;
+
+impl Logging {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/logging")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(logging::retrieve_logging)))
+            .service(web::resource("/levels").route(web::put().to(logging::update_log_levels)))
+            .service(
+                web::resource("/sampling").route(web::put().to(logging::update_request_sampling)),
+            )
     }
 }
 
@@ -1288,6 +2123,19 @@ impl BusinessProfile {
                     .route(web::post().to(business_profile_create))
                     .route(web::get().to(business_profiles_list)),
             )
+            .service(
+                web::resource("/templates")
+                    .route(web::post().to(business_profile_template_upsert))
+                    .route(web::get().to(business_profile_templates_list)),
+            )
+            .service(
+                web::resource("/templates/{template_name}")
+                    .route(web::delete().to(business_profile_template_delete)),
+            )
+            .service(
+                web::resource("/templates/{template_name}/apply")
+                    .route(web::post().to(business_profile_template_apply)),
+            )
             .service(
                 web::scope("/{profile_id}")
                     .service(
@@ -1296,6 +2144,7 @@ impl BusinessProfile {
                             .route(web::post().to(business_profile_update))
                             .route(web::delete().to(business_profile_delete)),
                     )
+                    .service(web::resource("/clone").route(web::post().to(business_profile_clone)))
                     .service(
                         web::resource("/toggle_extended_card_info")
                             .route(web::post().to(toggle_extended_card_info)),
@@ -1303,11 +2152,130 @@ impl BusinessProfile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::resource("/velocity_limits")
+                            .route(web::post().to(velocity_limits_upsert))
+                            .route(web::get().to(velocity_limits_retrieve))
+                            .route(web::delete().to(velocity_limits_delete)),
+                    )
+                    .service(
+                        web::resource("/payment_method_display_rules")
+                            .route(web::post().to(pm_display_rules_upsert))
+                            .route(web::get().to(pm_display_rules_retrieve))
+                            .route(web::delete().to(pm_display_rules_delete)),
+                    )
+                    .service(
+                        web::resource("/wallet_decryption_keys")
+                            .route(web::post().to(wallet_decryption_key_create))
+                            .route(web::get().to(wallet_decryption_keys_list)),
+                    )
+                    .service(
+                        web::resource("/wallet_decryption_keys/{key_id}/retire")
+                            .route(web::post().to(wallet_decryption_key_retire)),
                     ),
             )
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct FeatureFlags;
+
+#[cfg(feature = "olap")]
+impl FeatureFlags {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/feature_flags")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{flag}")
+                    .route(web::get().to(feature_flags::retrieve_feature_flag))
+                    .route(web::post().to(feature_flags::update_feature_flag))
+                    .route(web::delete().to(feature_flags::delete_feature_flag)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct PmFilters;
+
+#[cfg(feature = "olap")]
+impl PmFilters {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/pm_filters")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(pm_filters::retrieve_pm_filters))
+                    .route(web::post().to(pm_filters::update_pm_filters))
+                    .route(web::delete().to(pm_filters::delete_pm_filters)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct BulkForceSync;
+
+#[cfg(feature = "olap")]
+impl BulkForceSync {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/force_sync")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::post().to(bulk_force_sync::schedule_bulk_force_sync)),
+            )
+            .service(
+                web::resource("/{job_id}")
+                    .route(web::get().to(bulk_force_sync::retrieve_bulk_force_sync)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ProcessTracker;
+
+#[cfg(feature = "olap")]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/process_tracker/{merchant_id}/tasks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(process_tracker::list_tasks)))
+            .service(
+                web::resource("/{task_id}").route(web::get().to(process_tracker::retrieve_task)),
+            )
+            .service(
+                web::resource("/{task_id}/pause")
+                    .route(web::post().to(process_tracker::pause_task)),
+            )
+            .service(
+                web::resource("/{task_id}/resume")
+                    .route(web::post().to(process_tracker::resume_task)),
+            )
+            .service(
+                web::resource("/{task_id}/reschedule")
+                    .route(web::post().to(process_tracker::reschedule_task)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct MasterKeyRotation;
+
+#[cfg(feature = "olap")]
+impl MasterKeyRotation {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/master_key/rotate")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(master_key_rotation::schedule_master_key_rotation)),
+            )
+            .service(
+                web::resource("/{job_id}")
+                    .route(web::get().to(master_key_rotation::retrieve_master_key_rotation)),
+            )
+    }
+}
+
 pub struct Gsm;
 
 #[cfg(feature = "olap")]
@@ -1523,6 +2491,15 @@ impl WebhookEvents {
         web::scope("/events/{merchant_id_or_profile_id}")
             .app_data(web::Data::new(config))
             .service(web::resource("").route(web::get().to(list_initial_webhook_delivery_attempts)))
+            // Registered before the event scope, which would otherwise match `incoming`
+            .service(
+                web::scope("/incoming")
+                    .service(web::resource("").route(web::get().to(list_incoming_webhooks)))
+                    .service(
+                        web::resource("/{archive_id}")
+                            .route(web::get().to(retrieve_incoming_webhook)),
+                    ),
+            )
             .service(
                 web::scope("/{event_id}")
                     .service(
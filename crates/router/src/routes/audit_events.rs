@@ -0,0 +1,28 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::audit_events::AuditEventsQuery;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, audit_events},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AuditEventsList))]
+pub async fn list_audit_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<AuditEventsQuery>,
+) -> HttpResponse {
+    let flow = Flow::AuditEventsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, query, _| audit_events::list_audit_events(state, query),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,97 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::authentication as authentication_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, authentication::standalone},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AuthenticationCreate))]
+pub async fn create_authentication(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<authentication_types::AuthenticationCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::AuthenticationCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            standalone::create_authentication(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AuthenticationAuthenticate))]
+pub async fn authenticate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<authentication_types::AuthenticationAuthenticateRequest>,
+) -> HttpResponse {
+    let flow = Flow::AuthenticationAuthenticate;
+    let authentication_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            standalone::authenticate(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                authentication_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AuthenticationRetrieve))]
+pub async fn retrieve_authentication(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::AuthenticationRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, authentication_id, _| {
+            standalone::retrieve_authentication(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                authentication_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
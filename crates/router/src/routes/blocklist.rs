@@ -155,3 +155,113 @@ pub async fn toggle_blocklist_guard(
     ))
     .await
 }
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/allow",
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Entry Allowed", body = BlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Add an entry to the allowlist",
+    security(("api_key" = []))
+)]
+pub async fn add_entry_to_allowlist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::AddToBlocklistRequest>,
+) -> HttpResponse {
+    let flow = Flow::AddToAllowlist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::add_entry_to_allowlist(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/blocklist/allow",
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Entry Removed", body = BlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Remove an entry from the allowlist",
+    security(("api_key" = []))
+)]
+pub async fn remove_entry_from_allowlist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::DeleteFromBlocklistRequest>,
+) -> HttpResponse {
+    let flow = Flow::DeleteFromAllowlist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::remove_entry_from_allowlist(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/allow",
+    params (
+        ("data_kind" = BlocklistDataKind, Query, description = "Kind of the fingerprint list requested"),
+    ),
+    responses(
+        (status = 200, description = "Allowed Fingerprints", body = BlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "List Allowed fingerprints of a particular kind",
+    security(("api_key" = []))
+)]
+pub async fn list_allowed_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_blocklist::ListBlocklistQuery>,
+) -> HttpResponse {
+    let flow = Flow::ListAllowlist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            blocklist::list_allowlist_entries(state, auth.merchant_account, query)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,52 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::bulk_force_sync as bulk_force_sync_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, bulk_force_sync},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::BulkForceSyncSchedule))]
+pub async fn schedule_bulk_force_sync(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<bulk_force_sync_types::BulkForceSyncRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkForceSyncSchedule;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| bulk_force_sync::schedule_bulk_force_sync(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BulkForceSyncRetrieve))]
+pub async fn retrieve_bulk_force_sync(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BulkForceSyncRetrieve;
+    let payload = bulk_force_sync_types::BulkForceSyncJobId {
+        job_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| bulk_force_sync::retrieve_bulk_force_sync(state, req.job_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,4 +1,5 @@
 use actix_web::{web, HttpRequest, Responder};
+use api_models::cache as cache_api;
 use router_env::{instrument, tracing, Flow};
 
 use super::AppState;
@@ -28,3 +29,63 @@ pub async fn invalidate(
     )
     .await
 }
+
+#[instrument(skip_all)]
+pub async fn list_in_memory_caches(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::InMemoryCacheList;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| cache::list_in_memory_caches(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_in_memory_cache_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::InMemoryCacheKeyRetrieve;
+    let (cache_name, key) = path.into_inner();
+    let payload = cache_api::InMemoryCacheKeyRequest { cache_name, key };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| cache::retrieve_in_memory_cache_key(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn flush_in_memory_cache_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::InMemoryCacheKeyFlush;
+    let (cache_name, key) = path.into_inner();
+    let payload = cache_api::InMemoryCacheKeyRequest { cache_name, key };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| cache::flush_in_memory_cache_key(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
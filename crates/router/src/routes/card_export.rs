@@ -0,0 +1,160 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::card_export as card_export_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, card_export},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::CardExportCreate))]
+pub async fn create_card_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<card_export_types::CardExportCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::CardExportCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, req_state| {
+            card_export::create_card_export(state, req_state, auth.merchant_account, user_id, req)
+        },
+        &auth::JWTAuth(Permission::MerchantAccountWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CardExportList))]
+pub async fn list_card_exports(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::CardExportList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            card_export::list_card_exports(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CardExportRetrieve))]
+pub async fn retrieve_card_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CardExportRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, export_id, _| {
+            card_export::retrieve_card_export(state, auth.merchant_account, export_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CardExportApprove))]
+pub async fn approve_card_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CardExportApprove;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, export_id, req_state| {
+            card_export::approve_card_export(
+                state,
+                req_state,
+                auth.merchant_account,
+                user_id,
+                export_id,
+            )
+        },
+        &auth::JWTAuth(Permission::MerchantAccountWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CardExportReject))]
+pub async fn reject_card_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<card_export_types::CardExportRejectRequest>,
+) -> HttpResponse {
+    let flow = Flow::CardExportReject;
+    let export_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, req_state| {
+            card_export::reject_card_export(
+                state,
+                req_state,
+                auth.merchant_account,
+                user_id,
+                export_id.clone(),
+                req,
+            )
+        },
+        &auth::JWTAuth(Permission::MerchantAccountWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CardExportDownload))]
+pub async fn download_card_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CardExportDownload;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, export_id, req_state| {
+            card_export::download_card_export(
+                state,
+                req_state,
+                auth.merchant_account,
+                user_id,
+                export_id,
+            )
+        },
+        &auth::JWTAuth(Permission::MerchantAccountWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,177 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::{connected_accounts as connected_accounts_types, payments::TimeRange};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connected_accounts},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectedAccountCreate))]
+pub async fn create_connected_account(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<connected_accounts_types::ConnectedAccountCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectedAccountCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            connected_accounts::create_connected_account(state, auth.merchant_account, payload)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectedAccountsList))]
+pub async fn list_connected_accounts(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ConnectedAccountsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            connected_accounts::list_connected_accounts(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectedAccountRetrieve))]
+pub async fn retrieve_connected_account(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ConnectedAccountRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, merchant_id, _| {
+            connected_accounts::retrieve_connected_account(
+                state,
+                auth.merchant_account,
+                merchant_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectedAccountUpdate))]
+pub async fn update_connected_account(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<connected_accounts_types::ConnectedAccountUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectedAccountUpdate;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            connected_accounts::update_connected_account(
+                state,
+                auth.merchant_account,
+                merchant_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectedAccountConnectorCreate))]
+pub async fn create_connected_account_connector(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<connected_accounts_types::ConnectedAccountConnectorRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectedAccountConnectorCreate;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            connected_accounts::create_connected_account_connector(
+                state,
+                auth.merchant_account,
+                merchant_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantConnectorAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectedAccountsReport))]
+pub async fn get_connected_accounts_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::ConnectedAccountsReport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, time_range, _| {
+            connected_accounts::get_connected_accounts_report(
+                state,
+                auth.merchant_account,
+                time_range,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,155 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::connector_migration as connector_migration_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_migration},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationCreate))]
+pub async fn create_connector_migration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<connector_migration_types::ConnectorMigrationCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMigrationCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            connector_migration::create_connector_migration(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantConnectorAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationList))]
+pub async fn list_connector_migrations(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMigrationList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            connector_migration::list_connector_migrations(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantConnectorAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationRetrieve))]
+pub async fn retrieve_connector_migration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMigrationRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, migration_id, _| {
+            connector_migration::retrieve_connector_migration(
+                state,
+                auth.merchant_account,
+                migration_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantConnectorAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationCancel))]
+pub async fn cancel_connector_migration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMigrationCancel;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, migration_id, _| {
+            connector_migration::cancel_connector_migration(
+                state,
+                auth.merchant_account,
+                migration_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantConnectorAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationPaymentMethodsList))]
+pub async fn list_connector_migration_payment_methods(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<
+        connector_migration_types::ConnectorMigrationPaymentMethodListConstraints,
+    >,
+) -> HttpResponse {
+    let flow = Flow::ConnectorMigrationPaymentMethodsList;
+    let migration_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            connector_migration::list_connector_migration_payment_methods(
+                state,
+                auth.merchant_account,
+                migration_id.clone(),
+                constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantConnectorAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
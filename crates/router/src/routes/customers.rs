@@ -173,3 +173,87 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersOverview))]
+pub async fn customers_overview(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> HttpResponse {
+    let flow = Flow::CustomersOverview;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            get_customer_overview(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRedact))]
+pub async fn customers_redact(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> HttpResponse {
+    let flow = Flow::CustomersRedact;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| redact_customer(state, auth.merchant_account, auth.key_store, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRedactionRetrieve))]
+pub async fn customers_redaction_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> HttpResponse {
+    let flow = Flow::CustomersRedactionRetrieve;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| retrieve_customer_redaction(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -2,3 +2,4 @@ pub const PAYMENT_ID_PREFIX: &str = "dummy_pay";
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
+pub const WEBHOOK_DELAY_IN_MILLIS: u64 = 2000;
@@ -94,12 +94,6 @@ pub async fn payment_complete(
 
     let payment_data = utils::get_payment_data_by_attempt_id(&state, req.attempt_id.clone()).await;
 
-    let payment_status = if req.confirm {
-        types::DummyConnectorStatus::Succeeded
-    } else {
-        types::DummyConnectorStatus::Failed
-    };
-
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -109,6 +103,11 @@ pub async fn payment_complete(
     let _ = redis_conn.delete_key(req.attempt_id.as_str()).await;
 
     if let Ok(payment_data) = payment_data {
+        let payment_status = if req.confirm {
+            payment_data.get_successful_status()
+        } else {
+            types::DummyConnectorStatus::Failed
+        };
         let updated_payment_data = types::DummyConnectorPaymentData {
             status: payment_status,
             next_action: None,
@@ -121,6 +120,7 @@ pub async fn payment_complete(
             state.conf.dummy_connector.payment_ttl,
         )
         .await?;
+        send_payment_webhook(&state, &updated_payment_data);
         return Ok(api::ApplicationResponse::JsonForRedirection(
             api_models::payments::RedirectionResponse {
                 return_url: String::new(),
@@ -144,6 +144,83 @@ pub async fn payment_complete(
     ))
 }
 
+fn send_payment_webhook(state: &SessionState, payment_data: &types::DummyConnectorPaymentData) {
+    let webhook = types::DummyConnectorWebhookEventType::for_payment(&payment_data.status).map(
+        |event_type| types::DummyConnectorWebhook {
+            event_type,
+            payment_id: payment_data.payment_id.clone(),
+            refund_id: None,
+            created: common_utils::date_time::now(),
+        },
+    );
+    utils::send_webhook(state, payment_data.webhook_url.clone(), webhook);
+}
+
+pub async fn payment_capture(
+    state: SessionState,
+    req: types::DummyConnectorCaptureRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+
+    let payment_data = utils::get_payment_data_from_payment_id(&state, payment_id).await?;
+    payment_data.is_eligible_for_capture(req.amount)?;
+
+    let payment_data = types::DummyConnectorPaymentData {
+        status: types::DummyConnectorStatus::Succeeded,
+        eligible_amount: req.amount,
+        ..payment_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        payment_data.payment_id.clone(),
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    send_payment_webhook(&state, &payment_data);
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+pub async fn payment_void(
+    state: SessionState,
+    req: types::DummyConnectorVoidRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let payment_data = utils::get_payment_data_from_payment_id(&state, req.payment_id).await?;
+    payment_data.is_eligible_for_void()?;
+
+    let payment_data = types::DummyConnectorPaymentData {
+        status: types::DummyConnectorStatus::Cancelled,
+        eligible_amount: 0,
+        ..payment_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        payment_data.payment_id.clone(),
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    send_payment_webhook(&state, &payment_data);
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
 pub async fn refund_payment(
     state: SessionState,
     req: types::DummyConnectorRefundRequest,
@@ -167,18 +244,22 @@ pub async fn refund_payment(
     payment_data.is_eligible_for_refund(req.amount)?;
 
     let refund_id = generate_id_with_default_len(consts::REFUND_ID_PREFIX);
-    payment_data.eligible_amount -= req.amount;
-
-    utils::store_data_in_redis(
-        &state,
-        payment_id,
-        payment_data.to_owned(),
-        state.conf.dummy_connector.payment_ttl,
-    )
-    .await?;
+    let refund_status = if utils::is_refund_declined(req.amount) {
+        types::DummyConnectorStatus::Failed
+    } else {
+        payment_data.eligible_amount -= req.amount;
+        utils::store_data_in_redis(
+            &state,
+            payment_id.clone(),
+            payment_data.to_owned(),
+            state.conf.dummy_connector.payment_ttl,
+        )
+        .await?;
+        types::DummyConnectorStatus::Succeeded
+    };
 
     let refund_data = types::DummyConnectorRefundResponse::new(
-        types::DummyConnectorStatus::Succeeded,
+        refund_status,
         refund_id.to_owned(),
         payment_data.currency,
         common_utils::date_time::now(),
@@ -188,11 +269,21 @@ pub async fn refund_payment(
 
     utils::store_data_in_redis(
         &state,
-        refund_id,
+        refund_id.clone(),
         refund_data.to_owned(),
         state.conf.dummy_connector.refund_ttl,
     )
     .await?;
+    let webhook =
+        types::DummyConnectorWebhookEventType::for_refund(&refund_data.status).map(|event_type| {
+            types::DummyConnectorWebhook {
+                event_type,
+                payment_id,
+                refund_id: Some(refund_id),
+                created: common_utils::date_time::now(),
+            }
+        });
+    utils::send_webhook(&state, req.webhook_url, webhook);
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
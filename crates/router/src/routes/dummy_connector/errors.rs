@@ -4,6 +4,7 @@ pub enum ErrorType {
     ServerNotAvailable,
     ObjectNotFound,
     InvalidRequestError,
+    RateLimitError,
 }
 
 #[derive(Debug, Clone, router_derive::ApiError)]
@@ -37,6 +38,21 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Payment is not authorized")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "The capture amount exceeds the amount authorized")]
+    CaptureAmountExceedsPaymentAmount,
+
+    #[error(error_type = ErrorType::RateLimitError, code = "DC_11", message = "Too many requests")]
+    TooManyRequests,
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_12", message = "Service temporarily unavailable")]
+    ServiceUnavailable,
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_13", message = "Request timed out")]
+    GatewayTimeout,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +99,23 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::CaptureAmountExceedsPaymentAmount => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::TooManyRequests => {
+                AER::TooManyRequests(ApiError::new("DC", 11, self.error_message(), None), 1)
+            }
+            Self::ServiceUnavailable => AER::ConnectorError(
+                ApiError::new("DC", 12, self.error_message(), None),
+                reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            Self::GatewayTimeout => AER::ConnectorError(
+                ApiError::new("DC", 13, self.error_message(), None),
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ),
         }
     }
 }
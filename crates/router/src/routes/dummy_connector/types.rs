@@ -1,4 +1,4 @@
-use api_models::enums::Currency;
+use api_models::enums::{CaptureMethod, Currency};
 use common_utils::{errors::CustomResult, generate_id_with_default_len};
 use error_stack::report;
 use masking::Secret;
@@ -16,6 +16,8 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
+    DummyPaymentVoid,
     DummyRefundCreate,
     DummyRefundRetrieve,
 }
@@ -64,6 +66,8 @@ pub enum DummyConnectorStatus {
     #[default]
     Processing,
     Failed,
+    Authorized,
+    Cancelled,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -95,7 +99,7 @@ impl DummyConnectorPaymentAttempt {
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
     ) -> DummyConnectorPaymentData {
-        DummyConnectorPaymentData {
+        let payment_data = DummyConnectorPaymentData {
             attempt_id: self.attempt_id,
             payment_id: self.payment_id,
             status,
@@ -107,6 +111,15 @@ impl DummyConnectorPaymentAttempt {
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            capture_method: self.payment_request.capture_method,
+            webhook_url: self.payment_request.webhook_url,
+        };
+        match payment_data.status {
+            DummyConnectorStatus::Succeeded => DummyConnectorPaymentData {
+                status: payment_data.get_successful_status(),
+                ..payment_data
+            },
+            _ => payment_data,
         }
     }
 }
@@ -118,6 +131,8 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    pub capture_method: Option<CaptureMethod>,
+    pub webhook_url: Option<String>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -259,9 +274,42 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    pub capture_method: Option<CaptureMethod>,
+    pub webhook_url: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
+    /// The status of the payment once authorized: manually captured payments are only authorized
+    /// until they are captured
+    pub fn get_successful_status(&self) -> DummyConnectorStatus {
+        match self.capture_method {
+            Some(CaptureMethod::Manual) => DummyConnectorStatus::Authorized,
+            _ => DummyConnectorStatus::Succeeded,
+        }
+    }
+
+    pub fn is_eligible_for_capture(&self, capture_amount: i64) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the capture"));
+        }
+        if self.amount < capture_amount {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+                    .attach_printable("Authorized amount is lesser than capture amount"),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn is_eligible_for_void(&self) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the void"));
+        }
+        Ok(())
+    }
+
     pub fn is_eligible_for_refund(&self, refund_amount: i64) -> DummyConnectorResult<()> {
         if self.eligible_amount < refund_amount {
             return Err(
@@ -330,10 +378,22 @@ pub struct DummyConnectorPaymentCompleteBody {
     pub confirm: bool,
 }
 
+#[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorVoidRequest {
+    pub payment_id: String,
+}
+
 #[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
     pub payment_id: Option<String>,
+    pub webhook_url: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -372,6 +432,48 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentAuthorized,
+    PaymentCancelled,
+    RefundSucceeded,
+    RefundFailed,
+}
+
+impl DummyConnectorWebhookEventType {
+    pub fn for_payment(status: &DummyConnectorStatus) -> Option<Self> {
+        match status {
+            DummyConnectorStatus::Succeeded => Some(Self::PaymentSucceeded),
+            DummyConnectorStatus::Failed => Some(Self::PaymentFailed),
+            DummyConnectorStatus::Authorized => Some(Self::PaymentAuthorized),
+            DummyConnectorStatus::Cancelled => Some(Self::PaymentCancelled),
+            DummyConnectorStatus::Processing => None,
+        }
+    }
+
+    pub fn for_refund(status: &DummyConnectorStatus) -> Option<Self> {
+        match status {
+            DummyConnectorStatus::Succeeded => Some(Self::RefundSucceeded),
+            DummyConnectorStatus::Failed => Some(Self::RefundFailed),
+            DummyConnectorStatus::Processing
+            | DummyConnectorStatus::Authorized
+            | DummyConnectorStatus::Cancelled => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub payment_id: String,
+    pub refund_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
@@ -1,17 +1,19 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{ext_traits::AsyncExt, request::RequestContent};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
 use rand::{distributions::Uniform, prelude::Distribution};
+use router_env::logger;
 use tokio::time as tokio;
+use tracing_futures::Instrument;
 
 use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::SessionState};
+use crate::{configs::settings, routes::SessionState, services};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
@@ -25,6 +27,46 @@ pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     .await
 }
 
+/// Notifies the router of the outcome of a payment or a refund the way a connector would, once
+/// the router has had the time to process the response of the request. The webhooks are sent in
+/// the background, the failures being logged.
+pub fn send_webhook(
+    state: &SessionState,
+    webhook_url: Option<String>,
+    webhook: Option<types::DummyConnectorWebhook>,
+) {
+    let (Some(webhook_url), Some(webhook)) = (webhook_url, webhook) else {
+        return;
+    };
+    let state = state.clone();
+    let _task_handle = ::tokio::spawn(
+        async move {
+            tokio::sleep(tokio::Duration::from_millis(
+                consts::WEBHOOK_DELAY_IN_MILLIS,
+            ))
+            .await;
+            let request = services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&webhook_url)
+                .attach_default_headers()
+                .set_body(RequestContent::Json(Box::new(webhook)))
+                .build();
+            match state
+                .api_client
+                .send_request(&state, request, None, None, false)
+                .await
+            {
+                Ok(response) if response.status().is_success() => {}
+                Ok(response) => {
+                    logger::warn!(status = %response.status(), "Dummy connector webhook rejected")
+                }
+                Err(error) => logger::error!(?error, "Failed to send the dummy connector webhook"),
+            }
+        }
+        .in_current_span(),
+    );
+}
+
 pub async fn store_data_in_redis(
     state: &SessionState,
     key: String,
@@ -237,6 +279,32 @@ impl ProcessPaymentAttempt for types::DummyConnectorCard {
     }
 }
 
+/// Failures simulated for the payments of these amounts (in minor units), whatever the payment
+/// method, so that the handling of connector errors can be tested with any test card
+fn get_error_from_amount(amount: i64) -> Option<errors::DummyConnectorErrors> {
+    match amount {
+        4001 => Some(errors::DummyConnectorErrors::PaymentDeclined {
+            message: "Do not honor",
+        }),
+        4002 => Some(errors::DummyConnectorErrors::PaymentDeclined {
+            message: "Insufficient funds",
+        }),
+        4003 => Some(errors::DummyConnectorErrors::PaymentDeclined {
+            message: "Expired card",
+        }),
+        4290 => Some(errors::DummyConnectorErrors::TooManyRequests),
+        5000 => Some(errors::DummyConnectorErrors::InternalServerError),
+        5030 => Some(errors::DummyConnectorErrors::ServiceUnavailable),
+        5040 => Some(errors::DummyConnectorErrors::GatewayTimeout),
+        _ => None,
+    }
+}
+
+/// Refunds of this amount (in minor units) are simulated to be declined by the connector
+pub fn is_refund_declined(amount: i64) -> bool {
+    amount == 4001
+}
+
 impl types::DummyConnectorCard {
     pub fn get_flow_from_card_number(
         self,
@@ -339,6 +407,9 @@ impl types::DummyConnectorPaymentData {
         state: &SessionState,
         payment_attempt: types::DummyConnectorPaymentAttempt,
     ) -> types::DummyConnectorResult<Self> {
+        if let Some(error) = get_error_from_amount(payment_attempt.payment_request.amount) {
+            Err(report!(error).attach_printable("Simulated failure for the payment amount"))?;
+        }
         let redirect_url = format!(
             "{}/dummy-connector/authorize/{}",
             state.base_url, payment_attempt.attempt_id
@@ -0,0 +1,75 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::feature_flags as feature_flag_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, feature_flags},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::FeatureFlagRetrieve))]
+pub async fn retrieve_feature_flag(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<feature_flag_types::FeatureFlag>,
+    query: web::Query<feature_flag_types::FeatureFlagTarget>,
+) -> HttpResponse {
+    let flow = Flow::FeatureFlagRetrieve;
+    let flag = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, target, _| feature_flags::retrieve_feature_flag(state, flag, target),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::FeatureFlagUpdate))]
+pub async fn update_feature_flag(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<feature_flag_types::FeatureFlag>,
+    json_payload: web::Json<feature_flag_types::FeatureFlagUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::FeatureFlagUpdate;
+    let flag = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| feature_flags::update_feature_flag(state, flag, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::FeatureFlagDelete))]
+pub async fn delete_feature_flag(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<feature_flag_types::FeatureFlag>,
+    query: web::Query<feature_flag_types::FeatureFlagTarget>,
+) -> HttpResponse {
+    let flow = Flow::FeatureFlagDelete;
+    let flag = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, target, _| feature_flags::delete_feature_flag(state, flag, target),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
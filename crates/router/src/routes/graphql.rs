@@ -0,0 +1,43 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::graphql as graphql_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, graphql, response_field_access::ResponseFieldRestrictions},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::GraphQLQuery))]
+pub async fn graphql_query(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<graphql_types::GraphQLRequest>,
+) -> HttpResponse {
+    let flow = Flow::GraphQLQuery;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            // The sensitive fields are hidden in each object looked up by the query rather than
+            // in the response, whose fields are named after the query
+            let restrictions = req.extensions_mut().remove::<ResponseFieldRestrictions>();
+            graphql::execute_query(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                restrictions,
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,10 +1,13 @@
 use actix_web::{web, HttpRequest};
-use api_models::health_check::RouterHealthCheckResponse;
+use api_models::health_check::{DependencyStatus, RouterHealthCheckResponse};
 use router_env::{instrument, logger, tracing, Flow};
 
 use super::app;
 use crate::{
-    core::{api_locking, health_check::HealthCheckInterface},
+    core::{
+        api_locking,
+        health_check::{self, HealthCheckInterface},
+    },
     errors::{self, RouterResponse},
     routes::metrics,
     services::{api, authentication as auth},
@@ -41,6 +44,39 @@ pub async fn deep_health_check(
     .await
 }
 
+/// Reports the status of each dependency, responding with 503 when a critical dependency is
+/// unhealthy so that the load balancers stop routing to the instance
+#[instrument(skip_all, fields(flow = ?Flow::DetailedHealthCheck))]
+pub async fn detailed_health_check(
+    state: web::Data<app::AppState>,
+    request: HttpRequest,
+) -> impl actix_web::Responder {
+    metrics::HEALTH_METRIC.add(&metrics::CONTEXT, 1, &[]);
+
+    let flow = Flow::DetailedHealthCheck;
+
+    let mut response = Box::pin(api::server_wrap(
+        flow,
+        state,
+        &request,
+        (),
+        |state, _: (), _, _| health_check::detailed_health_check(state),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await;
+
+    let is_unhealthy = response
+        .headers()
+        .get(health_check::HEALTH_STATUS_HEADER)
+        .is_some_and(|status| status == DependencyStatus::Unhealthy.to_string().as_str());
+    if is_unhealthy {
+        *response.status_mut() = actix_web::http::StatusCode::SERVICE_UNAVAILABLE;
+    }
+
+    response
+}
+
 async fn deep_health_check_func(
     state: app::SessionState,
 ) -> RouterResponse<RouterHealthCheckResponse> {
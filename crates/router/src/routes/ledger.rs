@@ -0,0 +1,84 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::ledger as ledger_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, ledger::reporting},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerBalancesRetrieve))]
+pub async fn retrieve_ledger_balances(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ledger_types::LedgerFilters>,
+) -> HttpResponse {
+    let flow = Flow::LedgerBalancesRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, filters, _| {
+            reporting::retrieve_ledger_balances(state, auth.merchant_account, filters)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerEntriesList))]
+pub async fn list_ledger_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ledger_types::LedgerEntriesQuery>,
+) -> HttpResponse {
+    let flow = Flow::LedgerEntriesList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            reporting::list_ledger_entries(state, auth.merchant_account, query)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LedgerEntriesExport))]
+pub async fn export_ledger_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ledger_types::LedgerFilters>,
+) -> HttpResponse {
+    let flow = Flow::LedgerEntriesExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, filters, _| {
+            reporting::export_ledger_entries(state, auth.merchant_account, filters)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -20,6 +20,7 @@ pub enum ApiIdentifier {
     CardsInfo,
     Files,
     Cache,
+    Logging,
     Business,
     Verification,
     ApiKeys,
@@ -29,6 +30,13 @@ pub enum ApiIdentifier {
     Forex,
     RustLockerMigration,
     Gsm,
+    FeatureFlags,
+    PmFilters,
+    BulkForceSync,
+    ProcessTracker,
+    Jobs,
+    MasterKeyRotation,
+    SandboxTraffic,
     Role,
     User,
     UserRole,
@@ -36,6 +44,19 @@ pub enum ApiIdentifier {
     Recon,
     Poll,
     ApplePayCertificatesMigration,
+    Reports,
+    Settlements,
+    Ledger,
+    Search,
+    GraphQL,
+    CardExport,
+    ConnectorMigration,
+    AuditEvents,
+    Orders,
+    Organization,
+    Tenants,
+    ConnectorCapabilities,
+    Authentication,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -45,7 +66,29 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
-            | Flow::MerchantAccountList => Self::MerchantAccount,
+            | Flow::MerchantAccountList
+            | Flow::MerchantKvSettingsRetrieve
+            | Flow::MerchantKvSettingsUpdate
+            | Flow::MerchantOnboardingStatus
+            | Flow::MerchantRateLimitsRetrieve
+            | Flow::MerchantRateLimitsUpdate
+            | Flow::MerchantAlertRulesRetrieve
+            | Flow::MerchantAlertRulesUpdate
+            | Flow::MerchantResponseFieldAccessRetrieve
+            | Flow::MerchantResponseFieldAccessUpdate
+            | Flow::MerchantApiVersionRetrieve
+            | Flow::MerchantApiVersionUpdate
+            | Flow::MerchantKeyRotationSchedule
+            | Flow::MerchantKeyRotationRetrieve
+            | Flow::DrainerTuningRetrieve
+            | Flow::DrainerTuningUpdate
+            | Flow::KvBacklogRetrieve
+            | Flow::ConnectedAccountCreate
+            | Flow::ConnectedAccountsList
+            | Flow::ConnectedAccountRetrieve
+            | Flow::ConnectedAccountUpdate
+            | Flow::ConnectedAccountConnectorCreate
+            | Flow::ConnectedAccountsReport => Self::MerchantAccount,
 
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
@@ -59,7 +102,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingDeleteConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
-            | Flow::DecisionManagerUpsertConfig => Self::Routing,
+            | Flow::DecisionManagerUpsertConfig
+            | Flow::FraudRulesUpsertConfig
+            | Flow::FraudRulesDeleteConfig
+            | Flow::FraudRulesRetrieveConfig => Self::Routing,
 
             Flow::RetrieveForexFlow => Self::Forex,
 
@@ -67,9 +113,15 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
+            Flow::AddToAllowlist => Self::Blocklist,
+            Flow::DeleteFromAllowlist => Self::Blocklist,
+            Flow::ListAllowlist => Self::Blocklist,
 
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
+            | Flow::MerchantConnectorsValidate
+            | Flow::MerchantConnectorsCredentialsStage
+            | Flow::MerchantConnectorsCredentialsCutover
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
             | Flow::MerchantConnectorsList => Self::MerchantConnector,
@@ -85,12 +137,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
-            | Flow::CustomersList => Self::Customers,
+            | Flow::CustomersList
+            | Flow::CustomersOverview
+            | Flow::CustomersRedact
+            | Flow::CustomersRedactionRetrieve => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
-            Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
-            Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
+            Flow::DeepHealthCheck | Flow::DetailedHealthCheck | Flow::HealthCheck => Self::Health,
+            Flow::MandatesRetrieve
+            | Flow::MandatesRevoke
+            | Flow::MandatesList
+            | Flow::MandatesMigrate
+            | Flow::MandatesPreNotification => Self::Mandates,
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsList
@@ -102,7 +161,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
-            | Flow::PaymentMethodSave => Self::PaymentMethods,
+            | Flow::PaymentMethodOrderSet
+            | Flow::PaymentMethodUsageRestrictionsSet
+            | Flow::PaymentMethodSave
+            | Flow::VaultTokenExpire
+            | Flow::VaultCleanup
+            | Flow::VaultAuditEventsList => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
 
@@ -112,6 +176,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
+            | Flow::PaymentsCaptureSchedule
+            | Flow::PaymentsCaptureScheduleCancel
+            | Flow::PaymentsMetadataUpdate
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
@@ -124,8 +191,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
+            | Flow::PaymentsConnectorExchanges
+            | Flow::PaymentsStatusHistory
             | Flow::PaymentsCompleteAuthorize
-            | Flow::PaymentsManualUpdate => Self::Payments,
+            | Flow::PaymentsManualUpdate
+            | Flow::PaymentMethodSessionCreate
+            | Flow::PaymentMethodSessionConfirm
+            | Flow::PaymentMethodSessionRetrieve => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -143,19 +215,24 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsRetrieveForceSync
             | Flow::RefundsUpdate
             | Flow::RefundsList
-            | Flow::RefundsFilters => Self::Refunds,
+            | Flow::RefundsFilters
+            | Flow::RefundsCreateBulk => Self::Refunds,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventIncomingList
+            | Flow::WebhookEventIncomingRetrieve => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
-            | Flow::ApiKeyList => Self::ApiKeys,
+            | Flow::ApiKeyList
+            | Flow::OAuth2TokenCreate
+            | Flow::OAuth2TokenRevoke => Self::ApiKeys,
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
@@ -168,15 +245,36 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
 
-            Flow::CacheInvalidate => Self::Cache,
+            Flow::CacheInvalidate
+            | Flow::InMemoryCacheList
+            | Flow::InMemoryCacheKeyRetrieve
+            | Flow::InMemoryCacheKeyFlush => Self::Cache,
+
+            Flow::LoggingRetrieve | Flow::LogLevelsUpdate | Flow::RequestSamplingUpdate => {
+                Self::Logging
+            }
 
             Flow::BusinessProfileCreate
             | Flow::BusinessProfileUpdate
             | Flow::BusinessProfileRetrieve
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList
+            | Flow::BusinessProfileClone
+            | Flow::BusinessProfileTemplateUpsert
+            | Flow::BusinessProfileTemplatesList
+            | Flow::BusinessProfileTemplateDelete
+            | Flow::BusinessProfileTemplateApply
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Business,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::VelocityLimitsUpsert
+            | Flow::VelocityLimitsRetrieve
+            | Flow::VelocityLimitsDelete
+            | Flow::PmDisplayRulesUpsert
+            | Flow::PmDisplayRulesRetrieve
+            | Flow::PmDisplayRulesDelete
+            | Flow::WalletDecryptionKeyCreate
+            | Flow::WalletDecryptionKeysList
+            | Flow::WalletDecryptionKeyRetire => Self::Business,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -191,6 +289,30 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GsmRuleUpdate
             | Flow::GsmRuleDelete => Self::Gsm,
 
+            Flow::FeatureFlagRetrieve | Flow::FeatureFlagUpdate | Flow::FeatureFlagDelete => {
+                Self::FeatureFlags
+            }
+
+            Flow::PmFiltersRetrieve | Flow::PmFiltersUpdate | Flow::PmFiltersDelete => {
+                Self::PmFilters
+            }
+
+            Flow::SandboxTrafficGenerate => Self::SandboxTraffic,
+
+            Flow::BulkForceSyncSchedule | Flow::BulkForceSyncRetrieve => Self::BulkForceSync,
+
+            Flow::ProcessTrackerTaskList
+            | Flow::ProcessTrackerTaskRetrieve
+            | Flow::ProcessTrackerTaskPause
+            | Flow::ProcessTrackerTaskResume
+            | Flow::ProcessTrackerTaskReschedule => Self::ProcessTracker,
+
+            Flow::JobRetrieve | Flow::JobCancel => Self::Jobs,
+
+            Flow::MasterKeyRotationSchedule | Flow::MasterKeyRotationRetrieve => {
+                Self::MasterKeyRotation
+            }
+
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
             Flow::UserConnectAccount
@@ -258,6 +380,61 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ReconVerifyToken => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::ReportTemplateCreate
+            | Flow::ReportTemplateList
+            | Flow::ReportTemplateRetrieve
+            | Flow::ReportTemplateUpdate
+            | Flow::ReportTemplateDelete
+            | Flow::ReportDownload => Self::Reports,
+
+            Flow::CardExportCreate
+            | Flow::CardExportList
+            | Flow::CardExportRetrieve
+            | Flow::CardExportApprove
+            | Flow::CardExportReject
+            | Flow::CardExportDownload => Self::CardExport,
+
+            Flow::ConnectorMigrationCreate
+            | Flow::ConnectorMigrationList
+            | Flow::ConnectorMigrationRetrieve
+            | Flow::ConnectorMigrationCancel
+            | Flow::ConnectorMigrationPaymentMethodsList => Self::ConnectorMigration,
+
+            Flow::SettlementFileIngest
+            | Flow::SettlementLinesIngest
+            | Flow::SettlementBatchRetrieve
+            | Flow::SettlementBatchRematch
+            | Flow::SettlementExceptionsList
+            | Flow::SettlementLineResolve
+            | Flow::PaymentReconciliationRetrieve => Self::Settlements,
+
+            Flow::LedgerBalancesRetrieve | Flow::LedgerEntriesList | Flow::LedgerEntriesExport => {
+                Self::Ledger
+            }
+
+            Flow::Search => Self::Search,
+
+            Flow::GraphQLQuery => Self::GraphQL,
+
+            Flow::AuditEventsList => Self::AuditEvents,
+
+            Flow::OrderCreate | Flow::OrderRetrieve | Flow::OrderUpdate => Self::Orders,
+
+            Flow::AuthenticationCreate
+            | Flow::AuthenticationAuthenticate
+            | Flow::AuthenticationRetrieve => Self::Authentication,
+
+            Flow::OrganizationMerchantsList
+            | Flow::OrganizationMerchantUpdate
+            | Flow::OrganizationPaymentsAggregate
+            | Flow::OrganizationConnectorShare => Self::Organization,
+
+            Flow::TenantCreate | Flow::TenantList | Flow::TenantRetrieve | Flow::TenantUpdate => {
+                Self::Tenants
+            }
+
+            Flow::ConnectorCapabilitiesRetrieve => Self::ConnectorCapabilities,
         }
     }
 }
@@ -0,0 +1,65 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::logging as logging_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, logging},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::LoggingRetrieve))]
+pub async fn retrieve_logging(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::LoggingRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| logging::retrieve_logging(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LogLevelsUpdate))]
+pub async fn update_log_levels(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<logging_types::LogLevelsUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::LogLevelsUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| logging::update_log_levels(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RequestSamplingUpdate))]
+pub async fn update_request_sampling(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<logging_types::RequestSamplingUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::RequestSamplingUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| logging::update_request_sampling(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -138,3 +138,86 @@ pub async fn retrieve_mandates_list(
     ))
     .await
 }
+
+/// Mandates - Migrate Mandates
+///
+/// Imports mandates set up with another processor, so that they can be used for merchant initiated transactions
+#[utoipa::path(
+    post,
+    path = "/mandates/migrate",
+    request_body = MandateMigrationRequest,
+    responses(
+        (status = 200, description = "The mandates were processed, the result of each import is part of the response", body = MandateMigrationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Mandates",
+    operation_id = "Migrate Mandates",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesMigrate))]
+pub async fn migrate_mandates(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<mandates::MandateMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MandatesMigrate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            mandate::migrate_mandates(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Mandates - Schedule Pre-notification
+///
+/// Schedules the notification of the customer, as required by the scheme rules, ahead of a direct debit on the mandate
+#[utoipa::path(
+    post,
+    path = "/mandates/{mandate_id}/pre_notification",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    request_body = MandatePreNotificationRequest,
+    responses(
+        (status = 200, description = "The pre-notification was scheduled successfully", body = MandatePreNotificationResponse),
+        (status = 400, description = "The debit date does not satisfy the notification window of the scheme"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Schedule Mandate Pre-notification",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesPreNotification))]
+pub async fn schedule_mandate_pre_notification(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<mandates::MandatePreNotificationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MandatesPreNotification;
+    let mandate_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            mandate::schedule_mandate_pre_notification(
+                state,
+                auth.merchant_account,
+                mandate_id.clone(),
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
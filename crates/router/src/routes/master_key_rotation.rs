@@ -0,0 +1,52 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::master_key_rotation as master_key_rotation_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, master_key_rotation},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::MasterKeyRotationSchedule))]
+pub async fn schedule_master_key_rotation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<master_key_rotation_types::MasterKeyRotationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MasterKeyRotationSchedule;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| master_key_rotation::schedule_master_key_rotation(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::MasterKeyRotationRetrieve))]
+pub async fn retrieve_master_key_rotation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MasterKeyRotationRetrieve;
+    let payload = master_key_rotation_types::MasterKeyRotationJobId {
+        job_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| master_key_rotation::retrieve_master_key_rotation(state, req.job_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
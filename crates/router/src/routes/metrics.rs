@@ -15,6 +15,9 @@ counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 counter_metric!(REQUEST_STATUS, GLOBAL_METER);
 histogram_metric!(REQUEST_TIME, GLOBAL_METER);
 histogram_metric!(EXTERNAL_REQUEST_TIME, GLOBAL_METER);
+counter_metric!(REQUESTS_SHED_COUNT, GLOBAL_METER); // No. of requests shed by the overloaded instance
+histogram_metric!(REQUEST_ADMISSION_WAIT_TIME, GLOBAL_METER); // Time spent waiting for admission
+counter_metric!(DEPRECATED_API_VERSION_REQUESTS, GLOBAL_METER); // Responses of deprecated versions
 
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
@@ -39,6 +42,10 @@ counter_metric!(MANUAL_RETRY_VALIDATION_FAILED, GLOBAL_METER);
 counter_metric!(STORED_TO_LOCKER, GLOBAL_METER);
 counter_metric!(GET_FROM_LOCKER, GLOBAL_METER);
 counter_metric!(DELETE_FROM_LOCKER, GLOBAL_METER);
+counter_metric!(CARDS_EXPORTED, GLOBAL_METER); // Cards exported to another payment service provider
+counter_metric!(CONNECTOR_MIGRATION_PAYMENT_METHODS, GLOBAL_METER); // Stored credentials migrated
+counter_metric!(VAULT_ENTRIES_CLEANED_UP, GLOBAL_METER); // Orphaned vault entries deleted
+counter_metric!(PAYMENTS_FORCE_SYNCED_IN_BULK, GLOBAL_METER); // Stuck payments force synced in bulk
 
 counter_metric!(CREATED_TOKENIZED_CARD, GLOBAL_METER);
 counter_metric!(DELETED_TOKENIZED_CARD, GLOBAL_METER);
@@ -60,6 +67,7 @@ histogram_metric!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
 counter_metric!(SESSION_TOKEN_CREATED, GLOBAL_METER);
 
 counter_metric!(CONNECTOR_CALL_COUNT, GLOBAL_METER); // Attributes needed
+counter_metric!(CONNECTOR_RESPONSE_CACHE_HIT_COUNT, GLOBAL_METER); // Syncs served a cached response
 
 counter_metric!(THREE_DS_PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
@@ -121,6 +129,9 @@ counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process trac
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
 
+// Alerting metrics
+counter_metric!(ALERTS_TRIGGERED_COUNT, GLOBAL_METER); // Alerts raised by the merchant alert rules
+
 // Access token metrics
 //
 // A counter to indicate the number of new access tokens created
@@ -6,14 +6,7 @@ pub fn spawn_metrics_collector(metrics_collection_interval_in_secs: &Option<u16>
     let metrics_collection_interval = metrics_collection_interval_in_secs
         .unwrap_or(DEFAULT_BG_METRICS_COLLECTION_INTERVAL_IN_SECS);
 
-    let cache_instances = [
-        &cache::CONFIG_CACHE,
-        &cache::ACCOUNTS_CACHE,
-        &cache::ROUTING_CACHE,
-        &cache::CGRAPH_CACHE,
-        &cache::DECISION_MANAGER_CACHE,
-        &cache::SURCHARGE_CACHE,
-    ];
+    let cache_instances = cache::all_caches();
 
     tokio::spawn(async move {
         loop {
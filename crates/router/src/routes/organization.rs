@@ -0,0 +1,133 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::{admin, organization::ConnectorShareRequest, payments::TimeRange};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, organization},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationMerchantsList))]
+pub async fn list_organization_merchants(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationMerchantsList;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        org_id.clone(),
+        |state, _, org_id, _| organization::list_organization_merchants(state, org_id),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthOrganizationFromRoute {
+                org_id,
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationMerchantUpdate))]
+pub async fn update_organization_merchant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<admin::MerchantAccountUpdate>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationMerchantUpdate;
+    let (org_id, merchant_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            organization::update_organization_merchant(
+                state,
+                org_id.clone(),
+                merchant_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthOrganizationFromRoute {
+                org_id: org_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationPaymentsAggregate))]
+pub async fn aggregate_organization_payments(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationPaymentsAggregate;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, time_range, _| {
+            organization::aggregate_organization_payments(state, org_id.clone(), time_range)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthOrganizationFromRoute {
+                org_id: org_id.clone(),
+                required_permission: Permission::PaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationConnectorShare))]
+pub async fn share_organization_connector(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<ConnectorShareRequest>,
+) -> HttpResponse {
+    let flow = Flow::OrganizationConnectorShare;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| organization::share_connector(state, org_id.clone(), req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthOrganizationFromRoute {
+                org_id: org_id.clone(),
+                required_permission: Permission::MerchantConnectorAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
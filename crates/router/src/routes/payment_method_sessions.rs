@@ -0,0 +1,153 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::{
+    payment_method_sessions as payment_method_session_types, payments::HeaderPayload,
+};
+use router_env::{instrument, tracing, Flow};
+
+use super::{app::AppState, payments::helpers};
+use crate::{
+    core::{
+        api_locking::{self, GetLockingInput},
+        payment_method_sessions,
+    },
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::transformers::ForeignTryFrom,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodSessionCreate))]
+pub async fn create_payment_method_session(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_method_session_types::PaymentMethodSessionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodSessionCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            payment_method_sessions::create_payment_method_session(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodSessionConfirm, payment_id))]
+pub async fn confirm_payment_method_session(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_method_session_types::PaymentMethodSessionConfirmRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodSessionConfirm;
+    let session_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &session_id);
+
+    let mut payload = match payment_method_sessions::get_session_confirm_request(
+        session_id,
+        json_payload.into_inner(),
+    ) {
+        Ok(payload) => payload,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    if let Err(err) = helpers::populate_ip_into_browser_info(&req, &mut payload) {
+        return api::log_and_return_error_response(err);
+    }
+
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let (auth_type, auth_flow) =
+        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, req_state| {
+            payment_method_sessions::confirm_payment_method_session(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                auth_flow,
+                header_payload.clone(),
+            )
+        },
+        &*auth_type,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodSessionRetrieve, payment_id))]
+pub async fn retrieve_payment_method_session(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<payment_method_session_types::PaymentMethodSessionRetrieveQuery>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodSessionRetrieve;
+    let session_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &session_id);
+
+    let payload =
+        match payment_method_sessions::get_session_retrieve_request(session_id, query.into_inner())
+        {
+            Ok(payload) => payload,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    let (auth_type, auth_flow) =
+        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, req_state| {
+            payment_method_sessions::retrieve_payment_method_session(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                auth_flow,
+            )
+        },
+        auth::auth_type(
+            &*auth_type,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
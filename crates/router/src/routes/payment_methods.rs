@@ -441,6 +441,70 @@ pub async fn default_payment_method_set_api(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodOrderSet))]
+pub async fn payment_method_order_set_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    json_payload: web::Json<payment_methods::CustomerPaymentMethodOrderRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodOrderSet;
+    let payload = payment_methods::CustomerPaymentMethodOrderRequest {
+        customer_id: Some(path.into_inner()),
+        ..json_payload.into_inner()
+    };
+
+    let ephemeral_auth = match auth::is_ephemeral_auth(req.headers()) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            cards::set_payment_method_order(state, auth.merchant_account, auth.key_store, req)
+        },
+        &*ephemeral_auth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodUsageRestrictionsSet))]
+pub async fn payment_method_usage_restrictions_set_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::PaymentMethodUsageRestrictions>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodUsageRestrictionsSet;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            cards::set_payment_method_usage_restrictions(
+                state,
+                auth.merchant_account,
+                payment_method_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
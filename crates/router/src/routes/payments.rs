@@ -14,8 +14,11 @@ use super::app::ReqState;
 use crate::{
     self as app,
     core::{
+        connector_exchanges,
         errors::{self, http_not_implemented},
-        payments::{self, PaymentRedirectFlow},
+        payments::{
+            self, async_confirmation, capture_schedule, metadata_update, PaymentRedirectFlow,
+        },
         utils as core_utils,
     },
     routes::lock_utils,
@@ -124,17 +127,32 @@ pub async fn payments_create(
         state,
         &req,
         payload,
-        |state, auth, req, req_state| {
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
-                state,
-                req_state,
-                auth.merchant_account,
-                auth.key_store,
-                HeaderPayload::default(),
-                req,
-                api::AuthFlow::Merchant,
-            )
+        |state, auth, req, req_state| async move {
+            if req.is_async_confirmation() {
+                async_confirmation::payments_confirm_async(
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payments::PaymentCreate,
+                    req,
+                    api::AuthFlow::Merchant,
+                    HeaderPayload::default(),
+                )
+                .await
+            } else {
+                authorize_verify_select::<_>(
+                    payments::PaymentCreate,
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    HeaderPayload::default(),
+                    req,
+                    api::AuthFlow::Merchant,
+                )
+                .await
+            }
         },
         match env::which() {
             env::Env::Production => &auth::ApiKeyAuth,
@@ -271,6 +289,11 @@ pub async fn payments_retrieve(
         &req,
         payload,
         |state, auth, req, req_state| {
+            let state = if req.force_sync {
+                state.with_status_actor(api_enums::PaymentStatusActor::Sync)
+            } else {
+                state
+            };
             payments::payments_core::<api_types::PSync, payment_types::PaymentsResponse, _, _, _>(
                 state,
                 req_state,
@@ -492,16 +515,36 @@ pub async fn payments_confirm(
         &req,
         payload,
         |state, auth, req, req_state| {
-            authorize_verify_select::<_>(
-                payments::PaymentConfirm,
-                state,
-                req_state,
-                auth.merchant_account,
-                auth.key_store,
-                header_payload.clone(),
-                req,
-                auth_flow,
-            )
+            let header_payload = header_payload.clone();
+            async move {
+                // The payment is saved with the details of the request before being confirmed in
+                // the background, the confirmation itself running the `PaymentConfirm` operation
+                if req.is_async_confirmation() {
+                    async_confirmation::payments_confirm_async(
+                        state,
+                        req_state,
+                        auth.merchant_account,
+                        auth.key_store,
+                        payments::PaymentUpdate,
+                        req,
+                        auth_flow,
+                        header_payload,
+                    )
+                    .await
+                } else {
+                    authorize_verify_select::<_>(
+                        payments::PaymentConfirm,
+                        state,
+                        req_state,
+                        auth.merchant_account,
+                        auth.key_store,
+                        header_payload,
+                        req,
+                        auth_flow,
+                    )
+                    .await
+                }
+            }
         },
         &*auth_type,
         locking_action,
@@ -569,6 +612,121 @@ pub async fn payments_capture(
     ))
     .await
 }
+
+/// Payments - Schedule Capture
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureSchedule, payment_id))]
+pub async fn payments_capture_schedule(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsCaptureScheduleRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    let flow = Flow::PaymentsCaptureSchedule;
+    let payload = payment_types::PaymentsCaptureScheduleRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            capture_schedule::payments_capture_schedule(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payments - Cancel Scheduled Capture
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureScheduleCancel, payment_id))]
+pub async fn payments_capture_schedule_cancel(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    let flow = Flow::PaymentsCaptureScheduleCancel;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            capture_schedule::payments_capture_schedule_cancel(
+                state,
+                auth.merchant_account,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payments - Update Metadata
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsMetadataUpdate, payment_id))]
+pub async fn payments_metadata_update(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsMetadataUpdateRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    let flow = Flow::PaymentsMetadataUpdate;
+    let payload = payment_types::PaymentsMetadataUpdateRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            metadata_update::payments_metadata_update(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payments - Session token
 ///
 /// To create the session object or to get session token for wallets
@@ -1060,20 +1218,15 @@ pub async fn payments_approve(
         &http_req,
         payload.clone(),
         |state, auth, req, req_state| {
-            payments::payments_core::<api_types::Capture, payment_types::PaymentsResponse, _, _, _>(
+            payments::payments_approve_core(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.key_store,
-                payments::PaymentApprove,
                 payment_types::PaymentsCaptureRequest {
                     payment_id: req.payment_id,
                     ..Default::default()
                 },
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
             )
         },
         match env::which() {
@@ -1114,21 +1267,16 @@ pub async fn payments_reject(
         &http_req,
         payload.clone(),
         |state, auth, req, req_state| {
-            payments::payments_core::<api_types::Void, payment_types::PaymentsResponse, _, _, _>(
+            payments::payments_reject_core(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.key_store,
-                payments::PaymentReject,
                 payment_types::PaymentsCancelRequest {
                     payment_id: req.payment_id,
                     cancellation_reason: Some("Rejected by merchant".to_string()),
                     ..Default::default()
                 },
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
             )
         },
         match env::which() {
@@ -1447,6 +1595,73 @@ pub async fn retrieve_extended_card_info(
     .await
 }
 
+/// Retrieve the exchanges with the connectors captured for the payment, only available to dashboard
+/// users allowed to update the merchant account
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsConnectorExchanges, payment_id))]
+pub async fn payments_connector_exchanges(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsConnectorExchanges;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            connector_exchanges::retrieve_connector_exchanges(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        &auth::JWTAuth(Permission::MerchantAccountWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Retrieve the changes of the status of the payment and of its attempts
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsStatusHistory, payment_id))]
+pub async fn payments_status_history(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsStatusHistory;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::status_history::retrieve_payment_status_history(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub fn get_or_generate_payment_id(
     payload: &mut payment_types::PaymentsRequest,
 ) -> errors::RouterResult<()> {
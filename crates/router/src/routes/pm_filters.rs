@@ -0,0 +1,73 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::pm_filters as pm_filter_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, pm_filters},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PmFiltersRetrieve))]
+pub async fn retrieve_pm_filters(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PmFiltersRetrieve;
+    let connector = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        connector,
+        |state, _, connector, _| pm_filters::retrieve_pm_filters(state, connector),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmFiltersUpdate))]
+pub async fn update_pm_filters(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<pm_filter_types::ConnectorPmFiltersRequest>,
+) -> HttpResponse {
+    let flow = Flow::PmFiltersUpdate;
+    let connector = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| pm_filters::update_pm_filters(state, connector.clone(), request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmFiltersDelete))]
+pub async fn delete_pm_filters(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PmFiltersDelete;
+    let connector = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        connector,
+        |state, _, connector, _| pm_filters::delete_pm_filters(state, connector),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,132 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::process_tracker as process_tracker_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, process_tracker},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskList))]
+pub async fn list_tasks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<process_tracker_types::ProcessTrackerTaskListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerTaskList;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| {
+            process_tracker::list_tasks(state, merchant_id.clone(), constraints)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskRetrieve))]
+pub async fn retrieve_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerTaskRetrieve;
+    let (merchant_id, task_id) = path.into_inner();
+    let payload = process_tracker_types::ProcessTrackerTaskId {
+        merchant_id,
+        task_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| process_tracker::retrieve_task(state, req.merchant_id, req.task_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskPause))]
+pub async fn pause_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerTaskPause;
+    let (merchant_id, task_id) = path.into_inner();
+    let payload = process_tracker_types::ProcessTrackerTaskId {
+        merchant_id,
+        task_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| process_tracker::pause_task(state, req.merchant_id, req.task_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskResume))]
+pub async fn resume_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerTaskResume;
+    let (merchant_id, task_id) = path.into_inner();
+    let payload = process_tracker_types::ProcessTrackerTaskId {
+        merchant_id,
+        task_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| process_tracker::resume_task(state, req.merchant_id, req.task_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskReschedule))]
+pub async fn reschedule_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<process_tracker_types::ProcessTrackerTaskRescheduleRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerTaskReschedule;
+    let (merchant_id, task_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            process_tracker::reschedule_task(state, merchant_id.clone(), task_id.clone(), req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{api_locking, jobs, refunds::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -46,6 +46,34 @@ pub async fn refunds_create(
     ))
     .await
 }
+
+/// Creates the refunds of many payments in the background, through a job whose progress is
+/// retrieved through the jobs API
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreateBulk))]
+pub async fn refunds_create_bulk(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::refunds::BulkRefundRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsCreateBulk;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            jobs::bulk_refunds::create_bulk_refund_job(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::RefundWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Refunds - Retrieve (GET)
 ///
 /// To retrieve the properties of a Refund. This may be used to get the status of a previously initiated payment or next action for an ongoing payment
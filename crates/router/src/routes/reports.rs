@@ -0,0 +1,156 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::reports as report_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, reports},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateCreate))]
+pub async fn create_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<report_types::ReportTemplateCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReportTemplateCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            reports::create_report_template(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateList))]
+pub async fn list_report_templates(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ReportTemplateList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            reports::list_report_templates(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateRetrieve))]
+pub async fn retrieve_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReportTemplateRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, template_id, _| {
+            reports::retrieve_report_template(state, auth.merchant_account, template_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateUpdate))]
+pub async fn update_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<report_types::ReportTemplateUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReportTemplateUpdate;
+    let template_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            reports::update_report_template(state, auth.merchant_account, template_id.clone(), req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateDelete))]
+pub async fn delete_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReportTemplateDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, template_id, _| {
+            reports::delete_report_template(state, auth.merchant_account, template_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// The download links are signed, so they do not require the merchant to be authenticated
+#[instrument(skip_all, fields(flow = ?Flow::ReportDownload))]
+pub async fn download_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    query: web::Query<report_types::ReportDownloadQuery>,
+) -> HttpResponse {
+    let flow = Flow::ReportDownload;
+    let (merchant_id, file_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _: (), query, _| {
+            reports::download_report(state, merchant_id.clone(), file_id.clone(), query)
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
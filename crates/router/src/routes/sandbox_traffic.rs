@@ -0,0 +1,44 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::sandbox_traffic as sandbox_traffic_types;
+use router_env::{env, instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, sandbox_traffic},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SandboxTrafficGenerate))]
+pub async fn generate_sandbox_traffic(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<sandbox_traffic_types::SandboxTrafficRequest>,
+) -> HttpResponse {
+    let flow = Flow::SandboxTrafficGenerate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, req_state| {
+            sandbox_traffic::generate_sandbox_traffic(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        match env::which() {
+            env::Env::Production => &auth::ApiKeyAuth,
+            _ => auth::auth_type(
+                &auth::ApiKeyAuth,
+                &auth::JWTAuth(Permission::PaymentWrite),
+                req.headers(),
+            ),
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
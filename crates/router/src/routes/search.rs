@@ -0,0 +1,34 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::search as search_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, search},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::Search))]
+pub async fn search(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<search_types::SearchRequest>,
+) -> HttpResponse {
+    let flow = Flow::Search;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            search::search(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,203 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::settlements as settlement_types;
+use router_env::{instrument, tracing, Flow};
+
+pub mod transformers;
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, settlements},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SettlementFileIngest))]
+pub async fn ingest_settlement_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let flow = Flow::SettlementFileIngest;
+    let ingest_request = match transformers::get_settlement_file_ingest_request(payload).await {
+        Ok(ingest_request) => ingest_request,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        ingest_request,
+        |state, auth: auth::AuthenticationData, req, _| {
+            settlements::ingest_settlement_file(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SettlementLinesIngest))]
+pub async fn ingest_settlement_lines(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<settlement_types::SettlementLinesIngestRequest>,
+) -> HttpResponse {
+    let flow = Flow::SettlementLinesIngest;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            settlements::ingest_settlement_lines(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SettlementBatchRetrieve))]
+pub async fn retrieve_settlement_batch(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SettlementBatchRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            settlements::retrieve_settlement_batch(state, auth.merchant_account, batch_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SettlementBatchRematch))]
+pub async fn rematch_settlement_batch(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SettlementBatchRematch;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            settlements::rematch_settlement_batch(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                batch_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SettlementExceptionsList))]
+pub async fn list_settlement_exceptions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<settlement_types::SettlementExceptionsQuery>,
+) -> HttpResponse {
+    let flow = Flow::SettlementExceptionsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            settlements::list_settlement_exceptions(state, auth.merchant_account, query)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SettlementLineResolve))]
+pub async fn resolve_settlement_line(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<settlement_types::SettlementLineResolveRequest>,
+) -> HttpResponse {
+    let flow = Flow::SettlementLineResolve;
+    let line_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            settlements::resolve_settlement_line(state, auth.merchant_account, line_id.clone(), req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReconciliationRetrieve))]
+pub async fn retrieve_payment_reconciliation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentReconciliationRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            settlements::retrieve_payment_reconciliation(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,69 @@
+use actix_multipart::Multipart;
+use actix_web::web::Bytes;
+use api_models::settlements::SettlementFileIngestRequest;
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{
+    core::{errors, files::helpers},
+    utils::OptionExt,
+};
+
+pub async fn get_settlement_file_ingest_request(
+    mut payload: Multipart,
+) -> CustomResult<SettlementFileIngestRequest, errors::ApiErrorResponse> {
+    let mut connector: Option<String> = None;
+    let mut merchant_connector_id: Option<String> = None;
+
+    let mut file_name: Option<String> = None;
+    let mut file_content: Option<Vec<Bytes>> = None;
+
+    while let Ok(Some(mut field)) = payload.try_next().await {
+        let content_disposition = field.content_disposition();
+        let field_name = content_disposition.get_name();
+        // Parse the different parameters expected in the multipart request
+        match field_name {
+            Some("connector") => {
+                connector = helpers::read_string(&mut field).await;
+            }
+            Some("merchant_connector_id") => {
+                merchant_connector_id = helpers::read_string(&mut field).await;
+            }
+            Some("file") => {
+                file_name = content_disposition.get_filename().map(String::from);
+
+                //Collect the file content and throw error if something fails
+                let mut file_data = Vec::new();
+                let mut stream = field.into_stream();
+                while let Some(chunk) = stream.next().await {
+                    match chunk {
+                        Ok(bytes) => file_data.push(bytes),
+                        Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable(format!("{}{}", "File parsing error: ", err))?,
+                    }
+                }
+                file_content = Some(file_data)
+            }
+            // Can ignore other params
+            _ => (),
+        }
+    }
+    let connector = connector.get_required_value("connector")?;
+    let file = match file_content {
+        Some(valid_file_content) => valid_file_content.concat().to_vec(),
+        None => Err(errors::ApiErrorResponse::MissingFile)
+            .attach_printable("Missing / Invalid file in the request")?,
+    };
+    if file.is_empty() {
+        Err(errors::ApiErrorResponse::MissingFile)
+            .attach_printable("Missing / Invalid file in the request")?
+    }
+
+    Ok(SettlementFileIngestRequest {
+        connector,
+        merchant_connector_id,
+        file_name,
+        file,
+    })
+}
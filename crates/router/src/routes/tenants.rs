@@ -0,0 +1,90 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::tenant::{TenantCreateRequest, TenantUpdateRequest};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, tenants},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantCreate))]
+pub async fn create_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<TenantCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::TenantCreate;
+    let app_state = state.get_ref().clone();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| tenants::create_tenant(state, app_state.clone(), req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantList))]
+pub async fn list_tenants(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::TenantList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| tenants::list_tenants(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantRetrieve))]
+pub async fn retrieve_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::TenantRetrieve;
+    let tenant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        tenant_id,
+        |state, _, tenant_id, _| tenants::retrieve_tenant(state, tenant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::TenantUpdate))]
+pub async fn update_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<TenantUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::TenantUpdate;
+    let tenant_id = path.into_inner();
+    let app_state = state.get_ref().clone();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| tenants::update_tenant(state, app_state.clone(), tenant_id.clone(), req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,85 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::vault_tokens as vault_token_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+#[cfg(feature = "olap")]
+use crate::services::authorization::permissions::Permission;
+use crate::{
+    core::{api_locking, vault_tokens},
+    services::{api, authentication as auth},
+};
+
+#[cfg(feature = "oltp")]
+#[instrument(skip_all, fields(flow = ?Flow::VaultTokenExpire))]
+pub async fn expire_vault_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<vault_token_types::VaultTokenExpireRequest>,
+) -> HttpResponse {
+    let flow = Flow::VaultTokenExpire;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            vault_tokens::expire_vault_token(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::VaultCleanup))]
+pub async fn schedule_vault_cleanup(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<vault_token_types::VaultCleanupRequest>,
+) -> HttpResponse {
+    let flow = Flow::VaultCleanup;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            vault_tokens::schedule_vault_cleanup(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::VaultAuditEventsList))]
+pub async fn list_vault_audit_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<vault_token_types::VaultAuditQuery>,
+) -> HttpResponse {
+    let flow = Flow::VaultAuditEventsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            vault_tokens::list_vault_audit_events(state, auth.merchant_account, query)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -6,8 +6,9 @@ use crate::{
     routes::AppState,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        EventListConstraints, EventListRequestInternal, IncomingWebhookListConstraints,
+        IncomingWebhookListRequestInternal, IncomingWebhookRetrieveRequestInternal,
+        WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryRequestInternal,
     },
 };
 
@@ -129,3 +130,83 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventIncomingList))]
+pub async fn list_incoming_webhooks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<IncomingWebhookListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventIncomingList;
+    let merchant_id_or_profile_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    let request_internal = IncomingWebhookListRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::list_incoming_webhooks(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventIncomingRetrieve))]
+pub async fn retrieve_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventIncomingRetrieve;
+    let (merchant_id_or_profile_id, archive_id) = path.into_inner();
+
+    let request_internal = IncomingWebhookRetrieveRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        archive_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_incoming_webhook(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.archive_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -9,6 +9,7 @@ pub mod encryption;
 #[cfg(feature = "olap")]
 pub mod jwt;
 pub mod kafka;
+pub mod key_management;
 pub mod logger;
 pub mod pm_auth;
 #[cfg(feature = "recon")]
@@ -24,7 +25,6 @@ use hyperswitch_domain_models::errors::StorageResult;
 pub use hyperswitch_interfaces::connector_integration_v2::{
     BoxedConnectorIntegrationV2, ConnectorIntegrationAnyV2, ConnectorIntegrationV2,
 };
-use masking::{ExposeInterface, StrongSecret};
 #[cfg(feature = "kv_store")]
 use storage_impl::KVRouterStore;
 use storage_impl::{config::TenantConfig, redis::RedisStore, RouterStore};
@@ -43,13 +43,10 @@ pub type Store = RouterStore<StoreType>;
 #[cfg(feature = "kv_store")]
 pub type Store = KVRouterStore<StoreType>;
 
-/// # Panics
-///
-/// Will panic if hex decode of master key fails
-#[allow(clippy::expect_used)]
 pub async fn get_store(
     config: &Settings,
     tenant: &dyn TenantConfig,
+    master_keys: key_management::MasterKeys,
     cache_store: Arc<RedisStore>,
     test_transaction: bool,
 ) -> StorageResult<Store> {
@@ -58,17 +55,18 @@ pub async fn get_store(
     #[cfg(feature = "olap")]
     let replica_config = config.replica_database.clone().into_inner();
 
-    #[allow(clippy::expect_used)]
-    let master_enc_key = hex::decode(config.secrets.get_inner().master_enc_key.clone().expose())
-        .map(StrongSecret::new)
-        .expect("Failed to decode master key from hex");
+    let master_enc_key = master_keys.current;
 
     #[cfg(not(feature = "olap"))]
     let conf = master_config.into();
     #[cfg(feature = "olap")]
     // this would get abstracted, for all cases
     #[allow(clippy::useless_conversion)]
-    let conf = (master_config.into(), replica_config.into());
+    let conf = (
+        master_config.into(),
+        replica_config.into(),
+        config.replica_routing.clone(),
+    );
 
     let store: RouterStore<StoreType> = if test_transaction {
         RouterStore::test_store(conf, tenant, &config.redis, master_enc_key).await?
@@ -82,6 +80,7 @@ pub async fn get_store(
         )
         .await?
     };
+    let store = store.with_previous_master_key(master_keys.previous);
 
     #[cfg(feature = "kv_store")]
     let store = KVRouterStore::from_store(
@@ -101,6 +100,8 @@ pub async fn get_cache_store(
     shut_down_signal: oneshot::Sender<()>,
     _test_transaction: bool,
 ) -> StorageResult<Arc<RedisStore>> {
+    storage_impl::redis::region::init(&config.multi_region).await?;
+    storage_impl::redis::cache::init_limits(&config.in_memory_cache);
     RouterStore::<StoreType>::cache_store(&config.redis, shut_down_signal).await
 }
 
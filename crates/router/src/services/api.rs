@@ -2,7 +2,7 @@ pub mod client;
 pub mod generic_link_response;
 pub mod request;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     error::Error,
     fmt::{Debug, Display},
     future::Future,
@@ -16,12 +16,16 @@ use actix_web::{
     body, http::header::HeaderValue, web, FromRequest, HttpRequest, HttpResponse, Responder,
     ResponseError,
 };
-use api_models::enums::{CaptureMethod, PaymentMethodType};
+use api_models::{
+    alerts::AlertMetric,
+    enums::{CaptureMethod, PaymentMethodType},
+};
 pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use common_utils::{
     consts::{DEFAULT_TENANT, TENANT_HEADER, X_HS_LATENCY},
     errors::{ErrorSwitch, ReportSwitchExt},
+    ext_traits::StringExt,
     request::RequestContent,
 };
 use error_stack::{report, Report, ResultExt};
@@ -43,20 +47,21 @@ use tera::{Context, Tera};
 
 use self::request::{HeaderExt, RequestBuilderExt};
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{ApiKeyRequestContext, AuthenticateAndFetch},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        alerts, api_locking, api_versioning,
+        connector_response_cache::ConnectorResponseCache,
         errors::{self, CustomResult},
-        payments,
+        load_shedding, logging, payments, rate_limits, response_field_access, tenants,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
-        connector_api_logs::ConnectorEvent,
+        connector_api_logs::{ConnectorErrorClass, ConnectorEvent},
     },
     logger,
     routes::{
@@ -150,12 +155,21 @@ pub trait ConnectorValidation: ConnectorCommon {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
+
+    /// Whether the connector can release part of an authorization while keeping the rest of it
+    /// capturable
+    fn validate_partial_authorization_reversal(&self) -> CustomResult<(), errors::ConnectorError> {
+        Err(errors::ConnectorError::NotSupported {
+            message: "Partial authorization reversal".to_string(),
+            connector: self.id(),
+        }
+        .into())
+    }
 }
 
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
-#[instrument(skip_all, fields(connector_name, payment_method))]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -170,6 +184,39 @@ pub async fn execute_connector_processing_step<
     call_connector_action: payments::CallConnectorAction,
     connector_request: Option<Request>,
 ) -> CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>
+where
+    T: Clone + Debug + 'static,
+{
+    execute_connector_processing_step_with_response_cache(
+        state,
+        connector_integration,
+        req,
+        call_connector_action,
+        connector_request,
+        None,
+    )
+    .await
+}
+
+/// Handles the flow like [`execute_connector_processing_step`], the connector being called only
+/// when the response cache holds no response when the `CallConnectorAction` is `Trigger`. The
+/// successful response of the connector is cached for the next calls.
+#[instrument(skip_all, fields(connector_name, payment_method))]
+pub async fn execute_connector_processing_step_with_response_cache<
+    'b,
+    'a,
+    T,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    state: &'b SessionState,
+    connector_integration: BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &'b types::RouterData<T, Req, Resp>,
+    call_connector_action: payments::CallConnectorAction,
+    connector_request: Option<Request>,
+    response_cache: Option<&ConnectorResponseCache>,
+) -> CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>
 where
     T: Clone + Debug + 'static,
     // BoxedConnectorIntegration<T, Req, Resp>: 'b,
@@ -212,6 +259,22 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
+            let cached_response = match response_cache {
+                Some(response_cache) => response_cache.get(state).await,
+                None => None,
+            };
+            if let Some(response) = cached_response {
+                metrics::CONNECTOR_RESPONSE_CACHE_HIT_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("connector", req.connector.to_string())]),
+                );
+                let connector_http_status_code = Some(response.status_code);
+                let mut data = connector_integration.handle_response(req, None, response)?;
+                data.connector_http_status_code = connector_http_status_code;
+                return Ok(data);
+            }
+
             metrics::CONNECTOR_CALL_COUNT.add(
                 &metrics::CONTEXT,
                 1,
@@ -265,9 +328,22 @@ where
                     let request_url = request.url.clone();
                     let request_method = request.method;
                     let current_time = Instant::now();
-                    let response =
-                        call_connector_api(state, request, "execute_connector_processing_step")
-                            .await;
+                    let client_policy = get_connector_http_client_policy(
+                        state,
+                        &req.connector,
+                        std::any::type_name::<T>()
+                            .split("::")
+                            .last()
+                            .unwrap_or_default(),
+                    )
+                    .await;
+                    let response = call_connector_api_with_client_policy(
+                        state,
+                        request,
+                        "execute_connector_processing_step",
+                        client_policy.as_ref(),
+                    )
+                    .await;
                     let external_latency = current_time.elapsed().as_millis();
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
@@ -277,6 +353,13 @@ where
                                 .map_or_else(|value| value.status_code, |value| value.status_code)
                         })
                         .unwrap_or_default();
+                    alerts::record_alert_signal(
+                        state,
+                        &req.merchant_id,
+                        AlertMetric::Connector5xxRate,
+                        Some(&req.connector),
+                        response.is_err() || status_code >= 500,
+                    );
                     let mut connector_event = ConnectorEvent::new(
                         req.connector.clone(),
                         std::any::type_name::<T>(),
@@ -297,6 +380,9 @@ where
                             let response = match body {
                                 Ok(body) => {
                                     let connector_http_status_code = Some(body.status_code);
+                                    if let Some(response_cache) = response_cache {
+                                        response_cache.store(state, &body);
+                                    }
                                     let handle_response_result = connector_integration
                                         .handle_response(req, Some(&mut connector_event), body)
                                         .map_err(|error| {
@@ -316,7 +402,7 @@ where
                                         });
                                     match handle_response_result {
                                         Ok(mut data) => {
-                                            state.event_handler().log_event(&connector_event);
+                                            log_connector_event(state, req, &connector_event);
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
@@ -332,7 +418,7 @@ where
                                             connector_event
                                                 .set_error(json!({"error": err.to_string()}));
 
-                                            state.event_handler().log_event(&connector_event);
+                                            log_connector_event(state, req, &connector_event);
                                             Err(err)
                                         }
                                     }?
@@ -357,7 +443,7 @@ where
                                                     body,
                                                     Some(&mut connector_event),
                                                 )?;
-                                            state.event_handler().log_event(&connector_event);
+                                            log_connector_event(state, req, &connector_event);
                                             error_res
                                         }
                                         _ => {
@@ -369,7 +455,7 @@ where
                                             if let Some(status) = error_res.attempt_status {
                                                 router_data.status = status;
                                             };
-                                            state.event_handler().log_event(&connector_event);
+                                            log_connector_event(state, req, &connector_event);
                                             error_res
                                         }
                                     };
@@ -383,7 +469,14 @@ where
                         }
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
-                            state.event_handler().log_event(&connector_event);
+                            connector_event.set_error_class(
+                                if error.current_context().is_upstream_timeout() {
+                                    ConnectorErrorClass::Timeout
+                                } else {
+                                    ConnectorErrorClass::NetworkError
+                                },
+                            );
+                            log_connector_event(state, req, &connector_event);
                             if error.current_context().is_upstream_timeout() {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
@@ -415,31 +508,105 @@ where
     }
 }
 
-#[instrument(skip_all)]
+/// Logs the event of a call to the connector, storing the exchange with the connector as well while
+/// the connector debug capture is enabled
+fn log_connector_event<T, Req, Resp>(
+    state: &SessionState,
+    req: &types::RouterData<T, Req, Resp>,
+    connector_event: &ConnectorEvent,
+) {
+    state.event_handler().log_event(connector_event);
+    if let Some(connector_debug_capture) = &state.connector_debug_capture {
+        connector_debug_capture.record(state, &req.attempt_id, connector_event);
+    }
+}
+
+/// The key of the config holding the HTTP client policy of the connector, or of one of its flows
+pub fn get_connector_http_client_policy_key(connector: &str, flow: Option<&str>) -> String {
+    match flow {
+        Some(flow) => format!(
+            "{}_{connector}_{flow}",
+            consts::CONNECTOR_HTTP_CLIENT_POLICY_CONFIG_PREFIX
+        ),
+        None => format!(
+            "{}_{connector}",
+            consts::CONNECTOR_HTTP_CLIENT_POLICY_CONFIG_PREFIX
+        ),
+    }
+}
+
+async fn find_http_client_policy(
+    state: &SessionState,
+    key: &str,
+) -> Option<client::HttpClientPolicy> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(key, Some("{}".to_string()))
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch the HTTP client policy config"))
+        .ok()?
+        .config
+        .parse_struct("HttpClientPolicy")
+        .map_err(|error| logger::error!(?error, %key, "Failed to parse the HTTP client policy"))
+        .ok()
+}
+
+/// The HTTP client policy of the flow of the connector, with the settings not set for the flow
+/// taken from the policy of the connector. Failures to fetch the policies are logged, the request
+/// then being sent with the global client.
+pub async fn get_connector_http_client_policy(
+    state: &SessionState,
+    connector: &str,
+    flow: &str,
+) -> Option<client::HttpClientPolicy> {
+    let flow_policy = find_http_client_policy(
+        state,
+        &get_connector_http_client_policy_key(connector, Some(flow)),
+    )
+    .await
+    .unwrap_or_default();
+    let connector_policy = find_http_client_policy(
+        state,
+        &get_connector_http_client_policy_key(connector, None),
+    )
+    .await
+    .unwrap_or_default();
+
+    Some(flow_policy.or(connector_policy))
+        .filter(|client_policy| client_policy != &client::HttpClientPolicy::default())
+}
+
+/// Calls the connector API with the global HTTP client, like
+/// [`call_connector_api_with_client_policy`] without a policy.
 pub async fn call_connector_api(
     state: &SessionState,
     request: Request,
     flow_name: &str,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
-    let current_time = Instant::now();
-    let headers = request.headers.clone();
-    let url = request.url.clone();
+    call_connector_api_with_client_policy(state, request, flow_name, None).await
+}
+
+/// Calls the connector API within a span recording the flow and the status code of the response,
+/// the latency of the call being the duration of the span. The request is sent with the HTTP
+/// client policy of the connector if any.
+#[instrument(
+    skip_all,
+    fields(flow = %flow_name, http.status_code = tracing::field::Empty)
+)]
+pub async fn call_connector_api_with_client_policy(
+    state: &SessionState,
+    request: Request,
+    flow_name: &str,
+    client_policy: Option<&client::HttpClientPolicy>,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(state, request, None, client_policy, true)
         .await;
 
     match response.as_ref() {
         Ok(resp) => {
-            let status_code = resp.status().as_u16();
-            let elapsed_time = current_time.elapsed();
-            logger::info!(
-                headers=?headers,
-                url=?url,
-                status_code=?status_code,
-                flow=?flow_name,
-                elapsed_time=?elapsed_time
-            );
+            tracing::Span::current().record("http.status_code", resp.status().as_u16());
         }
         Err(err) => {
             logger::info!(
@@ -451,16 +618,29 @@ pub async fn call_connector_api(
     handle_response(response).await
 }
 
-#[instrument(skip_all)]
+#[instrument(
+    skip_all,
+    fields(otel.kind = "client", http.method = %request.method, http.host)
+)]
 pub async fn send_request(
     state: &SessionState,
-    request: Request,
+    mut request: Request,
     option_timeout_secs: Option<u64>,
+    client_policy: Option<&client::HttpClientPolicy>,
 ) -> CustomResult<reqwest::Response, errors::ApiClientError> {
     logger::info!(method=?request.method, headers=?request.headers, payload=?request.body, ?request);
 
     let url = reqwest::Url::parse(&request.url)
         .change_context(errors::ApiClientError::UrlEncodingFailed)?;
+    tracing::Span::current().record("http.host", url.host_str().unwrap_or_default());
+
+    // Propagate the trace context of the request, for the spans of the called service to be
+    // attached to the trace of the payment
+    request.headers.extend(
+        router_env::get_trace_context_headers()
+            .into_iter()
+            .map(|(header_name, header_value)| (header_name, header_value.into())),
+    );
 
     #[cfg(feature = "dummy_connector")]
     let should_bypass_proxy = url
@@ -474,6 +654,7 @@ pub async fn send_request(
         should_bypass_proxy,
         request.certificate,
         request.certificate_key,
+        client_policy,
     )?;
 
     let headers = request.headers.construct_header_map()?;
@@ -533,7 +714,9 @@ pub async fn send_request(
         }
         .add_headers(headers)
         .timeout(Duration::from_secs(
-            option_timeout_secs.unwrap_or(consts::REQUEST_TIME_OUT),
+            option_timeout_secs
+                .or(client_policy.and_then(|policy| policy.request_timeout_in_secs))
+                .unwrap_or(consts::REQUEST_TIME_OUT),
         ))
     };
 
@@ -542,17 +725,7 @@ pub async fn send_request(
         cloned_request
             .send()
             .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
-            })
+            .map_err(get_send_request_error)
             .attach_printable("Unable to send request to connector")
     });
 
@@ -560,17 +733,7 @@ pub async fn send_request(
         request
             .send()
             .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
-            })
+            .map_err(get_send_request_error)
             .attach_printable("Unable to send request to connector")
     };
 
@@ -616,10 +779,51 @@ pub async fn send_request(
                 }
             }
         }
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiClientError::ConnectionFailed(_)
+            ) && client_policy
+                .is_some_and(client::HttpClientPolicy::should_retry_on_connect_failure) =>
+        {
+            match cloned_send_request {
+                Some(cloned_request) => {
+                    logger::info!("Retrying request due to failure to connect");
+                    common_utils::metrics::utils::record_operation_time(
+                        cloned_request,
+                        &metrics::EXTERNAL_REQUEST_TIME,
+                        &metrics::CONTEXT,
+                        &[metrics_tag],
+                    )
+                    .await
+                }
+                None => {
+                    logger::info!(
+                        "Retrying request due to failure to connect failed as request is not clonable"
+                    );
+                    Err(error)
+                }
+            }
+        }
         err @ Err(_) => err,
     }
 }
 
+fn get_send_request_error(error: reqwest::Error) -> errors::ApiClientError {
+    match error {
+        error if error.is_timeout() => {
+            metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+            errors::ApiClientError::RequestTimeoutReceived
+        }
+        error if is_connection_closed_before_message_could_complete(&error) => {
+            metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+            errors::ApiClientError::ConnectionClosedIncompleteMessage
+        }
+        error if error.is_connect() => errors::ApiClientError::ConnectionFailed(error.to_string()),
+        _ => errors::ApiClientError::RequestNotSent(error.to_string()),
+    }
+}
+
 fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) -> bool {
     let mut source = error.source();
     while let Some(err) = source {
@@ -855,6 +1059,14 @@ where
         .record_info(("flow".to_string(), flow.to_string()));
     // request_state.event_context.record_info(request.clone());
 
+    // Held until the request is processed, the requests being shed before being authenticated
+    let _admission_permit = load_shedding::admit_request(
+        &state.conf.load_shedding,
+        load_shedding::get_priority(request.method(), request.path()),
+    )
+    .await
+    .switch()?;
+
     let mut app_state = state.get_ref().clone();
 
     let start_instant = Instant::now();
@@ -863,12 +1075,6 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError.switch())?;
 
     let mut event_type = payload.get_api_event_type();
-    let tenants: HashSet<_> = state
-        .conf
-        .multitenancy
-        .get_tenant_names()
-        .into_iter()
-        .collect();
     let tenant_id = if !state.conf.multitenancy.enabled {
         DEFAULT_TENANT.to_string()
     } else {
@@ -877,7 +1083,7 @@ where
             .and_then(|value| value.to_str().ok())
             .ok_or_else(|| errors::ApiErrorResponse::MissingTenantId.switch())
             .map(|req_tenant_id| {
-                if !tenants.contains(req_tenant_id) {
+                if !state.is_tenant_active(req_tenant_id) {
                     Err(errors::ApiErrorResponse::InvalidTenant {
                         tenant_id: req_tenant_id.to_string(),
                     }
@@ -899,6 +1105,7 @@ where
             .switch()
         })?;
     session_state.add_request_id(request_id);
+    session_state.api_key_request_context = Some(ApiKeyRequestContext::from_request(request));
 
     // Currently auth failures are not recorded as API events
     let (auth_out, auth_type) = api_auth
@@ -907,6 +1114,7 @@ where
         .switch()?;
 
     request_state.event_context.record_info(auth_type.clone());
+    session_state.auth_type = Some(auth_type.clone());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -919,6 +1127,30 @@ where
 
     tracing::Span::current().record("merchant_id", &merchant_id);
 
+    tenants::enforce_tenant_request_quota(&session_state)
+        .await
+        .switch()?;
+
+    if auth_type.get_merchant_id().is_some() {
+        rate_limits::enforce_rate_limit(&session_state, &merchant_id, request)
+            .await
+            .switch()?;
+    }
+
+    if let Some(restrictions) =
+        response_field_access::get_response_field_restrictions(&session_state, &auth_type, request)
+            .await
+            .switch()?
+    {
+        request.extensions_mut().insert(restrictions);
+    }
+
+    let response_version =
+        api_versioning::get_response_version(&session_state, &auth_type, request)
+            .await
+            .switch()?;
+    request.extensions_mut().insert(response_version);
+
     let output = {
         lock_action
             .clone()
@@ -979,6 +1211,19 @@ where
         }
     };
 
+    if logging::should_sample_request(auth_type.get_merchant_id()) {
+        logger::info!(
+            target: router_env::SAMPLED_REQUESTS_TARGET,
+            %merchant_id,
+            flow = ?flow,
+            status_code,
+            request = %serialized_request,
+            response = ?serialized_response,
+            error = ?error,
+            "Sampled request"
+        );
+    }
+
     let api_event = ApiEvent::new(
         Some(merchant_id.clone()),
         flow,
@@ -1005,6 +1250,29 @@ where
     output
 }
 
+/// Serializes the JSON response in the schema of the version of the response, hiding the
+/// sensitive fields the caller is not allowed to see
+pub fn serialize_json_response<R: Serialize>(
+    response: &R,
+    request: &HttpRequest,
+) -> serde_json::Result<String> {
+    let extensions = request.extensions();
+    let restrictions = extensions.get::<response_field_access::ResponseFieldRestrictions>();
+    let response_version = extensions.get::<api_versioning::ResponseVersion>();
+    if restrictions.is_none() && response_version.is_none() {
+        return serde_json::to_string(response);
+    }
+
+    let mut response = serde_json::to_value(response)?;
+    if let Some(response_version) = response_version {
+        response_version.apply(&mut response);
+    }
+    if let Some(restrictions) = restrictions {
+        restrictions.apply(&mut response);
+    }
+    serde_json::to_string(&response)
+}
+
 #[instrument(
     skip(request, state, func, api_auth, payload),
     fields(request_method, request_url_path, status_code)
@@ -1076,17 +1344,19 @@ where
         response
     });
 
-    let res = match server_wrap_util_res {
-        Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
-            Ok(res) => http_response_json(res),
-            Err(_) => http_response_err(
-                r#"{
+    let mut res = match server_wrap_util_res {
+        Ok(ApplicationResponse::Json(response)) => {
+            match serialize_json_response(&response, request) {
+                Ok(res) => http_response_json(res),
+                Err(_) => http_response_err(
+                    r#"{
                     "error": {
                         "message": "Error serializing response from connector"
                     }
                 }"#,
-            ),
-        },
+                ),
+            }
+        }
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
@@ -1164,7 +1434,7 @@ where
                     None
                 }
             });
-            match serde_json::to_string(&response) {
+            match serialize_json_response(&response, request) {
                 Ok(res) => http_response_json_with_headers(res, headers, request_elapsed_time),
                 Err(_) => http_response_err(
                     r#"{
@@ -1178,6 +1448,13 @@ where
         Err(error) => log_and_return_error_response(error),
     };
 
+    if let Some(response_version) = request
+        .extensions()
+        .get::<api_versioning::ResponseVersion>()
+    {
+        response_version.add_headers(res.headers_mut());
+    }
+
     let response_code = res.status().as_u16();
     tracing::Span::current().record("status_code", response_code);
 
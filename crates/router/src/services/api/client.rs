@@ -1,9 +1,9 @@
-use std::time::Duration;
+use std::{collections::HashMap, sync::RwLock, time::Duration};
 
 use error_stack::ResultExt;
 use http::{HeaderValue, Method};
 use masking::PeekInterface;
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 use reqwest::multipart::Form;
 use router_env::tracing_actix_web::RequestId;
 
@@ -20,18 +20,101 @@ use crate::{
 
 static NON_PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+/// The clients built for the HTTP client policies, keyed by whether they bypass the proxy and by
+/// the client settings of the policy, so that the connectors sharing settings share a pool
+static POLICY_CLIENTS: Lazy<RwLock<HashMap<(bool, HttpClientPolicy), reqwest::Client>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The policy of the HTTP client the requests to a connector are sent with. It is configured per
+/// connector and per flow through the configs API, so that a slow connector can be isolated from
+/// the others. The settings not set in it are those of the global client.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpClientPolicy {
+    /// Timeout for establishing the connection to the connector
+    pub connect_timeout_in_secs: Option<u64>,
+    /// Timeout for the whole request, until the response has been read
+    pub request_timeout_in_secs: Option<u64>,
+    /// Whether the request is retried once when the connection to the connector could not be
+    /// established, the connector not having received the request then
+    pub retry_on_connect_failure: Option<bool>,
+    /// Maximum number of idle connections kept in the pool per host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Duration after which the idle connections of the pool are closed
+    pub pool_idle_timeout_in_secs: Option<u64>,
+    /// Interval of the TCP keepalive probes of the connections
+    pub tcp_keepalive_in_secs: Option<u64>,
+}
+
+impl HttpClientPolicy {
+    /// The policy with the settings not set in it taken from the fallback policy
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            connect_timeout_in_secs: self
+                .connect_timeout_in_secs
+                .or(fallback.connect_timeout_in_secs),
+            request_timeout_in_secs: self
+                .request_timeout_in_secs
+                .or(fallback.request_timeout_in_secs),
+            retry_on_connect_failure: self
+                .retry_on_connect_failure
+                .or(fallback.retry_on_connect_failure),
+            pool_max_idle_per_host: self
+                .pool_max_idle_per_host
+                .or(fallback.pool_max_idle_per_host),
+            pool_idle_timeout_in_secs: self
+                .pool_idle_timeout_in_secs
+                .or(fallback.pool_idle_timeout_in_secs),
+            tcp_keepalive_in_secs: self
+                .tcp_keepalive_in_secs
+                .or(fallback.tcp_keepalive_in_secs),
+        }
+    }
+
+    /// The settings of the policy applied when building the client, the others being applied to
+    /// each request
+    fn get_client_settings(&self) -> Self {
+        Self {
+            request_timeout_in_secs: None,
+            retry_on_connect_failure: None,
+            ..self.clone()
+        }
+    }
+
+    pub fn should_retry_on_connect_failure(&self) -> bool {
+        self.retry_on_connect_failure.unwrap_or(false)
+    }
+}
 
 fn get_client_builder(
     proxy_config: &Proxy,
     should_bypass_proxy: bool,
+    client_policy: Option<&HttpClientPolicy>,
 ) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
-    let mut client_builder = reqwest::Client::builder()
-        .redirect(reqwest::redirect::Policy::none())
-        .pool_idle_timeout(Duration::from_secs(
+    let pool_idle_timeout_in_secs = client_policy
+        .and_then(|policy| policy.pool_idle_timeout_in_secs)
+        .unwrap_or(
             proxy_config
                 .idle_pool_connection_timeout
                 .unwrap_or_default(),
-        ));
+        );
+    let mut client_builder = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .pool_idle_timeout(Duration::from_secs(pool_idle_timeout_in_secs));
+
+    if let Some(policy) = client_policy {
+        if let Some(connect_timeout_in_secs) = policy.connect_timeout_in_secs {
+            client_builder =
+                client_builder.connect_timeout(Duration::from_secs(connect_timeout_in_secs));
+        }
+        if let Some(pool_max_idle_per_host) = policy.pool_max_idle_per_host {
+            client_builder = client_builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        if let Some(tcp_keepalive_in_secs) = policy.tcp_keepalive_in_secs {
+            client_builder =
+                client_builder.tcp_keepalive(Duration::from_secs(tcp_keepalive_in_secs));
+        }
+    }
 
     if should_bypass_proxy {
         return Ok(client_builder);
@@ -58,10 +141,48 @@ fn get_client_builder(
     Ok(client_builder)
 }
 
+/// The client built for the client settings of the policy, built on the first request made with
+/// them and reused for the following ones
+fn get_policy_client(
+    proxy_config: &Proxy,
+    should_bypass_proxy: bool,
+    client_settings: HttpClientPolicy,
+) -> CustomResult<reqwest::Client, ApiClientError> {
+    let cache_key = (should_bypass_proxy, client_settings);
+    if let Some(client) = POLICY_CLIENTS
+        .read()
+        .map_err(|_| ApiClientError::UnexpectedState)
+        .attach_printable("The lock on the clients of the HTTP client policies is poisoned")?
+        .get(&cache_key)
+    {
+        return Ok(client.clone());
+    }
+
+    let client = get_client_builder(proxy_config, should_bypass_proxy, Some(&cache_key.1))?
+        .build()
+        .change_context(ApiClientError::ClientConstructionFailed)
+        .attach_printable("Failed to construct client for the HTTP client policy")?;
+    Ok(POLICY_CLIENTS
+        .write()
+        .map_err(|_| ApiClientError::UnexpectedState)
+        .attach_printable("The lock on the clients of the HTTP client policies is poisoned")?
+        .entry(cache_key)
+        .or_insert(client)
+        .clone())
+}
+
 fn get_base_client(
     proxy_config: &Proxy,
     should_bypass_proxy: bool,
+    client_policy: Option<&HttpClientPolicy>,
 ) -> CustomResult<reqwest::Client, ApiClientError> {
+    if let Some(client_settings) = client_policy
+        .map(HttpClientPolicy::get_client_settings)
+        .filter(|client_settings| client_settings != &HttpClientPolicy::default())
+    {
+        return get_policy_client(proxy_config, should_bypass_proxy, client_settings);
+    }
+
     Ok(if should_bypass_proxy
         || (proxy_config.http_url.is_none() && proxy_config.https_url.is_none())
     {
@@ -70,7 +191,7 @@ fn get_base_client(
         &PROXIED_CLIENT
     }
     .get_or_try_init(|| {
-        get_client_builder(proxy_config, should_bypass_proxy)?
+        get_client_builder(proxy_config, should_bypass_proxy, None)?
             .build()
             .change_context(ApiClientError::ClientConstructionFailed)
             .attach_printable("Failed to construct base client")
@@ -85,10 +206,12 @@ pub fn create_client(
     should_bypass_proxy: bool,
     client_certificate: Option<masking::Secret<String>>,
     client_certificate_key: Option<masking::Secret<String>>,
+    client_policy: Option<&HttpClientPolicy>,
 ) -> CustomResult<reqwest::Client, ApiClientError> {
     match (client_certificate, client_certificate_key) {
         (Some(encoded_certificate), Some(encoded_certificate_key)) => {
-            let client_builder = get_client_builder(proxy_config, should_bypass_proxy)?;
+            let client_builder =
+                get_client_builder(proxy_config, should_bypass_proxy, client_policy)?;
 
             let identity = payments::helpers::create_identity_from_certificate_and_key(
                 encoded_certificate.clone(),
@@ -106,7 +229,7 @@ pub fn create_client(
                 .change_context(ApiClientError::ClientConstructionFailed)
                 .attach_printable("Failed to construct client with certificate and certificate key")
         }
-        _ => get_base_client(proxy_config, should_bypass_proxy),
+        _ => get_base_client(proxy_config, should_bypass_proxy, client_policy),
     }
 }
 
@@ -168,6 +291,7 @@ where
         state: &SessionState,
         request: Request,
         option_timeout_secs: Option<u64>,
+        client_policy: Option<&HttpClientPolicy>,
         forward_to_kafka: bool,
     ) -> CustomResult<reqwest::Response, ApiClientError>;
 
@@ -343,9 +467,10 @@ impl ApiClient for ProxyClient {
         state: &SessionState,
         request: Request,
         option_timeout_secs: Option<u64>,
+        client_policy: Option<&HttpClientPolicy>,
         _forward_to_kafka: bool,
     ) -> CustomResult<reqwest::Response, ApiClientError> {
-        crate::services::send_request(state, request, option_timeout_secs).await
+        crate::services::send_request(state, request, option_timeout_secs, client_policy).await
     }
 
     fn add_request_id(&mut self, request_id: RequestId) {
@@ -395,6 +520,7 @@ impl ApiClient for MockApiClient {
         _state: &SessionState,
         _request: Request,
         _option_timeout_secs: Option<u64>,
+        _client_policy: Option<&HttpClientPolicy>,
         _forward_to_kafka: bool,
     ) -> CustomResult<reqwest::Response, ApiClientError> {
         // [#2066]: Add Mock implementation for ApiClient
@@ -2,6 +2,7 @@ use actix_web::http::header::HeaderMap;
 #[cfg(feature = "payouts")]
 use api_models::payouts;
 use api_models::{
+    api_keys::ApiKeyScope,
     payment_methods::{PaymentMethodCreate, PaymentMethodListRequest},
     payments,
 };
@@ -23,19 +24,19 @@ use super::recon::ReconToken;
 #[cfg(feature = "olap")]
 use crate::configs::Settings;
 #[cfg(feature = "olap")]
-use crate::consts;
-#[cfg(feature = "olap")]
 use crate::core::errors::UserResult;
 #[cfg(feature = "recon")]
 use crate::routes::SessionState;
 use crate::{
+    consts,
     core::{
         api_keys,
         errors::{self, utils::StorageErrorExt, RouterResult},
     },
+    headers,
     routes::app::SessionStateInfo,
     services::api,
-    types::domain,
+    types::{domain, storage, transformers},
     utils::OptionExt,
 };
 pub mod blacklist;
@@ -58,6 +59,10 @@ pub enum AuthenticationType {
         merchant_id: String,
         key_id: String,
     },
+    OAuth2 {
+        merchant_id: String,
+        client_id: String,
+    },
     AdminApiKey,
     MerchantJwt {
         merchant_id: String,
@@ -105,6 +110,10 @@ impl AuthenticationType {
                 merchant_id,
                 key_id: _,
             }
+            | Self::OAuth2 {
+                merchant_id,
+                client_id: _,
+            }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
             | Self::MerchantJwt {
@@ -169,6 +178,10 @@ pub struct AuthToken {
     pub role_id: String,
     pub exp: u64,
     pub org_id: String,
+    /// The business profile the user is restricted to, the user having access to all the
+    /// profiles of the merchant when not set
+    #[serde(default)]
+    pub profile_id: Option<String>,
 }
 
 #[cfg(feature = "olap")]
@@ -179,6 +192,7 @@ impl AuthToken {
         role_id: String,
         settings: &Settings,
         org_id: String,
+        profile_id: Option<String>,
     ) -> UserResult<String> {
         let exp_duration = std::time::Duration::from_secs(consts::JWT_TOKEN_TIME_IN_SECS);
         let exp = jwt::generate_exp(exp_duration)?.as_secs();
@@ -188,6 +202,7 @@ impl AuthToken {
             role_id,
             exp,
             org_id,
+            profile_id,
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
@@ -285,38 +300,17 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("API key is empty");
-        }
-
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
+        let oauth2_access_token = get_oauth2_access_token(request_headers);
+        let stored_api_key = match oauth2_access_token {
+            Some(access_token) => authenticate_oauth2_access_token(access_token, state).await?,
+            None => {
+                let api_key = get_api_key(request_headers)
+                    .change_context(errors::ApiErrorResponse::Unauthorized)?;
+                authenticate_api_key(api_key, state).await?
+            }
         };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
-        }
+        verify_api_key_restrictions(&stored_api_key, state.get_api_key_request_context())?;
 
         let key_store = state
             .store()
@@ -334,18 +328,426 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
-        let auth = AuthenticationData {
-            merchant_account: merchant,
-            key_store,
-        };
-        Ok((
-            auth.clone(),
-            AuthenticationType::ApiKey {
+        verify_request_signature(request_headers, state, &merchant).await?;
+
+        let auth = get_connected_account_auth(
+            request_headers,
+            state,
+            AuthenticationData {
+                merchant_account: merchant,
+                key_store,
+            },
+        )
+        .await?;
+        let authentication_type = match oauth2_access_token {
+            Some(_) => AuthenticationType::OAuth2 {
+                merchant_id: auth.merchant_account.merchant_id.clone(),
+                client_id: stored_api_key.key_id,
+            },
+            None => AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.merchant_id.clone(),
                 key_id: stored_api_key.key_id,
             },
-        ))
+        };
+        Ok((auth, authentication_type))
+    }
+}
+
+/// The connected account the platform acts on behalf of when the request names one, the platform
+/// otherwise. Only the connected accounts onboarded by the platform can be acted on behalf of.
+async fn get_connected_account_auth<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    platform: AuthenticationData,
+) -> RouterResult<AuthenticationData>
+where
+    A: SessionStateInfo + Sync,
+{
+    let Some(connected_account_id) =
+        get_header_value_by_key(headers::X_CONNECTED_ACCOUNT_ID.into(), request_headers)?
+    else {
+        return Ok(platform);
+    };
+
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            connected_account_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the connected account")?;
+    let merchant_account = state
+        .store()
+        .find_merchant_account_by_merchant_id(connected_account_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    if merchant_account.parent_merchant_id.as_ref() != Some(&platform.merchant_account.merchant_id)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Merchant is not a connected account of the platform");
+    }
+
+    logger::info!(
+        platform_merchant_id = %platform.merchant_account.merchant_id,
+        "Acting on behalf of the connected account"
+    );
+    Ok(AuthenticationData {
+        merchant_account,
+        key_store,
+    })
+}
+
+/// Finds the API key, failing when it does not exist or has expired
+pub async fn authenticate_api_key<A>(api_key: &str, state: &A) -> RouterResult<storage::ApiKey>
+where
+    A: SessionStateInfo + Sync,
+{
+    let api_key = api_key.trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized).attach_printable("API key is empty");
+    }
+
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        config.api_keys.get_inner().get_hash_key()?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
+
+    Ok(stored_api_key)
+}
+
+/// Claims of the OAuth2 access tokens issued with the client credentials grant
+#[derive(Clone, Debug, Serialize, serde::Deserialize)]
+pub struct OAuth2AccessTokenClaims {
+    pub jti: String,
+    pub merchant_id: String,
+    /// The identifier of the API key the access token was issued to
+    pub client_id: String,
+    /// The scopes the access token is restricted to, none when it has all the scopes of the API key
+    pub scopes: Option<Vec<ApiKeyScope>>,
+    pub exp: u64,
+}
+
+pub fn get_oauth2_revoked_token_key(jti: &str) -> String {
+    format!("{}_{jti}", consts::OAUTH2_REVOKED_TOKEN_REDIS_KEY_PREFIX)
+}
+
+/// The OAuth2 access token sent as a bearer token, told apart from the JWTs of the dashboard users
+/// by the key identifier in its header
+pub fn get_oauth2_access_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(headers::AUTHORIZATION)
+        .and_then(|header_value| header_value.to_str().ok())
+        .and_then(|header_value| header_value.strip_prefix("Bearer "))
+        .filter(|token| {
+            jsonwebtoken::decode_header(token)
+                .map(|header| header.kid.as_deref() == Some(consts::OAUTH2_ACCESS_TOKEN_KID))
+                .unwrap_or(false)
+        })
+}
+
+/// Decodes the OAuth2 access token, failing when it has expired or has been revoked
+pub async fn decode_oauth2_access_token<A>(
+    access_token: &str,
+    state: &A,
+) -> RouterResult<OAuth2AccessTokenClaims>
+where
+    A: SessionStateInfo + Sync,
+{
+    let claims = decode_jwt::<OAuth2AccessTokenClaims>(access_token, state)
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Invalid OAuth2 access token")?;
+
+    let is_revoked = state
+        .store()
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .exists::<()>(&get_oauth2_revoked_token_key(&claims.jti))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check if the OAuth2 access token was revoked")?;
+    if is_revoked {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("OAuth2 access token has been revoked");
+    }
+
+    Ok(claims)
+}
+
+/// Authenticates the OAuth2 access token, returning the API key it was issued to as long as the
+/// API key is still valid
+async fn authenticate_oauth2_access_token<A>(
+    access_token: &str,
+    state: &A,
+) -> RouterResult<storage::ApiKey>
+where
+    A: SessionStateInfo + Sync,
+{
+    let claims = decode_oauth2_access_token(access_token, state).await?;
+
+    if let Some(scopes) = &claims.scopes {
+        let context = state
+            .get_api_key_request_context()
+            .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Request details are needed to verify the access token scopes")?;
+        verify_api_key_scopes(scopes, context)?;
+    }
+
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_merchant_id_key_id_optional(&claims.merchant_id, &claims.client_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("API key of the OAuth2 access token has been revoked")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key of the OAuth2 access token has expired");
     }
+
+    Ok(stored_api_key)
+}
+
+/// Details of the incoming request the restrictions of the API keys are enforced against
+#[derive(Clone, Debug)]
+pub struct ApiKeyRequestContext {
+    pub client_ip: Option<std::net::IpAddr>,
+    pub path: String,
+    /// The scope needed to call the API, none when the API cannot be called with API keys
+    /// restricted to scopes
+    pub required_scope: Option<ApiKeyScope>,
+}
+
+impl ApiKeyRequestContext {
+    pub fn from_request(request: &actix_web::HttpRequest) -> Self {
+        let client_ip = request
+            .connection_info()
+            .realip_remote_addr()
+            .and_then(|address| {
+                address.parse::<std::net::IpAddr>().ok().or_else(|| {
+                    address
+                        .parse::<std::net::SocketAddr>()
+                        .ok()
+                        .map(|address| address.ip())
+                })
+            });
+        let is_read = matches!(
+            *request.method(),
+            actix_web::http::Method::GET | actix_web::http::Method::HEAD
+        );
+        let required_scope = match request.path().trim_start_matches('/').split('/').next() {
+            Some("payments") if is_read => Some(ApiKeyScope::PaymentsRead),
+            Some("payments") => Some(ApiKeyScope::PaymentsWrite),
+            Some("refunds") if is_read => Some(ApiKeyScope::RefundsRead),
+            Some("refunds") => Some(ApiKeyScope::RefundsWrite),
+            Some("routing") => Some(ApiKeyScope::RoutingAdmin),
+            _ => None,
+        };
+
+        Self {
+            client_ip,
+            path: request.path().to_owned(),
+            required_scope,
+        }
+    }
+}
+
+/// Verifies that the request is allowed by the scopes and the IP allowlist of the API key
+fn verify_api_key_restrictions(
+    api_key: &storage::ApiKey,
+    context: Option<ApiKeyRequestContext>,
+) -> RouterResult<()> {
+    if api_key.scopes.is_none() && api_key.allowed_ips.is_none() {
+        return Ok(());
+    }
+    let context = context
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Request details are needed to verify the API key restrictions")?;
+
+    verify_api_key_allowed_ips(api_key, Some(&context))?;
+
+    if let Some(scopes) = &api_key.scopes {
+        verify_api_key_scopes(&transformers::parse_api_key_scopes(scopes), context)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies that the request comes from one of the IP addresses the API key is restricted to
+pub fn verify_api_key_allowed_ips(
+    api_key: &storage::ApiKey,
+    context: Option<&ApiKeyRequestContext>,
+) -> RouterResult<()> {
+    let Some(allowed_ips) = &api_key.allowed_ips else {
+        return Ok(());
+    };
+
+    let client_ip = context.and_then(|context| context.client_ip);
+    let is_ip_allowed = client_ip.map_or(false, |client_ip| {
+        allowed_ips
+            .iter()
+            .any(|allowed_ip| allowed_ip.parse::<std::net::IpAddr>().ok() == Some(client_ip))
+    });
+    if !is_ip_allowed {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable(format!("API key cannot be used from {client_ip:?}"));
+    }
+
+    Ok(())
+}
+
+fn verify_api_key_scopes(
+    scopes: &[ApiKeyScope],
+    context: ApiKeyRequestContext,
+) -> RouterResult<()> {
+    let is_scope_granted = context.required_scope.map_or(false, |required_scope| {
+        scopes
+            .iter()
+            .any(|scope| required_scope.is_granted_by(*scope))
+    });
+    if !is_scope_granted {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: context.path,
+        }))
+        .attach_printable("API key does not have the scope needed for the API");
+    }
+
+    Ok(())
+}
+
+pub fn get_request_signing_config_key(merchant_id: &str) -> String {
+    format!("request_signing_enabled_{merchant_id}")
+}
+
+/// Verifies the signature of the request when the merchant has enabled request signing, the
+/// signature being the HMAC-SHA256 of `{timestamp}.{hex encoded SHA-256 digest of the body}`
+/// keyed with the payment response hash key of the merchant. API keys are only stored hashed,
+/// which is why the signatures cannot be keyed with them.
+async fn verify_request_signature<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    use common_utils::crypto::{HmacSha256, VerifySignature};
+
+    let is_request_signing_enabled = state
+        .store()
+        .find_config_by_key_unwrap_or(
+            &get_request_signing_config_key(&merchant_account.merchant_id),
+            Some("false".to_string()),
+        )
+        .await
+        .map(|config| config.config == "true")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the request signing config of the merchant")?;
+    if !is_request_signing_enabled {
+        return Ok(());
+    }
+
+    let signature = get_header_value_by_key(headers::X_REQUEST_SIGNATURE.into(), request_headers)?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Request signature is missing")?;
+    let timestamp = get_header_value_by_key(headers::X_REQUEST_TIMESTAMP.into(), request_headers)?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Request timestamp is missing")?;
+    // Set by the `RequestBodyDigest` middleware whenever the request carries a signature
+    let body_digest = get_header_value_by_key(
+        headers::X_INTERNAL_REQUEST_BODY_DIGEST.into(),
+        request_headers,
+    )?
+    .get_required_value("body_digest")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Digest of the request body was not computed")?;
+
+    let signed_at = timestamp
+        .parse::<i64>()
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Request timestamp is not a unix timestamp")?;
+    if (date_time::now_unix_timestamp() - signed_at).abs()
+        > consts::REQUEST_SIGNATURE_TOLERANCE_IN_SECS
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Request timestamp is outside of the tolerated window");
+    }
+
+    let secret = merchant_account
+        .payment_response_hash_key
+        .as_ref()
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+        .attach_printable("Merchant has no payment response hash key to verify signatures with")?;
+    let decoded_signature = hex::decode(signature)
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Request signature is not hex encoded")?;
+    let is_signature_valid = HmacSha256
+        .verify_signature(
+            secret.as_bytes(),
+            &decoded_signature,
+            format!("{timestamp}.{body_digest}").as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify the request signature")?;
+    if !is_signature_valid {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Request signature does not match");
+    }
+
+    // A signature is accepted only once within the tolerated window, rejecting replayed requests
+    let reply = state
+        .store()
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_if_not_exists_with_expiry(
+            &format!(
+                "{}_{}_{signature}",
+                consts::REQUEST_SIGNATURE_REDIS_KEY_PREFIX,
+                merchant_account.merchant_id
+            ),
+            signed_at,
+            Some(2 * consts::REQUEST_SIGNATURE_TOLERANCE_IN_SECS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the request signature")?;
+    if reply == redis_interface::SetnxReply::KeyNotSet {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Request signature has already been used");
+    }
+
+    Ok(())
 }
 
 #[cfg(feature = "olap")]
@@ -720,6 +1122,95 @@ where
     }
 }
 
+pub struct JWTAuthProfileFromRoute {
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub required_permission: Permission,
+}
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<(), A> for JWTAuthProfileFromRoute
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<((), AuthenticationType)> {
+        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        if payload.check_in_blacklist(state).await? {
+            return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+        }
+
+        let permissions = authorization::get_permissions(state, &payload).await?;
+        authorization::check_authorization(&self.required_permission, &permissions)?;
+
+        // Check if token has access to MerchantId that has been requested through query param
+        if payload.merchant_id != self.merchant_id {
+            return Err(report!(errors::ApiErrorResponse::InvalidJwtToken));
+        }
+
+        // Users restricted to a business profile only have access to that profile
+        if payload
+            .profile_id
+            .as_ref()
+            .is_some_and(|profile_id| *profile_id != self.profile_id)
+        {
+            return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: self.profile_id.clone(),
+            }));
+        }
+        Ok((
+            (),
+            AuthenticationType::MerchantJwt {
+                merchant_id: payload.merchant_id,
+                user_id: Some(payload.user_id),
+            },
+        ))
+    }
+}
+
+/// Authenticates the users of the organization in the route having access to the whole
+/// organization, which only the roles with the organization manage group have
+pub struct JWTAuthOrganizationFromRoute {
+    pub org_id: String,
+    pub required_permission: Permission,
+}
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<(), A> for JWTAuthOrganizationFromRoute
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<((), AuthenticationType)> {
+        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        if payload.check_in_blacklist(state).await? {
+            return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+        }
+
+        let permissions = authorization::get_permissions(state, &payload).await?;
+        authorization::check_authorization(&Permission::MerchantAccountCreate, &permissions)?;
+        authorization::check_authorization(&self.required_permission, &permissions)?;
+
+        // Check if token has access to the organization that has been requested through the route
+        if payload.org_id != self.org_id {
+            return Err(report!(errors::ApiErrorResponse::InvalidJwtToken));
+        }
+        Ok((
+            (),
+            AuthenticationType::MerchantJwt {
+                merchant_id: payload.merchant_id,
+                user_id: Some(payload.user_id),
+            },
+        ))
+    }
+}
+
 pub struct JWTAuthMerchantOrProfileFromRoute {
     pub merchant_id_or_profile_id: String,
     pub required_permission: Permission,
@@ -1063,6 +1554,9 @@ pub fn get_auth_type_and_flow<A: SessionStateInfo + Sync>(
     Box<dyn AuthenticateAndFetch<AuthenticationData, A>>,
     api::AuthFlow,
 )> {
+    if get_oauth2_access_token(headers).is_some() {
+        return Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant));
+    }
     let api_key = get_api_key(headers)?;
 
     if api_key.starts_with("pk_") {
@@ -1083,8 +1577,9 @@ where
     ApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
     PublishableKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
 {
-    let api_key = get_api_key(headers)?;
-    if api_key.starts_with("pk_") {
+    let is_publishable_key =
+        get_oauth2_access_token(headers).is_none() && get_api_key(headers)?.starts_with("pk_");
+    if is_publishable_key {
         payload
             .get_client_secret()
             .check_value_present("client_secret")
@@ -1118,9 +1613,10 @@ where
     PublishableKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
     EphemeralKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
 {
-    let api_key = get_api_key(headers)?;
+    let is_ephemeral_key =
+        get_oauth2_access_token(headers).is_none() && get_api_key(headers)?.starts_with("epk");
 
-    if api_key.starts_with("epk") {
+    if is_ephemeral_key {
         Ok((Box::new(EphemeralKeyAuth), api::AuthFlow::Client, true))
     } else if is_merchant_flow {
         Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant, false))
@@ -1134,9 +1630,10 @@ where
 pub fn is_ephemeral_auth<A: SessionStateInfo + Sync>(
     headers: &HeaderMap,
 ) -> RouterResult<Box<dyn AuthenticateAndFetch<AuthenticationData, A>>> {
-    let api_key = get_api_key(headers)?;
+    let is_ephemeral_key =
+        get_oauth2_access_token(headers).is_none() && get_api_key(headers)?.starts_with("epk");
 
-    if !api_key.starts_with("epk") {
+    if !is_ephemeral_key {
         Ok(Box::new(ApiKeyAuth))
     } else {
         Ok(Box::new(EphemeralKeyAuth))
@@ -1144,7 +1641,8 @@ pub fn is_ephemeral_auth<A: SessionStateInfo + Sync>(
 }
 
 pub fn is_jwt_auth(headers: &HeaderMap) -> bool {
-    headers.get(crate::headers::AUTHORIZATION).is_some()
+    (headers.get(crate::headers::AUTHORIZATION).is_some()
+        && get_oauth2_access_token(headers).is_none())
         || get_cookie_from_header(headers)
             .and_then(cookies::parse_cookie)
             .is_ok()
@@ -176,6 +176,7 @@ impl ModuleInfo {
                 permissions: get_permission_info_from_permissions(&[
                     Permission::PayoutRead,
                     Permission::PayoutWrite,
+                    Permission::PayoutApprove,
                 ]),
             },
         }
@@ -215,5 +216,9 @@ fn get_group_description(group: PermissionGroup) -> &'static str {
         PermissionGroup::MerchantDetailsView => "View Merchant Details",
         PermissionGroup::MerchantDetailsManage => "Create, modify and delete Merchant Details like api keys, webhooks, etc",
         PermissionGroup::OrganizationManage => "Manage organization level tasks like create new Merchant accounts, Organization level roles, etc",
+        PermissionGroup::PaymentsView => "View Payments and Refunds",
+        PermissionGroup::RoutingView => "View Routing",
+        PermissionGroup::RoutingManage => "Create, modify and activate Routing",
+        PermissionGroup::PayoutsApprove => "View, fulfill and cancel Payouts",
     }
 }
@@ -16,6 +16,10 @@ pub fn get_permissions_vec(permission_group: &PermissionGroup) -> &[Permission]
         PermissionGroup::MerchantDetailsView => &MERCHANT_DETAILS_VIEW,
         PermissionGroup::MerchantDetailsManage => &MERCHANT_DETAILS_MANAGE,
         PermissionGroup::OrganizationManage => &ORGANIZATION_MANAGE,
+        PermissionGroup::PaymentsView => &PAYMENTS_VIEW,
+        PermissionGroup::RoutingView => &ROUTING_VIEW,
+        PermissionGroup::RoutingManage => &ROUTING_MANAGE,
+        PermissionGroup::PayoutsApprove => &PAYOUTS_APPROVE,
     }
 }
 
@@ -88,3 +92,27 @@ pub static ORGANIZATION_MANAGE: [Permission; 2] = [
     Permission::MerchantAccountCreate,
     Permission::MerchantAccountRead,
 ];
+
+pub static PAYMENTS_VIEW: [Permission; 3] = [
+    Permission::PaymentRead,
+    Permission::RefundRead,
+    Permission::MerchantAccountRead,
+];
+
+pub static ROUTING_VIEW: [Permission; 3] = [
+    Permission::RoutingRead,
+    Permission::MerchantConnectorAccountRead,
+    Permission::MerchantAccountRead,
+];
+
+pub static ROUTING_MANAGE: [Permission; 3] = [
+    Permission::RoutingWrite,
+    Permission::MerchantConnectorAccountRead,
+    Permission::MerchantAccountRead,
+];
+
+pub static PAYOUTS_APPROVE: [Permission; 3] = [
+    Permission::PayoutRead,
+    Permission::PayoutApprove,
+    Permission::MerchantAccountRead,
+];
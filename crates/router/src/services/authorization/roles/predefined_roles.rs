@@ -24,6 +24,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::MerchantDetailsView,
                 PermissionGroup::MerchantDetailsManage,
                 PermissionGroup::OrganizationManage,
+                PermissionGroup::PayoutsApprove,
             ],
             role_id: consts::user_role::ROLE_ID_INTERNAL_ADMIN.to_string(),
             role_name: "internal_admin".to_string(),
@@ -71,6 +72,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::MerchantDetailsView,
                 PermissionGroup::MerchantDetailsManage,
                 PermissionGroup::OrganizationManage,
+                PermissionGroup::PayoutsApprove,
             ],
             role_id: consts::user_role::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
             role_name: "organization_admin".to_string(),
@@ -98,6 +100,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
                 PermissionGroup::UsersManage,
                 PermissionGroup::MerchantDetailsView,
                 PermissionGroup::MerchantDetailsManage,
+                PermissionGroup::PayoutsApprove,
             ],
             role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
             role_name: "admin".to_string(),
@@ -56,6 +56,16 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    AlertNotification {
+        merchant_id: String,
+        rule_id: String,
+        metric: String,
+        connector: Option<String>,
+        threshold_percentage: f64,
+        observed_percentage: f64,
+        volume: u64,
+        window_in_mins: u16,
+    },
 }
 
 pub mod html {
@@ -143,6 +153,28 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::AlertNotification {
+                merchant_id,
+                rule_id,
+                metric,
+                connector,
+                threshold_percentage,
+                observed_percentage,
+                volume,
+                window_in_mins,
+            } => format!(
+                "Dear Hyperswitch user,
+
+The alert rule {rule_id} of the merchant {merchant_id} was breached.
+Metric     : {metric}
+Connector  : {connector}
+Threshold  : {threshold_percentage:.2}%
+Observed   : {observed_percentage:.2}%
+Volume     : {volume} in the last {window_in_mins} minutes
+
+(note: This is an auto generated email. The rule raises no other alert until its cooldown elapses)",
+                connector = connector.as_deref().unwrap_or("all"),
+            ),
         }
     }
 }
@@ -528,3 +560,33 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct AlertNotification {
+    pub recipient_email: domain::UserEmail,
+    pub subject: String,
+    pub alert: api_models::alerts::AlertEvent,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AlertNotification {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::AlertNotification {
+            merchant_id: self.alert.merchant_id.clone(),
+            rule_id: self.alert.rule_id.clone(),
+            metric: self.alert.metric.to_string(),
+            connector: self.alert.connector.clone(),
+            threshold_percentage: self.alert.threshold_percentage,
+            observed_percentage: self.alert.observed_percentage,
+            volume: self.alert.volume,
+            window_in_mins: self.alert.window_in_mins,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
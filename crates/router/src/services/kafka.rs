@@ -11,7 +11,7 @@ use rdkafka::{
 };
 #[cfg(feature = "payouts")]
 pub mod payout;
-use crate::events::EventType;
+use crate::events::{domain_events::DomainEventRecord, EventType};
 mod authentication;
 mod authentication_event;
 mod dispute;
@@ -303,6 +303,21 @@ impl KafkaProducer {
         })
     }
 
+    /// Publishes a domain event to the topic configured for the tenant of the event
+    pub fn log_domain_event(&self, topic: &str, event: &DomainEventRecord<'_>) -> MQResult<()> {
+        router_env::logger::debug!("Logging Kafka Domain Event {event:?}");
+        self.producer
+            .0
+            .send(
+                BaseRecord::to(topic)
+                    .key(&event.key())
+                    .payload(&serde_json::to_vec(event).change_context(KafkaError::GenericError)?)
+                    .timestamp(event.creation_timestamp()),
+            )
+            .map_err(|(error, record)| report!(error).attach_printable(format!("{record:?}")))
+            .change_context(KafkaError::GenericError)
+    }
+
     pub fn log_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
         router_env::logger::debug!("Logging Kafka Event {event:?}");
         let topic = self.get_topic(event.event_type());
@@ -51,6 +51,7 @@ pub struct KafkaPaymentAttempt<'a> {
     pub net_amount: MinorUnit,
     pub unified_code: Option<&'a String>,
     pub unified_message: Option<&'a String>,
+    pub unified_error_details: Option<String>,
     pub mandate_data: Option<&'a MandateDetails>,
     pub client_source: Option<&'a String>,
     pub client_version: Option<&'a String>,
@@ -97,6 +98,10 @@ impl<'a> KafkaPaymentAttempt<'a> {
             net_amount: attempt.net_amount,
             unified_code: attempt.unified_code.as_ref(),
             unified_message: attempt.unified_message.as_ref(),
+            unified_error_details: attempt
+                .unified_error_details
+                .as_ref()
+                .map(|details| details.to_string()),
             mandate_data: attempt.mandate_data.as_ref(),
             client_source: attempt.client_source.as_ref(),
             client_version: attempt.client_version.as_ref(),
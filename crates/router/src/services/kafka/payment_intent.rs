@@ -31,6 +31,8 @@ pub struct KafkaPaymentIntent<'a> {
     pub business_label: Option<&'a String>,
     pub attempt_count: i16,
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
+    pub profile_id: Option<&'a String>,
+    pub custom_fields: Option<&'a serde_json::Value>,
 }
 
 impl<'a> KafkaPaymentIntent<'a> {
@@ -59,6 +61,8 @@ impl<'a> KafkaPaymentIntent<'a> {
             business_label: intent.business_label.as_ref(),
             attempt_count: intent.attempt_count,
             payment_confirm_source: intent.payment_confirm_source,
+            profile_id: intent.profile_id.as_ref(),
+            custom_fields: intent.custom_fields.as_ref(),
         }
     }
 }
@@ -0,0 +1,76 @@
+use error_stack::ResultExt;
+use hyperswitch_interfaces::encryption_interface::{
+    EncryptionError, EncryptionManagementInterface,
+};
+use masking::{ExposeInterface, PeekInterface, Secret, StrongSecret};
+
+use crate::{
+    configs::settings::{Settings, TenantKeyManagement},
+    core::errors::CustomResult,
+};
+
+/// The master keys with which the merchant key stores of a tenant are encrypted
+#[derive(Clone)]
+pub struct MasterKeys {
+    pub current: StrongSecret<Vec<u8>>,
+    /// The master key being rotated out, with which the key stores not yet re-encrypted with the
+    /// current master key are decrypted
+    pub previous: Option<StrongSecret<Vec<u8>>>,
+}
+
+fn decode_master_key(
+    master_key: Secret<String>,
+) -> CustomResult<StrongSecret<Vec<u8>>, EncryptionError> {
+    hex::decode(master_key.expose())
+        .map(StrongSecret::new)
+        .change_context(EncryptionError::DecryptionFailed)
+        .attach_printable("Failed to decode the master key from hex")
+}
+
+async fn unwrap_master_key(
+    key_manager: &dyn EncryptionManagementInterface,
+    wrapped_master_key: &Secret<String>,
+) -> CustomResult<StrongSecret<Vec<u8>>, EncryptionError> {
+    let master_key = key_manager
+        .decrypt(wrapped_master_key.peek().as_bytes())
+        .await
+        .attach_printable("Failed to unwrap the master key with the key manager")?;
+
+    String::from_utf8(master_key)
+        .change_context(EncryptionError::DecryptionFailed)
+        .attach_printable("Failed to UTF-8 decode the unwrapped master key")
+        .and_then(|master_key| decode_master_key(master_key.into()))
+}
+
+/// Gets the master keys of the tenant, unwrapped with the key manager of the tenant when it has
+/// one, the master encryption keys of the deployment being used otherwise
+pub async fn get_master_keys(
+    conf: &Settings,
+    key_management: Option<&TenantKeyManagement>,
+) -> CustomResult<MasterKeys, EncryptionError> {
+    let Some(key_management) = key_management else {
+        let secrets = conf.secrets.get_inner();
+        return Ok(MasterKeys {
+            current: decode_master_key(secrets.master_enc_key.clone())?,
+            previous: secrets
+                .previous_master_enc_key
+                .clone()
+                .map(decode_master_key)
+                .transpose()?,
+        });
+    };
+
+    let key_manager = key_management
+        .key_manager
+        .get_encryption_management_client()
+        .await?;
+    let current = unwrap_master_key(&*key_manager, &key_management.wrapped_master_key).await?;
+    let previous = match &key_management.previous_wrapped_master_key {
+        Some(previous_wrapped_master_key) => {
+            Some(unwrap_master_key(&*key_manager, previous_wrapped_master_key).await?)
+        }
+        None => None,
+    };
+
+    Ok(MasterKeys { current, previous })
+}
@@ -476,7 +476,7 @@ impl Capturable for PaymentsCancelData {
     }
     fn get_amount_capturable<F>(
         &self,
-        _payment_data: &PaymentData<F>,
+        payment_data: &PaymentData<F>,
         attempt_status: common_enums::AttemptStatus,
     ) -> Option<i64>
     where
@@ -487,13 +487,19 @@ impl Capturable for PaymentsCancelData {
             common_enums::IntentStatus::Cancelled
             | common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::PartiallyCaptured => Some(0),
+            // The part of the authorization that is not reversed remains capturable
+            common_enums::IntentStatus::RequiresCapture => {
+                self.minor_amount_to_reverse.map(|amount_to_reverse| {
+                    (payment_data.payment_attempt.amount_capturable - amount_to_reverse)
+                        .get_amount_as_i64()
+                })
+            }
             common_enums::IntentStatus::Succeeded
             | common_enums::IntentStatus::Failed
             | common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
-            | common_enums::IntentStatus::RequiresCapture
             | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
         }
     }
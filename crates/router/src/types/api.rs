@@ -415,6 +415,9 @@ impl ConnectorData {
                     &connector::DummyConnector::<7>,
                 ))),
                 enums::Connector::Ebanx => Ok(ConnectorEnum::Old(Box::new(&connector::Ebanx))),
+                enums::Connector::Externaladapter => {
+                    Ok(ConnectorEnum::Old(Box::new(&connector::Externaladapter)))
+                }
                 enums::Connector::Fiserv => Ok(ConnectorEnum::Old(Box::new(&connector::Fiserv))),
                 enums::Connector::Forte => Ok(ConnectorEnum::Old(Box::new(&connector::Forte))),
                 enums::Connector::Globalpay => {
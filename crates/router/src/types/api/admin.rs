@@ -1,10 +1,11 @@
 pub use api_models::admin::{
-    BusinessProfileCreate, BusinessProfileResponse, BusinessProfileUpdate, MerchantAccountCreate,
-    MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
-    MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
-    MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse, MerchantDetails,
-    MerchantId, PaymentMethodsEnabled, ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest,
-    ToggleKVResponse, WebhookDetails,
+    BusinessProfileCreate, BusinessProfileResponse, BusinessProfileUpdate, DrainerTuning,
+    KvBacklogResponse, KvShardBacklog, MerchantAccountCreate, MerchantAccountDeleteResponse,
+    MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorCreate,
+    MerchantConnectorDeleteResponse, MerchantConnectorDetails, MerchantConnectorDetailsWrap,
+    MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId,
+    MerchantKvSettingsResponse, MerchantKvSettingsUpdateRequest, PaymentMethodsEnabled,
+    ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
@@ -100,6 +101,38 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
             collect_shipping_details_from_wallet_connector: item
                 .collect_shipping_details_from_wallet_connector,
             is_connector_agnostic_mit_enabled: item.is_connector_agnostic_mit_enabled,
+            frm_decision_matrix: item
+                .frm_decision_matrix
+                .map(|matrix| matrix.parse_value("FrmDecisionMatrix"))
+                .transpose()?,
+            frm_post_auth_review: item
+                .frm_post_auth_review
+                .map(|config| config.parse_value("FrmPostAuthReviewConfig"))
+                .transpose()?,
+            connector_debug_capture: item
+                .connector_debug_capture
+                .map(|config| config.parse_value("ConnectorDebugCaptureConfig"))
+                .transpose()?,
+            connector_failover_policy: item
+                .connector_failover_policy
+                .map(|policy| policy.parse_value("ConnectorFailoverPolicy"))
+                .transpose()?,
+            vault_provider: item
+                .vault_provider
+                .map(|provider| provider.parse_value("VaultProvider"))
+                .transpose()?,
+            tax_provider: item
+                .tax_provider
+                .map(|provider| provider.parse_value("TaxProvider"))
+                .transpose()?,
+            dispute_auto_response: item
+                .dispute_auto_response
+                .map(|config| config.parse_value("DisputeAutoResponseConfig"))
+                .transpose()?,
+            custom_fields_schema: item
+                .custom_fields_schema
+                .map(|schema| schema.parse_value("CustomFieldsSchema"))
+                .transpose()?,
         })
     }
 }
@@ -212,6 +245,70 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(Some(true)),
             collect_shipping_details_from_wallet_connector: request
                 .collect_shipping_details_from_wallet_connector,
+            frm_decision_matrix: request
+                .frm_decision_matrix
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "frm_decision_matrix",
+                })?,
+            frm_post_auth_review: request
+                .frm_post_auth_review
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "frm_post_auth_review",
+                })?,
+            connector_debug_capture: request
+                .connector_debug_capture
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "connector_debug_capture",
+                })?,
+            connector_failover_policy: request
+                .connector_failover_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "connector_failover_policy",
+                })?,
+            vault_provider: request
+                .vault_provider
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "vault_provider",
+                })?,
+            tax_provider: request
+                .tax_provider
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "tax_provider",
+                })?,
+            dispute_auto_response: request
+                .dispute_auto_response
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dispute_auto_response",
+                })?,
+            custom_fields_schema: request
+                .custom_fields_schema
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "custom_fields_schema",
+                })?,
         })
     }
 }
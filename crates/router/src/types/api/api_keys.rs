@@ -1,4 +1,5 @@
 pub use api_models::api_keys::{
     ApiKeyExpiration, CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeyConstraints,
+    OAuth2GrantType, OAuth2RevokeRequest, OAuth2TokenRequest, OAuth2TokenResponse,
     RetrieveApiKeyResponse, RevokeApiKeyResponse, UpdateApiKeyRequest,
 };
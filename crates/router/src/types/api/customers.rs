@@ -1,5 +1,9 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerFraudFlag, CustomerId, CustomerOverviewMandate,
+    CustomerOverviewPayment, CustomerOverviewPaymentMethod, CustomerOverviewResponse,
+    CustomerRedactionResponse, CustomerRedactionStatus, CustomerRequest, CustomerSpend,
+};
 use serde::Serialize;
 
 use super::payments;
@@ -12,7 +12,7 @@ pub use hyperswitch_domain_models::router_flow_types::dispute::{Accept, Defend,
 
 pub use super::disputes_v2::{AcceptDisputeV2, DefendDisputeV2, DisputeV2, SubmitEvidenceV2};
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct DisputePayload {
     pub amount: String,
     pub currency: String,
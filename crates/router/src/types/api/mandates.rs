@@ -1,5 +1,9 @@
 use api_models::mandates;
-pub use api_models::mandates::{MandateId, MandateResponse, MandateRevokedResponse};
+pub use api_models::mandates::{
+    MandateId, MandateMigrationRecord, MandateMigrationRecordResponse, MandateMigrationRequest,
+    MandateMigrationResponse, MandatePreNotificationRequest, MandatePreNotificationResponse,
+    MandateResponse, MandateRevokedResponse,
+};
 use common_utils::ext_traits::OptionExt;
 use error_stack::ResultExt;
 use masking::PeekInterface;
@@ -39,7 +39,8 @@ impl VerifyConnectorData {
             session_token: None,
             order_details: None,
             order_category: None,
-            capture_method: None,
+            // Verifying the credentials should not move any funds
+            capture_method: Some(storage_enums::CaptureMethod::Manual),
             enrolled_for_3ds: false,
             router_return_url: None,
             surcharge_details: None,
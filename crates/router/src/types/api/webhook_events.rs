@@ -1,6 +1,8 @@
 pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    EventListRequestInternal, EventRetrieveResponse, IncomingWebhookListConstraints,
+    IncomingWebhookListItemResponse, IncomingWebhookListRequestInternal,
+    IncomingWebhookRetrieveRequestInternal, IncomingWebhookRetrieveResponse,
+    OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+    WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryRequestInternal,
 };
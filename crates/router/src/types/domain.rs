@@ -5,6 +5,7 @@ pub mod behaviour {
 
 mod customer;
 mod event;
+mod incoming_webhook_archive;
 mod merchant_account;
 mod merchant_connector_account;
 mod merchant_key_store {
@@ -19,6 +20,7 @@ pub mod user_key_store;
 pub use address::*;
 pub use customer::*;
 pub use event::*;
+pub use incoming_webhook_archive::*;
 pub use merchant_account::*;
 pub use merchant_connector_account::*;
 pub use merchant_key_store::*;
@@ -24,6 +24,9 @@ pub struct Customer {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
+    pub payment_method_order: Option<serde_json::Value>,
+    pub email_blind_index: Option<String>,
+    pub phone_blind_index: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -49,6 +52,9 @@ impl super::behaviour::Conversion for Customer {
             address_id: self.address_id,
             default_payment_method_id: self.default_payment_method_id,
             updated_by: self.updated_by,
+            payment_method_order: self.payment_method_order,
+            email_blind_index: self.email_blind_index,
+            phone_blind_index: self.phone_blind_index,
         })
     }
 
@@ -78,6 +84,9 @@ impl super::behaviour::Conversion for Customer {
                 address_id: item.address_id,
                 default_payment_method_id: item.default_payment_method_id,
                 updated_by: item.updated_by,
+                payment_method_order: item.payment_method_order,
+                email_blind_index: item.email_blind_index,
+                phone_blind_index: item.phone_blind_index,
             })
         }
         .await
@@ -102,6 +111,8 @@ impl super::behaviour::Conversion for Customer {
             connector_customer: self.connector_customer,
             address_id: self.address_id,
             updated_by: self.updated_by,
+            email_blind_index: self.email_blind_index,
+            phone_blind_index: self.phone_blind_index,
         })
     }
 }
@@ -117,6 +128,8 @@ pub enum CustomerUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         connector_customer: Option<serde_json::Value>,
         address_id: Option<String>,
+        email_blind_index: Option<Option<String>>,
+        phone_blind_index: Option<Option<String>>,
     },
     ConnectorCustomer {
         connector_customer: Option<serde_json::Value>,
@@ -124,6 +137,9 @@ pub enum CustomerUpdate {
     UpdateDefaultPaymentMethod {
         default_payment_method_id: Option<Option<String>>,
     },
+    UpdatePaymentMethodOrder {
+        payment_method_order: serde_json::Value,
+    },
 }
 
 impl From<CustomerUpdate> for CustomerUpdateInternal {
@@ -138,6 +154,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 address_id,
+                email_blind_index,
+                phone_blind_index,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -148,6 +166,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer,
                 modified_at: Some(date_time::now()),
                 address_id,
+                email_blind_index,
+                phone_blind_index,
                 ..Default::default()
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
@@ -162,6 +182,13 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 modified_at: Some(date_time::now()),
                 ..Default::default()
             },
+            CustomerUpdate::UpdatePaymentMethodOrder {
+                payment_method_order,
+            } => Self {
+                payment_method_order: Some(payment_method_order),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -37,6 +37,11 @@ pub enum EventUpdate {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
     },
+    /// Replaces the request and response of the event, once re-encrypted with a new key
+    EncryptedDataUpdate {
+        request: OptionalEncryptableSecretString,
+        response: OptionalEncryptableSecretString,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -47,6 +52,12 @@ impl From<EventUpdate> for EventUpdateInternal {
                 response,
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
+                request: None,
+                response: response.map(Into::into),
+            },
+            EventUpdate::EncryptedDataUpdate { request, response } => Self {
+                is_webhook_notified: None,
+                request: request.map(Into::into),
                 response: response.map(Into::into),
             },
         }
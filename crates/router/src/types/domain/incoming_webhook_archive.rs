@@ -0,0 +1,97 @@
+use common_utils::{
+    crypto::{Encryptable, GcmAes256},
+    errors::{CustomResult, ValidationError},
+};
+use diesel_models::incoming_webhook_archive::IncomingWebhookArchiveUpdateInternal;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use super::{behaviour, types::TypeEncryption};
+
+/// The body of an incoming webhook as it was received, decrypted with the key of the merchant
+#[derive(Clone, Debug)]
+pub struct IncomingWebhookArchive {
+    pub archive_id: String,
+    pub merchant_id: String,
+    pub business_profile_id: Option<String>,
+    pub connector_name: String,
+    pub merchant_connector_id: Option<String>,
+    pub event_type: Option<String>,
+    pub body: Encryptable<Secret<String>>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum IncomingWebhookArchiveUpdate {
+    /// Replaces the body of the webhook, once re-encrypted with a new key
+    EncryptedDataUpdate { body: Encryptable<Secret<String>> },
+}
+
+impl From<IncomingWebhookArchiveUpdate> for IncomingWebhookArchiveUpdateInternal {
+    fn from(archive_update: IncomingWebhookArchiveUpdate) -> Self {
+        match archive_update {
+            IncomingWebhookArchiveUpdate::EncryptedDataUpdate { body } => {
+                Self { body: body.into() }
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl behaviour::Conversion for IncomingWebhookArchive {
+    type DstType = diesel_models::incoming_webhook_archive::IncomingWebhookArchive;
+    type NewDstType = diesel_models::incoming_webhook_archive::IncomingWebhookArchiveNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_archive::IncomingWebhookArchive {
+                archive_id: self.archive_id,
+                merchant_id: self.merchant_id,
+                business_profile_id: self.business_profile_id,
+                connector_name: self.connector_name,
+                merchant_connector_id: self.merchant_connector_id,
+                event_type: self.event_type,
+                body: self.body.into(),
+                created_at: self.created_at,
+            },
+        )
+    }
+
+    async fn convert_back(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            archive_id: item.archive_id,
+            merchant_id: item.merchant_id,
+            business_profile_id: item.business_profile_id,
+            connector_name: item.connector_name,
+            merchant_connector_id: item.merchant_connector_id,
+            event_type: item.event_type,
+            body: Encryptable::decrypt(item.body, key.peek(), GcmAes256)
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting incoming webhook body".to_string(),
+                })?,
+            created_at: item.created_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_archive::IncomingWebhookArchiveNew {
+                archive_id: self.archive_id,
+                merchant_id: self.merchant_id,
+                business_profile_id: self.business_profile_id,
+                connector_name: self.connector_name,
+                merchant_connector_id: self.merchant_connector_id,
+                event_type: self.event_type,
+                body: self.body.into(),
+                created_at: self.created_at,
+            },
+        )
+    }
+}
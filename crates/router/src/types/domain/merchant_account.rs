@@ -82,6 +82,16 @@ pub enum MerchantAccountUpdate {
     },
     UnsetDefaultProfile,
     ModifiedAtUpdate,
+    /// Replaces the encrypted details of the merchant, once re-encrypted with a new key
+    EncryptedDataUpdate {
+        merchant_name: OptionalEncryptableName,
+        merchant_details: OptionalEncryptableValue,
+    },
+    /// Links the merchant to the platform it was onboarded by as a connected account
+    ConnectedAccountUpdate {
+        parent_merchant_id: String,
+        default_profile: Option<String>,
+    },
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -149,6 +159,25 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 modified_at: Some(date_time::now()),
                 ..Default::default()
             },
+            MerchantAccountUpdate::EncryptedDataUpdate {
+                merchant_name,
+                merchant_details,
+            } => Self {
+                merchant_name: merchant_name.map(Encryption::from),
+                merchant_details: merchant_details.map(Encryption::from),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
+            MerchantAccountUpdate::ConnectedAccountUpdate {
+                parent_merchant_id,
+                default_profile,
+            } => Self {
+                sub_merchants_enabled: Some(true),
+                parent_merchant_id: Some(parent_merchant_id),
+                default_profile: default_profile.map(Some),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -40,6 +40,12 @@ pub struct MerchantConnectorAccount {
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: enums::ConnectorStatus,
     pub connector_wallets_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    /// The credentials staged to replace the connector account details on the next cutover
+    pub next_connector_account_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    /// The credentials replaced on the last cutover, still used as a fallback during the rotation
+    /// window
+    pub previous_connector_account_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    pub credentials_rotation_window_ends_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug)]
@@ -65,6 +71,19 @@ pub enum MerchantConnectorAccountUpdate {
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<Secret<serde_json::Value>>,
     },
+    CredentialsRotationUpdate {
+        connector_account_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        next_connector_account_details: Option<Option<Encryptable<Secret<serde_json::Value>>>>,
+        previous_connector_account_details: Option<Option<Encryptable<Secret<serde_json::Value>>>>,
+        credentials_rotation_window_ends_at: Option<Option<time::PrimitiveDateTime>>,
+    },
+    /// Replaces the encrypted details of the connector account, once re-encrypted with a new key
+    EncryptedDataUpdate {
+        connector_account_details: Encryptable<Secret<serde_json::Value>>,
+        connector_wallets_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        next_connector_account_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        previous_connector_account_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    },
 }
 
 #[async_trait::async_trait]
@@ -101,6 +120,13 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 pm_auth_config: self.pm_auth_config,
                 status: self.status,
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
+                next_connector_account_details: self
+                    .next_connector_account_details
+                    .map(Encryption::from),
+                previous_connector_account_details: self
+                    .previous_connector_account_details
+                    .map(Encryption::from),
+                credentials_rotation_window_ends_at: self.credentials_rotation_window_ends_at,
             },
         )
     }
@@ -148,6 +174,22 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 .change_context(ValidationError::InvalidValue {
                     message: "Failed while decrypting connector wallets details".to_string(),
                 })?,
+            next_connector_account_details: other
+                .next_connector_account_details
+                .async_lift(|inner| types::decrypt(inner, key.peek()))
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting next connector account details".to_string(),
+                })?,
+            previous_connector_account_details: other
+                .previous_connector_account_details
+                .async_lift(|inner| types::decrypt(inner, key.peek()))
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting previous connector account details"
+                        .to_string(),
+                })?,
+            credentials_rotation_window_ends_at: other.credentials_rotation_window_ends_at,
         })
     }
 
@@ -177,6 +219,13 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             pm_auth_config: self.pm_auth_config,
             status: self.status,
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
+            next_connector_account_details: self
+                .next_connector_account_details
+                .map(Encryption::from),
+            previous_connector_account_details: self
+                .previous_connector_account_details
+                .map(Encryption::from),
+            credentials_rotation_window_ends_at: self.credentials_rotation_window_ends_at,
         })
     }
 }
@@ -220,6 +269,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_label,
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
+                next_connector_account_details: None,
+                previous_connector_account_details: None,
+                credentials_rotation_window_ends_at: None,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -242,6 +294,69 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 applepay_verified_domains: None,
                 pm_auth_config: None,
                 status: None,
+                next_connector_account_details: None,
+                previous_connector_account_details: None,
+                credentials_rotation_window_ends_at: None,
+            },
+            MerchantConnectorAccountUpdate::CredentialsRotationUpdate {
+                connector_account_details,
+                next_connector_account_details,
+                previous_connector_account_details,
+                credentials_rotation_window_ends_at,
+            } => Self {
+                connector_account_details: connector_account_details.map(Encryption::from),
+                next_connector_account_details: next_connector_account_details
+                    .map(|details| details.map(Encryption::from)),
+                previous_connector_account_details: previous_connector_account_details
+                    .map(|details| details.map(Encryption::from)),
+                credentials_rotation_window_ends_at,
+                modified_at: Some(date_time::now()),
+                merchant_id: None,
+                connector_type: None,
+                connector_name: None,
+                connector_label: None,
+                test_mode: None,
+                disabled: None,
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                frm_configs: None,
+                metadata: None,
+                connector_webhook_details: None,
+                frm_config: None,
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                status: None,
+                connector_wallets_details: None,
+            },
+            MerchantConnectorAccountUpdate::EncryptedDataUpdate {
+                connector_account_details,
+                connector_wallets_details,
+                next_connector_account_details,
+                previous_connector_account_details,
+            } => Self {
+                connector_account_details: Some(Encryption::from(connector_account_details)),
+                connector_wallets_details: connector_wallets_details.map(Encryption::from),
+                next_connector_account_details: next_connector_account_details
+                    .map(|details| Some(Encryption::from(details))),
+                previous_connector_account_details: previous_connector_account_details
+                    .map(|details| Some(Encryption::from(details))),
+                modified_at: Some(date_time::now()),
+                merchant_id: None,
+                connector_type: None,
+                connector_name: None,
+                connector_label: None,
+                test_mode: None,
+                disabled: None,
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                frm_configs: None,
+                metadata: None,
+                connector_webhook_details: None,
+                frm_config: None,
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                status: None,
+                credentials_rotation_window_ends_at: None,
             },
         }
     }
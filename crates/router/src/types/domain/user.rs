@@ -609,6 +609,7 @@ impl NewUser {
                     .get_new_merchant()
                     .get_new_organization()
                     .get_organization_id(),
+                profile_id: None,
             })
             .await
             .change_context(UserErrors::InternalServerError)
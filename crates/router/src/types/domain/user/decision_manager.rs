@@ -112,6 +112,7 @@ impl JWTFlow {
             user_role.role_id.clone(),
             &state.conf,
             user_role.org_id.clone(),
+            user_role.profile_id.clone(),
         )
         .await
         .map(|token| token.into())
@@ -1,14 +1,20 @@
 pub mod address;
+pub mod alert;
 pub mod api_keys;
+pub mod audit_event;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
+pub mod bulk_force_sync;
 pub mod business_profile;
 pub mod capture;
+pub mod card_export_batch;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_exchange;
+pub mod connector_migration;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -19,25 +25,36 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_archive;
+pub mod job;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod order;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_status_transition;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
+pub mod report_template;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod search;
+pub mod settlement_line;
+pub mod tenant;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod vault_cleanup;
+pub mod wallet_decryption_key;
 
 use std::collections::HashMap;
 
@@ -58,13 +75,17 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, user::*, user_authentication_method::*, user_role::*,
+    address::*, alert::*, api_keys::*, audit_event::*, authentication::*, authorization::*,
+    blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, bulk_force_sync::*,
+    business_profile::*, capture::*, card_export_batch::*, cards_info::*, configs::*,
+    connector_exchange::*, connector_migration::*, customers::*, dashboard_metadata::*, dispute::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*,
+    incoming_webhook_archive::*, job::*, ledger_entry::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, order::*,
+    payment_link::*, payment_method::*, payment_status_transition::*, process_tracker::*,
+    refund::*, report_template::*, reverse_lookup::*, role::*, routing_algorithm::*, search::*,
+    settlement_line::*, tenant::*, user::*, user_authentication_method::*, user_role::*,
+    vault_cleanup::*, wallet_decryption_key::*,
 };
 use crate::types::api::routing;
 
@@ -79,6 +100,14 @@ pub struct RoutingData {
     pub algorithm: Option<api_models::routing::StraightThroughAlgorithm>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AsyncConfirmationTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    /// The request the payment is confirmed with, without the details of the customer
+    pub request: api_models::payments::PaymentsRequest,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(from = "PaymentRoutingInfoSerde", into = "PaymentRoutingInfoSerde")]
 pub struct PaymentRoutingInfo {
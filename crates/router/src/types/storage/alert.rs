@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AlertEvaluationTrackingData {
+    pub merchant_id: String,
+}
@@ -0,0 +1 @@
+pub use diesel_models::audit_event::{AuditEvent, AuditEventConstraints, AuditEventNew};
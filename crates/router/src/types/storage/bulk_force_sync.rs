@@ -0,0 +1,110 @@
+use api_models::bulk_force_sync::BulkForceSyncSummary;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel_models::{
+    enums, errors, payment_attempt::PaymentAttempt, query::generics::db_metrics, schema,
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::connection::PgPooledConn;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BulkForceSyncTrackingData {
+    pub merchant_id: Option<String>,
+    pub connectors: Vec<String>,
+    pub statuses: Vec<enums::AttemptStatus>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_before: PrimitiveDateTime,
+    pub batch_size: u16,
+    pub max_syncs_per_second: u16,
+    /// The report of the payments synced, set once the job is completed
+    pub summary: Option<BulkForceSyncSummary>,
+}
+
+/// A payment attempt left in a non-terminal status, which is synced with its connector
+#[derive(Debug, Clone)]
+pub struct StuckPaymentAttempt {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: Option<String>,
+}
+
+#[async_trait::async_trait]
+pub trait BulkForceSyncDbExt: Sized {
+    /// Lists the payment attempts made with a connector in one of the statuses provided that were
+    /// last modified before the time provided, in the order of their identifiers, starting after
+    /// the identifier provided. All the connectors are considered when no connector is provided.
+    #[allow(clippy::too_many_arguments)]
+    async fn list_stuck_payment_attempts(
+        conn: &PgPooledConn,
+        merchant_id: Option<&str>,
+        connectors: &[String],
+        statuses: &[enums::AttemptStatus],
+        modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<StuckPaymentAttempt>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl BulkForceSyncDbExt for PaymentAttempt {
+    async fn list_stuck_payment_attempts(
+        conn: &PgPooledConn,
+        merchant_id: Option<&str>,
+        connectors: &[String],
+        statuses: &[enums::AttemptStatus],
+        modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<StuckPaymentAttempt>, errors::DatabaseError> {
+        use schema::payment_attempt::dsl;
+
+        let mut query = <Self as HasTable>::table()
+            .select((
+                dsl::merchant_id,
+                dsl::payment_id,
+                dsl::attempt_id,
+                dsl::connector,
+            ))
+            .filter(dsl::status.eq_any(statuses.to_vec()))
+            .filter(dsl::modified_at.lt(modified_before))
+            .filter(dsl::connector.is_not_null())
+            .order(dsl::attempt_id.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(merchant_id) = merchant_id {
+            query = query.filter(dsl::merchant_id.eq(merchant_id.to_owned()));
+        }
+        if !connectors.is_empty() {
+            query = query.filter(dsl::connector.eq_any(connectors.to_vec()));
+        }
+        if let Some(starting_after) = starting_after {
+            query = query.filter(dsl::attempt_id.gt(starting_after.to_owned()));
+        }
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(String, String, String, Option<String>)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error listing the stuck payment attempts")
+        .map(|attempts| {
+            attempts
+                .into_iter()
+                .map(
+                    |(merchant_id, payment_id, attempt_id, connector)| StuckPaymentAttempt {
+                        merchant_id,
+                        payment_id,
+                        attempt_id,
+                        connector,
+                    },
+                )
+                .collect()
+        })
+    }
+}
@@ -1 +1,8 @@
 pub use diesel_models::capture::*;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScheduledCaptureTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub amount_to_capture: Option<common_utils::types::MinorUnit>,
+}
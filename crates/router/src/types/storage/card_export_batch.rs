@@ -0,0 +1,67 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::card_export_batch::{
+    CardExportBatch, CardExportBatchNew, CardExportBatchUpdate, CardExportBatchUpdateInternal,
+};
+use diesel_models::{
+    enums, errors, payment_method::PaymentMethod, query::generics::db_metrics, schema,
+};
+use error_stack::ResultExt;
+
+use crate::connection::PgPooledConn;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CardExportTrackingData {
+    pub merchant_id: String,
+    pub export_id: String,
+}
+
+#[async_trait::async_trait]
+pub trait CardExportDbExt: Sized {
+    /// Lists the active card payment methods of the merchant stored in the card vault, in the
+    /// order of their identifiers, starting after the identifier provided
+    async fn list_cards_for_export(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_method_ids: Option<&[String]>,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl CardExportDbExt for PaymentMethod {
+    async fn list_cards_for_export(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_method_ids: Option<&[String]>,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        use schema::payment_methods::dsl;
+
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::payment_method.eq(enums::PaymentMethod::Card))
+            .filter(dsl::status.eq(enums::PaymentMethodStatus::Active))
+            .order(dsl::payment_method_id.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(payment_method_ids) = payment_method_ids {
+            query = query.filter(dsl::payment_method_id.eq_any(payment_method_ids.to_vec()));
+        }
+        if let Some(starting_after) = starting_after {
+            query = query.filter(dsl::payment_method_id.gt(starting_after.to_owned()));
+        }
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error listing the cards to export")
+    }
+}
@@ -0,0 +1,10 @@
+pub use diesel_models::connector_migration::{
+    ConnectorMigration, ConnectorMigrationNew, ConnectorMigrationPaymentMethod,
+    ConnectorMigrationPaymentMethodNew, ConnectorMigrationUpdate, ConnectorMigrationUpdateInternal,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorMigrationTrackingData {
+    pub merchant_id: String,
+    pub migration_id: String,
+}
@@ -1,3 +1,187 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::{errors::CustomResult, id_type, types::MinorUnit};
+use diesel::{ExpressionMethods, QueryDsl};
 pub use diesel_models::customers::{Customer, CustomerNew, CustomerUpdateInternal};
+use diesel_models::{enums, errors, query::generics::db_metrics, schema};
+use error_stack::ResultExt;
 
 pub use crate::types::domain::CustomerUpdate;
+use crate::{connection::PgPooledConn, core::customers::REDACTED};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CustomerRedactionTrackingData {
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+}
+
+/// Aggregates of the activity of a customer across their payments
+#[derive(Clone, Debug, Default)]
+pub struct CustomerActivity {
+    /// The amounts captured from the payments of the customer, by currency
+    pub captured_amounts: Vec<(enums::Currency, MinorUnit)>,
+    /// The number of disputes raised against the payments of the customer
+    pub dispute_count: i64,
+    /// The number of payments of the customer flagged as fraudulent by a fraud check
+    pub fraudulent_payment_count: i64,
+}
+
+#[async_trait::async_trait]
+pub trait CustomerActivityDbExt: Sized {
+    async fn get_activity(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<CustomerActivity, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerActivityDbExt for Customer {
+    async fn get_activity(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<CustomerActivity, errors::DatabaseError> {
+        use schema::{dispute, fraud_check, payment_intent};
+
+        let customer_payment_ids = payment_intent::table
+            .select(payment_intent::payment_id)
+            .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payment_intent::customer_id.eq(customer_id.to_owned()));
+
+        let captured: Vec<(Option<enums::Currency>, Option<MinorUnit>)> =
+            db_metrics::track_database_call::<schema::payment_intent::table, _, _>(
+                payment_intent::table
+                    .select((payment_intent::currency, payment_intent::amount_captured))
+                    .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+                    .filter(payment_intent::customer_id.eq(customer_id.to_owned()))
+                    .filter(payment_intent::status.eq_any([
+                        enums::IntentStatus::Succeeded,
+                        enums::IntentStatus::PartiallyCaptured,
+                        enums::IntentStatus::PartiallyCapturedAndCapturable,
+                    ]))
+                    .filter(payment_intent::amount_captured.is_not_null())
+                    .get_results_async(conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error fetching the captured amounts of the customer")?;
+
+        let mut captured_amounts: Vec<(enums::Currency, MinorUnit)> = Vec::new();
+        for (currency, amount) in captured {
+            let (Some(currency), Some(amount)) = (currency, amount) else {
+                continue;
+            };
+            match captured_amounts
+                .iter_mut()
+                .find(|(total_currency, _)| *total_currency == currency)
+            {
+                Some((_, total)) => *total = *total + amount,
+                None => captured_amounts.push((currency, amount)),
+            }
+        }
+
+        let dispute_count = db_metrics::track_database_call::<schema::dispute::table, _, _>(
+            dispute::table
+                .count()
+                .filter(dispute::merchant_id.eq(merchant_id.to_owned()))
+                .filter(dispute::payment_id.eq_any(customer_payment_ids.clone()))
+                .get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting the disputes of the customer")?;
+
+        let fraudulent_payment_count =
+            db_metrics::track_database_call::<schema::fraud_check::table, _, _>(
+                fraud_check::table
+                    .select(diesel::dsl::count_distinct(fraud_check::payment_id))
+                    .filter(fraud_check::merchant_id.eq(merchant_id.to_owned()))
+                    .filter(fraud_check::frm_status.eq(enums::FraudCheckStatus::Fraud))
+                    .filter(fraud_check::payment_id.eq_any(customer_payment_ids))
+                    .get_result_async::<i64>(conn),
+                db_metrics::DatabaseOperation::Count,
+            )
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error counting the fraudulent payments of the customer")?;
+
+        Ok(CustomerActivity {
+            captured_amounts,
+            dispute_count,
+            fraudulent_payment_count,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+pub trait CustomerRedactionDbExt: Sized {
+    /// Redacts the personal data the payments and mandates of the customer hold, leaving their
+    /// amounts, statuses and identifiers untouched
+    async fn redact_payment_data(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<(), errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerRedactionDbExt for Customer {
+    async fn redact_payment_data(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<(), errors::DatabaseError> {
+        use schema::{mandate, payment_attempt, payment_intent};
+
+        let customer_payment_ids = payment_intent::table
+            .select(payment_intent::payment_id)
+            .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payment_intent::customer_id.eq(customer_id.to_owned()));
+
+        db_metrics::track_database_call::<schema::payment_intent::table, _, _>(
+            diesel::update(payment_intent::table)
+                .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+                .filter(payment_intent::customer_id.eq(customer_id.to_owned()))
+                .filter(payment_intent::description.is_not_null())
+                .set(payment_intent::description.eq(REDACTED))
+                .execute_async(conn),
+            db_metrics::DatabaseOperation::Update,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error redacting the payment intents of the customer")?;
+
+        // The browser information holds the IP address and the user agent of the customer
+        db_metrics::track_database_call::<schema::payment_attempt::table, _, _>(
+            diesel::update(payment_attempt::table)
+                .filter(payment_attempt::merchant_id.eq(merchant_id.to_owned()))
+                .filter(payment_attempt::payment_id.eq_any(customer_payment_ids))
+                .filter(payment_attempt::browser_info.is_not_null())
+                .set(payment_attempt::browser_info.eq(None::<serde_json::Value>))
+                .execute_async(conn),
+            db_metrics::DatabaseOperation::Update,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error redacting the payment attempts of the customer")?;
+
+        db_metrics::track_database_call::<schema::mandate::table, _, _>(
+            diesel::update(mandate::table)
+                .filter(mandate::merchant_id.eq(merchant_id.to_owned()))
+                .filter(mandate::customer_id.eq(customer_id.to_owned()))
+                .set((
+                    mandate::customer_ip_address.eq(None::<String>),
+                    mandate::customer_user_agent.eq(None::<String>),
+                ))
+                .execute_async(conn),
+            db_metrics::DatabaseOperation::Update,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error redacting the mandates of the customer")?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::ResultExt;
@@ -25,7 +25,7 @@ impl DisputeDbExt for Dispute {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::dispute_id.desc()))
             .into_boxed();
 
         if let Some(profile_id) = dispute_list_constraints.profile_id {
@@ -46,6 +46,19 @@ impl DisputeDbExt for Dispute {
         if let Some(received_time_gte) = dispute_list_constraints.received_time_gte {
             filter = filter.filter(dsl::created_at.ge(received_time_gte));
         }
+        if let Some(dispute_amount_gte) = dispute_list_constraints.dispute_amount_gte {
+            filter = filter.filter(dsl::dispute_amount.ge(dispute_amount_gte));
+        }
+        if let Some(dispute_amount_lte) = dispute_list_constraints.dispute_amount_lte {
+            filter = filter.filter(dsl::dispute_amount.le(dispute_amount_lte));
+        }
+        if let Some(cursor) = dispute_list_constraints.cursor {
+            filter = filter.filter(
+                dsl::created_at.lt(cursor.created_at).or(dsl::created_at
+                    .eq(cursor.created_at)
+                    .and(dsl::dispute_id.lt(cursor.id))),
+            );
+        }
         if let Some(connector) = dispute_list_constraints.connector {
             filter = filter.filter(dsl::connector.eq(connector));
         }
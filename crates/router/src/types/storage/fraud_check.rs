@@ -1,3 +1,10 @@
 pub use diesel_models::fraud_check::{
     FraudCheck, FraudCheckNew, FraudCheckUpdate, FraudCheckUpdateInternal,
 };
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrmReviewTimeoutTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub timeout_action: api_models::enums::FrmReviewAction,
+}
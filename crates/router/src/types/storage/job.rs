@@ -0,0 +1,7 @@
+pub use diesel_models::job::{Job, JobNew, JobUpdate, JobUpdateInternal};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct JobTrackingData {
+    pub merchant_id: String,
+    pub job_id: String,
+}
@@ -7,7 +7,16 @@ pub use diesel_models::mandate::{
 use diesel_models::{errors, schema::mandate::dsl};
 use error_stack::ResultExt;
 
-use crate::{connection::PgPooledConn, logger};
+use crate::{connection::PgPooledConn, logger, types::storage::enums};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MandatePreNotificationTrackingData {
+    pub mandate_id: String,
+    pub merchant_id: String,
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub debit_date: time::PrimitiveDateTime,
+}
 
 #[async_trait::async_trait]
 pub trait MandateDbExt: Sized {
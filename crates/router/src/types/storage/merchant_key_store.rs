@@ -1 +1,20 @@
+use api_models::{
+    master_key_rotation::MasterKeyRotationSummary,
+    merchant_key_rotation::MerchantKeyRotationSummary,
+};
 pub use diesel_models::merchant_key_store::MerchantKeyStore;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MasterKeyRotationTrackingData {
+    pub batch_size: u16,
+    /// The progress of the job, updated after each batch of key stores
+    pub summary: MasterKeyRotationSummary,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyRotationTrackingData {
+    pub merchant_id: String,
+    pub batch_size: u16,
+    /// The progress of the job, updated after each batch of records
+    pub summary: MerchantKeyRotationSummary,
+}
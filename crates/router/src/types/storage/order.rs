@@ -0,0 +1 @@
+pub use diesel_models::order::{Order, OrderNew, OrderUpdate, OrderUpdateInternal};
@@ -0,0 +1,3 @@
+pub use diesel_models::payment_status_transition::{
+    PaymentStatusTransition, PaymentStatusTransitionNew,
+};
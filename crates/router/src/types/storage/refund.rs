@@ -1,7 +1,7 @@
 use api_models::payments::AmountFilter;
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 pub use diesel_models::refund::{
     Refund, RefundCoreWorkflow, RefundNew, RefundUpdate, RefundUpdateInternal,
 };
@@ -49,7 +49,7 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::refund_id.desc()))
             .into_boxed();
         let mut search_by_pay_or_ref_id = false;
 
@@ -105,6 +105,16 @@ impl RefundDbExt for Refund {
             }
         }
 
+        // The refunds are listed from the most recent, so the next page holds the refunds created
+        // before the last refund of the previous page
+        if let Some(cursor) = &refund_list_details.cursor {
+            filter = filter.filter(
+                dsl::created_at.lt(cursor.created_at).or(dsl::created_at
+                    .eq(cursor.created_at)
+                    .and(dsl::refund_id.lt(cursor.id.clone()))),
+            );
+        }
+
         filter = match refund_list_details.amount_filter {
             Some(AmountFilter {
                 start_amount: Some(start),
@@ -0,0 +1,9 @@
+pub use diesel_models::report_template::{
+    ReportTemplate, ReportTemplateNew, ReportTemplateUpdate, ReportTemplateUpdateInternal,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ReportGenerationTrackingData {
+    pub merchant_id: String,
+    pub template_id: String,
+}
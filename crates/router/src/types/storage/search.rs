@@ -0,0 +1,216 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{
+    associations::HasTable,
+    pg::{PgAnyJsonExpressionMethods, PgJsonbExpressionMethods},
+    ExpressionMethods, QueryDsl,
+};
+use diesel_models::{
+    dispute::Dispute, errors, payment_attempt::PaymentAttempt, payment_intent::PaymentIntent,
+    query::generics::db_metrics, refund::Refund, schema,
+};
+use error_stack::ResultExt;
+
+use crate::{connection::PgPooledConn, logger};
+
+/// A single criterion of a search, each criterion being looked up with its own query so that the
+/// results can be ranked by the criteria they matched
+#[derive(Clone, Copy, Debug)]
+pub enum SearchFilter<'a> {
+    /// The identifier of the object
+    Id(&'a str),
+    /// The identifiers of the payments of the objects
+    PaymentIds(&'a [String]),
+    ConnectorTransactionId(&'a str),
+    Reference(&'a str),
+    CustomerIds(&'a [String]),
+    Last4(&'a str),
+    Metadata(&'a serde_json::Map<String, serde_json::Value>),
+}
+
+#[async_trait::async_trait]
+pub trait SearchDbExt: Sized {
+    /// Finds the objects of the merchant matching the filter, or nothing when the filter does not
+    /// apply to these objects
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        filter: SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl SearchDbExt for PaymentIntent {
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        filter: SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        use schema::payment_intent::dsl;
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        let query = match filter {
+            SearchFilter::Id(payment_id) => query.filter(dsl::payment_id.eq(payment_id.to_owned())),
+            SearchFilter::PaymentIds(payment_ids) => {
+                query.filter(dsl::payment_id.eq_any(payment_ids.to_vec()))
+            }
+            SearchFilter::CustomerIds(customer_ids) => {
+                query.filter(dsl::customer_id.eq_any(customer_ids.to_vec()))
+            }
+            SearchFilter::Metadata(metadata) => {
+                query.filter(dsl::metadata.contains(serde_json::Value::Object(metadata.clone())))
+            }
+            SearchFilter::ConnectorTransactionId(_)
+            | SearchFilter::Reference(_)
+            | SearchFilter::Last4(_) => return Ok(Vec::new()),
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error searching payment intents")
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchDbExt for PaymentAttempt {
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        filter: SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        use schema::payment_attempt::dsl;
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        let query = match filter {
+            SearchFilter::ConnectorTransactionId(connector_transaction_id) => {
+                query.filter(dsl::connector_transaction_id.eq(connector_transaction_id.to_owned()))
+            }
+            SearchFilter::Reference(reference) => {
+                query.filter(dsl::connector_response_reference_id.eq(reference.to_owned()))
+            }
+            SearchFilter::Last4(last4) => query.filter(
+                dsl::payment_method_data
+                    .retrieve_as_object("card")
+                    .retrieve_as_text("last4")
+                    .eq(last4.to_owned()),
+            ),
+            SearchFilter::Id(_)
+            | SearchFilter::PaymentIds(_)
+            | SearchFilter::CustomerIds(_)
+            | SearchFilter::Metadata(_) => return Ok(Vec::new()),
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error searching payment attempts")
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchDbExt for Refund {
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        filter: SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        use schema::refund::dsl;
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        let query = match filter {
+            SearchFilter::Id(refund_id) => query.filter(dsl::refund_id.eq(refund_id.to_owned())),
+            SearchFilter::PaymentIds(payment_ids) => {
+                query.filter(dsl::payment_id.eq_any(payment_ids.to_vec()))
+            }
+            SearchFilter::ConnectorTransactionId(connector_refund_id) => {
+                query.filter(dsl::connector_refund_id.eq(connector_refund_id.to_owned()))
+            }
+            SearchFilter::Reference(_)
+            | SearchFilter::CustomerIds(_)
+            | SearchFilter::Last4(_)
+            | SearchFilter::Metadata(_) => return Ok(Vec::new()),
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error searching refunds")
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchDbExt for Dispute {
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        filter: SearchFilter<'_>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        use schema::dispute::dsl;
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        let query = match filter {
+            SearchFilter::Id(dispute_id) => query.filter(dsl::dispute_id.eq(dispute_id.to_owned())),
+            SearchFilter::PaymentIds(payment_ids) => {
+                query.filter(dsl::payment_id.eq_any(payment_ids.to_vec()))
+            }
+            SearchFilter::ConnectorTransactionId(connector_dispute_id) => {
+                query.filter(dsl::connector_dispute_id.eq(connector_dispute_id.to_owned()))
+            }
+            SearchFilter::Reference(_)
+            | SearchFilter::CustomerIds(_)
+            | SearchFilter::Last4(_)
+            | SearchFilter::Metadata(_) => return Ok(Vec::new()),
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error searching disputes")
+    }
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::settlement_line::{
+    SettlementLine, SettlementLineNew, SettlementLineUpdate, SettlementLineUpdateInternal,
+};
@@ -0,0 +1,4 @@
+pub use diesel_models::{
+    query::tenant::setup_tenant_schema,
+    tenant::{Tenant, TenantNew, TenantUpdate},
+};
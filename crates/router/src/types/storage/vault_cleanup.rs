@@ -0,0 +1,86 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{
+    associations::HasTable, dsl::exists, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use diesel_models::{
+    enums, errors, payment_method::PaymentMethod, query::generics::db_metrics, schema,
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::connection::PgPooledConn;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VaultCleanupTrackingData {
+    pub merchant_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_before: PrimitiveDateTime,
+}
+
+#[async_trait::async_trait]
+pub trait VaultCleanupDbExt: Sized {
+    /// Lists the payment methods of the merchant holding a vault entry that is no longer linked
+    /// to a customer or a mandate, in the order of their identifiers, starting after the
+    /// identifier provided. A vault entry is orphaned when its payment method was last modified
+    /// before the time provided, is not referenced by any mandate, and either is not active or
+    /// belongs to a customer that no longer exists.
+    async fn list_orphaned_vault_entries(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl VaultCleanupDbExt for PaymentMethod {
+    async fn list_orphaned_vault_entries(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_modified_before: PrimitiveDateTime,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        use schema::{customers, mandate, payment_methods::dsl};
+
+        let mandate_exists = exists(
+            mandate::table
+                .filter(mandate::merchant_id.eq(dsl::merchant_id))
+                .filter(mandate::payment_method_id.eq(dsl::payment_method_id)),
+        );
+        let customer_exists = exists(
+            customers::table
+                .filter(customers::merchant_id.eq(dsl::merchant_id))
+                .filter(customers::customer_id.eq(dsl::customer_id)),
+        );
+
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::locker_id.is_not_null())
+            .filter(dsl::last_modified.lt(last_modified_before))
+            .filter(dsl::connector_mandate_details.is_null())
+            .filter(diesel::dsl::not(mandate_exists))
+            .filter(
+                dsl::status
+                    .ne(enums::PaymentMethodStatus::Active)
+                    .or(diesel::dsl::not(customer_exists)),
+            )
+            .order(dsl::payment_method_id.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(starting_after) = starting_after {
+            query = query.filter(dsl::payment_method_id.gt(starting_after.to_owned()));
+        }
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error listing the orphaned vault entries")
+    }
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::wallet_decryption_key::{
+    WalletDecryptionKey, WalletDecryptionKeyNew, WalletDecryptionKeyUpdate,
+};
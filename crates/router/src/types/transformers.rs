@@ -20,6 +20,7 @@ use super::domain;
 use crate::{
     core::errors,
     headers::{X_CLIENT_SOURCE, X_CLIENT_VERSION, X_PAYMENT_CONFIRM_SOURCE},
+    logger,
     services::authentication::get_header_value_by_key,
     types::{
         api::{self as api_types, routing as routing_types},
@@ -227,6 +228,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             // api_enums::Connector::Datatrans => Self::Datatrans,  added as template code for future use
             api_enums::Connector::Dlocal => Self::Dlocal,
             api_enums::Connector::Ebanx => Self::Ebanx,
+            api_enums::Connector::Externaladapter => Self::Externaladapter,
             api_enums::Connector::Fiserv => Self::Fiserv,
             api_enums::Connector::Forte => Self::Forte,
             api_enums::Connector::Globalpay => Self::Globalpay,
@@ -748,6 +750,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes.as_deref().map(parse_api_key_scopes),
+            allowed_ips: api_key.allowed_ips,
         }
     }
 }
@@ -762,10 +766,30 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes.as_deref().map(parse_api_key_scopes),
+            allowed_ips: api_key.allowed_ips,
         }
     }
 }
 
+/// Parses the scopes stored for an API key, skipping the ones no longer known
+pub fn parse_api_key_scopes(scopes: &[String]) -> Vec<api_models::api_keys::ApiKeyScope> {
+    scopes
+        .iter()
+        .filter_map(|scope| {
+            scope
+                .parse()
+                .map_err(|error| logger::warn!(?error, %scope, "Unknown API key scope"))
+                .ok()
+        })
+        .collect()
+}
+
+/// Encodes the restrictions of an API key for storage, the empty lists lifting the restriction
+pub fn encode_api_key_restriction<T: ToString>(restriction: Vec<T>) -> Option<Vec<String>> {
+    (!restriction.is_empty()).then(|| restriction.iter().map(ToString::to_string).collect())
+}
+
 impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
     for diesel_models::api_keys::ApiKeyUpdate
 {
@@ -775,6 +799,8 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key.scopes.map(encode_api_key_restriction),
+            allowed_ips: api_key.allowed_ips.map(encode_api_key_restriction),
         }
     }
 }
@@ -894,7 +920,16 @@ impl ForeignFrom<storage::FileMetadata> for api_models::files::FileMetadataRespo
 
 impl ForeignFrom<diesel_models::cards_info::CardInfo> for api_models::cards_info::CardInfoResponse {
     fn foreign_from(item: diesel_models::cards_info::CardInfo) -> Self {
+        let co_badged_card_networks = item.is_co_badged().then(|| {
+            item.get_card_networks()
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        });
         Self {
+            co_badged_card_networks,
+            card_funding_type: item.get_card_funding_type(),
+            is_regulated: item.is_regulated,
             card_iin: item.card_iin,
             card_type: item.card_type,
             card_sub_type: item.card_subtype,
@@ -990,6 +1025,12 @@ impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
             unified_message: payment_attempt.unified_message,
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
+            failover_action: payment_attempt
+                .failover_action
+                .and_then(|action| action.parse().ok()),
+            unified_error_details: payment_attempt
+                .unified_error_details
+                .and_then(|details| details.parse_value("UnifiedErrorDetails").ok()),
         }
     }
 }
@@ -1217,6 +1258,9 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            decline_category: value.decline_category.map(|category| category.to_string()),
+            network_advice_code: value.network_advice_code,
+            user_actionable: value.user_actionable,
         }
     }
 }
@@ -1235,6 +1279,11 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            decline_category: value
+                .decline_category
+                .and_then(|category| category.parse().ok()),
+            network_advice_code: value.network_advice_code,
+            user_actionable: value.user_actionable,
         }
     }
 }
@@ -1329,6 +1378,36 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
     }
 }
 
+#[cfg(feature = "olap")]
+impl ForeignFrom<domain::IncomingWebhookArchive>
+    for api_models::webhook_events::IncomingWebhookListItemResponse
+{
+    fn foreign_from(item: domain::IncomingWebhookArchive) -> Self {
+        Self {
+            archive_id: item.archive_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.business_profile_id,
+            connector_name: item.connector_name,
+            merchant_connector_id: item.merchant_connector_id,
+            event_type: item.event_type,
+            created: item.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl ForeignFrom<domain::IncomingWebhookArchive>
+    for api_models::webhook_events::IncomingWebhookRetrieveResponse
+{
+    fn foreign_from(item: domain::IncomingWebhookArchive) -> Self {
+        let body = item.body.clone().into_inner();
+        Self {
+            webhook_information: ForeignFrom::foreign_from(item),
+            body,
+        }
+    }
+}
+
 #[cfg(feature = "olap")]
 impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveResponse {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
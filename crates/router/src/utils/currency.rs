@@ -333,6 +333,7 @@ async fn fetch_forex_rates(
             &state.clone(),
             forex_request,
             Some(FOREX_API_TIMEOUT),
+            None,
             false,
         )
         .await
@@ -389,6 +390,7 @@ pub async fn fallback_fetch_forex_rates(
             &state.clone(),
             fallback_forex_request,
             Some(FOREX_API_TIMEOUT),
+            None,
             false,
         )
         .await
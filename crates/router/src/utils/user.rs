@@ -87,6 +87,7 @@ pub async fn generate_jwt_auth_token(
         user_role.role_id.clone(),
         &state.conf,
         user_role.org_id.clone(),
+        user_role.profile_id.clone(),
     )
     .await?;
     Ok(Secret::new(token))
@@ -105,6 +106,7 @@ pub async fn generate_jwt_auth_token_with_custom_role_attributes(
         role_id,
         &state.conf,
         org_id,
+        None,
     )
     .await?;
     Ok(Secret::new(token))
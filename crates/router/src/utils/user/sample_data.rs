@@ -225,6 +225,10 @@ pub async fn generate_sample_data(
             request_external_three_ds_authentication: None,
             charges: None,
             frm_metadata: Default::default(),
+            tax_details: None,
+            order_id: None,
+            merchant_reference: None,
+            custom_fields: None,
         };
         let payment_attempt = PaymentAttemptBatchNew {
             attempt_id: attempt_id.clone(),
@@ -302,6 +306,7 @@ pub async fn generate_sample_data(
                 updated_by: merchant_from_db.storage_scheme.to_string(),
                 merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
                 charges: None,
+                estimated_arrival_at: None,
             })
         } else {
             None
@@ -46,6 +46,7 @@ impl From<Permission> for user_role_api::Permission {
             Permission::WebhookEventRead => Self::WebhookEventRead,
             Permission::WebhookEventWrite => Self::WebhookEventWrite,
             Permission::PayoutRead => Self::PayoutRead,
+            Permission::PayoutApprove => Self::PayoutApprove,
             Permission::PayoutWrite => Self::PayoutWrite,
         }
     }
@@ -72,6 +73,34 @@ pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
     Ok(())
 }
 
+/// Checks that the business profile a user is being restricted to belongs to the merchant
+pub async fn validate_profile_id(
+    state: &SessionState,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+) -> UserResult<()> {
+    let Some(profile_id) = profile_id else {
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+            "Business profile not found".to_string(),
+        ))?;
+
+    if business_profile.merchant_id != merchant_id {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Business profile not found".to_string(),
+        )))
+        .attach_printable("Business profile belongs to another merchant");
+    }
+
+    Ok(())
+}
+
 pub async fn validate_role_name(
     state: &SessionState,
     role_name: &domain::RoleName,
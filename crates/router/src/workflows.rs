@@ -1,9 +1,29 @@
+pub mod alert_evaluation;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
+pub mod async_confirmation;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+#[cfg(feature = "olap")]
+pub mod bulk_force_sync;
+#[cfg(feature = "olap")]
+pub mod card_export;
+#[cfg(feature = "olap")]
+pub mod connector_migration;
+pub mod customer_redaction;
+#[cfg(feature = "frm")]
+pub mod frm_review_timeout;
+pub mod job;
+pub mod mandate_pre_notification;
+pub mod master_key_rotation;
+pub mod merchant_key_rotation;
+pub mod outbox_relay;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
 pub mod refund_router;
+#[cfg(feature = "olap")]
+pub mod report_generation;
+pub mod scheduled_capture;
 pub mod tokenized_data;
+pub mod vault_cleanup;
@@ -0,0 +1,67 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::alerts,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, AlertEvaluationTrackingData},
+};
+
+/// Evaluates the alert rules of a merchant at the interval of the deployment, until the merchant
+/// has no rules to evaluate
+pub struct AlertEvaluationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AlertEvaluationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: AlertEvaluationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AlertEvaluationTrackingData")?;
+
+        if !alerts::evaluate_alert_rules(state, &tracking_data.merchant_id).await? {
+            info!(merchant_id = %tracking_data.merchant_id, "Merchant has no alert rules enabled");
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let next_evaluation_at = common_utils::date_time::now().saturating_add(
+            time::Duration::seconds(i64::from(state.conf.alerting.evaluation_interval_in_secs)),
+        );
+        db.as_scheduler()
+            .reset_process(process, next_evaluation_at)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+
+        // The evaluation is attempted again at the next interval rather than given up on
+        let next_evaluation_at = common_utils::date_time::now().saturating_add(
+            time::Duration::seconds(i64::from(state.conf.alerting.evaluation_interval_in_secs)),
+        );
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, next_evaluation_at)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::payments::async_confirmation,
+    errors,
+    logger::{error, warn},
+    routes::SessionState,
+    types::storage::{self, AsyncConfirmationTrackingData},
+};
+
+pub struct AsyncConfirmationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AsyncConfirmationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: AsyncConfirmationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AsyncConfirmationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payments_response = Box::pin(async_confirmation::confirm_scheduled_payment(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            &tracking_data,
+        ))
+        .await;
+
+        let business_status = match payments_response {
+            Ok(Some(_)) => business_status::COMPLETED_BY_PT,
+            // The payment was confirmed or cancelled by the merchant in the meantime
+            Ok(None) => business_status::RESOURCE_STATUS_MISMATCH,
+            Err(error)
+                if matches!(
+                    error.current_context().error_type(),
+                    errors::ErrorType::ServerNotAvailable | errors::ErrorType::LockTimeout
+                ) && process.retry_count
+                    < async_confirmation::MAX_ASYNC_CONFIRMATION_RETRIES =>
+            {
+                warn!(?error, payment_id = %tracking_data.payment_id, "Retrying the confirmation");
+                let schedule_time = common_utils::date_time::now().saturating_add(
+                    async_confirmation::ASYNC_CONFIRMATION_RETRY_DELAY * (process.retry_count + 1),
+                );
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => {
+                error!(?error, payment_id = %tracking_data.payment_id, "Failed to confirm");
+                Box::pin(async_confirmation::fail_scheduled_payment(
+                    state,
+                    state.get_req_state(),
+                    merchant_account,
+                    key_store,
+                    &tracking_data,
+                    error.current_context().to_string(),
+                ))
+                .await?;
+                business_status::FAILURE
+            }
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
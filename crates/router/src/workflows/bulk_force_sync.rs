@@ -0,0 +1,67 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::bulk_force_sync,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums, BulkForceSyncTrackingData},
+};
+
+pub struct BulkForceSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for BulkForceSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: BulkForceSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BulkForceSyncTrackingData")?;
+
+        let summary = bulk_force_sync::run_bulk_force_sync(state, &tracking_data).await?;
+        info!(
+            %process.id,
+            found_count = summary.found_count,
+            synced_count = summary.synced_count,
+            resolved_count = summary.resolved_count,
+            failed_count = summary.failed_count,
+            "Force synced the stuck payments"
+        );
+
+        // The summary is kept in the tracking data of the task, to be retrieved through the API
+        tracking_data.summary = Some(summary);
+        let tracking_data = tracking_data.encode_to_value()?;
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(tracking_data),
+                    business_status: Some(String::from(storage::business_status::COMPLETED_BY_PT)),
+                    status: Some(enums::ProcessTrackerStatus::Finish),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::card_export,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums, CardExportTrackingData},
+};
+
+pub struct CardExportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: CardExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardExportTrackingData")?;
+
+        let card_export_batch = db
+            .find_card_export_batch_by_merchant_id_export_id(
+                &tracking_data.merchant_id,
+                &tracking_data.export_id,
+            )
+            .await?;
+        if card_export_batch.status != enums::CardExportStatus::Processing {
+            info!(
+                export_id = %tracking_data.export_id,
+                status = %card_export_batch.status,
+                "Card export is not awaiting processing"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        card_export::run_card_export(state, card_export_batch).await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::connector_migration,
+    errors,
+    logger::{error, info, warn},
+    routes::SessionState,
+    types::storage::{self, enums, ConnectorMigrationTrackingData},
+};
+
+pub struct ConnectorMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ConnectorMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ConnectorMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorMigrationTrackingData")?;
+
+        let migration = db
+            .find_connector_migration_by_merchant_id_migration_id(
+                &tracking_data.merchant_id,
+                &tracking_data.migration_id,
+            )
+            .await?;
+        if migration.status != enums::ConnectorMigrationStatus::Processing {
+            info!(
+                migration_id = %tracking_data.migration_id,
+                status = %migration.status,
+                "Connector migration is not being processed"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let business_status = match Box::pin(connector_migration::run_connector_migration(
+            state,
+            &merchant_account,
+            &key_store,
+            migration.clone(),
+        ))
+        .await
+        {
+            // The next page of payment methods is migrated by the next run of the task
+            Ok(migration) if migration.status == enums::ConnectorMigrationStatus::Processing => {
+                db.as_scheduler()
+                    .reset_process(process, common_utils::date_time::now())
+                    .await?;
+                return Ok(());
+            }
+            Ok(_) => storage::business_status::COMPLETED_BY_PT,
+            Err(error)
+                if process.retry_count < connector_migration::MAX_CONNECTOR_MIGRATION_RETRIES =>
+            {
+                warn!(?error, migration_id = %tracking_data.migration_id, "Retrying the migration");
+                let schedule_time = common_utils::date_time::now().saturating_add(
+                    connector_migration::CONNECTOR_MIGRATION_RETRY_DELAY
+                        * (process.retry_count + 1),
+                );
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => {
+                error!(?error, migration_id = %tracking_data.migration_id, "Failed to migrate");
+                connector_migration::fail_connector_migration(
+                    state,
+                    migration,
+                    error.current_context().to_string(),
+                )
+                .await?;
+                storage::business_status::FAILURE
+            }
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{utils as pt_utils, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{customers, webhooks as webhooks_core},
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::{
+        api,
+        storage::{self, enums, CustomerRedactionTrackingData},
+    },
+};
+
+/// Intervals in seconds at which a failed redaction is retried, along with the number of retries
+/// made at each interval
+const CUSTOMER_REDACTION_RETRY_FREQUENCIES: [(i32, i32); 2] = [(60 * 5, 3), (60 * 60, 5)];
+
+pub struct CustomerRedactionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CustomerRedactionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: CustomerRedactionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerRedactionTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        customers::run_customer_redaction(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.customer_id,
+        )
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process.clone(), business_status::COMPLETED_BY_PT)
+            .await?;
+
+        // The redaction has completed by now, so failures to raise the webhook are not handled
+        // as failures of the redaction, the delivery of the webhook being retried on its own
+
+        let Some(profile_id) = merchant_account.default_profile.clone() else {
+            info!(%process.id, "No default business profile to send the redaction webhook to");
+            return Ok(());
+        };
+        let business_profile = match db.find_business_profile_by_profile_id(&profile_id).await {
+            Ok(business_profile) => business_profile,
+            Err(error) => {
+                error!(?error, %process.id, "Failed to fetch the business profile of the webhook");
+                return Ok(());
+            }
+        };
+
+        let mut redaction_response =
+            customers::get_customer_redaction_response(&process, tracking_data.customer_id);
+        redaction_response.status = api::customers::CustomerRedactionStatus::Completed;
+        redaction_response.completed_at = Some(common_utils::date_time::now());
+
+        if let Err(error) = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account,
+            business_profile,
+            &key_store,
+            enums::EventType::CustomerRedacted,
+            enums::EventClass::Customers,
+            redaction_response.customer_id.get_string_repr().to_owned(),
+            enums::EventObjectType::CustomerRedactionDetails,
+            api::OutgoingWebhookContent::CustomerRedactionDetails(Box::new(redaction_response)),
+            Some(process.created_at),
+        ))
+        .await
+        {
+            error!(?error, %process.id, "Failed to raise the customer redaction webhook");
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+
+        let db = state.store.as_scheduler();
+        let delay = pt_utils::get_delay(
+            process.retry_count + 1,
+            &CUSTOMER_REDACTION_RETRY_FREQUENCIES,
+        );
+        match pt_utils::get_time_from_delta(delay) {
+            Some(schedule_time) => db.retry_process(process, schedule_time).await?,
+            None => {
+                db.finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::fraud_check::post_auth_review::{self, PostAuthReviewResolution},
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, FrmReviewTimeoutTrackingData},
+};
+
+pub struct FrmReviewTimeoutWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for FrmReviewTimeoutWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: FrmReviewTimeoutTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("FrmReviewTimeoutTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payments_response = Box::pin(post_auth_review::resolve_post_auth_review(
+            state,
+            state.get_req_state(),
+            &merchant_account,
+            &key_store,
+            &tracking_data.payment_id,
+            PostAuthReviewResolution::TimedOut(tracking_data.timeout_action),
+            Some("Timed out awaiting the verdict of the FRM connector".to_string()),
+        ))
+        .await?;
+
+        // The review was resolved before the timeout elapsed
+        let business_status = match payments_response {
+            Some(_) => business_status::COMPLETED_BY_PT,
+            None => business_status::RESOURCE_STATUS_MISMATCH,
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
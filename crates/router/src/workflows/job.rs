@@ -0,0 +1,103 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::jobs,
+    errors,
+    logger::{error, info, warn},
+    routes::SessionState,
+    types::storage::{self, enums, JobTrackingData},
+};
+
+pub struct JobWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for JobWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: JobTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("JobTrackingData")?;
+
+        let job = db
+            .find_job_by_merchant_id_job_id(&tracking_data.merchant_id, &tracking_data.job_id)
+            .await?;
+        if !matches!(
+            job.status,
+            enums::JobStatus::Queued | enums::JobStatus::Running
+        ) {
+            info!(job_id = %job.job_id, status = %job.status, "Job is no longer active");
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let business_status = match Box::pin(jobs::run_job(
+            state,
+            &merchant_account,
+            &key_store,
+            job.clone(),
+        ))
+        .await
+        {
+            // The next batch of the job is run by the next run of the task
+            Ok(job) if job.status == enums::JobStatus::Running => {
+                db.as_scheduler()
+                    .reset_process(process, common_utils::date_time::now())
+                    .await?;
+                return Ok(());
+            }
+            Ok(job) if job.status == enums::JobStatus::Cancelled => {
+                storage::business_status::REVOKED
+            }
+            Ok(_) => storage::business_status::COMPLETED_BY_PT,
+            Err(error) if job.attempt_count + 1 < job.max_attempts => {
+                warn!(?error, job_id = %job.job_id, "Retrying the job");
+                let job = jobs::retry_job(state, job, error.current_context().to_string()).await?;
+                let schedule_time = common_utils::date_time::now()
+                    .saturating_add(jobs::JOB_RETRY_DELAY * job.attempt_count);
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => {
+                error!(?error, job_id = %job.job_id, "Failed to run the job");
+                jobs::fail_job(state, job, error.current_context().to_string()).await?;
+                storage::business_status::FAILURE
+            }
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{mandate::helpers as mandate_helpers, webhooks as webhooks_core},
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::{
+        api::{self, mandates::MandateResponseExt},
+        storage::{self, enums, MandatePreNotificationTrackingData},
+    },
+};
+
+pub struct MandatePreNotificationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MandatePreNotificationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: MandatePreNotificationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MandatePreNotificationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                &tracking_data.merchant_id,
+                &tracking_data.mandate_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        if mandate.mandate_status != enums::MandateStatus::Active {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let profile_id = mandate_helpers::get_profile_id_for_mandate(
+            state,
+            &merchant_account,
+            &key_store,
+            mandate.clone(),
+        )
+        .await?;
+
+        let business_profile = db.find_business_profile_by_profile_id(&profile_id).await?;
+
+        let mandate_response = api::mandates::MandateResponse::from_db_mandate(
+            state,
+            key_store.clone(),
+            mandate.clone(),
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        // The debit date is a part of the primary object id, so that the events raised for each
+        // of the upcoming debits on the mandate are considered to be distinct
+        let primary_object_id = format!(
+            "{}_{}",
+            mandate.mandate_id,
+            tracking_data.debit_date.assume_utc().unix_timestamp()
+        );
+
+        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account,
+            business_profile,
+            &key_store,
+            enums::EventType::MandatePreNotification,
+            enums::EventClass::Mandates,
+            primary_object_id,
+            enums::EventObjectType::MandateDetails,
+            api::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+            Some(mandate.created_at),
+        ))
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::master_key_rotation,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums, MasterKeyRotationTrackingData},
+};
+
+pub struct MasterKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MasterKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: MasterKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MasterKeyRotationTrackingData")?;
+
+        let mut process = process;
+        loop {
+            let is_completed =
+                master_key_rotation::rotate_merchant_key_stores_batch(state, &mut tracking_data)
+                    .await?;
+
+            // The progress is kept in the tracking data of the task after each batch, for it to
+            // be retrieved through the API and for the job to resume after the last key store
+            // processed when retried
+            process = db
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(tracking_data.encode_to_value()?),
+                        business_status: is_completed
+                            .then(|| String::from(storage::business_status::COMPLETED_BY_PT)),
+                        status: is_completed.then_some(enums::ProcessTrackerStatus::Finish),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+
+            if is_completed {
+                break;
+            }
+        }
+
+        info!(
+            %process.id,
+            rotated_count = tracking_data.summary.rotated_count,
+            failed_count = tracking_data.summary.failed_count,
+            "Re-encrypted the merchant key stores with the master key"
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::merchant_key_rotation,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, enums, MerchantKeyRotationTrackingData},
+};
+
+pub struct MerchantKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MerchantKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: MerchantKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyRotationTrackingData")?;
+
+        let mut process = process;
+        loop {
+            let is_completed =
+                merchant_key_rotation::reencrypt_merchant_data_batch(state, &mut tracking_data)
+                    .await?;
+
+            // The key rotated out is only discarded once every record has been re-encrypted, the
+            // records which failed to be still needing it to be decrypted
+            let business_status = if !is_completed {
+                None
+            } else if tracking_data.summary.failed_count == 0 {
+                merchant_key_rotation::complete_merchant_key_rotation(
+                    state,
+                    &tracking_data.merchant_id,
+                )
+                .await?;
+                Some(String::from(storage::business_status::COMPLETED_BY_PT))
+            } else {
+                Some(String::from(storage::business_status::FAILURE))
+            };
+
+            // The progress is kept in the tracking data of the task after each batch, for it to
+            // be retrieved through the API and for the job to resume after the last record
+            // processed when retried
+            process = db
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(tracking_data.encode_to_value()?),
+                        business_status,
+                        status: is_completed.then_some(enums::ProcessTrackerStatus::Finish),
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+
+            if is_completed {
+                break;
+            }
+        }
+
+        info!(
+            %process.id,
+            merchant_id = %tracking_data.merchant_id,
+            reencrypted_count = tracking_data.summary.reencrypted_count,
+            failed_count = tracking_data.summary.failed_count,
+            "Re-encrypted the data of the merchant with the new merchant key"
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use api_models::{
+    payments::{HeaderPayload, PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
+    webhooks::OutgoingWebhookContent,
+};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{outbox::OutboxEventTrackingData, process_tracker::business_status};
+use error_stack::report;
+use router_env::tracing::{self, instrument};
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        payments::{payments_core, CallConnectorAction, PaymentStatus},
+        webhooks as webhooks_core,
+    },
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    services::{ApplicationResponse, AuthFlow},
+    types::{
+        api::PSync,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Number of times the relay of an outbox event is retried before giving up
+const MAX_OUTBOX_RELAY_RETRIES: i32 = 5;
+
+/// Delay before the relay of an outbox event is retried, multiplied by the number of retries
+const OUTBOX_RELAY_RETRY_DELAY: time::Duration = time::Duration::minutes(1);
+
+/// Relays an outgoing webhook event of the outbox, raising the event unless it was raised already
+pub struct OutboxRelayWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for OutboxRelayWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: OutboxEventTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutboxEventTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_id = tracking_data.primary_object_id.clone();
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The event raised by a status the payment no longer is in is stale, the current status
+        // of the payment has an outbox task of its own
+        if payment_intent.status != tracking_data.status {
+            info!(%payment_id, "Skipping the outbox event of a superseded payment status");
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+            error!(%payment_id, "Missing business profile of the payment of the outbox event");
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::FAILURE)
+                .await?;
+            return Ok(());
+        };
+        let business_profile = db.find_business_profile_by_profile_id(profile_id).await?;
+
+        let request = PaymentsRetrieveRequest {
+            resource_id: PaymentIdType::PaymentIntentId(payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        };
+        let payments_response = match Box::pin(payments_core::<PSync, PaymentsResponse, _, _, _>(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            PaymentStatus,
+            request,
+            AuthFlow::Merchant,
+            CallConnectorAction::Avoid,
+            None,
+            HeaderPayload::default(),
+        ))
+        .await?
+        {
+            ApplicationResponse::Json(payments_response)
+            | ApplicationResponse::JsonWithHeaders((payments_response, _)) => Ok(payments_response),
+            ApplicationResponse::StatusOk
+            | ApplicationResponse::TextPlain(_)
+            | ApplicationResponse::JsonForRedirection(_)
+            | ApplicationResponse::Form(_)
+            | ApplicationResponse::GenericLinkForm(_)
+            | ApplicationResponse::PaymentLinkForm(_)
+            | ApplicationResponse::FileData(_) => {
+                Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                    resource_name: payment_id.clone(),
+                })
+            }
+        }?;
+
+        if let Some(event_type) = Option::<enums::EventType>::foreign_from(payments_response.status)
+        {
+            let primary_object_created_at = payments_response.created;
+            // The event is not raised again if the application raised it already, the events being
+            // unique by the payment and the event type
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account,
+                business_profile,
+                &key_store,
+                event_type,
+                enums::EventClass::Payments,
+                payment_id,
+                enums::EventObjectType::PaymentDetails,
+                OutgoingWebhookContent::PaymentDetails(payments_response),
+                primary_object_created_at,
+            ))
+            .await?;
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retries the relay with a linear backoff, as the event would otherwise never be raised
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed to relay the outbox event");
+        if process.retry_count < MAX_OUTBOX_RELAY_RETRIES {
+            let schedule_time = common_utils::date_time::now()
+                .saturating_add(OUTBOX_RELAY_RETRY_DELAY * (process.retry_count + 1));
+            state
+                .store
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .map_err(|error| report!(errors::ProcessTrackerError::from(error)))
+        } else {
+            consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+        }
+    }
+}
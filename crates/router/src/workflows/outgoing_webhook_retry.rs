@@ -164,7 +164,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_account,
                             outgoing_webhook,
-                            business_profile.payment_response_hash_key.as_deref(),
+                            &business_profile,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -333,6 +333,7 @@ async fn get_outgoing_webhook_content_and_event_type(
 
     use crate::{
         core::{
+            customers::retrieve_customer_redaction,
             disputes::retrieve_dispute,
             mandate::get_mandate,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
@@ -340,7 +341,10 @@ async fn get_outgoing_webhook_content_and_event_type(
         },
         services::{ApplicationResponse, AuthFlow},
         types::{
-            api::{DisputeId, PSync},
+            api::{
+                customers::{CustomerId, CustomerRedactionStatus},
+                DisputeId, PSync,
+            },
             transformers::ForeignFrom,
         },
     };
@@ -410,7 +414,12 @@ async fn get_outgoing_webhook_content_and_event_type(
                 request,
             ))
             .await?;
-            let event_type = Option::<EventType>::foreign_from(refund.refund_status);
+            // The details of the refund are sent again as they are now, whatever its status
+            let event_type = if tracking_data.event_type == EventType::RefundUpdated {
+                Some(EventType::RefundUpdated)
+            } else {
+                Option::<EventType>::foreign_from(refund.refund_status)
+            };
             logger::debug!(current_resource_status=%refund.refund_status);
             let refund_response = RefundResponse::foreign_from(refund);
 
@@ -511,5 +520,43 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::Customers => {
+            let customer_id = common_utils::id_type::CustomerId::from(
+                tracking_data.primary_object_id.clone().into(),
+            )
+            .map_err(|_| errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })?;
+            let request = CustomerId { customer_id };
+
+            let redaction_response =
+                match retrieve_customer_redaction(state, merchant_account, request).await? {
+                    ApplicationResponse::Json(redaction_response)
+                    | ApplicationResponse::JsonWithHeaders((redaction_response, _)) => {
+                        Ok(redaction_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+            let event_type = (redaction_response.status == CustomerRedactionStatus::Completed)
+                .then_some(EventType::CustomerRedacted);
+            logger::debug!(current_resource_status=?redaction_response.status);
+
+            Ok((
+                OutgoingWebhookContent::CustomerRedactionDetails(redaction_response),
+                event_type,
+            ))
+        }
     }
 }
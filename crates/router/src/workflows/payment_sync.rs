@@ -1,15 +1,16 @@
-use common_utils::ext_traits::{OptionExt, StringExt, ValueExt};
+use common_utils::ext_traits::{OptionExt, ValueExt};
 use diesel_models::process_tracker::business_status;
 use error_stack::ResultExt;
 use router_env::logger;
 use scheduler::{
-    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
-    errors as sch_errors, utils as scheduler_utils,
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
 };
 
 use crate::{
     consts,
     core::{
+        connector_polling,
         errors::StorageErrorExt,
         payments::{self as payment_flows, operations},
     },
@@ -100,10 +101,11 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
                     .clone()
                     .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
 
-                let is_last_retry = retry_sync_task(
+                let is_last_retry = connector_polling::retry_polling_task(
                     db,
-                    connector,
-                    payment_data.payment_attempt.merchant_id.clone(),
+                    connector_polling::PollingObject::Payment,
+                    &connector,
+                    &payment_data.payment_attempt.merchant_id,
                     process,
                 )
                 .await?;
@@ -132,6 +134,7 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
                             updated_by: merchant_account.storage_scheme.to_string(),
                             unified_code: None,
                             unified_message: None,
+                            unified_error_details: None,
                             connector_transaction_id: None,
                             payment_method_data: None,
                             authentication_type: None,
@@ -202,103 +205,3 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
         consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
     }
 }
-
-/// Get the next schedule time
-///
-/// The schedule time can be configured in configs by this key `pt_mapping_trustpay`
-/// ```json
-/// {
-///     "default_mapping": {
-///         "start_after": 60,
-///         "frequency": [300],
-///         "count": [5]
-///     },
-///     "max_retries_count": 5
-/// }
-/// ```
-///
-/// This config represents
-///
-/// `start_after`: The first psync should happen after 60 seconds
-///
-/// `frequency` and `count`: The next 5 retries should have an interval of 300 seconds between them
-///
-pub async fn get_sync_process_schedule_time(
-    db: &dyn StorageInterface,
-    connector: &str,
-    merchant_id: &str,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let mapping: common_utils::errors::CustomResult<
-        process_data::ConnectorPTMapping,
-        errors::StorageError,
-    > = db
-        .find_config_by_key(&format!("pt_mapping_{connector}"))
-        .await
-        .map(|value| value.config)
-        .and_then(|config| {
-            config
-                .parse_struct("ConnectorPTMapping")
-                .change_context(errors::StorageError::DeserializationFailed)
-        });
-    let mapping = match mapping {
-        Ok(x) => x,
-        Err(error) => {
-            logger::info!(?error, "Redis Mapping Error");
-            process_data::ConnectorPTMapping::default()
-        }
-    };
-    let time_delta = scheduler_utils::get_schedule_time(mapping, merchant_id, retry_count);
-
-    Ok(scheduler_utils::get_time_from_delta(time_delta))
-}
-
-/// Schedule the task for retry
-///
-/// Returns bool which indicates whether this was the last retry or not
-pub async fn retry_sync_task(
-    db: &dyn StorageInterface,
-    connector: String,
-    merchant_id: String,
-    pt: storage::ProcessTracker,
-) -> Result<bool, sch_errors::ProcessTrackerError> {
-    let schedule_time =
-        get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count + 1).await?;
-
-    match schedule_time {
-        Some(s_time) => {
-            db.as_scheduler().retry_process(pt, s_time).await?;
-            Ok(false)
-        }
-        None => {
-            db.as_scheduler()
-                .finish_process_with_business_status(pt, business_status::RETRIES_EXCEEDED)
-                .await?;
-            Ok(true)
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    #![allow(clippy::expect_used, clippy::unwrap_used)]
-    use super::*;
-
-    #[test]
-    fn test_get_default_schedule_time() {
-        let schedule_time_delta =
-            scheduler_utils::get_schedule_time(process_data::ConnectorPTMapping::default(), "-", 0)
-                .unwrap();
-        let first_retry_time_delta =
-            scheduler_utils::get_schedule_time(process_data::ConnectorPTMapping::default(), "-", 1)
-                .unwrap();
-        let cpt_default = process_data::ConnectorPTMapping::default().default_mapping;
-        assert_eq!(
-            vec![schedule_time_delta, first_retry_time_delta],
-            vec![
-                cpt_default.start_after,
-                cpt_default.frequencies.first().unwrap().0
-            ]
-        );
-    }
-}
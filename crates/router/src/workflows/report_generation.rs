@@ -0,0 +1,84 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::reports,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, ReportGenerationTrackingData},
+};
+
+pub struct ReportGenerationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReportGenerationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ReportGenerationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReportGenerationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let report_template = match db
+            .find_report_template_by_merchant_id_template_id(
+                &tracking_data.merchant_id,
+                &tracking_data.template_id,
+            )
+            .await
+        {
+            Ok(report_template) if report_template.is_active => report_template,
+            Ok(_) => {
+                info!(template_id = %tracking_data.template_id, "Report template is inactive");
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                    .await
+                    .map_err(Into::<errors::ProcessTrackerError>::into);
+            }
+            Err(error) if error.current_context().is_db_not_found() => {
+                info!(template_id = %tracking_data.template_id, "Report template was deleted");
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                    .await
+                    .map_err(Into::<errors::ProcessTrackerError>::into);
+            }
+            Err(error) => Err(error)?,
+        };
+
+        let report_template =
+            reports::run_scheduled_report(state, &merchant_account, &key_store, report_template)
+                .await?;
+
+        db.as_scheduler()
+            .reset_process(process, report_template.next_run_at)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
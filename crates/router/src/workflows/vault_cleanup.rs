@@ -0,0 +1,52 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::vault_tokens,
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage::{self, VaultCleanupTrackingData},
+};
+
+pub struct VaultCleanupWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for VaultCleanupWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: VaultCleanupTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("VaultCleanupTrackingData")?;
+
+        let summary = vault_tokens::run_vault_cleanup(state, &tracking_data).await?;
+        info!(
+            %process.id,
+            merchant_id = %tracking_data.merchant_id,
+            deleted_count = summary.deleted_count,
+            failed_count = summary.failed_count,
+            "Cleaned up the orphaned vault entries"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -973,6 +973,7 @@ impl Default for BrowserInfoType {
             java_enabled: Some(true),
             java_script_enabled: Some(true),
             ip_address: Some("127.0.0.1".parse().unwrap()),
+            device_fingerprint: None,
         };
         Self(data)
     }
@@ -16,8 +16,14 @@ pub use types::{Category, Flow, Level, Tag};
 mod setup;
 pub use setup::{setup, TelemetryGuard};
 
+pub mod propagation;
+pub use propagation::get_trace_context_headers;
+
 pub mod formatter;
 pub use formatter::FormattingLayer;
 
 pub mod storage;
 pub use storage::{Storage, StorageSubscription};
+
+pub mod reload;
+pub use reload::SAMPLED_REQUESTS_TARGET;
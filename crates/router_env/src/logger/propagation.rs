@@ -0,0 +1,17 @@
+//! Propagation of the trace context to the services called by the application.
+
+use std::collections::HashMap;
+
+use opentelemetry::global;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Returns the headers carrying the trace context of the current span, such as the `traceparent`
+/// header of the W3C trace context propagator, to be attached to outgoing HTTP requests.
+///
+/// No headers are returned when the current span is not sampled or when tracing is disabled.
+pub fn get_trace_context_headers() -> Vec<(String, String)> {
+    let context = tracing::Span::current().context();
+    let mut headers = HashMap::new();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&context, &mut headers));
+    headers.into_iter().collect()
+}
@@ -0,0 +1,182 @@
+//!
+//! Changing the log levels at runtime, without restarting the application.
+//!
+
+use std::{collections::BTreeMap, fmt, sync::RwLock};
+
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use tracing_subscriber::{reload, EnvFilter};
+
+/// Target of the logs of the requests sampled at runtime. The target is enabled at the `INFO`
+/// level whatever the log levels, nothing being logged under it while no request is sampled.
+pub const SAMPLED_REQUESTS_TARGET: &str = "sampled_requests";
+
+/// The outputs the logs are written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogOutput {
+    /// Logging to a console.
+    Console,
+    /// Logging to a file.
+    File,
+}
+
+/// The filter of the logs written to an output, as currently applied.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogFilterStatus {
+    /// The output the filter applies to.
+    pub output: LogOutput,
+    /// The filtering directive the output was set up with.
+    pub initial_directive: String,
+    /// The filtering directive currently applied.
+    pub directive: String,
+}
+
+/// Errors changing the log levels.
+#[derive(Debug)]
+pub enum LogLevelError {
+    /// The logging subsystem was not set up with reloadable filters.
+    NotReloadable,
+    /// The level of a target is not a valid level.
+    InvalidLevel {
+        /// The target whose level is invalid.
+        target: String,
+        /// The level provided.
+        level: String,
+    },
+    /// The filter could not be replaced.
+    ReloadFailed(String),
+}
+
+impl fmt::Display for LogLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotReloadable => write!(f, "Log levels cannot be changed at runtime"),
+            Self::InvalidLevel { target, level } => {
+                write!(f, "Invalid log level `{level}` for target `{target}`")
+            }
+            Self::ReloadFailed(error) => write!(f, "Failed to change the log levels: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LogLevelError {}
+
+type ReloadFn = Box<dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync>;
+
+/// A filter of the logs written to an output which can be replaced at runtime.
+pub(super) struct ReloadableFilter {
+    output: LogOutput,
+    initial_directive: String,
+    reload: ReloadFn,
+}
+
+impl fmt::Debug for ReloadableFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReloadableFilter")
+            .field("output", &self.output)
+            .field("initial_directive", &self.initial_directive)
+            .finish()
+    }
+}
+
+impl ReloadableFilter {
+    pub(super) fn new<S: 'static>(
+        output: LogOutput,
+        filter: EnvFilter,
+    ) -> (reload::Layer<EnvFilter, S>, Self) {
+        let initial_directive = filter.to_string();
+        let (layer, handle) = reload::Layer::new(filter);
+        (
+            layer,
+            Self {
+                output,
+                initial_directive,
+                reload: Box::new(move |filter| handle.reload(filter)),
+            },
+        )
+    }
+
+    fn get_directive(&self, levels: &BTreeMap<String, String>) -> String {
+        levels.iter().fold(
+            self.initial_directive.clone(),
+            |directive, (target, level)| format!("{directive},{target}={level}"),
+        )
+    }
+}
+
+static RELOADABLE_FILTERS: OnceCell<Vec<ReloadableFilter>> = OnceCell::new();
+
+/// The levels of the targets set at runtime, on top of the directives the outputs were set up with
+static RUNTIME_LEVELS: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+pub(super) fn set_reloadable_filters(filters: Vec<ReloadableFilter>) {
+    // The logging subsystem is set up once, later calls being ignored as `init()` would be
+    let _ = RELOADABLE_FILTERS.set(filters);
+}
+
+/// The levels of the targets set at runtime.
+pub fn get_runtime_log_levels() -> BTreeMap<String, String> {
+    RUNTIME_LEVELS
+        .read()
+        .map(|levels| levels.clone())
+        .unwrap_or_default()
+}
+
+/// The filters of the logs of each output, with the levels set at runtime applied.
+pub fn get_log_filters() -> Vec<LogFilterStatus> {
+    let levels = get_runtime_log_levels();
+    RELOADABLE_FILTERS
+        .get()
+        .map(|filters| {
+            filters
+                .iter()
+                .map(|filter| LogFilterStatus {
+                    output: filter.output,
+                    initial_directive: filter.initial_directive.clone(),
+                    directive: filter.get_directive(&levels),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Sets the levels of the targets, a target being a crate or a module such as
+/// `router::core::payments`, and a level being one of `trace`, `debug`, `info`, `warn`, `error`
+/// and `off`. The levels previously set at runtime are replaced, no levels restoring the levels
+/// the outputs were set up with.
+pub fn set_runtime_log_levels(levels: BTreeMap<String, String>) -> Result<(), LogLevelError> {
+    let filters = RELOADABLE_FILTERS
+        .get()
+        .ok_or(LogLevelError::NotReloadable)?;
+
+    let levels = levels
+        .into_iter()
+        .map(|(target, level)| {
+            let level = level.to_lowercase();
+            if (level != "off" && level.parse::<tracing::Level>().is_err())
+                || target.is_empty()
+                || target.contains([',', '=', '[', ']', '{', '}'])
+            {
+                Err(LogLevelError::InvalidLevel { target, level })
+            } else {
+                Ok((target, level))
+            }
+        })
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+    let mut runtime_levels = RUNTIME_LEVELS
+        .write()
+        .map_err(|error| LogLevelError::ReloadFailed(error.to_string()))?;
+    for filter in filters {
+        let env_filter = EnvFilter::builder()
+            .parse(filter.get_directive(&levels))
+            .map_err(|error| LogLevelError::ReloadFailed(error.to_string()))?;
+        (filter.reload)(env_filter)
+            .map_err(|error| LogLevelError::ReloadFailed(error.to_string()))?;
+    }
+    *runtime_levels = levels;
+
+    Ok(())
+}
@@ -20,6 +20,7 @@ use serde_json::ser::{CompactFormatter, PrettyFormatter};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer};
 
+use super::reload::{self, LogOutput, ReloadableFilter};
 use crate::{config, FormattingLayer, StorageSubscription};
 
 /// Contains guards necessary for logging and metrics collection.
@@ -38,6 +39,7 @@ pub fn setup(
     crates_to_filter: impl AsRef<[&'static str]>,
 ) -> TelemetryGuard {
     let mut guards = Vec::new();
+    let mut reloadable_filters = Vec::new();
 
     // Setup OpenTelemetry traces and metrics
     let traces_layer = if config.telemetry.traces_enabled {
@@ -69,6 +71,8 @@ pub fn setup(
             &crates_to_filter,
         );
         println!("Using file logging filter: {file_filter}");
+        let (file_filter, reloadable_filter) = ReloadableFilter::new(LogOutput::File, file_filter);
+        reloadable_filters.push(reloadable_filter);
 
         Some(
             FormattingLayer::new(service_name, file_writer, CompactFormatter)
@@ -95,6 +99,9 @@ pub fn setup(
             &crates_to_filter,
         );
         println!("Using console logging filter: {console_filter}");
+        let (console_filter, reloadable_filter) =
+            ReloadableFilter::new(LogOutput::Console, console_filter);
+        reloadable_filters.push(reloadable_filter);
 
         match config.console.log_format {
             config::LogFormat::Default => {
@@ -123,6 +130,7 @@ pub fn setup(
     } else {
         subscriber.init();
     };
+    reload::set_reloadable_filters(reloadable_filters);
 
     // Returning the TelemetryGuard for logs to be printed and metrics to be collected until it is
     // dropped
@@ -323,12 +331,20 @@ fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicControll
     }
 }
 
+/// The filter of the logs of an output, with the logs of the sampled requests always enabled
 fn get_envfilter(
     filtering_directive: Option<&String>,
     default_log_level: config::Level,
     filter_log_level: config::Level,
     crates_to_filter: impl AsRef<[&'static str]>,
 ) -> EnvFilter {
+    // Safety: This is a hardcoded basic filtering directive. If even the basic filter is wrong,
+    // it's better to panic.
+    #[allow(clippy::expect_used)]
+    let sampled_requests_directive = format!("{}=info", reload::SAMPLED_REQUESTS_TARGET)
+        .parse()
+        .expect("Invalid EnvFilter directive format");
+
     filtering_directive
         .map(|filter| {
             // Try to create target filter from specified filtering directive, if set
@@ -363,4 +379,5 @@ fn get_envfilter(
                     },
                 )
         })
+        .add_directive(sampled_requests_directive)
 }
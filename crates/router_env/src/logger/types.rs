@@ -61,6 +61,8 @@ pub enum Flow {
     HealthCheck,
     /// Deep health Check
     DeepHealthCheck,
+    /// Health check reporting the status of each dependency
+    DetailedHealthCheck,
     /// Merchants account create flow.
     MerchantsAccountCreate,
     /// Merchants account retrieve flow.
@@ -109,6 +111,10 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Mandates migration flow.
+    MandatesMigrate,
+    /// Mandates pre-notification flow.
+    MandatesPreNotification,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods list flow.
@@ -131,6 +137,10 @@ pub enum Flow {
     PaymentMethodsDelete,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
+    /// Payment method order set flow.
+    PaymentMethodOrderSet,
+    /// Payment method usage restrictions set flow.
+    PaymentMethodUsageRestrictionsSet,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -200,6 +210,8 @@ pub enum Flow {
     RefundsList,
     /// Refunds filters flow
     RefundsFilters,
+    /// Refunds create bulk flow.
+    RefundsCreateBulk,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -238,6 +250,12 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Add record to allowlist
+    AddToAllowlist,
+    /// Delete record from allowlist
+    DeleteFromAllowlist,
+    /// List entries from allowlist
+    ListAllowlist,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Validate payment method flow
@@ -276,6 +294,12 @@ pub enum Flow {
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// In-memory caches list flow
+    InMemoryCacheList,
+    /// In-memory cache key retrieve flow
+    InMemoryCacheKeyRetrieve,
+    /// In-memory cache key flush flow
+    InMemoryCacheKeyFlush,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -294,6 +318,28 @@ pub enum Flow {
     BusinessProfileDelete,
     /// List all the business profiles for a merchant
     BusinessProfileList,
+    /// Clone a business profile
+    BusinessProfileClone,
+    /// Create or replace a business profile template
+    BusinessProfileTemplateUpsert,
+    /// List the business profile templates of a merchant
+    BusinessProfileTemplatesList,
+    /// Delete a business profile template
+    BusinessProfileTemplateDelete,
+    /// Create a business profile from a template
+    BusinessProfileTemplateApply,
+    /// Onboard a connected account of a platform
+    ConnectedAccountCreate,
+    /// List the connected accounts of a platform
+    ConnectedAccountsList,
+    /// Retrieve a connected account of a platform
+    ConnectedAccountRetrieve,
+    /// Update the split of the payments of a connected account
+    ConnectedAccountUpdate,
+    /// Create a merchant connector account for a connected account
+    ConnectedAccountConnectorCreate,
+    /// Report the payments of the connected accounts of a platform
+    ConnectedAccountsReport,
     /// Different verification flows
     Verification,
     /// Rust locker migration
@@ -322,6 +368,12 @@ pub enum Flow {
     DecisionManagerDeleteConfig,
     /// Retrieve Decision Manager Config
     DecisionManagerRetrieveConfig,
+    /// Upsert Fraud Rules of a business profile
+    FraudRulesUpsertConfig,
+    /// Delete Fraud Rules of a business profile
+    FraudRulesDeleteConfig,
+    /// Retrieve Fraud Rules of a business profile
+    FraudRulesRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
     /// Change password flow
@@ -444,16 +496,240 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// List the incoming webhooks received
+    WebhookEventIncomingList,
+    /// Retrieve an incoming webhook received
+    WebhookEventIncomingRetrieve,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// Upsert the velocity limits of a business profile
+    VelocityLimitsUpsert,
+    /// Retrieve the velocity limits of a business profile
+    VelocityLimitsRetrieve,
+    /// Delete the velocity limits of a business profile
+    VelocityLimitsDelete,
+    /// Upsert the payment method display rules of a business profile
+    PmDisplayRulesUpsert,
+    /// Retrieve the payment method display rules of a business profile
+    PmDisplayRulesRetrieve,
+    /// Delete the payment method display rules of a business profile
+    PmDisplayRulesDelete,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// Create a report template
+    ReportTemplateCreate,
+    /// List the report templates of a merchant
+    ReportTemplateList,
+    /// Retrieve a report template
+    ReportTemplateRetrieve,
+    /// Update a report template
+    ReportTemplateUpdate,
+    /// Delete a report template
+    ReportTemplateDelete,
+    /// Download a generated report through a signed link
+    ReportDownload,
+    /// Ingest a settlement file of a connector
+    SettlementFileIngest,
+    /// Ingest the settlement lines fetched from a connector
+    SettlementLinesIngest,
+    /// Retrieve the lines of a settlement batch
+    SettlementBatchRetrieve,
+    /// Match the exceptions of a settlement batch again
+    SettlementBatchRematch,
+    /// List the settlement lines which need to be looked into
+    SettlementExceptionsList,
+    /// Manually resolve a settlement line
+    SettlementLineResolve,
+    /// Retrieve the reconciliation status of a payment
+    PaymentReconciliationRetrieve,
+    /// Retrieve the balances of the ledger accounts
+    LedgerBalancesRetrieve,
+    /// List the entries of the ledger
+    LedgerEntriesList,
+    /// Export the entries of the ledger
+    LedgerEntriesExport,
+    /// Search the payments, refunds, disputes and customers
+    Search,
+    /// Execute a GraphQL query reading the payments, refunds, disputes, customers and analytics
+    GraphQLQuery,
+    /// Customers overview flow.
+    CustomersOverview,
+    /// Customers redact flow.
+    CustomersRedact,
+    /// Customers redaction retrieve flow.
+    CustomersRedactionRetrieve,
+    /// Card export create flow.
+    CardExportCreate,
+    /// Card export list flow.
+    CardExportList,
+    /// Card export retrieve flow.
+    CardExportRetrieve,
+    /// Card export approve flow.
+    CardExportApprove,
+    /// Card export reject flow.
+    CardExportReject,
+    /// Card export download flow.
+    CardExportDownload,
+    /// Connector migration create flow.
+    ConnectorMigrationCreate,
+    /// Connector migration list flow.
+    ConnectorMigrationList,
+    /// Connector migration retrieve flow.
+    ConnectorMigrationRetrieve,
+    /// Connector migration cancel flow.
+    ConnectorMigrationCancel,
+    /// Connector migration payment methods list flow.
+    ConnectorMigrationPaymentMethodsList,
+    /// Merchant KV settings retrieve flow.
+    MerchantKvSettingsRetrieve,
+    /// Merchant KV settings update flow.
+    MerchantKvSettingsUpdate,
+    /// Drainer tuning retrieve flow.
+    DrainerTuningRetrieve,
+    /// Drainer tuning update flow.
+    DrainerTuningUpdate,
+    /// KV backlog retrieve flow.
+    KvBacklogRetrieve,
+    /// Payments connector exchanges flow.
+    PaymentsConnectorExchanges,
+    /// Payments status history flow.
+    PaymentsStatusHistory,
+    /// OAuth2 token create flow.
+    OAuth2TokenCreate,
+    /// OAuth2 token revoke flow.
+    OAuth2TokenRevoke,
+    /// Merchant rate limits retrieve flow.
+    MerchantRateLimitsRetrieve,
+    /// Merchant rate limits update flow.
+    MerchantRateLimitsUpdate,
+    /// Merchant alert rules retrieve flow.
+    MerchantAlertRulesRetrieve,
+    /// Merchant alert rules update flow.
+    MerchantAlertRulesUpdate,
+    /// Merchant response field access retrieve flow.
+    MerchantResponseFieldAccessRetrieve,
+    /// Merchant response field access update flow.
+    MerchantResponseFieldAccessUpdate,
+    /// Merchant API version retrieve flow.
+    MerchantApiVersionRetrieve,
+    /// Merchant API version update flow.
+    MerchantApiVersionUpdate,
+    /// Feature flag retrieve flow.
+    FeatureFlagRetrieve,
+    /// Feature flag update flow.
+    FeatureFlagUpdate,
+    /// Feature flag delete flow.
+    FeatureFlagDelete,
+    /// Payment method filters retrieve flow.
+    PmFiltersRetrieve,
+    /// Payment method filters update flow.
+    PmFiltersUpdate,
+    /// Payment method filters delete flow.
+    PmFiltersDelete,
+    /// Sandbox traffic generate flow.
+    SandboxTrafficGenerate,
+    /// Bulk force sync schedule flow.
+    BulkForceSyncSchedule,
+    /// Bulk force sync retrieve flow.
+    BulkForceSyncRetrieve,
+    /// Process tracker task list flow.
+    ProcessTrackerTaskList,
+    /// Process tracker task retrieve flow.
+    ProcessTrackerTaskRetrieve,
+    /// Process tracker task pause flow.
+    ProcessTrackerTaskPause,
+    /// Process tracker task resume flow.
+    ProcessTrackerTaskResume,
+    /// Process tracker task reschedule flow.
+    ProcessTrackerTaskReschedule,
+    /// Job retrieve flow.
+    JobRetrieve,
+    /// Job cancel flow.
+    JobCancel,
+    /// Master key rotation schedule flow.
+    MasterKeyRotationSchedule,
+    /// Master key rotation retrieve flow.
+    MasterKeyRotationRetrieve,
+    /// Merchant key rotation schedule flow.
+    MerchantKeyRotationSchedule,
+    /// Merchant key rotation retrieve flow.
+    MerchantKeyRotationRetrieve,
+    /// Audit events list flow.
+    AuditEventsList,
+    /// Organization merchants list flow.
+    OrganizationMerchantsList,
+    /// Organization merchant update flow.
+    OrganizationMerchantUpdate,
+    /// Organization payments aggregate flow.
+    OrganizationPaymentsAggregate,
+    /// Organization connector share flow.
+    OrganizationConnectorShare,
+    /// Tenant create flow.
+    TenantCreate,
+    /// Tenant list flow.
+    TenantList,
+    /// Tenant retrieve flow.
+    TenantRetrieve,
+    /// Tenant update flow.
+    TenantUpdate,
+    /// Merchant connector validate flow.
+    MerchantConnectorsValidate,
+    /// Merchant onboarding status flow.
+    MerchantOnboardingStatus,
+    /// Merchant connector credentials stage flow.
+    MerchantConnectorsCredentialsStage,
+    /// Merchant connector credentials cutover flow.
+    MerchantConnectorsCredentialsCutover,
+    /// Connector capabilities retrieve flow.
+    ConnectorCapabilitiesRetrieve,
+    /// Wallet decryption key create flow.
+    WalletDecryptionKeyCreate,
+    /// Wallet decryption keys list flow.
+    WalletDecryptionKeysList,
+    /// Wallet decryption key retire flow.
+    WalletDecryptionKeyRetire,
+    /// Vault token expire flow.
+    VaultTokenExpire,
+    /// Vault cleanup flow.
+    VaultCleanup,
+    /// Vault audit events list flow.
+    VaultAuditEventsList,
+    /// Order create flow.
+    OrderCreate,
+    /// Order retrieve flow.
+    OrderRetrieve,
+    /// Order update flow.
+    OrderUpdate,
+    /// Standalone authentication create flow.
+    AuthenticationCreate,
+    /// Standalone authentication authenticate flow.
+    AuthenticationAuthenticate,
+    /// Standalone authentication retrieve flow.
+    AuthenticationRetrieve,
+    /// Payments capture schedule flow.
+    PaymentsCaptureSchedule,
+    /// Payments capture schedule cancel flow.
+    PaymentsCaptureScheduleCancel,
+    /// Payments metadata update flow.
+    PaymentsMetadataUpdate,
+    /// Logging retrieve flow.
+    LoggingRetrieve,
+    /// Log levels update flow.
+    LogLevelsUpdate,
+    /// Request sampling update flow.
+    RequestSamplingUpdate,
+    /// Payment method session create flow.
+    PaymentMethodSessionCreate,
+    /// Payment method session confirm flow.
+    PaymentMethodSessionConfirm,
+    /// Payment method session retrieve flow.
+    PaymentMethodSessionRetrieve,
 }
 
 ///
@@ -25,3 +25,31 @@ async fn basic() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn runtime_log_levels() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use std::collections::BTreeMap;
+
+    use router_env::reload;
+
+    logger();
+
+    reload::set_runtime_log_levels(BTreeMap::from([(
+        "router_env".to_string(),
+        "TRACE".to_string(),
+    )]))?;
+    assert!(reload::get_log_filters()
+        .iter()
+        .all(|filter| filter.directive.ends_with(",router_env=trace")));
+
+    assert!(reload::set_runtime_log_levels(BTreeMap::from([(
+        "router_env".to_string(),
+        "verbose".to_string(),
+    )]))
+    .is_err());
+
+    reload::set_runtime_log_levels(BTreeMap::new())?;
+    assert!(reload::get_runtime_log_levels().is_empty());
+
+    Ok(())
+}
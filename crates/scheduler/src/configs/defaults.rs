@@ -7,6 +7,7 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            workflows: std::collections::HashMap::new(),
         }
     }
 }
@@ -19,6 +20,7 @@ impl Default for super::settings::ProducerSettings {
             lock_key: "PRODUCER_LOCKING_KEY".into(),
             lock_ttl: 160,
             batch_size: 200,
+            max_tasks_per_merchant: None,
         }
     }
 }
@@ -28,6 +30,16 @@ impl Default for super::settings::ConsumerSettings {
         Self {
             disabled: false,
             consumer_group: "SCHEDULER_GROUP".into(),
+            max_concurrent_tasks_per_merchant: None,
+        }
+    }
+}
+
+impl Default for super::settings::WorkflowSettings {
+    fn default() -> Self {
+        Self {
+            weight: 1,
+            max_concurrent_tasks: None,
         }
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -10,6 +12,8 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    /// The settings of each workflow, keyed by the name of its runner in lowercase
+    pub workflows: HashMap<String, WorkflowSettings>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -29,6 +33,9 @@ pub struct ProducerSettings {
     pub lock_key: String,
     pub lock_ttl: i64,
     pub batch_size: usize,
+    /// The number of tasks of a merchant added to the stream by each run of the producer, the
+    /// other tasks of the merchant being left for the next runs
+    pub max_tasks_per_merchant: Option<usize>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -36,4 +43,15 @@ pub struct ProducerSettings {
 pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
+    /// The number of tasks of a merchant run at the same time by a consumer
+    pub max_concurrent_tasks_per_merchant: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WorkflowSettings {
+    /// The number of tasks of the workflow taken for each task of a workflow of weight 1
+    pub weight: u32,
+    /// The number of tasks of the workflow run at the same time by a consumer
+    pub max_concurrent_tasks: Option<usize>,
 }
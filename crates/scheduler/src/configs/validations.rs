@@ -17,20 +17,60 @@ impl super::settings::SchedulerSettings {
             ))
         })?;
 
+        when(
+            self.consumer.max_concurrent_tasks_per_merchant == Some(0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "scheduler consumer max concurrent tasks per merchant must be greater than 0"
+                        .into(),
+                ))
+            },
+        )?;
+
         self.producer.validate()?;
 
         self.server.validate()?;
 
+        self.workflows
+            .iter()
+            .try_for_each(|(runner, workflow)| workflow.validate(runner))?;
+
         Ok(())
     }
 }
 
 impl super::settings::ProducerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
-        common_utils::fp_utils::when(self.lock_key.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.lock_key.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "producer lock key must not be empty".into(),
             ))
+        })?;
+
+        when(self.max_tasks_per_merchant == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "producer max tasks per merchant must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WorkflowSettings {
+    pub fn validate(&self, runner: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.weight == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "weight of workflow {runner} must be greater than 0"
+            )))
+        })?;
+
+        when(self.max_concurrent_tasks == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "max concurrent tasks of workflow {runner} must be greater than 0"
+            )))
         })
     }
 }
@@ -25,7 +25,8 @@ use super::env::logger;
 pub use super::workflows::ProcessTrackerWorkflow;
 use crate::{
     configs::settings::SchedulerSettings, db::process_tracker::ProcessTrackerInterface, errors,
-    metrics, utils as pt_utils, SchedulerAppState, SchedulerInterface, SchedulerSessionState,
+    fairness, metrics, utils as pt_utils, SchedulerAppState, SchedulerInterface,
+    SchedulerSessionState,
 };
 
 // Valid consumer business statuses
@@ -149,7 +150,7 @@ pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
         .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
         .await;
 
-    let mut tasks = state
+    let tasks = state
         .get_db()
         .as_scheduler()
         .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
@@ -158,6 +159,8 @@ pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
     if !tasks.is_empty() {
         logger::info!("{} picked {} tasks", consumer_name, tasks.len());
     }
+    let mut tasks = fairness::order_tasks_fairly(tasks, &settings.workflows);
+    let limiter = sync::Arc::new(fairness::ConcurrencyLimiter::new(&tasks, settings));
     let mut handler = vec![];
 
     for task in tasks.iter_mut() {
@@ -167,12 +170,13 @@ pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
 
         metrics::TASK_CONSUMED.add(&metrics::CONTEXT, 1, &[]);
 
-        handler.push(tokio::task::spawn(start_workflow(
-            state.clone(),
-            task.clone(),
-            pickup_time,
-            workflow_selector,
-        )))
+        let state = state.clone();
+        let task = task.clone();
+        let limiter = limiter.clone();
+        handler.push(tokio::task::spawn(async move {
+            let _permits = limiter.acquire(&task).await;
+            start_workflow(state, task, pickup_time, workflow_selector).await
+        }))
     }
     future::join_all(handler).await;
 
@@ -9,11 +9,23 @@ pub struct RetryMapping {
     pub frequencies: Vec<(i32, i32)>, // (frequency, count)
 }
 
+/// The percentage of the delay by which the polls of the connectors are moved earlier or later
+/// at random, when not configured for the connector
+pub const DEFAULT_POLLING_JITTER_PERCENTAGE: u8 = 10;
+
+fn default_polling_jitter_percentage() -> u8 {
+    DEFAULT_POLLING_JITTER_PERCENTAGE
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ConnectorPTMapping {
     pub default_mapping: RetryMapping,
     pub custom_merchant_mapping: HashMap<String, RetryMapping>,
     pub max_retries_count: i32,
+    /// The percentage of the delay by which each poll is moved earlier or later at random, so
+    /// that the objects awaiting a terminal status are not all polled at the same time
+    #[serde(default = "default_polling_jitter_percentage")]
+    pub jitter_percentage: u8,
 }
 
 impl Default for ConnectorPTMapping {
@@ -25,6 +37,7 @@ impl Default for ConnectorPTMapping {
                 frequencies: vec![(300, 5)],
             },
             max_retries_count: 5,
+            jitter_percentage: DEFAULT_POLLING_JITTER_PERCENTAGE,
         }
     }
 }
@@ -62,6 +62,40 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn find_processes_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        runner: Option<String>,
+        statuses: Option<Vec<storage_enums::ProcessTrackerStatus>>,
+        business_statuses: Option<Vec<String>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    /// The earliest schedule time of the processes with one of the statuses, scheduled within the
+    /// limits
+    async fn find_earliest_process_schedule_time(
+        &self,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Option<PrimitiveDateTime>, errors::StorageError>;
+
+    async fn count_processes_by_runners_statuses(
+        &self,
+        runners: Vec<String>,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    /// Updates the process only if it still has one of the statuses provided
+    async fn update_process_with_status(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -106,6 +140,69 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_processes_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        runner: Option<String>,
+        statuses: Option<Vec<storage_enums::ProcessTrackerStatus>>,
+        business_statuses: Option<Vec<String>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            runner,
+            statuses,
+            business_statuses,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn find_earliest_process_schedule_time(
+        &self,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Option<PrimitiveDateTime>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_earliest_schedule_time_by_statuses(
+            &conn,
+            time_lower_limit,
+            time_upper_limit,
+            statuses,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn count_processes_by_runners_statuses(
+        &self,
+        runners: Vec<String>,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::count_processes_by_runners_statuses(&conn, runners, statuses)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn update_process_with_status(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_with_status(&conn, statuses, process)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -298,4 +395,46 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_processes_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &str,
+        _runner: Option<String>,
+        _statuses: Option<Vec<storage_enums::ProcessTrackerStatus>>,
+        _business_statuses: Option<Vec<String>>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_earliest_process_schedule_time(
+        &self,
+        _time_lower_limit: PrimitiveDateTime,
+        _time_upper_limit: PrimitiveDateTime,
+        _statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<Option<PrimitiveDateTime>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_processes_by_runners_statuses(
+        &self,
+        _runners: Vec<String>,
+        _statuses: Vec<storage_enums::ProcessTrackerStatus>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_process_with_status(
+        &self,
+        _this: storage::ProcessTracker,
+        _statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        _process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
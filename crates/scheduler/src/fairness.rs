@@ -0,0 +1,260 @@
+//! Fairness between the workflows and the merchants whose tasks are run by the scheduler. The
+//! tasks of a merchant with a large backlog are spread out between the tasks of the other
+//! merchants, and the workflows given a larger weight have more of their tasks run first.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+use diesel_models::process_tracker as storage;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::configs::settings::{SchedulerSettings, WorkflowSettings};
+
+/// The merchant of a task is the `merchant_id` of its tracking data. The tasks without a merchant
+/// are treated as the tasks of a single merchant.
+fn get_merchant_id(task: &storage::ProcessTracker) -> &str {
+    task.tracking_data
+        .get("merchant_id")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default()
+}
+
+fn get_runner(task: &storage::ProcessTracker) -> &str {
+    task.runner.as_deref().unwrap_or_default()
+}
+
+/// The settings of the workflow, looked up by the name of its runner in lowercase
+fn get_workflow_settings<'a>(
+    workflows: &'a HashMap<String, WorkflowSettings>,
+    runner: &str,
+) -> Option<&'a WorkflowSettings> {
+    workflows.get(&runner.to_lowercase())
+}
+
+/// Keeps at most `max_tasks_per_merchant` tasks of each merchant, the ones scheduled the earliest.
+/// Returns the tasks kept and the tasks left for later.
+pub fn limit_tasks_per_merchant(
+    mut tasks: Vec<storage::ProcessTracker>,
+    max_tasks_per_merchant: Option<usize>,
+) -> (Vec<storage::ProcessTracker>, Vec<storage::ProcessTracker>) {
+    let Some(max_tasks_per_merchant) = max_tasks_per_merchant else {
+        return (tasks, Vec::new());
+    };
+
+    tasks.sort_by(|a, b| a.schedule_time.cmp(&b.schedule_time));
+    let mut task_counts = HashMap::<String, usize>::new();
+    tasks.into_iter().partition(|task| {
+        let task_count = task_counts
+            .entry(get_merchant_id(task).to_owned())
+            .or_default();
+        *task_count += 1;
+        *task_count <= max_tasks_per_merchant
+    })
+}
+
+/// Orders the tasks so that the merchants take turns within each workflow, and the workflows take
+/// turns according to their weight: a workflow of weight 3 has 3 tasks taken for each task of a
+/// workflow of weight 1. The tasks of a merchant within a workflow keep the order of their
+/// schedule time.
+pub fn order_tasks_fairly(
+    mut tasks: Vec<storage::ProcessTracker>,
+    workflows: &HashMap<String, WorkflowSettings>,
+) -> Vec<storage::ProcessTracker> {
+    let task_count = tasks.len();
+    tasks.sort_by(|a, b| a.schedule_time.cmp(&b.schedule_time));
+
+    // The tasks of each merchant of each workflow, in the order the merchants were first seen
+    let mut runners: Vec<(String, Vec<(String, VecDeque<storage::ProcessTracker>)>)> = Vec::new();
+    for task in tasks {
+        let runner = get_runner(&task).to_owned();
+        let merchant_id = get_merchant_id(&task).to_owned();
+        let merchants = match runners.iter().position(|(name, _)| *name == runner) {
+            Some(index) => &mut runners[index].1,
+            None => {
+                runners.push((runner, Vec::new()));
+                &mut runners[runners.len() - 1].1
+            }
+        };
+        match merchants.iter_mut().find(|(id, _)| *id == merchant_id) {
+            Some((_, merchant_tasks)) => merchant_tasks.push_back(task),
+            None => merchants.push((merchant_id, VecDeque::from([task]))),
+        }
+    }
+
+    // Each workflow takes its merchants in turns, skipping the merchants left without tasks
+    let mut queues = runners
+        .into_iter()
+        .map(|(runner, merchants)| {
+            let weight = get_workflow_settings(workflows, &runner)
+                .map_or(1, |workflow| workflow.weight)
+                .max(1);
+            let mut merchants = VecDeque::from(merchants);
+            let mut queue = VecDeque::new();
+            while let Some((merchant_id, mut merchant_tasks)) = merchants.pop_front() {
+                if let Some(task) = merchant_tasks.pop_front() {
+                    queue.push_back(task);
+                    merchants.push_back((merchant_id, merchant_tasks));
+                }
+            }
+            (weight, queue)
+        })
+        .collect::<Vec<_>>();
+    queues.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut ordered_tasks = Vec::with_capacity(task_count);
+    while ordered_tasks.len() < task_count {
+        for (weight, queue) in queues.iter_mut() {
+            for _ in 0..*weight {
+                match queue.pop_front() {
+                    Some(task) => ordered_tasks.push(task),
+                    None => break,
+                }
+            }
+        }
+    }
+    ordered_tasks
+}
+
+/// Limits the number of tasks of each workflow and of each merchant run at the same time by a
+/// consumer
+#[derive(Debug)]
+pub struct ConcurrencyLimiter {
+    runner_semaphores: HashMap<String, Arc<Semaphore>>,
+    merchant_semaphores: HashMap<String, Arc<Semaphore>>,
+}
+
+impl ConcurrencyLimiter {
+    pub fn new(tasks: &[storage::ProcessTracker], settings: &SchedulerSettings) -> Self {
+        let mut runner_semaphores = HashMap::new();
+        let mut merchant_semaphores = HashMap::new();
+        for task in tasks {
+            let runner = get_runner(task);
+            if let Some(max_concurrent_tasks) = get_workflow_settings(&settings.workflows, runner)
+                .and_then(|workflow| workflow.max_concurrent_tasks)
+            {
+                runner_semaphores
+                    .entry(runner.to_owned())
+                    .or_insert_with(|| Arc::new(Semaphore::new(max_concurrent_tasks)));
+            }
+            if let Some(max_concurrent_tasks) = settings.consumer.max_concurrent_tasks_per_merchant
+            {
+                merchant_semaphores
+                    .entry(get_merchant_id(task).to_owned())
+                    .or_insert_with(|| Arc::new(Semaphore::new(max_concurrent_tasks)));
+            }
+        }
+
+        Self {
+            runner_semaphores,
+            merchant_semaphores,
+        }
+    }
+
+    /// Waits for the task to be allowed to run. The task is run while the permits returned are
+    /// held.
+    pub async fn acquire(&self, task: &storage::ProcessTracker) -> Vec<OwnedSemaphorePermit> {
+        let semaphores = [
+            self.runner_semaphores.get(get_runner(task)),
+            self.merchant_semaphores.get(get_merchant_id(task)),
+        ];
+
+        let mut permits = Vec::new();
+        for semaphore in semaphores.into_iter().flatten() {
+            // The semaphores are never closed
+            if let Ok(permit) = Arc::clone(semaphore).acquire_owned().await {
+                permits.push(permit);
+            }
+        }
+        permits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn task(
+        id: &str,
+        runner: &str,
+        merchant_id: &str,
+        schedule_offset: i64,
+    ) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: id.to_owned(),
+            name: None,
+            tag: Vec::new(),
+            runner: Some(runner.to_owned()),
+            retry_count: 0,
+            schedule_time: Some(now.saturating_add(time::Duration::seconds(schedule_offset))),
+            rule: String::new(),
+            tracking_data: serde_json::json!({ "merchant_id": merchant_id }),
+            business_status: String::from(storage::business_status::PENDING),
+            status: diesel_models::enums::ProcessTrackerStatus::New,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn ids(tasks: &[storage::ProcessTracker]) -> Vec<&str> {
+        tasks.iter().map(|task| task.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_merchants_take_turns() {
+        let tasks = vec![
+            task("a1", "PAYMENTS_SYNC_WORKFLOW", "merchant_a", 0),
+            task("a2", "PAYMENTS_SYNC_WORKFLOW", "merchant_a", 1),
+            task("a3", "PAYMENTS_SYNC_WORKFLOW", "merchant_a", 2),
+            task("b1", "PAYMENTS_SYNC_WORKFLOW", "merchant_b", 3),
+            task("c1", "PAYMENTS_SYNC_WORKFLOW", "merchant_c", 4),
+        ];
+
+        let ordered_tasks = order_tasks_fairly(tasks, &HashMap::new());
+
+        assert_eq!(ids(&ordered_tasks), ["a1", "b1", "c1", "a2", "a3"]);
+    }
+
+    #[test]
+    fn test_workflows_take_turns_by_weight() {
+        let tasks = vec![
+            task("w1", "OUTGOING_WEBHOOK_RETRY_WORKFLOW", "merchant_a", 0),
+            task("w2", "OUTGOING_WEBHOOK_RETRY_WORKFLOW", "merchant_a", 1),
+            task("w3", "OUTGOING_WEBHOOK_RETRY_WORKFLOW", "merchant_a", 2),
+            task("c1", "SCHEDULED_CAPTURE_WORKFLOW", "merchant_b", 3),
+            task("c2", "SCHEDULED_CAPTURE_WORKFLOW", "merchant_b", 4),
+            task("c3", "SCHEDULED_CAPTURE_WORKFLOW", "merchant_b", 5),
+        ];
+        let workflows = HashMap::from([(
+            "scheduled_capture_workflow".to_owned(),
+            WorkflowSettings {
+                weight: 2,
+                max_concurrent_tasks: None,
+            },
+        )]);
+
+        let ordered_tasks = order_tasks_fairly(tasks, &workflows);
+
+        assert_eq!(ids(&ordered_tasks), ["c1", "c2", "w1", "c3", "w2", "w3"]);
+    }
+
+    #[test]
+    fn test_limit_tasks_per_merchant() {
+        let tasks = vec![
+            task("a2", "PAYMENTS_SYNC_WORKFLOW", "merchant_a", 1),
+            task("a1", "PAYMENTS_SYNC_WORKFLOW", "merchant_a", 0),
+            task("b1", "REFUND_WORKFLOW_ROUTER", "merchant_b", 2),
+            task("a3", "REFUND_WORKFLOW_ROUTER", "merchant_a", 3),
+        ];
+
+        let (tasks, deferred_tasks) = limit_tasks_per_merchant(tasks, Some(2));
+
+        assert_eq!(ids(&tasks), ["a1", "a2", "b1"]);
+        assert_eq!(ids(&deferred_tasks), ["a3"]);
+    }
+}
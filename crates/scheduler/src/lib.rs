@@ -3,6 +3,7 @@ pub mod consumer;
 pub mod db;
 pub mod env;
 pub mod errors;
+pub mod fairness;
 pub mod flow;
 pub mod metrics;
 pub mod producer;
@@ -8,6 +8,7 @@ histogram_metric!(CONSUMER_STATS, PT_METER, "CONSUMER_OPS");
 counter_metric!(PAYMENT_COUNT, PT_METER); // No. of payments created
 counter_metric!(TASKS_PICKED_COUNT, PT_METER); // Tasks picked by
 counter_metric!(BATCHES_CREATED, PT_METER); // Batches added to stream
+counter_metric!(TASKS_DEFERRED, PT_METER); // Tasks left by the producer for its next runs
 counter_metric!(BATCHES_CONSUMED, PT_METER); // Batches consumed by consumer
 counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
@@ -15,7 +15,7 @@ use super::{
     metrics,
 };
 use crate::{
-    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow,
+    configs::settings::SchedulerSettings, errors, fairness, flow::SchedulerFlow,
     scheduler::SchedulerInterface, utils::*, SchedulerAppState, SchedulerSessionState,
 };
 
@@ -110,7 +110,12 @@ where
         let tasks = fetch_producer_tasks(state.get_db().as_scheduler(), settings).await?;
         debug!("Producer count of tasks {}", tasks.len());
 
-        // [#268]: Allow task based segregation of tasks
+        let (tasks, deferred_tasks) =
+            fairness::limit_tasks_per_merchant(tasks, settings.producer.max_tasks_per_merchant);
+        if !deferred_tasks.is_empty() {
+            defer_producer_tasks(state.get_db().as_scheduler(), settings, deferred_tasks).await?;
+        }
+        let tasks = fairness::order_tasks_fairly(tasks, &settings.workflows);
 
         divide_and_append_tasks(
             state.get_db().as_scheduler(),
@@ -178,3 +183,52 @@ pub async fn fetch_producer_tasks(
     metrics::TASKS_PICKED_COUNT.add(&metrics::CONTEXT, new_tasks.len() as u64, &[]);
     Ok(new_tasks)
 }
+
+/// Leaves the tasks for the next runs of the producer. The tasks about to fall out of the window
+/// fetched by the producer are moved to the current time, so that they are not missed.
+#[instrument(skip_all)]
+async fn defer_producer_tasks(
+    db: &dyn SchedulerInterface,
+    conf: &SchedulerSettings,
+    tasks: Vec<storage::ProcessTracker>,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let now = common_utils::date_time::now();
+    let task_count = tasks.len();
+    let time_reschedule_limit = now
+        .checked_sub(Duration::seconds(conf.producer.lower_fetch_limit / 2))
+        .ok_or_else(|| {
+            report!(errors::ProcessTrackerError::ConfigurationError)
+                .attach_printable("Error obtaining limit to reschedule producer tasks")
+        })?;
+
+    let task_ids = tasks
+        .into_iter()
+        .filter(|task| {
+            task.schedule_time
+                .map_or(true, |schedule_time| schedule_time < time_reschedule_limit)
+        })
+        .map(|task| task.id)
+        .collect::<Vec<_>>();
+    if !task_ids.is_empty() {
+        db.process_tracker_update_process_status_by_ids(
+            task_ids,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(now),
+                tracking_data: None,
+                business_status: None,
+                status: None,
+                updated_at: Some(now),
+            },
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    }
+    debug!("Producer deferred {task_count} tasks");
+
+    // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
+    #[allow(clippy::as_conversions)]
+    metrics::TASKS_DEFERRED.add(&metrics::CONTEXT, task_count as u64, &[]);
+    Ok(())
+}
@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use common_utils::DbConnectionParams;
 use masking::Secret;
 
+use crate::errors::ApplicationError;
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct Database {
     pub username: String,
@@ -33,6 +37,65 @@ impl DbConnectionParams for Database {
     }
 }
 
+/// The database which a kind of read is served by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadTarget {
+    #[default]
+    Primary,
+    Replica,
+}
+
+/// Routing of the reads between the primary database and its read replica, by kind of read
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct ReplicaRouting {
+    /// Reads of single resources made while processing payments, refunds and the like
+    pub transactional: ReadTarget,
+    /// Reads listing resources
+    pub list: ReadTarget,
+    /// Reads aggregating resources, such as filters and counts
+    pub analytics: ReadTarget,
+    /// Reads of the connectors of the merchants made while refreshing the constraint graphs
+    pub cgraph: ReadTarget,
+    /// Replication lag in seconds beyond which the reads are served by the primary instead
+    pub max_lag_in_secs: u64,
+    /// Interval in seconds at which the replication lag of the replica is checked
+    pub lag_check_interval_in_secs: u64,
+}
+
+impl Default for ReplicaRouting {
+    fn default() -> Self {
+        Self {
+            transactional: ReadTarget::Primary,
+            list: ReadTarget::Primary,
+            analytics: ReadTarget::Primary,
+            cgraph: ReadTarget::Primary,
+            max_lag_in_secs: 5,
+            lag_check_interval_in_secs: 5,
+        }
+    }
+}
+
+impl ReplicaRouting {
+    pub fn routes_to_replica(&self) -> bool {
+        [self.transactional, self.list, self.analytics, self.cgraph].contains(&ReadTarget::Replica)
+    }
+
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.routes_to_replica() && self.lag_check_interval_in_secs == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "replica_routing lag_check_interval_in_secs must not be 0".into(),
+                ))
+            },
+        )
+    }
+}
+
 pub trait TenantConfig: Send + Sync {
     fn get_schema(&self) -> &str;
     fn get_redis_key_prefix(&self) -> &str;
@@ -75,3 +138,106 @@ impl Default for Database {
         }
     }
 }
+
+/// Settings of a deployment running in several regions at once, each region having its own
+/// Redis and in-memory caches
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct MultiRegion {
+    pub enabled: bool,
+    /// Name of the region this instance runs in
+    pub region: String,
+    /// The other regions of the deployment, which the cache invalidations are forwarded to
+    pub peer_regions: Vec<PeerRegion>,
+    /// Name of the region whose Redis holds the API locks of every region, so that concurrent
+    /// requests for a resource landing in different regions are serialized. The local Redis is
+    /// used when not set
+    pub lock_region: Option<String>,
+    /// Time in seconds for which values just invalidated are not cached again, so that reads
+    /// served by a lagging database replica do not cache stale values
+    pub replication_lag_tolerance_in_secs: u64,
+}
+
+impl MultiRegion {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.region.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "multi_region region must not be empty".into(),
+            ))
+        })?;
+
+        self.peer_regions.iter().try_for_each(|peer| {
+            when(peer.name == self.region, || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "multi_region peer region {} must not be the region itself",
+                    peer.name
+                )))
+            })?;
+            peer.redis.validate().map_err(|error| {
+                ApplicationError::InvalidConfigurationValueError(format!(
+                    "Redis configuration of multi_region peer region {}: {error}",
+                    peer.name
+                ))
+            })
+        })?;
+
+        self.lock_region
+            .as_ref()
+            .filter(|lock_region| {
+                **lock_region != self.region
+                    && !self
+                        .peer_regions
+                        .iter()
+                        .any(|peer| &peer.name == *lock_region)
+            })
+            .map_or(Ok(()), |lock_region| {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "multi_region lock region {lock_region} is neither the region nor a peer region"
+                )))
+            })
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PeerRegion {
+    pub name: String,
+    pub redis: redis_interface::RedisSettings,
+}
+
+/// Limits of the in-memory caches of an instance, keyed by the name of the cache, e.g.
+/// `ROUTING_CACHE`. The least recently used entries are evicted once a cache is full.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct InMemoryCacheSettings {
+    pub limits: HashMap<String, InMemoryCacheLimit>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct InMemoryCacheLimit {
+    /// Maximum number of entries the cache holds
+    pub max_entries: u64,
+}
+
+impl InMemoryCacheSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        for (name, limit) in &self.limits {
+            if !crate::redis::cache::CACHE_NAMES.contains(&name.as_str()) {
+                return Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "in_memory_cache limit set for unknown cache {name}"
+                )));
+            }
+            if limit.max_entries == 0 {
+                return Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "in_memory_cache max_entries of {name} must be greater than 0"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,11 +1,20 @@
-use async_bb8_diesel::{AsyncConnection, ConnectionError};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, ConnectionError};
 use bb8::CustomizeConnection;
 use common_utils::DbConnectionParams;
 use diesel::PgConnection;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::{StorageError, StorageResult};
+use router_env::{logger, tracing::Instrument};
 
-use crate::config::{Database, TenantConfig};
+use crate::config::{Database, ReadTarget, ReplicaRouting, TenantConfig};
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
@@ -20,6 +29,23 @@ pub trait DatabaseStore: Clone + Send + Sync {
     ) -> StorageResult<Self>;
     fn get_master_pool(&self) -> &PgPool;
     fn get_replica_pool(&self) -> &PgPool;
+    /// The pool serving the kind of read, writes always being served by the master pool
+    fn get_read_pool(&self, query: ReadQuery) -> &PgPool;
+    /// How the reads are routed to the read replica, `None` when there is no read replica
+    fn get_replica_routing(&self) -> Option<&ReplicaRouting>;
+}
+
+/// The kinds of reads, each of which can be routed to the read replica
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadQuery {
+    /// Reads of single resources made while processing payments, refunds and the like
+    Transactional,
+    /// Reads listing resources
+    List,
+    /// Reads aggregating resources, such as filters and counts
+    Analytics,
+    /// Reads of the connectors of the merchants made while refreshing the constraint graphs
+    CGraph,
 }
 
 #[derive(Debug, Clone)]
@@ -48,23 +74,35 @@ impl DatabaseStore for Store {
     fn get_replica_pool(&self) -> &PgPool {
         &self.master_pool
     }
+
+    fn get_read_pool(&self, _query: ReadQuery) -> &PgPool {
+        &self.master_pool
+    }
+
+    fn get_replica_routing(&self) -> Option<&ReplicaRouting> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ReplicaStore {
     pub master_pool: PgPool,
     pub replica_pool: PgPool,
+    pub replica_routing: ReplicaRouting,
+    /// Whether the replication lag of the replica is beyond the tolerated lag, in which case all
+    /// the reads are served by the master pool
+    replica_lagging: Arc<AtomicBool>,
 }
 
 #[async_trait::async_trait]
 impl DatabaseStore for ReplicaStore {
-    type Config = (Database, Database);
+    type Config = (Database, Database, ReplicaRouting);
     async fn new(
-        config: (Database, Database),
+        config: (Database, Database, ReplicaRouting),
         tenant_config: &dyn TenantConfig,
         test_transaction: bool,
     ) -> StorageResult<Self> {
-        let (master_config, replica_config) = config;
+        let (master_config, replica_config, replica_routing) = config;
         let master_pool =
             diesel_make_pg_pool(&master_config, tenant_config.get_schema(), test_transaction)
                 .await
@@ -76,9 +114,25 @@ impl DatabaseStore for ReplicaStore {
         )
         .await
         .attach_printable("failed to create replica pool")?;
+
+        let replica_lagging = Arc::new(AtomicBool::new(false));
+        if !test_transaction && replica_routing.routes_to_replica() {
+            let _task_handle = tokio::spawn(
+                monitor_replication_lag(
+                    replica_pool.clone(),
+                    Duration::from_secs(replica_routing.max_lag_in_secs),
+                    Duration::from_secs(replica_routing.lag_check_interval_in_secs),
+                    replica_lagging.clone(),
+                )
+                .in_current_span(),
+            );
+        }
+
         Ok(Self {
             master_pool,
             replica_pool,
+            replica_routing,
+            replica_lagging,
         })
     }
 
@@ -89,6 +143,78 @@ impl DatabaseStore for ReplicaStore {
     fn get_replica_pool(&self) -> &PgPool {
         &self.replica_pool
     }
+
+    fn get_read_pool(&self, query: ReadQuery) -> &PgPool {
+        let target = match query {
+            ReadQuery::Transactional => self.replica_routing.transactional,
+            ReadQuery::List => self.replica_routing.list,
+            ReadQuery::Analytics => self.replica_routing.analytics,
+            ReadQuery::CGraph => self.replica_routing.cgraph,
+        };
+        match target {
+            ReadTarget::Replica if !self.replica_lagging.load(Ordering::Relaxed) => {
+                &self.replica_pool
+            }
+            ReadTarget::Replica | ReadTarget::Primary => &self.master_pool,
+        }
+    }
+
+    fn get_replica_routing(&self) -> Option<&ReplicaRouting> {
+        Some(&self.replica_routing)
+    }
+}
+
+/// Replication lag of the replica in seconds, zero when the replica has replayed all the changes
+/// it received so that an idle primary is not mistaken for a lagging replica
+const REPLICATION_LAG_QUERY: &str = "CAST(CASE \
+    WHEN NOT pg_is_in_recovery() OR pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+    ELSE COALESCE(EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()), 0) \
+    END AS DOUBLE PRECISION)";
+
+/// Periodically checks the replication lag of the replica, flagging it as lagging when the lag is
+/// beyond the tolerated lag or cannot be determined
+async fn monitor_replication_lag(
+    replica_pool: PgPool,
+    max_lag: Duration,
+    check_interval: Duration,
+    replica_lagging: Arc<AtomicBool>,
+) {
+    let mut interval = tokio::time::interval(check_interval);
+    loop {
+        interval.tick().await;
+
+        let is_lagging = match get_replication_lag(&replica_pool).await {
+            Ok(lag) => lag > max_lag.as_secs_f64(),
+            Err(error) => {
+                logger::error!(?error, "Failed to fetch the replication lag of the replica");
+                true
+            }
+        };
+
+        if replica_lagging.swap(is_lagging, Ordering::Relaxed) != is_lagging {
+            if is_lagging {
+                logger::warn!("Replica is lagging, serving the reads from the primary");
+            } else {
+                logger::info!("Replica caught up, serving the reads from the replica again");
+            }
+        }
+    }
+}
+
+/// Replication lag of the replica in seconds
+pub async fn get_replication_lag(replica_pool: &PgPool) -> StorageResult<f64> {
+    let conn = replica_pool
+        .get()
+        .await
+        .change_context(StorageError::DatabaseConnectionError)?;
+
+    diesel::select(diesel::dsl::sql::<diesel::sql_types::Double>(
+        REPLICATION_LAG_QUERY,
+    ))
+    .get_result_async::<f64>(&*conn)
+    .await
+    .change_context(StorageError::DatabaseConnectionError)
+    .attach_printable("Failed to fetch the replication lag")
 }
 
 pub async fn diesel_make_pg_pool(
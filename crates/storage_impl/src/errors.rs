@@ -242,6 +242,9 @@ pub enum ApiClientError {
     #[error("connection closed before a message could complete")]
     ConnectionClosedIncompleteMessage,
 
+    #[error("Failed to connect to the server {0}")]
+    ConnectionFailed(String),
+
     #[error("Server responded with Internal Server Error")]
     InternalServerErrorReceived,
     #[error("Server responded with Bad Gateway")]
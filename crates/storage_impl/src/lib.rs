@@ -31,16 +31,22 @@ use hyperswitch_domain_models::{PayoutAttemptInterface, PayoutsInterface};
 pub use mock_db::MockDb;
 use redis_interface::{errors::RedisError, RedisConnectionPool, SaddReply};
 
-pub use crate::database::store::DatabaseStore;
 #[cfg(not(feature = "payouts"))]
 pub use crate::database::store::Store;
+pub use crate::database::store::{DatabaseStore, ReadQuery};
 
 #[derive(Debug, Clone)]
 pub struct RouterStore<T: DatabaseStore> {
     db_store: T,
     cache_store: Arc<RedisStore>,
     master_encryption_key: StrongSecret<Vec<u8>>,
+    /// The master encryption key being rotated out, with which the data not yet re-encrypted with
+    /// the master encryption key is decrypted
+    previous_master_encryption_key: Option<StrongSecret<Vec<u8>>>,
     pub request_id: Option<String>,
+    /// The actor the status transitions of the payments updated through the store are recorded
+    /// with
+    status_actor: diesel_models::enums::PaymentStatusActor,
 }
 
 #[async_trait::async_trait]
@@ -84,6 +90,12 @@ where
     fn get_replica_pool(&self) -> &PgPool {
         self.db_store.get_replica_pool()
     }
+    fn get_read_pool(&self, query: ReadQuery) -> &PgPool {
+        self.db_store.get_read_pool(query)
+    }
+    fn get_replica_routing(&self) -> Option<&config::ReplicaRouting> {
+        self.db_store.get_replica_routing()
+    }
 }
 
 impl<T: DatabaseStore> RedisConnInterface for RouterStore<T> {
@@ -119,7 +131,9 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            previous_master_encryption_key: None,
             request_id: None,
+            status_actor: Default::default(),
         })
     }
 
@@ -132,6 +146,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             .change_context(StorageError::InitializationError)
             .attach_printable("Failed to create cache store")?;
         cache_store.set_error_callback(cache_error_signal);
+        cache_store.spawn_shard_health_collector();
         Ok(Arc::new(cache_store))
     }
 
@@ -139,6 +154,20 @@ impl<T: DatabaseStore> RouterStore<T> {
         &self.master_encryption_key
     }
 
+    pub fn previous_master_key(&self) -> Option<&StrongSecret<Vec<u8>>> {
+        self.previous_master_encryption_key.as_ref()
+    }
+
+    /// Sets the master encryption key being rotated out, while the data encrypted with it is being
+    /// re-encrypted with the master encryption key of the store
+    pub fn with_previous_master_key(
+        mut self,
+        previous_encryption_key: Option<StrongSecret<Vec<u8>>>,
+    ) -> Self {
+        self.previous_master_encryption_key = previous_encryption_key;
+        self
+    }
+
     /// # Panics
     ///
     /// Will panic if `CONNECTOR_AUTH_FILE_PATH` is not set
@@ -158,7 +187,9 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store: Arc::new(cache_store),
             master_encryption_key: encryption_key,
+            previous_master_encryption_key: None,
             request_id: None,
+            status_actor: Default::default(),
         })
     }
 }
@@ -201,6 +232,12 @@ where
     fn get_replica_pool(&self) -> &PgPool {
         self.router_store.get_replica_pool()
     }
+    fn get_read_pool(&self, query: ReadQuery) -> &PgPool {
+        self.router_store.get_read_pool(query)
+    }
+    fn get_replica_routing(&self) -> Option<&config::ReplicaRouting> {
+        self.router_store.get_replica_routing()
+    }
 }
 
 impl<T: DatabaseStore> RedisConnInterface for KVRouterStore<T> {
@@ -233,10 +270,60 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         self.router_store.master_key()
     }
 
+    pub fn previous_master_key(&self) -> Option<&StrongSecret<Vec<u8>>> {
+        self.router_store.previous_master_key()
+    }
+
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
 
+    /// The number of drainer shards the writes of the merchant are spread across, falling back to
+    /// all the shards when the KV settings of the merchant cannot be fetched
+    async fn get_merchant_shard_count(&self, merchant_id: &str) -> u8 {
+        use common_utils::ext_traits::StringExt;
+
+        let key = redis::kv_store::get_merchant_kv_settings_key(merchant_id);
+        let find_settings_from_db = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            match store::configs::Config::find_by_key(&conn, &key).await {
+                Ok(config) => config
+                    .config
+                    .parse_struct("MerchantKvSettings")
+                    .change_context(errors::StorageError::DeserializationFailed),
+                Err(error) => match error.current_context() {
+                    store::errors::DatabaseError::NotFound => {
+                        Ok(redis::kv_store::MerchantKvSettings::default())
+                    }
+                    _ => Err(error.change_context(errors::StorageError::KVError)),
+                },
+            }
+        };
+
+        match redis::cache::get_or_populate_in_memory(
+            self,
+            &key,
+            find_settings_from_db,
+            &redis::cache::CONFIG_CACHE,
+        )
+        .await
+        {
+            Ok(settings) => settings
+                .shard_count
+                .filter(|shard_count| *shard_count > 0)
+                .map_or(self.drainer_num_partitions, |shard_count| {
+                    shard_count.min(self.drainer_num_partitions)
+                }),
+            Err(error) => {
+                router_env::logger::error!(
+                    ?error,
+                    "Failed to fetch the KV settings of the merchant"
+                );
+                self.drainer_num_partitions
+            }
+        }
+    }
+
     pub async fn push_to_drainer_stream<R>(
         &self,
         redis_entry: diesel_models::kv::TypedSql,
@@ -248,7 +335,11 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         let global_id = format!("{}", partition_key);
         let request_id = self.request_id.clone().unwrap_or_default();
 
-        let shard_key = R::shard_key(partition_key, self.drainer_num_partitions);
+        let num_partitions = match partition_key.merchant_id() {
+            Some(merchant_id) => self.get_merchant_shard_count(merchant_id).await,
+            None => self.drainer_num_partitions,
+        };
+        let shard_key = R::shard_key(partition_key, num_partitions);
         let stream_name = self.get_drainer_stream_name(&shard_key);
         self.router_store
             .cache_store
@@ -17,3 +17,9 @@ gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+
+// Metrics for the shards of redis
+gauge_metric!(REDIS_SHARD_CONNECTED, GLOBAL_METER);
+
+// Metrics for multi-region deployments
+counter_metric!(PEER_REGION_INVALIDATION_FAILURE, GLOBAL_METER);
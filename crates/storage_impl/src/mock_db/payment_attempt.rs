@@ -1,4 +1,6 @@
-use api_models::enums::{AuthenticationType, Connector, PaymentMethod, PaymentMethodType};
+use api_models::enums::{
+    AuthenticationType, CardNetwork, Connector, PaymentMethod, PaymentMethodType,
+};
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
 use hyperswitch_domain_models::{
@@ -45,6 +47,7 @@ impl PaymentAttemptInterface for MockDb {
         _payment_method_type: Option<Vec<PaymentMethodType>>,
         _authentication_type: Option<Vec<AuthenticationType>>,
         _merchanat_connector_id: Option<Vec<String>>,
+        _card_network: Option<Vec<CardNetwork>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
         Err(StorageError::MockDbError)?
@@ -157,6 +160,9 @@ impl PaymentAttemptInterface for MockDb {
             charge_id: payment_attempt.charge_id,
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
+            failover_action: payment_attempt.failover_action,
+            unified_error_details: payment_attempt.unified_error_details,
+            amount_reversed: payment_attempt.amount_reversed,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -1,5 +1,6 @@
 pub mod payment_attempt;
 pub mod payment_intent;
+pub(crate) mod status_transition;
 
 use diesel_models::{payment_attempt::PaymentAttempt, PaymentIntent};
 
@@ -1,4 +1,6 @@
-use api_models::enums::{AuthenticationType, Connector, PaymentMethod, PaymentMethodType};
+use api_models::enums::{
+    AuthenticationType, CardNetwork, Connector, PaymentMethod, PaymentMethodType,
+};
 use common_utils::{errors::CustomResult, fallback_reverse_lookup_not_found, types::MinorUnit};
 use diesel_models::{
     enums::{
@@ -32,9 +34,13 @@ use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
+    payments::status_transition::StatusTransition,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
-    utils::{pg_connection_read, pg_connection_write, try_redis_get_else_try_database_get},
-    DataModelExt, DatabaseStore, KVRouterStore, RouterStore,
+    utils::{
+        pg_connection_read, pg_connection_read_for, pg_connection_write,
+        try_redis_get_else_try_database_get,
+    },
+    DataModelExt, DatabaseStore, KVRouterStore, ReadQuery, RouterStore,
 };
 
 #[async_trait::async_trait]
@@ -64,15 +70,21 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         payment_attempt: PaymentAttemptUpdate,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
+        let status_transition = StatusTransition::for_attempt(&this, &payment_attempt)?;
         let conn = pg_connection_write(self).await?;
-        this.to_storage_model()
+        let updated_attempt = this
+            .to_storage_model()
             .update_with_attempt_id(&conn, payment_attempt.to_storage_model())
             .await
             .map_err(|er| {
                 let new_err = diesel_error_to_data_error(er.current_context());
                 er.change_context(new_err)
             })
-            .map(PaymentAttempt::from_storage_model)
+            .map(PaymentAttempt::from_storage_model)?;
+        self.record_status_transition(status_transition, self.request_id.clone())
+            .await;
+
+        Ok(updated_attempt)
     }
 
     #[instrument(skip_all)]
@@ -192,7 +204,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentListFilters, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_for(self, ReadQuery::Analytics).await?;
         let intents = futures::future::try_join_all(pi.iter().cloned().map(|pi| async {
             pi.convert()
                 .await
@@ -296,11 +308,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         payment_method_type: Option<Vec<PaymentMethodType>>,
         authentication_type: Option<Vec<AuthenticationType>>,
         merchant_connector_id: Option<Vec<String>>,
+        card_network: Option<Vec<CardNetwork>>,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
         let conn = self
             .db_store
-            .get_replica_pool()
+            .get_read_pool(ReadQuery::Analytics)
             .get()
             .await
             .change_context(errors::StorageError::DatabaseConnectionError)?;
@@ -319,6 +332,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             payment_method_type,
             authentication_type,
             merchant_connector_id,
+            card_network.map(|card_network| {
+                card_network
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+            }),
         )
         .await
         .map_err(|er| {
@@ -418,6 +437,9 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     charge_id: payment_attempt.charge_id.clone(),
                     client_source: payment_attempt.client_source.clone(),
                     client_version: payment_attempt.client_version.clone(),
+                    failover_action: payment_attempt.failover_action.clone(),
+                    unified_error_details: payment_attempt.unified_error_details.clone(),
+                    amount_reversed: payment_attempt.amount_reversed,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -495,6 +517,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             }
             MerchantStorageScheme::RedisKv => {
                 let key_str = key.to_string();
+                let status_transition = StatusTransition::for_attempt(&this, &payment_attempt)?;
                 let old_connector_transaction_id = &this.connector_transaction_id;
                 let old_preprocessing_id = &this.preprocessing_step_id;
                 let updated_attempt = PaymentAttempt::from_storage_model(
@@ -587,6 +610,10 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                 .try_into_hset()
                 .change_context(errors::StorageError::KVError)?;
 
+                self.router_store
+                    .record_status_transition(status_transition, self.request_id.clone())
+                    .await;
+
                 Ok(updated_attempt)
             }
         }
@@ -1054,6 +1081,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         payment_method_type: Option<Vec<PaymentMethodType>>,
         authentication_type: Option<Vec<AuthenticationType>>,
         merchant_connector_id: Option<Vec<String>>,
+        card_network: Option<Vec<CardNetwork>>,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
         self.router_store
@@ -1065,6 +1093,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                 payment_method_type,
                 authentication_type,
                 merchant_connector_id,
+                card_network,
                 storage_scheme,
             )
             .await
@@ -1202,6 +1231,11 @@ impl DataModelExt for PaymentAttempt {
             charge_id: self.charge_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            failover_action: self.failover_action,
+            unified_error_details: self.unified_error_details,
+            amount_reversed: self
+                .amount_reversed
+                .map(|amount_reversed| amount_reversed.get_amount_as_i64()),
         }
     }
 
@@ -1268,6 +1302,9 @@ impl DataModelExt for PaymentAttempt {
             charge_id: storage_model.charge_id,
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
+            failover_action: storage_model.failover_action,
+            unified_error_details: storage_model.unified_error_details,
+            amount_reversed: storage_model.amount_reversed.map(MinorUnit::new),
         }
     }
 }
@@ -1339,6 +1376,11 @@ impl DataModelExt for PaymentAttemptNew {
             charge_id: self.charge_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            failover_action: self.failover_action,
+            unified_error_details: self.unified_error_details,
+            amount_reversed: self
+                .amount_reversed
+                .map(|amount_reversed| amount_reversed.get_amount_as_i64()),
         }
     }
 
@@ -1404,6 +1446,9 @@ impl DataModelExt for PaymentAttemptNew {
             charge_id: storage_model.charge_id,
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
+            failover_action: storage_model.failover_action,
+            unified_error_details: storage_model.unified_error_details,
+            amount_reversed: storage_model.amount_reversed.map(MinorUnit::new),
         }
     }
 }
@@ -1648,6 +1693,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 updated_by,
                 unified_code,
                 unified_message,
+                unified_error_details,
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
@@ -1662,6 +1708,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 updated_by,
                 unified_code,
                 unified_message,
+                unified_error_details,
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
@@ -1765,6 +1812,20 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
             },
+            Self::FailoverUpdate {
+                failover_action,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::FailoverUpdate {
+                failover_action,
+                updated_by,
+            },
+            Self::AuthorizationReversalUpdate {
+                amount_reversed,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::AuthorizationReversalUpdate {
+                amount_reversed: amount_reversed.get_amount_as_i64(),
+                updated_by,
+            },
         }
     }
 
@@ -1998,6 +2059,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 updated_by,
                 unified_code,
                 unified_message,
+                unified_error_details,
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
@@ -2011,6 +2073,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 updated_by,
                 unified_code,
                 unified_message,
+                unified_error_details,
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
@@ -2113,6 +2176,20 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
             },
+            DieselPaymentAttemptUpdate::FailoverUpdate {
+                failover_action,
+                updated_by,
+            } => Self::FailoverUpdate {
+                failover_action,
+                updated_by,
+            },
+            DieselPaymentAttemptUpdate::AuthorizationReversalUpdate {
+                amount_reversed,
+                updated_by,
+            } => Self::AuthorizationReversalUpdate {
+                amount_reversed: MinorUnit::new(amount_reversed),
+                updated_by,
+            },
         }
     }
 }
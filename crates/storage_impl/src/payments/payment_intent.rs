@@ -2,11 +2,13 @@
 use api_models::payments::AmountFilter;
 #[cfg(feature = "olap")]
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
-#[cfg(feature = "olap")]
-use common_utils::errors::ReportSwitchExt;
 use common_utils::ext_traits::{AsyncExt, Encode};
 #[cfg(feature = "olap")]
-use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+    associations::HasTable,
+    pg::{PgAnyJsonExpressionMethods, PgJsonbExpressionMethods},
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl,
+};
 use diesel_models::{
     enums::MerchantStorageScheme,
     kv,
@@ -39,15 +41,16 @@ use redis_interface::HsetnxReply;
 use router_env::logger;
 use router_env::{instrument, tracing};
 
-#[cfg(feature = "olap")]
-use crate::connection;
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
+    payments::status_transition::StatusTransition,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
     utils::{self, pg_connection_read, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
+#[cfg(feature = "olap")]
+use crate::{utils::pg_connection_read_for, ReadQuery};
 
 #[async_trait::async_trait]
 impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
@@ -152,6 +155,8 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             }
             MerchantStorageScheme::RedisKv => {
                 let key_str = key.to_string();
+                let status_transition =
+                    StatusTransition::for_intent(&this, &payment_intent_update)?;
 
                 let diesel_intent_update = DieselPaymentIntentUpdate::from(payment_intent_update);
                 let origin_diesel_intent = this
@@ -189,6 +194,10 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                 .try_into_hset()
                 .change_context(StorageError::KVError)?;
 
+                self.router_store
+                    .record_status_transition(status_transition, self.request_id.clone())
+                    .await;
+
                 let payment_intent =
                     PaymentIntent::convert_back(diesel_intent, merchant_key_store.key.get_inner())
                         .await
@@ -380,6 +389,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         merchant_key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, StorageError> {
+        let status_transition = StatusTransition::for_intent(&this, &payment_intent)?;
         let conn = pg_connection_write(self).await?;
         let diesel_payment_intent_update = DieselPaymentIntentUpdate::from(payment_intent);
 
@@ -393,6 +403,8 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 let new_err = diesel_error_to_data_error(er.current_context());
                 er.change_context(new_err)
             })?;
+        self.record_status_transition(status_transition, self.request_id.clone())
+            .await;
 
         PaymentIntent::convert_back(diesel_payment_intent, merchant_key_store.key.get_inner())
             .await
@@ -463,10 +475,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         merchant_key_store: &MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
-        use common_utils::errors::ReportSwitchExt;
         use futures::{future::try_join_all, FutureExt};
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = pg_connection_read_for(self, ReadQuery::List).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
@@ -488,6 +499,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(order_id) = &params.order_id {
+                    query = query.filter(pi_dsl::order_id.eq(order_id.clone()));
+                }
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
@@ -606,7 +620,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
         use futures::{future::try_join_all, FutureExt};
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = pg_connection_read_for(self, ReadQuery::List).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .inner_join(
@@ -614,7 +628,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     .on(pa_dsl::attempt_id.eq(pi_dsl::active_attempt_id)),
             )
             .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(pi_dsl::created_at.desc())
+            .order((pi_dsl::created_at.desc(), pi_dsl::payment_id.desc()))
             .into_boxed();
 
         query = match constraints {
@@ -626,9 +640,24 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     query = query.limit(limit.into());
                 }
 
+                // The payments are listed from the most recent, so the next page holds the
+                // payments created before the last payment of the previous page
+                if let Some(cursor) = &params.cursor {
+                    query = query.filter(
+                        pi_dsl::created_at
+                            .lt(cursor.created_at)
+                            .or(pi_dsl::created_at
+                                .eq(cursor.created_at)
+                                .and(pi_dsl::payment_id.lt(cursor.id.clone()))),
+                    );
+                }
+
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(order_id) = &params.order_id {
+                    query = query.filter(pi_dsl::order_id.eq(order_id.clone()));
+                }
 
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
@@ -734,6 +763,32 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     None => query,
                 };
 
+                if let Some(card_network) = &params.card_network {
+                    let card_network = card_network
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>();
+                    query = query.filter(
+                        pa_dsl::payment_method_data
+                            .retrieve_as_object("card")
+                            .retrieve_as_text("card_network")
+                            .eq_any(card_network),
+                    );
+                }
+
+                if let Some(metadata) = &params.metadata {
+                    query = query.filter(
+                        pi_dsl::metadata.contains(serde_json::Value::Object(metadata.clone())),
+                    );
+                }
+
+                if let Some(custom_fields) = &params.custom_fields {
+                    query = query.filter(
+                        pi_dsl::custom_fields
+                            .contains(serde_json::Value::Object(custom_fields.clone())),
+                    );
+                }
+
                 query
             }
         };
@@ -772,7 +827,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = pg_connection_read_for(self, ReadQuery::Analytics).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .select(pi_dsl::active_attempt_id)
@@ -788,6 +843,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(order_id) = &params.order_id {
+                    query = query.filter(pi_dsl::order_id.eq(order_id.clone()));
+                }
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
@@ -828,6 +886,19 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     None => query,
                 };
 
+                if let Some(metadata) = &params.metadata {
+                    query = query.filter(
+                        pi_dsl::metadata.contains(serde_json::Value::Object(metadata.clone())),
+                    );
+                }
+
+                if let Some(custom_fields) = &params.custom_fields {
+                    query = query.filter(
+                        pi_dsl::custom_fields
+                            .contains(serde_json::Value::Object(custom_fields.clone())),
+                    );
+                }
+
                 query
             }
         };
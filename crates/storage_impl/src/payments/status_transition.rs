@@ -0,0 +1,130 @@
+use diesel_models::{
+    enums::{PaymentStatusActor, PaymentStatusObject},
+    payment_status_transition::PaymentStatusTransitionNew,
+};
+use hyperswitch_domain_models::{
+    errors::StorageError,
+    payments::{
+        payment_attempt::{PaymentAttempt, PaymentAttemptUpdate},
+        payment_intent::{PaymentIntentUpdate, PaymentIntentUpdateInternal},
+        state_machine, PaymentIntent,
+    },
+};
+use router_env::logger;
+
+use crate::{utils::pg_connection_write, DataModelExt, DatabaseStore, KVRouterStore, RouterStore};
+
+const TRANSITION_ID_PREFIX: &str = "pst";
+
+/// A status transition of a payment intent or attempt, to be validated against the state machine
+/// before the update is stored and recorded in the history of the payment once it is stored
+pub(crate) struct StatusTransition {
+    merchant_id: String,
+    payment_id: String,
+    attempt_id: Option<String>,
+    object_type: PaymentStatusObject,
+    previous_status: String,
+    status: String,
+}
+
+impl StatusTransition {
+    /// The transition of the status of the intent made by the update, if its status changes
+    pub(crate) fn for_intent(
+        this: &PaymentIntent,
+        payment_intent_update: &PaymentIntentUpdate,
+    ) -> error_stack::Result<Option<Self>, StorageError> {
+        let status = PaymentIntentUpdateInternal::from(payment_intent_update.clone())
+            .status
+            .unwrap_or(this.status);
+        if status == this.status {
+            return Ok(None);
+        }
+        state_machine::validate_intent_transition(this.status, status)?;
+
+        Ok(Some(Self {
+            merchant_id: this.merchant_id.clone(),
+            payment_id: this.payment_id.clone(),
+            attempt_id: None,
+            object_type: PaymentStatusObject::PaymentIntent,
+            previous_status: this.status.to_string(),
+            status: status.to_string(),
+        }))
+    }
+
+    /// The transition of the status of the attempt made by the update, if its status changes
+    pub(crate) fn for_attempt(
+        this: &PaymentAttempt,
+        payment_attempt_update: &PaymentAttemptUpdate,
+    ) -> error_stack::Result<Option<Self>, StorageError> {
+        let status = payment_attempt_update
+            .clone()
+            .to_storage_model()
+            .apply_changeset(this.clone().to_storage_model())
+            .status;
+        if status == this.status {
+            return Ok(None);
+        }
+        state_machine::validate_attempt_transition(this.status, status)?;
+
+        Ok(Some(Self {
+            merchant_id: this.merchant_id.clone(),
+            payment_id: this.payment_id.clone(),
+            attempt_id: Some(this.attempt_id.clone()),
+            object_type: PaymentStatusObject::PaymentAttempt,
+            previous_status: this.status.to_string(),
+            status: status.to_string(),
+        }))
+    }
+}
+
+impl<T: DatabaseStore> RouterStore<T> {
+    /// The status transitions of the payments updated through the store are recorded with this
+    /// actor
+    pub fn set_status_actor(&mut self, status_actor: PaymentStatusActor) {
+        self.status_actor = status_actor;
+    }
+
+    /// Records a status transition in the history of the payment. The history does not affect the
+    /// payment flows, so failures are only logged.
+    pub(crate) async fn record_status_transition(
+        &self,
+        transition: Option<StatusTransition>,
+        request_id: Option<String>,
+    ) {
+        let Some(transition) = transition else {
+            return;
+        };
+
+        let payment_id = transition.payment_id.clone();
+        let new = PaymentStatusTransitionNew {
+            transition_id: common_utils::generate_time_ordered_id(TRANSITION_ID_PREFIX),
+            merchant_id: transition.merchant_id,
+            payment_id: transition.payment_id,
+            attempt_id: transition.attempt_id,
+            object_type: transition.object_type,
+            previous_status: transition.previous_status,
+            status: transition.status,
+            actor: self.status_actor,
+            request_id,
+            created_at: common_utils::date_time::now(),
+        };
+        let conn = match pg_connection_write(self).await {
+            Ok(conn) => conn,
+            Err(error) => {
+                logger::error!(?error, %payment_id, "Failed to record the status transition of the payment");
+                return;
+            }
+        };
+        if let Err(error) = new.insert(&conn).await {
+            logger::error!(?error, %payment_id, "Failed to record the status transition of the payment");
+        }
+    }
+}
+
+impl<T: DatabaseStore> KVRouterStore<T> {
+    /// The status transitions of the payments updated through the store are recorded with this
+    /// actor
+    pub fn set_status_actor(&mut self, status_actor: PaymentStatusActor) {
+        self.router_store.set_status_actor(status_actor);
+    }
+}
@@ -1,12 +1,17 @@
 pub mod cache;
 pub mod kv_store;
 pub mod pub_sub;
+pub mod region;
 
 use std::sync::{atomic, Arc};
 
-use router_env::tracing::Instrument;
+use router_env::{logger, metrics::add_attributes, tracing::Instrument};
 
 use self::{kv_store::RedisConnInterface, pub_sub::PubSubInterface};
+use crate::metrics;
+
+/// Interval at which the connection status of the shards of redis is recorded
+const SHARD_HEALTH_COLLECTION_INTERVAL_IN_SECS: u64 = 15;
 
 #[derive(Clone)]
 pub struct RedisStore {
@@ -40,6 +45,38 @@ impl RedisStore {
             .in_current_span(),
         );
     }
+
+    /// Periodically records whether every shard of redis is connected, so that a shard lost to a
+    /// failover, or a partitioned cluster, can be alerted upon
+    pub fn spawn_shard_health_collector(&self) {
+        let redis_conn = self.redis_conn.clone();
+        let _task_handle = tokio::spawn(
+            async move {
+                loop {
+                    match redis_conn.get_shards_health().await {
+                        Ok(shards) => {
+                            for shard in shards {
+                                metrics::REDIS_SHARD_CONNECTED.observe(
+                                    &metrics::CONTEXT,
+                                    u64::from(shard.is_connected),
+                                    &add_attributes([("shard", shard.shard)]),
+                                );
+                            }
+                        }
+                        Err(error) => {
+                            logger::error!(?error, "Failed to get the health of the redis shards")
+                        }
+                    }
+
+                    tokio::time::sleep(std::time::Duration::from_secs(
+                        SHARD_HEALTH_COLLECTION_INTERVAL_IN_SECS,
+                    ))
+                    .await;
+                }
+            }
+            .in_current_span(),
+        );
+    }
 }
 
 impl RedisConnInterface for RedisStore {
@@ -1,4 +1,11 @@
-use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use common_utils::{
     errors::{self, CustomResult},
@@ -6,18 +13,20 @@ use common_utils::{
 };
 use dyn_clone::DynClone;
 use error_stack::{Report, ResultExt};
-use moka::future::Cache as MokaCache;
-use once_cell::sync::Lazy;
+use moka::{future::Cache as MokaCache, policy::EvictionPolicy};
+use once_cell::sync::{Lazy, OnceCell};
 use redis_interface::{errors::RedisError, RedisConnectionPool, RedisValue};
 use router_env::{
+    logger,
     metrics::add_attributes,
     tracing::{self, instrument},
 };
 
 use crate::{
+    config::InMemoryCacheSettings,
     errors::StorageError,
     metrics,
-    redis::{PubSubInterface, RedisConnInterface},
+    redis::{region, PubSubInterface, RedisConnInterface},
 };
 
 /// Redis channel name used for publishing invalidation messages
@@ -41,6 +50,9 @@ const SURCHARGE_CACHE_PREFIX: &str = "surcharge";
 /// Prefix for cgraph cache key
 const CGRAPH_CACHE_PREFIX: &str = "cgraph";
 
+/// Prefix for the invalidation of all the cgraphs
+const ALL_CGRAPHS_CACHE_PREFIX: &str = "all_cgraphs";
+
 /// Prefix for all kinds of cache key
 const ALL_CACHE_PREFIX: &str = "all_cache_kind";
 
@@ -50,8 +62,11 @@ const CACHE_TTL: u64 = 30 * 60;
 /// Time to idle 10 mins
 const CACHE_TTI: u64 = 10 * 60;
 
-/// Max Capacity of Cache in MB
-const MAX_CAPACITY: u64 = 30;
+/// Max number of entries of a cache, unless configured otherwise
+const MAX_ENTRIES: u64 = 10_000;
+
+/// Time to live and time to idle of the routing algorithms found missing, 1 min
+const MISSING_ROUTING_ALGORITHM_CACHE_TTL: u64 = 60;
 
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
 pub static CONFIG_CACHE: Lazy<Cache> =
@@ -59,11 +74,22 @@ pub static CONFIG_CACHE: Lazy<Cache> =
 
 /// Accounts cache with time_to_live as 30 mins and size limit
 pub static ACCOUNTS_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("ACCOUNTS_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+    Lazy::new(|| Cache::new("ACCOUNTS_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_ENTRIES)));
 
 /// Routing Cache
 pub static ROUTING_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("ROUTING_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+    Lazy::new(|| Cache::new("ROUTING_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_ENTRIES)));
+
+/// Routing algorithms found missing from the database, cached briefly so that the payments of a
+/// misconfigured business profile do not query the database every time
+pub static MISSING_ROUTING_ALGORITHM_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "MISSING_ROUTING_ALGORITHM_CACHE",
+        MISSING_ROUTING_ALGORITHM_CACHE_TTL,
+        MISSING_ROUTING_ALGORITHM_CACHE_TTL,
+        Some(MAX_ENTRIES),
+    )
+});
 
 /// 3DS Decision Manager Cache
 pub static DECISION_MANAGER_CACHE: Lazy<Cache> = Lazy::new(|| {
@@ -71,17 +97,62 @@ pub static DECISION_MANAGER_CACHE: Lazy<Cache> = Lazy::new(|| {
         "DECISION_MANAGER_CACHE",
         CACHE_TTL,
         CACHE_TTI,
-        Some(MAX_CAPACITY),
+        Some(MAX_ENTRIES),
     )
 });
 
 /// Surcharge Cache
 pub static SURCHARGE_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("SURCHARGE_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+    Lazy::new(|| Cache::new("SURCHARGE_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_ENTRIES)));
 
 /// CGraph Cache
 pub static CGRAPH_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("CGRAPH_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+    Lazy::new(|| Cache::new("CGRAPH_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_ENTRIES)));
+
+/// Names of all the in-memory caches
+pub const CACHE_NAMES: [&str; 7] = [
+    "CONFIG_CACHE",
+    "ACCOUNTS_CACHE",
+    "ROUTING_CACHE",
+    "MISSING_ROUTING_ALGORITHM_CACHE",
+    "DECISION_MANAGER_CACHE",
+    "SURCHARGE_CACHE",
+    "CGRAPH_CACHE",
+];
+
+/// Max number of entries of the caches, as configured for the deployment
+static CACHE_LIMITS: OnceCell<HashMap<String, u64>> = OnceCell::new();
+
+/// Sets the configured limits of the caches. Only the first call has an effect, and it has to
+/// happen before any cache is used, as the caches are built on their first use.
+pub fn init_limits(settings: &InMemoryCacheSettings) {
+    let limits = settings
+        .limits
+        .iter()
+        .map(|(name, limit)| (name.clone(), limit.max_entries))
+        .collect();
+    if CACHE_LIMITS.set(limits).is_err() {
+        logger::warn!("In-memory cache limits were already initialized");
+    }
+}
+
+/// All the in-memory caches
+pub fn all_caches() -> [&'static Cache; 7] {
+    [
+        &CONFIG_CACHE,
+        &ACCOUNTS_CACHE,
+        &ROUTING_CACHE,
+        &MISSING_ROUTING_ALGORITHM_CACHE,
+        &DECISION_MANAGER_CACHE,
+        &SURCHARGE_CACHE,
+        &CGRAPH_CACHE,
+    ]
+}
+
+/// The in-memory cache with the given name, e.g. `ROUTING_CACHE`
+pub fn get_cache_by_name(name: &str) -> Option<&'static Cache> {
+    all_caches().into_iter().find(|cache| cache.name() == name)
+}
 
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
@@ -95,6 +166,8 @@ pub enum CacheKind<'a> {
     DecisionManager(Cow<'a, str>),
     Surcharge(Cow<'a, str>),
     CGraph(Cow<'a, str>),
+    /// Invalidates all the cgraphs, built from configuration shared by every merchant
+    AllCGraphs,
     All(Cow<'a, str>),
 }
 
@@ -107,6 +180,7 @@ impl<'a> From<CacheKind<'a>> for RedisValue {
             CacheKind::DecisionManager(s) => format!("{DECISION_MANAGER_CACHE_PREFIX},{s}"),
             CacheKind::Surcharge(s) => format!("{SURCHARGE_CACHE_PREFIX},{s}"),
             CacheKind::CGraph(s) => format!("{CGRAPH_CACHE_PREFIX},{s}"),
+            CacheKind::AllCGraphs => format!("{ALL_CGRAPHS_CACHE_PREFIX},"),
             CacheKind::All(s) => format!("{ALL_CACHE_PREFIX},{s}"),
         };
         Self::from_string(value)
@@ -130,6 +204,7 @@ impl<'a> TryFrom<RedisValue> for CacheKind<'a> {
             }
             SURCHARGE_CACHE_PREFIX => Ok(Self::Surcharge(Cow::Owned(split.1.to_string()))),
             CGRAPH_CACHE_PREFIX => Ok(Self::CGraph(Cow::Owned(split.1.to_string()))),
+            ALL_CGRAPHS_CACHE_PREFIX => Ok(Self::AllCGraphs),
             ALL_CACHE_PREFIX => Ok(Self::All(Cow::Owned(split.1.to_string()))),
             _ => Err(validation_err.into()),
         }
@@ -150,6 +225,9 @@ dyn_clone::clone_trait_object!(Cacheable);
 pub struct Cache {
     name: &'static str,
     inner: MokaCache<String, Arc<dyn Cacheable>>,
+    /// The instants at which the keys were last invalidated, recorded only in multi-region
+    /// deployments
+    invalidated_at: MokaCache<String, Instant>,
 }
 
 #[derive(Debug, Clone)]
@@ -175,12 +253,13 @@ impl Cache {
     /// `name`        : Cache type name to be used as an attribute in metrics
     /// `time_to_live`: Time in seconds before an object is stored in a caching system before it’s deleted
     /// `time_to_idle`: Time in seconds before a `get` or `insert` operation an object is stored in a caching system before it's deleted
-    /// `max_capacity`: Max size in MB's that the cache can hold
+    /// `max_entries` : Max number of entries that the cache can hold, overridden by the limit
+    ///                 configured for the cache. The least recently used entries are evicted first.
     pub fn new(
         name: &'static str,
         time_to_live: u64,
         time_to_idle: u64,
-        max_capacity: Option<u64>,
+        max_entries: Option<u64>,
     ) -> Self {
         // Record the metrics of manual invalidation of cache entry by the application
         let eviction_listener = move |_, _, cause| {
@@ -194,22 +273,55 @@ impl Cache {
             );
         };
         let mut cache_builder = MokaCache::builder()
-            .time_to_live(std::time::Duration::from_secs(time_to_live))
-            .time_to_idle(std::time::Duration::from_secs(time_to_idle))
+            .time_to_live(Duration::from_secs(time_to_live))
+            .time_to_idle(Duration::from_secs(time_to_idle))
             .eviction_listener(eviction_listener);
 
-        if let Some(capacity) = max_capacity {
-            cache_builder = cache_builder.max_capacity(capacity * 1024 * 1024);
+        let configured_max_entries = CACHE_LIMITS
+            .get()
+            .and_then(|limits| limits.get(name))
+            .copied();
+        if let Some(max_entries) = configured_max_entries.or(max_entries) {
+            cache_builder = cache_builder
+                .max_capacity(max_entries)
+                .eviction_policy(EvictionPolicy::lru());
         }
 
         Self {
             name,
             inner: cache_builder.build(),
+            invalidated_at: MokaCache::builder()
+                .time_to_live(Duration::from_secs(time_to_live))
+                .build(),
         }
     }
 
+    /// Caches the value, unless the key was invalidated within the replication lag tolerance, in
+    /// which case the value may have been read from a database replica which is not up to date yet
     pub async fn push<T: Cacheable>(&self, key: CacheKey, val: T) {
-        self.inner.insert(key.into(), Arc::new(val)).await;
+        let key: String = key.into();
+        if self.is_within_replication_lag(&key).await {
+            return;
+        }
+        self.inner.insert(key, Arc::new(val)).await;
+    }
+
+    /// Whether the key was invalidated so recently that the replicas may still hold the values
+    /// prior to the invalidation
+    pub async fn is_settling(&self, key: CacheKey) -> bool {
+        let key: String = key.into();
+        self.is_within_replication_lag(&key).await
+    }
+
+    async fn is_within_replication_lag(&self, key: &str) -> bool {
+        let tolerance = region::replication_lag_tolerance();
+        if tolerance.is_zero() {
+            return false;
+        }
+        self.invalidated_at
+            .get(key)
+            .await
+            .is_some_and(|invalidated_at| invalidated_at.elapsed() < tolerance)
     }
 
     pub async fn get_val<T: Clone + Cacheable>(&self, key: CacheKey) -> Option<T> {
@@ -241,7 +353,18 @@ impl Cache {
     }
 
     pub async fn remove(&self, key: CacheKey) {
-        self.inner.invalidate::<String>(&key.into()).await;
+        let key: String = key.into();
+        if !region::replication_lag_tolerance().is_zero() {
+            self.invalidated_at
+                .insert(key.clone(), Instant::now())
+                .await;
+        }
+        self.inner.invalidate::<String>(&key).await;
+    }
+
+    /// Removes all the entries of the cache, of every tenant
+    pub async fn remove_all(&self) {
+        self.inner.invalidate_all();
     }
 
     /// Performs any pending maintenance operations needed by the cache.
@@ -258,6 +381,20 @@ impl Cache {
         self.name
     }
 
+    /// The limits and the number of entries of the cache
+    pub async fn stats(&self) -> CacheStats {
+        self.run_pending_tasks().await;
+        let policy = self.inner.policy();
+
+        CacheStats {
+            name: self.name,
+            entry_count: self.get_entry_count(),
+            max_entries: policy.max_capacity(),
+            time_to_live: policy.time_to_live(),
+            time_to_idle: policy.time_to_idle(),
+        }
+    }
+
     pub async fn record_entry_count_metric(&self) {
         self.run_pending_tasks().await;
 
@@ -269,6 +406,15 @@ impl Cache {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct CacheStats {
+    pub name: &'static str,
+    pub entry_count: u64,
+    pub max_entries: Option<u64>,
+    pub time_to_live: Option<Duration>,
+    pub time_to_idle: Option<Duration>,
+}
+
 #[instrument(skip_all)]
 pub async fn get_or_populate_redis<T, F, Fut>(
     redis: &Arc<RedisConnectionPool>,
@@ -281,6 +427,7 @@ where
     Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
 {
     let type_name = std::any::type_name::<T>();
+    let key = region::region_scoped_key(key.as_ref());
     let key = key.as_ref();
     let redis_val = redis.get_and_deserialize_key::<T>(key, type_name).await;
     let get_data_set_redis = || async {
@@ -322,12 +469,16 @@ where
             RedisError::RedisConnectionError.into(),
         ))
         .attach_printable("Failed to get redis connection")?;
-    let cache_val = cache
-        .get_val::<T>(CacheKey {
-            key: key.to_string(),
-            prefix: redis.key_prefix.clone(),
-        })
-        .await;
+    let cache_key = CacheKey {
+        key: key.to_string(),
+        prefix: redis.key_prefix.clone(),
+    };
+    // Values read right after an invalidation are neither cached in memory nor in redis, as the
+    // database replica they are read from may be lagging behind
+    if cache.is_settling(cache_key.clone()).await {
+        return fun().await;
+    }
+    let cache_val = cache.get_val::<T>(cache_key).await;
     if let Some(val) = cache_val {
         Ok(val)
     } else {
@@ -371,7 +522,7 @@ where
     in_memory.async_map(|cache| cache.remove(tenant_key)).await;
 
     redis_conn
-        .delete_key(key)
+        .delete_key(&region::region_scoped_key(key))
         .await
         .change_context(StorageError::KVError)?;
     Ok(data)
@@ -484,6 +635,42 @@ mod cache_tests {
         );
     }
 
+    #[tokio::test]
+    async fn eviction_of_least_recently_used_test() {
+        let cache = Cache::new("test", 1800, 1800, Some(1));
+        for key in ["old_key", "new_key"] {
+            cache
+                .push(
+                    CacheKey {
+                        key: key.to_string(),
+                        prefix: "prefix".to_string(),
+                    },
+                    "val".to_string(),
+                )
+                .await;
+        }
+        cache.run_pending_tasks().await;
+
+        assert_eq!(
+            cache
+                .get_val::<String>(CacheKey {
+                    key: "old_key".to_string(),
+                    prefix: "prefix".to_string()
+                })
+                .await,
+            None
+        );
+        assert_eq!(
+            cache
+                .get_val::<String>(CacheKey {
+                    key: "new_key".to_string(),
+                    prefix: "prefix".to_string()
+                })
+                .await,
+            Some(String::from("val"))
+        );
+    }
+
     #[tokio::test]
     async fn invalidate_cache_for_key() {
         let cache = Cache::new("test", 1800, 1800, None);
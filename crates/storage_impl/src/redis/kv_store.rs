@@ -47,6 +47,35 @@ pub enum PartitionKey<'a> {
         mandate_id: &'a str,
     },
 }
+impl<'a> PartitionKey<'a> {
+    /// The merchant the partition belongs to, if the partition is specific to a merchant
+    pub fn merchant_id(&self) -> Option<&'a str> {
+        match *self {
+            PartitionKey::MerchantIdPaymentId { merchant_id, .. }
+            | PartitionKey::MerchantIdCustomerId { merchant_id, .. }
+            | PartitionKey::MerchantIdPayoutId { merchant_id, .. }
+            | PartitionKey::MerchantIdPayoutAttemptId { merchant_id, .. }
+            | PartitionKey::MerchantIdMandateId { merchant_id, .. } => Some(merchant_id),
+            PartitionKey::CombinationKey { .. } => None,
+        }
+    }
+}
+
+/// The KV settings of a merchant, stored as a config under [`get_merchant_kv_settings_key`]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKvSettings {
+    /// The number of drainer shards the writes of the merchant are spread across, all the shards
+    /// when not set
+    pub shard_count: Option<u8>,
+}
+
+/// Key of the config holding the KV settings of the merchant. The config is cached as
+/// [`MerchantKvSettings`] rather than as a config, so it must only be read from the database
+/// when read as a config
+pub fn get_merchant_kv_settings_key(merchant_id: &str) -> String {
+    format!("kv_settings_{merchant_id}")
+}
+
 // PartitionKey::MerchantIdPaymentId {merchant_id, payment_id}
 impl<'a> std::fmt::Display for PartitionKey<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -2,11 +2,14 @@ use std::sync::atomic;
 
 use error_stack::ResultExt;
 use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
-use router_env::{logger, tracing::Instrument};
-
-use crate::redis::cache::{
-    CacheKey, CacheKind, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE, DECISION_MANAGER_CACHE,
-    ROUTING_CACHE, SURCHARGE_CACHE,
+use router_env::{logger, metrics::add_attributes, tracing::Instrument};
+
+use crate::{
+    metrics,
+    redis::cache::{
+        CacheKey, CacheKind, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE, DECISION_MANAGER_CACHE,
+        MISSING_ROUTING_ALGORITHM_CACHE, ROUTING_CACHE, SURCHARGE_CACHE,
+    },
 };
 
 #[async_trait::async_trait]
@@ -66,10 +69,32 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
         channel: &str,
         key: CacheKind<'a>,
     ) -> error_stack::Result<usize, redis_errors::RedisError> {
-        self.publisher
-            .publish(channel, RedisValue::from(key).into_inner())
+        let value = RedisValue::from(key).into_inner();
+        let receivers = self
+            .publisher
+            .publish(channel, value.clone())
             .await
-            .change_context(redis_errors::RedisError::SubscribeError)
+            .change_context(redis_errors::RedisError::SubscribeError)?;
+
+        // The messages received are never forwarded again, the invalidation being published in
+        // the peer regions only by the region it originates from. A peer region not being
+        // reachable is not failing the invalidation, its cached values expiring on their own
+        for (region, redis_conn) in super::region::peer_redis_conns() {
+            if let Err(error) = redis_conn
+                .publisher
+                .publish::<usize, _, _>(channel, value.clone())
+                .await
+            {
+                logger::error!(?error, %region, "Failed to publish the invalidation to peer region");
+                metrics::PEER_REGION_INVALIDATION_FAILURE.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("region", region.to_owned())]),
+                );
+            }
+        }
+
+        Ok(receivers)
     }
 
     #[inline]
@@ -93,6 +118,15 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                     };
 
                     let key = match key {
+                        CacheKind::AllCGraphs => {
+                            // The cgraphs are only cached in memory, there is no redis key to
+                            // delete
+                            CGRAPH_CACHE.remove_all().await;
+                            logger::debug!(
+                                "Handled message on channel {channel_name} - Done invalidating all the cgraphs"
+                            );
+                            continue;
+                        }
                         CacheKind::Config(key) => {
                             CONFIG_CACHE
                                 .remove(CacheKey {
@@ -127,6 +161,13 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                     prefix: self.key_prefix.clone(),
                                 })
                                 .await;
+                            // The routing algorithm activated may be the one found missing
+                            MISSING_ROUTING_ALGORITHM_CACHE
+                                .remove(CacheKey {
+                                    key: key.to_string(),
+                                    prefix: self.key_prefix.clone(),
+                                })
+                                .await;
                             key
                         }
                         CacheKind::DecisionManager(key) => {
@@ -172,6 +213,12 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                     prefix: self.key_prefix.clone(),
                                 })
                                 .await;
+                            MISSING_ROUTING_ALGORITHM_CACHE
+                                .remove(CacheKey {
+                                    key: key.to_string(),
+                                    prefix: self.key_prefix.clone(),
+                                })
+                                .await;
                             DECISION_MANAGER_CACHE
                                 .remove(CacheKey {
                                     key: key.to_string(),
@@ -189,7 +236,7 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                         }
                     };
 
-                    self.delete_key(key.as_ref())
+                    self.delete_key(&super::region::region_scoped_key(key.as_ref()))
                         .await
                         .map_err(|err| logger::error!("Error while deleting redis key: {err:?}"))
                         .ok();
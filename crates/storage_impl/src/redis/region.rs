@@ -0,0 +1,112 @@
+//! State of a deployment running in several regions at once.
+//!
+//! Every region has its own Redis and in-memory caches, so the invalidations published in a region
+//! are forwarded to the Redis of the other regions, the cached values are stored under keys scoped
+//! to the region and the API locks are all held in a single region.
+
+use std::{borrow::Cow, sync::Arc, time::Duration};
+
+use error_stack::ResultExt;
+use hyperswitch_domain_models::errors::{StorageError, StorageResult};
+use once_cell::sync::OnceCell;
+use redis_interface::RedisConnectionPool;
+use router_env::logger;
+
+use crate::config::MultiRegion;
+
+static REGION: OnceCell<Region> = OnceCell::new();
+
+struct Region {
+    name: String,
+    peers: Vec<(String, Arc<RedisConnectionPool>)>,
+    lock_redis_conn: Option<Arc<RedisConnectionPool>>,
+    replication_lag_tolerance: Duration,
+}
+
+/// Connects to the Redis of the peer regions, doing nothing unless multi-region is enabled. Only
+/// the first call has an effect.
+pub async fn init(config: &MultiRegion) -> StorageResult<()> {
+    if !config.enabled || REGION.get().is_some() {
+        return Ok(());
+    }
+
+    let mut peers = Vec::with_capacity(config.peer_regions.len());
+    for peer in &config.peer_regions {
+        let redis_conn = RedisConnectionPool::new(&peer.redis)
+            .await
+            .change_context(StorageError::InitializationError)
+            .attach_printable_lazy(|| {
+                format!("Failed to connect to the redis of region {}", peer.name)
+            })?;
+        peers.push((peer.name.clone(), Arc::new(redis_conn)));
+    }
+
+    let lock_redis_conn = match config.lock_region.as_deref() {
+        None => None,
+        Some(lock_region) if lock_region == config.region => None,
+        Some(lock_region) => Some(
+            peers
+                .iter()
+                .find(|(name, _)| name == lock_region)
+                .map(|(_, redis_conn)| redis_conn.clone())
+                .ok_or(StorageError::InitializationError)
+                .attach_printable_lazy(|| {
+                    format!("Lock region {lock_region} is not one of the peer regions")
+                })?,
+        ),
+    };
+
+    let region = Region {
+        name: config.region.clone(),
+        peers,
+        lock_redis_conn,
+        replication_lag_tolerance: Duration::from_secs(config.replication_lag_tolerance_in_secs),
+    };
+    if REGION.set(region).is_err() {
+        logger::warn!("Multi-region state was already initialized");
+    }
+
+    Ok(())
+}
+
+/// The name of the region this instance runs in, if multi-region is enabled
+pub fn current_region() -> Option<&'static str> {
+    REGION.get().map(|region| region.name.as_str())
+}
+
+/// The Redis of the other regions, empty unless multi-region is enabled
+pub fn peer_redis_conns() -> &'static [(String, Arc<RedisConnectionPool>)] {
+    REGION
+        .get()
+        .map(|region| region.peers.as_slice())
+        .unwrap_or_default()
+}
+
+/// Scopes the key of a value cached in Redis to the region, so that regions sharing a replicated
+/// Redis never overwrite the values cached by each other
+pub fn region_scoped_key(key: &str) -> Cow<'_, str> {
+    match current_region() {
+        Some(region) => Cow::Owned(format!("{region}:{key}")),
+        None => Cow::Borrowed(key),
+    }
+}
+
+/// The time for which invalidated values must not be cached again, zero unless multi-region is
+/// enabled
+pub fn replication_lag_tolerance() -> Duration {
+    REGION
+        .get()
+        .map(|region| region.replication_lag_tolerance)
+        .unwrap_or_default()
+}
+
+/// The Redis holding the API locks, the lock region's one when it is not the local region
+pub fn lock_redis_conn(local: Arc<RedisConnectionPool>) -> Arc<RedisConnectionPool> {
+    match REGION
+        .get()
+        .and_then(|region| region.lock_redis_conn.as_ref())
+    {
+        Some(redis_conn) => Arc::new(redis_conn.clone(&local.key_prefix)),
+        None => local,
+    }
+}
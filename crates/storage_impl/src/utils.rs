@@ -3,7 +3,7 @@ use diesel::PgConnection;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::StorageError;
 
-use crate::{errors::RedisErrorExt, metrics, DatabaseStore};
+use crate::{errors::RedisErrorExt, metrics, DatabaseStore, ReadQuery};
 
 pub async fn pg_connection_read<T: DatabaseStore>(
     store: &T,
@@ -31,6 +31,22 @@ pub async fn pg_connection_read<T: DatabaseStore>(
         .change_context(StorageError::DatabaseConnectionError)
 }
 
+/// Gets a connection from the pool serving the kind of read, the master pool being used in place
+/// of the replica while the replica is lagging
+pub async fn pg_connection_read_for<T: DatabaseStore>(
+    store: &T,
+    query: ReadQuery,
+) -> error_stack::Result<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    StorageError,
+> {
+    store
+        .get_read_pool(query)
+        .get()
+        .await
+        .change_context(StorageError::DatabaseConnectionError)
+}
+
 pub async fn pg_connection_write<T: DatabaseStore>(
     store: &T,
 ) -> error_stack::Result<
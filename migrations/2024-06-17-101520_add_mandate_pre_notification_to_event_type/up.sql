@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'mandate_pre_notification';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS frm_decision_matrix;
+
+ALTER TABLE fraud_check DROP COLUMN IF EXISTS frm_decision;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS frm_decision_matrix JSONB DEFAULT NULL;
+
+ALTER TABLE fraud_check ADD COLUMN IF NOT EXISTS frm_decision VARCHAR(64) DEFAULT NULL;
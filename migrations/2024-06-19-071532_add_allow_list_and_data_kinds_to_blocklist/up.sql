@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_id';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS list_type VARCHAR(16) NOT NULL DEFAULT 'block';
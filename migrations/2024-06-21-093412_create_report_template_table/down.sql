@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS report_template_merchant_id_index;
+DROP TABLE IF EXISTS report_template;
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS report_template (
+    template_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    name VARCHAR(255) NOT NULL,
+    report_type VARCHAR(32) NOT NULL,
+    columns JSONB NOT NULL,
+    filters JSONB,
+    schedule JSONB NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at TIMESTAMP NOT NULL,
+    last_run_at TIMESTAMP,
+    last_run_status VARCHAR(32),
+    last_file_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS report_template_merchant_id_index ON report_template (merchant_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS settlement_line_merchant_id_recon_status_index;
+DROP INDEX IF EXISTS settlement_line_merchant_id_reference_id_index;
+DROP INDEX IF EXISTS settlement_line_merchant_id_payment_id_index;
+DROP INDEX IF EXISTS settlement_line_merchant_id_batch_id_index;
+DROP TABLE IF EXISTS settlement_line;
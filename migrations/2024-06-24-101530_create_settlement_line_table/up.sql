@@ -0,0 +1,32 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS settlement_line (
+    line_id VARCHAR(64) PRIMARY KEY,
+    batch_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64),
+    line_type VARCHAR(32) NOT NULL,
+    reference_id VARCHAR(255) NOT NULL,
+    payment_reference_id VARCHAR(255),
+    gross_amount BIGINT NOT NULL,
+    fee_amount BIGINT NOT NULL,
+    net_amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    settled_at TIMESTAMP,
+    payment_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    dispute_id VARCHAR(64),
+    recon_status VARCHAR(32) NOT NULL,
+    recon_error_message TEXT,
+    resolution_note TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS settlement_line_merchant_id_batch_id_index ON settlement_line (merchant_id, batch_id);
+
+CREATE INDEX IF NOT EXISTS settlement_line_merchant_id_payment_id_index ON settlement_line (merchant_id, payment_id);
+
+CREATE INDEX IF NOT EXISTS settlement_line_merchant_id_reference_id_index ON settlement_line (merchant_id, connector, line_type, reference_id);
+
+CREATE INDEX IF NOT EXISTS settlement_line_merchant_id_recon_status_index ON settlement_line (merchant_id, recon_status);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS ledger_entry_merchant_id_created_at_index;
+DROP INDEX IF EXISTS ledger_entry_merchant_id_payment_id_index;
+DROP INDEX IF EXISTS ledger_entry_merchant_id_transaction_id_account_index;
+DROP TABLE IF EXISTS ledger_entry;
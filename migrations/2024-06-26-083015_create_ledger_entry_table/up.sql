@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS ledger_entry (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    transaction_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64),
+    account VARCHAR(32) NOT NULL,
+    event_type VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    payment_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    dispute_id VARCHAR(64),
+    payout_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_entry_merchant_id_transaction_id_account_index ON ledger_entry (merchant_id, transaction_id, account);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_merchant_id_payment_id_index ON ledger_entry (merchant_id, payment_id);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_merchant_id_created_at_index ON ledger_entry (merchant_id, created_at);
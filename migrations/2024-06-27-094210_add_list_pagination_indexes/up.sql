@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_created_at_payment_id_index ON payment_intent (merchant_id, created_at DESC, payment_id DESC);
+
+CREATE INDEX IF NOT EXISTS payment_intent_metadata_index ON payment_intent USING GIN (metadata jsonb_path_ops);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_card_network_index ON payment_attempt ((payment_method_data -> 'card' ->> 'card_network'));
+
+CREATE INDEX IF NOT EXISTS refund_merchant_id_created_at_refund_id_index ON refund (merchant_id, created_at DESC, refund_id DESC);
+
+CREATE INDEX IF NOT EXISTS dispute_merchant_id_created_at_dispute_id_index ON dispute (merchant_id, created_at DESC, dispute_id DESC);
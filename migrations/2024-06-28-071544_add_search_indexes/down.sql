@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS dispute_merchant_id_connector_dispute_id_index;
+DROP INDEX IF EXISTS refund_merchant_id_connector_refund_id_index;
+DROP INDEX IF EXISTS payment_intent_merchant_id_customer_id_index;
+DROP INDEX IF EXISTS payment_attempt_merchant_id_card_last4_index;
+DROP INDEX IF EXISTS payment_attempt_merchant_id_connector_response_reference_id_index;
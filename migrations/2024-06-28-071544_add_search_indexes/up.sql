@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_connector_response_reference_id_index ON payment_attempt (merchant_id, connector_response_reference_id);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_card_last4_index ON payment_attempt (merchant_id, (payment_method_data -> 'card' ->> 'last4'));
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_customer_id_index ON payment_intent (merchant_id, customer_id);
+
+CREATE INDEX IF NOT EXISTS refund_merchant_id_connector_refund_id_index ON refund (merchant_id, connector_refund_id);
+
+CREATE INDEX IF NOT EXISTS dispute_merchant_id_connector_dispute_id_index ON dispute (merchant_id, connector_dispute_id);
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'customers';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'customer_redacted';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'customer_redaction_details';
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS card_export_batch_merchant_id_index;
+DROP TABLE IF EXISTS card_export_batch;
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS card_export_batch (
+    export_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    receiving_psp VARCHAR(255) NOT NULL,
+    receiver_public_key TEXT NOT NULL,
+    payment_method_ids JSONB,
+    status VARCHAR(32) NOT NULL,
+    reason TEXT,
+    requested_by VARCHAR(64) NOT NULL,
+    reviewed_by VARCHAR(64),
+    reviewed_at TIMESTAMP,
+    rejection_reason TEXT,
+    total_count INTEGER,
+    exported_count INTEGER,
+    failed_count INTEGER,
+    file_id VARCHAR(64),
+    error_message TEXT,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS card_export_batch_merchant_id_index ON card_export_batch (merchant_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS connector_exchange_merchant_id_expires_at_index;
+DROP INDEX IF EXISTS connector_exchange_merchant_id_payment_id_index;
+DROP TABLE IF EXISTS connector_exchange;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS connector_debug_capture;
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS connector_debug_capture JSONB DEFAULT NULL;
+
+CREATE TABLE IF NOT EXISTS connector_exchange (
+    exchange_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    request_id VARCHAR(64),
+    url TEXT NOT NULL,
+    method VARCHAR(16) NOT NULL,
+    request JSONB,
+    response JSONB,
+    error JSONB,
+    status_code INTEGER NOT NULL,
+    latency_in_ms BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS connector_exchange_merchant_id_payment_id_index ON connector_exchange (merchant_id, payment_id);
+
+CREATE INDEX IF NOT EXISTS connector_exchange_merchant_id_expires_at_index ON connector_exchange (merchant_id, expires_at);
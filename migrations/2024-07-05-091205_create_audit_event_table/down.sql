@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS audit_event_append_only ON audit_event;
+DROP FUNCTION IF EXISTS reject_audit_event_modification;
+DROP INDEX IF EXISTS audit_event_resource_type_resource_id_index;
+DROP INDEX IF EXISTS audit_event_merchant_id_created_at_index;
+DROP TABLE IF EXISTS audit_event;
@@ -0,0 +1,28 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_event (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id VARCHAR(255),
+    resource_type VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    diff JSONB,
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS audit_event_merchant_id_created_at_index ON audit_event (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS audit_event_resource_type_resource_id_index ON audit_event (resource_type, resource_id);
+
+-- The audit log is append-only, the events can neither be modified nor removed once recorded
+CREATE OR REPLACE FUNCTION reject_audit_event_modification() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit events cannot be modified or deleted';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_event_append_only
+    BEFORE UPDATE OR DELETE ON audit_event
+    FOR EACH ROW EXECUTE FUNCTION reject_audit_event_modification();
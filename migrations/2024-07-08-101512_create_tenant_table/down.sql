@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS tenant_redis_key_prefix_index;
+DROP INDEX IF EXISTS tenant_schema_name_index;
+DROP TABLE IF EXISTS tenant;
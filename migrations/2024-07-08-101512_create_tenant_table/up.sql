@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS tenant (
+    tenant_id VARCHAR(64) PRIMARY KEY,
+    tenant_name VARCHAR(255) NOT NULL,
+    base_url TEXT NOT NULL,
+    schema_name VARCHAR(64) NOT NULL,
+    redis_key_prefix VARCHAR(64) NOT NULL,
+    clickhouse_database VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    quotas JSONB,
+    isolation_test_mode BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS tenant_schema_name_index ON tenant (schema_name);
+
+CREATE UNIQUE INDEX IF NOT EXISTS tenant_redis_key_prefix_index ON tenant (redis_key_prefix);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account
+DROP COLUMN IF EXISTS next_connector_account_details,
+DROP COLUMN IF EXISTS previous_connector_account_details,
+DROP COLUMN IF EXISTS credentials_rotation_window_ends_at;
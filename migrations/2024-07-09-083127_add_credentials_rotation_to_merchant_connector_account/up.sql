@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account
+ADD COLUMN IF NOT EXISTS next_connector_account_details BYTEA DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS previous_connector_account_details BYTEA DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS credentials_rotation_window_ends_at TIMESTAMP DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS connector_failover_policy JSONB DEFAULT NULL;
+
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS failover_action VARCHAR(32) DEFAULT NULL;
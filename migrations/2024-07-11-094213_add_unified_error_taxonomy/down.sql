@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE gateway_status_map
+DROP COLUMN IF EXISTS decline_category,
+DROP COLUMN IF EXISTS network_advice_code,
+DROP COLUMN IF EXISTS user_actionable;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS unified_error_details;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map
+ADD COLUMN IF NOT EXISTS decline_category VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS network_advice_code VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS user_actionable BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS unified_error_details JSONB DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS wallet_decryption_key_profile_id_wallet_index;
+
+DROP TABLE IF EXISTS wallet_decryption_key;
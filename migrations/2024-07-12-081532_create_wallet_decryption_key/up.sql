@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS wallet_decryption_key (
+    key_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    wallet VARCHAR(32) NOT NULL,
+    key_identifier VARCHAR(255) NOT NULL,
+    key_details BYTEA NOT NULL,
+    expires_at TIMESTAMP DEFAULT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS wallet_decryption_key_profile_id_wallet_index ON wallet_decryption_key (profile_id, wallet);
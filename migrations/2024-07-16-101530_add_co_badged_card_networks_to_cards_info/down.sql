@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE cards_info DROP COLUMN IF EXISTS co_badged_card_networks;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE cards_info
+ADD COLUMN IF NOT EXISTS co_badged_card_networks JSONB DEFAULT NULL;
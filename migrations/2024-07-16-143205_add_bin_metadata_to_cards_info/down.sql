@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE cards_info
+DROP COLUMN IF EXISTS is_prepaid,
+DROP COLUMN IF EXISTS is_regulated;
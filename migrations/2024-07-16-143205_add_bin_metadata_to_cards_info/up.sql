@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE cards_info
+ADD COLUMN IF NOT EXISTS is_prepaid BOOLEAN DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS is_regulated BOOLEAN DEFAULT NULL;
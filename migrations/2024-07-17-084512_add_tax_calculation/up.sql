@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS tax_provider JSONB DEFAULT NULL;
+
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS tax_details JSONB DEFAULT NULL;
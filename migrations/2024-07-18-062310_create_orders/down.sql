@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_order_id_index;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS order_id;
+
+DROP TABLE IF EXISTS orders;
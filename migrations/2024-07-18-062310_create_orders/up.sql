@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS orders (
+    order_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    customer_id VARCHAR(64),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS orders_merchant_id_index ON orders (merchant_id);
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS order_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_order_id_index ON payment_intent (merchant_id, order_id);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS estimated_arrival_at TIMESTAMP;
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'refund_updated';
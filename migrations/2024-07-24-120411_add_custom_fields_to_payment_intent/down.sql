@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_custom_fields_index;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS custom_fields;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS custom_fields_schema;
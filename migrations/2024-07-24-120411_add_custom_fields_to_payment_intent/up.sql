@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS custom_fields_schema JSONB DEFAULT NULL;
+
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS custom_fields JSONB DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_intent_custom_fields_index ON payment_intent USING GIN (custom_fields jsonb_path_ops);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE customers
+ADD COLUMN IF NOT EXISTS payment_method_order JSONB DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customers_merchant_id_email_blind_index_index;
+
+DROP INDEX IF EXISTS customers_merchant_id_phone_blind_index_index;
+
+ALTER TABLE customers
+DROP COLUMN IF EXISTS email_blind_index,
+DROP COLUMN IF EXISTS phone_blind_index;
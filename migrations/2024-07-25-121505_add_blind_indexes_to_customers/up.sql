@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE customers
+ADD COLUMN IF NOT EXISTS email_blind_index VARCHAR(64),
+ADD COLUMN IF NOT EXISTS phone_blind_index VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_email_blind_index_index ON customers (merchant_id, email_blind_index);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_phone_blind_index_index ON customers (merchant_id, phone_blind_index);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "PaymentSource" ADD VALUE IF NOT EXISTS 'async_processing';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS connector_migration_payment_method;
+DROP INDEX IF EXISTS connector_migration_merchant_id_index;
+DROP TABLE IF EXISTS connector_migration;
@@ -0,0 +1,35 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS connector_migration (
+    migration_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    source_merchant_connector_id VARCHAR(128) NOT NULL,
+    target_merchant_connector_id VARCHAR(128) NOT NULL,
+    target_connector VARCHAR(64) NOT NULL,
+    currency "Currency" NOT NULL,
+    allowed_methods JSONB NOT NULL,
+    payment_method_ids JSONB,
+    status VARCHAR(32) NOT NULL,
+    last_payment_method_id VARCHAR(64),
+    total_count INTEGER NOT NULL DEFAULT 0,
+    migrated_count INTEGER NOT NULL DEFAULT 0,
+    skipped_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS connector_migration_merchant_id_index ON connector_migration (merchant_id);
+
+CREATE TABLE IF NOT EXISTS connector_migration_payment_method (
+    migration_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    migration_method VARCHAR(32),
+    payment_id VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (migration_id, payment_method_id)
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_status_transition_merchant_id_payment_id_index;
+DROP TABLE IF EXISTS payment_status_transition;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_status_transition (
+    transition_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64),
+    object_type VARCHAR(32) NOT NULL,
+    previous_status VARCHAR(64) NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    actor VARCHAR(32) NOT NULL,
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payment_status_transition_merchant_id_payment_id_index ON payment_status_transition (merchant_id, payment_id, created_at);
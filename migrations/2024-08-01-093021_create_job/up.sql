@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS job (
+    job_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    job_type VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    input JSONB NOT NULL,
+    output JSONB,
+    total_count INTEGER NOT NULL DEFAULT 0,
+    processed_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL,
+    error_message TEXT,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS job_merchant_id_created_at_index ON job (merchant_id, created_at);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS incoming_webhook_archive_merchant_id_created_at_index;
+DROP TABLE IF EXISTS incoming_webhook_archive;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incoming_webhook_archive (
+    archive_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    business_profile_id VARCHAR(64),
+    connector_name VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64),
+    event_type VARCHAR(64),
+    body BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS incoming_webhook_archive_merchant_id_created_at_index ON incoming_webhook_archive (merchant_id, created_at);